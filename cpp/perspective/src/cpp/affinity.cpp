@@ -0,0 +1,312 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+
+#include <perspective/first.h>
+#include <perspective/affinity.h>
+#include <fstream>
+#include <mutex>
+#include <sstream>
+#include <string>
+
+#if defined(__linux__) && !defined(PSP_ENABLE_WASM)
+#include <pthread.h>
+#include <sched.h>
+#include <sys/syscall.h>
+#include <unistd.h>
+#define PSP_AFFINITY_SUPPORTED
+#endif
+
+#if defined(PSP_AFFINITY_SUPPORTED) && defined(PSP_PARALLEL_FOR)
+#include <arrow/util/thread_pool.h>
+#include <condition_variable>
+#endif
+
+namespace perspective {
+
+#ifdef PSP_AFFINITY_SUPPORTED
+
+namespace {
+
+    // From `<numaif.h>`, which is only installed with `libnuma`.
+    constexpr int PSP_MPOL_PREFERRED = 1;
+
+    // The kernel reads one fewer than `maxnode` bits of a node mask, so this
+    // is the maximum node id plus two.
+    constexpr unsigned long PSP_MAX_NODE = 65;
+
+    struct t_engine_affinity {
+        cpu_set_t m_cpus;
+        std::optional<std::uint32_t> m_numa_node;
+    };
+
+    std::mutex g_affinity_mtx;
+    std::shared_ptr<const t_engine_affinity> g_affinity;
+
+    std::shared_ptr<const t_engine_affinity>
+    get_affinity() {
+        std::lock_guard<std::mutex> lock(g_affinity_mtx);
+        return g_affinity;
+    }
+
+    // Parses a sysfs CPU list, e.g. `0-3,8-11`.
+    std::vector<std::uint32_t>
+    parse_cpu_list(const std::string& list) {
+        std::vector<std::uint32_t> cores;
+        std::stringstream ss(list);
+        std::string range;
+        while (std::getline(ss, range, ',')) {
+            if (range.empty()) {
+                continue;
+            }
+
+            auto dash = range.find('-');
+            std::uint32_t start = std::stoul(range.substr(0, dash));
+            std::uint32_t end = dash == std::string::npos
+                ? start
+                : std::stoul(range.substr(dash + 1));
+
+            for (auto core = start; core <= end; ++core) {
+                cores.push_back(core);
+            }
+        }
+
+        return cores;
+    }
+
+    std::vector<std::uint32_t>
+    numa_node_cores(std::uint32_t node) {
+        std::ifstream file(
+            "/sys/devices/system/node/node" + std::to_string(node) + "/cpulist"
+        );
+
+        if (!file) {
+            PSP_COMPLAIN_AND_ABORT("Unknown NUMA node " + std::to_string(node));
+        }
+
+        std::string list;
+        std::getline(file, list);
+        return parse_cpu_list(list);
+    }
+
+    void
+    set_memory_policy(int mode, unsigned long nodemask) {
+        if (syscall(SYS_set_mempolicy, mode, &nodemask, PSP_MAX_NODE) != 0) {
+            PSP_COMPLAIN_AND_ABORT("Failed to set NUMA memory policy");
+        }
+    }
+
+    void
+    apply_affinity(const t_engine_affinity& affinity) {
+        if (pthread_setaffinity_np(
+                pthread_self(), sizeof(cpu_set_t), &affinity.m_cpus
+            )
+            != 0) {
+            PSP_COMPLAIN_AND_ABORT("Failed to set thread affinity");
+        }
+
+        if (affinity.m_numa_node.has_value()) {
+            set_memory_policy(
+                PSP_MPOL_PREFERRED, 1UL << *affinity.m_numa_node
+            );
+        }
+    }
+
+#ifdef PSP_PARALLEL_FOR
+    // Resizes the thread pool, then runs one task on each of its threads
+    // which pins it. Each task waits for every other to start, so no thread
+    // runs two of them.
+    void
+    pin_thread_pool(const t_engine_affinity& affinity, int num_threads) {
+        auto* pool = arrow::internal::GetCpuThreadPool();
+        if (!pool->SetCapacity(num_threads).ok()) {
+            PSP_COMPLAIN_AND_ABORT("Failed to resize thread pool");
+        }
+
+        std::mutex mtx;
+        std::condition_variable cv;
+        int started = 0;
+        bool failed = false;
+        std::vector<arrow::Future<>> tasks;
+        for (int ii = 0; ii < num_threads; ++ii) {
+            auto task = pool->Submit([&]() {
+                bool ok = true;
+                try {
+                    apply_affinity(affinity);
+                } catch (...) {
+                    ok = false;
+                }
+
+                std::unique_lock<std::mutex> lock(mtx);
+                failed = failed || !ok;
+                ++started;
+                cv.notify_all();
+                cv.wait(lock, [&]() { return started == num_threads; });
+            });
+
+            if (!task.ok()) {
+                PSP_COMPLAIN_AND_ABORT("Failed to pin thread pool");
+            }
+
+            tasks.push_back(*task);
+        }
+
+        for (auto& task : tasks) {
+            task.Wait();
+        }
+
+        if (failed) {
+            PSP_COMPLAIN_AND_ABORT("Failed to pin thread pool");
+        }
+    }
+#endif
+
+} // namespace
+
+// A thread's own affinity and memory policy, while in a `t_affinity_scope`.
+struct t_saved_affinity {
+    cpu_set_t m_cpus;
+    bool m_has_memory_policy = false;
+    int m_mode = 0;
+    unsigned long m_nodemask = 0;
+};
+
+namespace {
+
+    std::unique_ptr<t_saved_affinity>
+    save_affinity(bool memory_policy) {
+        auto saved = std::make_unique<t_saved_affinity>();
+        pthread_getaffinity_np(
+            pthread_self(), sizeof(cpu_set_t), &saved->m_cpus
+        );
+
+        saved->m_has_memory_policy = memory_policy;
+        if (memory_policy) {
+            syscall(
+                SYS_get_mempolicy,
+                &saved->m_mode,
+                &saved->m_nodemask,
+                PSP_MAX_NODE,
+                nullptr,
+                0
+            );
+        }
+
+        return saved;
+    }
+
+    // Errors are ignored, as this may run during unwinding.
+    void
+    restore_affinity(const t_saved_affinity& saved) {
+        pthread_setaffinity_np(
+            pthread_self(), sizeof(cpu_set_t), &saved.m_cpus
+        );
+
+        if (saved.m_has_memory_policy) {
+            syscall(
+                SYS_set_mempolicy,
+                saved.m_mode,
+                &saved.m_nodemask,
+                PSP_MAX_NODE
+            );
+        }
+    }
+
+    // Applies `affinity` to the calling thread, returning what to restore.
+    std::unique_ptr<t_saved_affinity>
+    enter_affinity(const t_engine_affinity& affinity) {
+        auto saved = save_affinity(affinity.m_numa_node.has_value());
+        try {
+            apply_affinity(affinity);
+        } catch (...) {
+            restore_affinity(*saved);
+            throw;
+        }
+
+        return saved;
+    }
+
+} // namespace
+
+t_affinity_scope::t_affinity_scope() {
+    auto affinity = get_affinity();
+    if (affinity != nullptr) {
+        m_saved = enter_affinity(*affinity);
+    }
+}
+
+t_affinity_scope::~t_affinity_scope() {
+    if (m_saved != nullptr) {
+        restore_affinity(*m_saved);
+    }
+}
+
+void
+set_engine_affinity(const t_affinity_options& options) {
+    auto cores = options.m_cores;
+    if (options.m_numa_node.has_value()) {
+        if (*options.m_numa_node >= PSP_MAX_NODE - 1) {
+            PSP_COMPLAIN_AND_ABORT("NUMA node out of range");
+        }
+
+        if (cores.empty()) {
+            cores = numa_node_cores(*options.m_numa_node);
+        }
+    }
+
+    auto affinity = std::make_shared<t_engine_affinity>();
+    affinity->m_numa_node = options.m_numa_node;
+    CPU_ZERO(&affinity->m_cpus);
+    for (auto core : cores) {
+        if (core >= CPU_SETSIZE) {
+            PSP_COMPLAIN_AND_ABORT("Core out of range " + std::to_string(core));
+        }
+
+        CPU_SET(core, &affinity->m_cpus);
+    }
+
+    if (cores.empty()) {
+        if (sched_getaffinity(0, sizeof(cpu_set_t), &affinity->m_cpus) != 0) {
+            PSP_COMPLAIN_AND_ABORT("Failed to read process affinity");
+        }
+    }
+
+    // Check that `affinity` applies before any request depends on it.
+    restore_affinity(*enter_affinity(*affinity));
+
+#ifdef PSP_PARALLEL_FOR
+    auto num_threads = options.m_num_threads.value_or(
+        cores.empty() ? CPU_COUNT(&affinity->m_cpus) : cores.size()
+    );
+
+    pin_thread_pool(*affinity, static_cast<int>(num_threads));
+#endif
+
+    std::lock_guard<std::mutex> lock(g_affinity_mtx);
+    g_affinity = std::move(affinity);
+}
+
+#else
+
+struct t_saved_affinity {};
+
+t_affinity_scope::t_affinity_scope() = default;
+t_affinity_scope::~t_affinity_scope() = default;
+
+void
+set_engine_affinity(const t_affinity_options& /* options */) {
+    PSP_COMPLAIN_AND_ABORT("Engine affinity is only supported on Linux");
+}
+
+#endif
+
+} // namespace perspective
@@ -167,13 +167,29 @@ t_aggregate::init() {
         } break;
         case AGGTYPE_COUNT: {
             switch (m_icolumns[0]->get_dtype()) {
-                case DTYPE_STR: {
+                case DTYPE_STR:
+                case DTYPE_LIST:
+                case DTYPE_JSON:
+                case DTYPE_BINARY: {
                     build_aggregate<t_aggimpl_count<
                         std::uint64_t,
                         std::uint64_t,
                         std::uint64_t>>();
                 } break;
+                case DTYPE_UUID: {
+                    build_aggregate<t_aggimpl_count<
+                        t_uuid,
+                        std::uint64_t,
+                        std::uint64_t>>();
+                } break;
+                case DTYPE_IPADDR: {
+                    build_aggregate<t_aggimpl_count<
+                        t_ipaddr,
+                        std::uint64_t,
+                        std::uint64_t>>();
+                } break;
                 case DTYPE_TIME:
+                case DTYPE_DURATION:
                 case DTYPE_INT64: {
                     build_aggregate<t_aggimpl_count<
                         std::int64_t,
@@ -319,6 +335,7 @@ t_aggregate::init() {
         case AGGTYPE_HIGH_WATER_MARK: {
             switch (m_icolumns[0]->get_dtype()) {
                 case DTYPE_TIME:
+                case DTYPE_DURATION:
                 case DTYPE_INT64: {
                     build_aggregate<t_aggimpl_hwm<
                         std::int64_t,
@@ -390,6 +407,7 @@ t_aggregate::init() {
         case AGGTYPE_LOW_WATER_MARK: {
             switch (m_icolumns[0]->get_dtype()) {
                 case DTYPE_TIME:
+                case DTYPE_DURATION:
                 case DTYPE_INT64: {
                     build_aggregate<t_aggimpl_lwm<
                         std::int64_t,
@@ -235,6 +235,12 @@ t_aggspec::agg_str() const {
         case AGGTYPE_STANDARD_DEVIATION: {
             return "stddev";
         }
+        case AGGTYPE_TOTAL_BYTES: {
+            return "total_bytes";
+        }
+        case AGGTYPE_MAX_BYTES: {
+            return "max_bytes";
+        }
         default: {
             PSP_COMPLAIN_AND_ABORT("Unknown agg type");
             return "unknown";
@@ -267,6 +273,9 @@ get_simple_accumulator_type(t_dtype coltype) {
         case DTYPE_FLOAT32: {
             return DTYPE_FLOAT64;
         }
+        case DTYPE_DURATION: {
+            return DTYPE_DURATION;
+        }
 
         default: {
             PSP_COMPLAIN_AND_ABORT("Unexpected coltype");
@@ -396,6 +405,10 @@ t_aggspec::get_output_specs(const t_schema& schema) const {
         case AGGTYPE_DISTINCT_COUNT: {
             return mk_col_name_type_vec(name(), DTYPE_UINT32);
         }
+        case AGGTYPE_TOTAL_BYTES:
+        case AGGTYPE_MAX_BYTES: {
+            return mk_col_name_type_vec(name(), DTYPE_INT64);
+        }
         default: {
             PSP_COMPLAIN_AND_ABORT("Unknown agg type");
         }
@@ -14,6 +14,8 @@
 #include <perspective/arrow_csv.h>
 #include <arrow/util/value_parsing.h>
 #include <arrow/io/memory.h>
+#include <arrow/type_traits.h>
+#include <cctype>
 
 #ifdef PSP_ENABLE_WASM
 // This causes build warnings
@@ -423,14 +425,63 @@ parseAsArrowTimestamp(const std::string& input) {
     return std::nullopt;
 }
 
+/**
+ * @brief Remove `sep` where it separates groups of 3 digits, e.g.
+ * `1.234.567,5` becomes `1234567,5` for `sep = '.'`.
+ */
+static std::string
+strip_thousands_separator(const std::string_view& csv, char sep) {
+    std::string out;
+    out.reserve(csv.size());
+    auto is_digit = [&](std::size_t i) {
+        return i < csv.size() && std::isdigit(csv[i]) != 0;
+    };
+
+    for (std::size_t i = 0; i < csv.size(); ++i) {
+        if (csv[i] == sep && i > 0 && is_digit(i - 1) && is_digit(i + 1)
+            && is_digit(i + 2) && is_digit(i + 3) && !is_digit(i + 4)) {
+            continue;
+        }
+
+        out.push_back(csv[i]);
+    }
+
+    return out;
+}
+
+static std::shared_ptr<::arrow::Table>
+read_csv(
+    const std::string_view& csv,
+    const arrow::csv::ReadOptions& read_options,
+    const arrow::csv::ParseOptions& parse_options,
+    const arrow::csv::ConvertOptions& convert_options
+) {
+    const arrow::io::IOContext& io_context = arrow::io::default_io_context();
+    auto input = std::make_shared<arrow::io::BufferReader>(csv);
+    auto maybe_reader = arrow::csv::TableReader::Make(
+        io_context, input, read_options, parse_options, convert_options
+    );
+
+    if (!maybe_reader.ok()) {
+        PSP_COMPLAIN_AND_ABORT(maybe_reader.status().ToString());
+    }
+
+    std::shared_ptr<arrow::csv::TableReader> reader = *maybe_reader;
+
+    auto maybe_table = reader->Read();
+    if (!maybe_table.ok()) {
+        PSP_COMPLAIN_AND_ABORT(maybe_table.status().ToString());
+    }
+    return *maybe_table;
+}
+
 std::shared_ptr<::arrow::Table>
 csvToTable(
     const std::string_view& csv,
     bool is_update,
-    std::unordered_map<std::string, std::shared_ptr<arrow::DataType>>& schema
+    std::unordered_map<std::string, std::shared_ptr<arrow::DataType>>& schema,
+    const CsvOptions& options
 ) {
-    const arrow::io::IOContext& io_context = arrow::io::default_io_context();
-    auto input = std::make_shared<arrow::io::BufferReader>(csv);
     auto read_options = arrow::csv::ReadOptions::Defaults();
     auto parse_options = arrow::csv::ParseOptions::Defaults();
     auto convert_options = arrow::csv::ConvertOptions::Defaults();
@@ -441,25 +492,72 @@ csvToTable(
     // #endif
     read_options.use_threads = false;
     parse_options.newlines_in_values = true;
+    parse_options.delimiter = options.delimiter;
+    parse_options.quote_char = options.quote_char;
+    convert_options.decimal_point = options.decimal_point;
+    if (options.header_rows == 0) {
+        read_options.autogenerate_column_names = true;
+    } else {
+        read_options.skip_rows =
+            static_cast<std::int32_t>(options.header_rows - 1);
+    }
+
+    if (options.infer_sample_bytes.has_value()) {
+        read_options.block_size = *options.infer_sample_bytes;
+    }
 
+    if (!options.null_values.empty()) {
+        convert_options.null_values = options.null_values;
+        convert_options.strings_can_be_null = true;
+    }
+
+    std::vector<std::shared_ptr<arrow::TimestampParser>> parsers;
+    for (const auto& format : options.timestamp_formats) {
+        parsers.push_back(arrow::TimestampParser::MakeStrptime(format));
+    }
+
+    const auto& defaults = is_update ? DATE_READERS : DATE_PARSERS;
+    parsers.insert(parsers.end(), defaults.begin(), defaults.end());
+    convert_options.timestamp_parsers = std::move(parsers);
     if (is_update) {
         convert_options.column_types = std::move(schema);
-        convert_options.timestamp_parsers = DATE_READERS;
-    } else {
-        convert_options.timestamp_parsers = DATE_PARSERS;
     }
 
-    auto maybe_reader = arrow::csv::TableReader::Make(
-        io_context, input, read_options, parse_options, convert_options
-    );
+    std::string stripped;
+    std::string_view input = csv;
+    if (options.thousands_separator.has_value()) {
+        if (*options.thousands_separator == options.delimiter
+            || *options.thousands_separator == options.decimal_point) {
+            PSP_COMPLAIN_AND_ABORT(
+                "CSV thousands separator must differ from the delimiter and "
+                "decimal point"
+            );
+        }
 
-    std::shared_ptr<arrow::csv::TableReader> reader = *maybe_reader;
+        stripped = strip_thousands_separator(csv, *options.thousands_separator);
+        input = stripped;
+    }
 
-    auto maybe_table = reader->Read();
-    if (!maybe_table.ok()) {
-        PSP_COMPLAIN_AND_ABORT(maybe_table.status().ToString());
+    auto table = read_csv(input, read_options, parse_options, convert_options);
+    if (is_update || !options.infer_int_as_float) {
+        return table;
     }
-    return *maybe_table;
+
+    // Re-read with integer columns promoted to `float`, as Arrow's CSV
+    // inference has no promotion rules of its own.
+    bool has_int_column = false;
+    for (const auto& field : table->schema()->fields()) {
+        if (arrow::is_integer(field->type()->id())) {
+            convert_options.column_types[field->name()] = arrow::float64();
+            has_int_column = true;
+        }
+    }
+
+    if (!has_int_column) {
+        return table;
+    }
+
+    return read_csv(input, read_options, parse_options, convert_options);
 }
 
 } // namespace perspective::apachearrow
\ No newline at end of file
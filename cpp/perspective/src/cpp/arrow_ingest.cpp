@@ -0,0 +1,69 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+#include <perspective/arrow_ingest.h>
+#include <perspective/base.h>
+#include <arrow/buffer.h>
+#include <sstream>
+
+namespace perspective {
+
+arrow::Status
+ArrowIngest::Listener::OnRecordBatchDecoded(
+    std::shared_ptr<arrow::RecordBatch> batch
+) {
+    m_batches.push_back(std::move(batch));
+    return arrow::Status::OK();
+}
+
+ArrowIngest::ArrowIngest() :
+    m_listener(std::make_shared<Listener>()),
+    m_decoder(std::make_unique<arrow::ipc::StreamDecoder>(m_listener)),
+    m_num_rows(0) {}
+
+std::shared_ptr<arrow::Table>
+ArrowIngest::consume(const std::string_view& chunk) {
+    // The decoder holds on to incomplete messages across calls, so it must
+    // own the chunk rather than borrow the request's buffer.
+    auto status =
+        m_decoder->Consume(arrow::Buffer::FromString(std::string(chunk)));
+
+    if (!status.ok()) {
+        std::stringstream ss;
+        ss << "Failed to decode Arrow stream chunk: " << status.ToString();
+        PSP_COMPLAIN_AND_ABORT(ss.str());
+    }
+
+    if (m_listener->m_batches.empty()) {
+        return nullptr;
+    }
+
+    auto batches = std::move(m_listener->m_batches);
+    m_listener->m_batches.clear();
+    auto table = arrow::Table::FromRecordBatches(batches);
+    if (!table.ok()) {
+        std::stringstream ss;
+        ss << "Failed to read Arrow stream record batch: "
+           << table.status().ToString();
+        PSP_COMPLAIN_AND_ABORT(ss.str());
+    }
+
+    m_num_rows += (*table)->num_rows();
+    return *table;
+}
+
+std::uint64_t
+ArrowIngest::num_rows() const {
+    return m_num_rows;
+}
+
+} // namespace perspective
@@ -14,8 +14,13 @@
 #include <exception>
 #include <memory>
 #include <mutex>
+#include <set>
 #include <perspective/arrow_loader.h>
+#include <perspective/base64.h>
+#include <perspective/uuid.h>
+#include <perspective/ipaddr.h>
 #include "perspective/exception.h"
+#include <arrow/c/bridge.h>
 
 namespace perspective::apachearrow {
 
@@ -48,6 +53,28 @@ load_stream(
     }
 }
 
+void
+load_c_stream(ArrowArrayStream* stream, std::shared_ptr<arrow::Table>& table) {
+    auto status = arrow::ImportRecordBatchReader(stream);
+    if (!status.ok()) {
+        std::stringstream ss;
+        ss << "Failed to import ArrowArrayStream: "
+           << status.status().ToString() << std::endl;
+        PSP_COMPLAIN_AND_ABORT(ss.str());
+    } else {
+        auto batch_reader = *status;
+        auto status2 = batch_reader->ToTable();
+        if (!status2.ok()) {
+            std::stringstream ss;
+            ss << "Failed to read ArrowArrayStream record batch: "
+               << status2.status().ToString() << std::endl;
+            PSP_COMPLAIN_AND_ABORT(ss.str());
+        };
+
+        table = *status2;
+    }
+}
+
 void
 load_file(
     const std::uint8_t* ptr,
@@ -99,10 +126,13 @@ ArrowLoader::~ArrowLoader() = default;
 
 t_dtype
 convert_type(const std::string& src) {
-    if (src == "dictionary" || src == "utf8" || src == "binary"
-        || src == "large_utf8") {
+    if (src == "dictionary" || src == "utf8" || src == "large_utf8") {
         return DTYPE_STR;
     }
+    if (src == "binary" || src == "large_binary"
+        || src == "fixed_size_binary") {
+        return DTYPE_BINARY;
+    }
     if (src == "bool") {
         return DTYPE_BOOL;
     }
@@ -142,6 +172,12 @@ convert_type(const std::string& src) {
     if (src == "date32" || src == "date64") {
         return DTYPE_DATE;
     }
+    if (src == "duration" || src == "day_time_interval") {
+        return DTYPE_DURATION;
+    }
+    if (src == "list" || src == "large_list") {
+        return DTYPE_LIST;
+    }
     if (src == "null") {
         return DTYPE_STR;
     }
@@ -151,6 +187,53 @@ convert_type(const std::string& src) {
     return DTYPE_STR;
 }
 
+// As `convert_type`, but reads `fixed_size_binary(16)` fields tagged with
+// the canonical `arrow.uuid` extension name as `DTYPE_UUID`.
+t_dtype
+convert_field_type(const arrow::Field& field) {
+    const auto& type = field.type();
+    if (type->id() == arrow::Type::FIXED_SIZE_BINARY
+        && std::static_pointer_cast<arrow::FixedSizeBinaryType>(type)
+                   ->byte_width()
+            == sizeof(t_uuid)
+        && field.HasMetadata()) {
+        auto name = field.metadata()->Get("ARROW:extension:name");
+        if (name.ok() && *name == "arrow.uuid") {
+            return DTYPE_UUID;
+        }
+    }
+
+    return convert_type(type->name());
+}
+
+// Replaces struct columns with one column per field, named by their dot
+// path, e.g. `order.price`. `Flatten()` only descends one level per call, so
+// repeat until no structs remain.
+void
+flatten_structs(std::shared_ptr<arrow::Table>& table) {
+    auto has_struct = [](const std::shared_ptr<arrow::Table>& table) {
+        for (const auto& field : table->schema()->fields()) {
+            if (field->type()->id() == arrow::Type::STRUCT) {
+                return true;
+            }
+        }
+
+        return false;
+    };
+
+    while (has_struct(table)) {
+        arrow::Result<std::shared_ptr<arrow::Table>> flat = table->Flatten();
+        if (!flat.ok()) {
+            std::stringstream ss;
+            ss << "Failed to flatten struct columns: "
+               << flat.status().ToString() << std::endl;
+            PSP_COMPLAIN_AND_ABORT(ss.str());
+        }
+
+        table = *flat;
+    }
+}
+
 void
 ArrowLoader::initialize(const std::uint8_t* ptr, const uint32_t length) {
     if (std::memcmp("ARROW1", (const void*)ptr, 6) == 0) {
@@ -159,12 +242,31 @@ ArrowLoader::initialize(const std::uint8_t* ptr, const uint32_t length) {
         load_stream(ptr, length, m_table);
     }
 
+    init_table();
+}
+
+void
+ArrowLoader::initialize(ArrowArrayStream* stream) {
+    load_c_stream(stream, m_table);
+    init_table();
+}
+
+void
+ArrowLoader::initialize(std::shared_ptr<arrow::Table> table) {
+    m_table = std::move(table);
+    init_table();
+}
+
+void
+ArrowLoader::init_table() {
+    flatten_structs(m_table);
+
     std::shared_ptr<arrow::Schema> schema = m_table->schema();
     std::vector<std::shared_ptr<arrow::Field>> fields = schema->fields();
 
     for (const auto& field : fields) {
         m_names.push_back(field->name());
-        m_types.push_back(convert_type(field->type()->name()));
+        m_types.push_back(convert_field_type(*field));
     }
 }
 
@@ -173,9 +275,10 @@ ArrowLoader::init_csv(
     const std::string_view& csv,
     bool is_update,
     std::unordered_map<std::string, std::shared_ptr<arrow::DataType>>&
-        psp_schema
+        psp_schema,
+    const CsvOptions& options
 ) {
-    m_table = csvToTable(csv, is_update, psp_schema);
+    m_table = csvToTable(csv, is_update, psp_schema, options);
 
     std::shared_ptr<arrow::Schema> schema = m_table->schema();
     std::vector<std::shared_ptr<arrow::Field>> fields = schema->fields();
@@ -269,6 +372,204 @@ iter_col_copy(
     }
 }
 
+// The bytes of the `i`th element of a binary, large binary or fixed size
+// binary array.
+std::string_view
+binary_view(const std::shared_ptr<arrow::Array>& array, int64_t i) {
+    switch (array->type()->id()) {
+        case arrow::LargeBinaryType::type_id:
+            return std::static_pointer_cast<arrow::LargeBinaryArray>(array)
+                ->GetView(i);
+        case arrow::FixedSizeBinaryType::type_id:
+            return std::static_pointer_cast<arrow::FixedSizeBinaryArray>(array)
+                ->GetView(i);
+        default:
+            return std::static_pointer_cast<arrow::BinaryArray>(array)->GetView(
+                i
+            );
+    }
+}
+
+// Serializes the `i`th element of an arrow array as JSON text, recursing
+// into nested lists. `DTYPE_LIST` columns store their cells this way.
+void
+write_list_value(
+    const std::shared_ptr<arrow::Array>& values, int64_t i, std::string& out
+) {
+    if (values->IsNull(i)) {
+        out += "null";
+        return;
+    }
+
+    switch (values->type()->id()) {
+        case arrow::BooleanType::type_id: {
+            auto scol = std::static_pointer_cast<arrow::BooleanArray>(values);
+            out += scol->Value(i) ? "true" : "false";
+        } break;
+        case arrow::LargeStringType::type_id:
+        case arrow::StringType::type_id: {
+            std::string elem = values->GetScalar(i).ValueOrDie()->ToString();
+            out += '"';
+            for (char c : elem) {
+                switch (c) {
+                    case '"':
+                        out += "\\\"";
+                        break;
+                    case '\\':
+                        out += "\\\\";
+                        break;
+                    case '\n':
+                        out += "\\n";
+                        break;
+                    case '\t':
+                        out += "\\t";
+                        break;
+                    case '\r':
+                        out += "\\r";
+                        break;
+                    default:
+                        out += c;
+                }
+            }
+            out += '"';
+        } break;
+        case arrow::ListType::type_id: {
+            auto scol = std::static_pointer_cast<arrow::ListArray>(values);
+            std::shared_ptr<arrow::Array> slice = scol->value_slice(i);
+            out += '[';
+            for (int64_t j = 0; j < slice->length(); ++j) {
+                if (j > 0) {
+                    out += ',';
+                }
+                write_list_value(slice, j, out);
+            }
+            out += ']';
+        } break;
+        case arrow::LargeListType::type_id: {
+            auto scol = std::static_pointer_cast<arrow::LargeListArray>(values);
+            std::shared_ptr<arrow::Array> slice = scol->value_slice(i);
+            out += '[';
+            for (int64_t j = 0; j < slice->length(); ++j) {
+                if (j > 0) {
+                    out += ',';
+                }
+                write_list_value(slice, j, out);
+            }
+            out += ']';
+        } break;
+        default: {
+            out += values->GetScalar(i).ValueOrDie()->ToString();
+        }
+    }
+}
+
+// Fills a `DTYPE_UUID` column from 16 byte binary values, or from UUID
+// strings as read from CSV.
+void
+copy_uuid_array(
+    const std::shared_ptr<t_column>& dest,
+    const std::shared_ptr<arrow::Array>& src,
+    const int64_t offset,
+    const int64_t len
+) {
+    t_uuid uuid;
+    for (std::uint32_t i = 0; i < len; ++i) {
+        if (src->IsNull(i)) {
+            dest->set_nth<t_uuid>(offset + i, t_uuid{});
+            continue;
+        }
+
+        switch (src->type()->id()) {
+            case arrow::StringType::type_id:
+            case arrow::LargeStringType::type_id: {
+                std::string text = src->GetScalar(i).ValueOrDie()->ToString();
+                if (!parse_uuid(text, uuid)) {
+                    PSP_COMPLAIN_AND_ABORT(
+                        "Expected UUID, found `" + text + "`"
+                    );
+                }
+            } break;
+            case arrow::BinaryType::type_id:
+            case arrow::LargeBinaryType::type_id:
+            case arrow::FixedSizeBinaryType::type_id: {
+                std::string_view bytes = binary_view(src, i);
+                if (bytes.size() != sizeof(t_uuid)) {
+                    PSP_COMPLAIN_AND_ABORT(
+                        "Expected 16 byte UUID, found "
+                        + std::to_string(bytes.size()) + " bytes"
+                    );
+                }
+
+                std::memcpy(uuid.m_bytes, bytes.data(), sizeof(t_uuid));
+            } break;
+            default: {
+                std::stringstream ss;
+                ss << "Could not load Arrow column of type `"
+                   << src->type()->ToString() << "` as UUID." << std::endl;
+                PSP_COMPLAIN_AND_ABORT(ss.str());
+            }
+        }
+
+        dest->set_nth<t_uuid>(offset + i, uuid);
+    }
+}
+
+// Fills a `DTYPE_IPADDR` column from address strings, or from 4 (IPv4) or
+// 16 (IPv6) byte binary values in network order.
+void
+copy_ipaddr_array(
+    const std::shared_ptr<t_column>& dest,
+    const std::shared_ptr<arrow::Array>& src,
+    const int64_t offset,
+    const int64_t len
+) {
+    t_ipaddr addr;
+    for (std::uint32_t i = 0; i < len; ++i) {
+        if (src->IsNull(i)) {
+            dest->set_nth<t_ipaddr>(offset + i, t_ipaddr{});
+            continue;
+        }
+
+        switch (src->type()->id()) {
+            case arrow::StringType::type_id:
+            case arrow::LargeStringType::type_id: {
+                std::string text = src->GetScalar(i).ValueOrDie()->ToString();
+                if (!parse_ipaddr(text, addr)) {
+                    PSP_COMPLAIN_AND_ABORT(
+                        "Expected IP address, found `" + text + "`"
+                    );
+                }
+            } break;
+            case arrow::BinaryType::type_id:
+            case arrow::LargeBinaryType::type_id:
+            case arrow::FixedSizeBinaryType::type_id: {
+                std::string_view bytes = binary_view(src, i);
+                if (bytes.size() == 4) {
+                    addr = t_ipaddr::from_v4(
+                        reinterpret_cast<const std::uint8_t*>(bytes.data())
+                    );
+                } else if (bytes.size() == sizeof(t_ipaddr)) {
+                    std::memcpy(addr.m_bytes, bytes.data(), sizeof(t_ipaddr));
+                } else {
+                    PSP_COMPLAIN_AND_ABORT(
+                        "Expected 4 or 16 byte IP address, found "
+                        + std::to_string(bytes.size()) + " bytes"
+                    );
+                }
+            } break;
+            default: {
+                std::stringstream ss;
+                ss << "Could not load Arrow column of type `"
+                   << src->type()->ToString() << "` as IP address."
+                   << std::endl;
+                PSP_COMPLAIN_AND_ABORT(ss.str());
+            }
+        }
+
+        dest->set_nth<t_ipaddr>(offset + i, addr);
+    }
+}
+
 void
 copy_array(
     const std::shared_ptr<t_column>& dest,
@@ -276,6 +577,16 @@ copy_array(
     const int64_t offset,
     const int64_t len
 ) {
+    if (dest->get_dtype() == DTYPE_UUID) {
+        copy_uuid_array(dest, src, offset, len);
+        return;
+    }
+
+    if (dest->get_dtype() == DTYPE_IPADDR) {
+        copy_ipaddr_array(dest, src, offset, len);
+        return;
+    }
+
     switch (src->type()->id()) {
         case arrow::DictionaryType::type_id: {
             // If there are duplicate values in the dictionary at different
@@ -365,7 +676,23 @@ copy_array(
                 dest->set_nth(offset + i, elem);
             }
         } break;
+        case arrow::LargeListType::type_id:
+        case arrow::ListType::type_id: {
+            std::string elem;
+            for (std::uint32_t i = 0; i < len; ++i) {
+                elem.clear();
+                write_list_value(src, i, elem);
+                dest->set_nth(offset + i, elem);
+            }
+        } break;
         case arrow::BinaryType::type_id:
+        case arrow::LargeBinaryType::type_id:
+        case arrow::FixedSizeBinaryType::type_id: {
+            // Binary cells are stored as base64 text.
+            for (std::uint32_t i = 0; i < len; ++i) {
+                dest->set_nth(offset + i, base64_encode(binary_view(src, i)));
+            }
+        } break;
         case arrow::StringType::type_id: {
             std::shared_ptr<arrow::StringArray> scol =
                 std::static_pointer_cast<arrow::StringArray>(src);
@@ -477,6 +804,48 @@ copy_array(
                 } break;
             }
         } break;
+        case arrow::DurationType::type_id: {
+            std::shared_ptr<arrow::DurationType> tunit =
+                std::static_pointer_cast<arrow::DurationType>(src->type());
+            auto scol = std::static_pointer_cast<arrow::DurationArray>(src);
+            const int64_t* vals = scol->raw_values();
+            switch (tunit->unit()) {
+                case arrow::TimeUnit::MILLI: {
+                    std::memcpy(
+                        dest->get_nth<std::int64_t>(offset),
+                        (void*)vals,
+                        len * 8
+                    );
+                } break;
+                case arrow::TimeUnit::NANO: {
+                    for (uint32_t i = 0; i < len; i++) {
+                        dest->set_nth<int64_t>(offset + i, vals[i] / 1000000);
+                    }
+                } break;
+                case arrow::TimeUnit::MICRO: {
+                    for (uint32_t i = 0; i < len; i++) {
+                        dest->set_nth<int64_t>(offset + i, vals[i] / 1000);
+                    }
+                } break;
+                case arrow::TimeUnit::SECOND: {
+                    for (uint32_t i = 0; i < len; i++) {
+                        dest->set_nth<int64_t>(offset + i, vals[i] * 1000);
+                    }
+                } break;
+            }
+        } break;
+        case arrow::DayTimeIntervalType::type_id: {
+            auto scol =
+                std::static_pointer_cast<arrow::DayTimeIntervalArray>(src);
+            for (uint32_t i = 0; i < len; i++) {
+                auto interval = scol->GetValue(i);
+                dest->set_nth<int64_t>(
+                    offset + i,
+                    static_cast<std::int64_t>(interval.days) * 86400000
+                        + interval.milliseconds
+                );
+            }
+        } break;
         case arrow::Date64Type::type_id: {
             std::shared_ptr<arrow::Date64Type> date_type =
                 std::static_pointer_cast<arrow::Date64Type>(src->type());
@@ -586,7 +955,8 @@ copy_array(
         case DTYPE_UINT32: {                                                   \
             iter_col_copy<ARRAY_TYPE, std::uint32_t>(col, array, offset, len); \
         } break;                                                               \
-        case DTYPE_INT64: {                                                    \
+        case DTYPE_INT64:                                                      \
+        case DTYPE_DURATION: {                                                 \
             iter_col_copy<ARRAY_TYPE, std::int64_t>(col, array, offset, len);  \
         } break;                                                               \
         case DTYPE_UINT64: {                                                   \
@@ -637,7 +1007,16 @@ ArrowLoader::fill_column(
 
         // `type`: arrow array dtype converted to `t_dtype`
         // `column_dtype`: dtype of the `t_column`
-        if (type != column_dtype) {
+        // Lists and JSON documents are stored as JSON text, and binary
+        // values as base64 text, so a string array may fill them directly.
+        // UUID and IP address columns parse strings and copy binary values.
+        if (type != column_dtype
+            && !(type == DTYPE_STR
+                 && (column_dtype == DTYPE_LIST || column_dtype == DTYPE_JSON
+                     || column_dtype == DTYPE_BINARY))
+            && !((type == DTYPE_STR || type == DTYPE_BINARY)
+                 && (column_dtype == DTYPE_UUID
+                     || column_dtype == DTYPE_IPADDR))) {
             LOG_DEBUG(
                 "Type " << type << " != " << column_dtype << " for column "
                         << name << " - filling iteratively"
@@ -737,4 +1116,44 @@ ArrowLoader::types() const {
     return m_types;
 }
 
+std::map<std::string, std::vector<std::string>>
+ArrowLoader::ordered_dictionaries() const {
+    std::map<std::string, std::vector<std::string>> rval;
+    const auto& fields = m_table->schema()->fields();
+    for (std::size_t cidx = 0; cidx < fields.size(); ++cidx) {
+        const auto& type = fields[cidx]->type();
+        if (type->id() != arrow::DictionaryType::type_id) {
+            continue;
+        }
+
+        auto dict_type = std::static_pointer_cast<arrow::DictionaryType>(type);
+        if (!dict_type->ordered()
+            || dict_type->value_type()->id() != arrow::StringType::type_id) {
+            continue;
+        }
+
+        // Chunks may carry different dictionaries, so keep the first
+        // occurrence of each value.
+        std::vector<std::string> categories;
+        std::set<std::string> seen;
+        for (const auto& chunk : m_table->column(cidx)->chunks()) {
+            auto dict = std::static_pointer_cast<arrow::StringArray>(
+                std::static_pointer_cast<arrow::DictionaryArray>(chunk)
+                    ->dictionary()
+            );
+
+            for (std::int64_t i = 0; i < dict->length(); ++i) {
+                auto value = dict->GetString(i);
+                if (seen.insert(value).second) {
+                    categories.push_back(std::move(value));
+                }
+            }
+        }
+
+        rval[fields[cidx]->name()] = std::move(categories);
+    }
+
+    return rval;
+}
+
 } // namespace perspective::apachearrow
\ No newline at end of file
@@ -15,6 +15,8 @@
 #include <cstdint>
 #include <limits>
 #include <perspective/exception.h>
+#include <perspective/uuid.h>
+#include <perspective/ipaddr.h>
 
 namespace perspective {
 
@@ -35,7 +37,8 @@ is_numeric_type(t_dtype dtype) {
         case DTYPE_INT32:
         case DTYPE_INT64:
         case DTYPE_FLOAT32:
-        case DTYPE_FLOAT64: {
+        case DTYPE_FLOAT64:
+        case DTYPE_DURATION: {
             return true;
         } break;
         default: {
@@ -59,6 +62,7 @@ is_linear_order_type(t_dtype dtype) {
         case DTYPE_FLOAT64:
         case DTYPE_DATE:
         case DTYPE_TIME:
+        case DTYPE_DURATION:
         case DTYPE_BOOL: {
             return true;
         } break;
@@ -89,8 +93,14 @@ is_deterministic_sized(t_dtype dtype) {
         case DTYPE_FLOAT64:
         case DTYPE_FLOAT32:
         case DTYPE_STR:
+        case DTYPE_LIST:
+        case DTYPE_JSON:
+        case DTYPE_BINARY:
         case DTYPE_TIME:
+        case DTYPE_DURATION:
         case DTYPE_DATE:
+        case DTYPE_UUID:
+        case DTYPE_IPADDR:
         case DTYPE_F64PAIR: {
             return true;
         }
@@ -133,15 +143,25 @@ get_dtype_size(t_dtype dtype) {
         case DTYPE_FLOAT32: {
             return sizeof(float);
         }
-        case DTYPE_STR: {
+        case DTYPE_STR:
+        case DTYPE_LIST:
+        case DTYPE_JSON:
+        case DTYPE_BINARY: {
             return sizeof(t_uindex);
         }
-        case DTYPE_TIME: {
+        case DTYPE_TIME:
+        case DTYPE_DURATION: {
             return sizeof(std::int64_t);
         }
         case DTYPE_DATE: {
             return sizeof(std::uint32_t);
         }
+        case DTYPE_UUID: {
+            return sizeof(t_uuid);
+        }
+        case DTYPE_IPADDR: {
+            return sizeof(t_ipaddr);
+        }
         case DTYPE_F64PAIR: {
             return sizeof(std::pair<double, double>);
         }
@@ -156,7 +176,8 @@ get_dtype_size(t_dtype dtype) {
 
 bool
 is_vlen_dtype(t_dtype dtype) {
-    return dtype == DTYPE_STR || dtype == DTYPE_USER_VLEN;
+    return dtype == DTYPE_STR || dtype == DTYPE_LIST || dtype == DTYPE_JSON
+        || dtype == DTYPE_BINARY || dtype == DTYPE_USER_VLEN;
 }
 
 std::string
@@ -201,12 +222,30 @@ get_dtype_descr(t_dtype dtype) {
         case DTYPE_STR: {
             return "str";
         } break;
+        case DTYPE_LIST: {
+            return "list";
+        } break;
+        case DTYPE_JSON: {
+            return "json";
+        } break;
+        case DTYPE_BINARY: {
+            return "binary";
+        } break;
         case DTYPE_TIME: {
             return "datetime";
         } break;
         case DTYPE_DATE: {
             return "date";
         } break;
+        case DTYPE_DURATION: {
+            return "duration";
+        } break;
+        case DTYPE_UUID: {
+            return "uuid";
+        } break;
+        case DTYPE_IPADDR: {
+            return "ip";
+        } break;
         case DTYPE_ENUM: {
             return "e";
         } break;
@@ -262,9 +301,27 @@ dtype_to_str(t_dtype dtype) {
         case DTYPE_TIME: {
             ss << "datetime";
         } break;
+        case DTYPE_DURATION: {
+            ss << "duration";
+        } break;
+        case DTYPE_UUID: {
+            ss << "uuid";
+        } break;
+        case DTYPE_IPADDR: {
+            ss << "ip";
+        } break;
         case DTYPE_STR: {
             ss << "string";
         } break;
+        case DTYPE_LIST: {
+            ss << "list";
+        } break;
+        case DTYPE_JSON: {
+            ss << "json";
+        } break;
+        case DTYPE_BINARY: {
+            ss << "binary";
+        } break;
         case DTYPE_OBJECT: {
             ss << "object";
         } break;
@@ -297,9 +354,27 @@ str_to_dtype(const std::string& typestring) {
     if (typestring == "datetime") {
         return DTYPE_TIME;
     }
+    if (typestring == "duration") {
+        return DTYPE_DURATION;
+    }
     if (typestring == "string") {
         return DTYPE_STR;
     }
+    if (typestring == "list") {
+        return DTYPE_LIST;
+    }
+    if (typestring == "json") {
+        return DTYPE_JSON;
+    }
+    if (typestring == "binary") {
+        return DTYPE_BINARY;
+    }
+    if (typestring == "uuid") {
+        return DTYPE_UUID;
+    }
+    if (typestring == "ip") {
+        return DTYPE_IPADDR;
+    }
 
     PSP_COMPLAIN_AND_ABORT(
         "Could not convert unknown type string `" + typestring + "` to dtype."
@@ -355,6 +430,9 @@ filter_op_to_str(t_filter_op op) {
         case FILTER_OP_IS_NOT_NULL: {
             return "is not null";
         } break;
+        case FILTER_OP_IN_SUBNET: {
+            return "in subnet";
+        } break;
     }
     PSP_COMPLAIN_AND_ABORT("Reached end of function");
     return "";
@@ -407,6 +485,9 @@ str_to_filter_op(const std::string& str) {
     if (str == "is not null" || str == "is not None") {
         return t_filter_op::FILTER_OP_IS_NOT_NULL;
     }
+    if (str == "in subnet" || str == "in_subnet") {
+        return t_filter_op::FILTER_OP_IN_SUBNET;
+    }
 
     std::stringstream ss;
     ss << "Unknown filter operator string: `" << str << "`" << std::endl;
@@ -576,6 +657,12 @@ str_to_aggtype(const std::string& str) {
     if (str == "stddev" || str == "standard deviation") {
         return t_aggtype::AGGTYPE_STANDARD_DEVIATION;
     }
+    if (str == "total bytes" || str == "total_bytes") {
+        return t_aggtype::AGGTYPE_TOTAL_BYTES;
+    }
+    if (str == "max bytes" || str == "max_bytes") {
+        return t_aggtype::AGGTYPE_MAX_BYTES;
+    }
 
     std::stringstream ss;
     ss << "Encountered unknown aggregate operation: '" << str << "'"
@@ -598,7 +685,8 @@ _get_default_aggregate(t_dtype dtype) {
         case DTYPE_INT8:
         case DTYPE_INT16:
         case DTYPE_INT32:
-        case DTYPE_INT64: {
+        case DTYPE_INT64:
+        case DTYPE_DURATION: {
             agg_op = t_aggtype::AGGTYPE_SUM;
         } break;
         default: {
@@ -621,7 +709,8 @@ _get_default_aggregate_string(t_dtype dtype) {
         case DTYPE_INT8:
         case DTYPE_INT16:
         case DTYPE_INT32:
-        case DTYPE_INT64: {
+        case DTYPE_INT64:
+        case DTYPE_DURATION: {
             agg_op_str = "sum";
         } break;
         default: {
@@ -631,6 +720,18 @@ _get_default_aggregate_string(t_dtype dtype) {
     return agg_op_str;
 }
 
+bool
+show_values_as_is_percent(t_show_values_as show) {
+    switch (show) {
+        case SHOW_VALUES_AS_PCT_ROW_TOTAL:
+        case SHOW_VALUES_AS_PCT_COLUMN_TOTAL:
+        case SHOW_VALUES_AS_PCT_GRAND_TOTAL:
+            return true;
+        default:
+            return false;
+    }
+}
+
 std::string
 get_status_descr(t_status status) {
     switch (status) {
@@ -775,12 +876,30 @@ type_to_dtype<t_time>() {
     return DTYPE_TIME;
 }
 
+template <>
+t_dtype
+type_to_dtype<t_tdelta>() {
+    return DTYPE_DURATION;
+}
+
 template <>
 t_dtype
 type_to_dtype<t_date>() {
     return DTYPE_DATE;
 }
 
+template <>
+t_dtype
+type_to_dtype<t_uuid>() {
+    return DTYPE_UUID;
+}
+
+template <>
+t_dtype
+type_to_dtype<t_ipaddr>() {
+    return DTYPE_IPADDR;
+}
+
 template <>
 t_dtype
 type_to_dtype<std::string>() {
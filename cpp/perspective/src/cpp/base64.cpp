@@ -0,0 +1,118 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+#include <perspective/base64.h>
+#include <array>
+#include <cstdint>
+
+namespace perspective {
+
+static constexpr char BASE64_ALPHABET[] =
+    "ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+std::string
+base64_encode(std::string_view bytes) {
+    std::string rval;
+    rval.reserve(((bytes.size() + 2) / 3) * 4);
+    std::size_t i = 0;
+    for (; i + 2 < bytes.size(); i += 3) {
+        std::uint32_t n = (std::uint8_t(bytes[i]) << 16)
+            | (std::uint8_t(bytes[i + 1]) << 8) | std::uint8_t(bytes[i + 2]);
+        rval.push_back(BASE64_ALPHABET[(n >> 18) & 63]);
+        rval.push_back(BASE64_ALPHABET[(n >> 12) & 63]);
+        rval.push_back(BASE64_ALPHABET[(n >> 6) & 63]);
+        rval.push_back(BASE64_ALPHABET[n & 63]);
+    }
+
+    std::size_t rest = bytes.size() - i;
+    if (rest > 0) {
+        std::uint32_t n = std::uint8_t(bytes[i]) << 16;
+        if (rest == 2) {
+            n |= std::uint8_t(bytes[i + 1]) << 8;
+        }
+
+        rval.push_back(BASE64_ALPHABET[(n >> 18) & 63]);
+        rval.push_back(BASE64_ALPHABET[(n >> 12) & 63]);
+        rval.push_back(rest == 2 ? BASE64_ALPHABET[(n >> 6) & 63] : '=');
+        rval.push_back('=');
+    }
+
+    return rval;
+}
+
+std::optional<std::string>
+base64_decode(std::string_view text) {
+    static const auto lookup = [] {
+        std::array<std::int8_t, 256> table{};
+        table.fill(-1);
+        for (std::int8_t i = 0; i < 64; ++i) {
+            table[std::uint8_t(BASE64_ALPHABET[i])] = i;
+        }
+
+        return table;
+    }();
+
+    if (text.size() % 4 != 0) {
+        return std::nullopt;
+    }
+
+    std::string rval;
+    rval.reserve(base64_decoded_size(text));
+    for (std::size_t i = 0; i < text.size(); i += 4) {
+        bool is_last = i + 4 == text.size();
+        std::uint32_t n = 0;
+        std::size_t padding = 0;
+        for (std::size_t j = 0; j < 4; ++j) {
+            char c = text[i + j];
+            if (c == '=' && is_last && j >= 2) {
+                ++padding;
+                n <<= 6;
+                continue;
+            }
+
+            auto value = lookup[std::uint8_t(c)];
+            if (value < 0 || padding > 0) {
+                return std::nullopt;
+            }
+
+            n = (n << 6) | std::uint32_t(value);
+        }
+
+        rval.push_back(char((n >> 16) & 255));
+        if (padding < 2) {
+            rval.push_back(char((n >> 8) & 255));
+        }
+
+        if (padding < 1) {
+            rval.push_back(char(n & 255));
+        }
+    }
+
+    return rval;
+}
+
+std::size_t
+base64_decoded_size(std::string_view text) {
+    if (text.size() < 4) {
+        return 0;
+    }
+
+    std::size_t padding = 0;
+    for (auto it = text.rbegin(); it != text.rend() && *it == '=' && padding < 2;
+         ++it) {
+        ++padding;
+    }
+
+    return (text.size() / 4) * 3 - padding;
+}
+
+} // namespace perspective
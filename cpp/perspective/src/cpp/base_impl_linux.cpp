@@ -13,6 +13,8 @@
 #ifdef __linux__
 #include <perspective/first.h>
 #include <perspective/base.h>
+#include <fstream>
+#include <unistd.h>
 
 namespace perspective {
 
@@ -23,4 +25,14 @@ get_error_str() {
 }
 
 } // end namespace perspective
+
+extern "C" size_t
+psp_heap_size() {
+    // `statm` is in pages: total program size, then resident set size.
+    std::ifstream statm("/proc/self/statm");
+    size_t size = 0;
+    size_t resident = 0;
+    statm >> size >> resident;
+    return resident * sysconf(_SC_PAGESIZE);
+}
 #endif
@@ -13,6 +13,7 @@
 #ifdef __APPLE__
 #include <perspective/first.h>
 #include <perspective/base.h>
+#include <mach/mach.h>
 
 namespace perspective {
 
@@ -23,4 +24,18 @@ get_error_str() {
 }
 
 } // end namespace perspective
+
+extern "C" size_t
+psp_heap_size() {
+    mach_task_basic_info_data_t info;
+    mach_msg_type_number_t count = MACH_TASK_BASIC_INFO_COUNT;
+    auto status = task_info(
+        mach_task_self(),
+        MACH_TASK_BASIC_INFO,
+        reinterpret_cast<task_info_t>(&info),
+        &count
+    );
+
+    return status == KERN_SUCCESS ? info.resident_size : 0;
+}
 #endif
@@ -23,4 +23,16 @@ get_error_str() {
 }
 
 } // end namespace perspective
+
+#ifdef PSP_ENABLE_WASI
+extern "C" const char*
+psp_stack_trace() {
+    return "";
+}
+
+extern "C" size_t
+psp_heap_size() {
+    return __builtin_wasm_memory_size(0) * 65536;
+}
+#endif
 #endif
@@ -13,6 +13,7 @@
 #ifdef WIN32
 #include <perspective/first.h>
 #include <perspective/base.h>
+#include <psapi.h>
 
 namespace perspective {
 
@@ -44,4 +45,16 @@ get_error_str() {
 }
 
 } // end namespace perspective
+
+extern "C" size_t
+psp_heap_size() {
+    PROCESS_MEMORY_COUNTERS counters;
+    if (!GetProcessMemoryInfo(
+            GetCurrentProcess(), &counters, sizeof(counters)
+        )) {
+        return 0;
+    }
+
+    return counters.WorkingSetSize;
+}
 #endif
\ No newline at end of file
@@ -17,6 +17,8 @@ SUPPRESS_WARNINGS_VC(4505)
 #include <perspective/defaults.h>
 #include <perspective/base.h>
 #include <perspective/sym_table.h>
+#include <perspective/uuid.h>
+#include <perspective/ipaddr.h>
 #include <tsl/hopscotch_set.h>
 
 #include <memory>
@@ -308,13 +310,23 @@ t_column::push_back<t_tscalar>(t_tscalar elem) {
         case DTYPE_BOOL: {
             push_back(elem.get<bool>(), elem.m_status);
         } break;
-        case DTYPE_TIME: {
+        case DTYPE_TIME:
+        case DTYPE_DURATION: {
             push_back(elem.get<std::int64_t>(), elem.m_status);
         } break;
         case DTYPE_DATE: {
             push_back(elem.get<std::uint32_t>(), elem.m_status);
         } break;
-        case DTYPE_STR: {
+        case DTYPE_UUID: {
+            push_back(elem.get<t_uuid>(), elem.m_status);
+        } break;
+        case DTYPE_IPADDR: {
+            push_back(elem.get<t_ipaddr>(), elem.m_status);
+        } break;
+        case DTYPE_STR:
+        case DTYPE_LIST:
+        case DTYPE_JSON:
+        case DTYPE_BINARY: {
             push_back(elem.get<const char*>(), elem.m_status);
         } break;
         case DTYPE_OBJECT: {
@@ -446,16 +458,40 @@ t_column::get_scalar(t_uindex idx) const {
                 m_data->get_nth<t_time::t_rawtype>(idx);
             rv.set(t_time(*v));
         } break;
+        case DTYPE_DURATION: {
+            rv.set(t_tdelta(*(m_data->get_nth<std::int64_t>(idx))));
+        } break;
         case DTYPE_DATE: {
             const t_date::t_rawtype* v =
                 m_data->get_nth<t_date::t_rawtype>(idx);
             rv.set(t_date(*v));
         } break;
+        case DTYPE_UUID: {
+            rv.set(*(m_data->get_nth<t_uuid>(idx)));
+        } break;
+        case DTYPE_IPADDR: {
+            rv.set(*(m_data->get_nth<t_ipaddr>(idx)));
+        } break;
         case DTYPE_STR: {
             COLUMN_CHECK_STRCOL();
             const t_uindex* sidx = m_data->get_nth<t_uindex>(idx);
             rv.set(m_vocab->unintern_c(*sidx));
         } break;
+        case DTYPE_LIST: {
+            COLUMN_CHECK_STRCOL();
+            const t_uindex* sidx = m_data->get_nth<t_uindex>(idx);
+            rv.set_list(m_vocab->unintern_c(*sidx));
+        } break;
+        case DTYPE_JSON: {
+            COLUMN_CHECK_STRCOL();
+            const t_uindex* sidx = m_data->get_nth<t_uindex>(idx);
+            rv.set_json(m_vocab->unintern_c(*sidx));
+        } break;
+        case DTYPE_BINARY: {
+            COLUMN_CHECK_STRCOL();
+            const t_uindex* sidx = m_data->get_nth<t_uindex>(idx);
+            rv.set_binary(m_vocab->unintern_c(*sidx));
+        } break;
         case DTYPE_F64PAIR: {
             const std::pair<double, double>* pair =
                 m_data->get_nth<std::pair<double, double>>(idx);
@@ -492,11 +528,15 @@ t_column::clear(t_uindex idx) {
 void
 t_column::clear(t_uindex idx, t_status status) {
     switch (m_dtype) {
-        case DTYPE_STR: {
+        case DTYPE_STR:
+        case DTYPE_LIST:
+        case DTYPE_JSON:
+        case DTYPE_BINARY: {
             t_uindex v = 0;
             set_nth<t_uindex>(idx, v, status);
         } break;
         case DTYPE_TIME:
+        case DTYPE_DURATION:
         case DTYPE_FLOAT64:
         case DTYPE_UINT64:
         case DTYPE_INT64: {
@@ -517,7 +557,9 @@ t_column::clear(t_uindex idx, t_status status) {
         case DTYPE_INT8: {
             set_nth<std::uint8_t>(idx, 0, status);
         } break;
-        case DTYPE_F64PAIR: {
+        case DTYPE_F64PAIR:
+        case DTYPE_UUID:
+        case DTYPE_IPADDR: {
             std::pair<std::uint64_t, std::uint64_t> v;
             v.first = 0;
             v.second = 0;
@@ -673,18 +715,32 @@ t_column::set_scalar(t_uindex idx, t_tscalar value) {
             t_time tgt = value.get<t_time>();
             set_nth<t_time>(idx, tgt, value.m_status);
         } break;
+        case DTYPE_DURATION: {
+            set_nth<std::int64_t>(
+                idx, value.get<std::int64_t>(), value.m_status
+            );
+        } break;
         case DTYPE_DATE: {
             t_date tgt = value.get<t_date>();
             set_nth<t_date>(idx, tgt, value.m_status);
         } break;
-        case DTYPE_STR: {
+        case DTYPE_UUID: {
+            set_nth<t_uuid>(idx, value.get<t_uuid>(), value.m_status);
+        } break;
+        case DTYPE_IPADDR: {
+            set_nth<t_ipaddr>(idx, value.get<t_ipaddr>(), value.m_status);
+        } break;
+        case DTYPE_STR:
+        case DTYPE_LIST:
+        case DTYPE_JSON:
+        case DTYPE_BINARY: {
             COLUMN_CHECK_STRCOL();
             const char* tgt = value.get_char_ptr();
             std::string empty;
 
             if (tgt != nullptr) {
                 PSP_VERBOSE_ASSERT(
-                    value.m_type == DTYPE_STR,
+                    value.m_type == m_dtype,
                     "Setting non string scalar on string column"
                 );
                 set_nth<const char*>(idx, tgt, value.m_status);
@@ -752,7 +808,7 @@ void
 t_column::clear() {
     // clear out the data store
     m_data->set_size(0);
-    if (m_dtype == DTYPE_STR) {
+    if (is_vlen_dtype(m_dtype)) {
         m_data->clear();
     }
     if (is_status_enabled()) {
@@ -761,6 +817,27 @@ t_column::clear() {
     m_size = 0;
 }
 
+void
+t_column::compress() {
+    m_data->compress(get_dtype_size(m_dtype));
+    if (is_status_enabled()) {
+        m_status->compress(get_dtype_size(DTYPE_UINT8));
+    }
+}
+
+t_storage_bytes
+t_column::get_storage_bytes() const {
+    t_storage_bytes bytes{
+        .m_raw = m_data->size(), .m_stored = m_data->stored_size()
+    };
+    if (is_status_enabled()) {
+        bytes.m_raw += m_status->size();
+        bytes.m_stored += m_status->stored_size();
+    }
+
+    return bytes;
+}
+
 void
 t_column::clear_objects() const {
     for (t_uindex idx = 0, loop_end = size(); idx < loop_end; ++idx) {
@@ -916,13 +993,23 @@ t_column::copy(
         case DTYPE_BOOL: {
             copy_helper<std::uint8_t>(other, indices, offset);
         } break;
-        case DTYPE_TIME: {
+        case DTYPE_TIME:
+        case DTYPE_DURATION: {
             copy_helper<std::int64_t>(other, indices, offset);
         } break;
         case DTYPE_DATE: {
             copy_helper<std::uint32_t>(other, indices, offset);
         } break;
-        case DTYPE_STR: {
+        case DTYPE_UUID: {
+            copy_helper<t_uuid>(other, indices, offset);
+        } break;
+        case DTYPE_IPADDR: {
+            copy_helper<t_ipaddr>(other, indices, offset);
+        } break;
+        case DTYPE_STR:
+        case DTYPE_LIST:
+        case DTYPE_JSON:
+        case DTYPE_BINARY: {
             copy_helper<const char>(other, indices, offset);
         } break;
         case DTYPE_OBJECT: {
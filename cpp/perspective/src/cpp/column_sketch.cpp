@@ -0,0 +1,168 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+#include <perspective/column_sketch.h>
+#include <algorithm>
+
+namespace perspective {
+
+// The number of Space-Saving counters, which bounds the error of the counts
+// of the `PSP_SKETCH_TOP_K` most frequent values.
+static const t_uindex SKETCH_CAPACITY = PSP_SKETCH_TOP_K * 4;
+
+static bool
+is_categorical(t_dtype dtype) {
+    switch (dtype) {
+        case DTYPE_STR:
+        case DTYPE_BOOL:
+        case DTYPE_DATE:
+        case DTYPE_INT8:
+        case DTYPE_INT16:
+        case DTYPE_INT32:
+        case DTYPE_INT64:
+        case DTYPE_UINT8:
+        case DTYPE_UINT16:
+        case DTYPE_UINT32:
+        case DTYPE_UINT64:
+            return true;
+        default:
+            return false;
+    }
+}
+
+static t_tscalar
+string_scalar(const std::string& value) {
+    t_tscalar rval;
+    rval.set(value.c_str());
+    return rval;
+}
+
+t_column_sketch::t_column_sketch(t_dtype dtype) :
+    m_dtype(dtype),
+    m_categorical(is_categorical(dtype)),
+    m_count(0) {}
+
+template <typename K>
+void
+t_column_sketch::count(
+    std::unordered_map<K, t_uindex>& counters, const K& key
+) {
+    auto iter = counters.find(key);
+    if (iter != counters.end()) {
+        iter->second++;
+        return;
+    }
+
+    if (counters.size() < SKETCH_CAPACITY) {
+        counters.emplace(key, 1);
+        return;
+    }
+
+    // Replace the least frequent value, inheriting its count.
+    auto min = std::min_element(
+        counters.begin(),
+        counters.end(),
+        [](const auto& a, const auto& b) { return a.second < b.second; }
+    );
+
+    auto min_count = min->second;
+    counters.erase(min);
+    counters.emplace(key, min_count + 1);
+}
+
+void
+t_column_sketch::update(const t_tscalar& value) {
+    if (!value.is_valid() || value.is_nan()) {
+        return;
+    }
+
+    m_count++;
+    if (m_dtype == DTYPE_STR) {
+        std::string str = value.get_char_ptr();
+        if (!m_min_string.has_value() || str < *m_min_string) {
+            m_min_string = str;
+        }
+
+        if (!m_max_string.has_value() || *m_max_string < str) {
+            m_max_string = str;
+        }
+
+        count(m_string_counters, str);
+        return;
+    }
+
+    if (!m_min.has_value() || value < *m_min) {
+        m_min = value;
+    }
+
+    if (!m_max.has_value() || *m_max < value) {
+        m_max = value;
+    }
+
+    if (m_categorical) {
+        count(m_counters, value);
+    }
+}
+
+t_uindex
+t_column_sketch::get_count() const {
+    return m_count;
+}
+
+std::optional<t_tscalar>
+t_column_sketch::get_min() const {
+    if (m_dtype == DTYPE_STR) {
+        return m_min_string.has_value()
+            ? std::optional<t_tscalar>(string_scalar(*m_min_string))
+            : std::nullopt;
+    }
+
+    return m_min;
+}
+
+std::optional<t_tscalar>
+t_column_sketch::get_max() const {
+    if (m_dtype == DTYPE_STR) {
+        return m_max_string.has_value()
+            ? std::optional<t_tscalar>(string_scalar(*m_max_string))
+            : std::nullopt;
+    }
+
+    return m_max;
+}
+
+std::vector<std::pair<t_tscalar, t_uindex>>
+t_column_sketch::get_top_values(t_uindex k) const {
+    std::vector<std::pair<t_tscalar, t_uindex>> out;
+    if (m_dtype == DTYPE_STR) {
+        for (const auto& [value, count] : m_string_counters) {
+            out.emplace_back(string_scalar(value), count);
+        }
+    } else {
+        for (const auto& [value, count] : m_counters) {
+            out.emplace_back(value, count);
+        }
+    }
+
+    // Most frequent first, ties broken by value so output is deterministic.
+    std::sort(out.begin(), out.end(), [](const auto& a, const auto& b) {
+        return a.second != b.second ? a.second > b.second : a.first < b.first;
+    });
+
+    if (out.size() > k) {
+        out.resize(k);
+    }
+
+    return out;
+}
+
+} // namespace perspective
@@ -19,11 +19,12 @@
 #include <perspective/raw_types.h>
 #include <perspective/utils.h>
 #include <fcntl.h>
-#include <sys/mman.h>
+#ifndef PSP_ENABLE_WASI
 #include <sys/mman.h>
 #include <sys/resource.h>
-#include <sys/stat.h>
 #include <sys/syscall.h>
+#endif
+#include <sys/stat.h>
 #include <sys/types.h>
 #include <unistd.h>
 #include <stdio.h>
@@ -0,0 +1,253 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+
+#include <perspective/compression.h>
+#include <cstring>
+#include <limits>
+#include <unordered_map>
+
+namespace perspective {
+
+namespace {
+
+    // Elements are read into, and written from, the low bytes of a
+    // `std::uint64_t`, which is exact for every supported width.
+    std::uint64_t
+    read_elem(const std::uint8_t* base, t_uindex idx, t_uindex width) {
+        std::uint64_t value = 0;
+        std::memcpy(&value, base + idx * width, width);
+        return value;
+    }
+
+    void
+    write_elem(
+        std::uint8_t* base, t_uindex idx, t_uindex width, std::uint64_t value
+    ) {
+        std::memcpy(base + idx * width, &value, width);
+    }
+
+    void
+    push_elem(
+        std::vector<std::uint8_t>& out, std::uint64_t value, t_uindex width
+    ) {
+        auto offset = out.size();
+        out.resize(offset + width);
+        std::memcpy(out.data() + offset, &value, width);
+    }
+
+    void
+    push_varint(std::vector<std::uint8_t>& out, std::uint64_t value) {
+        while (value >= 0x80) {
+            out.push_back(static_cast<std::uint8_t>(value | 0x80));
+            value >>= 7;
+        }
+
+        out.push_back(static_cast<std::uint8_t>(value));
+    }
+
+    std::uint64_t
+    read_varint(const std::uint8_t*& ptr) {
+        std::uint64_t value = 0;
+        std::uint32_t shift = 0;
+        while ((*ptr & 0x80) != 0) {
+            value |= static_cast<std::uint64_t>(*ptr++ & 0x7F) << shift;
+            shift += 7;
+        }
+
+        return value | (static_cast<std::uint64_t>(*ptr++) << shift);
+    }
+
+    std::uint64_t
+    zigzag(std::uint64_t delta) {
+        auto signed_delta = static_cast<std::int64_t>(delta);
+        return (delta << 1) ^ static_cast<std::uint64_t>(signed_delta >> 63);
+    }
+
+    std::uint64_t
+    unzigzag(std::uint64_t value) {
+        return (value >> 1) ^ (~(value & 1) + 1);
+    }
+
+    // Each encoder gives up, returning `false`, once its output would be no
+    // smaller than `limit`.
+    bool
+    encode_rle(
+        const std::uint8_t* base,
+        t_uindex nelems,
+        t_uindex width,
+        t_uindex limit,
+        std::vector<std::uint8_t>& out
+    ) {
+        t_uindex idx = 0;
+        while (idx < nelems) {
+            auto value = read_elem(base, idx, width);
+            t_uindex run = 1;
+            while (idx + run < nelems
+                   && read_elem(base, idx + run, width) == value) {
+                ++run;
+            }
+
+            push_varint(out, run);
+            push_elem(out, value, width);
+            if (out.size() >= limit) {
+                return false;
+            }
+
+            idx += run;
+        }
+
+        return true;
+    }
+
+    bool
+    encode_dictionary(
+        const std::uint8_t* base,
+        t_uindex nelems,
+        t_uindex width,
+        t_uindex limit,
+        std::vector<std::uint8_t>& out
+    ) {
+        std::unordered_map<std::uint64_t, std::uint32_t> dictionary;
+        std::vector<std::uint64_t> values;
+        std::vector<std::uint16_t> codes(nelems);
+        for (t_uindex idx = 0; idx < nelems; ++idx) {
+            auto value = read_elem(base, idx, width);
+            auto [it, inserted] = dictionary.emplace(value, values.size());
+            if (inserted) {
+                if (values.size() > std::numeric_limits<std::uint16_t>::max()) {
+                    return false;
+                }
+
+                values.push_back(value);
+            }
+
+            codes[idx] = static_cast<std::uint16_t>(it->second);
+        }
+
+        t_uindex code_width = values.size() <= 256 ? 1 : 2;
+        if (code_width >= width) {
+            return false;
+        }
+
+        push_varint(out, values.size());
+        for (auto value : values) {
+            push_elem(out, value, width);
+        }
+
+        for (auto code : codes) {
+            push_elem(out, code, code_width);
+        }
+
+        return out.size() < limit;
+    }
+
+    bool
+    encode_delta(
+        const std::uint8_t* base,
+        t_uindex nelems,
+        t_uindex width,
+        t_uindex limit,
+        std::vector<std::uint8_t>& out
+    ) {
+        std::uint64_t prev = read_elem(base, 0, width);
+        push_elem(out, prev, width);
+        for (t_uindex idx = 1; idx < nelems; ++idx) {
+            auto value = read_elem(base, idx, width);
+            push_varint(out, zigzag(value - prev));
+            if (out.size() >= limit) {
+                return false;
+            }
+
+            prev = value;
+        }
+
+        return true;
+    }
+
+} // namespace
+
+t_compressed_buffer
+compress_buffer(const void* base, t_uindex size, t_uindex width) {
+    t_compressed_buffer rval;
+    rval.m_width = width;
+    rval.m_size = size;
+    if ((width != 1 && width != 2 && width != 4 && width != 8) || size == 0
+        || size % width != 0) {
+        return rval;
+    }
+
+    const auto* bytes = static_cast<const std::uint8_t*>(base);
+    t_uindex nelems = size / width;
+    auto try_encoding = [&](t_compression_encoding encoding, auto encode) {
+        t_uindex limit = rval.m_encoding == COMPRESSION_NONE
+            ? size
+            : rval.m_data.size();
+
+        std::vector<std::uint8_t> out;
+        if (encode(bytes, nelems, width, limit, out)) {
+            rval.m_encoding = encoding;
+            rval.m_data = std::move(out);
+        }
+    };
+
+    try_encoding(COMPRESSION_RLE, encode_rle);
+    try_encoding(COMPRESSION_DICTIONARY, encode_dictionary);
+    try_encoding(COMPRESSION_DELTA, encode_delta);
+    rval.m_data.shrink_to_fit();
+    return rval;
+}
+
+void
+decompress_buffer(const t_compressed_buffer& buffer, void* out) {
+    auto* bytes = static_cast<std::uint8_t*>(out);
+    const std::uint8_t* ptr = buffer.m_data.data();
+    t_uindex width = buffer.m_width;
+    t_uindex nelems = width == 0 ? 0 : buffer.m_size / width;
+    switch (buffer.m_encoding) {
+        case COMPRESSION_NONE: {
+            PSP_COMPLAIN_AND_ABORT("Cannot decompress an unencoded buffer");
+        } break;
+        case COMPRESSION_RLE: {
+            t_uindex idx = 0;
+            while (idx < nelems) {
+                auto run = read_varint(ptr);
+                auto value = read_elem(ptr, 0, width);
+                ptr += width;
+                for (t_uindex ii = 0; ii < run; ++ii) {
+                    write_elem(bytes, idx++, width, value);
+                }
+            }
+        } break;
+        case COMPRESSION_DICTIONARY: {
+            auto nvalues = read_varint(ptr);
+            const std::uint8_t* values = ptr;
+            const std::uint8_t* codes = ptr + nvalues * width;
+            t_uindex code_width = nvalues <= 256 ? 1 : 2;
+            for (t_uindex idx = 0; idx < nelems; ++idx) {
+                auto code = read_elem(codes, idx, code_width);
+                write_elem(bytes, idx, width, read_elem(values, code, width));
+            }
+        } break;
+        case COMPRESSION_DELTA: {
+            std::uint64_t value = read_elem(ptr, 0, width);
+            ptr += width;
+            write_elem(bytes, 0, width, value);
+            for (t_uindex idx = 1; idx < nelems; ++idx) {
+                value += unzigzag(read_varint(ptr));
+                write_elem(bytes, idx, width, value);
+            }
+        } break;
+    }
+}
+
+} // namespace perspective
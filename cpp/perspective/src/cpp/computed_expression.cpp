@@ -12,10 +12,12 @@
 
 #include <perspective/computed_expression.h>
 
+#include <cctype>
 #include <utility>
 
 namespace perspective {
 
+#ifndef PSP_DISABLE_EXPRESSIONS
 // Change ExprTk's default compilation options to only check for correctness
 // of brackets and sequences. ExprTk defaults will replace "true" and "false"
 // with 1 and 0, which we don't want. Using the tokens "true" and "false"
@@ -34,18 +36,44 @@ std::shared_ptr<exprtk::parser<t_tscalar>>
             t_computed_expression_parser::PARSER_COMPILE_OPTIONS
         );
 
-computed_function::bucket t_computed_expression_parser::BUCKET_FN =
-    computed_function::bucket();
-
-computed_function::hour_of_day t_computed_expression_parser::HOUR_OF_DAY_FN =
-    computed_function::hour_of_day();
-
 computed_function::percent_of t_computed_expression_parser::PERCENT_OF_FN =
     computed_function::percent_of();
 
 computed_function::inrange_fn t_computed_expression_parser::INRANGE_FN =
     computed_function::inrange_fn();
 
+computed_function::haversine_distance
+    t_computed_expression_parser::HAVERSINE_DISTANCE_FN =
+        computed_function::haversine_distance();
+
+computed_function::within_bbox t_computed_expression_parser::WITHIN_BBOX_FN =
+    computed_function::within_bbox();
+
+computed_function::in_subnet t_computed_expression_parser::IN_SUBNET_FN =
+    computed_function::in_subnet();
+
+computed_function::round_to_tick
+    t_computed_expression_parser::ROUND_TO_TICK_FN =
+        computed_function::round_to_tick();
+
+computed_function::bps t_computed_expression_parser::BPS_FN =
+    computed_function::bps();
+
+computed_function::pv t_computed_expression_parser::PV_FN =
+    computed_function::pv();
+
+computed_function::fv t_computed_expression_parser::FV_FN =
+    computed_function::fv();
+
+computed_function::rate t_computed_expression_parser::RATE_FN =
+    computed_function::rate();
+
+computed_function::irr t_computed_expression_parser::IRR_FN =
+    computed_function::irr();
+
+computed_function::year_frac t_computed_expression_parser::YEAR_FRAC_FN =
+    computed_function::year_frac();
+
 computed_function::min_fn t_computed_expression_parser::MIN_FN =
     computed_function::min_fn();
 
@@ -64,9 +92,39 @@ computed_function::cross_product3 t_computed_expression_parser::cross_product3 =
 computed_function::dot_product3 t_computed_expression_parser::dot_product3 =
     computed_function::dot_product3();
 
+computed_function::sum_fn t_computed_expression_parser::SUM_FN =
+    computed_function::sum_fn();
+
 computed_function::length t_computed_expression_parser::LENGTH_FN =
     computed_function::length();
 
+computed_function::len t_computed_expression_parser::LEN_FN =
+    computed_function::len();
+
+computed_function::contains t_computed_expression_parser::CONTAINS_FN =
+    computed_function::contains();
+
+computed_function::levenshtein t_computed_expression_parser::LEVENSHTEIN_FN =
+    computed_function::levenshtein();
+
+computed_function::jaro_winkler
+    t_computed_expression_parser::JARO_WINKLER_FN =
+        computed_function::jaro_winkler();
+
+computed_function::starts_with t_computed_expression_parser::STARTS_WITH_FN =
+    computed_function::starts_with();
+
+computed_function::ends_with t_computed_expression_parser::ENDS_WITH_FN =
+    computed_function::ends_with();
+
+computed_function::json_extract_float
+    t_computed_expression_parser::JSON_EXTRACT_FLOAT_FN =
+        computed_function::json_extract_float();
+
+computed_function::json_extract_bool
+    t_computed_expression_parser::JSON_EXTRACT_BOOL_FN =
+        computed_function::json_extract_bool();
+
 computed_function::is_null t_computed_expression_parser::IS_NULL_FN =
     computed_function::is_null();
 
@@ -89,30 +147,90 @@ computed_function::make_datetime
     t_computed_expression_parser::MAKE_DATETIME_FN =
         computed_function::make_datetime();
 
+computed_function::make_duration
+    t_computed_expression_parser::MAKE_DURATION_FN =
+        computed_function::make_duration();
+
 computed_function::random t_computed_expression_parser::RANDOM_FN =
     computed_function::random();
 
 t_tscalar t_computed_expression_parser::TRUE_SCALAR = mktscalar(true);
 
 t_tscalar t_computed_expression_parser::FALSE_SCALAR = mktscalar(false);
+#endif
 
 /******************************************************************************
  *
  * t_computed_expression
  */
 
+// Functions whose value for a row depends on other rows of the table, or on
+// when they are called.
+static const tsl::hopscotch_set<std::string> NON_ROW_LOCAL_FUNCTIONS = {
+    "vlookup",
+    "order",
+    "now",
+    "today",
+    "random",
+    "zscore",
+    "percent_of_group",
+    "percent_of_total",
+    "rank_in_group"
+};
+
+static bool
+is_row_local_expression(const std::string& expression) {
+    auto is_word = [](char ch) {
+        return std::isalnum(static_cast<unsigned char>(ch)) || ch == '_';
+    };
+
+    std::size_t idx = 0;
+    std::size_t size = expression.size();
+    while (idx < size) {
+        char c = expression[idx];
+        if (c == '\'' || c == '"') {
+            // Skip string literals and quoted column names.
+            ++idx;
+            while (idx < size && expression[idx] != c) {
+                idx += expression[idx] == '\\' ? 2 : 1;
+            }
+
+            ++idx;
+        } else if (is_word(c)) {
+            // Digits start a number rather than a name, but can't start a
+            // function name either way.
+            std::size_t start = idx;
+            while (idx < size && is_word(expression[idx])) {
+                ++idx;
+            }
+
+            std::string word = expression.substr(start, idx - start);
+            if (NON_ROW_LOCAL_FUNCTIONS.count(word) > 0) {
+                return false;
+            }
+        } else {
+            ++idx;
+        }
+    }
+
+    return true;
+}
+
 t_computed_expression::t_computed_expression(
     std::string expression_alias,
     std::string expression_string,
     std::string parsed_expression_string,
     const std::vector<std::pair<std::string, std::string>>& column_ids,
-    t_dtype dtype
+    t_dtype dtype,
+    std::string timezone
 ) :
     m_expression_alias(std::move(expression_alias)),
     m_expression_string(std::move(expression_string)),
     m_parsed_expression_string(std::move(parsed_expression_string)),
     m_column_ids(column_ids),
-    m_dtype(dtype) {}
+    m_dtype(dtype),
+    m_timezone(std::move(timezone)),
+    m_is_row_local(is_row_local_expression(m_parsed_expression_string)) {}
 
 void
 t_computed_expression::compute(
@@ -120,8 +238,83 @@ t_computed_expression::compute(
     const t_gstate::t_mapping& pkey_map,
     const std::shared_ptr<t_data_table>& destination_table,
     t_expression_vocab& vocab,
+    t_regex_mapping& regex_mapping,
+    const std::shared_ptr<t_data_table>& master
+) const {
+    _compute(
+        source_table,
+        pkey_map,
+        destination_table,
+        nullptr,
+        vocab,
+        regex_mapping,
+        master
+    );
+}
+
+void
+t_computed_expression::compute_rows(
+    const std::shared_ptr<t_data_table>& source_table,
+    const t_gstate::t_mapping& pkey_map,
+    const std::shared_ptr<t_data_table>& destination_table,
+    const std::vector<t_uindex>& rows,
+    t_expression_vocab& vocab,
     t_regex_mapping& regex_mapping
 ) const {
+    _compute(
+        source_table,
+        pkey_map,
+        destination_table,
+        &rows,
+        vocab,
+        regex_mapping,
+        nullptr
+    );
+}
+
+#ifdef PSP_DISABLE_EXPRESSIONS
+void
+t_computed_expression::_compute(
+    const std::shared_ptr<t_data_table>& source_table,
+    const t_gstate::t_mapping& pkey_map,
+    const std::shared_ptr<t_data_table>& destination_table,
+    const std::vector<t_uindex>* rows,
+    t_expression_vocab& vocab,
+    t_regex_mapping& regex_mapping,
+    const std::shared_ptr<t_data_table>& master
+) const {
+    PSP_COMPLAIN_AND_ABORT("Expressions are not supported by this build");
+}
+#else
+void
+t_computed_expression::_compute(
+    const std::shared_ptr<t_data_table>& source_table,
+    const t_gstate::t_mapping& pkey_map,
+    const std::shared_ptr<t_data_table>& destination_table,
+    const std::vector<t_uindex>* rows,
+    t_expression_vocab& vocab,
+    t_regex_mapping& regex_mapping,
+    const std::shared_ptr<t_data_table>& master
+) const {
+    // create or get output column using m_expression_alias
+    auto output_column =
+        destination_table->add_column_sptr(m_expression_alias, m_dtype, true);
+    output_column->reserve(source_table->size());
+
+    // Simple numeric expressions skip ExprTk, unless the data holds a case
+    // the fast path can't reproduce.
+    auto vectorized = t_vectorized_expression::compile(
+        m_parsed_expression_string,
+        m_column_ids,
+        source_table->get_schema(),
+        m_dtype
+    );
+
+    if (vectorized != nullptr
+        && vectorized->compute(*source_table, rows, *output_column)) {
+        return;
+    }
+
     // TODO: share symtables across pre/re/compute
     exprtk::symbol_table<t_tscalar> sym_table;
 
@@ -130,10 +323,22 @@ t_computed_expression::compute(
 
     t_uindex row_idx = 0;
 
+    // The time zone was validated when the view was created.
+    t_tz timezone;
+    bool has_timezone =
+        !m_timezone.empty() && t_tz::parse(m_timezone, timezone);
+
     // Create a function store, with is_type_validator set to false as we
     // are calculating values, not type-checking.
     t_computed_function_store function_store(
-        vocab, regex_mapping, false, source_table, pkey_map, row_idx
+        vocab,
+        regex_mapping,
+        false,
+        source_table,
+        pkey_map,
+        row_idx,
+        has_timezone ? &timezone : nullptr,
+        master
     );
     function_store.register_computed_functions(sym_table);
 
@@ -171,13 +376,10 @@ t_computed_expression::compute(
         PSP_COMPLAIN_AND_ABORT(ss.str());
     }
 
-    // create or get output column using m_expression_alias
-    auto output_column =
-        destination_table->add_column_sptr(m_expression_alias, m_dtype, true);
-    auto num_rows = source_table->size();
-    output_column->reserve(num_rows);
-
-    for (t_uindex ridx = 0; ridx < num_rows; ++ridx) {
+    // Every row of the source table, unless only some rows were asked for.
+    auto num_rows = rows != nullptr ? rows->size() : source_table->size();
+    for (t_uindex idx = 0; idx < num_rows; ++idx) {
+        t_uindex ridx = rows != nullptr ? (*rows)[idx] : idx;
         for (t_uindex cidx = 0; cidx < num_input_columns; ++cidx) {
             const std::string& column_id = m_column_ids[cidx].first;
             values[cidx].second.set(columns[column_id]->get_scalar(ridx));
@@ -196,6 +398,12 @@ t_computed_expression::compute(
 
     function_store.clear_computed_function_state();
 };
+#endif
+
+bool
+t_computed_expression::is_row_local() const {
+    return m_is_row_local;
+}
 
 const std::string&
 t_computed_expression::get_expression_alias() const {
@@ -222,11 +430,103 @@ t_computed_expression::get_dtype() const {
     return m_dtype;
 }
 
+const std::string&
+t_computed_expression::get_timezone() const {
+    return m_timezone;
+}
+
+std::vector<t_uindex>
+get_master_rows(
+    const std::shared_ptr<t_data_table>& flattened,
+    const t_gstate::t_mapping& pkey_map
+) {
+    std::vector<t_uindex> rows;
+    const t_column& pkey_col = *(flattened->get_const_column("psp_pkey"));
+    rows.reserve(flattened->size());
+
+    for (t_uindex ridx = 0; ridx < flattened->size(); ++ridx) {
+        auto it = pkey_map.find(pkey_col.get_scalar(ridx));
+        if (it != pkey_map.end()) {
+            rows.push_back(it->second);
+        }
+    }
+
+    return rows;
+}
+
+void
+compute_master_expressions(
+    const std::vector<std::shared_ptr<t_computed_expression>>& expressions,
+    const std::shared_ptr<t_data_table>& master,
+    const std::shared_ptr<t_data_table>& flattened,
+    const t_gstate::t_mapping& pkey_map,
+    const std::shared_ptr<t_data_table>& destination_table,
+    t_expression_vocab& vocab,
+    t_regex_mapping& regex_mapping
+) {
+    std::vector<t_uindex> updated_rows = get_master_rows(flattened, pkey_map);
+    for (const auto& expr : expressions) {
+        if (expr->is_row_local()) {
+            expr->compute_rows(
+                master,
+                pkey_map,
+                destination_table,
+                updated_rows,
+                vocab,
+                regex_mapping
+            );
+        } else {
+            expr->compute(
+                master, pkey_map, destination_table, vocab, regex_mapping
+            );
+        }
+    }
+}
+
 /******************************************************************************
  *
  * t_computed_expression_parser
  */
 
+#ifdef PSP_DISABLE_EXPRESSIONS
+void
+t_computed_expression_parser::init() {}
+
+std::shared_ptr<t_computed_expression>
+t_computed_expression_parser::precompute(
+    const std::string& expression_alias,
+    const std::string& expression_string,
+    const std::string& parsed_expression_string,
+    const std::vector<std::pair<std::string, std::string>>& column_ids,
+    const std::shared_ptr<t_data_table>& source_table,
+    const t_gstate::t_mapping& pkey_map,
+    const std::shared_ptr<t_schema>& schema,
+    t_expression_vocab& vocab,
+    t_regex_mapping& regex_mapping
+) {
+    PSP_COMPLAIN_AND_ABORT("Expressions are not supported by this build");
+    return nullptr;
+}
+
+t_dtype
+t_computed_expression_parser::get_dtype(
+    const std::string& expression_alias,
+    const std::string& expression_string,
+    const std::string& parsed_expression_string,
+    const std::vector<std::pair<std::string, std::string>>& column_ids,
+    const std::shared_ptr<t_data_table>& source_table,
+    const t_gstate::t_mapping& pkey_map,
+    const t_schema& schema,
+    t_expression_error& error,
+    t_expression_vocab& vocab,
+    t_regex_mapping& regex_mapping
+) {
+    error.m_error_message = "Expressions are not supported by this build";
+    error.m_line = 0;
+    error.m_column = 0;
+    return DTYPE_NONE;
+}
+#else
 void
 t_computed_expression_parser::init() {
     t_computed_expression_parser::PARSER->settings()
@@ -241,6 +541,9 @@ t_computed_expression_parser::init() {
         )
         .disable_base_function(
             exprtk::parser<t_tscalar>::settings_store::e_bf_max
+        )
+        .disable_base_function(
+            exprtk::parser<t_tscalar>::settings_store::e_bf_sum
         );
 }
 
@@ -289,6 +592,15 @@ t_computed_expression_parser::precompute(
         if (rval.m_type == DTYPE_STR) {
             rval.set(vocab.get_empty_string());
             rval.m_status = STATUS_INVALID;
+        } else if (rval.m_type == DTYPE_LIST) {
+            rval.set_list(vocab.get_empty_string());
+            rval.m_status = STATUS_INVALID;
+        } else if (rval.m_type == DTYPE_JSON) {
+            rval.set_json(vocab.get_empty_string());
+            rval.m_status = STATUS_INVALID;
+        } else if (rval.m_type == DTYPE_BINARY) {
+            rval.set_binary(vocab.get_empty_string());
+            rval.m_status = STATUS_INVALID;
         }
 
         values[cidx] = rval;
@@ -381,6 +693,15 @@ t_computed_expression_parser::get_dtype(
         if (rval.m_type == DTYPE_STR) {
             rval.set(vocab.get_empty_string());
             rval.m_status = STATUS_INVALID;
+        } else if (rval.m_type == DTYPE_LIST) {
+            rval.set_list(vocab.get_empty_string());
+            rval.m_status = STATUS_INVALID;
+        } else if (rval.m_type == DTYPE_JSON) {
+            rval.set_json(vocab.get_empty_string());
+            rval.m_status = STATUS_INVALID;
+        } else if (rval.m_type == DTYPE_BINARY) {
+            rval.set_binary(vocab.get_empty_string());
+            rval.m_status = STATUS_INVALID;
         }
 
         values[cidx] = rval;
@@ -441,6 +762,7 @@ t_computed_expression_parser::get_dtype(
 
     return dtype;
 }
+#endif
 
 t_validated_expression_map::t_validated_expression_map() = default;
 
@@ -490,17 +812,24 @@ t_validated_expression_map::get_expression_errors() const {
     return m_expression_errors;
 }
 
+#ifndef PSP_DISABLE_EXPRESSIONS
 t_computed_function_store::t_computed_function_store(
     t_expression_vocab& vocab,
     t_regex_mapping& regex_mapping,
     bool is_type_validator,
     const std::shared_ptr<t_data_table>& source_table,
     const t_gstate::t_mapping& pkey_map,
-    t_uindex& row_idx
+    t_uindex& row_idx,
+    const t_tz* timezone,
+    const std::shared_ptr<t_data_table>& master
 ) :
-    m_day_of_week_fn(computed_function::day_of_week(vocab, is_type_validator)),
+    m_bucket_fn(computed_function::bucket(timezone)),
+    m_hour_of_day_fn(computed_function::hour_of_day(timezone)),
+    m_day_of_week_fn(
+        computed_function::day_of_week(vocab, is_type_validator, timezone)
+    ),
     m_month_of_year_fn(
-        computed_function::month_of_year(vocab, is_type_validator)
+        computed_function::month_of_year(vocab, is_type_validator, timezone)
     ),
     m_intern_fn(computed_function::intern(vocab, is_type_validator)),
     m_concat_fn(computed_function::concat(vocab, is_type_validator)),
@@ -521,12 +850,53 @@ t_computed_function_store::t_computed_function_store(
     m_replace_all_fn(
         computed_function::replace_all(vocab, regex_mapping, is_type_validator)
     ),
+    m_regex_extract_fn(computed_function::regex_extract(
+        vocab, regex_mapping, is_type_validator
+    )),
     m_index_fn(computed_function::index(pkey_map, source_table, row_idx)),
     m_col_fn(
         computed_function::col(vocab, is_type_validator, source_table, row_idx)
     ),
     m_vlookup_fn(computed_function::vlookup(
         vocab, is_type_validator, source_table, row_idx
+    )),
+    m_convert_tz_fn(computed_function::convert_tz()),
+    m_at_tz_fn(computed_function::at_tz()),
+    m_json_extract_fn(computed_function::json_extract(vocab, is_type_validator)
+    ),
+    m_geohash_fn(computed_function::geohash(vocab, is_type_validator)),
+    m_soundex_fn(computed_function::soundex(vocab, is_type_validator)),
+    m_zscore_fn(computed_function::group_stat(
+        computed_function::GROUP_STAT_ZSCORE,
+        is_type_validator,
+        source_table,
+        master ? master : source_table,
+        pkey_map,
+        row_idx
+    )),
+    m_percent_of_group_fn(computed_function::group_stat(
+        computed_function::GROUP_STAT_PERCENT_OF_GROUP,
+        is_type_validator,
+        source_table,
+        master ? master : source_table,
+        pkey_map,
+        row_idx
+    )),
+    m_percent_of_total_fn(computed_function::group_stat(
+        computed_function::GROUP_STAT_PERCENT_OF_TOTAL,
+        is_type_validator,
+        source_table,
+        master ? master : source_table,
+        pkey_map,
+        row_idx
+    )),
+    m_rank_in_group_fn(computed_function::group_stat(
+        computed_function::GROUP_STAT_RANK,
+        is_type_validator,
+        source_table,
+        master ? master : source_table,
+        pkey_map,
+        row_idx
     )) {}
 
 void
@@ -534,7 +904,7 @@ t_computed_function_store::register_computed_functions(
     exprtk::symbol_table<t_tscalar>& sym_table
 ) {
     // General/numeric functions
-    sym_table.add_function("bucket", t_computed_expression_parser::BUCKET_FN);
+    sym_table.add_function("bucket", m_bucket_fn);
     sym_table.add_reserved_function(
         "inrange", t_computed_expression_parser::INRANGE_FN
     );
@@ -544,6 +914,9 @@ t_computed_function_store::register_computed_functions(
     sym_table.add_reserved_function(
         "max", t_computed_expression_parser::MAX_FN
     );
+    sym_table.add_reserved_function(
+        "sum", t_computed_expression_parser::SUM_FN
+    );
     sym_table.add_reserved_function(
         "diff3", t_computed_expression_parser::diff3
     );
@@ -565,14 +938,41 @@ t_computed_function_store::register_computed_functions(
     );
     sym_table.add_function("random", t_computed_expression_parser::RANDOM_FN);
 
-    // Date/datetime functions
+    // Geospatial functions
     sym_table.add_function(
-        "hour_of_day", t_computed_expression_parser::HOUR_OF_DAY_FN
+        "haversine_distance", t_computed_expression_parser::HAVERSINE_DISTANCE_FN
     );
+    sym_table.add_function(
+        "within_bbox", t_computed_expression_parser::WITHIN_BBOX_FN
+    );
+    sym_table.add_function("geohash", m_geohash_fn);
+
+    // Network functions
+    sym_table.add_function(
+        "in_subnet", t_computed_expression_parser::IN_SUBNET_FN
+    );
+
+    // Financial functions
+    sym_table.add_function(
+        "round_to_tick", t_computed_expression_parser::ROUND_TO_TICK_FN
+    );
+    sym_table.add_function("bps", t_computed_expression_parser::BPS_FN);
+    sym_table.add_function("pv", t_computed_expression_parser::PV_FN);
+    sym_table.add_function("fv", t_computed_expression_parser::FV_FN);
+    sym_table.add_function("rate", t_computed_expression_parser::RATE_FN);
+    sym_table.add_function("irr", t_computed_expression_parser::IRR_FN);
+    sym_table.add_function(
+        "year_frac", t_computed_expression_parser::YEAR_FRAC_FN
+    );
+
+    // Date/datetime functions
+    sym_table.add_function("hour_of_day", m_hour_of_day_fn);
     sym_table.add_function("day_of_week", m_day_of_week_fn);
     sym_table.add_function("month_of_year", m_month_of_year_fn);
     sym_table.add_function("today", computed_function::today);
     sym_table.add_function("now", computed_function::now);
+    sym_table.add_function("convert_tz", m_convert_tz_fn);
+    sym_table.add_function("at_tz", m_at_tz_fn);
 
     // String functions
     sym_table.add_function("intern", m_intern_fn);
@@ -581,6 +981,34 @@ t_computed_function_store::register_computed_functions(
     sym_table.add_function("upper", m_upper_fn);
     sym_table.add_function("lower", m_lower_fn);
     sym_table.add_function("length", t_computed_expression_parser::LENGTH_FN);
+    sym_table.add_function(
+        "levenshtein", t_computed_expression_parser::LEVENSHTEIN_FN
+    );
+    sym_table.add_function(
+        "jaro_winkler", t_computed_expression_parser::JARO_WINKLER_FN
+    );
+    sym_table.add_function("soundex", m_soundex_fn);
+    sym_table.add_function(
+        "starts_with", t_computed_expression_parser::STARTS_WITH_FN
+    );
+    sym_table.add_function(
+        "ends_with", t_computed_expression_parser::ENDS_WITH_FN
+    );
+
+    // List functions
+    sym_table.add_function("len", t_computed_expression_parser::LEN_FN);
+    sym_table.add_function(
+        "contains", t_computed_expression_parser::CONTAINS_FN
+    );
+
+    // JSON functions
+    sym_table.add_function("json_extract", m_json_extract_fn);
+    sym_table.add_function(
+        "json_extract_float", t_computed_expression_parser::JSON_EXTRACT_FLOAT_FN
+    );
+    sym_table.add_function(
+        "json_extract_bool", t_computed_expression_parser::JSON_EXTRACT_BOOL_FN
+    );
 
     // Type conversion functions
     sym_table.add_function(
@@ -594,6 +1022,9 @@ t_computed_function_store::register_computed_functions(
     sym_table.add_function(
         "datetime", t_computed_expression_parser::MAKE_DATETIME_FN
     );
+    sym_table.add_function(
+        "duration", t_computed_expression_parser::MAKE_DURATION_FN
+    );
     sym_table.add_function("string", m_to_string_fn);
 
     // Regex functions
@@ -604,10 +1035,22 @@ t_computed_function_store::register_computed_functions(
     sym_table.add_function("substring", m_substring_fn);
     sym_table.add_function("replace", m_replace_fn);
     sym_table.add_function("replace_all", m_replace_all_fn);
+
+    // `regex_` names for the functions above, sharing their compiled
+    // pattern cache.
+    sym_table.add_function("regex_match", m_match_fn);
+    sym_table.add_function("regex_extract", m_regex_extract_fn);
+    sym_table.add_function("regex_replace", m_replace_all_fn);
     sym_table.add_function("index", m_index_fn);
     sym_table.add_function("col", m_col_fn);
     sym_table.add_function("vlookup", m_vlookup_fn);
 
+    // Group statistic functions
+    sym_table.add_function("zscore", m_zscore_fn);
+    sym_table.add_function("percent_of_group", m_percent_of_group_fn);
+    sym_table.add_function("percent_of_total", m_percent_of_total_fn);
+    sym_table.add_function("rank_in_group", m_rank_in_group_fn);
+
     // And scalar constants
     sym_table.add_constant("True", t_computed_expression_parser::TRUE_SCALAR);
     sym_table.add_constant("False", t_computed_expression_parser::FALSE_SCALAR);
@@ -616,6 +1059,11 @@ t_computed_function_store::register_computed_functions(
 void
 t_computed_function_store::clear_computed_function_state() {
     m_order_fn.clear_order_map();
+    m_zscore_fn.clear_groups();
+    m_percent_of_group_fn.clear_groups();
+    m_percent_of_total_fn.clear_groups();
+    m_rank_in_group_fn.clear_groups();
 }
+#endif
 
 } // end namespace perspective
\ No newline at end of file
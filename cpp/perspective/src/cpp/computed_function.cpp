@@ -13,9 +13,18 @@
 #include <perspective/computed_function.h>
 #include <perspective/gnode_state.h>
 #include <perspective/column.h>
+#include <algorithm>
+#include <cctype>
 #include <cmath>
+#include <cstring>
+#include <functional>
+#include <numeric>
+#include <rapidjson/document.h>
+#include <rapidjson/stringbuffer.h>
+#include <rapidjson/writer.h>
 
 #include <utility>
+#include <variant>
 
 namespace perspective::computed_function {
 
@@ -265,616 +274,821 @@ length::operator()(t_parameter_list parameters) {
     return rval;
 }
 
-order::order(bool is_type_validator) :
-    m_order_map({}),
-    m_order_idx(0),
-    m_is_type_validator(is_type_validator) {
-    t_tscalar sentinel;
-    sentinel.clear();
-    sentinel.m_type = DTYPE_FLOAT64;
-    m_sentinel = sentinel;
+// List cells are stored as JSON text; parse one into `doc`, returning whether
+// it parsed to an array.
+static bool
+parse_list(const t_tscalar& list, rapidjson::Document& doc) {
+    doc.Parse(list.to_string().c_str());
+    return !doc.HasParseError() && doc.IsArray();
 }
 
-order::~order() = default;
+len::len() : exprtk::igeneric_function<t_tscalar>("T") {}
+
+len::~len() = default;
 
 t_tscalar
-order::operator()(t_parameter_list parameters) {
+len::operator()(t_parameter_list parameters) {
     t_tscalar rval;
     rval.clear();
 
-    // return a float to be compatible with the most comparisons - even
-    // though a uint would make most sense here, if this column returned a
-    // uint comparisons to numeric literals and other numeric columns would
-    // always be false, as comparisons are False across types.
+    // float for the same reason as `length` above.
     rval.m_type = DTYPE_FLOAT64;
 
-    if (parameters.size() <= 1) {
+    t_scalar_view temp(parameters[0]);
+    t_tscalar val = temp();
+
+    if ((val.get_dtype() != DTYPE_LIST && val.get_dtype() != DTYPE_STR)
+        || val.m_status == STATUS_CLEAR) {
         rval.m_status = STATUS_CLEAR;
         return rval;
     }
 
-    // Validate that the input column is of string type
-    const t_generic_type& input_scalar_gt = parameters[0];
-
-    // if first input is not scalar, return
-    if (input_scalar_gt.type != t_generic_type::e_scalar) {
-        rval.m_status = STATUS_CLEAR;
+    if (!val.is_valid() || val.is_none()) {
         return rval;
     }
 
-    t_scalar_view input_scalar_view(input_scalar_gt);
-    t_tscalar input_scalar = input_scalar_view();
-
-    // Invalid type
-    if (input_scalar.get_dtype() != DTYPE_STR
-        || input_scalar.m_status == STATUS_CLEAR) {
-        rval.m_status = STATUS_CLEAR;
+    if (val.get_dtype() == DTYPE_STR) {
+        rval.set(static_cast<double>(val.to_string().length()));
         return rval;
     }
 
-    // generate the map if not generated
-    if (m_order_map.empty()) {
-        // Validate order parameters
-        for (auto i = 1; i < parameters.size(); ++i) {
-            // Because all strings are interned, there should be no string
-            // literals passed to any functions.
-            const t_generic_type& gt = parameters[i];
+    rapidjson::Document doc;
+    if (parse_list(val, doc)) {
+        rval.set(static_cast<double>(doc.Size()));
+    }
 
-            // Make sure all params are scalars too
-            if (gt.type != t_generic_type::e_scalar) {
-                rval.m_status = STATUS_CLEAR;
-                return rval;
-            }
+    return rval;
+}
 
-            t_scalar_view temp(gt);
-            t_tscalar temp_scalar = temp();
+contains::contains() : exprtk::igeneric_function<t_tscalar>("TT") {}
 
-            // Invalid type
-            if (temp_scalar.get_dtype() != DTYPE_STR
-                || temp_scalar.m_status == STATUS_CLEAR) {
-                rval.m_status = STATUS_CLEAR;
-                return rval;
-            }
+contains::~contains() = default;
 
-            // current param is the right type and we are type checking,
-            // so move on to the next param
-            if (m_is_type_validator) {
-                continue;
-            }
+t_tscalar
+contains::operator()(t_parameter_list parameters) {
+    t_tscalar rval;
+    rval.clear();
+    rval.m_type = DTYPE_BOOL;
 
-            // no longer in type-checking - return if the param is invalid.
-            if (!temp_scalar.is_valid()) {
-                return rval;
-            }
+    t_scalar_view haystack_view(parameters[0]);
+    t_scalar_view needle_view(parameters[1]);
+    t_tscalar haystack = haystack_view();
+    t_tscalar needle = needle_view();
 
-            // params[0] is the column, params[1] onward are sort params
-            if (i > 0) {
-                // Read the string param and assign to the order map, and
-                // then increment the internal counter.
-                std::string value = temp_scalar.to_string();
-                m_order_map[value] = m_order_idx;
-                m_order_idx++;
-            }
-        }
+    t_dtype haystack_dtype = haystack.get_dtype();
+    if ((haystack_dtype != DTYPE_LIST && haystack_dtype != DTYPE_STR)
+        || haystack.m_status == STATUS_CLEAR
+        || needle.m_status == STATUS_CLEAR) {
+        rval.m_status = STATUS_CLEAR;
+        return rval;
     }
 
-    // We know the params are valid - so return the sentinel float value.
-    if (m_is_type_validator) {
-        return m_sentinel;
+    // Substring search only makes sense against another string.
+    if (haystack_dtype == DTYPE_STR && needle.get_dtype() != DTYPE_STR) {
+        rval.m_status = STATUS_CLEAR;
+        return rval;
     }
 
-    // read from the map or add the param to the map
-    t_generic_type& gt = parameters[0];
-    t_scalar_view temp(gt);
-    t_tscalar col_value = temp();
+    if (!haystack.is_valid() || haystack.is_none() || !needle.is_valid()) {
+        return rval;
+    }
 
-    // Don't calculate order for invalid scalars.
-    if (!col_value.is_valid()) {
+    if (haystack_dtype == DTYPE_STR) {
+        rval.set(
+            haystack.to_string().find(needle.to_string()) != std::string::npos
+        );
         return rval;
     }
 
-    std::string key = col_value.to_string();
-    auto found = m_order_map.find(key);
+    rapidjson::Document doc;
+    if (!parse_list(haystack, doc)) {
+        return rval;
+    }
 
-    if (found != m_order_map.end()) {
-        rval.set(found->second);
-    } else {
-        // if the value is not in the map, then put it at the end so that
-        // natural sorting can be applied.
-        rval.set(m_order_idx);
+    bool found = false;
+    for (const auto& elem : doc.GetArray()) {
+        if (needle.get_dtype() == DTYPE_STR) {
+            found = elem.IsString()
+                && std::strcmp(elem.GetString(), needle.get_char_ptr()) == 0;
+        } else if (needle.get_dtype() == DTYPE_BOOL) {
+            found = elem.IsBool() && elem.GetBool() == needle.as_bool();
+        } else if (needle.is_numeric()) {
+            found = elem.IsNumber() && elem.GetDouble() == needle.to_double();
+        }
+
+        if (found) {
+            break;
+        }
     }
 
+    rval.set(found);
     return rval;
 }
 
-void
-order::clear_order_map() {
-    m_order_map.clear();
-    m_order_idx = 0;
+// Reads two string arguments into `a` and `b`, returning whether both are
+// valid. Clears `rval` if either argument is not a string.
+static bool
+string_pair_args(
+    t_parameter_list parameters, t_tscalar& rval, std::string& a, std::string& b
+) {
+    t_scalar_view a_view(parameters[0]);
+    t_scalar_view b_view(parameters[1]);
+    t_tscalar a_scalar = a_view();
+    t_tscalar b_scalar = b_view();
+
+    if (a_scalar.get_dtype() != DTYPE_STR || b_scalar.get_dtype() != DTYPE_STR
+        || a_scalar.m_status == STATUS_CLEAR
+        || b_scalar.m_status == STATUS_CLEAR) {
+        rval.m_status = STATUS_CLEAR;
+        return false;
+    }
+
+    if (!a_scalar.is_valid() || a_scalar.is_none() || !b_scalar.is_valid()
+        || b_scalar.is_none()) {
+        return false;
+    }
+
+    a = a_scalar.to_string();
+    b = b_scalar.to_string();
+    return true;
 }
 
-match::match(t_regex_mapping& regex_mapping) :
-    exprtk::igeneric_function<t_tscalar>("TS"),
-    m_regex_mapping(regex_mapping) {}
+// Decodes UTF-8 into code points so edit distances count characters rather
+// than bytes. Malformed bytes decode as themselves.
+static std::u32string
+decode_utf8(const std::string& str) {
+    std::u32string rval;
+    rval.reserve(str.size());
+    std::size_t idx = 0;
+    while (idx < str.size()) {
+        auto lead = static_cast<unsigned char>(str[idx]);
+        std::size_t len = 0;
+        if (lead < 0x80) {
+            len = 1;
+        } else if ((lead >> 5) == 0x6) {
+            len = 2;
+        } else if ((lead >> 4) == 0xE) {
+            len = 3;
+        } else if ((lead >> 3) == 0x1E) {
+            len = 4;
+        }
 
-match::~match() = default;
+        bool valid = len > 0 && idx + len <= str.size();
+        for (std::size_t i = 1; valid && i < len; ++i) {
+            valid = (static_cast<unsigned char>(str[idx + i]) >> 6) == 0x2;
+        }
+
+        if (!valid) {
+            rval.push_back(lead);
+            ++idx;
+            continue;
+        }
+
+        char32_t cp = len == 1 ? lead : lead & (0x7F >> len);
+        for (std::size_t i = 1; i < len; ++i) {
+            cp = (cp << 6) | (static_cast<unsigned char>(str[idx + i]) & 0x3F);
+        }
+
+        rval.push_back(cp);
+        idx += len;
+    }
+
+    return rval;
+}
+
+levenshtein::levenshtein() : exprtk::igeneric_function<t_tscalar>("TT") {}
+
+levenshtein::~levenshtein() = default;
 
 t_tscalar
-match::operator()(t_parameter_list parameters) {
+levenshtein::operator()(t_parameter_list parameters) {
     t_tscalar rval;
     rval.clear();
-    rval.m_type = DTYPE_BOOL;
-
-    // Parameters already validated
-    t_scalar_view str_view(parameters[0]);
-    t_string_view pattern_view(parameters[1]);
 
-    t_tscalar str = str_view();
-    std::string match_pattern =
-        std::string(pattern_view.begin(), pattern_view.end());
+    // float for the same reason as `length` above.
+    rval.m_type = DTYPE_FLOAT64;
 
-    // Type-check: only operate on strings, and pattern must be > size 0
-    if (str.get_dtype() != DTYPE_STR || str.m_status == STATUS_CLEAR
-        || match_pattern.empty()) {
-        rval.m_status = STATUS_CLEAR;
+    std::string a_str;
+    std::string b_str;
+    if (!string_pair_args(parameters, rval, a_str, b_str)) {
         return rval;
     }
 
-    RE2* compiled_pattern = m_regex_mapping.intern(match_pattern);
-
-    if (compiled_pattern == nullptr) {
-        rval.m_status = STATUS_CLEAR;
-        return rval;
-    }
+    std::u32string a = decode_utf8(a_str);
+    std::u32string b = decode_utf8(b_str);
 
-    if (!str.is_valid()) {
-        return rval;
+    // Two rows of the edit distance matrix, indexed by position in `b`.
+    std::vector<std::size_t> prev(b.size() + 1);
+    std::vector<std::size_t> curr(b.size() + 1);
+    for (std::size_t j = 0; j <= b.size(); ++j) {
+        prev[j] = j;
     }
 
-    const std::string& match_string = str.to_string();
+    for (std::size_t i = 1; i <= a.size(); ++i) {
+        curr[0] = i;
+        for (std::size_t j = 1; j <= b.size(); ++j) {
+            std::size_t cost = a[i - 1] == b[j - 1] ? 0 : 1;
+            curr[j] = std::min(
+                {prev[j] + 1, curr[j - 1] + 1, prev[j - 1] + cost}
+            );
+        }
 
-    // Get the pattern from the map and perform the match.
-    rval.set(RE2::PartialMatch(match_string, *compiled_pattern));
+        std::swap(prev, curr);
+    }
 
+    rval.set(static_cast<double>(prev[b.size()]));
     return rval;
 }
 
-match_all::match_all(t_regex_mapping& regex_mapping) :
-    exprtk::igeneric_function<t_tscalar>("TS"),
-    m_regex_mapping(regex_mapping) {}
+jaro_winkler::jaro_winkler() : exprtk::igeneric_function<t_tscalar>("TT") {}
 
-match_all::~match_all() = default;
+jaro_winkler::~jaro_winkler() = default;
 
 t_tscalar
-match_all::operator()(t_parameter_list parameters) {
+jaro_winkler::operator()(t_parameter_list parameters) {
     t_tscalar rval;
     rval.clear();
-    rval.m_type = DTYPE_BOOL;
-
-    // Parameters already validated
-    t_scalar_view str_view(parameters[0]);
-    t_string_view pattern_view(parameters[1]);
+    rval.m_type = DTYPE_FLOAT64;
 
-    t_tscalar str = str_view();
-    std::string match_pattern =
-        std::string(pattern_view.begin(), pattern_view.end());
+    std::string a_str;
+    std::string b_str;
+    if (!string_pair_args(parameters, rval, a_str, b_str)) {
+        return rval;
+    }
 
-    // Type-check: only operate on strings, and pattern must be > size 0
-    if (str.get_dtype() != DTYPE_STR || str.m_status == STATUS_CLEAR
-        || match_pattern.empty()) {
-        rval.m_status = STATUS_CLEAR;
+    std::u32string a = decode_utf8(a_str);
+    std::u32string b = decode_utf8(b_str);
+    if (a.empty() && b.empty()) {
+        rval.set(1.0);
         return rval;
     }
 
-    RE2* compiled_pattern = m_regex_mapping.intern(match_pattern);
+    // Characters match if they are equal and no further apart than half the
+    // longer string's length, less one.
+    std::size_t window = std::max(a.size(), b.size()) / 2;
+    window = window > 0 ? window - 1 : 0;
 
-    if (compiled_pattern == nullptr) {
-        rval.m_status = STATUS_CLEAR;
-        return rval;
+    std::vector<bool> a_matched(a.size());
+    std::vector<bool> b_matched(b.size());
+    double matches = 0;
+    for (std::size_t i = 0; i < a.size(); ++i) {
+        std::size_t lo = i > window ? i - window : 0;
+        std::size_t hi = std::min(i + window + 1, b.size());
+        for (std::size_t j = lo; j < hi; ++j) {
+            if (!b_matched[j] && a[i] == b[j]) {
+                a_matched[i] = true;
+                b_matched[j] = true;
+                ++matches;
+                break;
+            }
+        }
     }
 
-    if (!str.is_valid()) {
+    if (matches == 0) {
+        rval.set(0.0);
         return rval;
     }
 
-    const std::string& match_string = str.to_string();
+    // Half the number of matched characters which are out of order.
+    double transpositions = 0;
+    std::size_t j = 0;
+    for (std::size_t i = 0; i < a.size(); ++i) {
+        if (!a_matched[i]) {
+            continue;
+        }
 
-    // Get the pattern from the map and perform the match.
-    rval.set(RE2::FullMatch(match_string, *compiled_pattern));
+        while (!b_matched[j]) {
+            ++j;
+        }
+
+        if (a[i] != b[j]) {
+            ++transpositions;
+        }
+
+        ++j;
+    }
+
+    transpositions /= 2;
+
+    double jaro = (matches / a.size() + matches / b.size()
+                   + (matches - transpositions) / matches)
+        / 3;
+
+    // Boost similar strings by the length of their common prefix, up to 4.
+    if (jaro > 0.7) {
+        std::size_t prefix = 0;
+        while (prefix < 4 && prefix < a.size() && prefix < b.size()
+               && a[prefix] == b[prefix]) {
+            ++prefix;
+        }
+
+        jaro += prefix * 0.1 * (1 - jaro);
+    }
 
+    rval.set(jaro);
     return rval;
 }
 
-search::search(
-    t_expression_vocab& expression_vocab,
-    t_regex_mapping& regex_mapping,
-    bool is_type_validator
-) :
-    exprtk::igeneric_function<t_tscalar>("TS"),
+soundex::soundex(t_expression_vocab& expression_vocab, bool is_type_validator) :
+    exprtk::igeneric_function<t_tscalar>("T"),
     m_expression_vocab(expression_vocab),
-    m_regex_mapping(regex_mapping),
-    m_is_type_validator(is_type_validator) {}
+    m_is_type_validator(is_type_validator) {
+    t_tscalar sentinel;
+    sentinel.clear();
+    sentinel.set(m_expression_vocab.get_empty_string());
+    sentinel.m_status = STATUS_INVALID;
+    m_sentinel = sentinel;
+}
 
-search::~search() = default;
+soundex::~soundex() = default;
 
 t_tscalar
-search::operator()(t_parameter_list parameters) {
+soundex::operator()(t_parameter_list parameters) {
     t_tscalar rval;
     rval.clear();
     rval.m_type = DTYPE_STR;
 
-    // Parameters already validated
-    t_scalar_view str_view(parameters[0]);
-    t_string_view pattern_view(parameters[1]);
-
-    t_tscalar str = str_view();
-    std::string match_pattern =
-        std::string(pattern_view.begin(), pattern_view.end());
+    t_scalar_view temp(parameters[0]);
+    t_tscalar val = temp();
 
-    // Type-check: only operate on strings, and pattern must be > size 0
-    if (str.get_dtype() != DTYPE_STR || str.m_status == STATUS_CLEAR
-        || match_pattern.empty()) {
+    if (val.get_dtype() != DTYPE_STR || val.m_status == STATUS_CLEAR) {
         rval.m_status = STATUS_CLEAR;
         return rval;
     }
 
-    RE2* compiled_pattern = m_regex_mapping.intern(match_pattern);
-
-    if (compiled_pattern == nullptr
-        || compiled_pattern->NumberOfCapturingGroups() < 1) {
-        rval.m_status = STATUS_CLEAR;
-        return rval;
+    if (m_is_type_validator) {
+        return m_sentinel;
     }
 
-    if (!str.is_valid() || m_is_type_validator) {
+    if (!val.is_valid() || val.is_none()) {
         return rval;
     }
 
-    re2::StringPiece result;
-    const std::string& match_string = str.to_string();
-    bool found = RE2::PartialMatch(match_string, *compiled_pattern, &result);
+    // Digit for each letter A-Z, where '0' marks a vowel (or Y) which
+    // separates repeated digits. H and W are skipped without separating.
+    static constexpr const char* codes = "01230120022455012623010202";
 
-    // Return null if no match, or if the match is size 0 - don't allow
-    // empty strings back out.
-    if (!found || result.empty()) {
+    std::string code;
+    char last = '0';
+    for (char c : val.to_string()) {
+        if (!std::isalpha(static_cast<unsigned char>(c))) {
+            continue;
+        }
+
+        c = static_cast<char>(std::toupper(static_cast<unsigned char>(c)));
+        char digit = codes[c - 'A'];
+        if (code.empty()) {
+            code.push_back(c);
+            last = digit;
+        } else if (c == 'H' || c == 'W') {
+            continue;
+        } else if (digit != last && digit != '0') {
+            code.push_back(digit);
+            if (code.size() == 4) {
+                break;
+            }
+        }
+
+        last = digit;
+    }
+
+    if (code.empty()) {
         return rval;
     }
 
-    rval.set(m_expression_vocab.intern(result.ToString()));
-
+    code.resize(4, '0');
+    rval.set(m_expression_vocab.intern(code));
     return rval;
 }
 
-indexof::indexof(t_regex_mapping& regex_mapping) :
-    exprtk::igeneric_function<t_tscalar>("TSV"),
-    m_regex_mapping(regex_mapping) {}
+starts_with::starts_with() : exprtk::igeneric_function<t_tscalar>("TT") {}
 
-indexof::~indexof() = default;
+starts_with::~starts_with() = default;
 
 t_tscalar
-indexof::operator()(t_parameter_list parameters) {
+starts_with::operator()(t_parameter_list parameters) {
     t_tscalar rval;
     rval.clear();
     rval.m_type = DTYPE_BOOL;
 
-    // Parameters already validated
-    t_scalar_view str_view(parameters[0]);
-    t_string_view pattern_view(parameters[1]);
-    t_vector_view output_vector(parameters[2]);
-
-    t_tscalar str = str_view();
-    std::string match_pattern =
-        std::string(pattern_view.begin(), pattern_view.end());
-
-    // Type-check: only operate on strings, and pattern must be > size 0,
-    // and output vector must be big enough to hold the output
-    if (str.get_dtype() != DTYPE_STR || str.m_status == STATUS_CLEAR
-        || match_pattern.empty() || output_vector.size() < 2) {
-        rval.m_status = STATUS_CLEAR;
+    std::string str;
+    std::string prefix;
+    if (!string_pair_args(parameters, rval, str, prefix)) {
         return rval;
     }
 
-    RE2* compiled_pattern = m_regex_mapping.intern(match_pattern);
+    rval.set(str.compare(0, prefix.size(), prefix) == 0);
+    return rval;
+}
 
-    if (compiled_pattern == nullptr
-        || compiled_pattern->NumberOfCapturingGroups() < 1) {
-        rval.m_status = STATUS_CLEAR;
+ends_with::ends_with() : exprtk::igeneric_function<t_tscalar>("TT") {}
+
+ends_with::~ends_with() = default;
+
+t_tscalar
+ends_with::operator()(t_parameter_list parameters) {
+    t_tscalar rval;
+    rval.clear();
+    rval.m_type = DTYPE_BOOL;
+
+    std::string str;
+    std::string suffix;
+    if (!string_pair_args(parameters, rval, str, suffix)) {
         return rval;
     }
 
-    if (!str.is_valid()) {
-        return rval;
+    rval.set(
+        str.size() >= suffix.size()
+        && str.compare(str.size() - suffix.size(), suffix.size(), suffix) == 0
+    );
+    return rval;
+}
+
+// One step of a JSON path: a member name or an array index.
+typedef std::variant<std::string, std::size_t> t_json_path_segment;
+
+// Parses a path such as `$.order.items[0].sku` or `$['order']`, returning
+// whether it is well formed.
+static bool
+parse_json_path(
+    const std::string& path, std::vector<t_json_path_segment>& segments
+) {
+    if (path.empty() || path[0] != '$') {
+        return false;
+    }
+
+    std::size_t idx = 1;
+    while (idx < path.size()) {
+        if (path[idx] == '.') {
+            std::size_t end = path.find_first_of(".[", idx + 1);
+            if (end == std::string::npos) {
+                end = path.size();
+            }
+
+            if (end == idx + 1) {
+                return false;
+            }
+
+            segments.emplace_back(path.substr(idx + 1, end - idx - 1));
+            idx = end;
+        } else if (path[idx] == '[') {
+            std::size_t end = path.find(']', idx);
+            if (end == std::string::npos || end == idx + 1) {
+                return false;
+            }
+
+            std::string inner = path.substr(idx + 1, end - idx - 1);
+            if (inner.size() >= 2 && (inner[0] == '\'' || inner[0] == '"')
+                && inner.back() == inner[0]) {
+                segments.emplace_back(inner.substr(1, inner.size() - 2));
+            } else if (inner.find_first_not_of("0123456789")
+                       == std::string::npos) {
+                segments.emplace_back(std::stoul(inner));
+            } else {
+                return false;
+            }
+
+            idx = end + 1;
+        } else {
+            return false;
+        }
     }
 
-    re2::StringPiece result;
-    const std::string& match_string = str.to_string();
-    bool found = RE2::PartialMatch(match_string, *compiled_pattern, &result);
+    return true;
+}
 
-    if (!found) {
-        // no-op on the input vector
-        rval.set(false);
-        return rval;
+// Shared argument handling for the `json_extract` functions. Returns the
+// value at the path in the document, or nullptr if the document is null or
+// has no such value. Type errors set `rval` to STATUS_CLEAR.
+static const rapidjson::Value*
+json_extract_value(
+    t_parameter_list parameters, rapidjson::Document& doc, t_tscalar& rval
+) {
+    t_scalar_view doc_view(parameters[0]);
+    t_string_view path_view(parameters[1]);
+    t_tscalar doc_scalar = doc_view();
+    std::string path(path_view.begin(), path_view.end());
+
+    std::vector<t_json_path_segment> segments;
+    if ((doc_scalar.get_dtype() != DTYPE_JSON
+         && doc_scalar.get_dtype() != DTYPE_STR)
+        || doc_scalar.m_status == STATUS_CLEAR
+        || !parse_json_path(path, segments)) {
+        rval.m_status = STATUS_CLEAR;
+        return nullptr;
     }
 
-    // re2::StringPiece::data is a ptr into the string being matched,
-    // so we can pointer math the start and end index of the match
-    std::size_t start_idx = result.data() - match_string.data();
-    std::size_t end_idx = start_idx + result.size() - 1;
+    if (!doc_scalar.is_valid() || doc_scalar.is_none()) {
+        return nullptr;
+    }
 
-    if (start_idx < 0 || end_idx < 0 || end_idx >= match_string.size()
-        || (start_idx > end_idx)) {
-        rval.set(false);
-        return rval;
+    doc.Parse(doc_scalar.to_string().c_str());
+    if (doc.HasParseError()) {
+        return nullptr;
     }
 
-    t_tscalar start_scalar;
-    t_tscalar end_scalar;
+    const rapidjson::Value* value = &doc;
+    for (const auto& segment : segments) {
+        if (const auto* key = std::get_if<std::string>(&segment)) {
+            if (!value->IsObject()) {
+                return nullptr;
+            }
 
-    start_scalar.set(static_cast<double>(start_idx));
-    end_scalar.set(static_cast<double>(end_idx));
+            auto member = value->FindMember(key->c_str());
+            if (member == value->MemberEnd()) {
+                return nullptr;
+            }
 
-    output_vector[0] = start_scalar;
-    output_vector[1] = end_scalar;
+            value = &member->value;
+        } else {
+            std::size_t idx = std::get<std::size_t>(segment);
+            if (!value->IsArray() || idx >= value->Size()) {
+                return nullptr;
+            }
 
-    rval.set(true);
-    return rval;
+            value = &(*value)[idx];
+        }
+    }
+
+    if (value->IsNull()) {
+        return nullptr;
+    }
+
+    return value;
 }
 
-substring::substring(
+json_extract::json_extract(
     t_expression_vocab& expression_vocab, bool is_type_validator
 ) :
+    exprtk::igeneric_function<t_tscalar>("TS"),
     m_expression_vocab(expression_vocab),
-    m_is_type_validator(is_type_validator) {}
+    m_is_type_validator(is_type_validator) {
+    t_tscalar sentinel;
+    sentinel.clear();
+    sentinel.set(m_expression_vocab.get_empty_string());
+    sentinel.m_status = STATUS_INVALID;
+    m_sentinel = sentinel;
+}
 
-substring::~substring() = default;
+json_extract::~json_extract() = default;
 
 t_tscalar
-substring::operator()(t_parameter_list parameters) {
+json_extract::operator()(t_parameter_list parameters) {
     t_tscalar rval;
     rval.clear();
     rval.m_type = DTYPE_STR;
-    auto num_params = parameters.size();
 
-    // substring(string, start_idx) or substring(string, start_idx, length)
-    if (num_params != 2 && num_params != 3) {
-        rval.m_status = STATUS_CLEAR;
+    rapidjson::Document doc;
+    const rapidjson::Value* value = json_extract_value(parameters, doc, rval);
+    if (rval.m_status == STATUS_CLEAR) {
         return rval;
     }
 
-    std::string search_string;
-
-    // Must be able to check for negative indices from the user -
-    // std::size_t is unsigned so a user passing in -1 is automatically
-    // cast to 0, which is incorrect as we want to detect the -1 and return
-    // null.
-    std::int64_t start_idx;
+    if (m_is_type_validator) {
+        return m_sentinel;
+    }
 
-    // npos == all chars until end of the string
-    std::int64_t substring_length = std::string::npos;
+    if (value == nullptr) {
+        return rval;
+    }
 
-    for (auto i = 0; i < num_params; ++i) {
-        const t_generic_type& gt = parameters[i];
+    // Strings are returned unquoted, anything else as its JSON text.
+    std::string text;
+    if (value->IsString()) {
+        text = value->GetString();
+    } else {
+        rapidjson::StringBuffer buffer;
+        rapidjson::Writer<rapidjson::StringBuffer> writer(buffer);
+        value->Accept(writer);
+        text = buffer.GetString();
+    }
 
-        if (gt.type == t_generic_type::e_scalar) {
-            t_scalar_view temp_scalar_view(gt);
-            t_tscalar temp_scalar = temp_scalar_view();
+    if (text.empty()) {
+        return m_sentinel;
+    }
 
-            // type check - first param must be string, 2nd and 3rd param
-            // must be numeric, all must be valid
-            t_dtype dtype = temp_scalar.get_dtype();
+    rval.set(m_expression_vocab.intern(text));
+    return rval;
+}
 
-            if ((i == 0 && dtype != DTYPE_STR)
-                || (i != 0 && !temp_scalar.is_numeric())
-                || temp_scalar.m_status == STATUS_CLEAR) {
-                rval.m_status = STATUS_CLEAR;
-                return rval;
-            }
+json_extract_float::json_extract_float() :
+    exprtk::igeneric_function<t_tscalar>("TS") {}
 
-            // Only check for types - bad indices will always return null
-            // but be valid expressions.
-            if (m_is_type_validator || !temp_scalar.is_valid()) {
-                return rval;
-            }
+json_extract_float::~json_extract_float() = default;
 
-            // Passed type checking, assign values
-            if (i == 0) {
-                search_string = temp_scalar.to_string();
-            } else if (i == 1) {
-                start_idx = temp_scalar.to_double();
-            } else if (i == 2) {
-                substring_length = temp_scalar.to_double();
-            }
-        } else {
-            // called with invalid params - exit
-            rval.m_status = STATUS_CLEAR;
-            return rval;
-        }
-    }
+t_tscalar
+json_extract_float::operator()(t_parameter_list parameters) {
+    t_tscalar rval;
+    rval.clear();
+    rval.m_type = DTYPE_FLOAT64;
 
-    // done type checking
-    if (m_is_type_validator) {
+    rapidjson::Document doc;
+    const rapidjson::Value* value = json_extract_value(parameters, doc, rval);
+    if (value == nullptr) {
         return rval;
     }
 
-    std::size_t length = search_string.length();
-
-    // Value check: strings cannot be 0 length, indices must be valid
-    if (length == 0 || start_idx < 0
-        || (num_params == 3 && substring_length < 0) || start_idx >= length
-        || (substring_length != std::string::npos
-            && start_idx + substring_length > length)) {
-        return rval;
+    if (value->IsNumber()) {
+        rval.set(value->GetDouble());
+    } else if (value->IsBool()) {
+        rval.set(value->GetBool() ? 1.0 : 0.0);
+    } else if (value->IsString()) {
+        char* end = nullptr;
+        double number = std::strtod(value->GetString(), &end);
+        if (end != value->GetString() && *end == '\0') {
+            rval.set(number);
+        }
     }
 
-    rval.set(m_expression_vocab.intern(
-        search_string.substr(start_idx, substring_length)
-    ));
-
     return rval;
 }
 
-replace::replace(
-    t_expression_vocab& expression_vocab,
-    t_regex_mapping& regex_mapping,
-    bool is_type_validator
-) :
-    exprtk::igeneric_function<t_tscalar>("TS?"),
-    m_expression_vocab(expression_vocab),
-    m_regex_mapping(regex_mapping),
-    m_is_type_validator(is_type_validator) {}
+json_extract_bool::json_extract_bool() :
+    exprtk::igeneric_function<t_tscalar>("TS") {}
 
-replace::~replace() = default;
+json_extract_bool::~json_extract_bool() = default;
 
 t_tscalar
-replace::operator()(t_parameter_list parameters) {
+json_extract_bool::operator()(t_parameter_list parameters) {
     t_tscalar rval;
     rval.clear();
-    rval.m_type = DTYPE_STR;
+    rval.m_type = DTYPE_BOOL;
 
-    // the string to be replaced
-    t_scalar_view string_scalar_view(parameters[0]);
-    t_tscalar string_scalar = string_scalar_view();
+    rapidjson::Document doc;
+    const rapidjson::Value* value = json_extract_value(parameters, doc, rval);
+    if (value == nullptr) {
+        return rval;
+    }
 
-    // the replace pattern
-    t_string_view pattern_view(parameters[1]);
-    std::string match_pattern =
-        std::string(pattern_view.begin(), pattern_view.end());
+    if (value->IsBool()) {
+        rval.set(value->GetBool());
+    } else if (value->IsNumber()) {
+        rval.set(value->GetDouble() != 0);
+    } else if (value->IsString()) {
+        std::string text = value->GetString();
+        if (text == "true" || text == "false") {
+            rval.set(text == "true");
+        }
+    }
 
-    // replacer can be a string literal, for the string '' as intern does
-    // not pick up on empty strings but we need to be able to replace
-    // with empty string. Thus, type-check replacer before continuing.
-    const t_generic_type& gt(parameters[2]);
-    t_tscalar replacer_scalar;
+    return rval;
+}
 
-    if (gt.type == t_generic_type::e_scalar) {
-        t_scalar_view replacer_view(gt);
-        replacer_scalar = replacer_view();
-    } else if (gt.type == t_generic_type::e_string) {
-        t_string_view replacer_view(gt);
-        std::string replacer_str =
-            std::string(replacer_view.begin(), replacer_view.end());
+order::order(bool is_type_validator) :
+    m_order_map({}),
+    m_order_idx(0),
+    m_is_type_validator(is_type_validator) {
+    t_tscalar sentinel;
+    sentinel.clear();
+    sentinel.m_type = DTYPE_FLOAT64;
+    m_sentinel = sentinel;
+}
 
-        // only the empty string should be passed in as a string literal,
-        // all other strings must be interned first.
-        if (!replacer_str.empty()) {
-            rval.m_status = STATUS_CLEAR;
-            return rval;
-        }
+order::~order() = default;
 
-        // use the empty string from vocab
-        replacer_scalar.set(m_expression_vocab.get_empty_string());
-    } else {
+t_tscalar
+order::operator()(t_parameter_list parameters) {
+    t_tscalar rval;
+    rval.clear();
+
+    // return a float to be compatible with the most comparisons - even
+    // though a uint would make most sense here, if this column returned a
+    // uint comparisons to numeric literals and other numeric columns would
+    // always be false, as comparisons are False across types.
+    rval.m_type = DTYPE_FLOAT64;
+
+    if (parameters.size() <= 1) {
         rval.m_status = STATUS_CLEAR;
         return rval;
     }
 
-    if (string_scalar.m_type != DTYPE_STR || replacer_scalar.m_type != DTYPE_STR
-        || match_pattern.empty()) {
+    // Validate that the input column is of string type
+    const t_generic_type& input_scalar_gt = parameters[0];
+
+    // if first input is not scalar, return
+    if (input_scalar_gt.type != t_generic_type::e_scalar) {
         rval.m_status = STATUS_CLEAR;
         return rval;
     }
 
-    // typecheck the regex
-    RE2* compiled_pattern = m_regex_mapping.intern(match_pattern);
+    t_scalar_view input_scalar_view(input_scalar_gt);
+    t_tscalar input_scalar = input_scalar_view();
 
-    if (compiled_pattern == nullptr) {
+    // Invalid type
+    if (input_scalar.get_dtype() != DTYPE_STR
+        || input_scalar.m_status == STATUS_CLEAR) {
         rval.m_status = STATUS_CLEAR;
         return rval;
     }
 
-    // done with type_checking
+    // generate the map if not generated
+    if (m_order_map.empty()) {
+        // Validate order parameters
+        for (auto i = 1; i < parameters.size(); ++i) {
+            // Because all strings are interned, there should be no string
+            // literals passed to any functions.
+            const t_generic_type& gt = parameters[i];
+
+            // Make sure all params are scalars too
+            if (gt.type != t_generic_type::e_scalar) {
+                rval.m_status = STATUS_CLEAR;
+                return rval;
+            }
+
+            t_scalar_view temp(gt);
+            t_tscalar temp_scalar = temp();
+
+            // Invalid type
+            if (temp_scalar.get_dtype() != DTYPE_STR
+                || temp_scalar.m_status == STATUS_CLEAR) {
+                rval.m_status = STATUS_CLEAR;
+                return rval;
+            }
+
+            // current param is the right type and we are type checking,
+            // so move on to the next param
+            if (m_is_type_validator) {
+                continue;
+            }
+
+            // no longer in type-checking - return if the param is invalid.
+            if (!temp_scalar.is_valid()) {
+                return rval;
+            }
+
+            // params[0] is the column, params[1] onward are sort params
+            if (i > 0) {
+                // Read the string param and assign to the order map, and
+                // then increment the internal counter.
+                std::string value = temp_scalar.to_string();
+                m_order_map[value] = m_order_idx;
+                m_order_idx++;
+            }
+        }
+    }
+
+    // We know the params are valid - so return the sentinel float value.
     if (m_is_type_validator) {
-        return rval;
+        return m_sentinel;
     }
 
-    // make a copy of search_str, as replace() will mutate it and we
-    // don't want to mutate the string in the vocab
-    std::string search_string = string_scalar.to_string();
+    // read from the map or add the param to the map
+    t_generic_type& gt = parameters[0];
+    t_scalar_view temp(gt);
+    t_tscalar col_value = temp();
 
-    if (search_string.empty()) {
+    // Don't calculate order for invalid scalars.
+    if (!col_value.is_valid()) {
         return rval;
     }
 
-    // but we can take a reference to the replacer
-    const std::string& replacer_string = replacer_scalar.to_string();
-    re2::StringPiece replacer(replacer_string);
-
-    bool replaced =
-        RE2::Replace(&(search_string), *(compiled_pattern), replacer);
+    std::string key = col_value.to_string();
+    auto found = m_order_map.find(key);
 
-    if (!replaced) {
-        // Return the original result if the replacement didn't happen
-        return string_scalar;
+    if (found != m_order_map.end()) {
+        rval.set(found->second);
+    } else {
+        // if the value is not in the map, then put it at the end so that
+        // natural sorting can be applied.
+        rval.set(m_order_idx);
     }
 
-    // Or the string with the replacement set
-    rval.set(m_expression_vocab.intern(search_string));
-
     return rval;
 }
 
-replace_all::replace_all(
-    t_expression_vocab& expression_vocab,
-    t_regex_mapping& regex_mapping,
-    bool is_type_validator
-) :
-    exprtk::igeneric_function<t_tscalar>("TS?"),
-    m_expression_vocab(expression_vocab),
-    m_regex_mapping(regex_mapping),
-    m_is_type_validator(is_type_validator) {}
+void
+order::clear_order_map() {
+    m_order_map.clear();
+    m_order_idx = 0;
+}
 
-replace_all::~replace_all() = default;
+match::match(t_regex_mapping& regex_mapping) :
+    exprtk::igeneric_function<t_tscalar>("TS"),
+    m_regex_mapping(regex_mapping) {}
+
+match::~match() = default;
 
 t_tscalar
-replace_all::operator()(t_parameter_list parameters) {
+match::operator()(t_parameter_list parameters) {
     t_tscalar rval;
     rval.clear();
-    rval.m_type = DTYPE_STR;
-
-    // the string to be replaced
-    t_scalar_view string_scalar_view(parameters[0]);
-    t_tscalar string_scalar = string_scalar_view();
+    rval.m_type = DTYPE_BOOL;
 
-    // the replace pattern
+    // Parameters already validated
+    t_scalar_view str_view(parameters[0]);
     t_string_view pattern_view(parameters[1]);
+
+    t_tscalar str = str_view();
     std::string match_pattern =
         std::string(pattern_view.begin(), pattern_view.end());
 
-    // replacer can be a string literal, for the string '' as intern does
-    // not pick up on empty strings but we need to be able to replace
-    // with empty string. Thus, type-check replacer before continuing.
-    const t_generic_type& gt(parameters[2]);
-    t_tscalar replacer_scalar;
-
-    if (gt.type == t_generic_type::e_scalar) {
-        t_scalar_view replacer_view(gt);
-        replacer_scalar = replacer_view();
-    } else if (gt.type == t_generic_type::e_string) {
-        t_string_view replacer_view(gt);
-        std::string replacer_str =
-            std::string(replacer_view.begin(), replacer_view.end());
-
-        // only the empty string should be passed in as a string literal,
-        // all other strings must be interned first.
-        if (!replacer_str.empty()) {
-            rval.m_status = STATUS_CLEAR;
-            return rval;
-        }
-
-        // use the empty string from vocab
-        replacer_scalar.set(m_expression_vocab.get_empty_string());
-    } else {
-        rval.m_status = STATUS_CLEAR;
-        return rval;
-    }
-
-    if (string_scalar.m_type != DTYPE_STR || replacer_scalar.m_type != DTYPE_STR
+    // Type-check: only operate on strings, and pattern must be > size 0
+    if (str.get_dtype() != DTYPE_STR || str.m_status == STATUS_CLEAR
         || match_pattern.empty()) {
         rval.m_status = STATUS_CLEAR;
         return rval;
     }
 
-    // typecheck the regex
     RE2* compiled_pattern = m_regex_mapping.intern(match_pattern);
 
     if (compiled_pattern == nullptr) {
@@ -882,435 +1096,984 @@ replace_all::operator()(t_parameter_list parameters) {
         return rval;
     }
 
-    // done with type_checking
-    if (m_is_type_validator) {
+    if (!str.is_valid()) {
         return rval;
     }
 
-    // make a copy of search_str, as replace() will mutate it and we
-    // don't want to mutate the string in the vocab
-    std::string search_string = string_scalar.to_string();
+    const std::string& match_string = str.to_string();
 
-    if (search_string.empty()) {
+    // Get the pattern from the map and perform the match.
+    rval.set(RE2::PartialMatch(match_string, *compiled_pattern));
+
+    return rval;
+}
+
+match_all::match_all(t_regex_mapping& regex_mapping) :
+    exprtk::igeneric_function<t_tscalar>("TS"),
+    m_regex_mapping(regex_mapping) {}
+
+match_all::~match_all() = default;
+
+t_tscalar
+match_all::operator()(t_parameter_list parameters) {
+    t_tscalar rval;
+    rval.clear();
+    rval.m_type = DTYPE_BOOL;
+
+    // Parameters already validated
+    t_scalar_view str_view(parameters[0]);
+    t_string_view pattern_view(parameters[1]);
+
+    t_tscalar str = str_view();
+    std::string match_pattern =
+        std::string(pattern_view.begin(), pattern_view.end());
+
+    // Type-check: only operate on strings, and pattern must be > size 0
+    if (str.get_dtype() != DTYPE_STR || str.m_status == STATUS_CLEAR
+        || match_pattern.empty()) {
+        rval.m_status = STATUS_CLEAR;
         return rval;
     }
 
-    // but we can take a reference to the replacer
-    const std::string& replacer_string = replacer_scalar.to_string();
-    re2::StringPiece replacer(replacer_string);
+    RE2* compiled_pattern = m_regex_mapping.intern(match_pattern);
 
-    std::size_t replaced =
-        RE2::GlobalReplace(&(search_string), *(compiled_pattern), replacer);
+    if (compiled_pattern == nullptr) {
+        rval.m_status = STATUS_CLEAR;
+        return rval;
+    }
 
-    if (replaced == 0) {
-        // Return the original result if the replacement didn't happen
-        return string_scalar;
+    if (!str.is_valid()) {
+        return rval;
     }
 
-    // Or the string with the replacement set
-    rval.set(m_expression_vocab.intern(search_string));
+    const std::string& match_string = str.to_string();
+
+    // Get the pattern from the map and perform the match.
+    rval.set(RE2::FullMatch(match_string, *compiled_pattern));
 
     return rval;
 }
 
-hour_of_day::hour_of_day() : exprtk::igeneric_function<t_tscalar>("T") {}
+search::search(
+    t_expression_vocab& expression_vocab,
+    t_regex_mapping& regex_mapping,
+    bool is_type_validator
+) :
+    exprtk::igeneric_function<t_tscalar>("TS"),
+    m_expression_vocab(expression_vocab),
+    m_regex_mapping(regex_mapping),
+    m_is_type_validator(is_type_validator) {}
 
-hour_of_day::~hour_of_day() = default;
+search::~search() = default;
 
 t_tscalar
-hour_of_day::operator()(t_parameter_list parameters) {
-    t_tscalar val;
+search::operator()(t_parameter_list parameters) {
     t_tscalar rval;
     rval.clear();
+    rval.m_type = DTYPE_STR;
 
-    // return a float to be compatible with the most comparisons - even
-    // though a uint would make most sense here, if this column returned a
-    // uint comparisons to numeric literals and other numeric columns would
-    // always be false, as comparisons are False across types.
-    rval.m_type = DTYPE_FLOAT64;
-
-    t_generic_type& gt = parameters[0];
-    t_scalar_view temp(gt);
-    t_tscalar temp_scalar = temp();
+    // Parameters already validated
+    t_scalar_view str_view(parameters[0]);
+    t_string_view pattern_view(parameters[1]);
 
-    t_dtype dtype = temp_scalar.get_dtype();
-    bool valid_dtype = dtype == DTYPE_DATE || dtype == DTYPE_TIME;
+    t_tscalar str = str_view();
+    std::string match_pattern =
+        std::string(pattern_view.begin(), pattern_view.end());
 
-    if (!valid_dtype || temp_scalar.m_status == STATUS_CLEAR) {
+    // Type-check: only operate on strings, and pattern must be > size 0
+    if (str.get_dtype() != DTYPE_STR || str.m_status == STATUS_CLEAR
+        || match_pattern.empty()) {
         rval.m_status = STATUS_CLEAR;
+        return rval;
     }
 
-    if (!temp_scalar.is_valid()) {
+    RE2* compiled_pattern = m_regex_mapping.intern(match_pattern);
+
+    if (compiled_pattern == nullptr
+        || compiled_pattern->NumberOfCapturingGroups() < 1) {
+        rval.m_status = STATUS_CLEAR;
         return rval;
     }
 
-    val.set(temp_scalar);
-
-    if (val.get_dtype() == DTYPE_TIME) {
-        // Convert the int64 to a milliseconds duration timestamp
-        std::chrono::milliseconds timestamp(val.to_int64());
+    if (!str.is_valid() || m_is_type_validator) {
+        return rval;
+    }
 
-        // Convert the timestamp to a `sys_time` (alias for `time_point`)
-        date::sys_time<std::chrono::milliseconds> ts(timestamp);
+    re2::StringPiece result;
+    const std::string& match_string = str.to_string();
+    bool found = RE2::PartialMatch(match_string, *compiled_pattern, &result);
 
-        // Use localtime so that the hour of day is consistent with all
-        // output datetimes, which are in local time
-        std::time_t temp = std::chrono::system_clock::to_time_t(ts);
-        std::tm* t = std::localtime(&temp);
-
-        // Get the hour from the resulting `std::tm`
-        rval.set(static_cast<double>(t->tm_hour));
-    } else {
-        // Hour of day for date column is always 0
-        rval.set(0.0);
+    // Return null if no match, or if the match is size 0 - don't allow
+    // empty strings back out.
+    if (!found || result.empty()) {
+        return rval;
     }
 
+    rval.set(m_expression_vocab.intern(result.ToString()));
+
     return rval;
 }
 
-const std::string days_of_week[7] = {
-    "1 Sunday",
-    "2 Monday",
-    "3 Tuesday",
-    "4 Wednesday",
-    "5 Thursday",
-    "6 Friday",
-    "7 Saturday"
-};
-
-const std::string months_of_year[12] = {
-    "01 January",
-    "02 February",
-    "03 March",
-    "04 April",
-    "05 May",
-    "06 June",
-    "07 July",
-    "08 August",
-    "09 September",
-    "10 October",
-    "11 November",
-    "12 December"
-};
-
-day_of_week::day_of_week(
-    t_expression_vocab& expression_vocab, bool is_type_validator
-) :
-    exprtk::igeneric_function<t_tscalar>("T"),
-    m_expression_vocab(expression_vocab),
-    m_is_type_validator(is_type_validator) {
-    t_tscalar sentinel;
-    sentinel.clear();
-    sentinel.set(m_expression_vocab.get_empty_string());
-    sentinel.m_status = STATUS_INVALID;
-
-    m_sentinel = sentinel;
-}
+indexof::indexof(t_regex_mapping& regex_mapping) :
+    exprtk::igeneric_function<t_tscalar>("TSV"),
+    m_regex_mapping(regex_mapping) {}
 
-day_of_week::~day_of_week() = default;
+indexof::~indexof() = default;
 
 t_tscalar
-day_of_week::operator()(t_parameter_list parameters) {
-    t_tscalar val;
+indexof::operator()(t_parameter_list parameters) {
     t_tscalar rval;
     rval.clear();
-    rval.m_type = DTYPE_STR;
+    rval.m_type = DTYPE_BOOL;
 
-    t_generic_type& gt = parameters[0];
-    t_scalar_view temp(gt);
-    t_tscalar temp_scalar = temp();
-    t_dtype dtype = temp_scalar.get_dtype();
-    bool valid_dtype = dtype == DTYPE_DATE || dtype == DTYPE_TIME;
+    // Parameters already validated
+    t_scalar_view str_view(parameters[0]);
+    t_string_view pattern_view(parameters[1]);
+    t_vector_view output_vector(parameters[2]);
 
-    if (!valid_dtype || temp_scalar.m_status == STATUS_CLEAR) {
-        rval.m_status = STATUS_CLEAR;
-    }
+    t_tscalar str = str_view();
+    std::string match_pattern =
+        std::string(pattern_view.begin(), pattern_view.end());
 
-    if (!temp_scalar.is_valid()) {
+    // Type-check: only operate on strings, and pattern must be > size 0,
+    // and output vector must be big enough to hold the output
+    if (str.get_dtype() != DTYPE_STR || str.m_status == STATUS_CLEAR
+        || match_pattern.empty() || output_vector.size() < 2) {
+        rval.m_status = STATUS_CLEAR;
         return rval;
     }
 
-    val.set(temp_scalar);
+    RE2* compiled_pattern = m_regex_mapping.intern(match_pattern);
 
-    if (m_is_type_validator) {
-        return m_sentinel;
+    if (compiled_pattern == nullptr
+        || compiled_pattern->NumberOfCapturingGroups() < 1) {
+        rval.m_status = STATUS_CLEAR;
+        return rval;
     }
 
-    std::string result;
-
-    if (val.get_dtype() == DTYPE_TIME) {
-        // Convert the int64 to a milliseconds duration timestamp
-        std::chrono::milliseconds timestamp(val.to_int64());
+    if (!str.is_valid()) {
+        return rval;
+    }
 
-        // Convert the timestamp to a `sys_time` (alias for `time_point`)
-        date::sys_time<std::chrono::milliseconds> ts(timestamp);
+    re2::StringPiece result;
+    const std::string& match_string = str.to_string();
+    bool found = RE2::PartialMatch(match_string, *compiled_pattern, &result);
 
-        // Use localtime so that the hour of day is consistent with all
-        // output datetimes, which are in local time
-        std::time_t temp = std::chrono::system_clock::to_time_t(ts);
-        std::tm* t = std::localtime(&temp);
+    if (!found) {
+        // no-op on the input vector
+        rval.set(false);
+        return rval;
+    }
 
-        // Get the weekday from the resulting `std::tm`
-        result = days_of_week[t->tm_wday];
-    } else {
-        // Retrieve the `t_date` struct from the scalar
-        t_date date_val = val.get<t_date>();
+    // re2::StringPiece::data is a ptr into the string being matched,
+    // so we can pointer math the start and end index of the match
+    std::size_t start_idx = result.data() - match_string.data();
+    std::size_t end_idx = start_idx + result.size() - 1;
 
-        // Construct a `date::year_month_day` value
-        date::year year{date_val.year()};
+    if (start_idx < 0 || end_idx < 0 || end_idx >= match_string.size()
+        || (start_idx > end_idx)) {
+        rval.set(false);
+        return rval;
+    }
 
-        // date::month is [1-12], whereas `t_date.month()` is [0-11]
-        date::month month{static_cast<std::uint32_t>(date_val.month()) + 1};
-        date::day day{static_cast<std::uint32_t>(date_val.day())};
-        date::year_month_day ymd(year, month, day);
+    t_tscalar start_scalar;
+    t_tscalar end_scalar;
 
-        // Construct a `date::year_month_weekday` from `date::sys_days`
-        // since epoch
-        auto weekday =
-            date::year_month_weekday(ymd).weekday_indexed().weekday();
+    start_scalar.set(static_cast<double>(start_idx));
+    end_scalar.set(static_cast<double>(end_idx));
 
-        result = days_of_week[(weekday - date::Sunday).count()];
-    }
+    output_vector[0] = start_scalar;
+    output_vector[1] = end_scalar;
 
-    rval.set(m_expression_vocab.intern(result));
+    rval.set(true);
     return rval;
 }
 
-month_of_year::month_of_year(
+substring::substring(
     t_expression_vocab& expression_vocab, bool is_type_validator
 ) :
-    exprtk::igeneric_function<t_tscalar>("T"),
     m_expression_vocab(expression_vocab),
-    m_is_type_validator(is_type_validator) {
-    t_tscalar sentinel;
-    sentinel.clear();
-    sentinel.set(m_expression_vocab.get_empty_string());
-    sentinel.m_status = STATUS_INVALID;
-
-    m_sentinel = sentinel;
-}
+    m_is_type_validator(is_type_validator) {}
 
-month_of_year::~month_of_year() = default;
+substring::~substring() = default;
 
 t_tscalar
-month_of_year::operator()(t_parameter_list parameters) {
-    t_tscalar val;
+substring::operator()(t_parameter_list parameters) {
     t_tscalar rval;
     rval.clear();
     rval.m_type = DTYPE_STR;
+    auto num_params = parameters.size();
 
-    t_generic_type& gt = parameters[0];
-    t_scalar_view temp(gt);
-    t_tscalar temp_scalar = temp();
-
-    t_dtype dtype = temp_scalar.get_dtype();
-    bool valid_dtype = dtype == DTYPE_DATE || dtype == DTYPE_TIME;
-
-    if (!valid_dtype || temp_scalar.m_status == STATUS_CLEAR) {
+    // substring(string, start_idx) or substring(string, start_idx, length)
+    if (num_params != 2 && num_params != 3) {
         rval.m_status = STATUS_CLEAR;
-    }
-
-    if (!temp_scalar.is_valid()) {
         return rval;
     }
 
-    val.set(temp_scalar);
+    std::string search_string;
 
-    if (m_is_type_validator) {
-        return m_sentinel;
-    }
+    // Must be able to check for negative indices from the user -
+    // std::size_t is unsigned so a user passing in -1 is automatically
+    // cast to 0, which is incorrect as we want to detect the -1 and return
+    // null.
+    std::int64_t start_idx;
 
-    std::string result;
+    // npos == all chars until end of the string
+    std::int64_t substring_length = std::string::npos;
 
-    if (val.get_dtype() == DTYPE_TIME) {
-        // Convert the int64 to a milliseconds duration timestamp
-        std::chrono::milliseconds timestamp(val.to_int64());
+    for (auto i = 0; i < num_params; ++i) {
+        const t_generic_type& gt = parameters[i];
 
-        // Convert the timestamp to a `sys_time` (alias for `time_point`)
-        date::sys_time<std::chrono::milliseconds> ts(timestamp);
+        if (gt.type == t_generic_type::e_scalar) {
+            t_scalar_view temp_scalar_view(gt);
+            t_tscalar temp_scalar = temp_scalar_view();
 
-        // Use localtime so that the hour of day is consistent with all
-        // output datetimes, which are in local time
-        std::time_t temp = std::chrono::system_clock::to_time_t(ts);
-        std::tm* t = std::localtime(&temp);
+            // type check - first param must be string, 2nd and 3rd param
+            // must be numeric, all must be valid
+            t_dtype dtype = temp_scalar.get_dtype();
 
-        // Get the month from the resulting `std::tm`
-        auto month = t->tm_mon;
+            if ((i == 0 && dtype != DTYPE_STR)
+                || (i != 0 && !temp_scalar.is_numeric())
+                || temp_scalar.m_status == STATUS_CLEAR) {
+                rval.m_status = STATUS_CLEAR;
+                return rval;
+            }
 
-        // Get the month string and write into the output column
-        result = months_of_year[month];
-    } else {
-        t_date date_val = val.get<t_date>();
+            // Only check for types - bad indices will always return null
+            // but be valid expressions.
+            if (m_is_type_validator || !temp_scalar.is_valid()) {
+                return rval;
+            }
 
-        // `t_date.month()` is [0-11]
-        std::int32_t month = date_val.month();
-        result = months_of_year[month];
+            // Passed type checking, assign values
+            if (i == 0) {
+                search_string = temp_scalar.to_string();
+            } else if (i == 1) {
+                start_idx = temp_scalar.to_double();
+            } else if (i == 2) {
+                substring_length = temp_scalar.to_double();
+            }
+        } else {
+            // called with invalid params - exit
+            rval.m_status = STATUS_CLEAR;
+            return rval;
+        }
     }
 
-    // Intern the string pointer so it does not fall out of reference and
-    // cause a memory error.
-    rval.set(m_expression_vocab.intern(result));
-    return rval;
-}
+    // done type checking
+    if (m_is_type_validator) {
+        return rval;
+    }
 
-tsl::hopscotch_map<char, t_date_bucket_unit> bucket::UNIT_MAP = {
-    {'s', t_date_bucket_unit::SECONDS},
-    {'m', t_date_bucket_unit::MINUTES},
-    {'h', t_date_bucket_unit::HOURS},
-    {'D', t_date_bucket_unit::DAYS},
-    {'W', t_date_bucket_unit::WEEKS},
-    {'M', t_date_bucket_unit::MONTHS},
-    {'Y', t_date_bucket_unit::YEARS}
-};
+    std::size_t length = search_string.length();
 
-bucket::bucket() : exprtk::igeneric_function<t_tscalar>("T?") {}
+    // Value check: strings cannot be 0 length, indices must be valid
+    if (length == 0 || start_idx < 0
+        || (num_params == 3 && substring_length < 0) || start_idx >= length
+        || (substring_length != std::string::npos
+            && start_idx + substring_length > length)) {
+        return rval;
+    }
 
-bucket::~bucket() = default;
+    rval.set(m_expression_vocab.intern(
+        search_string.substr(start_idx, substring_length)
+    ));
 
-t_tscalar
-bucket::operator()(t_parameter_list parameters) {
-    t_tscalar val;
-    t_tscalar unit;
-    t_tscalar rval;
-    rval.clear();
+    return rval;
+}
 
-    // Parameters are already validated in the constructor by Exprtk
-    t_generic_type& gt_val = parameters[0];  // always a scalar
-    t_generic_type& gt_unit = parameters[1]; // scalar or string
+replace::replace(
+    t_expression_vocab& expression_vocab,
+    t_regex_mapping& regex_mapping,
+    bool is_type_validator
+) :
+    exprtk::igeneric_function<t_tscalar>("TS?"),
+    m_expression_vocab(expression_vocab),
+    m_regex_mapping(regex_mapping),
+    m_is_type_validator(is_type_validator) {}
 
-    // Convert value to scalar
-    t_scalar_view temp_val(gt_val);
-    val.set(temp_val());
+replace::~replace() = default;
 
-    if (val.is_numeric()) {
-        rval.m_type = DTYPE_FLOAT64;
+t_tscalar
+replace::operator()(t_parameter_list parameters) {
+    t_tscalar rval;
+    rval.clear();
+    rval.m_type = DTYPE_STR;
 
-        // Bucket by numeric value
-        t_scalar_view temp_unit(gt_unit);
-        unit.set(temp_unit());
+    // the string to be replaced
+    t_scalar_view string_scalar_view(parameters[0]);
+    t_tscalar string_scalar = string_scalar_view();
 
-        // type-check
-        if (!unit.is_numeric() || val.m_status == STATUS_CLEAR
-            || unit.m_status == STATUS_CLEAR) {
-            rval.m_status = STATUS_CLEAR;
-            return rval;
-        }
+    // the replace pattern
+    t_string_view pattern_view(parameters[1]);
+    std::string match_pattern =
+        std::string(pattern_view.begin(), pattern_view.end());
 
-        if (!val.is_valid() || !unit.is_valid()) {
+    // replacer can be a string literal, for the string '' as intern does
+    // not pick up on empty strings but we need to be able to replace
+    // with empty string. Thus, type-check replacer before continuing.
+    const t_generic_type& gt(parameters[2]);
+    t_tscalar replacer_scalar;
+
+    if (gt.type == t_generic_type::e_scalar) {
+        t_scalar_view replacer_view(gt);
+        replacer_scalar = replacer_view();
+    } else if (gt.type == t_generic_type::e_string) {
+        t_string_view replacer_view(gt);
+        std::string replacer_str =
+            std::string(replacer_view.begin(), replacer_view.end());
+
+        // only the empty string should be passed in as a string literal,
+        // all other strings must be interned first.
+        if (!replacer_str.empty()) {
+            rval.m_status = STATUS_CLEAR;
             return rval;
         }
 
-        rval.set(floor(val.to_double() / unit.to_double()) * unit.to_double());
+        // use the empty string from vocab
+        replacer_scalar.set(m_expression_vocab.get_empty_string());
+    } else {
+        rval.m_status = STATUS_CLEAR;
+        return rval;
+    }
 
+    if (string_scalar.m_type != DTYPE_STR || replacer_scalar.m_type != DTYPE_STR
+        || match_pattern.empty()) {
+        rval.m_status = STATUS_CLEAR;
         return rval;
     }
 
-    // Must be a datetime - second parameter is a string
-    t_string_view temp_string(gt_unit);
-    std::string unit_str = std::string(temp_string.begin(), temp_string.end());
-    char temp_unit = 0;
-    auto len = unit_str.size();
-    unsigned long multiplicity;
-    t_date_bucket_unit date_unit;
-    if (len == 0) {
-        // Does not type-check!
+    // typecheck the regex
+    RE2* compiled_pattern = m_regex_mapping.intern(match_pattern);
+
+    if (compiled_pattern == nullptr) {
         rval.m_status = STATUS_CLEAR;
         return rval;
     }
-    if (len == 1) {
-        // No multiplicity explicity given, defaults to 1.
-        multiplicity = 1;
-        temp_unit = unit_str.at(0);
-    } else {
-        temp_unit = unit_str.at(len - 1);
-        std::string mult = unit_str.substr(0, len - 1);
-        if (!std::all_of(mult.begin(), mult.end(), ::isdigit)) {
-            // multiplicity is not a non-negative integer
+
+    // done with type_checking
+    if (m_is_type_validator) {
+        return rval;
+    }
+
+    // make a copy of search_str, as replace() will mutate it and we
+    // don't want to mutate the string in the vocab
+    std::string search_string = string_scalar.to_string();
+
+    if (search_string.empty()) {
+        return rval;
+    }
+
+    // but we can take a reference to the replacer
+    const std::string& replacer_string = replacer_scalar.to_string();
+    re2::StringPiece replacer(replacer_string);
+
+    bool replaced =
+        RE2::Replace(&(search_string), *(compiled_pattern), replacer);
+
+    if (!replaced) {
+        // Return the original result if the replacement didn't happen
+        return string_scalar;
+    }
+
+    // Or the string with the replacement set
+    rval.set(m_expression_vocab.intern(search_string));
+
+    return rval;
+}
+
+replace_all::replace_all(
+    t_expression_vocab& expression_vocab,
+    t_regex_mapping& regex_mapping,
+    bool is_type_validator
+) :
+    exprtk::igeneric_function<t_tscalar>("TS?"),
+    m_expression_vocab(expression_vocab),
+    m_regex_mapping(regex_mapping),
+    m_is_type_validator(is_type_validator) {}
+
+replace_all::~replace_all() = default;
+
+t_tscalar
+replace_all::operator()(t_parameter_list parameters) {
+    t_tscalar rval;
+    rval.clear();
+    rval.m_type = DTYPE_STR;
+
+    // the string to be replaced
+    t_scalar_view string_scalar_view(parameters[0]);
+    t_tscalar string_scalar = string_scalar_view();
+
+    // the replace pattern
+    t_string_view pattern_view(parameters[1]);
+    std::string match_pattern =
+        std::string(pattern_view.begin(), pattern_view.end());
+
+    // replacer can be a string literal, for the string '' as intern does
+    // not pick up on empty strings but we need to be able to replace
+    // with empty string. Thus, type-check replacer before continuing.
+    const t_generic_type& gt(parameters[2]);
+    t_tscalar replacer_scalar;
+
+    if (gt.type == t_generic_type::e_scalar) {
+        t_scalar_view replacer_view(gt);
+        replacer_scalar = replacer_view();
+    } else if (gt.type == t_generic_type::e_string) {
+        t_string_view replacer_view(gt);
+        std::string replacer_str =
+            std::string(replacer_view.begin(), replacer_view.end());
+
+        // only the empty string should be passed in as a string literal,
+        // all other strings must be interned first.
+        if (!replacer_str.empty()) {
             rval.m_status = STATUS_CLEAR;
             return rval;
         }
-        multiplicity = std::stoul(mult);
-    }
-    std::string allowed_units = "smhDWMY";
-    if (allowed_units.find(temp_unit) == std::string::npos) {
-        std::cerr << "[bucket] unknown unit in bucket - the valid units "
-                     "are 's', 'm', 'h', 'D', 'W', 'M', and 'Y'."
-                  << '\n';
-        rval.m_type = DTYPE_TIME;
+
+        // use the empty string from vocab
+        replacer_scalar.set(m_expression_vocab.get_empty_string());
+    } else {
         rval.m_status = STATUS_CLEAR;
         return rval;
     }
-    date_unit = bucket::UNIT_MAP[temp_unit];
 
-    // type-check multiplicity
-    switch (date_unit) {
-        case t_date_bucket_unit::SECONDS:
-            if (multiplicity != 1 && multiplicity != 5 && multiplicity != 10
-                && multiplicity != 15 && multiplicity != 20
-                && multiplicity != 30) {
-                rval.m_status = STATUS_CLEAR;
-                return rval;
-            }
-            break;
-        case t_date_bucket_unit::MINUTES:
-            if (multiplicity != 1 && multiplicity != 5 && multiplicity != 10
-                && multiplicity != 15 && multiplicity != 20
-                && multiplicity != 30) {
-                rval.m_status = STATUS_CLEAR;
-                return rval;
-            }
-            break;
-        case t_date_bucket_unit::HOURS:
-            if (multiplicity != 1 && multiplicity != 2 && multiplicity != 3
-                && multiplicity != 4 && multiplicity != 6
-                && multiplicity != 12) {
-                rval.m_status = STATUS_CLEAR;
-                return rval;
-            }
-            break;
-        case t_date_bucket_unit::DAYS:
-            // TODO: day multiplicity.
-            if (multiplicity != 1) {
-                rval.m_status = STATUS_CLEAR;
-                return rval;
-            }
-            break;
-        case t_date_bucket_unit::WEEKS:
-            // TODO: week multiplicity
-            if (multiplicity != 1) {
-                rval.m_status = STATUS_CLEAR;
-                return rval;
-            }
-            break;
-        case t_date_bucket_unit::MONTHS:
-            if (multiplicity != 1 && multiplicity != 2 && multiplicity != 3
-                && multiplicity != 4 && multiplicity != 6) {
-                rval.m_status = STATUS_CLEAR;
-                return rval;
-            }
-            break;
-        case t_date_bucket_unit::YEARS:
-            break;
-        default:
-            PSP_COMPLAIN_AND_ABORT("[bucket] invalid date bucket unit!");
-            break;
+    if (string_scalar.m_type != DTYPE_STR || replacer_scalar.m_type != DTYPE_STR
+        || match_pattern.empty()) {
+        rval.m_status = STATUS_CLEAR;
+        return rval;
     }
-    t_dtype val_dtype = val.get_dtype();
-    // type-check
-    if (val_dtype != DTYPE_DATE && val_dtype != DTYPE_TIME) {
+
+    // typecheck the regex
+    RE2* compiled_pattern = m_regex_mapping.intern(match_pattern);
+
+    if (compiled_pattern == nullptr) {
         rval.m_status = STATUS_CLEAR;
+        return rval;
     }
 
-    // Depending on unit, datetime columns can result in a date column or a
-    // datetime column.
-    if (val_dtype == DTYPE_TIME) {
-        switch (date_unit) {
-            case t_date_bucket_unit::SECONDS:
-            case t_date_bucket_unit::MINUTES:
-            case t_date_bucket_unit::HOURS: {
-                rval.m_type = DTYPE_TIME;
-            } break;
-            case t_date_bucket_unit::DAYS:
+    // done with type_checking
+    if (m_is_type_validator) {
+        return rval;
+    }
+
+    // make a copy of search_str, as replace() will mutate it and we
+    // don't want to mutate the string in the vocab
+    std::string search_string = string_scalar.to_string();
+
+    if (search_string.empty()) {
+        return rval;
+    }
+
+    // but we can take a reference to the replacer
+    const std::string& replacer_string = replacer_scalar.to_string();
+    re2::StringPiece replacer(replacer_string);
+
+    std::size_t replaced =
+        RE2::GlobalReplace(&(search_string), *(compiled_pattern), replacer);
+
+    if (replaced == 0) {
+        // Return the original result if the replacement didn't happen
+        return string_scalar;
+    }
+
+    // Or the string with the replacement set
+    rval.set(m_expression_vocab.intern(search_string));
+
+    return rval;
+}
+
+regex_extract::regex_extract(
+    t_expression_vocab& expression_vocab,
+    t_regex_mapping& regex_mapping,
+    bool is_type_validator
+) :
+    exprtk::igeneric_function<t_tscalar>("TST"),
+    m_expression_vocab(expression_vocab),
+    m_regex_mapping(regex_mapping),
+    m_is_type_validator(is_type_validator) {}
+
+regex_extract::~regex_extract() = default;
+
+t_tscalar
+regex_extract::operator()(t_parameter_list parameters) {
+    t_tscalar rval;
+    rval.clear();
+    rval.m_type = DTYPE_STR;
+
+    t_scalar_view str_view(parameters[0]);
+    t_string_view pattern_view(parameters[1]);
+    t_scalar_view group_view(parameters[2]);
+
+    t_tscalar str = str_view();
+    t_tscalar group = group_view();
+    std::string match_pattern =
+        std::string(pattern_view.begin(), pattern_view.end());
+
+    // Type-check: only operate on strings with a numeric group, and pattern
+    // must be > size 0
+    if (str.get_dtype() != DTYPE_STR || str.m_status == STATUS_CLEAR
+        || !group.is_numeric() || group.m_status == STATUS_CLEAR
+        || match_pattern.empty()) {
+        rval.m_status = STATUS_CLEAR;
+        return rval;
+    }
+
+    RE2* compiled_pattern = m_regex_mapping.intern(match_pattern);
+
+    if (compiled_pattern == nullptr) {
+        rval.m_status = STATUS_CLEAR;
+        return rval;
+    }
+
+    if (!group.is_valid()) {
+        return rval;
+    }
+
+    double group_idx = group.to_double();
+    if (group_idx < 0
+        || group_idx > compiled_pattern->NumberOfCapturingGroups()) {
+        rval.m_status = STATUS_CLEAR;
+        return rval;
+    }
+
+    if (!str.is_valid() || m_is_type_validator) {
+        return rval;
+    }
+
+    // Submatch 0 is the whole match, so read through the requested group.
+    auto ngroups = static_cast<std::size_t>(group_idx) + 1;
+    std::vector<re2::StringPiece> submatches(ngroups);
+    const std::string& match_string = str.to_string();
+    bool found = compiled_pattern->Match(
+        match_string,
+        0,
+        match_string.size(),
+        RE2::UNANCHORED,
+        submatches.data(),
+        static_cast<int>(ngroups)
+    );
+
+    // Return null if no match, or if the group did not participate or is
+    // empty - don't allow empty strings back out.
+    if (!found || submatches.back().empty()) {
+        return rval;
+    }
+
+    rval.set(m_expression_vocab.intern(submatches.back().ToString()));
+
+    return rval;
+}
+
+std::tm
+to_calendar_tm(std::int64_t timestamp, const t_tz* tz) {
+    if (tz != nullptr) {
+        std::int64_t local = tz->to_local(timestamp);
+        std::int64_t secs =
+            local / 1000 - static_cast<std::int64_t>(local % 1000 < 0);
+        std::tm t{};
+        t_time().gmtime(t, secs, 0);
+        return t;
+    }
+
+    // Convert the int64 to a milliseconds duration timestamp
+    std::chrono::milliseconds ms_timestamp(timestamp);
+
+    // Convert the timestamp to a `sys_time` (alias for `time_point`)
+    date::sys_time<std::chrono::milliseconds> ts(ms_timestamp);
+
+    // Use localtime so that the calendar fields are consistent with all
+    // output datetimes, which are in local time
+    std::time_t temp = std::chrono::system_clock::to_time_t(ts);
+    return *std::localtime(&temp);
+}
+
+hour_of_day::hour_of_day(const t_tz* timezone) :
+    exprtk::igeneric_function<t_tscalar>("T"),
+    m_timezone(timezone) {}
+
+hour_of_day::~hour_of_day() = default;
+
+t_tscalar
+hour_of_day::operator()(t_parameter_list parameters) {
+    t_tscalar val;
+    t_tscalar rval;
+    rval.clear();
+
+    // return a float to be compatible with the most comparisons - even
+    // though a uint would make most sense here, if this column returned a
+    // uint comparisons to numeric literals and other numeric columns would
+    // always be false, as comparisons are False across types.
+    rval.m_type = DTYPE_FLOAT64;
+
+    t_generic_type& gt = parameters[0];
+    t_scalar_view temp(gt);
+    t_tscalar temp_scalar = temp();
+
+    t_dtype dtype = temp_scalar.get_dtype();
+    bool valid_dtype = dtype == DTYPE_DATE || dtype == DTYPE_TIME;
+
+    if (!valid_dtype || temp_scalar.m_status == STATUS_CLEAR) {
+        rval.m_status = STATUS_CLEAR;
+    }
+
+    if (!temp_scalar.is_valid()) {
+        return rval;
+    }
+
+    val.set(temp_scalar);
+
+    if (val.get_dtype() == DTYPE_TIME) {
+        // Break the timestamp into calendar fields in the view's time
+        // zone, or in local time if the view has none.
+        std::tm t = to_calendar_tm(val.to_int64(), m_timezone);
+
+        // Get the hour from the resulting `std::tm`
+        rval.set(static_cast<double>(t.tm_hour));
+    } else {
+        // Hour of day for date column is always 0
+        rval.set(0.0);
+    }
+
+    return rval;
+}
+
+const std::string days_of_week[7] = {
+    "1 Sunday",
+    "2 Monday",
+    "3 Tuesday",
+    "4 Wednesday",
+    "5 Thursday",
+    "6 Friday",
+    "7 Saturday"
+};
+
+const std::string months_of_year[12] = {
+    "01 January",
+    "02 February",
+    "03 March",
+    "04 April",
+    "05 May",
+    "06 June",
+    "07 July",
+    "08 August",
+    "09 September",
+    "10 October",
+    "11 November",
+    "12 December"
+};
+
+day_of_week::day_of_week(
+    t_expression_vocab& expression_vocab,
+    bool is_type_validator,
+    const t_tz* timezone
+) :
+    exprtk::igeneric_function<t_tscalar>("T"),
+    m_expression_vocab(expression_vocab),
+    m_is_type_validator(is_type_validator),
+    m_timezone(timezone) {
+    t_tscalar sentinel;
+    sentinel.clear();
+    sentinel.set(m_expression_vocab.get_empty_string());
+    sentinel.m_status = STATUS_INVALID;
+
+    m_sentinel = sentinel;
+}
+
+day_of_week::~day_of_week() = default;
+
+t_tscalar
+day_of_week::operator()(t_parameter_list parameters) {
+    t_tscalar val;
+    t_tscalar rval;
+    rval.clear();
+    rval.m_type = DTYPE_STR;
+
+    t_generic_type& gt = parameters[0];
+    t_scalar_view temp(gt);
+    t_tscalar temp_scalar = temp();
+    t_dtype dtype = temp_scalar.get_dtype();
+    bool valid_dtype = dtype == DTYPE_DATE || dtype == DTYPE_TIME;
+
+    if (!valid_dtype || temp_scalar.m_status == STATUS_CLEAR) {
+        rval.m_status = STATUS_CLEAR;
+    }
+
+    if (!temp_scalar.is_valid()) {
+        return rval;
+    }
+
+    val.set(temp_scalar);
+
+    if (m_is_type_validator) {
+        return m_sentinel;
+    }
+
+    std::string result;
+
+    if (val.get_dtype() == DTYPE_TIME) {
+        // Break the timestamp into calendar fields in the view's time
+        // zone, or in local time if the view has none.
+        std::tm t = to_calendar_tm(val.to_int64(), m_timezone);
+
+        // Get the weekday from the resulting `std::tm`
+        result = days_of_week[t.tm_wday];
+    } else {
+        // Retrieve the `t_date` struct from the scalar
+        t_date date_val = val.get<t_date>();
+
+        // Construct a `date::year_month_day` value
+        date::year year{date_val.year()};
+
+        // date::month is [1-12], whereas `t_date.month()` is [0-11]
+        date::month month{static_cast<std::uint32_t>(date_val.month()) + 1};
+        date::day day{static_cast<std::uint32_t>(date_val.day())};
+        date::year_month_day ymd(year, month, day);
+
+        // Construct a `date::year_month_weekday` from `date::sys_days`
+        // since epoch
+        auto weekday =
+            date::year_month_weekday(ymd).weekday_indexed().weekday();
+
+        result = days_of_week[(weekday - date::Sunday).count()];
+    }
+
+    rval.set(m_expression_vocab.intern(result));
+    return rval;
+}
+
+month_of_year::month_of_year(
+    t_expression_vocab& expression_vocab,
+    bool is_type_validator,
+    const t_tz* timezone
+) :
+    exprtk::igeneric_function<t_tscalar>("T"),
+    m_expression_vocab(expression_vocab),
+    m_is_type_validator(is_type_validator),
+    m_timezone(timezone) {
+    t_tscalar sentinel;
+    sentinel.clear();
+    sentinel.set(m_expression_vocab.get_empty_string());
+    sentinel.m_status = STATUS_INVALID;
+
+    m_sentinel = sentinel;
+}
+
+month_of_year::~month_of_year() = default;
+
+t_tscalar
+month_of_year::operator()(t_parameter_list parameters) {
+    t_tscalar val;
+    t_tscalar rval;
+    rval.clear();
+    rval.m_type = DTYPE_STR;
+
+    t_generic_type& gt = parameters[0];
+    t_scalar_view temp(gt);
+    t_tscalar temp_scalar = temp();
+
+    t_dtype dtype = temp_scalar.get_dtype();
+    bool valid_dtype = dtype == DTYPE_DATE || dtype == DTYPE_TIME;
+
+    if (!valid_dtype || temp_scalar.m_status == STATUS_CLEAR) {
+        rval.m_status = STATUS_CLEAR;
+    }
+
+    if (!temp_scalar.is_valid()) {
+        return rval;
+    }
+
+    val.set(temp_scalar);
+
+    if (m_is_type_validator) {
+        return m_sentinel;
+    }
+
+    std::string result;
+
+    if (val.get_dtype() == DTYPE_TIME) {
+        // Break the timestamp into calendar fields in the view's time
+        // zone, or in local time if the view has none.
+        std::tm t = to_calendar_tm(val.to_int64(), m_timezone);
+
+        // Get the month from the resulting `std::tm`
+        auto month = t.tm_mon;
+
+        // Get the month string and write into the output column
+        result = months_of_year[month];
+    } else {
+        t_date date_val = val.get<t_date>();
+
+        // `t_date.month()` is [0-11]
+        std::int32_t month = date_val.month();
+        result = months_of_year[month];
+    }
+
+    // Intern the string pointer so it does not fall out of reference and
+    // cause a memory error.
+    rval.set(m_expression_vocab.intern(result));
+    return rval;
+}
+
+tsl::hopscotch_map<char, t_date_bucket_unit> bucket::UNIT_MAP = {
+    {'s', t_date_bucket_unit::SECONDS},
+    {'m', t_date_bucket_unit::MINUTES},
+    {'h', t_date_bucket_unit::HOURS},
+    {'D', t_date_bucket_unit::DAYS},
+    {'W', t_date_bucket_unit::WEEKS},
+    {'M', t_date_bucket_unit::MONTHS},
+    {'Y', t_date_bucket_unit::YEARS}
+};
+
+bucket::bucket(const t_tz* timezone) :
+    exprtk::igeneric_function<t_tscalar>("T?"),
+    m_timezone(timezone) {}
+
+bucket::~bucket() = default;
+
+t_tscalar
+bucket::operator()(t_parameter_list parameters) {
+    t_tscalar val;
+    t_tscalar unit;
+    t_tscalar rval;
+    rval.clear();
+
+    // Parameters are already validated in the constructor by Exprtk
+    t_generic_type& gt_val = parameters[0];  // always a scalar
+    t_generic_type& gt_unit = parameters[1]; // scalar or string
+
+    // Convert value to scalar
+    t_scalar_view temp_val(gt_val);
+    val.set(temp_val());
+
+    if (val.is_numeric()) {
+        rval.m_type = DTYPE_FLOAT64;
+
+        // Bucket by numeric value
+        t_scalar_view temp_unit(gt_unit);
+        unit.set(temp_unit());
+
+        // type-check
+        if (!unit.is_numeric() || val.m_status == STATUS_CLEAR
+            || unit.m_status == STATUS_CLEAR) {
+            rval.m_status = STATUS_CLEAR;
+            return rval;
+        }
+
+        if (!val.is_valid() || !unit.is_valid()) {
+            return rval;
+        }
+
+        rval.set(floor(val.to_double() / unit.to_double()) * unit.to_double());
+
+        return rval;
+    }
+
+    // Must be a datetime - second parameter is a string
+    t_string_view temp_string(gt_unit);
+    std::string unit_str = std::string(temp_string.begin(), temp_string.end());
+    char temp_unit = 0;
+    auto len = unit_str.size();
+    unsigned long multiplicity;
+    t_date_bucket_unit date_unit;
+    if (len == 0) {
+        // Does not type-check!
+        rval.m_status = STATUS_CLEAR;
+        return rval;
+    }
+    if (len == 1) {
+        // No multiplicity explicity given, defaults to 1.
+        multiplicity = 1;
+        temp_unit = unit_str.at(0);
+    } else {
+        temp_unit = unit_str.at(len - 1);
+        std::string mult = unit_str.substr(0, len - 1);
+        if (!std::all_of(mult.begin(), mult.end(), ::isdigit)) {
+            // multiplicity is not a non-negative integer
+            rval.m_status = STATUS_CLEAR;
+            return rval;
+        }
+        multiplicity = std::stoul(mult);
+    }
+    std::string allowed_units = "smhDWMY";
+    if (allowed_units.find(temp_unit) == std::string::npos) {
+        std::cerr << "[bucket] unknown unit in bucket - the valid units "
+                     "are 's', 'm', 'h', 'D', 'W', 'M', and 'Y'."
+                  << '\n';
+        rval.m_type = DTYPE_TIME;
+        rval.m_status = STATUS_CLEAR;
+        return rval;
+    }
+    date_unit = bucket::UNIT_MAP[temp_unit];
+
+    // type-check multiplicity
+    switch (date_unit) {
+        case t_date_bucket_unit::SECONDS:
+            if (multiplicity != 1 && multiplicity != 5 && multiplicity != 10
+                && multiplicity != 15 && multiplicity != 20
+                && multiplicity != 30) {
+                rval.m_status = STATUS_CLEAR;
+                return rval;
+            }
+            break;
+        case t_date_bucket_unit::MINUTES:
+            if (multiplicity != 1 && multiplicity != 5 && multiplicity != 10
+                && multiplicity != 15 && multiplicity != 20
+                && multiplicity != 30) {
+                rval.m_status = STATUS_CLEAR;
+                return rval;
+            }
+            break;
+        case t_date_bucket_unit::HOURS:
+            if (multiplicity != 1 && multiplicity != 2 && multiplicity != 3
+                && multiplicity != 4 && multiplicity != 6
+                && multiplicity != 12) {
+                rval.m_status = STATUS_CLEAR;
+                return rval;
+            }
+            break;
+        case t_date_bucket_unit::DAYS:
+            // TODO: day multiplicity.
+            if (multiplicity != 1) {
+                rval.m_status = STATUS_CLEAR;
+                return rval;
+            }
+            break;
+        case t_date_bucket_unit::WEEKS:
+            // TODO: week multiplicity
+            if (multiplicity != 1) {
+                rval.m_status = STATUS_CLEAR;
+                return rval;
+            }
+            break;
+        case t_date_bucket_unit::MONTHS:
+            if (multiplicity != 1 && multiplicity != 2 && multiplicity != 3
+                && multiplicity != 4 && multiplicity != 6) {
+                rval.m_status = STATUS_CLEAR;
+                return rval;
+            }
+            break;
+        case t_date_bucket_unit::YEARS:
+            break;
+        default:
+            PSP_COMPLAIN_AND_ABORT("[bucket] invalid date bucket unit!");
+            break;
+    }
+    t_dtype val_dtype = val.get_dtype();
+    // type-check
+    if (val_dtype != DTYPE_DATE && val_dtype != DTYPE_TIME) {
+        rval.m_status = STATUS_CLEAR;
+    }
+
+    // Depending on unit, datetime columns can result in a date column or a
+    // datetime column.
+    if (val_dtype == DTYPE_TIME) {
+        switch (date_unit) {
+            case t_date_bucket_unit::SECONDS:
+            case t_date_bucket_unit::MINUTES:
+            case t_date_bucket_unit::HOURS: {
+                rval.m_type = DTYPE_TIME;
+            } break;
+            case t_date_bucket_unit::DAYS:
             case t_date_bucket_unit::WEEKS:
             case t_date_bucket_unit::MONTHS:
             case t_date_bucket_unit::YEARS: {
@@ -1320,603 +2083,1254 @@ bucket::operator()(t_parameter_list parameters) {
                 PSP_COMPLAIN_AND_ABORT("[bucket] invalid date bucket unit!");
             } break;
         }
-    } else {
-        // but date columns will always output date columns
-        rval.m_type = DTYPE_DATE;
+    } else {
+        // but date columns will always output date columns
+        rval.m_type = DTYPE_DATE;
+    }
+
+    if (!val.is_valid()) {
+        return rval;
+    }
+
+    switch (date_unit) {
+        case t_date_bucket_unit::SECONDS: {
+            _second_bucket(val, rval, multiplicity);
+        } break;
+        case t_date_bucket_unit::MINUTES: {
+            _minute_bucket(val, rval, multiplicity);
+        } break;
+        case t_date_bucket_unit::HOURS: {
+            _hour_bucket(val, rval, multiplicity, m_timezone);
+        } break;
+        case t_date_bucket_unit::DAYS: {
+            _day_bucket(val, rval, m_timezone);
+        } break;
+        case t_date_bucket_unit::WEEKS: {
+            _week_bucket(val, rval, m_timezone);
+        } break;
+        case t_date_bucket_unit::MONTHS: {
+            _month_bucket(val, rval, multiplicity, m_timezone);
+        } break;
+        case t_date_bucket_unit::YEARS: {
+            _year_bucket(val, rval, multiplicity, m_timezone);
+        } break;
+        default: {
+            PSP_COMPLAIN_AND_ABORT("[bucket] invalid date bucket unit!");
+        } break;
+    }
+
+    return rval;
+}
+
+/// @brief Buckets a given time into a date at multiplicity*T resolution.
+/// @tparam T The std::chrono::duration to bucket by.
+/// @param val The input date.
+/// @param multiplicity How many Ts to put in a bucket.
+/// @return The bucketed time.
+template <typename T>
+t_time
+bucket_time(t_tscalar& val, t_uindex multiplicity) {
+    std::chrono::milliseconds millis(val.to_int64());
+    auto raw = std::chrono::duration_cast<T>(millis).count();
+    int64_t bucket =
+        floor(static_cast<double>(raw) / multiplicity) * multiplicity;
+    T refined(bucket);
+    return t_time(
+        std::chrono::duration_cast<std::chrono::milliseconds>(refined).count()
+    );
+}
+
+void
+_second_bucket(t_tscalar& val, t_tscalar& rval, t_uindex multiplicity) {
+    switch (val.get_dtype()) {
+        case DTYPE_TIME: {
+            rval.set(bucket_time<std::chrono::seconds>(val, multiplicity));
+        } break;
+        default: {
+            // echo the original value back into the column.
+            rval.set(val);
+        }
+    }
+}
+
+void
+_minute_bucket(t_tscalar& val, t_tscalar& rval, t_uindex multiplicity) {
+    switch (val.get_dtype()) {
+        case DTYPE_TIME: {
+            rval.set(bucket_time<std::chrono::minutes>(val, multiplicity));
+        } break;
+        default: {
+            rval.set(val);
+        } break;
+    }
+}
+
+void
+_hour_bucket(
+    t_tscalar& val,
+    t_tscalar& rval,
+    t_uindex multiplicity,
+    const t_tz* tz
+) {
+    switch (val.get_dtype()) {
+        case DTYPE_TIME: {
+            if (tz == nullptr) {
+                rval.set(bucket_time<std::chrono::hours>(val, multiplicity));
+                break;
+            }
+
+            // Bucket on the wall clock, so that buckets start on the hour
+            // in zones with fractional offsets, then map back to an instant.
+            t_tscalar local;
+            local.set(t_time(tz->to_local(val.to_int64())));
+            t_time bucketed =
+                bucket_time<std::chrono::hours>(local, multiplicity);
+            rval.set(t_time(tz->to_utc(bucketed.raw_value())));
+        } break;
+        default: {
+            rval.set(val);
+        } break;
+    }
+}
+
+void
+_day_bucket(t_tscalar& val, t_tscalar& rval, const t_tz* tz) {
+    switch (val.get_dtype()) {
+        case DTYPE_TIME: {
+            // Break the timestamp into calendar fields in the view's time
+            // zone, or in local time if the view has none.
+            std::tm t = to_calendar_tm(val.to_int64(), tz);
+
+            // Get the year and create a new `t_date`
+            auto year = static_cast<std::int32_t>(t.tm_year + 1900);
+
+            // Month in `t_date` is [0-11]
+            std::int32_t month = static_cast<std::uint32_t>(t.tm_mon);
+            auto day = static_cast<std::uint32_t>(t.tm_mday);
+
+            rval.set(t_date(year, month, day));
+        } break;
+        case DTYPE_DATE:
+        default: {
+            // echo the original value back into the column.
+            rval.set(val);
+        } break;
+    }
+}
+
+void
+_week_bucket(t_tscalar& val, t_tscalar& rval, const t_tz* tz) {
+    switch (val.get_dtype()) {
+        case DTYPE_DATE: {
+            // Retrieve the `t_date` struct from the scalar
+            t_date date_val = val.get<t_date>();
+
+            // Construct a `date::year_month_day` value
+            date::year year{date_val.year()};
+
+            // date::month is [1-12], whereas `t_date.month()` is [0-11]
+            date::month month{static_cast<std::uint32_t>(date_val.month()) + 1};
+            date::day day{static_cast<std::uint32_t>(date_val.day())};
+            date::year_month_day ymd(year, month, day);
+
+            // Convert to a `sys_days` representing no. of days since epoch
+            date::sys_days days_since_epoch = ymd;
+
+            // Subtract Sunday from the ymd to get the beginning of the last
+            // day
+            ymd = days_since_epoch
+                - (date::weekday{days_since_epoch} - date::Monday);
+
+            // Get the day of month and day of the week
+            std::int32_t year_int = static_cast<std::int32_t>(ymd.year());
+
+            // date::month is [1-12], whereas `t_date.month()` is [0-11]
+            std::uint32_t month_int =
+                static_cast<std::uint32_t>(ymd.month()) - 1;
+            std::uint32_t day_int = static_cast<std::uint32_t>(ymd.day());
+
+            // Return the new `t_date`
+            t_date new_date = t_date(year_int, month_int, day_int);
+            rval.set(new_date);
+        } break;
+        case DTYPE_TIME: {
+            // Break the timestamp into calendar fields in the view's time
+            // zone, or in local time if the view has none.
+            std::tm t = to_calendar_tm(val.to_int64(), tz);
+
+            // Take the ymd from the `tm`, now in wall-clock time, and create a
+            // date::year_month_day.
+            date::year year{1900 + t.tm_year};
+
+            // date::month is [1-12], whereas `std::tm::tm_mon` is [0-11]
+            date::month month{static_cast<std::uint32_t>(t.tm_mon) + 1};
+            date::day day{static_cast<std::uint32_t>(t.tm_mday)};
+            date::year_month_day ymd(year, month, day);
+
+            // Convert to a `sys_days` representing no. of days since epoch
+            date::sys_days days_since_epoch = ymd;
+
+            // Subtract Sunday from the ymd to get the beginning of the last
+            // day
+            ymd = days_since_epoch
+                - (date::weekday{days_since_epoch} - date::Monday);
+
+            // Get the day of month and day of the week
+            std::int32_t year_int = static_cast<std::int32_t>(ymd.year());
+
+            // date::month is [1-12], whereas `t_date.month()` is [0-11]
+            std::uint32_t month_int =
+                static_cast<std::uint32_t>(ymd.month()) - 1;
+            std::uint32_t day_int = static_cast<std::uint32_t>(ymd.day());
+
+            // Return the new `t_date`
+            t_date new_date = t_date(year_int, month_int, day_int);
+            rval.set(new_date);
+        } break;
+        default:
+            break;
+    }
+}
+
+void
+_month_bucket(
+    t_tscalar& val,
+    t_tscalar& rval,
+    t_uindex multiplicity,
+    const t_tz* tz
+) {
+    switch (val.get_dtype()) {
+        case DTYPE_DATE: {
+            t_date date_val = val.get<t_date>();
+            auto in_month = date_val.month();
+            int8_t out_month =
+                floor(static_cast<double>(in_month) / multiplicity)
+                * multiplicity;
+            rval.set(t_date(date_val.year(), out_month, 1));
+        } break;
+        case DTYPE_TIME: {
+            // Break the timestamp into calendar fields in the view's time
+            // zone, or in local time if the view has none.
+            std::tm t = to_calendar_tm(val.to_int64(), tz);
+
+            // Use the `tm` to create the `t_date`
+            auto year = static_cast<std::int32_t>(t.tm_year + 1900);
+            std::int32_t month = static_cast<std::uint32_t>(t.tm_mon);
+            if (multiplicity != 1) {
+                month = floor(static_cast<double>(month) / multiplicity)
+                    * multiplicity;
+            }
+            rval.set(t_date(year, month, 1));
+        } break;
+        default:
+            break;
+    }
+}
+
+void
+_year_bucket(
+    t_tscalar& val,
+    t_tscalar& rval,
+    t_uindex multiplicity,
+    const t_tz* tz
+) {
+    switch (val.get_dtype()) {
+        case DTYPE_DATE: {
+            t_date date_val = val.get<t_date>();
+            rval.set(t_date(
+                floor(static_cast<double>(date_val.year()) / multiplicity)
+                    * multiplicity,
+                0,
+                1
+            ));
+        } break;
+        case DTYPE_TIME: {
+            // Break the timestamp into calendar fields in the view's time
+            // zone, or in local time if the view has none.
+            std::tm t = to_calendar_tm(val.to_int64(), tz);
+
+            // Use the `tm` to create the `t_date`
+            auto year = static_cast<std::int32_t>(t.tm_year + 1900);
+            if (multiplicity != 1) {
+                year = floor(static_cast<double>(year) / multiplicity)
+                    * multiplicity;
+            }
+            rval.set(t_date(year, 0, 1));
+        } break;
+        default:
+            break;
+    }
+}
+
+t_tscalar
+now() {
+    t_tscalar rval;
+    auto now = std::chrono::duration_cast<std::chrono::milliseconds>(
+                   std::chrono::system_clock::now().time_since_epoch()
+    )
+                   .count();
+    rval.set(t_time(now));
+    return rval;
+}
+
+t_tscalar
+today() {
+    t_tscalar rval;
+
+    auto now = std::chrono::duration_cast<std::chrono::milliseconds>(
+        std::chrono::system_clock::now().time_since_epoch()
+    );
+
+    // Convert the timestamp to a `sys_time` (alias for `time_point`)
+    date::sys_time<std::chrono::milliseconds> ts(now);
+
+    // Use localtime so that the day of week is consistent with all output
+    // datetimes, which are in local time
+    std::time_t temp = std::chrono::system_clock::to_time_t(ts);
+
+    // Convert to a std::tm
+    std::tm* t = std::localtime(&temp);
+
+    // Get the year and create a new `t_date`
+    auto year = static_cast<std::int32_t>(t->tm_year + 1900);
+
+    // Month in `t_date` is [0-11]
+    std::int32_t month = static_cast<std::uint32_t>(t->tm_mon);
+    auto day = static_cast<std::uint32_t>(t->tm_mday);
+
+    rval.set(t_date(year, month, day));
+    return rval;
+}
+
+inrange_fn::inrange_fn() : exprtk::igeneric_function<t_tscalar>("TTT") {}
+
+inrange_fn::~inrange_fn() = default;
+
+t_tscalar
+inrange_fn::operator()(t_parameter_list parameters) {
+    t_tscalar rval;
+    rval.clear();
+    rval.m_type = DTYPE_BOOL;
+
+    t_scalar_view _low(parameters[0]);
+    t_scalar_view _val(parameters[1]);
+    t_scalar_view _high(parameters[2]);
+
+    t_tscalar low = _low();
+    t_tscalar val = _val();
+    t_tscalar high = _high();
+
+    // make sure we are comparing items of the same type, otherwise
+    // comparisons will fail.
+    t_dtype val_dtype = val.get_dtype();
+
+    if (low.get_dtype() != val_dtype || val_dtype != high.get_dtype()) {
+        rval.m_status = STATUS_CLEAR;
+        return rval;
+    }
+
+    // no need to type check - just check validity
+    if (!low.is_valid() || !val.is_valid() || !high.is_valid()) {
+        return rval;
+    }
+
+    rval.set((low <= val) && (val <= high));
+    return rval;
+}
+
+min_fn::min_fn() = default;
+
+min_fn::~min_fn() = default;
+
+t_tscalar
+min_fn::operator()(t_parameter_list parameters) {
+    t_tscalar rval;
+    rval.clear();
+    rval.m_type = DTYPE_FLOAT64;
+
+    std::vector<t_tscalar> inputs;
+    inputs.resize(parameters.size());
+
+    // type check through all parameters first before calculating
+    for (auto i = 0; i < parameters.size(); ++i) {
+        t_generic_type& gt = parameters[i];
+
+        if (gt.type == t_generic_type::e_scalar) {
+            t_scalar_view _temp(gt);
+            t_tscalar temp = _temp();
+
+            if (!temp.is_numeric()) {
+                rval.m_status = STATUS_CLEAR;
+                return rval;
+            } // correct type - we will check for STATUS_VALID later
+            inputs[i] = temp;
+            continue;
+
+        } // An invalid call - needs to fail at the type check.
+        rval.m_status = STATUS_CLEAR;
+        return rval;
+    }
+
+    // types are now valid - we can calculate the value
+    for (auto i = 0; i < inputs.size(); ++i) {
+        const t_tscalar& val = inputs[i];
+
+        // correct type input but invalid - return
+        if (!val.is_valid()) {
+            return rval;
+        }
+
+        if (i == 0 || (val.to_double() < rval.to_double())) {
+            rval.set(val.to_double());
+        }
     }
 
-    if (!val.is_valid()) {
+    return rval;
+}
+
+sum_fn::sum_fn() = default;
+
+sum_fn::~sum_fn() = default;
+
+t_tscalar
+sum_fn::operator()(t_parameter_list parameters) {
+    t_tscalar rval;
+    rval.clear();
+    rval.m_type = DTYPE_FLOAT64;
+
+    std::vector<t_tscalar> inputs;
+    inputs.resize(parameters.size());
+
+    // type check through all parameters first before calculating
+    for (auto i = 0; i < parameters.size(); ++i) {
+        t_generic_type& gt = parameters[i];
+
+        if (gt.type == t_generic_type::e_scalar) {
+            t_scalar_view _temp(gt);
+            t_tscalar temp = _temp();
+
+            if (!temp.is_numeric() && temp.get_dtype() != DTYPE_LIST) {
+                rval.m_status = STATUS_CLEAR;
+                return rval;
+            }
+            inputs[i] = temp;
+            continue;
+        }
+
+        rval.m_status = STATUS_CLEAR;
+        return rval;
+    }
+
+    double total = 0;
+    for (const t_tscalar& val : inputs) {
+        if (!val.is_valid()) {
+            return rval;
+        }
+
+        if (val.get_dtype() != DTYPE_LIST) {
+            total += val.to_double();
+            continue;
+        }
+
+        // Non-numeric list elements are skipped.
+        rapidjson::Document doc;
+        if (parse_list(val, doc)) {
+            for (const auto& elem : doc.GetArray()) {
+                if (elem.IsNumber()) {
+                    total += elem.GetDouble();
+                }
+            }
+        }
+    }
+
+    rval.set(total);
+    return rval;
+}
+
+max_fn::max_fn() = default;
+
+max_fn::~max_fn() = default;
+
+t_tscalar
+max_fn::operator()(t_parameter_list parameters) {
+    t_tscalar rval;
+    rval.clear();
+    rval.m_type = DTYPE_FLOAT64;
+
+    std::vector<t_tscalar> inputs;
+    inputs.resize(parameters.size());
+
+    // type check through all parameters first before calculating
+    for (auto i = 0; i < parameters.size(); ++i) {
+        t_generic_type& gt = parameters[i];
+
+        if (gt.type == t_generic_type::e_scalar) {
+            t_scalar_view _temp(gt);
+            t_tscalar temp = _temp();
+
+            if (!temp.is_numeric()) {
+                rval.m_status = STATUS_CLEAR;
+                return rval;
+            } // correct type - we will check for STATUS_VALID later
+            inputs[i] = temp;
+            continue;
+
+        } // An invalid call - needs to fail at the type check.
+        rval.m_status = STATUS_CLEAR;
         return rval;
     }
 
-    switch (date_unit) {
-        case t_date_bucket_unit::SECONDS: {
-            _second_bucket(val, rval, multiplicity);
-        } break;
-        case t_date_bucket_unit::MINUTES: {
-            _minute_bucket(val, rval, multiplicity);
-        } break;
-        case t_date_bucket_unit::HOURS: {
-            _hour_bucket(val, rval, multiplicity);
-        } break;
-        case t_date_bucket_unit::DAYS: {
-            _day_bucket(val, rval);
-        } break;
-        case t_date_bucket_unit::WEEKS: {
-            _week_bucket(val, rval);
-        } break;
-        case t_date_bucket_unit::MONTHS: {
-            _month_bucket(val, rval, multiplicity);
-        } break;
-        case t_date_bucket_unit::YEARS: {
-            _year_bucket(val, rval, multiplicity);
-        } break;
-        default: {
-            PSP_COMPLAIN_AND_ABORT("[bucket] invalid date bucket unit!");
-        } break;
+    // types are now valid - we can calculate the value
+    for (auto i = 0; i < inputs.size(); ++i) {
+        const t_tscalar& val = inputs[i];
+
+        // correct type input but invalid - return
+        if (!val.is_valid()) {
+            return rval;
+        }
+
+        if (i == 0 || (val.to_double() > rval.to_double())) {
+            rval.set(val.to_double());
+        }
     }
 
     return rval;
 }
 
-/// @brief Buckets a given time into a date at multiplicity*T resolution.
-/// @tparam T The std::chrono::duration to bucket by.
-/// @param val The input date.
-/// @param multiplicity How many Ts to put in a bucket.
-/// @return The bucketed time.
-template <typename T>
-t_time
-bucket_time(t_tscalar& val, t_uindex multiplicity) {
-    std::chrono::milliseconds millis(val.to_int64());
-    auto raw = std::chrono::duration_cast<T>(millis).count();
-    int64_t bucket =
-        floor(static_cast<double>(raw) / multiplicity) * multiplicity;
-    T refined(bucket);
-    return t_time(
-        std::chrono::duration_cast<std::chrono::milliseconds>(refined).count()
-    );
-}
+diff3::diff3() : exprtk::igeneric_function<t_tscalar>("VVV") {}
 
-void
-_second_bucket(t_tscalar& val, t_tscalar& rval, t_uindex multiplicity) {
-    switch (val.get_dtype()) {
-        case DTYPE_TIME: {
-            rval.set(bucket_time<std::chrono::seconds>(val, multiplicity));
-        } break;
-        default: {
-            // echo the original value back into the column.
-            rval.set(val);
-        }
-    }
-}
+diff3::~diff3() = default;
 
-void
-_minute_bucket(t_tscalar& val, t_tscalar& rval, t_uindex multiplicity) {
-    switch (val.get_dtype()) {
-        case DTYPE_TIME: {
-            rval.set(bucket_time<std::chrono::minutes>(val, multiplicity));
-        } break;
-        default: {
-            rval.set(val);
-        } break;
-    }
-}
+t_tscalar
+diff3::operator()(t_parameter_list parameters) {
+    t_tscalar rval;
+    rval.clear();
+    rval.m_type = DTYPE_BOOL;
 
-void
-_hour_bucket(t_tscalar& val, t_tscalar& rval, t_uindex multiplicity) {
-    switch (val.get_dtype()) {
-        case DTYPE_TIME: {
-            rval.set(bucket_time<std::chrono::hours>(val, multiplicity));
-        } break;
-        default: {
-            rval.set(val);
-        } break;
-    }
-}
+    t_vector_view v1(parameters[0]);
+    t_vector_view v2(parameters[1]);
+    t_vector_view out(parameters[2]);
 
-void
-_day_bucket(t_tscalar& val, t_tscalar& rval) {
-    switch (val.get_dtype()) {
-        case DTYPE_TIME: {
-            // Convert the int64 to a milliseconds duration timestamp
-            std::chrono::milliseconds ms_timestamp(val.to_int64());
+    t_tscalar o1;
+    o1.set(v1[0] - v2[0]);
 
-            // Convert the timestamp to a `sys_time` (alias for
-            // `time_point`)
-            date::sys_time<std::chrono::milliseconds> ts(ms_timestamp);
+    t_tscalar o2;
+    o2.set(v1[1] - v2[1]);
 
-            // Use localtime so that the day of week is consistent with all
-            // output datetimes, which are in local time
-            std::time_t temp = std::chrono::system_clock::to_time_t(ts);
+    t_tscalar o3;
+    o3.set(v1[2] - v2[2]);
 
-            // Convert to a std::tm
-            std::tm* t = std::localtime(&temp);
+    out[0] = o1;
+    out[1] = o2;
+    out[2] = o3;
 
-            // Get the year and create a new `t_date`
-            auto year = static_cast<std::int32_t>(t->tm_year + 1900);
+    rval.set(true);
+    return rval;
+}
 
-            // Month in `t_date` is [0-11]
-            std::int32_t month = static_cast<std::uint32_t>(t->tm_mon);
-            auto day = static_cast<std::uint32_t>(t->tm_mday);
+norm3::norm3() : exprtk::igeneric_function<t_tscalar>("V") {}
 
-            rval.set(t_date(year, month, day));
-        } break;
-        case DTYPE_DATE:
-        default: {
-            // echo the original value back into the column.
-            rval.set(val);
-        } break;
-    }
+norm3::~norm3() = default;
+
+t_tscalar
+norm3::operator()(t_parameter_list parameters) {
+    t_tscalar rval;
+    rval.clear();
+    rval.m_type = DTYPE_FLOAT64;
+    t_vector_view v1(parameters[0]);
+    double a = v1[0].to_double();
+    double b = v1[1].to_double();
+    double c = v1[2].to_double();
+    rval.set(sqrt(pow(a, 2) + pow(b, 2) + pow(c, 2)));
+    return rval;
 }
 
-void
-_week_bucket(t_tscalar& val, t_tscalar& rval) {
-    switch (val.get_dtype()) {
-        case DTYPE_DATE: {
-            // Retrieve the `t_date` struct from the scalar
-            t_date date_val = val.get<t_date>();
+cross_product3::cross_product3() :
+    exprtk::igeneric_function<t_tscalar>("VVV") {}
 
-            // Construct a `date::year_month_day` value
-            date::year year{date_val.year()};
+cross_product3::~cross_product3() = default;
 
-            // date::month is [1-12], whereas `t_date.month()` is [0-11]
-            date::month month{static_cast<std::uint32_t>(date_val.month()) + 1};
-            date::day day{static_cast<std::uint32_t>(date_val.day())};
-            date::year_month_day ymd(year, month, day);
+t_tscalar
+cross_product3::operator()(t_parameter_list parameters) {
+    t_tscalar rval;
+    rval.clear();
+    rval.m_type = DTYPE_BOOL;
 
-            // Convert to a `sys_days` representing no. of days since epoch
-            date::sys_days days_since_epoch = ymd;
+    // Parameters already validated
+    t_vector_view v1(parameters[0]);
+    t_vector_view v2(parameters[1]);
+    t_vector_view out(parameters[2]);
 
-            // Subtract Sunday from the ymd to get the beginning of the last
-            // day
-            ymd = days_since_epoch
-                - (date::weekday{days_since_epoch} - date::Monday);
+    // a2 * b3 - a3 * b2
+    t_tscalar o1;
+    o1.set(v1[1] * v2[2] - v1[2] * v2[1]);
 
-            // Get the day of month and day of the week
-            std::int32_t year_int = static_cast<std::int32_t>(ymd.year());
+    // a3 * b1 - a1 * b3,
+    t_tscalar o2;
+    o2.set(v1[2] * v2[0] - v1[0] * v2[2]);
 
-            // date::month is [1-12], whereas `t_date.month()` is [0-11]
-            std::uint32_t month_int =
-                static_cast<std::uint32_t>(ymd.month()) - 1;
-            std::uint32_t day_int = static_cast<std::uint32_t>(ymd.day());
+    // a1 * b2 - a2 * b1
+    t_tscalar o3;
+    o3.set(v1[0] * v2[1] - v1[1] * v2[0]);
 
-            // Return the new `t_date`
-            t_date new_date = t_date(year_int, month_int, day_int);
-            rval.set(new_date);
-        } break;
-        case DTYPE_TIME: {
-            // Convert the int64 to a milliseconds duration timestamp
-            std::chrono::milliseconds timestamp(val.to_int64());
+    out[0] = o1;
+    out[1] = o2;
+    out[2] = o3;
 
-            // Convert the timestamp to a `sys_time` (alias for
-            // `time_point`)
-            date::sys_time<std::chrono::milliseconds> ts(timestamp);
+    rval.set(true);
+    return rval;
+}
 
-            // Convert the timestamp to local time
-            std::time_t temp = std::chrono::system_clock::to_time_t(ts);
-            std::tm* t = std::localtime(&temp);
+dot_product3::dot_product3() : exprtk::igeneric_function<t_tscalar>("VV") {}
 
-            // Take the ymd from the `tm`, now in local time, and create a
-            // date::year_month_day.
-            date::year year{1900 + t->tm_year};
+dot_product3::~dot_product3() = default;
 
-            // date::month is [1-12], whereas `std::tm::tm_mon` is [0-11]
-            date::month month{static_cast<std::uint32_t>(t->tm_mon) + 1};
-            date::day day{static_cast<std::uint32_t>(t->tm_mday)};
-            date::year_month_day ymd(year, month, day);
+t_tscalar
+dot_product3::operator()(t_parameter_list parameters) {
+    t_tscalar rval;
+    rval.clear();
+    rval.m_type = DTYPE_FLOAT64;
 
-            // Convert to a `sys_days` representing no. of days since epoch
-            date::sys_days days_since_epoch = ymd;
+    // Parameters already validated
+    t_vector_view v1(parameters[0]);
+    t_vector_view v2(parameters[1]);
 
-            // Subtract Sunday from the ymd to get the beginning of the last
-            // day
-            ymd = days_since_epoch
-                - (date::weekday{days_since_epoch} - date::Monday);
+    rval.set(v1[0] * v2[0] + v1[1] * v2[1] + v1[2] * v2[2]);
+    return rval;
+}
 
-            // Get the day of month and day of the week
-            std::int32_t year_int = static_cast<std::int32_t>(ymd.year());
+percent_of::percent_of() : exprtk::igeneric_function<t_tscalar>("TT") {}
 
-            // date::month is [1-12], whereas `t_date.month()` is [0-11]
-            std::uint32_t month_int =
-                static_cast<std::uint32_t>(ymd.month()) - 1;
-            std::uint32_t day_int = static_cast<std::uint32_t>(ymd.day());
+percent_of::~percent_of() = default;
 
-            // Return the new `t_date`
-            t_date new_date = t_date(year_int, month_int, day_int);
-            rval.set(new_date);
-        } break;
-        default:
-            break;
+t_tscalar
+percent_of::operator()(t_parameter_list parameters) {
+    t_tscalar rval;
+    rval.clear();
+
+    // Return a float so we can use it in conditionals
+    rval.m_type = DTYPE_FLOAT64;
+
+    t_generic_type& _x = parameters[0];
+    t_generic_type& _y = parameters[1];
+
+    t_scalar_view _x_view(_x);
+    t_scalar_view _y_view(_y);
+
+    t_tscalar x = _x_view();
+    t_tscalar y = _y_view();
+
+    if (!x.is_numeric() || !y.is_numeric()) {
+        rval.m_status = STATUS_CLEAR;
     }
-}
 
-void
-_month_bucket(t_tscalar& val, t_tscalar& rval, t_uindex multiplicity) {
-    switch (val.get_dtype()) {
-        case DTYPE_DATE: {
-            t_date date_val = val.get<t_date>();
-            auto in_month = date_val.month();
-            int8_t out_month =
-                floor(static_cast<double>(in_month) / multiplicity)
-                * multiplicity;
-            rval.set(t_date(date_val.year(), out_month, 1));
-        } break;
-        case DTYPE_TIME: {
-            // Convert the int64 to a milliseconds duration
-            // timestamp
-            std::chrono::milliseconds ms_timestamp(val.to_int64());
+    if (!x.is_valid() || !y.is_valid()) {
+        return rval;
+    }
 
-            // Convert the timestamp to a `sys_time` (alias for
-            // `time_point`)
-            date::sys_time<std::chrono::milliseconds> ts(ms_timestamp);
+    if (y.to_double() == 0) {
+        return rval;
+    }
 
-            // Convert the timestamp to local time
-            std::time_t temp = std::chrono::system_clock::to_time_t(ts);
-            std::tm* t = std::localtime(&temp);
+    rval.set((x.to_double() / y.to_double()) * 100);
+    return rval;
+}
 
-            // Use the `tm` to create the `t_date`
-            auto year = static_cast<std::int32_t>(t->tm_year + 1900);
-            std::int32_t month = static_cast<std::uint32_t>(t->tm_mon);
-            if (multiplicity != 1) {
-                month = floor(static_cast<double>(month) / multiplicity)
-                    * multiplicity;
-            }
-            rval.set(t_date(year, month, 1));
-        } break;
-        default:
-            break;
+// Reads numeric scalar arguments into `out`, returning false if any argument
+// is invalid. Non-numeric arguments clear `rval` so they fail type checking.
+static bool
+numeric_args(t_parameter_list parameters, t_tscalar& rval, double* out) {
+    bool valid = true;
+    for (t_uindex idx = 0; idx < parameters.size(); ++idx) {
+        t_scalar_view _view(parameters[idx]);
+        t_tscalar val = _view();
+        if (!val.is_numeric()) {
+            rval.m_status = STATUS_CLEAR;
+        }
+
+        if (!val.is_valid()) {
+            valid = false;
+        } else {
+            out[idx] = val.to_double();
+        }
     }
+
+    return valid && rval.m_status != STATUS_CLEAR;
 }
 
-void
-_year_bucket(t_tscalar& val, t_tscalar& rval, t_uindex multiplicity) {
-    switch (val.get_dtype()) {
-        case DTYPE_DATE: {
-            t_date date_val = val.get<t_date>();
-            rval.set(t_date(
-                floor(static_cast<double>(date_val.year()) / multiplicity)
-                    * multiplicity,
-                0,
-                1
-            ));
-        } break;
-        case DTYPE_TIME: {
-            // Convert the int64 to a milliseconds duration timestamp
-            std::chrono::milliseconds ms_timestamp(val.to_int64());
+haversine_distance::haversine_distance() :
+    exprtk::igeneric_function<t_tscalar>("TTTT") {}
 
-            // Convert the timestamp to a `sys_time` (alias for
-            // `time_point`)
-            date::sys_time<std::chrono::milliseconds> ts(ms_timestamp);
+haversine_distance::~haversine_distance() = default;
 
-            // Convert the timestamp to local time
-            std::time_t temp = std::chrono::system_clock::to_time_t(ts);
-            std::tm* t = std::localtime(&temp);
+t_tscalar
+haversine_distance::operator()(t_parameter_list parameters) {
+    t_tscalar rval;
+    rval.clear();
+    rval.m_type = DTYPE_FLOAT64;
 
-            // Use the `tm` to create the `t_date`
-            auto year = static_cast<std::int32_t>(t->tm_year + 1900);
-            if (multiplicity != 1) {
-                year = floor(static_cast<double>(year) / multiplicity)
-                    * multiplicity;
-            }
-            rval.set(t_date(year, 0, 1));
-        } break;
-        default:
-            break;
+    double args[4];
+    if (!numeric_args(parameters, rval, args)) {
+        return rval;
     }
+
+    // Mean radius of the earth, in kilometers.
+    constexpr double radius = 6371.0088;
+    constexpr double to_radians = 3.14159265358979323846 / 180.0;
+    double lat1 = args[0] * to_radians;
+    double lat2 = args[2] * to_radians;
+    double dlat = (args[2] - args[0]) * to_radians;
+    double dlon = (args[3] - args[1]) * to_radians;
+    double a = std::sin(dlat / 2) * std::sin(dlat / 2)
+        + std::cos(lat1) * std::cos(lat2) * std::sin(dlon / 2)
+            * std::sin(dlon / 2);
+
+    rval.set(2 * radius * std::asin(std::sqrt(std::min(1.0, a))));
+    return rval;
 }
 
+within_bbox::within_bbox() : exprtk::igeneric_function<t_tscalar>("TTTTTT") {}
+
+within_bbox::~within_bbox() = default;
+
 t_tscalar
-now() {
+within_bbox::operator()(t_parameter_list parameters) {
     t_tscalar rval;
-    auto now = std::chrono::duration_cast<std::chrono::milliseconds>(
-                   std::chrono::system_clock::now().time_since_epoch()
-    )
-                   .count();
-    rval.set(t_time(now));
+    rval.clear();
+    rval.m_type = DTYPE_BOOL;
+
+    double args[6];
+    if (!numeric_args(parameters, rval, args)) {
+        return rval;
+    }
+
+    double lat = args[0];
+    double lon = args[1];
+    double min_lat = args[2];
+    double min_lon = args[3];
+    double max_lat = args[4];
+    double max_lon = args[5];
+    bool in_lat = min_lat <= lat && lat <= max_lat;
+    bool in_lon = min_lon <= max_lon ? (min_lon <= lon && lon <= max_lon)
+                                     : (min_lon <= lon || lon <= max_lon);
+
+    rval.set(in_lat && in_lon);
     return rval;
 }
 
+geohash::geohash(t_expression_vocab& expression_vocab, bool is_type_validator) :
+    exprtk::igeneric_function<t_tscalar>("TTT"),
+    m_expression_vocab(expression_vocab),
+    m_is_type_validator(is_type_validator) {
+    t_tscalar sentinel;
+    sentinel.clear();
+    sentinel.set(m_expression_vocab.get_empty_string());
+    sentinel.m_status = STATUS_INVALID;
+    m_sentinel = sentinel;
+}
+
+geohash::~geohash() = default;
+
 t_tscalar
-today() {
+geohash::operator()(t_parameter_list parameters) {
     t_tscalar rval;
+    rval.clear();
+    rval.m_type = DTYPE_STR;
 
-    auto now = std::chrono::duration_cast<std::chrono::milliseconds>(
-        std::chrono::system_clock::now().time_since_epoch()
-    );
+    double args[3];
+    bool valid = numeric_args(parameters, rval, args);
+    if (rval.m_status == STATUS_CLEAR) {
+        return rval;
+    }
 
-    // Convert the timestamp to a `sys_time` (alias for `time_point`)
-    date::sys_time<std::chrono::milliseconds> ts(now);
+    if (m_is_type_validator) {
+        return m_sentinel;
+    }
 
-    // Use localtime so that the day of week is consistent with all output
-    // datetimes, which are in local time
-    std::time_t temp = std::chrono::system_clock::to_time_t(ts);
+    if (!valid) {
+        return rval;
+    }
 
-    // Convert to a std::tm
-    std::tm* t = std::localtime(&temp);
+    double lat = args[0];
+    double lon = args[1];
+    auto precision = static_cast<std::int64_t>(args[2]);
+    if (lat < -90 || lat > 90 || lon < -180 || lon > 180 || precision < 1
+        || precision > 12) {
+        return rval;
+    }
 
-    // Get the year and create a new `t_date`
-    auto year = static_cast<std::int32_t>(t->tm_year + 1900);
+    static constexpr const char* base32 = "0123456789bcdefghjkmnpqrstuvwxyz";
+    double lat_range[2] = {-90.0, 90.0};
+    double lon_range[2] = {-180.0, 180.0};
+    std::string hash;
+    hash.reserve(precision);
 
-    // Month in `t_date` is [0-11]
-    std::int32_t month = static_cast<std::uint32_t>(t->tm_mon);
-    auto day = static_cast<std::uint32_t>(t->tm_mday);
+    // Interleave longitude and latitude bits, starting with longitude, and
+    // emit a character every 5 bits.
+    bool is_lon = true;
+    int bit = 0;
+    int idx = 0;
+    while (hash.size() < static_cast<std::size_t>(precision)) {
+        double* range = is_lon ? lon_range : lat_range;
+        double val = is_lon ? lon : lat;
+        double mid = (range[0] + range[1]) / 2;
+        idx <<= 1;
+        if (val >= mid) {
+            idx |= 1;
+            range[0] = mid;
+        } else {
+            range[1] = mid;
+        }
 
-    rval.set(t_date(year, month, day));
+        is_lon = !is_lon;
+        if (++bit == 5) {
+            hash.push_back(base32[idx]);
+            bit = 0;
+            idx = 0;
+        }
+    }
+
+    rval.set(m_expression_vocab.intern(hash));
     return rval;
 }
 
-inrange_fn::inrange_fn() : exprtk::igeneric_function<t_tscalar>("TTT") {}
+in_subnet::in_subnet() : exprtk::igeneric_function<t_tscalar>("TT") {}
 
-inrange_fn::~inrange_fn() = default;
+in_subnet::~in_subnet() = default;
 
 t_tscalar
-inrange_fn::operator()(t_parameter_list parameters) {
+in_subnet::operator()(t_parameter_list parameters) {
     t_tscalar rval;
     rval.clear();
     rval.m_type = DTYPE_BOOL;
 
-    t_scalar_view _low(parameters[0]);
-    t_scalar_view _val(parameters[1]);
-    t_scalar_view _high(parameters[2]);
-
-    t_tscalar low = _low();
-    t_tscalar val = _val();
-    t_tscalar high = _high();
+    t_tscalar addr = t_scalar_view(parameters[0])();
+    t_tscalar block = t_scalar_view(parameters[1])();
+    if ((addr.get_dtype() != DTYPE_IPADDR && addr.get_dtype() != DTYPE_STR)
+        || block.get_dtype() != DTYPE_STR) {
+        rval.m_status = STATUS_CLEAR;
+        return rval;
+    }
 
-    // make sure we are comparing items of the same type, otherwise
-    // comparisons will fail.
-    t_dtype val_dtype = val.get_dtype();
+    if (!addr.is_valid() || !block.is_valid()) {
+        return rval;
+    }
 
-    if (low.get_dtype() != val_dtype || val_dtype != high.get_dtype()) {
+    t_subnet subnet;
+    if (!parse_subnet(block.get_char_ptr(), subnet)) {
         rval.m_status = STATUS_CLEAR;
         return rval;
     }
 
-    // no need to type check - just check validity
-    if (!low.is_valid() || !val.is_valid() || !high.is_valid()) {
+    t_ipaddr ip;
+    if (addr.get_dtype() == DTYPE_IPADDR) {
+        ip = addr.get<t_ipaddr>();
+    } else if (!parse_ipaddr(addr.get_char_ptr(), ip)) {
         return rval;
     }
 
-    rval.set((low <= val) && (val <= high));
+    rval.set(subnet.contains(ip));
     return rval;
 }
 
-min_fn::min_fn() = default;
+round_to_tick::round_to_tick() : exprtk::igeneric_function<t_tscalar>("TT") {}
 
-min_fn::~min_fn() = default;
+round_to_tick::~round_to_tick() = default;
 
 t_tscalar
-min_fn::operator()(t_parameter_list parameters) {
+round_to_tick::operator()(t_parameter_list parameters) {
     t_tscalar rval;
     rval.clear();
     rval.m_type = DTYPE_FLOAT64;
 
-    std::vector<t_tscalar> inputs;
-    inputs.resize(parameters.size());
-
-    // type check through all parameters first before calculating
-    for (auto i = 0; i < parameters.size(); ++i) {
-        t_generic_type& gt = parameters[i];
-
-        if (gt.type == t_generic_type::e_scalar) {
-            t_scalar_view _temp(gt);
-            t_tscalar temp = _temp();
-
-            if (!temp.is_numeric()) {
-                rval.m_status = STATUS_CLEAR;
-                return rval;
-            } // correct type - we will check for STATUS_VALID later
-            inputs[i] = temp;
-            continue;
-
-        } // An invalid call - needs to fail at the type check.
-        rval.m_status = STATUS_CLEAR;
+    double args[2];
+    if (!numeric_args(parameters, rval, args)) {
         return rval;
     }
 
-    // types are now valid - we can calculate the value
-    for (auto i = 0; i < inputs.size(); ++i) {
-        const t_tscalar& val = inputs[i];
+    double tick = args[1];
+    if (tick <= 0) {
+        return rval;
+    }
 
-        // correct type input but invalid - return
-        if (!val.is_valid()) {
-            return rval;
-        }
+    double rounded = std::round(args[0] / tick) * tick;
 
-        if (i == 0 || (val.to_double() < rval.to_double())) {
-            rval.set(val.to_double());
-        }
+    // Snap to the tick's decimal places, so 1.24 rounds to 1.25 on a 0.05
+    // tick rather than to 1.2500000000000002.
+    double scale = 1;
+    for (int i = 0; i < 15
+         && std::abs(tick * scale - std::round(tick * scale)) > 1e-9 * scale;
+         ++i) {
+        scale *= 10;
     }
 
+    rval.set(std::round(rounded * scale) / scale);
     return rval;
 }
 
-max_fn::max_fn() = default;
+bps::bps() : exprtk::igeneric_function<t_tscalar>("TT") {}
 
-max_fn::~max_fn() = default;
+bps::~bps() = default;
 
 t_tscalar
-max_fn::operator()(t_parameter_list parameters) {
+bps::operator()(t_parameter_list parameters) {
     t_tscalar rval;
     rval.clear();
     rval.m_type = DTYPE_FLOAT64;
 
-    std::vector<t_tscalar> inputs;
-    inputs.resize(parameters.size());
+    double args[2];
+    if (!numeric_args(parameters, rval, args) || args[1] == 0) {
+        return rval;
+    }
 
-    // type check through all parameters first before calculating
-    for (auto i = 0; i < parameters.size(); ++i) {
-        t_generic_type& gt = parameters[i];
+    rval.set((args[0] / args[1] - 1) * 10000);
+    return rval;
+}
 
-        if (gt.type == t_generic_type::e_scalar) {
-            t_scalar_view _temp(gt);
-            t_tscalar temp = _temp();
+// The balance left after `nper` periods of growth at `rate` on `pv`, with
+// payments of `pmt` each period and a final `fv`. Zero when the five time
+// value of money quantities are consistent.
+static double
+tvm_balance(double rate, double nper, double pmt, double pv, double fv) {
+    if (std::abs(rate) < 1e-12) {
+        return pv + pmt * nper + fv;
+    }
 
-            if (!temp.is_numeric()) {
-                rval.m_status = STATUS_CLEAR;
-                return rval;
-            } // correct type - we will check for STATUS_VALID later
-            inputs[i] = temp;
-            continue;
+    double growth = std::pow(1 + rate, nper);
+    return pv * growth + pmt * (growth - 1) / rate + fv;
+}
 
-        } // An invalid call - needs to fail at the type check.
-        rval.m_status = STATUS_CLEAR;
-        return rval;
-    }
+// Solves `fn(rate) == 0` for a rate above -100% by Newton's method from
+// `guess`, giving up after a bounded number of iterations so a cash flow
+// with no solution can't stall the expression. Returns whether it converged.
+static bool
+solve_rate(
+    const std::function<double(double)>& fn, double guess, double& rate
+) {
+    static constexpr int MAX_ITERATIONS = 100;
+    static constexpr double TOLERANCE = 1e-10;
+
+    rate = guess;
+    for (int i = 0; i < MAX_ITERATIONS; ++i) {
+        double value = fn(rate);
+        double step = 1e-7 * std::max(1.0, std::abs(rate));
+        double slope = (fn(rate + step) - fn(rate - step)) / (2 * step);
+        if (!std::isfinite(value) || !std::isfinite(slope) || slope == 0) {
+            return false;
+        }
 
-    // types are now valid - we can calculate the value
-    for (auto i = 0; i < inputs.size(); ++i) {
-        const t_tscalar& val = inputs[i];
+        double next = rate - value / slope;
+        if (!std::isfinite(next)) {
+            return false;
+        }
 
-        // correct type input but invalid - return
-        if (!val.is_valid()) {
-            return rval;
+        // Stay above -100%, where the discount factor is undefined.
+        if (next <= -1) {
+            next = (rate - 1) / 2;
         }
 
-        if (i == 0 || (val.to_double() > rval.to_double())) {
-            rval.set(val.to_double());
+        if (std::abs(next - rate) < TOLERANCE) {
+            rate = next;
+            return true;
         }
+
+        rate = next;
     }
 
-    return rval;
+    return false;
 }
 
-diff3::diff3() : exprtk::igeneric_function<t_tscalar>("VVV") {}
+pv::pv() : exprtk::igeneric_function<t_tscalar>("TTTT") {}
 
-diff3::~diff3() = default;
+pv::~pv() = default;
 
 t_tscalar
-diff3::operator()(t_parameter_list parameters) {
+pv::operator()(t_parameter_list parameters) {
     t_tscalar rval;
     rval.clear();
-    rval.m_type = DTYPE_BOOL;
+    rval.m_type = DTYPE_FLOAT64;
 
-    t_vector_view v1(parameters[0]);
-    t_vector_view v2(parameters[1]);
-    t_vector_view out(parameters[2]);
+    double args[4];
+    if (!numeric_args(parameters, rval, args)) {
+        return rval;
+    }
 
-    t_tscalar o1;
-    o1.set(v1[0] - v2[0]);
+    double result = -tvm_balance(args[0], args[1], args[2], 0, args[3])
+        / std::pow(1 + args[0], args[1]);
 
-    t_tscalar o2;
-    o2.set(v1[1] - v2[1]);
+    if (std::isfinite(result)) {
+        rval.set(result);
+    }
 
-    t_tscalar o3;
-    o3.set(v1[2] - v2[2]);
+    return rval;
+}
 
-    out[0] = o1;
-    out[1] = o2;
-    out[2] = o3;
+fv::fv() : exprtk::igeneric_function<t_tscalar>("TTTT") {}
+
+fv::~fv() = default;
+
+t_tscalar
+fv::operator()(t_parameter_list parameters) {
+    t_tscalar rval;
+    rval.clear();
+    rval.m_type = DTYPE_FLOAT64;
+
+    double args[4];
+    if (!numeric_args(parameters, rval, args)) {
+        return rval;
+    }
+
+    double result = -tvm_balance(args[0], args[1], args[2], args[3], 0);
+    if (std::isfinite(result)) {
+        rval.set(result);
+    }
 
-    rval.set(true);
     return rval;
 }
 
-norm3::norm3() : exprtk::igeneric_function<t_tscalar>("V") {}
+rate::rate() : exprtk::igeneric_function<t_tscalar>("TTTT") {}
 
-norm3::~norm3() = default;
+rate::~rate() = default;
 
 t_tscalar
-norm3::operator()(t_parameter_list parameters) {
+rate::operator()(t_parameter_list parameters) {
     t_tscalar rval;
     rval.clear();
     rval.m_type = DTYPE_FLOAT64;
-    t_vector_view v1(parameters[0]);
-    double a = v1[0].to_double();
-    double b = v1[1].to_double();
-    double c = v1[2].to_double();
-    rval.set(sqrt(pow(a, 2) + pow(b, 2) + pow(c, 2)));
+
+    double args[4];
+    if (!numeric_args(parameters, rval, args)) {
+        return rval;
+    }
+
+    if (args[0] <= 0) {
+        return rval;
+    }
+
+    double result;
+    auto balance = [&](double r) {
+        return tvm_balance(r, args[0], args[1], args[2], args[3]);
+    };
+
+    if (solve_rate(balance, 0.1, result)) {
+        rval.set(result);
+    }
+
     return rval;
 }
 
-cross_product3::cross_product3() :
-    exprtk::igeneric_function<t_tscalar>("VVV") {}
+irr::irr() = default;
 
-cross_product3::~cross_product3() = default;
+irr::~irr() = default;
 
 t_tscalar
-cross_product3::operator()(t_parameter_list parameters) {
+irr::operator()(t_parameter_list parameters) {
     t_tscalar rval;
     rval.clear();
-    rval.m_type = DTYPE_BOOL;
+    rval.m_type = DTYPE_FLOAT64;
 
-    // Parameters already validated
-    t_vector_view v1(parameters[0]);
-    t_vector_view v2(parameters[1]);
-    t_vector_view out(parameters[2]);
+    // Cash flows are either a single list, or two or more numbers.
+    std::vector<double> flows;
+    bool valid = true;
+    for (t_uindex idx = 0; idx < parameters.size(); ++idx) {
+        if (parameters[idx].type != t_generic_type::e_scalar) {
+            rval.m_status = STATUS_CLEAR;
+            return rval;
+        }
 
-    // a2 * b3 - a3 * b2
-    t_tscalar o1;
-    o1.set(v1[1] * v2[2] - v1[2] * v2[1]);
+        t_scalar_view view(parameters[idx]);
+        t_tscalar val = view();
+        bool is_list = parameters.size() == 1 && val.get_dtype() == DTYPE_LIST;
+        if ((!is_list && !val.is_numeric()) || val.m_status == STATUS_CLEAR) {
+            rval.m_status = STATUS_CLEAR;
+            return rval;
+        }
 
-    // a3 * b1 - a1 * b3,
-    t_tscalar o2;
-    o2.set(v1[2] * v2[0] - v1[0] * v2[2]);
+        if (!val.is_valid() || val.is_none()) {
+            valid = false;
+        } else if (!is_list) {
+            flows.push_back(val.to_double());
+        } else {
+            rapidjson::Document doc;
+            if (!parse_list(val, doc)) {
+                return rval;
+            }
 
-    // a1 * b2 - a2 * b1
-    t_tscalar o3;
-    o3.set(v1[0] * v2[1] - v1[1] * v2[0]);
+            for (const auto& elem : doc.GetArray()) {
+                if (!elem.IsNumber()) {
+                    return rval;
+                }
 
-    out[0] = o1;
-    out[1] = o2;
-    out[2] = o3;
+                flows.push_back(elem.GetDouble());
+            }
+        }
+    }
+
+    if (!valid) {
+        return rval;
+    }
+
+    // A rate of return needs money both paid and received.
+    bool has_inflow = std::any_of(flows.begin(), flows.end(), [](double x) {
+        return x > 0;
+    });
+
+    bool has_outflow = std::any_of(flows.begin(), flows.end(), [](double x) {
+        return x < 0;
+    });
+
+    if (!has_inflow || !has_outflow) {
+        return rval;
+    }
+
+    auto npv = [&](double r) {
+        double total = 0;
+        double discount = 1;
+        for (double flow : flows) {
+            total += flow / discount;
+            discount *= 1 + r;
+        }
+
+        return total;
+    };
+
+    double result;
+    if (solve_rate(npv, 0.1, result)) {
+        rval.set(result);
+    }
 
-    rval.set(true);
     return rval;
 }
 
-dot_product3::dot_product3() : exprtk::igeneric_function<t_tscalar>("VV") {}
+// The calendar date of a date, or of a datetime in UTC.
+static date::year_month_day
+to_year_month_day(const t_tscalar& val) {
+    if (val.get_dtype() == DTYPE_TIME) {
+        date::sys_time<std::chrono::milliseconds> time{
+            std::chrono::milliseconds{val.to_int64()}
+        };
 
-dot_product3::~dot_product3() = default;
+        return date::year_month_day{date::floor<date::days>(time)};
+    }
 
-t_tscalar
-dot_product3::operator()(t_parameter_list parameters) {
-    t_tscalar rval;
-    rval.clear();
-    rval.m_type = DTYPE_FLOAT64;
+    // date::month is [1-12], whereas `t_date.month()` is [0-11]
+    t_date date_val = val.get<t_date>();
+    return date::year_month_day{
+        date::year{date_val.year()},
+        date::month{static_cast<std::uint32_t>(date_val.month()) + 1},
+        date::day{static_cast<std::uint32_t>(date_val.day())}
+    };
+}
 
-    // Parameters already validated
-    t_vector_view v1(parameters[0]);
-    t_vector_view v2(parameters[1]);
+static bool
+is_day_count_basis(const std::string& basis) {
+    return basis == "act/360" || basis == "act/365" || basis == "30/360"
+        || basis == "act/act";
+}
 
-    rval.set(v1[0] * v2[0] + v1[1] * v2[1] + v1[2] * v2[2]);
-    return rval;
+// The number of years from `start` to `end` under the day count convention
+// `basis`, one of those accepted by `is_day_count_basis`.
+static double
+year_fraction(
+    const date::year_month_day& start,
+    const date::year_month_day& end,
+    const std::string& basis
+) {
+    double days = (date::sys_days{end} - date::sys_days{start}).count();
+    if (basis == "act/360") {
+        return days / 360;
+    }
+
+    if (basis == "act/365") {
+        return days / 365;
+    }
+
+    if (basis == "30/360") {
+        // US (bond basis) 30/360: the 31st counts as the 30th, except at
+        // the end of a period which starts before the 30th.
+        int d1 = std::min(static_cast<int>(unsigned{start.day()}), 30);
+        int d2 = static_cast<int>(unsigned{end.day()});
+        if (d1 == 30 && d2 == 31) {
+            d2 = 30;
+        }
+
+        int years = static_cast<int>(end.year())
+            - static_cast<int>(start.year());
+        int months = static_cast<int>(unsigned{end.month()})
+            - static_cast<int>(unsigned{start.month()});
+
+        return (360.0 * years + 30.0 * months + (d2 - d1)) / 360;
+    }
+
+    // ISDA actual/actual: days in each calendar year over that year's length.
+    if (end < start) {
+        return -year_fraction(end, start, basis);
+    }
+
+    double out = 0;
+    for (auto year = start.year(); year <= end.year(); ++year) {
+            date::sys_days from = std::max(
+                date::sys_days{start}, date::sys_days{year / date::January / 1}
+            );
+
+            date::sys_days to = std::min(
+                date::sys_days{end},
+                date::sys_days{(year + date::years{1}) / date::January / 1}
+            );
+
+        out += static_cast<double>((to - from).count())
+            / (year.is_leap() ? 366 : 365);
+    }
+
+    return out;
 }
 
-percent_of::percent_of() : exprtk::igeneric_function<t_tscalar>("TT") {}
+year_frac::year_frac() : exprtk::igeneric_function<t_tscalar>("TTT") {}
 
-percent_of::~percent_of() = default;
+year_frac::~year_frac() = default;
 
 t_tscalar
-percent_of::operator()(t_parameter_list parameters) {
+year_frac::operator()(t_parameter_list parameters) {
     t_tscalar rval;
     rval.clear();
-
-    // Return a float so we can use it in conditionals
     rval.m_type = DTYPE_FLOAT64;
 
-    t_generic_type& _x = parameters[0];
-    t_generic_type& _y = parameters[1];
-
-    t_scalar_view _x_view(_x);
-    t_scalar_view _y_view(_y);
+    t_scalar_view start_view(parameters[0]);
+    t_scalar_view end_view(parameters[1]);
+    t_scalar_view basis_view(parameters[2]);
+    t_tscalar start = start_view();
+    t_tscalar end = end_view();
+    t_tscalar basis = basis_view();
 
-    t_tscalar x = _x_view();
-    t_tscalar y = _y_view();
+    for (const auto& val : {start, end}) {
+        if ((val.get_dtype() != DTYPE_DATE && val.get_dtype() != DTYPE_TIME)
+            || val.m_status == STATUS_CLEAR) {
+            rval.m_status = STATUS_CLEAR;
+            return rval;
+        }
+    }
 
-    if (!x.is_numeric() || !y.is_numeric()) {
+    if (basis.get_dtype() != DTYPE_STR || !basis.is_valid()) {
         rval.m_status = STATUS_CLEAR;
+        return rval;
     }
 
-    if (!x.is_valid() || !y.is_valid()) {
+    std::string basis_str = basis.to_string();
+    boost::to_lower(basis_str);
+
+    if (!is_day_count_basis(basis_str)) {
+        rval.m_status = STATUS_CLEAR;
         return rval;
     }
 
-    if (y.to_double() == 0) {
+    if (!start.is_valid() || !end.is_valid()) {
         return rval;
     }
 
-    rval.set((x.to_double() / y.to_double()) * 100);
+    rval.set(year_fraction(
+        to_year_month_day(start), to_year_month_day(end), basis_str
+    ));
+
     return rval;
 }
 
@@ -2200,6 +3614,128 @@ make_datetime::operator()(t_parameter_list parameters) {
     return rval;
 }
 
+make_duration::make_duration() : exprtk::igeneric_function<t_tscalar>("T") {}
+
+make_duration::~make_duration() = default;
+
+t_tscalar
+make_duration::operator()(t_parameter_list parameters) {
+    t_tscalar rval;
+    rval.clear();
+    rval.m_type = DTYPE_DURATION;
+
+    t_generic_type& gt = parameters[0];
+    t_scalar_view temp(gt);
+    t_tscalar temp_scalar;
+
+    temp_scalar.set(temp());
+
+    if (!temp_scalar.is_numeric()) {
+        rval.m_status = STATUS_CLEAR;
+        return rval;
+    }
+
+    if (!temp_scalar.is_valid()) {
+        return rval;
+    }
+
+    rval.set(t_tdelta(static_cast<std::int64_t>(temp_scalar.to_double())));
+    return rval;
+}
+
+namespace {
+    // Parse a time zone name, reusing the previous result when the name is
+    // unchanged as it is almost always a literal.
+    const t_tz*
+    cached_timezone(
+        const std::string& name,
+        std::string& cached_name,
+        t_tz& cached_tz,
+        bool& cached_valid
+    ) {
+        if (name != cached_name) {
+            cached_name = name;
+            cached_valid = t_tz::parse(name, cached_tz);
+        }
+
+        return cached_valid ? &cached_tz : nullptr;
+    }
+
+    // Shared by `convert_tz` and `at_tz`: validate the arguments, then apply
+    // `convert` to the datetime in the named zone.
+    t_tscalar
+    convert_timezone(
+        t_tscalar val,
+        const t_tz* tz,
+        std::int64_t (t_tz::*convert)(std::int64_t) const
+    ) {
+        t_tscalar rval;
+        rval.clear();
+        rval.m_type = DTYPE_TIME;
+
+        if (val.get_dtype() != DTYPE_TIME || tz == nullptr
+            || val.m_status == STATUS_CLEAR) {
+            rval.m_status = STATUS_CLEAR;
+            return rval;
+        }
+
+        if (!val.is_valid()) {
+            return rval;
+        }
+
+        rval.set(t_time((tz->*convert)(val.to_int64())));
+        return rval;
+    }
+} // namespace
+
+convert_tz::convert_tz() :
+    exprtk::igeneric_function<t_tscalar>("TS"),
+    m_timezone_valid(false) {}
+
+convert_tz::~convert_tz() = default;
+
+const t_tz*
+convert_tz::get_timezone(const std::string& name) {
+    return cached_timezone(
+        name, m_timezone_name, m_timezone, m_timezone_valid
+    );
+}
+
+t_tscalar
+convert_tz::operator()(t_parameter_list parameters) {
+    t_scalar_view temp(parameters[0]);
+    t_string_view name(parameters[1]);
+    return convert_timezone(
+        temp(),
+        get_timezone(std::string(name.begin(), name.end())),
+        &t_tz::to_local
+    );
+}
+
+at_tz::at_tz() :
+    exprtk::igeneric_function<t_tscalar>("TS"),
+    m_timezone_valid(false) {}
+
+at_tz::~at_tz() = default;
+
+const t_tz*
+at_tz::get_timezone(const std::string& name) {
+    return cached_timezone(
+        name, m_timezone_name, m_timezone, m_timezone_valid
+    );
+}
+
+t_tscalar
+at_tz::operator()(t_parameter_list parameters) {
+    t_scalar_view temp(parameters[0]);
+    t_string_view name(parameters[1]);
+    return convert_timezone(
+        temp(),
+        get_timezone(std::string(name.begin(), name.end())),
+        &t_tz::to_utc
+    );
+}
+
 index::index(
     const t_gstate::t_mapping& pkey_map,
     std::shared_ptr<t_data_table> source_table,
@@ -2329,6 +3865,207 @@ vlookup::operator()(t_parameter_list parameters) {
     return rval;
 }
 
+group_stat::group_stat(
+    t_group_stat stat,
+    bool is_type_validator,
+    std::shared_ptr<t_data_table> source_table,
+    std::shared_ptr<t_data_table> master,
+    const t_pkey_mapping& pkey_map,
+    t_uindex& row_idx
+) :
+    m_stat(stat),
+    m_is_type_validator(is_type_validator),
+    m_source_table(std::move(source_table)),
+    m_master(std::move(master)),
+    m_pkey_map(pkey_map),
+    m_row_idx(row_idx) {}
+
+group_stat::~group_stat() = default;
+
+// Null group values form a group of their own, distinct from any string.
+static std::string
+group_key(const t_tscalar& val) {
+    if (!val.is_valid() || val.is_none()) {
+        return "n";
+    }
+
+    return "v" + val.to_string();
+}
+
+const group_stat::t_groups&
+group_stat::get_groups(
+    const std::string& value_column, const std::string& group_column
+) {
+    auto key = std::make_pair(value_column, group_column);
+    auto it = m_groups.find(key);
+    if (it != m_groups.end()) {
+        return it->second;
+    }
+
+    t_groups& groups = m_groups[key];
+    auto values = m_master->get_const_column(value_column);
+    std::shared_ptr<const t_column> keys;
+    if (!group_column.empty()) {
+        keys = m_master->get_const_column(group_column);
+    }
+
+    // Only rows in the primary key mapping are live; the master table keeps
+    // the slots of removed rows for reuse.
+    for (const auto& [pkey, ridx] : m_pkey_map) {
+        if (ridx >= values->size()) {
+            continue;
+        }
+
+        t_tscalar val = values->get_scalar(ridx);
+        if (!val.is_valid() || val.is_none()) {
+            continue;
+        }
+
+        std::string gkey = keys ? group_key(keys->get_scalar(ridx)) : "";
+        auto& group = groups[gkey];
+        group.m_sorted.push_back(val.to_double());
+    }
+
+    for (auto it = groups.begin(); it != groups.end(); ++it) {
+        t_group& group = it.value();
+        std::sort(group.m_sorted.begin(), group.m_sorted.end());
+        group.m_sum = std::accumulate(
+            group.m_sorted.begin(), group.m_sorted.end(), 0.0
+        );
+
+        // Population standard deviation.
+        double mean = group.m_sum / group.m_sorted.size();
+        double squares = 0;
+        for (double x : group.m_sorted) {
+            squares += (x - mean) * (x - mean);
+        }
+
+        group.m_stddev = std::sqrt(squares / group.m_sorted.size());
+    }
+
+    return groups;
+}
+
+void
+group_stat::clear_groups() {
+    m_groups.clear();
+}
+
+t_tscalar
+group_stat::operator()(t_parameter_list parameters) {
+    t_tscalar rval;
+    rval.clear();
+    rval.m_type = DTYPE_FLOAT64;
+
+    if (m_stat == GROUP_STAT_RANK) {
+        // Use 32-bit integers for WASM
+#if defined PSP_ENABLE_WASM && !defined(PSP_ENABLE_PYTHON)
+        rval.m_type = DTYPE_INT32;
+#else
+        rval.m_type = DTYPE_INT64;
+#endif
+    }
+
+    // zscore takes an optional group column, percent_of_total none, and the
+    // others exactly one.
+    std::size_t min_params = 2;
+    std::size_t max_params = 2;
+    if (m_stat == GROUP_STAT_ZSCORE) {
+        min_params = 1;
+    } else if (m_stat == GROUP_STAT_PERCENT_OF_TOTAL) {
+        min_params = 1;
+        max_params = 1;
+    }
+    if (parameters.size() < min_params || parameters.size() > max_params) {
+        rval.m_status = STATUS_CLEAR;
+        return rval;
+    }
+
+    std::string names[2];
+    const t_schema& schema = m_source_table->get_schema();
+    for (t_uindex idx = 0; idx < parameters.size(); ++idx) {
+        if (parameters[idx].type != t_generic_type::e_scalar) {
+            rval.m_status = STATUS_CLEAR;
+            return rval;
+        }
+
+        t_scalar_view view(parameters[idx]);
+        t_tscalar name = view();
+        if (name.get_dtype() != DTYPE_STR || !name.is_valid()) {
+            rval.m_status = STATUS_CLEAR;
+            return rval;
+        }
+
+        names[idx] = name.to_string();
+        if (!schema.has_column(names[idx])) {
+            rval.m_status = STATUS_CLEAR;
+            return rval;
+        }
+    }
+
+    t_dtype value_dtype = schema.get_dtype(names[0]);
+    if (!is_numeric_type(value_dtype)) {
+        rval.m_status = STATUS_CLEAR;
+        return rval;
+    }
+
+    if (m_is_type_validator) {
+        return rval;
+    }
+
+    t_tscalar val =
+        m_source_table->get_const_column(names[0])->get_scalar(m_row_idx);
+
+    if (!val.is_valid() || val.is_none()) {
+        return rval;
+    }
+
+    std::string gkey;
+    if (!names[1].empty()) {
+        gkey = group_key(
+            m_source_table->get_const_column(names[1])->get_scalar(m_row_idx)
+        );
+    }
+
+    const t_groups& groups = get_groups(names[0], names[1]);
+    auto it = groups.find(gkey);
+    if (it == groups.end()) {
+        return rval;
+    }
+
+    const t_group& group = it->second;
+    const auto& sorted = group.m_sorted;
+    double x = val.to_double();
+    switch (m_stat) {
+        case GROUP_STAT_ZSCORE: {
+            if (group.m_stddev > 0) {
+                double mean = group.m_sum / sorted.size();
+                rval.set((x - mean) / group.m_stddev);
+            }
+        } break;
+        case GROUP_STAT_PERCENT_OF_GROUP:
+        case GROUP_STAT_PERCENT_OF_TOTAL: {
+            if (group.m_sum != 0) {
+                rval.set(x / group.m_sum * 100);
+            }
+        } break;
+        case GROUP_STAT_RANK: {
+            auto greater = std::distance(
+                std::upper_bound(sorted.begin(), sorted.end(), x), sorted.end()
+            );
+
+            std::int64_t rank = greater + 1;
+#if defined PSP_ENABLE_WASM && !defined(PSP_ENABLE_PYTHON)
+            rval.set(static_cast<std::int32_t>(rank));
+#else
+            rval.set(rank);
+#endif
+        } break;
+    }
+
+    return rval;
+}
+
 // Set up random number generator
 std::default_random_engine random::RANDOM_ENGINE = std::default_random_engine();
 std::uniform_real_distribution<double> random::DISTRIBUTION =
@@ -227,6 +227,8 @@ t_config::setup(
             case AGGTYPE_DISTINCT_LEAF:
             case AGGTYPE_VARIANCE:
             case AGGTYPE_STANDARD_DEVIATION:
+            case AGGTYPE_TOTAL_BYTES:
+            case AGGTYPE_MAX_BYTES:
                 m_has_pkey_agg = true;
                 break;
             default:
@@ -322,6 +324,119 @@ t_config::get_totals_string() const {
     }
 }
 
+void
+t_config::set_category_order(
+    const std::string& colname, const std::vector<std::string>& categories
+) {
+    auto& ranks = m_category_ranks[colname];
+    ranks.clear();
+    for (const auto& category : categories) {
+        ranks.emplace(category, ranks.size());
+    }
+}
+
+const std::map<std::string, t_category_ranks>&
+t_config::get_category_ranks() const {
+    return m_category_ranks;
+}
+
+t_tscalar
+t_config::get_category_sort_value(
+    const std::string& colname, const t_tscalar& value
+) const {
+    auto iter = m_category_ranks.find(colname);
+    if (iter == m_category_ranks.end()) {
+        return value;
+    }
+
+    return category_rank(iter->second, value);
+}
+
+t_tscalar
+category_rank(const t_category_ranks& ranks, const t_tscalar& value) {
+    t_tscalar rval;
+    if (!value.is_valid() || value.get_dtype() != DTYPE_STR) {
+        rval.set(std::int64_t(ranks.size()));
+        rval.m_status = value.m_status;
+        return rval;
+    }
+
+    auto iter = ranks.find(value.get_char_ptr());
+    rval.set(std::int64_t(iter == ranks.end() ? ranks.size() : iter->second));
+
+    return rval;
+}
+
+void
+t_config::set_exclude_null_groups(bool exclude) {
+    m_null_group_fterms.clear();
+    if (!exclude) {
+        return;
+    }
+
+    for (const auto& pivot : get_pivots()) {
+        m_null_group_fterms.emplace_back(
+            pivot.colname(),
+            FILTER_OP_IS_NOT_NULL,
+            mknone(),
+            std::vector<t_tscalar>{}
+        );
+    }
+}
+
+const std::vector<t_fterm>&
+t_config::get_null_group_fterms() const {
+    return m_null_group_fterms;
+}
+
+void
+t_config::set_null_aggregates(t_null_aggregates null_aggregates) {
+    m_null_aggregates = null_aggregates;
+}
+
+t_null_aggregates
+t_config::get_null_aggregates() const {
+    return m_null_aggregates;
+}
+
+void
+t_config::set_row_totals(t_totals grand_total, t_totals subtotals) {
+    m_row_grand_total = grand_total;
+    m_row_subtotals = subtotals;
+}
+
+t_totals
+t_config::get_row_grand_total() const {
+    return m_row_grand_total;
+}
+
+t_totals
+t_config::get_row_subtotals() const {
+    return m_row_subtotals;
+}
+
+void
+t_config::set_show_values_as(
+    const std::map<std::string, t_show_values_as>& show_values_as
+) {
+    m_show_values_as.clear();
+    for (const auto& aggspec : m_aggregates) {
+        auto iter = show_values_as.find(aggspec.name());
+        m_show_values_as.push_back(
+            iter == show_values_as.end() ? SHOW_VALUES_AS_VALUE : iter->second
+        );
+    }
+}
+
+t_show_values_as
+t_config::get_show_values_as(t_uindex aggidx) const {
+    if (aggidx >= m_show_values_as.size()) {
+        return SHOW_VALUES_AS_VALUE;
+    }
+
+    return m_show_values_as[aggidx];
+}
+
 std::string
 t_config::get_sort_by(const std::string& pivot) const {
     std::string rval;
@@ -401,7 +516,7 @@ bool
 t_config::has_filters() const {
     switch (m_fmode) {
         case FMODE_SIMPLE_CLAUSES: {
-            return !m_fterms.empty();
+            return !m_fterms.empty() || !m_null_group_fterms.empty();
         } break;
         default: {
             return false;
@@ -415,6 +530,13 @@ t_config::get_fterms() const {
     return m_fterms;
 }
 
+void
+t_config::set_filter(const std::vector<t_fterm>& fterms, t_filter_op combiner) {
+    m_fterms = fterms;
+    m_combiner = combiner;
+    m_is_trivial_config = m_is_trivial_config && m_fterms.empty();
+}
+
 std::vector<std::shared_ptr<t_computed_expression>>
 t_config::get_expressions() const {
     return m_expressions;
@@ -773,23 +773,27 @@ t_ctx_grouped_pkey::compute_expressions(
     m_expression_tables->m_master->set_size(master_num_rows);
 
     const auto& expressions = m_config.get_expressions();
-    for (const auto& expr : expressions) {
-        // master: compute based on latest state of the gnode state table
-        expr->compute(
-            master,
-            pkey_map,
-            m_expression_tables->m_master,
-            expression_vocab,
-            regex_mapping
-        );
 
+    // master: compute based on latest state of the gnode state table
+    compute_master_expressions(
+        expressions,
+        master,
+        flattened,
+        pkey_map,
+        m_expression_tables->m_master,
+        expression_vocab,
+        regex_mapping
+    );
+
+    for (const auto& expr : expressions) {
         // flattened: compute based on the latest update dataset
         expr->compute(
             flattened,
             pkey_map,
             m_expression_tables->m_flattened,
             expression_vocab,
-            regex_mapping
+            regex_mapping,
+            master
         );
 
         // delta: for each numerical column, the numerical delta between the
@@ -799,7 +803,8 @@ t_ctx_grouped_pkey::compute_expressions(
             pkey_map,
             m_expression_tables->m_delta,
             expression_vocab,
-            regex_mapping
+            regex_mapping,
+            master
         );
 
         // prev: the values of the updated rows before this update was applied
@@ -808,7 +813,8 @@ t_ctx_grouped_pkey::compute_expressions(
             pkey_map,
             m_expression_tables->m_prev,
             expression_vocab,
-            regex_mapping
+            regex_mapping,
+            master
         );
 
         // current: the current values of the updated rows
@@ -817,7 +823,8 @@ t_ctx_grouped_pkey::compute_expressions(
             pkey_map,
             m_expression_tables->m_current,
             expression_vocab,
-            regex_mapping
+            regex_mapping,
+            master
         );
     }
 
@@ -31,7 +31,8 @@ namespace perspective {
 t_ctx1::t_ctx1(const t_schema& schema, const t_config& pivot_config) :
     t_ctxbase<t_ctx1>(schema, pivot_config),
     m_depth(0),
-    m_depth_set(false) {}
+    m_depth_set(false),
+    m_has_row_order(false) {}
 
 t_ctx1::~t_ctx1() = default;
 
@@ -51,12 +52,17 @@ t_ctx1::init() {
     m_expression_tables = std::make_shared<t_expression_tables>(expressions);
 
     m_init = true;
+    update_row_order();
 }
 
 t_index
 t_ctx1::get_row_count() const {
     PSP_TRACE_SENTINEL();
     PSP_VERBOSE_ASSERT(m_init, "touching uninited object");
+    if (m_has_row_order) {
+        return m_row_order.size();
+    }
+
     return m_traversal->size();
 }
 
@@ -89,12 +95,13 @@ t_ctx1::open(t_index idx) {
     m_depth_set = false;
     m_depth = 0;
 
-    if (idx >= t_index(m_traversal->size())) {
+    if (idx >= get_row_count()) {
         return 0;
     }
 
-    t_index retval = m_traversal->expand_node(m_sortby, idx);
+    t_index retval = m_traversal->expand_node(m_sortby, to_traversal_idx(idx));
     m_rows_changed = (retval > 0);
+    update_row_order();
     return retval;
 }
 
@@ -106,12 +113,13 @@ t_ctx1::close(t_index idx) {
     m_depth_set = false;
     m_depth = 0;
 
-    if (idx >= t_index(m_traversal->size())) {
+    if (idx >= get_row_count()) {
         return 0;
     }
 
-    t_index retval = m_traversal->collapse_node(idx);
+    t_index retval = m_traversal->collapse_node(to_traversal_idx(idx));
     m_rows_changed = (retval > 0);
+    update_row_order();
     return retval;
 }
 
@@ -126,8 +134,8 @@ t_ctx1::get_min_max(const std::string& colname) const {
     const std::vector<t_aggspec>& aggspecs = m_config.get_aggregates();
     bool is_finished = false;
     while (!is_finished && depth > 0) {
-        for (std::size_t i = 0; i < m_traversal->size(); i++) {
-            t_index nidx = m_traversal->get_tree_index(i);
+        for (t_index i = 0; i < get_row_count(); i++) {
+            t_index nidx = m_traversal->get_tree_index(to_traversal_idx(i));
             t_index pnidx = m_tree->get_parent_idx(nidx);
             if (m_tree->get_depth(nidx) != depth) {
                 continue;
@@ -193,7 +201,7 @@ t_ctx1::get_data(
     const std::vector<t_aggspec>& aggspecs = m_config.get_aggregates();
 
     for (t_index ridx = ext.m_srow; ridx < ext.m_erow; ++ridx) {
-        t_index nidx = m_traversal->get_tree_index(ridx);
+        t_index nidx = m_traversal->get_tree_index(to_traversal_idx(ridx));
         t_index pnidx = m_tree->get_parent_idx(nidx);
 
         t_uindex agg_ridx = m_tree->get_aggidx(nidx);
@@ -205,8 +213,11 @@ t_ctx1::get_data(
 
         for (t_index aggidx = 0, loop_end = aggcols.size(); aggidx < loop_end;
              ++aggidx) {
-            t_tscalar value = extract_aggregate(
-                aggspecs[aggidx], aggcols[aggidx], agg_ridx, agg_pridx
+            t_tscalar value = show_value_as(
+                aggidx,
+                extract_aggregate(
+                    aggspecs[aggidx], aggcols[aggidx], agg_ridx, agg_pridx
+                )
             );
             if (!value.is_valid()) {
                 value.set(none); // todo: fix null handling
@@ -253,7 +264,7 @@ t_ctx1::get_data(const std::vector<t_uindex>& rows) const {
     // start from 0
     for (t_uindex idx = 0; idx < nrows; ++idx) {
         t_uindex ridx = rows[idx];
-        t_index nidx = m_traversal->get_tree_index(ridx);
+        t_index nidx = m_traversal->get_tree_index(to_traversal_idx(ridx));
         t_index pnidx = m_tree->get_parent_idx(nidx);
 
         t_uindex agg_ridx = m_tree->get_aggidx(nidx);
@@ -265,8 +276,11 @@ t_ctx1::get_data(const std::vector<t_uindex>& rows) const {
 
         for (t_index aggidx = 0, loop_end = aggcols.size(); aggidx < loop_end;
              ++aggidx) {
-            t_tscalar value = extract_aggregate(
-                aggspecs[aggidx], aggcols[aggidx], agg_ridx, agg_pridx
+            t_tscalar value = show_value_as(
+                aggidx,
+                extract_aggregate(
+                    aggspecs[aggidx], aggcols[aggidx], agg_ridx, agg_pridx
+                )
             );
             if (!value.is_valid()) {
                 value.set(none); // todo: fix null handling
@@ -302,6 +316,8 @@ t_ctx1::notify(const t_data_table& flattened) {
         *m_gstate,
         *(m_expression_tables->m_master)
     );
+
+    update_row_order();
 }
 
 void
@@ -333,6 +349,8 @@ t_ctx1::notify(
         *m_gstate,
         *(m_expression_tables->m_master)
     );
+
+    update_row_order();
 }
 
 void
@@ -388,7 +406,7 @@ t_ctx1::get_row_path(t_index idx) const {
     if (idx < 0) {
         return {};
     }
-    return ctx_get_path(m_tree, m_traversal, idx);
+    return ctx_get_path(m_tree, m_traversal, to_traversal_idx(idx));
 }
 
 void
@@ -403,10 +421,11 @@ t_ctx1::sort_by(const std::vector<t_sortspec>& sortby) {
     PSP_TRACE_SENTINEL();
     PSP_VERBOSE_ASSERT(m_init, "touching uninited object");
     m_sortby = sortby;
-    if (m_sortby.empty()) {
-        return;
+    if (!m_sortby.empty()) {
+        m_traversal->sort_by(m_config, sortby, *(m_tree));
     }
-    m_traversal->sort_by(m_config, sortby, *(m_tree));
+
+    update_row_order();
 }
 
 void
@@ -422,6 +441,7 @@ t_ctx1::set_depth(t_depth depth) {
     m_rows_changed = (retval > 0);
     m_depth = depth;
     m_depth_set = true;
+    update_row_order();
 }
 
 std::vector<t_tscalar>
@@ -430,14 +450,19 @@ t_ctx1::get_pkeys(const std::vector<std::pair<t_uindex, t_uindex>>& cells
     PSP_TRACE_SENTINEL();
     PSP_VERBOSE_ASSERT(m_init, "touching uninited object");
 
-    if (!m_traversal->validate_cells(cells)) {
+    std::vector<std::pair<t_uindex, t_uindex>> tcells;
+    tcells.reserve(cells.size());
+    for (const auto& c : cells) {
+        tcells.emplace_back(to_traversal_idx(c.first), c.second);
+    }
+
+    if (!m_traversal->validate_cells(tcells)) {
         std::vector<t_tscalar> rval;
         return rval;
     }
 
     std::vector<t_tscalar> rval;
-    std::vector<t_index> tindices(cells.size());
-    for (const auto& c : cells) {
+    for (const auto& c : tcells) {
         auto ptidx = m_traversal->get_tree_index(c.first);
         auto pkeys = m_tree->get_pkeys(ptidx);
 
@@ -479,8 +504,8 @@ t_stepdelta
 t_ctx1::get_step_delta(t_index bidx, t_index eidx) {
     PSP_TRACE_SENTINEL();
     PSP_VERBOSE_ASSERT(m_init, "touching uninited object");
-    bidx = std::min(bidx, t_index(m_traversal->size()));
-    eidx = std::min(eidx, t_index(m_traversal->size()));
+    bidx = std::min(bidx, get_row_count());
+    eidx = std::min(eidx, get_row_count());
 
     t_stepdelta rval(
         m_rows_changed, m_columns_changed, get_cell_delta(bidx, eidx)
@@ -511,10 +536,10 @@ std::vector<t_uindex>
 t_ctx1::get_rows_changed() {
     std::vector<t_uindex> rows;
     const auto& deltas = m_tree->get_deltas();
-    auto eidx = t_uindex(m_traversal->size());
+    auto eidx = t_uindex(get_row_count());
 
     for (t_uindex idx = 0; idx < eidx; ++idx) {
-        t_index ptidx = m_traversal->get_tree_index(idx);
+        t_index ptidx = m_traversal->get_tree_index(to_traversal_idx(idx));
         // Retrieve delta from storage and check if the row has been changed
         auto iterators = deltas->get<by_tc_nidx_aggidx>().equal_range(ptidx);
         bool unique_ridx =
@@ -532,11 +557,11 @@ std::vector<t_cellupd>
 t_ctx1::get_cell_delta(t_index bidx, t_index eidx) const {
     PSP_TRACE_SENTINEL();
     PSP_VERBOSE_ASSERT(m_init, "touching uninited object");
-    eidx = std::min(eidx, t_index(m_traversal->size()));
+    eidx = std::min(eidx, get_row_count());
     std::vector<t_cellupd> rval;
     const auto& deltas = m_tree->get_deltas();
     for (t_index idx = bidx; idx < eidx; ++idx) {
-        t_index ptidx = m_traversal->get_tree_index(idx);
+        t_index ptidx = m_traversal->get_tree_index(to_traversal_idx(idx));
         auto iterators = deltas->get<by_tc_nidx_aggidx>().equal_range(ptidx);
         for (auto iter = iterators.first; iter != iterators.second; ++iter) {
             rval.emplace_back(
@@ -556,6 +581,7 @@ t_ctx1::reset(bool reset_expressions) {
     m_tree->init();
     m_tree->set_deltas_enabled(get_feature_state(CTX_FEAT_DELTA));
     m_traversal = std::make_shared<t_traversal>(m_tree);
+    update_row_order();
 
     if (reset_expressions) {
         m_expression_tables->reset();
@@ -613,7 +639,7 @@ t_ctx1::pprint() const {
     const std::vector<t_aggspec>& aggspecs = m_config.get_aggregates();
 
     for (auto ridx = 0; ridx < get_row_count(); ++ridx) {
-        t_index nidx = m_traversal->get_tree_index(ridx);
+        t_index nidx = m_traversal->get_tree_index(to_traversal_idx(ridx));
         t_index pnidx = m_tree->get_parent_idx(nidx);
 
         t_uindex agg_ridx = m_tree->get_aggidx(nidx);
@@ -646,7 +672,7 @@ t_ctx1::get_row_idx(const std::vector<t_tscalar>& path) const {
         return nidx;
     }
 
-    return m_traversal->get_traversal_index(nidx);
+    return from_traversal_idx(m_traversal->get_traversal_index(nidx));
 }
 
 t_dtype
@@ -654,12 +680,91 @@ t_ctx1::get_column_dtype(t_uindex idx) const {
     if (idx == 0 || idx >= static_cast<t_uindex>(get_column_count())) {
         return DTYPE_NONE;
     }
+    if (show_values_as_is_percent(m_config.get_show_values_as(idx - 1))) {
+        return DTYPE_FLOAT64;
+    }
     return m_tree->get_aggtable()->get_const_column(idx - 1)->get_dtype();
 }
 
+t_tscalar
+t_ctx1::show_value_as(t_uindex aggidx, const t_tscalar& value) const {
+    auto show_values_as = m_config.get_show_values_as(aggidx);
+    if (show_values_as == SHOW_VALUES_AS_VALUE) {
+        return value;
+    }
+
+    // Without `split_by` columns, a row's total is its own value and every
+    // other total is the grand total.
+    t_tscalar total = value;
+    if (show_values_as != SHOW_VALUES_AS_PCT_ROW_TOTAL) {
+        const auto* aggcol =
+            m_tree->get_aggtable()->get_const_column(aggidx).get();
+        total = extract_aggregate(
+            m_config.get_aggregates()[aggidx],
+            aggcol,
+            m_tree->get_aggidx(0),
+            INVALID_INDEX
+        );
+    }
+
+    return ctx_show_value_as(show_values_as, value, total, {});
+}
+
 t_depth
 t_ctx1::get_trav_depth(t_index idx) const {
-    return m_traversal->get_depth(idx);
+    return m_traversal->get_depth(to_traversal_idx(idx));
+}
+
+void
+t_ctx1::update_row_order() {
+    m_has_row_order = m_config.get_num_rpivots() > 0
+        && (m_config.get_row_grand_total() != TOTALS_BEFORE
+            || m_config.get_row_subtotals() != TOTALS_BEFORE);
+
+    m_row_order.clear();
+    m_row_order_inverse.clear();
+    if (!m_has_row_order) {
+        return;
+    }
+
+    m_row_order = ctx_get_row_order(
+        *m_traversal,
+        m_config.get_row_grand_total(),
+        m_config.get_row_subtotals()
+    );
+
+    m_row_order_inverse.resize(m_traversal->size(), INVALID_INDEX);
+    for (t_index ridx = 0, loop_end = m_row_order.size(); ridx < loop_end;
+         ++ridx) {
+        m_row_order_inverse[m_row_order[ridx]] = ridx;
+    }
+}
+
+t_index
+t_ctx1::to_traversal_idx(t_index idx) const {
+    if (!m_has_row_order) {
+        return idx;
+    }
+
+    // Rows past the end stay past the end of the traversal.
+    if (idx < 0 || idx >= t_index(m_row_order.size())) {
+        return m_traversal->size();
+    }
+
+    return m_row_order[idx];
+}
+
+t_index
+t_ctx1::from_traversal_idx(t_index idx) const {
+    if (!m_has_row_order) {
+        return idx;
+    }
+
+    if (idx < 0 || idx >= t_index(m_row_order_inverse.size())) {
+        return INVALID_INDEX;
+    }
+
+    return m_row_order_inverse[idx];
 }
 
 void
@@ -721,23 +826,27 @@ t_ctx1::compute_expressions(
     m_expression_tables->m_master->set_size(master_num_rows);
 
     const auto& expressions = m_config.get_expressions();
-    for (const auto& expr : expressions) {
-        // master: compute based on latest state of the gnode state table
-        expr->compute(
-            master,
-            pkey_map,
-            m_expression_tables->m_master,
-            expression_vocab,
-            regex_mapping
-        );
 
+    // master: compute based on latest state of the gnode state table
+    compute_master_expressions(
+        expressions,
+        master,
+        flattened,
+        pkey_map,
+        m_expression_tables->m_master,
+        expression_vocab,
+        regex_mapping
+    );
+
+    for (const auto& expr : expressions) {
         // flattened: compute based on the latest update dataset
         expr->compute(
             flattened,
             pkey_map,
             m_expression_tables->m_flattened,
             expression_vocab,
-            regex_mapping
+            regex_mapping,
+            master
         );
 
         // delta: for each numerical column, the numerical delta between the
@@ -747,7 +856,8 @@ t_ctx1::compute_expressions(
             pkey_map,
             m_expression_tables->m_delta,
             expression_vocab,
-            regex_mapping
+            regex_mapping,
+            master
         );
 
         // prev: the values of the updated rows before this update was applied
@@ -756,7 +866,8 @@ t_ctx1::compute_expressions(
             pkey_map,
             m_expression_tables->m_prev,
             expression_vocab,
-            regex_mapping
+            regex_mapping,
+            master
         );
 
         // current: the current values of the updated rows
@@ -765,7 +876,8 @@ t_ctx1::compute_expressions(
             pkey_map,
             m_expression_tables->m_current,
             expression_vocab,
-            regex_mapping
+            regex_mapping,
+            master
         );
     }
 
@@ -818,7 +930,7 @@ t_ctx1::unity_get_column_path(t_uindex idx) const {
 
 t_uindex
 t_ctx1::unity_get_row_depth(t_uindex ridx) const {
-    return m_traversal->get_depth(ridx);
+    return m_traversal->get_depth(to_traversal_idx(ridx));
 }
 
 t_uindex
@@ -870,7 +982,7 @@ t_ctx1::unity_get_row_count() const {
 
 bool
 t_ctx1::unity_get_row_expanded(t_uindex idx) const {
-    return m_traversal->get_node_expanded(idx);
+    return m_traversal->get_node_expanded(to_traversal_idx(idx));
 }
 
 bool
@@ -27,14 +27,16 @@ t_ctx2::t_ctx2() :
     m_row_depth(0),
     m_row_depth_set(false),
     m_column_depth(0),
-    m_column_depth_set(false) {}
+    m_column_depth_set(false),
+    m_has_row_order(false) {}
 
 t_ctx2::t_ctx2(const t_schema& schema, const t_config& pivot_config) :
     t_ctxbase<t_ctx2>(schema, pivot_config),
     m_row_depth(0),
     m_row_depth_set(false),
     m_column_depth(0),
-    m_column_depth_set(false) {}
+    m_column_depth_set(false),
+    m_has_row_order(false) {}
 
 t_ctx2::~t_ctx2() = default;
 
@@ -90,6 +92,7 @@ t_ctx2::init() {
     m_expression_tables = std::make_shared<t_expression_tables>(expressions);
 
     m_init = true;
+    update_row_order();
 }
 
 t_uindex
@@ -120,6 +123,10 @@ t_ctx2::step_end() {
 
 t_index
 t_ctx2::get_row_count() const {
+    if (m_has_row_order) {
+        return m_row_order.size();
+    }
+
     return m_rtraversal->size();
 }
 
@@ -133,17 +140,19 @@ t_ctx2::open(t_header header, t_index idx) {
     t_index retval;
 
     if (header == HEADER_ROW) {
-        if (!m_rtraversal->is_valid_idx(idx)) {
+        t_index tvidx = to_traversal_idx(idx);
+        if (!m_rtraversal->is_valid_idx(tvidx)) {
             return 0;
         }
         m_row_depth_set = false;
         m_row_depth = 0;
         if (m_sortby.empty()) {
-            retval = m_rtraversal->expand_node(idx);
+            retval = m_rtraversal->expand_node(tvidx);
         } else {
-            retval = m_rtraversal->expand_node(m_sortby, idx);
+            retval = m_rtraversal->expand_node(m_sortby, tvidx);
         }
         m_rows_changed = (retval > 0);
+        update_row_order();
     } else {
         if (!m_ctraversal->is_valid_idx(idx)) {
             return 0;
@@ -163,13 +172,15 @@ t_ctx2::close(t_header header, t_index idx) {
 
     switch (header) {
         case HEADER_ROW: {
-            if (!m_rtraversal->is_valid_idx(idx)) {
+            t_index tvidx = to_traversal_idx(idx);
+            if (!m_rtraversal->is_valid_idx(tvidx)) {
                 return 0;
             }
             m_row_depth_set = false;
             m_row_depth = 0;
-            retval = m_rtraversal->collapse_node(idx);
+            retval = m_rtraversal->collapse_node(tvidx);
             m_rows_changed = (retval > 0);
+            update_row_order();
         } break;
         case HEADER_COLUMN: {
             if (!m_ctraversal->is_valid_idx(idx)) {
@@ -267,7 +278,8 @@ t_ctx2::get_min_max(const std::string& colname) const {
             if (cinfo.m_idx < 0 || cinfo.m_agg_index != scol) {
                 continue;
             }
-            t_index nidx = m_rtraversal->get_tree_index(cinfo.m_ridx);
+            t_index nidx =
+                m_rtraversal->get_tree_index(to_traversal_idx(cinfo.m_ridx));
             if (rtree()->get_depth(nidx) != row_depth) {
                 continue;
             }
@@ -331,6 +343,7 @@ t_ctx2::get_data(
     }
 
     auto cells_info = resolve_cells(cells);
+    std::vector<t_index> c_tvindices = get_ctraversal_indices();
 
     t_index nrows = ext.m_erow - ext.m_srow;
     t_index stride = ext.m_ecol - ext.m_scol;
@@ -361,8 +374,9 @@ t_ctx2::get_data(
 
     for (t_index ridx = ext.m_srow; ridx < ext.m_erow; ++ridx) {
         if (ext.m_scol == 0) {
+            t_index tvidx = to_traversal_idx(ridx);
             retval[(ridx - ext.m_srow) * stride].set(
-                rtree()->get_value(m_rtraversal->get_tree_index(ridx))
+                rtree()->get_value(m_rtraversal->get_tree_index(tvidx))
             );
         }
 
@@ -388,8 +402,12 @@ t_ctx2::get_data(
                     ? INVALID_INDEX
                     : m_trees[cinfo.m_treenum]->get_aggidx(p_idx);
 
-                auto value = extract_aggregate(
-                    aggspecs[cinfo.m_agg_index], aggcol, agg_ridx, agg_pridx
+                auto value = show_value_as(
+                    cinfo,
+                    extract_aggregate(
+                        aggspecs[cinfo.m_agg_index], aggcol, agg_ridx, agg_pridx
+                    ),
+                    c_tvindices
                 );
 
                 if (!value.is_valid()) {
@@ -439,6 +457,7 @@ t_ctx2::get_data(const std::vector<t_uindex>& rows) const {
     }
 
     auto cells_info = resolve_cells(cells);
+    std::vector<t_index> c_tvindices = get_ctraversal_indices();
     std::vector<t_tscalar> rval(nrows * ncols);
 
     t_tscalar empty = mknone();
@@ -488,8 +507,12 @@ t_ctx2::get_data(const std::vector<t_uindex>& rows) const {
                     ? INVALID_INDEX
                     : m_trees[cinfo.m_treenum]->get_aggidx(p_idx);
 
-                auto value = extract_aggregate(
-                    aggspecs[cinfo.m_agg_index], aggcol, agg_ridx, agg_pridx
+                auto value = show_value_as(
+                    cinfo,
+                    extract_aggregate(
+                        aggspecs[cinfo.m_agg_index], aggcol, agg_ridx, agg_pridx
+                    ),
+                    c_tvindices
                 );
 
                 if (!value.is_valid()) {
@@ -516,10 +539,11 @@ t_ctx2::sort_by(const std::vector<t_sortspec>& sortby) {
     PSP_TRACE_SENTINEL();
     PSP_VERBOSE_ASSERT(m_init, "touching uninited object");
     m_sortby = sortby;
-    if (m_sortby.empty()) {
-        return;
+    if (!m_sortby.empty()) {
+        m_rtraversal->sort_by(m_config, sortby, *(rtree()), this);
     }
-    m_rtraversal->sort_by(m_config, sortby, *(rtree()), this);
+
+    update_row_order();
 }
 
 void
@@ -577,6 +601,8 @@ t_ctx2::notify(const t_data_table& flattened) {
     if (!m_sortby.empty()) {
         sort_by(m_sortby);
     }
+
+    update_row_order();
 }
 
 void
@@ -651,6 +677,8 @@ t_ctx2::notify(
     if (!m_sortby.empty()) {
         sort_by(m_sortby);
     }
+
+    update_row_order();
 }
 
 t_uindex
@@ -672,6 +700,105 @@ t_ctx2::calc_translated_colidx(t_uindex n_aggs, t_uindex cidx) const {
     }
 }
 
+t_uindex
+t_ctx2::calc_view_colidx(
+    t_uindex n_aggs, t_uindex translated_cidx, t_uindex agg_idx
+) const {
+    if (m_config.get_totals() == TOTALS_HIDDEN) {
+        return (translated_cidx - 1) * n_aggs + agg_idx + 1;
+    }
+
+    return translated_cidx * n_aggs + agg_idx + 1;
+}
+
+t_tscalar
+t_ctx2::get_tree_aggregate(
+    t_uindex treenum, t_index nidx, t_index agg_idx
+) const {
+    const auto& tree = m_trees[treenum];
+    const auto* aggcol = tree->get_aggtable()->get_const_column(agg_idx).get();
+    t_index p_idx = tree->get_parent_idx(nidx);
+    t_index agg_pridx =
+        p_idx == INVALID_INDEX ? INVALID_INDEX : tree->get_aggidx(p_idx);
+
+    return extract_aggregate(
+        m_config.get_aggregates()[agg_idx],
+        aggcol,
+        tree->get_aggidx(nidx),
+        agg_pridx
+    );
+}
+
+t_tscalar
+t_ctx2::show_value_as(
+    const t_cellinfo& cinfo,
+    const t_tscalar& value,
+    const std::vector<t_index>& c_tvindices
+) const {
+    auto show_values_as = m_config.get_show_values_as(cinfo.m_agg_index);
+    if (show_values_as == SHOW_VALUES_AS_VALUE) {
+        return value;
+    }
+
+    t_index n_aggs = m_config.get_num_aggregates();
+    t_uindex translated_cidx = calc_translated_colidx(n_aggs, cinfo.m_cidx);
+    t_index r_nidx =
+        m_rtraversal->get_tree_index(to_traversal_idx(cinfo.m_ridx));
+    t_index c_nidx =
+        m_ctraversal->get_tree_index(c_tvindices[translated_cidx]);
+
+    // The row tree aggregates each row over every column, and the column
+    // tree aggregates each column over every row.
+    t_uindex r_treenum = m_trees.size() - 1;
+    t_tscalar total = mknone();
+    std::vector<t_tscalar> previous;
+    switch (show_values_as) {
+        case SHOW_VALUES_AS_PCT_ROW_TOTAL: {
+            total = get_tree_aggregate(r_treenum, r_nidx, cinfo.m_agg_index);
+        } break;
+        case SHOW_VALUES_AS_PCT_COLUMN_TOTAL: {
+            total = get_tree_aggregate(0, c_nidx, cinfo.m_agg_index);
+        } break;
+        case SHOW_VALUES_AS_PCT_GRAND_TOTAL: {
+            total = get_tree_aggregate(r_treenum, 0, cinfo.m_agg_index);
+        } break;
+        case SHOW_VALUES_AS_DIFFERENCE_FROM_PREVIOUS:
+        case SHOW_VALUES_AS_RUNNING_TOTAL: {
+            // The columns before this one with the same parent column, i.e.
+            // in the same `split_by` group. The total column has none.
+            t_index c_pnidx = ctree()->get_parent_idx(c_nidx);
+            if (c_pnidx == INVALID_INDEX) {
+                break;
+            }
+
+            std::vector<std::pair<t_uindex, t_uindex>> cells;
+            for (t_uindex tidx = 0; tidx < translated_cidx; ++tidx) {
+                t_index nidx = m_ctraversal->get_tree_index(c_tvindices[tidx]);
+                if (ctree()->get_parent_idx(nidx) == c_pnidx) {
+                    cells.emplace_back(
+                        cinfo.m_ridx,
+                        calc_view_colidx(n_aggs, tidx, cinfo.m_agg_index)
+                    );
+                }
+            }
+
+            for (const auto& prev : resolve_cells(cells)) {
+                previous.push_back(
+                    prev.m_idx < 0
+                        ? mknone()
+                        : get_tree_aggregate(
+                              prev.m_treenum, prev.m_idx, prev.m_agg_index
+                          )
+                );
+            }
+        } break;
+        default:
+            break;
+    }
+
+    return ctx_show_value_as(show_values_as, value, total, previous);
+}
+
 std::vector<t_cellinfo>
 t_ctx2::resolve_cells(const std::vector<std::pair<t_uindex, t_uindex>>& cells
 ) const {
@@ -694,14 +821,15 @@ t_ctx2::resolve_cells(const std::vector<std::pair<t_uindex, t_uindex>>& cells
 
     for (t_index idx = 0, loop_end = cells.size(); idx < loop_end; ++idx) {
         const auto& cell = cells[idx];
+        t_index r_tvidx = to_traversal_idx(cell.first);
 
-        if (cell.first >= m_rtraversal->size() || cell.second == 0
+        if (r_tvidx >= t_index(m_rtraversal->size()) || cell.second == 0
             || cell.second >= ncols) {
             rval[idx].m_idx = INVALID_INDEX;
             continue;
         }
 
-        const t_tvnode& r_tvnode = m_rtraversal->get_node(cell.first);
+        const t_tvnode& r_tvnode = m_rtraversal->get_node(r_tvidx);
 
         t_index r_ptidx = r_tvnode.m_tnid;
         t_depth r_depth = r_tvnode.m_depth;
@@ -729,7 +857,7 @@ t_ctx2::resolve_cells(const std::vector<std::pair<t_uindex, t_uindex>>& cells
 
         rval[idx].m_agg_index = agg_idx;
 
-        if (cell.first == 0) {
+        if (r_tvidx == 0) {
             rval[idx].m_idx = c_ptidx;
             rval[idx].m_treenum = 0;
         } else if (c_path.empty()) {
@@ -818,7 +946,37 @@ t_ctx2::get_row_path(t_index idx) const {
     if (idx < 0) {
         return {};
     }
-    return ctx_get_path(rtree(), m_rtraversal, idx);
+    return ctx_get_path(rtree(), m_rtraversal, to_traversal_idx(idx));
+}
+
+void
+t_ctx2::update_row_order() {
+    m_has_row_order = m_config.get_num_rpivots() > 0
+        && (m_config.get_row_grand_total() != TOTALS_BEFORE
+            || m_config.get_row_subtotals() != TOTALS_BEFORE);
+
+    m_row_order.clear();
+    if (m_has_row_order) {
+        m_row_order = ctx_get_row_order(
+            *m_rtraversal,
+            m_config.get_row_grand_total(),
+            m_config.get_row_subtotals()
+        );
+    }
+}
+
+t_index
+t_ctx2::to_traversal_idx(t_index idx) const {
+    if (!m_has_row_order) {
+        return idx;
+    }
+
+    // Rows past the end stay past the end of the traversal.
+    if (idx < 0 || idx >= t_index(m_row_order.size())) {
+        return m_rtraversal->size();
+    }
+
+    return m_row_order[idx];
 }
 
 std::vector<t_tscalar>
@@ -909,6 +1067,7 @@ t_ctx2::set_depth(t_header header, t_depth depth) {
             m_rtraversal->set_depth(m_sortby, new_depth);
             m_row_depth = new_depth;
             m_row_depth_set = true;
+            update_row_order();
         } break;
         case HEADER_COLUMN: {
             if (m_config.get_num_cpivots() == 0) {
@@ -1080,6 +1239,7 @@ t_ctx2::reset(bool reset_expressions) {
 
     m_rtraversal = std::make_shared<t_traversal>(rtree());
     m_ctraversal = std::make_shared<t_traversal>(ctree());
+    update_row_order();
 
     if (reset_expressions) {
         m_expression_tables->reset();
@@ -1156,10 +1316,12 @@ t_ctx2::get_column_dtype(t_uindex idx) const {
         return DTYPE_NONE;
     }
 
-    return rtree()
-        ->get_aggtable()
-        ->get_const_column((idx - 1) % naggs)
-        ->get_dtype();
+    t_uindex aggidx = (idx - 1) % naggs;
+    if (show_values_as_is_percent(m_config.get_show_values_as(aggidx))) {
+        return DTYPE_FLOAT64;
+    }
+
+    return rtree()->get_aggtable()->get_const_column(aggidx)->get_dtype();
 }
 
 void
@@ -1221,23 +1383,27 @@ t_ctx2::compute_expressions(
     m_expression_tables->m_master->set_size(master_num_rows);
 
     const auto& expressions = m_config.get_expressions();
-    for (const auto& expr : expressions) {
-        // master: compute based on latest state of the gnode state table
-        expr->compute(
-            master,
-            pkey_map,
-            m_expression_tables->m_master,
-            expression_vocab,
-            regex_mapping
-        );
 
+    // master: compute based on latest state of the gnode state table
+    compute_master_expressions(
+        expressions,
+        master,
+        flattened,
+        pkey_map,
+        m_expression_tables->m_master,
+        expression_vocab,
+        regex_mapping
+    );
+
+    for (const auto& expr : expressions) {
         // flattened: compute based on the latest update dataset
         expr->compute(
             flattened,
             pkey_map,
             m_expression_tables->m_flattened,
             expression_vocab,
-            regex_mapping
+            regex_mapping,
+            master
         );
 
         // delta: for each numerical column, the numerical delta between the
@@ -1247,7 +1413,8 @@ t_ctx2::compute_expressions(
             pkey_map,
             m_expression_tables->m_delta,
             expression_vocab,
-            regex_mapping
+            regex_mapping,
+            master
         );
 
         // prev: the values of the updated rows before this update was applied
@@ -1256,7 +1423,8 @@ t_ctx2::compute_expressions(
             pkey_map,
             m_expression_tables->m_prev,
             expression_vocab,
-            regex_mapping
+            regex_mapping,
+            master
         );
 
         // current: the current values of the updated rows
@@ -1265,7 +1433,8 @@ t_ctx2::compute_expressions(
             pkey_map,
             m_expression_tables->m_current,
             expression_vocab,
-            regex_mapping
+            regex_mapping,
+            master
         );
     }
 
@@ -1365,7 +1534,7 @@ t_ctx2::unity_get_row_count() const {
 
 bool
 t_ctx2::unity_get_row_expanded(t_uindex idx) const {
-    return m_rtraversal->get_node_expanded(idx);
+    return m_rtraversal->get_node_expanded(to_traversal_idx(idx));
 }
 
 bool
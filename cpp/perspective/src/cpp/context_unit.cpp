@@ -41,6 +41,7 @@ t_ctxunit::step_begin() {
     }
 
     m_delta_pkeys.clear();
+    m_removed_pkeys.clear();
     m_rows_changed = false;
     m_columns_changed = false;
 }
@@ -90,6 +91,7 @@ t_ctxunit::notify(
             case OP_INSERT: {
             } break;
             case OP_DELETE: {
+                m_removed_pkeys.push_back(pkey);
                 delete_encountered = true;
             } break;
             default: {
@@ -359,6 +361,11 @@ t_ctxunit::get_delta_pkeys() const {
     return m_delta_pkeys;
 }
 
+const std::vector<t_tscalar>&
+t_ctxunit::get_removed_pkeys() const {
+    return m_removed_pkeys;
+}
+
 std::vector<std::string>
 t_ctxunit::get_column_names() const {
     return m_schema.columns();
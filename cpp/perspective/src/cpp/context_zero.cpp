@@ -57,6 +57,7 @@ t_ctx0::step_begin() {
 
     m_deltas = std::make_shared<t_zcdeltas>();
     m_delta_pkeys.clear();
+    m_removed_pkeys.clear();
     m_rows_changed = false;
     m_columns_changed = false;
     m_traversal->step_begin();
@@ -137,6 +138,7 @@ t_ctx0::notify(
                             );
                         } else {
                             m_traversal->delete_row(pkey);
+                            m_removed_pkeys.push_back(pkey);
                         }
                     } else {
                         if (filter_curr) {
@@ -151,6 +153,7 @@ t_ctx0::notify(
                 } break;
                 case OP_DELETE: {
                     m_traversal->delete_row(pkey);
+                    m_removed_pkeys.push_back(pkey);
                     delete_encountered = true;
                 } break;
                 default: {
@@ -195,6 +198,7 @@ t_ctx0::notify(
             } break;
             case OP_DELETE: {
                 m_traversal->delete_row(pkey);
+                m_removed_pkeys.push_back(pkey);
                 delete_encountered = true;
             } break;
             default: {
@@ -474,6 +478,11 @@ t_ctx0::get_delta_pkeys() const {
     return m_delta_pkeys;
 }
 
+const std::vector<t_tscalar>&
+t_ctx0::get_removed_pkeys() const {
+    return m_removed_pkeys;
+}
+
 std::vector<std::string>
 t_ctx0::get_column_names() const {
     return m_config.get_column_names();
@@ -726,23 +735,27 @@ t_ctx0::compute_expressions(
     m_expression_tables->m_master->set_size(master_num_rows);
 
     const auto& expressions = m_config.get_expressions();
-    for (const auto& expr : expressions) {
-        // master: compute based on latest state of the gnode state table
-        expr->compute(
-            master,
-            pkey_map,
-            m_expression_tables->m_master,
-            expression_vocab,
-            regex_mapping
-        );
 
+    // master: compute based on latest state of the gnode state table
+    compute_master_expressions(
+        expressions,
+        master,
+        flattened,
+        pkey_map,
+        m_expression_tables->m_master,
+        expression_vocab,
+        regex_mapping
+    );
+
+    for (const auto& expr : expressions) {
         // flattened: compute based on the latest update dataset
         expr->compute(
             flattened,
             pkey_map,
             m_expression_tables->m_flattened,
             expression_vocab,
-            regex_mapping
+            regex_mapping,
+            master
         );
 
         // delta: for each numerical column, the numerical delta between the
@@ -752,7 +765,8 @@ t_ctx0::compute_expressions(
             pkey_map,
             m_expression_tables->m_delta,
             expression_vocab,
-            regex_mapping
+            regex_mapping,
+            master
         );
 
         // prev: the values of the updated rows before this update was applied
@@ -761,7 +775,8 @@ t_ctx0::compute_expressions(
             pkey_map,
             m_expression_tables->m_prev,
             expression_vocab,
-            regex_mapping
+            regex_mapping,
+            master
         );
 
         // current: the current values of the updated rows
@@ -770,7 +785,8 @@ t_ctx0::compute_expressions(
             pkey_map,
             m_expression_tables->m_current,
             expression_vocab,
-            regex_mapping
+            regex_mapping,
+            master
         );
     }
 
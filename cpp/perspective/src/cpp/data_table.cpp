@@ -475,6 +475,29 @@ t_data_table::clear() {
     m_size = 0;
 }
 
+void
+t_data_table::compress() {
+    PSP_TRACE_SENTINEL();
+    PSP_VERBOSE_ASSERT(m_init, "touching uninited object");
+    for (const auto& column : m_columns) {
+        column->compress();
+    }
+}
+
+t_storage_bytes
+t_data_table::get_storage_bytes() const {
+    PSP_TRACE_SENTINEL();
+    PSP_VERBOSE_ASSERT(m_init, "touching uninited object");
+    t_storage_bytes bytes;
+    for (const auto& column : m_columns) {
+        auto column_bytes = column->get_storage_bytes();
+        bytes.m_raw += column_bytes.m_raw;
+        bytes.m_stored += column_bytes.m_stored;
+    }
+
+    return bytes;
+}
+
 t_mask
 t_data_table::filter_cpp(
     t_filter_op combiner, const std::vector<t_fterm>& fterms_
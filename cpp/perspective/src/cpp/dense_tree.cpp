@@ -17,6 +17,7 @@
 #include <perspective/comparators.h>
 #include <perspective/sort_specification.h>
 #include <perspective/data_table.h>
+#include <perspective/engine_config.h>
 
 #include <utility>
 
@@ -64,14 +65,15 @@ t_dtree::t_dtree(
 
 void
 t_dtree::init() {
+    t_uindex capacity = get_engine_config().m_tree_capacity;
     t_lstore_recipe leaf_args(
-        m_dirname, leaves_colname(), DEFAULT_CAPACITY, m_backing_store
+        m_dirname, leaves_colname(), capacity, m_backing_store
     );
-    m_leaves = t_column(DTYPE_UINT64, false, leaf_args, DEFAULT_CAPACITY);
+    m_leaves = t_column(DTYPE_UINT64, false, leaf_args, capacity);
     m_leaves.init();
 
     t_lstore_recipe node_args(
-        m_dirname, nodes_colname(), DEFAULT_CAPACITY, m_backing_store
+        m_dirname, nodes_colname(), capacity, m_backing_store
     );
 
     m_values = std::vector<t_column>(m_pivots.size() + 1);
@@ -85,10 +87,10 @@ t_dtree::init() {
     }
 
     t_lstore_recipe root_args(
-        m_dirname, values_colname("_root_"), DEFAULT_CAPACITY, m_backing_store
+        m_dirname, values_colname("_root_"), capacity, m_backing_store
     );
 
-    m_values[0] = t_column(DTYPE_STR, true, leaf_args, DEFAULT_CAPACITY);
+    m_values[0] = t_column(DTYPE_STR, true, leaf_args, capacity);
     m_values[0].init();
 
     m_sortby_dpthcol.emplace_back("");
@@ -96,10 +98,7 @@ t_dtree::init() {
     for (t_uindex idx = 0, loop_end = m_pivots.size(); idx < loop_end; ++idx) {
         auto colname = m_pivots[idx].colname();
         t_lstore_recipe leaf_args(
-            m_dirname,
-            values_colname(colname),
-            DEFAULT_CAPACITY,
-            m_backing_store
+            m_dirname, values_colname(colname), capacity, m_backing_store
         );
 
         auto siter = m_sortby_columns.find(colname);
@@ -109,7 +108,7 @@ t_dtree::init() {
         std::string sortby_column = has_sortby ? siter->second : colname;
         m_sortby_dpthcol.push_back(sortby_column);
         t_dtype dtype = m_ds->get_dtype(colname);
-        m_values[idx + 1] = t_column(dtype, true, leaf_args, DEFAULT_CAPACITY);
+        m_values[idx + 1] = t_column(dtype, true, leaf_args, capacity);
         m_values[idx + 1].init();
     }
 
@@ -215,7 +214,10 @@ t_dtree::pivot(const t_filter& filter, t_uindex level) {
             t_uindex next_neidx = 0;
 
             switch (piv_dtype) {
-                case DTYPE_STR: {
+                case DTYPE_STR:
+                case DTYPE_LIST:
+                case DTYPE_JSON:
+                case DTYPE_BINARY: {
                     next_neidx = t_pivot_processor<DTYPE_STR>()(
                         pivcol,
                         &m_nodes,
@@ -348,7 +350,8 @@ t_dtree::pivot(const t_filter& filter, t_uindex level) {
                         mask
                     );
                 } break;
-                case DTYPE_TIME: {
+                case DTYPE_TIME:
+                case DTYPE_DURATION: {
                     next_neidx = t_pivot_processor<DTYPE_INT64>()(
                         pivcol,
                         &m_nodes,
@@ -370,6 +373,28 @@ t_dtree::pivot(const t_filter& filter, t_uindex level) {
                         mask
                     );
                 } break;
+                case DTYPE_UUID: {
+                    next_neidx = t_pivot_processor<DTYPE_UUID>()(
+                        pivcol,
+                        &m_nodes,
+                        &(m_values[pidx]),
+                        &m_leaves,
+                        nbidx,
+                        neidx,
+                        mask
+                    );
+                } break;
+                case DTYPE_IPADDR: {
+                    next_neidx = t_pivot_processor<DTYPE_IPADDR>()(
+                        pivcol,
+                        &m_nodes,
+                        &(m_values[pidx]),
+                        &m_leaves,
+                        nbidx,
+                        neidx,
+                        mask
+                    );
+                } break;
                 default: {
                     PSP_COMPLAIN_AND_ABORT("Not supported yet");
                 } break;
@@ -0,0 +1,82 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+
+#include <perspective/downsample.h>
+#include <cmath>
+#include <numeric>
+
+namespace perspective {
+
+std::vector<t_uindex>
+lttb(
+    const std::vector<double>& x,
+    const std::vector<double>& y,
+    t_uindex n_points
+) {
+    t_uindex size = x.size();
+    std::vector<t_uindex> out;
+    if (n_points < 3 || n_points >= size) {
+        out.resize(size);
+        std::iota(out.begin(), out.end(), 0);
+        return out;
+    }
+
+    out.reserve(n_points);
+    double every = static_cast<double>(size - 2) / (n_points - 2);
+    t_uindex a = 0;
+    out.push_back(a);
+    for (t_uindex ii = 0; ii < n_points - 2; ++ii) {
+        // The average of the next bucket, or of the last point for the last
+        // bucket.
+        auto avg_start =
+            static_cast<t_uindex>(std::floor((ii + 1) * every)) + 1;
+        auto avg_end = std::min(
+            static_cast<t_uindex>(std::floor((ii + 2) * every)) + 1, size
+        );
+
+        double avg_x = 0;
+        double avg_y = 0;
+        for (t_uindex jj = avg_start; jj < avg_end; ++jj) {
+            avg_x += x[jj];
+            avg_y += y[jj];
+        }
+
+        auto avg_len = static_cast<double>(avg_end - avg_start);
+        avg_x /= avg_len;
+        avg_y /= avg_len;
+
+        auto start = static_cast<t_uindex>(std::floor(ii * every)) + 1;
+        auto end = static_cast<t_uindex>(std::floor((ii + 1) * every)) + 1;
+        double max_area = -1;
+        t_uindex next_a = start;
+        for (t_uindex jj = start; jj < end; ++jj) {
+            double area = std::abs(
+                (x[a] - avg_x) * (y[jj] - y[a])
+                - (x[a] - x[jj]) * (avg_y - y[a])
+            );
+
+            if (area > max_area) {
+                max_area = area;
+                next_a = jj;
+            }
+        }
+
+        out.push_back(next_a);
+        a = next_a;
+    }
+
+    out.push_back(size - 1);
+    return out;
+}
+
+} // namespace perspective
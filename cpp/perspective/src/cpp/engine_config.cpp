@@ -0,0 +1,59 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+#include <perspective/engine_config.h>
+#include <atomic>
+#include <stdexcept>
+
+namespace perspective {
+
+// Each tunable is read independently (and often, e.g. on every
+// `parallel_for()`), so they are kept as separate atomics rather than a
+// locked `t_engine_config`.
+static std::atomic<t_uindex> PARALLEL_THRESHOLD{
+    t_engine_config{}.m_parallel_threshold
+};
+static std::atomic<t_uindex> EXPRESSION_BLOCK_SIZE{
+    t_engine_config{}.m_expression_block_size
+};
+static std::atomic<t_uindex> TREE_CAPACITY{t_engine_config{}.m_tree_capacity};
+static std::atomic<t_uindex> PORT_CAPACITY{t_engine_config{}.m_port_capacity};
+
+t_engine_config
+get_engine_config() {
+    t_engine_config config;
+    config.m_parallel_threshold =
+        PARALLEL_THRESHOLD.load(std::memory_order_relaxed);
+    config.m_expression_block_size =
+        EXPRESSION_BLOCK_SIZE.load(std::memory_order_relaxed);
+    config.m_tree_capacity = TREE_CAPACITY.load(std::memory_order_relaxed);
+    config.m_port_capacity = PORT_CAPACITY.load(std::memory_order_relaxed);
+    return config;
+}
+
+void
+set_engine_config(const t_engine_config& config) {
+    if (config.m_expression_block_size == 0 || config.m_tree_capacity == 0
+        || config.m_port_capacity == 0) {
+        throw std::invalid_argument("Engine config sizes must be non-zero");
+    }
+
+    PARALLEL_THRESHOLD.store(
+        config.m_parallel_threshold, std::memory_order_relaxed
+    );
+    EXPRESSION_BLOCK_SIZE.store(
+        config.m_expression_block_size, std::memory_order_relaxed
+    );
+    TREE_CAPACITY.store(config.m_tree_capacity, std::memory_order_relaxed);
+    PORT_CAPACITY.store(config.m_port_capacity, std::memory_order_relaxed);
+}
+
+} // namespace perspective
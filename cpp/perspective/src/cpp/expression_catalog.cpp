@@ -0,0 +1,356 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+#include <perspective/expression_catalog.h>
+
+namespace perspective {
+
+const std::vector<t_expression_function>&
+expression_functions() {
+#ifdef PSP_DISABLE_EXPRESSIONS
+    static const std::vector<t_expression_function> functions;
+#else
+    static const std::vector<t_expression_function> functions = {
+        {"var",
+         "var x := 1",
+         "var ${1:x := 1}",
+         "Declare a new local variable"},
+        {"abs", "abs(x)", "abs(${1:x})", "Absolute value of x"},
+        {"avg", "avg(x)", "avg(${1:x})", "Average of all inputs"},
+        {"bucket", "bucket(x, y)", "bucket(${1:x}, ${2:y})", "Bucket x by y"},
+        {"ceil", "ceil(x)", "ceil(${1:x})", "Smallest integer >= x"},
+        {"exp", "exp(x)", "exp(${1:x})", "Natural exponent of x (e ^ x)"},
+        {"floor", "floor(x)", "floor(${1:x})", "Largest integer <= x"},
+        {"frac",
+         "frac(x)",
+         "frac(${1:x})",
+         "Fractional portion (after the decimal) of x"},
+        {"iclamp",
+         "iclamp(x)",
+         "iclamp(${1:x})",
+         "Inverse clamp x within a range"},
+        {"inrange",
+         "inrange(x)",
+         "inrange(${1:x})",
+         "Returns whether x is within a range"},
+        {"log", "log(x)", "log(${1:x})", "Natural log of x"},
+        {"log10", "log10(x)", "log10(${1:x})", "Base 10 log of x"},
+        {"log1p",
+         "log1p(x)",
+         "log1p(${1:x})",
+         "Natural log of 1 + x where x is very small"},
+        {"log2", "log2(x)", "log2(${1:x})", "Base 2 log of x"},
+        {"logn",
+         "logn(x, N)",
+         "logn(${1:x}, ${2:N})",
+         "Base N log of x where N >= 0"},
+        {"max", "max(x)", "max(${1:x})", "Maximum value of all inputs"},
+        {"min", "min(x)", "min(${1:x})", "Minimum value of all inputs"},
+        {"mul", "mul(x)", "mul(${1:x})", "Product of all inputs"},
+        {"percent_of", "percent_of(x)", "percent_of(${1:x})", "Percent y of x"},
+        {"pow", "pow(x, y)", "pow(${1:x}, ${2:y})", "x to the power of y"},
+        {"root",
+         "root(x, N)",
+         "root(${1:x}, ${2:N})",
+         "N-th root of x where N >= 0"},
+        {"round",
+         "round(x)",
+         "round(${1:x})",
+         "Round x to the nearest integer"},
+        {"sgn", "sgn(x)", "sgn(${1:x})", "Sign of x: -1, 1, or 0"},
+        {"sqrt", "sqrt(x)", "sqrt(${1:x})", "Square root of x"},
+        {"sum",
+         "sum(x)",
+         "sum(${1:x})",
+         "Sum of all inputs, including the elements of lists"},
+        {"trunc", "trunc(x)", "trunc(${1:x})", "Integer portion of x"},
+        {"acos", "acos(x)", "acos(${1:x})", "Arc cosine of x in radians"},
+        {"acosh",
+         "acosh(x)",
+         "acosh(${1:x})",
+         "Inverse hyperbolic cosine of x in radians"},
+        {"asin", "asin(x)", "asin(${1:x})", "Arc sine of x in radians"},
+        {"asinh",
+         "asinh(x)",
+         "asinh(${1:x})",
+         "Inverse hyperbolic sine of x in radians"},
+        {"atan", "atan(x)", "atan(${1:x})", "Arc tangent of x in radians"},
+        {"atanh",
+         "atanh(x)",
+         "atanh(${1:x})",
+         "Inverse hyperbolic tangent of x in radians"},
+        {"cos", "cos(x)", "cos(${1:x})", "Cosine of x"},
+        {"cosh", "cosh(x)", "cosh(${1:x})", "Hyperbolic cosine of x"},
+        {"cot", "cot(x)", "cot(${1:x})", "Cotangent of x"},
+        {"sin", "sin(x)", "sin(${1:x})", "Sine of x"},
+        {"sinc", "sinc(x)", "sinc(${1:x})", "Sine cardinal of x"},
+        {"sinh", "sinh(x)", "sinh(${1:x})", "Hyperbolic sine of x"},
+        {"tan", "tan(x)", "tan(${1:x})", "Tangent of x"},
+        {"tanh", "tanh(x)", "tanh(${1:x})", "Hyperbolic tangent of x"},
+        {"deg2rad",
+         "deg2rad(x)",
+         "deg2rad(${1:x})",
+         "Convert x from degrees to radians"},
+        {"deg2grad",
+         "deg2grad(x)",
+         "deg2grad(${1:x})",
+         "Convert x from degrees to gradians"},
+        {"rad2deg",
+         "rad2deg(x)",
+         "rad2deg(${1:x})",
+         "Convert x from radians to degrees"},
+        {"grad2deg",
+         "grad2deg(x)",
+         "grad2deg(${1:x})",
+         "Convert x from gradians to degrees"},
+        {"concat",
+         "concat(x, y)",
+         "concat(${1:x}, ${2:y})",
+         "Concatenate string columns and string literals, such as:\nconcat(\"State\" ', ', \"City\")"},
+        {"order",
+         "order(input column, value, ...)",
+         "order(${1:input column}, ${2:value}, ...)",
+         "Generates a sort order for a string column based on the input order of the parameters, such as:\norder(\"State\", 'Texas', 'New York')"},
+        {"upper", "upper(x)", "upper(${1:x})", "Uppercase of x"},
+        {"lower", "lower(x)", "lower(${1:x})", "Lowercase of x"},
+        {"levenshtein",
+         "levenshtein(x, y)",
+         "levenshtein(${1:x}, ${2:y})",
+         "Number of single character edits needed to turn string x into string y"},
+        {"jaro_winkler",
+         "jaro_winkler(x, y)",
+         "jaro_winkler(${1:x}, ${2:y})",
+         "Jaro-Winkler similarity of strings x and y, from 0 (no characters in common) to 1 (identical)"},
+        {"soundex",
+         "soundex(x)",
+         "soundex(${1:x})",
+         "Four character Soundex code of a name, such as soundex('Robert') == 'R163'"},
+        {"starts_with",
+         "starts_with(x, prefix)",
+         "starts_with(${1:x}, ${2:prefix})",
+         "Whether string x starts with prefix"},
+        {"ends_with",
+         "ends_with(x, suffix)",
+         "ends_with(${1:x}, ${2:suffix})",
+         "Whether string x ends with suffix"},
+        {"len",
+         "len(x)",
+         "len(${1:x})",
+         "Number of elements in a list, or characters in a string"},
+        {"contains",
+         "contains(x, value)",
+         "contains(${1:x}, ${2:value})",
+         "Whether a list contains value, or a string contains the substring value"},
+        {"json_extract",
+         "json_extract(x, '$.path')",
+         "json_extract(${1:x}, '${2:$.path}')",
+         "Returns the value at a path in a JSON document as a string"},
+        {"json_extract_float",
+         "json_extract_float(x, '$.path')",
+         "json_extract_float(${1:x}, '${2:$.path}')",
+         "Returns the value at a path in a JSON document as a float"},
+        {"json_extract_bool",
+         "json_extract_bool(x, '$.path')",
+         "json_extract_bool(${1:x}, '${2:$.path}')",
+         "Returns the value at a path in a JSON document as a boolean"},
+        {"haversine_distance",
+         "haversine_distance(lat1, lon1, lat2, lon2)",
+         "haversine_distance(${1:lat1}, ${2:lon1}, ${3:lat2}, ${4:lon2})",
+         "Great-circle distance in kilometers between two points"},
+        {"within_bbox",
+         "within_bbox(lat, lon, min_lat, min_lon, max_lat, max_lon)",
+         "within_bbox(${1:lat}, ${2:lon}, ${3:min_lat}, ${4:min_lon}, ${5:max_lat}, ${6:max_lon})",
+         "Whether a point lies inside a bounding box"},
+        {"geohash",
+         "geohash(lat, lon, precision)",
+         "geohash(${1:lat}, ${2:lon}, ${3:precision})",
+         "Geohash of a point with precision characters, for bucketing"},
+        {"in_subnet",
+         "in_subnet(ip, '10.0.0.0/8')",
+         "in_subnet(${1:ip}, '${2:10.0.0.0/8}')",
+         "Whether an IP address lies within a CIDR block"},
+        {"round_to_tick",
+         "round_to_tick(x, 0.05)",
+         "round_to_tick(${1:x}, ${2:0.05})",
+         "Round a number to the nearest multiple of a tick size"},
+        {"bps",
+         "bps(x, base)",
+         "bps(${1:x}, ${2:base})",
+         "Change from base to x in basis points"},
+        {"pv",
+         "pv(rate, nper, pmt, fv)",
+         "pv(${1:rate}, ${2:nper}, ${3:pmt}, ${4:fv})",
+         "Present value of an annuity"},
+        {"fv",
+         "fv(rate, nper, pmt, pv)",
+         "fv(${1:rate}, ${2:nper}, ${3:pmt}, ${4:pv})",
+         "Future value of an annuity"},
+        {"rate",
+         "rate(nper, pmt, pv, fv)",
+         "rate(${1:nper}, ${2:pmt}, ${3:pv}, ${4:fv})",
+         "Per-period interest rate of an annuity, solved iteratively"},
+        {"irr",
+         "irr(flows)",
+         "irr(${1:flows})",
+         "Internal rate of return of a list of cash flows, solved iteratively"},
+        {"year_frac",
+         "year_frac(start, end, 'act/360')",
+         "year_frac(${1:start}, ${2:end}, '${3:act/360}')",
+         "Years between two dates under a day count convention: 'act/360', 'act/365', '30/360' or 'act/act'"},
+        {"hour_of_day",
+         "hour_of_day(x)",
+         "hour_of_day(${1:x})",
+         "Return a datetime's hour of the day as a string"},
+        {"month_of_year",
+         "month_of_year(x)",
+         "month_of_year(${1:x})",
+         "Return a datetime's month of the year as a string"},
+        {"day_of_week",
+         "day_of_week(x)",
+         "day_of_week(${1:x})",
+         "Return a datetime's day of week as a string"},
+        {"now", "now()", "now()", "The current datetime in local time"},
+        {"today", "today()", "today()", "The current date in local time"},
+        {"is_null",
+         "is_null(x)",
+         "is_null(${1:x})",
+         "Whether x is a null value"},
+        {"is_not_null",
+         "is_not_null(x)",
+         "is_not_null(${1:x})",
+         "Whether x is not a null value"},
+        {"not", "not(x)", "not(${1:x})", "not x"},
+        {"true", "true", "true", "Boolean value true"},
+        {"false", "false", "false", "Boolean value false"},
+        {"if",
+         "if (condition) {} else if (condition) {} else {}",
+         "if (${1:condition}) {} else if (${2:condition}) {} else {}",
+         "An if/else conditional, which evaluates a condition such as:\n if (\"Sales\" > 100) { true } else { false }"},
+        {"for",
+         "for (expression) {}",
+         "for (${1:expression}) {}",
+         "A for loop, which repeatedly evaluates an incrementing expression such as:\nvar x := 0; var y := 1; for (x < 10; x += 1) { y := x + y }"},
+        {"string",
+         "string(x)",
+         "string(${1:x})",
+         "Converts the given argument to a string"},
+        {"integer",
+         "integer(x)",
+         "integer(${1:x})",
+         "Converts the given argument to a 32-bit integer. If the result over/under-flows, null is returned"},
+        {"float",
+         "float(x)",
+         "float(${1:x})",
+         "Converts the argument to a float"},
+        {"date",
+         "date(year, month, day)",
+         "date(${1:year}, ${1:month}, ${1:day})",
+         "Given a year, month (1-12) and day, create a new date"},
+        {"datetime",
+         "datetime(timestamp)",
+         "datetime(${1:timestamp})",
+         "Given a POSIX timestamp of milliseconds since epoch, create a new datetime"},
+        {"duration",
+         "duration(milliseconds)",
+         "duration(${1:milliseconds})",
+         "Given a number of milliseconds, create a new duration"},
+        {"convert_tz",
+         "convert_tz(x, 'America/New_York')",
+         "convert_tz(${1:x}, '${2:America/New_York}')",
+         "Returns the wall-clock time of a datetime in the given time zone"},
+        {"at_tz",
+         "at_tz(x, 'America/New_York')",
+         "at_tz(${1:x}, '${2:America/New_York}')",
+         "Reads a datetime as wall-clock time in the given time zone"},
+        {"boolean",
+         "boolean(x)",
+         "boolean(${1:x})",
+         "Converts the given argument to a boolean"},
+        {"random",
+         "random()",
+         "random()",
+         "Returns a random float between 0 and 1, inclusive."},
+        {"match",
+         "match(string, pattern)",
+         "match(${1:string}, ${2:pattern})",
+         "Returns True if any part of string matches pattern, and False otherwise."},
+        {"match_all",
+         "match_all(string, pattern)",
+         "match_all(${1:string}, ${2:pattern})",
+         "Returns True if the whole string matches pattern, and False otherwise."},
+        {"search",
+         "search(string, pattern)",
+         "search(${1:string}, ${2:pattern})",
+         "Returns the substring that matches the first capturing group in pattern, or null if there are no capturing groups in the pattern or if there are no matches."},
+        {"indexof",
+         "indexof(string, pattern, output_vector)",
+         "indexof(${1:string}, ${2:pattern}, ${3:output_vector})",
+         "Writes into index 0 and 1 of output_vector the start and end indices of the substring that matches the first capturing group in pattern.\n\nReturns true if there is a match and output was written, or false if there are no capturing groups in the pattern, if there are no matches, or if the indices are invalid."},
+        {"substring",
+         "substring(string, start_idx, length)",
+         "substring(${1:string}, ${2:start_idx}, ${3:length})",
+         "Returns a substring of string from start_idx with the given length. If length is not passed in, returns substring from start_idx to the end of the string. Returns null if the string or any indices are invalid."},
+        {"replace",
+         "replace(string, pattern, replacer)",
+         "replace(${1:string}, ${2:pattern}, ${3:replacer})",
+         "Replaces the first match of pattern in string with replacer, or return the original string if no replaces were made."},
+        {"replace_all",
+         "replace(string, pattern, replacer)",
+         "replace(${1:string}, ${2:pattern}, ${3:replacer})",
+         "Replaces all non-overlapping matches of pattern in string with replacer, or return the original string if no replaces were made."},
+        {"regex_match",
+         "regex_match(string, 'pattern')",
+         "regex_match(${1:string}, '${2:pattern}')",
+         "Whether the string contains a match of pattern, like match()"},
+        {"regex_extract",
+         "regex_extract(string, 'pattern', 1)",
+         "regex_extract(${1:string}, '${2:pattern}', ${3:1})",
+         "Returns the substring matched by a capturing group of the first match of pattern (0 for the whole match), or null if the string does not match"},
+        {"regex_replace",
+         "regex_replace(string, 'pattern', replacer)",
+         "regex_replace(${1:string}, '${2:pattern}', ${3:replacer})",
+         "Replaces all matches of pattern in string with replacer, which may refer to capturing groups as \\1, like replace_all()"},
+        {"index",
+         "index()",
+         "index()",
+         "Looks up the index value of the current row"},
+        {"col",
+         "col(string)",
+         "col(${1:string})",
+         "Looks up a column value by name"},
+        {"vlookup",
+         "vlookup(string, uint64)",
+         "vlookup(${1:string}, ${2:uint64})",
+         "Looks up a value in another column by index"},
+        {"zscore",
+         "zscore('x', 'group')",
+         "zscore('${1:x}', '${2:group}')",
+         "Standard score of a column's value within its group, or across the table without a group column"},
+        {"percent_of_group",
+         "percent_of_group('x', 'group')",
+         "percent_of_group('${1:x}', '${2:group}')",
+         "A column's value as a percentage of its group's total"},
+        {"percent_of_total",
+         "percent_of_total('x')",
+         "percent_of_total('${1:x}')",
+         "A column's value as a percentage of the column's total"},
+        {"rank_in_group",
+         "rank_in_group('x', 'group')",
+         "rank_in_group('${1:x}', '${2:group}')",
+         "Rank of a column's value within its group, largest first"},
+    };
+#endif
+
+    return functions;
+}
+
+} // namespace perspective
@@ -81,7 +81,9 @@ extract_aggregate(
         case AGGTYPE_DISTINCT_COUNT:
         case AGGTYPE_DISTINCT_LEAF:
         case AGGTYPE_VARIANCE:
-        case AGGTYPE_STANDARD_DEVIATION: {
+        case AGGTYPE_STANDARD_DEVIATION:
+        case AGGTYPE_TOTAL_BYTES:
+        case AGGTYPE_MAX_BYTES: {
             t_tscalar rval = aggcol->get_scalar(ridx);
             return rval;
         } break;
@@ -86,6 +86,10 @@ t_fterm::get_expr() const {
             }
             ss << " )";
         } break;
+        case FILTER_OP_IN_SUBNET: {
+            ss << filter_op_to_str(m_op) << " " << m_bag[0].to_string(true)
+               << " .. " << m_bag[1].to_string(true);
+        } break;
         case FILTER_OP_BEGINS_WITH:
         case FILTER_OP_ENDS_WITH: {
             ss << "." << filter_op_to_str(m_op) << "( "
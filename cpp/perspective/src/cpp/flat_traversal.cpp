@@ -118,11 +118,12 @@ t_ftrav::fill_sort_elem(
 
         const std::string& sortby_colname = config.get_sort_by(colname);
 
-        out_elem.m_row.push_back(
+        out_elem.m_row.push_back(config.get_category_sort_value(
+            sortby_colname,
             m_symtable.get_interned_tscalar(get_from_gstate(
                 gstate, expression_master_table, sortby_colname, pkey
             ))
-        );
+        ));
     }
 }
 
@@ -148,9 +149,10 @@ t_ftrav::fill_sort_elem(
 
         const std::string& sortby_colname = config.get_sort_by(colname);
 
-        out_elem.m_row.push_back(
+        out_elem.m_row.push_back(config.get_category_sort_value(
+            sortby_colname,
             get_interned_tscalar(row.at(config.get_colidx(sortby_colname)))
-        );
+        ));
     }
 }
 
@@ -26,6 +26,7 @@
 #include <perspective/parallel_for.h>
 #include <perspective/pyutils.h>
 
+#include <algorithm>
 #include <utility>
 
 namespace perspective {
@@ -250,12 +251,19 @@ t_gnode::_process_mask_existed_rows(t_process_state& process_state) {
             case OP_INSERT: {
                 row_pre_existed =
                     row_pre_existed && !process_state.m_prev_pkey_eq_vec[idx];
+                if (row_pre_existed) {
+                    ++process_state.m_counts.m_updated;
+                } else {
+                    ++process_state.m_counts.m_added;
+                }
+
                 mask.set(idx, true);
                 existed_column->set_nth(added_count, row_pre_existed);
                 ++added_count;
             } break;
             case OP_DELETE: {
                 if (row_pre_existed) {
+                    ++process_state.m_counts.m_removed;
                     mask.set(idx, true);
                     existed_column->set_nth(added_count, row_pre_existed);
                     ++added_count;
@@ -316,6 +324,15 @@ t_gnode::_process_table(t_uindex port_id) {
 
     // first update - master table is empty
     if (m_gstate->mapping_size() == 0) {
+        const auto* op_base =
+            flattened->get_column("psp_op")->get_nth<std::uint8_t>(0);
+        for (t_uindex idx = 0; idx < flattened_num_rows; ++idx) {
+            if (op_base[idx] == OP_INSERT) {
+                ++result.m_counts.m_added;
+            }
+        }
+
+        _compute_virtual_columns(flattened);
         m_gstate->update_master_table(flattened.get());
         m_oports[PSP_PORT_FLATTENED]->set_table(flattened);
 
@@ -367,194 +384,247 @@ t_gnode::_process_table(t_uindex port_id) {
     // mask_count = flattened_num_rows - number of rows that were removed
     _process_state.set_size_transitional_data_tables(mask_count);
 
-    // Only real columns from the gstate table here
-    const std::vector<std::string>& column_names =
-        get_output_schema().m_columns;
-    t_uindex ncols = column_names.size();
+    // Only real columns from the gstate table here. Virtual columns are
+    // computed from the processed values of the others, so go last.
+    std::vector<std::string> column_names;
+    std::vector<std::string> virtual_names;
+    for (const auto& cname : get_output_schema().m_columns) {
+        auto is_virtual = std::any_of(
+            m_virtual_columns.begin(),
+            m_virtual_columns.end(),
+            [&cname](const auto& expr) {
+                return expr->get_expression_alias() == cname;
+            }
+        );
 
-    parallel_for(
-        int(ncols),
-        [&_process_state, &column_names, this](int colidx) {
-            const std::string& cname = column_names[colidx];
-            auto* fcolumn =
-                _process_state.m_flattened_data_table->get_column(cname).get();
-            auto* scolumn =
-                _process_state.m_state_data_table->get_column(cname).get();
-            auto* dcolumn =
-                _process_state.m_delta_data_table->get_column(cname).get();
-            auto* pcolumn =
-                _process_state.m_prev_data_table->get_column(cname).get();
-            auto* ccolumn =
-                _process_state.m_current_data_table->get_column(cname).get();
-            auto* tcolumn =
-                _process_state.m_transitions_data_table->get_column(cname).get(
-                );
+        if (is_virtual) {
+            virtual_names.push_back(cname);
+        } else {
+            column_names.push_back(cname);
+        }
+    }
 
-            t_dtype col_dtype = fcolumn->get_dtype();
-
-            switch (col_dtype) {
-                case DTYPE_INT64: {
-                    _process_column<std::int64_t>(
-                        fcolumn,
-                        scolumn,
-                        dcolumn,
-                        pcolumn,
-                        ccolumn,
-                        tcolumn,
-                        _process_state
-                    );
-                } break;
-                case DTYPE_INT32: {
-                    _process_column<std::int32_t>(
-                        fcolumn,
-                        scolumn,
-                        dcolumn,
-                        pcolumn,
-                        ccolumn,
-                        tcolumn,
-                        _process_state
-                    );
-                } break;
-                case DTYPE_INT16: {
-                    _process_column<std::int16_t>(
-                        fcolumn,
-                        scolumn,
-                        dcolumn,
-                        pcolumn,
-                        ccolumn,
-                        tcolumn,
-                        _process_state
-                    );
-                } break;
-                case DTYPE_INT8: {
-                    _process_column<std::int8_t>(
-                        fcolumn,
-                        scolumn,
-                        dcolumn,
-                        pcolumn,
-                        ccolumn,
-                        tcolumn,
-                        _process_state
-                    );
-                } break;
-                case DTYPE_UINT64: {
-                    _process_column<std::uint64_t>(
-                        fcolumn,
-                        scolumn,
-                        dcolumn,
-                        pcolumn,
-                        ccolumn,
-                        tcolumn,
-                        _process_state
-                    );
-                } break;
-                case DTYPE_UINT32: {
-                    _process_column<std::uint32_t>(
-                        fcolumn,
-                        scolumn,
-                        dcolumn,
-                        pcolumn,
-                        ccolumn,
-                        tcolumn,
-                        _process_state
-                    );
-                } break;
-                case DTYPE_UINT16: {
-                    _process_column<std::uint16_t>(
-                        fcolumn,
-                        scolumn,
-                        dcolumn,
-                        pcolumn,
-                        ccolumn,
-                        tcolumn,
-                        _process_state
-                    );
-                } break;
-                case DTYPE_UINT8: {
-                    _process_column<std::uint8_t>(
-                        fcolumn,
-                        scolumn,
-                        dcolumn,
-                        pcolumn,
-                        ccolumn,
-                        tcolumn,
-                        _process_state
-                    );
-                } break;
-                case DTYPE_FLOAT64: {
-                    _process_column<double>(
-                        fcolumn,
-                        scolumn,
-                        dcolumn,
-                        pcolumn,
-                        ccolumn,
-                        tcolumn,
-                        _process_state
-                    );
-                } break;
-                case DTYPE_FLOAT32: {
-                    _process_column<float>(
-                        fcolumn,
-                        scolumn,
-                        dcolumn,
-                        pcolumn,
-                        ccolumn,
-                        tcolumn,
-                        _process_state
-                    );
-                } break;
-                case DTYPE_BOOL: {
-                    _process_column<std::uint8_t>(
-                        fcolumn,
-                        scolumn,
-                        dcolumn,
-                        pcolumn,
-                        ccolumn,
-                        tcolumn,
-                        _process_state
-                    );
-                } break;
-                case DTYPE_TIME: {
-                    _process_column<std::int64_t>(
-                        fcolumn,
-                        scolumn,
-                        dcolumn,
-                        pcolumn,
-                        ccolumn,
-                        tcolumn,
-                        _process_state
-                    );
-                } break;
-                case DTYPE_DATE: {
-                    _process_column<std::uint32_t>(
-                        fcolumn,
-                        scolumn,
-                        dcolumn,
-                        pcolumn,
-                        ccolumn,
-                        tcolumn,
-                        _process_state
-                    );
-                } break;
-                case DTYPE_STR: {
-                    _process_column<std::string>(
-                        fcolumn,
-                        scolumn,
-                        dcolumn,
-                        pcolumn,
-                        ccolumn,
-                        tcolumn,
-                        _process_state
-                    );
-                } break;
-                case DTYPE_OBJECT:
-                default: {
-                    PSP_COMPLAIN_AND_ABORT("Unsupported column dtype");
-                }
+    auto process_column = [&_process_state, this](const std::string& cname) {
+        auto* fcolumn =
+            _process_state.m_flattened_data_table->get_column(cname).get();
+        auto* scolumn =
+            _process_state.m_state_data_table->get_column(cname).get();
+        auto* dcolumn =
+            _process_state.m_delta_data_table->get_column(cname).get();
+        auto* pcolumn =
+            _process_state.m_prev_data_table->get_column(cname).get();
+        auto* ccolumn =
+            _process_state.m_current_data_table->get_column(cname).get();
+        auto* tcolumn =
+            _process_state.m_transitions_data_table->get_column(cname).get();
+
+        t_dtype col_dtype = fcolumn->get_dtype();
+
+        switch (col_dtype) {
+            case DTYPE_INT64: {
+                _process_column<std::int64_t>(
+                    fcolumn,
+                    scolumn,
+                    dcolumn,
+                    pcolumn,
+                    ccolumn,
+                    tcolumn,
+                    _process_state
+                );
+            } break;
+            case DTYPE_INT32: {
+                _process_column<std::int32_t>(
+                    fcolumn,
+                    scolumn,
+                    dcolumn,
+                    pcolumn,
+                    ccolumn,
+                    tcolumn,
+                    _process_state
+                );
+            } break;
+            case DTYPE_INT16: {
+                _process_column<std::int16_t>(
+                    fcolumn,
+                    scolumn,
+                    dcolumn,
+                    pcolumn,
+                    ccolumn,
+                    tcolumn,
+                    _process_state
+                );
+            } break;
+            case DTYPE_INT8: {
+                _process_column<std::int8_t>(
+                    fcolumn,
+                    scolumn,
+                    dcolumn,
+                    pcolumn,
+                    ccolumn,
+                    tcolumn,
+                    _process_state
+                );
+            } break;
+            case DTYPE_UINT64: {
+                _process_column<std::uint64_t>(
+                    fcolumn,
+                    scolumn,
+                    dcolumn,
+                    pcolumn,
+                    ccolumn,
+                    tcolumn,
+                    _process_state
+                );
+            } break;
+            case DTYPE_UINT32: {
+                _process_column<std::uint32_t>(
+                    fcolumn,
+                    scolumn,
+                    dcolumn,
+                    pcolumn,
+                    ccolumn,
+                    tcolumn,
+                    _process_state
+                );
+            } break;
+            case DTYPE_UINT16: {
+                _process_column<std::uint16_t>(
+                    fcolumn,
+                    scolumn,
+                    dcolumn,
+                    pcolumn,
+                    ccolumn,
+                    tcolumn,
+                    _process_state
+                );
+            } break;
+            case DTYPE_UINT8: {
+                _process_column<std::uint8_t>(
+                    fcolumn,
+                    scolumn,
+                    dcolumn,
+                    pcolumn,
+                    ccolumn,
+                    tcolumn,
+                    _process_state
+                );
+            } break;
+            case DTYPE_FLOAT64: {
+                _process_column<double>(
+                    fcolumn,
+                    scolumn,
+                    dcolumn,
+                    pcolumn,
+                    ccolumn,
+                    tcolumn,
+                    _process_state
+                );
+            } break;
+            case DTYPE_FLOAT32: {
+                _process_column<float>(
+                    fcolumn,
+                    scolumn,
+                    dcolumn,
+                    pcolumn,
+                    ccolumn,
+                    tcolumn,
+                    _process_state
+                );
+            } break;
+            case DTYPE_BOOL: {
+                _process_column<std::uint8_t>(
+                    fcolumn,
+                    scolumn,
+                    dcolumn,
+                    pcolumn,
+                    ccolumn,
+                    tcolumn,
+                    _process_state
+                );
+            } break;
+            case DTYPE_TIME:
+            case DTYPE_DURATION: {
+                _process_column<std::int64_t>(
+                    fcolumn,
+                    scolumn,
+                    dcolumn,
+                    pcolumn,
+                    ccolumn,
+                    tcolumn,
+                    _process_state
+                );
+            } break;
+            case DTYPE_DATE: {
+                _process_column<std::uint32_t>(
+                    fcolumn,
+                    scolumn,
+                    dcolumn,
+                    pcolumn,
+                    ccolumn,
+                    tcolumn,
+                    _process_state
+                );
+            } break;
+            case DTYPE_UUID: {
+                _process_opaque_column<t_uuid>(
+                    fcolumn,
+                    scolumn,
+                    dcolumn,
+                    pcolumn,
+                    ccolumn,
+                    tcolumn,
+                    _process_state
+                );
+            } break;
+            case DTYPE_IPADDR: {
+                _process_opaque_column<t_ipaddr>(
+                    fcolumn,
+                    scolumn,
+                    dcolumn,
+                    pcolumn,
+                    ccolumn,
+                    tcolumn,
+                    _process_state
+                );
+            } break;
+            case DTYPE_STR:
+            case DTYPE_LIST:
+            case DTYPE_JSON:
+            case DTYPE_BINARY: {
+                _process_column<std::string>(
+                    fcolumn,
+                    scolumn,
+                    dcolumn,
+                    pcolumn,
+                    ccolumn,
+                    tcolumn,
+                    _process_state
+                );
+            } break;
+            case DTYPE_OBJECT:
+            default: {
+                PSP_COMPLAIN_AND_ABORT("Unsupported column dtype");
             }
         }
+    };
+
+    parallel_for(
+        int(column_names.size()),
+        [&process_column, &column_names](int colidx) {
+            process_column(column_names[colidx]);
+        }
     );
 
+    if (!virtual_names.empty()) {
+        _compute_virtual_columns(_process_state);
+        parallel_for(
+            int(virtual_names.size()),
+            [&process_column, &virtual_names](int colidx) {
+                process_column(virtual_names[colidx]);
+            }
+        );
+    }
+
     /**
      * After all columns have been processed (transitional tables written into),
      * `_process_state.m_flattened_data_table` contains the accumulated state
@@ -598,6 +668,7 @@ t_gnode::_process_table(t_uindex port_id) {
 
     result.m_flattened_data_table = flattened_masked;
     result.m_should_notify_userspace = true;
+    result.m_counts = _process_state.m_counts;
 
     return result;
 }
@@ -707,6 +778,96 @@ t_gnode::_process_column<std::string>(
     }
 }
 
+template <typename T>
+void
+t_gnode::_process_opaque_column(
+    const t_column* fcolumn,
+    const t_column* scolumn,
+    t_column* dcolumn,
+    t_column* pcolumn,
+    t_column* ccolumn,
+    t_column* tcolumn,
+    const t_process_state& process_state
+) {
+    for (t_uindex idx = 0, loop_end = fcolumn->size(); idx < loop_end; ++idx) {
+        std::uint8_t op_ = process_state.m_op_base[idx];
+        t_op op = static_cast<t_op>(op_);
+        t_uindex added_count = process_state.m_added_offset[idx];
+
+        const t_rlookup& rlookup = process_state.m_lookup[idx];
+        bool row_pre_existed = rlookup.m_exists;
+        auto prev_pkey_eq = process_state.m_prev_pkey_eq_vec[idx];
+
+        switch (op) {
+            case OP_INSERT: {
+                row_pre_existed = row_pre_existed && !prev_pkey_eq;
+
+                T prev_value{};
+                bool prev_valid = false;
+
+                T cur_value = *(fcolumn->get_nth<T>(idx));
+                bool cur_valid = fcolumn->is_valid(idx);
+
+                if (row_pre_existed) {
+                    prev_value = *(scolumn->get_nth<T>(rlookup.m_idx));
+                    prev_valid = scolumn->is_valid(rlookup.m_idx);
+                }
+
+                bool exists = cur_valid;
+                bool prev_existed = row_pre_existed && prev_valid;
+                bool prev_cur_eq = prev_value == cur_value;
+
+                auto trans = calc_transition(
+                    prev_existed,
+                    row_pre_existed,
+                    exists,
+                    prev_valid,
+                    cur_valid,
+                    prev_cur_eq,
+                    prev_pkey_eq
+                );
+
+                dcolumn->set_nth<T>(added_count, T{});
+                dcolumn->set_valid(added_count, true);
+
+                pcolumn->set_nth<T>(added_count, prev_value);
+                pcolumn->set_valid(added_count, prev_valid);
+
+                ccolumn->set_nth<T>(
+                    added_count, cur_valid ? cur_value : prev_value
+                );
+                ccolumn->set_valid(
+                    added_count, cur_valid ? cur_valid : prev_valid
+                );
+
+                tcolumn->set_nth<std::uint8_t>(idx, trans);
+            } break;
+            case OP_DELETE: {
+                if (row_pre_existed) {
+                    T prev_value = *(scolumn->get_nth<T>(rlookup.m_idx));
+                    bool prev_valid = scolumn->is_valid(rlookup.m_idx);
+
+                    pcolumn->set_nth<T>(added_count, prev_value);
+                    pcolumn->set_valid(added_count, prev_valid);
+
+                    ccolumn->set_nth<T>(added_count, prev_value);
+                    ccolumn->set_valid(added_count, prev_valid);
+
+                    dcolumn->set_nth<T>(added_count, T{});
+                    dcolumn->set_valid(added_count, true);
+
+                    tcolumn->set_nth<std::uint8_t>(
+                        added_count, VALUE_TRANSITION_NEQ_TDF
+                    );
+                }
+            } break;
+            default: {
+                PSP_COMPLAIN_AND_ABORT("Unknown OP");
+            }
+        }
+    }
+}
+
 void
 t_gnode::send(t_uindex port_id, const t_data_table& fragments) {
     PSP_TRACE_SENTINEL();
@@ -729,6 +890,7 @@ t_gnode::process(t_uindex port_id) {
     PSP_GIL_UNLOCK();
     PSP_WRITE_LOCK(*m_lock);
     t_process_table_result result = _process_table(port_id);
+    m_update_stats.record(result.m_counts);
 
     if (result.m_flattened_data_table) {
         notify_contexts(result.m_flattened_data_table);
@@ -739,6 +901,24 @@ t_gnode::process(t_uindex port_id) {
     return result.m_should_notify_userspace;
 }
 
+void
+t_gnode::compress() {
+    PSP_TRACE_SENTINEL();
+    PSP_VERBOSE_ASSERT(m_init, "Cannot `compress` on an uninited gnode.");
+    PSP_GIL_UNLOCK();
+    PSP_WRITE_LOCK(*m_lock);
+    m_gstate->get_table()->compress();
+}
+
+t_storage_bytes
+t_gnode::get_storage_bytes() const {
+    PSP_TRACE_SENTINEL();
+    PSP_VERBOSE_ASSERT(m_init, "touching uninited object");
+    PSP_GIL_UNLOCK();
+    PSP_READ_LOCK(*m_lock);
+    return m_gstate->get_table()->get_storage_bytes();
+}
+
 t_uindex
 t_gnode::mapping_size() const {
     return m_gstate->mapping_size();
@@ -1352,6 +1532,67 @@ t_gnode::_compute_expressions(
     }
 }
 
+void
+t_gnode::_compute_virtual_columns(const std::shared_ptr<t_data_table>& flattened
+) {
+    for (const auto& expr : m_virtual_columns) {
+        expr->compute(
+            flattened,
+            m_gstate->get_pkey_map(),
+            flattened,
+            *m_expression_vocab,
+            *m_expression_regex_mapping
+        );
+    }
+}
+
+void
+t_gnode::_compute_virtual_columns(t_process_state& process_state) {
+    const auto& current = process_state.m_current_data_table;
+    const auto& flattened = process_state.m_flattened_data_table;
+    for (const auto& expr : m_virtual_columns) {
+        // `current` holds each row's values after the update, including the
+        // columns a partial update left out.
+        expr->compute(
+            current,
+            m_gstate->get_pkey_map(),
+            current,
+            *m_expression_vocab,
+            *m_expression_regex_mapping,
+            get_table_sptr()
+        );
+
+        const auto& alias = expr->get_expression_alias();
+        auto computed = current->get_column(alias);
+        auto fcolumn = flattened->get_column(alias);
+        for (t_uindex idx = 0; idx < flattened->size(); ++idx) {
+            if (process_state.m_op_base[idx] != OP_INSERT) {
+                continue;
+            }
+
+            auto value =
+                computed->get_scalar(process_state.m_added_offset[idx]);
+            if (value.is_valid()) {
+                fcolumn->set_scalar(idx, value);
+            } else {
+                fcolumn->clear(idx);
+            }
+        }
+    }
+}
+
+void
+t_gnode::set_virtual_columns(
+    std::vector<std::shared_ptr<t_computed_expression>> virtual_columns
+) {
+    m_virtual_columns = std::move(virtual_columns);
+}
+
+const std::vector<std::shared_ptr<t_computed_expression>>&
+t_gnode::get_virtual_columns() const {
+    return m_virtual_columns;
+}
+
 /******************************************************************************
  *
  * Getters
@@ -1627,6 +1868,11 @@ t_gnode::was_updated() const {
     return m_was_updated;
 }
 
+const t_update_stats&
+t_gnode::get_update_stats() const {
+    return m_update_stats;
+}
+
 void
 t_gnode::clear_updated() {
     m_was_updated = false;
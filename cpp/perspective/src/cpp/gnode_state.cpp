@@ -351,7 +351,8 @@ t_gstate::update_master_column(
                     *(flattened_column->get_nth<std::uint8_t>(idx))
                 );
             } break;
-            case DTYPE_TIME: {
+            case DTYPE_TIME:
+            case DTYPE_DURATION: {
                 master_column->set_nth<std::int64_t>(
                     master_table_idx,
                     *(flattened_column->get_nth<std::int64_t>(idx))
@@ -363,7 +364,21 @@ t_gstate::update_master_column(
                     *(flattened_column->get_nth<std::uint32_t>(idx))
                 );
             } break;
-            case DTYPE_STR: {
+            case DTYPE_UUID: {
+                master_column->set_nth<t_uuid>(
+                    master_table_idx, *(flattened_column->get_nth<t_uuid>(idx))
+                );
+            } break;
+            case DTYPE_IPADDR: {
+                master_column->set_nth<t_ipaddr>(
+                    master_table_idx,
+                    *(flattened_column->get_nth<t_ipaddr>(idx))
+                );
+            } break;
+            case DTYPE_STR:
+            case DTYPE_LIST:
+            case DTYPE_JSON:
+            case DTYPE_BINARY: {
                 master_column->set_nth<const char*>(
                     master_table_idx, flattened_column->get_nth<const char>(idx)
                 );
@@ -0,0 +1,298 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+#include <perspective/ipaddr.h>
+#include <charconv>
+#include <vector>
+
+namespace perspective {
+
+static constexpr std::uint8_t V4_MAPPED_PREFIX[12] =
+    {0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0xff, 0xff};
+
+static int
+hex_value(char c) {
+    if (c >= '0' && c <= '9') {
+        return c - '0';
+    }
+
+    if (c >= 'a' && c <= 'f') {
+        return c - 'a' + 10;
+    }
+
+    if (c >= 'A' && c <= 'F') {
+        return c - 'A' + 10;
+    }
+
+    return -1;
+}
+
+// Parses a dotted quad into `out[0..4]`.
+static bool
+parse_v4(std::string_view str, std::uint8_t* out) {
+    for (int i = 0; i < 4; ++i) {
+        if (i > 0) {
+            if (str.empty() || str.front() != '.') {
+                return false;
+            }
+
+            str.remove_prefix(1);
+        }
+
+        // Leading zeros are rejected, as they are read as octal elsewhere.
+        if (str.size() > 1 && str[0] == '0' && str[1] >= '0' && str[1] <= '9') {
+            return false;
+        }
+
+        unsigned int octet = 0;
+        auto [ptr, ec] =
+            std::from_chars(str.data(), str.data() + str.size(), octet);
+        if (ec != std::errc() || ptr == str.data() || octet > 255) {
+            return false;
+        }
+
+        out[i] = static_cast<std::uint8_t>(octet);
+        str.remove_prefix(ptr - str.data());
+    }
+
+    return str.empty();
+}
+
+static bool
+parse_v6(std::string_view str, t_ipaddr& out) {
+    // Groups before and after the `::`, if any.
+    std::vector<std::uint8_t> head;
+    std::vector<std::uint8_t> tail;
+    bool compressed = false;
+
+    if (str.size() >= 2 && str.substr(0, 2) == "::") {
+        compressed = true;
+        str.remove_prefix(2);
+    }
+
+    while (!str.empty()) {
+        std::vector<std::uint8_t>& bytes = compressed ? tail : head;
+
+        // A trailing dotted quad fills the last 4 bytes.
+        std::size_t sep = str.find(':');
+        std::string_view group = str.substr(0, sep);
+        if (sep == std::string_view::npos
+            && group.find('.') != std::string_view::npos) {
+            std::uint8_t v4[4];
+            if (!parse_v4(group, v4)) {
+                return false;
+            }
+
+            bytes.insert(bytes.end(), v4, v4 + 4);
+            break;
+        }
+
+        if (group.empty() || group.size() > 4) {
+            return false;
+        }
+
+        unsigned int value = 0;
+        for (char c : group) {
+            int digit = hex_value(c);
+            if (digit < 0) {
+                return false;
+            }
+
+            value = (value << 4) | digit;
+        }
+
+        bytes.push_back(static_cast<std::uint8_t>(value >> 8));
+        bytes.push_back(static_cast<std::uint8_t>(value & 0xff));
+        if (sep == std::string_view::npos) {
+            break;
+        }
+
+        str.remove_prefix(sep + 1);
+        if (!str.empty() && str.front() == ':') {
+            if (compressed) {
+                return false;
+            }
+
+            compressed = true;
+            str.remove_prefix(1);
+        } else if (str.empty()) {
+            return false;
+        }
+    }
+
+    std::size_t size = head.size() + tail.size();
+    if (compressed ? size > 14 : size != 16) {
+        return false;
+    }
+
+    std::memset(out.m_bytes, 0, 16);
+    std::memcpy(out.m_bytes, head.data(), head.size());
+    std::memcpy(out.m_bytes + 16 - tail.size(), tail.data(), tail.size());
+    return true;
+}
+
+t_ipaddr
+t_ipaddr::from_v4(const std::uint8_t* octets) {
+    t_ipaddr rval;
+    std::memcpy(rval.m_bytes, V4_MAPPED_PREFIX, 12);
+    std::memcpy(rval.m_bytes + 12, octets, 4);
+    return rval;
+}
+
+bool
+t_ipaddr::is_v4() const {
+    return std::memcmp(m_bytes, V4_MAPPED_PREFIX, 12) == 0;
+}
+
+std::string
+t_ipaddr::str() const {
+    std::string rval;
+    if (is_v4()) {
+        for (int i = 12; i < 16; ++i) {
+            if (i > 12) {
+                rval.push_back('.');
+            }
+
+            rval += std::to_string(m_bytes[i]);
+        }
+
+        return rval;
+    }
+
+    std::uint16_t groups[8];
+    for (int i = 0; i < 8; ++i) {
+        groups[i] = (m_bytes[i * 2] << 8) | m_bytes[i * 2 + 1];
+    }
+
+    // Compress the first longest run of two or more zero groups.
+    int best_start = -1;
+    int best_len = 1;
+    for (int i = 0; i < 8;) {
+        int j = i;
+        while (j < 8 && groups[j] == 0) {
+            ++j;
+        }
+
+        if (j - i > best_len) {
+            best_start = i;
+            best_len = j - i;
+        }
+
+        i = j == i ? i + 1 : j;
+    }
+
+    char buf[5];
+    for (int i = 0; i < 8; ++i) {
+        if (i == best_start) {
+            rval += "::";
+            i += best_len - 1;
+            continue;
+        }
+
+        if (!rval.empty() && rval.back() != ':') {
+            rval.push_back(':');
+        }
+
+        auto [ptr, ec] = std::to_chars(buf, buf + 4, groups[i], 16);
+        rval.append(buf, ptr);
+    }
+
+    return rval;
+}
+
+bool
+t_subnet::contains(const t_ipaddr& addr) const {
+    return !(addr < m_first) && !(m_last < addr);
+}
+
+std::string
+t_subnet::str() const {
+    int prefix = 0;
+    while (prefix < 128) {
+        std::uint8_t mask = 0x80 >> (prefix % 8);
+        if ((m_first.m_bytes[prefix / 8] & mask)
+            != (m_last.m_bytes[prefix / 8] & mask)) {
+            break;
+        }
+
+        ++prefix;
+    }
+
+    if (m_first.is_v4() && prefix >= 96) {
+        prefix -= 96;
+    }
+
+    return m_first.str() + "/" + std::to_string(prefix);
+}
+
+bool
+parse_ipaddr(std::string_view str, t_ipaddr& out) {
+    if (str.find(':') == std::string_view::npos) {
+        std::uint8_t octets[4];
+        if (!parse_v4(str, octets)) {
+            return false;
+        }
+
+        out = t_ipaddr::from_v4(octets);
+        return true;
+    }
+
+    return parse_v6(str, out);
+}
+
+bool
+parse_subnet(std::string_view str, t_subnet& out) {
+    std::size_t slash = str.find('/');
+    t_ipaddr addr;
+    if (!parse_ipaddr(str.substr(0, slash), addr)) {
+        return false;
+    }
+
+    int max_prefix = addr.is_v4() && str.find(':') == std::string_view::npos
+        ? 32
+        : 128;
+
+    int prefix = max_prefix;
+    if (slash != std::string_view::npos) {
+        std::string_view bits = str.substr(slash + 1);
+        auto [ptr, ec] =
+            std::from_chars(bits.data(), bits.data() + bits.size(), prefix);
+        if (ec != std::errc() || ptr != bits.data() + bits.size() || prefix < 0
+            || prefix > max_prefix) {
+            return false;
+        }
+    }
+
+    if (max_prefix == 32) {
+        prefix += 96;
+    }
+
+    out.m_first = addr;
+    out.m_last = addr;
+    for (int bit = prefix; bit < 128; ++bit) {
+        std::uint8_t mask = 0x80 >> (bit % 8);
+        out.m_first.m_bytes[bit / 8] &= ~mask;
+        out.m_last.m_bytes[bit / 8] |= mask;
+    }
+
+    return true;
+}
+
+} // end namespace perspective
+
+namespace std {
+std::ostream&
+operator<<(std::ostream& os, const perspective::t_ipaddr& addr) {
+    os << addr.str();
+    return os;
+}
+} // namespace std
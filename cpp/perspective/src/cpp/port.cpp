@@ -12,6 +12,7 @@
 
 #include <perspective/first.h>
 #include <perspective/port.h>
+#include <perspective/engine_config.h>
 
 #include <utility>
 
@@ -31,7 +32,11 @@ void
 t_port::init() {
     m_table = nullptr;
     m_table = std::make_shared<t_data_table>(
-        "", "", m_schema, DEFAULT_EMPTY_CAPACITY, BACKING_STORE_MEMORY
+        "",
+        "",
+        m_schema,
+        get_engine_config().m_port_capacity,
+        BACKING_STORE_MEMORY
     );
     m_table->init();
     m_init = true;
@@ -75,7 +80,11 @@ t_port::release()
 
     m_table = nullptr;
     m_table = std::make_shared<t_data_table>(
-        "", "", m_schema, DEFAULT_EMPTY_CAPACITY, BACKING_STORE_MEMORY
+        "",
+        "",
+        m_schema,
+        get_engine_config().m_port_capacity,
+        BACKING_STORE_MEMORY
     );
     m_table->init();
 
@@ -12,7 +12,12 @@
 
 #include "perspective/server.h"
 #include "perspective/proto_api.h"
+#include "perspective/affinity.h"
+#include "perspective/engine_config.h"
+#include <chrono>
 #include <memory>
+#include <optional>
+#include <stdexcept>
 
 class ProtoApiServer::ProtoApiServerImpl {
 public:
@@ -40,9 +45,14 @@ ProtoApiServer::close_session(const std::uint32_t& client_id) {
 }
 
 std::vector<ProtoApiResponse>
-ProtoApiServer::handle_request(std::uint32_t client_id, const std::string& data)
-    const {
-    auto responses = m_impl->m_server->handle_request(client_id, data);
+ProtoApiServer::handle_request(
+    std::uint32_t client_id,
+    const std::string& data,
+    const std::string& request_id
+) const {
+    perspective::t_affinity_scope affinity;
+    auto responses =
+        m_impl->m_server->handle_request(client_id, data, request_id);
     std::vector<ProtoApiResponse> results;
     for (const auto& msg : responses) {
         ProtoApiResponse resp;
@@ -54,8 +64,23 @@ ProtoApiServer::handle_request(std::uint32_t client_id, const std::string& data)
     return results;
 }
 
+std::vector<ProtoApiResponse>
+ProtoApiServer::unhost_table(const std::string& table_id) {
+    perspective::t_affinity_scope affinity;
+    std::vector<ProtoApiResponse> results;
+    for (const auto& msg : m_impl->m_server->unhost_table(table_id)) {
+        ProtoApiResponse resp;
+        resp.client_id = msg.client_id;
+        resp.data = msg.data;
+        results.push_back(resp);
+    }
+
+    return results;
+}
+
 std::vector<ProtoApiResponse>
 ProtoApiServer::poll() {
+    perspective::t_affinity_scope affinity;
     std::vector<ProtoApiResponse> results;
     for (const auto& msg : m_impl->m_server->poll()) {
         ProtoApiResponse resp;
@@ -66,3 +91,121 @@ ProtoApiServer::poll() {
 
     return results;
 }
+
+std::int64_t
+ProtoApiServer::get_poll_delay_ms() {
+    auto delay = m_impl->m_server->get_poll_delay();
+    return delay.has_value() ? delay->count() : -1;
+}
+
+std::vector<ProtoApiResponse>
+ProtoApiServer::host_arrow_stream(
+    const std::string& table_id,
+    const std::string& index,
+    ArrowArrayStream* stream
+) {
+    perspective::t_affinity_scope affinity;
+    std::vector<ProtoApiResponse> results;
+    for (const auto& msg :
+         m_impl->m_server->host_arrow_stream(table_id, index, stream)) {
+        ProtoApiResponse resp;
+        resp.client_id = msg.client_id;
+        resp.data = msg.data;
+        results.push_back(resp);
+    }
+
+    return results;
+}
+
+void
+ProtoApiServer::update_arrow_stream(
+    const std::string& table_id,
+    ArrowArrayStream* stream,
+    std::uint32_t port_id
+) {
+    perspective::t_affinity_scope affinity;
+    m_impl->m_server->update_arrow_stream(table_id, stream, port_id);
+}
+
+std::vector<ProtoApiResponse>
+ProtoApiServer::view_to_arrow_stream(
+    const std::string& view_id, ArrowArrayStream* out
+) {
+    perspective::t_affinity_scope affinity;
+    std::vector<ProtoApiResponse> results;
+    for (const auto& msg :
+         m_impl->m_server->view_to_arrow_stream(view_id, out)) {
+        ProtoApiResponse resp;
+        resp.client_id = msg.client_id;
+        resp.data = msg.data;
+        results.push_back(resp);
+    }
+
+    return results;
+}
+
+void
+ProtoApiServer::set_slow_op_threshold(std::int64_t threshold_us) {
+    std::optional<std::chrono::microseconds> threshold;
+    if (threshold_us >= 0) {
+        threshold = std::chrono::microseconds(threshold_us);
+    }
+
+    m_impl->m_server->set_slow_op_threshold(threshold);
+}
+
+void
+ProtoApiServer::push_profile(const std::string& profile) {
+    perspective::proto::Profile proto_profile;
+    if (!proto_profile.ParseFromString(profile)) {
+        throw std::runtime_error("Invalid profile");
+    }
+
+    m_impl->m_server->push_profile(std::move(proto_profile));
+}
+
+void
+ProtoApiServer::define_expression(
+    const std::string& name, const std::string& expression
+) {
+    m_impl->m_server->define_expression(name, expression);
+}
+
+void
+ProtoApiServer::remove_expression(const std::string& name) {
+    m_impl->m_server->remove_expression(name);
+}
+
+void
+ProtoApiServer::set_engine_affinity(
+    const std::vector<std::uint32_t>& cores,
+    std::int32_t numa_node,
+    std::uint32_t num_threads
+) {
+    perspective::t_affinity_options options;
+    options.m_cores = cores;
+    if (numa_node >= 0) {
+        options.m_numa_node = numa_node;
+    }
+
+    if (num_threads > 0) {
+        options.m_num_threads = num_threads;
+    }
+
+    perspective::set_engine_affinity(options);
+}
+
+void
+ProtoApiServer::set_engine_config(
+    std::uint64_t parallel_threshold,
+    std::uint64_t expression_block_size,
+    std::uint64_t tree_capacity,
+    std::uint64_t port_capacity
+) {
+    perspective::t_engine_config config;
+    config.m_parallel_threshold = parallel_threshold;
+    config.m_expression_block_size = expression_block_size;
+    config.m_tree_capacity = tree_capacity;
+    config.m_port_capacity = port_capacity;
+    perspective::set_engine_config(config);
+}
@@ -19,6 +19,7 @@
 #include <cstdio>
 #include <functional>
 #include <cstdint>
+#include <limits>
 #include <vector>
 #include <boost/algorithm/string/case_conv.hpp>
 #include <sstream>
@@ -33,6 +34,89 @@ operator>(const std::size_t& lhs, const t_tscalar& rhs) {
     return rhs.operator<(lhs);
 }
 
+/**
+ * @brief Whether an arithmetic operator on `lhs` and `rhs` is evaluated
+ * exactly as `int64`, rather than as `float64`. This is the case when both
+ * operands are signed integers and at least one is an `int64`, so that
+ * values beyond 2^53 are not rounded, while `int32` arithmetic keeps its
+ * `float64` result type.
+ */
+static bool
+is_int64_arithmetic(const t_tscalar& lhs, const t_tscalar& rhs) {
+    auto is_signed_int = [](t_dtype dtype) {
+        switch (dtype) {
+            case DTYPE_INT64:
+            case DTYPE_INT32:
+            case DTYPE_INT16:
+            case DTYPE_INT8:
+                return true;
+            default:
+                return false;
+        }
+    };
+
+    return is_signed_int(lhs.get_dtype()) && is_signed_int(rhs.get_dtype())
+        && (lhs.get_dtype() == DTYPE_INT64 || rhs.get_dtype() == DTYPE_INT64);
+}
+
+// Checked `int64` arithmetic, which returns `true` on overflow (like the GCC
+// `__builtin_*_overflow` builtins, which MSVC lacks).
+static bool
+checked_add(std::int64_t a, std::int64_t b, std::int64_t* out) {
+    constexpr auto max = std::numeric_limits<std::int64_t>::max();
+    constexpr auto min = std::numeric_limits<std::int64_t>::min();
+    if ((b > 0 && a > max - b) || (b < 0 && a < min - b)) {
+        return true;
+    }
+
+    *out = a + b;
+    return false;
+}
+
+static bool
+checked_sub(std::int64_t a, std::int64_t b, std::int64_t* out) {
+    constexpr auto max = std::numeric_limits<std::int64_t>::max();
+    constexpr auto min = std::numeric_limits<std::int64_t>::min();
+    if ((b < 0 && a > max + b) || (b > 0 && a < min + b)) {
+        return true;
+    }
+
+    *out = a - b;
+    return false;
+}
+
+static bool
+checked_mul(std::int64_t a, std::int64_t b, std::int64_t* out) {
+    constexpr auto max = std::numeric_limits<std::int64_t>::max();
+    constexpr auto min = std::numeric_limits<std::int64_t>::min();
+    bool overflow = a > 0 ? (b > 0 ? a > max / b : b < min / a)
+                          : (b > 0 ? a < min / b : (a != 0 && b < max / a));
+
+    if (overflow) {
+        return true;
+    }
+
+    *out = a * b;
+    return false;
+}
+
+// `int64` arithmetic which overflows is null, rather than wrapping.
+#define INT64_OPERATOR_BODY(CHECKED_OP)                                        \
+    if (is_int64_arithmetic(*this, other)) {                                   \
+        t_tscalar rval;                                                        \
+        rval.clear();                                                          \
+        rval.m_type = DTYPE_INT64;                                             \
+        if (!other.is_valid() || !is_valid()) {                                \
+            return rval;                                                       \
+        }                                                                      \
+        std::int64_t result;                                                   \
+        if (CHECKED_OP(to_int64(), other.to_int64(), &result)) {               \
+            return rval;                                                       \
+        }                                                                      \
+        rval.set(result);                                                      \
+        return rval;                                                           \
+    }
+
 #define BINARY_OPERATOR_BODY(OP)                                               \
     t_tscalar rval;                                                            \
     rval.clear();                                                              \
@@ -89,7 +173,16 @@ t_tscalar::operator==(const t_tscalar& rhs) const {
         return get<bool>() == rhs.get<bool>();
     }
 
-    if (m_type != DTYPE_STR) {
+    if (m_type == DTYPE_UUID) {
+        return std::memcmp(m_data.m_uuid, rhs.m_data.m_uuid, 16) == 0;
+    }
+
+    if (m_type == DTYPE_IPADDR) {
+        return std::memcmp(m_data.m_ipaddr, rhs.m_data.m_ipaddr, 16) == 0;
+    }
+
+    if (m_type != DTYPE_STR && m_type != DTYPE_LIST && m_type != DTYPE_JSON
+        && m_type != DTYPE_BINARY) {
         return m_data.m_uint64 == rhs.m_data.m_uint64;
     }
 
@@ -225,14 +318,80 @@ t_tscalar::operator-() const {
     return rval;
 }
 
+/**
+ * @brief Arithmetic between datetimes and durations, which preserves the
+ * temporal type of the result rather than collapsing to `DTYPE_FLOAT64`:
+ *
+ * datetime - datetime => duration
+ * datetime +/- duration => datetime
+ * duration +/- duration => duration
+ *
+ * Returns `false` if the operands are not a temporal combination, in which
+ * case the caller should fall back to numeric arithmetic.
+ */
+static bool
+temporal_arithmetic(
+    const t_tscalar& lhs, const t_tscalar& rhs, bool subtract, t_tscalar& rval
+) {
+    t_dtype ltype = lhs.get_dtype();
+    t_dtype rtype = rhs.get_dtype();
+    if (ltype == DTYPE_TIME && rtype == DTYPE_TIME && subtract) {
+        rval.m_type = DTYPE_DURATION;
+    } else if (ltype == DTYPE_TIME && rtype == DTYPE_DURATION) {
+        rval.m_type = DTYPE_TIME;
+    } else if (ltype == DTYPE_DURATION && rtype == DTYPE_TIME && !subtract) {
+        rval.m_type = DTYPE_TIME;
+    } else if (ltype == DTYPE_DURATION && rtype == DTYPE_DURATION) {
+        rval.m_type = DTYPE_DURATION;
+    } else {
+        return false;
+    }
+
+    if (!lhs.is_valid() || !rhs.is_valid()) {
+        return true;
+    }
+
+    std::int64_t a = lhs.get<std::int64_t>();
+    std::int64_t b = rhs.get<std::int64_t>();
+    std::int64_t result = subtract ? a - b : a + b;
+    if (rval.m_type == DTYPE_TIME) {
+        rval.set(t_time(result));
+    } else {
+        rval.set(t_tdelta(result));
+    }
+
+    return true;
+}
+
+t_tscalar
+t_tscalar::operator+(const t_tscalar& other) const {
+    t_tscalar temporal;
+    temporal.clear();
+    if (temporal_arithmetic(*this, other, false, temporal)) {
+        return temporal;
+    }
+
+    INT64_OPERATOR_BODY(checked_add)
+    BINARY_OPERATOR_BODY(+)
+}
+
 t_tscalar
-t_tscalar::operator+(const t_tscalar& other) const {BINARY_OPERATOR_BODY(+)}
+t_tscalar::operator-(const t_tscalar& other) const {
+    t_tscalar temporal;
+    temporal.clear();
+    if (temporal_arithmetic(*this, other, true, temporal)) {
+        return temporal;
+    }
 
-t_tscalar t_tscalar::operator-(const t_tscalar& other
-) const {BINARY_OPERATOR_BODY(-)}
+    INT64_OPERATOR_BODY(checked_sub)
+    BINARY_OPERATOR_BODY(-)
+}
 
-t_tscalar t_tscalar::operator*(const t_tscalar& other
-) const {BINARY_OPERATOR_BODY(*)}
+t_tscalar
+t_tscalar::operator*(const t_tscalar& other) const {
+    INT64_OPERATOR_BODY(checked_mul)
+    BINARY_OPERATOR_BODY(*)
+}
 
 t_tscalar t_tscalar::operator/(const t_tscalar& other) const {
     t_tscalar rval;
@@ -461,7 +620,7 @@ t_tscalar::is_numeric() const {
 void
 t_tscalar::clear() {
     m_type = DTYPE_NONE;
-    m_data.m_uint64 = 0;
+    std::memset(&m_data, 0, sizeof(m_data));
     m_status = STATUS_INVALID;
 }
 
@@ -510,14 +669,26 @@ t_tscalar::canonical(t_dtype dtype) {
         case DTYPE_TIME: {
             rval.set(t_time(0));
         } break;
+        case DTYPE_DURATION: {
+            rval.set(t_tdelta(0));
+        } break;
+        case DTYPE_UUID: {
+            rval.set(t_uuid{});
+        } break;
+        case DTYPE_IPADDR: {
+            rval.set(t_ipaddr{});
+        } break;
         case DTYPE_BOOL: {
             rval.set(false);
         } break;
         case DTYPE_NONE: {
             // handled trivially
         } break;
-        case DTYPE_STR: {
-            rval.m_type = DTYPE_STR;
+        case DTYPE_STR:
+        case DTYPE_LIST:
+        case DTYPE_JSON:
+        case DTYPE_BINARY: {
+            rval.m_type = dtype;
         } break;
         case DTYPE_OBJECT:
         default: {
@@ -628,6 +799,24 @@ t_tscalar::set(const char* v) {
     m_status = STATUS_VALID;
 }
 
+void
+t_tscalar::set_list(const char* v) {
+    set(v);
+    m_type = DTYPE_LIST;
+}
+
+void
+t_tscalar::set_json(const char* v) {
+    set(v);
+    m_type = DTYPE_JSON;
+}
+
+void
+t_tscalar::set_binary(const char* v) {
+    set(v);
+    m_type = DTYPE_BINARY;
+}
+
 void
 t_tscalar::set(const t_date v) {
     m_type = DTYPE_DATE;
@@ -643,6 +832,27 @@ t_tscalar::set(const t_time v) {
     m_status = STATUS_VALID;
 }
 
+void
+t_tscalar::set(const t_tdelta v) {
+    m_type = DTYPE_DURATION;
+    m_data.m_int64 = v.raw_value();
+    m_status = STATUS_VALID;
+}
+
+void
+t_tscalar::set(const t_uuid& v) {
+    m_type = DTYPE_UUID;
+    std::memcpy(m_data.m_uuid, v.m_bytes, 16);
+    m_status = STATUS_VALID;
+}
+
+void
+t_tscalar::set(const t_ipaddr& v) {
+    m_type = DTYPE_IPADDR;
+    std::memcpy(m_data.m_ipaddr, v.m_bytes, 16);
+    m_status = STATUS_VALID;
+}
+
 void
 t_tscalar::set(const t_none v) {
     m_data.m_uint64 = 0;
@@ -653,7 +863,7 @@ t_tscalar::set(const t_none v) {
 void
 t_tscalar::set(const t_tscalar v) {
     m_type = v.m_type;
-    memcpy(&m_data, &(v.m_data), SCALAR_INPLACE_LEN);
+    memcpy(&m_data, &(v.m_data), sizeof(m_data));
     m_status = v.m_status;
     m_inplace = v.m_inplace;
 }
@@ -678,6 +888,9 @@ t_tscalar::abs() const {
             std::int64_t v = std::abs(to_double());
             rval.set(v);
         } break;
+        case DTYPE_DURATION: {
+            rval.set(t_tdelta(std::abs(m_data.m_int64)));
+        } break;
         case DTYPE_INT32: {
             std::int32_t v = std::abs(to_double());
             rval.set(v);
@@ -724,6 +937,9 @@ t_tscalar::negate() const {
         case DTYPE_INT64: {
             rval.set(-(m_data.m_int64));
         } break;
+        case DTYPE_DURATION: {
+            rval.set(t_tdelta(-(m_data.m_int64)));
+        } break;
         case DTYPE_INT32: {
             rval.set(-(m_data.m_int32));
         } break;
@@ -791,6 +1007,9 @@ t_tscalar::add(const t_tscalar& other) const {
         case DTYPE_INT64: {
             rval.set(m_data.m_int64 + other.m_data.m_int64);
         } break;
+        case DTYPE_DURATION: {
+            rval.set(t_tdelta(m_data.m_int64 + other.m_data.m_int64));
+        } break;
         case DTYPE_INT32: {
             rval.set(m_data.m_int32 + other.m_data.m_int32);
         } break;
@@ -858,6 +1077,9 @@ t_tscalar::difference(const t_tscalar& other) const {
         case DTYPE_INT64: {
             rval.set(m_data.m_int64 - other.m_data.m_int64);
         } break;
+        case DTYPE_DURATION: {
+            rval.set(t_tdelta(m_data.m_int64 - other.m_data.m_int64));
+        } break;
         case DTYPE_INT32: {
             rval.set(m_data.m_int32 - other.m_data.m_int32);
         } break;
@@ -981,16 +1203,26 @@ t_tscalar::as_bool() const {
         case DTYPE_DATE: {
             return bool(get<std::uint32_t>());
         } break;
-        case DTYPE_TIME: {
+        case DTYPE_TIME:
+        case DTYPE_DURATION: {
             return bool(get<std::int64_t>());
         } break;
+        case DTYPE_UUID: {
+            return get<t_uuid>() != t_uuid{};
+        } break;
+        case DTYPE_IPADDR: {
+            return get<t_ipaddr>() != t_ipaddr{};
+        } break;
         case DTYPE_BOOL: {
             return bool(get<bool>());
         } break;
         case DTYPE_NONE: {
             return bool(false);
         } break;
-        case DTYPE_STR: {
+        case DTYPE_STR:
+        case DTYPE_LIST:
+        case DTYPE_JSON:
+        case DTYPE_BINARY: {
             return m_data.m_charptr != nullptr;
         } break;
         case DTYPE_OBJECT:
@@ -1085,6 +1317,23 @@ t_tscalar::to_string(bool for_expr) const {
             date::sys_time<std::chrono::milliseconds> ts(timestamp);
             return date::format("%F %T", ts);
         } break;
+        case DTYPE_DURATION: {
+            return get<t_tdelta>().str();
+        } break;
+        case DTYPE_UUID: {
+            if (for_expr) {
+                return "'" + get<t_uuid>().str() + "'";
+            }
+
+            return get<t_uuid>().str();
+        } break;
+        case DTYPE_IPADDR: {
+            if (for_expr) {
+                return "'" + get<t_ipaddr>().str() + "'";
+            }
+
+            return get<t_ipaddr>().str();
+        } break;
         case DTYPE_STR: {
             if (for_expr) {
                 ss << "'";
@@ -1104,6 +1353,27 @@ t_tscalar::to_string(bool for_expr) const {
             }
             return ss.str();
         } break;
+        case DTYPE_LIST: {
+            if (m_data.m_charptr == nullptr) {
+                return "[]";
+            }
+
+            return get_char_ptr();
+        } break;
+        case DTYPE_JSON: {
+            if (m_data.m_charptr == nullptr) {
+                return "null";
+            }
+
+            return get_char_ptr();
+        } break;
+        case DTYPE_BINARY: {
+            if (m_data.m_charptr == nullptr) {
+                return "";
+            }
+
+            return get_char_ptr();
+        } break;
         case DTYPE_OBJECT:
         default: {
             PSP_COMPLAIN_AND_ABORT("Unrecognized dtype");
@@ -1148,7 +1418,8 @@ t_tscalar::to_double() const {
         case DTYPE_DATE: {
             return get<std::uint32_t>();
         } break;
-        case DTYPE_TIME: {
+        case DTYPE_TIME:
+        case DTYPE_DURATION: {
             return get<std::int64_t>();
         } break;
         case DTYPE_BOOL: {
@@ -1199,6 +1470,11 @@ t_tscalar::coerce_numeric_dtype(t_dtype dtype) const {
         case DTYPE_BOOL: {
             return coerce_numeric<bool>();
         } break;
+        case DTYPE_DURATION: {
+            t_tscalar rv = mknone();
+            rv.set(t_tdelta(to_int64()));
+            return rv;
+        } break;
         default: {
             return *this;
         }
@@ -1243,7 +1519,8 @@ t_tscalar::to_int64() const {
         case DTYPE_DATE: {
             return get<std::uint32_t>();
         } break;
-        case DTYPE_TIME: {
+        case DTYPE_TIME:
+        case DTYPE_DURATION: {
             return get<std::int64_t>();
         } break;
         case DTYPE_BOOL: {
@@ -1294,7 +1571,8 @@ t_tscalar::to_int32() const {
         case DTYPE_DATE: {
             return get<std::uint32_t>();
         } break;
-        case DTYPE_TIME: {
+        case DTYPE_TIME:
+        case DTYPE_DURATION: {
             return get<std::int64_t>();
         } break;
         case DTYPE_BOOL: {
@@ -1345,7 +1623,8 @@ t_tscalar::to_uint64() const {
         case DTYPE_DATE: {
             return get<std::uint32_t>();
         } break;
-        case DTYPE_TIME: {
+        case DTYPE_TIME:
+        case DTYPE_DURATION: {
             return get<std::int64_t>();
         } break;
         case DTYPE_BOOL: {
@@ -1410,10 +1689,20 @@ repr(const t_tscalar& s) {
 size_t
 hash_value(const t_tscalar& s) {
     std::size_t seed = 0;
-    if (s.m_type == DTYPE_STR) {
+    if (s.m_type == DTYPE_STR || s.m_type == DTYPE_LIST
+        || s.m_type == DTYPE_JSON || s.m_type == DTYPE_BINARY) {
         const char* c = s.get_char_ptr();
         boost::hash_combine(seed, boost::hash_range(c, c + std::strlen(c)));
 
+    } else if (s.m_type == DTYPE_UUID) {
+        boost::hash_combine(
+            seed, boost::hash_range(s.m_data.m_uuid, s.m_data.m_uuid + 16)
+        );
+    } else if (s.m_type == DTYPE_IPADDR) {
+        boost::hash_combine(
+            seed,
+            boost::hash_range(s.m_data.m_ipaddr, s.m_data.m_ipaddr + 16)
+        );
     } else {
         boost::hash_combine(seed, s.m_data.m_uint64);
     }
@@ -1514,6 +1803,28 @@ t_tscalar::get() const {
     return t_time(m_data.m_int64);
 }
 
+template <>
+t_uuid
+t_tscalar::get() const {
+    t_uuid rval;
+    std::memcpy(rval.m_bytes, m_data.m_uuid, 16);
+    return rval;
+}
+
+template <>
+t_ipaddr
+t_tscalar::get() const {
+    t_ipaddr rval;
+    std::memcpy(rval.m_bytes, m_data.m_ipaddr, 16);
+    return rval;
+}
+
+template <>
+t_tdelta
+t_tscalar::get() const {
+    return t_tdelta(m_data.m_int64);
+}
+
 template <>
 t_none
 t_tscalar::get() const {
@@ -1616,10 +1927,11 @@ mknone() {
 t_tscalar
 mknull(t_dtype dtype) {
     t_tscalar rval;
-    rval.m_data.m_uint64 = 0;
+    std::memset(&rval.m_data, 0, sizeof(rval.m_data));
     rval.m_status = STATUS_INVALID;
     rval.m_type = dtype;
-    if (dtype == DTYPE_STR) {
+    if (dtype == DTYPE_STR || dtype == DTYPE_LIST || dtype == DTYPE_JSON
+        || dtype == DTYPE_BINARY) {
         rval.m_inplace = true;
     }
     return rval;
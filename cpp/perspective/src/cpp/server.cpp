@@ -13,9 +13,11 @@
 #include "google/protobuf/repeated_ptr_field.h"
 #include "google/protobuf/struct.pb.h"
 #include "perspective.pb.h"
+#include "perspective/arrow_ingest.h"
 #include "perspective/base.h"
 #include "perspective/computed_expression.h"
 #include "perspective/exception.h"
+#include "perspective/expression_catalog.h"
 #include "perspective/pyutils.h"
 #include "perspective/raw_types.h"
 #include "perspective/scalar.h"
@@ -24,13 +26,18 @@
 #include "perspective/time.h"
 #include "perspective/view.h"
 #include "perspective/view_config.h"
+#include "rapidjson/document.h"
 #include "re2/re2.h"
+#include <algorithm>
 #include <chrono>
 #include <cstdint>
 #include <cstring>
+#include <iostream>
+#include <iterator>
 #include <limits>
 #include <memory>
 #include <perspective/server.h>
+#include <set>
 #include <re2/stringpiece.h>
 #include <string>
 #include <tsl/hopscotch_map.h>
@@ -41,6 +48,97 @@
 namespace perspective {
 std::uint32_t server::ProtoServer::m_client_id = 1;
 
+// Apply the `Table`'s declared category orders to a context's config, so its
+// pivots and sorts follow them.
+static void
+set_category_orders(t_config& cfg, const std::shared_ptr<Table>& table) {
+    for (const auto& [column, categories] : table->get_categories()) {
+        cfg.set_category_order(column, categories);
+    }
+}
+
+// Encode a scalar as JSON, as `write_scalar` does for `View` output.
+static std::string
+scalar_to_json(const t_tscalar& scalar) {
+    rapidjson::StringBuffer s;
+    rapidjson::Writer<rapidjson::StringBuffer> writer(s);
+    write_scalar(scalar, true, writer);
+    return s.GetString();
+}
+
+// Apply the view's null handling policies to a pivoted context's config.
+static void
+set_null_policies(
+    t_config& cfg, const std::shared_ptr<t_view_config>& view_config
+) {
+    cfg.set_exclude_null_groups(view_config->get_exclude_null_groups());
+    cfg.set_null_aggregates(view_config->get_null_aggregates());
+}
+
+static t_totals
+total_position_to_totals(proto::ViewConfig_TotalPosition position) {
+    switch (position) {
+        case proto::ViewConfig_TotalPosition_TOTAL_POSITION_BOTTOM:
+            return TOTALS_AFTER;
+        case proto::ViewConfig_TotalPosition_TOTAL_POSITION_HIDDEN:
+            return TOTALS_HIDDEN;
+        case proto::ViewConfig_TotalPosition_TOTAL_POSITION_TOP:
+        default:
+            return TOTALS_BEFORE;
+    }
+}
+
+static t_show_values_as
+show_values_as_from_proto(proto::ViewConfig_ShowValuesAs show) {
+    switch (show) {
+        case proto::ViewConfig::SHOW_VALUES_AS_PERCENT_OF_ROW_TOTAL:
+            return SHOW_VALUES_AS_PCT_ROW_TOTAL;
+        case proto::ViewConfig::SHOW_VALUES_AS_PERCENT_OF_COLUMN_TOTAL:
+            return SHOW_VALUES_AS_PCT_COLUMN_TOTAL;
+        case proto::ViewConfig::SHOW_VALUES_AS_PERCENT_OF_GRAND_TOTAL:
+            return SHOW_VALUES_AS_PCT_GRAND_TOTAL;
+        case proto::ViewConfig::SHOW_VALUES_AS_DIFFERENCE_FROM_PREVIOUS:
+            return SHOW_VALUES_AS_DIFFERENCE_FROM_PREVIOUS;
+        case proto::ViewConfig::SHOW_VALUES_AS_RUNNING_TOTAL:
+            return SHOW_VALUES_AS_RUNNING_TOTAL;
+        case proto::ViewConfig::SHOW_VALUES_AS_VALUE:
+        default:
+            return SHOW_VALUES_AS_VALUE;
+    }
+}
+
+static proto::ViewConfig_ShowValuesAs
+show_values_as_to_proto(t_show_values_as show) {
+    switch (show) {
+        case SHOW_VALUES_AS_PCT_ROW_TOTAL:
+            return proto::ViewConfig::SHOW_VALUES_AS_PERCENT_OF_ROW_TOTAL;
+        case SHOW_VALUES_AS_PCT_COLUMN_TOTAL:
+            return proto::ViewConfig::SHOW_VALUES_AS_PERCENT_OF_COLUMN_TOTAL;
+        case SHOW_VALUES_AS_PCT_GRAND_TOTAL:
+            return proto::ViewConfig::SHOW_VALUES_AS_PERCENT_OF_GRAND_TOTAL;
+        case SHOW_VALUES_AS_DIFFERENCE_FROM_PREVIOUS:
+            return proto::ViewConfig::SHOW_VALUES_AS_DIFFERENCE_FROM_PREVIOUS;
+        case SHOW_VALUES_AS_RUNNING_TOTAL:
+            return proto::ViewConfig::SHOW_VALUES_AS_RUNNING_TOTAL;
+        case SHOW_VALUES_AS_VALUE:
+        default:
+            return proto::ViewConfig::SHOW_VALUES_AS_VALUE;
+    }
+}
+
+static proto::ViewConfig_TotalPosition
+totals_to_total_position(t_totals totals) {
+    switch (totals) {
+        case TOTALS_AFTER:
+            return proto::ViewConfig_TotalPosition_TOTAL_POSITION_BOTTOM;
+        case TOTALS_HIDDEN:
+            return proto::ViewConfig_TotalPosition_TOTAL_POSITION_HIDDEN;
+        case TOTALS_BEFORE:
+        default:
+            return proto::ViewConfig_TotalPosition_TOTAL_POSITION_TOP;
+    }
+}
+
 template <>
 std::shared_ptr<t_ctxunit>
 make_context(
@@ -83,6 +181,7 @@ make_context(
     auto expressions = view_config->get_used_expressions();
 
     auto cfg = t_config(columns, fterm, filter_op, expressions);
+    set_category_orders(cfg, table);
     auto ctx0 = std::make_shared<t_ctx0>(*schema, cfg);
     ctx0->init();
     ctx0->sort_by(sortspec);
@@ -115,6 +214,12 @@ make_context(
     auto expressions = view_config->get_used_expressions();
 
     auto cfg = t_config(row_pivots, aggspecs, fterm, filter_op, expressions);
+    set_category_orders(cfg, table);
+    set_null_policies(cfg, view_config);
+    cfg.set_row_totals(
+        view_config->get_grand_total(), view_config->get_subtotals()
+    );
+    cfg.set_show_values_as(view_config->get_show_values_as());
     auto ctx1 = std::make_shared<t_ctx1>(*schema, cfg);
 
     ctx1->init();
@@ -170,6 +275,18 @@ make_context(
         expressions,
         column_only
     );
+    set_category_orders(cfg, table);
+    set_null_policies(cfg, view_config);
+
+    // Column only views skip their first row, which is always the grand
+    // total, and their rows are not groups with subtotals.
+    if (!column_only) {
+        cfg.set_row_totals(
+            view_config->get_grand_total(), view_config->get_subtotals()
+        );
+    }
+
+    cfg.set_show_values_as(view_config->get_show_values_as());
     auto ctx2 = std::make_shared<t_ctx2>(*schema, cfg);
 
     ctx2->init();
@@ -303,7 +420,9 @@ re_intern_strings(std::string&& expression) {
 static auto
 re_unintern_some_exprs(std::string&& expression) {
     static const RE2 interned_param(
-        "(?:bucket|match|match_all|search|indexof|replace|replace_all)\\("
+        "(?:bucket|match|match_all|search|indexof|replace|replace_all|"
+        "regex_match|regex_extract|regex_replace|"
+        "convert_tz|at_tz|json_extract|json_extract_float|json_extract_bool)\\("
         "(?:.*?,\\s*(intern\\(('.*?')\\)))"
     );
     static const RE2 intern_match("intern\\(('.*?')\\)");
@@ -375,6 +494,7 @@ namespace perspective::server {
 void
 ServerResources::host_table(const t_id& id, std::shared_ptr<Table> table) {
     PSP_WRITE_LOCK(m_write_lock);
+    m_table_aliases.erase(id);
     m_tables.emplace(id, std::move(table));
 }
 
@@ -397,6 +517,28 @@ ServerResources::host_view(
     }
 }
 
+void
+ServerResources::replace_view(
+    const t_id& id, std::shared_ptr<ErasedView> view
+) {
+    PSP_WRITE_LOCK(m_write_lock);
+    m_views.at(id) = std::move(view);
+}
+
+void
+ServerResources::set_view_proto_config(
+    const t_id& id, proto::ViewConfig config
+) {
+    PSP_WRITE_LOCK(m_write_lock);
+    m_view_proto_configs[id] = std::move(config);
+}
+
+proto::ViewConfig
+ServerResources::get_view_proto_config(const t_id& id) {
+    PSP_READ_LOCK(m_write_lock);
+    return m_view_proto_configs.at(id);
+}
+
 std::shared_ptr<Table>
 ServerResources::get_table(const t_id& id) {
     PSP_READ_LOCK(m_write_lock);
@@ -452,6 +594,9 @@ ServerResources::delete_view(const std::uint32_t& client_id, const t_id& id) {
             m_views.erase(id);
         }
 
+        m_view_proto_configs.erase(id);
+        m_unnest_tables.erase(id);
+
         if (m_view_to_table.find(id) != m_view_to_table.end()) {
             m_view_to_table.erase(id);
         }
@@ -476,8 +621,43 @@ void
 ServerResources::delete_table(const t_id& id) {
     PSP_WRITE_LOCK(m_write_lock);
     if (m_tables.find(id) != m_tables.end()) {
+        // An overlay reads its base table's rows, so must be deleted first.
+        for (const auto& [overlay_id, overlay] : m_overlays) {
+            if (overlay.base_id == id) {
+                PSP_COMPLAIN_AND_ABORT(
+                    "Cannot delete table with overlay `" + overlay_id + "`"
+                );
+            }
+        }
+
         if (m_table_to_view.find(id) == m_table_to_view.end()) {
             m_tables.erase(id);
+            m_exclusive_writers.erase(id);
+            m_batch_latencies.erase(id);
+            m_last_commits.erase(id);
+            m_overlays.erase(id);
+            m_edit_logs.erase(id);
+            m_edit_histories.erase(id);
+            m_annotations.erase(id);
+
+            // An Arrow ingest stream in progress for the table is abandoned.
+            for (auto it = m_arrow_ingests.begin();
+                 it != m_arrow_ingests.end();) {
+                if (std::get<1>(it->first) == id) {
+                    it = m_arrow_ingests.erase(it);
+                } else {
+                    ++it;
+                }
+            }
+
+            for (auto it = m_table_aliases.begin();
+                 it != m_table_aliases.end();) {
+                if (it->second == id) {
+                    it = m_table_aliases.erase(it);
+                } else {
+                    ++it;
+                }
+            }
         } else {
             std::cout << *m_table_to_view.find(id) << std::endl;
             PSP_COMPLAIN_AND_ABORT("Cannot delete table with views");
@@ -486,405 +666,1668 @@ ServerResources::delete_table(const t_id& id) {
 }
 
 void
-ServerResources::mark_table_dirty(const t_id& id) {
+ServerResources::rename_table(const t_id& id, const t_id& new_id) {
     PSP_WRITE_LOCK(m_write_lock);
-    m_dirty_tables.insert(id);
+    if (m_tables.find(new_id) != m_tables.end()) {
+        PSP_COMPLAIN_AND_ABORT("Table `" + new_id + "` already exists");
+    }
+
+    auto table = m_tables.at(id);
+    m_tables.erase(id);
+    m_tables.emplace(new_id, std::move(table));
+
+    std::vector<t_id> view_ids;
+    auto range = m_table_to_view.equal_range(id);
+    for (auto it = range.first; it != range.second; ++it) {
+        view_ids.push_back(it->second);
+    }
+
+    m_table_to_view.erase(id);
+    for (const auto& view_id : view_ids) {
+        m_table_to_view.emplace(new_id, view_id);
+        m_view_to_table[view_id] = new_id;
+    }
+
+    if (m_table_on_delete_subs.contains(id)) {
+        auto subs = std::move(m_table_on_delete_subs[id]);
+        m_table_on_delete_subs.erase(id);
+        m_table_on_delete_subs[new_id] = std::move(subs);
+    }
+
+    if (m_dirty_tables.contains(id)) {
+        m_dirty_tables.erase(id);
+        m_dirty_tables.insert(new_id);
+    }
+
+    if (m_exclusive_writers.contains(id)) {
+        auto writer = m_exclusive_writers[id];
+        m_exclusive_writers.erase(id);
+        m_exclusive_writers[new_id] = writer;
+    }
+
+    if (m_batch_latencies.contains(id)) {
+        m_batch_latencies[new_id] = m_batch_latencies[id];
+        m_last_commits[new_id] = m_last_commits[id];
+        m_batch_latencies.erase(id);
+        m_last_commits.erase(id);
+    }
+
+    if (m_overlays.contains(id)) {
+        auto overlay = std::move(m_overlays[id]);
+        m_overlays.erase(id);
+        m_overlays[new_id] = std::move(overlay);
+    }
+
+    for (auto it = m_overlays.begin(); it != m_overlays.end(); ++it) {
+        if (it->second.base_id == id) {
+            it.value().base_id = new_id;
+        }
+    }
+
+    if (m_edit_logs.contains(id)) {
+        auto log = std::move(m_edit_logs[id]);
+        m_edit_logs.erase(id);
+        m_edit_logs[new_id] = std::move(log);
+    }
+
+    if (m_edit_histories.contains(id)) {
+        auto history = std::move(m_edit_histories[id]);
+        m_edit_histories.erase(id);
+        m_edit_histories[new_id] = std::move(history);
+    }
+
+    if (m_annotations.contains(id)) {
+        auto annotations = std::move(m_annotations[id]);
+        m_annotations.erase(id);
+        m_annotations[new_id] = std::move(annotations);
+    }
+
+    // An Arrow ingest stream in progress continues under the new name.
+    for (auto it = m_arrow_ingests.begin(); it != m_arrow_ingests.end();) {
+        const auto& [client_id, table_id, stream_id] = it->first;
+        if (table_id == id) {
+            auto key = std::make_tuple(client_id, new_id, stream_id);
+            auto ingest = std::move(it->second);
+            it = m_arrow_ingests.erase(it);
+            m_arrow_ingests.emplace(std::move(key), std::move(ingest));
+        } else {
+            ++it;
+        }
+    }
+
+    for (auto it = m_table_aliases.begin(); it != m_table_aliases.end();
+         ++it) {
+        if (it->second == id) {
+            it.value() = new_id;
+        }
+    }
+
+    m_table_aliases.erase(new_id);
+    m_table_aliases[id] = new_id;
 }
 
-void
-ServerResources::mark_table_clean(const t_id& id) {
-    PSP_WRITE_LOCK(m_write_lock);
-    m_dirty_tables.erase(id);
+ServerResources::t_id
+ServerResources::resolve_table_id(const t_id& id) {
+    PSP_READ_LOCK(m_write_lock);
+    if (m_table_aliases.contains(id)) {
+        return m_table_aliases.at(id);
+    }
+
+    return id;
 }
 
 void
-ServerResources::mark_all_tables_clean() {
+ServerResources::host_overlay(
+    const t_id& overlay_id, const t_id& base_id, const std::uint32_t client_id
+) {
     PSP_WRITE_LOCK(m_write_lock);
-    m_dirty_tables.clear();
+    m_overlays[overlay_id] = Overlay{base_id, client_id, {}};
+}
+
+bool
+ServerResources::is_overlay(const t_id& table_id) {
+    PSP_READ_LOCK(m_write_lock);
+    return m_overlays.contains(table_id);
 }
 
 void
-ServerResources::create_table_on_delete_sub(
-    const t_id& table_id, Subscription sub_id
+ServerResources::check_overlay_owner(
+    const t_id& table_id, const std::uint32_t client_id
 ) {
-    PSP_WRITE_LOCK(m_write_lock);
-    if (!m_table_on_delete_subs.contains(table_id)) {
-        m_table_on_delete_subs[table_id] = {sub_id};
-    } else {
-        m_table_on_delete_subs[table_id].push_back(sub_id);
+    PSP_READ_LOCK(m_write_lock);
+    auto it = m_overlays.find(table_id);
+    if (it != m_overlays.end() && it->second.client_id != client_id) {
+        PSP_COMPLAIN_AND_ABORT(
+            "Table `" + table_id + "` is an overlay private to session "
+            + std::to_string(it->second.client_id)
+        );
     }
 }
 
-std::vector<Subscription>
-ServerResources::get_table_on_delete_sub(const t_id& table_id) {
+ServerResources::t_id
+ServerResources::get_overlay_base(const t_id& overlay_id) {
     PSP_READ_LOCK(m_write_lock);
-    if (!m_table_on_delete_subs.contains(table_id)) {
+    auto it = m_overlays.find(overlay_id);
+    if (it == m_overlays.end()) {
+        PSP_COMPLAIN_AND_ABORT("Table `" + overlay_id + "` is not an overlay");
         return {};
     }
-    return m_table_on_delete_subs.at(table_id);
+
+    return it->second.base_id;
 }
 
 void
-ServerResources::remove_table_on_delete_sub(
-    const t_id& table_id,
-    const std::uint32_t sub_id,
-    const std::uint32_t client_id
+ServerResources::push_overlay_removes(
+    const t_id& overlay_id, const std::vector<std::string>& index_values
 ) {
-    {
-        PSP_WRITE_LOCK(m_write_lock);
-        if (!m_table_on_delete_subs.contains(table_id)) {
-            m_table_on_delete_subs[table_id] = {};
-        }
+    PSP_WRITE_LOCK(m_write_lock);
+    auto it = m_overlays.find(overlay_id);
+    if (it != m_overlays.end()) {
+        it.value().removed.insert(index_values.begin(), index_values.end());
     }
+}
 
+std::set<std::string>
+ServerResources::get_overlay_removes(const t_id& overlay_id) {
     PSP_READ_LOCK(m_write_lock);
-    auto& subs = m_table_on_delete_subs.at(table_id);
-    for (auto sub = subs.begin(); sub != subs.end();) {
-        if (sub->id == sub_id && sub->client_id == client_id) {
-            subs.erase(sub);
-            break;
+    auto it = m_overlays.find(overlay_id);
+    if (it == m_overlays.end()) {
+        return {};
+    }
+
+    return it->second.removed;
+}
+
+std::set<std::string>
+ServerResources::take_overlay_removes(const t_id& overlay_id) {
+    PSP_WRITE_LOCK(m_write_lock);
+    std::set<std::string> removed;
+    auto it = m_overlays.find(overlay_id);
+    if (it != m_overlays.end()) {
+        std::swap(removed, it.value().removed);
+    }
+
+    return removed;
+}
+
+std::vector<ServerResources::t_id>
+ServerResources::get_client_overlays(const std::uint32_t client_id) {
+    PSP_READ_LOCK(m_write_lock);
+    std::vector<t_id> out;
+    for (const auto& [id, overlay] : m_overlays) {
+        if (overlay.client_id == client_id) {
+            out.push_back(id);
         }
+    }
 
-        ++sub;
+    return out;
+}
+
+std::vector<ServerResources::t_id>
+ServerResources::get_base_overlays(const t_id& base_id) {
+    PSP_READ_LOCK(m_write_lock);
+    std::vector<t_id> out;
+    for (const auto& [id, overlay] : m_overlays) {
+        if (overlay.base_id == base_id) {
+            out.push_back(id);
+        }
     }
+
+    return out;
 }
 
 void
-ServerResources::create_view_on_delete_sub(
-    const t_id& view_id, Subscription sub
+ServerResources::push_edit_records(
+    const t_id& table_id, std::vector<proto::CellEditRecord> records
 ) {
     PSP_WRITE_LOCK(m_write_lock);
-    if (!m_view_on_delete_subs.contains(view_id)) {
-        m_view_on_delete_subs[view_id] = {sub};
-    } else {
-        m_view_on_delete_subs[view_id].push_back(sub);
+    auto& log = m_edit_logs[table_id];
+    for (auto& record : records) {
+        if (log.size() == PSP_EDIT_LOG_CAPACITY) {
+            log.pop_front();
+        }
+
+        log.push_back(std::move(record));
     }
 }
 
-std::vector<Subscription>
-ServerResources::get_view_on_delete_sub(const t_id& view_id) {
+std::vector<proto::CellEditRecord>
+ServerResources::get_edit_records(
+    const t_id& table_id, std::optional<std::uint32_t> limit
+) {
     PSP_READ_LOCK(m_write_lock);
-    if (!m_view_on_delete_subs.contains(view_id)) {
+    auto it = m_edit_logs.find(table_id);
+    if (it == m_edit_logs.end()) {
         return {};
     }
 
-    return m_view_on_delete_subs.at(view_id);
+    const auto& log = it->second;
+    auto count = static_cast<std::ptrdiff_t>(
+        std::min<std::size_t>(limit.value_or(log.size()), log.size())
+    );
+
+    return std::vector<proto::CellEditRecord>(log.end() - count, log.end());
 }
 
 void
-ServerResources::remove_view_on_delete_sub(
-    const t_id& view_id,
-    const std::uint32_t sub_id,
-    const std::uint32_t client_id
+ServerResources::push_edit_batch(
+    const t_id& table_id, std::vector<proto::CellEditRecord> records
 ) {
     PSP_WRITE_LOCK(m_write_lock);
-    if (!m_view_on_delete_subs.contains(view_id)) {
-        m_view_on_delete_subs[view_id] = {};
+    auto& history = m_edit_histories[table_id];
+    if (history.undo.size() == PSP_UNDO_CAPACITY) {
+        history.undo.pop_front();
     }
 
-    auto& subs = m_view_on_delete_subs.at(view_id);
-    for (auto sub = subs.begin(); sub != subs.end();) {
-        if (sub->id == sub_id && sub->client_id == client_id) {
-            subs.erase(sub);
-            break;
-        }
+    history.undo.push_back(std::move(records));
+    history.redo.clear();
+}
 
-        ++sub;
+std::optional<std::vector<proto::CellEditRecord>>
+ServerResources::take_undo(const t_id& table_id) {
+    PSP_WRITE_LOCK(m_write_lock);
+    auto it = m_edit_histories.find(table_id);
+    if (it == m_edit_histories.end() || it->second.undo.empty()) {
+        return std::nullopt;
+    }
+
+    auto& history = it.value();
+    auto batch = std::move(history.undo.back());
+    history.undo.pop_back();
+    history.redo.push_back(batch);
+    return batch;
+}
+
+std::optional<std::vector<proto::CellEditRecord>>
+ServerResources::take_redo(const t_id& table_id) {
+    PSP_WRITE_LOCK(m_write_lock);
+    auto it = m_edit_histories.find(table_id);
+    if (it == m_edit_histories.end() || it->second.redo.empty()) {
+        return std::nullopt;
     }
+
+    auto& history = it.value();
+    auto batch = std::move(history.redo.back());
+    history.redo.pop_back();
+    history.undo.push_back(batch);
+    return batch;
 }
 
 void
-ServerResources::create_view_on_update_sub(
-    const t_id& view_id, Subscription sub
+ServerResources::set_annotation(
+    const t_id& table_id, proto::CellAnnotation annotation
 ) {
     PSP_WRITE_LOCK(m_write_lock);
-    if (!m_view_on_update_subs.contains(view_id)) {
-        m_view_on_update_subs[view_id] = {sub};
-    } else {
-        m_view_on_update_subs[view_id].push_back(sub);
+    auto key = std::make_pair(annotation.index(), annotation.column());
+    m_annotations[table_id][key] = std::move(annotation);
+}
+
+void
+ServerResources::remove_annotation(
+    const t_id& table_id, const std::string& index, const std::string& column
+) {
+    PSP_WRITE_LOCK(m_write_lock);
+    auto it = m_annotations.find(table_id);
+    if (it != m_annotations.end()) {
+        it.value().erase(std::make_pair(index, column));
     }
 }
 
-std::vector<Subscription>
-ServerResources::get_view_on_update_sub(const t_id& view_id) {
+std::vector<proto::CellAnnotation>
+ServerResources::get_annotations(const t_id& table_id) {
     PSP_READ_LOCK(m_write_lock);
-    if (!m_view_on_update_subs.contains(view_id)) {
-        return {};
+    std::vector<proto::CellAnnotation> out;
+    auto it = m_annotations.find(table_id);
+    if (it != m_annotations.end()) {
+        for (const auto& [_, annotation] : it->second) {
+            out.push_back(annotation);
+        }
     }
 
-    return m_view_on_update_subs.at(view_id);
+    return out;
 }
 
 void
-ServerResources::drop_view_on_update_sub(const t_id& view_id) {
+ServerResources::set_unnest_table(
+    const t_id& view_id, std::shared_ptr<Table> table
+) {
     PSP_WRITE_LOCK(m_write_lock);
-    m_view_on_update_subs.erase(view_id);
+    if (table == nullptr) {
+        m_unnest_tables.erase(view_id);
+    } else {
+        m_unnest_tables[view_id] = std::move(table);
+    }
 }
 
-std::vector<std::pair<std::shared_ptr<Table>, const ServerResources::t_id>>
-ServerResources::get_dirty_tables() {
+std::shared_ptr<Table>
+ServerResources::get_unnest_table(const t_id& view_id) {
     PSP_READ_LOCK(m_write_lock);
-    std::vector<std::pair<std::shared_ptr<Table>, const t_id>> out;
-    for (const auto& id : m_dirty_tables) {
-        out.emplace_back(m_tables[id], id);
-    }
-    return out;
+    auto it = m_unnest_tables.find(view_id);
+    return it == m_unnest_tables.end() ? nullptr : it->second;
+}
+
+void
+ServerResources::set_exclusive_writer(
+    const t_id& table_id, const std::uint32_t client_id
+) {
+    PSP_WRITE_LOCK(m_write_lock);
+    m_exclusive_writers[table_id] = client_id;
 }
 
 bool
-ServerResources::is_table_dirty(const t_id& id) {
+ServerResources::is_exclusive_writer(const t_id& table_id) {
     PSP_READ_LOCK(m_write_lock);
-    return m_dirty_tables.contains(id);
+    return m_exclusive_writers.contains(table_id);
 }
 
-void
-ServerResources::drop_client(const std::uint32_t client_id) {
-    if (m_client_to_view.contains(client_id)) {
-
-        // Load-bearing copy
-        std::vector<t_id> remove = m_client_to_view[client_id];
-        for (const auto& view_id : remove) {
-            delete_view(client_id, view_id);
-        }
+std::shared_ptr<ArrowIngest>
+ServerResources::get_arrow_ingest(
+    const std::uint32_t client_id,
+    const t_id& table_id,
+    const std::uint32_t stream_id
+) {
+    PSP_WRITE_LOCK(m_write_lock);
+    auto& ingest = m_arrow_ingests[{client_id, table_id, stream_id}];
+    if (ingest == nullptr) {
+        ingest = std::make_shared<ArrowIngest>();
     }
-}
 
-std::uint32_t
-ProtoServer::new_session() {
-    return m_client_id++;
+    return ingest;
 }
 
 void
-ProtoServer::close_session(const std::uint32_t client_id) {
-    m_resources.drop_client(client_id);
+ServerResources::drop_arrow_ingest(
+    const std::uint32_t client_id,
+    const t_id& table_id,
+    const std::uint32_t stream_id
+) {
+    PSP_WRITE_LOCK(m_write_lock);
+    m_arrow_ingests.erase({client_id, table_id, stream_id});
 }
 
-std::vector<ProtoServerResp<std::string>>
-ProtoServer::handle_request(
-    std::uint32_t client_id, const std::string_view& data
+void
+ServerResources::check_writer(
+    const t_id& table_id, const std::uint32_t client_id
 ) {
-    proto::Request req_env;
-    req_env.ParseFromString(data);
-    std::vector<ProtoServerResp<std::string>> serialized_responses;
-    std::vector<proto::Response> responses;
-    try {
-        auto resp_msg = _handle_request(client_id, req_env);
-        for (auto& resp : resp_msg) {
-            ProtoServerResp<std::string> str_resp;
-            str_resp.data = resp.data.SerializeAsString();
-            str_resp.client_id = resp.client_id;
-            serialized_responses.emplace_back(str_resp);
-        }
-    } catch (const PerspectiveException& e) {
-        proto::Response resp;
-        auto* err = resp.mutable_server_error()->mutable_message();
-        *err = std::string(e.what());
-        responses.emplace_back(std::move(resp));
-    } catch (const std::exception& e) {
-        proto::Response resp;
-        auto* err = resp.mutable_server_error()->mutable_message();
-        *err = std::string(e.what());
-        responses.emplace_back(std::move(resp));
-    } catch (...) {
-        proto::Response resp;
-        auto* err = resp.mutable_server_error()->mutable_message();
-        std::exception_ptr p = std::current_exception();
-        *err = "Unknown exception";
-        responses.emplace_back(std::move(resp));
+    PSP_WRITE_LOCK(m_write_lock);
+    if (!m_exclusive_writers.contains(table_id)) {
+        return;
     }
 
-    // proto::Response resp_env;
-    serialized_responses.reserve(responses.size());
-    for (auto& resp : responses) {
-        resp.set_msg_id(req_env.msg_id());
-        resp.set_entity_id(req_env.entity_id());
+    // A table whose writer session has closed is claimed by the next session
+    // to write to it.
+    auto& writer = m_exclusive_writers[table_id];
+    if (!writer.has_value()) {
+        writer = client_id;
+    } else if (*writer != client_id) {
+        PSP_COMPLAIN_AND_ABORT(
+            "Table `" + table_id
+            + "` is read-only in this session (exclusive writer is session "
+            + std::to_string(*writer) + ")"
+        );
+    }
+}
 
-        ProtoServerResp<std::string> str_resp;
-        str_resp.data = resp.SerializeAsString();
-        str_resp.client_id = client_id;
-        serialized_responses.emplace_back(str_resp);
+void
+ServerResources::set_batch_latency(
+    const t_id& table_id, std::chrono::milliseconds latency
+) {
+    PSP_WRITE_LOCK(m_write_lock);
+    m_batch_latencies[table_id] = latency;
+    m_last_commits[table_id] = std::chrono::steady_clock::now();
+}
+
+bool
+ServerResources::is_commit_due(const t_id& table_id) {
+    PSP_READ_LOCK(m_write_lock);
+    if (!m_batch_latencies.contains(table_id)) {
+        return true;
     }
 
-    return serialized_responses;
+    // A table which has not committed within its latency commits right away,
+    // so only updates arriving faster than that are held back and coalesced.
+    auto elapsed =
+        std::chrono::steady_clock::now() - m_last_commits.at(table_id);
+    return elapsed >= m_batch_latencies.at(table_id);
 }
 
-std::vector<ProtoServerResp<std::string>>
-ProtoServer::poll() {
-    std::vector<ProtoServerResp<std::string>> out;
-    for (auto& resp : _poll()) {
-        ProtoServerResp<std::string> str_resp;
-        str_resp.data = resp.data.SerializeAsString();
-        str_resp.client_id = resp.client_id;
-        out.emplace_back(str_resp);
+std::optional<std::chrono::milliseconds>
+ServerResources::get_commit_delay() {
+    PSP_READ_LOCK(m_write_lock);
+    std::optional<std::chrono::milliseconds> delay;
+    auto now = std::chrono::steady_clock::now();
+    for (const auto& id : m_dirty_tables) {
+        if (!m_batch_latencies.contains(id)) {
+            continue;
+        }
+
+        auto due = m_last_commits.at(id) + m_batch_latencies.at(id);
+        auto remaining = std::chrono::ceil<std::chrono::milliseconds>(
+            std::max(due - now, std::chrono::steady_clock::duration::zero())
+        );
+
+        if (!delay.has_value() || remaining < *delay) {
+            delay = remaining;
+        }
     }
 
-    return out;
+    return delay;
 }
 
-proto::ColumnType
-dtype_to_column_type(const t_dtype& t) {
-    switch (t) {
-        case t_dtype::DTYPE_BOOL:
-            return proto::ColumnType::BOOLEAN;
-        case t_dtype::DTYPE_UINT8:
-        case t_dtype::DTYPE_UINT16:
-        case t_dtype::DTYPE_UINT32:
-        case t_dtype::DTYPE_UINT64:
-        case t_dtype::DTYPE_INT8:
-        case t_dtype::DTYPE_INT16:
-        case t_dtype::DTYPE_INT32:
-        case t_dtype::DTYPE_INT64:
-            return proto::ColumnType::INTEGER;
-        case t_dtype::DTYPE_FLOAT32:
-        case t_dtype::DTYPE_FLOAT64:
-            return proto::ColumnType::FLOAT;
-        case t_dtype::DTYPE_STR:
-            return proto::ColumnType::STRING;
-        case t_dtype::DTYPE_DATE:
-            return proto::ColumnType::DATE;
-        case t_dtype::DTYPE_TIME:
-            return proto::ColumnType::DATETIME;
-        default:
-            PSP_COMPLAIN_AND_ABORT("Invalid type " + dtype_to_str(t));
-            return proto::ColumnType::STRING;
+std::uint32_t
+ServerResources::get_view_client_id(const t_id& view_id) {
+    PSP_READ_LOCK(m_write_lock);
+    for (const auto& [client_id, view_ids] : m_client_to_view) {
+        if (std::find(view_ids.begin(), view_ids.end(), view_id)
+            != view_ids.end()) {
+            return client_id;
+        }
     }
+
+    PSP_COMPLAIN_AND_ABORT("View `" + view_id + "` has no owner");
+    return 0;
 }
 
-t_dtype
-column_type_to_dtype(const proto::ColumnType& t) {
-    switch (t) {
-        case proto::ColumnType::BOOLEAN:
-            return t_dtype::DTYPE_BOOL;
-        case proto::ColumnType::FLOAT:
-            return t_dtype::DTYPE_FLOAT64;
-        case proto::ColumnType::INTEGER:
-            return t_dtype::DTYPE_INT32;
-        case proto::ColumnType::DATE:
-            return t_dtype::DTYPE_DATE;
-        case proto::ColumnType::DATETIME:
-            return t_dtype::DTYPE_TIME;
-        case proto::ColumnType::STRING:
-            return t_dtype::DTYPE_STR;
-        default:
-            PSP_COMPLAIN_AND_ABORT("Invalid column type");
-            return t_dtype::DTYPE_STR;
+void
+ServerResources::mark_table_dirty(const t_id& id) {
+    PSP_WRITE_LOCK(m_write_lock);
+    m_dirty_tables.insert(id);
+}
+
+void
+ServerResources::mark_table_clean(const t_id& id) {
+    PSP_WRITE_LOCK(m_write_lock);
+    m_dirty_tables.erase(id);
+    if (m_batch_latencies.contains(id)) {
+        m_last_commits[id] = std::chrono::steady_clock::now();
     }
 }
 
-struct ValidViewPort {
-    std::uint32_t start_row;
-    std::uint32_t end_row;
-    std::uint32_t start_col;
-    std::uint32_t end_col;
-};
+void
+ServerResources::create_table_on_delete_sub(
+    const t_id& table_id, Subscription sub_id
+) {
+    PSP_WRITE_LOCK(m_write_lock);
+    if (!m_table_on_delete_subs.contains(table_id)) {
+        m_table_on_delete_subs[table_id] = {sub_id};
+    } else {
+        m_table_on_delete_subs[table_id].push_back(sub_id);
+    }
+}
 
-static ValidViewPort
-parse_format_options(
-    const proto::ViewPort& viewport,
-    std::uint32_t num_columns,
-    std::uint32_t num_rows,
-    std::uint32_t sides,
-    bool column_only,
-    std::uint32_t num_hidden,
-    std::uint32_t viewport_top = 0,
-    std::uint32_t viewport_left = 0,
-    std::uint32_t viewport_height = 0,
-    std::uint32_t viewport_width = 0
+std::vector<Subscription>
+ServerResources::get_table_on_delete_sub(const t_id& table_id) {
+    PSP_READ_LOCK(m_write_lock);
+    if (!m_table_on_delete_subs.contains(table_id)) {
+        return {};
+    }
+    return m_table_on_delete_subs.at(table_id);
+}
+
+void
+ServerResources::remove_table_on_delete_sub(
+    const t_id& table_id,
+    const std::uint32_t sub_id,
+    const std::uint32_t client_id
 ) {
-    ValidViewPort out;
-    out.start_row =
-        viewport.has_start_row() ? viewport.start_row() : viewport_top;
-    out.start_col =
-        viewport.has_start_col() ? viewport.start_col() : viewport_left;
+    {
+        PSP_WRITE_LOCK(m_write_lock);
+        if (!m_table_on_delete_subs.contains(table_id)) {
+            m_table_on_delete_subs[table_id] = {};
+        }
+    }
 
-    std::uint32_t max_cols = num_columns + (sides == 0 ? 0 : 1);
-    std::uint32_t max_rows = num_rows;
-    std::uint32_t psp_offset = sides > 0 || column_only ? 1 : 0;
-    std::uint32_t hidden = num_hidden;
+    PSP_READ_LOCK(m_write_lock);
+    auto& subs = m_table_on_delete_subs.at(table_id);
+    for (auto sub = subs.begin(); sub != subs.end();) {
+        if (sub->id == sub_id && sub->client_id == client_id) {
+            subs.erase(sub);
+            break;
+        }
 
-    out.end_row = std::min(
-        max_rows,
-        viewport.has_end_row()
-            ? viewport.end_row()
-            : (viewport_height != 0 ? out.start_row + viewport_height : max_rows
-            )
-    );
-    out.end_col = std::min(
-        max_cols,
-        (viewport.has_end_col()
-             ? viewport.end_col() + psp_offset
-             : (viewport_width != 0 ? out.start_col + viewport_width : max_cols)
-        ) * (hidden + 1)
-    );
+        ++sub;
+    }
+}
 
-    return out;
+void
+ServerResources::create_on_hosted_tables_update_sub(Subscription sub) {
+    PSP_WRITE_LOCK(m_write_lock);
+    m_on_hosted_tables_update_subs.push_back(sub);
 }
 
-static constexpr bool
-needs_poll(const proto::Request::ClientReqCase proto_case) {
-    using ReqCase = proto::Request::ClientReqCase;
+std::vector<Subscription>
+ServerResources::get_on_hosted_tables_update_sub() {
+    PSP_READ_LOCK(m_write_lock);
+    return m_on_hosted_tables_update_subs;
+}
 
-    switch (proto_case) {
-        case ReqCase::kTableSizeReq:
-        case ReqCase::kTableSchemaReq:
-        case ReqCase::kTableMakePortReq:
-        case ReqCase::kTableValidateExprReq:
-        case ReqCase::kMakeTableReq:
-        case ReqCase::kViewDimensionsReq:
-        case ReqCase::kViewToColumnsStringReq:
-        case ReqCase::kViewToCsvReq:
-        case ReqCase::kViewToRowsStringReq:
-        case ReqCase::kViewToArrowReq:
-        case ReqCase::kViewSchemaReq:
-        case ReqCase::kViewGetMinMaxReq:
-        case ReqCase::kTableRemoveReq:
-        case ReqCase::kTableMakeViewReq:
-        case ReqCase::kViewOnUpdateReq:
-        case ReqCase::kViewCollapseReq:
-        case ReqCase::kViewExpandReq:
-        case ReqCase::kViewSetDepthReq:
-            return true;
-        case ReqCase::kTableOnDeleteReq:
-        case ReqCase::kViewOnDeleteReq:
-        case ReqCase::kViewRemoveDeleteReq:
-        case ReqCase::kTableUpdateReq:
-        case ReqCase::kTableRemoveDeleteReq:
-        case ReqCase::kGetHostedTablesReq:
-        case ReqCase::kTableReplaceReq:
-        case ReqCase::kTableDeleteReq:
-        case ReqCase::kViewGetConfigReq:
-        case ReqCase::kViewColumnPathsReq:
-        case ReqCase::kViewDeleteReq:
-        case ReqCase::kViewExpressionSchemaReq:
-        case ReqCase::kViewRemoveOnUpdateReq:
-        case ReqCase::kServerSystemInfoReq:
-        case ReqCase::kGetFeaturesReq:
-            return false;
-        case proto::Request::CLIENT_REQ_NOT_SET:
-            throw std::runtime_error("Unhandled request type 2");
+void
+ServerResources::remove_on_hosted_tables_update_sub(
+    const std::uint32_t sub_id, const std::uint32_t client_id
+) {
+    PSP_WRITE_LOCK(m_write_lock);
+    auto& subs = m_on_hosted_tables_update_subs;
+    for (auto sub = subs.begin(); sub != subs.end(); ++sub) {
+        if (sub->id == sub_id && sub->client_id == client_id) {
+            subs.erase(sub);
+            break;
+        }
     }
-    throw std::runtime_error("Unhandled request type");
 }
 
-static constexpr bool
-entity_type_is_table(const proto::Request::ClientReqCase proto_case) {
-    using ReqCase = proto::Request::ClientReqCase;
+void
+ServerResources::create_view_on_delete_sub(
+    const t_id& view_id, Subscription sub
+) {
+    PSP_WRITE_LOCK(m_write_lock);
+    if (!m_view_on_delete_subs.contains(view_id)) {
+        m_view_on_delete_subs[view_id] = {sub};
+    } else {
+        m_view_on_delete_subs[view_id].push_back(sub);
+    }
+}
 
-    switch (proto_case) {
-        case ReqCase::kTableSizeReq:
-        case ReqCase::kTableSchemaReq:
+std::vector<Subscription>
+ServerResources::get_view_on_delete_sub(const t_id& view_id) {
+    PSP_READ_LOCK(m_write_lock);
+    if (!m_view_on_delete_subs.contains(view_id)) {
+        return {};
+    }
+
+    return m_view_on_delete_subs.at(view_id);
+}
+
+void
+ServerResources::remove_view_on_delete_sub(
+    const t_id& view_id,
+    const std::uint32_t sub_id,
+    const std::uint32_t client_id
+) {
+    PSP_WRITE_LOCK(m_write_lock);
+    if (!m_view_on_delete_subs.contains(view_id)) {
+        m_view_on_delete_subs[view_id] = {};
+    }
+
+    auto& subs = m_view_on_delete_subs.at(view_id);
+    for (auto sub = subs.begin(); sub != subs.end();) {
+        if (sub->id == sub_id && sub->client_id == client_id) {
+            subs.erase(sub);
+            break;
+        }
+
+        ++sub;
+    }
+}
+
+void
+ServerResources::create_view_on_update_sub(
+    const t_id& view_id, Subscription sub
+) {
+    PSP_WRITE_LOCK(m_write_lock);
+    if (!m_view_on_update_subs.contains(view_id)) {
+        m_view_on_update_subs[view_id] = {sub};
+    } else {
+        m_view_on_update_subs[view_id].push_back(sub);
+    }
+}
+
+std::vector<Subscription>
+ServerResources::get_view_on_update_sub(const t_id& view_id) {
+    PSP_READ_LOCK(m_write_lock);
+    if (!m_view_on_update_subs.contains(view_id)) {
+        return {};
+    }
+
+    return m_view_on_update_subs.at(view_id);
+}
+
+void
+ServerResources::drop_view_on_update_sub(const t_id& view_id) {
+    PSP_WRITE_LOCK(m_write_lock);
+    m_view_on_update_subs.erase(view_id);
+    m_viewport_subs.erase(view_id);
+    m_update_sequences.erase(view_id);
+}
+
+std::uint64_t
+ServerResources::next_update_sequence(
+    const t_id& view_id, const Subscription& sub
+) {
+    PSP_WRITE_LOCK(m_write_lock);
+    return ++m_update_sequences[view_id][{sub.client_id, sub.id}];
+}
+
+std::uint64_t
+ServerResources::get_update_sequence(
+    const t_id& view_id, const Subscription& sub
+) {
+    PSP_READ_LOCK(m_write_lock);
+    if (!m_update_sequences.contains(view_id)) {
+        return 0;
+    }
+
+    const auto& sequences = m_update_sequences.at(view_id);
+    auto iter = sequences.find({sub.client_id, sub.id});
+    return iter == sequences.end() ? 0 : iter->second;
+}
+
+void
+ServerResources::create_viewport_sub(
+    const t_id& view_id, ViewportSubscription viewport_sub
+) {
+    PSP_WRITE_LOCK(m_write_lock);
+    m_viewport_subs[view_id].push_back(std::move(viewport_sub));
+}
+
+std::optional<ViewportSubscription>
+ServerResources::get_viewport_sub(
+    const t_id& view_id, const Subscription& sub
+) {
+    PSP_READ_LOCK(m_write_lock);
+    if (!m_viewport_subs.contains(view_id)) {
+        return std::nullopt;
+    }
+
+    for (const auto& viewport_sub : m_viewport_subs.at(view_id)) {
+        if (viewport_sub.sub.id == sub.id
+            && viewport_sub.sub.client_id == sub.client_id) {
+            return viewport_sub;
+        }
+    }
+
+    return std::nullopt;
+}
+
+void
+ServerResources::set_viewport_rows(
+    const t_id& view_id, const Subscription& sub, std::vector<std::string> rows
+) {
+    PSP_WRITE_LOCK(m_write_lock);
+    if (!m_viewport_subs.contains(view_id)) {
+        return;
+    }
+
+    for (auto& viewport_sub : m_viewport_subs[view_id]) {
+        if (viewport_sub.sub.id == sub.id
+            && viewport_sub.sub.client_id == sub.client_id) {
+            viewport_sub.rows = std::move(rows);
+            return;
+        }
+    }
+}
+
+std::vector<std::pair<std::shared_ptr<Table>, const ServerResources::t_id>>
+ServerResources::get_dirty_tables() {
+    PSP_READ_LOCK(m_write_lock);
+    std::vector<std::pair<std::shared_ptr<Table>, const t_id>> out;
+    for (const auto& id : m_dirty_tables) {
+        out.emplace_back(m_tables[id], id);
+    }
+    return out;
+}
+
+bool
+ServerResources::is_table_dirty(const t_id& id) {
+    PSP_READ_LOCK(m_write_lock);
+    return m_dirty_tables.contains(id);
+}
+
+void
+ServerResources::drop_client(const std::uint32_t client_id) {
+    if (m_client_to_view.contains(client_id)) {
+
+        // Load-bearing copy
+        std::vector<t_id> remove = m_client_to_view[client_id];
+        for (const auto& view_id : remove) {
+            delete_view(client_id, view_id);
+        }
+    }
+
+    PSP_WRITE_LOCK(m_write_lock);
+    auto& subs = m_on_hosted_tables_update_subs;
+    subs.erase(
+        std::remove_if(
+            subs.begin(),
+            subs.end(),
+            [client_id](const Subscription& sub) {
+                return sub.client_id == client_id;
+            }
+        ),
+        subs.end()
+    );
+
+    for (auto it = m_exclusive_writers.begin(); it != m_exclusive_writers.end();
+         ++it) {
+        if (it->second == client_id) {
+            it.value() = std::nullopt;
+        }
+    }
+
+    for (auto it = m_arrow_ingests.begin(); it != m_arrow_ingests.end();) {
+        if (std::get<0>(it->first) == client_id) {
+            it = m_arrow_ingests.erase(it);
+        } else {
+            ++it;
+        }
+    }
+}
+
+std::uint32_t
+ProtoServer::new_session() {
+    return m_client_id++;
+}
+
+void
+ProtoServer::close_session(const std::uint32_t client_id) {
+    m_resources.drop_client(client_id);
+
+    // Overlays are private to their session, so any views on them were
+    // deleted with the session's other views above, and uncommitted edits
+    // are discarded.
+    for (const auto& overlay_id : m_resources.get_client_overlays(client_id)) {
+        m_resources.delete_table(overlay_id);
+        m_resources.mark_table_clean(overlay_id);
+    }
+}
+
+// Optional features this server supports, offered to clients in
+// `ServerHelloResp` when the client supports them too.
+static const std::vector<std::string> PROTOCOL_FEATURES = {
+    "arrow_ingest",
+    "bulk_export",
+    "server_broadcast",
+    "table_flush",
+    "table_remove_where",
+    "table_replace_atomic",
+    "table_schema_override",
+    "view_downsample",
+    "view_resync",
+};
+
+// Thrown when a `TableEditCellsReq` fails its checks, as opposed to any other
+// failure while applying it.
+class EditRejectedException : public PerspectiveException {
+public:
+    using PerspectiveException::PerspectiveException;
+};
+
+static void
+reject_edit(const std::string& message) {
+    throw EditRejectedException(message.c_str());
+}
+
+// A `TableMakeViewReq` for a `Table` which exists can only fail on its
+// `ViewConfig`.
+static proto::StatusCode
+error_status_code(ServerResources& resources, const proto::Request& req) {
+    if (req.client_req_case() == proto::Request::kTableMakeViewReq) {
+        auto table_ids = resources.get_table_ids();
+        if (std::find(table_ids.begin(), table_ids.end(), req.entity_id())
+            != table_ids.end()) {
+            return proto::VIEW_CONFIG_ERROR;
+        }
+    }
+
+    return proto::SERVER_ERROR;
+}
+
+static constexpr bool
+entity_type_is_table(proto::Request::ClientReqCase proto_case);
+
+std::vector<ProtoServerResp<std::string>>
+ProtoServer::handle_request(
+    std::uint32_t client_id,
+    const std::string_view& data,
+    const std::string& request_id
+) {
+    proto::Request req_env;
+    req_env.ParseFromString(data);
+
+    // `Table` handles opened before a `Table::rename()` address the table by
+    // its former name.
+    if (entity_type_is_table(req_env.client_req_case())
+        && req_env.client_req_case() != proto::Request::kMakeTableReq) {
+        auto table_id = m_resources.resolve_table_id(req_env.entity_id());
+        req_env.set_entity_id(std::move(table_id));
+    }
+    std::vector<ProtoServerResp<std::string>> serialized_responses;
+    std::vector<proto::Response> responses;
+    auto make_error = [&](const std::string& message,
+                          proto::StatusCode status_code) {
+        proto::Response resp;
+        auto* err = resp.mutable_server_error();
+        err->set_message(message);
+        err->set_status_code(status_code);
+        err->set_request_id(request_id);
+
+        // Logged with its request id, so an error a client reports can be
+        // found in the server's log.
+        std::cerr << "[" << request_id << "] " << message << '\n';
+        responses.emplace_back(std::move(resp));
+    };
+
+    try {
+        auto resp_msg = _handle_request(client_id, req_env);
+        for (auto& resp : resp_msg) {
+            ProtoServerResp<std::string> str_resp;
+            str_resp.data = resp.data.SerializeAsString();
+            str_resp.client_id = resp.client_id;
+            serialized_responses.emplace_back(str_resp);
+        }
+    } catch (const std::bad_alloc& e) {
+        make_error("Out of memory", proto::MEMORY_LIMIT);
+    } catch (const EditRejectedException& e) {
+        make_error(std::string(e.what()), proto::EDIT_REJECTED);
+    } catch (const PerspectiveException& e) {
+        make_error(
+            std::string(e.what()), error_status_code(m_resources, req_env)
+        );
+    } catch (const std::exception& e) {
+        make_error(
+            std::string(e.what()), error_status_code(m_resources, req_env)
+        );
+    } catch (...) {
+        make_error("Unknown exception", proto::SERVER_ERROR);
+    }
+
+    // proto::Response resp_env;
+    serialized_responses.reserve(responses.size());
+    for (auto& resp : responses) {
+        resp.set_msg_id(req_env.msg_id());
+        resp.set_entity_id(req_env.entity_id());
+
+        ProtoServerResp<std::string> str_resp;
+        str_resp.data = resp.SerializeAsString();
+        str_resp.client_id = client_id;
+        serialized_responses.emplace_back(str_resp);
+    }
+
+    return serialized_responses;
+}
+
+static bool
+has_table(ServerResources& resources, const std::string& table_id) {
+    auto table_ids = resources.get_table_ids();
+    return std::find(table_ids.begin(), table_ids.end(), table_id)
+        != table_ids.end();
+}
+
+std::vector<ProtoServerResp<std::string>>
+ProtoServer::unhost_table(const std::string& table_id) {
+    if (!has_table(m_resources, table_id)) {
+        PSP_COMPLAIN_AND_ABORT("Table `" + table_id + "` not found");
+    }
+
+    std::vector<ProtoServerResp<Response>> resps;
+    for (const auto& view_id : m_resources.get_view_ids(table_id)) {
+        for (const auto& sub : m_resources.get_view_on_delete_sub(view_id)) {
+            Response resp;
+            resp.mutable_view_on_delete_resp();
+            resp.set_msg_id(sub.id);
+            resp.set_entity_id(view_id);
+            ProtoServerResp<Response> resp2;
+            resp2.data = std::move(resp);
+            resp2.client_id = sub.client_id;
+            resps.emplace_back(std::move(resp2));
+        }
+
+        m_resources.delete_view(
+            m_resources.get_view_client_id(view_id), view_id
+        );
+    }
+
+    for (const auto& sub : m_resources.get_table_on_delete_sub(table_id)) {
+        Response resp;
+        resp.mutable_table_on_delete_resp();
+        resp.set_msg_id(sub.id);
+        resp.set_entity_id(table_id);
+        ProtoServerResp<Response> resp2;
+        resp2.data = std::move(resp);
+        resp2.client_id = sub.client_id;
+        resps.emplace_back(std::move(resp2));
+    }
+
+    m_resources.delete_table(table_id);
+    m_resources.mark_table_clean(table_id);
+    _hosted_tables_update(resps);
+    std::vector<ProtoServerResp<std::string>> out;
+    for (auto& resp : resps) {
+        ProtoServerResp<std::string> str_resp;
+        str_resp.data = resp.data.SerializeAsString();
+        str_resp.client_id = resp.client_id;
+        out.emplace_back(str_resp);
+    }
+
+    return out;
+}
+
+void
+ProtoServer::set_slow_op_threshold(
+    std::optional<std::chrono::microseconds> threshold
+) {
+    m_slow_ops.set_threshold(threshold);
+}
+
+/**
+ * @brief The group path of each column in `columns` which is a member of one
+ * of `cfg`'s `column_groups` (directly or through its parent groups),
+ * outermost group first. Members which are not in `columns` are ignored, so
+ * hiding a grouped column does not invalidate its group.
+ */
+static std::map<std::string, std::vector<std::string>>
+column_group_paths(
+    const proto::ViewConfig& cfg, const std::vector<std::string>& columns
+) {
+    std::set<std::string> column_names(columns.begin(), columns.end());
+    std::set<std::string> group_names;
+    for (const auto& group : cfg.column_groups()) {
+        if (column_names.count(group.name()) > 0
+            || !group_names.insert(group.name()).second) {
+            PSP_COMPLAIN_AND_ABORT(
+                "Duplicate column group name `" + group.name() + "`"
+            );
+        }
+    }
+
+    std::map<std::string, std::string> parents;
+    for (const auto& group : cfg.column_groups()) {
+        for (const auto& member : group.columns()) {
+            if (!parents.emplace(member, group.name()).second) {
+                PSP_COMPLAIN_AND_ABORT(
+                    "`" + member + "` is in more than one column group"
+                );
+            }
+        }
+    }
+
+    std::map<std::string, std::vector<std::string>> paths;
+    for (const auto& [member, _] : parents) {
+        std::vector<std::string> path;
+        auto parent = parents.find(member);
+        while (parent != parents.end()) {
+            if (path.size() > group_names.size()) {
+                PSP_COMPLAIN_AND_ABORT(
+                    "Column group `" + parent->second + "` contains itself"
+                );
+            }
+
+            path.insert(path.begin(), parent->second);
+            parent = parents.find(parent->second);
+        }
+
+        if (column_names.count(member) > 0) {
+            paths.emplace(member, std::move(path));
+        }
+    }
+
+    return paths;
+}
+
+// The time zone hint shared by every hinted column an expression reads, or
+// an empty string if there is none or the hints disagree.
+static std::string
+column_timezone(
+    const Table& table,
+    const tsl::hopscotch_map<std::string, std::string>& column_id_map
+) {
+    const auto& hints = table.get_column_hints();
+    std::string timezone;
+    for (const auto& [column_id, column] : column_id_map) {
+        auto iter = hints.find(column);
+        if (iter == hints.end() || !iter->second.m_timezone.has_value()) {
+            continue;
+        }
+
+        if (!timezone.empty() && timezone != *iter->second.m_timezone) {
+            return "";
+        }
+
+        timezone = *iter->second.m_timezone;
+    }
+
+    return timezone;
+}
+
+std::shared_ptr<t_view_config>
+ProtoServer::_make_view_config(
+    const std::shared_ptr<Table>& table,
+    const std::shared_ptr<t_schema>& schema,
+    const proto::ViewConfig& cfg
+) {
+    const auto& group_by = cfg.group_by();
+    std::vector<std::string> row_pivots{group_by.begin(), group_by.end()};
+
+    const auto& split_by = cfg.split_by();
+    std::vector<std::string> column_pivots{split_by.begin(), split_by.end()};
+
+    const auto& aggs = cfg.aggregates();
+    tsl::ordered_map<std::string, std::vector<std::string>> aggregates;
+    for (const auto& [col_name, agg_list] : aggs) {
+        aggregates[col_name] = std::vector<std::string>();
+        for (const auto& agg : agg_list.aggregations()) {
+            aggregates[col_name].push_back(agg);
+        }
+    }
+
+    const auto& sorts = cfg.sort();
+    std::vector<t_sortspec> sortby;
+    std::vector<std::vector<std::string>> sort_str;
+    for (const auto& sort : sorts) {
+        const char* column_sort = sort_op_str_from_proto(sort.op());
+        sort_str.push_back({sort.column(), column_sort});
+    }
+
+    bool column_only = false;
+
+    // make sure that primary keys are created for column-only views
+    if (row_pivots.empty() && !column_pivots.empty()) {
+        row_pivots.emplace_back("psp_okey");
+        column_only = true;
+    }
+
+    std::string timezone = cfg.has_timezone() ? cfg.timezone() : "";
+    t_tz parsed_timezone;
+    if (!timezone.empty() && !t_tz::parse(timezone, parsed_timezone)) {
+        PSP_COMPLAIN_AND_ABORT("Unknown time zone: " + timezone);
+    }
+
+    std::vector<std::shared_ptr<t_computed_expression>> expressions;
+    auto exprs = parse_expression_strings(cfg.expressions());
+
+    std::vector<std::tuple<
+        std::string,
+        std::string,
+        std::string,
+        std::vector<std::pair<std::string, std::string>>>>
+        legacy_exprs;
+
+    legacy_exprs.resize(1);
+    for (const auto& expr : exprs) {
+        legacy_exprs[0] = {
+            expr.expression_alias,
+            expr.expression,
+            expr.parse_expression_string,
+            std::vector<std::pair<std::string, std::string>>{
+                expr.column_id_map.begin(), expr.column_id_map.end()
+            }
+        };
+
+        // Validate these expression, creating is not the same thing!
+        const auto& res = table->validate_expressions(legacy_exprs);
+        if (!res.get_expression_errors().empty()) {
+            // TODO unify error reporting - this works differently than
+            // `validate_expressions()`. In this case there is
+            // guaranteed to only be one ...
+            PSP_COMPLAIN_AND_ABORT(res.get_expression_errors()
+                                       .at(expr.expression_alias)
+                                       .m_error_message);
+        }
+
+        const auto& gnode = table->get_gnode();
+        auto column_id_map = std::vector<std::pair<std::string, std::string>>(
+            expr.column_id_map.begin(), expr.column_id_map.end()
+        );
+
+        auto expr_vocab = gnode->get_expression_vocab();
+        t_expression_vocab& expression_vocab = *expr_vocab;
+        auto expression_regex_mapping = gnode->get_expression_regex_mapping();
+        t_regex_mapping& regex_mapping = *expression_regex_mapping;
+
+        std::shared_ptr<t_computed_expression> computed_expression =
+            t_computed_expression_parser::precompute(
+                expr.expression_alias,
+                expr.expression,
+                expr.parse_expression_string,
+                column_id_map,
+                gnode->get_table_sptr(),
+                gnode->get_pkey_map(),
+                schema,
+                expression_vocab,
+                regex_mapping
+            );
+
+        auto dtype = computed_expression->get_dtype();
+
+        schema->add_column(expr.expression_alias, dtype);
+        expressions.push_back(std::make_shared<t_computed_expression>(
+            expr.expression_alias,
+            expr.expression,
+            expr.parse_expression_string,
+            column_id_map,
+            dtype,
+            timezone.empty() ? column_timezone(*table, expr.column_id_map)
+                             : timezone
+        ));
+    }
+
+    std::vector<std::tuple<std::string, std::string, std::vector<t_tscalar>>>
+        filter;
+    for (const auto& f : cfg.filter()) {
+        auto args = filter_args_from_proto(*schema, f);
+        filter.emplace_back(f.column(), f.op(), args);
+    }
+
+    const auto& cols = cfg.columns();
+    std::vector<std::string> columns;
+    if (cols.has_columns()) {
+        columns = {
+            cols.columns().columns().begin(), cols.columns().columns().end()
+        };
+    } else {
+        columns = table->get_column_names();
+        for (const auto& f : expressions) {
+            columns.push_back(f->get_expression_alias());
+        }
+    }
+
+    LOG_DEBUG(
+        "Creating view config with \n"
+        << "row_pivots: " << row_pivots << '\n'
+        << "column_pivots: " << column_pivots
+        << '\n'
+        // << "aggregates: " << aggregates << '\n'
+        << "columns: " << columns
+        << '\n'
+        // << "filter: " << filter << '\n'
+        << "sort_str: " << sort_str << '\n'
+        << "expressions: " << expressions << '\n'
+        << "column_only: " << column_only << '\n'
+    );
+
+    std::string filter_op;
+    switch (cfg.filter_op()) {
+        case proto::ViewConfig_FilterReducer::ViewConfig_FilterReducer_OR:
+            filter_op = "or";
+            break;
+        case proto::ViewConfig_FilterReducer::ViewConfig_FilterReducer_AND:
+        default:
+            filter_op = "and";
+            break;
+    }
+
+    LOG_DEBUG("FILTER_OP: " << filter_op);
+
+    auto config = std::make_shared<t_view_config>(
+        row_pivots,
+        column_pivots,
+        aggregates,
+        columns,
+        filter,
+        sort_str,
+        expressions,
+        filter_op,
+        column_only
+    );
+    config->init(schema);
+
+    if (cfg.has_group_by_depth()) {
+        config->set_row_pivot_depth(cfg.group_by_depth());
+    }
+
+    config->set_timezone(timezone);
+    config->set_column_groups(column_group_paths(cfg, columns));
+
+    if (cfg.has_null_groups()) {
+        config->set_exclude_null_groups(
+            cfg.null_groups()
+            == proto::ViewConfig_NullGroups_NULL_GROUPS_EXCLUDE
+        );
+    }
+
+    if (cfg.has_null_aggregates()) {
+        switch (cfg.null_aggregates()) {
+            case proto::ViewConfig_NullAggregates_NULL_AGGREGATES_IGNORE:
+                config->set_null_aggregates(NULL_AGGREGATES_IGNORE);
+                break;
+            case proto::ViewConfig_NullAggregates_NULL_AGGREGATES_PROPAGATE:
+                config->set_null_aggregates(NULL_AGGREGATES_PROPAGATE);
+                break;
+            case proto::ViewConfig_NullAggregates_NULL_AGGREGATES_DEFAULT:
+            default:
+                config->set_null_aggregates(NULL_AGGREGATES_DEFAULT);
+                break;
+        }
+    }
+
+    config->set_row_totals(
+        total_position_to_totals(cfg.grand_total()),
+        total_position_to_totals(cfg.subtotals())
+    );
+
+    std::map<std::string, t_show_values_as> show_values_as;
+    for (const auto& [column, show] : cfg.show_values_as()) {
+        show_values_as.emplace(column, show_values_as_from_proto(show));
+    }
+
+    config->set_show_values_as(std::move(show_values_as));
+    return config;
+}
+
+std::shared_ptr<ErasedView>
+ProtoServer::_make_view(
+    std::shared_ptr<Table> table,
+    const std::string& context_name,
+    const proto::ViewConfig& cfg
+) {
+    auto schema =
+        std::make_shared<t_schema>(table->get_gnode()->get_output_schema());
+
+    auto config = _make_view_config(table, schema, cfg);
+    const auto& group_by = cfg.group_by();
+    const auto& split_by = cfg.split_by();
+    std::uint32_t sides;
+
+    if (!group_by.empty() || !split_by.empty()) {
+        if (!split_by.empty()) {
+            sides = 2;
+        } else {
+            sides = 1;
+        }
+    } else {
+        sides = 0;
+    }
+
+    // An unnested table replaces its rows on each update, which a unit
+    // context, reading the rows of the table directly, can't follow.
+    bool is_unit_context = table->get_index().empty() && sides == 0
+        && cfg.unnest().empty()
+        && config->get_row_pivots().empty()
+        && config->get_column_pivots().empty() && cfg.aggregates().empty()
+        && config->get_columns().empty() && cfg.sort().empty()
+        && cfg.expressions().empty();
+
+    std::shared_ptr<ErasedView> erased_view;
+
+    if (is_unit_context) {
+        auto ctx = make_context<t_ctxunit>(table, schema, config, context_name);
+        auto view = std::make_shared<View<t_ctxunit>>(
+            table, ctx, context_name, "|", config
+        );
+        erased_view = std::make_shared<CtxUnitView>(std::move(view));
+    } else if (sides == 0) {
+        auto ctx = make_context<t_ctx0>(table, schema, config, context_name);
+        auto view = std::make_shared<View<t_ctx0>>(
+            table, ctx, context_name, "|", config
+        );
+        erased_view = std::make_shared<Ctx0View>(view);
+    } else if (sides == 1) {
+        auto ctx = make_context<t_ctx1>(table, schema, config, context_name);
+        auto view = std::make_shared<View<t_ctx1>>(
+            table, ctx, context_name, "|", config
+        );
+        erased_view = std::make_shared<Ctx1View>(std::move(view));
+    } else if (sides == 2) {
+        auto ctx = make_context<t_ctx2>(table, schema, config, context_name);
+        auto view = std::make_shared<View<t_ctx2>>(
+            table, ctx, context_name, "|", config
+        );
+        erased_view = std::make_shared<Ctx2View>(std::move(view));
+    } else {
+        PSP_COMPLAIN_AND_ABORT("Invalid number of sides");
+    }
+
+    return erased_view;
+}
+
+void
+ProtoServer::define_expression(
+    const std::string& name, const std::string& expression
+) {
+    if (name.empty()) {
+        PSP_COMPLAIN_AND_ABORT("Expression name must not be empty");
+    }
+
+    m_expression_library[name] = expression;
+}
+
+void
+ProtoServer::remove_expression(const std::string& name) {
+    m_expression_library.erase(name);
+}
+
+void
+ProtoServer::_resolve_library_expressions(
+    const t_schema& schema, proto::ViewConfig& cfg
+) const {
+    if (m_expression_library.empty()) {
+        return;
+    }
+
+    auto* expressions = cfg.mutable_expressions();
+    auto resolve = [&](const std::string& name) {
+        if (schema.has_column(name)
+            || expressions->find(name) != expressions->end()) {
+            return;
+        }
+
+        auto it = m_expression_library.find(name);
+        if (it != m_expression_library.end()) {
+            (*expressions)[name] = it->second;
+        }
+    };
+
+    for (const auto& name : cfg.columns().columns().columns()) {
+        resolve(name);
+    }
+
+    for (const auto& name : cfg.group_by()) {
+        resolve(name);
+    }
+
+    for (const auto& name : cfg.split_by()) {
+        resolve(name);
+    }
+
+    for (const auto& sort : cfg.sort()) {
+        resolve(sort.column());
+    }
+
+    for (const auto& filter : cfg.filter()) {
+        resolve(filter.column());
+    }
+
+    for (const auto& [name, _] : cfg.aggregates()) {
+        resolve(name);
+    }
+}
+
+void
+ProtoServer::push_profile(proto::Profile&& profile) {
+    if (m_profiles.size() == PSP_PROFILE_CAPACITY) {
+        m_profiles.pop_front();
+    }
+
+    m_profiles.push_back(std::move(profile));
+}
+
+void
+SlowOpLog::set_threshold(std::optional<std::chrono::microseconds> threshold) {
+    m_threshold = threshold;
+}
+
+bool
+SlowOpLog::is_slow(std::chrono::microseconds duration) const {
+    return m_threshold.has_value() && duration >= *m_threshold;
+}
+
+void
+SlowOpLog::push(proto::SlowOp&& op) {
+    if (m_ops.size() == PSP_SLOW_OP_LOG_CAPACITY) {
+        m_ops.pop_front();
+    }
+
+    m_ops.push_back(std::move(op));
+}
+
+const std::deque<proto::SlowOp>&
+SlowOpLog::get_ops() const {
+    return m_ops;
+}
+
+void
+SlowOpLog::clear() {
+    m_ops.clear();
+}
+
+std::vector<ProtoServerResp<std::string>>
+ProtoServer::poll() {
+    std::vector<ProtoServerResp<std::string>> out;
+    for (auto& resp : _poll()) {
+        ProtoServerResp<std::string> str_resp;
+        str_resp.data = resp.data.SerializeAsString();
+        str_resp.client_id = resp.client_id;
+        out.emplace_back(str_resp);
+    }
+
+    return out;
+}
+
+std::optional<std::chrono::milliseconds>
+ProtoServer::get_poll_delay() {
+    return m_resources.get_commit_delay();
+}
+
+proto::ColumnType
+dtype_to_column_type(const t_dtype& t) {
+    switch (t) {
+        case t_dtype::DTYPE_BOOL:
+            return proto::ColumnType::BOOLEAN;
+        case t_dtype::DTYPE_UINT8:
+        case t_dtype::DTYPE_UINT16:
+        case t_dtype::DTYPE_UINT32:
+        case t_dtype::DTYPE_UINT64:
+        case t_dtype::DTYPE_INT8:
+        case t_dtype::DTYPE_INT16:
+        case t_dtype::DTYPE_INT32:
+        case t_dtype::DTYPE_INT64:
+            return proto::ColumnType::INTEGER;
+        case t_dtype::DTYPE_FLOAT32:
+        case t_dtype::DTYPE_FLOAT64:
+            return proto::ColumnType::FLOAT;
+        case t_dtype::DTYPE_STR:
+            return proto::ColumnType::STRING;
+        case t_dtype::DTYPE_DATE:
+            return proto::ColumnType::DATE;
+        case t_dtype::DTYPE_TIME:
+            return proto::ColumnType::DATETIME;
+        case t_dtype::DTYPE_DURATION:
+            return proto::ColumnType::DURATION;
+        case t_dtype::DTYPE_LIST:
+            return proto::ColumnType::LIST;
+        case t_dtype::DTYPE_JSON:
+            return proto::ColumnType::JSON;
+        case t_dtype::DTYPE_BINARY:
+            return proto::ColumnType::BINARY;
+        case t_dtype::DTYPE_UUID:
+            return proto::ColumnType::UUID;
+        case t_dtype::DTYPE_IPADDR:
+            return proto::ColumnType::IP;
+        default:
+            PSP_COMPLAIN_AND_ABORT("Invalid type " + dtype_to_str(t));
+            return proto::ColumnType::STRING;
+    }
+}
+
+t_dtype
+column_type_to_dtype(const proto::ColumnType& t) {
+    switch (t) {
+        case proto::ColumnType::BOOLEAN:
+            return t_dtype::DTYPE_BOOL;
+        case proto::ColumnType::FLOAT:
+            return t_dtype::DTYPE_FLOAT64;
+        case proto::ColumnType::INTEGER:
+            return t_dtype::DTYPE_INT32;
+        case proto::ColumnType::DATE:
+            return t_dtype::DTYPE_DATE;
+        case proto::ColumnType::DATETIME:
+            return t_dtype::DTYPE_TIME;
+        case proto::ColumnType::DURATION:
+            return t_dtype::DTYPE_DURATION;
+        case proto::ColumnType::STRING:
+            return t_dtype::DTYPE_STR;
+        case proto::ColumnType::LIST:
+            return t_dtype::DTYPE_LIST;
+        case proto::ColumnType::JSON:
+            return t_dtype::DTYPE_JSON;
+        case proto::ColumnType::BINARY:
+            return t_dtype::DTYPE_BINARY;
+        case proto::ColumnType::UUID:
+            return t_dtype::DTYPE_UUID;
+        case proto::ColumnType::IP:
+            return t_dtype::DTYPE_IPADDR;
+        default:
+            PSP_COMPLAIN_AND_ABORT("Invalid column type");
+            return t_dtype::DTYPE_STR;
+    }
+}
+
+// Row indices are 64-bit, as tables (e.g. tick history) may exceed 2^32 rows.
+struct ValidViewPort {
+    t_uindex start_row;
+    t_uindex end_row;
+    t_uindex start_col;
+    t_uindex end_col;
+};
+
+static ValidViewPort
+parse_format_options(
+    const proto::ViewPort& viewport,
+    std::uint32_t num_columns,
+    t_uindex num_rows,
+    std::uint32_t sides,
+    bool column_only,
+    std::uint32_t num_hidden,
+    t_uindex viewport_top = 0,
+    std::uint32_t viewport_left = 0,
+    t_uindex viewport_height = 0,
+    std::uint32_t viewport_width = 0
+) {
+    // Rows are clamped before narrowing to `t_uindex`, which is 32-bit in
+    // WebAssembly builds.
+    std::uint64_t start_row =
+        viewport.has_start_row() ? viewport.start_row() : viewport_top;
+    std::uint64_t end_row = viewport.has_end_row()
+        ? viewport.end_row()
+        : (viewport_height != 0 ? start_row + viewport_height : num_rows);
+
+    ValidViewPort out;
+    out.start_row = std::min<std::uint64_t>(start_row, num_rows);
+    out.end_row = std::min<std::uint64_t>(end_row, num_rows);
+    out.start_col =
+        viewport.has_start_col() ? viewport.start_col() : viewport_left;
+
+    std::uint32_t max_cols = num_columns + (sides == 0 ? 0 : 1);
+    std::uint32_t psp_offset = sides > 0 || column_only ? 1 : 0;
+    std::uint32_t hidden = num_hidden;
+    out.end_col = std::min(
+        max_cols,
+        (viewport.has_end_col()
+             ? viewport.end_col() + psp_offset
+             : (viewport_width != 0 ? out.start_col + viewport_width : max_cols)
+        ) * (hidden + 1)
+    );
+
+    return out;
+}
+
+static constexpr bool
+needs_poll(const proto::Request::ClientReqCase proto_case) {
+    using ReqCase = proto::Request::ClientReqCase;
+
+    switch (proto_case) {
+        case ReqCase::kTableSizeReq:
+        case ReqCase::kTableSchemaReq:
+        case ReqCase::kTableExpressionCompletionsReq:
+        case ReqCase::kTableMakePortReq:
+        case ReqCase::kTableValidateExprReq:
+        case ReqCase::kMakeTableReq:
+        case ReqCase::kViewDimensionsReq:
+        case ReqCase::kViewToColumnsStringReq:
+        case ReqCase::kViewToCsvReq:
+        case ReqCase::kViewToRowsStringReq:
+        case ReqCase::kViewAnnotationsReq:
+        case ReqCase::kViewToArrowReq:
+        case ReqCase::kViewSchemaReq:
+        case ReqCase::kViewGetMinMaxReq:
+        case ReqCase::kViewDownsampleReq:
+        case ReqCase::kTableRemoveReq:
+        case ReqCase::kTableRemoveWhereReq:
+        case ReqCase::kTableReplaceAtomicReq:
+        case ReqCase::kTableMakeViewReq:
+        case ReqCase::kViewOnUpdateReq:
+        case ReqCase::kViewResyncReq:
+        case ReqCase::kViewCollapseReq:
+        case ReqCase::kViewExpandReq:
+        case ReqCase::kViewSetDepthReq:
+        case ReqCase::kTableFlushReq:
+        case ReqCase::kTableStatsReq:
+        case ReqCase::kTableOverlayReq:
+        case ReqCase::kTableOverlayCommitReq:
+        case ReqCase::kTableEditCellsReq:
+        case ReqCase::kTableUndoReq:
+        case ReqCase::kTableRedoReq:
+            return true;
+        case ReqCase::kTableOnDeleteReq:
+        case ReqCase::kViewOnDeleteReq:
+        case ReqCase::kViewRemoveDeleteReq:
+        case ReqCase::kTableRenameReq:
+        case ReqCase::kRemoveHostedTablesUpdateReq:
+        case ReqCase::kTableTakeWriterReq:
+        case ReqCase::kTableCategoriesReq:
+        case ReqCase::kTableDictionaryStatsReq:
+        case ReqCase::kTableSketchesReq:
+        case ReqCase::kTableUpdateReq:
+        case ReqCase::kTableIngestArrowReq:
+        case ReqCase::kTableEditLogReq:
+        case ReqCase::kTableAnnotateReq:
+        case ReqCase::kTableAnnotationsReq:
+        case ReqCase::kTableRemoveDeleteReq:
+        case ReqCase::kGetHostedTablesReq:
+        case ReqCase::kTableReplaceReq:
+        case ReqCase::kTableDeleteReq:
+        case ReqCase::kViewGetConfigReq:
+        case ReqCase::kViewColumnPathsReq:
+        case ReqCase::kViewDeleteReq:
+        case ReqCase::kViewExpressionSchemaReq:
+        case ReqCase::kViewRemoveOnUpdateReq:
+        case ReqCase::kServerSystemInfoReq:
+        case ReqCase::kServerDiagnosticsReq:
+        case ReqCase::kServerExpressionsReq:
+        case ReqCase::kViewSetParamsReq:
+        case ReqCase::kViewUpdateConfigReq:
+        case ReqCase::kGetFeaturesReq:
+        case ReqCase::kServerHelloReq:
+        case ReqCase::kServerBulkExportReq:
+            return false;
+        case proto::Request::CLIENT_REQ_NOT_SET:
+            throw std::runtime_error("Unhandled request type 2");
+    }
+    throw std::runtime_error("Unhandled request type");
+}
+
+static constexpr bool
+entity_type_is_table(proto::Request::ClientReqCase proto_case) {
+    using ReqCase = proto::Request::ClientReqCase;
+
+    switch (proto_case) {
+        case ReqCase::kTableSizeReq:
+        case ReqCase::kTableSchemaReq:
+        case ReqCase::kTableExpressionCompletionsReq:
         case ReqCase::kTableMakePortReq:
         case ReqCase::kTableValidateExprReq:
         case ReqCase::kMakeTableReq:
         case ReqCase::kTableOnDeleteReq:
         case ReqCase::kTableRemoveReq:
+        case ReqCase::kTableRemoveWhereReq:
+        case ReqCase::kTableReplaceAtomicReq:
         case ReqCase::kTableUpdateReq:
+        case ReqCase::kTableIngestArrowReq:
         case ReqCase::kTableRemoveDeleteReq:
         case ReqCase::kGetHostedTablesReq:
+        case ReqCase::kRemoveHostedTablesUpdateReq:
+        case ReqCase::kTableTakeWriterReq:
+        case ReqCase::kTableCategoriesReq:
+        case ReqCase::kTableDictionaryStatsReq:
+        case ReqCase::kTableSketchesReq:
+        case ReqCase::kTableFlushReq:
+        case ReqCase::kTableStatsReq:
+        case ReqCase::kTableOverlayReq:
+        case ReqCase::kTableOverlayCommitReq:
+        case ReqCase::kTableEditCellsReq:
+        case ReqCase::kTableUndoReq:
+        case ReqCase::kTableRedoReq:
+        case ReqCase::kTableEditLogReq:
+        case ReqCase::kTableAnnotateReq:
+        case ReqCase::kTableAnnotationsReq:
         case ReqCase::kServerSystemInfoReq:
+        case ReqCase::kServerDiagnosticsReq:
+        case ReqCase::kServerExpressionsReq:
         case ReqCase::kGetFeaturesReq:
+        case ReqCase::kServerHelloReq:
+        case ReqCase::kServerBulkExportReq:
         case ReqCase::kTableReplaceReq:
         case ReqCase::kTableDeleteReq:
+        case ReqCase::kTableRenameReq:
         case ReqCase::kTableMakeViewReq:
             return true;
         case ReqCase::kViewOnDeleteReq:
@@ -893,9 +2336,11 @@ entity_type_is_table(const proto::Request::ClientReqCase proto_case) {
         case ReqCase::kViewToColumnsStringReq:
         case ReqCase::kViewToCsvReq:
         case ReqCase::kViewToRowsStringReq:
+        case ReqCase::kViewAnnotationsReq:
         case ReqCase::kViewToArrowReq:
         case ReqCase::kViewSchemaReq:
         case ReqCase::kViewGetMinMaxReq:
+        case ReqCase::kViewDownsampleReq:
         case ReqCase::kViewOnUpdateReq:
         case ReqCase::kViewCollapseReq:
         case ReqCase::kViewExpandReq:
@@ -905,179 +2350,1210 @@ entity_type_is_table(const proto::Request::ClientReqCase proto_case) {
         case ReqCase::kViewDeleteReq:
         case ReqCase::kViewExpressionSchemaReq:
         case ReqCase::kViewRemoveOnUpdateReq:
+        case ReqCase::kViewResyncReq:
+        case ReqCase::kViewSetParamsReq:
+        case ReqCase::kViewUpdateConfigReq:
             return false;
         case proto::Request::CLIENT_REQ_NOT_SET:
             throw std::runtime_error("Unhandled request type 2");
     }
-    throw std::runtime_error("Unhandled request type");
+    throw std::runtime_error("Unhandled request type");
+}
+
+void
+ProtoServer::handle_process_table(
+    const Request& req,
+    std::vector<ProtoServerResp<ProtoServer::Response>>& proto_resp
+) {
+    if (needs_poll(req.client_req_case())) {
+        auto table_id = entity_type_is_table(req.client_req_case())
+            ? req.entity_id()
+            : m_resources.get_table_id_for_view(req.entity_id());
+
+        // An overlay's views read its base table's rows as well as its own.
+        if (m_resources.is_overlay(table_id)) {
+            auto base_id = m_resources.get_overlay_base(table_id);
+            if (m_resources.is_table_dirty(base_id)) {
+                auto base = m_resources.get_table(base_id);
+                _process_table(base, base_id, proto_resp);
+            }
+        }
+
+        if (m_resources.is_table_dirty(table_id)) {
+            auto table = m_resources.get_table(table_id);
+            _process_table(table, table_id, proto_resp);
+        }
+    }
+}
+
+static std::string_view
+view_sides_to_string(const ErasedView& view) {
+    switch (view.sides()) {
+        case 0:
+            return "zero";
+        case 1:
+            return "one";
+        case 2:
+            return "two";
+        default:
+            PSP_COMPLAIN_AND_ABORT("Invalid number of sides");
+    }
+}
+
+static char
+csv_char_from_proto(const std::string& value, const char* name) {
+    if (value.size() != 1) {
+        PSP_COMPLAIN_AND_ABORT(
+            std::string("CSV ") + name + " must be a single character"
+        );
+    }
+
+    return value[0];
+}
+
+static apachearrow::CsvOptions
+csv_options_from_proto(const proto::MakeTableData& data) {
+    apachearrow::CsvOptions options;
+    if (!data.has_csv_options()) {
+        return options;
+    }
+
+    const auto& r = data.csv_options();
+    if (r.has_delimiter()) {
+        options.delimiter = csv_char_from_proto(r.delimiter(), "delimiter");
+    }
+
+    if (r.has_quote_char()) {
+        options.quote_char = csv_char_from_proto(r.quote_char(), "quote_char");
+    }
+
+    if (r.has_decimal_separator()) {
+        options.decimal_point =
+            csv_char_from_proto(r.decimal_separator(), "decimal_separator");
+    }
+
+    if (r.has_thousands_separator()) {
+        options.thousands_separator = csv_char_from_proto(
+            r.thousands_separator(), "thousands_separator"
+        );
+    }
+
+    if (r.has_header_rows()) {
+        options.header_rows = r.header_rows();
+    }
+
+    if (r.has_infer_sample_bytes()) {
+        options.infer_sample_bytes =
+            static_cast<std::int32_t>(r.infer_sample_bytes());
+    }
+
+    options.timestamp_formats.assign(
+        r.timestamp_formats().begin(), r.timestamp_formats().end()
+    );
+
+    options.null_values.assign(
+        r.null_values().begin(), r.null_values().end()
+    );
+
+    options.infer_int_as_float = r.infer_int_as_float();
+    return options;
+}
+
+// Apply a `TableUpdateReq` to `table` on `port_id`.
+static void
+apply_table_update(
+    Table& table, const proto::TableUpdateReq& r, std::uint32_t port_id
+) {
+    switch (r.data().data_case()) {
+        case proto::MakeTableData::kFromArrow: {
+            table.update_arrow(r.data().from_arrow(), port_id);
+            break;
+        }
+        case proto::MakeTableData::kFromCsv: {
+            table.update_csv(
+                r.data().from_csv(), port_id, csv_options_from_proto(r.data())
+            );
+            break;
+        }
+        case proto::MakeTableData::kFromRows: {
+            table.update_rows(r.data().from_rows(), port_id);
+            break;
+        }
+        case proto::MakeTableData::kFromCols: {
+            table.update_cols(r.data().from_cols(), port_id);
+            break;
+        }
+        case proto::MakeTableData::kFromSchema:
+        case proto::MakeTableData::DATA_NOT_SET:
+        default: {
+            PSP_COMPLAIN_AND_ABORT("MakeTableReq malformed");
+            break;
+        }
+    }
+}
+
+static void
+apply_table_remove(Table& table, const proto::TableRemoveReq& r) {
+    switch (r.data().data_case()) {
+        case proto::MakeTableData::kFromCols: {
+            table.remove_cols(r.data().from_cols());
+            break;
+        }
+        case proto::MakeTableData::kFromRows: {
+            table.remove_rows(r.data().from_rows());
+            break;
+        }
+        case proto::MakeTableData::kFromArrow:
+        case proto::MakeTableData::kFromCsv:
+        case proto::MakeTableData::kFromSchema:
+        case proto::MakeTableData::DATA_NOT_SET:
+        default: {
+            PSP_COMPLAIN_AND_ABORT("remove malformed");
+            break;
+        }
+    }
+}
+
+static std::uint32_t
+calculate_num_hidden(const ErasedView& view, const t_view_config& config) {
+    LOG_DEBUG("Calculating num hidden");
+    auto num_hidden = 0;
+    auto sides = view.sides();
+    LOG_DEBUG("View sides: " << sides);
+    switch (sides) {
+        case 0:
+            break;
+        case 1:
+        case 2: {
+            const auto& cols = config.get_columns();
+            for (const auto& s : config.get_sortspec()) {
+                LOG_DEBUG("Checking sort spec: " << s.m_colname);
+                if (std::find(cols.begin(), cols.end(), s.m_colname)
+                    == cols.end()) {
+                    LOG_DEBUG("Found hidden column: " << s.m_colname);
+                    num_hidden++;
+                }
+            }
+            for (const auto& s : config.get_col_sortspec()) {
+                if (std::find(cols.begin(), cols.end(), s.m_colname)
+                    == cols.end()) {
+                    LOG_DEBUG("Found hidden column: " << s.m_colname);
+                    num_hidden++;
+                }
+            }
+        } break;
+        default:
+            PSP_COMPLAIN_AND_ABORT(
+                "Invalid number of sides: " + std::to_string(view.sides())
+            );
+    }
+    return num_hidden;
+}
+
+/**
+ * @brief The rows of `view` within `viewport`, each serialized as a JSON
+ * object in the same format as `View::to_json`.
+ */
+static std::vector<std::string>
+viewport_rows(const ErasedView& view, const proto::ViewPort& viewport) {
+    auto config = view.get_view_config();
+    std::string nidx{view_sides_to_string(view)};
+    auto num_hidden = calculate_num_hidden(view, *config);
+    auto dims = parse_format_options(
+        viewport,
+        view.num_columns(),
+        view.num_rows(),
+        view.sides(),
+        config->is_column_only(),
+        num_hidden
+    );
+
+    auto json_str = view.to_rows(
+        dims.start_row,
+        dims.end_row,
+        dims.start_col,
+        dims.end_col,
+        num_hidden,
+        false,
+        false,
+        false,
+        false,
+        view.sides(),
+        view.sides() > 0 && !config->is_column_only(),
+        nidx,
+        config->get_columns().size(),
+        config->get_row_pivots().size()
+    );
+
+    rapidjson::Document document;
+    document.Parse(json_str.c_str());
+    std::vector<std::string> rows;
+    rows.reserve(document.Size());
+    for (const auto& row : document.GetArray()) {
+        rapidjson::StringBuffer buffer;
+        rapidjson::Writer<rapidjson::StringBuffer> writer(buffer);
+        row.Accept(writer);
+        rows.emplace_back(buffer.GetString());
+    }
+
+    return rows;
+}
+
+/**
+ * @brief Write the rows of `next` which differ from `prev` by position into
+ * `out`, returning whether the window changed at all.
+ */
+static bool
+diff_viewport_rows(
+    const std::vector<std::string>& prev,
+    const std::vector<std::string>& next,
+    proto::ViewportUpdate& out
+) {
+    out.set_num_rows(next.size());
+    auto& rows = *out.mutable_rows();
+    for (t_uindex ii = 0; ii < next.size(); ++ii) {
+        if (ii >= prev.size() || prev[ii] != next[ii]) {
+            rows[ii] = next[ii];
+        }
+    }
+
+    return !rows.empty() || prev.size() != next.size();
+}
+
+template <typename A>
+static t_tscalar
+coerce_to(const t_dtype dtype, const A& val) {
+    if constexpr (std::is_same_v<A, std::string>) {
+        t_tscalar scalar;
+        scalar.clear();
+        switch (dtype) {
+            case DTYPE_STR:
+                scalar.set(val.c_str());
+                return scalar;
+            case DTYPE_LIST:
+                scalar.set_list(val.c_str());
+                return scalar;
+            case DTYPE_JSON:
+                scalar.set_json(val.c_str());
+                return scalar;
+            case DTYPE_BINARY:
+                scalar.set_binary(val.c_str());
+                return scalar;
+            case DTYPE_BOOL:
+                scalar.set(val == "true");
+                return scalar;
+            case DTYPE_FLOAT64:
+                scalar.set(std::stod(val));
+                return scalar;
+            case DTYPE_UINT32:
+                scalar.set((std::uint32_t)std::stoul(val));
+                return scalar;
+            case DTYPE_UINT64:
+                scalar.set((std::uint64_t)std::stoull(val));
+                return scalar;
+            case DTYPE_INT32:
+                scalar.set(std::stoi(val));
+                return scalar;
+            case DTYPE_INT64:
+                scalar.set((std::int64_t)std::stoll(val));
+                return scalar;
+            case DTYPE_TIME: {
+                std::chrono::system_clock::time_point tp;
+
+                if (!parse_all_date_time(tp, val)) {
+                    PSP_COMPLAIN_AND_ABORT("Invalid date format");
+                }
+
+                // If parsing succeeded, create a time structure
+                t_time time{
+                    std::chrono::duration_cast<std::chrono::milliseconds>(
+                        tp.time_since_epoch()
+                    )
+                        .count()
+                };
+
+                LOG_DEBUG("Parsed time: " << time.raw_value());
+                scalar.set(time);
+                return scalar;
+            }
+            case DTYPE_DURATION: {
+                t_tdelta delta;
+                if (!parse_duration(val.c_str(), delta)) {
+                    PSP_COMPLAIN_AND_ABORT("Invalid duration format");
+                }
+
+                scalar.set(delta);
+                return scalar;
+            }
+            case DTYPE_UUID: {
+                t_uuid uuid;
+                if (!parse_uuid(val, uuid)) {
+                    PSP_COMPLAIN_AND_ABORT("Invalid UUID format");
+                }
+
+                scalar.set(uuid);
+                return scalar;
+            }
+            case DTYPE_IPADDR: {
+                t_ipaddr addr;
+                if (!parse_ipaddr(val, addr)) {
+                    PSP_COMPLAIN_AND_ABORT("Invalid IP address format");
+                }
+
+                scalar.set(addr);
+                return scalar;
+            }
+            case DTYPE_DATE: {
+                std::tm tm = {};
+                if (!parse_all_date_time(tm, val)) {
+                    PSP_COMPLAIN_AND_ABORT("Invalid date format");
+                }
+                t_date date{
+                    static_cast<std::int16_t>(tm.tm_year + 1900),
+                    static_cast<std::int8_t>(tm.tm_mon),
+                    static_cast<std::int8_t>(tm.tm_mday)
+                };
+
+                LOG_DEBUG("Parsed date: " << date.str());
+                scalar.set(date);
+                return scalar;
+            }
+            default:
+                PSP_COMPLAIN_AND_ABORT(
+                    "Column of type " + dtype_to_str(dtype)
+                    + " cannot cast from string"
+                );
+        }
+    } else if constexpr (std::is_same_v<A, double>) {
+        switch (dtype) {
+            case DTYPE_FLOAT32:
+                return t_tscalar(static_cast<float>(val));
+            case DTYPE_FLOAT64:
+                return t_tscalar(val);
+            default:
+                PSP_COMPLAIN_AND_ABORT("Unsupported type");
+        }
+    } else if constexpr (std::is_same_v<A, float>) {
+        switch (dtype) {
+            case DTYPE_FLOAT32:
+                return t_tscalar(val);
+            case DTYPE_FLOAT64:
+                return t_tscalar(static_cast<double>(val));
+            default:
+                PSP_COMPLAIN_AND_ABORT("Unsupported type");
+        }
+    } else if constexpr (std::is_same_v<A, std::int32_t>) {
+        return t_tscalar(val);
+    } else if constexpr (std::is_same_v<A, std::int64_t>) {
+        return t_tscalar(val);
+    } else if constexpr (std::is_same_v<A, bool>) {
+        return t_tscalar(val);
+    } else {
+        static_assert(!std::is_same_v<A, A>, "Unsupported type");
+    }
+}
+
+// The primary key of `table` whose `index` value is the JSON `index_json`.
+// `value` must outlive the key, which may point into it.
+static t_tscalar
+parse_index(
+    const Table& table, const std::string& index_json, std::string& value
+) {
+    rapidjson::Document doc;
+    doc.Parse(index_json.c_str());
+    if (doc.HasParseError() || !(doc.IsString() || doc.IsNumber())) {
+        PSP_COMPLAIN_AND_ABORT("Invalid index `" + index_json + "`");
+    }
+
+    value = doc.IsString() ? doc.GetString() : index_json;
+    auto dtype = table.get_schema().get_dtype(table.get_index());
+    return coerce_to(dtype, value);
+}
+
+// `index_json` as the JSON of `table`'s primary key, so index values which
+// address the same row compare equal.
+static std::string
+normalize_index(const Table& table, const std::string& index_json) {
+    std::string value;
+    return scalar_to_json(parse_index(table, index_json, value));
+}
+
+// The row of `table`'s master table whose `index` value is the JSON
+// `index_json`, if there is one.
+static std::optional<t_uindex>
+lookup_index_row(const Table& table, const std::string& index_json) {
+    std::string value;
+    auto pkey = parse_index(table, index_json, value);
+    const auto& mapping = table.get_gnode()->get_pkey_map();
+    auto iter = mapping.find(pkey);
+    if (iter == mapping.end()) {
+        return std::nullopt;
+    }
+
+    return iter->second;
+}
+
+// Whether the JSON `value` of a cell edit can be written to a column of type
+// `dtype`. `null` clears a cell of any type.
+static bool
+edit_value_fits(const rapidjson::Value& value, t_dtype dtype) {
+    if (value.IsNull()) {
+        return true;
+    }
+
+    switch (dtype) {
+        case DTYPE_BOOL:
+            return value.IsBool();
+        case DTYPE_STR:
+            return value.IsString();
+        case DTYPE_DURATION:
+            return value.IsNumber() || value.IsString();
+        default:
+            if (is_numeric_type(dtype)) {
+                return value.IsNumber();
+            }
+
+            return value.IsNumber() || value.IsString();
+    }
+}
+
+// Write a `from_rows` row which sets `column` of the row whose `index` is
+// `index_json` to `value_json`.
+static void
+write_cell_row(
+    rapidjson::Writer<rapidjson::StringBuffer>& writer,
+    const std::string& index,
+    const std::string& index_json,
+    const std::string& column,
+    const std::string& value_json
+) {
+    writer.StartObject();
+    writer.Key(index.c_str());
+    writer.RawValue(
+        index_json.c_str(), index_json.size(), rapidjson::kObjectType
+    );
+
+    writer.Key(column.c_str());
+    writer.RawValue(
+        value_json.c_str(), value_json.size(), rapidjson::kObjectType
+    );
+
+    writer.EndObject();
+}
+
+static std::vector<t_tscalar>
+filter_args_from_proto(
+    const t_schema& schema, const proto::ViewConfig_Filter& f
+) {
+    std::vector<t_tscalar> args;
+    for (const auto& arg : f.value()) {
+        t_tscalar a;
+        a.clear();
+
+        switch (arg.scalar_case()) {
+            case proto::Scalar::kBool: {
+                a.set(arg.bool_());
+                args.push_back(a);
+                break;
+            }
+            case proto::Scalar::kFloat: {
+                a.set(arg.float_());
+                args.push_back(a);
+                break;
+            }
+            case proto::Scalar::kInt: {
+                a.set(arg.int_());
+                args.push_back(a);
+                break;
+            }
+            case proto::Scalar::kInt64: {
+                a.set(static_cast<std::int64_t>(arg.int64()));
+                args.push_back(a);
+                break;
+            }
+            case proto::Scalar::kUint64: {
+                a.set(static_cast<std::uint64_t>(arg.uint64()));
+                args.push_back(a);
+                break;
+            }
+
+            case proto::Scalar::kString: {
+                if (!schema.has_column(f.column())) {
+                    PSP_COMPLAIN_AND_ABORT(
+                        "Filter column not in schema: " + f.column()
+                    );
+                }
+
+                // A CIDR block is held as its first and last address.
+                if (str_to_filter_op(f.op()) == FILTER_OP_IN_SUBNET) {
+                    if (schema.get_dtype(f.column()) != DTYPE_IPADDR) {
+                        PSP_COMPLAIN_AND_ABORT(
+                            "`in subnet` requires an IP column: " + f.column()
+                        );
+                    }
+
+                    t_subnet subnet;
+                    if (!parse_subnet(arg.string(), subnet)) {
+                        PSP_COMPLAIN_AND_ABORT(
+                            "Invalid subnet: " + arg.string()
+                        );
+                    }
+
+                    a.set(subnet.m_first);
+                    args.push_back(a);
+                    a.set(subnet.m_last);
+                    args.push_back(a);
+                    break;
+                }
+
+                a = coerce_to(schema.get_dtype(f.column()), arg.string());
+                args.push_back(a);
+                break;
+            }
+
+            case proto::Scalar::kDate: {
+                auto date_ts = arg.date();
+                // convert ts to date
+                auto tt = std::chrono::system_clock::to_time_t(
+                    std::chrono::system_clock::time_point(
+                        std::chrono::seconds(date_ts)
+                    )
+                );
+
+                auto* date = std::localtime(&tt);
+
+                t_date d{
+                    static_cast<std::int16_t>(date->tm_year + 1900),
+                    static_cast<std::int8_t>(date->tm_mon),
+                    static_cast<std::int8_t>(date->tm_mday)
+                };
+                a.set(d);
+                args.push_back(a);
+                break;
+            }
+            case proto::Scalar::kDatetime: {
+                auto datetime_ts = arg.datetime();
+                // convert ts to date
+                auto tt = t_time(datetime_ts);
+
+                a.set(tt);
+                args.push_back(a);
+
+                break;
+            }
+
+            case proto::Scalar::kNull:
+                a.set(t_none());
+                args.push_back(a);
+                break;
+            case proto::Scalar::SCALAR_NOT_SET:
+                PSP_COMPLAIN_AND_ABORT(
+                    "Filter scalar type not implemented: "
+                    + std::to_string(arg.scalar_case())
+                )
+                break;
+        }
+    }
+
+    if (str_to_filter_op(f.op()) == FILTER_OP_IN_SUBNET && args.size() != 2) {
+        PSP_COMPLAIN_AND_ABORT("`in subnet` expects a single CIDR string");
+    }
+
+    return args;
 }
 
-void
-ProtoServer::handle_process_table(
-    const Request& req,
-    std::vector<ProtoServerResp<ProtoServer::Response>>& proto_resp
+// A temporary flat view of every column of `table`, for exporting a
+// snapshot of the table. Its context is unregistered when it is released.
+static std::shared_ptr<ErasedView>
+make_snapshot_view(
+    const std::shared_ptr<Table>& table, const std::string& name
 ) {
-    if (needs_poll(req.client_req_case())) {
-        if (entity_type_is_table(req.client_req_case())) {
-            if (m_resources.is_table_dirty(req.entity_id())) {
-                auto table = m_resources.get_table(req.entity_id());
-                const auto& table_id = req.entity_id();
-                _process_table(table, table_id, proto_resp);
+    auto schema =
+        std::make_shared<t_schema>(table->get_gnode()->get_output_schema());
+
+    auto config = std::make_shared<t_view_config>(
+        std::vector<std::string>{},
+        std::vector<std::string>{},
+        tsl::ordered_map<std::string, std::vector<std::string>>{},
+        table->get_column_names(),
+        std::vector<
+            std::tuple<std::string, std::string, std::vector<t_tscalar>>>{},
+        std::vector<std::vector<std::string>>{},
+        std::vector<std::shared_ptr<t_computed_expression>>{},
+        "and",
+        false
+    );
+
+    config->init(schema);
+    auto ctx = make_context<t_ctx0>(table, schema, config, name);
+    auto view =
+        std::make_shared<View<t_ctx0>>(table, ctx, name, "|", config);
+
+    return std::make_shared<Ctx0View>(std::move(view));
+}
+
+// Every row and column of `view` as Arrow, as `ViewToArrowReq` without a
+// viewport, and the number of rows exported.
+static std::pair<std::shared_ptr<std::string>, std::uint64_t>
+view_snapshot_to_arrow(const ErasedView& view, bool compress) {
+    auto config = view.get_view_config();
+    auto num_hidden = calculate_num_hidden(view, *config);
+    auto dims = parse_format_options(
+        proto::ViewPort(),
+        view.num_columns(),
+        view.num_rows(),
+        view.sides(),
+        config->is_column_only(),
+        num_hidden
+    );
+
+    auto arrow = view.to_arrow(
+        dims.start_row,
+        dims.end_row,
+        dims.start_col,
+        dims.end_col,
+        true,
+        compress
+    );
+
+    return {arrow, dims.end_row - dims.start_row};
+}
+
+// The cells of an overlay's `delta` as `from_rows` JSON: each row's index and
+// the cells the overlay has set, which are written over its base's rows.
+static std::string
+overlay_rows_json(const Table& delta) {
+    const auto* master = delta.get_gnode()->get_table();
+    const auto& index = delta.get_index();
+    rapidjson::StringBuffer rows;
+    rapidjson::Writer<rapidjson::StringBuffer> writer(rows);
+    writer.StartArray();
+    for (const auto& [_, ridx] : delta.get_gnode()->get_pkey_map()) {
+        writer.StartObject();
+        for (const auto& column : delta.get_column_names()) {
+            auto value = master->get_const_column(column)->get_scalar(ridx);
+            if (column != index && !value.is_valid()) {
+                continue;
             }
-        } else {
-            auto table_id = m_resources.get_table_id_for_view(req.entity_id());
-            if (m_resources.is_table_dirty(table_id)) {
-                auto table = m_resources.get_table(table_id);
-                _process_table(table, table_id, proto_resp);
+
+            auto json = scalar_to_json(value);
+            writer.Key(column.c_str());
+            writer.RawValue(json.c_str(), json.size(), rapidjson::kObjectType);
+        }
+
+        writer.EndObject();
+    }
+
+    writer.EndArray();
+    return rows.GetString();
+}
+
+// An overlay's removed rows, as their normalized index JSON, as
+// `remove_rows` JSON.
+static std::string
+removed_rows_json(const std::set<std::string>& removed) {
+    rapidjson::StringBuffer rows;
+    rapidjson::Writer<rapidjson::StringBuffer> writer(rows);
+    writer.StartArray();
+    for (const auto& index_json : removed) {
+        writer.RawValue(
+            index_json.c_str(), index_json.size(), rapidjson::kObjectType
+        );
+    }
+
+    writer.EndArray();
+    return rows.GetString();
+}
+
+// The index values, normalized by `normalize_index`, of the rows a
+// `TableRemoveReq` removes from `table`.
+static std::vector<std::string>
+remove_index_values(const Table& table, const proto::TableRemoveReq& r) {
+    const auto& data = r.data();
+    if (data.data_case() != proto::MakeTableData::kFromRows
+        && data.data_case() != proto::MakeTableData::kFromCols) {
+        PSP_COMPLAIN_AND_ABORT("remove malformed");
+    }
+
+    const auto& index = table.get_index();
+    rapidjson::Document doc;
+    const auto& json = data.data_case() == proto::MakeTableData::kFromRows
+        ? data.from_rows()
+        : data.from_cols();
+
+    doc.Parse(json.c_str());
+
+    const rapidjson::Value* cells = &doc;
+    if (doc.IsObject() && doc.HasMember(index.c_str())) {
+        cells = &doc[index.c_str()];
+    }
+
+    if (doc.HasParseError() || !cells->IsArray()) {
+        PSP_COMPLAIN_AND_ABORT("remove malformed");
+    }
+
+    std::vector<std::string> index_values;
+    for (const auto& row : cells->GetArray()) {
+        const auto& cell = row.IsObject() && row.HasMember(index.c_str())
+            ? row[index.c_str()]
+            : row;
+
+        rapidjson::StringBuffer cell_json;
+        rapidjson::Writer<rapidjson::StringBuffer> writer(cell_json);
+        cell.Accept(writer);
+        index_values.push_back(normalize_index(table, cell_json.GetString()));
+    }
+
+    return index_values;
+}
+
+// Replace the rows of `out` with those the views of an overlay read: the live
+// rows of its `base`, less those it `removed`, with the cells of its `delta`
+// written over them.
+static void
+fill_overlay_table(
+    Table& out,
+    const std::shared_ptr<Table>& base,
+    const Table& delta,
+    const std::set<std::string>& removed
+) {
+    out.remove_all();
+    auto view = make_snapshot_view(base, "__overlay__");
+    auto [arrow, num_rows] = view_snapshot_to_arrow(*view, false);
+    if (num_rows > 0) {
+        out.update_arrow(*arrow, 0);
+    }
+
+    // A removed row which the overlay has since updated has only the cells
+    // of its delta, as it would on a `Table` updated after the remove.
+    if (!removed.empty()) {
+        out.remove_rows(removed_rows_json(removed));
+    }
+
+    if (!delta.get_gnode()->get_pkey_map().empty()) {
+        out.update_rows(overlay_rows_json(delta), 0);
+    }
+
+    out.get_pool()->_process();
+}
+
+// Rebuild `table` with `virtual_columns`, expression columns of the table
+// itself, as real columns which its gnode computes on every update.
+static std::shared_ptr<Table>
+make_virtual_table(
+    const std::shared_ptr<Table>& table,
+    const google::protobuf::Map<std::string, std::string>& virtual_columns
+) {
+    const auto& gnode = table->get_gnode();
+    auto schema = table->get_schema();
+    auto base_schema = std::make_shared<t_schema>(schema);
+    std::vector<std::shared_ptr<t_computed_expression>> expressions;
+    for (const auto& expr : parse_expression_strings(virtual_columns)) {
+        std::vector<std::pair<std::string, std::string>> column_ids(
+            expr.column_id_map.begin(), expr.column_id_map.end()
+        );
+
+        const auto& res = table->validate_expressions({std::make_tuple(
+            expr.expression_alias,
+            expr.expression,
+            expr.parse_expression_string,
+            column_ids
+        )});
+
+        if (!res.get_expression_errors().empty()) {
+            PSP_COMPLAIN_AND_ABORT(res.get_expression_errors()
+                                       .at(expr.expression_alias)
+                                       .m_error_message);
+        }
+
+        auto computed_expression = t_computed_expression_parser::precompute(
+            expr.expression_alias,
+            expr.expression,
+            expr.parse_expression_string,
+            column_ids,
+            gnode->get_table_sptr(),
+            gnode->get_pkey_map(),
+            base_schema,
+            *gnode->get_expression_vocab(),
+            *gnode->get_expression_regex_mapping()
+        );
+
+        auto dtype = computed_expression->get_dtype();
+        schema.add_column(expr.expression_alias, dtype);
+        expressions.push_back(std::make_shared<t_computed_expression>(
+            expr.expression_alias,
+            expr.expression,
+            expr.parse_expression_string,
+            column_ids,
+            dtype
+        ));
+    }
+
+    // The rows are copied into a table whose schema includes the virtual
+    // columns, which computes them as it processes the copy.
+    auto out =
+        Table::from_schema(table->get_index(), schema, table->get_limit());
+
+    out->get_gnode()->set_virtual_columns(std::move(expressions));
+    auto view = make_snapshot_view(table, "__virtual_columns__");
+    auto [arrow, num_rows] = view_snapshot_to_arrow(*view, false);
+    if (num_rows > 0) {
+        out->update_arrow(*arrow, 0);
+        out->get_pool()->_process();
+    }
+
+    return out;
+}
+
+// The `Table` a view of `cfg` reads: `table` itself, or if `cfg` has
+// `unnest` columns, a copy of `table` with them unnested, which
+// `_process_table_unchecked` rebuilds whenever `table` is updated.
+static std::shared_ptr<Table>
+unnest_view_table(
+    const std::shared_ptr<Table>& table, const proto::ViewConfig& cfg
+) {
+    if (cfg.unnest().empty()) {
+        return table;
+    }
+
+    return Table::from_unnested(
+        *table, {cfg.unnest().begin(), cfg.unnest().end()}
+    );
+}
+
+/**
+ * @brief A copy of `cfg` in which each filter value that is a string `:name`,
+ * for a `name` in `cfg.params()`, is replaced by the value of that parameter.
+ */
+static proto::ViewConfig
+bind_view_params(const proto::ViewConfig& cfg) {
+    proto::ViewConfig bound = cfg;
+    bound.clear_params();
+    if (cfg.params().empty()) {
+        return bound;
+    }
+
+    for (auto& filter : *bound.mutable_filter()) {
+        for (auto& value : *filter.mutable_value()) {
+            if (value.scalar_case() != proto::Scalar::kString
+                || value.string().size() < 2 || value.string()[0] != ':') {
+                continue;
+            }
+
+            auto param = cfg.params().find(value.string().substr(1));
+            if (param != cfg.params().end()) {
+                value = param->second;
             }
         }
     }
+
+    return bound;
 }
 
-static std::string_view
-view_sides_to_string(const ErasedView& view) {
-    switch (view.sides()) {
-        case 0:
-            return "zero";
-        case 1:
-            return "one";
-        case 2:
-            return "two";
-        default:
-            PSP_COMPLAIN_AND_ABORT("Invalid number of sides");
+/**
+ * @brief Replace each field of `cfg` named in `fields` with that field of
+ * `update`, for `View::update_config()`.
+ */
+static void
+apply_view_config_update(
+    proto::ViewConfig& cfg,
+    const proto::ViewConfig& update,
+    const google::protobuf::RepeatedPtrField<std::string>& fields
+) {
+    for (const auto& field : fields) {
+        if (field == "group_by") {
+            *cfg.mutable_group_by() = update.group_by();
+        } else if (field == "split_by") {
+            *cfg.mutable_split_by() = update.split_by();
+        } else if (field == "columns") {
+            *cfg.mutable_columns() = update.columns();
+        } else if (field == "filter") {
+            *cfg.mutable_filter() = update.filter();
+        } else if (field == "filter_op") {
+            cfg.set_filter_op(update.filter_op());
+        } else if (field == "sort") {
+            *cfg.mutable_sort() = update.sort();
+        } else if (field == "expressions") {
+            *cfg.mutable_expressions() = update.expressions();
+        } else if (field == "aggregates") {
+            *cfg.mutable_aggregates() = update.aggregates();
+        } else if (field == "group_by_depth") {
+            cfg.set_group_by_depth(update.group_by_depth());
+        } else if (field == "timezone") {
+            cfg.set_timezone(update.timezone());
+        } else if (field == "null_groups") {
+            cfg.set_null_groups(update.null_groups());
+        } else if (field == "null_aggregates") {
+            cfg.set_null_aggregates(update.null_aggregates());
+        } else if (field == "params") {
+            *cfg.mutable_params() = update.params();
+        } else if (field == "column_groups") {
+            *cfg.mutable_column_groups() = update.column_groups();
+        } else if (field == "grand_total") {
+            cfg.set_grand_total(update.grand_total());
+        } else if (field == "subtotals") {
+            cfg.set_subtotals(update.subtotals());
+        } else if (field == "show_values_as") {
+            *cfg.mutable_show_values_as() = update.show_values_as();
+        } else if (field == "unnest") {
+            *cfg.mutable_unnest() = update.unnest();
+        } else {
+            PSP_COMPLAIN_AND_ABORT("Unknown view config field `" + field + "`");
+        }
     }
 }
 
-static std::uint32_t
-calculate_num_hidden(const ErasedView& view, const t_view_config& config) {
-    LOG_DEBUG("Calculating num hidden");
-    auto num_hidden = 0;
-    auto sides = view.sides();
-    LOG_DEBUG("View sides: " << sides);
-    switch (sides) {
-        case 0:
-            break;
-        case 1:
-        case 2: {
-            const auto& cols = config.get_columns();
-            for (const auto& s : config.get_sortspec()) {
-                LOG_DEBUG("Checking sort spec: " << s.m_colname);
-                if (std::find(cols.begin(), cols.end(), s.m_colname)
-                    == cols.end()) {
-                    LOG_DEBUG("Found hidden column: " << s.m_colname);
-                    num_hidden++;
-                }
+static std::vector<std::string>
+row_path_strings(const ErasedView& view, t_uindex ridx) {
+    std::vector<std::string> path;
+    for (const auto& scalar : view.get_row_path(ridx)) {
+        path.push_back(scalar.to_string());
+    }
+
+    return path;
+}
+
+/**
+ * @brief Whether each group row of `view` is expanded, by row path.
+ */
+static std::map<std::vector<std::string>, bool>
+expansion_state(const ErasedView& view) {
+    std::map<std::vector<std::string>, bool> expanded;
+    const auto& config = *view.get_view_config();
+    auto num_pivots = config.get_row_pivots().size();
+    if (num_pivots == 0 || config.is_column_only()) {
+        return expanded;
+    }
+
+    for (t_uindex ridx = 0; ridx < view.num_rows(); ++ridx) {
+        auto path = row_path_strings(view, ridx);
+        if (path.size() < num_pivots) {
+            expanded.emplace(std::move(path), view.get_row_expanded(ridx));
+        }
+    }
+
+    return expanded;
+}
+
+/**
+ * @brief Expand and collapse the rows of `view` to match `expanded`, the
+ * `expansion_state()` of a view with the same `group_by`. Rows which are not
+ * in `expanded` keep their default state.
+ */
+static void
+apply_expansion(
+    ErasedView& view, const std::map<std::vector<std::string>, bool>& expanded
+) {
+    const auto& config = *view.get_view_config();
+    auto num_pivots = config.get_row_pivots().size();
+    if (num_pivots == 0 || config.is_column_only()) {
+        return;
+    }
+
+    // Collapsing a row removes the rows after it and expanding one inserts
+    // rows after it, so `num_rows()` is re-read on each iteration.
+    for (t_uindex ridx = 0; ridx < view.num_rows(); ++ridx) {
+        auto state = expanded.find(row_path_strings(view, ridx));
+        if (state == expanded.end()
+            || state->second == view.get_row_expanded(ridx)) {
+            continue;
+        }
+
+        if (state->second) {
+            view.expand(ridx);
+        } else {
+            view.collapse(ridx);
+        }
+    }
+}
+
+/**
+ * @brief Expand and collapse the rows of `view` to match the rows of
+ * `previous`, a view with the same `group_by`, by row path, so a rebuilt view
+ * keeps the expand state of the view it replaces.
+ */
+static void
+restore_expansion(const ErasedView& previous, ErasedView& view) {
+    apply_expansion(view, expansion_state(previous));
+}
+
+/**
+ * @brief Write `view_config` to `view_config_proto`, as it was normalized when
+ * its `View` was created.
+ */
+static void
+view_config_to_proto(
+    const t_view_config& view_config, proto::ViewConfig* view_config_proto
+) {
+    for (const auto& col : view_config.get_columns()) {
+        view_config_proto->mutable_columns()->mutable_columns()->add_columns(
+            col
+        );
+    }
+
+    for (const auto& agg : view_config.get_row_pivots()) {
+        if (agg == "psp_pkey" || agg == "psp_okey") {
+            continue;
+        }
+        view_config_proto->add_group_by(agg);
+    }
+
+    for (const auto& agg : view_config.get_column_pivots()) {
+        view_config_proto->add_split_by(agg);
+    }
+
+    // TODO: Sort, Expressions, and Aggregations
+
+    for (const auto& sort : view_config.get_sortspec()) {
+        auto* proto_sort = view_config_proto->mutable_sort();
+        auto* s = proto_sort->Add();
+        s->set_column(sort.m_colname);
+        s->set_op(sort_op_to_proto(sort.m_sort_type));
+    }
+
+    for (const auto& filter : view_config.get_fterm()) {
+        auto* proto_filter = view_config_proto->mutable_filter();
+        auto* f = proto_filter->Add();
+        f->set_column(filter.m_colname);
+        f->set_op(filter_op_to_str(filter.m_op));
+        if (filter.m_op == FILTER_OP_IN_SUBNET) {
+            t_subnet subnet{
+                filter.m_bag[0].get<t_ipaddr>(),
+                filter.m_bag[1].get<t_ipaddr>()
+            };
+
+            f->mutable_value()->Add()->set_string(subnet.str());
+            continue;
+        }
+
+        auto vals = std::vector<t_tscalar>(filter.m_bag.size() + 1);
+        if (filter.m_op != FILTER_OP_NOT_IN && filter.m_op != FILTER_OP_IN) {
+            vals.push_back(filter.m_threshold);
+        } else {
+            for (const auto& scalar : filter.m_bag) {
+                vals.push_back(scalar);
             }
-            for (const auto& s : config.get_col_sortspec()) {
-                if (std::find(cols.begin(), cols.end(), s.m_colname)
-                    == cols.end()) {
-                    LOG_DEBUG("Found hidden column: " << s.m_colname);
-                    num_hidden++;
+        }
+        for (const auto& scalar : vals) {
+            auto* s = f->mutable_value()->Add();
+            switch (scalar.get_dtype()) {
+                case DTYPE_BOOL:
+                    s->set_bool_(scalar.get<bool>());
+                    break;
+                case DTYPE_FLOAT64:
+                    s->set_float_(scalar.get<double>());
+                    break;
+                case DTYPE_INT64: {
+                    auto val = scalar.get<std::int64_t>();
+                    if (val > std::numeric_limits<std::int32_t>::max()
+                        || val < std::numeric_limits<std::int32_t>::min()) {
+                        s->set_int64(val);
+                    } else {
+                        s->set_int_(static_cast<std::int32_t>(val));
+                    }
+                    break;
+                }
+                case DTYPE_UINT64:
+                    s->set_uint64(scalar.get<std::uint64_t>());
+                    break;
+                case DTYPE_STR:
+                    s->set_string(scalar.get<const char*>());
+                    break;
+                case DTYPE_DATE: {
+                    auto tm = scalar.get<t_date>().get_tm();
+                    s->set_date(std::mktime(&tm));
+                    break;
                 }
+                case DTYPE_TIME:
+                    s->set_datetime(scalar.get<t_time>().raw_value());
+                    break;
+                case DTYPE_DURATION:
+                case DTYPE_LIST:
+                case DTYPE_JSON:
+                case DTYPE_BINARY:
+                case DTYPE_UUID:
+                case DTYPE_IPADDR:
+                    s->set_string(scalar.to_string());
+                    break;
+                case DTYPE_NONE:
+                    s->set_null(::google::protobuf::NullValue::NULL_VALUE);
+                    break;
+                default:
+                    PSP_COMPLAIN_AND_ABORT(
+                        "Invalid scalar type: " + scalar.to_string()
+                    );
             }
-        } break;
+        }
+    }
+
+    switch (view_config.get_filter_op()) {
+        case FILTER_OP_OR:
+            view_config_proto->set_filter_op(
+                proto::ViewConfig_FilterReducer::ViewConfig_FilterReducer_OR
+            );
+            break;
+        case FILTER_OP_AND:
         default:
-            PSP_COMPLAIN_AND_ABORT(
-                "Invalid number of sides: " + std::to_string(view.sides())
+            view_config_proto->set_filter_op(
+                proto::ViewConfig_FilterReducer::ViewConfig_FilterReducer_AND
             );
+            break;
     }
-    return num_hidden;
-}
 
-template <typename A>
-static t_tscalar
-coerce_to(const t_dtype dtype, const A& val) {
-    if constexpr (std::is_same_v<A, std::string>) {
-        t_tscalar scalar;
-        scalar.clear();
-        switch (dtype) {
-            case DTYPE_STR:
-                scalar.set(val.c_str());
-                return scalar;
-            case DTYPE_BOOL:
-                scalar.set(val == "true");
-                return scalar;
-            case DTYPE_FLOAT64:
-                scalar.set(std::stod(val));
-                return scalar;
-            case DTYPE_UINT32:
-                scalar.set((std::uint32_t)std::stoul(val));
-                return scalar;
-            case DTYPE_UINT64:
-                scalar.set((std::uint64_t)std::stoull(val));
-                return scalar;
-            case DTYPE_INT32:
-                scalar.set(std::stoi(val));
-                return scalar;
-            case DTYPE_INT64:
-                scalar.set((std::int64_t)std::stoll(val));
-                return scalar;
-            case DTYPE_TIME: {
-                std::chrono::system_clock::time_point tp;
+    if (view_config.get_row_pivot_depth() != -1) {
+        view_config_proto->set_group_by_depth(
+            view_config.get_row_pivot_depth()
+        );
+    }
 
-                if (!parse_all_date_time(tp, val)) {
-                    PSP_COMPLAIN_AND_ABORT("Invalid date format");
-                }
+    if (!view_config.get_timezone().empty()) {
+        view_config_proto->set_timezone(view_config.get_timezone());
+    }
 
-                // If parsing succeeded, create a time structure
-                t_time time{
-                    std::chrono::duration_cast<std::chrono::milliseconds>(
-                        tp.time_since_epoch()
-                    )
-                        .count()
-                };
+    if (view_config.get_exclude_null_groups()) {
+        view_config_proto->set_null_groups(
+            proto::ViewConfig_NullGroups_NULL_GROUPS_EXCLUDE
+        );
+    }
 
-                LOG_DEBUG("Parsed time: " << time.raw_value());
-                scalar.set(time);
-                return scalar;
-            }
-            case DTYPE_DATE: {
-                std::tm tm = {};
-                if (!parse_all_date_time(tm, val)) {
-                    PSP_COMPLAIN_AND_ABORT("Invalid date format");
-                }
-                t_date date{
-                    static_cast<std::int16_t>(tm.tm_year + 1900),
-                    static_cast<std::int8_t>(tm.tm_mon),
-                    static_cast<std::int8_t>(tm.tm_mday)
-                };
+    switch (view_config.get_null_aggregates()) {
+        case NULL_AGGREGATES_IGNORE:
+            view_config_proto->set_null_aggregates(
+                proto::ViewConfig_NullAggregates_NULL_AGGREGATES_IGNORE
+            );
+            break;
+        case NULL_AGGREGATES_PROPAGATE:
+            view_config_proto->set_null_aggregates(
+                proto::ViewConfig_NullAggregates_NULL_AGGREGATES_PROPAGATE
+            );
+            break;
+        case NULL_AGGREGATES_DEFAULT:
+            break;
+    }
+
+    if (view_config.get_grand_total() != TOTALS_BEFORE) {
+        view_config_proto->set_grand_total(
+            totals_to_total_position(view_config.get_grand_total())
+        );
+    }
 
-                LOG_DEBUG("Parsed date: " << date.str());
-                scalar.set(date);
-                return scalar;
-            }
-            default:
-                PSP_COMPLAIN_AND_ABORT(
-                    "Column of type " + dtype_to_str(dtype)
-                    + " cannot cast from string"
-                );
-        }
-    } else if constexpr (std::is_same_v<A, double>) {
-        switch (dtype) {
-            case DTYPE_FLOAT32:
-                return t_tscalar(static_cast<float>(val));
-            case DTYPE_FLOAT64:
-                return t_tscalar(val);
-            default:
-                PSP_COMPLAIN_AND_ABORT("Unsupported type");
-        }
-    } else if constexpr (std::is_same_v<A, float>) {
-        switch (dtype) {
-            case DTYPE_FLOAT32:
-                return t_tscalar(val);
-            case DTYPE_FLOAT64:
-                return t_tscalar(static_cast<double>(val));
-            default:
-                PSP_COMPLAIN_AND_ABORT("Unsupported type");
+    if (view_config.get_subtotals() != TOTALS_BEFORE) {
+        view_config_proto->set_subtotals(
+            totals_to_total_position(view_config.get_subtotals())
+        );
+    }
+
+    auto* proto_show_values_as = view_config_proto->mutable_show_values_as();
+    for (const auto& [column, show] : view_config.get_show_values_as()) {
+        if (show != SHOW_VALUES_AS_VALUE) {
+            (*proto_show_values_as)[column] = show_values_as_to_proto(show);
         }
-    } else if constexpr (std::is_same_v<A, std::int32_t>) {
-        return t_tscalar(val);
-    } else if constexpr (std::is_same_v<A, std::int64_t>) {
-        return t_tscalar(val);
-    } else if constexpr (std::is_same_v<A, bool>) {
-        return t_tscalar(val);
-    } else {
-        static_assert(!std::is_same_v<A, A>, "Unsupported type");
+    }
+
+    for (const auto& expr : view_config.get_expressions()) {
+        auto* proto_exprs = view_config_proto->mutable_expressions();
+        (*proto_exprs)[expr->get_expression_alias()] =
+            expr->get_expression_string();
     }
 }
 
@@ -1100,14 +3576,67 @@ ProtoServer::_handle_request(std::uint32_t client_id, const Request& req) {
         proto_resp.emplace_back(std::move(resp2));
     };
 
+    // Overlays are private to the session which created them, for every
+    // table request and not just those which read or write their data.
+    if (entity_type_is_table(req.client_req_case())) {
+        m_resources.check_overlay_owner(req.entity_id(), client_id);
+    }
+
     handle_process_table(req, proto_resp);
+    auto start = SlowOpLog::t_clock::now();
     switch (req.client_req_case()) {
+        case proto::Request::kServerHelloReq: {
+            const auto& r = req.server_hello_req();
+            auto version =
+                std::min(r.max_protocol_version(), PSP_MAX_PROTOCOL_VERSION);
+
+            if (version < r.min_protocol_version()
+                || version < PSP_MIN_PROTOCOL_VERSION) {
+                proto::Response resp;
+                auto* err = resp.mutable_server_error();
+                err->set_status_code(proto::PROTOCOL_MISMATCH);
+                err->set_message(
+                    "Client speaks protocol versions "
+                    + std::to_string(r.min_protocol_version()) + "-"
+                    + std::to_string(r.max_protocol_version())
+                    + " (perspective " + r.client_version()
+                    + "), server speaks "
+                    + std::to_string(PSP_MIN_PROTOCOL_VERSION) + "-"
+                    + std::to_string(PSP_MAX_PROTOCOL_VERSION)
+                );
+
+                push_resp(std::move(resp));
+                break;
+            }
+
+            proto::Response resp;
+            auto* hello = resp.mutable_server_hello_resp();
+            hello->set_protocol_version(version);
+            hello->set_server_version(std::to_string(PSP_VERSION));
+            for (const auto& feature : r.features()) {
+                if (std::find(
+                        PROTOCOL_FEATURES.begin(),
+                        PROTOCOL_FEATURES.end(),
+                        feature
+                    )
+                    != PROTOCOL_FEATURES.end()) {
+                    hello->add_features(feature);
+                }
+            }
+
+            push_resp(std::move(resp));
+            break;
+        }
         case proto::Request::kGetFeaturesReq: {
             proto::Response resp;
             const auto& features = resp.mutable_get_features_resp();
             features->set_group_by(true);
             features->set_split_by(true);
+#ifdef PSP_DISABLE_EXPRESSIONS
+            features->set_expressions(false);
+#else
             features->set_expressions(true);
+#endif
             proto::GetFeaturesResp_ColumnTypeOptions opts;
             opts.add_options("==");
             opts.add_options("!=");
@@ -1140,9 +3669,36 @@ ProtoServer::_handle_request(std::uint32_t client_id, const Request& req) {
             (*features->mutable_filter_ops())[proto::ColumnType::DATE] = opts2;
             (*features->mutable_filter_ops())[proto::ColumnType::DATETIME] =
                 opts2;
+            (*features->mutable_filter_ops())[proto::ColumnType::DURATION] =
+                opts2;
             (*features->mutable_filter_ops())[proto::ColumnType::INTEGER] =
                 std::move(opts2);
 
+            proto::GetFeaturesResp_ColumnTypeOptions uuid_opts;
+            uuid_opts.add_options("==");
+            uuid_opts.add_options("!=");
+            uuid_opts.add_options("in");
+            uuid_opts.add_options("not in");
+            uuid_opts.add_options("is not null");
+            uuid_opts.add_options("is null");
+            (*features->mutable_filter_ops())[proto::ColumnType::UUID] =
+                std::move(uuid_opts);
+
+            proto::GetFeaturesResp_ColumnTypeOptions ip_opts;
+            ip_opts.add_options("==");
+            ip_opts.add_options("!=");
+            ip_opts.add_options(">");
+            ip_opts.add_options(">=");
+            ip_opts.add_options("<");
+            ip_opts.add_options("<=");
+            ip_opts.add_options("in");
+            ip_opts.add_options("not in");
+            ip_opts.add_options("in subnet");
+            ip_opts.add_options("is not null");
+            ip_opts.add_options("is null");
+            (*features->mutable_filter_ops())[proto::ColumnType::IP] =
+                std::move(ip_opts);
+
             proto::GetFeaturesResp_ColumnTypeOptions opts3;
             opts3.add_options("==");
             // opts3.add_options("!=");
@@ -1155,10 +3711,20 @@ ProtoServer::_handle_request(std::uint32_t client_id, const Request& req) {
             break;
         }
         case proto::Request::kGetHostedTablesReq: {
+            if (req.get_hosted_tables_req().subscribe()) {
+                Subscription sub_info{req.msg_id(), client_id};
+                m_resources.create_on_hosted_tables_update_sub(sub_info);
+                break;
+            }
+
             proto::Response resp;
             const auto& tables = resp.mutable_get_hosted_tables_resp();
             const auto& infos = tables->mutable_table_infos();
             for (const auto& name : m_resources.get_table_ids()) {
+                if (m_resources.is_overlay(name)) {
+                    continue;
+                }
+
                 const auto& v = infos->Add();
 
                 v->set_entity_id(name);
@@ -1171,6 +3737,8 @@ ProtoServer::_handle_request(std::uint32_t client_id, const Request& req) {
                 if (tbl->get_limit() != std::numeric_limits<int>::max()) {
                     v->set_limit(tbl->get_limit());
                 }
+
+                v->set_exclusive_writer(m_resources.is_exclusive_writer(name));
             }
 
             push_resp(std::move(resp));
@@ -1223,12 +3791,24 @@ ProtoServer::_handle_request(std::uint32_t client_id, const Request& req) {
                     break;
                 }
                 case proto::MakeTableData::kFromCsv: {
-                    table = Table::from_csv(index, r.data().from_csv(), limit);
+                    table = Table::from_csv(
+                        index,
+                        r.data().from_csv(),
+                        limit,
+                        csv_options_from_proto(r.data())
+                    );
                     break;
                 }
                 case proto::MakeTableData::kFromCols: {
-                    table =
-                        Table::from_cols(index, r.data().from_cols(), limit);
+                    std::unordered_map<std::string, t_dtype> schema_override;
+                    for (const auto& it : r.data().schema().schema()) {
+                        schema_override[it.name()] =
+                            column_type_to_dtype(it.type());
+                    }
+
+                    table = Table::from_cols(
+                        index, r.data().from_cols(), limit, schema_override
+                    );
                     break;
                 }
                 case proto::MakeTableData::kFromRows: {
@@ -1255,17 +3835,88 @@ ProtoServer::_handle_request(std::uint32_t client_id, const Request& req) {
                 }
             }
 
+            if (!r.options().virtual_columns().empty()) {
+                table =
+                    make_virtual_table(table, r.options().virtual_columns());
+            }
+
+            for (const auto& [column, categories] : r.options().categories()) {
+                table->set_categories(
+                    column,
+                    {categories.categories().begin(),
+                     categories.categories().end()}
+                );
+            }
+
+            for (const auto& [column, dictionary] :
+                 r.options().dictionaries()) {
+                t_dictionary_options options;
+                if (dictionary.has_reserve()) {
+                    options.m_reserve = dictionary.reserve();
+                }
+
+                if (dictionary.has_max_cardinality()) {
+                    options.m_max_cardinality = dictionary.max_cardinality();
+                }
+
+                table->set_dictionary_options(column, options);
+            }
+
+            table->check_dictionary_cardinality();
+            for (const auto& [column, hints] : r.options().column_hints()) {
+                t_column_hints column_hints;
+                if (hints.has_aggregate()) {
+                    column_hints.m_aggregate = hints.aggregate();
+                }
+
+                if (hints.has_precision()) {
+                    column_hints.m_precision = hints.precision();
+                }
+
+                if (hints.has_thousands_separator()) {
+                    column_hints.m_thousands_separator =
+                        hints.thousands_separator();
+                }
+
+                if (hints.has_editable()) {
+                    column_hints.m_editable = hints.editable();
+                }
+
+                if (hints.has_timezone()) {
+                    column_hints.m_timezone = hints.timezone();
+                }
+
+                table->set_column_hints(column, column_hints);
+            }
+
+            table->set_compression(r.options().compress());
             m_resources.host_table(req.entity_id(), table);
+            if (r.options().exclusive_writer()) {
+                m_resources.set_exclusive_writer(req.entity_id(), client_id);
+            }
+
+            if (r.options().has_batch_latency_ms()) {
+                m_resources.set_batch_latency(
+                    req.entity_id(),
+                    std::chrono::milliseconds(r.options().batch_latency_ms())
+                );
+            }
+
             proto::Response resp;
             resp.mutable_make_table_resp();
             push_resp(std::move(resp));
+            _hosted_tables_update(proto_resp);
             break;
         }
         case proto::Request::kTableSizeReq: {
             auto table = m_resources.get_table(req.entity_id());
             proto::Response resp;
             auto* tbl_size = resp.mutable_table_size_resp();
-            tbl_size->set_size(table->size());
+            tbl_size->set_size(
+                m_resources.is_overlay(req.entity_id())
+                    ? _overlay_size(req.entity_id())
+                    : table->size()
+            );
             push_resp(std::move(resp));
             break;
         }
@@ -1284,6 +3935,246 @@ ProtoServer::_handle_request(std::uint32_t client_id, const Request& req) {
                 ktp->set_type(dtype_to_column_type(types[i]));
             }
 
+            auto* output_hints =
+                resp.mutable_table_schema_resp()->mutable_column_hints();
+            for (const auto& [column, hints] : table->get_column_hints()) {
+                auto& column_hints = (*output_hints)[column];
+                if (hints.m_aggregate.has_value()) {
+                    column_hints.set_aggregate(*hints.m_aggregate);
+                }
+
+                if (hints.m_precision.has_value()) {
+                    column_hints.set_precision(*hints.m_precision);
+                }
+
+                if (hints.m_thousands_separator.has_value()) {
+                    column_hints.set_thousands_separator(
+                        *hints.m_thousands_separator
+                    );
+                }
+
+                if (hints.m_editable.has_value()) {
+                    column_hints.set_editable(*hints.m_editable);
+                }
+
+                if (hints.m_timezone.has_value()) {
+                    column_hints.set_timezone(*hints.m_timezone);
+                }
+            }
+
+            auto* virtual_columns =
+                resp.mutable_table_schema_resp()->mutable_virtual_columns();
+            for (const auto& expr : table->get_gnode()->get_virtual_columns()) {
+                (*virtual_columns)[expr->get_expression_alias()] =
+                    expr->get_expression_string();
+            }
+
+            push_resp(std::move(resp));
+            break;
+        }
+        case proto::Request::kTableExpressionCompletionsReq: {
+            auto table = m_resources.get_table(req.entity_id());
+            proto::Response resp;
+            auto* completions =
+                resp.mutable_table_expression_completions_resp();
+            for (const auto& function : expression_functions()) {
+                auto* f = completions->add_functions();
+                f->set_name(function.m_name);
+                f->set_signature(function.m_signature);
+                f->set_insert_text(function.m_insert_text);
+                f->set_documentation(function.m_documentation);
+            }
+
+            for (const auto& column : table->get_schema().columns()) {
+                completions->add_column_names(column);
+            }
+
+            push_resp(std::move(resp));
+            break;
+        }
+        case proto::Request::kTableCategoriesReq: {
+            auto table = m_resources.get_table(req.entity_id());
+            proto::Response resp;
+            auto* categories =
+                resp.mutable_table_categories_resp()->mutable_categories();
+            for (const auto& [column, values] : table->get_categories()) {
+                auto& list = (*categories)[column];
+                for (const auto& value : values) {
+                    list.add_categories(value);
+                }
+            }
+
+            push_resp(std::move(resp));
+            break;
+        }
+        case proto::Request::kTableDictionaryStatsReq: {
+            auto table = m_resources.get_table(req.entity_id());
+            const auto& options = table->get_dictionary_options();
+            proto::Response resp;
+            auto* stats =
+                resp.mutable_table_dictionary_stats_resp()->mutable_stats();
+            for (const auto& [column, dictionary] :
+                 table->get_dictionary_stats()) {
+                auto& column_stats = (*stats)[column];
+                column_stats.set_size(dictionary.m_size);
+                column_stats.set_bytes(dictionary.m_bytes);
+                auto iter = options.find(column);
+                if (iter != options.end()
+                    && iter->second.m_max_cardinality.has_value()) {
+                    column_stats.set_max_cardinality(
+                        *iter->second.m_max_cardinality
+                    );
+                }
+            }
+
+            push_resp(std::move(resp));
+            break;
+        }
+        case proto::Request::kTableStatsReq: {
+            const auto& r = req.table_stats_req();
+            auto table = m_resources.get_table(req.entity_id());
+            const auto& update_stats = table->get_gnode()->get_update_stats();
+            auto interval_ms = r.has_interval_ms() ? r.interval_ms() : 60000;
+            interval_ms = std::min<std::uint64_t>(
+                interval_ms, PSP_UPDATE_STATS_MAX_INTERVAL_MS
+            );
+
+            proto::Response resp;
+            auto* stats = resp.mutable_table_stats_resp()->mutable_stats();
+            stats->set_num_rows(table->size());
+            auto set_counts = [](proto::TableUpdateCounts& out,
+                                 const t_update_counts& counts,
+                                 t_uindex batches) {
+                out.set_added(counts.m_added);
+                out.set_updated(counts.m_updated);
+                out.set_removed(counts.m_removed);
+                out.set_batches(batches);
+            };
+
+            set_counts(
+                *stats->mutable_total(),
+                update_stats.get_total(),
+                update_stats.get_num_batches()
+            );
+
+            auto [recent, recent_batches] =
+                update_stats.get_recent(interval_ms);
+            set_counts(*stats->mutable_recent(), recent, recent_batches);
+            stats->set_interval_ms(interval_ms);
+            if (auto last_update = update_stats.get_last_update()) {
+                stats->set_last_update(*last_update);
+            }
+
+            for (auto count : update_stats.get_batch_sizes()) {
+                stats->add_batch_sizes(count);
+            }
+
+            auto bytes = table->get_gnode()->get_storage_bytes();
+            stats->set_raw_bytes(bytes.m_raw);
+            stats->set_stored_bytes(bytes.m_stored);
+
+            push_resp(std::move(resp));
+            break;
+        }
+        case proto::Request::kTableOverlayReq: {
+            if (m_resources.is_overlay(req.entity_id())) {
+                PSP_COMPLAIN_AND_ABORT(
+                    "Cannot create an overlay of an overlay"
+                );
+            }
+
+            auto base = m_resources.get_table(req.entity_id());
+            if (base->get_index().empty()) {
+                PSP_COMPLAIN_AND_ABORT(
+                    "Overlays require an indexed table, so their edits can be "
+                    "merged with it by row"
+                );
+            }
+
+            // The overlay's table is its delta, which starts empty and holds
+            // only the rows the session edits; its views merge it over the
+            // base table's live rows.
+            auto overlay_id = req.entity_id() + "__overlay_"
+                + std::to_string(client_id) + "_"
+                + std::to_string(req.msg_id());
+
+            auto delta = Table::from_schema(
+                base->get_index(), base->get_schema(), base->get_limit()
+            );
+
+            for (const auto& [column, hints] : base->get_column_hints()) {
+                delta->set_column_hints(column, hints);
+            }
+
+            m_resources.host_table(overlay_id, delta);
+            m_resources.set_exclusive_writer(overlay_id, client_id);
+            m_resources.host_overlay(overlay_id, req.entity_id(), client_id);
+            proto::Response resp;
+            resp.mutable_table_overlay_resp()->set_overlay_id(overlay_id);
+            push_resp(std::move(resp));
+            break;
+        }
+        case proto::Request::kTableOverlayCommitReq: {
+            auto base_id = m_resources.get_overlay_base(req.entity_id());
+            m_resources.check_writer(base_id, client_id);
+            auto base = m_resources.get_table(base_id);
+            auto delta = m_resources.get_table(req.entity_id());
+            auto removed = m_resources.take_overlay_removes(req.entity_id());
+            std::uint32_t num_edits = removed.size();
+            if (!removed.empty()) {
+                base->remove_rows(removed_rows_json(removed));
+            }
+
+            // The delta is replayed after the removes, so a removed row the
+            // overlay has since updated has only the delta's cells.
+            const auto& rows = delta->get_gnode()->get_pkey_map();
+            if (!rows.empty()) {
+                num_edits += rows.size();
+                base->update_rows(overlay_rows_json(*delta), 0);
+                delta->remove_all();
+                m_resources.mark_table_dirty(req.entity_id());
+            }
+
+            if (num_edits > 0) {
+                m_resources.mark_table_dirty(base_id);
+            }
+
+            proto::Response resp;
+            resp.mutable_table_overlay_commit_resp()->set_num_edits(num_edits);
+            push_resp(std::move(resp));
+            break;
+        }
+        case proto::Request::kTableSketchesReq: {
+            auto table = m_resources.get_table(req.entity_id());
+            proto::Response resp;
+            auto* sketches =
+                resp.mutable_table_sketches_resp()->mutable_sketches();
+            for (const auto& [column, sketch] : table->get_sketches()) {
+                auto& column_sketch = (*sketches)[column];
+                column_sketch.set_count(sketch.get_count());
+                if (auto min = sketch.get_min()) {
+                    column_sketch.set_min(scalar_to_json(*min));
+                }
+
+                if (auto max = sketch.get_max()) {
+                    column_sketch.set_max(scalar_to_json(*max));
+                }
+
+                for (const auto& [value, count] :
+                     sketch.get_top_values(PSP_SKETCH_TOP_K)) {
+                    auto* top_value = column_sketch.add_top_values();
+                    top_value->set_value(scalar_to_json(value));
+                    top_value->set_count(count);
+                }
+            }
+
+            push_resp(std::move(resp));
+            break;
+        }
+        case proto::Request::kTableFlushReq: {
+            // Pending updates were processed by `handle_process_table`.
+            proto::Response resp;
+            resp.mutable_table_flush_resp();
             push_resp(std::move(resp));
             break;
         }
@@ -1291,7 +4182,11 @@ ProtoServer::_handle_request(std::uint32_t client_id, const Request& req) {
             auto table = m_resources.get_table(req.entity_id());
             proto::Response resp;
             auto* make_port = resp.mutable_table_make_port_resp();
-            make_port->set_port_id(table->make_port());
+            const auto& name = req.table_make_port_req().name();
+            make_port->set_port_id(
+                name.empty() ? table->make_port()
+                             : table->make_named_port(name)
+            );
 
             push_resp(std::move(resp));
             break;
@@ -1362,6 +4257,7 @@ ProtoServer::_handle_request(std::uint32_t client_id, const Request& req) {
             break;
         }
         case proto::Request::kTableReplaceReq: {
+            m_resources.check_writer(req.entity_id(), client_id);
             auto table = m_resources.get_table(req.entity_id());
             table->clear();
             const auto& r = req.table_replace_req();
@@ -1371,7 +4267,9 @@ ProtoServer::_handle_request(std::uint32_t client_id, const Request& req) {
                     break;
                 }
                 case proto::MakeTableData::kFromCsv: {
-                    table->update_csv(r.data().from_csv(), 0);
+                    table->update_csv(
+                        r.data().from_csv(), 0, csv_options_from_proto(r.data())
+                    );
                     break;
                 }
                 case proto::MakeTableData::kFromRows: {
@@ -1395,26 +4293,91 @@ ProtoServer::_handle_request(std::uint32_t client_id, const Request& req) {
             push_resp(std::move(resp));
             break;
         }
+        case proto::Request::kTableReplaceAtomicReq: {
+            m_resources.check_writer(req.entity_id(), client_id);
+            auto table = m_resources.get_table(req.entity_id());
+            const auto& r = req.table_replace_atomic_req();
+
+            // The removals and the new rows are both queued on port 0, so
+            // they are processed together and views never see the `Table`
+            // empty. Pending updates were processed by `handle_process_table`,
+            // so if the new data fails to load, the input ports only hold
+            // these removals and are cleared to leave the `Table` untouched.
+            table->remove_all();
+            try {
+                switch (r.data().data_case()) {
+                    case proto::MakeTableData::kFromView: {
+                        auto view =
+                            m_resources.get_view(r.data().from_view());
+                        proto::ViewPort viewport;
+                        auto dims = parse_format_options(
+                            viewport,
+                            view->num_columns(),
+                            view->num_rows(),
+                            view->sides(),
+                            view->get_view_config()->is_column_only(),
+                            0
+                        );
+                        auto arrow = view->to_arrow(
+                            dims.start_row,
+                            dims.end_row,
+                            dims.start_col,
+                            dims.end_col
+                        );
+
+                        table->update_arrow(*arrow, 0);
+                        break;
+                    }
+                    case proto::MakeTableData::kFromArrow: {
+                        table->update_arrow(r.data().from_arrow(), 0);
+                        break;
+                    }
+                    case proto::MakeTableData::kFromCsv: {
+                        table->update_csv(
+                            r.data().from_csv(),
+                            0,
+                            csv_options_from_proto(r.data())
+                        );
+                        break;
+                    }
+                    case proto::MakeTableData::kFromRows: {
+                        table->update_rows(r.data().from_rows(), 0);
+                        break;
+                    }
+                    case proto::MakeTableData::kFromCols:
+                        table->update_cols(r.data().from_cols(), 0);
+                        break;
+                    case proto::MakeTableData::kFromSchema:
+                    case proto::MakeTableData::DATA_NOT_SET:
+                    default: {
+                        PSP_COMPLAIN_AND_ABORT(
+                            "TableReplaceAtomicReq malformed"
+                        );
+                        break;
+                    }
+                }
+            } catch (...) {
+                table->get_gnode()->clear_input_ports();
+                throw;
+            }
+
+            m_resources.mark_table_dirty(req.entity_id());
+            proto::Response resp;
+            resp.mutable_table_replace_atomic_resp();
+            push_resp(std::move(resp));
+            break;
+        }
         case proto::Request::kTableRemoveReq: {
+            m_resources.check_writer(req.entity_id(), client_id);
             const auto& r = req.table_remove_req();
             auto table = m_resources.get_table(req.entity_id());
-            switch (r.data().data_case()) {
-                case proto::MakeTableData::kFromCols: {
-                    table->remove_cols(r.data().from_cols());
-                    break;
-                }
-                case proto::MakeTableData::kFromRows: {
-                    table->remove_rows(r.data().from_rows());
-                    break;
-                }
-                case proto::MakeTableData::kFromArrow:
-                case proto::MakeTableData::kFromCsv:
-                case proto::MakeTableData::kFromSchema:
-                case proto::MakeTableData::DATA_NOT_SET:
-                default: {
-                    PSP_COMPLAIN_AND_ABORT("remove malformed");
-                    break;
-                }
+            auto is_overlay = m_resources.is_overlay(req.entity_id());
+            auto index_values = is_overlay ? remove_index_values(*table, r)
+                                           : std::vector<std::string>{};
+
+            apply_table_remove(*table, r);
+            if (is_overlay) {
+                m_resources.push_overlay_removes(req.entity_id(), index_values);
             }
 
             //  proto_resp.should_poll = true;
@@ -1424,353 +4387,334 @@ ProtoServer::_handle_request(std::uint32_t client_id, const Request& req) {
             push_resp(std::move(resp));
             break;
         }
-        case proto::Request::kTableUpdateReq: {
-            const auto& r = req.table_update_req();
-            auto table = m_resources.get_table(req.entity_id());
-            switch (r.data().data_case()) {
-                case proto::MakeTableData::kFromArrow: {
-                    table->update_arrow(r.data().from_arrow(), r.port_id());
-                    break;
-                }
-                case proto::MakeTableData::kFromCsv: {
-                    table->update_csv(r.data().from_csv(), r.port_id());
-                    break;
-                }
-                case proto::MakeTableData::kFromRows: {
-                    table->update_rows(r.data().from_rows(), r.port_id());
-                    break;
-                }
-                case proto::MakeTableData::kFromCols: {
-                    table->update_cols(r.data().from_cols(), r.port_id());
-                    break;
-                }
-                case proto::MakeTableData::kFromSchema:
-                case proto::MakeTableData::DATA_NOT_SET:
-                default: {
-                    PSP_COMPLAIN_AND_ABORT("MakeTableReq malformed");
-                    break;
+        case proto::Request::kTableRemoveWhereReq: {
+            m_resources.check_writer(req.entity_id(), client_id);
+            if (m_resources.is_overlay(req.entity_id())) {
+                PSP_COMPLAIN_AND_ABORT(
+                    "`remove_where` is not supported on an overlay"
+                );
+            }
+
+            const auto& r = req.table_remove_where_req();
+            auto table = m_resources.get_table(req.entity_id());
+            auto schema = table->get_schema();
+            std::vector<t_fterm> fterms;
+            for (const auto& f : r.filter()) {
+                auto op = str_to_filter_op(f.op());
+                auto args = filter_args_from_proto(schema, f);
+                switch (op) {
+                    case FILTER_OP_NOT_IN:
+                    case FILTER_OP_IN:
+                    case FILTER_OP_IN_SUBNET: {
+                        fterms.emplace_back(f.column(), op, mktscalar(0), args);
+                    } break;
+                    default: {
+                        auto threshold = args.empty() ? mktscalar(0) : args[0];
+                        fterms.emplace_back(
+                            f.column(), op, threshold, std::vector<t_tscalar>()
+                        );
+                    }
                 }
             }
 
-            m_resources.mark_table_dirty(req.entity_id());
+            auto num_rows = table->remove_where(fterms);
+            if (num_rows > 0) {
+                m_resources.mark_table_dirty(req.entity_id());
+            }
+
             proto::Response resp;
-            resp.mutable_table_update_resp();
+            resp.mutable_table_remove_where_resp()->set_num_rows(num_rows);
             push_resp(std::move(resp));
             break;
         }
-        case proto::Request::kTableMakeViewReq: {
+        case proto::Request::kTableUpdateReq: {
+            m_resources.check_writer(req.entity_id(), client_id);
+            const auto& r = req.table_update_req();
             auto table = m_resources.get_table(req.entity_id());
-            auto schema = std::make_shared<t_schema>(
-                table->get_gnode()->get_output_schema()
+            apply_table_update(*table, r, r.port_id());
+            m_resources.mark_table_dirty(req.entity_id());
+            proto::Response resp;
+            resp.mutable_table_update_resp()->set_sequence(
+                table->next_port_sequence(r.port_id())
             );
-            const auto& r = req.table_make_view_req();
-            const auto& cfg = r.config();
-
-            const auto& group_by = cfg.group_by();
-            std::vector<std::string> row_pivots{
-                group_by.begin(), group_by.end()
-            };
+            push_resp(std::move(resp));
+            break;
+        }
+        case proto::Request::kTableEditCellsReq: {
+            m_resources.check_writer(req.entity_id(), client_id);
+            const auto& r = req.table_edit_cells_req();
+            auto table = m_resources.get_table(req.entity_id());
+            const auto& index = table->get_index();
+            if (index.empty()) {
+                PSP_COMPLAIN_AND_ABORT("Cell edits require an indexed table");
+            }
 
-            const auto& split_by = cfg.split_by();
-            std::vector<std::string> column_pivots{
-                split_by.begin(), split_by.end()
-            };
+            // Every edit is checked before any is applied, so a rejected
+            // request leaves the table unchanged.
+            auto schema = table->get_schema();
+            auto wall_clock =
+                std::chrono::system_clock::now().time_since_epoch();
+            auto timestamp = static_cast<double>(
+                std::chrono::duration_cast<std::chrono::milliseconds>(
+                    wall_clock
+                )
+                    .count()
+            );
 
-            const auto& aggs = cfg.aggregates();
-            tsl::ordered_map<std::string, std::vector<std::string>> aggregates;
-            for (const auto& [col_name, agg_list] : aggs) {
-                aggregates[col_name] = std::vector<std::string>();
-                for (const auto& agg : agg_list.aggregations()) {
-                    aggregates[col_name].push_back(agg);
+            std::vector<proto::CellEditRecord> records;
+            rapidjson::StringBuffer rows;
+            rapidjson::Writer<rapidjson::StringBuffer> writer(rows);
+            writer.StartArray();
+            for (const auto& edit : r.edits()) {
+                if (!schema.has_column(edit.column())) {
+                    reject_edit(
+                        "Column `" + edit.column() + "` does not exist"
+                    );
                 }
-            }
 
-            const auto& sorts = cfg.sort();
-            std::vector<t_sortspec> sortby;
-            std::vector<std::vector<std::string>> sort_str;
-            for (const auto& sort : sorts) {
-                const char* column_sort = sort_op_str_from_proto(sort.op());
-                sort_str.push_back({sort.column(), column_sort});
-            }
+                if (!table->is_column_editable(edit.column())) {
+                    reject_edit(
+                        "Column `" + edit.column() + "` is not editable"
+                    );
+                }
 
-            bool column_only = false;
+                auto previous = _lookup_cell(
+                    req.entity_id(), *table, edit.index(), edit.column()
+                );
 
-            // make sure that primary keys are created for column-only views
-            if (row_pivots.empty() && !column_pivots.empty()) {
-                row_pivots.emplace_back("psp_okey");
-                column_only = true;
-            }
+                if (!previous.has_value()) {
+                    reject_edit("No row with index " + edit.index());
+                }
 
-            std::vector<std::shared_ptr<t_computed_expression>> expressions;
-            auto exprs = parse_expression_strings(cfg.expressions());
+                rapidjson::Document value;
+                value.Parse(edit.value().c_str());
+                if (value.HasParseError()) {
+                    reject_edit("Invalid value `" + edit.value() + "`");
+                }
 
-            std::vector<std::tuple<
-                std::string,
-                std::string,
-                std::string,
-                std::vector<std::pair<std::string, std::string>>>>
-                legacy_exprs;
+                auto dtype = schema.get_dtype(edit.column());
+                if (!edit_value_fits(value, dtype)) {
+                    reject_edit(
+                        "Value `" + edit.value() + "` does not fit column `"
+                        + edit.column() + "` of type " + dtype_to_str(dtype)
+                    );
+                }
 
-            legacy_exprs.resize(1);
-            for (const auto& expr : exprs) {
-                legacy_exprs[0] = {
-                    expr.expression_alias,
-                    expr.expression,
-                    expr.parse_expression_string,
-                    std::vector<std::pair<std::string, std::string>>{
-                        expr.column_id_map.begin(), expr.column_id_map.end()
-                    }
-                };
+                auto& record = records.emplace_back();
+                *record.mutable_edit() = edit;
+                record.set_previous_value(*previous);
 
-                // Validate these expression, creating is not the same thing!
-                const auto& res = table->validate_expressions(legacy_exprs);
-                if (!res.get_expression_errors().empty()) {
-                    // TODO unify error reporting - this works differently than
-                    // `validate_expressions()`. In this case there is
-                    // guaranteed to only be one ...
-                    PSP_COMPLAIN_AND_ABORT(res.get_expression_errors()
-                                               .at(expr.expression_alias)
-                                               .m_error_message);
+                if (r.has_user()) {
+                    record.set_user(r.user());
                 }
 
-                const auto& gnode = table->get_gnode();
-                auto column_id_map =
-                    std::vector<std::pair<std::string, std::string>>(
-                        expr.column_id_map.begin(), expr.column_id_map.end()
-                    );
+                record.set_session_id(client_id);
+                record.set_timestamp(timestamp);
+                write_cell_row(
+                    writer, index, edit.index(), edit.column(), edit.value()
+                );
+            }
 
-                auto expr_vocab = gnode->get_expression_vocab();
-                t_expression_vocab& expression_vocab = *expr_vocab;
-                auto expression_regex_mapping =
-                    gnode->get_expression_regex_mapping();
-                t_regex_mapping& regex_mapping = *expression_regex_mapping;
-
-                std::shared_ptr<t_computed_expression> computed_expression =
-                    t_computed_expression_parser::precompute(
-                        expr.expression_alias,
-                        expr.expression,
-                        expr.parse_expression_string,
-                        column_id_map,
-                        gnode->get_table_sptr(),
-                        gnode->get_pkey_map(),
-                        schema,
-                        expression_vocab,
-                        regex_mapping
-                    );
+            writer.EndArray();
+            if (!records.empty()) {
+                _apply_cell_rows(req.entity_id(), *table, rows.GetString());
+                m_resources.push_edit_records(req.entity_id(), records);
+                m_resources.push_edit_batch(
+                    req.entity_id(), std::move(records)
+                );
+            }
 
-                auto dtype = computed_expression->get_dtype();
+            proto::Response resp;
+            resp.mutable_table_edit_cells_resp();
+            push_resp(std::move(resp));
+            break;
+        }
+        case proto::Request::kTableUndoReq:
+        case proto::Request::kTableRedoReq: {
+            m_resources.check_writer(req.entity_id(), client_id);
+            auto table = m_resources.get_table(req.entity_id());
+            auto undo = req.client_req_case() == proto::Request::kTableUndoReq;
+            auto requested = undo ? req.table_undo_req().count()
+                                  : req.table_redo_req().count();
 
-                schema->add_column(expr.expression_alias, dtype);
-                expressions.push_back(std::make_shared<t_computed_expression>(
-                    expr.expression_alias,
-                    expr.expression,
-                    expr.parse_expression_string,
-                    column_id_map,
-                    dtype
-                ));
-            }
+            std::uint32_t count = 0;
+            std::vector<proto::CellEditRecord> records;
+            for (; count < requested; ++count) {
+                auto batch = undo ? m_resources.take_undo(req.entity_id())
+                                  : m_resources.take_redo(req.entity_id());
 
-            std::vector<
-                std::tuple<std::string, std::string, std::vector<t_tscalar>>>
-                filter;
-            for (const auto& f : cfg.filter()) {
-                std::vector<t_tscalar> args;
-                for (const auto& arg : f.value()) {
-                    t_tscalar a;
-                    a.clear();
-
-                    switch (arg.scalar_case()) {
-                        case proto::Scalar::kBool: {
-                            a.set(arg.bool_());
-                            args.push_back(a);
-                            break;
-                        }
-                        case proto::Scalar::kFloat: {
-                            a.set(arg.float_());
-                            args.push_back(a);
-                            break;
-                        }
-                        case proto::Scalar::kInt: {
-                            a.set(arg.int_());
-                            args.push_back(a);
-                            break;
-                        }
-
-                        case proto::Scalar::kString: {
-                            if (!schema->has_column(f.column())) {
-                                PSP_COMPLAIN_AND_ABORT(
-                                    "Filter column not in schema: " + f.column()
-                                );
-                            }
-                            a = coerce_to(
-                                schema->get_dtype(f.column()), arg.string()
-                            );
-                            args.push_back(a);
-                            break;
-                        }
-
-                        case proto::Scalar::kDate: {
-                            auto date_ts = arg.date();
-                            // convert ts to date
-                            auto tt = std::chrono::system_clock::to_time_t(
-                                std::chrono::system_clock::time_point(
-                                    std::chrono::seconds(date_ts)
-                                )
-                            );
-
-                            auto* date = std::localtime(&tt);
-
-                            t_date d{
-                                static_cast<std::int16_t>(date->tm_year + 1900),
-                                static_cast<std::int8_t>(date->tm_mon),
-                                static_cast<std::int8_t>(date->tm_mday)
-                            };
-                            a.set(d);
-                            args.push_back(a);
-                            break;
-                        }
-                        case proto::Scalar::kDatetime: {
-                            auto datetime_ts = arg.datetime();
-                            // convert ts to date
-                            auto tt = t_time(datetime_ts);
-
-                            a.set(tt);
-                            args.push_back(a);
-
-                            break;
-                        }
-
-                        case proto::Scalar::kNull:
-                            a.set(t_none());
-                            args.push_back(a);
-                            break;
-                        case proto::Scalar::SCALAR_NOT_SET:
-                            PSP_COMPLAIN_AND_ABORT(
-                                "Filter scalar type not implemented: "
-                                + std::to_string(arg.scalar_case())
-                            )
-                            break;
-                    }
+                if (!batch.has_value()) {
+                    break;
                 }
 
-                filter.emplace_back(f.column(), f.op(), args);
+                auto replayed = _replay_cell_edits(
+                    req.entity_id(), *table, *batch, undo, client_id
+                );
+
+                std::move(
+                    replayed.begin(),
+                    replayed.end(),
+                    std::back_inserter(records)
+                );
             }
 
-            const auto& cols = cfg.columns();
-            std::vector<std::string> columns;
-            if (cols.has_columns()) {
-                columns = {
-                    cols.columns().columns().begin(),
-                    cols.columns().columns().end()
-                };
+            if (!records.empty()) {
+                m_resources.push_edit_records(
+                    req.entity_id(), std::move(records)
+                );
+            }
+
+            proto::Response resp;
+            if (undo) {
+                resp.mutable_table_undo_resp()->set_count(count);
             } else {
-                columns = table->get_column_names();
-                for (const auto& f : expressions) {
-                    columns.push_back(f->get_expression_alias());
-                }
-            }
-
-            LOG_DEBUG(
-                "Creating view config with \n"
-                << "row_pivots: " << row_pivots << '\n'
-                << "column_pivots: " << column_pivots
-                << '\n'
-                // << "aggregates: " << aggregates << '\n'
-                << "columns: " << columns
-                << '\n'
-                // << "filter: " << filter << '\n'
-                << "sort_str: " << sort_str << '\n'
-                << "expressions: " << expressions << '\n'
-                << "column_only: " << column_only << '\n'
+                resp.mutable_table_redo_resp()->set_count(count);
+            }
+
+            push_resp(std::move(resp));
+            break;
+        }
+        case proto::Request::kTableEditLogReq: {
+            const auto& r = req.table_edit_log_req();
+            auto records = m_resources.get_edit_records(
+                req.entity_id(),
+                r.has_limit() ? std::optional(r.limit()) : std::nullopt
             );
 
-            std::string filter_op;
-            switch (cfg.filter_op()) {
-                case proto::ViewConfig_FilterReducer::
-                    ViewConfig_FilterReducer_OR:
-                    filter_op = "or";
-                    break;
-                case proto::ViewConfig_FilterReducer::
-                    ViewConfig_FilterReducer_AND:
-                default:
-                    filter_op = "and";
-                    break;
+            proto::Response resp;
+            auto* edit_log = resp.mutable_table_edit_log_resp();
+            for (auto& record : records) {
+                *edit_log->add_records() = std::move(record);
             }
 
-            LOG_DEBUG("FILTER_OP: " << filter_op);
-
-            auto config = std::make_shared<t_view_config>(
-                row_pivots,
-                column_pivots,
-                aggregates,
-                columns,
-                filter,
-                sort_str,
-                expressions,
-                filter_op,
-                column_only
-            );
-            config->init(schema);
+            push_resp(std::move(resp));
+            break;
+        }
+        case proto::Request::kTableAnnotateReq: {
+            const auto& r = req.table_annotate_req();
+            auto table = m_resources.get_table(req.entity_id());
+            const auto& index = table->get_index();
+            if (index.empty()) {
+                PSP_COMPLAIN_AND_ABORT("Annotations require an indexed table");
+            }
 
-            if (cfg.has_group_by_depth()) {
-                config->set_row_pivot_depth(cfg.group_by_depth());
+            if (!table->get_schema().has_column(r.column())) {
+                PSP_COMPLAIN_AND_ABORT(
+                    "Column `" + r.column() + "` does not exist"
+                );
             }
 
-            std::uint32_t sides;
+            auto row = lookup_index_row(*table, r.index());
+            if (!row.has_value()) {
+                PSP_COMPLAIN_AND_ABORT("No row with index " + r.index());
+            }
 
-            if (!group_by.empty() || !split_by.empty()) {
-                if (!split_by.empty()) {
-                    sides = 2;
-                } else {
-                    sides = 1;
+            // Keyed by the index as `View::annotations` encodes it, so that
+            // e.g. `1` and `1.0` address the same cell.
+            auto master = table->get_gnode()->get_table_sptr();
+            auto pkey = master->get_const_column(index)->get_scalar(*row);
+            auto index_json = scalar_to_json(pkey);
+
+            if (r.has_text()) {
+                auto wall_clock =
+                    std::chrono::system_clock::now().time_since_epoch();
+                proto::CellAnnotation annotation;
+                annotation.set_index(index_json);
+                annotation.set_column(r.column());
+                annotation.set_text(r.text());
+                if (r.has_user()) {
+                    annotation.set_user(r.user());
                 }
+
+                annotation.set_session_id(client_id);
+                annotation.set_timestamp(static_cast<double>(
+                    std::chrono::duration_cast<std::chrono::milliseconds>(
+                        wall_clock
+                    )
+                        .count()
+                ));
+
+                m_resources.set_annotation(
+                    req.entity_id(), std::move(annotation)
+                );
             } else {
-                sides = 0;
+                m_resources.remove_annotation(
+                    req.entity_id(), index_json, r.column()
+                );
             }
 
-            bool is_unit_context = table->get_index().empty() && sides == 0
-                && row_pivots.empty() && column_pivots.empty()
-                && aggregates.empty() && columns.empty() && sort_str.empty()
-                && cfg.expressions().empty();
+            proto::Response resp;
+            resp.mutable_table_annotate_resp();
+            push_resp(std::move(resp));
+            break;
+        }
+        case proto::Request::kTableAnnotationsReq: {
+            proto::Response resp;
+            auto* annotations = resp.mutable_table_annotations_resp();
+            for (auto& annotation :
+                 m_resources.get_annotations(req.entity_id())) {
+                *annotations->add_annotations() = std::move(annotation);
+            }
 
-            std::shared_ptr<ErasedView> erased_view;
+            push_resp(std::move(resp));
+            break;
+        }
+        case proto::Request::kTableIngestArrowReq: {
+            m_resources.check_writer(req.entity_id(), client_id);
+            const auto& r = req.table_ingest_arrow_req();
+            auto table = m_resources.get_table(req.entity_id());
+            auto ingest = m_resources.get_arrow_ingest(
+                client_id, req.entity_id(), r.stream_id()
+            );
 
-            if (is_unit_context) {
-                auto ctx =
-                    make_context<t_ctxunit>(table, schema, config, r.view_id());
-                auto view = std::make_shared<View<t_ctxunit>>(
-                    table, ctx, r.view_id(), "|", config
-                );
-                erased_view = std::make_shared<CtxUnitView>(std::move(view));
-            } else if (sides == 0) {
-                auto ctx =
-                    make_context<t_ctx0>(table, schema, config, r.view_id());
-                auto view = std::make_shared<View<t_ctx0>>(
-                    table, ctx, r.view_id(), "|", config
-                );
-                erased_view = std::make_shared<Ctx0View>(view);
-            } else if (sides == 1) {
-                auto ctx =
-                    make_context<t_ctx1>(table, schema, config, r.view_id());
-                auto view = std::make_shared<View<t_ctx1>>(
-                    table, ctx, r.view_id(), "|", config
+            // A stream which fails to decode can't be resumed.
+            try {
+                if (auto batches = ingest->consume(r.chunk())) {
+                    table->update_arrow_table(batches, r.port_id());
+                    m_resources.mark_table_dirty(req.entity_id());
+                }
+            } catch (...) {
+                m_resources.drop_arrow_ingest(
+                    client_id, req.entity_id(), r.stream_id()
                 );
-                erased_view = std::make_shared<Ctx1View>(std::move(view));
-            } else if (sides == 2) {
-                auto ctx =
-                    make_context<t_ctx2>(table, schema, config, r.view_id());
-                auto view = std::make_shared<View<t_ctx2>>(
-                    table, ctx, r.view_id(), "|", config
+
+                throw;
+            }
+
+            if (r.end()) {
+                m_resources.drop_arrow_ingest(
+                    client_id, req.entity_id(), r.stream_id()
                 );
-                erased_view = std::make_shared<Ctx2View>(std::move(view));
-            } else {
-                PSP_COMPLAIN_AND_ABORT("Invalid number of sides");
             }
 
+            proto::Response resp;
+            resp.mutable_table_ingest_arrow_resp()->set_num_rows(
+                ingest->num_rows()
+            );
+
+            push_resp(std::move(resp));
+            break;
+        }
+        case proto::Request::kTableMakeViewReq: {
+            auto table = m_resources.get_table(req.entity_id());
+            const auto& r = req.table_make_view_req();
+            proto::ViewConfig cfg = r.config();
+            _resolve_library_expressions(
+                table->get_gnode()->get_output_schema(), cfg
+            );
+
+            auto view_table = _view_table(req.entity_id(), table, cfg);
+            auto erased_view =
+                _make_view(view_table, r.view_id(), bind_view_params(cfg));
             m_resources.host_view(
                 client_id, r.view_id(), req.entity_id(), erased_view
             );
 
+            if (view_table != table) {
+                m_resources.set_unnest_table(r.view_id(), view_table);
+            }
+
+            m_resources.set_view_proto_config(r.view_id(), std::move(cfg));
             proto::Response resp;
             auto* make_view = resp.mutable_table_make_view_resp();
             make_view->set_view_id(r.view_id());
@@ -1782,6 +4726,38 @@ ProtoServer::_handle_request(std::uint32_t client_id, const Request& req) {
             m_resources.create_table_on_delete_sub(req.entity_id(), sub_info);
             break;
         }
+        case proto::Request::kTableRenameReq: {
+            m_resources.check_writer(req.entity_id(), client_id);
+            const auto& new_name = req.table_rename_req().new_name();
+            m_resources.rename_table(req.entity_id(), new_name);
+            proto::Response resp;
+            resp.mutable_table_rename_resp();
+            push_resp(std::move(resp));
+            _hosted_tables_update(proto_resp);
+            break;
+        }
+        case proto::Request::kTableTakeWriterReq: {
+            if (!m_resources.is_exclusive_writer(req.entity_id())) {
+                PSP_COMPLAIN_AND_ABORT(
+                    "Table `" + req.entity_id()
+                    + "` is not in exclusive-writer mode"
+                );
+            }
+
+            m_resources.set_exclusive_writer(req.entity_id(), client_id);
+            proto::Response resp;
+            resp.mutable_table_take_writer_resp();
+            push_resp(std::move(resp));
+            break;
+        }
+        case proto::Request::kRemoveHostedTablesUpdateReq: {
+            auto sub_id = req.remove_hosted_tables_update_req().id();
+            m_resources.remove_on_hosted_tables_update_sub(sub_id, client_id);
+            proto::Response resp;
+            resp.mutable_remove_hosted_tables_update_resp();
+            push_resp(std::move(resp));
+            break;
+        }
         case proto::Request::kTableRemoveDeleteReq: {
             auto sub_id = req.table_remove_delete_req().id();
             m_resources.remove_table_on_delete_sub(
@@ -1850,118 +4826,21 @@ ProtoServer::_handle_request(std::uint32_t client_id, const Request& req) {
         }
         case proto::Request::kViewGetConfigReq: {
             auto view = m_resources.get_view(req.entity_id());
-            auto view_config = view->get_view_config();
-
             proto::Response resp;
-            auto* view_config_proto =
+            auto* config =
                 resp.mutable_view_get_config_resp()->mutable_config();
-
-            for (const auto& col : view_config->get_columns()) {
-                view_config_proto->mutable_columns()
-                    ->mutable_columns()
-                    ->add_columns(col);
-            }
-
-            for (const auto& agg : view_config->get_row_pivots()) {
-                if (agg == "psp_pkey" || agg == "psp_okey") {
-                    continue;
-                }
-                view_config_proto->add_group_by(agg);
-            }
-
-            for (const auto& agg : view_config->get_column_pivots()) {
-                view_config_proto->add_split_by(agg);
-            }
-
-            // TODO: Sort, Expressions, and Aggregations
-
-            for (const auto& sort : view_config->get_sortspec()) {
-                auto* proto_sort = view_config_proto->mutable_sort();
-                auto* s = proto_sort->Add();
-                s->set_column(sort.m_colname);
-                s->set_op(sort_op_to_proto(sort.m_sort_type));
-            }
-
-            for (const auto& filter : view_config->get_fterm()) {
-                auto* proto_filter = view_config_proto->mutable_filter();
-                auto* f = proto_filter->Add();
-                f->set_column(filter.m_colname);
-                f->set_op(filter_op_to_str(filter.m_op));
-                auto vals = std::vector<t_tscalar>(filter.m_bag.size() + 1);
-                if (filter.m_op != FILTER_OP_NOT_IN
-                    && filter.m_op != FILTER_OP_IN) {
-                    vals.push_back(filter.m_threshold);
-                } else {
-                    for (const auto& scalar : filter.m_bag) {
-                        vals.push_back(scalar);
-                    }
-                }
-                for (const auto& scalar : vals) {
-                    auto* s = f->mutable_value()->Add();
-                    switch (scalar.get_dtype()) {
-                        case DTYPE_BOOL:
-                            s->set_bool_(scalar.get<bool>());
-                            break;
-                        case DTYPE_FLOAT64:
-                            s->set_float_(scalar.get<double>());
-                            break;
-                        case DTYPE_INT64:
-                            s->set_int_(static_cast<std::int32_t>(
-                                scalar.get<std::int64_t>()
-                            ));
-                            break;
-                        case DTYPE_STR:
-                            s->set_string(scalar.get<const char*>());
-                            break;
-                        case DTYPE_DATE: {
-                            auto tm = scalar.get<t_date>().get_tm();
-                            s->set_date(std::mktime(&tm));
-                            break;
-                        }
-                        case DTYPE_TIME:
-                            s->set_datetime(scalar.get<t_time>().raw_value());
-                            break;
-                        case DTYPE_NONE:
-                            s->set_null(
-                                ::google::protobuf::NullValue::NULL_VALUE
-                            );
-                            break;
-                        default:
-                            PSP_COMPLAIN_AND_ABORT(
-                                "Invalid scalar type: " + scalar.to_string()
-                            );
-                    }
-                }
-            }
-
-            switch (view_config->get_filter_op()) {
-                case FILTER_OP_OR:
-                    view_config_proto->set_filter_op(
-                        proto::ViewConfig_FilterReducer::
-                            ViewConfig_FilterReducer_OR
-                    );
-                    break;
-                case FILTER_OP_AND:
-                default:
-                    view_config_proto->set_filter_op(
-                        proto::ViewConfig_FilterReducer::
-                            ViewConfig_FilterReducer_AND
-                    );
-                    break;
-            }
-
-            if (view_config->get_row_pivot_depth() != -1) {
-                view_config_proto->set_group_by_depth(
-                    view_config->get_row_pivot_depth()
-                );
-            }
-
-            for (const auto& expr : view_config->get_expressions()) {
-                auto* proto_exprs = view_config_proto->mutable_expressions();
-                (*proto_exprs)[expr->get_expression_alias()] =
-                    expr->get_expression_string();
+            view_config_to_proto(*view->get_view_config(), config);
+
+            // Report the filters of a parameterized view unbound, so its
+            // config re-creates a view with the same parameters.
+            auto cfg = m_resources.get_view_proto_config(req.entity_id());
+            if (!cfg.params().empty()) {
+                *config->mutable_filter() = cfg.filter();
+                *config->mutable_params() = cfg.params();
             }
 
+            *config->mutable_column_groups() = cfg.column_groups();
+            *config->mutable_unnest() = cfg.unnest();
             push_resp(std::move(resp));
             break;
         }
@@ -2081,6 +4960,7 @@ ProtoServer::_handle_request(std::uint32_t client_id, const Request& req) {
             break;
         }
         case proto::Request::kTableDeleteReq: {
+            m_resources.check_writer(req.entity_id(), client_id);
             m_resources.delete_table(req.entity_id());
 
             for (const auto& sub :
@@ -2098,6 +4978,7 @@ ProtoServer::_handle_request(std::uint32_t client_id, const Request& req) {
             proto::Response resp;
             resp.mutable_table_delete_resp();
             push_resp(std::move(resp));
+            _hosted_tables_update(proto_resp);
             break;
         }
         case proto::Request::kViewDeleteReq: {
@@ -2130,6 +5011,62 @@ ProtoServer::_handle_request(std::uint32_t client_id, const Request& req) {
             push_resp(std::move(resp));
             break;
         }
+        case proto::Request::kViewResyncReq: {
+            auto sub_id = req.view_resync_req().id();
+            auto subs = m_resources.get_view_on_update_sub(req.entity_id());
+            auto sub = std::find_if(
+                subs.begin(),
+                subs.end(),
+                [sub_id, client_id](const Subscription& x) {
+                    return x.id == sub_id && x.client_id == client_id;
+                }
+            );
+
+            if (sub == subs.end()) {
+                throw std::runtime_error(
+                    "Unknown `on_update` subscription " + std::to_string(sub_id)
+                );
+            }
+
+            auto view = m_resources.get_view(req.entity_id());
+            proto::Response resp;
+            auto* r = resp.mutable_view_resync_resp();
+            r->set_sequence(
+                m_resources.get_update_sequence(req.entity_id(), *sub)
+            );
+
+            auto viewport_sub =
+                m_resources.get_viewport_sub(req.entity_id(), *sub);
+            if (viewport_sub.has_value()) {
+                auto rows = viewport_rows(*view, viewport_sub->viewport);
+                diff_viewport_rows({}, rows, *r->mutable_viewport());
+                m_resources.set_viewport_rows(
+                    req.entity_id(), *sub, std::move(rows)
+                );
+            } else {
+                auto config = view->get_view_config();
+                auto dims = parse_format_options(
+                    proto::ViewPort(),
+                    view->num_columns(),
+                    view->num_rows(),
+                    view->sides(),
+                    config->is_column_only(),
+                    calculate_num_hidden(*view, *config)
+                );
+
+                *r->mutable_arrow() = *view->to_arrow(
+                    dims.start_row,
+                    dims.end_row,
+                    dims.start_col,
+                    dims.end_col,
+                    true,
+                    false
+                );
+            }
+
+            push_resp(std::move(resp));
+            break;
+        }
         case proto::Request::kViewExpressionSchemaReq: {
             auto view = m_resources.get_view(req.entity_id());
 
@@ -2172,6 +5109,97 @@ ProtoServer::_handle_request(std::uint32_t client_id, const Request& req) {
             push_resp(std::move(resp));
             break;
         }
+        case proto::Request::kServerBulkExportReq: {
+            const auto& r = req.server_bulk_export_req();
+            proto::Response resp;
+            auto* bulk_export = resp.mutable_server_bulk_export_resp();
+            auto* payload = bulk_export->mutable_payload();
+            for (const auto& entity : r.entities()) {
+                auto is_view = entity.kind() == proto::BulkExportEntity::VIEW;
+                auto table_id = is_view
+                    ? m_resources.get_table_id_for_view(entity.entity_id())
+                    : entity.entity_id();
+
+                auto table = m_resources.get_table(table_id);
+                if (m_resources.is_table_dirty(table_id)) {
+                    _process_table(table, table_id, proto_resp);
+                }
+
+                auto view = is_view
+                    ? m_resources.get_view(entity.entity_id())
+                    : make_snapshot_view(
+                          table,
+                          "__bulk_export_" + std::to_string(client_id) + "_"
+                              + std::to_string(req.msg_id())
+                      );
+
+                auto [arrow, num_rows] =
+                    view_snapshot_to_arrow(*view, r.compression() == "lz4");
+
+                auto* entry = bulk_export->add_manifest();
+                *entry->mutable_entity() = entity;
+                entry->set_offset(payload->size());
+                entry->set_length(arrow->size());
+                entry->set_num_rows(num_rows);
+                payload->append(*arrow);
+            }
+
+            push_resp(std::move(resp));
+            break;
+        }
+        case proto::Request::kViewAnnotationsReq: {
+            auto view = m_resources.get_view(req.entity_id());
+            const auto& r = req.view_annotations_req();
+            auto config = view->get_view_config();
+            auto num_hidden = calculate_num_hidden(*view, *config);
+            auto dims = parse_format_options(
+                r.viewport(),
+                view->num_columns(),
+                view->num_rows(),
+                view->sides(),
+                config->is_column_only(),
+                num_hidden
+            );
+
+            // Only annotations of the `View`'s columns, by the index of their
+            // row.
+            auto table_id = m_resources.get_table_id_for_view(req.entity_id());
+            const auto& columns = config->get_columns();
+            tsl::hopscotch_map<std::string, std::vector<proto::CellAnnotation>>
+                by_index;
+
+            for (auto& annotation : m_resources.get_annotations(table_id)) {
+                if (std::find(
+                        columns.begin(), columns.end(), annotation.column()
+                    )
+                    != columns.end()) {
+                    auto key = annotation.index();
+                    by_index[key].push_back(std::move(annotation));
+                }
+            }
+
+            proto::Response resp;
+            auto* annotations = resp.mutable_view_annotations_resp();
+            for (auto ridx = dims.start_row;
+                 !by_index.empty() && ridx < dims.end_row;
+                 ++ridx) {
+                for (const auto& pkey : view->get_row_pkeys(ridx)) {
+                    auto it = by_index.find(scalar_to_json(pkey));
+                    if (it == by_index.end()) {
+                        continue;
+                    }
+
+                    for (const auto& annotation : it->second) {
+                        auto* out = annotations->add_annotations();
+                        out->set_row(ridx);
+                        *out->mutable_annotation() = annotation;
+                    }
+                }
+            }
+
+            push_resp(std::move(resp));
+            break;
+        }
         case proto::Request::kViewToCsvReq: {
             LOG_DEBUG("Handling ViewToCsvReq");
             auto view = m_resources.get_view(req.entity_id());
@@ -2199,12 +5227,45 @@ ProtoServer::_handle_request(std::uint32_t client_id, const Request& req) {
         case proto::Request::kViewOnUpdateReq: {
             Subscription sub_info{.id = req.msg_id(), .client_id = client_id};
             m_resources.create_view_on_update_sub(req.entity_id(), sub_info);
-            if (req.view_on_update_req().has_mode()
-                && req.view_on_update_req().mode()
+            const auto& r = req.view_on_update_req();
+            if (r.has_mode()
+                && r.mode()
                     == proto::ViewOnUpdateReq_Mode::ViewOnUpdateReq_Mode_ROW) {
                 auto view = m_resources.get_view(req.entity_id());
                 view->set_deltas_enabled(true);
             }
+
+            // A viewport subscription starts with the entire window, so the
+            // client need not fetch it separately (and race the first update).
+            if (r.has_mode()
+                && r.mode()
+                    == proto::ViewOnUpdateReq_Mode::
+                        ViewOnUpdateReq_Mode_VIEWPORT) {
+                auto view = m_resources.get_view(req.entity_id());
+                auto rows = viewport_rows(*view, r.viewport());
+                proto::Response resp;
+                diff_viewport_rows(
+                    {},
+                    rows,
+                    *resp.mutable_view_on_update_resp()->mutable_viewport()
+                );
+
+                resp.mutable_view_on_update_resp()->set_sequence(
+                    m_resources.next_update_sequence(req.entity_id(), sub_info)
+                );
+
+                m_resources.create_viewport_sub(
+                    req.entity_id(),
+                    ViewportSubscription{
+                        .sub = sub_info,
+                        .viewport = r.viewport(),
+                        .rows = std::move(rows)
+                    }
+                );
+
+                push_resp(std::move(resp));
+            }
+
             break;
         }
         case proto::Request::kViewGetMinMaxReq: {
@@ -2224,6 +5285,27 @@ ProtoServer::_handle_request(std::uint32_t client_id, const Request& req) {
             push_resp(std::move(resp));
             break;
         }
+        case proto::Request::kViewDownsampleReq: {
+            const auto& r = req.view_downsample_req();
+            std::vector<std::string> y_columns(
+                r.y_columns().begin(), r.y_columns().end()
+            );
+
+            auto view = m_resources.get_view(req.entity_id());
+            auto series =
+                view->downsample(r.x_column(), y_columns, r.n_points());
+
+            proto::Response resp;
+            auto* out = resp.mutable_view_downsample_resp()->mutable_series();
+            for (const auto& [name, s] : series) {
+                auto& proto_series = (*out)[name];
+                proto_series.mutable_x()->Add(s.m_x.begin(), s.m_x.end());
+                proto_series.mutable_y()->Add(s.m_y.begin(), s.m_y.end());
+            }
+
+            push_resp(std::move(resp));
+            break;
+        }
         case proto::Request::kViewCollapseReq: {
             const auto& r = req.view_collapse_req();
             auto view = m_resources.get_view(req.entity_id());
@@ -2253,17 +5335,129 @@ ProtoServer::_handle_request(std::uint32_t client_id, const Request& req) {
             push_resp(std::move(resp));
             break;
         }
+        case proto::Request::kViewSetParamsReq: {
+            auto cfg = m_resources.get_view_proto_config(req.entity_id());
+            auto& params = *cfg.mutable_params();
+            for (const auto& [name, value] :
+                 req.view_set_params_req().params()) {
+                if (params.find(name) == params.end()) {
+                    PSP_COMPLAIN_AND_ABORT(
+                        "View has no parameter `" + name + "`"
+                    );
+                }
+
+                params[name] = value;
+            }
+
+            _rebuild_view(req.entity_id(), std::move(cfg), proto_resp);
+            proto::Response resp;
+            resp.mutable_view_set_params_resp();
+            push_resp(std::move(resp));
+            break;
+        }
+        case proto::Request::kViewUpdateConfigReq: {
+            const auto& r = req.view_update_config_req();
+            auto cfg = m_resources.get_view_proto_config(req.entity_id());
+            apply_view_config_update(cfg, r.config(), r.fields());
+            auto table = m_resources.get_table_for_view(req.entity_id());
+            _resolve_library_expressions(
+                table->get_gnode()->get_output_schema(), cfg
+            );
+
+            // Only the expansion depth changes, which the view's context can
+            // apply itself.
+            bool is_depth_only = std::all_of(
+                r.fields().begin(),
+                r.fields().end(),
+                [](const std::string& field) {
+                    return field == "group_by_depth";
+                }
+            );
+
+            // Only the filter and sort change, which can be applied to the
+            // view's existing context rather than a new one.
+            bool is_filter_and_sort_only = std::all_of(
+                r.fields().begin(),
+                r.fields().end(),
+                [](const std::string& field) {
+                    return field == "filter" || field == "filter_op"
+                        || field == "sort";
+                }
+            );
+
+            bool refilter = std::any_of(
+                r.fields().begin(),
+                r.fields().end(),
+                [](const std::string& field) {
+                    return field == "filter" || field == "filter_op";
+                }
+            );
+
+            if (r.fields().empty()) {
+                // Nothing to apply.
+            } else if (is_depth_only) {
+                auto view = m_resources.get_view(req.entity_id());
+                if (cfg.has_group_by_depth()) {
+                    auto depth =
+                        static_cast<std::int32_t>(cfg.group_by_depth());
+                    view->set_depth(depth - 1);
+                    view->get_view_config()->set_row_pivot_depth(depth);
+                }
+
+                m_resources.set_view_proto_config(
+                    req.entity_id(), std::move(cfg)
+                );
+            } else if (!is_filter_and_sort_only
+                       || !_update_view_filter_and_sort(
+                           req.entity_id(), cfg, refilter, proto_resp
+                       )) {
+                _rebuild_view(req.entity_id(), std::move(cfg), proto_resp);
+            }
+
+            proto::Response resp;
+            resp.mutable_view_update_config_resp();
+            push_resp(std::move(resp));
+            break;
+        }
         case proto::Request::kServerSystemInfoReq: {
             proto::Response resp;
             auto* sys_info = resp.mutable_server_system_info_resp();
-#ifdef PSP_ENABLE_WASM
-            auto heap_size = psp_heap_size();
-            sys_info->set_heap_size(heap_size);
-#else
-            PSP_COMPLAIN_AND_ABORT(
-                "ServerSystemInfoReq not implemented for non-emscripten targets"
-            );
-#endif
+            sys_info->set_heap_size(psp_heap_size());
+            sys_info->set_engine_target(PSP_ENGINE_TARGET);
+            sys_info->set_engine_version(PSP_ENGINE_VERSION);
+            push_resp(std::move(resp));
+            break;
+        }
+        case proto::Request::kServerDiagnosticsReq: {
+            proto::Response resp;
+            auto* diagnostics = resp.mutable_server_diagnostics_resp();
+            for (const auto& op : m_slow_ops.get_ops()) {
+                *diagnostics->add_slow_ops() = op;
+            }
+
+            if (req.server_diagnostics_req().clear_slow_ops()) {
+                m_slow_ops.clear();
+            }
+
+            if (req.server_diagnostics_req().take_profiles()) {
+                for (auto& profile : m_profiles) {
+                    *diagnostics->add_profiles() = std::move(profile);
+                }
+
+                m_profiles.clear();
+            }
+
+            push_resp(std::move(resp));
+            break;
+        }
+        case proto::Request::kServerExpressionsReq: {
+            proto::Response resp;
+            auto* expressions =
+                resp.mutable_server_expressions_resp()->mutable_expressions();
+            for (const auto& [name, expression] : m_expression_library) {
+                (*expressions)[name] = expression;
+            }
+
             push_resp(std::move(resp));
             break;
         }
@@ -2273,18 +5467,374 @@ ProtoServer::_handle_request(std::uint32_t client_id, const Request& req) {
         }
     }
 
+    _log_slow_op(req, start);
     return proto_resp;
 }
 
+// Set the duration of `op`, which finished now.
+static void
+finish_slow_op(proto::SlowOp& op, std::chrono::microseconds duration) {
+    auto wall_clock = std::chrono::system_clock::now().time_since_epoch();
+    op.set_duration_ms(static_cast<double>(duration.count()) / 1000.0);
+    op.set_timestamp(static_cast<double>(
+        std::chrono::duration_cast<std::chrono::milliseconds>(wall_clock)
+            .count()
+    ));
+}
+
+// Apply `from_rows` JSON `rows` of cell edits to `table`.
+void
+ProtoServer::_apply_cell_rows(
+    const ServerResources::t_id& table_id, Table& table, const std::string& rows
+) {
+    table.update_rows(rows, 0);
+    m_resources.mark_table_dirty(table_id);
+}
+
+// The JSON value of `column` of the row whose index is `index_json` in the
+// table `table_id`, as its views read it, or `std::nullopt` if there is no
+// such row. An overlay's rows are those of its base, less those it removed,
+// with the cells of its delta `table` written over them.
+std::optional<std::string>
+ProtoServer::_lookup_cell(
+    const ServerResources::t_id& table_id,
+    const Table& table,
+    const std::string& index_json,
+    const std::string& column
+) {
+    auto row = lookup_index_row(table, index_json);
+    if (row.has_value()) {
+        auto value = table.get_gnode()->get_table()->get_const_column(column)
+                         ->get_scalar(*row);
+
+        if (value.is_valid() || !m_resources.is_overlay(table_id)) {
+            return scalar_to_json(value);
+        }
+    }
+
+    if (!m_resources.is_overlay(table_id)) {
+        return std::nullopt;
+    }
+
+    auto removed = m_resources.get_overlay_removes(table_id);
+    if (removed.count(normalize_index(table, index_json)) > 0) {
+        return row.has_value() ? std::optional<std::string>("null")
+                               : std::nullopt;
+    }
+
+    auto base = m_resources.get_table(m_resources.get_overlay_base(table_id));
+    auto base_row = lookup_index_row(*base, index_json);
+    if (!base_row.has_value()) {
+        return row.has_value() ? std::optional<std::string>("null")
+                               : std::nullopt;
+    }
+
+    return scalar_to_json(base->get_gnode()->get_table()
+                              ->get_const_column(column)
+                              ->get_scalar(*base_row));
+}
+
+// The number of rows the views of the overlay `overlay_id` read, those of its
+// base less those it removed, and those of its delta not among them.
+std::uint64_t
+ProtoServer::_overlay_size(const ServerResources::t_id& overlay_id) {
+    auto base = m_resources.get_table(m_resources.get_overlay_base(overlay_id));
+    auto delta = m_resources.get_table(overlay_id);
+    auto removed = m_resources.get_overlay_removes(overlay_id);
+    const auto& base_rows = base->get_gnode()->get_pkey_map();
+    std::uint64_t size = base_rows.size();
+    for (const auto& index_json : removed) {
+        if (lookup_index_row(*base, index_json).has_value()) {
+            --size;
+        }
+    }
+
+    for (const auto& [pkey, _] : delta->get_gnode()->get_pkey_map()) {
+        if (base_rows.find(pkey) == base_rows.end()
+            || removed.count(scalar_to_json(pkey)) > 0) {
+            ++size;
+        }
+    }
+
+    return size;
+}
+
+std::shared_ptr<Table>
+ProtoServer::_view_table(
+    const ServerResources::t_id& table_id,
+    const std::shared_ptr<Table>& table,
+    const proto::ViewConfig& cfg
+) {
+    if (!m_resources.is_overlay(table_id)) {
+        return unnest_view_table(table, cfg);
+    }
+
+    auto base = m_resources.get_table(m_resources.get_overlay_base(table_id));
+    auto merged = Table::from_schema(
+        base->get_index(), base->get_schema(), base->get_limit()
+    );
+
+    fill_overlay_table(
+        *merged, base, *table, m_resources.get_overlay_removes(table_id)
+    );
+
+    return unnest_view_table(merged, cfg);
+}
+
+// Rebuild the table the view `view_id` of the overlay `overlay_id` reads from
+// the live rows of its base and the overlay's delta.
+void
+ProtoServer::_refresh_overlay_view(
+    const ServerResources::t_id& overlay_id,
+    const ServerResources::t_id& view_id
+) {
+    auto view_table = m_resources.get_unnest_table(view_id);
+    auto cfg = m_resources.get_view_proto_config(view_id);
+    auto base = m_resources.get_table(m_resources.get_overlay_base(overlay_id));
+    auto delta = m_resources.get_table(overlay_id);
+    auto removed = m_resources.get_overlay_removes(overlay_id);
+    if (cfg.unnest().empty()) {
+        fill_overlay_table(*view_table, base, *delta, removed);
+        return;
+    }
+
+    auto merged = Table::from_schema(
+        base->get_index(), base->get_schema(), base->get_limit()
+    );
+
+    fill_overlay_table(*merged, base, *delta, removed);
+    view_table->replace_unnested(
+        *merged, {cfg.unnest().begin(), cfg.unnest().end()}
+    );
+
+    view_table->get_pool()->_process();
+}
+
+// Re-apply a `batch` of cell edits to `table`, or restore their previous
+// values in reverse order if `undo`. Edits to rows which have since been
+// removed are skipped. Returns the edit log records of the cells written.
+std::vector<proto::CellEditRecord>
+ProtoServer::_replay_cell_edits(
+    const ServerResources::t_id& table_id,
+    Table& table,
+    const std::vector<proto::CellEditRecord>& batch,
+    bool undo,
+    std::uint32_t client_id
+) {
+    const auto& index = table.get_index();
+    auto wall_clock = std::chrono::system_clock::now().time_since_epoch();
+    auto timestamp = static_cast<double>(
+        std::chrono::duration_cast<std::chrono::milliseconds>(wall_clock)
+            .count()
+    );
+
+    std::vector<const proto::CellEditRecord*> ordered;
+    for (const auto& record : batch) {
+        ordered.push_back(&record);
+    }
+
+    if (undo) {
+        std::reverse(ordered.begin(), ordered.end());
+    }
+
+    std::vector<proto::CellEditRecord> records;
+    rapidjson::StringBuffer rows;
+    rapidjson::Writer<rapidjson::StringBuffer> writer(rows);
+    writer.StartArray();
+    for (const auto* source : ordered) {
+        const auto& edit = source->edit();
+        auto previous =
+            _lookup_cell(table_id, table, edit.index(), edit.column());
+
+        if (!previous.has_value()) {
+            continue;
+        }
+
+        const auto& value = undo ? source->previous_value() : edit.value();
+        auto& record = records.emplace_back();
+        auto* record_edit = record.mutable_edit();
+        record_edit->set_index(edit.index());
+        record_edit->set_column(edit.column());
+        record_edit->set_value(value);
+        record.set_previous_value(*previous);
+
+        record.set_session_id(client_id);
+        record.set_timestamp(timestamp);
+        write_cell_row(writer, index, edit.index(), edit.column(), value);
+    }
+
+    writer.EndArray();
+    if (!records.empty()) {
+        _apply_cell_rows(table_id, table, rows.GetString());
+    }
+
+    return records;
+}
+
+void
+ProtoServer::_log_slow_op(
+    const Request& req, SlowOpLog::t_clock::time_point start
+) {
+    proto::SlowOp op;
+    std::string view_id;
+    switch (req.client_req_case()) {
+        case proto::Request::kTableMakeViewReq:
+            op.set_kind(proto::SlowOp::VIEW_CREATE);
+            view_id = req.table_make_view_req().view_id();
+            break;
+        case proto::Request::kViewToColumnsStringReq:
+            op.set_kind(proto::SlowOp::VIEW_TO_COLUMNS);
+            view_id = req.entity_id();
+            break;
+        case proto::Request::kViewToRowsStringReq:
+            op.set_kind(proto::SlowOp::VIEW_TO_ROWS);
+            view_id = req.entity_id();
+            break;
+        case proto::Request::kViewToCsvReq:
+            op.set_kind(proto::SlowOp::VIEW_TO_CSV);
+            view_id = req.entity_id();
+            break;
+        case proto::Request::kViewToArrowReq:
+            op.set_kind(proto::SlowOp::VIEW_TO_ARROW);
+            view_id = req.entity_id();
+            break;
+        default:
+            return;
+    }
+
+    auto duration = std::chrono::duration_cast<std::chrono::microseconds>(
+        SlowOpLog::t_clock::now() - start
+    );
+
+    if (!m_slow_ops.is_slow(duration)) {
+        return;
+    }
+
+    auto table_id = m_resources.get_table_id_for_view(view_id);
+    op.set_entity_id(view_id);
+    op.set_table_id(table_id);
+    op.set_table_size(m_resources.get_table(table_id)->size());
+    view_config_to_proto(
+        *m_resources.get_view(view_id)->get_view_config(),
+        op.mutable_view_config()
+    );
+
+    finish_slow_op(op, duration);
+    m_slow_ops.push(std::move(op));
+}
+
+/**
+ * @brief Release an Arrow C stream which will not be consumed, as streams
+ * passed to `ProtoServer` are owned by it even on error.
+ */
+static void
+release_arrow_stream(ArrowArrayStream* stream) {
+    if (stream->release != nullptr) {
+        stream->release(stream);
+    }
+}
+
+std::vector<ProtoServerResp<std::string>>
+ProtoServer::host_arrow_stream(
+    const std::string& table_id,
+    const std::string& index,
+    ArrowArrayStream* stream
+) {
+    if (has_table(m_resources, table_id)) {
+        release_arrow_stream(stream);
+        PSP_COMPLAIN_AND_ABORT("Table `" + table_id + "` already exists");
+    }
+
+    auto table = Table::from_arrow_stream(index, stream);
+    m_resources.host_table(table_id, table);
+    std::vector<ProtoServerResp<Response>> resps;
+    _hosted_tables_update(resps);
+    std::vector<ProtoServerResp<std::string>> out;
+    for (auto& resp : resps) {
+        ProtoServerResp<std::string> str_resp;
+        str_resp.data = resp.data.SerializeAsString();
+        str_resp.client_id = resp.client_id;
+        out.emplace_back(str_resp);
+    }
+
+    return out;
+}
+
+void
+ProtoServer::update_arrow_stream(
+    const std::string& table_id,
+    ArrowArrayStream* stream,
+    std::uint32_t port_id
+) {
+    if (!has_table(m_resources, table_id)) {
+        release_arrow_stream(stream);
+        PSP_COMPLAIN_AND_ABORT("Table `" + table_id + "` not found");
+    }
+
+    auto table = m_resources.get_table(table_id);
+    table->update_arrow_stream(stream, port_id);
+    m_resources.mark_table_dirty(table_id);
+}
+
+std::vector<ProtoServerResp<std::string>>
+ProtoServer::view_to_arrow_stream(
+    const std::string& view_id, ArrowArrayStream* out
+) {
+    std::vector<ProtoServerResp<Response>> resps;
+    auto table_id = m_resources.get_table_id_for_view(view_id);
+    if (m_resources.is_table_dirty(table_id)) {
+        auto table = m_resources.get_table(table_id);
+        _process_table(table, table_id, resps);
+    }
+
+    auto view = m_resources.get_view(view_id);
+    auto config = view->get_view_config();
+    auto num_hidden = calculate_num_hidden(*view, *config);
+    auto dims = parse_format_options(
+        proto::ViewPort(),
+        view->num_columns(),
+        view->num_rows(),
+        view->sides(),
+        config->is_column_only(),
+        num_hidden
+    );
+
+    view->to_arrow_stream(
+        dims.start_row, dims.end_row, dims.start_col, dims.end_col, true, out
+    );
+
+    std::vector<ProtoServerResp<std::string>> str_resps;
+    for (auto& resp : resps) {
+        ProtoServerResp<std::string> str_resp;
+        str_resp.data = resp.data.SerializeAsString();
+        str_resp.client_id = resp.client_id;
+        str_resps.emplace_back(str_resp);
+    }
+
+    return str_resps;
+}
+
 std::vector<ProtoServerResp<ProtoServer::Response>>
 ProtoServer::_poll() {
     std::vector<ProtoServerResp<Response>> resp_envs;
     auto tables = m_resources.get_dirty_tables();
     for (auto& [table, table_id] : tables) {
+        // Batched tables hold their updates until their latency elapses,
+        // unless a read request processes them first.
+        if (!m_resources.is_commit_due(table_id)) {
+            continue;
+        }
+
         _process_table_unchecked(table, table_id, resp_envs);
+        m_resources.mark_table_clean(table_id);
+    }
+
+    // Recompress the columns which requests since the last poll have
+    // decompressed or grown; the rest are skipped.
+    for (const auto& table_id : m_resources.get_table_ids()) {
+        m_resources.get_table(table_id)->compress();
     }
 
-    m_resources.mark_all_tables_clean();
     return resp_envs;
 }
 
@@ -2294,29 +5844,204 @@ ProtoServer::_process_table_unchecked(
     const ServerResources::t_id& table_id,
     std::vector<ProtoServerResp<ProtoServer::Response>>& outs
 ) {
-    table->get_pool()->_process([this, table_id, &outs](auto port_id) {
+    auto start = SlowOpLog::t_clock::now();
+    auto did_update = false;
+    auto is_overlay = m_resources.is_overlay(table_id);
+    table->get_pool()->_process([&](auto port_id) {
+        did_update = true;
         // record changes per port.
         auto view_ids = m_resources.get_view_ids(table_id);
         for (const auto& view_id : view_ids) {
-            auto view = m_resources.get_view(view_id);
-            auto subscriptions = m_resources.get_view_on_update_sub(view_id);
-            for (auto& subscription : subscriptions) {
-                Response out;
-                out.set_msg_id(subscription.id);
-                out.set_entity_id(view_id);
-                auto* r = out.mutable_view_on_update_resp();
-                r->set_port_id(port_id);
-                if (view->get_deltas_enabled()) {
-                    *r->mutable_delta() = *view->get_row_delta_as_arrow();
-                }
+            if (is_overlay) {
+                _refresh_overlay_view(table_id, view_id);
+            } else if (auto unnest = m_resources.get_unnest_table(view_id)) {
+                auto cfg = m_resources.get_view_proto_config(view_id);
+                unnest->replace_unnested(
+                    *table, {cfg.unnest().begin(), cfg.unnest().end()}
+                );
 
-                ProtoServerResp<proto::Response> resp2;
-                resp2.data = std::move(out);
-                resp2.client_id = subscription.client_id;
-                outs.emplace_back(std::move(resp2));
+                unnest->get_pool()->_process();
             }
+
+            _notify_view_on_update(*table, view_id, port_id, true, outs);
         }
     });
+
+    // The views of this table's overlays read its rows.
+    if (did_update) {
+        for (const auto& overlay_id : m_resources.get_base_overlays(table_id)) {
+            for (const auto& view_id : m_resources.get_view_ids(overlay_id)) {
+                _refresh_overlay_view(overlay_id, view_id);
+                _notify_view_on_update(*table, view_id, 0, true, outs);
+            }
+        }
+    }
+
+    table->check_dictionary_cardinality();
+    auto duration = std::chrono::duration_cast<std::chrono::microseconds>(
+        SlowOpLog::t_clock::now() - start
+    );
+
+    if (did_update && m_slow_ops.is_slow(duration)) {
+        proto::SlowOp op;
+        op.set_kind(proto::SlowOp::TABLE_UPDATE);
+        op.set_entity_id(table_id);
+        op.set_table_id(table_id);
+        op.set_table_size(table->size());
+        finish_slow_op(op, duration);
+        m_slow_ops.push(std::move(op));
+    }
+}
+
+void
+ProtoServer::_notify_view_on_update(
+    const Table& table,
+    const ServerResources::t_id& view_id,
+    std::uint32_t port_id,
+    bool with_delta,
+    std::vector<ProtoServerResp<Response>>& outs
+) {
+    auto view = m_resources.get_view(view_id);
+    auto subscriptions = m_resources.get_view_on_update_sub(view_id);
+    for (auto& subscription : subscriptions) {
+        Response out;
+        out.set_msg_id(subscription.id);
+        out.set_entity_id(view_id);
+        auto* r = out.mutable_view_on_update_resp();
+
+        // Viewport subscriptions are only notified when their window
+        // changes, and only with the rows which did.
+        auto viewport_sub = m_resources.get_viewport_sub(view_id, subscription);
+        if (viewport_sub.has_value()) {
+            auto rows = viewport_rows(*view, viewport_sub->viewport);
+            if (!diff_viewport_rows(
+                    viewport_sub->rows, rows, *r->mutable_viewport()
+                )) {
+                continue;
+            }
+
+            m_resources.set_viewport_rows(
+                view_id, subscription, std::move(rows)
+            );
+        }
+
+        r->set_sequence(m_resources.next_update_sequence(view_id, subscription)
+        );
+        r->set_port_id(port_id);
+        r->set_port_sequence(table.get_port_sequence(port_id));
+        if (auto name = table.get_port_name(port_id)) {
+            r->set_port_name(*name);
+        }
+
+        if (with_delta && view->get_deltas_enabled()) {
+            *r->mutable_delta() = *view->get_row_delta_as_arrow();
+            for (const auto& pkey : view->get_removed_pkeys()) {
+                r->add_removed(scalar_to_json(pkey));
+            }
+        }
+
+        ProtoServerResp<proto::Response> resp2;
+        resp2.data = std::move(out);
+        resp2.client_id = subscription.client_id;
+        outs.emplace_back(std::move(resp2));
+    }
+}
+
+// The aggregates of a pivoted context, which include those of the columns
+// its sort reads.
+static std::vector<std::pair<std::string, std::string>>
+aggspec_names(const t_view_config& config) {
+    std::vector<std::pair<std::string, std::string>> names;
+    for (const auto& aggspec : config.get_aggspecs()) {
+        names.emplace_back(aggspec.name(), aggspec.agg_str());
+    }
+
+    return names;
+}
+
+bool
+ProtoServer::_update_view_filter_and_sort(
+    const ServerResources::t_id& view_id,
+    const proto::ViewConfig& cfg,
+    bool refilter,
+    std::vector<ProtoServerResp<Response>>& outs
+) {
+    auto table = m_resources.get_table_for_view(view_id);
+    auto view = m_resources.get_view(view_id);
+    if (std::dynamic_pointer_cast<CtxUnitView>(view) != nullptr) {
+        return false;
+    }
+
+    auto view_table = m_resources.get_unnest_table(view_id);
+    if (view_table == nullptr) {
+        view_table = table;
+    }
+
+    auto schema = std::make_shared<t_schema>(
+        view_table->get_gnode()->get_output_schema()
+    );
+
+    auto config = _make_view_config(view_table, schema, bind_view_params(cfg));
+    const auto& previous = *view->get_view_config();
+
+    // A two-sided context only has totals if it is sorted.
+    if (aggspec_names(previous) != aggspec_names(*config)
+        || (view->sides() == 2
+            && previous.get_sortspec().empty()
+                != config->get_sortspec().empty())) {
+        return false;
+    }
+
+    auto expanded = expansion_state(*view);
+    view->set_filter_and_sort(config, refilter);
+    apply_expansion(*view, expanded);
+    m_resources.set_view_proto_config(view_id, cfg);
+    _notify_view_on_update(*table, view_id, 0, false, outs);
+    return true;
+}
+
+void
+ProtoServer::_rebuild_view(
+    const ServerResources::t_id& view_id,
+    proto::ViewConfig cfg,
+    std::vector<ProtoServerResp<Response>>& outs
+) {
+    auto table = m_resources.get_table_for_view(view_id);
+    auto previous = m_resources.get_view(view_id);
+
+    // The old context stays registered until `previous` is released, so the
+    // new one needs a distinct name.
+    auto context_name = view_id + "#" + std::to_string(++m_view_generation);
+    auto view_table =
+        _view_table(m_resources.get_table_id_for_view(view_id), table, cfg);
+    auto view = _make_view(view_table, context_name, bind_view_params(cfg));
+    view->set_deltas_enabled(previous->get_deltas_enabled());
+    if (previous->get_view_config()->get_row_pivots()
+        == view->get_view_config()->get_row_pivots()) {
+        restore_expansion(*previous, *view);
+    }
+
+    m_resources.replace_view(view_id, view);
+    m_resources.set_unnest_table(
+        view_id, view_table != table ? view_table : nullptr
+    );
+
+    m_resources.set_view_proto_config(view_id, std::move(cfg));
+    _notify_view_on_update(*table, view_id, 0, false, outs);
+}
+
+void
+ProtoServer::_hosted_tables_update(std::vector<ProtoServerResp<Response>>& outs
+) {
+    for (const auto& sub : m_resources.get_on_hosted_tables_update_sub()) {
+        Response resp;
+        resp.mutable_get_hosted_tables_resp();
+        resp.set_msg_id(sub.id);
+        ProtoServerResp<Response> resp2;
+        resp2.data = std::move(resp);
+        resp2.client_id = sub.client_id;
+        outs.emplace_back(std::move(resp2));
+    }
 }
 
 void
@@ -2350,6 +6075,25 @@ ServerResources::remove_view_on_update_sub(
             subs.end()
         );
     }
+
+    if (m_viewport_subs.find(view_id) != m_viewport_subs.end()) {
+        auto& subs = m_viewport_subs[view_id];
+        subs.erase(
+            std::remove_if(
+                subs.begin(),
+                subs.end(),
+                [sub_id, client_id](const ViewportSubscription& viewport_sub) {
+                    return viewport_sub.sub.id == sub_id
+                        && viewport_sub.sub.client_id == client_id;
+                }
+            ),
+            subs.end()
+        );
+    }
+
+    if (m_update_sequences.find(view_id) != m_update_sequences.end()) {
+        m_update_sequences[view_id].erase({client_id, sub_id});
+    }
 }
 
 void
@@ -15,6 +15,7 @@
 #include <cmath>
 #include <fstream>
 #include <perspective/base.h>
+#include <perspective/base64.h>
 #include <perspective/compat.h>
 #include <perspective/extract_aggregate.h>
 #include <perspective/multi_sort.h>
@@ -91,7 +92,9 @@ t_stree::t_stree(
     m_aggspecs(aggspecs),
     m_schema(std::move(schema)),
     m_cur_aggidx(1),
-    m_has_delta(false) {
+    m_has_delta(false),
+    m_category_ranks(cfg.get_category_ranks()),
+    m_null_aggregates(cfg.get_null_aggregates()) {
     const auto& g_agg_str = cfg.get_grand_agg_str();
     m_grand_agg_str = g_agg_str.empty() ? "Grand Aggregate" : g_agg_str;
 }
@@ -857,6 +860,11 @@ t_stree::update_shape_from_static(const t_dtree_ctx& ctx) {
             dtree.get_sortby_value(filter, dptidx)
         );
 
+        auto ranks = m_category_ranks.find(m_pivots[ndepth - 1].colname());
+        if (ranks != m_category_ranks.end()) {
+            sortby_value = category_rank(ranks->second, sortby_value);
+        }
+
         t_uindex src_ridx = dptidx;
 
         auto iter =
@@ -1175,7 +1183,23 @@ t_stree::update_agg_table(
                 dst->set_scalar(dst_ridx, new_value);
             } break;
             case AGGTYPE_COUNT: {
-                if (nidx == 0) {
+                if (m_null_aggregates == NULL_AGGREGATES_IGNORE) {
+                    auto pkeys = get_pkeys(nidx);
+                    std::vector<t_tscalar> values;
+                    read_column_from_gstate(
+                        gstate,
+                        expression_master_table,
+                        spec.get_dependencies()[0].name(),
+                        pkeys,
+                        values
+                    );
+
+                    new_value.set(std::int64_t(std::count_if(
+                        values.begin(),
+                        values.end(),
+                        [](const t_tscalar& v) { return v.is_valid(); }
+                    )));
+                } else if (nidx == 0) {
                     new_value.set(nstrands - 1);
                 } else {
                     new_value.set(nstrands);
@@ -1543,7 +1567,9 @@ t_stree::update_agg_table(
                     true
                 );
                 new_value.set(*std::max_element(values.begin(), values.end()));
-                dst->set_scalar(dst_ridx, new_value);
+                dst->set_scalar(
+                    dst_ridx, new_value.coerce_numeric_dtype(dst->get_dtype())
+                );
             } break;
             case AGGTYPE_MIN: {
                 t_tscalar dst_scalar = dst->get_scalar(dst_ridx);
@@ -1559,7 +1585,9 @@ t_stree::update_agg_table(
                     true
                 );
                 new_value.set(*std::min_element(values.begin(), values.end()));
-                dst->set_scalar(dst_ridx, new_value);
+                dst->set_scalar(
+                    dst_ridx, new_value.coerce_numeric_dtype(dst->get_dtype())
+                );
             } break;
             case AGGTYPE_HIGH_WATER_MARK: {
                 t_tscalar src_scalar = src->get_scalar(src_ridx);
@@ -1753,6 +1781,45 @@ t_stree::update_agg_table(
 
                 dst->set_scalar(dst_ridx, new_value);
             } break;
+            case AGGTYPE_TOTAL_BYTES:
+            case AGGTYPE_MAX_BYTES: {
+                old_value.set(dst->get_scalar(dst_ridx));
+                auto pkeys = get_pkeys(nidx);
+                bool is_total = spec.agg() == AGGTYPE_TOTAL_BYTES;
+
+                // Binary values are interned as base64 text, so sizes are
+                // measured on the decoded payload.
+                new_value.set(
+                    reduce_from_gstate<
+                        std::function<std::int64_t(std::vector<t_tscalar>&)>>(
+                        gstate,
+                        expression_master_table,
+                        spec.get_dependencies()[0].name(),
+                        pkeys,
+                        [is_total](std::vector<t_tscalar>& values) {
+                            std::int64_t rv = 0;
+                            for (const auto& v : values) {
+                                if (!v.is_valid() || v.is_none()
+                                    || !is_vlen_dtype(v.m_type)) {
+                                    continue;
+                                }
+
+                                auto size = static_cast<std::int64_t>(
+                                    v.m_type == DTYPE_BINARY
+                                        ? base64_decoded_size(v.get_char_ptr())
+                                        : std::strlen(v.get_char_ptr())
+                                );
+
+                                rv = is_total ? rv + size : std::max(rv, size);
+                            }
+
+                            return rv;
+                        }
+                    )
+                );
+
+                dst->set_scalar(dst_ridx, new_value);
+            } break;
             case AGGTYPE_DISTINCT_LEAF: {
                 auto pkeys = get_pkeys(nidx);
                 old_value.set(dst->get_scalar(dst_ridx));
@@ -1832,6 +1899,15 @@ t_stree::update_agg_table(
             }
         } // end switch
 
+        if (m_null_aggregates == NULL_AGGREGATES_PROPAGATE
+            && spec.agg() != AGGTYPE_COUNT
+            && has_null_from_gstate(
+                gstate, expression_master_table, spec, get_pkeys(nidx)
+            )) {
+            new_value = mknone();
+            dst->set_valid(dst_ridx, false);
+        }
+
         bool val_neq = old_value != new_value;
 
         m_has_delta = m_has_delta || val_neq;
@@ -2602,6 +2678,29 @@ t_stree::is_unique_from_gstate(
     return gstate.is_unique(*gstate_master_table, colname, pkeys, value);
 }
 
+bool
+t_stree::has_null_from_gstate(
+    const t_gstate& gstate,
+    const t_data_table& expression_master_table,
+    const t_aggspec& spec,
+    const std::vector<t_tscalar>& pkeys
+) const {
+    std::vector<t_tscalar> values;
+    for (const auto& dep : spec.get_dependencies()) {
+        read_column_from_gstate(
+            gstate, expression_master_table, dep.name(), pkeys, values
+        );
+
+        for (const auto& value : values) {
+            if (!value.is_valid()) {
+                return true;
+            }
+        }
+    }
+
+    return false;
+}
+
 bool
 t_stree::apply_from_gstate(
     const t_gstate& gstate,
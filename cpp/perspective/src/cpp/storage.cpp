@@ -360,6 +360,7 @@ void
 t_lstore::reserve_impl(t_uindex capacity, bool allow_shrink) {
     PSP_TRACE_SENTINEL();
     PSP_VERBOSE_ASSERT(m_init, "touching uninited object");
+    inflate_if_compressed();
     if ((capacity < m_capacity) && !allow_shrink) {
         return;
     }
@@ -458,6 +459,7 @@ t_lstore::load(const std::string& fname) {
 
     t_rfmapping imap;
     map_file_read(fname, imap);
+    inflate_if_compressed();
     reserve(imap.m_size);
     memcpy(m_base, imap.m_base, size_t(imap.m_size));
     m_size = imap.m_size;
@@ -469,6 +471,7 @@ t_lstore::save(const std::string& fname) {
     PSP_TRACE_SENTINEL();
     PSP_VERBOSE_ASSERT(m_init, "touching uninited object");
     PSP_VERBOSE_ASSERT(m_init, "Store not inited.");
+    inflate_if_compressed();
 
     t_rfmapping omap;
     map_file_write(fname, capacity(), omap);
@@ -517,6 +520,7 @@ t_lstore::get_fname() const {
 void
 t_lstore::push_back(const void* ptr, t_uindex len) {
     PSP_TRACE_SENTINEL();
+    inflate_if_compressed();
     if (m_size + len >= m_capacity) {
         reserve(static_cast<t_uindex>(m_size + len)
         ); // reserve() will multiply by m_resize_factor internally
@@ -535,11 +539,13 @@ t_lstore::push_back(const void* ptr, t_uindex len) {
 
 void*
 t_lstore::get_ptr(t_uindex offset) {
+    inflate_if_compressed();
     return static_cast<void*>(static_cast<unsigned char*>(m_base) + offset);
 }
 
 const void*
 t_lstore::get_ptr(t_uindex offset) const {
+    inflate_if_compressed();
     return static_cast<void*>(static_cast<unsigned char*>(m_base) + offset);
 }
 
@@ -561,6 +567,7 @@ void
 t_lstore::append(const t_lstore& other) {
     PSP_TRACE_SENTINEL();
     PSP_VERBOSE_ASSERT(m_init, "touching uninited object");
+    other.inflate_if_compressed();
     push_back(other.m_base, other.size());
 }
 
@@ -568,6 +575,7 @@ void
 t_lstore::clear() {
     PSP_TRACE_SENTINEL();
     PSP_VERBOSE_ASSERT(m_init, "touching uninited object");
+    inflate_if_compressed();
 #ifndef PSP_ENABLE_WASM
     memset(m_base, 0, size_t(capacity()));
 #endif
@@ -601,6 +609,7 @@ void
 t_lstore::fill(const t_lstore& other) {
     PSP_TRACE_SENTINEL();
     PSP_VERBOSE_ASSERT(m_init, "touching uninited object");
+    other.inflate_if_compressed();
     reserve(other.size());
     memcpy(m_base, const_cast<void*>(other.m_base), size_t(other.size()));
     set_size(other.size());
@@ -610,6 +619,7 @@ void
 t_lstore::fill(const t_lstore& other, const t_mask& mask, t_uindex elem_size) {
     PSP_TRACE_SENTINEL();
     PSP_VERBOSE_ASSERT(m_init, "touching uninited object");
+    inflate_if_compressed();
     reserve(mask.size() * elem_size);
 
     PSP_VERBOSE_ASSERT(
@@ -655,4 +665,62 @@ t_lstore::clone() const {
     return rval;
 }
 
+void
+t_lstore::compress(t_uindex width) {
+    PSP_TRACE_SENTINEL();
+    if (!m_init || m_backing_store != BACKING_STORE_MEMORY || m_alignment >= 2
+        || is_compressed() || m_size == m_incompressible_size) {
+        return;
+    }
+
+    auto buffer = compress_buffer(m_base, m_size, width);
+    if (buffer.m_encoding == COMPRESSION_NONE) {
+        m_incompressible_size = m_size;
+        return;
+    }
+
+    std::lock_guard<std::mutex> lock(m_compressed_mtx);
+    free(m_base);
+    m_base = nullptr;
+    m_compressed = std::make_unique<t_compressed_buffer>(std::move(buffer));
+    m_incompressible_size = std::numeric_limits<t_uindex>::max();
+    m_is_compressed.store(true, std::memory_order_release);
+}
+
+bool
+t_lstore::is_compressed() const {
+    return m_is_compressed.load(std::memory_order_acquire);
+}
+
+t_uindex
+t_lstore::stored_size() const {
+    std::lock_guard<std::mutex> lock(m_compressed_mtx);
+    if (m_is_compressed.load(std::memory_order_relaxed)) {
+        return m_compressed->m_data.size();
+    }
+
+    return m_size;
+}
+
+void
+t_lstore::inflate() const {
+    PSP_TRACE_SENTINEL();
+    std::lock_guard<std::mutex> lock(m_compressed_mtx);
+
+    // Another reader may have decompressed this store while we waited.
+    if (!m_is_compressed.load(std::memory_order_relaxed)) {
+        return;
+    }
+
+    // Reads through `const` accessors decompress too, so the data members
+    // are mutated through a non-`const` alias, under `m_compressed_mtx`.
+    auto* self = const_cast<t_lstore*>(this);
+    void* base = calloc(std::max(size_t(m_capacity), size_t(8U)), 1);
+    PSP_VERBOSE_ASSERT(base, "MALLOC_FAILED");
+    decompress_buffer(*m_compressed, base);
+    self->m_base = base;
+    self->m_compressed.reset();
+    m_is_compressed.store(false, std::memory_order_release);
+}
+
 } // end namespace perspective
@@ -12,18 +12,25 @@
 
 #include "perspective/arrow_loader.h"
 #include "perspective/base.h"
+#include "perspective/base64.h"
 #include "perspective/column.h"
 #include "perspective/data_table.h"
 #include "perspective/raw_types.h"
 #include "perspective/schema.h"
+#include "perspective/time.h"
+#include "perspective/uuid.h"
+#include "perspective/ipaddr.h"
 // #include "arrow/vendored/datetime/date.h"
 #include "rapidjson/document.h"
+#include <algorithm>
 #include <chrono>
 #include <ctime>
 #include <memory>
 #include <optional>
 #include <perspective/table.h>
+#include <rapidjson/stringbuffer.h>
 #include <rapidjson/writer.h>
+#include <set>
 #include <sstream>
 #include <string>
 #include <string_view>
@@ -49,7 +56,8 @@ Table::Table(
     m_offset(0),
     m_limit(limit),
     m_index(std::move(index)),
-    m_gnode_set(false) {
+    m_gnode_set(false),
+    m_compress(false) {
     validate_columns(m_column_names);
 }
 
@@ -77,6 +85,10 @@ Table::init(
     }
 
     PSP_VERBOSE_ASSERT(m_gnode_set, "gnode is not set!");
+    if (op == OP_INSERT) {
+        update_sketches(data_table);
+    }
+
     m_pool->send(m_gnode->get_id(), port_id, data_table);
 
     m_init = true;
@@ -216,6 +228,40 @@ Table::make_port() const {
     return m_gnode->make_input_port();
 }
 
+t_uindex
+Table::make_named_port(const std::string& name) {
+    auto iter = m_named_ports.find(name);
+    if (iter != m_named_ports.end()) {
+        return iter->second;
+    }
+
+    auto port_id = make_port();
+    m_named_ports[name] = port_id;
+    return port_id;
+}
+
+std::optional<std::string>
+Table::get_port_name(t_uindex port_id) const {
+    for (const auto& [name, id] : m_named_ports) {
+        if (id == port_id) {
+            return name;
+        }
+    }
+
+    return std::nullopt;
+}
+
+std::uint64_t
+Table::next_port_sequence(t_uindex port_id) {
+    return ++m_port_sequences[port_id];
+}
+
+std::uint64_t
+Table::get_port_sequence(t_uindex port_id) const {
+    auto iter = m_port_sequences.find(port_id);
+    return iter == m_port_sequences.end() ? 0 : iter->second;
+}
+
 void
 Table::remove_port(t_uindex port_id) const {
     PSP_VERBOSE_ASSERT(m_init, "touching uninited object");
@@ -277,6 +323,211 @@ Table::get_limit() const {
     return m_limit;
 }
 
+const std::map<std::string, std::vector<std::string>>&
+Table::get_categories() const {
+    return m_categories;
+}
+
+void
+Table::set_categories(
+    const std::string& column, const std::vector<std::string>& categories
+) {
+    auto schema = get_schema();
+    if (!schema.has_column(column)) {
+        PSP_COMPLAIN_AND_ABORT(
+            "Cannot set categories of non-existent column `" + column + "`"
+        );
+    }
+
+    if (schema.get_dtype(column) != DTYPE_STR) {
+        PSP_COMPLAIN_AND_ABORT(
+            "Cannot set categories of non-string column `" + column + "`"
+        );
+    }
+
+    std::vector<std::string> unique;
+    std::set<std::string> seen;
+    for (const auto& category : categories) {
+        if (seen.insert(category).second) {
+            unique.push_back(category);
+        }
+    }
+
+    m_categories[column] = std::move(unique);
+}
+
+const std::map<std::string, t_dictionary_options>&
+Table::get_dictionary_options() const {
+    return m_dictionaries;
+}
+
+void
+Table::set_dictionary_options(
+    const std::string& column, const t_dictionary_options& options
+) {
+    auto schema = get_schema();
+    if (!schema.has_column(column)) {
+        PSP_COMPLAIN_AND_ABORT(
+            "Cannot set dictionary options of non-existent column `" + column
+            + "`"
+        );
+    }
+
+    if (schema.get_dtype(column) != DTYPE_STR) {
+        PSP_COMPLAIN_AND_ABORT(
+            "Cannot set dictionary options of non-string column `" + column
+            + "`"
+        );
+    }
+
+    m_dictionaries[column] = options;
+    m_dictionary_warnings.erase(column);
+    reserve_dictionary(column);
+}
+
+const std::map<std::string, t_column_hints>&
+Table::get_column_hints() const {
+    return m_column_hints;
+}
+
+bool
+Table::is_column_editable(const std::string& column) const {
+    if (column == m_index || is_virtual_column(column)) {
+        return false;
+    }
+
+    auto iter = m_column_hints.find(column);
+    return iter == m_column_hints.end()
+        || iter->second.m_editable.value_or(true);
+}
+
+bool
+Table::is_virtual_column(const std::string& column) const {
+    const auto& virtual_columns = m_gnode->get_virtual_columns();
+    return std::any_of(
+        virtual_columns.begin(),
+        virtual_columns.end(),
+        [&column](const auto& expr) {
+            return expr->get_expression_alias() == column;
+        }
+    );
+}
+
+void
+Table::set_column_hints(
+    const std::string& column, const t_column_hints& hints
+) {
+    if (!get_schema().has_column(column)) {
+        PSP_COMPLAIN_AND_ABORT(
+            "Cannot set hints of non-existent column `" + column + "`"
+        );
+    }
+
+    if (hints.m_aggregate.has_value()) {
+        // Throws on an unknown aggregate name.
+        str_to_aggtype(*hints.m_aggregate);
+    }
+
+    t_tz timezone;
+    if (hints.m_timezone.has_value()
+        && !t_tz::parse(*hints.m_timezone, timezone)) {
+        PSP_COMPLAIN_AND_ABORT("Unknown time zone: " + *hints.m_timezone);
+    }
+
+    m_column_hints[column] = hints;
+}
+
+void
+Table::reserve_dictionary(const std::string& column) {
+    auto iter = m_dictionaries.find(column);
+    if (iter == m_dictionaries.end() || !iter->second.m_reserve.has_value()) {
+        return;
+    }
+
+    auto master = m_gnode->get_table_sptr();
+    master->get_column(column)->_get_vocab()->reserve(
+        0, *iter->second.m_reserve
+    );
+}
+
+std::map<std::string, t_dictionary_stats>
+Table::get_dictionary_stats() const {
+    std::map<std::string, t_dictionary_stats> stats;
+    auto schema = get_schema();
+    auto master = m_gnode->get_table_sptr();
+    for (const auto& column : schema.columns()) {
+        if (schema.get_dtype(column) != DTYPE_STR) {
+            continue;
+        }
+
+        auto* vocab = master->get_column(column)->_get_vocab();
+        stats[column] = {vocab->get_vlenidx(), vocab->nbytes()};
+    }
+
+    return stats;
+}
+
+const std::map<std::string, t_column_sketch>&
+Table::get_sketches() const {
+    return m_sketches;
+}
+
+void
+Table::update_sketches(const t_data_table& data_table) {
+    if (data_table.num_rows() == 0) {
+        return;
+    }
+
+    const auto& schema = data_table.get_schema();
+    for (t_uindex cidx = 0; cidx < schema.size(); ++cidx) {
+        const auto& column = schema.m_columns[cidx];
+        auto dtype = schema.m_types[cidx];
+        if (column.rfind("psp_", 0) == 0 || dtype == DTYPE_LIST
+            || dtype == DTYPE_OBJECT) {
+            continue;
+        }
+
+        auto iter = m_sketches.try_emplace(column, dtype).first;
+        auto col = data_table.get_const_column(column);
+        for (t_uindex ridx = 0; ridx < data_table.num_rows(); ++ridx) {
+            iter->second.update(col->get_scalar(ridx));
+        }
+    }
+}
+
+void
+Table::check_dictionary_cardinality() {
+    auto master = m_gnode->get_table_sptr();
+    for (const auto& [column, options] : m_dictionaries) {
+        if (!options.m_max_cardinality.has_value()
+            || m_dictionary_warnings.count(column) > 0) {
+            continue;
+        }
+
+        auto size = master->get_column(column)->get_vlenidx();
+        if (size > *options.m_max_cardinality) {
+            std::cerr << "Column `" << column << "` has " << size
+                      << " unique strings, more than its max_cardinality of "
+                      << *options.m_max_cardinality
+                      << "; consider making it the index or a numeric column"
+                      << '\n';
+            m_dictionary_warnings.insert(column);
+        }
+    }
+}
+
+void
+Table::set_compression(bool compress) {
+    m_compress = compress;
+}
+
+void
+Table::compress() {
+    if (m_compress) {
+        m_gnode->compress();
+    }
+}
+
 void
 Table::set_column_names(const std::vector<std::string>& column_names) {
     validate_columns(column_names);
@@ -307,6 +558,11 @@ schema_to_arrow_map(const t_schema& gnode_output_schema) {
                 map[name] = std::make_shared<arrow::DoubleType>();
                 break;
             case DTYPE_STR:
+            case DTYPE_LIST:
+            case DTYPE_JSON:
+            case DTYPE_BINARY:
+            case DTYPE_UUID:
+            case DTYPE_IPADDR:
                 map[name] = std::make_shared<arrow::StringType>();
                 break;
             case DTYPE_BOOL:
@@ -327,6 +583,11 @@ schema_to_arrow_map(const t_schema& gnode_output_schema) {
             case DTYPE_TIME:
                 map[name] = std::make_shared<arrow::TimestampType>();
                 break;
+            case DTYPE_DURATION:
+                // Arrow's CSV reader cannot parse durations, so these are
+                // read as integer milliseconds and cast on fill.
+                map[name] = std::make_shared<arrow::Int64Type>();
+                break;
             case DTYPE_DATE:
                 map[name] = std::make_shared<arrow::Date64Type>();
                 break;
@@ -342,10 +603,14 @@ schema_to_arrow_map(const t_schema& gnode_output_schema) {
 }
 
 void
-Table::update_csv(const std::string_view& data, std::uint32_t port_id) {
+Table::update_csv(
+    const std::string_view& data,
+    std::uint32_t port_id,
+    const apachearrow::CsvOptions& options
+) {
     auto type_map = schema_to_arrow_map(get_gnode()->get_output_schema());
     apachearrow::ArrowLoader arrow_loader;
-    arrow_loader.init_csv(data, true, type_map);
+    arrow_loader.init_csv(data, true, type_map, options);
     std::uint32_t row_count = 0;
     row_count = arrow_loader.row_count();
     t_data_table data_table(get_schema());
@@ -356,12 +621,16 @@ Table::update_csv(const std::string_view& data, std::uint32_t port_id) {
     );
     process_op_column(data_table, t_op::OP_INSERT);
     calculate_offset(row_count);
+    update_sketches(data_table);
     m_pool->send(get_gnode()->get_id(), port_id, data_table);
 }
 
 std::shared_ptr<Table>
 Table::from_csv(
-    const std::string& index, const std::string_view& data, std::uint32_t limit
+    const std::string& index,
+    const std::string_view& data,
+    std::uint32_t limit,
+    const apachearrow::CsvOptions& options
 ) {
     auto pool = std::make_shared<t_pool>();
     pool->init();
@@ -369,7 +638,7 @@ Table::from_csv(
         std::unordered_map<std::string, std::shared_ptr<arrow::DataType>>();
 
     apachearrow::ArrowLoader arrow_loader;
-    arrow_loader.init_csv(data.data(), false, map);
+    arrow_loader.init_csv(data, false, map, options);
 
     std::vector<std::string> column_names;
     std::vector<t_dtype> data_types;
@@ -492,8 +761,9 @@ rapidjson_type_to_dtype(const rapidjson::Value& value) {
             return t_dtype::DTYPE_BOOL;
         case rapidjson::kNullType:
             return t_dtype::DTYPE_NONE;
-        case rapidjson::kObjectType:
         case rapidjson::kArrayType:
+            return t_dtype::DTYPE_LIST;
+        case rapidjson::kObjectType:
             PSP_COMPLAIN_AND_ABORT("Unknown JSON type");
             return t_dtype::DTYPE_NONE;
     }
@@ -502,6 +772,11 @@ rapidjson_type_to_dtype(const rapidjson::Value& value) {
 void
 Table::clear() {
     reset_gnode(m_gnode->get_id());
+    m_sketches.clear();
+    m_dictionary_warnings.clear();
+    for (const auto& [column, _] : m_dictionaries) {
+        reserve_dictionary(column);
+    }
 }
 
 template <t_dtype A, t_dtype B>
@@ -664,12 +939,10 @@ fill_column_json(
             }
 
             if (value.IsInt64()) {
-                if (value.GetInt64() > std::numeric_limits<std::int32_t>::max())
-                    [[likely]] {
-                    if (!is_update) {
-                        LOG_DEBUG("Promoting due to int32 overflow");
-                        return {DTYPE_FLOAT64};
-                    }
+                // `IsInt()` failed, so this value is out of `int32` range.
+                if (!is_update) {
+                    LOG_DEBUG("Promoting due to int32 overflow");
+                    return {DTYPE_INT64};
                 }
 
                 // Coerce in update mode
@@ -703,9 +976,18 @@ fill_column_json(
                 }
 
                 char* endptr;
-                std::int32_t result = strtol(str, &endptr, 10);
+                std::int64_t result = strtoll(str, &endptr, 10);
                 if (*endptr == '\0') {
-                    col->set_nth(i, result);
+                    if ((result > std::numeric_limits<std::int32_t>::max()
+                         || result < std::numeric_limits<std::int32_t>::min())
+                        && !is_update) {
+                        LOG_DEBUG("Promoting due to int32 overflow");
+                        return {DTYPE_INT64};
+                    }
+
+                    col->set_nth<std::int32_t>(
+                        i, static_cast<std::int32_t>(result)
+                    );
                     return std::nullopt;
                 }
 
@@ -731,11 +1013,15 @@ fill_column_json(
         }
         case t_dtype::DTYPE_INT64: {
             if (value.IsInt64()) [[likely]] {
-                col->set_nth<std::int64_t>(i, value.GetInt());
+                col->set_nth<std::int64_t>(i, value.GetInt64());
             } else if (value.IsDouble()) {
                 return {DTYPE_FLOAT64};
             } else if (value.IsString()) {
-                col->set_nth(i, std::atoll(value.GetString()));
+                // Large values are string-encoded by `to_json`, so accept
+                // them back without a round trip through `double`.
+                col->set_nth<std::int64_t>(
+                    i, std::strtoll(value.GetString(), nullptr, 10)
+                );
             } else {
                 std::stringstream ss;
                 ss << "Expected int64, found " << value.GetType();
@@ -743,6 +1029,24 @@ fill_column_json(
             }
             return std::nullopt;
         }
+        case t_dtype::DTYPE_UINT64: {
+            if (value.IsUint64()) [[likely]] {
+                col->set_nth<std::uint64_t>(i, value.GetUint64());
+            } else if (value.IsString()) {
+                col->set_nth<std::uint64_t>(
+                    i, std::strtoull(value.GetString(), nullptr, 10)
+                );
+            } else if (value.IsDouble() && is_update) {
+                col->set_nth<std::uint64_t>(
+                    i, static_cast<std::uint64_t>(value.GetDouble())
+                );
+            } else {
+                std::stringstream ss;
+                ss << "Expected uint64, found " << value.GetType();
+                PSP_COMPLAIN_AND_ABORT(ss.str());
+            }
+            return std::nullopt;
+        }
         case t_dtype::DTYPE_FLOAT64: {
             if (value.IsDouble()) [[likely]] {
                 col->set_nth<double>(i, value.GetDouble());
@@ -783,12 +1087,194 @@ fill_column_json(
             col->set_nth(i, json_into<t_date>(value));
             return std::nullopt;
         }
+        case t_dtype::DTYPE_DURATION: {
+            t_tdelta delta;
+            if (value.IsInt64()) [[likely]] {
+                delta = t_tdelta(value.GetInt64());
+            } else if (value.IsDouble()) {
+                delta = t_tdelta(static_cast<std::int64_t>(value.GetDouble()));
+            } else if (!value.IsString()
+                       || !parse_duration(value.GetString(), delta)) {
+                std::stringstream ss;
+                ss << "Expected duration, found " << value.GetType();
+                PSP_COMPLAIN_AND_ABORT(ss.str());
+            }
+
+            col->set_nth<std::int64_t>(i, delta.raw_value());
+            return std::nullopt;
+        }
+        case t_dtype::DTYPE_LIST: {
+            // Lists are stored as their JSON text. Strings are parsed first
+            // so that `"[1, 2]"` and `[1,2]` intern to the same value.
+            rapidjson::Document parsed;
+            const rapidjson::Value* list = &value;
+            if (value.IsString()) {
+                parsed.Parse(value.GetString());
+                list = &parsed;
+            }
+
+            if (!list->IsArray()) {
+                std::stringstream ss;
+                ss << "Expected list, found " << value.GetType();
+                PSP_COMPLAIN_AND_ABORT(ss.str());
+            }
+
+            rapidjson::StringBuffer buffer;
+            rapidjson::Writer<rapidjson::StringBuffer> writer(buffer);
+            list->Accept(writer);
+            col->set_nth(i, std::string(buffer.GetString()));
+            return std::nullopt;
+        }
+        case t_dtype::DTYPE_JSON: {
+            // Strings holding JSON text are stored as that document, and
+            // any other string as a JSON string.
+            rapidjson::Document parsed;
+            const rapidjson::Value* doc = &value;
+            if (value.IsString()) {
+                parsed.Parse(value.GetString());
+                if (!parsed.HasParseError()) {
+                    doc = &parsed;
+                }
+            }
+
+            rapidjson::StringBuffer buffer;
+            rapidjson::Writer<rapidjson::StringBuffer> writer(buffer);
+            doc->Accept(writer);
+            col->set_nth(i, std::string(buffer.GetString()));
+            return std::nullopt;
+        }
+        case t_dtype::DTYPE_BINARY: {
+            // Binary values are stored as base64 text, and may be written as
+            // a base64 string or an array of byte values.
+            if (value.IsString()) {
+                std::string_view text(
+                    value.GetString(), value.GetStringLength()
+                );
+
+                if (!base64_decode(text)) {
+                    PSP_COMPLAIN_AND_ABORT(
+                        "Expected base64 string, found `" + std::string(text)
+                        + "`"
+                    );
+                }
+
+                col->set_nth(i, std::string(text));
+                return std::nullopt;
+            }
+
+            if (value.IsArray()) {
+                std::string bytes;
+                for (const auto& byte : value.GetArray()) {
+                    if (!byte.IsUint() || byte.GetUint() > 255) {
+                        PSP_COMPLAIN_AND_ABORT("Expected byte value in array");
+                    }
+
+                    bytes.push_back(static_cast<char>(byte.GetUint()));
+                }
+
+                col->set_nth(i, base64_encode(bytes));
+                return std::nullopt;
+            }
+
+            std::stringstream ss;
+            ss << "Expected base64 string, found " << value.GetType();
+            PSP_COMPLAIN_AND_ABORT(ss.str());
+            return std::nullopt;
+        }
+        case t_dtype::DTYPE_UUID: {
+            t_uuid uuid;
+            if (!value.IsString()) {
+                std::stringstream ss;
+                ss << "Expected UUID, found " << value.GetType();
+                PSP_COMPLAIN_AND_ABORT(ss.str());
+            }
+
+            std::string_view text(value.GetString(), value.GetStringLength());
+            if (!parse_uuid(text, uuid)) {
+                PSP_COMPLAIN_AND_ABORT(
+                    "Expected UUID, found `" + std::string(text) + "`"
+                );
+            }
+
+            col->set_nth<t_uuid>(i, uuid);
+            return std::nullopt;
+        }
+        case t_dtype::DTYPE_IPADDR: {
+            t_ipaddr addr;
+            if (!value.IsString()) {
+                std::stringstream ss;
+                ss << "Expected IP address, found " << value.GetType();
+                PSP_COMPLAIN_AND_ABORT(ss.str());
+            }
+
+            std::string_view text(value.GetString(), value.GetStringLength());
+            if (!parse_ipaddr(text, addr)) {
+                PSP_COMPLAIN_AND_ABORT(
+                    "Expected IP address, found `" + std::string(text) + "`"
+                );
+            }
+
+            col->set_nth<t_ipaddr>(i, addr);
+            return std::nullopt;
+        }
         default:
             PSP_COMPLAIN_AND_ABORT("JSON field not yet implemented");
             return std::nullopt;
     }
 }
 
+// Replaces nested objects in `src` with members named by their dot path,
+// e.g. `{"order": {"price": 1}}` becomes `{"order.price": 1}`. Objects bound
+// for a `DTYPE_JSON` column of `schema` are kept whole.
+static void
+flatten_json_object(
+    const std::string& prefix,
+    rapidjson::Value& src,
+    rapidjson::Value& dst,
+    rapidjson::Document::AllocatorType& allocator,
+    const t_schema* schema
+) {
+    for (auto& member : src.GetObject()) {
+        std::string name = prefix + member.name.GetString();
+        bool is_json_column = schema != nullptr && schema->has_column(name)
+            && schema->get_dtype(name) == DTYPE_JSON;
+
+        if (member.value.IsObject() && !is_json_column) {
+            flatten_json_object(
+                name + ".", member.value, dst, allocator, schema
+            );
+        } else {
+            dst.AddMember(
+                rapidjson::Value(name.c_str(), allocator),
+                member.value,
+                allocator
+            );
+        }
+    }
+}
+
+static void
+flatten_json_structs(
+    rapidjson::Value& obj,
+    rapidjson::Document& document,
+    const t_schema* schema = nullptr
+) {
+    if (!obj.IsObject()) {
+        return;
+    }
+
+    bool has_struct = false;
+    for (const auto& member : obj.GetObject()) {
+        has_struct = has_struct || member.value.IsObject();
+    }
+
+    if (has_struct) {
+        rapidjson::Value flat(rapidjson::kObjectType);
+        flatten_json_object("", obj, flat, document.GetAllocator(), schema);
+        obj = flat;
+    }
+}
+
 void
 Table::remove_rows(const std::string_view& data) {
     // 1.) Infer schema
@@ -902,6 +1388,260 @@ Table::remove_cols(const std::string_view& data) {
     m_pool->send(get_gnode()->get_id(), 0, data_table);
 }
 
+t_uindex
+Table::remove_where(const std::vector<t_fterm>& fterms) {
+    PSP_VERBOSE_ASSERT(m_init, "touching uninited object");
+    if (m_index.empty()) {
+        PSP_COMPLAIN_AND_ABORT("Cannot remove from unindexed Table\n")
+    }
+
+    const auto* master = m_gnode->get_table();
+    for (const auto& fterm : fterms) {
+        if (!master->get_schema().has_column(fterm.m_colname)) {
+            PSP_COMPLAIN_AND_ABORT(
+                "Filter column not in schema: " + fterm.m_colname
+            );
+        }
+    }
+
+    auto mask = master->filter_cpp(FILTER_OP_AND, fterms);
+    std::vector<t_tscalar> pkeys;
+    for (const auto& [pkey, ridx] : m_gnode->get_pkey_map()) {
+        if (mask.get(ridx)) {
+            pkeys.push_back(pkey);
+        }
+    }
+
+    remove_pkeys(pkeys);
+    return pkeys.size();
+}
+
+void
+Table::remove_all() {
+    PSP_VERBOSE_ASSERT(m_init, "touching uninited object");
+    std::vector<t_tscalar> pkeys;
+    for (const auto& [pkey, _] : m_gnode->get_pkey_map()) {
+        pkeys.push_back(pkey);
+    }
+
+    remove_pkeys(pkeys);
+}
+
+void
+Table::remove_pkeys(const std::vector<t_tscalar>& pkeys) {
+    if (pkeys.empty()) {
+        return;
+    }
+
+    auto dtype = pkeys.front().get_dtype();
+    t_data_table data_table(t_schema{});
+    data_table.init();
+    data_table.extend(pkeys.size());
+    data_table.add_column("psp_pkey", dtype, true);
+
+    auto psp_pkey_col = data_table.get_column("psp_pkey");
+    for (t_uindex ii = 0; ii < pkeys.size(); ++ii) {
+        psp_pkey_col->set_scalar(ii, pkeys[ii]);
+    }
+
+    if (!m_index.empty()) {
+        data_table.clone_column("psp_pkey", m_index);
+    }
+
+    data_table.clone_column("psp_pkey", "psp_okey");
+    process_op_column(data_table, OP_DELETE);
+    m_pool->send(get_gnode()->get_id(), 0, data_table);
+}
+
+// Lists are stored as JSON text; parse the list in row `ridx` of `col` into
+// `doc`, returning whether it is a (non-null) list.
+static bool
+parse_list_cell(const t_column& col, t_uindex ridx, rapidjson::Document& doc) {
+    auto list = col.get_scalar(ridx);
+    if (!list.is_valid()) {
+        return false;
+    }
+
+    doc.Parse(list.to_string().c_str());
+    return !doc.HasParseError() && doc.IsArray();
+}
+
+// The type of the column the elements of the list column `col` unnest into,
+// from the elements of the rows in `mapping`.
+static t_dtype
+unnest_dtype(const t_column& col, const t_gstate::t_mapping& mapping) {
+    bool is_empty = true;
+    bool is_bool = true;
+    bool is_int = true;
+    bool is_number = true;
+    rapidjson::Document doc;
+    for (const auto& [_, ridx] : mapping) {
+        if (!parse_list_cell(col, ridx, doc)) {
+            continue;
+        }
+
+        for (const auto& elem : doc.GetArray()) {
+            if (elem.IsNull()) {
+                continue;
+            }
+
+            is_empty = false;
+            is_bool = is_bool && elem.IsBool();
+            is_int = is_int && elem.IsInt64();
+            is_number = is_number && elem.IsNumber();
+        }
+    }
+
+    if (is_empty) {
+        return DTYPE_STR;
+    }
+
+    if (is_bool) {
+        return DTYPE_BOOL;
+    }
+
+    if (is_int) {
+        return DTYPE_INT64;
+    }
+
+    return is_number ? DTYPE_FLOAT64 : DTYPE_STR;
+}
+
+// Write the list element `elem` (or null, if it is `nullptr`) to row `idx`
+// of `col`, a column of a type `unnest_dtype` returns.
+static void
+set_unnest_element(
+    t_column& col, t_uindex idx, const rapidjson::Value* elem
+) {
+    if (elem == nullptr || elem->IsNull()) {
+        col.clear(idx);
+        return;
+    }
+
+    switch (col.get_dtype()) {
+        case DTYPE_BOOL: {
+            if (elem->IsBool()) {
+                col.set_nth<bool>(idx, elem->GetBool());
+                return;
+            }
+        } break;
+        case DTYPE_INT64: {
+            if (elem->IsInt64()) {
+                col.set_nth<std::int64_t>(idx, elem->GetInt64());
+                return;
+            }
+        } break;
+        case DTYPE_FLOAT64: {
+            if (elem->IsNumber()) {
+                col.set_nth<double>(idx, elem->GetDouble());
+                return;
+            }
+        } break;
+        case DTYPE_STR: {
+            if (elem->IsString()) {
+                col.set_nth(idx, std::string(elem->GetString()));
+            } else {
+                rapidjson::StringBuffer buffer;
+                rapidjson::Writer<rapidjson::StringBuffer> writer(buffer);
+                elem->Accept(writer);
+                col.set_nth(idx, std::string(buffer.GetString()));
+            }
+
+            return;
+        }
+        default:
+            break;
+    }
+
+    col.clear(idx);
+}
+
+void
+Table::replace_unnested(
+    const Table& source, const std::vector<std::string>& unnest
+) {
+    PSP_VERBOSE_ASSERT(m_init, "touching uninited object");
+    const auto* master = source.get_gnode()->get_table();
+    const auto& mapping = source.get_gnode()->get_pkey_map();
+
+    // Source rows in primary key order, as an unsorted view reads them.
+    std::vector<std::pair<t_tscalar, t_uindex>> rows(
+        mapping.begin(), mapping.end()
+    );
+
+    std::sort(rows.begin(), rows.end(), [](const auto& a, const auto& b) {
+        return a.first < b.first;
+    });
+
+    // Each source row unnests into a row per element of its longest list,
+    // or a single row if its lists are all empty or null.
+    rapidjson::Document doc;
+    std::vector<t_uindex> counts;
+    counts.reserve(rows.size());
+    t_uindex size = 0;
+    for (const auto& [_, ridx] : rows) {
+        t_uindex count = 1;
+        for (const auto& column : unnest) {
+            if (parse_list_cell(*master->get_const_column(column), ridx, doc)) {
+                count = std::max<t_uindex>(count, doc.Size());
+            }
+        }
+
+        counts.push_back(count);
+        size += count;
+    }
+
+    remove_all();
+    if (size == 0) {
+        return;
+    }
+
+    auto schema = get_schema();
+    t_data_table data_table(schema);
+    data_table.init();
+    data_table.extend(size);
+    data_table.add_column("psp_pkey", DTYPE_INT32, true);
+    const auto& psp_pkey_col = data_table.get_column("psp_pkey");
+    for (t_uindex ii = 0; ii < size; ++ii) {
+        psp_pkey_col->set_nth<std::uint32_t>(ii, (ii + m_offset) % m_limit);
+    }
+
+    for (const auto& column : schema.columns()) {
+        auto col = data_table.get_column(column);
+        auto src = master->get_const_column(column);
+        bool is_unnest =
+            std::find(unnest.begin(), unnest.end(), column) != unnest.end();
+
+        t_uindex idx = 0;
+        for (t_uindex row = 0; row < rows.size(); ++row) {
+            auto ridx = rows[row].second;
+            if (!is_unnest) {
+                auto value = src->get_scalar(ridx);
+                for (t_uindex ii = 0; ii < counts[row]; ++ii) {
+                    col->set_scalar(idx + ii, value);
+                }
+            } else {
+                bool is_list = parse_list_cell(*src, ridx, doc);
+                for (t_uindex ii = 0; ii < counts[row]; ++ii) {
+                    const rapidjson::Value* elem = nullptr;
+                    if (is_list && ii < doc.Size()) {
+                        elem = &doc[static_cast<rapidjson::SizeType>(ii)];
+                    }
+
+                    set_unnest_element(*col, idx + ii, elem);
+                }
+            }
+
+            idx += counts[row];
+        }
+    }
+
+    data_table.clone_column("psp_pkey", "psp_okey");
+    process_op_column(data_table, t_op::OP_INSERT);
+    calculate_offset(size);
+    m_pool->send(get_gnode()->get_id(), 0, data_table);
+}
+
 void
 Table::update_cols(const std::string_view& data, std::uint32_t port_id) {
     // 1.) Infer schema
@@ -914,6 +1654,8 @@ Table::update_cols(const std::string_view& data, std::uint32_t port_id) {
         )
     }
 
+    flatten_json_structs(document, document);
+
     t_uindex nrows = 0;
     for (const auto& it : document.GetObject()) {
         if (!it.value.IsArray()) {
@@ -991,12 +1733,16 @@ Table::update_cols(const std::string_view& data, std::uint32_t port_id) {
 
     process_op_column(data_table, t_op::OP_INSERT);
     calculate_offset(nrows);
+    update_sketches(data_table);
     m_pool->send(get_gnode()->get_id(), port_id, data_table);
 }
 
 std::shared_ptr<Table>
 Table::from_cols(
-    const std::string& index, const std::string_view& data, std::uint32_t limit
+    const std::string& index,
+    const std::string_view& data,
+    std::uint32_t limit,
+    const std::unordered_map<std::string, t_dtype>& schema_override
 ) {
     auto pool = std::make_shared<t_pool>();
     pool->init();
@@ -1004,6 +1750,7 @@ Table::from_cols(
     // 1.) Infer schema
     rapidjson::Document document;
     document.Parse(data.data());
+    flatten_json_structs(document, document);
 
     std::vector<std::string> column_names;
     std::vector<t_dtype> data_types;
@@ -1025,6 +1772,13 @@ Table::from_cols(
         }
 
         nrows = it.value.Size();
+        auto type_override = schema_override.find(it.name.GetString());
+        if (type_override != schema_override.end()) {
+            data_types.push_back(type_override->second);
+            column_names.emplace_back(it.name.GetString());
+            continue;
+        }
+
         bool found = false;
         for (const auto& column_value : it.value.GetArray()) {
             auto dtype = rapidjson_type_to_dtype(column_value);
@@ -1070,10 +1824,15 @@ Table::from_cols(
             << col_name << " dtype "
             << dtype_to_str(data_table.get_column(col_name)->get_dtype())
         );
+        const bool is_override = schema_override.count(col_name) > 0;
         for (const auto& cell : col.value.GetArray()) {
             auto col = data_table.get_column(col_name);
             auto promote = fill_column_json(col, ii, cell, false);
-            if (promote) {
+            if (promote && is_override) {
+                // Explicitly typed columns are never promoted, so coerce the
+                // value instead.
+                fill_column_json(col, ii, cell, true);
+            } else if (promote) {
                 LOG_DEBUG(
                     "Promoting column " << col_name << " from "
                                         << dtype_to_str(col->get_dtype())
@@ -1134,6 +1893,10 @@ Table::update_rows(const std::string_view& data, std::uint32_t port_id) {
     bool is_implicit = m_index.empty();
     t_schema table_schema = get_schema();
 
+    for (auto& row : document.GetArray()) {
+        flatten_json_structs(row, document, &table_schema);
+    }
+
     // 2.) Create table
     t_uindex size = document.Size();
     t_data_table data_table(table_schema);
@@ -1223,6 +1986,7 @@ Table::update_rows(const std::string_view& data, std::uint32_t port_id) {
     data_table.clone_column("psp_pkey", "psp_okey");
     process_op_column(data_table, t_op::OP_INSERT);
     calculate_offset(size);
+    update_sketches(data_table);
     m_pool->send(get_gnode()->get_id(), port_id, data_table);
 }
 
@@ -1248,6 +2012,10 @@ Table::from_rows(
         )
     }
 
+    for (auto& row : document.GetArray()) {
+        flatten_json_structs(row, document);
+    }
+
     std::vector<std::string> column_names;
     std::vector<t_dtype> data_types;
     bool is_implicit = true;
@@ -1404,6 +2172,29 @@ Table::update_arrow(const std::string_view& data, std::uint32_t port_id) {
         reinterpret_cast<const std::uint8_t*>(data.data()), data.size()
     );
 
+    update_arrow_loader(arrow_loader, port_id);
+}
+
+void
+Table::update_arrow_stream(ArrowArrayStream* stream, std::uint32_t port_id) {
+    apachearrow::ArrowLoader arrow_loader;
+    arrow_loader.initialize(stream);
+    update_arrow_loader(arrow_loader, port_id);
+}
+
+void
+Table::update_arrow_table(
+    std::shared_ptr<arrow::Table> table, std::uint32_t port_id
+) {
+    apachearrow::ArrowLoader arrow_loader;
+    arrow_loader.initialize(std::move(table));
+    update_arrow_loader(arrow_loader, port_id);
+}
+
+void
+Table::update_arrow_loader(
+    apachearrow::ArrowLoader& arrow_loader, std::uint32_t port_id
+) {
     t_data_table data_table{this->get_schema()};
     data_table.init();
     auto row_count = arrow_loader.row_count();
@@ -1428,7 +2219,21 @@ Table::update_arrow(const std::string_view& data, std::uint32_t port_id) {
 
     process_op_column(data_table, t_op::OP_INSERT);
     calculate_offset(row_count);
+    update_sketches(data_table);
     m_pool->send(get_gnode()->get_id(), port_id, data_table);
+
+    // Values new to an ordered dictionary rank after the known categories.
+    for (const auto& [column, categories] :
+         arrow_loader.ordered_dictionaries()) {
+        auto iter = m_categories.find(column);
+        if (iter == m_categories.end()) {
+            continue;
+        }
+
+        auto merged = iter->second;
+        merged.insert(merged.end(), categories.begin(), categories.end());
+        set_categories(column, merged);
+    }
 }
 
 std::shared_ptr<Table>
@@ -1442,6 +2247,58 @@ Table::from_arrow(
         reinterpret_cast<const std::uint8_t*>(data.data()), data.size()
     );
 
+    return from_arrow_loader(index, arrow_loader, limit);
+}
+
+std::shared_ptr<Table>
+Table::from_arrow_stream(
+    const std::string& index, ArrowArrayStream* stream, std::uint32_t limit
+) {
+    apachearrow::ArrowLoader arrow_loader;
+    arrow_loader.initialize(stream);
+    return from_arrow_loader(index, arrow_loader, limit);
+}
+
+std::shared_ptr<Table>
+Table::from_unnested(
+    const Table& source, const std::vector<std::string>& unnest
+) {
+    auto schema = source.get_schema();
+    const auto* master = source.get_gnode()->get_table();
+    const auto& mapping = source.get_gnode()->get_pkey_map();
+    auto types = schema.types();
+    for (const auto& column : unnest) {
+        if (!schema.has_column(column)) {
+            PSP_COMPLAIN_AND_ABORT("Unnest column not in schema: " + column);
+        }
+
+        if (schema.get_dtype(column) != DTYPE_LIST) {
+            PSP_COMPLAIN_AND_ABORT(
+                "Cannot unnest column `" + column + "` of type "
+                + dtype_to_str(schema.get_dtype(column))
+            );
+        }
+
+        if (std::count(unnest.begin(), unnest.end(), column) > 1) {
+            PSP_COMPLAIN_AND_ABORT("Duplicate unnest column: " + column);
+        }
+
+        types[schema.get_colidx(column)] =
+            unnest_dtype(*master->get_const_column(column), mapping);
+    }
+
+    auto out = from_schema("", t_schema{schema.columns(), types});
+    out->replace_unnested(source, unnest);
+    out->get_pool()->_process();
+    return out;
+}
+
+std::shared_ptr<Table>
+Table::from_arrow_loader(
+    const std::string& index,
+    apachearrow::ArrowLoader& arrow_loader,
+    std::uint32_t limit
+) {
     // Infer schema
     auto columns = arrow_loader.names();
     auto types = arrow_loader.types();
@@ -1472,6 +2329,11 @@ Table::from_arrow(
     pool->init();
     auto table = std::make_shared<Table>(pool, columns, types, limit, index);
     table->init(data_table, data_table.num_rows(), t_op::OP_INSERT, 0);
+    for (const auto& [column, categories] :
+         arrow_loader.ordered_dictionaries()) {
+        table->set_categories(column, categories);
+    }
+
     pool->_process();
     return table;
 }
@@ -13,6 +13,12 @@
 #include <perspective/first.h>
 #include <perspective/time.h>
 #include <perspective/utils.h>
+#include <cctype>
+#include <cstdlib>
+#include <cstring>
+#ifdef PSP_ENABLE_TZDB
+#include <date/tz.h>
+#endif
 
 namespace perspective {
 
@@ -26,6 +32,119 @@ t_tdelta::operator*=(std::int64_t multiplier) {
     return *this;
 }
 
+std::int64_t
+t_tdelta::raw_value() const {
+    return v;
+}
+
+std::string
+t_tdelta::str() const {
+    std::uint64_t ms = v < 0 ? -static_cast<std::uint64_t>(v)
+                             : static_cast<std::uint64_t>(v);
+
+    std::uint64_t days = ms / (SECS_PER_DAY * 1000ULL);
+    ms -= days * SECS_PER_DAY * 1000ULL;
+    std::uint64_t hours = ms / (SECS_PER_HOUR * 1000ULL);
+    ms -= hours * SECS_PER_HOUR * 1000ULL;
+    std::uint64_t minutes = ms / 60000;
+    ms -= minutes * 60000;
+    std::uint64_t seconds = ms / 1000;
+    ms -= seconds * 1000;
+
+    std::stringstream ss;
+    if (v < 0) {
+        ss << "-";
+    }
+
+    if (days > 0) {
+        ss << days << "d ";
+    }
+
+    ss << std::setfill('0') << std::setw(2) << hours << ":" << std::setw(2)
+       << minutes << ":" << std::setw(2) << seconds;
+
+    if (ms > 0) {
+        ss << "." << std::setw(3) << ms;
+    }
+
+    return ss.str();
+}
+
+bool
+parse_duration(const char* str, t_tdelta& out) {
+    char* endptr;
+    std::int64_t ms = std::strtoll(str, &endptr, 10);
+    if (endptr != str && *endptr == '\0') {
+        out = t_tdelta(ms);
+        return true;
+    }
+
+    const char* cursor = str;
+    bool negative = *cursor == '-';
+    if (negative) {
+        ++cursor;
+    }
+
+    std::uint64_t days = 0;
+    std::uint64_t hours;
+    std::uint64_t minutes;
+    std::uint64_t seconds;
+    std::uint64_t millis = 0;
+    const char* day_sep = std::strchr(cursor, 'd');
+    if (day_sep != nullptr) {
+        days = std::strtoull(cursor, &endptr, 10);
+        if (endptr != day_sep) {
+            return false;
+        }
+
+        cursor = day_sep + 1;
+        while (*cursor == ' ') {
+            ++cursor;
+        }
+    }
+
+    hours = std::strtoull(cursor, &endptr, 10);
+    if (endptr == cursor || *endptr != ':') {
+        return false;
+    }
+
+    cursor = endptr + 1;
+    minutes = std::strtoull(cursor, &endptr, 10);
+    if (endptr == cursor || *endptr != ':') {
+        return false;
+    }
+
+    cursor = endptr + 1;
+    seconds = std::strtoull(cursor, &endptr, 10);
+    if (endptr == cursor) {
+        return false;
+    }
+
+    if (*endptr == '.') {
+        // Fractional seconds are truncated to millisecond precision.
+        cursor = endptr + 1;
+        std::uint64_t scale = 100;
+        while (*cursor >= '0' && *cursor <= '9') {
+            millis += (*cursor - '0') * scale;
+            scale /= 10;
+            ++cursor;
+        }
+
+        endptr = const_cast<char*>(cursor);
+    }
+
+    if (*endptr != '\0') {
+        return false;
+    }
+
+    std::int64_t total = static_cast<std::int64_t>(
+        ((days * 24 + hours) * 60 + minutes) * 60000 + seconds * 1000 + millis
+    );
+
+    out = t_tdelta(negative ? -total : total);
+    return true;
+}
+
 t_time::t_time() : m_storage(0) {}
 
 t_time::t_time(std::int64_t raw_val) : m_storage(raw_val) {}
@@ -253,6 +372,325 @@ operator-(const t_time& a, const t_time& b) {
     return {a.m_storage - b.m_storage};
 }
 
+namespace {
+    std::int64_t
+    floor_div(std::int64_t a, std::int64_t b) {
+        return a / b - static_cast<std::int64_t>(a % b < 0);
+    }
+
+    bool
+    parse_int(const char*& str, std::int32_t& out) {
+        if (std::isdigit(static_cast<unsigned char>(*str)) == 0) {
+            return false;
+        }
+
+        out = 0;
+        while (std::isdigit(static_cast<unsigned char>(*str)) != 0) {
+            out = out * 10 + (*str - '0');
+            ++str;
+        }
+
+        return true;
+    }
+
+    // `[+-]hh[:mm[:ss]]`, in seconds, with `+` meaning the same thing as an
+    // unsigned value.
+    bool
+    parse_hms(const char*& str, std::int32_t& out) {
+        std::int32_t sign = 1;
+        if (*str == '+' || *str == '-') {
+            sign = *str == '-' ? -1 : 1;
+            ++str;
+        }
+
+        std::int32_t hours;
+        std::int32_t minutes = 0;
+        std::int32_t seconds = 0;
+        if (!parse_int(str, hours)) {
+            return false;
+        }
+
+        if (*str == ':') {
+            ++str;
+            if (!parse_int(str, minutes)) {
+                return false;
+            }
+
+            if (*str == ':') {
+                ++str;
+                if (!parse_int(str, seconds)) {
+                    return false;
+                }
+            }
+        }
+
+        out = sign * (hours * SECS_PER_HOUR + minutes * 60 + seconds);
+        return true;
+    }
+
+    bool
+    parse_tz_name(const char*& str) {
+        if (*str == '<') {
+            const char* end = std::strchr(str, '>');
+            if (end == nullptr) {
+                return false;
+            }
+
+            str = end + 1;
+            return true;
+        }
+
+        const char* start = str;
+        while (std::isalpha(static_cast<unsigned char>(*str)) != 0) {
+            ++str;
+        }
+
+        return str - start >= 3;
+    }
+
+    std::int32_t
+    year_of(std::int64_t secs) {
+        struct tm t;
+        t_time().gmtime(t, secs, 0);
+        return t.tm_year + 1900;
+    }
+} // namespace
+
+t_tz::t_tz() :
+    m_zone(nullptr),
+    m_std_offset(0),
+    m_dst_offset(0),
+    m_has_dst(false),
+    m_start(),
+    m_end() {}
+
+bool
+t_tz::parse(const std::string& spec, t_tz& out) {
+    t_tz tz;
+    if (spec == "UTC" || spec == "GMT" || spec == "Z") {
+        out = tz;
+        return true;
+    }
+
+#ifdef PSP_ENABLE_TZDB
+    if (spec.find('/') != std::string::npos) {
+        try {
+            tz.m_zone = date::locate_zone(spec);
+        } catch (const std::exception&) {
+            return false;
+        }
+
+        out = tz;
+        return true;
+    }
+#endif
+
+    const char* rule = spec.c_str();
+
+    // A bare `+05:30`-style offset is east of UTC, as in ISO 8601.
+    if (*rule == '+' || *rule == '-') {
+        const char* str = rule;
+        std::int32_t offset;
+        if (!parse_hms(str, offset) || *str != '\0') {
+            return false;
+        }
+
+        tz.m_std_offset = offset;
+        tz.m_dst_offset = offset;
+        out = tz;
+        return true;
+    }
+
+    // POSIX offsets are west of UTC, so the sign is flipped.
+    const char* str = rule;
+    std::int32_t offset;
+    if (!parse_tz_name(str) || !parse_hms(str, offset)) {
+        return false;
+    }
+
+    tz.m_std_offset = -offset;
+    tz.m_dst_offset = tz.m_std_offset;
+    if (*str == '\0') {
+        out = tz;
+        return true;
+    }
+
+    if (!parse_tz_name(str)) {
+        return false;
+    }
+
+    tz.m_has_dst = true;
+    tz.m_dst_offset = tz.m_std_offset + SECS_PER_HOUR;
+    if (*str != ',' && *str != '\0') {
+        if (!parse_hms(str, offset)) {
+            return false;
+        }
+
+        tz.m_dst_offset = -offset;
+    }
+
+    if (*str == '\0') {
+        // POSIX leaves the default implementation-defined; use the current
+        // US rules, as glibc does.
+        const char* us_rules = ",M3.2.0,M11.1.0";
+        str = us_rules;
+    }
+
+    if (*str++ != ',' || !parse_rule(str, tz.m_start) || *str++ != ','
+        || !parse_rule(str, tz.m_end) || *str != '\0') {
+        return false;
+    }
+
+    out = tz;
+    return true;
+}
+
+bool
+t_tz::parse_rule(const char*& str, t_rule& out) {
+    out = t_rule{'N', 0, 0, 0, 2 * SECS_PER_HOUR};
+    if (*str == 'M') {
+        ++str;
+        out.m_kind = 'M';
+        if (!parse_int(str, out.m_month) || *str++ != '.'
+            || !parse_int(str, out.m_week) || *str++ != '.'
+            || !parse_int(str, out.m_day)) {
+            return false;
+        }
+
+        if (out.m_month < 1 || out.m_month > 12 || out.m_week < 1
+            || out.m_week > 5 || out.m_day > 6) {
+            return false;
+        }
+    } else {
+        if (*str == 'J') {
+            ++str;
+            out.m_kind = 'J';
+        }
+
+        if (!parse_int(str, out.m_day) || out.m_day > 365) {
+            return false;
+        }
+    }
+
+    if (*str == '/') {
+        ++str;
+        return parse_hms(str, out.m_time);
+    }
+
+    return true;
+}
+
+std::int64_t
+t_tz::rule_to_local(const t_rule& rule, std::int32_t year) {
+    std::int64_t jan1 = to_gmtime(year, 1, 1, 0, 0, 0) / SECS_PER_DAY;
+    std::int64_t day;
+    switch (rule.m_kind) {
+        case 'M': {
+            std::int64_t first =
+                jan1 + days_before_month(year, rule.m_month);
+
+            // January 1, 1970 was a Thursday.
+            std::int64_t wday = ((4 + first) % 7 + 7) % 7;
+            day = first + (rule.m_day - wday + 7) % 7 + (rule.m_week - 1) * 7;
+
+            // Week 5 means the last such weekday of the month.
+            std::int32_t month_len = rule.m_month == 12
+                ? 31
+                : days_before_month(year, rule.m_month + 1)
+                    - days_before_month(year, rule.m_month);
+
+            while (day >= first + month_len) {
+                day -= 7;
+            }
+        } break;
+        case 'J': {
+            day = jan1 + rule.m_day - 1
+                + static_cast<std::int64_t>(
+                      isleap(year) != 0 && rule.m_day >= 60
+                );
+        } break;
+        default: {
+            day = jan1 + rule.m_day;
+        } break;
+    }
+
+    return day * SECS_PER_DAY + rule.m_time;
+}
+
+bool
+t_tz::is_dst(std::int64_t utc_ms) const {
+    if (!m_has_dst) {
+        return false;
+    }
+
+    std::int64_t secs = floor_div(utc_ms, 1000);
+    std::int32_t year = year_of(secs + m_std_offset);
+    std::int64_t start = rule_to_local(m_start, year) - m_std_offset;
+    std::int64_t end = rule_to_local(m_end, year) - m_dst_offset;
+
+    // Southern hemisphere rules start DST late in the year and end it early
+    // in the next.
+    if (start < end) {
+        return secs >= start && secs < end;
+    }
+
+    return secs < end || secs >= start;
+}
+
+std::int64_t
+t_tz::utc_offset(std::int64_t utc_ms) const {
+#ifdef PSP_ENABLE_TZDB
+    if (m_zone != nullptr) {
+        auto info = m_zone->get_info(
+            date::sys_seconds{std::chrono::seconds{floor_div(utc_ms, 1000)}}
+        );
+
+        return std::chrono::duration_cast<std::chrono::milliseconds>(
+                   info.offset
+        )
+            .count();
+    }
+#endif
+
+    return static_cast<std::int64_t>(
+               is_dst(utc_ms) ? m_dst_offset : m_std_offset
+           )
+        * 1000;
+}
+
+std::int64_t
+t_tz::to_local(std::int64_t utc_ms) const {
+    return utc_ms + utc_offset(utc_ms);
+}
+
+std::int64_t
+t_tz::to_utc(std::int64_t local_ms) const {
+#ifdef PSP_ENABLE_TZDB
+    if (m_zone != nullptr) {
+        // For ambiguous and skipped wall times, `first` is the offset in
+        // effect before the transition.
+        auto info = m_zone->get_info(date::local_seconds{
+            std::chrono::seconds{floor_div(local_ms, 1000)}
+        });
+
+        return local_ms
+            - std::chrono::duration_cast<std::chrono::milliseconds>(
+                  info.first.offset
+            )
+                  .count();
+    }
+#endif
+
+    std::int64_t dst_candidate =
+        local_ms - static_cast<std::int64_t>(m_dst_offset) * 1000;
+
+    if (is_dst(dst_candidate)) {
+        return dst_candidate;
+    }
+
+    return local_ms - static_cast<std::int64_t>(m_std_offset) * 1000;
+}
+
 } // end namespace perspective
 
 namespace std {
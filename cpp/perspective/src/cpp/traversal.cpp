@@ -295,7 +295,7 @@ t_traversal::update_sucessors(t_index nidx, t_index n_changed) {
         t_index p_nchild = p_node.m_nchild;
         t_index coffset = 1;
 
-        for (int i = 0; i < p_nchild; i++) {
+        for (t_index i = 0; i < p_nchild; i++) {
             t_index curr_cidx = pidx + coffset;
             t_tvnode& child_node = (*m_nodes)[curr_cidx];
             if (curr_cidx > nidx) {
@@ -488,7 +488,7 @@ t_traversal::get_child_indices(
     t_index nchild = tvnode.m_nchild;
     t_index coffset = 1;
 
-    for (int i = 0; i < nchild; i++) {
+    for (t_index i = 0; i < nchild; i++) {
         t_index curr_cidx = nidx + coffset;
         const t_tvnode& child_node = (*m_nodes)[curr_cidx];
         out_data.emplace_back(curr_cidx, child_node.m_tnid);
@@ -585,7 +585,7 @@ t_traversal::get_flattened_tree(t_index idx, t_depth stop_depth) const {
             rnode.m_depth = c_node.m_depth;
             t_index curr_cidx = hidx + 1;
             std::vector<t_index> children(nchild);
-            for (int cidx = 0; cidx < nchild; cidx++) {
+            for (t_index cidx = 0; cidx < nchild; cidx++) {
                 const t_tvnode& child_node = (*m_nodes)[curr_cidx];
                 children[cidx] = curr_cidx;
                 if (child_node.m_expanded) {
@@ -253,6 +253,104 @@ ctx_get_path(
     return rval;
 }
 
+std::vector<t_index>
+ctx_get_row_order(
+    const t_traversal& traversal, t_totals grand_total, t_totals subtotals
+) {
+    auto nrows = t_index(traversal.size());
+    std::vector<t_index> rval;
+    rval.reserve(nrows);
+    if (nrows == 0) {
+        return rval;
+    }
+
+    if (grand_total == TOTALS_BEFORE) {
+        rval.push_back(0);
+    }
+
+    // Subtotals placed after their group wait here until a row at or above
+    // their depth closes the group.
+    std::vector<t_index> pending;
+    for (t_index idx = 1; idx < nrows; ++idx) {
+        auto depth = traversal.get_depth(idx);
+        while (!pending.empty()
+               && traversal.get_depth(pending.back()) >= depth) {
+            rval.push_back(pending.back());
+            pending.pop_back();
+        }
+
+        bool is_subtotal =
+            idx + 1 < nrows && traversal.get_depth(idx + 1) > depth;
+        if (!is_subtotal || subtotals == TOTALS_BEFORE) {
+            rval.push_back(idx);
+        } else if (subtotals == TOTALS_AFTER) {
+            pending.push_back(idx);
+        }
+    }
+
+    while (!pending.empty()) {
+        rval.push_back(pending.back());
+        pending.pop_back();
+    }
+
+    if (grand_total == TOTALS_AFTER) {
+        rval.push_back(0);
+    }
+
+    return rval;
+}
+
+t_tscalar
+ctx_show_value_as(
+    t_show_values_as show_values_as,
+    const t_tscalar& value,
+    const t_tscalar& total,
+    const std::vector<t_tscalar>& previous
+) {
+    auto is_number = [](const t_tscalar& scalar) {
+        return scalar.is_valid() && scalar.is_numeric();
+    };
+
+    if (show_values_as != SHOW_VALUES_AS_VALUE && !is_number(value)) {
+        return mknone();
+    }
+
+    switch (show_values_as) {
+        case SHOW_VALUES_AS_PCT_ROW_TOTAL:
+        case SHOW_VALUES_AS_PCT_COLUMN_TOTAL:
+        case SHOW_VALUES_AS_PCT_GRAND_TOTAL: {
+            if (!is_number(total) || total.to_double() == 0) {
+                return mknone();
+            }
+
+            return mktscalar<double>(
+                100.0 * (value.to_double() / total.to_double())
+            );
+        }
+        case SHOW_VALUES_AS_DIFFERENCE_FROM_PREVIOUS: {
+            // The first column has nothing to differ from.
+            if (previous.empty() || !is_number(previous.back())) {
+                return mknone();
+            }
+
+            return value.difference(previous.back());
+        }
+        case SHOW_VALUES_AS_RUNNING_TOTAL: {
+            t_tscalar rval = value;
+            for (const auto& prev : previous) {
+                if (is_number(prev)) {
+                    rval = rval.add(prev);
+                }
+            }
+
+            return rval;
+        }
+        case SHOW_VALUES_AS_VALUE:
+        default:
+            return value;
+    }
+}
+
 std::vector<t_ftreenode>
 ctx_get_flattened_tree(
     t_index idx,
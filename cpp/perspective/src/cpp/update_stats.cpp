@@ -0,0 +1,110 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+#include <perspective/update_stats.h>
+#include <algorithm>
+
+namespace perspective {
+
+t_update_stats::t_update_stats() :
+    m_num_batches(0),
+    m_batch_sizes(PSP_UPDATE_STATS_BUCKETS, 0) {}
+
+void
+t_update_stats::record(const t_update_counts& counts) {
+    auto size = counts.size();
+    if (size == 0) {
+        return;
+    }
+
+    m_total.m_added += counts.m_added;
+    m_total.m_updated += counts.m_updated;
+    m_total.m_removed += counts.m_removed;
+    ++m_num_batches;
+    auto wall_clock = std::chrono::system_clock::now().time_since_epoch();
+    m_last_update =
+        std::chrono::duration_cast<std::chrono::milliseconds>(wall_clock)
+            .count();
+
+    t_uindex bucket = 0;
+    while (size > 1 && bucket + 1 < PSP_UPDATE_STATS_BUCKETS) {
+        size >>= 1;
+        ++bucket;
+    }
+
+    ++m_batch_sizes[bucket];
+
+    auto now = t_clock::now();
+    expire(now);
+    if (m_recent.empty()
+        || now - m_recent.back().m_time >= std::chrono::seconds(1)) {
+        m_recent.push_back({now, {}, 0});
+    }
+
+    auto& recent = m_recent.back();
+    recent.m_counts.m_added += counts.m_added;
+    recent.m_counts.m_updated += counts.m_updated;
+    recent.m_counts.m_removed += counts.m_removed;
+    ++recent.m_num_batches;
+}
+
+const t_update_counts&
+t_update_stats::get_total() const {
+    return m_total;
+}
+
+t_uindex
+t_update_stats::get_num_batches() const {
+    return m_num_batches;
+}
+
+std::pair<t_update_counts, t_uindex>
+t_update_stats::get_recent(std::int64_t interval_ms) const {
+    auto interval = std::min(interval_ms, PSP_UPDATE_STATS_MAX_INTERVAL_MS);
+    auto since = t_clock::now() - std::chrono::milliseconds(interval);
+
+    t_update_counts counts;
+    t_uindex num_batches = 0;
+    for (auto it = m_recent.rbegin(); it != m_recent.rend(); ++it) {
+        if (it->m_time < since) {
+            break;
+        }
+
+        counts.m_added += it->m_counts.m_added;
+        counts.m_updated += it->m_counts.m_updated;
+        counts.m_removed += it->m_counts.m_removed;
+        num_batches += it->m_num_batches;
+    }
+
+    return {counts, num_batches};
+}
+
+std::optional<std::int64_t>
+t_update_stats::get_last_update() const {
+    return m_last_update;
+}
+
+const std::vector<t_uindex>&
+t_update_stats::get_batch_sizes() const {
+    return m_batch_sizes;
+}
+
+void
+t_update_stats::expire(t_clock::time_point now) {
+    auto since =
+        now - std::chrono::milliseconds(PSP_UPDATE_STATS_MAX_INTERVAL_MS);
+    while (!m_recent.empty() && m_recent.front().m_time < since) {
+        m_recent.pop_front();
+    }
+}
+
+} // namespace perspective
@@ -0,0 +1,95 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+#include <perspective/uuid.h>
+
+namespace perspective {
+
+static constexpr char HEX_DIGITS[] = "0123456789abcdef";
+
+static int
+hex_value(char c) {
+    if (c >= '0' && c <= '9') {
+        return c - '0';
+    }
+
+    if (c >= 'a' && c <= 'f') {
+        return c - 'a' + 10;
+    }
+
+    if (c >= 'A' && c <= 'F') {
+        return c - 'A' + 10;
+    }
+
+    return -1;
+}
+
+std::string
+t_uuid::str() const {
+    std::string rval;
+    rval.reserve(36);
+    for (int i = 0; i < 16; ++i) {
+        if (i == 4 || i == 6 || i == 8 || i == 10) {
+            rval.push_back('-');
+        }
+
+        rval.push_back(HEX_DIGITS[m_bytes[i] >> 4]);
+        rval.push_back(HEX_DIGITS[m_bytes[i] & 15]);
+    }
+
+    return rval;
+}
+
+bool
+parse_uuid(std::string_view str, t_uuid& out) {
+    if (str.size() > 9 && str.substr(0, 9) == "urn:uuid:") {
+        str.remove_prefix(9);
+    } else if (str.size() > 2 && str.front() == '{' && str.back() == '}') {
+        str = str.substr(1, str.size() - 2);
+    }
+
+    bool hyphenated = str.size() == 36;
+    if (!hyphenated && str.size() != 32) {
+        return false;
+    }
+
+    t_uuid rval;
+    std::size_t pos = 0;
+    for (int i = 0; i < 16; ++i) {
+        if (hyphenated && (i == 4 || i == 6 || i == 8 || i == 10)) {
+            if (str[pos++] != '-') {
+                return false;
+            }
+        }
+
+        int hi = hex_value(str[pos++]);
+        int lo = hex_value(str[pos++]);
+        if (hi < 0 || lo < 0) {
+            return false;
+        }
+
+        rval.m_bytes[i] = static_cast<std::uint8_t>((hi << 4) | lo);
+    }
+
+    out = rval;
+    return true;
+}
+
+} // end namespace perspective
+
+namespace std {
+std::ostream&
+operator<<(std::ostream& os, const perspective::t_uuid& uuid) {
+    os << uuid.str();
+    return os;
+}
+} // namespace std
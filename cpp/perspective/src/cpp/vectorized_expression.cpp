@@ -0,0 +1,539 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+#include <perspective/vectorized_expression.h>
+#include <perspective/engine_config.h>
+#include <algorithm>
+#include <cctype>
+#include <cstdlib>
+#include <cstring>
+#include <functional>
+
+namespace perspective {
+
+// Types ExprTk's arithmetic accepts, converting each value with
+// `t_tscalar::to_double()`.
+static bool
+is_arithmetic_type(t_dtype dtype) {
+    return is_numeric_type(dtype) && dtype != DTYPE_DURATION;
+}
+
+// Types whose every value is exactly a double, so that comparing them as
+// doubles matches ExprTk comparing them in their own type.
+static bool
+is_exact_type(t_dtype dtype) {
+    switch (dtype) {
+        case DTYPE_FLOAT64:
+        case DTYPE_FLOAT32:
+        case DTYPE_INT32:
+        case DTYPE_INT16:
+        case DTYPE_INT8:
+        case DTYPE_UINT32:
+        case DTYPE_UINT16:
+        case DTYPE_UINT8:
+            return true;
+        default:
+            return false;
+    }
+}
+
+/**
+ * @brief A recursive descent parser over the grammar:
+ *
+ * expression := sum [comparison sum]
+ * sum        := term (('+' | '-') term)*
+ * term       := unary (('*' | '/') unary)*
+ * unary      := '-' unary | primary
+ * primary    := number | column | '(' sum ')'
+ *
+ * Each method returns false on input outside the grammar.
+ */
+class t_vectorized_expression::t_parser {
+public:
+    t_parser(
+        const std::string& expression,
+        const std::vector<std::pair<std::string, std::string>>& column_ids,
+        const t_schema& schema,
+        std::vector<t_node>& nodes
+    ) :
+        m_expression(expression),
+        m_column_ids(column_ids),
+        m_schema(schema),
+        m_nodes(nodes),
+        m_idx(0) {}
+
+    bool
+    parse_sum(t_uindex& out) {
+        if (!parse_term(out)) {
+            return false;
+        }
+
+        while (peek() == '+' || peek() == '-') {
+            t_node_type type = next() == '+' ? NODE_ADD : NODE_SUBTRACT;
+            t_uindex rhs;
+            if (!parse_term(rhs)) {
+                return false;
+            }
+
+            out = push_binary(type, out, rhs);
+        }
+
+        return true;
+    }
+
+    bool
+    parse_comparison(t_comparison& out) {
+        static const std::vector<std::pair<std::string, t_comparison>>
+            OPERATORS = {
+                {"<=", COMPARISON_LTE},
+                {">=", COMPARISON_GTE},
+                {"==", COMPARISON_EQ},
+                {"!=", COMPARISON_NE},
+                {"<>", COMPARISON_NE},
+                {"<", COMPARISON_LT},
+                {">", COMPARISON_GT},
+                {"=", COMPARISON_EQ}
+            };
+
+        peek();
+        for (const auto& [token, comparison] : OPERATORS) {
+            if (m_expression.compare(m_idx, token.size(), token) == 0) {
+                m_idx += token.size();
+                out = comparison;
+                return true;
+            }
+        }
+
+        return false;
+    }
+
+    bool
+    at_end() {
+        return peek() == '\0';
+    }
+
+private:
+    bool
+    parse_term(t_uindex& out) {
+        if (!parse_unary(out)) {
+            return false;
+        }
+
+        while (peek() == '*' || peek() == '/') {
+            t_node_type type = next() == '*' ? NODE_MULTIPLY : NODE_DIVIDE;
+            t_uindex rhs;
+            if (!parse_unary(rhs)) {
+                return false;
+            }
+
+            out = push_binary(type, out, rhs);
+        }
+
+        return true;
+    }
+
+    bool
+    parse_unary(t_uindex& out) {
+        if (peek() != '-') {
+            return parse_primary(out);
+        }
+
+        next();
+        t_uindex operand;
+        if (!parse_unary(operand)) {
+            return false;
+        }
+
+        // `t_tscalar::operator-()` keeps the operand's type, and promotes or
+        // wraps integers, so only floats negate exactly as doubles.
+        t_dtype dtype = m_nodes[operand].m_dtype;
+        if (dtype != DTYPE_FLOAT64 && dtype != DTYPE_FLOAT32) {
+            return false;
+        }
+
+        m_nodes.push_back({NODE_NEGATE, dtype, "", 0, operand, 0});
+        out = m_nodes.size() - 1;
+        return true;
+    }
+
+    bool
+    parse_primary(t_uindex& out) {
+        char c = peek();
+        if (c == '(') {
+            next();
+            if (!parse_sum(out) || peek() != ')') {
+                return false;
+            }
+
+            next();
+            return true;
+        }
+
+        if (std::isdigit(static_cast<unsigned char>(c)) || c == '.') {
+            const char* start = m_expression.c_str() + m_idx;
+            char* end = nullptr;
+            double value = std::strtod(start, &end);
+            if (end == start || is_word(*end)) {
+                return false;
+            }
+
+            m_idx += end - start;
+            m_nodes.push_back({NODE_LITERAL, DTYPE_FLOAT64, "", value, 0, 0});
+            out = m_nodes.size() - 1;
+            return true;
+        }
+
+        if (!is_word(c)) {
+            return false;
+        }
+
+        std::size_t start = m_idx;
+        while (is_word(current())) {
+            ++m_idx;
+        }
+
+        std::string word = m_expression.substr(start, m_idx - start);
+        auto column = std::find_if(
+            m_column_ids.begin(),
+            m_column_ids.end(),
+            [&word](const auto& column_id) { return column_id.first == word; }
+        );
+
+        if (column == m_column_ids.end()
+            || !m_schema.has_column(column->second)) {
+            return false;
+        }
+
+        t_dtype dtype = m_schema.get_dtype(column->second);
+        if (!is_arithmetic_type(dtype)) {
+            return false;
+        }
+
+        m_nodes.push_back({NODE_COLUMN, dtype, column->second, 0, 0, 0});
+        out = m_nodes.size() - 1;
+        return true;
+    }
+
+    t_uindex
+    push_binary(t_node_type type, t_uindex lhs, t_uindex rhs) {
+        m_nodes.push_back({type, DTYPE_FLOAT64, "", 0, lhs, rhs});
+        return m_nodes.size() - 1;
+    }
+
+    static bool
+    is_word(char c) {
+        return std::isalnum(static_cast<unsigned char>(c)) || c == '_';
+    }
+
+    char
+    current() const {
+        return m_idx < m_expression.size() ? m_expression[m_idx] : '\0';
+    }
+
+    // The next character after any whitespace, without consuming it.
+    char
+    peek() {
+        while (std::isspace(static_cast<unsigned char>(current()))) {
+            ++m_idx;
+        }
+
+        return current();
+    }
+
+    char
+    next() {
+        char c = peek();
+        ++m_idx;
+        return c;
+    }
+
+    const std::string& m_expression;
+    const std::vector<std::pair<std::string, std::string>>& m_column_ids;
+    const t_schema& m_schema;
+    std::vector<t_node>& m_nodes;
+    std::size_t m_idx;
+};
+
+std::shared_ptr<t_vectorized_expression>
+t_vectorized_expression::compile(
+    const std::string& parsed_expression_string,
+    const std::vector<std::pair<std::string, std::string>>& column_ids,
+    const t_schema& schema,
+    t_dtype dtype
+) {
+    auto rval = std::make_shared<t_vectorized_expression>();
+    t_parser parser(
+        parsed_expression_string, column_ids, schema, rval->m_nodes
+    );
+    rval->m_rhs = 0;
+    rval->m_comparison = COMPARISON_NONE;
+    if (!parser.parse_sum(rval->m_lhs)) {
+        return nullptr;
+    }
+
+    if (parser.at_end()) {
+        // The output column is written as doubles.
+        if (dtype != DTYPE_FLOAT64
+            || rval->m_nodes[rval->m_lhs].m_dtype != DTYPE_FLOAT64) {
+            return nullptr;
+        }
+
+        return rval;
+    }
+
+    if (!parser.parse_comparison(rval->m_comparison)
+        || !parser.parse_sum(rval->m_rhs) || !parser.at_end()
+        || dtype != DTYPE_BOOL) {
+        return nullptr;
+    }
+
+    // ExprTk compares scalars of different types by their type rather than
+    // their value, which the fast path leaves to ExprTk.
+    t_dtype lhs_dtype = rval->m_nodes[rval->m_lhs].m_dtype;
+    t_dtype rhs_dtype = rval->m_nodes[rval->m_rhs].m_dtype;
+    if (lhs_dtype != rhs_dtype || !is_exact_type(lhs_dtype)) {
+        return nullptr;
+    }
+
+    return rval;
+}
+
+template <typename T>
+static void
+gather(
+    const t_column& column,
+    t_uindex start,
+    const t_uindex* rows,
+    t_uindex count,
+    double* values,
+    std::uint8_t* valid
+) {
+    bool has_status = column.is_status_enabled();
+    for (t_uindex idx = 0; idx < count; ++idx) {
+        t_uindex ridx = rows != nullptr ? rows[idx] : start + idx;
+        values[idx] = static_cast<double>(*column.get_nth<T>(ridx));
+        valid[idx] = !has_status || column.is_valid(ridx);
+    }
+}
+
+static void
+gather_column(
+    const t_column& column,
+    t_uindex start,
+    const t_uindex* rows,
+    t_uindex count,
+    double* values,
+    std::uint8_t* valid
+) {
+    switch (column.get_dtype()) {
+        case DTYPE_FLOAT64: {
+            gather<double>(column, start, rows, count, values, valid);
+        } break;
+        case DTYPE_FLOAT32: {
+            gather<float>(column, start, rows, count, values, valid);
+        } break;
+        case DTYPE_INT64: {
+            gather<std::int64_t>(column, start, rows, count, values, valid);
+        } break;
+        case DTYPE_INT32: {
+            gather<std::int32_t>(column, start, rows, count, values, valid);
+        } break;
+        case DTYPE_INT16: {
+            gather<std::int16_t>(column, start, rows, count, values, valid);
+        } break;
+        case DTYPE_INT8: {
+            gather<std::int8_t>(column, start, rows, count, values, valid);
+        } break;
+        case DTYPE_UINT64: {
+            gather<std::uint64_t>(column, start, rows, count, values, valid);
+        } break;
+        case DTYPE_UINT32: {
+            gather<std::uint32_t>(column, start, rows, count, values, valid);
+        } break;
+        case DTYPE_UINT16: {
+            gather<std::uint16_t>(column, start, rows, count, values, valid);
+        } break;
+        case DTYPE_UINT8: {
+            gather<std::uint8_t>(column, start, rows, count, values, valid);
+        } break;
+        default: {
+            PSP_COMPLAIN_AND_ABORT("Unexpected column type in fast path");
+        } break;
+    }
+}
+
+// Equality as `t_tscalar::operator==` has it for two valid floats, which
+// compares their bits: `-0.0 != 0.0` and a NaN equals itself.
+static bool
+bitwise_equal(double lhs, double rhs) {
+    return std::memcmp(&lhs, &rhs, sizeof(double)) == 0;
+}
+
+template <template <typename> class COMPARER_T>
+static void
+compare(
+    const double* lhs, const double* rhs, t_uindex count, std::uint8_t* out
+) {
+    COMPARER_T<double> cmp;
+    for (t_uindex idx = 0; idx < count; ++idx) {
+        out[idx] = cmp(lhs[idx], rhs[idx]);
+    }
+}
+
+bool
+t_vectorized_expression::compute(
+    const t_data_table& source_table,
+    const std::vector<t_uindex>* rows,
+    t_column& output
+) const {
+    // Rows per block: enough to amortize dispatching on each node, few
+    // enough that a block's buffers stay in cache.
+    t_uindex block_size = get_engine_config().m_expression_block_size;
+    t_uindex num_nodes = m_nodes.size();
+    std::vector<std::shared_ptr<const t_column>> columns(num_nodes);
+    std::vector<std::vector<double>> values(
+        num_nodes, std::vector<double>(block_size)
+    );
+
+    std::vector<std::vector<std::uint8_t>> valid(
+        num_nodes, std::vector<std::uint8_t>(block_size)
+    );
+
+    for (t_uindex nidx = 0; nidx < num_nodes; ++nidx) {
+        if (m_nodes[nidx].m_type == NODE_COLUMN) {
+            columns[nidx] =
+                source_table.get_const_column(m_nodes[nidx].m_column);
+        }
+    }
+
+    std::vector<std::uint8_t> compared(block_size);
+    t_uindex num_rows = rows != nullptr ? rows->size() : source_table.size();
+    for (t_uindex start = 0; start < num_rows; start += block_size) {
+        t_uindex count = std::min(block_size, num_rows - start);
+        const t_uindex* block_rows =
+            rows != nullptr ? rows->data() + start : nullptr;
+
+        for (t_uindex nidx = 0; nidx < num_nodes; ++nidx) {
+            const t_node& node = m_nodes[nidx];
+            double* out = values[nidx].data();
+            std::uint8_t* out_valid = valid[nidx].data();
+            const double* lhs = values[node.m_lhs].data();
+            const double* rhs = values[node.m_rhs].data();
+            const std::uint8_t* lhs_valid = valid[node.m_lhs].data();
+            const std::uint8_t* rhs_valid = valid[node.m_rhs].data();
+
+            switch (node.m_type) {
+                case NODE_COLUMN: {
+                    gather_column(
+                        *columns[nidx], start, block_rows, count, out, out_valid
+                    );
+                } break;
+                case NODE_LITERAL: {
+                    std::fill(out, out + count, node.m_literal);
+                    std::fill(out_valid, out_valid + count, 1);
+                } break;
+                case NODE_NEGATE: {
+                    for (t_uindex idx = 0; idx < count; ++idx) {
+                        out[idx] = -lhs[idx];
+                        out_valid[idx] = lhs_valid[idx];
+                    }
+                } break;
+                case NODE_ADD: {
+                    for (t_uindex idx = 0; idx < count; ++idx) {
+                        out[idx] = lhs[idx] + rhs[idx];
+                        out_valid[idx] = lhs_valid[idx] & rhs_valid[idx];
+                    }
+                } break;
+                case NODE_SUBTRACT: {
+                    for (t_uindex idx = 0; idx < count; ++idx) {
+                        out[idx] = lhs[idx] - rhs[idx];
+                        out_valid[idx] = lhs_valid[idx] & rhs_valid[idx];
+                    }
+                } break;
+                case NODE_MULTIPLY: {
+                    for (t_uindex idx = 0; idx < count; ++idx) {
+                        out[idx] = lhs[idx] * rhs[idx];
+                        out_valid[idx] = lhs_valid[idx] & rhs_valid[idx];
+                    }
+                } break;
+                case NODE_DIVIDE: {
+                    // Division by zero is null, as in `t_tscalar::operator/`.
+                    for (t_uindex idx = 0; idx < count; ++idx) {
+                        out[idx] = lhs[idx] / rhs[idx];
+                        out_valid[idx] = lhs_valid[idx] & rhs_valid[idx]
+                            & (rhs[idx] != 0);
+                    }
+                } break;
+            }
+        }
+
+        const double* lhs = values[m_lhs].data();
+        const std::uint8_t* lhs_valid = valid[m_lhs].data();
+        if (m_comparison == COMPARISON_NONE) {
+            for (t_uindex idx = 0; idx < count; ++idx) {
+                t_uindex ridx = block_rows != nullptr ? block_rows[idx]
+                                                      : start + idx;
+                if (lhs_valid[idx]) {
+                    output.set_nth<double>(ridx, lhs[idx]);
+                } else {
+                    output.clear(ridx);
+                }
+            }
+
+            continue;
+        }
+
+        // ExprTk compares nulls by their status rather than their value.
+        const double* rhs = values[m_rhs].data();
+        const std::uint8_t* rhs_valid = valid[m_rhs].data();
+        for (t_uindex idx = 0; idx < count; ++idx) {
+            if (!lhs_valid[idx] || !rhs_valid[idx]) {
+                return false;
+            }
+        }
+
+        std::uint8_t* out = compared.data();
+        switch (m_comparison) {
+            case COMPARISON_LT: {
+                compare<std::less>(lhs, rhs, count, out);
+            } break;
+            case COMPARISON_LTE: {
+                compare<std::less_equal>(lhs, rhs, count, out);
+            } break;
+            case COMPARISON_GT: {
+                compare<std::greater>(lhs, rhs, count, out);
+            } break;
+            case COMPARISON_GTE: {
+                compare<std::greater_equal>(lhs, rhs, count, out);
+            } break;
+            case COMPARISON_EQ:
+            case COMPARISON_NE: {
+                bool equal = m_comparison == COMPARISON_EQ;
+                for (t_uindex idx = 0; idx < count; ++idx) {
+                    out[idx] = bitwise_equal(lhs[idx], rhs[idx]) == equal;
+                }
+            } break;
+            case COMPARISON_NONE:
+                break;
+        }
+
+        for (t_uindex idx = 0; idx < count; ++idx) {
+            t_uindex ridx =
+                block_rows != nullptr ? block_rows[idx] : start + idx;
+            output.set_nth<bool>(ridx, out[idx] != 0);
+        }
+    }
+
+    return true;
+}
+
+} // end namespace perspective
@@ -18,6 +18,8 @@
 #include <rapidjson/writer.h>
 #include <rapidjson/stringbuffer.h>
 #include <arrow/csv/writer.h>
+#include <arrow/util/key_value_metadata.h>
+#include <arrow/c/bridge.h>
 #include <perspective/pyutils.h>
 
 namespace perspective {
@@ -125,7 +127,7 @@ View<t_ctx2>::sides() const {
 }
 
 template <typename CTX_T>
-std::int32_t
+t_index
 View<CTX_T>::num_rows() const {
     if (is_column_only()) {
         return m_ctx->get_row_count() - 1;
@@ -315,6 +317,12 @@ View<CTX_T>::schema() const {
             new_schema[agg_name] =
                 _map_aggregate_types(agg_name, new_schema[agg_name]);
         }
+
+        if (show_values_as_is_percent(
+                m_view_config->get_show_values_as(agg_name)
+            )) {
+            new_schema[agg_name] = "float";
+        }
     }
 
     return new_schema;
@@ -600,10 +608,10 @@ View<t_ctx2>::get_data(
 template <typename CTX_T>
 std::shared_ptr<std::string>
 View<CTX_T>::to_arrow(
-    std::int32_t start_row,
-    std::int32_t end_row,
-    std::int32_t start_col,
-    std::int32_t end_col,
+    t_uindex start_row,
+    t_uindex end_row,
+    t_uindex start_col,
+    t_uindex end_col,
     bool emit_group_by,
     bool compress
 ) const {
@@ -612,13 +620,40 @@ View<CTX_T>::to_arrow(
     return data_slice_to_arrow(data_slice, emit_group_by, compress);
 };
 
+template <typename CTX_T>
+void
+View<CTX_T>::to_arrow_stream(
+    t_uindex start_row,
+    t_uindex end_row,
+    t_uindex start_col,
+    t_uindex end_col,
+    bool emit_group_by,
+    ArrowArrayStream* out
+) const {
+    std::shared_ptr<t_data_slice<CTX_T>> data_slice =
+        get_data(start_row, end_row, start_col, end_col);
+    std::pair<
+        std::shared_ptr<arrow::Schema>,
+        std::shared_ptr<arrow::RecordBatch>>
+        pairs = data_slice_to_batches(emit_group_by, data_slice);
+    auto reader = arrow::RecordBatchReader::Make({pairs.second}, pairs.first);
+    if (!reader.ok()) {
+        std::stringstream ss;
+        ss << "Failed to make RecordBatchReader: "
+           << reader.status().message() << std::endl;
+        PSP_COMPLAIN_AND_ABORT(ss.str());
+    }
+
+    PSP_CHECK_ARROW_STATUS(arrow::ExportRecordBatchReader(*reader, out));
+}
+
 template <>
 std::shared_ptr<std::string>
 View<t_ctx2>::to_csv(
-    std::int32_t start_row,
-    std::int32_t end_row,
-    std::int32_t start_col,
-    std::int32_t end_col
+    t_uindex start_row,
+    t_uindex end_row,
+    t_uindex start_col,
+    t_uindex end_col
 ) const {
 
     // See generic instance.
@@ -634,10 +669,10 @@ View<t_ctx2>::to_csv(
 template <>
 std::shared_ptr<std::string>
 View<t_ctx1>::to_csv(
-    std::int32_t start_row,
-    std::int32_t end_row,
-    std::int32_t start_col,
-    std::int32_t end_col
+    t_uindex start_row,
+    t_uindex end_row,
+    t_uindex start_col,
+    t_uindex end_col
 ) const {
     std::shared_ptr<t_data_slice<t_ctx1>> data_slice =
         get_data(start_row, end_row, start_col, end_col);
@@ -647,10 +682,10 @@ View<t_ctx1>::to_csv(
 template <typename CTX_T>
 std::shared_ptr<std::string>
 View<CTX_T>::to_csv(
-    std::int32_t start_row,
-    std::int32_t end_row,
-    std::int32_t start_col,
-    std::int32_t end_col
+    t_uindex start_row,
+    t_uindex end_row,
+    t_uindex start_col,
+    t_uindex end_col
 ) const {
 
     // Arrow has a big whih miscalculates CSV header size as 1 when there are no
@@ -885,6 +920,23 @@ View<CTX_T>::data_slice_to_batches(
                         }
                     );
                 } break;
+                case DTYPE_DURATION: {
+                    fields[write_idx] = arrow::field(
+                        row_path_name, arrow::duration(arrow::TimeUnit::MILLI)
+                    );
+                    vectors[write_idx] = apachearrow::duration_col_to_array(
+                        extents,
+                        [&, rpidx](t_uindex ridx) {
+                            auto depth = m_ctx->unity_get_row_depth(ridx);
+                            if (rpidx < depth) {
+                                return m_ctx->unity_get_row_path(ridx).at(
+                                    (depth - 1) - rpidx
+                                );
+                            }
+                            return mknone();
+                        }
+                    );
+                } break;
                 case DTYPE_TIME: {
                     fields[write_idx] = arrow::field(
                         row_path_name, arrow::timestamp(arrow::TimeUnit::MILLI)
@@ -902,6 +954,37 @@ View<CTX_T>::data_slice_to_batches(
                         }
                     );
                 } break;
+                case DTYPE_UUID: {
+                    fields[write_idx] = apachearrow::uuid_field(row_path_name);
+                    vectors[write_idx] = apachearrow::uuid_col_to_array(
+                        extents,
+                        [&, rpidx](t_uindex ridx) {
+                            auto depth = m_ctx->unity_get_row_depth(ridx);
+                            if (rpidx < depth) {
+                                return m_ctx->unity_get_row_path(ridx).at(
+                                    (depth - 1) - rpidx
+                                );
+                            }
+                            return mknone();
+                        }
+                    );
+                } break;
+                case DTYPE_IPADDR: {
+                    fields[write_idx] =
+                        arrow::field(row_path_name, arrow::utf8());
+                    vectors[write_idx] = apachearrow::ipaddr_col_to_array(
+                        extents,
+                        [&, rpidx](t_uindex ridx) {
+                            auto depth = m_ctx->unity_get_row_depth(ridx);
+                            if (rpidx < depth) {
+                                return m_ctx->unity_get_row_path(ridx).at(
+                                    (depth - 1) - rpidx
+                                );
+                            }
+                            return mknone();
+                        }
+                    );
+                } break;
                 case DTYPE_BOOL: {
                     fields[write_idx] =
                         arrow::field(row_path_name, arrow::boolean());
@@ -918,6 +1001,9 @@ View<CTX_T>::data_slice_to_batches(
                         }
                     );
                 } break;
+                case DTYPE_LIST:
+                case DTYPE_JSON:
+                case DTYPE_BINARY:
                 case DTYPE_STR: {
                     fields[write_idx] = arrow::field(
                         row_path_name,
@@ -1114,6 +1200,19 @@ View<CTX_T>::data_slice_to_batches(
                              + (cidx - extents.m_scol)];
                     });
             } break;
+            case DTYPE_DURATION: {
+                fields[ccidx] = arrow::field(
+                    name, arrow::duration(arrow::TimeUnit::MILLI)
+                );
+                vectors[ccidx] = apachearrow::duration_col_to_array(
+                    extents,
+                    [&](t_uindex ridx) {
+                        return slice
+                            [(ridx - extents.m_srow) * stride
+                             + (cidx - extents.m_scol)];
+                    }
+                );
+            } break;
             case DTYPE_TIME: {
                 fields[ccidx] = arrow::field(
                     name, arrow::timestamp(arrow::TimeUnit::MILLI)
@@ -1138,6 +1237,41 @@ View<CTX_T>::data_slice_to_batches(
                     }
                 );
             } break;
+            case DTYPE_BINARY: {
+                fields[ccidx] = arrow::field(name, arrow::binary());
+                vectors[ccidx] = apachearrow::binary_col_to_array(
+                    extents,
+                    [&](t_uindex ridx) {
+                        return slice
+                            [(ridx - extents.m_srow) * stride
+                             + (cidx - extents.m_scol)];
+                    }
+                );
+            } break;
+            case DTYPE_UUID: {
+                fields[ccidx] = apachearrow::uuid_field(name);
+                vectors[ccidx] = apachearrow::uuid_col_to_array(
+                    extents,
+                    [&](t_uindex ridx) {
+                        return slice
+                            [(ridx - extents.m_srow) * stride
+                             + (cidx - extents.m_scol)];
+                    }
+                );
+            } break;
+            case DTYPE_IPADDR: {
+                fields[ccidx] = arrow::field(name, arrow::utf8());
+                vectors[ccidx] = apachearrow::ipaddr_col_to_array(
+                    extents,
+                    [&](t_uindex ridx) {
+                        return slice
+                            [(ridx - extents.m_srow) * stride
+                             + (cidx - extents.m_scol)];
+                    }
+                );
+            } break;
+            case DTYPE_LIST:
+            case DTYPE_JSON:
             case DTYPE_STR: {
                 fields[ccidx] = arrow::field(
                     name, arrow::dictionary(arrow::int32(), arrow::utf8())
@@ -1160,6 +1294,25 @@ View<CTX_T>::data_slice_to_batches(
                 PSP_COMPLAIN_AND_ABORT(ss.str());
             }
         }
+
+        // A grouped column carries its group path, outermost group first, so
+        // readers can render the groups as header rows above it.
+        const auto& column_groups = m_view_config->get_column_groups();
+        auto group = column_groups.find(col_path.back().to_string());
+        if (group != column_groups.end()) {
+            std::string group_path;
+            for (const auto& group_name : group->second) {
+                if (!group_path.empty()) {
+                    group_path += m_separator;
+                }
+
+                group_path += group_name;
+            }
+
+            fields[ccidx] = fields[ccidx]->WithMetadata(
+                arrow::key_value_metadata({"column_group"}, {group_path})
+            );
+        }
     });
     // }
 
@@ -1235,6 +1388,97 @@ View<CTX_T>::data_slice_to_csv(std::shared_ptr<t_data_slice<CTX_T>> data_slice
         pairs = data_slice_to_batches(true, data_slice);
     std::shared_ptr<arrow::RecordBatch> batches = pairs.second;
     std::shared_ptr<arrow::Schema> arrow_schema = pairs.first;
+
+    // Durations are written in their humanized form, e.g. `1d 02:03:04`,
+    // rather than as raw milliseconds.
+    for (int cidx = 0; cidx < batches->num_columns(); ++cidx) {
+        if (batches->column(cidx)->type_id() != arrow::Type::DURATION) {
+            continue;
+        }
+
+        auto durations = std::static_pointer_cast<arrow::DurationArray>(
+            batches->column(cidx)
+        );
+
+        arrow::StringBuilder builder;
+        for (int64_t ridx = 0; ridx < durations->length(); ++ridx) {
+            if (durations->IsNull(ridx)) {
+                PSP_CHECK_ARROW_STATUS(builder.AppendNull());
+            } else {
+                PSP_CHECK_ARROW_STATUS(
+                    builder.Append(t_tdelta(durations->Value(ridx)).str())
+                );
+            }
+        }
+
+        std::shared_ptr<arrow::Array> formatted;
+        PSP_CHECK_ARROW_STATUS(builder.Finish(&formatted));
+        auto field =
+            arrow::field(arrow_schema->field(cidx)->name(), arrow::utf8());
+        batches = *batches->SetColumn(cidx, field, formatted);
+        arrow_schema = batches->schema();
+    }
+
+    // Binary values are written as base64, as in JSON output.
+    for (int cidx = 0; cidx < batches->num_columns(); ++cidx) {
+        if (batches->column(cidx)->type_id() != arrow::Type::BINARY) {
+            continue;
+        }
+
+        auto binaries =
+            std::static_pointer_cast<arrow::BinaryArray>(batches->column(cidx));
+
+        arrow::StringBuilder builder;
+        for (int64_t ridx = 0; ridx < binaries->length(); ++ridx) {
+            if (binaries->IsNull(ridx)) {
+                PSP_CHECK_ARROW_STATUS(builder.AppendNull());
+            } else {
+                PSP_CHECK_ARROW_STATUS(
+                    builder.Append(base64_encode(binaries->GetView(ridx)))
+                );
+            }
+        }
+
+        std::shared_ptr<arrow::Array> formatted;
+        PSP_CHECK_ARROW_STATUS(builder.Finish(&formatted));
+        auto field =
+            arrow::field(arrow_schema->field(cidx)->name(), arrow::utf8());
+        batches = *batches->SetColumn(cidx, field, formatted);
+        arrow_schema = batches->schema();
+    }
+
+    // UUIDs are written in their canonical hyphenated form.
+    for (int cidx = 0; cidx < batches->num_columns(); ++cidx) {
+        if (batches->column(cidx)->type_id()
+            != arrow::Type::FIXED_SIZE_BINARY) {
+            continue;
+        }
+
+        auto uuids = std::static_pointer_cast<arrow::FixedSizeBinaryArray>(
+            batches->column(cidx)
+        );
+
+        arrow::StringBuilder builder;
+        t_uuid uuid;
+        for (int64_t ridx = 0; ridx < uuids->length(); ++ridx) {
+            if (uuids->IsNull(ridx)) {
+                PSP_CHECK_ARROW_STATUS(builder.AppendNull());
+            } else {
+                std::memcpy(
+                    uuid.m_bytes, uuids->GetValue(ridx), sizeof(t_uuid)
+                );
+                PSP_CHECK_ARROW_STATUS(builder.Append(uuid.str()));
+            }
+        }
+
+        std::shared_ptr<arrow::Array> formatted;
+        PSP_CHECK_ARROW_STATUS(builder.Finish(&formatted));
+        auto field =
+            arrow::field(arrow_schema->field(cidx)->name(), arrow::utf8());
+        batches = *batches->SetColumn(cidx, field, formatted);
+        arrow_schema = batches->schema();
+    }
+
     arrow::Result<std::shared_ptr<arrow::ResizableBuffer>> allocated =
         arrow::AllocateResizableBuffer(0);
     if (!allocated.ok()) {
@@ -1273,31 +1517,31 @@ View<CTX_T>::_set_deltas_enabled(bool enabled_state) {
 // Pivot table operations
 template <typename CTX_T>
 bool
-View<CTX_T>::get_row_expanded(std::int32_t ridx) const {
+View<CTX_T>::get_row_expanded(t_index ridx) const {
     return m_ctx->unity_get_row_expanded(ridx);
 }
 
 template <>
 t_index
-View<t_ctxunit>::expand(std::int32_t ridx, std::int32_t row_pivot_length) {
+View<t_ctxunit>::expand(t_index ridx, std::int32_t row_pivot_length) {
     return ridx;
 }
 
 template <>
 t_index
-View<t_ctx0>::expand(std::int32_t ridx, std::int32_t row_pivot_length) {
+View<t_ctx0>::expand(t_index ridx, std::int32_t row_pivot_length) {
     return ridx;
 }
 
 template <>
 t_index
-View<t_ctx1>::expand(std::int32_t ridx, std::int32_t row_pivot_length) {
+View<t_ctx1>::expand(t_index ridx, std::int32_t row_pivot_length) {
     return m_ctx->open(ridx);
 }
 
 template <>
 t_index
-View<t_ctx2>::expand(std::int32_t ridx, std::int32_t row_pivot_length) {
+View<t_ctx2>::expand(t_index ridx, std::int32_t row_pivot_length) {
     if (m_ctx->unity_get_row_depth(ridx) < t_uindex(row_pivot_length)) {
         return m_ctx->open(t_header::HEADER_ROW, ridx);
     }
@@ -1306,25 +1550,25 @@ View<t_ctx2>::expand(std::int32_t ridx, std::int32_t row_pivot_length) {
 
 template <>
 t_index
-View<t_ctxunit>::collapse(std::int32_t ridx) {
+View<t_ctxunit>::collapse(t_index ridx) {
     return ridx;
 }
 
 template <>
 t_index
-View<t_ctx0>::collapse(std::int32_t ridx) {
+View<t_ctx0>::collapse(t_index ridx) {
     return ridx;
 }
 
 template <>
 t_index
-View<t_ctx1>::collapse(std::int32_t ridx) {
+View<t_ctx1>::collapse(t_index ridx) {
     return m_ctx->close(ridx);
 }
 
 template <>
 t_index
-View<t_ctx2>::collapse(std::int32_t ridx) {
+View<t_ctx2>::collapse(t_index ridx) {
     return m_ctx->close(t_header::HEADER_ROW, ridx);
 }
 
@@ -1358,6 +1602,141 @@ View<t_ctx2>::set_depth(std::int32_t depth, std::int32_t row_pivot_length) {
     }
 }
 
+template <>
+void
+View<t_ctxunit>::set_filter_and_sort(
+    std::shared_ptr<t_view_config> view_config, bool refilter
+) {
+    PSP_COMPLAIN_AND_ABORT("Cannot filter or sort a unit context in place");
+}
+
+template <>
+void
+View<t_ctx0>::set_filter_and_sort(
+    std::shared_ptr<t_view_config> view_config, bool refilter
+) {
+    m_ctx->get_config().set_filter(
+        view_config->get_fterm(), view_config->get_filter_op()
+    );
+
+    if (refilter) {
+        _repopulate_context(ZERO_SIDED_CONTEXT);
+    }
+
+    auto sort = view_config->get_sortspec();
+    if (sort.empty()) {
+        m_ctx->reset_sortby();
+    } else {
+        m_ctx->sort_by(sort);
+    }
+
+    _set_filter_and_sort_config(std::move(view_config));
+}
+
+template <>
+void
+View<t_ctx1>::set_filter_and_sort(
+    std::shared_ptr<t_view_config> view_config, bool refilter
+) {
+    auto sort = view_config->get_sortspec();
+    m_ctx->get_config().set_filter(
+        view_config->get_fterm(), view_config->get_filter_op()
+    );
+
+    // A sorted tree can't be unsorted in place.
+    if (refilter || (!m_sort.empty() && sort.empty())) {
+        m_ctx->reset_sortby();
+        _repopulate_context(ONE_SIDED_CONTEXT);
+        auto depth = view_config->get_row_pivot_depth();
+        if (depth > -1) {
+            m_ctx->set_depth(depth - 1);
+        } else {
+            m_ctx->set_depth(m_row_pivots.size());
+        }
+    }
+
+    m_ctx->sort_by(sort);
+    _set_filter_and_sort_config(std::move(view_config));
+}
+
+template <>
+void
+View<t_ctx2>::set_filter_and_sort(
+    std::shared_ptr<t_view_config> view_config, bool refilter
+) {
+    auto sort = view_config->get_sortspec();
+    auto col_sort = view_config->get_col_sortspec();
+    m_ctx->get_config().set_filter(
+        view_config->get_fterm(), view_config->get_filter_op()
+    );
+
+    // Sorted trees can't be unsorted in place.
+    bool unsort = (!m_sort.empty() && sort.empty())
+        || (!m_view_config->get_col_sortspec().empty() && col_sort.empty());
+
+    if (refilter || unsort) {
+        m_ctx->reset_sortby();
+        _repopulate_context(TWO_SIDED_CONTEXT);
+        auto row_depth = view_config->get_row_pivot_depth();
+        if (row_depth > -1) {
+            m_ctx->set_depth(t_header::HEADER_ROW, row_depth - 1);
+        } else {
+            m_ctx->set_depth(t_header::HEADER_ROW, m_row_pivots.size());
+        }
+
+        auto column_depth = view_config->get_column_pivot_depth();
+        if (column_depth > -1) {
+            m_ctx->set_depth(t_header::HEADER_COLUMN, column_depth - 1);
+        } else {
+            m_ctx->set_depth(t_header::HEADER_COLUMN, m_column_pivots.size());
+        }
+    }
+
+    if (!sort.empty()) {
+        m_ctx->sort_by(sort);
+    }
+
+    if (!col_sort.empty()) {
+        m_ctx->column_sort_by(col_sort);
+    }
+
+    _set_filter_and_sort_config(std::move(view_config));
+}
+
+template <typename CTX_T>
+void
+View<CTX_T>::_repopulate_context(t_ctx_type type) {
+    // Registering a context again under its own name resets it, then
+    // populates it from the gnode's current state.
+    auto pool = m_table->get_pool();
+    auto gnode = m_table->get_gnode();
+    pool->register_context(
+        gnode->get_id(),
+        m_name,
+        type,
+        reinterpret_cast<std::uintptr_t>(m_ctx.get())
+    );
+}
+
+template <typename CTX_T>
+void
+View<CTX_T>::_set_filter_and_sort_config(
+    std::shared_ptr<t_view_config> view_config
+) {
+    m_view_config = std::move(view_config);
+    m_filter = m_view_config->get_fterm();
+    m_sort = m_view_config->get_sortspec();
+    m_hidden_sort.clear();
+    if (!m_sort.empty()) {
+        _find_hidden_sort(m_sort);
+    }
+
+    if (!m_column_pivots.empty()) {
+        auto column_sort = m_view_config->get_col_sortspec();
+        _find_hidden_sort(column_sort);
+    }
+}
+
 // Getters
 template <typename CTX_T>
 std::shared_ptr<CTX_T>
@@ -1431,6 +1810,30 @@ View<t_ctxunit>::get_step_delta(t_index bidx, t_index eidx) const {
     return {};
 }
 
+template <>
+std::vector<t_tscalar>
+View<t_ctxunit>::get_removed_pkeys() const {
+    return m_ctx->get_removed_pkeys();
+}
+
+template <>
+std::vector<t_tscalar>
+View<t_ctx0>::get_removed_pkeys() const {
+    return m_ctx->get_removed_pkeys();
+}
+
+template <>
+std::vector<t_tscalar>
+View<t_ctx1>::get_removed_pkeys() const {
+    return {};
+}
+
+template <>
+std::vector<t_tscalar>
+View<t_ctx2>::get_removed_pkeys() const {
+    return {};
+}
+
 template <typename CTX_T>
 std::shared_ptr<t_data_slice<CTX_T>>
 View<CTX_T>::get_row_delta() const {
@@ -1510,6 +1913,8 @@ View<CTX_T>::_map_aggregate_types(
         if (agg.name() == name) {
             switch (agg.agg()) {
                 case AGGTYPE_DISTINCT_COUNT:
+                case AGGTYPE_TOTAL_BYTES:
+                case AGGTYPE_MAX_BYTES:
                 case AGGTYPE_COUNT: {
                     return "integer";
                 } break;
@@ -1532,6 +1937,10 @@ View<CTX_T>::_map_aggregate_types(
     return typestring;
 }
 
+// Largest integer which round-trips exactly through an IEEE 754 double, i.e.
+// JavaScript's `Number.MAX_SAFE_INTEGER`.
+static constexpr std::int64_t MAX_SAFE_JSON_INTEGER = 9007199254740991LL;
+
 void
 write_scalar(
     t_tscalar scalar,
@@ -1563,10 +1972,27 @@ write_scalar(
         case DTYPE_INT32:
             writer.Int(scalar.get<int32_t>());
             break;
-        case DTYPE_UINT64:
-        case DTYPE_INT64:
-            writer.Int64(scalar.get<int64_t>());
+        case DTYPE_INT64: {
+            // Values outside the IEEE 754 safe integer range would be
+            // silently rounded by JSON consumers which parse numbers as
+            // doubles, so they are written as strings instead.
+            auto val = scalar.get<int64_t>();
+            if (val > MAX_SAFE_JSON_INTEGER || val < -MAX_SAFE_JSON_INTEGER) {
+                writer.String(std::to_string(val).c_str());
+            } else {
+                writer.Int64(val);
+            }
+            break;
+        }
+        case DTYPE_UINT64: {
+            auto val = scalar.get<uint64_t>();
+            if (val > static_cast<uint64_t>(MAX_SAFE_JSON_INTEGER)) {
+                writer.String(std::to_string(val).c_str());
+            } else {
+                writer.Uint64(val);
+            }
             break;
+        }
         case DTYPE_FLOAT32:
             if (scalar.is_nan()) {
                 writer.Null();
@@ -1582,9 +2008,26 @@ write_scalar(
             }
             break;
         case DTYPE_STR:
+        // Binary values are interned as base64 text.
+        case DTYPE_BINARY:
             writer.String(scalar.get<const char*>());
             break;
+        case DTYPE_UUID:
+        case DTYPE_IPADDR:
+            writer.String(scalar.to_string().c_str());
+            break;
+        case DTYPE_LIST: {
+            // Lists are interned as JSON text, so they are written through
+            // verbatim rather than quoted as a string.
+            std::string list = scalar.to_string();
+            writer.RawValue(list.c_str(), list.size(), rapidjson::kArrayType);
+        } break;
+        case DTYPE_JSON: {
+            std::string doc = scalar.to_string();
+            writer.RawValue(doc.c_str(), doc.size(), rapidjson::kObjectType);
+        } break;
         case DTYPE_TIME:
+        case DTYPE_DURATION:
             if (is_formatted) {
                 writer.String(scalar.to_string().c_str());
             } else {
@@ -2312,6 +2755,113 @@ View<t_ctx2>::to_columns(
     return s.GetString();
 }
 
+template <typename CTX_T>
+std::map<std::string, t_downsampled_series>
+View<CTX_T>::downsample(
+    const std::string& x_col,
+    const std::vector<std::string>& y_cols,
+    t_uindex n_points
+) const {
+    if (sides() != 0) {
+        PSP_COMPLAIN_AND_ABORT(
+            "`downsample()` is not supported on views with `group_by` or "
+            "`split_by`"
+        );
+    }
+
+    auto column_index = [&](const std::string& name) {
+        auto it = std::find(m_columns.begin(), m_columns.end(), name);
+        if (it == m_columns.end()) {
+            PSP_COMPLAIN_AND_ABORT(
+                "Column `" + name + "` is not in the view's `columns`"
+            );
+        }
+
+        return static_cast<t_uindex>(std::distance(m_columns.begin(), it));
+    };
+
+    // Converts a cell to a coordinate, returning `false` for nulls.
+    auto to_coord = [&](const t_tscalar& scalar, double& out) {
+        if (!scalar.is_valid()) {
+            return false;
+        }
+
+        switch (scalar.get_dtype()) {
+            case DTYPE_DATE: {
+                t_date date_val = scalar.get<t_date>();
+                tm t = date_val.get_tm();
+                out = static_cast<double>(mktime(&t)) * 1000;
+            } break;
+            case DTYPE_TIME:
+                out = static_cast<double>(scalar.get<std::int64_t>());
+                break;
+            default:
+                if (!is_numeric_type(scalar.get_dtype())) {
+                    PSP_COMPLAIN_AND_ABORT(
+                        "`downsample()` requires numeric, date or datetime "
+                        "columns"
+                    );
+                }
+
+                out = scalar.to_double();
+                break;
+        }
+
+        return true;
+    };
+
+    t_uindex x_idx = column_index(x_col);
+    std::vector<t_uindex> y_idxs;
+    y_idxs.reserve(y_cols.size());
+    for (const auto& y_col : y_cols) {
+        y_idxs.push_back(column_index(y_col));
+    }
+
+    PSP_GIL_UNLOCK();
+    PSP_READ_LOCK(*get_lock());
+    t_uindex nrows = num_rows();
+    auto slice = get_data(0, nrows, 0, m_columns.size());
+
+    std::map<std::string, t_downsampled_series> out;
+    for (t_uindex ii = 0; ii < y_cols.size(); ++ii) {
+        std::vector<std::pair<double, double>> points;
+        points.reserve(nrows);
+        for (t_uindex ridx = 0; ridx < nrows; ++ridx) {
+            double x;
+            double y;
+            if (to_coord(slice->get(ridx, x_idx), x)
+                && to_coord(slice->get(ridx, y_idxs[ii]), y)) {
+                points.emplace_back(x, y);
+            }
+        }
+
+        auto by_x = [](const auto& a, const auto& b) {
+            return a.first < b.first;
+        };
+
+        if (!std::is_sorted(points.begin(), points.end(), by_x)) {
+            std::stable_sort(points.begin(), points.end(), by_x);
+        }
+
+        std::vector<double> xs;
+        std::vector<double> ys;
+        xs.reserve(points.size());
+        ys.reserve(points.size());
+        for (const auto& [x, y] : points) {
+            xs.push_back(x);
+            ys.push_back(y);
+        }
+
+        auto& series = out[y_cols[ii]];
+        for (auto idx : lttb(xs, ys, n_points)) {
+            series.m_x.push_back(xs[idx]);
+            series.m_y.push_back(ys[idx]);
+        }
+    }
+
+    return out;
+}
+
 template <typename CTX_T>
 void
 View<CTX_T>::_find_hidden_sort(const std::vector<t_sortspec>& sort) {
@@ -38,6 +38,10 @@ t_view_config::t_view_config(
     m_expressions(expressions),
     m_row_pivot_depth(-1),
     m_column_pivot_depth(-1),
+    m_exclude_null_groups(false),
+    m_null_aggregates(NULL_AGGREGATES_DEFAULT),
+    m_grand_total(TOTALS_BEFORE),
+    m_subtotals(TOTALS_BEFORE),
     m_filter_op(std::move(filter_op)),
     m_column_only(column_only) {}
 
@@ -196,6 +200,41 @@ t_view_config::set_row_pivot_depth(std::int32_t depth) {
     m_row_pivot_depth = depth;
 }
 
+void
+t_view_config::set_timezone(const std::string& timezone) {
+    m_timezone = timezone;
+}
+
+void
+t_view_config::set_exclude_null_groups(bool exclude) {
+    m_exclude_null_groups = exclude;
+}
+
+void
+t_view_config::set_null_aggregates(t_null_aggregates null_aggregates) {
+    m_null_aggregates = null_aggregates;
+}
+
+void
+t_view_config::set_row_totals(t_totals grand_total, t_totals subtotals) {
+    m_grand_total = grand_total;
+    m_subtotals = subtotals;
+}
+
+void
+t_view_config::set_show_values_as(
+    std::map<std::string, t_show_values_as> show_values_as
+) {
+    m_show_values_as = std::move(show_values_as);
+}
+
+void
+t_view_config::set_column_groups(
+    std::map<std::string, std::vector<std::string>> column_groups
+) {
+    m_column_groups = std::move(column_groups);
+}
+
 void
 t_view_config::set_column_pivot_depth(std::int32_t depth) {
     PSP_VERBOSE_ASSERT(m_init, "touching uninited object");
@@ -268,6 +307,48 @@ t_view_config::get_row_pivot_depth() const {
     return m_row_pivot_depth;
 }
 
+const std::string&
+t_view_config::get_timezone() const {
+    return m_timezone;
+}
+
+bool
+t_view_config::get_exclude_null_groups() const {
+    return m_exclude_null_groups;
+}
+
+t_null_aggregates
+t_view_config::get_null_aggregates() const {
+    return m_null_aggregates;
+}
+
+t_totals
+t_view_config::get_grand_total() const {
+    return m_grand_total;
+}
+
+t_totals
+t_view_config::get_subtotals() const {
+    return m_subtotals;
+}
+
+const std::map<std::string, t_show_values_as>&
+t_view_config::get_show_values_as() const {
+    return m_show_values_as;
+}
+
+t_show_values_as
+t_view_config::get_show_values_as(const std::string& column) const {
+    auto iter = m_show_values_as.find(column);
+    return iter == m_show_values_as.end() ? SHOW_VALUES_AS_VALUE
+                                          : iter->second;
+}
+
+const std::map<std::string, std::vector<std::string>>&
+t_view_config::get_column_groups() const {
+    return m_column_groups;
+}
+
 std::int32_t
 t_view_config::get_column_pivot_depth() const {
     PSP_VERBOSE_ASSERT(m_init, "touching uninited object");
@@ -361,7 +442,8 @@ t_view_config::fill_fterm() {
         t_filter_op op = str_to_filter_op(std::get<1>(filter));
         switch (op) {
             case FILTER_OP_NOT_IN:
-            case FILTER_OP_IN: {
+            case FILTER_OP_IN:
+            case FILTER_OP_IN_SUBNET: {
                 m_fterm.emplace_back(
                     std::get<0>(filter), op, mktscalar(0), std::get<2>(filter)
                 );
@@ -56,6 +56,7 @@ t_vocab::reserve(size_t total_string_size, size_t string_count) {
     m_vlendata->reserve(total_string_size);
     m_extents->reserve(sizeof(std::pair<t_uindex, t_uindex>) * string_count);
     rebuild_map();
+    m_map.reserve(string_count);
 }
 
 bool
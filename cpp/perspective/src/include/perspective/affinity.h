@@ -0,0 +1,69 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+
+#pragma once
+
+#include <perspective/first.h>
+#include <perspective/exports.h>
+#include <perspective/base.h>
+#include <cstdint>
+#include <memory>
+#include <optional>
+#include <vector>
+
+namespace perspective {
+
+struct t_saved_affinity;
+
+/**
+ * @brief Where the engine should run, for `set_engine_affinity`.
+ */
+struct PERSPECTIVE_EXPORT t_affinity_options {
+    // Cores to run the engine on; when empty, the cores of `m_numa_node`.
+    std::vector<std::uint32_t> m_cores;
+
+    // The NUMA node which engine threads should allocate memory from.
+    std::optional<std::uint32_t> m_numa_node;
+
+    // The size of the thread pool used for parallel computation; defaults to
+    // the number of cores.
+    std::optional<std::uint32_t> m_num_threads;
+};
+
+/**
+ * @brief Pin the engine's thread pool, and any thread while it handles a
+ * request (see `t_affinity_scope`), to the cores and NUMA node of
+ * `options`. This is process-wide, and replaces any previous call. Only
+ * supported on Linux.
+ *
+ * @param options
+ */
+PERSPECTIVE_EXPORT void set_engine_affinity(const t_affinity_options& options);
+
+/**
+ * @brief While in scope, moves the calling thread onto the cores and NUMA
+ * memory policy set by `set_engine_affinity`, restoring the thread's own on
+ * destruction. Does nothing if no affinity has been set.
+ */
+class PERSPECTIVE_EXPORT t_affinity_scope {
+public:
+    t_affinity_scope();
+    ~t_affinity_scope();
+
+    PSP_NON_COPYABLE(t_affinity_scope);
+
+private:
+    std::unique_ptr<t_saved_affinity> m_saved;
+};
+
+} // namespace perspective
@@ -11,7 +11,11 @@
 // ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
 
 #pragma once
+#include <cstdint>
+#include <optional>
+#include <string>
 #include <unordered_map>
+#include <vector>
 #include <arrow/io/memory.h>
 #include <arrow/table.h>
 
@@ -20,6 +24,36 @@ namespace apachearrow {
 
     std::optional<int64_t> parseAsArrowTimestamp(const std::string& input);
 
+    /**
+     * @brief CSV dialect and type-inference options. The defaults match
+     * RFC 4180 with a single header row.
+     */
+    struct CsvOptions {
+        char delimiter = ',';
+        char quote_char = '"';
+        char decimal_point = '.';
+
+        // Removed from numbers before parsing, e.g. `.` for `1.234,56`.
+        std::optional<char> thousands_separator;
+
+        // `strptime`-style formats tried before the built-in date parsers.
+        std::vector<std::string> timestamp_formats;
+
+        // The number of rows before the data, the last of which holds the
+        // column names. If `0`, column names are generated.
+        std::uint32_t header_rows = 1;
+
+        // Tokens parsed as null, or Arrow's defaults if empty.
+        std::vector<std::string> null_values;
+
+        // Bytes of input sampled to infer column types.
+        std::optional<std::int32_t> infer_sample_bytes;
+
+        // Infer integer columns as `float`, so later fractional values
+        // aren't truncated.
+        bool infer_int_as_float = false;
+    };
+
     /**
      * @brief Initialize the arrow loader with a CSV.
      *
@@ -29,7 +63,8 @@ namespace apachearrow {
         const std::string_view& csv,
         bool is_update,
         std::unordered_map<std::string, std::shared_ptr<arrow::DataType>>&
-            schema
+            schema,
+        const CsvOptions& options = {}
     );
 
 } // namespace apachearrow
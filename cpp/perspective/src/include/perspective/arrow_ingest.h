@@ -0,0 +1,64 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+#pragma once
+
+#include <perspective/first.h>
+#include <perspective/exports.h>
+#include <arrow/ipc/reader.h>
+#include <arrow/table.h>
+#include <cstdint>
+#include <memory>
+#include <string_view>
+#include <vector>
+
+namespace perspective {
+
+/**
+ * @brief Incrementally decodes an Arrow IPC stream which arrives in chunks
+ * split at arbitrary byte boundaries, as sent by `TableIngestArrowReq`, so
+ * each record batch can be applied to a `Table` as soon as it is complete,
+ * without buffering (or re-encoding) the whole stream.
+ */
+class PERSPECTIVE_EXPORT ArrowIngest {
+public:
+    ArrowIngest();
+
+    /**
+     * @brief Decode `chunk`, returning the record batches it completed as an
+     * `arrow::Table`, or `nullptr` if it completed none.
+     *
+     * @param chunk
+     */
+    std::shared_ptr<arrow::Table> consume(const std::string_view& chunk);
+
+    /**
+     * @brief The number of rows decoded from this stream so far.
+     */
+    std::uint64_t num_rows() const;
+
+private:
+    class Listener : public arrow::ipc::Listener {
+    public:
+        arrow::Status
+        OnRecordBatchDecoded(std::shared_ptr<arrow::RecordBatch> batch
+        ) override;
+
+        std::vector<std::shared_ptr<arrow::RecordBatch>> m_batches;
+    };
+
+    std::shared_ptr<Listener> m_listener;
+    std::unique_ptr<arrow::ipc::StreamDecoder> m_decoder;
+    std::uint64_t m_num_rows;
+};
+
+} // namespace perspective
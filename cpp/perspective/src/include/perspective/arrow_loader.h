@@ -23,6 +23,7 @@
 #include <arrow/util/decimal.h>
 #include <arrow/io/memory.h>
 #include <arrow/ipc/reader.h>
+#include <arrow/c/abi.h>
 #include <perspective/arrow_csv.h>
 
 namespace perspective {
@@ -40,6 +41,22 @@ namespace apachearrow {
          */
         void initialize(const std::uint8_t* ptr, std::uint32_t);
 
+        /**
+         * @brief Initialize the arrow loader from an Arrow C stream, which is
+         * consumed (and released) by this call.
+         *
+         * @param stream
+         */
+        void initialize(ArrowArrayStream* stream);
+
+        /**
+         * @brief Initialize the arrow loader from already-decoded record
+         * batches, e.g. from an `ArrowIngest`.
+         *
+         * @param table
+         */
+        void initialize(std::shared_ptr<arrow::Table> table);
+
         /**
          * @brief Initialize the arrow loader with a CSV.
          *
@@ -49,7 +66,8 @@ namespace apachearrow {
             const std::string_view& csv,
             bool is_update,
             std::unordered_map<std::string, std::shared_ptr<arrow::DataType>>&
-                schema
+                schema,
+            const CsvOptions& options = {}
         );
 
         /**
@@ -77,7 +95,16 @@ namespace apachearrow {
         std::vector<t_dtype> types() const;
         std::uint32_t row_count() const;
 
+        /**
+         * @brief The dictionary values, in dictionary order, of each string
+         * column whose Arrow dictionary type is marked `ordered`.
+         */
+        std::map<std::string, std::vector<std::string>>
+        ordered_dictionaries() const;
+
     private:
+        void init_table();
+
         void fill_column(
             t_data_table& tbl,
             const std::shared_ptr<t_column>& col,
@@ -13,6 +13,7 @@
 #pragma once
 #include <perspective/first.h>
 #include <perspective/base.h>
+#include <perspective/base64.h>
 #include <perspective/date.h>
 #include <perspective/exports.h>
 #include <perspective/scalar.h>
@@ -239,6 +240,141 @@ namespace apachearrow {
         return array;
     }
 
+    /**
+     * @brief Build an `arrow::BinaryArray` from a column typed as
+     * `DTYPE_BINARY`, decoding its base64 text.
+     *
+     * @param extents
+     * @param f
+     * @return std::shared_ptr<arrow::Array>
+     */
+    template <typename F>
+    std::shared_ptr<arrow::Array>
+    binary_col_to_array(t_get_data_extents extents, F f) {
+        arrow::BinaryBuilder array_builder;
+        for (int ridx = extents.m_srow; ridx < extents.m_erow; ++ridx) {
+            t_tscalar scalar = f(ridx);
+            arrow::Status s;
+            if (scalar.is_valid() && scalar.get_dtype() != DTYPE_NONE) {
+                auto bytes = base64_decode(scalar.get<const char*>());
+                s = array_builder.Append(bytes.value_or(""));
+            } else {
+                s = array_builder.AppendNull();
+            }
+
+            if (!s.ok()) {
+                std::stringstream ss;
+                ss << "Could not append value to binary array: "
+                   << s.message() << "\n";
+                PSP_COMPLAIN_AND_ABORT(ss.str());
+            }
+        }
+
+        std::shared_ptr<arrow::Array> array;
+        arrow::Status status = array_builder.Finish(&array);
+        if (!status.ok()) {
+            PSP_COMPLAIN_AND_ABORT(status.message());
+        }
+        return array;
+    }
+
+    /**
+     * @brief A `fixed_size_binary(16)` field tagged with the canonical
+     * `arrow.uuid` extension name, so readers recognise it as a UUID.
+     *
+     * @param name
+     * @return std::shared_ptr<arrow::Field>
+     */
+    inline std::shared_ptr<arrow::Field>
+    uuid_field(const std::string& name) {
+        return arrow::field(
+            name,
+            arrow::fixed_size_binary(sizeof(t_uuid)),
+            true,
+            arrow::key_value_metadata(
+                {"ARROW:extension:name", "ARROW:extension:metadata"},
+                {"arrow.uuid", ""}
+            )
+        );
+    }
+
+    /**
+     * @brief Build an `arrow::FixedSizeBinaryArray` of width 16 from a column
+     * typed as `DTYPE_UUID`.
+     *
+     * @param extents
+     * @param f
+     * @return std::shared_ptr<arrow::Array>
+     */
+    template <typename F>
+    std::shared_ptr<arrow::Array>
+    uuid_col_to_array(t_get_data_extents extents, F f) {
+        arrow::FixedSizeBinaryBuilder array_builder(
+            arrow::fixed_size_binary(sizeof(t_uuid))
+        );
+
+        for (int ridx = extents.m_srow; ridx < extents.m_erow; ++ridx) {
+            t_tscalar scalar = f(ridx);
+            arrow::Status s;
+            if (scalar.is_valid() && scalar.get_dtype() != DTYPE_NONE) {
+                s = array_builder.Append(scalar.get<t_uuid>().m_bytes);
+            } else {
+                s = array_builder.AppendNull();
+            }
+
+            if (!s.ok()) {
+                std::stringstream ss;
+                ss << "Could not append value to UUID array: " << s.message()
+                   << "\n";
+                PSP_COMPLAIN_AND_ABORT(ss.str());
+            }
+        }
+
+        std::shared_ptr<arrow::Array> array;
+        arrow::Status status = array_builder.Finish(&array);
+        if (!status.ok()) {
+            PSP_COMPLAIN_AND_ABORT(status.message());
+        }
+        return array;
+    }
+
+    /**
+     * @brief Build an `arrow::StringArray` of address text from a column
+     * typed as `DTYPE_IPADDR`, as Arrow has no canonical IP address type.
+     *
+     * @param extents
+     * @param f
+     * @return std::shared_ptr<arrow::Array>
+     */
+    template <typename F>
+    std::shared_ptr<arrow::Array>
+    ipaddr_col_to_array(t_get_data_extents extents, F f) {
+        arrow::StringBuilder array_builder;
+        for (int ridx = extents.m_srow; ridx < extents.m_erow; ++ridx) {
+            t_tscalar scalar = f(ridx);
+            arrow::Status s;
+            if (scalar.is_valid() && scalar.get_dtype() != DTYPE_NONE) {
+                s = array_builder.Append(scalar.get<t_ipaddr>().str());
+            } else {
+                s = array_builder.AppendNull();
+            }
+
+            if (!s.ok()) {
+                std::stringstream ss;
+                ss << "Could not append value to IP address array: "
+                   << s.message() << "\n";
+                PSP_COMPLAIN_AND_ABORT(ss.str());
+            }
+        }
+
+        std::shared_ptr<arrow::Array> array;
+        arrow::Status status = array_builder.Finish(&array);
+        if (!status.ok()) {
+            PSP_COMPLAIN_AND_ABORT(status.message());
+        }
+        return array;
+    }
+
     template <typename F>
     std::shared_ptr<arrow::Array>
     boolean_col_to_array(t_get_data_extents extents, F f) {
@@ -352,5 +488,39 @@ namespace apachearrow {
         return array;
     }
 
+    template <typename F>
+    std::shared_ptr<arrow::Array>
+    duration_col_to_array(t_get_data_extents extents, F f) {
+        std::shared_ptr<arrow::DataType> type =
+            arrow::duration(arrow::TimeUnit::MILLI);
+        arrow::DurationBuilder array_builder(type, arrow::default_memory_pool());
+        auto reserve_status =
+            array_builder.Reserve(extents.m_erow - extents.m_srow);
+        if (!reserve_status.ok()) {
+            std::stringstream ss;
+            ss << "Failed to allocate buffer for column: "
+               << reserve_status.message() << "\n";
+            PSP_COMPLAIN_AND_ABORT(ss.str());
+        }
+
+        for (int ridx = extents.m_srow; ridx < extents.m_erow; ++ridx) {
+            t_tscalar scalar = f(ridx);
+            if (scalar.is_valid() && scalar.get_dtype() != DTYPE_NONE) {
+                array_builder.UnsafeAppend(get_scalar<std::int64_t>(scalar));
+            } else {
+                array_builder.UnsafeAppendNull();
+            }
+        }
+
+        std::shared_ptr<arrow::Array> array;
+        arrow::Status status = array_builder.Finish(&array);
+        if (!status.ok()) {
+            PSP_COMPLAIN_AND_ABORT(
+                "Could not serialize duration column: " + status.message()
+            );
+        }
+        return array;
+    }
+
 } // namespace apachearrow
 } // namespace perspective
\ No newline at end of file
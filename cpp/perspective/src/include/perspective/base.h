@@ -159,7 +159,14 @@ std::is_pod<X>::value && std::is_standard_layout<X>::value , \
 #define LOG_DEBUG(X)
 #endif
 
-#if defined(PSP_ENABLE_WASM)
+#if defined(PSP_ENABLE_WASI)
+// WASI hosts do not provide the Emscripten `env` imports, so these are
+// implemented in `base_impl_wasm.cpp` instead.
+extern "C" const char* psp_stack_trace();
+
+extern "C" size_t psp_heap_size();
+
+#elif defined(PSP_ENABLE_WASM)
 #define ESM_EXPORT(X) __attribute__((import_module("env"), import_name(X)))
 
 PERSPECTIVE_EXPORT ESM_EXPORT("psp_stack_trace") extern "C" const
@@ -168,6 +175,10 @@ PERSPECTIVE_EXPORT ESM_EXPORT("psp_stack_trace") extern "C" const
 PERSPECTIVE_EXPORT ESM_EXPORT("psp_heap_size") extern "C" size_t
     psp_heap_size();
 
+#else
+// The process's resident memory, in `base_impl_<platform>.cpp`.
+extern "C" size_t psp_heap_size();
+
 #endif
 
 #if defined(PSP_DEBUG) && defined(PSP_ENABLE_WASM)
@@ -232,7 +243,8 @@ enum t_filter_op {
     FILTER_OP_NOT_IN,
     FILTER_OP_AND,
     FILTER_OP_IS_NULL,
-    FILTER_OP_IS_NOT_NULL
+    FILTER_OP_IS_NOT_NULL,
+    FILTER_OP_IN_SUBNET
 };
 
 PERSPECTIVE_EXPORT std::string filter_op_to_str(t_filter_op op);
@@ -289,7 +301,9 @@ enum t_aggtype {
     AGGTYPE_PCT_SUM_PARENT,
     AGGTYPE_PCT_SUM_GRAND_TOTAL,
     AGGTYPE_VARIANCE,
-    AGGTYPE_STANDARD_DEVIATION
+    AGGTYPE_STANDARD_DEVIATION,
+    AGGTYPE_TOTAL_BYTES,
+    AGGTYPE_MAX_BYTES
 };
 
 PERSPECTIVE_EXPORT t_aggtype str_to_aggtype(const std::string& str);
@@ -298,6 +312,41 @@ PERSPECTIVE_EXPORT std::string _get_default_aggregate_string(t_dtype dtype);
 
 enum t_totals { TOTALS_BEFORE, TOTALS_HIDDEN, TOTALS_AFTER };
 
+// How aggregates treat null values in a group.
+enum t_null_aggregates {
+    // `count` counts every row, every other aggregate skips nulls.
+    NULL_AGGREGATES_DEFAULT,
+
+    // Every aggregate skips nulls, including `count`.
+    NULL_AGGREGATES_IGNORE,
+
+    // A null in a group makes its aggregates null, except for `count`.
+    NULL_AGGREGATES_PROPAGATE
+};
+
+// How a pivoted view shows an aggregate's value, relative to other cells.
+enum t_show_values_as {
+    // The aggregate's value.
+    SHOW_VALUES_AS_VALUE,
+
+    // Percent of the row's total over every `split_by` column.
+    SHOW_VALUES_AS_PCT_ROW_TOTAL,
+
+    // Percent of the column's total over every `group_by` row.
+    SHOW_VALUES_AS_PCT_COLUMN_TOTAL,
+
+    // Percent of the aggregate over the whole view.
+    SHOW_VALUES_AS_PCT_GRAND_TOTAL,
+
+    // Less the value of the previous column in the same `split_by` group.
+    SHOW_VALUES_AS_DIFFERENCE_FROM_PREVIOUS,
+
+    // Plus the values of the previous columns in the same `split_by` group.
+    SHOW_VALUES_AS_RUNNING_TOTAL
+};
+
+PERSPECTIVE_EXPORT bool show_values_as_is_percent(t_show_values_as show);
+
 enum t_ctx_type {
     UNIT_CONTEXT,
     ZERO_SIDED_CONTEXT,
@@ -529,9 +578,18 @@ PERSPECTIVE_EXPORT t_dtype type_to_dtype<bool>();
 template <>
 PERSPECTIVE_EXPORT t_dtype type_to_dtype<t_time>();
 
+template <>
+PERSPECTIVE_EXPORT t_dtype type_to_dtype<t_tdelta>();
+
 template <>
 PERSPECTIVE_EXPORT t_dtype type_to_dtype<t_date>();
 
+template <>
+PERSPECTIVE_EXPORT t_dtype type_to_dtype<t_uuid>();
+
+template <>
+PERSPECTIVE_EXPORT t_dtype type_to_dtype<t_ipaddr>();
+
 template <>
 PERSPECTIVE_EXPORT t_dtype type_to_dtype<std::string>();
 
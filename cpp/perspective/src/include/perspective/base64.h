@@ -0,0 +1,51 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+#pragma once
+
+#include <perspective/first.h>
+#include <perspective/exports.h>
+#include <cstddef>
+#include <optional>
+#include <string>
+#include <string_view>
+
+namespace perspective {
+
+/**
+ * @brief Encode `bytes` as standard (RFC 4648), padded base64.
+ *
+ * @param bytes
+ * @return std::string
+ */
+PERSPECTIVE_EXPORT std::string base64_encode(std::string_view bytes);
+
+/**
+ * @brief Decode standard, padded base64 `text`, or `std::nullopt` if `text`
+ * is not valid base64.
+ *
+ * @param text
+ * @return std::optional<std::string>
+ */
+PERSPECTIVE_EXPORT std::optional<std::string>
+base64_decode(std::string_view text);
+
+/**
+ * @brief The number of bytes which valid base64 `text` decodes to, without
+ * decoding it.
+ *
+ * @param text
+ * @return std::size_t
+ */
+PERSPECTIVE_EXPORT std::size_t base64_decoded_size(std::string_view text);
+
+} // namespace perspective
@@ -163,6 +163,19 @@ public:
     void clear();
     void clear_objects() const;
 
+    /**
+     * @brief Compress this column's data and validity in place, see
+     * `t_lstore::compress`. Reading or writing the column transparently
+     * decompresses it.
+     */
+    void compress();
+
+    /**
+     * @brief The bytes of this column's data and validity, uncompressed and
+     * as currently stored in memory.
+     */
+    t_storage_bytes get_storage_bytes() const;
+
     template <typename VEC_T>
     void fill(VEC_T& vec, const t_uindex* bidx, const t_uindex* eidx) const;
 
@@ -378,7 +391,7 @@ template <typename DATA_T>
 void
 t_column::set_nth_body(t_uindex idx, DATA_T elem, t_status status) {
     COLUMN_CHECK_ACCESS(idx);
-    PSP_VERBOSE_ASSERT(m_dtype == DTYPE_STR, "Setting non string column");
+    PSP_VERBOSE_ASSERT(m_isvlen, "Setting non string column");
     t_uindex interned = m_vocab->get_interned(elem);
     m_data->set_nth<t_uindex>(idx, interned);
 
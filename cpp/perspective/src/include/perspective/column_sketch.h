@@ -0,0 +1,91 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+#pragma once
+
+#include <perspective/first.h>
+#include <perspective/exports.h>
+#include <perspective/base.h>
+#include <perspective/scalar.h>
+#include <optional>
+#include <string>
+#include <unordered_map>
+#include <utility>
+#include <vector>
+
+namespace perspective {
+
+/**
+ * @brief The number of most-frequent values a `t_column_sketch` reports.
+ */
+const t_uindex PSP_SKETCH_TOP_K = 10;
+
+/**
+ * @brief A lightweight summary of every value written to a `Table` column,
+ * updated incrementally as updates arrive, for populating filter suggestions
+ * without a query: the count of non-null values, the minimum and maximum, and
+ * (for categorical types) approximately the most frequent values.
+ *
+ * Frequent values are counted with the Space-Saving algorithm in a fixed
+ * number of counters, so counts are over-estimates by at most the smallest
+ * counter. Like a column's string dictionary, a sketch is never shrunk by
+ * overwrites or removes, only reset by `Table::clear`.
+ */
+class PERSPECTIVE_EXPORT t_column_sketch {
+public:
+    explicit t_column_sketch(t_dtype dtype);
+
+    /**
+     * @brief Add a value to this sketch; invalid (null) and `NaN` values are
+     * ignored.
+     *
+     * @param value
+     */
+    void update(const t_tscalar& value);
+
+    t_uindex get_count() const;
+
+    /**
+     * @brief The minimum and maximum values written, if any. `string`
+     * scalars point into this sketch, and are only valid until it is next
+     * updated.
+     */
+    std::optional<t_tscalar> get_min() const;
+    std::optional<t_tscalar> get_max() const;
+
+    /**
+     * @brief The (at most) `k` most frequent values written and their
+     * approximate counts, most frequent first, or none if this column's type
+     * is not categorical. `string` scalars are valid as for `get_min`.
+     *
+     * @param k
+     * @return std::vector<std::pair<t_tscalar, t_uindex>>
+     */
+    std::vector<std::pair<t_tscalar, t_uindex>> get_top_values(t_uindex k
+    ) const;
+
+private:
+    template <typename K>
+    static void count(std::unordered_map<K, t_uindex>& counters, const K& key);
+
+    t_dtype m_dtype;
+    bool m_categorical;
+    t_uindex m_count;
+    std::optional<t_tscalar> m_min;
+    std::optional<t_tscalar> m_max;
+    std::optional<std::string> m_min_string;
+    std::optional<std::string> m_max_string;
+    std::unordered_map<std::string, t_uindex> m_string_counters;
+    std::unordered_map<t_tscalar, t_uindex> m_counters;
+};
+
+} // namespace perspective
@@ -0,0 +1,74 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+
+#pragma once
+
+#include <perspective/first.h>
+#include <perspective/exports.h>
+#include <perspective/base.h>
+#include <cstdint>
+#include <vector>
+
+namespace perspective {
+
+enum t_compression_encoding : std::uint8_t {
+    COMPRESSION_NONE,
+
+    // Runs of equal elements, as `(varint run length, element)` pairs.
+    COMPRESSION_RLE,
+
+    // Distinct elements, followed by each element's 1 or 2 byte index into
+    // them.
+    COMPRESSION_DICTIONARY,
+
+    // The first element, followed by the zig-zag varint difference of each
+    // element from the one before; best for sorted timestamps and sequential
+    // ids.
+    COMPRESSION_DELTA
+};
+
+/**
+ * @brief A buffer of fixed-width elements, encoded by `compress_buffer`.
+ */
+struct PERSPECTIVE_EXPORT t_compressed_buffer {
+    t_compression_encoding m_encoding = COMPRESSION_NONE;
+    t_uindex m_width = 0;
+    t_uindex m_size = 0;
+    std::vector<std::uint8_t> m_data;
+};
+
+/**
+ * @brief Encode the `size` bytes at `base`, a buffer of `width`-byte
+ * elements, with whichever of the RLE, dictionary and delta encodings is
+ * smallest. Returns a `COMPRESSION_NONE` buffer if no encoding is smaller
+ * than `size`, or if `width` is not 1, 2, 4 or 8.
+ *
+ * @param base
+ * @param size In bytes.
+ * @param width In bytes.
+ * @return t_compressed_buffer
+ */
+PERSPECTIVE_EXPORT t_compressed_buffer
+compress_buffer(const void* base, t_uindex size, t_uindex width);
+
+/**
+ * @brief Decode `buffer` into `out`, which must have room for
+ * `buffer.m_size` bytes.
+ *
+ * @param buffer
+ * @param out
+ */
+PERSPECTIVE_EXPORT void
+decompress_buffer(const t_compressed_buffer& buffer, void* out);
+
+} // namespace perspective
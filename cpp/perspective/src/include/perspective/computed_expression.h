@@ -19,14 +19,23 @@
 #include <perspective/column.h>
 #include <perspective/data_table.h>
 #include <perspective/rlookup.h>
-#include <perspective/computed_function.h>
 #include <perspective/gnode_state.h>
+#include <perspective/expression_vocab.h>
+#include <perspective/regex.h>
 #include <date/date.h>
 #include <tsl/hopscotch_set.h>
 
+// Builds with `PSP_DISABLE_EXPRESSIONS` (see `PSP_ENABLE_EXPRESSIONS` in
+// CMakeLists.txt) omit ExprTK and the computed functions, and reject every
+// expression, see `t_computed_expression_parser`.
+#ifndef PSP_DISABLE_EXPRESSIONS
+#include <perspective/computed_function.h>
+#include <perspective/vectorized_expression.h>
+
 // a header that includes exprtk and overload definitions for `t_tscalar` so
 // it can be used inside exprtk.
 #include <perspective/exprtk.h>
+#endif
 
 namespace perspective {
 
@@ -53,17 +62,49 @@ public:
         std::string expression_string,
         std::string parsed_expression_string,
         const std::vector<std::pair<std::string, std::string>>& column_ids,
-        t_dtype dtype
+        t_dtype dtype,
+        std::string timezone = ""
     );
 
+    /**
+     * @brief Compute the expression for each row of `source_table` into
+     * `destination_table`. `master` is the gnode's master table when
+     * `source_table` holds only the rows of an update, so functions that
+     * summarize the whole table still see every row; it defaults to
+     * `source_table` itself.
+     */
     void compute(
         const std::shared_ptr<t_data_table>& source_table,
         const t_gstate::t_mapping& pkey_map,
         const std::shared_ptr<t_data_table>& destination_table,
         t_expression_vocab& vocab,
+        t_regex_mapping& regex_mapping,
+        const std::shared_ptr<t_data_table>& master = nullptr
+    ) const;
+
+    /**
+     * @brief Compute the expression for only the given rows of
+     * `source_table`, writing each into the same row of
+     * `destination_table` and leaving the others untouched.
+     */
+    void compute_rows(
+        const std::shared_ptr<t_data_table>& source_table,
+        const t_gstate::t_mapping& pkey_map,
+        const std::shared_ptr<t_data_table>& destination_table,
+        const std::vector<t_uindex>& rows,
+        t_expression_vocab& vocab,
         t_regex_mapping& regex_mapping
     ) const;
 
+    /**
+     * @brief Whether the expression's value for a row depends only on that
+     * row, so an update need only recompute the rows it touched. Functions
+     * such as `vlookup()`, `order()`, `now()` and the group statistics read
+     * other rows or the clock, and make an expression depend on the whole
+     * table.
+     */
+    bool is_row_local() const;
+
     const std::string& get_expression_alias() const;
     const std::string& get_expression_string() const;
     const std::string& get_parsed_expression_string() const;
@@ -71,14 +112,57 @@ public:
     get_column_ids() const;
     t_dtype get_dtype() const;
 
+    /**
+     * @brief The time zone that calendar functions such as `bucket()` and
+     * `day_of_week()` use, or the empty string for the process's local time.
+     */
+    const std::string& get_timezone() const;
+
 private:
+    void _compute(
+        const std::shared_ptr<t_data_table>& source_table,
+        const t_gstate::t_mapping& pkey_map,
+        const std::shared_ptr<t_data_table>& destination_table,
+        const std::vector<t_uindex>* rows,
+        t_expression_vocab& vocab,
+        t_regex_mapping& regex_mapping,
+        const std::shared_ptr<t_data_table>& master
+    ) const;
+
     std::string m_expression_alias;
     std::string m_expression_string;
     std::string m_parsed_expression_string;
     std::vector<std::pair<std::string, std::string>> m_column_ids;
     t_dtype m_dtype;
+    std::string m_timezone;
+    bool m_is_row_local;
 };
 
+/**
+ * @brief The rows of the gnode's master table holding the primary keys of
+ * `flattened`, skipping keys that are no longer in the table.
+ */
+PERSPECTIVE_EXPORT std::vector<t_uindex> get_master_rows(
+    const std::shared_ptr<t_data_table>& flattened,
+    const t_gstate::t_mapping& pkey_map
+);
+
+/**
+ * @brief Compute `expressions` on the gnode's `master` table into
+ * `destination_table` after the update `flattened`. Row-local expressions
+ * are only recomputed for the master rows the update touched, which are
+ * the only rows whose value can have changed.
+ */
+PERSPECTIVE_EXPORT void compute_master_expressions(
+    const std::vector<std::shared_ptr<t_computed_expression>>& expressions,
+    const std::shared_ptr<t_data_table>& master,
+    const std::shared_ptr<t_data_table>& flattened,
+    const t_gstate::t_mapping& pkey_map,
+    const std::shared_ptr<t_data_table>& destination_table,
+    t_expression_vocab& vocab,
+    t_regex_mapping& regex_mapping
+);
+
 class PERSPECTIVE_EXPORT t_computed_expression_parser {
 public:
     static void init();
@@ -145,23 +229,41 @@ public:
         t_regex_mapping& regex_mapping
     );
 
+#ifndef PSP_DISABLE_EXPRESSIONS
     static std::shared_ptr<exprtk::parser<t_tscalar>> PARSER;
 
     // Applied to the parser
     static std::size_t PARSER_COMPILE_OPTIONS;
 
     // Static computed functions have no state
-    static computed_function::bucket BUCKET_FN;
-    static computed_function::hour_of_day HOUR_OF_DAY_FN;
     static computed_function::percent_of PERCENT_OF_FN;
     static computed_function::inrange_fn INRANGE_FN;
+    static computed_function::haversine_distance HAVERSINE_DISTANCE_FN;
+    static computed_function::within_bbox WITHIN_BBOX_FN;
+    static computed_function::in_subnet IN_SUBNET_FN;
+    static computed_function::round_to_tick ROUND_TO_TICK_FN;
+    static computed_function::bps BPS_FN;
+    static computed_function::pv PV_FN;
+    static computed_function::fv FV_FN;
+    static computed_function::rate RATE_FN;
+    static computed_function::irr IRR_FN;
+    static computed_function::year_frac YEAR_FRAC_FN;
     static computed_function::min_fn MIN_FN;
     static computed_function::max_fn MAX_FN;
+    static computed_function::sum_fn SUM_FN;
     static computed_function::diff3 diff3;
     static computed_function::norm3 norm3;
     static computed_function::cross_product3 cross_product3;
     static computed_function::dot_product3 dot_product3;
     static computed_function::length LENGTH_FN;
+    static computed_function::len LEN_FN;
+    static computed_function::contains CONTAINS_FN;
+    static computed_function::levenshtein LEVENSHTEIN_FN;
+    static computed_function::jaro_winkler JARO_WINKLER_FN;
+    static computed_function::starts_with STARTS_WITH_FN;
+    static computed_function::ends_with ENDS_WITH_FN;
+    static computed_function::json_extract_float JSON_EXTRACT_FLOAT_FN;
+    static computed_function::json_extract_bool JSON_EXTRACT_BOOL_FN;
     static computed_function::is_null IS_NULL_FN;
     static computed_function::is_not_null IS_NOT_NULL_FN;
     static computed_function::to_integer TO_INTEGER_FN;
@@ -169,11 +271,13 @@ public:
     static computed_function::to_boolean TO_BOOLEAN_FN;
     static computed_function::make_date MAKE_DATE_FN;
     static computed_function::make_datetime MAKE_DATETIME_FN;
+    static computed_function::make_duration MAKE_DURATION_FN;
     static computed_function::random RANDOM_FN;
 
     // constants for True and False as DTYPE_BOOL scalars
     static t_tscalar TRUE_SCALAR;
     static t_tscalar FALSE_SCALAR;
+#endif
 };
 
 /**
@@ -196,6 +300,7 @@ struct PERSPECTIVE_EXPORT t_validated_expression_map {
     std::map<std::string, t_expression_error> m_expression_errors;
 };
 
+#ifndef PSP_DISABLE_EXPRESSIONS
 /**
  * @brief Store instances of each computed function for use in
  * t_computed_expression::compute, t_computed_expression::precompute,
@@ -211,7 +316,9 @@ struct PERSPECTIVE_EXPORT t_computed_function_store {
         bool is_type_validator,
         const std::shared_ptr<t_data_table>& source_table,
         const t_gstate::t_mapping& pkey_map,
-        t_uindex& row_idx
+        t_uindex& row_idx,
+        const t_tz* timezone = nullptr,
+        const std::shared_ptr<t_data_table>& master = nullptr
     );
 
     void register_computed_functions(exprtk::symbol_table<t_tscalar>& sym_table
@@ -224,7 +331,10 @@ struct PERSPECTIVE_EXPORT t_computed_function_store {
     void clear_computed_function_state();
 
     // Member functions are instances that must be initialized per-method call,
-    // as they have references to a `t_expression_vocab`.
+    // as they have references to a `t_expression_vocab`, or to the view's
+    // time zone.
+    computed_function::bucket m_bucket_fn;
+    computed_function::hour_of_day m_hour_of_day_fn;
     computed_function::day_of_week m_day_of_week_fn;
     computed_function::month_of_year m_month_of_year_fn;
     computed_function::intern m_intern_fn;
@@ -240,9 +350,20 @@ struct PERSPECTIVE_EXPORT t_computed_function_store {
     computed_function::substring m_substring_fn;
     computed_function::replace m_replace_fn;
     computed_function::replace_all m_replace_all_fn;
+    computed_function::regex_extract m_regex_extract_fn;
     computed_function::index m_index_fn;
     computed_function::col m_col_fn;
     computed_function::vlookup m_vlookup_fn;
+    computed_function::convert_tz m_convert_tz_fn;
+    computed_function::at_tz m_at_tz_fn;
+    computed_function::json_extract m_json_extract_fn;
+    computed_function::geohash m_geohash_fn;
+    computed_function::soundex m_soundex_fn;
+    computed_function::group_stat m_zscore_fn;
+    computed_function::group_stat m_percent_of_group_fn;
+    computed_function::group_stat m_percent_of_total_fn;
+    computed_function::group_stat m_rank_in_group_fn;
 };
+#endif
 
 } // end namespace perspective
\ No newline at end of file
@@ -124,12 +124,6 @@ namespace computed_function {
         t_tscalar m_sentinel;
     };
 
-    /**
-     * @brief Given a string column and a non-regex string literal, check
-     * whether each row in the string column contains the string literal.
-     */
-    STRING_FUNCTION_HEADER(contains)
-
     /**
      * @brief match(string, pattern) => True if the string or a substring
      * partially matches pattern, and False otherwise.
@@ -178,6 +172,14 @@ namespace computed_function {
      */
     REGEX_STRING_FUNCTION_HEADER(replace_all)
 
+    /**
+     * @brief regex_extract(string, pattern, group) => the substring matched
+     * by capturing group number `group` of the first match of pattern, where
+     * group 0 is the whole match, or null if the string does not match or
+     * the group is empty. Fails type checking if pattern has fewer groups.
+     */
+    REGEX_STRING_FUNCTION_HEADER(regex_extract)
+
 #define FUNCTION_HEADER(NAME)                                                  \
     struct NAME : public exprtk::igeneric_function<t_tscalar> {                \
         NAME();                                                                \
@@ -188,6 +190,54 @@ namespace computed_function {
     // Length of the string
     FUNCTION_HEADER(length)
 
+    // Number of elements in a list, or characters in a string
+    FUNCTION_HEADER(len)
+
+    /**
+     * @brief json_extract(doc, '$.path') => the value at path in a JSON
+     * document (or a string holding JSON text) as a string. Strings are
+     * returned unquoted, and objects and arrays as their JSON text.
+     */
+    STRING_FUNCTION_HEADER(json_extract)
+
+    // json_extract, coercing numbers, booleans and numeric strings to float
+    FUNCTION_HEADER(json_extract_float)
+
+    // json_extract, coercing booleans, numbers and "true"/"false" to boolean
+    FUNCTION_HEADER(json_extract_bool)
+
+    /**
+     * @brief contains(list, value) => True if any element of the list equals
+     * value. For strings, contains(string, substring) checks whether the
+     * (non-regex) substring occurs in the string.
+     */
+    FUNCTION_HEADER(contains)
+
+    /**
+     * @brief levenshtein(a, b) => the number of single character insertions,
+     * deletions and substitutions needed to turn string a into string b.
+     */
+    FUNCTION_HEADER(levenshtein)
+
+    /**
+     * @brief jaro_winkler(a, b) => the Jaro-Winkler similarity of two
+     * strings, from 0 (no characters in common) to 1 (identical).
+     */
+    FUNCTION_HEADER(jaro_winkler)
+
+    /**
+     * @brief soundex(string) => the four character American Soundex code of
+     * a name, e.g. soundex('Robert') == 'R163', or null if the string has no
+     * ASCII letters.
+     */
+    STRING_FUNCTION_HEADER(soundex)
+
+    // starts_with(string, prefix) => True if string begins with prefix
+    FUNCTION_HEADER(starts_with)
+
+    // ends_with(string, suffix) => True if string ends with suffix
+    FUNCTION_HEADER(ends_with)
+
     struct index : public exprtk::igeneric_function<t_tscalar> {
         index(
             const t_pkey_mapping& pkey_map,
@@ -235,10 +285,104 @@ namespace computed_function {
         t_uindex& m_row_idx;
     };
 
+    /**
+     * @brief The statistic computed by a `group_stat` function.
+     */
+    enum t_group_stat {
+        GROUP_STAT_ZSCORE,
+        GROUP_STAT_PERCENT_OF_GROUP,
+        GROUP_STAT_PERCENT_OF_TOTAL,
+        GROUP_STAT_RANK
+    };
+
+    /**
+     * @brief A row's value measured against the other rows of its group,
+     * where a group is the rows sharing a value in a second column:
+     *
+     * zscore('x', 'g') => standard scores of x within each group of g, or
+     * across the whole table as zscore('x').
+     * percent_of_group('x', 'g') => x as a percentage of its group's total.
+     * percent_of_total('x') => x as a percentage of the column's total.
+     * rank_in_group('x', 'g') => the rank of x within its group, largest
+     * first, with ties sharing the lower rank.
+     *
+     * Columns are named by string literal, as in `col()`. Statistics are
+     * taken over every row of the table, not only the rows of an update.
+     */
+    struct group_stat : public exprtk::igeneric_function<t_tscalar> {
+        group_stat(
+            t_group_stat stat,
+            bool is_type_validator,
+            std::shared_ptr<t_data_table> source_table,
+            std::shared_ptr<t_data_table> master,
+            const t_pkey_mapping& pkey_map,
+            t_uindex& row_idx
+        );
+        ~group_stat();
+        t_tscalar operator()(t_parameter_list parameters);
+        void clear_groups();
+
+    private:
+        struct t_group {
+            double m_sum;
+            double m_stddev;
+            std::vector<double> m_sorted;
+        };
+
+        using t_groups = tsl::hopscotch_map<std::string, t_group>;
+
+        const t_groups& get_groups(
+            const std::string& value_column, const std::string& group_column
+        );
+
+        t_group_stat m_stat;
+        bool m_is_type_validator;
+        std::shared_ptr<t_data_table> m_source_table;
+        std::shared_ptr<t_data_table> m_master;
+        const t_pkey_mapping& m_pkey_map;
+        t_uindex& m_row_idx;
+
+        // Groups by value and group column name, built on first use.
+        std::map<std::pair<std::string, std::string>, t_groups> m_groups;
+    };
+
+    /**
+     * @brief Break a POSIX timestamp into calendar fields in `tz`, or in the
+     * process's local time if `tz` is null.
+     */
+    std::tm to_calendar_tm(std::int64_t timestamp, const t_tz* tz);
+
+// A date function that reads calendar fields in the view's time zone, which
+// is null if the view did not set one.
+#define CALENDAR_FUNCTION_HEADER(NAME)                                         \
+    struct NAME : public exprtk::igeneric_function<t_tscalar> {                \
+        NAME(const t_tz* timezone);                                            \
+        ~NAME();                                                               \
+        t_tscalar operator()(t_parameter_list parameters);                     \
+        const t_tz* m_timezone;                                                \
+    };
+
+// A string-returning date function that reads calendar fields in the view's
+// time zone.
+#define CALENDAR_STRING_FUNCTION_HEADER(NAME)                                  \
+    struct NAME : public exprtk::igeneric_function<t_tscalar> {                \
+        NAME(                                                                  \
+            t_expression_vocab& expression_vocab,                              \
+            bool is_type_validator,                                            \
+            const t_tz* timezone                                               \
+        );                                                                     \
+        ~NAME();                                                               \
+        t_tscalar operator()(t_parameter_list parameters);                     \
+        t_expression_vocab& m_expression_vocab;                                \
+        t_tscalar m_sentinel;                                                  \
+        bool m_is_type_validator;                                              \
+        const t_tz* m_timezone;                                                \
+    };
+
     /**
      * @brief Return the hour of the day the date/datetime belongs to.
      */
-    FUNCTION_HEADER(hour_of_day)
+    CALENDAR_FUNCTION_HEADER(hour_of_day)
 
     // Day of Week/Month of Year write strings directly, and use custom strings
     // so they are sorted by day/month and *not* alphabetically
@@ -247,10 +391,10 @@ namespace computed_function {
     extern const std::string months_of_year[12];
 
     // Return the day of the week the date/datetime belongs to
-    STRING_FUNCTION_HEADER(day_of_week)
+    CALENDAR_STRING_FUNCTION_HEADER(day_of_week)
 
     // Return the month of the year the date/datetime belongs to
-    STRING_FUNCTION_HEADER(month_of_year)
+    CALENDAR_STRING_FUNCTION_HEADER(month_of_year)
 
     enum t_date_bucket_unit {
         SECONDS,
@@ -270,14 +414,18 @@ namespace computed_function {
      *
      * - If the input is a number, the unit is also a number.
      *
-     * Any other inputs are invalid.
+     * Any other inputs are invalid. Hour and larger units bucket on the
+     * wall clock of the view's time zone, so days stay aligned to local
+     * midnight across daylight saving transitions.
      */
     struct bucket : public exprtk::igeneric_function<t_tscalar> {
-        bucket();
+        bucket(const t_tz* timezone);
         ~bucket();
 
         t_tscalar operator()(t_parameter_list parameters);
 
+        const t_tz* m_timezone;
+
         // faster unit lookups, since we are calling this lookup in a tight
         // loop.
         static tsl::hopscotch_map<char, t_date_bucket_unit> UNIT_MAP;
@@ -285,11 +433,26 @@ namespace computed_function {
 
     void _second_bucket(t_tscalar& val, t_tscalar& rval, t_uindex multiplicity);
     void _minute_bucket(t_tscalar& val, t_tscalar& rval, t_uindex multiplicity);
-    void _hour_bucket(t_tscalar& val, t_tscalar& rval, t_uindex multiplicity);
-    void _day_bucket(t_tscalar& val, t_tscalar& rval);
-    void _week_bucket(t_tscalar& val, t_tscalar& rval);
-    void _month_bucket(t_tscalar& val, t_tscalar& rval, t_uindex multiplicity);
-    void _year_bucket(t_tscalar& val, t_tscalar& rval, t_uindex multiplicity);
+    void _hour_bucket(
+        t_tscalar& val,
+        t_tscalar& rval,
+        t_uindex multiplicity,
+        const t_tz* tz
+    );
+    void _day_bucket(t_tscalar& val, t_tscalar& rval, const t_tz* tz);
+    void _week_bucket(t_tscalar& val, t_tscalar& rval, const t_tz* tz);
+    void _month_bucket(
+        t_tscalar& val,
+        t_tscalar& rval,
+        t_uindex multiplicity,
+        const t_tz* tz
+    );
+    void _year_bucket(
+        t_tscalar& val,
+        t_tscalar& rval,
+        t_uindex multiplicity,
+        const t_tz* tz
+    );
 
     /**
      * @brief Returns the current datetime. Will be recalculated on view
@@ -323,6 +486,12 @@ namespace computed_function {
      */
     FUNCTION_HEADER(max_fn)
 
+    /**
+     * @brief Get the sum of all the inputs. A list input contributes the sum
+     * of its numeric elements.
+     */
+    FUNCTION_HEADER(sum_fn)
+
     /**
      * @brief Get the cross product of two vec3s
      */
@@ -349,6 +518,74 @@ namespace computed_function {
      */
     FUNCTION_HEADER(percent_of)
 
+    /**
+     * @brief haversine_distance(lat1, lon1, lat2, lon2) => the great-circle
+     * distance in kilometers between two points given in degrees.
+     */
+    FUNCTION_HEADER(haversine_distance)
+
+    /**
+     * @brief within_bbox(lat, lon, min_lat, min_lon, max_lat, max_lon) =>
+     * whether a point lies inside a bounding box (inclusive). A box whose
+     * min_lon is greater than its max_lon crosses the antimeridian.
+     */
+    FUNCTION_HEADER(within_bbox)
+
+    /**
+     * @brief geohash(lat, lon, precision) => the geohash of a point, with
+     * precision (1 - 12) characters, for bucketing points with `group_by`.
+     */
+    STRING_FUNCTION_HEADER(geohash)
+
+    /**
+     * @brief in_subnet(ip, '10.0.0.0/8') => whether an IP address (or a
+     * string holding one) lies within a CIDR block.
+     */
+    FUNCTION_HEADER(in_subnet)
+
+    /**
+     * @brief round_to_tick(x, tick) => x rounded to the nearest multiple of
+     * tick.
+     */
+    FUNCTION_HEADER(round_to_tick)
+
+    /**
+     * @brief bps(x, base) => the change from base to x in basis points.
+     */
+    FUNCTION_HEADER(bps)
+
+    /**
+     * @brief pv(rate, nper, pmt, fv) => the present value of an annuity,
+     * with spreadsheet sign conventions.
+     */
+    FUNCTION_HEADER(pv)
+
+    /**
+     * @brief fv(rate, nper, pmt, pv) => the future value of an annuity,
+     * with spreadsheet sign conventions.
+     */
+    FUNCTION_HEADER(fv)
+
+    /**
+     * @brief rate(nper, pmt, pv, fv) => the per-period interest rate of an
+     * annuity, solved iteratively; null if it does not converge.
+     */
+    FUNCTION_HEADER(rate)
+
+    /**
+     * @brief irr(flows...) => the internal rate of return of a list of cash
+     * flows, or of two or more numbers, solved iteratively; null if it does
+     * not converge.
+     */
+    FUNCTION_HEADER(irr)
+
+    /**
+     * @brief year_frac(start, end, 'act/360') => the years between two dates
+     * under a day count convention: 'act/360', 'act/365', '30/360' or
+     * 'act/act'.
+     */
+    FUNCTION_HEADER(year_frac)
+
     /**
      * @brief Whether the input is null.
      *
@@ -403,6 +640,38 @@ namespace computed_function {
      */
     FUNCTION_HEADER(make_datetime)
 
+    /**
+     * @brief Given a number of milliseconds, create a new duration value.
+     * Durations are also the result of subtracting two datetimes.
+     */
+    FUNCTION_HEADER(make_duration)
+
+// A function taking a time zone name, which caches the most recently parsed
+// zone.
+#define TIMEZONE_FUNCTION_HEADER(NAME)                                         \
+    struct NAME : public exprtk::igeneric_function<t_tscalar> {                \
+        NAME();                                                                \
+        ~NAME();                                                               \
+        t_tscalar operator()(t_parameter_list parameters);                     \
+        const t_tz* get_timezone(const std::string& name);                     \
+        std::string m_timezone_name;                                           \
+        t_tz m_timezone;                                                       \
+        bool m_timezone_valid;                                                 \
+    };
+
+    /**
+     * @brief `convert_tz(datetime, 'zone')` returns the wall-clock time of
+     * `datetime` in `zone`, as a datetime with no offset applied.
+     */
+    TIMEZONE_FUNCTION_HEADER(convert_tz)
+
+    /**
+     * @brief `at_tz(datetime, 'zone')` is the inverse of `convert_tz`: it
+     * reads `datetime` as a wall-clock time in `zone` and returns the instant
+     * it names.
+     */
+    TIMEZONE_FUNCTION_HEADER(at_tz)
+
     /**
      * @brief Return a random float between 0.0 and 1.0, inclusive.
      */
@@ -23,6 +23,20 @@
 
 namespace perspective {
 
+// Rank of each declared category of a column, by category value.
+using t_category_ranks = std::map<std::string, t_index>;
+
+/**
+ * @brief Map a string `value` to its rank in `ranks`, as an `int64` scalar
+ * which sorts in category order. Values which are not categories rank after
+ * every category, and nulls stay null.
+ *
+ * @param ranks
+ * @param value
+ * @return t_tscalar
+ */
+t_tscalar category_rank(const t_category_ranks& ranks, const t_tscalar& value);
+
 /**
  * @brief `t_config` contains metadata for the `View` and `t_ctx*` structures,
  * containing specifications for how pivots, columns, filters, and sorts should
@@ -184,6 +198,16 @@ public:
 
     const std::vector<t_fterm>& get_fterms() const;
 
+    /**
+     * @brief Replace the filter terms and their combiner. A context only
+     * applies its filter as it is populated, so it must be reset and
+     * repopulated after this is called.
+     *
+     * @param fterms
+     * @param combiner
+     */
+    void set_filter(const std::vector<t_fterm>& fterms, t_filter_op combiner);
+
     std::vector<std::shared_ptr<t_computed_expression>> get_expressions() const;
 
     t_totals get_totals() const;
@@ -205,6 +229,75 @@ public:
         return m_grand_agg_str;
     }
 
+    /**
+     * @brief Pivot and sort `colname` in the order of `categories` rather
+     * than lexicographically.
+     *
+     * @param colname
+     * @param categories
+     */
+    void set_category_order(
+        const std::string& colname, const std::vector<std::string>& categories
+    );
+
+    const std::map<std::string, t_category_ranks>& get_category_ranks() const;
+
+    /**
+     * @brief The value to sort `value` of `colname` by - its category rank if
+     * `colname` has a category order, otherwise `value` itself.
+     *
+     * @param colname
+     * @param value
+     * @return t_tscalar
+     */
+    t_tscalar
+    get_category_sort_value(const std::string& colname, const t_tscalar& value)
+        const;
+
+    /**
+     * @brief Filter out rows whose row or column pivot value is null, rather
+     * than grouping them under a null group. Must be called after the pivots
+     * are set.
+     *
+     * @param exclude
+     */
+    void set_exclude_null_groups(bool exclude);
+
+    /**
+     * @brief The `is not null` terms on each pivot column which exclude null
+     * groups, which are applied in addition to (and regardless of the
+     * combiner of) `get_fterms()`.
+     *
+     * @return const std::vector<t_fterm>&
+     */
+    const std::vector<t_fterm>& get_null_group_fterms() const;
+
+    void set_null_aggregates(t_null_aggregates null_aggregates);
+    t_null_aggregates get_null_aggregates() const;
+
+    /**
+     * @brief Where a row pivoted context places its grand total row and the
+     * subtotal row of each expanded group, relative to the rows they total.
+     * `TOTALS_HIDDEN` leaves them out of the context's rows.
+     *
+     * @param grand_total
+     * @param subtotals
+     */
+    void set_row_totals(t_totals grand_total, t_totals subtotals);
+    t_totals get_row_grand_total() const;
+    t_totals get_row_subtotals() const;
+
+    /**
+     * @brief How a pivoted context shows each aggregate's values, keyed by
+     * aggregate name. Aggregates which are not keys show their values.
+     *
+     * @param show_values_as
+     */
+    void set_show_values_as(
+        const std::map<std::string, t_show_values_as>& show_values_as
+    );
+    t_show_values_as get_show_values_as(t_uindex aggidx) const;
+
 protected:
     void populate_sortby(const std::vector<t_pivot>& pivots);
 
@@ -215,9 +308,15 @@ private:
     std::vector<t_pivot> m_col_pivots;
     std::vector<t_aggspec> m_aggregates;
     std::map<std::string, std::string> m_sortby;
+    std::map<std::string, t_category_ranks> m_category_ranks;
     std::vector<t_sortspec> m_sortspecs;
     std::vector<t_sortspec> m_col_sortspecs;
     std::vector<t_fterm> m_fterms;
+    std::vector<t_fterm> m_null_group_fterms;
+    t_null_aggregates m_null_aggregates{NULL_AGGREGATES_DEFAULT};
+    t_totals m_row_grand_total{TOTALS_BEFORE};
+    t_totals m_row_subtotals{TOTALS_BEFORE};
+    std::vector<t_show_values_as> m_show_values_as;
     std::vector<std::shared_ptr<t_computed_expression>> m_expressions;
     t_filter_op m_combiner;
     bool m_column_only;
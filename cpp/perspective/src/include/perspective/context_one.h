@@ -54,12 +54,23 @@ public:
     using t_ctxbase<t_ctx1>::get_data;
 
 private:
+    // Map between the rows this context shows and traversal rows, which
+    // differ when the grand total or subtotals are moved or hidden.
+    void update_row_order();
+    t_index to_traversal_idx(t_index idx) const;
+    t_index from_traversal_idx(t_index idx) const;
+
+    t_tscalar show_value_as(t_uindex aggidx, const t_tscalar& value) const;
+
     std::shared_ptr<t_traversal> m_traversal;
     std::shared_ptr<t_stree> m_tree;
     std::vector<t_sortspec> m_sortby;
     std::shared_ptr<t_expression_tables> m_expression_tables;
     t_depth m_depth;
     bool m_depth_set;
+    bool m_has_row_order;
+    std::vector<t_index> m_row_order;
+    std::vector<t_index> m_row_order_inverse;
 };
 
 } // end namespace perspective
@@ -81,6 +81,26 @@ protected:
 
     t_uindex calc_translated_colidx(t_uindex n_aggs, t_uindex cidx) const;
 
+    // The view column of the aggregate `agg_idx` under the column traversal
+    // node at `translated_cidx` in `get_ctraversal_indices()`.
+    t_uindex calc_view_colidx(
+        t_uindex n_aggs, t_uindex translated_cidx, t_uindex agg_idx
+    ) const;
+
+    t_tscalar
+    get_tree_aggregate(t_uindex treenum, t_index nidx, t_index agg_idx) const;
+
+    t_tscalar show_value_as(
+        const t_cellinfo& cinfo,
+        const t_tscalar& value,
+        const std::vector<t_index>& c_tvindices
+    ) const;
+
+    // Map the rows this context shows to row traversal rows, which differ
+    // when the grand total or subtotals are moved or hidden.
+    void update_row_order();
+    t_index to_traversal_idx(t_index idx) const;
+
 private:
     std::shared_ptr<t_traversal> m_rtraversal;
     std::shared_ptr<t_traversal> m_ctraversal;
@@ -92,6 +112,8 @@ private:
     bool m_row_depth_set;
     t_depth m_column_depth;
     bool m_column_depth_set;
+    bool m_has_row_order;
+    std::vector<t_index> m_row_order;
     std::shared_ptr<t_expression_tables> m_expression_tables;
 };
 
@@ -125,6 +125,12 @@ public:
 
     const tsl::hopscotch_set<t_tscalar>& get_delta_pkeys() const;
 
+    /**
+     * @brief The primary keys of the rows removed from this context by the
+     * current step, in the order they were removed.
+     */
+    const std::vector<t_tscalar>& get_removed_pkeys() const;
+
     // Unity api
     std::vector<t_tscalar> unity_get_row_data(t_uindex idx) const;
     std::vector<t_tscalar> unity_get_row_path(t_uindex idx) const;
@@ -158,6 +164,7 @@ private:
      * to the master table's data.
      */
     tsl::hopscotch_set<t_tscalar> m_delta_pkeys;
+    std::vector<t_tscalar> m_removed_pkeys;
 
     t_symtable m_symtable;
     bool m_has_delta;
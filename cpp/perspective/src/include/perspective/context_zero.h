@@ -44,6 +44,12 @@ public:
 
     const tsl::hopscotch_set<t_tscalar>& get_delta_pkeys() const;
 
+    /**
+     * @brief The primary keys of the rows removed from this context by the
+     * current step, in the order they were removed.
+     */
+    const std::vector<t_tscalar>& get_removed_pkeys() const;
+
     void sort_by();
     std::vector<t_sortspec> get_sort_by() const;
 
@@ -105,6 +111,7 @@ private:
     std::shared_ptr<t_ftrav> m_traversal;
     std::shared_ptr<t_zcdeltas> m_deltas;
     tsl::hopscotch_set<t_tscalar> m_delta_pkeys;
+    std::vector<t_tscalar> m_removed_pkeys;
     std::shared_ptr<t_expression_tables> m_expression_tables;
     t_symtable m_symtable;
     bool m_has_delta;
@@ -16,6 +16,8 @@
 #include <perspective/column.h>
 #include <perspective/schema.h>
 #include <perspective/schema_column.h>
+#include <perspective/uuid.h>
+#include <perspective/ipaddr.h>
 #include <perspective/exports.h>
 #include <perspective/mask.h>
 #include <perspective/filter.h>
@@ -142,6 +144,16 @@ public:
     void clear();
     void reset();
 
+    /**
+     * @brief Compress every column of this table, see `t_column::compress`.
+     */
+    void compress();
+
+    /**
+     * @brief The total `t_column::get_storage_bytes` of every column.
+     */
+    t_storage_bytes get_storage_bytes() const;
+
     t_mask
     filter_cpp(t_filter_op combiner, const std::vector<t_fterm>& fterms_) const;
     t_data_table* clone_(const t_mask& mask) const;
@@ -292,13 +304,23 @@ t_data_table::flatten_body(FLATTENED_T flattened) const {
         case DTYPE_UINT8: {
             flatten_helper_1<FLATTENED_T, std::uint8_t>(flattened);
         } break;
-        case DTYPE_TIME: {
+        case DTYPE_TIME:
+        case DTYPE_DURATION: {
             flatten_helper_1<FLATTENED_T, std::int64_t>(flattened);
         } break;
         case DTYPE_DATE: {
             flatten_helper_1<FLATTENED_T, std::uint32_t>(flattened);
         } break;
-        case DTYPE_STR: {
+        case DTYPE_UUID: {
+            flatten_helper_1<FLATTENED_T, t_uuid>(flattened);
+        } break;
+        case DTYPE_IPADDR: {
+            flatten_helper_1<FLATTENED_T, t_ipaddr>(flattened);
+        } break;
+        case DTYPE_STR:
+        case DTYPE_LIST:
+        case DTYPE_JSON:
+        case DTYPE_BINARY: {
             flatten_helper_1<FLATTENED_T, t_uindex>(flattened);
         } break;
         case DTYPE_FLOAT64: {
@@ -527,7 +549,8 @@ t_data_table::flatten_helper_1(FLATTENED_T flattened) const {
                         sorted, fltrecs, scol, dcol
                     );
                 } break;
-                case DTYPE_TIME: {
+                case DTYPE_TIME:
+                case DTYPE_DURATION: {
                     this->flatten_helper_2<std::int64_t, t_rpvec>(
                         sorted, fltrecs, scol, dcol
                     );
@@ -537,7 +560,20 @@ t_data_table::flatten_helper_1(FLATTENED_T flattened) const {
                         sorted, fltrecs, scol, dcol
                     );
                 } break;
-                case DTYPE_STR: {
+                case DTYPE_UUID: {
+                    this->flatten_helper_2<t_uuid, t_rpvec>(
+                        sorted, fltrecs, scol, dcol
+                    );
+                } break;
+                case DTYPE_IPADDR: {
+                    this->flatten_helper_2<t_ipaddr, t_rpvec>(
+                        sorted, fltrecs, scol, dcol
+                    );
+                } break;
+                case DTYPE_STR:
+                case DTYPE_LIST:
+                case DTYPE_JSON:
+                case DTYPE_BINARY: {
                     this->flatten_helper_2<t_uindex, t_rpvec>(
                         sorted, fltrecs, scol, dcol
                     );
@@ -555,7 +591,7 @@ t_data_table::flatten_helper_1(FLATTENED_T flattened) const {
         [&flattened, this](int colidx) {
             const auto& colname = this->m_schema.m_columns[colidx];
             auto col = get_const_column(colname).get();
-            if (col->get_dtype() == DTYPE_STR) {
+            if (col->is_vlen()) {
                 flattened->get_column(colname)->copy_vocabulary(col);
             }
         }
@@ -0,0 +1,55 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+
+#pragma once
+
+#include <perspective/first.h>
+#include <perspective/exports.h>
+#include <perspective/base.h>
+#include <vector>
+
+namespace perspective {
+
+/**
+ * @brief A series of `(x, y)` points selected from a `View` by `downsample`.
+ */
+struct PERSPECTIVE_EXPORT t_downsampled_series {
+    std::vector<double> m_x;
+    std::vector<double> m_y;
+};
+
+/**
+ * @brief Select at most `n_points` of the points `(x[i], y[i])` which best
+ * preserve the visual shape of the line through them, per the
+ * Largest-Triangle-Three-Buckets algorithm. The first and last points are
+ * always selected; of the points between, which are split into
+ * `n_points - 2` equal-sized buckets, the one forming the largest triangle
+ * with the previously selected point and the average of the next bucket is
+ * selected from each bucket.
+ *
+ * `x` must be in ascending order. If `n_points` is less than 3 or at least
+ * the number of points, every point is selected.
+ *
+ * @param x
+ * @param y
+ * @param n_points
+ * @return std::vector<t_uindex> The indices of the selected points, in
+ * ascending order.
+ */
+PERSPECTIVE_EXPORT std::vector<t_uindex> lttb(
+    const std::vector<double>& x,
+    const std::vector<double>& y,
+    t_uindex n_points
+);
+
+} // namespace perspective
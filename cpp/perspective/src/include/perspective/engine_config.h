@@ -0,0 +1,50 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+#pragma once
+
+#include <perspective/first.h>
+#include <perspective/exports.h>
+#include <perspective/base.h>
+
+namespace perspective {
+
+/**
+ * @brief Engine tunables which were previously compile-time constants, for
+ * large deployments to tune without patching the engine. These are
+ * process-wide, and read when they are used, so a change applies to tables
+ * and views created (and work started) after it.
+ */
+struct PERSPECTIVE_EXPORT t_engine_config {
+    // `parallel_for()` runs fewer tasks than this inline on the calling
+    // thread, rather than dispatching them to the thread pool. Only builds
+    // with `PSP_PARALLEL_FOR` have a thread pool.
+    t_uindex m_parallel_threshold = 0;
+
+    // Rows per block evaluated by `t_vectorized_expression`.
+    t_uindex m_expression_block_size = 1024;
+
+    // The initial row capacity of a `t_dtree`'s columns.
+    t_uindex m_tree_capacity = DEFAULT_CAPACITY;
+
+    // The initial row capacity of a `t_port`, which queues a gnode's updates
+    // until it is processed.
+    t_uindex m_port_capacity = DEFAULT_EMPTY_CAPACITY;
+};
+
+PERSPECTIVE_EXPORT t_engine_config get_engine_config();
+
+/**
+ * @brief Replace the process-wide `t_engine_config`. Sizes must be non-zero.
+ */
+PERSPECTIVE_EXPORT void set_engine_config(const t_engine_config& config);
+
+} // namespace perspective
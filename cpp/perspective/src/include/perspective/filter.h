@@ -158,6 +158,10 @@ struct PERSPECTIVE_EXPORT t_fterm {
             case FILTER_OP_IN: {
                 rv = std::find(m_bag.begin(), m_bag.end(), s) != m_bag.end();
             } break;
+            case FILTER_OP_IN_SUBNET: {
+                // The bag holds the first and last address of the block.
+                rv = s.is_valid() && s >= m_bag[0] && s <= m_bag[1];
+            } break;
             default: {
                 rv = s.cmp(m_op, m_threshold);
             } break;
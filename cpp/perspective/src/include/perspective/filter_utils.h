@@ -23,7 +23,18 @@ filter_table_for_config(const t_data_table& tbl, const t_config& config) {
 
     switch (config.get_fmode()) {
         case FMODE_SIMPLE_CLAUSES: {
-            return tbl.filter_cpp(config.get_combiner(), config.get_fterms());
+            const auto& fterms = config.get_fterms();
+            const auto& null_group_fterms = config.get_null_group_fterms();
+            if (fterms.empty()) {
+                return tbl.filter_cpp(FILTER_OP_AND, null_group_fterms);
+            }
+
+            auto mask = tbl.filter_cpp(config.get_combiner(), fterms);
+            if (!null_group_fterms.empty()) {
+                mask &= tbl.filter_cpp(FILTER_OP_AND, null_group_fterms);
+            }
+
+            return mask;
         } break;
         default: {
         }
@@ -23,8 +23,11 @@
 #include <perspective/gnode_state.h>
 #include <perspective/sparse_tree.h>
 #include <perspective/process_state.h>
+#include <perspective/update_stats.h>
 #include <perspective/computed_expression.h>
+#ifndef PSP_DISABLE_EXPRESSIONS
 #include <perspective/computed_function.h>
+#endif
 #include <perspective/expression_tables.h>
 #include <perspective/regex.h>
 #include <tsl/ordered_map.h>
@@ -72,6 +75,7 @@ class t_ctx_grouped_pkey;
 struct PERSPECTIVE_EXPORT t_process_table_result {
     std::shared_ptr<t_data_table> m_flattened_data_table;
     bool m_should_notify_userspace;
+    t_update_counts m_counts;
 };
 class PERSPECTIVE_EXPORT t_gnode {
 public:
@@ -118,6 +122,18 @@ public:
      */
     bool process(t_uindex port_id);
 
+    /**
+     * @brief Compress the columns of the master table, see
+     * `t_data_table::compress`.
+     */
+    void compress();
+
+    /**
+     * @brief The size of the master table's columns, see
+     * `t_data_table::get_storage_bytes`.
+     */
+    t_storage_bytes get_storage_bytes() const;
+
     /**
      * @brief Create a new input port, store it in `m_input_ports`, and
      * return the integer ID that references the new port.
@@ -154,6 +170,21 @@ public:
      */
     void _unregister_context(const std::string& name);
 
+    /**
+     * @brief Set the expressions which this gnode computes into columns of
+     * the master table itself, once per update, so that every context reads
+     * them as "real" columns. Each expression's alias must be a column of
+     * the gnode's schema, and any value an update writes to it is replaced.
+     *
+     * @param virtual_columns
+     */
+    void set_virtual_columns(
+        std::vector<std::shared_ptr<t_computed_expression>> virtual_columns
+    );
+
+    const std::vector<std::shared_ptr<t_computed_expression>>&
+    get_virtual_columns() const;
+
     const t_data_table* get_table() const;
     t_data_table* get_table();
 
@@ -192,6 +223,12 @@ public:
 
     void set_pool_cleanup(std::function<void()> cleanup);
     bool was_updated() const;
+
+    /**
+     * @brief Counters of the rows added, updated and removed by every call
+     * to `process`.
+     */
+    const t_update_stats& get_update_stats() const;
     void clear_updated();
 
     t_uindex mapping_size() const;
@@ -312,6 +349,21 @@ protected:
         const t_process_state& process_state
     );
 
+    /**
+     * @brief As `_process_column`, for fixed width types with no arithmetic
+     * such as `t_uuid`, whose delta column is left zeroed.
+     */
+    template <typename T>
+    void _process_opaque_column(
+        const t_column* fcolumn,
+        const t_column* scolumn,
+        t_column* dcolumn,
+        t_column* pcolumn,
+        t_column* ccolumn,
+        t_column* tcolumn,
+        const t_process_state& process_state
+    );
+
     /**
      * @brief Calculate the transition state for a single cell, which depends
      * on whether the cell is/was valid, existed, or is new.
@@ -358,6 +410,21 @@ protected:
         const std::shared_ptr<t_data_table>& flattened
     );
 
+    /**
+     * @brief Compute the virtual columns of each row of the flattened table
+     * of the first update, in place.
+     */
+    void _compute_virtual_columns(const std::shared_ptr<t_data_table>& flattened
+    );
+
+    /**
+     * @brief Compute the virtual columns of each inserted row of a
+     * subsequent update from the row's current values, which the real
+     * columns must already have been processed into, and write them into
+     * the flattened table.
+     */
+    void _compute_virtual_columns(t_process_state& process_state);
+
 private:
     /**
      * @brief Process the input data table by flattening it, calculating
@@ -397,10 +464,13 @@ private:
     std::chrono::high_resolution_clock::time_point m_epoch;
     std::function<void()> m_pool_cleanup;
     bool m_was_updated;
+    t_update_stats m_update_stats;
 
     std::shared_ptr<t_expression_vocab> m_expression_vocab;
     std::shared_ptr<t_regex_mapping> m_expression_regex_mapping;
 
+    std::vector<std::shared_ptr<t_computed_expression>> m_virtual_columns;
+
 #ifdef PSP_PARALLEL_FOR
     std::shared_mutex* m_lock;
 #endif
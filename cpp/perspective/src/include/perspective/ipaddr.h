@@ -0,0 +1,103 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+#pragma once
+
+#include <perspective/first.h>
+#include <perspective/exports.h>
+#include <cstdint>
+#include <cstring>
+#include <ostream>
+#include <string>
+#include <string_view>
+
+namespace perspective {
+
+/**
+ * @brief An IPv4 or IPv6 address, stored as 16 bytes in network order. IPv4
+ * addresses are stored in their IPv4-mapped IPv6 form (`::ffff:a.b.c.d`), so
+ * byte-wise comparison orders addresses numerically and keeps each family
+ * together.
+ */
+struct PERSPECTIVE_EXPORT t_ipaddr {
+    std::uint8_t m_bytes[16];
+
+    /**
+     * @brief The IPv4-mapped address of 4 octets in network order.
+     */
+    static t_ipaddr from_v4(const std::uint8_t* octets);
+
+    /**
+     * @brief Whether this is an IPv4-mapped address.
+     */
+    bool is_v4() const;
+
+    /**
+     * @brief Dotted-quad form for IPv4 addresses, and the RFC 5952 canonical
+     * (lowercase, zero-compressed) form for IPv6.
+     */
+    std::string str() const;
+
+    bool
+    operator==(const t_ipaddr& rhs) const {
+        return std::memcmp(m_bytes, rhs.m_bytes, 16) == 0;
+    }
+
+    bool
+    operator!=(const t_ipaddr& rhs) const {
+        return !operator==(rhs);
+    }
+
+    bool
+    operator<(const t_ipaddr& rhs) const {
+        return std::memcmp(m_bytes, rhs.m_bytes, 16) < 0;
+    }
+};
+
+/**
+ * @brief A CIDR block, held as its first and last address so that membership
+ * is a pair of byte-wise comparisons.
+ */
+struct PERSPECTIVE_EXPORT t_subnet {
+    t_ipaddr m_first;
+    t_ipaddr m_last;
+
+    bool contains(const t_ipaddr& addr) const;
+
+    /**
+     * @brief The `address/prefix` form, e.g. `10.0.0.0/8`.
+     */
+    std::string str() const;
+};
+
+/**
+ * @brief Parse an IPv4 address in dotted-quad form, or an IPv6 address,
+ * including `::` compression and a trailing dotted-quad.
+ *
+ * @return `true` if `str` was a valid address, in which case `out` is set.
+ */
+PERSPECTIVE_EXPORT bool parse_ipaddr(std::string_view str, t_ipaddr& out);
+
+/**
+ * @brief Parse a CIDR block such as `10.0.0.0/8` or `2001:db8::/32`. Host
+ * bits below the prefix are ignored, and an address without a prefix is a
+ * block of one.
+ *
+ * @return `true` if `str` was a valid block, in which case `out` is set.
+ */
+PERSPECTIVE_EXPORT bool parse_subnet(std::string_view str, t_subnet& out);
+
+} // end namespace perspective
+
+namespace std {
+std::ostream& operator<<(std::ostream& os, const perspective::t_ipaddr& addr);
+} // namespace std
@@ -14,6 +14,7 @@
 
 #ifdef PSP_PARALLEL_FOR
 #include "base.h"
+#include "engine_config.h"
 #include <arrow/util/parallel.h>
 #include <arrow/status.h>
 #include <mutex>
@@ -27,6 +28,15 @@ template <class FUNCTION>
 void
 parallel_for(int num_tasks, FUNCTION&& func) {
 #ifdef PSP_PARALLEL_FOR
+    if (static_cast<t_uindex>(num_tasks)
+        < get_engine_config().m_parallel_threshold) {
+        for (int task = 0; task < num_tasks; ++task) {
+            func(task);
+        }
+
+        return;
+    }
+
     std::exception_ptr e;
     std::mutex e_mtx;
     const auto rethrow_wrapper = [&](int64_t task) {
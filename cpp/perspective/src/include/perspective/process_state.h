@@ -16,6 +16,7 @@
 #include <perspective/port.h>
 #include <perspective/schema.h>
 #include <perspective/rlookup.h>
+#include <perspective/update_stats.h>
 
 namespace perspective {
 
@@ -60,6 +61,7 @@ struct t_process_state {
     std::vector<bool> m_prev_pkey_eq_vec;
 
     std::uint8_t* m_op_base;
+    t_update_counts m_counts;
 };
 
 } // end namespace perspective
\ No newline at end of file
@@ -18,6 +18,8 @@
 #include <string_view>
 #include <vector>
 
+struct ArrowArrayStream;
+
 struct ProtoApiResponse {
     std::string data;
     std::uint32_t client_id;
@@ -37,8 +39,96 @@ public:
 
     [[nodiscard]]
     std::vector<ProtoApiResponse>
-    handle_request(std::uint32_t client_id, const std::string& data) const;
+    handle_request(
+        std::uint32_t client_id,
+        const std::string& data,
+        const std::string& request_id = ""
+    ) const;
+
+    [[nodiscard]]
+    std::vector<ProtoApiResponse> unhost_table(const std::string& table_id);
 
     [[nodiscard]]
     std::vector<ProtoApiResponse> poll();
+
+    /**
+     * @brief Milliseconds until a `poll()` is needed to commit held batched
+     * updates, or `-1` if none are held, see `ProtoServer::get_poll_delay`.
+     */
+    std::int64_t get_poll_delay_ms();
+
+    /**
+     * @brief Host a new table named `table_id` from the Arrow C stream
+     * `stream`, which is consumed (and released) by this call.
+     */
+    [[nodiscard]]
+    std::vector<ProtoApiResponse> host_arrow_stream(
+        const std::string& table_id,
+        const std::string& index,
+        ArrowArrayStream* stream
+    );
+
+    /**
+     * @brief Update the hosted table `table_id` from the Arrow C stream
+     * `stream`, which is consumed (and released) by this call. The update is
+     * processed on the next `poll()`.
+     */
+    void update_arrow_stream(
+        const std::string& table_id,
+        ArrowArrayStream* stream,
+        std::uint32_t port_id
+    );
+
+    /**
+     * @brief Export the hosted view `view_id` to the uninitialized Arrow C
+     * stream `out`, which the caller must release.
+     */
+    [[nodiscard]]
+    std::vector<ProtoApiResponse>
+    view_to_arrow_stream(const std::string& view_id, ArrowArrayStream* out);
+
+    /**
+     * @brief Log view creations, exports and table update cycles which take
+     * at least `threshold_us` microseconds in the slow op log, read by
+     * `ServerDiagnosticsReq`, or disable it if `threshold_us` is negative.
+     */
+    void set_slow_op_threshold(std::int64_t threshold_us);
+
+    /**
+     * @brief Keep the serialized `proto::Profile` `profile` until it is
+     * taken by a `ServerDiagnosticsReq`.
+     */
+    void push_profile(const std::string& profile);
+
+    /**
+     * @brief Add `expression` to the expression library as `name`, see
+     * `ProtoServer::define_expression`.
+     */
+    void
+    define_expression(const std::string& name, const std::string& expression);
+
+    void remove_expression(const std::string& name);
+
+    /**
+     * @brief Pin the engine, process-wide, to `cores` (or the cores of
+     * `numa_node`, if `cores` is empty), preferring memory from `numa_node`
+     * if it is not negative, with a thread pool of `num_threads` threads (or
+     * one per core, if 0). Only supported on Linux.
+     */
+    static void set_engine_affinity(
+        const std::vector<std::uint32_t>& cores,
+        std::int32_t numa_node,
+        std::uint32_t num_threads
+    );
+
+    /**
+     * @brief Replace the engine's process-wide tunables, see
+     * `t_engine_config`. Throws if a size is 0.
+     */
+    static void set_engine_config(
+        std::uint64_t parallel_threshold,
+        std::uint64_t expression_block_size,
+        std::uint64_t tree_capacity,
+        std::uint64_t port_capacity
+    );
 };
\ No newline at end of file
@@ -29,6 +29,9 @@ enum t_status : std::uint8_t { STATUS_INVALID, STATUS_VALID, STATUS_CLEAR };
 
 class t_date;
 class t_time;
+struct t_tdelta;
+struct t_uuid;
+struct t_ipaddr;
 
 enum t_dtype {
     DTYPE_NONE,
@@ -45,12 +48,18 @@ enum t_dtype {
     DTYPE_BOOL,
     DTYPE_TIME,
     DTYPE_DATE,
+    DTYPE_DURATION,
+    DTYPE_UUID,
+    DTYPE_IPADDR,
     DTYPE_ENUM,
     DTYPE_OID,
     DTYPE_OBJECT,
     DTYPE_F64PAIR,
     DTYPE_USER_FIXED,
     DTYPE_STR,
+    DTYPE_LIST,
+    DTYPE_JSON,
+    DTYPE_BINARY,
     DTYPE_USER_VLEN,
     DTYPE_LAST_VLEN,
     DTYPE_LAST
@@ -18,6 +18,8 @@
 #include <perspective/date.h>
 #include <perspective/time.h>
 #include <perspective/none.h>
+#include <perspective/uuid.h>
+#include <perspective/ipaddr.h>
 #include <chrono>
 #include <cstring>
 #include <cstdio>
@@ -63,6 +65,10 @@ union t_scalar_u {
 
     const char* m_charptr;
     char m_inplace_char[SCALAR_INPLACE_LEN];
+
+    // Fits within the union's (8-byte aligned) size, so does not grow it.
+    std::uint8_t m_uuid[16];
+    std::uint8_t m_ipaddr[16];
 };
 
 // t_scalar should remain a POD type.
@@ -95,7 +101,26 @@ struct PERSPECTIVE_EXPORT t_tscalar {
     void set(bool v);
     void set(t_date v);
     void set(t_time v);
+    void set(t_tdelta v);
+    void set(const t_uuid& v);
+    void set(const t_ipaddr& v);
     void set(const char* v);
+
+    /**
+     * @brief Set this scalar to a list, given its JSON array text. The text
+     * must outlive the scalar, as with `set(const char*)`.
+     */
+    void set_list(const char* v);
+
+    /**
+     * @brief Set this scalar to a JSON document, given its text.
+     */
+    void set_json(const char* v);
+
+    /**
+     * @brief Set this scalar to a binary value, given its base64 text.
+     */
+    void set_binary(const char* v);
     void set(t_none v);
     void set(double v);
     void set(float v);
@@ -290,6 +315,15 @@ PERSPECTIVE_EXPORT t_date t_tscalar::get() const;
 template <>
 PERSPECTIVE_EXPORT t_time t_tscalar::get() const;
 
+template <>
+PERSPECTIVE_EXPORT t_tdelta t_tscalar::get() const;
+
+template <>
+PERSPECTIVE_EXPORT t_uuid t_tscalar::get() const;
+
+template <>
+PERSPECTIVE_EXPORT t_ipaddr t_tscalar::get() const;
+
 template <>
 PERSPECTIVE_EXPORT const char* t_tscalar::get() const;
 
@@ -368,10 +402,21 @@ t_tscalar::compare_common(const t_tscalar& rhs) const {
             COMPARER_T<std::uint32_t> cmp;
             return cmp(m_data.m_uint32, rhs.m_data.m_uint32);
         } break;
-        case DTYPE_TIME: {
+        case DTYPE_TIME:
+        case DTYPE_DURATION: {
             COMPARER_T<std::int64_t> cmp;
             return cmp(m_data.m_int64, rhs.m_data.m_int64);
         } break;
+        case DTYPE_UUID: {
+            COMPARER_T<int> cmp;
+            return cmp(std::memcmp(m_data.m_uuid, rhs.m_data.m_uuid, 16), 0);
+        } break;
+        case DTYPE_IPADDR: {
+            COMPARER_T<int> cmp;
+            return cmp(
+                std::memcmp(m_data.m_ipaddr, rhs.m_data.m_ipaddr, 16), 0
+            );
+        } break;
         case DTYPE_BOOL: {
             COMPARER_T<bool> cmp;
             return cmp(m_data.m_bool, rhs.m_data.m_bool);
@@ -380,7 +425,10 @@ t_tscalar::compare_common(const t_tscalar& rhs) const {
             COMPARER_T<t_none> cmp;
             return cmp(t_none(), t_none());
         } break;
-        case DTYPE_STR: {
+        case DTYPE_STR:
+        case DTYPE_LIST:
+        case DTYPE_JSON:
+        case DTYPE_BINARY: {
             t_const_char_comparator<COMPARER_T> cmp;
             return cmp(get_char_ptr(), rhs.get_char_ptr());
         } break;
@@ -18,14 +18,20 @@
 #include "perspective/schema.h"
 #include "perspective/view.h"
 #include "perspective/view_config.h"
+#include <chrono>
 #include <cstdint>
+#include <deque>
 #include <memory>
+#include <optional>
 #include <tsl/hopscotch_set.h>
 #include <utility>
 #include <perspective/table.h>
 #include <string>
 #include <tsl/hopscotch_map.h>
 #include <perspective.pb.h>
+#include <map>
+#include <set>
+#include <tuple>
 
 namespace perspective {
 
@@ -135,6 +141,8 @@ std::shared_ptr<CTX_T> make_context(
     const std::string& name
 );
 
+class ArrowIngest;
+
 namespace server {
 
     class PERSPECTIVE_EXPORT ErasedView {
@@ -145,7 +153,7 @@ namespace server {
         virtual std::map<std::string, std::string> schema() const = 0;
 
         [[nodiscard]]
-        virtual std::uint32_t num_rows() const = 0;
+        virtual t_uindex num_rows() const = 0;
         [[nodiscard]]
         virtual std::uint32_t num_columns() const = 0;
         [[nodiscard]]
@@ -161,6 +169,15 @@ namespace server {
             bool compress = true
         ) const = 0;
 
+        virtual void to_arrow_stream(
+            t_uindex start_row,
+            t_uindex end_row,
+            t_uindex start_col,
+            t_uindex end_col,
+            bool emit_group_by,
+            ArrowArrayStream* out
+        ) const = 0;
+
         [[nodiscard]]
         virtual std::string to_rows(
             t_uindex start_row,
@@ -197,6 +214,13 @@ namespace server {
             t_uindex group_by_length
         ) const = 0;
 
+        [[nodiscard]]
+        virtual std::map<std::string, t_downsampled_series> downsample(
+            const std::string& x_col,
+            const std::vector<std::string>& y_cols,
+            t_uindex n_points
+        ) const = 0;
+
         [[nodiscard]]
         virtual std::shared_ptr<std::string> to_csv(
             t_uindex start_row,
@@ -222,15 +246,40 @@ namespace server {
         [[nodiscard]]
         virtual std::shared_ptr<std::string> get_row_delta_as_arrow() const = 0;
 
+        [[nodiscard]]
+        virtual std::vector<t_tscalar> get_removed_pkeys() const = 0;
+
         virtual void set_deltas_enabled(bool enabled_state) = 0;
         [[nodiscard]]
         virtual bool get_deltas_enabled() const = 0;
 
-        virtual t_index collapse(std::int32_t row_idx) = 0;
+        virtual t_index collapse(t_index row_idx) = 0;
 
-        virtual t_index expand(std::int32_t row_idx) = 0;
+        virtual t_index expand(t_index row_idx) = 0;
 
         virtual void set_depth(std::int32_t depth) = 0;
+
+        /**
+         * @brief Apply `view_config`, which differs from this view's config
+         * only in its filter and sort, to the view's existing context, which
+         * is repopulated if `refilter` is set.
+         */
+        virtual void set_filter_and_sort(
+            std::shared_ptr<t_view_config> view_config, bool refilter
+        ) = 0;
+
+        /**
+         * @brief The primary keys of the `Table` rows which make up row
+         * `ridx` of this view; more than one for an aggregated row.
+         */
+        [[nodiscard]]
+        virtual std::vector<t_tscalar> get_row_pkeys(t_uindex ridx) const = 0;
+
+        [[nodiscard]]
+        virtual std::vector<t_tscalar> get_row_path(t_uindex ridx) const = 0;
+
+        [[nodiscard]]
+        virtual bool get_row_expanded(t_uindex ridx) const = 0;
     };
 
     template <typename CTX_T>
@@ -246,7 +295,7 @@ namespace server {
         }
 
         [[nodiscard]]
-        std::uint32_t
+        t_uindex
         num_rows() const override {
             return m_view->num_rows();
         }
@@ -278,6 +327,20 @@ namespace server {
             );
         }
 
+        void
+        to_arrow_stream(
+            t_uindex start_row,
+            t_uindex end_row,
+            t_uindex start_col,
+            t_uindex end_col,
+            bool emit_group_by,
+            ArrowArrayStream* out
+        ) const override {
+            m_view->to_arrow_stream(
+                start_row, end_row, start_col, end_col, emit_group_by, out
+            );
+        }
+
         [[nodiscard]]
         std::string
         to_rows(
@@ -350,6 +413,16 @@ namespace server {
             );
         }
 
+        [[nodiscard]]
+        std::map<std::string, t_downsampled_series>
+        downsample(
+            const std::string& x_col,
+            const std::vector<std::string>& y_cols,
+            t_uindex n_points
+        ) const override {
+            return m_view->downsample(x_col, y_cols, n_points);
+        }
+
         [[nodiscard]]
         std::shared_ptr<std::string>
         to_csv(
@@ -405,6 +478,12 @@ namespace server {
             return m_view->data_slice_to_arrow(delta, false, false);
         }
 
+        [[nodiscard]]
+        std::vector<t_tscalar>
+        get_removed_pkeys() const override {
+            return m_view->get_removed_pkeys();
+        }
+
         void
         set_deltas_enabled(bool enabled_state) override {
             m_view->get_context()->set_deltas_enabled(enabled_state);
@@ -417,12 +496,12 @@ namespace server {
         }
 
         t_index
-        collapse(std::int32_t row_idx) override {
+        collapse(t_index row_idx) override {
             return m_view->collapse(row_idx);
         }
 
         t_index
-        expand(std::int32_t row_idx) override {
+        expand(t_index row_idx) override {
             auto num_pivots =
                 m_view->get_view_config()->get_row_pivots().size();
             return m_view->expand(row_idx, num_pivots);
@@ -435,6 +514,32 @@ namespace server {
             m_view->set_depth(depth, num_pivots);
         }
 
+        void
+        set_filter_and_sort(
+            std::shared_ptr<t_view_config> view_config, bool refilter
+        ) override {
+            m_view->set_filter_and_sort(std::move(view_config), refilter);
+        }
+
+        [[nodiscard]]
+        std::vector<t_tscalar>
+        get_row_pkeys(t_uindex ridx) const override {
+            std::vector<std::pair<t_uindex, t_uindex>> cells{{ridx, 0}};
+            return m_view->get_context()->get_pkeys(cells);
+        }
+
+        [[nodiscard]]
+        std::vector<t_tscalar>
+        get_row_path(t_uindex ridx) const override {
+            return m_view->get_row_path(ridx);
+        }
+
+        [[nodiscard]]
+        bool
+        get_row_expanded(t_uindex ridx) const override {
+            return m_view->get_row_expanded(ridx);
+        }
+
     private:
         std::shared_ptr<View<CTX_T>> m_view;
     };
@@ -461,6 +566,53 @@ namespace server {
         uint32_t client_id;
     };
 
+    /**
+     * @brief An `on_update()` subscription in `VIEWPORT` mode, which holds
+     * the rows of its window last pushed to the client, serialized as JSON,
+     * so that subsequent updates only push the rows which differ.
+     */
+    struct ViewportSubscription {
+        Subscription sub;
+        proto::ViewPort viewport;
+        std::vector<std::string> rows;
+    };
+
+    /**
+     * @brief A `Table::overlay()` of a base table, private to one session.
+     * The overlay's hosted table is its delta, only the rows the session has
+     * edited keyed on the base table's index, whose cells are written over
+     * the base table's live rows for the overlay's views and by
+     * `Table::commit_overlay()`.
+     */
+    struct Overlay {
+        std::string base_id;
+        std::uint32_t client_id;
+
+        // The normalized index JSON of the base rows the session removed.
+        std::set<std::string> removed;
+    };
+
+    /**
+     * @brief The number of edits each `Table`'s edit log keeps; older edits
+     * are forgotten.
+     */
+    constexpr std::size_t PSP_EDIT_LOG_CAPACITY = 1024;
+
+    /**
+     * @brief The number of `Table::edit_cells()` calls each `Table` can
+     * `Table::undo()`; older calls are forgotten.
+     */
+    constexpr std::size_t PSP_UNDO_CAPACITY = 64;
+
+    /**
+     * @brief A `Table`'s undo and redo stacks. Each entry is the records of
+     * one `Table::edit_cells()`, `Table::undo()` or `Table::redo()` call.
+     */
+    struct EditHistory {
+        std::deque<std::vector<proto::CellEditRecord>> undo;
+        std::vector<std::vector<proto::CellEditRecord>> redo;
+    };
+
     /**
      * @brief ServerResources is a container for all the resources that the
      * server requires.
@@ -489,8 +641,96 @@ namespace server {
         std::shared_ptr<ErasedView> get_view(const t_id& id);
         std::vector<t_id> get_table_ids();
 
+        /**
+         * @brief Replace the view `id` with `view`, keeping its subscriptions.
+         */
+        void replace_view(const t_id& id, std::shared_ptr<ErasedView> view);
+
+        // The config each view was created with, before its parameters were
+        // bound, for `View::set_params()`.
+        void set_view_proto_config(const t_id& id, proto::ViewConfig config);
+        proto::ViewConfig get_view_proto_config(const t_id& id);
+
         void delete_view(const std::uint32_t& client_id, const t_id& id);
         void delete_table(const t_id& id);
+        void rename_table(const t_id& id, const t_id& new_id);
+
+        /**
+         * @brief The current name of the table hosted as `id`, which differs
+         * from `id` if the table has since been renamed.
+         */
+        t_id resolve_table_id(const t_id& id);
+        std::uint32_t get_view_client_id(const t_id& view_id);
+
+        // Exclusive-writer tables
+        void set_exclusive_writer(const t_id& table_id, std::uint32_t client_id);
+        bool is_exclusive_writer(const t_id& table_id);
+        void check_writer(const t_id& table_id, std::uint32_t client_id);
+
+        // Update batching
+        void set_batch_latency(
+            const t_id& table_id, std::chrono::milliseconds latency
+        );
+        bool is_commit_due(const t_id& table_id);
+        std::optional<std::chrono::milliseconds> get_commit_delay();
+
+        // `Table::overlay()`
+        void host_overlay(
+            const t_id& overlay_id, const t_id& base_id, std::uint32_t client_id
+        );
+        bool is_overlay(const t_id& table_id);
+        void check_overlay_owner(const t_id& table_id, std::uint32_t client_id);
+        t_id get_overlay_base(const t_id& overlay_id);
+        void push_overlay_removes(
+            const t_id& overlay_id, const std::vector<std::string>& index_values
+        );
+        std::set<std::string> get_overlay_removes(const t_id& overlay_id);
+        std::set<std::string> take_overlay_removes(const t_id& overlay_id);
+        std::vector<t_id> get_client_overlays(std::uint32_t client_id);
+        std::vector<t_id> get_base_overlays(const t_id& base_id);
+
+        // `Table::edit_cells()` edit log
+        void push_edit_records(
+            const t_id& table_id, std::vector<proto::CellEditRecord> records
+        );
+        std::vector<proto::CellEditRecord> get_edit_records(
+            const t_id& table_id, std::optional<std::uint32_t> limit
+        );
+
+        // `Table::undo()` / `Table::redo()` history
+        void push_edit_batch(
+            const t_id& table_id, std::vector<proto::CellEditRecord> records
+        );
+        std::optional<std::vector<proto::CellEditRecord>>
+        take_undo(const t_id& table_id);
+        std::optional<std::vector<proto::CellEditRecord>>
+        take_redo(const t_id& table_id);
+
+        // `Table::annotate()` annotations
+        void set_annotation(
+            const t_id& table_id, proto::CellAnnotation annotation
+        );
+        void remove_annotation(
+            const t_id& table_id,
+            const std::string& index,
+            const std::string& column
+        );
+        std::vector<proto::CellAnnotation>
+        get_annotations(const t_id& table_id);
+
+        // `ViewConfig.unnest`, the unnested copy of its table which a view
+        // reads, or `nullptr` if it has no `unnest` columns.
+        void
+        set_unnest_table(const t_id& view_id, std::shared_ptr<Table> table);
+        std::shared_ptr<Table> get_unnest_table(const t_id& view_id);
+
+        // `TableIngestArrowReq` streams
+        std::shared_ptr<ArrowIngest> get_arrow_ingest(
+            std::uint32_t client_id, const t_id& table_id, std::uint32_t stream_id
+        );
+        void drop_arrow_ingest(
+            std::uint32_t client_id, const t_id& table_id, std::uint32_t stream_id
+        );
 
         // `on_update()`
         void create_view_on_update_sub(const t_id& view_id, Subscription sub);
@@ -500,6 +740,24 @@ namespace server {
         );
         void drop_view_on_update_sub(const t_id& view_id);
 
+        // `on_update()` sequence numbers
+        std::uint64_t
+        next_update_sequence(const t_id& view_id, const Subscription& sub);
+        std::uint64_t
+        get_update_sequence(const t_id& view_id, const Subscription& sub);
+
+        // `on_update()` in `VIEWPORT` mode
+        void create_viewport_sub(
+            const t_id& view_id, ViewportSubscription viewport_sub
+        );
+        std::optional<ViewportSubscription>
+        get_viewport_sub(const t_id& view_id, const Subscription& sub);
+        void set_viewport_rows(
+            const t_id& view_id,
+            const Subscription& sub,
+            std::vector<std::string> rows
+        );
+
         // `Table::on_delete()`
         void create_table_on_delete_sub(const t_id& table_id, Subscription sub);
         std::vector<Subscription> get_table_on_delete_sub(const t_id& table_id);
@@ -515,9 +773,15 @@ namespace server {
         );
         void drop_view_on_delete_sub(const t_id& view_id);
 
+        // `Client::on_hosted_tables_update()`
+        void create_on_hosted_tables_update_sub(Subscription sub);
+        std::vector<Subscription> get_on_hosted_tables_update_sub();
+        void remove_on_hosted_tables_update_sub(
+            std::uint32_t sub_id, std::uint32_t client_id
+        );
+
         void mark_table_dirty(const t_id& id);
         void mark_table_clean(const t_id& id);
-        void mark_all_tables_clean();
 
         std::vector<std::pair<std::shared_ptr<Table>, const std::string>>
         get_dirty_tables();
@@ -529,24 +793,129 @@ namespace server {
         std::multimap<t_id, t_id> m_table_to_view;
         tsl::hopscotch_map<std::uint32_t, std::vector<t_id>> m_client_to_view;
         tsl::hopscotch_map<t_id, std::shared_ptr<Table>> m_tables;
+
+        // The former names of renamed tables, mapped to their current names,
+        // for handles which still address a table by a former name.
+        tsl::hopscotch_map<t_id, t_id> m_table_aliases;
         tsl::hopscotch_map<t_id, std::shared_ptr<ErasedView>> m_views;
+        tsl::hopscotch_map<t_id, proto::ViewConfig> m_view_proto_configs;
 
         tsl::hopscotch_map<t_id, std::vector<Subscription>>
             m_view_on_update_subs;
 
+        tsl::hopscotch_map<t_id, std::vector<ViewportSubscription>>
+            m_viewport_subs;
+
+        // The sequence number of the last notification of each `on_update()`
+        // subscription, by view and `(client_id, sub_id)`.
+        tsl::hopscotch_map<
+            t_id,
+            std::map<std::pair<std::uint32_t, std::uint32_t>, std::uint64_t>>
+            m_update_sequences;
+
         tsl::hopscotch_map<t_id, std::vector<Subscription>>
             m_view_on_delete_subs;
 
         tsl::hopscotch_map<t_id, std::vector<Subscription>>
             m_table_on_delete_subs;
 
+        std::vector<Subscription> m_on_hosted_tables_update_subs;
+
         tsl::hopscotch_set<t_id> m_dirty_tables;
 
+        // Tables in exclusive-writer mode, mapped to the session which may
+        // write to them, or `std::nullopt` if that session has closed.
+        tsl::hopscotch_map<t_id, std::optional<std::uint32_t>>
+            m_exclusive_writers;
+
+        // Tables which batch updates, mapped to the most time they may hold
+        // updates uncommitted, and the time of their last commit.
+        tsl::hopscotch_map<t_id, std::chrono::milliseconds> m_batch_latencies;
+        tsl::hopscotch_map<t_id, std::chrono::steady_clock::time_point>
+            m_last_commits;
+
+        // Tables created by `Table::overlay()`, by overlay id.
+        tsl::hopscotch_map<t_id, Overlay> m_overlays;
+
+        // The last `PSP_EDIT_LOG_CAPACITY` cell edits of each table, oldest
+        // first.
+        tsl::hopscotch_map<t_id, std::deque<proto::CellEditRecord>>
+            m_edit_logs;
+
+        // Undo and redo stacks of each table with cell edits.
+        tsl::hopscotch_map<t_id, EditHistory> m_edit_histories;
+
+        // The annotations of each table, by the `(index, column)` of their
+        // cell, with `index` as encoded by `scalar_to_json`.
+        using t_annotations = std::map<
+            std::pair<std::string, std::string>,
+            proto::CellAnnotation>;
+
+        tsl::hopscotch_map<t_id, t_annotations> m_annotations;
+
+        // The unnested tables of views with `unnest` columns, by view id.
+        tsl::hopscotch_map<t_id, std::shared_ptr<Table>> m_unnest_tables;
+
+        // In-progress `TableIngestArrowReq` streams, by
+        // `(client_id, table_id, stream_id)`.
+        std::map<
+            std::tuple<std::uint32_t, t_id, std::uint32_t>,
+            std::shared_ptr<ArrowIngest>>
+            m_arrow_ingests;
+
 #ifdef PSP_PARALLEL_FOR
         std::shared_mutex m_write_lock;
 #endif
     };
 
+    /**
+     * @brief The number of operations a `SlowOpLog` keeps; older operations
+     * are forgotten.
+     */
+    constexpr std::size_t PSP_SLOW_OP_LOG_CAPACITY = 256;
+
+    /**
+     * @brief A log of the view creations, exports and table update cycles
+     * which took longer than a threshold, in the manner of a database's slow
+     * query log, read by `ServerDiagnosticsReq`. Disabled until a threshold
+     * is set.
+     */
+    class PERSPECTIVE_EXPORT SlowOpLog {
+    public:
+        using t_clock = std::chrono::steady_clock;
+
+        /**
+         * @brief Log operations which take at least `threshold`, or none if
+         * it is `std::nullopt`.
+         */
+        void set_threshold(std::optional<std::chrono::microseconds> threshold
+        );
+
+        bool is_slow(std::chrono::microseconds duration) const;
+
+        void push(proto::SlowOp&& op);
+        const std::deque<proto::SlowOp>& get_ops() const;
+        void clear();
+
+    private:
+        std::optional<std::chrono::microseconds> m_threshold;
+        std::deque<proto::SlowOp> m_ops;
+    };
+
+    /**
+     * @brief The number of profiles a `ProtoServer` keeps until they are
+     * taken by a `ServerDiagnosticsReq`; older profiles are forgotten.
+     */
+    constexpr std::size_t PSP_PROFILE_CAPACITY = 8;
+
+    /**
+     * @brief The range of wire protocol versions `ProtoServer` speaks, as
+     * negotiated by `ServerHelloReq`. Version 1 is the protocol before
+     * `ServerHelloReq` was introduced.
+     */
+    constexpr std::uint32_t PSP_MIN_PROTOCOL_VERSION = 1;
+    constexpr std::uint32_t PSP_MAX_PROTOCOL_VERSION = 2;
+
     template <typename A>
     struct PERSPECTIVE_EXPORT ProtoServerResp {
         A data;
@@ -560,11 +929,182 @@ namespace server {
 
         std::uint32_t new_session();
         void close_session(std::uint32_t);
+        std::vector<ProtoServerResp<std::string>> handle_request(
+            std::uint32_t client_id,
+            const std::string_view& data,
+            const std::string& request_id = ""
+        );
+
+        /**
+         * @brief Delete a hosted table, first deleting all of its views and
+         * notifying their (and the table's) `on_delete` subscribers.
+         */
         std::vector<ProtoServerResp<std::string>>
-        handle_request(std::uint32_t client_id, const std::string_view& data);
+        unhost_table(const std::string& table_id);
+
+        /**
+         * @brief Host a new table named `table_id` from an Arrow C stream,
+         * which is consumed by this call.
+         */
+        std::vector<ProtoServerResp<std::string>> host_arrow_stream(
+            const std::string& table_id,
+            const std::string& index,
+            ArrowArrayStream* stream
+        );
+
+        /**
+         * @brief Update the hosted table `table_id` from an Arrow C stream,
+         * which is consumed by this call. The update is processed on the
+         * next `poll()`.
+         */
+        void update_arrow_stream(
+            const std::string& table_id,
+            ArrowArrayStream* stream,
+            std::uint32_t port_id
+        );
+
+        /**
+         * @brief Export every row and column of the hosted view `view_id` to
+         * the uninitialized Arrow C stream `out`, processing its table first
+         * if it is dirty.
+         */
+        std::vector<ProtoServerResp<std::string>> view_to_arrow_stream(
+            const std::string& view_id, ArrowArrayStream* out
+        );
+
         std::vector<ProtoServerResp<std::string>> poll();
 
+        /**
+         * @brief How long until the next `poll()` will commit the held
+         * updates of a batched table, or `std::nullopt` if no updates are
+         * held, see `ServerResources::set_batch_latency`.
+         */
+        std::optional<std::chrono::milliseconds> get_poll_delay();
+
+        /**
+         * @brief Log operations which take at least `threshold` in the slow
+         * op log, or disable it if `std::nullopt`, see `SlowOpLog`.
+         */
+        void set_slow_op_threshold(
+            std::optional<std::chrono::microseconds> threshold
+        );
+
+        /**
+         * @brief Keep a profile captured by the host, until it is taken by a
+         * `ServerDiagnosticsReq`.
+         */
+        void push_profile(proto::Profile&& profile);
+
+        /**
+         * @brief Add `expression` to the expression library as `name`,
+         * replacing any expression of that name. A view config may reference
+         * `name` as a column (in `columns`, `group_by`, `split_by`, `sort`,
+         * `filter` or `aggregates`) of any table which has no column or
+         * config expression of that name.
+         */
+        void define_expression(
+            const std::string& name, const std::string& expression
+        );
+
+        void remove_expression(const std::string& name);
+
     private:
+        void _resolve_library_expressions(
+            const t_schema& schema, proto::ViewConfig& cfg
+        ) const;
+
+        /**
+         * @brief Validate and normalize `cfg` into a `t_view_config` of
+         * `table`, adding its expression columns to `schema`.
+         */
+        std::shared_ptr<t_view_config> _make_view_config(
+            const std::shared_ptr<Table>& table,
+            const std::shared_ptr<t_schema>& schema,
+            const proto::ViewConfig& cfg
+        );
+
+        std::shared_ptr<ErasedView> _make_view(
+            std::shared_ptr<Table> table,
+            const std::string& context_name,
+            const proto::ViewConfig& cfg
+        );
+
+        /**
+         * @brief Apply `cfg`, which differs from the config of the view
+         * `view_id` only in its `filter`, `filter_op` or `sort`, to the
+         * view's existing context, and notify its `on_update()` subscribers.
+         * Returns `false`, having changed nothing, if the context can't be
+         * updated in place and the view must be rebuilt instead.
+         */
+        bool _update_view_filter_and_sort(
+            const ServerResources::t_id& view_id,
+            const proto::ViewConfig& cfg,
+            bool refilter,
+            std::vector<ProtoServerResp<Response>>& outs
+        );
+
+        /**
+         * @brief Replace the view `view_id` with a new view of `cfg` in
+         * place, keeping its id, subscriptions and (if its `group_by` is
+         * unchanged) expand state, and notify its `on_update()` subscribers.
+         */
+        void _rebuild_view(
+            const ServerResources::t_id& view_id,
+            proto::ViewConfig cfg,
+            std::vector<ProtoServerResp<Response>>& outs
+        );
+
+        void _notify_view_on_update(
+            const Table& table,
+            const ServerResources::t_id& view_id,
+            std::uint32_t port_id,
+            bool with_delta,
+            std::vector<ProtoServerResp<Response>>& outs
+        );
+
+        void _log_slow_op(
+            const Request& req, SlowOpLog::t_clock::time_point start
+        );
+
+        /**
+         * @brief The table a view of `cfg` on the table `table_id` reads:
+         * `table`, a copy of it with `cfg`'s `unnest` columns unnested, or
+         * for an overlay, its delta merged over its base table's rows.
+         */
+        std::shared_ptr<Table> _view_table(
+            const ServerResources::t_id& table_id,
+            const std::shared_ptr<Table>& table,
+            const proto::ViewConfig& cfg
+        );
+
+        void _refresh_overlay_view(
+            const ServerResources::t_id& overlay_id,
+            const ServerResources::t_id& view_id
+        );
+
+        std::uint64_t _overlay_size(const ServerResources::t_id& overlay_id);
+
+        std::optional<std::string> _lookup_cell(
+            const ServerResources::t_id& table_id,
+            const Table& table,
+            const std::string& index_json,
+            const std::string& column
+        );
+
+        void _apply_cell_rows(
+            const ServerResources::t_id& table_id,
+            Table& table,
+            const std::string& rows
+        );
+
+        std::vector<proto::CellEditRecord> _replay_cell_edits(
+            const ServerResources::t_id& table_id,
+            Table& table,
+            const std::vector<proto::CellEditRecord>& batch,
+            bool undo,
+            std::uint32_t client_id
+        );
+
         void handle_process_table(
             const Request& req,
             std::vector<ProtoServerResp<ProtoServer::Response>>& proto_resp
@@ -575,6 +1115,9 @@ namespace server {
 
         std::vector<ProtoServerResp<Response>> _poll();
 
+        void _hosted_tables_update(std::vector<ProtoServerResp<Response>>& outs
+        );
+
         void _process_table(
             std::shared_ptr<Table>& table,
             const ServerResources::t_id& table_id,
@@ -589,6 +1132,10 @@ namespace server {
 
         static std::uint32_t m_client_id;
         ServerResources m_resources;
+        SlowOpLog m_slow_ops;
+        std::deque<proto::Profile> m_profiles;
+        std::map<std::string, std::string> m_expression_library;
+        std::uint64_t m_view_generation = 0;
     };
 
 } // namespace server
@@ -427,6 +427,14 @@ protected:
         t_tscalar& value
     ) const;
 
+    // Whether any of `spec`'s dependencies is null for any row of `pkeys`.
+    bool has_null_from_gstate(
+        const t_gstate& gstate,
+        const t_data_table& expression_master_table,
+        const t_aggspec& spec,
+        const std::vector<t_tscalar>& pkeys
+    ) const;
+
     bool apply_from_gstate(
         const t_gstate& gstate,
         const t_data_table& expression_master_table,
@@ -467,6 +475,12 @@ private:
     t_symtable m_symtable;
     bool m_has_delta;
     std::string m_grand_agg_str;
+
+    // Category ranks by pivot column name, see `t_config::set_category_order`.
+    std::map<std::string, std::map<std::string, t_index>> m_category_ranks;
+
+    // See `t_config::set_null_aggregates`.
+    t_null_aggregates m_null_aggregates;
 };
 
 } // end namespace perspective
@@ -18,7 +18,12 @@
 #include <perspective/mask.h>
 #include <perspective/compat.h>
 #include <perspective/debug_helpers.h>
+#include <perspective/compression.h>
+#include <atomic>
 #include <cmath>
+#include <limits>
+#include <memory>
+#include <mutex>
 
 /*
 TODO.
@@ -26,8 +31,6 @@ TODO.
 1. Add support for "temp" storage where the
 file is deleted on exit.
 
-2. Add support for compression.
-
 
 */
 
@@ -35,6 +38,15 @@ namespace perspective {
 
 struct t_lstore_tmp_init_tag {};
 
+/**
+ * @brief The size in bytes of some stored data, uncompressed (`m_raw`) and as
+ * currently held in memory (`m_stored`).
+ */
+struct t_storage_bytes {
+    t_uindex m_raw = 0;
+    t_uindex m_stored = 0;
+};
+
 struct PERSPECTIVE_EXPORT t_lstore_recipe {
     t_lstore_recipe();
     t_lstore_recipe(t_uindex capacity);
@@ -217,6 +229,29 @@ public:
 
     std::shared_ptr<t_lstore> clone() const;
 
+    /**
+     * @brief Encode this store's `width`-byte elements with
+     * `compress_buffer()` and release the uncompressed buffer, if any
+     * encoding is smaller. Any subsequent access to the store's data first
+     * decompresses it. Only in-memory, unaligned stores are compressed.
+     *
+     * A store which is already compressed is skipped, as is one which no
+     * encoding shrank and which hasn't changed size since, so only stores
+     * which have been decompressed or grown since the last call are encoded
+     * again.
+     *
+     * @param width
+     */
+    void compress(t_uindex width);
+
+    bool is_compressed() const;
+
+    /**
+     * @brief The number of bytes this store's data currently occupies in
+     * memory, which is less than `size()` while it is compressed.
+     */
+    t_uindex stored_size() const;
+
     bool
     get_init() const {
         return m_init;
@@ -238,6 +273,14 @@ protected:
     void unfreeze_impl();
 
 private:
+    void
+    inflate_if_compressed() const {
+        if (m_is_compressed.load(std::memory_order_acquire)) {
+            inflate();
+        }
+    }
+
+    void inflate() const;
     void reserve_impl(t_uindex capacity, bool allow_shrink);
     t_handle create_file();
     // NOLINTNEXTLINE
@@ -265,6 +308,15 @@ private:
     t_uindex m_version;
     bool m_from_recipe;
 
+    // Set while `m_compressed` holds this store's data in place of `m_base`.
+    std::unique_ptr<t_compressed_buffer> m_compressed;
+
+    // The `m_size` at which no encoding was smaller, so `compress()` need
+    // not try again until the store grows or shrinks.
+    t_uindex m_incompressible_size = std::numeric_limits<t_uindex>::max();
+    mutable std::atomic<bool> m_is_compressed{false};
+    mutable std::mutex m_compressed_mtx;
+
 #ifdef PSP_MPROTECT
     // size of padding + size of fields above
     // ==
@@ -298,6 +350,7 @@ struct t_unlock_store {
 template <typename T>
 void
 t_lstore::push_back(T value) {
+    inflate_if_compressed();
     if (m_size + sizeof(T) >= m_capacity) {
         reserve(static_cast<t_uindex>(std::ceil(m_capacity + m_size + sizeof(T))
         )); // reserve will multiply by m_resize_factor
@@ -320,6 +373,7 @@ template <typename T>
 T*
 t_lstore::get(t_uindex idx) {
     STORAGE_CHECK_ACCESS_GET(idx);
+    inflate_if_compressed();
     T* ptr = reinterpret_cast<T*>(static_cast<unsigned char*>(m_base) + idx);
     return ptr;
 }
@@ -328,6 +382,7 @@ template <typename T>
 const T*
 t_lstore::get(t_uindex idx) const {
     STORAGE_CHECK_ACCESS_GET(idx);
+    inflate_if_compressed();
     T* ptr = reinterpret_cast<T*>(static_cast<unsigned char*>(m_base) + idx);
     return ptr;
 }
@@ -336,6 +391,7 @@ template <typename T>
 T*
 t_lstore::get_nth(t_uindex idx) {
     STORAGE_CHECK_ACCESS_GET(idx);
+    inflate_if_compressed();
     return static_cast<T*>(m_base) + idx;
 }
 
@@ -343,6 +399,7 @@ template <typename T>
 const T*
 t_lstore::get_nth(t_uindex idx) const {
     STORAGE_CHECK_ACCESS_GET(idx);
+    inflate_if_compressed();
     return static_cast<T*>(m_base) + idx;
 }
 
@@ -350,6 +407,7 @@ template <typename T>
 void
 t_lstore::set_nth(t_uindex idx, T v) {
     STORAGE_CHECK_ACCESS(idx);
+    inflate_if_compressed();
     T* tgt = static_cast<T*>(m_base) + idx;
     *tgt = v;
 }
@@ -357,6 +415,7 @@ t_lstore::set_nth(t_uindex idx, T v) {
 template <typename T>
 T*
 t_lstore::extend(t_uindex idx) {
+    inflate_if_compressed();
     t_uindex osize = m_size;
     t_uindex nsize = m_size + idx * sizeof(T);
     reserve(nsize);
@@ -378,6 +437,7 @@ t_lstore::extend() {
 template <typename DATA_T>
 void
 t_lstore::raw_fill(DATA_T v) {
+    inflate_if_compressed();
     auto biter = static_cast<DATA_T*>(m_base);
     auto eiter = reinterpret_cast<DATA_T*>(static_cast<char*>(m_base) + size());
     std::fill(biter, eiter, v);
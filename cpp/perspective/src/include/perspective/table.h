@@ -18,9 +18,56 @@
 #include <perspective/gnode.h>
 #include <perspective/pool.h>
 #include <perspective/data_table.h>
+#include <perspective/arrow_csv.h>
+#include <perspective/column_sketch.h>
+#include <arrow/c/abi.h>
+#include <map>
+#include <unordered_map>
+#include <optional>
+#include <set>
+
+namespace arrow {
+class Table;
+} // namespace arrow
 
 namespace perspective {
 
+namespace apachearrow {
+    class ArrowLoader;
+} // namespace apachearrow
+
+/**
+ * @brief Sizing hints and limits for the string dictionary (vocabulary) of a
+ * `Table` column.
+ */
+struct t_dictionary_options {
+    std::optional<std::uint32_t> m_reserve;
+    std::optional<std::uint32_t> m_max_cardinality;
+};
+
+/**
+ * @brief Display defaults for a `Table` column, which clients read from its
+ * schema: the aggregate to apply when grouped, and how to format its values.
+ * `m_editable` is also enforced by the server for `TableEditCellsReq`, and
+ * `m_timezone` is the default time zone of expressions over the column.
+ */
+struct t_column_hints {
+    std::optional<std::string> m_aggregate;
+    std::optional<std::uint32_t> m_precision;
+    std::optional<bool> m_thousands_separator;
+    std::optional<bool> m_editable;
+    std::optional<std::string> m_timezone;
+};
+
+/**
+ * @brief The size of a column's string dictionary: the number of unique
+ * strings it has interned, and the bytes it has allocated for them.
+ */
+struct t_dictionary_stats {
+    t_uindex m_size;
+    t_uindex m_bytes;
+};
+
 /**
  * @brief the `Table` class encapsulates `t_data_table`, `t_pool` and `t_gnode`,
  * offering a unified public API for consumption by binding languages.
@@ -168,6 +215,33 @@ public:
      */
     void remove_port(t_uindex port_id) const;
 
+    /**
+     * @brief Get the port named `name`, creating it if no such port exists.
+     *
+     * @param name
+     * @return t_uindex the ID of the port.
+     */
+    t_uindex make_named_port(const std::string& name);
+
+    /**
+     * @brief The name of the port `port_id`, if it was created by
+     * `make_named_port`.
+     *
+     * @param port_id
+     * @return std::optional<std::string>
+     */
+    std::optional<std::string> get_port_name(t_uindex port_id) const;
+
+    /**
+     * @brief Count an update on port `port_id`, returning the port's new
+     * sequence number (the number of updates it has received).
+     *
+     * @param port_id
+     * @return std::uint64_t
+     */
+    std::uint64_t next_port_sequence(t_uindex port_id);
+    std::uint64_t get_port_sequence(t_uindex port_id) const;
+
     /**
      * @brief The offset determines where we begin to write data into the Table.
      * Using `m_offset`, `m_limit`, and the length of the dataset, calculate the
@@ -186,16 +260,163 @@ public:
     std::uint32_t get_offset() const;
     std::uint32_t get_limit() const;
     const std::string& get_index() const;
+    const std::map<std::string, std::vector<std::string>>&
+    get_categories() const;
+    const std::map<std::string, t_dictionary_options>&
+    get_dictionary_options() const;
+    const std::map<std::string, t_column_hints>& get_column_hints() const;
+
+    /**
+     * @brief Whether `TableEditCellsReq` may edit `column`: every column
+     * except the `index` and virtual columns is, unless its hints set
+     * `m_editable` to `false`.
+     *
+     * @param column
+     * @return bool
+     */
+    bool is_column_editable(const std::string& column) const;
+
+    /**
+     * @brief Whether `column` is computed by the gnode from an expression,
+     * see `t_gnode::set_virtual_columns`.
+     *
+     * @param column
+     * @return bool
+     */
+    bool is_virtual_column(const std::string& column) const;
+
+    /**
+     * @brief Get the dictionary size of every string column, by column name.
+     *
+     * @return std::map<std::string, t_dictionary_stats>
+     */
+    std::map<std::string, t_dictionary_stats> get_dictionary_stats() const;
+
+    /**
+     * @brief Get the sketch of every column which has been written to, by
+     * column name, see `t_column_sketch`.
+     *
+     * @return const std::map<std::string, t_column_sketch>&
+     */
+    const std::map<std::string, t_column_sketch>& get_sketches() const;
 
     // Setters
     void set_column_names(const std::vector<std::string>& column_names);
     void set_data_types(const std::vector<t_dtype>& data_types);
 
+    /**
+     * @brief Declare the ordered categories of a string column, which pivots
+     * and sorts on that column follow in place of lexicographic order.
+     *
+     * @param column
+     * @param categories
+     */
+    void set_categories(
+        const std::string& column, const std::vector<std::string>& categories
+    );
+
+    /**
+     * @brief Set the dictionary options of a string column, reserving room
+     * for `m_reserve` unique strings.
+     *
+     * @param column
+     * @param options
+     */
+    void set_dictionary_options(
+        const std::string& column, const t_dictionary_options& options
+    );
+
+    /**
+     * @brief Set the default aggregate and format hints of a column, which
+     * must name a valid aggregate if `m_aggregate` is set.
+     *
+     * @param column
+     * @param hints
+     */
+    void
+    set_column_hints(const std::string& column, const t_column_hints& hints);
+
+    /**
+     * @brief Log a warning, once per column, for each string column whose
+     * dictionary has grown past its `m_max_cardinality`.
+     */
+    void check_dictionary_cardinality();
+
+    /**
+     * @brief Enable in-memory compression of this `Table`'s committed
+     * columns, see `compress`.
+     *
+     * @param compress
+     */
+    void set_compression(bool compress);
+
+    /**
+     * @brief If compression is enabled, compress the committed columns of
+     * this `Table` which have been decompressed (by a read or update) or
+     * have grown since the last call, see `t_lstore::compress`. This should
+     * be called after each batch of reads and commits.
+     */
+    void compress();
+
     void remove_cols(const std::string_view& data);
     void remove_rows(const std::string_view& data);
 
+    /**
+     * @brief Remove every row which matches all of `fterms`, evaluated in a
+     * single pass over the committed rows of this `Table`.
+     *
+     * @param fterms
+     * @return t_uindex The number of rows removed.
+     */
+    t_uindex remove_where(const std::vector<t_fterm>& fterms);
+
+    /**
+     * @brief Remove every committed row of this `Table`. Unlike `clear`,
+     * the removal is queued on port 0 like any other update, so data sent to
+     * port 0 before the next process replaces the rows in a single step,
+     * without views observing an empty `Table` in between.
+     */
+    void remove_all();
+
+    /**
+     * @brief Replace every row of this `Table`, created by `from_unnested`,
+     * with the committed rows of `source` unnested by its `unnest` columns.
+     * Like `remove_all`, the rows are replaced on port 0 in a single step.
+     *
+     * @param source
+     * @param unnest
+     */
+    void replace_unnested(
+        const Table& source, const std::vector<std::string>& unnest
+    );
+
     void update_arrow(const std::string_view& data, std::uint32_t port_id);
-    void update_csv(const std::string_view& data, std::uint32_t port_id);
+
+    /**
+     * @brief Update this `Table` from an Arrow C stream, which is consumed
+     * (and released) by this call. Record batches are read directly from
+     * the producer's buffers, without an IPC round trip.
+     *
+     * @param stream
+     * @param port_id
+     */
+    void update_arrow_stream(ArrowArrayStream* stream, std::uint32_t port_id);
+
+    /**
+     * @brief Update this `Table` from decoded Arrow record batches, e.g. the
+     * batches completed by a chunk of an `ArrowIngest` stream.
+     *
+     * @param table
+     * @param port_id
+     */
+    void update_arrow_table(
+        std::shared_ptr<arrow::Table> table, std::uint32_t port_id
+    );
+    void update_csv(
+        const std::string_view& data,
+        std::uint32_t port_id,
+        const apachearrow::CsvOptions& options = {}
+    );
     void update_rows(const std::string_view& data, std::uint32_t port_id);
     void update_cols(const std::string_view& data, std::uint32_t port_id);
     // void update_cols(const std::string_view& data) const;
@@ -203,13 +424,21 @@ public:
     static std::shared_ptr<Table> from_csv(
         const std::string& index,
         const std::string_view& data,
-        std::uint32_t limit = std::numeric_limits<std::uint32_t>::max()
+        std::uint32_t limit = std::numeric_limits<std::uint32_t>::max(),
+        const apachearrow::CsvOptions& options = {}
     );
 
+    /**
+     * Create a `Table` from column-oriented JSON. Types are inferred from each
+     * column's first non-null value, unless the column is in
+     * `schema_override`, in which case its values are coerced to the given
+     * type.
+     */
     static std::shared_ptr<Table> from_cols(
         const std::string& index,
         const std::string_view& data,
-        std::uint32_t limit = std::numeric_limits<std::uint32_t>::max()
+        std::uint32_t limit = std::numeric_limits<std::uint32_t>::max(),
+        const std::unordered_map<std::string, t_dtype>& schema_override = {}
     );
 
     static std::shared_ptr<Table> from_rows(
@@ -230,6 +459,39 @@ public:
         std::uint32_t limit = std::numeric_limits<std::uint32_t>::max()
     );
 
+    /**
+     * @brief Create a `Table` from an Arrow C stream, which is consumed (and
+     * released) by this call.
+     *
+     * @param index
+     * @param stream
+     * @param limit
+     * @return std::shared_ptr<Table>
+     */
+    static std::shared_ptr<Table> from_arrow_stream(
+        const std::string& index,
+        ArrowArrayStream* stream,
+        std::uint32_t limit = std::numeric_limits<std::uint32_t>::max()
+    );
+
+    /**
+     * @brief Create an unindexed `Table` of the committed rows of `source`,
+     * with each row repeated once per element of the lists in its `unnest`
+     * columns, which hold that element instead. Lists in the same row are
+     * unnested together, padded with nulls to the longest, and a row with
+     * no elements is kept with nulls. Elements are boolean, integer or float
+     * if all of them are when the `Table` is created, and string otherwise;
+     * later elements of another type are null (or their JSON text, in a
+     * string column).
+     *
+     * @param source
+     * @param unnest
+     * @return std::shared_ptr<Table>
+     */
+    static std::shared_ptr<Table> from_unnested(
+        const Table& source, const std::vector<std::string>& unnest
+    );
+
     static std::shared_ptr<Table> make_table(
         const std::vector<std::string>& column_names,
         const std::vector<t_dtype>& data_types,
@@ -246,6 +508,56 @@ private:
      * @param column_names
      */
     void validate_columns(const std::vector<std::string>& column_names);
+
+    /**
+     * @brief Reserve the master table dictionary of `column` per its
+     * `m_reserve` hint.
+     *
+     * @param column
+     */
+    void reserve_dictionary(const std::string& column);
+
+    /**
+     * @brief Add the values of every row of `data_table`, which is about to
+     * be sent to the pool as an `OP_INSERT`, to its columns' sketches.
+     *
+     * @param data_table
+     */
+    void update_sketches(const t_data_table& data_table);
+
+    /**
+     * @brief Queue an `OP_DELETE` on port 0 for each of `pkeys`.
+     *
+     * @param pkeys
+     */
+    void remove_pkeys(const std::vector<t_tscalar>& pkeys);
+
+    /**
+     * @brief Update this `Table` from an initialized `ArrowLoader`, shared
+     * by `update_arrow`, `update_arrow_stream` and `update_arrow_table`.
+     *
+     * @param arrow_loader
+     * @param port_id
+     */
+    void update_arrow_loader(
+        apachearrow::ArrowLoader& arrow_loader, std::uint32_t port_id
+    );
+
+    /**
+     * @brief Create a `Table` from an initialized `ArrowLoader`, shared by
+     * `from_arrow` and `from_arrow_stream`.
+     *
+     * @param index
+     * @param arrow_loader
+     * @param limit
+     * @return std::shared_ptr<Table>
+     */
+    static std::shared_ptr<Table> from_arrow_loader(
+        const std::string& index,
+        apachearrow::ArrowLoader& arrow_loader,
+        std::uint32_t limit
+    );
+
     /**
      * @brief Create a column for the table operation - either insert or delete.
      *
@@ -285,6 +597,48 @@ private:
      */
     const std::string m_index;
     bool m_gnode_set;
+
+    /**
+     * @brief Whether committed columns are compressed in memory.
+     *
+     */
+    bool m_compress;
+
+    /**
+     * @brief Declared category orders by column name, either set explicitly
+     * or read from ordered Arrow dictionaries.
+     *
+     */
+    std::map<std::string, std::vector<std::string>> m_categories;
+
+    /**
+     * @brief Dictionary options by column name, and the columns which have
+     * already been warned about exceeding their maximum cardinality.
+     *
+     */
+    std::map<std::string, t_dictionary_options> m_dictionaries;
+    std::set<std::string> m_dictionary_warnings;
+
+    /**
+     * @brief Default aggregate and format hints by column name.
+     *
+     */
+    std::map<std::string, t_column_hints> m_column_hints;
+
+    /**
+     * @brief Summaries of the values written to each column, maintained as
+     * updates arrive.
+     *
+     */
+    std::map<std::string, t_column_sketch> m_sketches;
+
+    /**
+     * @brief Named ports by name, and the number of updates each port has
+     * received.
+     *
+     */
+    std::map<std::string, t_uindex> m_named_ports;
+    std::map<t_uindex, std::uint64_t> m_port_sequences;
 };
 
 } // namespace perspective
\ No newline at end of file
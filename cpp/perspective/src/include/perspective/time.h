@@ -25,6 +25,10 @@
 #endif
 SUPPRESS_WARNINGS_VC(4244)
 
+namespace date {
+class time_zone;
+} // namespace date
+
 namespace perspective {
 
 const int SECS_PER_HOUR = 60 * 60;
@@ -48,10 +52,27 @@ struct t_tdelta {
     // Gets, say, a time difference twice as long as the
     // current value.
     t_tdelta& operator*=(std::int64_t multiplier);
+
+    std::int64_t raw_value() const;
+
+    /**
+     * @brief Format this duration for display, e.g. `1d 02:03:04.005`. The
+     * day component is omitted when zero, as are trailing milliseconds.
+     */
+    std::string str() const;
+
     friend std::ostream& operator<<(std::ostream& s, const t_tdelta& td);
     friend class t_time;
 };
 
+/**
+ * @brief Parse a duration from either an integer count of milliseconds or the
+ * `[-][Nd ]HH:MM:SS[.mmm]` format written by `t_tdelta::str()`.
+ *
+ * @return `true` if `str` was a valid duration, in which case `out` is set.
+ */
+bool parse_duration(const char* str, t_tdelta& out);
+
 std::int32_t isleap(long int year);
 std::int32_t days_before_year(std::int32_t year);
 std::int32_t days_before_month(std::int32_t year, std::int32_t month);
@@ -147,6 +168,68 @@ hash_value(const t_time& t) {
     return hasher(t.m_storage);
 }
 
+/**
+ * @brief A time zone: an IANA name such as `America/New_York`, a fixed UTC
+ * offset (`UTC`, `+05:30`) or a POSIX TZ rule such as
+ * `EST5EDT,M3.2.0,M11.1.0`. IANA names resolve through the system tz
+ * database, including historical rule changes, and are only available in
+ * builds with `PSP_ENABLE_TZDB`.
+ */
+class PERSPECTIVE_EXPORT t_tz {
+public:
+    t_tz();
+
+    /**
+     * @brief Parse a time zone name or rule.
+     *
+     * @return `true` if `spec` was recognized, in which case `out` is set.
+     */
+    static bool parse(const std::string& spec, t_tz& out);
+
+    /**
+     * @brief The offset from UTC in effect at `utc_ms`, in milliseconds east
+     * of UTC.
+     */
+    std::int64_t utc_offset(std::int64_t utc_ms) const;
+
+    /**
+     * @brief Convert a UTC timestamp to wall-clock milliseconds in this zone.
+     */
+    std::int64_t to_local(std::int64_t utc_ms) const;
+
+    /**
+     * @brief Convert wall-clock milliseconds in this zone to a UTC timestamp.
+     * Ambiguous wall times (when clocks fall back) resolve to the earlier
+     * instant, and skipped wall times (when clocks spring forward) resolve
+     * as if the standard offset still applied.
+     */
+    std::int64_t to_utc(std::int64_t local_ms) const;
+
+private:
+    struct t_rule {
+        // `M` (month.week.weekday), `J` (1-365, no leap day) or `N` (0-365).
+        char m_kind;
+        std::int32_t m_month;
+        std::int32_t m_week;
+        std::int32_t m_day;
+        std::int32_t m_time;
+    };
+
+    static bool parse_rule(const char*& str, t_rule& out);
+    static std::int64_t rule_to_local(const t_rule& rule, std::int32_t year);
+    bool is_dst(std::int64_t utc_ms) const;
+
+    // Set for IANA zones, in which case the POSIX rule fields are unused.
+    const date::time_zone* m_zone;
+
+    // Offsets are in seconds east of UTC.
+    std::int32_t m_std_offset;
+    std::int32_t m_dst_offset;
+    bool m_has_dst;
+    t_rule m_start;
+    t_rule m_end;
+};
+
 } // end namespace perspective
 
 namespace std {
@@ -116,6 +116,27 @@ PERSPECTIVE_EXPORT std::vector<t_tscalar> ctx_get_path(
     t_index idx
 );
 
+/**
+ * @brief The traversal index of each row a row pivoted context shows, with
+ * the grand total (the traversal root) and the subtotal row of each expanded
+ * group placed before or after the rows they total, or left out.
+ */
+PERSPECTIVE_EXPORT std::vector<t_index> ctx_get_row_order(
+    const t_traversal& traversal, t_totals grand_total, t_totals subtotals
+);
+
+/**
+ * @brief The value a pivoted context shows for an aggregate `value` under
+ * `show_values_as`, given the `total` a percent is of and the aggregates of
+ * the `previous` columns in the cell's `split_by` group, in column order.
+ */
+PERSPECTIVE_EXPORT t_tscalar ctx_show_value_as(
+    t_show_values_as show_values_as,
+    const t_tscalar& value,
+    const t_tscalar& total,
+    const std::vector<t_tscalar>& previous
+);
+
 PERSPECTIVE_EXPORT std::vector<t_ftreenode> ctx_get_flattened_tree(
     t_index idx,
     t_depth stop_depth,
@@ -0,0 +1,119 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+#pragma once
+
+#include <perspective/first.h>
+#include <perspective/exports.h>
+#include <perspective/base.h>
+#include <chrono>
+#include <deque>
+#include <optional>
+#include <utility>
+#include <vector>
+
+namespace perspective {
+
+/**
+ * @brief The number of buckets in a `t_update_stats` batch size histogram;
+ * bucket `i` counts batches of `[2^i, 2^(i+1))` rows, and the last bucket
+ * counts every larger batch.
+ */
+const t_uindex PSP_UPDATE_STATS_BUCKETS = 24;
+
+/**
+ * @brief The longest interval, in milliseconds, which `t_update_stats` can
+ * report recent counts over; older batches are forgotten.
+ */
+const std::int64_t PSP_UPDATE_STATS_MAX_INTERVAL_MS = 60 * 60 * 1000;
+
+/**
+ * @brief The rows one call to `t_gnode::_process_table` added (new primary
+ * keys), updated (existing primary keys) and removed.
+ */
+struct t_update_counts {
+    t_uindex m_added = 0;
+    t_uindex m_updated = 0;
+    t_uindex m_removed = 0;
+
+    t_uindex
+    size() const {
+        return m_added + m_updated + m_removed;
+    }
+};
+
+/**
+ * @brief Counters describing the updates a `Table` has processed, for
+ * spotting stalled or bursty feeds: total and recent row counts, the time of
+ * the last update, and the distribution of batch sizes.
+ *
+ * A batch is every `update` and `remove` queued on one port since the table
+ * was last processed, so a feed which updates faster than the server polls
+ * is counted in fewer, larger batches. Batches which change no rows are not
+ * counted.
+ */
+class PERSPECTIVE_EXPORT t_update_stats {
+public:
+    using t_clock = std::chrono::steady_clock;
+
+    t_update_stats();
+
+    /**
+     * @brief Count a processed batch.
+     *
+     * @param counts
+     */
+    void record(const t_update_counts& counts);
+
+    /**
+     * @brief The rows counted since this `Table` was created.
+     */
+    const t_update_counts& get_total() const;
+    t_uindex get_num_batches() const;
+
+    /**
+     * @brief The rows and batches counted in the last `interval_ms`
+     * milliseconds, at most `PSP_UPDATE_STATS_MAX_INTERVAL_MS`, to the
+     * nearest second.
+     *
+     * @param interval_ms
+     * @return std::pair<t_update_counts, t_uindex>
+     */
+    std::pair<t_update_counts, t_uindex>
+    get_recent(std::int64_t interval_ms) const;
+
+    /**
+     * @brief The wall clock time of the last batch, in milliseconds since
+     * the Unix epoch, if any.
+     */
+    std::optional<std::int64_t> get_last_update() const;
+
+    const std::vector<t_uindex>& get_batch_sizes() const;
+
+private:
+    // The batches counted in one second.
+    struct t_bucket {
+        t_clock::time_point m_time;
+        t_update_counts m_counts;
+        t_uindex m_num_batches;
+    };
+
+    void expire(t_clock::time_point now);
+
+    t_update_counts m_total;
+    t_uindex m_num_batches;
+    std::optional<std::int64_t> m_last_update;
+    std::vector<t_uindex> m_batch_sizes;
+    std::deque<t_bucket> m_recent;
+};
+
+} // namespace perspective
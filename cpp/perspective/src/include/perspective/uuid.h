@@ -0,0 +1,67 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+#pragma once
+
+#include <perspective/first.h>
+#include <perspective/exports.h>
+#include <cstdint>
+#include <cstring>
+#include <ostream>
+#include <string>
+#include <string_view>
+
+namespace perspective {
+
+/**
+ * @brief A UUID, stored as its 16 raw bytes in network (big-endian) order so
+ * that byte-wise comparison matches the ordering of the canonical text form.
+ */
+struct PERSPECTIVE_EXPORT t_uuid {
+    std::uint8_t m_bytes[16];
+
+    /**
+     * @brief The canonical lowercase `xxxxxxxx-xxxx-xxxx-xxxx-xxxxxxxxxxxx`
+     * form.
+     */
+    std::string str() const;
+
+    bool
+    operator==(const t_uuid& rhs) const {
+        return std::memcmp(m_bytes, rhs.m_bytes, 16) == 0;
+    }
+
+    bool
+    operator!=(const t_uuid& rhs) const {
+        return !operator==(rhs);
+    }
+
+    bool
+    operator<(const t_uuid& rhs) const {
+        return std::memcmp(m_bytes, rhs.m_bytes, 16) < 0;
+    }
+};
+
+/**
+ * @brief Parse a UUID from text, either canonical (hyphenated) or as 32 hex
+ * digits, case-insensitively, optionally wrapped in braces or prefixed with
+ * `urn:uuid:`.
+ *
+ * @return `true` if `str` was a valid UUID, in which case `out` is set.
+ */
+PERSPECTIVE_EXPORT bool parse_uuid(std::string_view str, t_uuid& out);
+
+} // end namespace perspective
+
+namespace std {
+std::ostream& operator<<(std::ostream& os, const perspective::t_uuid& uuid);
+} // namespace std
@@ -0,0 +1,108 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+#pragma once
+#include <perspective/first.h>
+#include <perspective/base.h>
+#include <perspective/column.h>
+#include <perspective/data_table.h>
+#include <perspective/exports.h>
+#include <perspective/schema.h>
+
+namespace perspective {
+
+/**
+ * @brief A fast path for simple numeric expressions, which evaluates them a
+ * block of rows at a time over contiguous buffers the compiler can
+ * vectorize, rather than a row at a time through ExprTk.
+ *
+ * Only expressions built from numeric columns, numeric literals,
+ * parentheses, unary minus and `+ - * /`, optionally compared once at the
+ * top level with `< <= > >= == !=`, compile. Anything else, and any
+ * expression whose ExprTk result the fast path can't reproduce exactly,
+ * fails to compile and is left to ExprTk.
+ */
+class PERSPECTIVE_EXPORT t_vectorized_expression {
+public:
+    /**
+     * @brief Compile `parsed_expression_string`, whose column references
+     * have been replaced with the IDs in `column_ids`, or return null if the
+     * expression is not one the fast path handles.
+     *
+     * @param dtype the expression's output type as ExprTk computes it,
+     * which the fast path must match.
+     */
+    static std::shared_ptr<t_vectorized_expression> compile(
+        const std::string& parsed_expression_string,
+        const std::vector<std::pair<std::string, std::string>>& column_ids,
+        const t_schema& schema,
+        t_dtype dtype
+    );
+
+    /**
+     * @brief Compute the expression into `output` for every row of
+     * `source_table`, or for only `rows` if it is not null.
+     *
+     * Returns false if the data holds a case the fast path does not
+     * reproduce, such as a comparison with a null operand; `output` may then
+     * be partly written, and the caller should compute it through ExprTk.
+     */
+    bool compute(
+        const t_data_table& source_table,
+        const std::vector<t_uindex>* rows,
+        t_column& output
+    ) const;
+
+private:
+    enum t_node_type {
+        NODE_COLUMN,
+        NODE_LITERAL,
+        NODE_NEGATE,
+        NODE_ADD,
+        NODE_SUBTRACT,
+        NODE_MULTIPLY,
+        NODE_DIVIDE
+    };
+
+    enum t_comparison {
+        COMPARISON_NONE,
+        COMPARISON_LT,
+        COMPARISON_LTE,
+        COMPARISON_GT,
+        COMPARISON_GTE,
+        COMPARISON_EQ,
+        COMPARISON_NE
+    };
+
+    // Nodes are stored in evaluation order, so each node's operands precede
+    // it and a single forward pass computes the whole tree.
+    struct t_node {
+        t_node_type m_type;
+        t_dtype m_dtype;
+        std::string m_column;
+        double m_literal;
+        t_uindex m_lhs;
+        t_uindex m_rhs;
+    };
+
+    class t_parser;
+
+    std::vector<t_node> m_nodes;
+
+    // The roots of the two sides of the comparison, or of the whole
+    // expression in `m_lhs` if there is no comparison.
+    t_uindex m_lhs;
+    t_uindex m_rhs;
+    t_comparison m_comparison;
+};
+
+} // end namespace perspective
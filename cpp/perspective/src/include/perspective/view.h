@@ -20,6 +20,7 @@
 #include <perspective/context_one.h>
 #include <perspective/context_two.h>
 #include <perspective/data_slice.h>
+#include <perspective/downsample.h>
 #include <perspective/table.h>
 #include <perspective/view_config.h>
 #include <rapidjson/writer.h>
@@ -73,9 +74,9 @@ public:
      * contructor.
      *
      *
-     * @return std::int32_t the number of aggregated rows
+     * @return t_index the number of aggregated rows
      */
-    std::int32_t num_rows() const;
+    t_index num_rows() const;
 
     /**
      * @brief The number of aggregated columns in this View. This is affected by
@@ -222,6 +223,25 @@ public:
         t_uindex group_by_length
     ) const;
 
+    /**
+     * @brief Downsamples each of `y_cols` against `x_col` to at most
+     * `n_points` points, selected per `lttb()`. Rows where either column is
+     * null are skipped, and points are sorted by `x_col` first if the `View`
+     * is not already sorted by it. Only valid for `View`s without
+     * `group_by` or `split_by`.
+     *
+     * @param x_col
+     * @param y_cols
+     * @param n_points
+     * @return std::map<std::string, t_downsampled_series> A series for each
+     * of `y_cols`.
+     */
+    std::map<std::string, t_downsampled_series> downsample(
+        const std::string& x_col,
+        const std::vector<std::string>& y_cols,
+        t_uindex n_points
+    ) const;
+
     std::string to_columns(
         t_uindex start_row,
         t_uindex end_row,
@@ -252,14 +272,36 @@ public:
      * @return std::shared_ptr<std::string>
      */
     std::shared_ptr<std::string> to_arrow(
-        std::int32_t start_row,
-        std::int32_t end_row,
-        std::int32_t start_col,
-        std::int32_t end_col,
+        t_uindex start_row,
+        t_uindex end_row,
+        t_uindex start_col,
+        t_uindex end_col,
         bool emit_group_by,
         bool compress
     ) const;
 
+    /**
+     * @brief Export the `View`'s data as an Arrow C stream, which the caller
+     * takes ownership of and must release. Unlike `to_arrow`, the record
+     * batch is not serialized, so an in-process consumer can read its
+     * buffers directly.
+     *
+     * @param start_row
+     * @param end_row
+     * @param start_col
+     * @param end_col
+     * @param emit_group_by
+     * @param out An uninitialized `ArrowArrayStream` to export into.
+     */
+    void to_arrow_stream(
+        t_uindex start_row,
+        t_uindex end_row,
+        t_uindex start_col,
+        t_uindex end_col,
+        bool emit_group_by,
+        ArrowArrayStream* out
+    ) const;
+
     /**
      * @brief Serializes the `View`'s data into the Apache Arrow format
      * as a bytestring. Using start/end row and column, retrieve a data
@@ -272,10 +314,10 @@ public:
      * @return std::shared_ptr<std::string>
      */
     std::shared_ptr<std::string> to_csv(
-        std::int32_t start_row,
-        std::int32_t end_row,
-        std::int32_t start_col,
-        std::int32_t end_col
+        t_uindex start_row,
+        t_uindex end_row,
+        t_uindex start_col,
+        t_uindex end_col
     ) const;
 
     /**
@@ -321,7 +363,7 @@ public:
      * @param ridx
      * @return std::int32_t
      */
-    bool get_row_expanded(std::int32_t ridx) const;
+    bool get_row_expanded(t_index ridx) const;
 
     /**
      * @brief Expands the row at "ridx".
@@ -330,7 +372,7 @@ public:
      * @param row_pivot_length
      * @return t_index
      */
-    t_index expand(std::int32_t ridx, std::int32_t row_pivot_length);
+    t_index expand(t_index ridx, std::int32_t row_pivot_length);
 
     /**
      * @brief Collapses the row at "ridx".
@@ -338,7 +380,7 @@ public:
      * @param ridx
      * @return t_index
      */
-    t_index collapse(std::int32_t ridx);
+    t_index collapse(t_index ridx);
 
     /**
      * @brief Set the expansion "depth" of the pivot tree.
@@ -348,6 +390,20 @@ public:
      */
     void set_depth(std::int32_t depth, std::int32_t row_pivot_length);
 
+    /**
+     * @brief Re-filter and re-sort this view's existing context with
+     * `view_config`, which must differ from the view's config only in its
+     * filter and sort. Contexts only apply their filter as they are
+     * populated, so if `refilter` is set the context is reset and
+     * repopulated from the `Table`, as it also is to undo a sort.
+     *
+     * @param view_config
+     * @param refilter
+     */
+    void set_filter_and_sort(
+        std::shared_ptr<t_view_config> view_config, bool refilter
+    );
+
     /**
      * @brief Returns a data slice that contains the dataset from the rows
      * that have been changed by a call to `update()`.
@@ -356,6 +412,15 @@ public:
      */
     std::shared_ptr<t_data_slice<CTX_T>> get_row_delta() const;
 
+    /**
+     * @brief Returns the primary keys of the rows removed from this view by
+     * the current `update()`, or `remove()`. Always empty for pivoted views,
+     * whose rows are aggregates.
+     *
+     * @return std::vector<t_tscalar>
+     */
+    std::vector<t_tscalar> get_removed_pkeys() const;
+
     // Getters
     std::shared_ptr<CTX_T> get_context() const;
     std::vector<std::string> get_row_pivots() const;
@@ -412,6 +477,11 @@ private:
 
     void _find_hidden_sort(const std::vector<t_sortspec>& sort);
 
+    void _repopulate_context(t_ctx_type type);
+
+    void _set_filter_and_sort_config(std::shared_ptr<t_view_config> view_config
+    );
+
     std::shared_ptr<Table> m_table;
     std::shared_ptr<CTX_T> m_ctx;
     std::string m_name;
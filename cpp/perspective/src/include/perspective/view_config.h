@@ -20,6 +20,7 @@
 #include <perspective/computed_expression.h>
 #include <tsl/ordered_map.h>
 #include <tsl/hopscotch_set.h>
+#include <map>
 #include <unordered_set>
 #include <tuple>
 
@@ -98,6 +99,58 @@ public:
     void set_row_pivot_depth(std::int32_t depth);
     void set_column_pivot_depth(std::int32_t depth);
 
+    /**
+     * @brief Set the time zone calendar expression functions use, as
+     * accepted by `t_tz::parse`.
+     *
+     * @param timezone
+     */
+    void set_timezone(const std::string& timezone);
+
+    /**
+     * @brief Set whether rows with a null row or column pivot value are
+     * excluded from the view, rather than grouped under a null group.
+     *
+     * @param exclude
+     */
+    void set_exclude_null_groups(bool exclude);
+
+    /**
+     * @brief Set how aggregates treat null values.
+     *
+     * @param null_aggregates
+     */
+    void set_null_aggregates(t_null_aggregates null_aggregates);
+
+    /**
+     * @brief Set where the grand total row and group subtotal rows are
+     * placed, or whether they are hidden.
+     *
+     * @param grand_total
+     * @param subtotals
+     */
+    void set_row_totals(t_totals grand_total, t_totals subtotals);
+
+    /**
+     * @brief Set how the values of each column, by name, are shown relative
+     * to other cells.
+     *
+     * @param show_values_as
+     */
+    void set_show_values_as(
+        std::map<std::string, t_show_values_as> show_values_as
+    );
+
+    /**
+     * @brief Set the column group path of each grouped column, keyed by
+     * column name with the outermost group first.
+     *
+     * @param column_groups
+     */
+    void set_column_groups(
+        std::map<std::string, std::vector<std::string>> column_groups
+    );
+
     std::vector<std::string> get_row_pivots() const;
 
     std::vector<std::string> get_column_pivots() const;
@@ -121,6 +174,23 @@ public:
     std::int32_t get_row_pivot_depth() const;
     std::int32_t get_column_pivot_depth() const;
 
+    const std::string& get_timezone() const;
+
+    bool get_exclude_null_groups() const;
+
+    t_null_aggregates get_null_aggregates() const;
+
+    t_totals get_grand_total() const;
+
+    t_totals get_subtotals() const;
+
+    const std::map<std::string, t_show_values_as>& get_show_values_as() const;
+
+    t_show_values_as get_show_values_as(const std::string& column) const;
+
+    const std::map<std::string, std::vector<std::string>>&
+    get_column_groups() const;
+
 private:
     bool m_init;
 
@@ -218,6 +288,37 @@ private:
     std::int32_t m_row_pivot_depth;
     std::int32_t m_column_pivot_depth;
 
+    /**
+     * @brief If specified, the time zone used by calendar expression
+     * functions instead of the process's local time.
+     */
+    std::string m_timezone;
+
+    /**
+     * @brief Whether rows with null pivot values are excluded, and how
+     * aggregates treat null values.
+     */
+    bool m_exclude_null_groups;
+    t_null_aggregates m_null_aggregates;
+
+    /**
+     * @brief Where the grand total and subtotal rows of a row pivoted view
+     * are placed.
+     */
+    t_totals m_grand_total;
+    t_totals m_subtotals;
+
+    /**
+     * @brief How the values of each column of a pivoted view are shown.
+     */
+    std::map<std::string, t_show_values_as> m_show_values_as;
+
+    /**
+     * @brief The column group path of each grouped column, which is written
+     * to the column's Arrow field metadata.
+     */
+    std::map<std::string, std::vector<std::string>> m_column_groups;
+
     /**
      * @brief the `t_filter_op` used to return data in the case of multiple
      * filters being applied.
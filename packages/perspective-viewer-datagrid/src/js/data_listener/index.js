@@ -81,18 +81,28 @@ export function createDataListener(viewer) {
         ) {
             const path = this._column_paths[ipath];
             const path_parts = path.split("|");
+            const column_name = path_parts[this._config.split_by.length];
             const column = columns[path] || new Array(y1 - y0).fill(null);
             data.push(
                 column.map((x) =>
                     format_cell.call(
                         this,
-                        path_parts[this._config.split_by.length],
+                        column_name,
                         x,
                         regularTable[PRIVATE_PLUGIN_SYMBOL]
                     )
                 )
             );
             metadata.push(column);
+
+            // Column groups are header rows between the `split_by` values
+            // and the column name.
+            path_parts.splice(
+                this._config.split_by.length,
+                0,
+                ...this.get_column_group_path(column_name)
+            );
+
             if (is_settings_open) {
                 path_parts.push("");
             }
@@ -130,10 +140,7 @@ export function createDataListener(viewer) {
             column_headers,
             data,
             metadata,
-            column_header_merge_depth: Math.max(
-                0,
-                this._config.split_by.length
-            ),
+            column_header_merge_depth: Math.max(0, this._column_header_depth),
         };
     };
 }
@@ -10,12 +10,24 @@
 // ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
 // ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
 
+/**
+ * Write `value` to the `Table` row and column of the cell described by `meta`.
+ */
+export function write_value(model, meta, value) {
+    const id = model._ids[meta.y - meta.y0][0];
+    const msg = {
+        __INDEX__: id,
+        [model._column_paths[meta.x]]: value,
+    };
+
+    model._table.update([msg], { port_id: model._edit_port });
+}
+
 export function write_cell(table, model, active_cell) {
     const meta = table.getMeta(active_cell);
     const type = model._schema[model._column_paths[meta.x]];
     if (meta) {
         let text = active_cell.textContent;
-        const id = model._ids[meta.y - meta.y0][0];
         if (type === "float" || type === "integer") {
             text = parseFloat(text.replace(/,/g, ""));
             if (isNaN(text)) {
@@ -30,12 +42,7 @@ export function write_cell(table, model, active_cell) {
             text = text === "true" ? false : text === "false" ? true : null;
         }
 
-        const msg = {
-            __INDEX__: id,
-            [model._column_paths[meta.x]]: text,
-        };
-
-        model._table.update([msg], { port_id: model._edit_port });
+        write_value(model, meta, text);
         return true;
     }
 }
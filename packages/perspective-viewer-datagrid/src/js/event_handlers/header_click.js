@@ -38,7 +38,7 @@ export async function mousedown_listener(regularTable, viewer, event) {
 
     if (target.classList.contains("psp-menu-enabled")) {
         const meta = regularTable.getMeta(target);
-        const column_name = meta.column_header?.[this._config.split_by.length];
+        const column_name = meta.column_header?.[this._column_header_depth];
         await viewer.toggleColumnSettings(column_name);
         event.preventDefault();
         event.stopImmediatePropagation();
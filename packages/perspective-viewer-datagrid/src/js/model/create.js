@@ -52,6 +52,46 @@ function get_psp_type(metadata) {
     }
 }
 
+/**
+ * The group path above each member of `column_groups`, outermost group
+ * first, padded with empty headers to the same depth so that column names
+ * share a header row.
+ *
+ * @returns A `[paths, depth]` pair.
+ */
+function get_column_group_paths(column_groups) {
+    const parents = new Map();
+    for (const group of column_groups) {
+        for (const member of group.columns) {
+            parents.set(member, group.name);
+        }
+    }
+
+    const paths = new Map();
+    for (const member of parents.keys()) {
+        const path = [];
+        let parent = parents.get(member);
+        while (parent !== undefined && path.length <= column_groups.length) {
+            path.unshift(parent);
+            parent = parents.get(parent);
+        }
+
+        paths.set(member, path);
+    }
+
+    const depth = Math.max(0, ...Array.from(paths.values(), (x) => x.length));
+    for (const [member, path] of paths) {
+        paths.set(member, new Array(depth - path.length).fill("").concat(path));
+    }
+
+    return [paths, depth];
+}
+
+function get_column_group_path(column) {
+    const depth = this._column_header_depth - this._config.split_by.length;
+    return this._column_group_paths.get(column) || new Array(depth).fill("");
+}
+
 export async function createModel(regular, table, view, extend = {}) {
     const config = await view.get_config();
 
@@ -116,6 +156,10 @@ export async function createModel(regular, table, view, extend = {}) {
         return path !== "__ROW_PATH__" && path !== "__ID__";
     });
 
+    const [_column_group_paths, column_group_depth] = get_column_group_paths(
+        config.column_groups || []
+    );
+
     const _is_editable = [];
     const _column_types = [];
     for (const column_path of _column_paths) {
@@ -141,6 +185,8 @@ export async function createModel(regular, table, view, extend = {}) {
         _pos_bg_color,
         _neg_bg_color,
         _column_paths,
+        _column_group_paths,
+        _column_header_depth: config.split_by.length + column_group_depth,
         _column_types,
         _is_editable,
         _selection_state: {
@@ -153,6 +199,7 @@ export async function createModel(regular, table, view, extend = {}) {
         _series_color_map: new Map(),
         _series_color_seed: new Map(),
         get_psp_type,
+        get_column_group_path,
     });
 
     // Re-use div factory
@@ -29,6 +29,7 @@ export default function column_style_opts(type, _group) {
                 number_bg_mode: "disabled",
             },
             number_string_format: true,
+            cell_renderer: true,
         };
     else if (type === "date" || type === "datetime" || type === "string") {
         let control =
@@ -40,6 +41,7 @@ export default function column_style_opts(type, _group) {
                 color: this.model._color[0],
                 bg_color: this.model._color[0],
             },
+            cell_renderer: true,
         };
     } else {
         return null;
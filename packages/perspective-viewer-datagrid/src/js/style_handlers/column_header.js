@@ -91,14 +91,14 @@ export function column_header_style_listener(regularTable, viewer) {
         );
 
         let [col_headers] = group_header_trs.splice(
-            this._config.split_by.length,
+            this._column_header_depth,
             1
         );
 
         style_column_header_row.call(this, regularTable, col_headers, false);
 
         let [style_menu_headers] = group_header_trs.splice(
-            this._config.split_by.length,
+            this._column_header_depth,
             1
         );
 
@@ -119,7 +119,7 @@ function style_column_header_row(regularTable, col_headers, is_menu_row) {
     for (const td of col_headers?.children) {
         const metadata = regularTable.getMeta(td);
         const column_name =
-            metadata.column_header?.[this._config.split_by.length];
+            metadata.column_header?.[this._column_header_depth];
         const sort = this._config.sort.find((x) => x[0] === column_name);
         let needs_border = metadata.row_header_x === header_depth;
         const is_corner = typeof metadata.x === "undefined";
@@ -160,14 +160,14 @@ function style_column_header_row(regularTable, col_headers, is_menu_row) {
             "psp-menu-enabled",
             (is_string || is_numeric || is_date || is_datetime) &&
                 !is_corner &&
-                metadata.column_header_y == this._config.split_by.length + 1
+                metadata.column_header_y == this._column_header_depth + 1
         );
 
         td.classList.toggle(
             "psp-sort-enabled",
             (is_string || is_numeric || is_date || is_datetime) &&
                 !is_corner &&
-                metadata.column_header_y === this._config.split_by.length
+                metadata.column_header_y === this._column_header_depth
         );
 
         td.classList.toggle(
@@ -12,7 +12,7 @@
 
 import { PRIVATE_PLUGIN_SYMBOL } from "../model";
 
-function isEditable(viewer, allowed = false) {
+export function isEditable(viewer, allowed = false) {
     const has_pivots =
         this._config.group_by.length === 0 &&
         this._config.split_by.length === 0;
@@ -37,8 +37,11 @@ export function editable_style_listener(table, viewer, datagrid) {
         const meta = table.getMeta(td);
         const type = this.get_psp_type(meta);
         if (edit && this._is_editable[meta.x]) {
-            const col_name = meta.column_header[this._config.split_by.length];
-            if (type === "string" && plugins[col_name]?.format === "link") {
+            const col_name = meta.column_header[this._column_header_depth];
+            if (
+                plugins[col_name]?.cell_renderer ||
+                (type === "string" && plugins[col_name]?.format === "link")
+            ) {
                 td.toggleAttribute("contenteditable", false);
                 td.classList.toggle("boolean-editable", false);
             } else if (type === "boolean") {
@@ -0,0 +1,39 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+import { isEditable } from "../editable.js";
+import { write_value } from "../../event_handlers/click/edit_click.js";
+
+/**
+ * Delegate rendering of a cell to the custom renderer registered for this
+ * column via `registerCellRenderer()`, using its `edit()` method instead when
+ * the cell is editable.
+ */
+export function cell_style_custom(plugin, td, metadata, viewer) {
+    const { cell_renderer } = plugin;
+    const editable =
+        !!this._is_editable[metadata.x] && isEditable.call(this, viewer);
+
+    const meta = {
+        column_name: metadata.column_header[this._column_header_depth],
+        type: this._column_types[metadata.x],
+        config: plugin,
+        editable,
+        commit: (value) => write_value(this, metadata, value),
+    };
+
+    if (editable && typeof cell_renderer.edit === "function") {
+        cell_renderer.edit(td, metadata.user, meta);
+    } else {
+        cell_renderer.render(td, metadata.user, meta);
+    }
+}
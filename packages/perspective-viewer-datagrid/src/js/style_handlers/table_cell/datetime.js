@@ -17,7 +17,7 @@ import {
 } from "../../color_utils.js";
 
 export function cell_style_datetime(plugin, td, metadata) {
-    const column_name = metadata.column_header?.[this._config.split_by.length];
+    const column_name = metadata.column_header?.[this._column_header_depth];
 
     const [hex, r, g, b, gradhex] = (() => {
         if (plugin?.color !== undefined) {
@@ -17,6 +17,7 @@ import { cell_style_string } from "./string.js";
 import { cell_style_datetime } from "./datetime.js";
 import { cell_style_boolean } from "./boolean.js";
 import { cell_style_row_header } from "./row_header.js";
+import { cell_style_custom } from "./custom.js";
 
 function get_psp_type(metadata) {
     if (metadata.x >= 0) {
@@ -34,7 +35,7 @@ export function table_cell_style_listener(regularTable, viewer) {
         for (const td of tr.children) {
             const metadata = regularTable.getMeta(td);
             const column_name =
-                metadata.column_header?.[this._config.split_by.length];
+                metadata.column_header?.[this._column_header_depth];
 
             let type = get_psp_type.call(this, metadata);
             const plugin = plugins[column_name];
@@ -67,6 +68,8 @@ export function table_cell_style_listener(regularTable, viewer) {
             const is_th = td.tagName === "TH";
             if (is_th) {
                 cell_style_row_header.call(this, regularTable, td, metadata);
+            } else if (plugin?.cell_renderer && metadata.x >= 0) {
+                cell_style_custom.call(this, plugin, td, metadata, viewer);
             }
 
             td.classList.toggle("psp-align-right", !is_th && is_numeric);
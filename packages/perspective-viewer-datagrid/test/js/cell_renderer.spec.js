@@ -0,0 +1,79 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+import { test, expect } from "@finos/perspective-test";
+
+test.describe("Cell Renderer Tests", () => {
+    test.beforeEach(async ({ page }) => {
+        await page.goto("/tools/perspective-test/src/html/basic-test.html");
+        await page.evaluate(async () => {
+            while (!window["__TEST_PERSPECTIVE_READY__"]) {
+                await new Promise((x) => setTimeout(x, 10));
+            }
+        });
+    });
+
+    test("Registered cell renderers render the cells of their column", async ({
+        page,
+    }) => {
+        const cells = await page.evaluate(async () => {
+            const Viewer = customElements.get("perspective-viewer");
+            await Viewer.registerCellRenderer("Pill", {
+                types: ["string"],
+                render(td, value, meta) {
+                    td.innerHTML = `<span class="pill">${meta.column_name}:${value}</span>`;
+                },
+            });
+
+            const viewer = document.querySelector("perspective-viewer");
+            await viewer.restore({
+                plugin: "Datagrid",
+                columns: ["Row ID", "State"],
+                columns_config: {
+                    State: { renderer: "Pill" },
+                },
+            });
+
+            const datagrid = viewer.querySelector(
+                "perspective-viewer-datagrid"
+            );
+
+            return Array.from(
+                datagrid.regular_table.querySelectorAll("tbody td span.pill")
+            )
+                .slice(0, 2)
+                .map((x) => x.textContent);
+        });
+
+        expect(cells).toEqual(["State:Kentucky", "State:Kentucky"]);
+    });
+
+    test("getCellRenderer returns registered renderers", async ({
+        page,
+    }) => {
+        const renderers = await page.evaluate(async () => {
+            const Viewer = customElements.get("perspective-viewer");
+            await Viewer.registerCellRenderer("Sparkline", {
+                types: ["float"],
+                render(td, value) {
+                    td.textContent = `~${value}`;
+                },
+            });
+
+            return [
+                (await Viewer.getCellRenderer("Sparkline"))?.types,
+                await Viewer.getCellRenderer("Missing"),
+            ];
+        });
+
+        expect(renderers).toEqual([["float"], undefined]);
+    });
+});
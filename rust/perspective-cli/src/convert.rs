@@ -0,0 +1,126 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use std::path::PathBuf;
+use std::str::FromStr;
+
+use clap::Args;
+use perspective::client::config::{Aggregate, Sort, SortDir};
+use perspective::client::TableInitOptions;
+use perspective::server::Server;
+use perspective::LocalClient;
+
+use crate::format::{read_file, read_view_config, write_view};
+use crate::CliError;
+
+#[derive(Args, Debug)]
+pub struct ConvertArgs {
+    /// The CSV, Arrow, Parquet or JSON file to read.
+    input: PathBuf,
+
+    /// The CSV, Arrow, Parquet or JSON file to write.
+    output: PathBuf,
+
+    /// A column to group by. May be repeated.
+    #[arg(long)]
+    group_by: Vec<String>,
+
+    /// A column to split by. May be repeated.
+    #[arg(long)]
+    split_by: Vec<String>,
+
+    /// A column to output, defaulting to all of them. May be repeated.
+    #[arg(long)]
+    columns: Vec<String>,
+
+    /// A `column:aggregate` pair, e.g. `price:mean`. May be repeated.
+    #[arg(long = "aggregate", value_parser = parse_aggregate)]
+    aggregates: Vec<(String, Aggregate)>,
+
+    /// A `column:direction` pair, e.g. `price:desc`, where the direction
+    /// defaults to `asc`. May be repeated.
+    #[arg(long, value_parser = parse_sort)]
+    sort: Vec<Sort>,
+
+    /// A JSON view config file, which the options above override.
+    #[arg(long)]
+    view_config: Option<PathBuf>,
+}
+
+fn parse_aggregate(value: &str) -> Result<(String, Aggregate), String> {
+    let (column, aggregate) = value
+        .rsplit_once(':')
+        .ok_or_else(|| format!("Expected `column:aggregate`, found `{}`", value))?;
+
+    Ok((column.to_owned(), Aggregate::from_str(aggregate)?))
+}
+
+fn parse_sort(value: &str) -> Result<Sort, String> {
+    let (column, dir) = value.rsplit_once(':').unwrap_or((value, "asc"));
+    let dir = serde_json::from_value::<SortDir>(serde_json::Value::String(dir.to_owned()))
+        .map_err(|_| format!("Unknown sort direction `{}`", dir))?;
+
+    Ok(Sort(column.to_owned(), dir))
+}
+
+/// Load `args.input` into a [`perspective::client::Table`] on an in-process
+/// [`Server`], query it with the view config described by `args` and write
+/// the result to `args.output`.
+pub async fn convert(args: ConvertArgs) -> Result<(), CliError> {
+    let mut config = args
+        .view_config
+        .as_deref()
+        .map(read_view_config)
+        .transpose()?
+        .unwrap_or_default();
+
+    if !args.group_by.is_empty() {
+        config.group_by = Some(args.group_by);
+    }
+
+    if !args.split_by.is_empty() {
+        config.split_by = Some(args.split_by);
+    }
+
+    if !args.columns.is_empty() {
+        config.columns = Some(args.columns.into_iter().map(Some).collect());
+    }
+
+    if !args.aggregates.is_empty() {
+        config
+            .aggregates
+            .get_or_insert_with(Default::default)
+            .extend(args.aggregates);
+    }
+
+    if !args.sort.is_empty() {
+        config.sort = Some(args.sort);
+    }
+
+    let data = read_file(&args.input)?;
+    let server = Server::default();
+    let client = LocalClient::new(&server);
+    let result = async {
+        let table = client
+            .table(data.into(), TableInitOptions::default())
+            .await?;
+        let view = table.view(Some(config)).await?;
+        write_view(&view, &args.output).await?;
+        view.delete().await?;
+        table.delete().await?;
+        Ok::<_, CliError>(())
+    }
+    .await;
+
+    client.close().await;
+    result
+}
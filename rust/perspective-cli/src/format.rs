@@ -0,0 +1,111 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use std::fs::File;
+use std::io::Cursor;
+use std::path::Path;
+
+use arrow::ipc::reader::StreamReader;
+use arrow::ipc::writer::StreamWriter;
+use arrow::record_batch::RecordBatchReader;
+use parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
+use parquet::arrow::ArrowWriter;
+use perspective::client::config::ViewConfigUpdate;
+use perspective::client::{UpdateData, View, ViewWindow};
+
+use crate::CliError;
+
+/// A file format supported by `perspective-cli`, detected from a file's
+/// extension.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Format {
+    Csv,
+    Arrow,
+    Parquet,
+    Json,
+}
+
+impl Format {
+    pub fn from_path(path: &Path) -> Result<Self, CliError> {
+        let ext = path
+            .extension()
+            .and_then(|x| x.to_str())
+            .map(|x| x.to_ascii_lowercase());
+
+        match ext.as_deref() {
+            Some("csv") => Ok(Format::Csv),
+            Some("arrow" | "feather" | "ipc") => Ok(Format::Arrow),
+            Some("parquet" | "pq") => Ok(Format::Parquet),
+            Some("json") => Ok(Format::Json),
+            _ => Err(format!("Unknown file format `{}`", path.display()).into()),
+        }
+    }
+}
+
+/// Read the file at `path` as [`UpdateData`] for a
+/// [`perspective::client::Table`]. Parquet has no engine support, so it is
+/// decoded here and re-encoded as an Arrow stream.
+pub fn read_file(path: &Path) -> Result<UpdateData, CliError> {
+    Ok(match Format::from_path(path)? {
+        Format::Csv => UpdateData::Csv(std::fs::read_to_string(path)?),
+        Format::Arrow => UpdateData::Arrow(std::fs::read(path)?.into()),
+        Format::Parquet => UpdateData::Arrow(parquet_to_arrow(path)?.into()),
+        Format::Json => {
+            let json = std::fs::read_to_string(path)?;
+            if json.trim_start().starts_with('{') {
+                UpdateData::JsonColumns(json)
+            } else {
+                UpdateData::JsonRows(json)
+            }
+        },
+    })
+}
+
+/// Read a JSON view config file.
+pub fn read_view_config(path: &Path) -> Result<ViewConfigUpdate, CliError> {
+    Ok(serde_json::from_str(&std::fs::read_to_string(path)?)?)
+}
+
+fn parquet_to_arrow(path: &Path) -> Result<Vec<u8>, CliError> {
+    let reader = ParquetRecordBatchReaderBuilder::try_new(File::open(path)?)?.build()?;
+    let mut writer = StreamWriter::try_new(Vec::new(), &reader.schema())?;
+    for batch in reader {
+        writer.write(&batch?)?;
+    }
+
+    Ok(writer.into_inner()?)
+}
+
+/// Write every row of `view` to the file at `path`, in the format of its
+/// extension.
+pub async fn write_view(view: &View, path: &Path) -> Result<(), CliError> {
+    let window = ViewWindow::default();
+    match Format::from_path(path)? {
+        Format::Csv => std::fs::write(path, view.to_csv(window).await?)?,
+        Format::Arrow => std::fs::write(path, view.to_arrow(window).await?)?,
+        Format::Parquet => arrow_to_parquet(&view.to_arrow(window).await?, path)?,
+        Format::Json => std::fs::write(path, view.to_json_string(window).await?)?,
+    };
+
+    Ok(())
+}
+
+fn arrow_to_parquet(arrow: &[u8], path: &Path) -> Result<(), CliError> {
+    let reader = StreamReader::try_new(Cursor::new(arrow), None)?;
+    let mut writer = ArrowWriter::try_new(File::create(path)?, reader.schema(), None)?;
+    for batch in reader {
+        writer.write(&batch?)?;
+    }
+
+    writer.close()?;
+    Ok(())
+}
@@ -0,0 +1,263 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+use clap::Args;
+use futures::channel::mpsc::{unbounded, UnboundedSender};
+use futures::{SinkExt, StreamExt};
+use perspective::client::config::ViewConfigUpdate;
+use perspective::client::{Client, ClientHandler, Table, View, ViewWindow};
+use tokio::io::{AsyncBufReadExt, BufReader};
+use tokio_tungstenite::tungstenite::Message;
+
+use crate::format::{read_view_config, write_view};
+use crate::CliError;
+
+const HELP: &str = "\
+tables           List the server's hosted tables
+use <table>      Select a table
+schema           Print the selected table's schema
+size             Print the selected table's row count
+view [<json>]    Query the selected table with a JSON view config
+show             Print the current query's first rows
+limit <n>        Set how many rows `show` and `view` print
+export <path>    Write the current query to a CSV, Arrow, Parquet or JSON file
+help             Print this message
+quit             Exit";
+
+#[derive(Args, Debug)]
+pub struct QueryArgs {
+    /// The WebSocket URL of a Perspective server, e.g.
+    /// `ws://localhost:8080/ws`.
+    url: String,
+
+    /// The table to query. Defaults to the server's only table, if it hosts
+    /// exactly one.
+    #[arg(short, long)]
+    table: Option<String>,
+
+    /// A JSON view config file to query with.
+    #[arg(long)]
+    view_config: Option<PathBuf>,
+
+    /// Write the query's result to this CSV, Arrow, Parquet or JSON file and
+    /// exit, instead of starting a REPL.
+    #[arg(short, long)]
+    output: Option<PathBuf>,
+}
+
+#[derive(Clone)]
+struct WebSocketClient(UnboundedSender<Vec<u8>>);
+
+impl ClientHandler for WebSocketClient {
+    async fn send_request<'a>(&'a self, msg: &'a [u8]) -> Result<(), CliError> {
+        Ok(self.0.unbounded_send(msg.to_vec())?)
+    }
+}
+
+/// Connect a [`Client`] to the Perspective server at `url`.
+async fn connect(url: &str) -> Result<Client, CliError> {
+    let (socket, _) = tokio_tungstenite::connect_async(url).await?;
+    let (mut sink, mut stream) = socket.split();
+    let (send, mut receiver) = unbounded::<Vec<u8>>();
+    let client = Client::new(WebSocketClient(send));
+    tokio::spawn(async move {
+        while let Some(msg) = receiver.next().await {
+            if let Err(err) = sink.send(Message::Binary(msg)).await {
+                tracing::error!("Send error {}", err);
+                break;
+            }
+        }
+    });
+
+    let handler = client.clone();
+    tokio::spawn(async move {
+        while let Some(msg) = stream.next().await {
+            match msg {
+                Ok(Message::Binary(bytes)) => {
+                    if let Err(err) = handler.handle_response(&bytes).await {
+                        tracing::error!("Response error {}", err);
+                    }
+                },
+                Ok(Message::Close(_)) => break,
+                Ok(_) => {},
+                Err(err) => {
+                    tracing::error!("Receive error {}", err);
+                    break;
+                },
+            }
+        }
+    });
+
+    Ok(client)
+}
+
+async fn open_table(client: &Client, name: Option<String>) -> Result<Option<Table>, CliError> {
+    let name = match name {
+        Some(name) => name,
+        None => match client.get_hosted_table_names().await?.as_slice() {
+            [name] => name.clone(),
+            _ => return Ok(None),
+        },
+    };
+
+    Ok(Some(client.open_table(name).await?))
+}
+
+/// The state of an interactive `query` session.
+struct Repl {
+    client: Client,
+    table: Option<Table>,
+    view: Option<View>,
+    limit: u32,
+}
+
+impl Repl {
+    fn table(&self) -> Result<&Table, CliError> {
+        self.table
+            .as_ref()
+            .ok_or_else(|| "No table selected, see `tables` and `use`".into())
+    }
+
+    fn view(&self) -> Result<&View, CliError> {
+        self.view
+            .as_ref()
+            .ok_or_else(|| "No query, see `view`".into())
+    }
+
+    async fn set_view(&mut self, view: Option<View>) -> Result<(), CliError> {
+        if let Some(old) = std::mem::replace(&mut self.view, view) {
+            old.delete().await?;
+        }
+
+        Ok(())
+    }
+
+    async fn show(&self) -> Result<(), CliError> {
+        let view = self.view()?;
+        let window = ViewWindow {
+            end_row: Some(self.limit as f64),
+            ..ViewWindow::default()
+        };
+
+        print!("{}", view.to_csv(window).await?);
+        println!("({} rows)", view.num_rows().await?);
+        Ok(())
+    }
+
+    /// Run a single REPL command, returning `false` if the REPL should exit.
+    async fn eval(&mut self, line: &str) -> Result<bool, CliError> {
+        let (command, arg) = line
+            .split_once(char::is_whitespace)
+            .map(|(command, arg)| (command, arg.trim()))
+            .unwrap_or((line, ""));
+
+        match command {
+            "" => {},
+            "quit" | "exit" => return Ok(false),
+            "help" => println!("{}", HELP),
+            "tables" => {
+                for name in self.client.get_hosted_table_names().await? {
+                    println!("{}", name);
+                }
+            },
+            "use" => {
+                self.set_view(None).await?;
+                self.table = open_table(&self.client, Some(arg.to_owned())).await?;
+            },
+            "schema" => {
+                let mut schema = self
+                    .table()?
+                    .schema()
+                    .await?
+                    .into_iter()
+                    .collect::<Vec<_>>();
+                schema.sort_by(|x, y| x.0.cmp(&y.0));
+                for (name, column_type) in schema {
+                    println!("{}: {:?}", name, column_type);
+                }
+            },
+            "size" => println!("{}", self.table()?.size().await?),
+            "view" => {
+                let config = if arg.is_empty() {
+                    ViewConfigUpdate::default()
+                } else {
+                    serde_json::from_str(arg)?
+                };
+
+                let view = self.table()?.view(Some(config)).await?;
+                self.set_view(Some(view)).await?;
+                self.show().await?;
+            },
+            "show" => self.show().await?,
+            "limit" => self.limit = arg.parse()?,
+            "export" => {
+                write_view(self.view()?, Path::new(arg)).await?;
+                println!("Wrote {}", arg);
+            },
+            _ => return Err(format!("Unknown command `{}`, see `help`", command).into()),
+        }
+
+        Ok(true)
+    }
+}
+
+/// Query the server at `args.url`, either once to `args.output` or
+/// interactively from stdin.
+pub async fn query(args: QueryArgs) -> Result<(), CliError> {
+    let client = connect(&args.url).await?;
+    let table = open_table(&client, args.table).await?;
+    let config = args
+        .view_config
+        .as_deref()
+        .map(read_view_config)
+        .transpose()?;
+
+    if let Some(output) = args.output {
+        let table = table.ok_or("Server does not host exactly one table, see `--table`")?;
+        let view = table.view(config).await?;
+        write_view(&view, &output).await?;
+        view.delete().await?;
+        return Ok(());
+    }
+
+    let view = match (&table, config) {
+        (Some(table), Some(config)) => Some(table.view(Some(config)).await?),
+        _ => None,
+    };
+
+    let mut repl = Repl {
+        client,
+        table,
+        view,
+        limit: 20,
+    };
+
+    let mut lines = BufReader::new(tokio::io::stdin()).lines();
+    loop {
+        print!("> ");
+        std::io::stdout().flush()?;
+        let Some(line) = lines.next_line().await? else {
+            break;
+        };
+
+        match repl.eval(line.trim()).await {
+            Ok(true) => {},
+            Ok(false) => break,
+            Err(err) => eprintln!("Error: {}", err),
+        }
+    }
+
+    repl.set_view(None).await
+}
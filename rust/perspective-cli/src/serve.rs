@@ -0,0 +1,235 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use std::collections::{HashMap, HashSet};
+use std::net::{IpAddr, SocketAddr};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use axum::extract::connect_info::ConnectInfo;
+use axum::extract::ws::{Message, WebSocket, WebSocketUpgrade};
+use axum::extract::State;
+use axum::response::{Html, IntoResponse};
+use axum::routing::get;
+use axum::Router;
+use clap::Args;
+use futures::channel::mpsc::{unbounded, UnboundedReceiver, UnboundedSender};
+use futures::future::{select, Either};
+use futures::{FutureExt, SinkExt, StreamExt};
+use notify::{Event, RecommendedWatcher, RecursiveMode, Watcher};
+use perspective::client::{Table, TableInitOptions};
+use perspective::server::{Server, Session, SessionHandler};
+use perspective::LocalClient;
+
+use crate::format::read_file;
+use crate::CliError;
+
+const INDEX_HTML: &str = include_str!("index.html");
+
+/// How long to wait after a file changes before reloading it, so the burst of
+/// events a single write produces causes one reload.
+const RELOAD_DEBOUNCE: Duration = Duration::from_millis(200);
+
+#[derive(Args, Debug)]
+pub struct ServeArgs {
+    /// CSV, Arrow, Parquet or JSON files to host, each as a table named after
+    /// its file name (without extension).
+    #[arg(required = true)]
+    files: Vec<PathBuf>,
+
+    /// The port to listen on.
+    #[arg(short, long, default_value_t = 8080)]
+    port: u16,
+
+    /// The address to listen on. Defaults to loopback only; use `0.0.0.0` to
+    /// accept connections from other hosts.
+    #[arg(long, default_value = "127.0.0.1")]
+    host: IpAddr,
+
+    /// A column to use as the index of every table.
+    #[arg(short, long)]
+    index: Option<String>,
+
+    /// Reload a table's data when its file changes.
+    #[arg(short, long)]
+    watch: bool,
+}
+
+#[derive(Clone)]
+struct WebSocketConnection(UnboundedSender<Vec<u8>>);
+
+impl SessionHandler for WebSocketConnection {
+    async fn send_response<'a>(&'a mut self, resp: &'a [u8]) -> Result<(), CliError> {
+        Ok(self.0.send(resp.to_vec()).await?)
+    }
+}
+
+enum WebSocketMessage {
+    Incoming(Vec<u8>),
+    Outgoing(Vec<u8>),
+    End,
+}
+
+async fn process_message_loop(
+    socket: &mut WebSocket,
+    receiver: &mut UnboundedReceiver<Vec<u8>>,
+    session: &mut Session,
+) -> Result<(), CliError> {
+    use Either::*;
+    use Message::*;
+    use WebSocketMessage::*;
+
+    loop {
+        let msg = match select(socket.recv().boxed(), receiver.next()).await {
+            Right((Some(bytes), _)) => Ok(Outgoing(bytes)),
+            Left((Some(Ok(Binary(bytes))), _)) => Ok(Incoming(bytes)),
+            Right((None, _)) | Left((None | Some(Ok(Close(_))), _)) => Ok(End),
+            Left((Some(Ok(_)), _)) => Err("Unexpected message type".to_string()),
+            Left((Some(Err(err)), _)) => Err(format!("{}", err)),
+        }?;
+
+        match msg {
+            End => break,
+            Outgoing(bytes) => socket.send(Binary(bytes)).await?,
+            Incoming(bytes) => {
+                session.handle_request(&bytes).await?;
+                session.poll().await?
+            },
+        }
+    }
+
+    Ok(())
+}
+
+async fn websocket_handler(
+    ws: WebSocketUpgrade,
+    State(server): State<Server>,
+    ConnectInfo(addr): ConnectInfo<SocketAddr>,
+) -> impl IntoResponse {
+    tracing::info!("{addr} Connected.");
+    ws.on_upgrade(move |mut socket| async move {
+        let (send, mut receiver) = unbounded::<Vec<u8>>();
+        let mut session = server.new_session(WebSocketConnection(send)).await;
+        if let Err(msg) = process_message_loop(&mut socket, &mut receiver, &mut session).await {
+            tracing::error!("Internal error {}", msg);
+        }
+
+        session.close().await;
+        tracing::info!("{addr} Disconnected.");
+    })
+}
+
+async fn index_handler() -> Html<String> {
+    Html(INDEX_HTML.replace("{{VERSION}}", env!("CARGO_PKG_VERSION")))
+}
+
+fn table_name(path: &Path) -> Result<String, CliError> {
+    path.file_stem()
+        .and_then(|x| x.to_str())
+        .map(|x| x.to_owned())
+        .ok_or_else(|| format!("Invalid file name `{}`", path.display()).into())
+}
+
+/// Reload each table in `tables` (by canonical file path) when its file
+/// changes. Parent directories are watched rather than the files themselves,
+/// so files replaced by rename (as many editors and writers do) keep
+/// reloading.
+fn watch_tables(tables: HashMap<PathBuf, Table>) -> Result<RecommendedWatcher, CliError> {
+    let (send, mut receiver) = tokio::sync::mpsc::unbounded_channel::<PathBuf>();
+    let mut watcher =
+        notify::recommended_watcher(move |event: notify::Result<Event>| match event {
+            Ok(event) if event.kind.is_create() || event.kind.is_modify() => {
+                for path in event.paths {
+                    let _ = send.send(path);
+                }
+            },
+            Ok(_) => {},
+            Err(err) => tracing::error!("Watch error {}", err),
+        })?;
+
+    let dirs = tables
+        .keys()
+        .filter_map(|x| x.parent())
+        .collect::<HashSet<_>>();
+
+    for dir in dirs {
+        watcher.watch(dir, RecursiveMode::NonRecursive)?;
+    }
+
+    tokio::spawn(async move {
+        while let Some(path) = receiver.recv().await {
+            tokio::time::sleep(RELOAD_DEBOUNCE).await;
+            let mut paths = HashSet::from([path]);
+            while let Ok(path) = receiver.try_recv() {
+                paths.insert(path);
+            }
+
+            for path in paths {
+                let Some(table) = tables.get(&path) else {
+                    continue;
+                };
+
+                let result = match read_file(&path) {
+                    Ok(data) => table.replace_atomic(data).await.map_err(CliError::from),
+                    Err(err) => Err(err),
+                };
+
+                match result {
+                    Ok(()) => tracing::info!("Reloaded `{}`", table.get_name()),
+                    Err(err) => tracing::warn!("Failed to reload `{}`: {}", path.display(), err),
+                }
+            }
+        }
+    });
+
+    Ok(watcher)
+}
+
+/// Host `args.files` as tables on a new [`Server`], and serve it over a
+/// WebSocket at `/ws` (with a viewer page at `/`) until the process exits.
+pub async fn serve(args: ServeArgs) -> Result<(), CliError> {
+    let server = Server::default();
+    let client = LocalClient::new(&server);
+    let mut tables = HashMap::new();
+    let mut names = HashSet::new();
+    for path in &args.files {
+        let name = table_name(path)?;
+        if !names.insert(name.clone()) {
+            return Err(format!("Duplicate table name `{}`", name).into());
+        }
+
+        let mut options = TableInitOptions::default();
+        options.set_name(&name);
+        options.index = args.index.clone();
+        let table = client.table(read_file(path)?.into(), options).await?;
+        tracing::info!("Hosted `{}` as `{}`", path.display(), name);
+        tables.insert(path.canonicalize()?, table);
+    }
+
+    let _watcher = if args.watch {
+        Some(watch_tables(tables)?)
+    } else {
+        None
+    };
+
+    let app = Router::new()
+        .route("/", get(index_handler))
+        .route("/ws", get(websocket_handler))
+        .with_state(server);
+
+    let service = app.into_make_service_with_connect_info::<SocketAddr>();
+    let listener = tokio::net::TcpListener::bind(SocketAddr::new(args.host, args.port)).await?;
+    tracing::info!("Listening on http://{}", listener.local_addr()?);
+    let result = axum::serve(listener, service).await;
+    client.close().await;
+    Ok(result?)
+}
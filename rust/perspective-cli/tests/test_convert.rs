@@ -0,0 +1,77 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+const CSV: &str = "sym,price,qty\nA,1.5,10\nA,3.5,20\nB,5.25,30\n";
+
+/// A fresh scratch directory for a test.
+fn scratch_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("perspective-cli-{}-{}", name, std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+    std::fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+fn convert(input: &Path, output: &Path, args: &[&str]) {
+    let status = Command::new(env!("CARGO_BIN_EXE_perspective-cli"))
+        .arg("convert")
+        .arg(input)
+        .arg(output)
+        .args(args)
+        .status()
+        .unwrap();
+
+    assert!(status.success());
+}
+
+#[test]
+fn test_convert_round_trips_every_format() {
+    let dir = scratch_dir("round-trip");
+    let input = dir.join("input.csv");
+    std::fs::write(&input, CSV).unwrap();
+    let expected = dir.join("expected.csv");
+    convert(&input, &expected, &[]);
+    let mut last = input;
+    for name in ["data.arrow", "data.parquet", "data.json", "output.csv"] {
+        let next = dir.join(name);
+        convert(&last, &next, &[]);
+        last = next;
+    }
+
+    assert_eq!(
+        std::fs::read_to_string(last).unwrap(),
+        std::fs::read_to_string(expected).unwrap()
+    );
+}
+
+#[test]
+fn test_convert_group_by_aggregate() {
+    let dir = scratch_dir("group-by");
+    let input = dir.join("input.csv");
+    let output = dir.join("output.csv");
+    std::fs::write(&input, CSV).unwrap();
+    convert(&input, &output, &[
+        "--group-by",
+        "sym",
+        "--columns",
+        "price",
+        "--aggregate",
+        "price:mean",
+    ]);
+
+    let csv = std::fs::read_to_string(output).unwrap().replace('"', "");
+    let lines = csv.lines().collect::<Vec<_>>();
+    assert!(lines.contains(&"A,2.5"));
+    assert!(lines.contains(&"B,5.25"));
+}
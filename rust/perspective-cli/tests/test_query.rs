@@ -0,0 +1,163 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use std::path::PathBuf;
+use std::process::{Output, Stdio};
+
+use futures::channel::mpsc::{unbounded, UnboundedSender};
+use futures::{SinkExt, StreamExt};
+use perspective::client::{TableInitOptions, UpdateData};
+use perspective::server::{Server, SessionHandler};
+use perspective::LocalClient;
+use tokio::io::AsyncWriteExt;
+use tokio::net::{TcpListener, TcpStream};
+use tokio::process::Command;
+use tokio_tungstenite::tungstenite::Message;
+
+type TestError = Box<dyn std::error::Error + Send + Sync>;
+
+const CSV: &str = "sym,price,qty\nA,1.5,10\nA,3.5,20\nB,5.25,30\n";
+
+/// A fresh scratch directory for a test.
+fn scratch_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("perspective-cli-{}-{}", name, std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+    std::fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+#[derive(Clone)]
+struct WebSocketConnection(UnboundedSender<Vec<u8>>);
+
+impl SessionHandler for WebSocketConnection {
+    async fn send_response<'a>(&'a mut self, resp: &'a [u8]) -> Result<(), TestError> {
+        Ok(self.0.send(resp.to_vec()).await?)
+    }
+}
+
+async fn handle_connection(server: Server, stream: TcpStream) -> Result<(), TestError> {
+    let (mut sink, mut stream) = tokio_tungstenite::accept_async(stream).await?.split();
+    let (send, mut receiver) = unbounded::<Vec<u8>>();
+    let mut session = server.new_session(WebSocketConnection(send)).await;
+    tokio::spawn(async move {
+        while let Some(msg) = receiver.next().await {
+            if sink.send(Message::Binary(msg)).await.is_err() {
+                break;
+            }
+        }
+    });
+
+    while let Some(Ok(msg)) = stream.next().await {
+        if let Message::Binary(bytes) = msg {
+            session.handle_request(&bytes).await?;
+            session.poll().await?;
+        }
+    }
+
+    session.close().await;
+    Ok(())
+}
+
+/// Host [`CSV`] as `trades` on an in-process [`Server`], and return the URL
+/// of its WebSocket.
+async fn host_trades() -> Result<String, TestError> {
+    let server = Server::default();
+    let client = LocalClient::new(&server);
+    let mut options = TableInitOptions::default();
+    options.set_name("trades");
+    client
+        .table(UpdateData::Csv(CSV.to_owned()).into(), options)
+        .await?;
+
+    let listener = TcpListener::bind("127.0.0.1:0").await?;
+    let url = format!("ws://{}/ws", listener.local_addr()?);
+    tokio::spawn(async move {
+        while let Ok((stream, _)) = listener.accept().await {
+            tokio::spawn(handle_connection(server.clone(), stream));
+        }
+    });
+
+    Ok(url)
+}
+
+/// Run `perspective-cli query` against `url` with `stdin` as its input.
+async fn query(url: &str, args: &[&str], stdin: &str) -> Result<Output, TestError> {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_perspective-cli"))
+        .arg("query")
+        .arg(url)
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()?;
+
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(stdin.as_bytes())
+        .await?;
+
+    Ok(child.wait_with_output().await?)
+}
+
+#[tokio::test]
+async fn test_query_writes_view_to_output() -> Result<(), TestError> {
+    let url = host_trades().await?;
+    let dir = scratch_dir("query-output");
+    let config = dir.join("config.json");
+    let output = dir.join("output.csv");
+    std::fs::write(
+        &config,
+        r#"{"columns": ["sym", "price"], "filter": [["price", ">", 2]], "sort": [["price", "desc"]]}"#,
+    )?;
+
+    let result = query(
+        &url,
+        &[
+            "--view-config",
+            config.to_str().unwrap(),
+            "--output",
+            output.to_str().unwrap(),
+        ],
+        "",
+    )
+    .await?;
+
+    assert!(result.status.success());
+    assert_eq!(
+        std::fs::read_to_string(output)?.replace('"', ""),
+        "sym,price\nB,5.25\nA,3.5\n"
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_query_repl_reports_bad_commands() -> Result<(), TestError> {
+    let url = host_trades().await?;
+    let input = ["bogus", r#"view {"group_by": ["#, "size", "quit"].join("\n");
+    let result = query(&url, &["--table", "trades"], &input).await?;
+    let stdout = String::from_utf8(result.stdout)?;
+    let stderr = String::from_utf8(result.stderr)?;
+    let errors = stderr
+        .lines()
+        .filter(|x| x.starts_with("Error: "))
+        .collect::<Vec<_>>();
+
+    // Each bad command is reported, and the REPL carries on to `size`.
+    assert!(result.status.success());
+    assert_eq!(errors.len(), 2);
+    assert_eq!(errors[0], "Error: Unknown command `bogus`, see `help`");
+    assert!(stdout.contains("> 3\n"));
+    Ok(())
+}
@@ -0,0 +1,158 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use std::io::{BufRead, BufReader};
+use std::path::{Path, PathBuf};
+use std::process::{Child, Command, Stdio};
+use std::time::Duration;
+
+use futures::channel::mpsc::{unbounded, UnboundedSender};
+use futures::{SinkExt, StreamExt};
+use perspective::client::{Client, ClientHandler, ViewWindow};
+use tokio_tungstenite::tungstenite::Message;
+
+type TestError = Box<dyn std::error::Error + Send + Sync>;
+
+/// A fresh scratch directory for a test.
+fn scratch_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("perspective-cli-{}-{}", name, std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+    std::fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+/// A running `perspective-cli serve`, killed on drop.
+struct Serve {
+    child: Child,
+    port: u16,
+}
+
+impl Drop for Serve {
+    fn drop(&mut self) {
+        let _ = self.child.kill();
+        let _ = self.child.wait();
+    }
+}
+
+/// Start `perspective-cli serve` on an ephemeral port, and wait until it is
+/// listening.
+fn serve(file: &Path, args: &[&str]) -> Serve {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_perspective-cli"))
+        .arg("serve")
+        .arg(file)
+        .args(["--port", "0"])
+        .args(args)
+        .stdout(Stdio::piped())
+        .spawn()
+        .unwrap();
+
+    let mut lines = BufReader::new(child.stdout.take().unwrap()).lines();
+    let port = loop {
+        let line = lines.next().expect("`serve` exited").unwrap();
+        if let Some((_, addr)) = line.split_once("Listening on http://127.0.0.1:") {
+            let port = addr
+                .chars()
+                .take_while(char::is_ascii_digit)
+                .collect::<String>();
+            break port.parse().unwrap();
+        }
+    };
+
+    // Keep draining the log, so `serve` never blocks writing to it.
+    std::thread::spawn(move || lines.for_each(drop));
+    Serve { child, port }
+}
+
+#[derive(Clone)]
+struct WebSocketClient(UnboundedSender<Vec<u8>>);
+
+impl ClientHandler for WebSocketClient {
+    async fn send_request<'a>(&'a self, msg: &'a [u8]) -> Result<(), TestError> {
+        Ok(self.0.unbounded_send(msg.to_vec())?)
+    }
+}
+
+/// Connect a [`Client`] to `serve`'s `/ws` endpoint.
+async fn connect(serve: &Serve) -> Result<Client, TestError> {
+    let url = format!("ws://127.0.0.1:{}/ws", serve.port);
+    let (socket, _) = tokio_tungstenite::connect_async(url).await?;
+    let (mut sink, mut stream) = socket.split();
+    let (send, mut receiver) = unbounded::<Vec<u8>>();
+    let client = Client::new(WebSocketClient(send));
+    tokio::spawn(async move {
+        while let Some(msg) = receiver.next().await {
+            if sink.send(Message::Binary(msg)).await.is_err() {
+                break;
+            }
+        }
+    });
+
+    let handler = client.clone();
+    tokio::spawn(async move {
+        while let Some(Ok(msg)) = stream.next().await {
+            if let Message::Binary(bytes) = msg {
+                handler.handle_response(&bytes).await.unwrap();
+            }
+        }
+    });
+
+    Ok(client)
+}
+
+/// The hosted table `name`, as JSON columns.
+async fn read_table(client: &Client, name: &str) -> Result<String, TestError> {
+    let view = client.open_table(name.to_owned()).await?.view(None).await?;
+    let json = view.to_columns_string(ViewWindow::default()).await?;
+    view.delete().await?;
+    Ok(json)
+}
+
+#[tokio::test]
+async fn test_serve_hosts_file_over_websocket() -> Result<(), TestError> {
+    let dir = scratch_dir("serve");
+    let file = dir.join("prices.csv");
+    std::fs::write(&file, "sym,price\nA,1.5\nB,2.5\n")?;
+    let serve = serve(&file, &[]);
+    let client = connect(&serve).await?;
+    assert_eq!(client.get_hosted_table_names().await?, vec!["prices"]);
+    assert_eq!(
+        read_table(&client, "prices").await?,
+        r#"{"sym":["A","B"],"price":[1.5,2.5]}"#
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_serve_watch_reloads_changed_file() -> Result<(), TestError> {
+    let dir = scratch_dir("serve-watch");
+    let file = dir.join("prices.csv");
+    std::fs::write(&file, "sym,price\nA,1.5\n")?;
+    let serve = serve(&file, &["--watch"]);
+    let client = connect(&serve).await?;
+    assert_eq!(
+        read_table(&client, "prices").await?,
+        r#"{"sym":["A"],"price":[1.5]}"#
+    );
+
+    std::fs::write(&file, "sym,price\nB,2.5\nC,3.5\n")?;
+    let expected = r#"{"sym":["B","C"],"price":[2.5,3.5]}"#;
+    for _ in 0..100 {
+        if read_table(&client, "prices").await? == expected {
+            return Ok(());
+        }
+
+        tokio::time::sleep(Duration::from_millis(100)).await;
+    }
+
+    Err("`--watch` did not reload the changed file".into())
+}
@@ -36,8 +36,18 @@ fn prost_build() -> Result<()> {
             // .bytes(["ViewToArrowResp.arrow", "from_arrow"])
             .type_attribute("ViewOnUpdateResp", "#[derive(ts_rs::TS)]")
             .field_attribute("ViewOnUpdateResp.delta", "#[serde(with = \"serde_bytes\")]")
+            .field_attribute("ViewOnUpdateResp.port_sequence", "#[ts(type = \"number\")]")
+            .type_attribute("ViewportUpdate", "#[derive(ts_rs::TS)]")
+            .field_attribute("ViewportUpdate.num_rows", "#[ts(type = \"number\")]")
+            .field_attribute(
+                "ViewportUpdate.rows",
+                "#[ts(type = \"Record<number, string>\")]",
+            )
             .field_attribute("ViewToArrowResp.arrow", "#[serde(skip)]")
             .field_attribute("from_arrow", "#[serde(skip)]")
+            .field_attribute("TableIngestArrowReq.chunk", "#[serde(skip)]")
+            .field_attribute("ServerBulkExportResp.payload", "#[serde(skip)]")
+            .field_attribute("Profile.pprof", "#[serde(with = \"serde_bytes\")]")
             .type_attribute(".", "#[derive(serde::Serialize)]")
             .type_attribute("ViewDimensionsResp", "#[derive(serde::Deserialize)]")
             .type_attribute("TableValidateExprResp", "#[derive(serde::Deserialize)]")
@@ -46,6 +56,8 @@ fn prost_build() -> Result<()> {
                 "#[derive(serde::Deserialize)]  #[serde(rename_all = \"snake_case\")]",
             )
             .type_attribute("ExprValidationError", "#[derive(serde::Deserialize)]")
+            .type_attribute("CellEdit", "#[derive(serde::Deserialize)]")
+            .file_descriptor_set_path(std::env::var("OUT_DIR").unwrap() + "/perspective.bin")
             .compile_protos(&[proto_file], &[include_path])
             .unwrap();
 
@@ -53,6 +65,12 @@ fn prost_build() -> Result<()> {
             std::env::var("OUT_DIR").unwrap() + "/perspective.proto.rs",
             "src/rust/proto.rs",
         )?;
+
+        // The encoded `FileDescriptorSet`, exported by `protocol::descriptor`.
+        std::fs::rename(
+            std::env::var("OUT_DIR").unwrap() + "/perspective.bin",
+            "src/rust/perspective.bin",
+        )?;
     }
 
     Ok(())
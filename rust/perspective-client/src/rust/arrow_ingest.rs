@@ -0,0 +1,66 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use prost::bytes::Bytes;
+
+use crate::table::{Table, UpdateOptions};
+use crate::utils::*;
+
+/// An Arrow IPC stream being written to a [`Table`] in chunks, created by
+/// [`Table::arrow_ingest`].
+///
+/// Chunks may be split at any byte boundary (e.g. as they are read from a
+/// socket or file) and are forwarded to the server as-is, which decodes them
+/// incrementally and applies each record batch as soon as it is complete.
+#[derive(Clone)]
+pub struct ArrowIngest {
+    table: Table,
+    stream_id: u32,
+    port_id: u32,
+}
+
+impl std::fmt::Debug for ArrowIngest {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ArrowIngest")
+            .field("stream_id", &self.stream_id)
+            .field("port_id", &self.port_id)
+            .finish()
+    }
+}
+
+impl ArrowIngest {
+    pub(crate) fn new(table: Table, stream_id: u32, options: UpdateOptions) -> Self {
+        ArrowIngest {
+            table,
+            stream_id,
+            port_id: options.port_id.unwrap_or(0),
+        }
+    }
+
+    /// Send the next `chunk` of the stream, returning the number of rows
+    /// decoded from the stream so far.
+    pub async fn write<B: Into<Bytes>>(&self, chunk: B) -> ClientResult<u64> {
+        self.table
+            .ingest_arrow_chunk(self.stream_id, self.port_id, chunk.into(), false)
+            .await
+    }
+
+    /// Send the final `chunk` of the stream (which may be empty), returning
+    /// the total number of rows decoded from it. The server releases the
+    /// stream's decoder state, so a stream which is never finished holds
+    /// onto it until the session closes.
+    pub async fn finish<B: Into<Bytes>>(self, chunk: B) -> ClientResult<u64> {
+        self.table
+            .ingest_arrow_chunk(self.stream_id, self.port_id, chunk.into(), true)
+            .await
+    }
+}
@@ -0,0 +1,77 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use prost::bytes::Bytes;
+
+use crate::proto::bulk_export_entity::Kind;
+use crate::proto::{BulkExportEntity, BulkExportManifestEntry};
+use crate::table::Table;
+use crate::view::View;
+
+impl From<&Table> for BulkExportEntity {
+    fn from(value: &Table) -> Self {
+        BulkExportEntity {
+            entity_id: value.get_name().to_owned(),
+            kind: Kind::Table as i32,
+        }
+    }
+}
+
+impl From<&View> for BulkExportEntity {
+    fn from(value: &View) -> Self {
+        BulkExportEntity {
+            entity_id: value.name.clone(),
+            kind: Kind::View as i32,
+        }
+    }
+}
+
+/// Snapshots of several [`Table`]s and [`View`]s, as returned by
+/// [`crate::Client::bulk_export`]: one Arrow IPC stream per entity, sliced
+/// out of a single payload by its manifest.
+#[derive(Clone, Debug)]
+pub struct BulkExport {
+    payload: Bytes,
+    manifest: Vec<BulkExportManifestEntry>,
+}
+
+impl BulkExport {
+    pub(crate) fn new(payload: Vec<u8>, manifest: Vec<BulkExportManifestEntry>) -> Self {
+        BulkExport {
+            payload: payload.into(),
+            manifest,
+        }
+    }
+
+    /// Where each entity's Arrow stream lies in the payload, in request
+    /// order.
+    pub fn manifest(&self) -> &[BulkExportManifestEntry] {
+        &self.manifest
+    }
+
+    /// The Arrow IPC stream of each exported entity, in request order.
+    pub fn iter(&self) -> impl Iterator<Item = (&BulkExportEntity, Bytes)> {
+        self.manifest.iter().filter_map(|entry| {
+            let start = usize::try_from(entry.offset).ok()?;
+            let end = start.checked_add(usize::try_from(entry.length).ok()?)?;
+            let arrow = self.payload.get(start..end)?;
+            Some((entry.entity.as_ref()?, self.payload.slice_ref(arrow)))
+        })
+    }
+
+    /// The Arrow IPC stream of the first exported entity named `entity_id`.
+    pub fn get(&self, entity_id: &str) -> Option<Bytes> {
+        self.iter()
+            .find(|(entity, _)| entity.entity_id == entity_id)
+            .map(|(_, arrow)| arrow)
+    }
+}
@@ -17,18 +17,23 @@ use std::sync::Arc;
 
 use async_lock::{Mutex, RwLock};
 use futures::future::BoxFuture;
-use futures::Future;
+use futures::{Future, FutureExt};
 use nanoid::*;
 use prost::Message;
 use tracing_unwrap::{OptionExt, ResultExt};
 
+use crate::bulk_export::BulkExport;
+use crate::offline::{OfflineBuffer, OfflineBufferOptions};
 use crate::proto::request::ClientReq;
 use crate::proto::response::ClientResp;
 use crate::proto::{
-    ColumnType, GetFeaturesReq, GetFeaturesResp, GetHostedTablesReq, GetHostedTablesResp,
-    HostedTable, MakeTableReq, Request, Response, ServerSystemInfoReq,
+    schema, BulkExportEntity, ColumnType, GetFeaturesReq, GetFeaturesResp, GetHostedTablesReq,
+    GetHostedTablesResp, HostedTable, MakeTableData, MakeTableReq, Profile,
+    RemoveHostedTablesUpdateReq, Request, Response, ServerBroadcastResp, ServerBulkExportReq,
+    ServerBulkExportResp, ServerDiagnosticsReq, ServerDiagnosticsResp, ServerExpressionsReq,
+    ServerExpressionsResp, ServerHelloReq, ServerHelloResp, ServerSystemInfoReq, StatusCode,
 };
-use crate::table::{SystemInfo, Table, TableInitOptions, TableOptions};
+use crate::table::{CsvOptions, Schema, SystemInfo, Table, TableInitOptions, TableOptions};
 use crate::table_data::{TableData, UpdateData};
 use crate::utils::*;
 use crate::view::ViewWindow;
@@ -43,8 +48,82 @@ impl GetFeaturesResp {
     }
 }
 
+/// The wire protocol version and optional features negotiated between this
+/// `Client` and the `Server` it is connected to.
+pub type Protocol = Arc<ServerHelloResp>;
+
+/// Diagnostics of the `Server` this `Client` is connected to, as returned by
+/// [`Client::diagnostics`].
+pub type Diagnostics = ServerDiagnosticsResp;
+
+impl ServerHelloResp {
+    /// Whether both peers support the optional protocol feature `feature`,
+    /// one of [`crate::protocol::FEATURES`].
+    pub fn supports(&self, feature: &str) -> bool {
+        self.features.iter().any(|x| x == feature)
+    }
+}
+
 type BoxFn<I, O> = Box<dyn Fn(I) -> O + Send + Sync + 'static>;
 
+/// A read's result, as received by each caller sharing it.
+type SharedResult = Result<ClientResp, Arc<ClientError>>;
+
+type SharedResponse = futures::channel::oneshot::Sender<SharedResult>;
+
+/// Identical read requests in-flight at the same time share the response to
+/// the first. Any other request ends sharing for the reads before it, so a
+/// read never observes state older than a write which preceded it.
+#[derive(Default)]
+struct ReadsInFlight {
+    /// Encoded requests (without `msg_id`) by the `msg_id` they were sent as.
+    requests: HashMap<Vec<u8>, u32>,
+
+    /// Callers awaiting the response to an in-flight read, by `msg_id`.
+    waiters: HashMap<u32, Vec<SharedResponse>>,
+}
+
+/// Ends sharing of the in-flight read `msg_id` when the caller which sent it
+/// completes _or_ is dropped (e.g. by a timeout). If it completed, its waiters
+/// receive its result. Otherwise they are woken with no result, and retry the
+/// read themselves.
+struct ReadLeader {
+    reads: Arc<std::sync::Mutex<ReadsInFlight>>,
+    subscriptions_once: Subscriptions<OnceCallback>,
+    key: Vec<u8>,
+    msg_id: u32,
+    result: Option<SharedResult>,
+}
+
+impl Drop for ReadLeader {
+    fn drop(&mut self) {
+        let waiters = match self.reads.lock() {
+            Ok(mut reads) => {
+                if reads.requests.get(&self.key) == Some(&self.msg_id) {
+                    reads.requests.remove(&self.key);
+                }
+
+                reads.waiters.remove(&self.msg_id).unwrap_or_default()
+            },
+            Err(_) => vec![],
+        };
+
+        match &self.result {
+            Some(result) => {
+                for waiter in waiters {
+                    let _ = waiter.send(result.clone());
+                }
+            },
+            None => {
+                // The response to a cancelled read is unsolicited.
+                if let Some(mut subscriptions) = self.subscriptions_once.try_write() {
+                    subscriptions.remove(&self.msg_id);
+                }
+            },
+        }
+    }
+}
+
 type Subscriptions<C> = Arc<RwLock<HashMap<u32, C>>>;
 type OnceCallback = Box<dyn FnOnce(ClientResp) -> ClientResult<()> + Send + Sync + 'static>;
 type SendCallback = Arc<
@@ -65,10 +144,15 @@ pub trait ClientHandler: Clone + Send + Sync + 'static {
 #[doc = include_str!("../../docs/client.md")]
 pub struct Client {
     features: Arc<Mutex<Option<Features>>>,
+    protocol: Arc<Mutex<Option<Protocol>>>,
     send: SendCallback,
     id_gen: Arc<AtomicU32>,
     subscriptions_once: Subscriptions<OnceCallback>,
     subscriptions: Subscriptions<BoxFn<ClientResp, BoxFuture<'static, Result<(), ClientError>>>>,
+    broadcast_subscriptions: Subscriptions<BoxFn<ServerBroadcastResp, ()>>,
+    reads_in_flight: Arc<std::sync::Mutex<ReadsInFlight>>,
+    offline: Arc<std::sync::Mutex<Option<OfflineBuffer>>>,
+    replay_lock: Arc<Mutex<()>>,
 }
 
 impl std::fmt::Debug for Client {
@@ -99,9 +183,14 @@ impl Client {
 
         Client {
             features: Arc::default(),
+            protocol: Arc::default(),
             id_gen: Arc::new(AtomicU32::new(1)),
             subscriptions_once: Arc::default(),
             subscriptions: Subscriptions::default(),
+            broadcast_subscriptions: Subscriptions::default(),
+            reads_in_flight: Arc::default(),
+            offline: Arc::default(),
+            replay_lock: Arc::default(),
             send,
         }
     }
@@ -127,6 +216,18 @@ impl Client {
         let msg = Response::decode(msg)?;
         tracing::debug!("RECV {}", msg);
         let payload = msg.client_resp.ok_or(ClientError::Option)?;
+        if let ClientResp::ServerError(err) = &payload {
+            tracing::warn!(request_id = %err.request_id, "Server error: {}", err.message);
+        }
+
+        if let ClientResp::ServerBroadcastResp(broadcast) = &payload {
+            for callback in self.broadcast_subscriptions.try_read().unwrap().values() {
+                callback(broadcast.clone());
+            }
+
+            return Ok(());
+        }
+
         let mut wr = self.subscriptions_once.try_write().unwrap();
         if let Some(handler) = (*wr).remove(&msg.msg_id) {
             drop(wr);
@@ -142,6 +243,7 @@ impl Client {
     }
 
     pub async fn init(&self) -> ClientResult<()> {
+        *self.protocol.lock().await = Some(Arc::new(self.hello().await?));
         let msg = Request {
             msg_id: self.gen_id(),
             entity_id: "".to_owned(),
@@ -156,6 +258,72 @@ impl Client {
         Ok(())
     }
 
+    /// Negotiate a wire protocol version with the server, before any other
+    /// request is sent.
+    async fn hello(&self) -> ClientResult<ServerHelloResp> {
+        let msg = Request {
+            msg_id: self.gen_id(),
+            entity_id: "".to_owned(),
+            client_req: Some(ClientReq::ServerHelloReq(ServerHelloReq {
+                min_protocol_version: crate::protocol::MIN_PROTOCOL_VERSION,
+                max_protocol_version: crate::protocol::MAX_PROTOCOL_VERSION,
+                features: crate::protocol::FEATURES
+                    .iter()
+                    .map(|x| x.to_string())
+                    .collect(),
+                client_version: crate::protocol::PACKAGE_VERSION.to_owned(),
+            })),
+        };
+
+        match self.oneshot(&msg).await? {
+            ClientResp::ServerHelloResp(resp) => Ok(resp),
+            ClientResp::ServerError(err) if err.status_code() != StatusCode::ProtocolMismatch => {
+                tracing::debug!("Server predates protocol negotiation: {}", err.message);
+                Ok(ServerHelloResp {
+                    protocol_version: 1,
+                    ..ServerHelloResp::default()
+                })
+            },
+            resp => Err(resp.into()),
+        }
+    }
+
+    #[doc = include_str!("../../docs/client/get_protocol.md")]
+    pub fn get_protocol(&self) -> ClientResult<Protocol> {
+        Ok(self
+            .protocol
+            .try_lock()
+            .ok_or(ClientError::NotInitialized)?
+            .as_ref()
+            .ok_or(ClientError::NotInitialized)?
+            .clone())
+    }
+
+    #[doc = include_str!("../../docs/client/on_broadcast.md")]
+    pub fn on_broadcast<T>(&self, on_broadcast: T) -> u32
+    where
+        T: Fn(ServerBroadcastResp) + Send + Sync + 'static,
+    {
+        let id = self.gen_id();
+        self.broadcast_subscriptions
+            .try_write()
+            .unwrap()
+            .insert(id, Box::new(on_broadcast));
+
+        id
+    }
+
+    #[doc = include_str!("../../docs/client/remove_broadcast.md")]
+    pub fn remove_broadcast(&self, callback_id: u32) -> ClientResult<()> {
+        self.broadcast_subscriptions
+            .try_write()
+            .unwrap()
+            .remove(&callback_id)
+            .ok_or(ClientError::Unknown("remove_broadcast".to_string()))?;
+
+        Ok(())
+    }
+
     /// Generate a message ID unique to this client.
     pub(crate) fn gen_id(&self) -> u32 {
         self.id_gen
@@ -203,8 +371,66 @@ impl Client {
     }
 
     /// Send a `ClientReq` and await both the successful completion of the
-    /// `send`, _and_ the `ClientResp` which is returned.
+    /// `send`, _and_ the `ClientResp` which is returned. Reads may share the
+    /// response to an identical in-flight request.
     pub(crate) async fn oneshot(&self, msg: &Request) -> ClientResult<ClientResp> {
+        if msg.client_req.as_ref().is_some_and(ClientReq::is_read) {
+            self.oneshot_read(msg).await
+        } else {
+            self.oneshot_write(msg).await
+        }
+    }
+
+    async fn oneshot_read(&self, msg: &Request) -> ClientResult<ClientResp> {
+        let key = Request {
+            msg_id: 0,
+            ..msg.clone()
+        }
+        .encode_to_vec();
+
+        loop {
+            let shared = {
+                let mut reads = self.reads_in_flight.lock().unwrap();
+                if let Some(msg_id) = reads.requests.get(&key).copied() {
+                    let (sender, receiver) = futures::channel::oneshot::channel();
+                    reads.waiters.entry(msg_id).or_default().push(sender);
+                    receiver
+                } else {
+                    reads.requests.insert(key.clone(), msg.msg_id);
+                    break;
+                }
+            };
+
+            // A cancelled sender means the read we shared was dropped before
+            // its response arrived, so retry (sending it ourselves, unless
+            // another waiter has already done so).
+            if let Ok(result) = shared.await {
+                return result.map_err(ClientError::from_shared);
+            }
+        }
+
+        let mut leader = ReadLeader {
+            reads: self.reads_in_flight.clone(),
+            subscriptions_once: self.subscriptions_once.clone(),
+            key,
+            msg_id: msg.msg_id,
+            result: None,
+        };
+
+        let result = self.send_once(msg).await.map_err(Arc::new);
+        leader.result = Some(result.clone());
+        drop(leader);
+        result.map_err(ClientError::from_shared)
+    }
+
+    /// Writes are never shared or delayed, so they are sent in the order
+    /// they are made.
+    async fn oneshot_write(&self, msg: &Request) -> ClientResult<ClientResp> {
+        self.reads_in_flight.lock().unwrap().requests.clear();
+        self.send_once(msg).await
+    }
+
+    async fn send_once(&self, msg: &Request) -> ClientResult<ClientResp> {
         let (sender, receiver) = futures::channel::oneshot::channel::<ClientResp>();
         let callback = Box::new(move |msg| sender.send(msg).map_err(|x| x.into()));
         self.subscriptions_once
@@ -219,6 +445,109 @@ impl Client {
             .map_err(|_| ClientError::Unknown("Internal error".to_owned()))
     }
 
+    /// [`Client::oneshot`] for a `TableUpdateReq`, which is buffered instead
+    /// (returning `None`) if the `send` fails, or if earlier updates are
+    /// already buffered, when [`Client::set_offline_buffer`] is enabled.
+    pub(crate) async fn oneshot_update(&self, msg: &Request) -> ClientResult<Option<ClientResp>> {
+        if let Some(buffer) = self.offline.lock().unwrap().as_mut() {
+            if !buffer.updates.is_empty() {
+                buffer.push(msg.clone())?;
+                return Ok(None);
+            }
+        }
+
+        match self.oneshot(msg).await {
+            Err(ClientError::ExternalError(err)) => {
+                self.subscriptions_once
+                    .try_write()
+                    .unwrap()
+                    .remove(&msg.msg_id);
+
+                match self.offline.lock().unwrap().as_mut() {
+                    Some(buffer) => {
+                        tracing::warn!("Buffering update while disconnected: {}", err);
+                        buffer.push(msg.clone())?;
+                        Ok(None)
+                    },
+                    None => Err(ClientError::ExternalError(err)),
+                }
+            },
+            resp => resp.map(Some),
+        }
+    }
+
+    #[doc = include_str!("../../docs/client/set_offline_buffer.md")]
+    pub fn set_offline_buffer(&self, options: Option<OfflineBufferOptions>) {
+        let mut offline = self.offline.lock().unwrap();
+        match (offline.as_mut(), options) {
+            (Some(buffer), Some(options)) => buffer.options = options,
+            (_, options) => {
+                let discarded = offline
+                    .as_ref()
+                    .map(|x| x.updates.len())
+                    .unwrap_or_default();
+                if discarded > 0 {
+                    tracing::warn!("Discarding {} buffered updates", discarded);
+                }
+
+                *offline = options.map(OfflineBuffer::new);
+            },
+        }
+    }
+
+    /// The number of updates buffered by [`Client::set_offline_buffer`].
+    pub fn offline_updates_len(&self) -> usize {
+        self.offline
+            .lock()
+            .unwrap()
+            .as_ref()
+            .map(|x| x.updates.len())
+            .unwrap_or_default()
+    }
+
+    #[doc = include_str!("../../docs/client/replay_offline_updates.md")]
+    pub async fn replay_offline_updates(&self) -> ClientResult<usize> {
+        let _guard = self.replay_lock.lock().await;
+        let mut count = 0;
+        loop {
+            // Updates stay buffered until sent, so updates made during the
+            // replay are buffered behind them. They may be evicted meanwhile
+            // by `OverflowPolicy::DropOldest`, so they are removed by sequence
+            // number rather than position.
+            let next = self
+                .offline
+                .lock()
+                .unwrap()
+                .as_ref()
+                .and_then(|x| x.updates.front().cloned());
+
+            let Some((seq, msg)) = next else {
+                return Ok(count);
+            };
+
+            let msg = Request {
+                msg_id: self.gen_id(),
+                ..msg
+            };
+
+            let resp = self.oneshot(&msg).await;
+            if let Err(ClientError::ExternalError(_)) = &resp {
+                self.subscriptions_once
+                    .try_write()
+                    .unwrap()
+                    .remove(&msg.msg_id);
+            } else if let Some(buffer) = self.offline.lock().unwrap().as_mut() {
+                buffer.remove(seq);
+                count += 1;
+            }
+
+            match resp? {
+                ClientResp::TableUpdateResp(_) => {},
+                resp => return Err(resp.into()),
+            }
+        }
+    }
+
     pub(crate) fn get_features(&self) -> ClientResult<Features> {
         Ok(self
             .features
@@ -231,6 +560,8 @@ impl Client {
 
     #[doc = include_str!("../../docs/client/table.md")]
     pub async fn table(&self, input: TableData, options: TableInitOptions) -> ClientResult<Table> {
+        let csv = options.csv.clone();
+        let schema = options.schema.clone();
         let entity_id = match options.name.clone() {
             Some(x) => x.to_owned(),
             None => nanoid!(),
@@ -240,7 +571,13 @@ impl Client {
             let window = ViewWindow::default();
             let arrow = view.to_arrow(window).await?;
             let mut table = self
-                .crate_table_inner(UpdateData::Arrow(arrow).into(), options.into(), entity_id)
+                .crate_table_inner(
+                    UpdateData::Arrow(arrow).into(),
+                    options.into(),
+                    entity_id,
+                    None,
+                    None,
+                )
                 .await?;
 
             let callback = {
@@ -263,13 +600,14 @@ impl Client {
             let on_update_token = view
                 .on_update(callback, crate::view::OnUpdateOptions {
                     mode: Some(crate::view::OnUpdateMode::Row),
+                    ..crate::view::OnUpdateOptions::default()
                 })
                 .await?;
 
             table.view_update_token = Some(on_update_token);
             Ok(table)
         } else {
-            self.crate_table_inner(input, options.into(), entity_id)
+            self.crate_table_inner(input, options.into(), entity_id, csv, schema)
                 .await
         }
     }
@@ -279,12 +617,25 @@ impl Client {
         input: TableData,
         options: TableOptions,
         entity_id: String,
+        csv: Option<CsvOptions>,
+        schema: Option<Schema>,
     ) -> ClientResult<Table> {
+        let mut data: MakeTableData = input.into();
+        data.csv_options = csv.map(|x| x.into());
+        data.schema = schema.map(|columns| crate::proto::Schema {
+            schema: columns
+                .into_iter()
+                .map(|(name, r#type)| schema::KeyTypePair {
+                    name,
+                    r#type: r#type as i32,
+                })
+                .collect(),
+        });
         let msg = Request {
             msg_id: self.gen_id(),
             entity_id: entity_id.clone(),
             client_req: Some(ClientReq::MakeTableReq(MakeTableReq {
-                data: Some(input.into()),
+                data: Some(data),
                 options: Some(options.clone().try_into()?),
             })),
         };
@@ -300,7 +651,9 @@ impl Client {
         let msg = Request {
             msg_id: self.gen_id(),
             entity_id: "".to_owned(),
-            client_req: Some(ClientReq::GetHostedTablesReq(GetHostedTablesReq {})),
+            client_req: Some(ClientReq::GetHostedTablesReq(GetHostedTablesReq {
+                subscribe: false,
+            })),
         };
 
         match self.oneshot(&msg).await? {
@@ -318,6 +671,13 @@ impl Client {
             let options = TableOptions {
                 index: info.index,
                 limit: info.limit,
+                exclusive_writer: info.exclusive_writer,
+                categories: HashMap::default(),
+                dictionaries: HashMap::default(),
+                batch_latency_ms: None,
+                compress: false,
+                column_hints: HashMap::default(),
+                virtual_columns: HashMap::default(),
             };
 
             let client = self.clone();
@@ -332,7 +692,9 @@ impl Client {
         let msg = Request {
             msg_id: self.gen_id(),
             entity_id: "".to_owned(),
-            client_req: Some(ClientReq::GetHostedTablesReq(GetHostedTablesReq {})),
+            client_req: Some(ClientReq::GetHostedTablesReq(GetHostedTablesReq {
+                subscribe: false,
+            })),
         };
 
         match self.oneshot(&msg).await? {
@@ -343,6 +705,113 @@ impl Client {
         }
     }
 
+    #[doc = include_str!("../../docs/client/on_hosted_tables_update.md")]
+    pub async fn on_hosted_tables_update<T, U>(&self, on_update: T) -> ClientResult<u32>
+    where
+        T: Fn() -> U + Send + Sync + 'static,
+        U: Future<Output = ()> + Send + 'static,
+    {
+        let on_update = Arc::new(on_update);
+        let callback = move |client_resp| {
+            let on_update = on_update.clone();
+            async move {
+                match client_resp {
+                    ClientResp::GetHostedTablesResp(_) => {
+                        on_update().await;
+                        Ok(())
+                    },
+                    other => Err(other.into()),
+                }
+            }
+            .boxed()
+        };
+
+        let msg = Request {
+            msg_id: self.gen_id(),
+            entity_id: "".to_owned(),
+            client_req: Some(ClientReq::GetHostedTablesReq(GetHostedTablesReq {
+                subscribe: true,
+            })),
+        };
+
+        self.subscribe(&msg, Box::new(callback)).await?;
+        Ok(msg.msg_id)
+    }
+
+    #[doc = include_str!("../../docs/client/remove_hosted_tables_update.md")]
+    pub async fn remove_hosted_tables_update(&self, update_id: u32) -> ClientResult<()> {
+        let msg = Request {
+            msg_id: self.gen_id(),
+            entity_id: "".to_owned(),
+            client_req: Some(ClientReq::RemoveHostedTablesUpdateReq(
+                RemoveHostedTablesUpdateReq { id: update_id },
+            )),
+        };
+
+        self.unsubscribe(update_id)?;
+        match self.oneshot(&msg).await? {
+            ClientResp::RemoveHostedTablesUpdateResp(_) => Ok(()),
+            resp => Err(resp.into()),
+        }
+    }
+
+    #[doc = include_str!("../../docs/client/bulk_export.md")]
+    pub async fn bulk_export(
+        &self,
+        entities: Vec<BulkExportEntity>,
+        compression: Option<String>,
+    ) -> ClientResult<BulkExport> {
+        let msg = Request {
+            msg_id: self.gen_id(),
+            entity_id: "".to_owned(),
+            client_req: Some(ClientReq::ServerBulkExportReq(ServerBulkExportReq {
+                entities,
+                compression: compression.unwrap_or_default(),
+            })),
+        };
+
+        match self.oneshot(&msg).await? {
+            ClientResp::ServerBulkExportResp(ServerBulkExportResp { payload, manifest }) => {
+                Ok(BulkExport::new(payload, manifest))
+            },
+            resp => Err(resp.into()),
+        }
+    }
+
+    #[doc = include_str!("../../docs/client/diagnostics.md")]
+    pub async fn diagnostics(&self, clear_slow_ops: bool) -> ClientResult<Diagnostics> {
+        let msg = Request {
+            msg_id: self.gen_id(),
+            entity_id: "".to_string(),
+            client_req: Some(ClientReq::ServerDiagnosticsReq(ServerDiagnosticsReq {
+                clear_slow_ops,
+                take_profiles: false,
+            })),
+        };
+
+        match self.oneshot(&msg).await? {
+            ClientResp::ServerDiagnosticsResp(resp) => Ok(resp),
+            resp => Err(resp.into()),
+        }
+    }
+
+    #[doc = include_str!("../../docs/client/take_profiles.md")]
+    pub async fn take_profiles(&self) -> ClientResult<Vec<Profile>> {
+        let msg = Request {
+            msg_id: self.gen_id(),
+            entity_id: "".to_string(),
+            client_req: Some(ClientReq::ServerDiagnosticsReq(ServerDiagnosticsReq {
+                clear_slow_ops: false,
+                take_profiles: true,
+            })),
+        };
+
+        match self.oneshot(&msg).await? {
+            ClientResp::ServerDiagnosticsResp(resp) => Ok(resp.profiles),
+            resp => Err(resp.into()),
+        }
+    }
+
     #[doc = include_str!("../../docs/client/system_info.md")]
     pub async fn system_info(&self) -> ClientResult<SystemInfo> {
         let msg = Request {
@@ -356,4 +825,20 @@ impl Client {
             resp => Err(resp.into()),
         }
     }
+
+    #[doc = include_str!("../../docs/client/server_expressions.md")]
+    pub async fn server_expressions(&self) -> ClientResult<HashMap<String, String>> {
+        let msg = Request {
+            msg_id: self.gen_id(),
+            entity_id: "".to_string(),
+            client_req: Some(ClientReq::ServerExpressionsReq(ServerExpressionsReq {})),
+        };
+
+        match self.oneshot(&msg).await? {
+            ClientResp::ServerExpressionsResp(ServerExpressionsResp { expressions }) => {
+                Ok(expressions)
+            },
+            resp => Err(resp.into()),
+        }
+    }
 }
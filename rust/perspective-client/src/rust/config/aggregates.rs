@@ -102,6 +102,12 @@ pub enum SingleAggregate {
 
     #[serde(rename = "var")]
     Var,
+
+    #[serde(rename = "total bytes")]
+    TotalBytes,
+
+    #[serde(rename = "max bytes")]
+    MaxBytes,
 }
 
 impl Display for SingleAggregate {
@@ -134,6 +140,8 @@ impl Display for SingleAggregate {
             Self::Var => "var",
             Self::Max => "max",
             Self::Min => "min",
+            Self::TotalBytes => "total bytes",
+            Self::MaxBytes => "max bytes",
         };
 
         write!(fmt, "{}", term)
@@ -172,6 +180,8 @@ impl FromStr for SingleAggregate {
             "high minus low" => Ok(Self::HighMinusLow),
             "stddev" => Ok(Self::StdDev),
             "var" => Ok(Self::Var),
+            "total bytes" => Ok(Self::TotalBytes),
+            "max bytes" => Ok(Self::MaxBytes),
             x => Err(format!("Unknown aggregate `{}`", x)),
         }
     }
@@ -266,6 +276,45 @@ const NUMBER_AGGREGATES: &[SingleAggregate] = &[
     SingleAggregate::Var,
 ];
 
+const DURATION_AGGREGATES: &[SingleAggregate] = &[
+    SingleAggregate::Any,
+    SingleAggregate::Avg,
+    SingleAggregate::Count,
+    SingleAggregate::DistinctCount,
+    SingleAggregate::First,
+    SingleAggregate::High,
+    SingleAggregate::Low,
+    SingleAggregate::Max,
+    SingleAggregate::Min,
+    SingleAggregate::LastByIndex,
+    SingleAggregate::Last,
+    SingleAggregate::Mean,
+    SingleAggregate::Median,
+    SingleAggregate::Sum,
+];
+
+const DOCUMENT_AGGREGATES: &[SingleAggregate] = &[
+    SingleAggregate::Any,
+    SingleAggregate::Count,
+    SingleAggregate::DistinctCount,
+    SingleAggregate::First,
+    SingleAggregate::Last,
+    SingleAggregate::LastByIndex,
+    SingleAggregate::Unique,
+];
+
+const BINARY_AGGREGATES: &[SingleAggregate] = &[
+    SingleAggregate::Any,
+    SingleAggregate::Count,
+    SingleAggregate::DistinctCount,
+    SingleAggregate::First,
+    SingleAggregate::Last,
+    SingleAggregate::LastByIndex,
+    SingleAggregate::MaxBytes,
+    SingleAggregate::TotalBytes,
+    SingleAggregate::Unique,
+];
+
 impl proto::ColumnType {
     pub fn aggregates_iter(&self) -> Box<dyn Iterator<Item = Aggregate>> {
         match self {
@@ -279,15 +328,38 @@ impl proto::ColumnType {
                     .iter()
                     .map(|x| Aggregate::SingleAggregate(*x)),
             ),
+            Self::Duration => Box::new(
+                DURATION_AGGREGATES
+                    .iter()
+                    .map(|x| Aggregate::SingleAggregate(*x)),
+            ),
+            Self::List | Self::Json | Self::Uuid | Self::Ip => Box::new(
+                DOCUMENT_AGGREGATES
+                    .iter()
+                    .map(|x| Aggregate::SingleAggregate(*x)),
+            ),
+            Self::Binary => Box::new(
+                BINARY_AGGREGATES
+                    .iter()
+                    .map(|x| Aggregate::SingleAggregate(*x)),
+            ),
         }
     }
 
     pub const fn default_aggregate(&self) -> Aggregate {
         match self {
-            Self::Boolean | Self::Date | Self::Datetime | Self::String => {
-                Aggregate::SingleAggregate(SingleAggregate::Count)
+            Self::Boolean
+            | Self::Date
+            | Self::Datetime
+            | Self::String
+            | Self::List
+            | Self::Json
+            | Self::Binary
+            | Self::Uuid
+            | Self::Ip => Aggregate::SingleAggregate(SingleAggregate::Count),
+            Self::Integer | Self::Float | Self::Duration => {
+                Aggregate::SingleAggregate(SingleAggregate::Sum)
             },
-            Self::Integer | Self::Float => Aggregate::SingleAggregate(SingleAggregate::Sum),
         }
     }
 }
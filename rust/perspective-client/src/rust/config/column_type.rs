@@ -25,6 +25,12 @@ impl Display for ColumnType {
             Self::Boolean => "boolean",
             Self::Date => "date",
             Self::Datetime => "datetime",
+            Self::Duration => "duration",
+            Self::List => "list",
+            Self::Json => "json",
+            Self::Binary => "binary",
+            Self::Uuid => "uuid",
+            Self::Ip => "ip",
         })
     }
 }
@@ -53,8 +59,23 @@ impl TryFrom<&str> for ColumnType {
             Ok(Self::Date)
         } else if val == "datetime" {
             Ok(Self::Datetime)
+        } else if val == "duration" {
+            Ok(Self::Duration)
+        } else if val == "list" {
+            Ok(Self::List)
+        } else if val == "json" {
+            Ok(Self::Json)
+        } else if val == "binary" {
+            Ok(Self::Binary)
+        } else if val == "uuid" {
+            Ok(Self::Uuid)
+        } else if val == "ip" {
+            Ok(Self::Ip)
         } else {
-            Err(ClientError::Internal(format!("Unknown type {}", val)))
+            Err(ClientError::Internal {
+                message: format!("Unknown type {}", val),
+                request_id: String::new(),
+            })
         }
     }
 }
@@ -68,6 +89,12 @@ impl ColumnType {
             ColumnType::Integer => "Integer",
             ColumnType::Float => "Float",
             ColumnType::Boolean => "Boolean",
+            ColumnType::Duration => "Duration",
+            ColumnType::List => "List",
+            ColumnType::Json => "Json",
+            ColumnType::Binary => "Binary",
+            ColumnType::Uuid => "Uuid",
+            ColumnType::Ip => "Ip",
         }
         .into()
     }
@@ -13,7 +13,7 @@
 use std::fmt::Display;
 
 use itertools::Itertools;
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use ts_rs::TS;
 
 use crate::proto;
@@ -27,11 +27,68 @@ pub enum Scalar {
     Bool(bool),
     DateTime(f64),
     Null,
+
+    /// An integer too large to represent exactly as a `f64`. JSON numbers
+    /// always deserialize as [`Scalar::Float`], so these serialize as a
+    /// tagged decimal string, e.g. `{"int64": "9007199254740993"}`, which
+    /// round-trips exactly through JavaScript and Python.
+    #[serde(
+        serialize_with = "serialize_int64",
+        deserialize_with = "deserialize_int64"
+    )]
+    Int64(#[ts(type = "{ int64: string }")] i64),
+
+    /// As [`Scalar::Int64`], e.g. `{"uint64": "18446744073709551615"}`.
+    #[serde(
+        serialize_with = "serialize_uint64",
+        deserialize_with = "deserialize_uint64"
+    )]
+    Uint64(#[ts(type = "{ uint64: string }")] u64),
     // // Can only have one u64 representation ...
     // Date(u64)
     // Int(u32)
 }
 
+#[derive(Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+struct TaggedInt64 {
+    int64: String,
+}
+
+#[derive(Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+struct TaggedUint64 {
+    uint64: String,
+}
+
+fn serialize_int64<S: Serializer>(value: &i64, serializer: S) -> Result<S::Ok, S::Error> {
+    TaggedInt64 {
+        int64: value.to_string(),
+    }
+    .serialize(serializer)
+}
+
+fn deserialize_int64<'de, D: Deserializer<'de>>(deserializer: D) -> Result<i64, D::Error> {
+    TaggedInt64::deserialize(deserializer)?
+        .int64
+        .parse()
+        .map_err(serde::de::Error::custom)
+}
+
+fn serialize_uint64<S: Serializer>(value: &u64, serializer: S) -> Result<S::Ok, S::Error> {
+    TaggedUint64 {
+        uint64: value.to_string(),
+    }
+    .serialize(serializer)
+}
+
+fn deserialize_uint64<'de, D: Deserializer<'de>>(deserializer: D) -> Result<u64, D::Error> {
+    TaggedUint64::deserialize(deserializer)?
+        .uint64
+        .parse()
+        .map_err(serde::de::Error::custom)
+}
+
 impl Default for Scalar {
     fn default() -> Self {
         Self::Null
@@ -46,6 +103,8 @@ impl Display for Scalar {
             Self::Bool(x) => write!(fmt, "{}", x),
             Self::DateTime(x) => write!(fmt, "{}", x),
             Self::Null => write!(fmt, ""),
+            Self::Int64(x) => write!(fmt, "{}", x),
+            Self::Uint64(x) => write!(fmt, "{}", x),
         }
     }
 }
@@ -148,16 +207,31 @@ impl From<Scalar> for proto::Scalar {
             Scalar::Null => proto::Scalar {
                 scalar: Some(scalar::Scalar::Null(0)),
             },
+            Scalar::Int64(x) => proto::Scalar {
+                scalar: Some(scalar::Scalar::Int64(x)),
+            },
+            Scalar::Uint64(x) => proto::Scalar {
+                scalar: Some(scalar::Scalar::Uint64(x)),
+            },
         }
     }
 }
 
+/// Largest integer which round-trips exactly through a `f64`.
+const MAX_SAFE_INTEGER: u64 = (1 << 53) - 1;
+
 impl From<proto::Scalar> for Scalar {
     fn from(value: proto::Scalar) -> Self {
         match value.scalar {
             Some(scalar::Scalar::Bool(x)) => Scalar::Bool(x),
             Some(scalar::Scalar::String(x)) => Scalar::String(x),
             Some(scalar::Scalar::Int(x)) => Scalar::Float(x as f64),
+            Some(scalar::Scalar::Int64(x)) if x.unsigned_abs() <= MAX_SAFE_INTEGER => {
+                Scalar::Float(x as f64)
+            },
+            Some(scalar::Scalar::Uint64(x)) if x <= MAX_SAFE_INTEGER => Scalar::Float(x as f64),
+            Some(scalar::Scalar::Int64(x)) => Scalar::Int64(x),
+            Some(scalar::Scalar::Uint64(x)) => Scalar::Uint64(x),
             Some(scalar::Scalar::Date(x)) => Scalar::DateTime(x as f64),
             Some(scalar::Scalar::Float(x)) => Scalar::Float(x),
             Some(scalar::Scalar::Datetime(x)) => Scalar::DateTime(x as f64),
@@ -53,6 +53,69 @@ pub struct ViewConfig {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(default)]
     pub group_by_depth: Option<u32>,
+
+    /// The time zone calendar expression functions (`bucket()`,
+    /// `day_of_week()`, etc.) use, e.g. `"America/New_York"`, instead of
+    /// the server's local time. If unset, an expression over columns with a
+    /// [`crate::ColumnHints::timezone`] uses that zone instead. IANA names
+    /// follow the server's tz database, including historical rule changes.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub timezone: Option<String>,
+
+    /// Whether rows with a null `group_by` or `split_by` value form their
+    /// own group, or are left out of the view.
+    #[serde(skip_serializing_if = "is_default_value")]
+    #[serde(default)]
+    pub null_groups: NullGroups,
+
+    /// How aggregates treat null values, see [`NullAggregates`].
+    #[serde(skip_serializing_if = "is_default_value")]
+    #[serde(default)]
+    pub null_aggregates: NullAggregates,
+
+    /// The values of this config's parameters, by name. A `filter` term
+    /// which is the string `":name"`, for a `name` in `params`, is replaced
+    /// by the parameter's value, which [`crate::View::set_params`] may
+    /// change without re-creating the [`crate::View`].
+    #[serde(skip_serializing_if = "HashMap::is_empty")]
+    #[serde(default)]
+    pub params: HashMap<String, Scalar>,
+
+    /// Groups of `columns`, or of other groups, which are presented as
+    /// column headers above their members, see [`ColumnGroup`].
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default)]
+    pub column_groups: Vec<ColumnGroup>,
+
+    /// Where the grand total row of a `group_by` view is placed, see
+    /// [`TotalPosition`].
+    #[serde(skip_serializing_if = "is_default_value")]
+    #[serde(default)]
+    pub grand_total: TotalPosition,
+
+    /// Where the subtotal row of each expanded group of a `group_by` view
+    /// is placed, see [`TotalPosition`].
+    #[serde(skip_serializing_if = "is_default_value")]
+    #[serde(default)]
+    pub subtotals: TotalPosition,
+
+    /// How the values of each column, by name, of a `group_by` or
+    /// `split_by` view are shown relative to other cells, see
+    /// [`ShowValuesAs`].
+    #[serde(skip_serializing_if = "HashMap::is_empty")]
+    #[serde(default)]
+    pub show_values_as: HashMap<String, ShowValuesAs>,
+
+    /// List columns whose elements are each unnested into their own row,
+    /// which repeats the other columns of the list's row, before the rest
+    /// of this config is applied. Lists in the same row are unnested
+    /// together, padded with `null` to the longest, and a row with no
+    /// elements is kept with a `null` element. The unnested column's type is
+    /// inferred from the elements when the [`crate::View`] is created.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default)]
+    pub unnest: Vec<String>,
 }
 
 fn is_default_value<A: Default + PartialEq>(value: &A) -> bool {
@@ -106,6 +169,166 @@ pub struct ViewConfigUpdate {
     #[serde(default)]
     #[ts(optional)]
     pub group_by_depth: Option<u32>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    #[ts(optional)]
+    pub timezone: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    #[ts(optional)]
+    pub null_groups: Option<NullGroups>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    #[ts(optional)]
+    pub null_aggregates: Option<NullAggregates>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    #[ts(optional)]
+    pub params: Option<HashMap<String, Scalar>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    #[ts(optional)]
+    pub column_groups: Option<Vec<ColumnGroup>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    #[ts(optional)]
+    pub grand_total: Option<TotalPosition>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    #[ts(optional)]
+    pub subtotals: Option<TotalPosition>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    #[ts(optional)]
+    pub show_values_as: Option<HashMap<String, ShowValuesAs>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    #[ts(optional)]
+    pub unnest: Option<Vec<String>>,
+}
+
+/// A named group of columns, e.g. `"EUR/USD"` over `"bid"` and `"ask"`.
+/// Its `columns` may name other groups to nest them, and each column or
+/// group is a member of at most one group. A grouped column's group path is
+/// written to its Arrow field metadata as `column_group`, and may be
+/// prefixed to its `to_columns` key with [`crate::ColumnGroupPaths`].
+#[derive(Clone, Debug, Deserialize, Default, PartialEq, Eq, Serialize, TS)]
+pub struct ColumnGroup {
+    pub name: String,
+    pub columns: Vec<String>,
+}
+
+impl From<ColumnGroup> for proto::view_config::ColumnGroup {
+    fn from(value: ColumnGroup) -> Self {
+        proto::view_config::ColumnGroup {
+            name: value.name,
+            columns: value.columns,
+        }
+    }
+}
+
+impl From<proto::view_config::ColumnGroup> for ColumnGroup {
+    fn from(value: proto::view_config::ColumnGroup) -> Self {
+        ColumnGroup {
+            name: value.name,
+            columns: value.columns,
+        }
+    }
+}
+
+/// Whether null `group_by` and `split_by` values form a group.
+#[derive(Clone, Copy, Debug, Default, Deserialize, Serialize, PartialEq, Eq, TS)]
+pub enum NullGroups {
+    /// Rows with a null group value are grouped together under a null
+    /// group.
+    #[default]
+    #[serde(rename = "group")]
+    Group,
+
+    /// Rows with a null group value are excluded from the view.
+    #[serde(rename = "exclude")]
+    Exclude,
+}
+
+/// How aggregates treat null values in a group.
+#[derive(Clone, Copy, Debug, Default, Deserialize, Serialize, PartialEq, Eq, TS)]
+pub enum NullAggregates {
+    /// `count` counts every row, and every other aggregate skips nulls.
+    #[default]
+    #[serde(rename = "default")]
+    Default,
+
+    /// Every aggregate skips nulls like SQL does, so `count` counts
+    /// non-null values and `mean` averages them.
+    #[serde(rename = "ignore")]
+    Ignore,
+
+    /// A null anywhere in a group makes its aggregates null, except for
+    /// `count` which still counts every row.
+    #[serde(rename = "propagate")]
+    Propagate,
+}
+
+/// Where a `group_by` view places a total row relative to the rows it
+/// totals. Exports and viewports see the rows in this order, and row
+/// indices (e.g. for [`crate::View::expand`]) count only the rows shown.
+#[derive(Clone, Copy, Debug, Default, Deserialize, Serialize, PartialEq, Eq, TS)]
+pub enum TotalPosition {
+    /// Before the rows it totals, so the grand total is the first row.
+    #[default]
+    #[serde(rename = "top")]
+    Top,
+
+    /// After the rows it totals, so the grand total is the last row.
+    #[serde(rename = "bottom")]
+    Bottom,
+
+    /// Left out of the view. Collapsed groups are still shown, as they have
+    /// no rows of their own to total.
+    #[serde(rename = "hidden")]
+    Hidden,
+}
+
+/// How a `group_by` or `split_by` view shows a column's aggregated values,
+/// like a spreadsheet pivot table's "show values as". Percents are from 0 to
+/// 100, and are `null` when the total is zero or `null`.
+#[derive(Clone, Copy, Debug, Default, Deserialize, Serialize, PartialEq, Eq, TS)]
+pub enum ShowValuesAs {
+    /// The aggregated value.
+    #[default]
+    #[serde(rename = "value")]
+    Value,
+
+    /// Percent of the row's total over every `split_by` column.
+    #[serde(rename = "percent_of_row_total")]
+    PercentOfRowTotal,
+
+    /// Percent of the column's total over every `group_by` row.
+    #[serde(rename = "percent_of_column_total")]
+    PercentOfColumnTotal,
+
+    /// Percent of the total of the whole view.
+    #[serde(rename = "percent_of_grand_total")]
+    PercentOfGrandTotal,
+
+    /// The value less that of the previous column in the same `split_by`
+    /// group, or `null` for the first column.
+    #[serde(rename = "difference_from_previous")]
+    DifferenceFromPrevious,
+
+    /// The value plus those of the previous columns in the same `split_by`
+    /// group.
+    #[serde(rename = "running_total")]
+    RunningTotal,
 }
 
 impl From<ViewConfigUpdate> for proto::ViewConfig {
@@ -144,6 +367,38 @@ impl From<ViewConfigUpdate> for proto::ViewConfig {
                 .map(|(x, y)| (x, y.into()))
                 .collect(),
             group_by_depth: value.group_by_depth,
+            timezone: value.timezone,
+            null_groups: value
+                .null_groups
+                .map(|x| proto::view_config::NullGroups::from(x) as i32),
+            null_aggregates: value
+                .null_aggregates
+                .map(|x| proto::view_config::NullAggregates::from(x) as i32),
+            params: value
+                .params
+                .unwrap_or_default()
+                .into_iter()
+                .map(|(x, y)| (x, y.into()))
+                .collect(),
+            column_groups: value
+                .column_groups
+                .unwrap_or_default()
+                .into_iter()
+                .map(|x| x.into())
+                .collect(),
+            grand_total: value
+                .grand_total
+                .map(|x| proto::view_config::TotalPosition::from(x) as i32),
+            subtotals: value
+                .subtotals
+                .map(|x| proto::view_config::TotalPosition::from(x) as i32),
+            show_values_as: value
+                .show_values_as
+                .unwrap_or_default()
+                .into_iter()
+                .map(|(x, y)| (x, proto::view_config::ShowValuesAs::from(y) as i32))
+                .collect(),
+            unnest: value.unnest.unwrap_or_default(),
         }
     }
 }
@@ -166,6 +421,102 @@ impl From<proto::view_config::FilterReducer> for FilterReducer {
     }
 }
 
+impl From<NullGroups> for proto::view_config::NullGroups {
+    fn from(value: NullGroups) -> Self {
+        match value {
+            NullGroups::Group => proto::view_config::NullGroups::Group,
+            NullGroups::Exclude => proto::view_config::NullGroups::Exclude,
+        }
+    }
+}
+
+impl From<proto::view_config::NullGroups> for NullGroups {
+    fn from(value: proto::view_config::NullGroups) -> Self {
+        match value {
+            proto::view_config::NullGroups::Group => NullGroups::Group,
+            proto::view_config::NullGroups::Exclude => NullGroups::Exclude,
+        }
+    }
+}
+
+impl From<NullAggregates> for proto::view_config::NullAggregates {
+    fn from(value: NullAggregates) -> Self {
+        match value {
+            NullAggregates::Default => proto::view_config::NullAggregates::Default,
+            NullAggregates::Ignore => proto::view_config::NullAggregates::Ignore,
+            NullAggregates::Propagate => proto::view_config::NullAggregates::Propagate,
+        }
+    }
+}
+
+impl From<proto::view_config::NullAggregates> for NullAggregates {
+    fn from(value: proto::view_config::NullAggregates) -> Self {
+        match value {
+            proto::view_config::NullAggregates::Default => NullAggregates::Default,
+            proto::view_config::NullAggregates::Ignore => NullAggregates::Ignore,
+            proto::view_config::NullAggregates::Propagate => NullAggregates::Propagate,
+        }
+    }
+}
+
+impl From<TotalPosition> for proto::view_config::TotalPosition {
+    fn from(value: TotalPosition) -> Self {
+        match value {
+            TotalPosition::Top => proto::view_config::TotalPosition::Top,
+            TotalPosition::Bottom => proto::view_config::TotalPosition::Bottom,
+            TotalPosition::Hidden => proto::view_config::TotalPosition::Hidden,
+        }
+    }
+}
+
+impl From<proto::view_config::TotalPosition> for TotalPosition {
+    fn from(value: proto::view_config::TotalPosition) -> Self {
+        match value {
+            proto::view_config::TotalPosition::Top => TotalPosition::Top,
+            proto::view_config::TotalPosition::Bottom => TotalPosition::Bottom,
+            proto::view_config::TotalPosition::Hidden => TotalPosition::Hidden,
+        }
+    }
+}
+
+impl From<ShowValuesAs> for proto::view_config::ShowValuesAs {
+    fn from(value: ShowValuesAs) -> Self {
+        match value {
+            ShowValuesAs::Value => proto::view_config::ShowValuesAs::Value,
+            ShowValuesAs::PercentOfRowTotal => proto::view_config::ShowValuesAs::PercentOfRowTotal,
+            ShowValuesAs::PercentOfColumnTotal => {
+                proto::view_config::ShowValuesAs::PercentOfColumnTotal
+            },
+            ShowValuesAs::PercentOfGrandTotal => {
+                proto::view_config::ShowValuesAs::PercentOfGrandTotal
+            },
+            ShowValuesAs::DifferenceFromPrevious => {
+                proto::view_config::ShowValuesAs::DifferenceFromPrevious
+            },
+            ShowValuesAs::RunningTotal => proto::view_config::ShowValuesAs::RunningTotal,
+        }
+    }
+}
+
+impl From<proto::view_config::ShowValuesAs> for ShowValuesAs {
+    fn from(value: proto::view_config::ShowValuesAs) -> Self {
+        match value {
+            proto::view_config::ShowValuesAs::Value => ShowValuesAs::Value,
+            proto::view_config::ShowValuesAs::PercentOfRowTotal => ShowValuesAs::PercentOfRowTotal,
+            proto::view_config::ShowValuesAs::PercentOfColumnTotal => {
+                ShowValuesAs::PercentOfColumnTotal
+            },
+            proto::view_config::ShowValuesAs::PercentOfGrandTotal => {
+                ShowValuesAs::PercentOfGrandTotal
+            },
+            proto::view_config::ShowValuesAs::DifferenceFromPrevious => {
+                ShowValuesAs::DifferenceFromPrevious
+            },
+            proto::view_config::ShowValuesAs::RunningTotal => ShowValuesAs::RunningTotal,
+        }
+    }
+}
+
 impl From<ViewConfig> for ViewConfigUpdate {
     fn from(value: ViewConfig) -> Self {
         ViewConfigUpdate {
@@ -178,6 +529,15 @@ impl From<ViewConfig> for ViewConfigUpdate {
             expressions: Some(value.expressions),
             aggregates: Some(value.aggregates),
             group_by_depth: value.group_by_depth,
+            timezone: value.timezone,
+            null_groups: Some(value.null_groups),
+            null_aggregates: Some(value.null_aggregates),
+            params: Some(value.params),
+            column_groups: Some(value.column_groups),
+            grand_total: Some(value.grand_total),
+            subtotals: Some(value.subtotals),
+            show_values_as: Some(value.show_values_as),
+            unnest: Some(value.unnest),
         }
     }
 }
@@ -207,10 +567,77 @@ impl From<proto::ViewConfig> for ViewConfig {
                 .map(|(x, y)| (x, y.into()))
                 .collect(),
             group_by_depth: value.group_by_depth,
+            timezone: value.timezone,
+            null_groups: value
+                .null_groups
+                .and_then(|x| proto::view_config::NullGroups::try_from(x).ok())
+                .unwrap_or_default()
+                .into(),
+            null_aggregates: value
+                .null_aggregates
+                .and_then(|x| proto::view_config::NullAggregates::try_from(x).ok())
+                .unwrap_or_default()
+                .into(),
+            params: value
+                .params
+                .into_iter()
+                .map(|(x, y)| (x, y.into()))
+                .collect(),
+            column_groups: value.column_groups.into_iter().map(|x| x.into()).collect(),
+            grand_total: value
+                .grand_total
+                .and_then(|x| proto::view_config::TotalPosition::try_from(x).ok())
+                .unwrap_or_default()
+                .into(),
+            subtotals: value
+                .subtotals
+                .and_then(|x| proto::view_config::TotalPosition::try_from(x).ok())
+                .unwrap_or_default()
+                .into(),
+            show_values_as: value
+                .show_values_as
+                .into_iter()
+                .map(|(x, y)| {
+                    let show = proto::view_config::ShowValuesAs::try_from(y).unwrap_or_default();
+                    (x, show.into())
+                })
+                .collect(),
+            unnest: value.unnest,
         }
     }
 }
 
+impl ViewConfigUpdate {
+    /// The names of the fields which this update sets, as named by the
+    /// `ViewConfig` proto message.
+    pub fn field_names(&self) -> Vec<String> {
+        [
+            ("group_by", self.group_by.is_some()),
+            ("split_by", self.split_by.is_some()),
+            ("columns", self.columns.is_some()),
+            ("filter", self.filter.is_some()),
+            ("filter_op", self.filter_op.is_some()),
+            ("sort", self.sort.is_some()),
+            ("expressions", self.expressions.is_some()),
+            ("aggregates", self.aggregates.is_some()),
+            ("group_by_depth", self.group_by_depth.is_some()),
+            ("timezone", self.timezone.is_some()),
+            ("null_groups", self.null_groups.is_some()),
+            ("null_aggregates", self.null_aggregates.is_some()),
+            ("params", self.params.is_some()),
+            ("column_groups", self.column_groups.is_some()),
+            ("grand_total", self.grand_total.is_some()),
+            ("subtotals", self.subtotals.is_some()),
+            ("show_values_as", self.show_values_as.is_some()),
+            ("unnest", self.unnest.is_some()),
+        ]
+        .into_iter()
+        .filter(|(_, is_set)| *is_set)
+        .map(|(name, _)| name.to_owned())
+        .collect()
+    }
+}
+
 impl ViewConfig {
     fn _apply<T>(field: &mut T, update: Option<T>) -> bool {
         match update {
@@ -241,6 +668,15 @@ impl ViewConfig {
         changed = Self::_apply(&mut self.sort, update.sort) || changed;
         changed = Self::_apply(&mut self.aggregates, update.aggregates) || changed;
         changed = Self::_apply(&mut self.expressions, update.expressions) || changed;
+        changed = Self::_apply(&mut self.timezone, update.timezone.map(Some)) || changed;
+        changed = Self::_apply(&mut self.null_groups, update.null_groups) || changed;
+        changed = Self::_apply(&mut self.null_aggregates, update.null_aggregates) || changed;
+        changed = Self::_apply(&mut self.params, update.params) || changed;
+        changed = Self::_apply(&mut self.column_groups, update.column_groups) || changed;
+        changed = Self::_apply(&mut self.grand_total, update.grand_total) || changed;
+        changed = Self::_apply(&mut self.subtotals, update.subtotals) || changed;
+        changed = Self::_apply(&mut self.show_values_as, update.show_values_as) || changed;
+        changed = Self::_apply(&mut self.unnest, update.unnest) || changed;
         changed
     }
 
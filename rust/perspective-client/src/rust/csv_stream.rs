@@ -0,0 +1,273 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use std::collections::HashMap;
+
+use futures::stream::{self, BoxStream};
+use futures::StreamExt;
+use serde::{Deserialize, Serialize};
+use ts_rs::TS;
+
+use crate::proto::ColumnType;
+use crate::utils::datetime::{format_duration, format_timestamp};
+use crate::utils::*;
+use crate::view::View;
+
+/// When [`View::to_csv_stream`] encloses a field in quotes.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize, Serialize, TS)]
+pub enum CsvQuoting {
+    /// Only fields containing the delimiter, a quote or a line break.
+    #[default]
+    #[serde(rename = "minimal")]
+    Minimal,
+
+    /// Every field, including the header.
+    #[serde(rename = "all")]
+    All,
+
+    /// Every field which is not a number (or null).
+    #[serde(rename = "non_numeric")]
+    NonNumeric,
+
+    /// No fields. The output may be ambiguous!
+    #[serde(rename = "never")]
+    Never,
+}
+
+/// Options for [`View::to_csv_stream`].
+#[derive(Clone, Debug, Default, Deserialize, Serialize, TS)]
+pub struct CsvExportOptions {
+    /// The field delimiter, `,` by default.
+    #[serde(default)]
+    #[ts(optional)]
+    pub delimiter: Option<char>,
+
+    #[serde(default)]
+    #[ts(optional)]
+    pub quoting: Option<CsvQuoting>,
+
+    /// A `strftime`-style format for `datetime` columns, ISO 8601
+    /// (`%Y-%m-%dT%H:%M:%S.%fZ`) by default.
+    #[serde(default)]
+    #[ts(optional)]
+    pub datetime_format: Option<String>,
+
+    /// A `strftime`-style format for `date` columns, `%Y-%m-%d` by default.
+    #[serde(default)]
+    #[ts(optional)]
+    pub date_format: Option<String>,
+
+    /// The number of digits after the decimal point for `float` columns, or
+    /// the shortest round-trip representation by default.
+    #[serde(default)]
+    #[ts(optional)]
+    pub precision: Option<u32>,
+
+    /// The decimal separator for `float` columns, e.g. `,`.
+    #[serde(default)]
+    #[ts(optional)]
+    pub decimal_separator: Option<char>,
+
+    /// The text written for null values, empty by default.
+    #[serde(default)]
+    #[ts(optional)]
+    pub null_value: Option<String>,
+
+    /// The number of rows fetched from the server (and emitted) per chunk.
+    #[serde(default)]
+    #[ts(optional)]
+    pub chunk_rows: Option<u32>,
+}
+
+const DEFAULT_CHUNK_ROWS: u32 = 10_000;
+
+struct CsvWriter {
+    options: CsvExportOptions,
+    columns: Vec<(String, Option<ColumnType>)>,
+}
+
+impl CsvWriter {
+    fn write_field(&self, out: &mut String, value: &str, numeric: bool) {
+        let delimiter = self.options.delimiter.unwrap_or(',');
+        let quote = match self.options.quoting.unwrap_or_default() {
+            CsvQuoting::All => true,
+            CsvQuoting::Never => false,
+            CsvQuoting::NonNumeric => !numeric,
+            CsvQuoting::Minimal => value
+                .chars()
+                .any(|c| c == delimiter || c == '"' || c == '\n' || c == '\r'),
+        };
+
+        if quote {
+            out.push('"');
+            out.push_str(&value.replace('"', "\"\""));
+            out.push('"');
+        } else {
+            out.push_str(value);
+        }
+    }
+
+    fn write_row(&self, out: &mut String, fields: impl Iterator<Item = (String, bool)>) {
+        let delimiter = self.options.delimiter.unwrap_or(',');
+        for (idx, (value, numeric)) in fields.enumerate() {
+            if idx > 0 {
+                out.push(delimiter);
+            }
+
+            self.write_field(out, &value, numeric);
+        }
+
+        out.push('\n');
+    }
+
+    fn header(&self) -> String {
+        let mut out = String::new();
+        self.write_row(
+            &mut out,
+            self.columns.iter().map(|(x, _)| (x.clone(), false)),
+        );
+        out
+    }
+
+    /// Format a JSON `value` from [`View::to_columns_string`] as a CSV field,
+    /// returning whether it is numeric.
+    fn format_value(&self, value: &serde_json::Value, ty: Option<ColumnType>) -> (String, bool) {
+        use serde_json::Value;
+        let null = || self.options.null_value.clone().unwrap_or_default();
+        match (value, ty) {
+            (Value::Null, _) => (null(), true),
+            (Value::Number(x), Some(ColumnType::Datetime)) => match x.as_f64() {
+                Some(ms) => {
+                    let format = self.options.datetime_format.as_deref();
+                    let format = format.unwrap_or("%Y-%m-%dT%H:%M:%S.%fZ");
+                    (format_timestamp(ms as i64, format), false)
+                },
+                None => (null(), true),
+            },
+            (Value::Number(x), Some(ColumnType::Duration)) => match x.as_i64() {
+                Some(ms) => (format_duration(ms), false),
+                None => (null(), true),
+            },
+            (Value::Number(x), Some(ColumnType::Date)) => match x.as_f64() {
+                Some(ms) => {
+                    let format = self.options.date_format.as_deref().unwrap_or("%Y-%m-%d");
+                    (format_timestamp(ms as i64, format), false)
+                },
+                None => (null(), true),
+            },
+            (Value::Number(x), ty) if x.is_f64() || ty == Some(ColumnType::Float) => {
+                let x = x.as_f64().unwrap_or_default();
+                let text = match self.options.precision {
+                    Some(precision) => format!("{:.*}", precision as usize, x),
+                    None => x.to_string(),
+                };
+
+                match self.options.decimal_separator {
+                    Some(sep) => (text.replace('.', &sep.to_string()), true),
+                    None => (text, true),
+                }
+            },
+            (Value::Number(x), _) => (x.to_string(), true),
+            (Value::String(x), _) => (x.clone(), false),
+            (Value::Bool(x), _) => (x.to_string(), false),
+            (Value::Array(_), Some(ColumnType::List)) => (value.to_string(), false),
+            (Value::Array(_) | Value::Object(_), Some(ColumnType::Json)) => {
+                (value.to_string(), false)
+            },
+            (Value::Array(path), _) => {
+                let path = path
+                    .iter()
+                    .map(|x| match x {
+                        Value::String(x) => x.clone(),
+                        x => x.to_string(),
+                    })
+                    .collect::<Vec<_>>();
+
+                (path.join("|"), false)
+            },
+            (x, _) => (x.to_string(), false),
+        }
+    }
+
+    fn rows(&self, json: &str) -> ClientResult<String> {
+        let mut data: HashMap<String, Vec<serde_json::Value>> = serde_json::from_str(json)
+            .map_err(|e| ClientError::Unknown(format!("Malformed columns: {}", e)))?;
+
+        let columns = self
+            .columns
+            .iter()
+            .map(|(name, ty)| (data.remove(name).unwrap_or_default(), *ty))
+            .collect::<Vec<_>>();
+
+        let num_rows = columns.iter().map(|(x, _)| x.len()).max().unwrap_or(0);
+        let mut out = String::new();
+        for ridx in 0..num_rows {
+            self.write_row(
+                &mut out,
+                columns.iter().map(|(col, ty)| match col.get(ridx) {
+                    Some(value) => self.format_value(value, *ty),
+                    None => self.format_value(&serde_json::Value::Null, *ty),
+                }),
+            );
+        }
+
+        Ok(out)
+    }
+}
+
+impl View {
+    /// The columns of an export of this [`View`] in output order, paired with
+    /// the type of their source column. Pivoted views are prefixed with a
+    /// `__ROW_PATH__` column, which has no type.
+    pub(crate) async fn export_columns(&self) -> ClientResult<Vec<(String, Option<ColumnType>)>> {
+        let schema = self.schema().await?;
+        let config = self.get_config().await?;
+        let mut columns = vec![];
+        if !config.group_by.is_empty() {
+            columns.push(("__ROW_PATH__".to_owned(), None));
+        }
+
+        for path in self.column_paths().await? {
+            let name = path.rsplit('|').next().unwrap_or(&path);
+            let ty = schema.get(name).copied();
+            columns.push((path, ty));
+        }
+
+        Ok(columns)
+    }
+
+    #[doc = include_str!("../../docs/view/to_csv_stream.md")]
+    pub async fn to_csv_stream(
+        &self,
+        options: CsvExportOptions,
+    ) -> ClientResult<BoxStream<'static, ClientResult<String>>> {
+        let columns = self.export_columns().await?;
+        let num_rows = self.num_rows().await?;
+        let chunk_rows = options.chunk_rows.unwrap_or(DEFAULT_CHUNK_ROWS).max(1);
+        let writer = CsvWriter { options, columns };
+        let header = stream::once(futures::future::ready(Ok(writer.header())));
+        let view = self.clone();
+        let writer = std::sync::Arc::new(writer);
+        let chunks =
+            stream::iter((0..num_rows).step_by(chunk_rows as usize)).then(move |start_row| {
+                let view = view.clone();
+                let writer = writer.clone();
+                async move {
+                    let end_row = num_rows.min(start_row + chunk_rows as u64);
+                    let json = view.to_columns_string_rows(start_row, end_row).await?;
+                    writer.rows(&json)
+                }
+            });
+
+        Ok(header.chain(chunks).boxed())
+    }
+}
@@ -0,0 +1,340 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use ts_rs::TS;
+
+use crate::config::ColumnGroup;
+use crate::proto::ColumnType;
+use crate::utils::datetime::{format_duration, format_timestamp};
+use crate::utils::*;
+use crate::view::{View, ViewWindow};
+
+/// How [`View::to_json_string`] and [`View::to_columns_string`] serialize
+/// `date` and `datetime` values.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize, Serialize, TS)]
+pub enum DatetimeFormat {
+    /// Milliseconds since the Unix epoch.
+    #[default]
+    #[serde(rename = "epoch")]
+    Epoch,
+
+    /// ISO 8601 strings in UTC, e.g. `2024-02-29` for `date` and
+    /// `2024-02-29T12:34:56.789Z` for `datetime`. `duration` values are
+    /// written in their humanized form, e.g. `1d 02:03:04.005`.
+    #[serde(rename = "iso8601")]
+    Iso8601,
+}
+
+/// How [`View::to_json_string`] and [`View::to_columns_string`] serialize
+/// null values.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize, Serialize, TS)]
+pub enum NullHandling {
+    /// As JSON `null`.
+    #[default]
+    #[serde(rename = "null")]
+    Null,
+
+    /// Omit the key from the row. Column-oriented output has no keys per
+    /// value, so nulls are kept as `null` there.
+    #[serde(rename = "omit")]
+    Omit,
+
+    /// As an empty string.
+    #[serde(rename = "empty_string")]
+    EmptyString,
+}
+
+/// How [`View::to_json_string`] and [`View::to_columns_string`] serialize the
+/// `__ROW_PATH__` of a `group_by` view.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize, Serialize, TS)]
+pub enum GroupPaths {
+    /// An array of group values, e.g. `["US", "NY"]`.
+    #[default]
+    #[serde(rename = "nested")]
+    Nested,
+
+    /// A single string joined with `|`, e.g. `"US|NY"`.
+    #[serde(rename = "flat")]
+    Flat,
+}
+
+/// How [`View::to_json_string`] and [`View::to_columns_string`] serialize
+/// columns flattened from struct fields, e.g. `order.price`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize, Serialize, TS)]
+pub enum StructPaths {
+    /// One key per field, named by its dot path, e.g. `"order.price": 1`.
+    #[default]
+    #[serde(rename = "flat")]
+    Flat,
+
+    /// Fields nested under their struct, e.g. `"order": {"price": 1}`. Keys
+    /// which would collide with another column are left flat.
+    #[serde(rename = "nested")]
+    Nested,
+}
+
+/// How [`View::to_json_string`] and [`View::to_columns_string`] name the
+/// columns in a `column_groups` group of the view's `ViewConfig`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize, Serialize, TS)]
+pub enum ColumnGroupPaths {
+    /// By column name alone, e.g. `"bid"`.
+    #[default]
+    #[serde(rename = "omit")]
+    Omit,
+
+    /// Prefixed by the column's group path, outermost group first, e.g.
+    /// `"FX|EUR/USD|bid"`. Split-by columns keep their split-by path in
+    /// front, e.g. `"London|FX|EUR/USD|bid"`.
+    #[serde(rename = "prefix")]
+    Prefix,
+}
+
+impl ViewWindow {
+    fn has_json_options(&self) -> bool {
+        self.datetime_format.unwrap_or_default() != DatetimeFormat::Epoch
+            || self.null_handling.unwrap_or_default() != NullHandling::Null
+            || self.group_paths.unwrap_or_default() != GroupPaths::Nested
+            || self.struct_paths.unwrap_or_default() != StructPaths::Flat
+            || self.column_group_paths.unwrap_or_default() != ColumnGroupPaths::Omit
+            || self.column_names.as_ref().is_some_and(|x| !x.is_empty())
+    }
+}
+
+struct JsonExport<'a> {
+    window: &'a ViewWindow,
+    schema: HashMap<String, ColumnType>,
+    column_groups: HashMap<String, String>,
+}
+
+impl JsonExport<'_> {
+    /// Prefix the leaf column of `key` with its column group path, if any.
+    fn group(&self, key: String) -> String {
+        let (prefix, leaf) = match key.rsplit_once('|') {
+            Some((prefix, leaf)) => (Some(prefix), leaf),
+            None => (None, key.as_str()),
+        };
+
+        match (self.column_groups.get(leaf), prefix) {
+            (Some(path), Some(prefix)) => format!("{}|{}|{}", prefix, path, leaf),
+            (Some(path), None) => format!("{}|{}", path, leaf),
+            (None, _) => key,
+        }
+    }
+
+    fn rename(&self, key: String) -> String {
+        let Some(names) = &self.window.column_names else {
+            return key;
+        };
+
+        if let Some(name) = names.get(&key) {
+            return name.clone();
+        }
+
+        // Split-by paths rename their leaf column, e.g. `US|Sales`.
+        match key.rsplit_once('|') {
+            Some((prefix, leaf)) if names.contains_key(leaf) => {
+                format!("{}|{}", prefix, names[leaf])
+            },
+            _ => key,
+        }
+    }
+
+    /// Transform a single value of the column `key`, or `None` to omit it.
+    fn value(&self, key: &str, value: Value, in_row: bool) -> Option<Value> {
+        match value {
+            Value::Null => match self.window.null_handling.unwrap_or_default() {
+                NullHandling::Null => Some(Value::Null),
+                NullHandling::Omit if in_row => None,
+                NullHandling::Omit => Some(Value::Null),
+                NullHandling::EmptyString => Some(Value::String(String::new())),
+            },
+            Value::Array(path)
+                if key == "__ROW_PATH__"
+                    && self.window.group_paths.unwrap_or_default() == GroupPaths::Flat =>
+            {
+                let path = path
+                    .into_iter()
+                    .map(|x| match x {
+                        Value::String(x) => x,
+                        x => x.to_string(),
+                    })
+                    .collect::<Vec<_>>();
+
+                Some(Value::String(path.join("|")))
+            },
+            Value::Number(ms)
+                if self.window.datetime_format.unwrap_or_default() == DatetimeFormat::Iso8601 =>
+            {
+                let leaf = key.rsplit('|').next().unwrap_or(key);
+                let format = match self.schema.get(leaf) {
+                    Some(ColumnType::Date) => "%Y-%m-%d",
+                    Some(ColumnType::Datetime) => "%Y-%m-%dT%H:%M:%S.%fZ",
+                    Some(ColumnType::Duration) => {
+                        let ms = ms.as_f64().unwrap_or_default() as i64;
+                        return Some(Value::String(format_duration(ms)));
+                    },
+                    _ => return Some(Value::Number(ms)),
+                };
+
+                let ms = ms.as_f64().unwrap_or_default() as i64;
+                Some(Value::String(format_timestamp(ms, format)))
+            },
+            x => Some(x),
+        }
+    }
+
+    fn object(&self, obj: Map<String, Value>, in_row: bool) -> Map<String, Value> {
+        let obj = obj
+            .into_iter()
+            .filter_map(|(key, value)| {
+                let value = match value {
+                    Value::Array(col) if !in_row => Value::Array(
+                        col.into_iter()
+                            .filter_map(|x| self.value(&key, x, false))
+                            .collect(),
+                    ),
+                    value => self.value(&key, value, true)?,
+                };
+
+                Some((self.rename(self.group(key)), value))
+            })
+            .collect();
+
+        match self.window.struct_paths.unwrap_or_default() {
+            StructPaths::Flat => obj,
+            StructPaths::Nested => nest(obj),
+        }
+    }
+
+    fn apply(&self, json: &str) -> ClientResult<String> {
+        let value: Value = serde_json::from_str(json)
+            .map_err(|e| ClientError::Unknown(format!("Malformed JSON: {}", e)))?;
+
+        let value = match value {
+            Value::Array(rows) => Value::Array(
+                rows.into_iter()
+                    .map(|row| match row {
+                        Value::Object(row) => Value::Object(self.object(row, true)),
+                        x => x,
+                    })
+                    .collect(),
+            ),
+            Value::Object(cols) => Value::Object(self.object(cols, false)),
+            x => x,
+        };
+
+        serde_json::to_string(&value).map_err(|e| ClientError::Unknown(e.to_string()))
+    }
+}
+
+/// The `|`-joined group path of each member of `groups`, outermost group
+/// first.
+fn column_group_paths(groups: &[ColumnGroup]) -> HashMap<String, String> {
+    let parents = groups
+        .iter()
+        .flat_map(|group| {
+            group
+                .columns
+                .iter()
+                .map(|member| (member.as_str(), group.name.as_str()))
+        })
+        .collect::<HashMap<_, _>>();
+
+    parents
+        .keys()
+        .map(|member| {
+            let mut path = vec![];
+            let mut next = parents.get(member);
+            // The server rejects groups which contain themselves, but don't
+            // loop forever on one.
+            while let Some(parent) = next.filter(|_| path.len() <= groups.len()) {
+                path.push(*parent);
+                next = parents.get(parent);
+            }
+
+            path.reverse();
+            ((*member).to_owned(), path.join("|"))
+        })
+        .collect()
+}
+
+/// Nest the dot-path keys of `obj` under their parent keys. Split-by
+/// columns, e.g. `US|order.price`, are left as they are.
+fn nest(obj: Map<String, Value>) -> Map<String, Value> {
+    let (paths, mut out): (Vec<_>, Map<_, _>) = obj
+        .into_iter()
+        .partition(|(key, _)| key.contains('.') && !key.contains('|'));
+
+    for (key, value) in paths {
+        let path = key.split('.').collect::<Vec<_>>();
+        if let Err(value) = insert_path(&mut out, &path, value) {
+            out.insert(key, value);
+        }
+    }
+
+    out
+}
+
+/// Insert `value` at `path`, returning it if a key along the way is taken.
+fn insert_path(map: &mut Map<String, Value>, path: &[&str], value: Value) -> Result<(), Value> {
+    match path {
+        [leaf] if !map.contains_key(*leaf) => {
+            map.insert((*leaf).to_owned(), value);
+            Ok(())
+        },
+        [head, rest @ ..] if !rest.is_empty() => {
+            match map
+                .entry((*head).to_owned())
+                .or_insert_with(|| Value::Object(Map::new()))
+            {
+                Value::Object(child) => insert_path(child, rest, value),
+                _ => Err(value),
+            }
+        },
+        _ => Err(value),
+    }
+}
+
+impl View {
+    /// Apply the JSON schema controls of `window` (if any) to the output of
+    /// [`View::to_json_string`] or [`View::to_columns_string`].
+    pub(crate) async fn apply_json_options(
+        &self,
+        json: String,
+        window: &ViewWindow,
+    ) -> ClientResult<String> {
+        if !window.has_json_options() {
+            return Ok(json);
+        }
+
+        let schema = match window.datetime_format.unwrap_or_default() {
+            DatetimeFormat::Iso8601 => self.schema().await?,
+            DatetimeFormat::Epoch => HashMap::default(),
+        };
+
+        let column_groups = match window.column_group_paths.unwrap_or_default() {
+            ColumnGroupPaths::Prefix => column_group_paths(&self.get_config().await?.column_groups),
+            ColumnGroupPaths::Omit => HashMap::default(),
+        };
+
+        JsonExport {
+            window,
+            schema,
+            column_groups,
+        }
+        .apply(&json)
+    }
+}
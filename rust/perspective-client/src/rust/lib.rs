@@ -16,19 +16,48 @@
     clippy::await_holding_refcell_ref
 )]
 
+mod arrow_ingest;
+mod bulk_export;
 mod client;
+mod csv_stream;
+mod json_export;
+mod load_stream;
+mod offline;
+mod pool;
+mod port;
 mod table;
 mod table_data;
 mod view;
 
+#[cfg(feature = "xlsx")]
+mod xlsx;
+
 pub mod config;
 pub mod proto;
+pub mod protocol;
 pub mod utils;
 
-pub use crate::client::{Client, ClientHandler, Features};
+pub use crate::arrow_ingest::ArrowIngest;
+pub use crate::bulk_export::BulkExport;
+pub use crate::client::{Client, ClientHandler, Diagnostics, Features, Protocol};
+pub use crate::csv_stream::{CsvExportOptions, CsvQuoting};
+pub use crate::json_export::{
+    ColumnGroupPaths, DatetimeFormat, GroupPaths, NullHandling, StructPaths,
+};
+pub use crate::load_stream::{LoadProgress, LoadStreamOptions, StreamFormat};
+pub use crate::offline::{OfflineBufferOptions, OverflowPolicy};
+pub use crate::pool::ClientPool;
+pub use crate::port::Port;
 pub use crate::proto::table_validate_expr_resp::ExprValidationError;
-pub use crate::proto::ColumnType;
-pub use crate::table::{Schema, Table, TableInitOptions, UpdateOptions, ValidateExpressionsData};
+pub use crate::proto::{
+    BulkExportEntity, BulkExportManifestEntry, CellAnnotation, CellEdit, CellEditRecord,
+    ColumnSketch, ColumnType, DictionaryStats, ExpressionFunction, FrequentValue, Profile, SlowOp,
+    TableStats, TableUpdateCounts, ViewAnnotation,
+};
+pub use crate::table::{
+    ColumnHints, CsvOptions, DictionaryOptions, Schema, Table, TableInitOptions, UpdateOptions,
+    ValidateExpressionsData,
+};
 pub use crate::table_data::{TableData, UpdateData};
 pub use crate::utils::*;
 pub use crate::view::{OnUpdateMode, OnUpdateOptions, View, ViewWindow};
@@ -81,6 +110,7 @@ macro_rules! assert_view_api {
                     &$x::column_paths,
                     &$x::delete,
                     &$x::dimensions,
+                    &$x::downsample,
                     &$x::expression_schema,
                     &$x::get_config,
                     &$x::get_min_max,
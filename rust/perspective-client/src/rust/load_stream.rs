@@ -0,0 +1,342 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use std::future::Future;
+use std::ops::Range;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::{Context, Poll};
+
+use futures::{AsyncRead, AsyncReadExt};
+
+use crate::table::{CsvOptions, Table, UpdateOptions};
+use crate::table_data::UpdateData;
+use crate::utils::*;
+
+/// The encoding of the byte stream passed to [`Table::load_stream`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum StreamFormat {
+    /// CSV, in the dialect of [`LoadStreamOptions::csv`]. Each chunk is split
+    /// on a record boundary and sent with the header rows prepended.
+    Csv,
+
+    /// Newline-delimited JSON, one row object per line.
+    Ndjson,
+
+    /// An Arrow IPC stream. Each chunk is split on a message boundary and
+    /// sent with the schema (and any dictionary batches) prepended, so a
+    /// record batch larger than the chunk size is sent whole.
+    Arrow,
+}
+
+/// Progress of a [`Table::load_stream`] call, reported after each chunk.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct LoadProgress {
+    /// Total bytes read from the stream so far.
+    pub bytes_read: u64,
+
+    /// Number of updates sent to the [`Table`] so far.
+    pub chunks_loaded: u64,
+}
+
+/// Options for [`Table::load_stream`].
+#[derive(Clone)]
+pub struct LoadStreamOptions {
+    /// The approximate size, in bytes, of each update sent to the server.
+    pub chunk_size: usize,
+
+    /// The port to send updates to, as in [`UpdateOptions::port_id`].
+    pub port_id: Option<u32>,
+
+    /// Called after each chunk is read and loaded.
+    pub on_progress: Option<Arc<dyn Fn(LoadProgress) + Send + Sync>>,
+
+    /// Options for parsing [`StreamFormat::Csv`] input, as in
+    /// [`UpdateOptions::csv`]. The `quote_char` and `header_rows` also decide
+    /// where the stream is split into chunks.
+    pub csv: Option<CsvOptions>,
+}
+
+impl Default for LoadStreamOptions {
+    fn default() -> Self {
+        Self {
+            chunk_size: 8 * 1024 * 1024,
+            port_id: None,
+            on_progress: None,
+            csv: None,
+        }
+    }
+}
+
+impl std::fmt::Debug for LoadStreamOptions {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("LoadStreamOptions")
+            .field("chunk_size", &self.chunk_size)
+            .field("port_id", &self.port_id)
+            .field("csv", &self.csv)
+            .finish()
+    }
+}
+
+impl Table {
+    #[doc = include_str!("../../docs/table/load_stream.md")]
+    pub async fn load_stream<R>(
+        &self,
+        mut reader: R,
+        format: StreamFormat,
+        options: LoadStreamOptions,
+    ) -> ClientResult<LoadProgress>
+    where
+        R: AsyncRead + Unpin,
+    {
+        let chunk_size = options.chunk_size.max(1);
+        let csv = options.csv.clone().unwrap_or_default();
+        let mut quote = [0; 4];
+        let quote = csv
+            .quote_char
+            .unwrap_or('"')
+            .encode_utf8(&mut quote)
+            .as_bytes();
+        let header_rows = csv.header_rows.unwrap_or(1) as usize;
+        let mut progress = LoadProgress::default();
+        let mut header: Option<Vec<u8>> = None;
+        let mut arrow_header: Vec<u8> = vec![];
+        let mut buf: Vec<u8> = Vec::with_capacity(chunk_size);
+        let mut read_buf = vec![0; chunk_size.min(64 * 1024)];
+        loop {
+            let n = reader.read(&mut read_buf).await?;
+            progress.bytes_read += n as u64;
+            buf.extend_from_slice(&read_buf[..n]);
+            let eof = n == 0;
+            if !eof && buf.len() < chunk_size {
+                continue;
+            }
+
+            let split = match format {
+                _ if eof => buf.len(),
+                StreamFormat::Arrow => arrow_messages(&buf).last().map_or(0, |x| x.range.end),
+                StreamFormat::Csv if header.is_none() => {
+                    last_record_boundary(&buf, Some(quote), header_rows)
+                },
+                StreamFormat::Csv => last_record_boundary(&buf, Some(quote), 0),
+                StreamFormat::Ndjson => last_record_boundary(&buf, None, 0),
+            };
+
+            let mut chunk: Vec<u8> = buf.drain(..split).collect();
+            if format == StreamFormat::Csv && header.is_none() && !chunk.is_empty() {
+                let end = first_record_boundary(&chunk, quote, header_rows).unwrap_or(chunk.len());
+                header = Some(chunk[..end].to_vec());
+                chunk.drain(..end);
+            }
+
+            if format == StreamFormat::Arrow {
+                chunk = arrow_chunk(&mut arrow_header, chunk);
+            }
+
+            if chunk.iter().any(|x| !x.is_ascii_whitespace()) {
+                let data = match format {
+                    StreamFormat::Arrow => UpdateData::Arrow(chunk.into()),
+                    StreamFormat::Csv => {
+                        let mut csv = header.clone().unwrap_or_default();
+                        csv.extend(chunk);
+                        UpdateData::Csv(std::str::from_utf8(&csv)?.to_owned())
+                    },
+                    StreamFormat::Ndjson => {
+                        let rows = std::str::from_utf8(&chunk)?
+                            .lines()
+                            .filter(|x| !x.trim().is_empty())
+                            .collect::<Vec<_>>()
+                            .join(",");
+
+                        UpdateData::JsonRows(format!("[{}]", rows))
+                    },
+                };
+
+                let update_options = UpdateOptions {
+                    port_id: options.port_id,
+                    csv: options.csv.clone().filter(|_| format == StreamFormat::Csv),
+                    ..UpdateOptions::default()
+                };
+
+                self.update(data, update_options).await?;
+                progress.chunks_loaded += 1;
+            }
+
+            if let Some(on_progress) = &options.on_progress {
+                on_progress(progress);
+            }
+
+            if eof {
+                return Ok(progress);
+            }
+
+            YieldNow(false).await;
+        }
+    }
+}
+
+/// The indices just past each newline in `buf` which is not inside a field
+/// quoted by the (UTF-8 encoded) `quote`, if any.
+fn record_boundaries<'a>(
+    buf: &'a [u8],
+    quote: Option<&'a [u8]>,
+) -> impl Iterator<Item = usize> + 'a {
+    let mut in_quotes = false;
+    buf.iter().enumerate().filter_map(move |(i, c)| {
+        if quote.is_some_and(|quote| buf[i..].starts_with(quote)) {
+            in_quotes = !in_quotes;
+        } else if *c == b'\n' && !in_quotes {
+            return Some(i + 1);
+        }
+
+        None
+    })
+}
+
+/// The index just past the last record boundary in `buf`, or `0` if there is
+/// none or fewer than `header_rows`, so the header is never split across
+/// chunks.
+fn last_record_boundary(buf: &[u8], quote: Option<&[u8]>, header_rows: usize) -> usize {
+    let (count, last) = record_boundaries(buf, quote).fold((0, 0), |(n, _), i| (n + 1, i));
+    if count >= header_rows {
+        last
+    } else {
+        0
+    }
+}
+
+/// The index just past the `header_rows` header records at the start of a
+/// CSV `buf`.
+fn first_record_boundary(buf: &[u8], quote: &[u8], header_rows: usize) -> Option<usize> {
+    match header_rows {
+        0 => Some(0),
+        n => record_boundaries(buf, Some(quote)).nth(n - 1),
+    }
+}
+
+/// A complete Arrow IPC message at the front of a stream buffer.
+struct ArrowMessage {
+    range: Range<usize>,
+
+    /// Whether this is a record batch, rather than a schema or dictionary
+    /// batch which every chunk needs a copy of.
+    is_batch: bool,
+}
+
+/// The complete Arrow IPC messages at the start of `buf`, which must begin on
+/// a message boundary. Stops at the end-of-stream marker, an incomplete
+/// message, or anything which can't be parsed.
+fn arrow_messages(buf: &[u8]) -> Vec<ArrowMessage> {
+    let mut messages = vec![];
+    let mut offset = 0;
+    while let Some((len, is_batch)) = arrow_message_len(&buf[offset..]) {
+        messages.push(ArrowMessage {
+            range: offset..offset + len,
+            is_batch,
+        });
+
+        offset += len;
+    }
+
+    messages
+}
+
+/// The length of the complete Arrow IPC message at the start of `buf`, and
+/// whether it is a record batch. A message is a `0xFFFFFFFF` continuation
+/// marker (absent before Arrow 0.15), the length of its `Message` flatbuffer,
+/// the flatbuffer itself, then a body of the flatbuffer's `bodyLength` bytes.
+fn arrow_message_len(buf: &[u8]) -> Option<(usize, bool)> {
+    let mut prefix = 4;
+    let mut meta_len = read_le::<4>(buf, 0)? as usize;
+    if meta_len == 0xFFFF_FFFF {
+        prefix = 8;
+        meta_len = read_le::<4>(buf, 4)? as usize;
+    }
+
+    if meta_len == 0 {
+        return None;
+    }
+
+    let meta = buf.get(prefix..prefix + meta_len)?;
+    let table = read_le::<4>(meta, 0)? as usize;
+    let vtable = table.checked_add_signed(-(read_le::<4>(meta, table)? as i32 as isize))?;
+    let vtable_len = read_le::<2>(meta, vtable)? as usize;
+    let field = |id: usize| match 4 + 2 * id {
+        pos if pos + 2 > vtable_len => Some(0),
+        pos => read_le::<2>(meta, vtable + pos).map(|x| x as usize),
+    };
+
+    // `Message` fields are `version`, `header_type`, `header`, `bodyLength`;
+    // a `header_type` of 3 is `RecordBatch`.
+    let is_batch = match field(1)? {
+        0 => false,
+        pos => *meta.get(table + pos)? == 3,
+    };
+
+    let body_len = match field(3)? {
+        0 => 0,
+        pos => usize::try_from(read_le::<8>(meta, table + pos)?).ok()?,
+    };
+
+    let len = prefix + meta_len + body_len;
+    (buf.len() >= len).then_some((len, is_batch))
+}
+
+/// The little-endian unsigned integer of `N` bytes at `offset` in `buf`.
+fn read_le<const N: usize>(buf: &[u8], offset: usize) -> Option<u64> {
+    let bytes = buf.get(offset..offset.checked_add(N)?)?;
+    Some(bytes.iter().rev().fold(0, |acc, x| (acc << 8) | *x as u64))
+}
+
+/// Prepends `header`, the schema and dictionary batches of the chunks sent so
+/// far, to an Arrow `chunk`, and appends those of `chunk` to `header`. A
+/// chunk with no record batches is empty once they've been taken, unless it
+/// has bytes which can't be parsed, which are sent for the server to reject.
+fn arrow_chunk(header: &mut Vec<u8>, chunk: Vec<u8>) -> Vec<u8> {
+    let messages = arrow_messages(&chunk);
+    let parsed = messages.last().map_or(0, |x| x.range.end);
+    let rest = &chunk[parsed..];
+    let is_eos = matches!(
+        rest,
+        [] | [0, 0, 0, 0] | [0xFF, 0xFF, 0xFF, 0xFF, 0, 0, 0, 0]
+    );
+    let has_batch = messages.iter().any(|x| x.is_batch);
+    let mut stream = header.clone();
+    for message in messages.iter().filter(|x| !x.is_batch) {
+        header.extend_from_slice(&chunk[message.range.clone()]);
+    }
+
+    if !has_batch && is_eos {
+        return vec![];
+    }
+
+    stream.extend(chunk);
+    stream
+}
+
+/// Yields to the executor once, so long loads don't starve other tasks
+/// (such as the server's poll loop) on a single-threaded runtime.
+struct YieldNow(bool);
+
+impl Future for YieldNow {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        if self.0 {
+            Poll::Ready(())
+        } else {
+            self.0 = true;
+            cx.waker().wake_by_ref();
+            Poll::Pending
+        }
+    }
+}
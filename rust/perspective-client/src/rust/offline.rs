@@ -0,0 +1,95 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use std::collections::VecDeque;
+
+use serde::{Deserialize, Serialize};
+
+use crate::proto::Request;
+use crate::utils::*;
+
+/// What [`crate::Table::update`] does when the offline buffer is full.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OverflowPolicy {
+    /// Discard the oldest buffered update to make room.
+    #[default]
+    DropOldest,
+
+    /// Discard the new update.
+    DropNewest,
+
+    /// Fail the new update with [`ClientError::OfflineBufferFull`].
+    Error,
+}
+
+/// Options for [`crate::Client::set_offline_buffer`].
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct OfflineBufferOptions {
+    /// The most updates to buffer while disconnected.
+    pub capacity: usize,
+
+    #[serde(default)]
+    pub overflow: OverflowPolicy,
+}
+
+/// `TableUpdateReq`s which could not be sent, in the order they were made,
+/// each tagged with a sequence number so that a replayed update can be
+/// removed once sent even if an overflow has since moved or evicted it.
+pub(crate) struct OfflineBuffer {
+    pub options: OfflineBufferOptions,
+    pub updates: VecDeque<(u64, Request)>,
+    next_seq: u64,
+}
+
+impl OfflineBuffer {
+    pub fn new(options: OfflineBufferOptions) -> Self {
+        OfflineBuffer {
+            options,
+            updates: VecDeque::default(),
+            next_seq: 0,
+        }
+    }
+
+    pub fn push(&mut self, msg: Request) -> ClientResult<()> {
+        let seq = self.next_seq;
+        self.next_seq += 1;
+        if self.updates.len() < self.options.capacity {
+            self.updates.push_back((seq, msg));
+            return Ok(());
+        }
+
+        match self.options.overflow {
+            OverflowPolicy::DropOldest => {
+                tracing::warn!("Offline buffer full, dropping oldest update");
+                self.updates.pop_front();
+                if self.options.capacity > 0 {
+                    self.updates.push_back((seq, msg));
+                }
+
+                Ok(())
+            },
+            OverflowPolicy::DropNewest => {
+                tracing::warn!("Offline buffer full, dropping update");
+                Ok(())
+            },
+            OverflowPolicy::Error => Err(ClientError::OfflineBufferFull(self.options.capacity)),
+        }
+    }
+
+    /// Remove the update `seq`, if it is still buffered.
+    pub fn remove(&mut self, seq: u64) {
+        if let Some(idx) = self.updates.iter().position(|(x, _)| *x == seq) {
+            self.updates.remove(idx);
+        }
+    }
+}
@@ -0,0 +1,104 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use std::collections::BTreeMap;
+use std::sync::{Arc, RwLock};
+
+use futures::future::try_join_all;
+
+use crate::client::Client;
+use crate::table::Table;
+use crate::utils::*;
+
+/// A set of [`Client`]s connected to different servers, under a unified
+/// namespace of `server_name/table_name` table names, for dashboards which
+/// aggregate several backends.
+#[derive(Clone, Default)]
+pub struct ClientPool {
+    clients: Arc<RwLock<BTreeMap<String, Client>>>,
+}
+
+impl std::fmt::Debug for ClientPool {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ClientPool")
+            .field("servers", &self.server_names())
+            .finish()
+    }
+}
+
+impl ClientPool {
+    /// Add `client` to this pool as `server_name`, which must not contain
+    /// `/`, replacing the [`Client`] previously added with this name.
+    pub fn add(&self, server_name: &str, client: Client) -> ClientResult<Option<Client>> {
+        if server_name.is_empty() || server_name.contains('/') {
+            return Err(ClientError::Unknown(format!(
+                "Invalid server name {:?}",
+                server_name
+            )));
+        }
+
+        Ok(self
+            .clients
+            .write()
+            .unwrap()
+            .insert(server_name.to_owned(), client))
+    }
+
+    /// Remove the [`Client`] named `server_name` from this pool. This does not
+    /// close its connection.
+    pub fn remove(&self, server_name: &str) -> Option<Client> {
+        self.clients.write().unwrap().remove(server_name)
+    }
+
+    pub fn get(&self, server_name: &str) -> ClientResult<Client> {
+        self.clients
+            .read()
+            .unwrap()
+            .get(server_name)
+            .cloned()
+            .ok_or_else(|| ClientError::UnknownServer(server_name.to_owned()))
+    }
+
+    pub fn server_names(&self) -> Vec<String> {
+        self.clients.read().unwrap().keys().cloned().collect()
+    }
+
+    /// [`Client::open_table`] on the server named by the first segment of a
+    /// `server_name/table_name` name.
+    pub async fn open_table(&self, name: &str) -> ClientResult<Table> {
+        let (server_name, table_name) = name.split_once('/').ok_or_else(|| {
+            ClientError::Unknown(format!("Expected server/table, got {:?}", name))
+        })?;
+
+        self.get(server_name)?
+            .open_table(table_name.to_owned())
+            .await
+    }
+
+    /// The `server_name/table_name` names of every table hosted by every
+    /// server in this pool.
+    pub async fn get_hosted_table_names(&self) -> ClientResult<Vec<String>> {
+        let clients: Vec<_> = self.clients.read().unwrap().clone().into_iter().collect();
+        let names = try_join_all(clients.iter().map(|(server_name, client)| async move {
+            let names = client.get_hosted_table_names().await?;
+            ClientResult::Ok(
+                names
+                    .into_iter()
+                    .map(|x| format!("{}/{}", server_name, x))
+                    .collect::<Vec<_>>(),
+            )
+        }))
+        .await?;
+
+        Ok(names.into_iter().flatten().collect())
+    }
+}
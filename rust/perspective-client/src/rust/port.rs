@@ -0,0 +1,74 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use crate::table::{Table, UpdateOptions};
+use crate::table_data::UpdateData;
+use crate::utils::*;
+
+/// An input channel on a [`Table`], created by [`Table::make_port`].
+///
+/// Updates sent through a [`Port`] are attributed to it in the
+/// [`crate::proto::ViewOnUpdateResp`] passed to [`crate::View::on_update`]
+/// callbacks, which carries the port's `port_id`, `port_name` and
+/// `port_sequence`. Each port counts the updates it receives, so a
+/// subscriber can tell which of a port's updates a callback reflects.
+#[derive(Clone)]
+pub struct Port {
+    table: Table,
+    id: u32,
+    name: String,
+}
+
+impl std::fmt::Debug for Port {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Port")
+            .field("id", &self.id)
+            .field("name", &self.name)
+            .finish()
+    }
+}
+
+impl Port {
+    pub(crate) fn new(table: Table, id: u32, name: String) -> Self {
+        Port { table, id, name }
+    }
+
+    /// The raw port ID, as in [`UpdateOptions::port_id`].
+    pub fn id(&self) -> u32 {
+        self.id
+    }
+
+    /// The name this port was created with, or `""` if it is anonymous.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// [`Table::update`] through this port, returning the port's sequence
+    /// number for this update: the number of updates it has received,
+    /// including this one.
+    pub async fn update(&self, input: UpdateData, options: UpdateOptions) -> ClientResult<u64> {
+        let options = UpdateOptions {
+            port_id: Some(self.id),
+            ..options
+        };
+
+        self.table.update_with_sequence(input, options).await
+    }
+
+    /// Commit this port's pending updates (and any others pending on its
+    /// [`Table`]), e.g. when the [`Table`] batches updates with
+    /// [`crate::TableInitOptions::batch_latency_ms`]. Resolves after the
+    /// resulting [`crate::View::on_update`] callbacks have been sent.
+    pub async fn flush(&self) -> ClientResult<()> {
+        self.table.flush().await
+    }
+}
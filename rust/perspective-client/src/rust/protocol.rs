@@ -0,0 +1,201 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+//! Machine-readable descriptions of Perspective's wire protocol, as spoken by
+//! this version of [`crate::Client`], for generating clients in other
+//! languages.
+
+use prost::Message;
+use prost_types::field_descriptor_proto::{Label, Type};
+use prost_types::{DescriptorProto, EnumDescriptorProto, FieldDescriptorProto, FileDescriptorSet};
+use serde_json::{json, Map, Value};
+
+/// The encoded protobuf `FileDescriptorSet` of `perspective.proto`.
+pub static DESCRIPTOR_SET: &[u8] = include_bytes!("perspective.bin");
+
+/// The version of this crate, which identifies the exact protocol described
+/// by [`descriptor`].
+pub const PACKAGE_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// The oldest wire protocol version this client speaks. Version 1 is the
+/// protocol before `ServerHelloReq` was introduced, spoken by servers which
+/// do not respond to it.
+pub const MIN_PROTOCOL_VERSION: u32 = 1;
+
+/// The newest wire protocol version this client speaks.
+pub const MAX_PROTOCOL_VERSION: u32 = 2;
+
+/// Optional features this client offers to use, if the server supports them.
+pub const FEATURES: &[&str] = &[
+    "arrow_ingest",
+    "bulk_export",
+    "server_broadcast",
+    "table_flush",
+    "table_remove_where",
+    "table_replace_atomic",
+    "table_schema_override",
+    "view_downsample",
+    "view_resync",
+];
+
+/// The protobuf `FileDescriptorSet` of the wire protocol, which can be fed to
+/// `protoc` plugins and other protobuf tooling to generate a client.
+pub fn descriptor() -> FileDescriptorSet {
+    FileDescriptorSet::decode(DESCRIPTOR_SET).expect("Malformed descriptor set")
+}
+
+/// A JSON Schema rendering of the wire protocol's messages and enums, under
+/// `$defs` by fully-qualified protobuf name, following the protobuf JSON
+/// mapping. The root schema is the `Request` message.
+pub fn json_schema() -> Value {
+    let mut defs = Map::new();
+    for file in descriptor().file {
+        let package = file.package().to_owned();
+        for message in &file.message_type {
+            render_message(&package, message, &mut defs);
+        }
+
+        for enumeration in &file.enum_type {
+            render_enum(&package, enumeration, &mut defs);
+        }
+    }
+
+    json!({
+        "$schema": "https://json-schema.org/draft/2020-12/schema",
+        "$id": format!("perspective-protocol-{}", PACKAGE_VERSION),
+        "$ref": "#/$defs/perspective.proto.Request",
+        "$defs": defs,
+    })
+}
+
+fn render_enum(scope: &str, enumeration: &EnumDescriptorProto, defs: &mut Map<String, Value>) {
+    let values: Vec<_> = enumeration.value.iter().map(|x| x.name()).collect();
+    defs.insert(
+        format!("{}.{}", scope, enumeration.name()),
+        json!({ "enum": values }),
+    );
+}
+
+fn render_message(scope: &str, message: &DescriptorProto, defs: &mut Map<String, Value>) {
+    let name = format!("{}.{}", scope, message.name());
+    for nested in &message.nested_type {
+        render_message(&name, nested, defs);
+    }
+
+    for enumeration in &message.enum_type {
+        render_enum(&name, enumeration, defs);
+    }
+
+    let properties: Map<String, Value> = message
+        .field
+        .iter()
+        .map(|field| (field.json_name().to_owned(), render_field(message, field)))
+        .collect();
+
+    defs.insert(
+        name,
+        json!({
+            "type": "object",
+            "properties": properties,
+            "additionalProperties": false,
+        }),
+    );
+}
+
+fn render_field(message: &DescriptorProto, field: &FieldDescriptorProto) -> Value {
+    // `map<K, V>` fields are repeated synthetic `*Entry` messages.
+    let map_entry = message.nested_type.iter().find(|x| {
+        x.options
+            .as_ref()
+            .and_then(|x| x.map_entry)
+            .unwrap_or_default()
+            && field.type_name().ends_with(&format!(".{}", x.name()))
+    });
+
+    if let Some(entry) = map_entry {
+        let value = entry
+            .field
+            .iter()
+            .find(|x| x.number() == 2)
+            .map(render_scalar)
+            .unwrap_or_default();
+
+        json!({ "type": "object", "additionalProperties": value })
+    } else if field.label() == Label::Repeated {
+        json!({ "type": "array", "items": render_scalar(field) })
+    } else {
+        render_scalar(field)
+    }
+}
+
+fn render_scalar(field: &FieldDescriptorProto) -> Value {
+    match field.r#type() {
+        Type::Double | Type::Float => json!({ "type": "number" }),
+        Type::Int32 | Type::Uint32 | Type::Sint32 | Type::Fixed32 | Type::Sfixed32 => {
+            json!({ "type": "integer" })
+        },
+        // 64-bit integers are strings in the protobuf JSON mapping.
+        Type::Int64 | Type::Uint64 | Type::Sint64 | Type::Fixed64 | Type::Sfixed64 => {
+            json!({ "type": ["integer", "string"] })
+        },
+        Type::Bool => json!({ "type": "boolean" }),
+        Type::String => json!({ "type": "string" }),
+        Type::Bytes => json!({ "type": "string", "contentEncoding": "base64" }),
+        Type::Message | Type::Enum | Type::Group => {
+            json!({ "$ref": format!("#/$defs/{}", field.type_name().trim_start_matches('.')) })
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_descriptor_contains_protocol() {
+        let descriptor = descriptor();
+        let file = descriptor
+            .file
+            .iter()
+            .find(|x| x.name() == "perspective.proto")
+            .unwrap();
+
+        assert_eq!(file.package(), "perspective.proto");
+        assert!(file.message_type.iter().any(|x| x.name() == "Request"));
+        assert!(file.message_type.iter().any(|x| x.name() == "Response"));
+    }
+
+    #[test]
+    fn test_json_schema_references_resolve() {
+        let schema = json_schema();
+        let defs = schema["$defs"].as_object().unwrap();
+        assert!(defs.contains_key("perspective.proto.Request"));
+        assert!(defs.contains_key("perspective.proto.ColumnType"));
+
+        fn check_refs(value: &Value, defs: &Map<String, Value>) {
+            match value {
+                Value::Object(obj) => {
+                    if let Some(Value::String(path)) = obj.get("$ref") {
+                        let name = path.trim_start_matches("#/$defs/");
+                        assert!(defs.contains_key(name), "Unresolved {}", path);
+                    }
+
+                    obj.values().for_each(|x| check_refs(x, defs));
+                },
+                Value::Array(arr) => arr.iter().for_each(|x| check_refs(x, defs)),
+                _ => {},
+            }
+        }
+
+        check_refs(&schema, defs);
+    }
+}
@@ -14,11 +14,14 @@ use std::collections::HashMap;
 use std::fmt::Display;
 
 use nanoid::*;
+use prost::bytes::Bytes;
 use serde::{Deserialize, Serialize};
 use ts_rs::TS;
 
+use crate::arrow_ingest::ArrowIngest;
 use crate::client::{Client, Features};
-use crate::config::{Expressions, ViewConfigUpdate};
+use crate::config::{Expressions, Filter, ViewConfigUpdate};
+use crate::port::Port;
 use crate::proto::make_table_req::make_table_options::MakeTableType;
 use crate::proto::make_table_req::MakeTableOptions;
 use crate::proto::request::ClientReq;
@@ -55,6 +58,242 @@ pub struct TableInitOptions {
     #[serde(default)]
     #[ts(optional)]
     pub limit: Option<u32>,
+
+    /// Only the session which created this [`Table`] may [`Table::update`],
+    /// [`Table::remove`], [`Table::replace`] or [`Table::delete`] it; these
+    /// methods fail in every other session. If the writer session closes, the
+    /// next session to write claims the role, and any session may take it
+    /// over explicitly with [`Table::take_writer`].
+    #[serde(default)]
+    #[ts(optional)]
+    pub exclusive_writer: Option<bool>,
+
+    /// Ordered categories of `string` columns, by column name. Group by and
+    /// sort on these columns follow the given order (e.g. `Low`, `Medium`,
+    /// `High`) rather than lexicographic order; values which are not listed
+    /// come after every category. Arrow dictionary columns whose type is
+    /// marked `ordered` are categorical in dictionary order by default. See
+    /// [`Table::categories`].
+    #[serde(default)]
+    #[ts(optional)]
+    pub categories: Option<HashMap<String, Vec<String>>>,
+
+    /// String dictionary options of `string` columns, by column name, see
+    /// [`DictionaryOptions`] and [`Table::dictionary_stats`].
+    #[serde(default)]
+    #[ts(optional)]
+    pub dictionaries: Option<HashMap<String, DictionaryOptions>>,
+
+    /// Coalesce high-frequency [`Table::update`] calls: updates which arrive
+    /// within `batch_latency_ms` milliseconds of this [`Table`]'s last commit
+    /// are held and committed together (and reported in a single
+    /// [`View::on_update`] callback), while an update after a quiet period
+    /// commits immediately. Held updates are committed by the first poll
+    /// after the latency elapses, or before any request which reads this
+    /// [`Table`] or its [`View`]s.
+    #[serde(default)]
+    #[ts(optional)]
+    pub batch_latency_ms: Option<u32>,
+
+    /// Compress this [`Table`]'s columns in memory, using whichever of
+    /// run-length, dictionary or delta encoding is smallest for each column.
+    /// Columns are decompressed transparently when read or updated, and
+    /// compressed again once the request which read them completes, trading
+    /// CPU for memory on large, mostly-static tables.
+    #[serde(default)]
+    #[ts(optional)]
+    pub compress: Option<bool>,
+
+    /// Default aggregate and format hints by column name, which every client
+    /// of this [`Table`] reads from [`Table::column_hints`] so dashboards need
+    /// not restate them, see [`ColumnHints`].
+    #[serde(default)]
+    #[ts(optional)]
+    pub column_hints: Option<HashMap<String, ColumnHints>>,
+
+    /// Expression columns of this [`Table`] itself, by column name, in the
+    /// syntax of [`ViewConfigUpdate::expressions`]. Unlike a [`View`]'s
+    /// expressions, these are computed once per update and are columns of
+    /// every [`View`] and export, see [`Table::virtual_columns`].
+    #[serde(default)]
+    #[ts(optional)]
+    pub virtual_columns: Option<HashMap<String, String>>,
+
+    /// Options for parsing CSV input, see [`CsvOptions`].
+    #[serde(default)]
+    #[ts(optional)]
+    pub csv: Option<CsvOptions>,
+
+    /// Column types for column-oriented JSON input (e.g. `{"a": [1, 2]}`),
+    /// by column name, which override the types [`Client::table`] would
+    /// otherwise infer from the data. Columns not in `schema` are still
+    /// inferred, and values are coerced to their column's type.
+    #[serde(default)]
+    #[ts(optional)]
+    #[ts(type = "Record<string, string>")]
+    pub schema: Option<Schema>,
+}
+
+/// CSV dialect and type-inference options, for [`Client::table`] and
+/// [`Table::update`] with [`UpdateData::Csv`] input. Unset fields default to
+/// RFC 4180 with a single header row.
+#[derive(Clone, Debug, Default, Serialize, Deserialize, TS)]
+pub struct CsvOptions {
+    /// The field delimiter, e.g. `;` for European-style CSV.
+    #[serde(default)]
+    #[ts(optional)]
+    pub delimiter: Option<char>,
+
+    /// The character quoting fields which contain delimiters, newlines or
+    /// (doubled) quotes. Defaults to `"`.
+    #[serde(default)]
+    #[ts(optional)]
+    pub quote_char: Option<char>,
+
+    /// The decimal separator for `float` columns, e.g. `,`.
+    #[serde(default)]
+    #[ts(optional)]
+    pub decimal_separator: Option<char>,
+
+    /// A digit group separator to strip from numbers, e.g. `.` for
+    /// `1.234,56`.
+    #[serde(default)]
+    #[ts(optional)]
+    pub thousands_separator: Option<char>,
+
+    /// `strptime`-style formats (e.g. `%d.%m.%Y`) to try before the built-in
+    /// date and datetime formats.
+    #[serde(default)]
+    #[ts(optional)]
+    pub timestamp_formats: Option<Vec<String>>,
+
+    /// The number of rows before the data, the last of which holds the
+    /// column names. If `0`, column names are generated.
+    #[serde(default)]
+    #[ts(optional)]
+    pub header_rows: Option<u32>,
+
+    /// Tokens to parse as null, e.g. `["", "NA", "-"]`.
+    #[serde(default)]
+    #[ts(optional)]
+    pub null_values: Option<Vec<String>>,
+
+    /// How many bytes of input to sample when inferring column types.
+    #[serde(default)]
+    #[ts(optional)]
+    pub infer_sample_bytes: Option<u32>,
+
+    /// Infer integer columns as `float`, so fractional values appearing after
+    /// the sample aren't rejected.
+    #[serde(default)]
+    #[ts(optional)]
+    pub infer_int_as_float: Option<bool>,
+}
+
+/// Sizing hints and limits for the dictionary of interned strings backing a
+/// `string` column. Every unique value a column has ever held stays in its
+/// dictionary, so a column of mostly-unique values (e.g. order IDs which are
+/// not the `index`) can quietly dominate memory.
+#[derive(Clone, Debug, Default, Serialize, Deserialize, TS)]
+pub struct DictionaryOptions {
+    /// The expected number of unique values, reserved up front to avoid
+    /// rehashing as the dictionary grows.
+    #[serde(default)]
+    #[ts(optional)]
+    pub reserve: Option<u32>,
+
+    /// Log a warning on the server when the number of unique values exceeds
+    /// this.
+    #[serde(default)]
+    #[ts(optional)]
+    pub max_cardinality: Option<u32>,
+}
+
+impl From<DictionaryOptions> for proto::DictionaryOptions {
+    fn from(value: DictionaryOptions) -> Self {
+        proto::DictionaryOptions {
+            reserve: value.reserve,
+            max_cardinality: value.max_cardinality,
+        }
+    }
+}
+
+/// Display defaults for a [`Table`] column, e.g. `notional` summed and shown
+/// to 2 decimal places with thousands separators. These are hints only; the
+/// server stores and returns them, and it is up to each client whether to
+/// apply them.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize, TS)]
+pub struct ColumnHints {
+    /// The aggregate to apply when this column is grouped, e.g. `sum`, which
+    /// must be a valid aggregate name.
+    #[serde(default)]
+    #[ts(optional)]
+    pub aggregate: Option<String>,
+
+    /// The number of decimal places to display.
+    #[serde(default)]
+    #[ts(optional)]
+    pub precision: Option<u32>,
+
+    /// Whether to group thousands with a separator when displayed.
+    #[serde(default)]
+    #[ts(optional)]
+    pub thousands_separator: Option<bool>,
+
+    /// Whether [`Table::edit_cells`] may edit this column, which the server
+    /// enforces. Columns are editable by default, except the `index` column,
+    /// which never is.
+    #[serde(default)]
+    #[ts(optional)]
+    pub editable: Option<bool>,
+
+    /// The time zone of this column's wall-clock values, e.g.
+    /// `America/New_York`, which calendar expression functions such as
+    /// `bucket` and `hour_of_day` over this column use when the [`View`]
+    /// sets no `timezone`. Unknown zones are rejected.
+    #[serde(default)]
+    #[ts(optional)]
+    pub timezone: Option<String>,
+}
+
+impl From<ColumnHints> for proto::ColumnHints {
+    fn from(value: ColumnHints) -> Self {
+        proto::ColumnHints {
+            aggregate: value.aggregate,
+            precision: value.precision,
+            thousands_separator: value.thousands_separator,
+            editable: value.editable,
+            timezone: value.timezone,
+        }
+    }
+}
+
+impl From<proto::ColumnHints> for ColumnHints {
+    fn from(value: proto::ColumnHints) -> Self {
+        ColumnHints {
+            aggregate: value.aggregate,
+            precision: value.precision,
+            thousands_separator: value.thousands_separator,
+            editable: value.editable,
+            timezone: value.timezone,
+        }
+    }
+}
+
+impl From<CsvOptions> for proto::CsvOptions {
+    fn from(value: CsvOptions) -> Self {
+        proto::CsvOptions {
+            delimiter: value.delimiter.map(String::from),
+            quote_char: value.quote_char.map(String::from),
+            decimal_separator: value.decimal_separator.map(String::from),
+            thousands_separator: value.thousands_separator.map(String::from),
+            timestamp_formats: value.timestamp_formats.unwrap_or_default(),
+            header_rows: value.header_rows,
+            null_values: value.null_values.unwrap_or_default(),
+            infer_sample_bytes: value.infer_sample_bytes,
+            infer_int_as_float: value.infer_int_as_float.unwrap_or_default(),
+        }
+    }
 }
 
 impl TableInitOptions {
@@ -72,6 +311,7 @@ impl TryFrom<TableOptions> for MakeTableOptions {
                 TableOptions {
                     index: Some(_),
                     limit: Some(_),
+                    ..
                 } => Err(ClientError::BadTableOptions)?,
                 TableOptions {
                     index: Some(index), ..
@@ -81,6 +321,25 @@ impl TryFrom<TableOptions> for MakeTableOptions {
                 } => Some(MakeTableType::MakeLimitTable(limit)),
                 _ => None,
             },
+            exclusive_writer: value.exclusive_writer,
+            categories: value
+                .categories
+                .into_iter()
+                .map(|(column, categories)| (column, CategoryList { categories }))
+                .collect(),
+            dictionaries: value
+                .dictionaries
+                .into_iter()
+                .map(|(column, options)| (column, options.into()))
+                .collect(),
+            batch_latency_ms: value.batch_latency_ms,
+            compress: value.compress,
+            column_hints: value
+                .column_hints
+                .into_iter()
+                .map(|(column, hints)| (column, hints.into()))
+                .collect(),
+            virtual_columns: value.virtual_columns,
         })
     }
 }
@@ -89,6 +348,13 @@ impl TryFrom<TableOptions> for MakeTableOptions {
 pub(crate) struct TableOptions {
     pub index: Option<String>,
     pub limit: Option<u32>,
+    pub exclusive_writer: bool,
+    pub categories: HashMap<String, Vec<String>>,
+    pub dictionaries: HashMap<String, DictionaryOptions>,
+    pub batch_latency_ms: Option<u32>,
+    pub compress: bool,
+    pub column_hints: HashMap<String, ColumnHints>,
+    pub virtual_columns: HashMap<String, String>,
 }
 
 impl From<TableInitOptions> for TableOptions {
@@ -96,6 +362,13 @@ impl From<TableInitOptions> for TableOptions {
         TableOptions {
             index: value.index,
             limit: value.limit,
+            exclusive_writer: value.exclusive_writer.unwrap_or_default(),
+            categories: value.categories.unwrap_or_default(),
+            dictionaries: value.dictionaries.unwrap_or_default(),
+            batch_latency_ms: value.batch_latency_ms,
+            compress: value.compress.unwrap_or_default(),
+            column_hints: value.column_hints.unwrap_or_default(),
+            virtual_columns: value.virtual_columns.unwrap_or_default(),
         }
     }
 }
@@ -104,6 +377,10 @@ impl From<TableInitOptions> for TableOptions {
 pub struct UpdateOptions {
     pub format: Option<String>,
     pub port_id: Option<u32>,
+
+    #[serde(default)]
+    #[ts(optional)]
+    pub csv: Option<CsvOptions>,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -128,12 +405,16 @@ pub struct Table {
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct SystemInfo {
     pub heap_size: f64,
+    pub engine_target: String,
+    pub engine_version: String,
 }
 
 impl From<proto::ServerSystemInfoResp> for SystemInfo {
     fn from(value: proto::ServerSystemInfoResp) -> Self {
         SystemInfo {
             heap_size: value.heap_size,
+            engine_target: value.engine_target,
+            engine_version: value.engine_version,
         }
     }
 }
@@ -191,11 +472,161 @@ impl Table {
         }
     }
 
+    #[doc = include_str!("../../docs/table/categories.md")]
+    pub async fn categories(&self) -> ClientResult<HashMap<String, Vec<String>>> {
+        let msg = self.client_message(ClientReq::TableCategoriesReq(TableCategoriesReq {}));
+        match self.client.oneshot(&msg).await? {
+            ClientResp::TableCategoriesResp(TableCategoriesResp { categories }) => Ok(categories
+                .into_iter()
+                .map(|(column, list)| (column, list.categories))
+                .collect()),
+            resp => Err(resp.into()),
+        }
+    }
+
+    #[doc = include_str!("../../docs/table/dictionary_stats.md")]
+    pub async fn dictionary_stats(&self) -> ClientResult<HashMap<String, DictionaryStats>> {
+        let msg = self.client_message(ClientReq::TableDictionaryStatsReq(
+            TableDictionaryStatsReq {},
+        ));
+        match self.client.oneshot(&msg).await? {
+            ClientResp::TableDictionaryStatsResp(TableDictionaryStatsResp { stats }) => Ok(stats),
+            resp => Err(resp.into()),
+        }
+    }
+
+    #[doc = include_str!("../../docs/table/stats.md")]
+    pub async fn stats(&self, interval_ms: Option<u64>) -> ClientResult<TableStats> {
+        let msg = self.client_message(ClientReq::TableStatsReq(TableStatsReq { interval_ms }));
+        match self.client.oneshot(&msg).await? {
+            ClientResp::TableStatsResp(TableStatsResp { stats: Some(stats) }) => Ok(stats),
+            resp => Err(resp.into()),
+        }
+    }
+
+    #[doc = include_str!("../../docs/table/overlay.md")]
+    pub async fn overlay(&self) -> ClientResult<Table> {
+        let msg = self.client_message(ClientReq::TableOverlayReq(TableOverlayReq {}));
+        match self.client.oneshot(&msg).await? {
+            ClientResp::TableOverlayResp(TableOverlayResp { overlay_id }) => {
+                let options = TableOptions {
+                    exclusive_writer: true,
+                    batch_latency_ms: None,
+                    ..self.options.clone()
+                };
+
+                Ok(Table::new(overlay_id, self.client.clone(), options))
+            },
+            resp => Err(resp.into()),
+        }
+    }
+
+    #[doc = include_str!("../../docs/table/commit_overlay.md")]
+    pub async fn commit_overlay(&self) -> ClientResult<u32> {
+        let msg = self.client_message(ClientReq::TableOverlayCommitReq(TableOverlayCommitReq {}));
+        match self.client.oneshot(&msg).await? {
+            ClientResp::TableOverlayCommitResp(TableOverlayCommitResp { num_edits }) => {
+                Ok(num_edits)
+            },
+            resp => Err(resp.into()),
+        }
+    }
+
+    #[doc = include_str!("../../docs/table/edit_cells.md")]
+    pub async fn edit_cells(&self, edits: Vec<CellEdit>, user: Option<String>) -> ClientResult<()> {
+        let msg = self.client_message(ClientReq::TableEditCellsReq(TableEditCellsReq {
+            edits,
+            user,
+        }));
+        match self.client.oneshot(&msg).await? {
+            ClientResp::TableEditCellsResp(_) => Ok(()),
+            resp => Err(resp.into()),
+        }
+    }
+
+    #[doc = include_str!("../../docs/table/edit_log.md")]
+    pub async fn edit_log(&self, limit: Option<u32>) -> ClientResult<Vec<CellEditRecord>> {
+        let msg = self.client_message(ClientReq::TableEditLogReq(TableEditLogReq { limit }));
+        match self.client.oneshot(&msg).await? {
+            ClientResp::TableEditLogResp(TableEditLogResp { records }) => Ok(records),
+            resp => Err(resp.into()),
+        }
+    }
+
+    #[doc = include_str!("../../docs/table/annotate.md")]
+    pub async fn annotate(
+        &self,
+        index: String,
+        column: String,
+        text: Option<String>,
+        user: Option<String>,
+    ) -> ClientResult<()> {
+        let msg = self.client_message(ClientReq::TableAnnotateReq(TableAnnotateReq {
+            index,
+            column,
+            text,
+            user,
+        }));
+        match self.client.oneshot(&msg).await? {
+            ClientResp::TableAnnotateResp(_) => Ok(()),
+            resp => Err(resp.into()),
+        }
+    }
+
+    #[doc = include_str!("../../docs/table/annotations.md")]
+    pub async fn annotations(&self) -> ClientResult<Vec<CellAnnotation>> {
+        let msg = self.client_message(ClientReq::TableAnnotationsReq(TableAnnotationsReq {}));
+        match self.client.oneshot(&msg).await? {
+            ClientResp::TableAnnotationsResp(TableAnnotationsResp { annotations }) => {
+                Ok(annotations)
+            },
+            resp => Err(resp.into()),
+        }
+    }
+
+    #[doc = include_str!("../../docs/table/undo.md")]
+    pub async fn undo(&self, count: u32) -> ClientResult<u32> {
+        let msg = self.client_message(ClientReq::TableUndoReq(TableUndoReq { count }));
+        match self.client.oneshot(&msg).await? {
+            ClientResp::TableUndoResp(TableUndoResp { count }) => Ok(count),
+            resp => Err(resp.into()),
+        }
+    }
+
+    #[doc = include_str!("../../docs/table/redo.md")]
+    pub async fn redo(&self, count: u32) -> ClientResult<u32> {
+        let msg = self.client_message(ClientReq::TableRedoReq(TableRedoReq { count }));
+        match self.client.oneshot(&msg).await? {
+            ClientResp::TableRedoResp(TableRedoResp { count }) => Ok(count),
+            resp => Err(resp.into()),
+        }
+    }
+
+    #[doc = include_str!("../../docs/table/sketches.md")]
+    pub async fn sketches(&self) -> ClientResult<HashMap<String, ColumnSketch>> {
+        let msg = self.client_message(ClientReq::TableSketchesReq(TableSketchesReq {}));
+        match self.client.oneshot(&msg).await? {
+            ClientResp::TableSketchesResp(TableSketchesResp { sketches }) => Ok(sketches),
+            resp => Err(resp.into()),
+        }
+    }
+
+    #[doc = include_str!("../../docs/table/expression_completions.md")]
+    pub async fn expression_completions(&self) -> ClientResult<TableExpressionCompletionsResp> {
+        let msg = self.client_message(ClientReq::TableExpressionCompletionsReq(
+            TableExpressionCompletionsReq {},
+        ));
+        match self.client.oneshot(&msg).await? {
+            ClientResp::TableExpressionCompletionsResp(resp) => Ok(resp),
+            resp => Err(resp.into()),
+        }
+    }
+
     #[doc = include_str!("../../docs/table/columns.md")]
     pub async fn columns(&self) -> ClientResult<Vec<String>> {
         let msg = self.client_message(ClientReq::TableSchemaReq(TableSchemaReq {}));
         match self.client.oneshot(&msg).await? {
-            ClientResp::TableSchemaResp(TableSchemaResp { schema }) => Ok(schema
+            ClientResp::TableSchemaResp(TableSchemaResp { schema, .. }) => Ok(schema
                 .map(|x| x.schema.into_iter().map(|x| x.name.to_owned()).collect())
                 .unwrap()),
             resp => Err(resp.into()),
@@ -215,7 +646,7 @@ impl Table {
     pub async fn schema(&self) -> ClientResult<HashMap<String, ColumnType>> {
         let msg = self.client_message(ClientReq::TableSchemaReq(TableSchemaReq {}));
         match self.client.oneshot(&msg).await? {
-            ClientResp::TableSchemaResp(TableSchemaResp { schema }) => Ok(schema
+            ClientResp::TableSchemaResp(TableSchemaResp { schema, .. }) => Ok(schema
                 .map(|x| {
                     x.schema
                         .into_iter()
@@ -227,15 +658,88 @@ impl Table {
         }
     }
 
+    #[doc = include_str!("../../docs/table/column_hints.md")]
+    pub async fn column_hints(&self) -> ClientResult<HashMap<String, ColumnHints>> {
+        let msg = self.client_message(ClientReq::TableSchemaReq(TableSchemaReq {}));
+        match self.client.oneshot(&msg).await? {
+            ClientResp::TableSchemaResp(TableSchemaResp { column_hints, .. }) => Ok(column_hints
+                .into_iter()
+                .map(|(column, hints)| (column, hints.into()))
+                .collect()),
+            resp => Err(resp.into()),
+        }
+    }
+
+    #[doc = include_str!("../../docs/table/virtual_columns.md")]
+    pub async fn virtual_columns(&self) -> ClientResult<HashMap<String, String>> {
+        let msg = self.client_message(ClientReq::TableSchemaReq(TableSchemaReq {}));
+        match self.client.oneshot(&msg).await? {
+            ClientResp::TableSchemaResp(TableSchemaResp {
+                virtual_columns, ..
+            }) => Ok(virtual_columns),
+            resp => Err(resp.into()),
+        }
+    }
+
     #[doc = include_str!("../../docs/table/make_port.md")]
-    pub async fn make_port(&self) -> ClientResult<i32> {
-        let msg = self.client_message(ClientReq::TableMakePortReq(TableMakePortReq {}));
+    pub async fn make_port(&self, name: &str) -> ClientResult<Port> {
+        let msg = self.client_message(ClientReq::TableMakePortReq(TableMakePortReq {
+            name: name.to_owned(),
+        }));
+
         match self.client.oneshot(&msg).await? {
-            ClientResp::TableMakePortResp(TableMakePortResp { port_id }) => Ok(port_id as i32),
+            ClientResp::TableMakePortResp(TableMakePortResp { port_id }) => {
+                Ok(Port::new(self.clone(), port_id, name.to_owned()))
+            },
             _ => Err(ClientError::Unknown("make_port".to_string())),
         }
     }
 
+    #[doc = include_str!("../../docs/table/arrow_ingest.md")]
+    pub fn arrow_ingest(&self, options: UpdateOptions) -> ArrowIngest {
+        ArrowIngest::new(self.clone(), self.client.gen_id(), options)
+    }
+
+    /// Send one chunk of an [`ArrowIngest`] stream, returning the number of
+    /// rows decoded from the stream so far.
+    pub(crate) async fn ingest_arrow_chunk(
+        &self,
+        stream_id: u32,
+        port_id: u32,
+        chunk: Bytes,
+        end: bool,
+    ) -> ClientResult<u64> {
+        let msg = self.client_message(ClientReq::TableIngestArrowReq(TableIngestArrowReq {
+            stream_id,
+            chunk: chunk.into(),
+            port_id,
+            end,
+        }));
+
+        match self.client.oneshot(&msg).await? {
+            ClientResp::TableIngestArrowResp(TableIngestArrowResp { num_rows }) => Ok(num_rows),
+            resp => Err(resp.into()),
+        }
+    }
+
+    /// Commit this [`Table`]'s pending updates, see [`Port::flush`].
+    pub(crate) async fn flush(&self) -> ClientResult<()> {
+        let msg = self.client_message(ClientReq::TableFlushReq(TableFlushReq {}));
+        match self.client.oneshot(&msg).await? {
+            ClientResp::TableFlushResp(_) => Ok(()),
+            resp => Err(resp.into()),
+        }
+    }
+
+    #[doc = include_str!("../../docs/table/take_writer.md")]
+    pub async fn take_writer(&self) -> ClientResult<()> {
+        let msg = self.client_message(ClientReq::TableTakeWriterReq(TableTakeWriterReq {}));
+        match self.client.oneshot(&msg).await? {
+            ClientResp::TableTakeWriterResp(_) => Ok(()),
+            resp => Err(resp.into()),
+        }
+    }
+
     #[doc = include_str!("../../docs/table/on_delete.md")]
     pub async fn on_delete(
         &self,
@@ -278,6 +782,33 @@ impl Table {
         }
     }
 
+    #[doc = include_str!("../../docs/table/remove_where.md")]
+    pub async fn remove_where(&self, filter: Vec<Filter>) -> ClientResult<u64> {
+        let msg = self.client_message(ClientReq::TableRemoveWhereReq(TableRemoveWhereReq {
+            filter: filter.into_iter().map(|x| x.into()).collect(),
+        }));
+
+        match self.client.oneshot(&msg).await? {
+            ClientResp::TableRemoveWhereResp(TableRemoveWhereResp { num_rows }) => Ok(num_rows),
+            resp => Err(resp.into()),
+        }
+    }
+
+    #[doc = include_str!("../../docs/table/rename.md")]
+    pub async fn rename(&mut self, new_name: String) -> ClientResult<()> {
+        let msg = self.client_message(ClientReq::TableRenameReq(TableRenameReq {
+            new_name: new_name.clone(),
+        }));
+
+        match self.client.oneshot(&msg).await? {
+            ClientResp::TableRenameResp(_) => {
+                self.name = new_name;
+                Ok(())
+            },
+            resp => Err(resp.into()),
+        }
+    }
+
     #[doc = include_str!("../../docs/table/replace.md")]
     pub async fn replace(&self, input: UpdateData) -> ClientResult<()> {
         let msg = self.client_message(ClientReq::TableReplaceReq(TableReplaceReq {
@@ -290,19 +821,50 @@ impl Table {
         }
     }
 
-    #[doc = include_str!("../../docs/table/update.md")]
-    pub async fn update(&self, input: UpdateData, options: UpdateOptions) -> ClientResult<()> {
-        let msg = self.client_message(ClientReq::TableUpdateReq(TableUpdateReq {
+    #[doc = include_str!("../../docs/table/replace_atomic.md")]
+    pub async fn replace_atomic(&self, input: UpdateData) -> ClientResult<()> {
+        let msg = self.client_message(ClientReq::TableReplaceAtomicReq(TableReplaceAtomicReq {
             data: Some(input.into()),
-            port_id: options.port_id.unwrap_or(0),
         }));
 
         match self.client.oneshot(&msg).await? {
-            ClientResp::TableUpdateResp(_) => Ok(()),
+            ClientResp::TableReplaceAtomicResp(_) => Ok(()),
             resp => Err(resp.into()),
         }
     }
 
+    #[doc = include_str!("../../docs/table/update.md")]
+    pub async fn update(&self, input: UpdateData, options: UpdateOptions) -> ClientResult<()> {
+        let msg = self.update_message(input, options);
+        match self.client.oneshot_update(&msg).await? {
+            None | Some(ClientResp::TableUpdateResp(_)) => Ok(()),
+            Some(resp) => Err(resp.into()),
+        }
+    }
+
+    /// [`Table::update`], returning the sequence number of the update on its
+    /// port, see [`Port::update`].
+    pub(crate) async fn update_with_sequence(
+        &self,
+        input: UpdateData,
+        options: UpdateOptions,
+    ) -> ClientResult<u64> {
+        let msg = self.update_message(input, options);
+        match self.client.oneshot(&msg).await? {
+            ClientResp::TableUpdateResp(TableUpdateResp { sequence }) => Ok(sequence),
+            resp => Err(resp.into()),
+        }
+    }
+
+    fn update_message(&self, input: UpdateData, options: UpdateOptions) -> Request {
+        let mut data: MakeTableData = input.into();
+        data.csv_options = options.csv.map(|x| x.into());
+        self.client_message(ClientReq::TableUpdateReq(TableUpdateReq {
+            data: Some(data),
+            port_id: options.port_id.unwrap_or(0),
+        }))
+    }
+
     #[doc = include_str!("../../docs/table/validate_expressions.md")]
     pub async fn validate_expressions(
         &self,
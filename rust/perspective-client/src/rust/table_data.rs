@@ -57,7 +57,10 @@ impl From<TableData> for proto::MakeTableData {
             }),
         };
 
-        MakeTableData { data: Some(data) }
+        MakeTableData {
+            data: Some(data),
+            ..MakeTableData::default()
+        }
     }
 }
 
@@ -70,6 +73,9 @@ impl From<UpdateData> for proto::MakeTableData {
             UpdateData::JsonColumns(x) => make_table_data::Data::FromCols(x),
         };
 
-        MakeTableData { data: Some(data) }
+        MakeTableData {
+            data: Some(data),
+            ..MakeTableData::default()
+        }
     }
 }
@@ -0,0 +1,89 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+//! A minimal `strftime` for formatting Perspective's `date` and `datetime`
+//! values (milliseconds since the Unix epoch, UTC) during export, without
+//! depending on a full date/time library, as well as `duration` values
+//! (milliseconds).
+
+use std::fmt::Write;
+
+/// Convert days since 1970-01-01 to a `(year, month, day)` civil date, per
+/// Howard Hinnant's `civil_from_days` algorithm.
+fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let z = days + 719468;
+    let era = z.div_euclid(146097);
+    let doe = z.rem_euclid(146097);
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = (doy - (153 * mp + 2) / 5 + 1) as u32;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+    let year = yoe + era * 400 + i64::from(month <= 2);
+    (year, month, day)
+}
+
+/// Format `ms` (milliseconds since the Unix epoch, UTC) with a `strftime`
+/// style `pattern`. Supports `%Y`, `%m`, `%d`, `%H`, `%M`, `%S`, `%f`
+/// (milliseconds), `%s` (seconds since the epoch) and `%%`; other
+/// specifiers are copied to the output unchanged.
+pub(crate) fn format_timestamp(ms: i64, pattern: &str) -> String {
+    let days = ms.div_euclid(86_400_000);
+    let ms_of_day = ms.rem_euclid(86_400_000);
+    let (year, month, day) = civil_from_days(days);
+    let (hour, minute) = (ms_of_day / 3_600_000, (ms_of_day / 60_000) % 60);
+    let (second, millis) = ((ms_of_day / 1000) % 60, ms_of_day % 1000);
+    let mut out = String::with_capacity(pattern.len() + 8);
+    let mut chars = pattern.chars();
+    while let Some(c) = chars.next() {
+        if c != '%' {
+            out.push(c);
+            continue;
+        }
+
+        let _ = match chars.next() {
+            Some('Y') => write!(out, "{:04}", year),
+            Some('m') => write!(out, "{:02}", month),
+            Some('d') => write!(out, "{:02}", day),
+            Some('H') => write!(out, "{:02}", hour),
+            Some('M') => write!(out, "{:02}", minute),
+            Some('S') => write!(out, "{:02}", second),
+            Some('f') => write!(out, "{:03}", millis),
+            Some('s') => write!(out, "{}", ms.div_euclid(1000)),
+            Some('%') => write!(out, "%"),
+            Some(x) => write!(out, "%{}", x),
+            None => write!(out, "%"),
+        };
+    }
+
+    out
+}
+
+/// Format a `duration` of `ms` milliseconds as `[-][Nd ]HH:MM:SS[.fff]`, the
+/// same format the engine uses when exporting formatted durations.
+pub(crate) fn format_duration(ms: i64) -> String {
+    let sign = if ms < 0 { "-" } else { "" };
+    let ms = ms.unsigned_abs();
+    let (days, hours) = (ms / 86_400_000, (ms / 3_600_000) % 24);
+    let (minutes, seconds, millis) = ((ms / 60_000) % 60, (ms / 1000) % 60, ms % 1000);
+    let mut out = sign.to_owned();
+    if days > 0 {
+        let _ = write!(out, "{}d ", days);
+    }
+
+    let _ = write!(out, "{:02}:{:02}:{:02}", hours, minutes, seconds);
+    if millis > 0 {
+        let _ = write!(out, ".{:03}", millis);
+    }
+
+    out
+}
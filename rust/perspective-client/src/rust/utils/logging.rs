@@ -16,7 +16,8 @@ use crate::proto::make_table_data::Data;
 use crate::proto::request::ClientReq;
 use crate::proto::response::ClientResp;
 use crate::proto::{
-    MakeTableData, MakeTableReq, Request, Response, TableUpdateReq, ViewToColumnsStringResp,
+    MakeTableData, MakeTableReq, Profile, Request, Response, ServerDiagnosticsResp,
+    TableIngestArrowReq, TableUpdateReq, ViewToColumnsStringResp,
 };
 
 fn replace(x: Data) -> Data {
@@ -41,9 +42,12 @@ impl std::fmt::Display for Request {
                     Some(ClientReq::MakeTableReq(MakeTableReq {
                         ref options,
                         data:
-                            Some(MakeTableData {
-                                data: Some(ref data),
-                            }),
+                            Some(
+                                ref make_table_data @ MakeTableData {
+                                    data: Some(ref data),
+                                    ..
+                                },
+                            ),
                     })),
                 ..
             } => Request {
@@ -51,6 +55,7 @@ impl std::fmt::Display for Request {
                     options: options.clone(),
                     data: Some(MakeTableData {
                         data: Some(replace(data.clone())),
+                        ..make_table_data.clone()
                     }),
                 })),
                 ..msg.clone()
@@ -60,9 +65,12 @@ impl std::fmt::Display for Request {
                     Some(ClientReq::TableUpdateReq(TableUpdateReq {
                         port_id,
                         data:
-                            Some(MakeTableData {
-                                data: Some(ref data),
-                            }),
+                            Some(
+                                ref make_table_data @ MakeTableData {
+                                    data: Some(ref data),
+                                    ..
+                                },
+                            ),
                     })),
                 ..
             } => Request {
@@ -70,10 +78,21 @@ impl std::fmt::Display for Request {
                     port_id,
                     data: Some(MakeTableData {
                         data: Some(replace(data.clone())),
+                        ..make_table_data.clone()
                     }),
                 })),
                 ..msg.clone()
             },
+            Request {
+                client_req: Some(ClientReq::TableIngestArrowReq(ref req)),
+                ..
+            } => Request {
+                client_req: Some(ClientReq::TableIngestArrowReq(TableIngestArrowReq {
+                    chunk: "<< redacted >>".to_string().encode_to_vec(),
+                    ..req.clone()
+                })),
+                ..msg.clone()
+            },
             x => x,
         };
 
@@ -100,6 +119,23 @@ impl std::fmt::Display for Response {
                 )),
                 ..msg.clone()
             },
+            Response {
+                client_resp: Some(ClientResp::ServerDiagnosticsResp(ref resp)),
+                ..
+            } => Response {
+                client_resp: Some(ClientResp::ServerDiagnosticsResp(ServerDiagnosticsResp {
+                    profiles: resp
+                        .profiles
+                        .iter()
+                        .map(|profile| Profile {
+                            pprof: "<< redacted >>".to_string().encode_to_vec(),
+                            ..profile.clone()
+                        })
+                        .collect(),
+                    ..resp.clone()
+                })),
+                ..msg.clone()
+            },
             x => x,
         };
 
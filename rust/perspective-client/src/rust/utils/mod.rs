@@ -11,19 +11,24 @@
 // ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
 
 mod clone;
+pub(crate) mod datetime;
 mod logging;
+mod request_name;
 
 #[cfg(test)]
 mod tests;
 
+use std::sync::Arc;
+
 use thiserror::*;
 
 use crate::proto;
 
 #[derive(Error, Debug)]
 pub enum ClientError {
-    #[error("Abort(): {0}")]
-    Internal(String),
+    /// `request_id` is empty for errors raised by the client itself.
+    #[error("Abort(): {message}")]
+    Internal { message: String, request_id: String },
 
     #[error("Client not yet initialized")]
     NotInitialized,
@@ -37,6 +42,9 @@ pub enum ClientError {
     #[error("Bad string")]
     Utf8(#[from] std::str::Utf8Error),
 
+    #[error("IO error: {0}")]
+    Io(#[from] std::io::Error),
+
     #[error("Undecipherable server message {0:?}")]
     DecodeError(#[from] prost::DecodeError),
 
@@ -49,16 +57,168 @@ pub enum ClientError {
     #[error("Can't use both `limit` and `index` arguments")]
     BadTableOptions,
 
+    #[error("Rate limited: {message}")]
+    RateLimited { message: String, request_id: String },
+
+    #[error("Incompatible server: {message}")]
+    ProtocolMismatch { message: String, request_id: String },
+
+    #[error("Offline buffer full ({0} updates)")]
+    OfflineBufferFull(usize),
+
+    #[error("Unknown server {0:?}")]
+    UnknownServer(String),
+
+    #[error("Abort(): {message}")]
+    ViewConfig { message: String, request_id: String },
+
+    #[error("Abort(): {message}")]
+    MemoryLimit { message: String, request_id: String },
+
+    #[error("Edit rejected: {message}")]
+    EditRejected { message: String, request_id: String },
+
     #[error("External error: {0:?}")]
     ExternalError(#[from] Box<dyn std::error::Error + Send + Sync>),
 }
 
+impl ClientError {
+    /// This caller's copy of an error shared by several callers of a read
+    /// (see `Client::oneshot`), which is the error itself for the last of
+    /// them. Each copy is the same variant, and the `Io` and `ExternalError`
+    /// payloads, which can't be cloned, are shared by the copies.
+    pub(crate) fn from_shared(err: Arc<ClientError>) -> ClientError {
+        let err = match Arc::try_unwrap(err) {
+            Ok(err) => return err,
+            Err(err) => err,
+        };
+
+        match &*err {
+            ClientError::Internal {
+                message,
+                request_id,
+            } => ClientError::Internal {
+                message: message.clone(),
+                request_id: request_id.clone(),
+            },
+            ClientError::NotInitialized => ClientError::NotInitialized,
+            ClientError::Unknown(x) => ClientError::Unknown(x.clone()),
+            ClientError::Option => ClientError::Option,
+            ClientError::Utf8(x) => ClientError::Utf8(*x),
+            ClientError::Io(x) => {
+                ClientError::Io(std::io::Error::new(x.kind(), SharedSource(err.clone())))
+            },
+            ClientError::DecodeError(x) => ClientError::DecodeError(x.clone()),
+            ClientError::ResponseFailed(x) => ClientError::ResponseFailed(x.clone()),
+            ClientError::NotImplemented(x) => ClientError::NotImplemented(*x),
+            ClientError::BadTableOptions => ClientError::BadTableOptions,
+            ClientError::RateLimited {
+                message,
+                request_id,
+            } => ClientError::RateLimited {
+                message: message.clone(),
+                request_id: request_id.clone(),
+            },
+            ClientError::ProtocolMismatch {
+                message,
+                request_id,
+            } => ClientError::ProtocolMismatch {
+                message: message.clone(),
+                request_id: request_id.clone(),
+            },
+            ClientError::OfflineBufferFull(x) => ClientError::OfflineBufferFull(*x),
+            ClientError::UnknownServer(x) => ClientError::UnknownServer(x.clone()),
+            ClientError::ViewConfig {
+                message,
+                request_id,
+            } => ClientError::ViewConfig {
+                message: message.clone(),
+                request_id: request_id.clone(),
+            },
+            ClientError::MemoryLimit {
+                message,
+                request_id,
+            } => ClientError::MemoryLimit {
+                message: message.clone(),
+                request_id: request_id.clone(),
+            },
+            ClientError::EditRejected {
+                message,
+                request_id,
+            } => ClientError::EditRejected {
+                message: message.clone(),
+                request_id: request_id.clone(),
+            },
+            ClientError::ExternalError(_) => {
+                ClientError::ExternalError(Box::new(SharedSource(err.clone())))
+            },
+        }
+    }
+}
+
+/// The `Io` or `ExternalError` payload of a shared [`ClientError`], which
+/// formats as the payload itself.
+struct SharedSource(Arc<ClientError>);
+
+impl SharedSource {
+    fn payload(&self) -> &(dyn std::error::Error + Send + Sync + 'static) {
+        match &*self.0 {
+            ClientError::Io(x) => x,
+            ClientError::ExternalError(x) => x.as_ref(),
+            x => x,
+        }
+    }
+}
+
+impl std::fmt::Debug for SharedSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Debug::fmt(self.payload(), f)
+    }
+}
+
+impl std::fmt::Display for SharedSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.payload(), f)
+    }
+}
+
+impl std::error::Error for SharedSource {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        self.payload().source()
+    }
+}
+
 pub type ClientResult<T> = Result<T, ClientError>;
 
 impl From<proto::response::ClientResp> for ClientError {
     fn from(value: proto::response::ClientResp) -> Self {
         match value {
-            proto::response::ClientResp::ServerError(x) => ClientError::Internal(x.message),
+            proto::response::ClientResp::ServerError(x) => match x.status_code() {
+                proto::StatusCode::RateLimited => ClientError::RateLimited {
+                    message: x.message,
+                    request_id: x.request_id,
+                },
+                proto::StatusCode::ServerError => ClientError::Internal {
+                    message: x.message,
+                    request_id: x.request_id,
+                },
+                proto::StatusCode::ViewConfigError => ClientError::ViewConfig {
+                    message: x.message,
+                    request_id: x.request_id,
+                },
+                proto::StatusCode::ProtocolMismatch => ClientError::ProtocolMismatch {
+                    message: x.message,
+                    request_id: x.request_id,
+                },
+                proto::StatusCode::MemoryLimit => ClientError::MemoryLimit {
+                    message: x.message,
+                    request_id: x.request_id,
+                },
+                proto::StatusCode::EditRejected => ClientError::EditRejected {
+                    message: x.message,
+                    request_id: x.request_id,
+                },
+            },
             x => ClientError::ResponseFailed(Box::new(x)),
         }
     }
@@ -0,0 +1,119 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use crate::proto::request::ClientReq;
+
+impl ClientReq {
+    /// The wire name of this request variant, e.g. `"view_to_arrow_req"`,
+    /// suitable for log output and for keying per-message-type configuration
+    /// (such as rate limits) without matching on the full enum.
+    pub fn name(&self) -> &'static str {
+        match self {
+            ClientReq::ServerHelloReq(_) => "server_hello_req",
+            ClientReq::ServerBulkExportReq(_) => "server_bulk_export_req",
+            ClientReq::GetFeaturesReq(_) => "get_features_req",
+            ClientReq::GetHostedTablesReq(_) => "get_hosted_tables_req",
+            ClientReq::TableMakePortReq(_) => "table_make_port_req",
+            ClientReq::TableMakeViewReq(_) => "table_make_view_req",
+            ClientReq::TableSchemaReq(_) => "table_schema_req",
+            ClientReq::TableSizeReq(_) => "table_size_req",
+            ClientReq::TableValidateExprReq(_) => "table_validate_expr_req",
+            ClientReq::ViewColumnPathsReq(_) => "view_column_paths_req",
+            ClientReq::ViewDeleteReq(_) => "view_delete_req",
+            ClientReq::ViewDimensionsReq(_) => "view_dimensions_req",
+            ClientReq::ViewExpressionSchemaReq(_) => "view_expression_schema_req",
+            ClientReq::ViewGetConfigReq(_) => "view_get_config_req",
+            ClientReq::ViewSchemaReq(_) => "view_schema_req",
+            ClientReq::ViewToArrowReq(_) => "view_to_arrow_req",
+            ClientReq::ServerSystemInfoReq(_) => "server_system_info_req",
+            ClientReq::ViewCollapseReq(_) => "view_collapse_req",
+            ClientReq::ViewExpandReq(_) => "view_expand_req",
+            ClientReq::ViewGetMinMaxReq(_) => "view_get_min_max_req",
+            ClientReq::ViewDownsampleReq(_) => "view_downsample_req",
+            ClientReq::ViewOnUpdateReq(_) => "view_on_update_req",
+            ClientReq::ViewRemoveOnUpdateReq(_) => "view_remove_on_update_req",
+            ClientReq::ViewSetDepthReq(_) => "view_set_depth_req",
+            ClientReq::ViewSetParamsReq(_) => "view_set_params_req",
+            ClientReq::ViewUpdateConfigReq(_) => "view_update_config_req",
+            ClientReq::ViewToColumnsStringReq(_) => "view_to_columns_string_req",
+            ClientReq::ViewToCsvReq(_) => "view_to_csv_req",
+            ClientReq::ViewToRowsStringReq(_) => "view_to_rows_string_req",
+            ClientReq::MakeTableReq(_) => "make_table_req",
+            ClientReq::TableDeleteReq(_) => "table_delete_req",
+            ClientReq::TableOnDeleteReq(_) => "table_on_delete_req",
+            ClientReq::TableRemoveDeleteReq(_) => "table_remove_delete_req",
+            ClientReq::TableRemoveReq(_) => "table_remove_req",
+            ClientReq::TableRemoveWhereReq(_) => "table_remove_where_req",
+            ClientReq::TableReplaceReq(_) => "table_replace_req",
+            ClientReq::TableReplaceAtomicReq(_) => "table_replace_atomic_req",
+            ClientReq::TableUpdateReq(_) => "table_update_req",
+            ClientReq::TableIngestArrowReq(_) => "table_ingest_arrow_req",
+            ClientReq::ViewOnDeleteReq(_) => "view_on_delete_req",
+            ClientReq::ViewRemoveDeleteReq(_) => "view_remove_delete_req",
+            ClientReq::TableRenameReq(_) => "table_rename_req",
+            ClientReq::RemoveHostedTablesUpdateReq(_) => "remove_hosted_tables_update_req",
+            ClientReq::TableTakeWriterReq(_) => "table_take_writer_req",
+            ClientReq::TableCategoriesReq(_) => "table_categories_req",
+            ClientReq::TableDictionaryStatsReq(_) => "table_dictionary_stats_req",
+            ClientReq::TableExpressionCompletionsReq(_) => "table_expression_completions_req",
+            ClientReq::TableSketchesReq(_) => "table_sketches_req",
+            ClientReq::TableStatsReq(_) => "table_stats_req",
+            ClientReq::ServerDiagnosticsReq(_) => "server_diagnostics_req",
+            ClientReq::ViewResyncReq(_) => "view_resync_req",
+            ClientReq::TableFlushReq(_) => "table_flush_req",
+            ClientReq::TableOverlayReq(_) => "table_overlay_req",
+            ClientReq::TableOverlayCommitReq(_) => "table_overlay_commit_req",
+            ClientReq::TableEditCellsReq(_) => "table_edit_cells_req",
+            ClientReq::TableEditLogReq(_) => "table_edit_log_req",
+            ClientReq::TableUndoReq(_) => "table_undo_req",
+            ClientReq::TableRedoReq(_) => "table_redo_req",
+            ClientReq::TableAnnotateReq(_) => "table_annotate_req",
+            ClientReq::TableAnnotationsReq(_) => "table_annotations_req",
+            ClientReq::ViewAnnotationsReq(_) => "view_annotations_req",
+            ClientReq::ServerExpressionsReq(_) => "server_expressions_req",
+        }
+    }
+
+    /// Whether this request only reads state, such that identical requests
+    /// which are in-flight at the same time may share a response.
+    pub fn is_read(&self) -> bool {
+        match self {
+            ClientReq::GetHostedTablesReq(x) => !x.subscribe,
+            ClientReq::GetFeaturesReq(_)
+            | ClientReq::ServerExpressionsReq(_)
+            | ClientReq::ServerSystemInfoReq(_)
+            | ClientReq::TableAnnotationsReq(_)
+            | ClientReq::TableCategoriesReq(_)
+            | ClientReq::TableDictionaryStatsReq(_)
+            | ClientReq::TableEditLogReq(_)
+            | ClientReq::TableExpressionCompletionsReq(_)
+            | ClientReq::TableSchemaReq(_)
+            | ClientReq::TableSizeReq(_)
+            | ClientReq::TableSketchesReq(_)
+            | ClientReq::TableStatsReq(_)
+            | ClientReq::TableValidateExprReq(_)
+            | ClientReq::ViewAnnotationsReq(_)
+            | ClientReq::ViewColumnPathsReq(_)
+            | ClientReq::ViewDimensionsReq(_)
+            | ClientReq::ViewDownsampleReq(_)
+            | ClientReq::ViewExpressionSchemaReq(_)
+            | ClientReq::ViewGetConfigReq(_)
+            | ClientReq::ViewGetMinMaxReq(_)
+            | ClientReq::ViewSchemaReq(_)
+            | ClientReq::ViewToArrowReq(_)
+            | ClientReq::ViewToColumnsStringReq(_)
+            | ClientReq::ViewToCsvReq(_)
+            | ClientReq::ViewToRowsStringReq(_) => true,
+            _ => false,
+        }
+    }
+}
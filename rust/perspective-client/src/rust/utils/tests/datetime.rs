@@ -0,0 +1,51 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use crate::utils::datetime::{format_duration, format_timestamp};
+
+#[test]
+fn test_format_epoch() {
+    assert_eq!(
+        format_timestamp(0, "%Y-%m-%d %H:%M:%S.%f"),
+        "1970-01-01 00:00:00.000"
+    );
+}
+
+#[test]
+fn test_format_leap_day() {
+    let ms = 1_709_210_096_789;
+    assert_eq!(
+        format_timestamp(ms, "%Y-%m-%dT%H:%M:%S.%fZ"),
+        "2024-02-29T12:34:56.789Z"
+    );
+    assert_eq!(format_timestamp(ms, "%d.%m.%Y"), "29.02.2024");
+}
+
+#[test]
+fn test_format_before_epoch() {
+    assert_eq!(
+        format_timestamp(-1, "%Y-%m-%d %H:%M:%S.%f"),
+        "1969-12-31 23:59:59.999"
+    );
+}
+
+#[test]
+fn test_format_passes_through_unknown_specifiers() {
+    assert_eq!(format_timestamp(0, "%Y %q 100%%"), "1970 %q 100%");
+}
+
+#[test]
+fn test_format_duration() {
+    assert_eq!(format_duration(0), "00:00:00");
+    assert_eq!(format_duration(93_784_005), "1d 02:03:04.005");
+    assert_eq!(format_duration(-3_600_000), "-01:00:00");
+}
@@ -22,6 +22,7 @@ use ts_rs::TS;
 use self::view_on_update_req::Mode;
 use crate::assert_view_api;
 use crate::client::Client;
+use crate::json_export::{ColumnGroupPaths, DatetimeFormat, GroupPaths, NullHandling, StructPaths};
 use crate::proto::request::ClientReq;
 use crate::proto::response::ClientResp;
 use crate::proto::*;
@@ -30,6 +31,10 @@ pub use crate::utils::*;
 #[derive(Default, Debug, Deserialize, TS)]
 pub struct OnUpdateOptions {
     pub mode: Option<OnUpdateMode>,
+
+    /// The window of an [`OnUpdateMode::Viewport`] subscription, which
+    /// defaults to the entire [`View`].
+    pub viewport: Option<ViewWindow>,
 }
 
 #[derive(Default, Debug, Deserialize, TS)]
@@ -37,6 +42,11 @@ pub enum OnUpdateMode {
     #[default]
     #[serde(rename = "row")]
     Row,
+
+    /// Only notify when rows enter, leave or change within
+    /// [`OnUpdateOptions::viewport`], with just those rows.
+    #[serde(rename = "viewport")]
+    Viewport,
 }
 
 impl FromStr for OnUpdateMode {
@@ -62,16 +72,16 @@ pub struct Dimensions {
 #[derive(Clone, Debug, Default, Deserialize, Serialize, TS)]
 pub struct ViewWindow {
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub start_row: Option<f32>,
+    pub start_row: Option<f64>,
 
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub start_col: Option<f32>,
+    pub start_col: Option<f64>,
 
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub end_row: Option<f32>,
+    pub end_row: Option<f64>,
 
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub end_col: Option<f32>,
+    pub end_col: Option<f64>,
 
     #[serde(skip_serializing_if = "Option::is_none")]
     pub id: Option<bool>,
@@ -87,14 +97,38 @@ pub struct ViewWindow {
 
     #[serde(skip_serializing_if = "Option::is_none")]
     pub compression: Option<String>,
+
+    /// JSON only: how `date` and `datetime` values are serialized.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub datetime_format: Option<DatetimeFormat>,
+
+    /// JSON only: how null values are serialized.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub null_handling: Option<NullHandling>,
+
+    /// JSON only: how `__ROW_PATH__` is serialized.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub group_paths: Option<GroupPaths>,
+
+    /// JSON only: how columns flattened from struct fields are serialized.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub struct_paths: Option<StructPaths>,
+
+    /// JSON only: whether grouped columns are prefixed by their group path.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub column_group_paths: Option<ColumnGroupPaths>,
+
+    /// JSON only: output names for columns, keyed by column name.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub column_names: Option<HashMap<String, String>>,
 }
 
 impl From<ViewWindow> for ViewPort {
     fn from(window: ViewWindow) -> Self {
         ViewPort {
-            start_row: window.start_row.map(|x| x.floor() as u32),
+            start_row: window.start_row.map(|x| x.floor() as u64),
             start_col: window.start_col.map(|x| x.floor() as u32),
-            end_row: window.end_row.map(|x| x.ceil() as u32),
+            end_row: window.end_row.map(|x| x.ceil() as u64),
             end_col: window.end_col.map(|x| x.ceil() as u32),
         }
     }
@@ -168,7 +202,7 @@ impl View {
     }
 
     #[doc = include_str!("../../docs/view/num_rows.md")]
-    pub async fn num_rows(&self) -> ClientResult<u32> {
+    pub async fn num_rows(&self) -> ClientResult<u64> {
         Ok(self.dimensions().await?.num_view_rows)
     }
 
@@ -207,6 +241,33 @@ impl View {
             leaves_only: window.leaves_only,
         }));
 
+        match self.client.oneshot(&msg).await? {
+            ClientResp::ViewToColumnsStringResp(ViewToColumnsStringResp { json_string }) => {
+                self.apply_json_options(json_string, &window).await
+            },
+            resp => Err(resp.into()),
+        }
+    }
+
+    /// The column-oriented JSON of every column for the rows
+    /// `start_row..end_row`, as [`View::to_columns_string`] returns with a
+    /// default [`ViewWindow`] (unformatted, and without the JSON export
+    /// options applied). Used by the streaming CSV and XLSX exporters, which
+    /// page through the view by `u64` row index.
+    pub(crate) async fn to_columns_string_rows(
+        &self,
+        start_row: u64,
+        end_row: u64,
+    ) -> ClientResult<String> {
+        let msg = self.client_message(ClientReq::ViewToColumnsStringReq(ViewToColumnsStringReq {
+            viewport: Some(ViewPort {
+                start_row: Some(start_row),
+                end_row: Some(end_row),
+                ..ViewPort::default()
+            }),
+            ..ViewToColumnsStringReq::default()
+        }));
+
         match self.client.oneshot(&msg).await? {
             ClientResp::ViewToColumnsStringResp(ViewToColumnsStringResp { json_string }) => {
                 Ok(json_string)
@@ -218,9 +279,9 @@ impl View {
     #[doc = include_str!("../../docs/view/to_json_string.md")]
     pub async fn to_json_string(&self, window: ViewWindow) -> ClientResult<String> {
         let viewport = ViewPort {
-            start_row: window.start_row.map(|x| x.floor() as u32),
+            start_row: window.start_row.map(|x| x.floor() as u64),
             start_col: window.start_col.map(|x| x.floor() as u32),
-            end_row: window.end_row.map(|x| x.ceil() as u32),
+            end_row: window.end_row.map(|x| x.ceil() as u64),
             end_col: window.end_col.map(|x| x.ceil() as u32),
         };
 
@@ -234,7 +295,7 @@ impl View {
 
         match self.client.oneshot(&msg).await? {
             ClientResp::ViewToRowsStringResp(ViewToRowsStringResp { json_string }) => {
-                Ok(json_string)
+                self.apply_json_options(json_string, &window).await
             },
             resp => Err(resp.into()),
         }
@@ -252,6 +313,18 @@ impl View {
         }
     }
 
+    #[doc = include_str!("../../docs/view/annotations.md")]
+    pub async fn annotations(&self, window: ViewWindow) -> ClientResult<Vec<ViewAnnotation>> {
+        let msg = self.client_message(ClientReq::ViewAnnotationsReq(ViewAnnotationsReq {
+            viewport: Some(window.into()),
+        }));
+
+        match self.client.oneshot(&msg).await? {
+            ClientResp::ViewAnnotationsResp(ViewAnnotationsResp { annotations }) => Ok(annotations),
+            resp => Err(resp.into()),
+        }
+    }
+
     #[doc = include_str!("../../docs/view/delete.md")]
     pub async fn delete(&self) -> ClientResult<()> {
         let msg = self.client_message(ClientReq::ViewDeleteReq(ViewDeleteReq {}));
@@ -273,6 +346,25 @@ impl View {
         }
     }
 
+    #[doc = include_str!("../../docs/view/downsample.md")]
+    pub async fn downsample(
+        &self,
+        x_column: String,
+        y_columns: Vec<String>,
+        n_points: u32,
+    ) -> ClientResult<HashMap<String, view_downsample_resp::Series>> {
+        let msg = self.client_message(ClientReq::ViewDownsampleReq(ViewDownsampleReq {
+            x_column,
+            y_columns,
+            n_points,
+        }));
+
+        match self.client.oneshot(&msg).await? {
+            ClientResp::ViewDownsampleResp(ViewDownsampleResp { series }) => Ok(series),
+            resp => Err(resp.into()),
+        }
+    }
+
     /// This is used when constructing a [`Table`] from a [`View`].
     /// The callback needs to be async to wire up the views on_update to the
     /// tables.
@@ -297,7 +389,11 @@ impl View {
         };
 
         let msg = self.client_message(ClientReq::ViewOnUpdateReq(ViewOnUpdateReq {
-            mode: options.mode.map(|OnUpdateMode::Row| Mode::Row as i32),
+            mode: options.mode.map(|mode| match mode {
+                OnUpdateMode::Row => Mode::Row as i32,
+                OnUpdateMode::Viewport => Mode::Viewport as i32,
+            }),
+            viewport: options.viewport.map(|x| x.into()),
         }));
 
         self.client.subscribe(&msg, Box::new(callback)).await?;
@@ -317,6 +413,15 @@ impl View {
         }
     }
 
+    #[doc = include_str!("../../docs/view/resync.md")]
+    pub async fn resync(&self, update_id: u32) -> ClientResult<ViewResyncResp> {
+        let msg = self.client_message(ClientReq::ViewResyncReq(ViewResyncReq { id: update_id }));
+        match self.client.oneshot(&msg).await? {
+            ClientResp::ViewResyncResp(resp) => Ok(resp),
+            resp => Err(resp.into()),
+        }
+    }
+
     #[doc = include_str!("../../docs/view/on_delete.md")]
     pub async fn on_delete(
         &self,
@@ -348,7 +453,7 @@ impl View {
     }
 
     #[doc = include_str!("../../docs/view/collapse.md")]
-    pub async fn collapse(&self, row_index: u32) -> ClientResult<u32> {
+    pub async fn collapse(&self, row_index: u64) -> ClientResult<u32> {
         let msg = self.client_message(ClientReq::ViewCollapseReq(ViewCollapseReq { row_index }));
         match self.client.oneshot(&msg).await? {
             ClientResp::ViewCollapseResp(ViewCollapseResp { num_changed }) => Ok(num_changed),
@@ -357,7 +462,7 @@ impl View {
     }
 
     #[doc = include_str!("../../docs/view/expand.md")]
-    pub async fn expand(&self, row_index: u32) -> ClientResult<u32> {
+    pub async fn expand(&self, row_index: u64) -> ClientResult<u32> {
         let msg = self.client_message(ClientReq::ViewExpandReq(ViewExpandReq { row_index }));
         match self.client.oneshot(&msg).await? {
             ClientResp::ViewExpandResp(ViewExpandResp { num_changed }) => Ok(num_changed),
@@ -373,4 +478,33 @@ impl View {
             resp => Err(resp.into()),
         }
     }
+
+    #[doc = include_str!("../../docs/view/set_params.md")]
+    pub async fn set_params(
+        &self,
+        params: HashMap<String, crate::config::Scalar>,
+    ) -> ClientResult<()> {
+        let msg = self.client_message(ClientReq::ViewSetParamsReq(ViewSetParamsReq {
+            params: params.into_iter().map(|(x, y)| (x, y.into())).collect(),
+        }));
+
+        match self.client.oneshot(&msg).await? {
+            ClientResp::ViewSetParamsResp(_) => Ok(()),
+            resp => Err(resp.into()),
+        }
+    }
+
+    #[doc = include_str!("../../docs/view/update_config.md")]
+    pub async fn update_config(&self, update: crate::config::ViewConfigUpdate) -> ClientResult<()> {
+        let fields = update.field_names();
+        let msg = self.client_message(ClientReq::ViewUpdateConfigReq(ViewUpdateConfigReq {
+            config: Some(update.into()),
+            fields,
+        }));
+
+        match self.client.oneshot(&msg).await? {
+            ClientResp::ViewUpdateConfigResp(_) => Ok(()),
+            resp => Err(resp.into()),
+        }
+    }
 }
@@ -0,0 +1,166 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use std::collections::HashMap;
+
+use rust_xlsxwriter::{Format, FormatAlign, FormatBorder, Workbook, XlsxError};
+
+use crate::proto::ColumnType;
+use crate::utils::*;
+use crate::view::View;
+
+/// Days between the Excel epoch (1899-12-30) and the Unix epoch.
+const EXCEL_UNIX_EPOCH_DAYS: f64 = 25569.0;
+
+const MS_PER_DAY: f64 = 86_400_000.0;
+
+impl From<XlsxError> for ClientError {
+    fn from(value: XlsxError) -> Self {
+        ClientError::ExternalError(Box::new(value))
+    }
+}
+
+fn number_format(ty: Option<ColumnType>) -> Format {
+    match ty {
+        Some(ColumnType::Integer) => Format::new().set_num_format("#,##0"),
+        Some(ColumnType::Float) => Format::new().set_num_format("#,##0.00"),
+        Some(ColumnType::Date) => Format::new().set_num_format("yyyy-mm-dd"),
+        Some(ColumnType::Datetime) => Format::new().set_num_format("yyyy-mm-dd hh:mm:ss"),
+        Some(ColumnType::Duration) => Format::new().set_num_format("[h]:mm:ss.000"),
+        _ => Format::new(),
+    }
+}
+
+impl View {
+    #[doc = include_str!("../../docs/view/to_xlsx.md")]
+    pub async fn to_xlsx(&self) -> ClientResult<Vec<u8>> {
+        let columns = self.export_columns().await?;
+        let config = self.get_config().await?;
+        let num_rows = self.num_rows().await?;
+        let json = self.to_columns_string_rows(0, num_rows).await?;
+        let mut data: HashMap<String, Vec<serde_json::Value>> = serde_json::from_str(&json)
+            .map_err(|e| ClientError::Unknown(format!("Malformed columns: {}", e)))?;
+
+        let mut workbook = Workbook::new();
+        let sheet = workbook.add_worksheet();
+        let header_format = Format::new()
+            .set_bold()
+            .set_align(FormatAlign::Center)
+            .set_border_bottom(FormatBorder::Thin);
+
+        // Split-by column paths become one header row per level, with runs
+        // of the same group merged into a single cell.
+        let paths = columns
+            .iter()
+            .map(|(name, _)| match name.as_str() {
+                "__ROW_PATH__" => vec![config.group_by.join(" / ")],
+                name => name.split('|').map(|x| x.to_owned()).collect::<Vec<_>>(),
+            })
+            .collect::<Vec<_>>();
+
+        let depth = paths.iter().map(|x| x.len()).max().unwrap_or(1);
+        let label = |idx: usize, level: usize| {
+            let path = &paths[idx];
+            if path.len() == depth {
+                path[level].as_str()
+            } else if level == depth - 1 {
+                path.last().map(|x| x.as_str()).unwrap_or_default()
+            } else {
+                ""
+            }
+        };
+
+        // Cells merge when their paths match up to and including this level.
+        let same_group = |a: usize, b: usize, level: usize| {
+            level + 1 < depth
+                && paths[a].len() == depth
+                && paths[b].len() == depth
+                && paths[a][..=level] == paths[b][..=level]
+        };
+
+        for level in 0..depth {
+            let mut cidx = 0;
+            while cidx < paths.len() {
+                let mut end = cidx;
+                while end + 1 < paths.len() && same_group(cidx, end + 1, level) {
+                    end += 1;
+                }
+
+                let (row, first, last) = (level as u32, cidx as u16, end as u16);
+                let text = label(cidx, level);
+                if end > cidx {
+                    sheet.merge_range(row, first, row, last, text, &header_format)?;
+                } else {
+                    sheet.write_string_with_format(row, first, text, &header_format)?;
+                }
+
+                cidx = end + 1;
+            }
+        }
+
+        let has_row_path = matches!(columns.first(), Some((x, _)) if x == "__ROW_PATH__");
+        sheet.set_freeze_panes(depth as u32, u16::from(has_row_path))?;
+        for (cidx, (name, ty)) in columns.iter().enumerate() {
+            let format = number_format(*ty);
+            let values = data.remove(name).unwrap_or_default();
+            sheet.set_column_width(cidx as u16, if cidx == 0 && has_row_path { 32 } else { 14 })?;
+            for (ridx, value) in values.iter().enumerate() {
+                let row = (depth + ridx) as u32;
+                let col = cidx as u16;
+                match (value, ty) {
+                    (serde_json::Value::Null, _) => {},
+                    (serde_json::Value::Array(path), None) => {
+                        let text = match path.last() {
+                            Some(serde_json::Value::String(x)) => x.clone(),
+                            Some(x) => x.to_string(),
+                            None => "TOTAL".to_owned(),
+                        };
+
+                        let indent = Format::new().set_indent(path.len().min(15) as u8);
+                        sheet.write_string_with_format(row, col, &text, &indent)?;
+                    },
+                    (
+                        serde_json::Value::Number(x),
+                        Some(ColumnType::Date | ColumnType::Datetime),
+                    ) => {
+                        let days = x.as_f64().unwrap_or_default() / MS_PER_DAY;
+                        sheet.write_number_with_format(
+                            row,
+                            col,
+                            days + EXCEL_UNIX_EPOCH_DAYS,
+                            &format,
+                        )?;
+                    },
+                    (serde_json::Value::Number(x), Some(ColumnType::Duration)) => {
+                        let days = x.as_f64().unwrap_or_default() / MS_PER_DAY;
+                        sheet.write_number_with_format(row, col, days, &format)?;
+                    },
+                    (serde_json::Value::Number(x), _) => {
+                        let x = x.as_f64().unwrap_or_default();
+                        sheet.write_number_with_format(row, col, x, &format)?;
+                    },
+                    (serde_json::Value::Bool(x), _) => {
+                        sheet.write_boolean(row, col, *x)?;
+                    },
+                    (serde_json::Value::String(x), _) => {
+                        sheet.write_string(row, col, x)?;
+                    },
+                    (x, _) => {
+                        sheet.write_string(row, col, x.to_string())?;
+                    },
+                }
+            }
+        }
+
+        Ok(workbook.save_to_buffer()?)
+    }
+}
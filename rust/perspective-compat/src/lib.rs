@@ -0,0 +1,162 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+//! Cross-version compatibility tests for Perspective's wire protocol.
+//!
+//! A [`Corpus`] is a recording of the messages a [`Scenario`] exchanged with
+//! a `Server` of some release. A [`Harness`] checks each corpus in both
+//! directions:
+//!
+//! - [`replay_server`] sends the recorded requests to the current `Server`,
+//!   which must respond with the same kinds of messages, i.e. new servers still
+//!   serve old clients.
+//! - [`replay_client`] runs the scenario with the current `Client`, against a
+//!   mock which replays the recorded responses, i.e. new clients can still talk
+//!   to old servers.
+//!
+//! This crate's own corpora, recorded from each release, live in its
+//! `corpora` directory. Downstream projects can [`record`] corpora of their
+//! own scenarios and add them to a [`Harness`].
+//!
+//! ```no_run
+//! # async fn test() {
+//! use perspective::server::Server;
+//! use perspective_compat::Harness;
+//!
+//! let errors = Harness::default().run(&Server::default()).await;
+//! assert!(errors.is_empty(), "{:?}", errors);
+//! # }
+//! ```
+
+mod corpus;
+mod record;
+mod replay;
+mod scenarios;
+
+use std::collections::HashMap;
+
+use perspective::client::ClientError;
+use perspective::server::ServerError;
+
+pub use crate::corpus::{Corpus, Exchange};
+pub use crate::record::record;
+pub use crate::replay::{replay_client, replay_server};
+pub use crate::scenarios::{basic_table, builtin_scenarios, Scenario};
+
+/// The corpora built into this crate, one file per release and scenario.
+const CORPORA: &[&str] = &[include_str!("../corpora/2.10.1/basic_table.json")];
+
+#[derive(Debug, thiserror::Error)]
+pub enum CompatError {
+    #[error("Malformed corpus: {0}")]
+    Corpus(#[from] serde_json::Error),
+
+    #[error("Undecipherable message: {0}")]
+    Decode(#[from] prost::DecodeError),
+
+    #[error("Server error: {0}")]
+    Server(#[from] ServerError),
+
+    #[error("Client error: {0}")]
+    Client(#[from] ClientError),
+
+    #[error("No scenario {scenario:?} for corpus {version}")]
+    UnknownScenario { version: String, scenario: String },
+
+    #[error("{version}/{scenario} request #{exchange}: {message}")]
+    Mismatch {
+        version: String,
+        scenario: String,
+        exchange: usize,
+        message: String,
+    },
+
+    #[error("{version}/{scenario} failed against mock server: {error}")]
+    ScenarioFailed {
+        version: String,
+        scenario: String,
+        error: ClientError,
+    },
+}
+
+/// The corpora built into this crate.
+pub fn builtin_corpora() -> Vec<Corpus> {
+    CORPORA
+        .iter()
+        .map(|x| Corpus::from_json(x).expect("Malformed builtin corpus"))
+        .collect()
+}
+
+/// A matrix of [`Corpus`] recordings and the [`Scenario`]s which generated
+/// them, to check against the current `Server` and `Client`.
+#[derive(Clone)]
+pub struct Harness {
+    corpora: Vec<Corpus>,
+    scenarios: HashMap<String, Scenario>,
+}
+
+impl Default for Harness {
+    /// A [`Harness`] of this crate's built-in corpora.
+    fn default() -> Self {
+        Harness {
+            corpora: builtin_corpora(),
+            scenarios: builtin_scenarios(),
+        }
+    }
+}
+
+impl Harness {
+    /// A [`Harness`] without the built-in corpora.
+    pub fn empty() -> Self {
+        Harness {
+            corpora: vec![],
+            scenarios: HashMap::default(),
+        }
+    }
+
+    pub fn with_corpus(mut self, corpus: Corpus) -> Self {
+        self.corpora.push(corpus);
+        self
+    }
+
+    pub fn with_scenario(mut self, name: &str, scenario: Scenario) -> Self {
+        self.scenarios.insert(name.to_owned(), scenario);
+        self
+    }
+
+    /// Replay every corpus against `server`, and its scenario against a mock
+    /// of the server which recorded it, returning every incompatibility
+    /// found (or none).
+    pub async fn run(&self, server: &perspective::server::Server) -> Vec<CompatError> {
+        let mut errors = vec![];
+        for corpus in &self.corpora {
+            if let Err(err) = replay_server(server, corpus).await {
+                errors.push(err);
+            }
+
+            let Some(scenario) = self.scenarios.get(&corpus.scenario) else {
+                errors.push(CompatError::UnknownScenario {
+                    version: corpus.version.clone(),
+                    scenario: corpus.scenario.clone(),
+                });
+
+                continue;
+            };
+
+            if let Err(err) = replay_client(corpus, scenario).await {
+                errors.push(err);
+            }
+        }
+
+        errors
+    }
+}
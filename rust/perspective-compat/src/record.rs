@@ -0,0 +1,92 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use std::sync::{Arc, Mutex, OnceLock};
+
+use async_lock::RwLock;
+use perspective::client::proto::{Request, Response};
+use perspective::client::Client;
+use perspective::server::{Server, Session};
+use prost::Message;
+
+use crate::{CompatError, Corpus, Exchange, Scenario};
+
+/// Record a [`Corpus`] of every message exchanged while `scenario` runs
+/// against `server`, to be checked into a downstream corpus (or this crate's
+/// `corpora` directory) and replayed against future releases.
+pub async fn record(
+    server: &Server,
+    version: &str,
+    name: &str,
+    scenario: &Scenario,
+) -> Result<Corpus, CompatError> {
+    let exchanges: Arc<Mutex<Vec<(u32, Exchange)>>> = Arc::default();
+    let client: Arc<OnceLock<Client>> = Arc::default();
+    let session = server
+        .new_session_with_callback({
+            let exchanges = exchanges.clone();
+            let client = client.clone();
+            move |msg| {
+                let exchanges = exchanges.clone();
+                let client = client.clone();
+                Box::pin(async move {
+                    let msg_id = Response::decode(msg)?.msg_id;
+                    if let Some((_, exchange)) = exchanges
+                        .lock()
+                        .unwrap()
+                        .iter_mut()
+                        .rev()
+                        .find(|(id, _)| *id == msg_id)
+                    {
+                        exchange.responses.push(msg.to_vec());
+                    }
+
+                    client.get().unwrap().handle_response(msg).await?;
+                    Ok(())
+                })
+            }
+        })
+        .await;
+
+    let session = Arc::new(RwLock::new(Some(session)));
+    let _ = client.set(Client::new_with_callback({
+        let exchanges = exchanges.clone();
+        let session = session.clone();
+        move |msg| {
+            let exchanges = exchanges.clone();
+            let session = session.clone();
+            Box::pin(async move {
+                let msg_id = Request::decode(msg)?.msg_id;
+                exchanges.lock().unwrap().push((msg_id, Exchange {
+                    request: msg.to_vec(),
+                    responses: vec![],
+                }));
+
+                let session = session.read().await;
+                let session: &Session = session.as_ref().ok_or("Session closed")?;
+                session.handle_request(msg).await?;
+                session.poll().await?;
+                Ok(())
+            })
+        }
+    }));
+
+    let result = scenario(client.get().unwrap().clone()).await;
+    session.write().await.take().unwrap().close().await;
+    result?;
+    let exchanges = std::mem::take(&mut *exchanges.lock().unwrap());
+    Ok(Corpus {
+        version: version.to_owned(),
+        scenario: name.to_owned(),
+        exchanges: exchanges.into_iter().map(|(_, x)| x).collect(),
+    })
+}
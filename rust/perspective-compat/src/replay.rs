@@ -0,0 +1,213 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use std::collections::HashMap;
+use std::mem::discriminant;
+use std::sync::{Arc, Mutex, OnceLock};
+
+use perspective::client::proto::request::ClientReq;
+use perspective::client::proto::response::ClientResp;
+use perspective::client::proto::{Request, Response, ServerError, StatusCode};
+use perspective::client::Client;
+use perspective::server::{Server, Session};
+use prost::Message;
+
+use crate::{CompatError, Corpus, Scenario};
+
+/// Replay a [`Corpus`]'s requests against `server`, checking that it
+/// responds to each with the same kinds of messages as the server which
+/// recorded it.
+pub async fn replay_server(server: &Server, corpus: &Corpus) -> Result<(), CompatError> {
+    let responses: Arc<Mutex<Vec<Vec<u8>>>> = Arc::default();
+    let session = server
+        .new_session_with_callback({
+            let responses = responses.clone();
+            move |msg| {
+                responses.lock().unwrap().push(msg.to_vec());
+                Box::pin(async { Ok(()) })
+            }
+        })
+        .await;
+
+    let result = replay_session(&session, &responses, corpus).await;
+    session.close().await;
+    result
+}
+
+async fn replay_session(
+    session: &Session,
+    responses: &Mutex<Vec<Vec<u8>>>,
+    corpus: &Corpus,
+) -> Result<(), CompatError> {
+    for (index, exchange) in corpus.exchanges.iter().enumerate() {
+        let msg_id = Request::decode(exchange.request.as_slice())?.msg_id;
+        session.handle_request(&exchange.request).await?;
+        session.poll().await?;
+        let mut live = vec![];
+        for msg in std::mem::take(&mut *responses.lock().unwrap()) {
+            let resp = Response::decode(msg.as_slice())?;
+            if resp.msg_id == msg_id {
+                live.push(resp);
+            }
+        }
+
+        let recorded = exchange
+            .responses
+            .iter()
+            .map(|x| Response::decode(x.as_slice()))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        compare(&recorded, &live).map_err(|message| CompatError::Mismatch {
+            version: corpus.version.clone(),
+            scenario: corpus.scenario.clone(),
+            exchange: index,
+            message,
+        })?;
+    }
+
+    Ok(())
+}
+
+fn compare(recorded: &[Response], live: &[Response]) -> Result<(), String> {
+    if recorded.len() != live.len() {
+        return Err(format!(
+            "Expected {} responses, got {}",
+            recorded.len(),
+            live.len()
+        ));
+    }
+
+    for (recorded, live) in recorded.iter().zip(live) {
+        match (&recorded.client_resp, &live.client_resp) {
+            (Some(ClientResp::ServerError(_)), Some(ClientResp::ServerError(_))) => {},
+            (_, Some(ClientResp::ServerError(err))) => {
+                return Err(format!("Server error: {}", err.message))
+            },
+            (Some(x), Some(y)) if discriminant(x) == discriminant(y) => {},
+            (x, y) => return Err(format!("Expected {:?}, got {:?}", x, y)),
+        }
+    }
+
+    Ok(())
+}
+
+/// Run `scenario` with the current `Client`, connected to a mock which replays
+/// a [`Corpus`]'s recorded responses, checking that the current `Client` can
+/// still talk to the server which recorded it.
+pub async fn replay_client(corpus: &Corpus, scenario: &Scenario) -> Result<(), CompatError> {
+    let mut exchanges = vec![];
+    for exchange in &corpus.exchanges {
+        let request = Request::decode(exchange.request.as_slice())?;
+        let responses = exchange
+            .responses
+            .iter()
+            .map(|x| Response::decode(x.as_slice()))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        exchanges.push(Some((request, responses)));
+    }
+
+    let mock = Arc::new(Mutex::new(MockServer {
+        exchanges,
+        ids: HashMap::default(),
+    }));
+
+    let client: Arc<OnceLock<Client>> = Arc::default();
+    let _ = client.set(Client::new_with_callback({
+        let client = client.clone();
+        move |msg| {
+            let mock = mock.clone();
+            let client = client.clone();
+            Box::pin(async move {
+                let req = Request::decode(msg)?;
+                let responses = mock.lock().unwrap().respond(&req);
+                for resp in responses {
+                    client
+                        .get()
+                        .unwrap()
+                        .handle_response(&resp.encode_to_vec())
+                        .await?;
+                }
+
+                Ok(())
+            })
+        }
+    }));
+
+    scenario(client.get().unwrap().clone())
+        .await
+        .map_err(|error| CompatError::ScenarioFailed {
+            version: corpus.version.clone(),
+            scenario: corpus.scenario.clone(),
+            error,
+        })
+}
+
+/// Responds to each request with the recorded responses to the first unused
+/// recorded request of the same type, and with an error (as a server which
+/// predates the request type would) if there is none.
+struct MockServer {
+    exchanges: Vec<Option<(Request, Vec<Response>)>>,
+
+    /// Client-generated ids (e.g. of `View`s) in the corpus, mapped to those
+    /// generated by the live `Client`.
+    ids: HashMap<String, String>,
+}
+
+impl MockServer {
+    fn respond(&mut self, req: &Request) -> Vec<Response> {
+        let name = req.client_req.as_ref().map(ClientReq::name);
+        let exchange = self.exchanges.iter_mut().find(|x| {
+            x.as_ref().is_some_and(|(recorded, _)| {
+                recorded.client_req.as_ref().map(ClientReq::name) == name
+            })
+        });
+
+        let Some((recorded, responses)) = exchange.and_then(Option::take) else {
+            return vec![Response {
+                msg_id: req.msg_id,
+                entity_id: req.entity_id.clone(),
+                client_resp: Some(ClientResp::ServerError(ServerError {
+                    message: "Unhandled request type".to_owned(),
+                    status_code: StatusCode::ServerError as i32,
+                    request_id: "".to_owned(),
+                })),
+            }];
+        };
+
+        self.ids
+            .insert(recorded.entity_id.clone(), req.entity_id.clone());
+
+        if let (Some(ClientReq::TableMakeViewReq(x)), Some(ClientReq::TableMakeViewReq(y))) =
+            (&recorded.client_req, &req.client_req)
+        {
+            self.ids.insert(x.view_id.clone(), y.view_id.clone());
+        }
+
+        responses
+            .into_iter()
+            .map(|mut resp| {
+                resp.msg_id = req.msg_id;
+                resp.entity_id = self.live_id(&resp.entity_id);
+                if let Some(ClientResp::TableMakeViewResp(x)) = &mut resp.client_resp {
+                    x.view_id = self.live_id(&x.view_id);
+                }
+
+                resp
+            })
+            .collect()
+    }
+
+    fn live_id(&self, id: &str) -> String {
+        self.ids.get(id).cloned().unwrap_or_else(|| id.to_owned())
+    }
+}
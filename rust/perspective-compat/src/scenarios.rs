@@ -0,0 +1,73 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+//! The client programs [`crate::Corpus`] files are recorded from, which are
+//! re-run against a mock of the recording server to check that the current
+//! `Client` still understands its responses.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use futures::future::BoxFuture;
+use perspective::client::{
+    Client, ClientError, ClientResult, ColumnType, TableInitOptions, UpdateData,
+};
+
+/// A client program, which should fail if the `Server` it is connected to
+/// responds incorrectly.
+pub type Scenario = Arc<dyn Fn(Client) -> BoxFuture<'static, ClientResult<()>> + Send + Sync>;
+
+/// The scenarios which generated the corpora built into this crate, by name.
+pub fn builtin_scenarios() -> HashMap<String, Scenario> {
+    let mut scenarios: HashMap<String, Scenario> = HashMap::new();
+    scenarios.insert("basic_table".to_owned(), Arc::new(basic_table));
+    scenarios
+}
+
+fn expect<T: PartialEq + std::fmt::Debug>(name: &str, actual: T, expected: T) -> ClientResult<()> {
+    if actual == expected {
+        Ok(())
+    } else {
+        Err(ClientError::Unknown(format!(
+            "Expected {} {:?}, got {:?}",
+            name, expected, actual
+        )))
+    }
+}
+
+/// Create a `Table` from CSV, read its metadata and that of a `View`, then
+/// delete both.
+pub fn basic_table(client: Client) -> BoxFuture<'static, ClientResult<()>> {
+    Box::pin(async move {
+        let options = TableInitOptions {
+            name: Some("compat_basic".to_owned()),
+            ..TableInitOptions::default()
+        };
+
+        let csv = UpdateData::Csv("x,y\n1,a\n2,b".to_owned());
+        let table = client.table(csv.into(), options).await?;
+        expect("size", table.size().await?, 2)?;
+        let schema = HashMap::from([
+            ("x".to_owned(), ColumnType::Integer),
+            ("y".to_owned(), ColumnType::String),
+        ]);
+
+        expect("table schema", table.schema().await?, schema.clone())?;
+        let view = table.view(None).await?;
+        let dimensions = view.dimensions().await?;
+        expect("view rows", dimensions.num_view_rows, 2)?;
+        expect("view columns", dimensions.num_view_columns, 2)?;
+        expect("view schema", view.schema().await?, schema)?;
+        view.delete().await?;
+        table.delete().await
+    })
+}
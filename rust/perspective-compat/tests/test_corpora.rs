@@ -0,0 +1,63 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use std::error::Error;
+
+use perspective::client::proto::Request;
+use perspective::server::Server;
+use perspective_compat::*;
+use prost::Message;
+
+#[tokio::test]
+async fn test_builtin_corpora_are_compatible() {
+    let errors = Harness::default().run(&Server::default()).await;
+    assert!(errors.is_empty(), "{:?}", errors);
+}
+
+#[tokio::test]
+async fn test_recorded_corpus_matches_builtin() -> Result<(), Box<dyn Error>> {
+    let scenario = builtin_scenarios().remove("basic_table").unwrap();
+    let recorded = record(&Server::default(), "current", "basic_table", &scenario).await?;
+    let recorded = Corpus::from_json(&recorded.to_json())?;
+    let builtin = builtin_corpora()
+        .into_iter()
+        .find(|x| x.scenario == "basic_table")
+        .unwrap();
+
+    let names = |corpus: &Corpus| {
+        corpus
+            .exchanges
+            .iter()
+            .map(|x| Request::decode(x.request.as_slice()).map(|x| x.client_req.unwrap().name()))
+            .collect::<Result<Vec<_>, _>>()
+    };
+
+    assert_eq!(names(&recorded)?, names(&builtin)?);
+    let errors = Harness::empty()
+        .with_corpus(recorded)
+        .with_scenario("basic_table", scenario)
+        .run(&Server::default())
+        .await;
+
+    assert!(errors.is_empty(), "{:?}", errors);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_missing_response_is_mismatch() {
+    let mut corpus = builtin_corpora().remove(0);
+    corpus.exchanges[1].responses.clear();
+    match replay_server(&Server::default(), &corpus).await {
+        Err(CompatError::Mismatch { exchange, .. }) => assert_eq!(exchange, 1),
+        x => panic!("Expected a mismatch, got {:?}", x),
+    }
+}
@@ -18,6 +18,7 @@
 )]
 #![allow(non_snake_case)]
 
+mod message_port;
 mod table;
 pub mod utils;
 mod view;
@@ -30,6 +31,7 @@ use ts_rs::TS;
 use utils::{ApiResult, LocalPollLoop};
 use wasm_bindgen::prelude::*;
 
+pub use crate::message_port::JsMessagePort;
 pub use crate::table::*;
 use crate::utils::{ApiError, JsValueSerdeExt};
 
@@ -155,10 +157,35 @@ impl JsClient {
         )?)
     }
 
+    #[doc = include_str!("../../docs/client/diagnostics.md")]
+    #[wasm_bindgen]
+    pub async fn diagnostics(&self, clear_slow_ops: Option<bool>) -> ApiResult<JsValue> {
+        let diagnostics = self
+            .client
+            .diagnostics(clear_slow_ops.unwrap_or_default())
+            .await?;
+
+        Ok(JsValue::from_serde_ext(&diagnostics)?)
+    }
+
+    #[doc = include_str!("../../docs/client/take_profiles.md")]
+    #[wasm_bindgen]
+    pub async fn take_profiles(&self) -> ApiResult<JsValue> {
+        let profiles = self.client.take_profiles().await?;
+        Ok(JsValue::from_serde_ext(&profiles)?)
+    }
+
     #[doc = include_str!("../../docs/client/system_info.md")]
     #[wasm_bindgen]
     pub async fn system_info(&self) -> ApiResult<JsValue> {
         let info = self.client.system_info().await?;
         Ok(JsValue::from_serde_ext(&info)?)
     }
+
+    #[doc = include_str!("../../docs/client/server_expressions.md")]
+    #[wasm_bindgen]
+    pub async fn server_expressions(&self) -> ApiResult<JsValue> {
+        let expressions = self.client.server_expressions().await?;
+        Ok(JsValue::from_serde_ext(&expressions)?)
+    }
 }
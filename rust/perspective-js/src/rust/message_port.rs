@@ -0,0 +1,140 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use js_sys::{Array, ArrayBuffer, Function, Reflect, Uint8Array};
+use perspective_client::Client;
+use wasm_bindgen::prelude::*;
+use wasm_bindgen_futures::spawn_local;
+
+use crate::utils::{ApiResult, LocalPollLoop};
+use crate::JsClient;
+
+#[wasm_bindgen]
+extern "C" {
+    /// Any `postMessage` endpoint: a `Worker`, a `SharedWorker` (via its
+    /// `port`), a `MessagePort` or, from inside a worker, its global scope.
+    #[derive(Clone)]
+    #[wasm_bindgen(typescript_type = "Worker | SharedWorker | MessagePort")]
+    pub type JsMessagePort;
+
+    #[wasm_bindgen(method, catch, js_name = postMessage)]
+    fn post_message(this: &JsMessagePort, msg: &JsValue, transfer: &Array) -> Result<(), JsValue>;
+
+    #[wasm_bindgen(method, js_name = addEventListener)]
+    fn add_event_listener(this: &JsMessagePort, name: &str, listener: &Function);
+
+    #[wasm_bindgen(method, js_name = removeEventListener)]
+    fn remove_event_listener(this: &JsMessagePort, name: &str, listener: &Function);
+}
+
+impl JsMessagePort {
+    /// The object to actually `postMessage` to: a `SharedWorker`'s `port`, or
+    /// `self` otherwise. `MessagePort`s only dispatch `message` events to
+    /// `addEventListener` listeners once started, so this starts them too.
+    /// Returns whether the endpoint is a `MessagePort`.
+    fn endpoint(&self) -> ApiResult<(JsMessagePort, bool)> {
+        let port = Reflect::get(self, &"port".into())?;
+        let endpoint = if port.is_object() {
+            port.unchecked_into::<JsMessagePort>()
+        } else {
+            self.clone()
+        };
+
+        let start = Reflect::get(&endpoint, &"start".into())?;
+        let is_port = if let Some(start) = start.dyn_ref::<Function>() {
+            start.call0(&endpoint)?;
+            true
+        } else {
+            false
+        };
+
+        Ok((endpoint, is_port))
+    }
+
+    /// Post `msg` as a fresh `ArrayBuffer` which is transferred rather than
+    /// cloned, so large payloads are not copied again by the structured clone.
+    fn post_buffer(&self, msg: &[u8]) -> Result<JsValue, JsValue> {
+        let buffer = Uint8Array::from(msg).buffer();
+        self.post_message(&buffer, &Array::of1(&buffer))?;
+        Ok(JsValue::UNDEFINED)
+    }
+}
+
+fn call_method(target: &JsValue, name: &str) -> bool {
+    match Reflect::get(target, &name.into()).map(|x| x.dyn_into::<Function>()) {
+        Ok(Ok(f)) => {
+            if let Err(err) = f.call0(target) {
+                web_sys::console::error_1(&err);
+            }
+
+            true
+        },
+        _ => false,
+    }
+}
+
+#[wasm_bindgen]
+impl JsClient {
+    #[doc = include_str!("../../docs/client/from_port.md")]
+    #[wasm_bindgen]
+    pub fn from_port(port: &JsMessagePort, close: Option<Function>) -> ApiResult<JsClient> {
+        let (endpoint, is_port) = port.endpoint()?;
+        let sender = endpoint.clone();
+        let send_loop = LocalPollLoop::new(move |buff: Vec<u8>| sender.post_buffer(&buff));
+        let client = Client::new_with_callback(move |msg| {
+            let task = send_loop.poll(msg.to_vec());
+            Box::pin(async move {
+                task.await;
+                Ok(())
+            })
+        });
+
+        // Other messages (e.g. the engine worker's `init` reply) are not
+        // responses, and are ignored.
+        let receiver = client.clone();
+        let on_message = Closure::<dyn Fn(JsValue)>::new(move |event: JsValue| {
+            let Ok(data) = Reflect::get(&event, &"data".into()) else {
+                return;
+            };
+
+            if data.is_instance_of::<ArrayBuffer>() || data.is_instance_of::<Uint8Array>() {
+                let bytes = Uint8Array::new(&data).to_vec();
+                let client = receiver.clone();
+                spawn_local(async move {
+                    if let Err(err) = client.handle_response(&bytes).await {
+                        tracing::error!("Failed to handle response: {}", err);
+                    }
+                });
+            }
+        })
+        .into_js_value()
+        .unchecked_into::<Function>();
+
+        endpoint.add_event_listener("message", &on_message);
+        let close = close.unwrap_or_else(|| {
+            let port = port.clone();
+            Closure::<dyn Fn()>::new(move || {
+                endpoint.remove_event_listener("message", &on_message);
+                if !call_method(&port, "terminate") && is_port {
+                    call_method(&endpoint, "close");
+                }
+            })
+            .into_js_value()
+            .unchecked_into()
+        });
+
+        Ok(JsClient {
+            close: Some(close),
+            client,
+        })
+    }
+}
@@ -189,6 +189,67 @@ impl JsTable {
         Ok(JsValue::from_serde_ext(&schema)?)
     }
 
+    #[doc = include_str!("../../docs/table/categories.md")]
+    #[wasm_bindgen]
+    pub async fn categories(&self) -> ApiResult<JsValue> {
+        let categories = self.0.categories().await?;
+        Ok(JsValue::from_serde_ext(&categories)?)
+    }
+
+    #[doc = include_str!("../../docs/table/dictionary_stats.md")]
+    #[wasm_bindgen]
+    pub async fn dictionary_stats(&self) -> ApiResult<JsValue> {
+        let stats = self.0.dictionary_stats().await?;
+        Ok(JsValue::from_serde_ext(&stats)?)
+    }
+
+    #[doc = include_str!("../../docs/table/column_hints.md")]
+    #[wasm_bindgen]
+    pub async fn column_hints(&self) -> ApiResult<JsValue> {
+        let hints = self.0.column_hints().await?;
+        Ok(JsValue::from_serde_ext(&hints)?)
+    }
+
+    #[doc = include_str!("../../docs/table/virtual_columns.md")]
+    #[wasm_bindgen]
+    pub async fn virtual_columns(&self) -> ApiResult<JsValue> {
+        let virtual_columns = self.0.virtual_columns().await?;
+        Ok(JsValue::from_serde_ext(&virtual_columns)?)
+    }
+
+    #[doc = include_str!("../../docs/table/stats.md")]
+    #[wasm_bindgen]
+    pub async fn stats(&self, interval_ms: Option<f64>) -> ApiResult<JsValue> {
+        let stats = self.0.stats(interval_ms.map(|x| x as u64)).await?;
+        Ok(JsValue::from_serde_ext(&stats)?)
+    }
+
+    #[doc = include_str!("../../docs/table/overlay.md")]
+    #[wasm_bindgen]
+    pub async fn overlay(&self) -> ApiResult<JsTable> {
+        Ok(JsTable(self.0.overlay().await?))
+    }
+
+    #[doc = include_str!("../../docs/table/commit_overlay.md")]
+    #[wasm_bindgen]
+    pub async fn commit_overlay(&self) -> ApiResult<u32> {
+        Ok(self.0.commit_overlay().await?)
+    }
+
+    #[doc = include_str!("../../docs/table/sketches.md")]
+    #[wasm_bindgen]
+    pub async fn sketches(&self) -> ApiResult<JsValue> {
+        let sketches = self.0.sketches().await?;
+        Ok(JsValue::from_serde_ext(&sketches)?)
+    }
+
+    #[doc = include_str!("../../docs/table/expression_completions.md")]
+    #[wasm_bindgen]
+    pub async fn expression_completions(&self) -> ApiResult<JsValue> {
+        let completions = self.0.expression_completions().await?;
+        Ok(JsValue::from_serde_ext(&completions)?)
+    }
+
     #[doc = include_str!("../../docs/table/columns.md")]
     #[wasm_bindgen]
     pub async fn columns(&self) -> ApiResult<JsValue> {
@@ -198,8 +259,19 @@ impl JsTable {
 
     #[doc = include_str!("../../docs/table/make_port.md")]
     #[wasm_bindgen]
-    pub async fn make_port(&self) -> ApiResult<i32> {
-        Ok(self.0.make_port().await?)
+    pub async fn make_port(&self, name: Option<String>) -> ApiResult<i32> {
+        let port = self.0.make_port(&name.unwrap_or_default()).await?;
+        Ok(port.id() as i32)
+    }
+
+    #[doc = include_str!("../../docs/table/arrow_ingest.md")]
+    #[wasm_bindgen]
+    pub fn arrow_ingest(&self, options: Option<JsUpdateOptions>) -> ApiResult<JsArrowIngest> {
+        let options = options
+            .into_serde_ext::<Option<UpdateOptions>>()?
+            .unwrap_or_default();
+
+        Ok(JsArrowIngest(self.0.arrow_ingest(options)))
     }
 
     #[doc = include_str!("../../docs/table/on_delete.md")]
@@ -228,6 +300,60 @@ impl JsTable {
         Ok(())
     }
 
+    #[doc = include_str!("../../docs/table/remove_where.md")]
+    #[wasm_bindgen]
+    pub async fn remove_where(&self, filter: &JsValue) -> ApiResult<f64> {
+        let filter = JsValue::into_serde_ext::<Vec<Filter>>(filter.clone())?;
+        Ok(self.0.remove_where(filter).await? as f64)
+    }
+
+    #[doc = include_str!("../../docs/table/edit_cells.md")]
+    #[wasm_bindgen]
+    pub async fn edit_cells(&self, edits: &JsValue, user: Option<String>) -> ApiResult<()> {
+        let edits = JsValue::into_serde_ext::<Vec<CellEdit>>(edits.clone())?;
+        self.0.edit_cells(edits, user).await?;
+        Ok(())
+    }
+
+    #[doc = include_str!("../../docs/table/edit_log.md")]
+    #[wasm_bindgen]
+    pub async fn edit_log(&self, limit: Option<u32>) -> ApiResult<JsValue> {
+        let records = self.0.edit_log(limit).await?;
+        Ok(JsValue::from_serde_ext(&records)?)
+    }
+
+    #[doc = include_str!("../../docs/table/annotate.md")]
+    #[wasm_bindgen]
+    pub async fn annotate(
+        &self,
+        index: String,
+        column: String,
+        text: Option<String>,
+        user: Option<String>,
+    ) -> ApiResult<()> {
+        self.0.annotate(index, column, text, user).await?;
+        Ok(())
+    }
+
+    #[doc = include_str!("../../docs/table/annotations.md")]
+    #[wasm_bindgen]
+    pub async fn annotations(&self) -> ApiResult<JsValue> {
+        let annotations = self.0.annotations().await?;
+        Ok(JsValue::from_serde_ext(&annotations)?)
+    }
+
+    #[doc = include_str!("../../docs/table/undo.md")]
+    #[wasm_bindgen]
+    pub async fn undo(&self, count: Option<u32>) -> ApiResult<u32> {
+        Ok(self.0.undo(count.unwrap_or(1)).await?)
+    }
+
+    #[doc = include_str!("../../docs/table/redo.md")]
+    #[wasm_bindgen]
+    pub async fn redo(&self, count: Option<u32>) -> ApiResult<u32> {
+        Ok(self.0.redo(count.unwrap_or(1)).await?)
+    }
+
     #[doc = include_str!("../../docs/table/replace.md")]
     #[wasm_bindgen]
     pub async fn replace(&self, input: &JsValue) -> ApiResult<()> {
@@ -236,6 +362,14 @@ impl JsTable {
         Ok(())
     }
 
+    #[doc = include_str!("../../docs/table/replace_atomic.md")]
+    #[wasm_bindgen]
+    pub async fn replace_atomic(&self, input: &JsValue) -> ApiResult<()> {
+        let input = UpdateData::from_js_value(input)?;
+        self.0.replace_atomic(input).await?;
+        Ok(())
+    }
+
     #[doc = include_str!("../../docs/table/update.md")]
     #[wasm_bindgen]
     pub async fn update(
@@ -280,3 +414,26 @@ impl JsTable {
         std::ptr::addr_of!(*self)
     }
 }
+
+/// An Arrow IPC stream being written to a `Table` in chunks, created by
+/// `Table::arrow_ingest`.
+#[wasm_bindgen]
+pub struct JsArrowIngest(ArrowIngest);
+
+#[wasm_bindgen]
+impl JsArrowIngest {
+    /// Send the next `chunk` of the stream, returning the number of rows
+    /// decoded from the stream so far.
+    #[wasm_bindgen]
+    pub async fn write(&self, chunk: &Uint8Array) -> ApiResult<f64> {
+        Ok(self.0.write(chunk.to_vec()).await? as f64)
+    }
+
+    /// Send the final `chunk` of the stream, if any, returning the total
+    /// number of rows decoded from it.
+    #[wasm_bindgen]
+    pub async fn finish(&self, chunk: Option<Uint8Array>) -> ApiResult<f64> {
+        let chunk = chunk.map(|x| x.to_vec()).unwrap_or_default();
+        Ok(self.0.clone().finish(chunk).await? as f64)
+    }
+}
@@ -103,11 +103,23 @@ impl JsView {
             .collect::<Result<_, _>>()?)
     }
 
+    #[doc = include_str!("../../docs/view/downsample.md")]
+    #[wasm_bindgen]
+    pub async fn downsample(
+        &self,
+        x_column: String,
+        y_columns: Vec<String>,
+        n_points: u32,
+    ) -> ApiResult<JsValue> {
+        let series = self.0.downsample(x_column, y_columns, n_points).await?;
+        Ok(JsValue::from_serde_ext(&series)?)
+    }
+
     #[doc = include_str!("../../docs/view/num_rows.md")]
     #[wasm_bindgen]
-    pub async fn num_rows(&self) -> ApiResult<i32> {
+    pub async fn num_rows(&self) -> ApiResult<f64> {
         let size = self.0.num_rows().await?;
-        Ok(size as i32)
+        Ok(size as f64)
     }
 
     #[doc = include_str!("../../docs/view/schema.md")]
@@ -164,6 +176,14 @@ impl JsView {
         Ok(self.0.to_csv(window.unwrap_or_default()).await?)
     }
 
+    #[doc = include_str!("../../docs/view/annotations.md")]
+    #[wasm_bindgen]
+    pub async fn annotations(&self, window: Option<JsViewWindow>) -> ApiResult<JsValue> {
+        let window = window.into_serde_ext::<Option<ViewWindow>>()?;
+        let annotations = self.0.annotations(window.unwrap_or_default()).await?;
+        Ok(JsValue::from_serde_ext(&annotations)?)
+    }
+
     #[doc = include_str!("../../docs/view/on_update.md")]
     #[wasm_bindgen]
     pub async fn on_update(
@@ -191,6 +211,13 @@ impl JsView {
         Ok(self.0.remove_update(callback_id).await?)
     }
 
+    #[doc = include_str!("../../docs/view/resync.md")]
+    #[wasm_bindgen]
+    pub async fn resync(&self, callback_id: u32) -> ApiResult<JsValue> {
+        let resp = self.0.resync(callback_id).await?;
+        Ok(JsValue::from_serde_ext(&resp)?)
+    }
+
     #[doc = include_str!("../../docs/view/on_delete.md")]
     #[wasm_bindgen]
     pub async fn on_delete(&self, on_delete: Function) -> ApiResult<u32> {
@@ -219,14 +246,14 @@ impl JsView {
 
     #[doc = include_str!("../../docs/view/collapse.md")]
     #[wasm_bindgen]
-    pub async fn collapse(&self, row_index: u32) -> ApiResult<u32> {
-        Ok(self.0.collapse(row_index).await?)
+    pub async fn collapse(&self, row_index: f64) -> ApiResult<u32> {
+        Ok(self.0.collapse(row_index as u64).await?)
     }
 
     #[doc = include_str!("../../docs/view/expand.md")]
     #[wasm_bindgen]
-    pub async fn expand(&self, row_index: u32) -> ApiResult<u32> {
-        Ok(self.0.expand(row_index).await?)
+    pub async fn expand(&self, row_index: f64) -> ApiResult<u32> {
+        Ok(self.0.expand(row_index as u64).await?)
     }
 
     #[doc = include_str!("../../docs/view/set_depth.md")]
@@ -234,4 +261,18 @@ impl JsView {
     pub async fn set_depth(&self, depth: u32) -> ApiResult<()> {
         Ok(self.0.set_depth(depth).await?)
     }
+
+    #[doc = include_str!("../../docs/view/set_params.md")]
+    #[wasm_bindgen]
+    pub async fn set_params(&self, params: JsValue) -> ApiResult<()> {
+        let params = params.into_serde_ext()?;
+        Ok(self.0.set_params(params).await?)
+    }
+
+    #[doc = include_str!("../../docs/view/update_config.md")]
+    #[wasm_bindgen]
+    pub async fn update_config(&self, config: JsValue) -> ApiResult<()> {
+        let config = config.into_serde_ext()?;
+        Ok(self.0.update_config(config).await?)
+    }
 }
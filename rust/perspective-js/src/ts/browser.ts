@@ -57,26 +57,34 @@ export async function worker(module: Promise<typeof psp>) {
         perspective_wasm_worker(),
     ]);
 
-    const client = new JsClient(
-        (proto: Uint8Array) => {
-            const f = proto.slice().buffer;
-            webworker.postMessage(f, { transfer: [f] });
-        },
-        () => {
-            console.debug("Closing WebWorker");
-            webworker.terminate();
-        }
-    );
-
     await _init(webworker, wasm);
-    webworker.addEventListener("message", (json: MessageEvent<Uint8Array>) => {
-        client.handle_response(json.data);
+    const client = JsClient.from_port(webworker, () => {
+        console.debug("Closing WebWorker");
+        webworker.terminate();
     });
 
     await client.init();
     return client;
 }
 
+/**
+ * Create a new client connected to a Perspective server over a `Worker`,
+ * `SharedWorker` or `MessagePort`, e.g. one end of a `MessageChannel` whose
+ * other end was transferred to a worker hosting the engine.
+ * @param module
+ * @param port
+ * @returns
+ */
+export async function from_port(
+    module: Promise<typeof psp>,
+    port: Worker | SharedWorker | MessagePort
+) {
+    const { JsClient } = await module;
+    const client = JsClient.from_port(port);
+    await client.init();
+    return client;
+}
+
 /**
  * Create a new client connected via WebSocket to a server implemnting the
  * Perspective Protocol.
@@ -113,4 +121,4 @@ export async function websocket(
     return client;
 }
 
-export default { websocket, worker };
+export default { websocket, worker, from_port };
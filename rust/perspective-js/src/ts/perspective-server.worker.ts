@@ -13,22 +13,41 @@
 import { PerspectiveSession, PerspectiveServer } from "./engine.ts";
 import { compile_perspective } from "./emscripten_api.ts";
 
-let server: PerspectiveServer;
-let session: PerspectiveSession;
+let server: Promise<PerspectiveServer> | undefined;
 
-self.addEventListener("message", async (msg) => {
-    if (msg.data.cmd === "init") {
-        const id = msg.data.id;
-        const module = await compile_perspective(msg.data.args[0]);
-        server = new PerspectiveServer(module);
-        session = server.make_session(async (resp) => {
-            const f = resp.slice().buffer;
-            self.postMessage(f, { transfer: [f] });
-        });
+/**
+ * Serve a session over `port`, compiling the engine from the first `init`
+ * message. A `SharedWorker` serves each connecting port, all sharing one
+ * `PerspectiveServer`.
+ */
+function serve(port: MessagePort) {
+    let session: PerspectiveSession;
+    port.addEventListener("message", async (msg) => {
+        if (msg.data.cmd === "init") {
+            const id = msg.data.id;
+            server ??= compile_perspective(msg.data.args[0]).then(
+                (module) => new PerspectiveServer(module)
+            );
 
-        self.postMessage({ id });
-    } else {
-        session.handle_request(new Uint8Array(msg.data));
-        setTimeout(() => session.poll());
-    }
-});
+            session = (await server).make_session(async (resp) => {
+                const f = resp.slice().buffer;
+                port.postMessage(f, { transfer: [f] });
+            });
+
+            port.postMessage({ id });
+        } else {
+            session.handle_request(new Uint8Array(msg.data));
+            setTimeout(() => session.poll());
+        }
+    });
+}
+
+if ("onconnect" in self) {
+    self.addEventListener("connect", (event) => {
+        const port = (event as MessageEvent).ports[0];
+        serve(port);
+        port.start();
+    });
+} else {
+    serve(self as unknown as MessagePort);
+}
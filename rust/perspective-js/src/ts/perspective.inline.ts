@@ -30,4 +30,8 @@ export async function worker() {
     return await api.worker.call(undefined, Promise.resolve(wasm_module));
 }
 
-export default { websocket, worker };
+export async function from_port(port: Worker | SharedWorker | MessagePort) {
+    return await api.from_port(Promise.resolve(wasm_module), port);
+}
+
+export default { websocket, worker, from_port };
@@ -281,9 +281,23 @@ export async function websocket(
     return client;
 }
 
+/**
+ * Create a new client connected to a Perspective server over a
+ * `MessagePort`, e.g. one end of a `MessageChannel` whose other end is served
+ * by a `worker_threads` worker.
+ * @param port
+ * @returns
+ */
+export async function from_port(port: MessagePort) {
+    const client = perspective_client.JsClient.from_port(port);
+    await client.init();
+    return client;
+}
+
 export default {
     table,
     websocket,
+    from_port,
     get_hosted_table_names,
     system_info,
     WebSocketServer,
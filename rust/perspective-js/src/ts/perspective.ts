@@ -45,4 +45,9 @@ export async function worker() {
     return await api.worker.call(undefined, wasm_module);
 }
 
-export default { websocket, worker };
+export async function from_port(port: Worker | SharedWorker | MessagePort) {
+    const wasm_module = get_module();
+    return await api.from_port(wasm_module, port);
+}
+
+export default { websocket, worker, from_port };
@@ -41,4 +41,37 @@ test.describe("JSON", function () {
             ]);
         });
     });
+
+    test.describe("Column-oriented JSON with a schema", function () {
+        test("schema overrides inferred column types", async function () {
+            const table = await perspective.table(
+                { a: ["1", "2"], b: ["2024-01-01", "x"], c: [1, 2] },
+                { schema: { a: "integer", b: "string" } }
+            );
+
+            expect(await table.schema()).toEqual({
+                a: "integer",
+                b: "string",
+                c: "integer",
+            });
+
+            const view = await table.view();
+            expect(await view.to_columns()).toEqual({
+                a: [1, 2],
+                b: ["2024-01-01", "x"],
+                c: [1, 2],
+            });
+        });
+
+        test("values are coerced rather than promoted", async function () {
+            const table = await perspective.table(
+                { a: [1, 2.5] },
+                { schema: { a: "integer" } }
+            );
+
+            expect(await table.schema()).toEqual({ a: "integer" });
+            const view = await table.view();
+            expect(await view.to_columns()).toEqual({ a: [1, 2] });
+        });
+    });
 });
@@ -0,0 +1,65 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+import { test, expect } from "@finos/perspective-test";
+import perspective, { make_session } from "@finos/perspective";
+
+/**
+ * Serve a session of the shared engine on `port`, the way the engine worker
+ * does.
+ */
+async function serve(port) {
+    const session = await make_session(async (resp) => {
+        const buffer = resp.slice().buffer;
+        port.postMessage(buffer, [buffer]);
+    });
+
+    port.onmessage = (msg) => {
+        session.handle_request(new Uint8Array(msg.data));
+        setTimeout(() => session.poll());
+    };
+
+    return session;
+}
+
+test.describe("MessagePort transport", function () {
+    test("Client connects over a MessageChannel", async function () {
+        const { port1, port2 } = new MessageChannel();
+        const session = await serve(port1);
+        const client = await perspective.from_port(port2);
+        const table = await client.table("x,y\n1,2\n3,4");
+        const view = await table.view();
+        expect(await view.to_columns()).toEqual({ x: [1, 3], y: [2, 4] });
+        await view.delete();
+        await table.delete();
+        client.terminate();
+        port1.close();
+        session.close();
+    });
+
+    test("on_update callbacks are delivered over the port", async function () {
+        const { port1, port2 } = new MessageChannel();
+        const session = await serve(port1);
+        const client = await perspective.from_port(port2);
+        const table = await client.table("x\n1");
+        const view = await table.view();
+        const updated = new Promise((resolve) => view.on_update(resolve));
+        await table.update("x\n2");
+        await updated;
+        expect(await view.num_rows()).toEqual(2);
+        await view.delete();
+        await table.delete();
+        client.terminate();
+        port1.close();
+        session.close();
+    });
+});
@@ -15,9 +15,16 @@ __all__ = [
     "PySyncClient",
     "PerspectiveError",
     "PerspectivePyError",
+    "ProtocolError",
+    "ViewConfigError",
+    "MemoryLimitError",
+    "RateLimitError",
+    "EditRejectedError",
+    "SessionClosedError",
     "PerspectiveWidget",
     "PerspectiveViewer",
     "PerspectiveTornadoHandler",
+    "PerspectiveServerProcess",
     "Table",
     "PerspectiveManager",
     "set_threadpool_size",
@@ -25,7 +32,16 @@ __all__ = [
     "create_sync_client",
 ]
 
-from .perspective import PySyncClient, PerspectivePyError
+from .perspective import (
+    PySyncClient,
+    PerspectivePyError,
+    ProtocolError,
+    ViewConfigError,
+    MemoryLimitError,
+    RateLimitError,
+    EditRejectedError,
+    SessionClosedError,
+)
 from .core.exception import PerspectiveError
 
 from .legacy import (
@@ -35,6 +51,7 @@ from .legacy import (
     sync_client,
     create_sync_client,
 )
+from .multiprocess import PerspectiveServerProcess
 from .widget import PerspectiveWidget
 from .viewer import PerspectiveViewer
 
@@ -25,3 +25,6 @@ class Session:
 
     def poll(self):
         self._session.poll()
+
+    def close(self):
+        self._session.close()
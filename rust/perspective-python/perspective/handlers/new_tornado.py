@@ -25,6 +25,7 @@ class PerspectiveTornadoHandler(WebSocketHandler):
         self.session = Session(inner)
 
     def on_close(self) -> None:
+        self.session.close()
         del self.session
 
     async def on_message(self, msg: bytes):
@@ -0,0 +1,269 @@
+#  ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+#  ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+#  ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+#  ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+#  ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+#  ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+#  ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+#  ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+#  ┃ This file is part of the Perspective library, distributed under the terms ┃
+#  ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+#  ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+"""Share one Perspective engine between processes.
+
+A single engine process hosts every `Table`, and worker processes (e.g.
+gunicorn workers) attach to it as clients over a Unix socket, instead of each
+loading its own copy of the dataset.
+
+Examples:
+    >>> def load(client):
+    ...     client.table({"a": [1, 2, 3]}, name="shared")
+    >>> engine = PerspectiveServerProcess("/tmp/perspective.sock", setup=load)
+    >>> engine.start()  # e.g. in gunicorn's `on_starting` hook
+    >>> conn = connect("/tmp/perspective.sock")  # in each worker
+    >>> conn.client.open_table("shared").size()
+    3
+"""
+
+import multiprocessing
+import os
+import select
+import socket
+import socketserver
+import struct
+import threading
+import time
+
+from .core.exception import PerspectiveError
+from .perspective import PySyncClient, PySyncServer, SessionClosedError
+
+# Messages are framed by a big-endian length. An empty frame from the engine
+# marks the end of the responses to a request.
+_HEADER = struct.Struct(">I")
+
+
+def _send_frame(sock, msg):
+    sock.sendall(_HEADER.pack(len(msg)) + msg)
+
+
+def _recv_exactly(sock, size):
+    buf = bytearray()
+    while len(buf) < size:
+        chunk = sock.recv(size - len(buf))
+        if not chunk:
+            return None
+        buf += chunk
+    return bytes(buf)
+
+
+def _recv_frame(sock):
+    header = _recv_exactly(sock, _HEADER.size)
+    if header is None:
+        return None
+    (size,) = _HEADER.unpack(header)
+    return _recv_exactly(sock, size)
+
+
+def _local_client(server):
+    """A client of `server` in this process, as `create_sync_client()`."""
+
+    def send_request(msg):
+        session.handle_request(msg)
+        session.poll()
+
+    def send_response(msg):
+        client.handle_response(msg)
+
+    session = server.new_session(send_response)
+    client = PySyncClient(send_request)
+    return client
+
+
+class _SessionHandler(socketserver.BaseRequestHandler):
+    """Serves one worker connection with its own engine session."""
+
+    def setup(self):
+        self._lock = threading.Lock()
+        self._session = self.server.perspective_server.new_session(
+            lambda msg: self._send(msg)
+        )
+
+    def _send(self, msg):
+        # Called from any connection's thread, when its requests update a
+        # `View` this connection subscribes to.
+        with self._lock:
+            try:
+                _send_frame(self.request, msg)
+            except OSError:
+                # The worker disconnected, `finish()` closes its session.
+                pass
+
+    def handle(self):
+        while True:
+            msg = _recv_frame(self.request)
+            if msg is None:
+                return
+            self._session.handle_request(msg)
+            self._session.poll()
+            self._send(b"")
+
+    def finish(self):
+        self._session.close()
+
+
+class _EngineServer(socketserver.ThreadingUnixStreamServer):
+    daemon_threads = True
+
+
+def serve(path, setup=None, ready=None):
+    """Run a Perspective engine in this process, serving clients which
+    `connect()` to the Unix socket at `path`. Blocks until the process exits.
+
+    Args:
+        path (:obj:`str`): the path of the Unix socket, which is replaced if
+            it exists.
+        setup (:obj:`callable`): called with a client of the engine before any
+            worker connects, e.g. to load the tables it hosts.
+        ready (:obj:`multiprocessing.Event`): set once workers may connect.
+    """
+    server = PySyncServer()
+    if os.path.exists(path):
+        os.unlink(path)
+
+    with _EngineServer(path, _SessionHandler) as engine:
+        engine.perspective_server = server
+        if setup is not None:
+            setup(_local_client(server))
+        if ready is not None:
+            ready.set()
+        engine.serve_forever()
+
+
+class Connection(object):
+    """A worker's connection to an engine served by `serve()` or
+    `PerspectiveServerProcess`.
+
+    `client` is used just like a `create_sync_client()` client: each request
+    is sent, and its responses handled, on the calling thread. Messages the
+    engine pushes between requests, e.g. `View.on_update` callbacks caused by
+    other workers' updates, are handled by the next request or by `poll()`.
+    """
+
+    def __init__(self, path):
+        self._sock = socket.socket(socket.AF_UNIX, socket.SOCK_STREAM)
+        self._sock.connect(path)
+        self._lock = threading.RLock()
+
+        # Requests sent and end-of-response markers received, so a request
+        # made from a callback during another request's responses waits for
+        # its own marker, rather than the outer request's.
+        self._sent = 0
+        self._received = 0
+
+        def send_request(msg):
+            with self._lock:
+                _send_frame(self._sock, msg)
+                self._sent += 1
+                expected = self._sent
+                while self._received < expected:
+                    self._handle_frame()
+
+        self.client = PySyncClient(send_request)
+
+    def _handle_frame(self):
+        msg = _recv_frame(self._sock)
+        if msg is None:
+            raise SessionClosedError("Perspective engine closed the connection")
+        elif msg:
+            self.client.handle_response(msg)
+        else:
+            self._received += 1
+
+    def poll(self, timeout=0):
+        """Handle any messages pushed by the engine since the last request,
+        waiting up to `timeout` seconds for the first.
+        """
+        with self._lock:
+            while select.select([self._sock], [], [], timeout)[0]:
+                self._handle_frame()
+                timeout = 0
+
+    def close(self):
+        """Close this connection. The engine deletes the views it created."""
+        self._sock.close()
+
+    def __enter__(self):
+        return self
+
+    def __exit__(self, *args):
+        self.close()
+
+
+def connect(path):
+    """Connect to the Perspective engine serving the Unix socket at `path`.
+
+    Returns:
+        :obj:`Connection`: the connection, whose `client` hosts no data itself.
+    """
+    return Connection(path)
+
+
+class PerspectiveServerProcess(object):
+    """Runs a Perspective engine in a child process via `serve()`, which
+    worker processes `connect()` to.
+
+    Args:
+        path (:obj:`str`): the path of the engine's Unix socket.
+        setup (:obj:`callable`): called in the engine process with a client
+            of the engine, e.g. to load the tables it hosts. Must be picklable
+            for the `"spawn"` start method.
+        context (:obj:`str`): the `multiprocessing` start method, which
+            defaults to the platform's.
+    """
+
+    def __init__(self, path, setup=None, context=None):
+        self.path = path
+        self._setup = setup
+        self._context = multiprocessing.get_context(context)
+        self._process = None
+
+    def start(self, timeout=None):
+        """Start the engine process, returning once workers may connect."""
+        if self._process is not None:
+            raise PerspectiveError("Perspective engine process already started")
+
+        ready = self._context.Event()
+        self._process = self._context.Process(
+            target=serve, args=(self.path, self._setup, ready), daemon=True
+        )
+
+        self._process.start()
+        deadline = None if timeout is None else time.monotonic() + timeout
+        while not ready.wait(0.1):
+            if not self._process.is_alive() or (
+                deadline is not None and time.monotonic() > deadline
+            ):
+                self.shutdown()
+                raise PerspectiveError("Perspective engine process failed to start")
+
+    def connect(self):
+        """Connect to this engine, see `connect()`."""
+        return connect(self.path)
+
+    def shutdown(self):
+        """Stop the engine process, discarding its tables."""
+        if self._process is not None:
+            self._process.terminate()
+            self._process.join()
+            self._process = None
+
+        if os.path.exists(self.path):
+            os.unlink(self.path)
+
+    def __enter__(self):
+        self.start()
+        return self
+
+    def __exit__(self, *args):
+        self.shutdown()
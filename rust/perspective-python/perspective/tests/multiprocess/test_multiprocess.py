@@ -0,0 +1,62 @@
+#  ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+#  ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+#  ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+#  ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+#  ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+#  ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+#  ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+#  ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+#  ┃ This file is part of the Perspective library, distributed under the terms ┃
+#  ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+#  ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+from perspective import PerspectiveError, PerspectiveServerProcess
+from perspective.multiprocess import connect
+from pytest import raises
+
+data = {"a": [1, 2, 3], "b": ["a", "b", "c"]}
+
+
+def load(client):
+    client.table(data, name="shared")
+
+
+def fail(client):
+    raise ValueError("bad setup")
+
+
+class TestMultiprocess(object):
+    def test_workers_share_engine_tables(self, tmp_path):
+        path = str(tmp_path / "engine.sock")
+        with PerspectiveServerProcess(path, setup=load) as engine:
+            with engine.connect() as a, connect(path) as b:
+                assert a.client.get_hosted_table_names() == ["shared"]
+                table_a = a.client.open_table("shared")
+                table_b = b.client.open_table("shared")
+                table_a.update({"a": [4], "b": ["d"]})
+                assert table_b.size() == 4
+
+    def test_worker_tables_are_shared(self, tmp_path):
+        path = str(tmp_path / "engine.sock")
+        with PerspectiveServerProcess(path) as engine:
+            with engine.connect() as a, engine.connect() as b:
+                a.client.table(data, name="from_a")
+                assert b.client.open_table("from_a").size() == 3
+
+    def test_on_update_pushed_between_requests(self, tmp_path):
+        path = str(tmp_path / "engine.sock")
+        with PerspectiveServerProcess(path, setup=load) as engine:
+            with engine.connect() as a, engine.connect() as b:
+                updates = []
+                view = b.client.open_table("shared").view()
+                view.on_update(lambda port_id: updates.append(port_id))
+                a.client.open_table("shared").update({"a": [4], "b": ["d"]})
+                b.poll(timeout=5)
+                assert len(updates) == 1
+                view.delete()
+
+    def test_failed_setup_raises(self, tmp_path):
+        path = str(tmp_path / "engine.sock")
+        engine = PerspectiveServerProcess(path, setup=fail)
+        with raises(PerspectiveError):
+            engine.start()
@@ -0,0 +1,62 @@
+#  ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+#  ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+#  ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+#  ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+#  ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+#  ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+#  ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+#  ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+#  ┃ This file is part of the Perspective library, distributed under the terms ┃
+#  ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+#  ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+import pyarrow as pa
+from perspective import PySyncClient
+from perspective.perspective import PySyncServer
+from pytest import raises
+
+
+def local_client():
+    server = PySyncServer()
+    client = PySyncClient(lambda msg: session.handle_request(msg))
+    session = server.new_session(lambda msg: client.handle_response(msg))
+    return server, client
+
+
+class TestArrowStream(object):
+    def test_host_arrow_stream(self):
+        server, client = local_client()
+        data = pa.table({"a": [1, 2, 3], "b": ["x", "y", "z"]})
+        server.host_arrow_stream("t", data)
+        tbl = client.open_table("t")
+        assert tbl.view().to_columns() == {"a": [1, 2, 3], "b": ["x", "y", "z"]}
+
+    def test_update_arrow_stream_indexed(self):
+        server, client = local_client()
+        data = pa.table({"a": [1, 2], "b": [1.5, 2.5]})
+        server.host_arrow_stream("t", data, index="a")
+        server.update_arrow_stream("t", pa.table({"a": [2, 3], "b": [3.5, 4.5]}))
+        tbl = client.open_table("t")
+        assert tbl.view().to_columns() == {"a": [1, 2, 3], "b": [1.5, 3.5, 4.5]}
+
+    def test_view_to_arrow_stream(self):
+        server, client = local_client()
+        server.host_arrow_stream("t", pa.table({"a": [1, 2, 3], "b": ["x", "y", "z"]}))
+        view = client.open_table("t").view()
+        stream = server.view_to_arrow_stream(view)
+        result = pa.table(stream)
+        assert result.to_pydict() == {"a": [1, 2, 3], "b": ["x", "y", "z"]}
+
+    def test_view_to_arrow_stream_consumed_once(self):
+        server, client = local_client()
+        server.host_arrow_stream("t", pa.table({"a": [1]}))
+        view = client.open_table("t").view()
+        stream = server.view_to_arrow_stream(view)
+        pa.table(stream)
+        with raises(ValueError):
+            pa.table(stream)
+
+    def test_host_arrow_stream_rejects_non_arrow(self):
+        server, _ = local_client()
+        with raises(TypeError):
+            server.host_arrow_stream("t", {"a": [1]})
@@ -11,7 +11,7 @@
 #  ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
 
 from pytest import raises
-from perspective import Table, PerspectivePyError
+from perspective import Table, PerspectivePyError, ProtocolError, ViewConfigError
 
 
 class TestException(object):
@@ -49,3 +49,23 @@ class TestException(object):
             tbl.view(group_by=["b"])
 
         assert str(ex.value) == "Abort(): Invalid column 'b' found in View group_by.\n"
+
+    def test_view_config_error(self):
+        tbl = Table({"a": [1, 2, 3]})
+        with raises(ViewConfigError) as ex:
+            tbl.view(group_by=["b"])
+
+        assert ex.value.message == "Invalid column 'b' found in View group_by.\n"
+        assert str(ex.value) == "Abort(): " + ex.value.message
+        assert ex.value.request_id.startswith("req-")
+
+    def test_error_types_are_distinct(self):
+        tbl = Table({"a": [1, 2, 3]})
+        tbl.view()
+        with raises(PerspectivePyError) as ex:
+            tbl.delete()
+
+        assert not isinstance(ex.value, (ViewConfigError, ProtocolError))
+        assert ex.value.message == "Cannot delete table with views"
+        assert ex.value.request_id.startswith("req-")
+        assert len(ex.value.request_id) > len("req-")
@@ -187,6 +187,12 @@ class TestTableInfer(object):
         tbl = Table(data)
         assert tbl.schema() == {"a": "datetime"}
 
+    def test_table_infer_schema_override(self):
+        data = {"a": ["1", "2"], "b": [1.5, 2.5]}
+        tbl = Table(data, schema={"a": "integer"})
+        assert tbl.schema() == {"a": "integer", "b": "float"}
+        assert tbl.view().to_columns() == {"a": [1, 2], "b": [1.5, 2.5]}
+
     def test_table_datetime_infer_no_false_positive(self):
         data = {"a": [" . - / but clearly not a date"]}
         tbl = Table(data)
@@ -997,3 +997,48 @@ class TestTableNumpy(object):
             "a": [1.100000023841858, None, 2.200000047683716],
             "b": [3.299999952316284, 4.400000095367432, None],
         }
+
+
+class TestTableNumpyBuffer(object):
+    def test_table_structured_array(self):
+        d = np.array([(1.0, 2), (3.0, 4)], dtype=[("x", "<f8"), ("y", "<i8")])
+        table = Table(d)
+        assert table.schema() == {"x": "float", "y": "integer"}
+        assert table.view().to_columns() == {"x": [1.0, 3.0], "y": [2, 4]}
+
+    def test_table_recarray_datetime(self):
+        d = np.array(
+            [
+                (datetime(2019, 7, 11, 8, 30, 29), 2),
+                (datetime(2019, 7, 11, 8, 30, 29), 4),
+            ],
+            dtype=[("x", "datetime64[ms]"), ("y", "<i8")],
+        ).view(np.recarray)
+        table = Table(d)
+        assert table.schema() == {"x": "datetime", "y": "integer"}
+        assert table.size() == 2
+
+    def test_table_2d_array(self):
+        d = np.arange(6, dtype=np.float64).reshape(3, 2)
+        table = Table(d, columns=["x", "y"])
+        assert table.schema() == {"x": "float", "y": "float"}
+        assert table.view().to_columns() == {"x": [0, 2, 4], "y": [1, 3, 5]}
+
+    def test_table_2d_array_requires_columns(self):
+        d = np.arange(6).reshape(3, 2)
+        with raises(ValueError):
+            Table(d)
+
+        with raises(ValueError):
+            Table(d, columns=["x"])
+
+    def test_table_structured_array_rejects_columns(self):
+        d = np.array([(1.0, 2)], dtype=[("x", "<f8"), ("y", "<i8")])
+        with raises(ValueError):
+            Table(d, columns=["a", "b"])
+
+    def test_update_2d_array(self):
+        table = Table({"x": "float", "y": "float"})
+        table.update(np.ones((2, 2)), columns=["x", "y"])
+        table.update(np.zeros((1, 2)), columns=["x", "y"])
+        assert table.view().to_columns() == {"x": [1, 1, 0], "y": [1, 1, 0]}
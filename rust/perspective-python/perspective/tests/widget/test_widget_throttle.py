@@ -0,0 +1,129 @@
+#  ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+#  ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+#  ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+#  ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+#  ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+#  ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+#  ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+#  ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+#  ┃ This file is part of the Perspective library, distributed under the terms ┃
+#  ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+#  ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+from perspective import Table
+from perspective.widget.throttle import WidgetUpdatePusher
+
+
+class FakeLoop(object):
+    """A clock and `call_later` which only advance when told to."""
+
+    def __init__(self):
+        self.now = 0.0
+        self.scheduled = []
+
+    def clock(self):
+        return self.now
+
+    def call_later(self, delay, callback):
+        self.scheduled.append((self.now + delay, callback))
+
+    def advance(self, seconds):
+        self.now += seconds
+        due = [cb for (at, cb) in self.scheduled if at <= self.now]
+        self.scheduled = [(at, cb) for (at, cb) in self.scheduled if at > self.now]
+        for callback in due:
+            callback()
+
+
+class TestWidgetUpdatePusher:
+    def pusher(self, view, throttle=None, viewport=None):
+        loop = FakeLoop()
+        sent = []
+        pusher = WidgetUpdatePusher(
+            view,
+            lambda msg, buffers=None: sent.append((msg, buffers)),
+            throttle=throttle,
+            viewport=viewport,
+            clock=loop.clock,
+            call_later=loop.call_later,
+        )
+
+        return pusher, loop, sent
+
+    def test_unthrottled_pushes_each_update(self):
+        table = Table({"a": [1, 2, 3]})
+        view = table.view()
+        pusher, loop, sent = self.pusher(view)
+        table.update({"a": [4]})
+        table.update({"a": [5]})
+        assert [msg for (msg, _) in sent] == [{"cmd": "update"}] * 2
+        assert [len(buffers) for (_, buffers) in sent] == [1, 1]
+        assert loop.scheduled == []
+        pusher.close()
+        view.delete()
+        table.delete()
+
+    def test_throttle_holds_updates_until_interval_ends(self):
+        table = Table({"a": [1, 2, 3]})
+        view = table.view()
+        pusher, loop, sent = self.pusher(view, throttle=100)
+        table.update({"a": [4]})
+        assert len(sent) == 1
+
+        # Within the interval, updates are held and sent together once.
+        table.update({"a": [5]})
+        table.update({"a": [6]})
+        loop.advance(0.05)
+        assert len(sent) == 1
+        assert len(loop.scheduled) == 1
+        loop.advance(0.05)
+        assert len(sent) == 2
+        assert len(sent[1][1]) == 2
+
+        # Every delta is a readable Arrow of the changed rows.
+        pushed = [Table(delta) for delta in sent[1][1]]
+        assert [t.view().to_columns() for t in pushed] == [{"a": [5]}, {"a": [6]}]
+        pusher.close()
+        view.delete()
+        table.delete()
+
+    def test_viewport_only_conflates_rows_in_view(self):
+        table = Table({"id": [1, 2, 3, 4], "x": ["a", "b", "c", "d"]}, index="id")
+        view = table.view()
+        viewport = {"start_row": 0, "end_row": 2}
+        pusher, loop, sent = self.pusher(view, throttle=100, viewport=viewport)
+
+        # The window's rows are pushed in full when subscribed.
+        assert sent == [
+            (
+                {
+                    "cmd": "viewport",
+                    "num_rows": 2,
+                    "rows": {"0": {"id": 1, "x": "a"}, "1": {"id": 2, "x": "b"}},
+                },
+                None,
+            )
+        ]
+
+        table.update({"id": [1], "x": ["y"]})
+        table.update({"id": [1], "x": ["z"]})
+        table.update({"id": [4], "x": ["w"]})
+        loop.advance(0.1)
+        assert len(sent) == 2
+        assert sent[1][0] == {
+            "cmd": "viewport",
+            "num_rows": 2,
+            "rows": {"0": {"id": 1, "x": "z"}},
+        }
+
+        # Scrolling pushes the new window's rows.
+        pusher.set_viewport({"start_row": 2, "end_row": 4})
+        loop.advance(0.1)
+        assert sent[-1][0]["rows"] == {
+            "0": {"id": 3, "x": "c"},
+            "1": {"id": 4, "x": "w"},
+        }
+
+        pusher.close()
+        view.delete()
+        table.delete()
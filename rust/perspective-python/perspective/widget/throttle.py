@@ -0,0 +1,148 @@
+#  ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+#  ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+#  ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+#  ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+#  ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+#  ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+#  ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+#  ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+#  ┃ This file is part of the Perspective library, distributed under the terms ┃
+#  ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+#  ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+import asyncio
+import json
+import threading
+import time
+
+
+def _call_later(delay, callback):
+    """Run `callback` after `delay` seconds on the running event loop (the
+    kernel's, in Jupyter), or on a timer thread if there is none."""
+    try:
+        asyncio.get_running_loop().call_later(delay, callback)
+    except RuntimeError:
+        threading.Timer(delay, callback).start()
+
+
+class WidgetUpdatePusher(object):
+    """Pushes a `View`'s updates to a widget's front-end, throttled so that a
+    fast-updating `Table` can't flood the Jupyter comm channel.
+
+    At most one message is sent per `throttle` milliseconds; updates which
+    arrive sooner are held and sent together when the interval ends. By
+    default, each update's changed rows are sent as Arrow buffers. With a
+    `viewport` (a `dict` of `start_row` and `end_row`, as reported by the
+    front-end), only rows which enter or change within that window are sent,
+    and held updates are conflated so each row position is sent once, with
+    its latest value.
+
+    Messages are `{"cmd": "update"}` with one Arrow buffer per held update,
+    or `{"cmd": "viewport", "num_rows": ..., "rows": {...}}` with each row
+    keyed by its position relative to the start of the window.
+    """
+
+    def __init__(
+        self,
+        view,
+        send,
+        throttle=None,
+        viewport=None,
+        clock=time.monotonic,
+        call_later=_call_later,
+    ):
+        """Subscribe to `view`'s updates.
+
+        Args:
+            view (:obj:`View`): the view whose updates to push.
+            send (:obj:`callable`): sends a message `dict` and an optional
+                list of binary buffers to the front-end, e.g. `Widget.send`.
+
+        Keyword Arguments:
+            throttle (:obj:`int`): the minimum interval between messages, in
+                milliseconds. Defaults to no throttling.
+            viewport (:obj:`dict`): push only the rows of this window.
+            clock (:obj:`callable`): the current time in seconds.
+            call_later (:obj:`callable`): calls a function after a delay in
+                seconds.
+        """
+        self._view = view
+        self._send = send
+        self._throttle = (throttle or 0) / 1000
+        self._clock = clock
+        self._call_later = call_later
+        self._lock = threading.Lock()
+        self._last_sent = None
+        self._scheduled = False
+        self._deltas = []
+        self._viewport = None
+        self._callback_id = None
+        self.set_viewport(viewport)
+
+    def set_viewport(self, viewport):
+        """Push only the rows of `viewport` from now on, or every changed row
+        if it is `None`. Held updates of the previous viewport are dropped,
+        and the new viewport's rows are pushed in full."""
+        if self._callback_id is not None:
+            self._view.remove_update(self._callback_id)
+
+        with self._lock:
+            self._deltas = []
+            self._viewport = None
+
+        if viewport is None:
+            self._callback_id = self._view.on_update(self._on_update, mode="row")
+        else:
+            self._callback_id = self._view.on_update(
+                self._on_update, mode="viewport", viewport=viewport
+            )
+
+    def _on_update(self, port_id, delta=None):
+        with self._lock:
+            if isinstance(delta, dict):
+                if self._viewport is None:
+                    self._viewport = {"num_rows": 0, "rows": {}}
+
+                self._viewport["num_rows"] = delta["num_rows"]
+                self._viewport["rows"].update(delta["rows"])
+            elif delta is not None:
+                self._deltas.append(delta)
+
+            now = self._clock()
+            if self._last_sent is not None and now - self._last_sent < self._throttle:
+                if not self._scheduled:
+                    self._scheduled = True
+                    wait = self._throttle - (now - self._last_sent)
+                    self._call_later(wait, self.flush)
+
+                return
+
+        self.flush()
+
+    def flush(self):
+        """Send any held updates now."""
+        with self._lock:
+            self._scheduled = False
+            deltas, self._deltas = self._deltas, []
+            viewport, self._viewport = self._viewport, None
+            if not deltas and viewport is None:
+                return
+
+            self._last_sent = self._clock()
+
+        if viewport is not None:
+            rows = viewport["rows"]
+            viewport["rows"] = {
+                str(position): json.loads(rows[position]) for position in sorted(rows)
+            }
+
+            self._send({"cmd": "viewport", **viewport})
+
+        if deltas:
+            self._send({"cmd": "update"}, deltas)
+
+    def close(self):
+        """Stop pushing the view's updates."""
+        if self._callback_id is not None:
+            self._view.remove_update(self._callback_id)
+            self._callback_id = None
@@ -25,6 +25,7 @@ from traitlets import Unicode, observe
 from ..core._version import __version__
 from ..core.exception import PerspectiveError
 from ..viewer import PerspectiveViewer
+from .throttle import WidgetUpdatePusher
 
 
 def _type_to_string(t):
@@ -223,6 +224,8 @@ class PerspectiveWidget(DOMWidget, PerspectiveViewer):
         index=None,
         limit=None,
         server=False,
+        throttle=None,
+        viewport_only=False,
         **kwargs,
     ):
         """Initialize an instance of :class`~perspective.PerspectiveWidget`
@@ -253,6 +256,15 @@ class PerspectiveWidget(DOMWidget, PerspectiveViewer):
                 binary and create the Table in Javascript using a copy of the
                 data. Defaults to False.
 
+            throttle (:obj:`int`): The minimum interval in milliseconds
+                between update messages pushed from the kernel to the
+                front-end. Updates which arrive sooner are held and sent
+                together. Ignored in client mode.
+
+            viewport_only (:obj:`bool`): Push only the rows which change within
+                the viewport the front-end last reported, conflating held
+                updates to each row's latest value. Ignored in client mode.
+
             kwargs (:obj:`dict`): configuration options for the `PerspectiveViewer`,
                 and `Table` constructor if `data` is a dataset.
 
@@ -278,6 +290,12 @@ class PerspectiveWidget(DOMWidget, PerspectiveViewer):
         # will proxy all operations back to the server.
         self.server = server
 
+        # Settings of the `WidgetUpdatePusher` which pushes the table's
+        # updates to the front-end, if either is set.
+        self.throttle = throttle
+        self.viewport_only = viewport_only
+        self._pusher = None
+
         # Pass table load options to the front-end, unless in server mode
         self._options = {}
 
@@ -357,11 +375,28 @@ class PerspectiveWidget(DOMWidget, PerspectiveViewer):
         else:
             # Viewer will ignore **options if `data` is a Table or View.
             super(PerspectiveWidget, self).load(data, **options)
+            self._push_updates()
 
         # Notify front-end of load immediately.
         message = self._make_load_message()
         self.send(message.to_dict())
 
+    def _push_updates(self, viewport=None):
+        """(Re)start pushing the table's updates to the front-end, if
+        `throttle` or `viewport_only` is set."""
+        if self.throttle is None and not self.viewport_only:
+            return
+
+        if self._pusher is not None:
+            self._pusher.close()
+
+        self._pusher = WidgetUpdatePusher(
+            self.table.view(),
+            lambda msg, buffers=None: self.send(msg, buffers=buffers),
+            throttle=self.throttle,
+            viewport=viewport if self.viewport_only else None,
+        )
+
     def update(self, data):
         """Update the widget with new data. If running in client mode, this
         method serializes the data and calls the browser viewer's update
@@ -410,6 +445,10 @@ class PerspectiveWidget(DOMWidget, PerspectiveViewer):
             delete_table (`bool`): whether the underlying `Table` will be
                 deleted. Defaults to True.
         """
+        if self._pusher is not None:
+            self._pusher.close()
+            self._pusher = None
+
         if self.client is False:
             super(PerspectiveWidget, self).delete(delete_table)
         self.post({"cmd": "delete"})
@@ -477,6 +516,10 @@ class PerspectiveWidget(DOMWidget, PerspectiveViewer):
                 # return the dataset or table name to the front-end
                 msg = self._make_load_message()
                 self.send(msg.to_dict())
+            elif parsed["cmd"] == "viewport":
+                # The front-end scrolled, so push the rows now in view.
+                if self._pusher is not None and self.viewport_only:
+                    self._pusher.set_viewport(parsed["viewport"])
             else:
                 # If the message has `binary_length` set, wait for the arrow
                 # and join it with the JSON message.
@@ -53,6 +53,11 @@ impl PyAsyncClient {
         let client = self.0.clone();
         future_into_py(py, async move { client.get_hosted_table_names().await })
     }
+
+    pub fn server_expressions<'a>(&self, py: Python<'a>) -> PyResult<&'a PyAny> {
+        let client = self.0.clone();
+        future_into_py(py, async move { client.server_expressions().await })
+    }
 }
 
 #[pyfunction]
@@ -84,6 +89,64 @@ assert_table_api!(PyAsyncTable);
 
 #[pymethods]
 impl PyAsyncTable {
+    #[doc = include_str!("../../docs/table/categories.md")]
+    pub fn categories<'a>(&self, py: Python<'a>) -> PyResult<&'a PyAny> {
+        let table = self.0.clone();
+        future_into_py(py, async move { table.categories().await })
+    }
+
+    #[doc = include_str!("../../docs/table/dictionary_stats.md")]
+    pub fn dictionary_stats<'a>(&self, py: Python<'a>) -> PyResult<&'a PyAny> {
+        let table = self.0.clone();
+        future_into_py(py, async move { table.dictionary_stats().await })
+    }
+
+    #[doc = include_str!("../../docs/table/column_hints.md")]
+    pub fn column_hints<'a>(&self, py: Python<'a>) -> PyResult<&'a PyAny> {
+        let table = self.0.clone();
+        future_into_py(py, async move { table.column_hints().await })
+    }
+
+    #[doc = include_str!("../../docs/table/virtual_columns.md")]
+    pub fn virtual_columns<'a>(&self, py: Python<'a>) -> PyResult<&'a PyAny> {
+        let table = self.0.clone();
+        future_into_py(py, async move { table.virtual_columns().await })
+    }
+
+    #[doc = include_str!("../../docs/table/stats.md")]
+    #[pyo3(signature = (interval_ms=None))]
+    pub fn stats<'a>(&self, py: Python<'a>, interval_ms: Option<u64>) -> PyResult<&'a PyAny> {
+        let table = self.0.clone();
+        future_into_py(py, async move { table.stats(interval_ms).await })
+    }
+
+    #[doc = include_str!("../../docs/table/overlay.md")]
+    pub fn overlay<'a>(&self, py: Python<'a>) -> PyResult<&'a PyAny> {
+        let table = self.0.clone();
+        future_into_py(py, async move {
+            let overlay = table.overlay().await?;
+            Ok(PyAsyncTable(overlay))
+        })
+    }
+
+    #[doc = include_str!("../../docs/table/commit_overlay.md")]
+    pub fn commit_overlay<'a>(&self, py: Python<'a>) -> PyResult<&'a PyAny> {
+        let table = self.0.clone();
+        future_into_py(py, async move { table.commit_overlay().await })
+    }
+
+    #[doc = include_str!("../../docs/table/sketches.md")]
+    pub fn sketches<'a>(&self, py: Python<'a>) -> PyResult<&'a PyAny> {
+        let table = self.0.clone();
+        future_into_py(py, async move { table.sketches().await })
+    }
+
+    #[doc = include_str!("../../docs/table/expression_completions.md")]
+    pub fn expression_completions<'a>(&self, py: Python<'a>) -> PyResult<&'a PyAny> {
+        let table = self.0.clone();
+        future_into_py(py, async move { table.expression_completions().await })
+    }
+
     #[doc = include_str!("../../docs/table/columns.md")]
     pub fn columns<'a>(&self, py: Python<'a>) -> PyResult<&'a PyAny> {
         let table = self.0.clone();
@@ -142,9 +205,10 @@ impl PyAsyncTable {
     }
 
     #[doc = include_str!("../../docs/table/make_port.md")]
-    pub fn make_port<'a>(&self, py: Python<'a>) -> PyResult<&'a PyAny> {
+    #[pyo3(signature = (name=None))]
+    pub fn make_port<'a>(&self, py: Python<'a>, name: Option<String>) -> PyResult<&'a PyAny> {
         let table = self.0.clone();
-        future_into_py(py, async move { table.make_port().await })
+        future_into_py(py, async move { table.make_port(name).await })
     }
 
     #[doc = include_str!("../../docs/table/on_delete.md")]
@@ -169,12 +233,79 @@ impl PyAsyncTable {
         future_into_py(py, async move { table.remove(input).await })
     }
 
+    #[doc = include_str!("../../docs/table/remove_where.md")]
+    pub fn remove_where<'a>(&self, py: Python<'a>, filter: Py<PyAny>) -> PyResult<&'a PyAny> {
+        let table = self.0.clone();
+        future_into_py(py, async move { table.remove_where(filter).await })
+    }
+
+    #[doc = include_str!("../../docs/table/edit_cells.md")]
+    #[pyo3(signature = (edits, user=None))]
+    pub fn edit_cells<'a>(
+        &self,
+        py: Python<'a>,
+        edits: Py<PyAny>,
+        user: Option<String>,
+    ) -> PyResult<&'a PyAny> {
+        let table = self.0.clone();
+        future_into_py(py, async move { table.edit_cells(edits, user).await })
+    }
+
+    #[doc = include_str!("../../docs/table/edit_log.md")]
+    #[pyo3(signature = (limit=None))]
+    pub fn edit_log<'a>(&self, py: Python<'a>, limit: Option<u32>) -> PyResult<&'a PyAny> {
+        let table = self.0.clone();
+        future_into_py(py, async move { table.edit_log(limit).await })
+    }
+
+    #[doc = include_str!("../../docs/table/annotate.md")]
+    #[pyo3(signature = (index, column, text=None, user=None))]
+    pub fn annotate<'a>(
+        &self,
+        py: Python<'a>,
+        index: String,
+        column: String,
+        text: Option<String>,
+        user: Option<String>,
+    ) -> PyResult<&'a PyAny> {
+        let table = self.0.clone();
+        future_into_py(py, async move {
+            table.annotate(index, column, text, user).await
+        })
+    }
+
+    #[doc = include_str!("../../docs/table/annotations.md")]
+    pub fn annotations<'a>(&self, py: Python<'a>) -> PyResult<&'a PyAny> {
+        let table = self.0.clone();
+        future_into_py(py, async move { table.annotations().await })
+    }
+
+    #[doc = include_str!("../../docs/table/undo.md")]
+    #[pyo3(signature = (count=1))]
+    pub fn undo<'a>(&self, py: Python<'a>, count: u32) -> PyResult<&'a PyAny> {
+        let table = self.0.clone();
+        future_into_py(py, async move { table.undo(count).await })
+    }
+
+    #[doc = include_str!("../../docs/table/redo.md")]
+    #[pyo3(signature = (count=1))]
+    pub fn redo<'a>(&self, py: Python<'a>, count: u32) -> PyResult<&'a PyAny> {
+        let table = self.0.clone();
+        future_into_py(py, async move { table.redo(count).await })
+    }
+
     #[doc = include_str!("../../docs/table/replace.md")]
     pub fn replace<'a>(&self, py: Python<'a>, data: Py<PyAny>) -> PyResult<&'a PyAny> {
         let table = self.0.clone();
         future_into_py(py, async move { table.replace(data).await })
     }
 
+    #[doc = include_str!("../../docs/table/replace_atomic.md")]
+    pub fn replace_atomic<'a>(&self, py: Python<'a>, data: Py<PyAny>) -> PyResult<&'a PyAny> {
+        let table = self.0.clone();
+        future_into_py(py, async move { table.replace_atomic(data).await })
+    }
+
     #[doc = include_str!("../../docs/table/validate_expressions.md")]
     pub fn validate_expressions<'a>(
         &self,
@@ -272,6 +403,39 @@ impl PyAsyncView {
         future_into_py(py, async move { view.to_csv(window).await })
     }
 
+    #[doc = include_str!("../../docs/view/annotations.md")]
+    #[pyo3(signature = (**window))]
+    pub fn annotations<'a>(
+        &self,
+        py: Python<'a>,
+        window: Option<Py<PyDict>>,
+    ) -> PyResult<&'a PyAny> {
+        let view = self.0.clone();
+        future_into_py(py, async move { view.annotations(window).await })
+    }
+
+    #[doc = include_str!("../../docs/view/set_params.md")]
+    #[pyo3(signature = (**params))]
+    pub fn set_params<'a>(
+        &self,
+        py: Python<'a>,
+        params: Option<Py<PyDict>>,
+    ) -> PyResult<&'a PyAny> {
+        let view = self.0.clone();
+        future_into_py(py, async move { view.set_params(params).await })
+    }
+
+    #[doc = include_str!("../../docs/view/update_config.md")]
+    #[pyo3(signature = (**config))]
+    pub fn update_config<'a>(
+        &self,
+        py: Python<'a>,
+        config: Option<Py<PyDict>>,
+    ) -> PyResult<&'a PyAny> {
+        let view = self.0.clone();
+        future_into_py(py, async move { view.update_config(config).await })
+    }
+
     #[doc = include_str!("../../docs/view/to_arrow.md")]
     #[pyo3(signature = (**window))]
     pub fn to_arrow<'a>(&self, py: Python<'a>, window: Option<Py<PyDict>>) -> PyResult<&'a PyAny> {
@@ -309,6 +473,20 @@ impl PyAsyncView {
         future_into_py(py, async move { view.get_min_max(column_name).await })
     }
 
+    #[doc = include_str!("../../docs/view/downsample.md")]
+    pub fn downsample<'a>(
+        &self,
+        py: Python<'a>,
+        x_column: String,
+        y_columns: Vec<String>,
+        n_points: u32,
+    ) -> PyResult<&'a PyAny> {
+        let view = self.0.clone();
+        future_into_py(py, async move {
+            view.downsample(x_column, y_columns, n_points).await
+        })
+    }
+
     #[doc = include_str!("../../docs/view/num_rows.md")]
     pub fn num_rows<'a>(&self, py: Python<'a>) -> PyResult<&'a PyAny> {
         let view = self.0.clone();
@@ -338,14 +516,19 @@ impl PyAsyncView {
     }
 
     #[doc = include_str!("../../docs/view/on_update.md")]
+    #[pyo3(signature = (callback, mode=None, viewport=None))]
     pub fn on_update<'a>(
         &self,
         py: Python<'a>,
         callback: Py<PyFunction>,
         mode: Option<String>,
+        viewport: Option<Py<PyDict>>,
     ) -> PyResult<&'a PyAny> {
         let view = self.0.clone();
-        future_into_py(py, async move { view.on_update(callback, mode).await })
+        future_into_py(
+            py,
+            async move { view.on_update(callback, mode, viewport).await },
+        )
     }
 
     #[doc = include_str!("../../docs/view/remove_update.md")]
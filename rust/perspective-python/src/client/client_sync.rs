@@ -50,7 +50,8 @@ impl PySyncClient {
     }
 
     #[doc = include_str!("../../docs/table.md")]
-    #[pyo3(signature = (input, limit=None, index=None, name=None))]
+    #[pyo3(signature = (input, limit=None, index=None, name=None, columns=None, schema=None))]
+    #[allow(clippy::too_many_arguments)]
     pub fn table(
         &self,
         py: Python<'_>,
@@ -58,9 +59,13 @@ impl PySyncClient {
         limit: Option<u32>,
         index: Option<Py<PyString>>,
         name: Option<Py<PyString>>,
+        columns: Option<Vec<String>>,
+        schema: Option<HashMap<String, String>>,
     ) -> PyResult<PySyncTable> {
         Ok(PySyncTable(
-            self.0.table(input, limit, index, name).py_block_on(py)?,
+            self.0
+                .table(input, limit, index, name, columns, schema)
+                .py_block_on(py)?,
         ))
     }
 
@@ -76,6 +81,11 @@ impl PySyncClient {
         self.0.get_hosted_table_names().block_on()
     }
 
+    #[doc = include_str!("../../docs/client/server_expressions.md")]
+    pub fn server_expressions(&self) -> PyResult<HashMap<String, String>> {
+        self.0.server_expressions().block_on()
+    }
+
     #[doc = include_str!("../../docs/client/set_loop_callback.md")]
     pub fn set_loop_callback(&self, loop_cb: Py<PyFunction>) -> PyResult<()> {
         self.0.set_loop_cb(loop_cb).block_on()
@@ -102,6 +112,52 @@ impl PySyncTable {
         self.0.clear().block_on()
     }
 
+    #[doc = include_str!("../../docs/table/categories.md")]
+    fn categories(&self) -> PyResult<HashMap<String, Vec<String>>> {
+        self.0.categories().block_on()
+    }
+
+    #[doc = include_str!("../../docs/table/dictionary_stats.md")]
+    fn dictionary_stats(&self) -> PyResult<Py<PyAny>> {
+        self.0.dictionary_stats().block_on()
+    }
+
+    #[doc = include_str!("../../docs/table/column_hints.md")]
+    fn column_hints(&self) -> PyResult<Py<PyAny>> {
+        self.0.column_hints().block_on()
+    }
+
+    #[doc = include_str!("../../docs/table/virtual_columns.md")]
+    fn virtual_columns(&self) -> PyResult<HashMap<String, String>> {
+        self.0.virtual_columns().block_on()
+    }
+
+    #[doc = include_str!("../../docs/table/stats.md")]
+    #[pyo3(signature = (interval_ms=None))]
+    fn stats(&self, interval_ms: Option<u64>) -> PyResult<Py<PyAny>> {
+        self.0.stats(interval_ms).block_on()
+    }
+
+    #[doc = include_str!("../../docs/table/overlay.md")]
+    fn overlay(&self) -> PyResult<PySyncTable> {
+        Ok(PySyncTable(self.0.overlay().block_on()?))
+    }
+
+    #[doc = include_str!("../../docs/table/commit_overlay.md")]
+    fn commit_overlay(&self) -> PyResult<u32> {
+        self.0.commit_overlay().block_on()
+    }
+
+    #[doc = include_str!("../../docs/table/sketches.md")]
+    fn sketches(&self) -> PyResult<Py<PyAny>> {
+        self.0.sketches().block_on()
+    }
+
+    #[doc = include_str!("../../docs/table/expression_completions.md")]
+    fn expression_completions(&self) -> PyResult<Py<PyAny>> {
+        self.0.expression_completions().block_on()
+    }
+
     #[doc = include_str!("../../docs/table/columns.md")]
     fn columns(&self) -> PyResult<Vec<String>> {
         self.0.columns().block_on()
@@ -113,9 +169,10 @@ impl PySyncTable {
     }
 
     #[doc = include_str!("../../docs/table/make_port.md")]
-    fn make_port(&self) -> PyResult<i32> {
+    #[pyo3(signature = (name=None))]
+    fn make_port(&self, name: Option<String>) -> PyResult<i32> {
         let table = self.0.clone();
-        table.make_port().block_on()
+        table.make_port(name).block_on()
     }
 
     #[doc = include_str!("../../docs/table/on_delete.md")]
@@ -130,6 +187,52 @@ impl PySyncTable {
         table.remove(input).block_on()
     }
 
+    #[doc = include_str!("../../docs/table/remove_where.md")]
+    pub fn remove_where(&self, filter: Py<PyAny>) -> PyResult<u64> {
+        self.0.remove_where(filter).block_on()
+    }
+
+    #[doc = include_str!("../../docs/table/edit_cells.md")]
+    #[pyo3(signature = (edits, user=None))]
+    pub fn edit_cells(&self, edits: Py<PyAny>, user: Option<String>) -> PyResult<()> {
+        self.0.edit_cells(edits, user).block_on()
+    }
+
+    #[doc = include_str!("../../docs/table/edit_log.md")]
+    #[pyo3(signature = (limit=None))]
+    pub fn edit_log(&self, limit: Option<u32>) -> PyResult<Py<PyAny>> {
+        self.0.edit_log(limit).block_on()
+    }
+
+    #[doc = include_str!("../../docs/table/annotate.md")]
+    #[pyo3(signature = (index, column, text=None, user=None))]
+    pub fn annotate(
+        &self,
+        index: String,
+        column: String,
+        text: Option<String>,
+        user: Option<String>,
+    ) -> PyResult<()> {
+        self.0.annotate(index, column, text, user).block_on()
+    }
+
+    #[doc = include_str!("../../docs/table/annotations.md")]
+    pub fn annotations(&self) -> PyResult<Py<PyAny>> {
+        self.0.annotations().block_on()
+    }
+
+    #[doc = include_str!("../../docs/table/undo.md")]
+    #[pyo3(signature = (count=1))]
+    pub fn undo(&self, count: u32) -> PyResult<u32> {
+        self.0.undo(count).block_on()
+    }
+
+    #[doc = include_str!("../../docs/table/redo.md")]
+    #[pyo3(signature = (count=1))]
+    pub fn redo(&self, count: u32) -> PyResult<u32> {
+        self.0.redo(count).block_on()
+    }
+
     #[doc = include_str!("../../docs/table/remove_delete.md")]
     fn remove_delete(&self, callback: Py<PyFunction>) -> PyResult<()> {
         let table = self.0.clone();
@@ -166,16 +269,24 @@ impl PySyncTable {
         self.0.replace(input).block_on()
     }
 
+    #[doc = include_str!("../../docs/table/replace_atomic.md")]
+    fn replace_atomic(&self, input: Py<PyAny>) -> PyResult<()> {
+        self.0.replace_atomic(input).block_on()
+    }
+
     #[doc = include_str!("../../docs/table/update.md")]
-    #[pyo3(signature = (input, format=None, port_id=None))]
+    #[pyo3(signature = (input, format=None, port_id=None, columns=None))]
     fn update(
         &self,
         py: Python<'_>,
         input: Py<PyAny>,
         format: Option<String>,
         port_id: Option<u32>,
+        columns: Option<Vec<String>>,
     ) -> PyResult<()> {
-        self.0.update(input, format, port_id).py_block_on(py)
+        self.0
+            .update(input, format, port_id, columns)
+            .py_block_on(py)
     }
 }
 
@@ -184,6 +295,12 @@ pub struct PySyncView(PyView);
 
 assert_view_api!(PySyncView);
 
+impl PySyncView {
+    pub(crate) fn name(&self) -> &str {
+        self.0.name()
+    }
+}
+
 #[pymethods]
 impl PySyncView {
     #[doc = include_str!("../../docs/view/column_paths.md")]
@@ -240,6 +357,12 @@ impl PySyncView {
         self.0.to_csv(window).block_on()
     }
 
+    #[doc = include_str!("../../docs/view/annotations.md")]
+    #[pyo3(signature = (**window))]
+    fn annotations(&self, window: Option<Py<PyDict>>) -> PyResult<Py<PyAny>> {
+        self.0.annotations(window).block_on()
+    }
+
     #[doc = include_str!("../../docs/view/to_csv.md")]
     #[pyo3(signature = (**window))]
     fn to_arrow(&self, window: Option<Py<PyDict>>) -> PyResult<Py<PyBytes>> {
@@ -252,15 +375,27 @@ impl PySyncView {
     }
 
     #[doc = include_str!("../../docs/view/expand.md")]
-    fn expand(&self, index: u32) -> PyResult<u32> {
+    fn expand(&self, index: u64) -> PyResult<u32> {
         self.0.expand(index).block_on()
     }
 
     #[doc = include_str!("../../docs/view/collapse.md")]
-    fn collapse(&self, index: u32) -> PyResult<u32> {
+    fn collapse(&self, index: u64) -> PyResult<u32> {
         self.0.collapse(index).block_on()
     }
 
+    #[doc = include_str!("../../docs/view/set_params.md")]
+    #[pyo3(signature = (**params))]
+    fn set_params(&self, params: Option<Py<PyDict>>) -> PyResult<()> {
+        self.0.set_params(params).block_on()
+    }
+
+    #[doc = include_str!("../../docs/view/update_config.md")]
+    #[pyo3(signature = (**config))]
+    fn update_config(&self, config: Option<Py<PyDict>>) -> PyResult<()> {
+        self.0.update_config(config).block_on()
+    }
+
     #[doc = include_str!("../../docs/view/dimensions.md")]
     fn dimensions(&self) -> PyResult<Py<PyAny>> {
         self.0.dimensions().block_on()
@@ -281,8 +416,18 @@ impl PySyncView {
         self.0.get_min_max(column_name).block_on()
     }
 
+    #[doc = include_str!("../../docs/view/downsample.md")]
+    fn downsample(
+        &self,
+        x_column: String,
+        y_columns: Vec<String>,
+        n_points: u32,
+    ) -> PyResult<Py<PyAny>> {
+        self.0.downsample(x_column, y_columns, n_points).block_on()
+    }
+
     #[doc = include_str!("../../docs/view/num_rows.md")]
-    fn num_rows(&self) -> PyResult<u32> {
+    fn num_rows(&self) -> PyResult<u64> {
         self.0.num_rows().block_on()
     }
 
@@ -302,8 +447,14 @@ impl PySyncView {
     }
 
     #[doc = include_str!("../../docs/view/on_update.md")]
-    fn on_update(&self, callback: Py<PyFunction>, mode: Option<String>) -> PyResult<u32> {
-        self.0.on_update(callback, mode).block_on()
+    #[pyo3(signature = (callback, mode=None, viewport=None))]
+    fn on_update(
+        &self,
+        callback: Py<PyFunction>,
+        mode: Option<String>,
+        viewport: Option<Py<PyDict>>,
+    ) -> PyResult<u32> {
+        self.0.on_update(callback, mode, viewport).block_on()
     }
 
     #[doc = include_str!("../../docs/view/remove_update.md")]
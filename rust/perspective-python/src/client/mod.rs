@@ -15,4 +15,7 @@ pub mod client_sync;
 
 mod python;
 
-pub use python::PerspectivePyError;
+pub use python::{
+    EditRejectedError, MemoryLimitError, PerspectivePyError, ProtocolError, RateLimitError,
+    SessionClosedError, ViewConfigError,
+};
@@ -17,15 +17,16 @@ use std::sync::Arc;
 
 use async_lock::RwLock;
 use futures::FutureExt;
-use perspective_client::proto::ViewOnUpdateResp;
+use perspective_client::proto::{ViewOnUpdateResp, ViewportUpdate};
 use perspective_client::{
-    assert_table_api, assert_view_api, clone, Client, ClientError, OnUpdateMode, OnUpdateOptions,
-    Table, TableData, TableInitOptions, UpdateData, UpdateOptions, View, ViewWindow,
+    assert_table_api, assert_view_api, clone, Client, ClientError, ColumnType, OnUpdateMode,
+    OnUpdateOptions, Table, TableData, TableInitOptions, UpdateData, UpdateOptions, View,
+    ViewWindow,
 };
 use pyo3::create_exception;
 use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
-use pyo3::types::{PyBytes, PyDict, PyFunction, PyList, PyString};
+use pyo3::types::{PyBytes, PyDict, PyFunction, PyList, PySlice, PyString};
 use pythonize::depythonize_bound;
 
 #[derive(Clone)]
@@ -37,10 +38,7 @@ pub struct PyClient {
 #[extend::ext]
 pub impl<T> Result<T, ClientError> {
     fn into_pyerr(self) -> PyResult<T> {
-        match self {
-            Ok(x) => Ok(x),
-            Err(x) => Err(PerspectivePyError::new_err(format!("{}", x))),
-        }
+        self.map_err(client_error_to_py)
     }
 }
 
@@ -50,6 +48,75 @@ create_exception!(
     pyo3::exceptions::PyException
 );
 
+create_exception!(perspective, ProtocolError, PerspectivePyError);
+create_exception!(perspective, ViewConfigError, PerspectivePyError);
+create_exception!(perspective, MemoryLimitError, PerspectivePyError);
+create_exception!(perspective, RateLimitError, PerspectivePyError);
+create_exception!(perspective, EditRejectedError, PerspectivePyError);
+create_exception!(perspective, SessionClosedError, PerspectivePyError);
+
+/// Raise each kind of [`ClientError`] as its own subclass of
+/// `PerspectivePyError`, with the error's fields as attributes (`message`, and
+/// `request_id` for errors reported by the server).
+fn client_error_to_py(err: ClientError) -> PyErr {
+    let display = err.to_string();
+    let (py_err, message, request_id) = match err {
+        ClientError::ViewConfig {
+            message,
+            request_id,
+        } => (ViewConfigError::new_err(display), message, request_id),
+        ClientError::MemoryLimit {
+            message,
+            request_id,
+        } => (MemoryLimitError::new_err(display), message, request_id),
+        ClientError::EditRejected {
+            message,
+            request_id,
+        } => (EditRejectedError::new_err(display), message, request_id),
+        ClientError::RateLimited {
+            message,
+            request_id,
+        } => (RateLimitError::new_err(display), message, request_id),
+        ClientError::ProtocolMismatch {
+            message,
+            request_id,
+        } => (ProtocolError::new_err(display), message, request_id),
+        ClientError::Internal {
+            message,
+            request_id,
+        } => (PerspectivePyError::new_err(display), message, request_id),
+        ClientError::DecodeError(_)
+        | ClientError::Utf8(_)
+        | ClientError::ResponseFailed(_)
+        | ClientError::Option => (
+            ProtocolError::new_err(display.clone()),
+            display,
+            String::new(),
+        ),
+        _ => (
+            PerspectivePyError::new_err(display.clone()),
+            display,
+            String::new(),
+        ),
+    };
+
+    // Errors raised by the client itself have no `request_id`.
+    let request_id = Some(request_id).filter(|x| !x.is_empty());
+
+    Python::with_gil(|py| {
+        let value = py_err.value_bound(py);
+        let fields = value
+            .setattr("message", message)
+            .and_then(|_| value.setattr("request_id", request_id));
+
+        if let Err(err) = fields {
+            tracing::warn!("Failed to set exception fields: {}", err);
+        }
+    });
+
+    py_err
+}
+
 #[extend::ext]
 impl UpdateData {
     fn from_py_partial(py: Python<'_>, input: &Py<PyAny>) -> Result<Option<UpdateData>, PyErr> {
@@ -135,6 +202,20 @@ impl TableData {
 
 const PSP_CALLBACK_ID: &str = "__PSP_CALLBACK_ID__";
 
+/// A [`ViewportUpdate`] as a `dict` of its `num_rows`, and its `rows` keyed
+/// by position, each a JSON string.
+fn viewport_to_py<'py>(py: Python<'py>, viewport: &ViewportUpdate) -> PyResult<Bound<'py, PyAny>> {
+    let rows = PyDict::new_bound(py);
+    for (position, row) in &viewport.rows {
+        rows.set_item(position, row)?;
+    }
+
+    let update = PyDict::new_bound(py);
+    update.set_item("num_rows", viewport.num_rows)?;
+    update.set_item("rows", rows)?;
+    Ok(update.into_any())
+}
+
 fn get_arrow_table_cls() -> Option<Py<PyAny>> {
     let res: PyResult<Py<PyAny>> = Python::with_gil(|py| {
         let pyarrow = PyModule::import_bound(py, "pyarrow")?;
@@ -258,6 +339,89 @@ fn pandas_to_arrow_bytes<'py>(
     }
 }
 
+fn get_numpy_ndarray_cls(py: Python<'_>) -> Option<Bound<'_, PyAny>> {
+    let res: PyResult<Py<PyAny>> = Python::with_gil(|py| {
+        let numpy = PyModule::import_bound(py, "numpy")?;
+        Ok(numpy.getattr("ndarray")?.to_object(py))
+    });
+
+    match res {
+        Ok(x) => Some(x.into_bound(py)),
+        Err(_) => {
+            tracing::warn!("Failed to import numpy.ndarray");
+            None
+        },
+    }
+}
+
+fn is_numpy_array(py: Python, arr: &Bound<'_, PyAny>) -> PyResult<bool> {
+    if let Some(arr_class) = get_numpy_ndarray_cls(py) {
+        arr.is_instance(&arr_class)
+    } else {
+        Ok(false)
+    }
+}
+
+/// Convert a NumPy structured array, or a 2-D array whose columns are named
+/// by `columns`, to Arrow. `pyarrow` reads each column through the buffer
+/// protocol, so numeric and datetime columns are not converted element by
+/// element to Python objects.
+fn numpy_to_arrow_bytes<'py>(
+    py: Python<'py>,
+    arr: &Bound<'py, PyAny>,
+    columns: Option<Vec<String>>,
+) -> PyResult<Bound<'py, PyBytes>> {
+    let pyarrow = PyModule::import_bound(py, "pyarrow")?;
+    let fields: Option<Vec<String>> = arr.getattr("dtype")?.getattr("names")?.extract()?;
+    let (names, arrays) = match (fields, columns) {
+        (Some(_), Some(_)) => {
+            return Err(PyValueError::new_err(
+                "`columns` cannot be set for a structured array",
+            ));
+        },
+        (Some(names), None) => {
+            let arrays = names
+                .iter()
+                .map(|name| pyarrow.call_method1("array", (arr.get_item(name)?,)))
+                .collect::<PyResult<Vec<_>>>()?;
+
+            (names, arrays)
+        },
+        (None, columns) => {
+            let shape: Vec<usize> = arr.getattr("shape")?.extract()?;
+            let (Some(names), [_, num_columns]) = (columns, shape.as_slice()) else {
+                return Err(PyValueError::new_err(
+                    "Expected a structured array, or a 2-D array and `columns`",
+                ));
+            };
+
+            if names.len() != *num_columns {
+                return Err(PyValueError::new_err(format!(
+                    "Expected {} `columns`, got {}",
+                    num_columns,
+                    names.len()
+                )));
+            }
+
+            let arrays = (0..*num_columns)
+                .map(|i| {
+                    let column = arr.get_item((PySlice::full_bound(py), i))?;
+                    pyarrow.call_method1("array", (column,))
+                })
+                .collect::<PyResult<Vec<_>>>()?;
+
+            (names, arrays)
+        },
+    };
+
+    let table = pyarrow.getattr("Table")?.call_method1(
+        "from_arrays",
+        (PyList::new_bound(py, arrays), PyList::new_bound(py, names)),
+    )?;
+
+    to_arrow_bytes(py, &table)
+}
+
 impl PyClient {
     pub fn new(handle_request: Py<PyFunction>) -> Self {
         let client = Client::new_with_callback({
@@ -297,12 +461,25 @@ impl PyClient {
         limit: Option<u32>,
         index: Option<Py<PyString>>,
         name: Option<Py<PyString>>,
+        columns: Option<Vec<String>>,
+        schema: Option<HashMap<String, String>>,
     ) -> PyResult<PyTable> {
         let client = self.client.clone();
         let py_client = self.clone();
         let table = Python::with_gil(|py| {
+            let schema = schema
+                .map(|schema| {
+                    schema
+                        .into_iter()
+                        .map(|(name, ty)| Ok((name, ColumnType::from_str(&ty)?)))
+                        .collect::<Result<_, ClientError>>()
+                })
+                .transpose()
+                .into_pyerr()?;
+
             let mut options = TableInitOptions {
                 name: name.map(|x| x.extract::<String>(py)).transpose()?,
+                schema,
                 ..TableInitOptions::default()
             };
 
@@ -321,6 +498,8 @@ impl PyClient {
                 to_arrow_bytes(py, input.bind(py))?.to_object(py)
             } else if is_pandas_df(py, input.bind(py))? {
                 pandas_to_arrow_bytes(py, input.bind(py))?.to_object(py)
+            } else if is_numpy_array(py, input.bind(py))? {
+                numpy_to_arrow_bytes(py, input.bind(py), columns)?.to_object(py)
             } else {
                 input
             };
@@ -351,6 +530,10 @@ impl PyClient {
         self.client.get_hosted_table_names().await.into_pyerr()
     }
 
+    pub async fn server_expressions(&self) -> PyResult<HashMap<String, String>> {
+        self.client.server_expressions().await.into_pyerr()
+    }
+
     pub async fn set_loop_cb(&self, loop_cb: Py<PyFunction>) -> PyResult<()> {
         *self.loop_cb.write().await = Some(loop_cb);
         Ok(())
@@ -382,6 +565,51 @@ impl PyTable {
         self.table.columns().await.into_pyerr()
     }
 
+    pub async fn categories(&self) -> PyResult<HashMap<String, Vec<String>>> {
+        self.table.categories().await.into_pyerr()
+    }
+
+    pub async fn dictionary_stats(&self) -> PyResult<Py<PyAny>> {
+        let stats = self.table.dictionary_stats().await.into_pyerr()?;
+        Python::with_gil(|py| Ok(pythonize::pythonize(py, &stats)?))
+    }
+
+    pub async fn column_hints(&self) -> PyResult<Py<PyAny>> {
+        let hints = self.table.column_hints().await.into_pyerr()?;
+        Python::with_gil(|py| Ok(pythonize::pythonize(py, &hints)?))
+    }
+
+    pub async fn virtual_columns(&self) -> PyResult<HashMap<String, String>> {
+        self.table.virtual_columns().await.into_pyerr()
+    }
+
+    pub async fn stats(&self, interval_ms: Option<u64>) -> PyResult<Py<PyAny>> {
+        let stats = self.table.stats(interval_ms).await.into_pyerr()?;
+        Python::with_gil(|py| Ok(pythonize::pythonize(py, &stats)?))
+    }
+
+    pub async fn overlay(&self) -> PyResult<PyTable> {
+        let table = self.table.overlay().await.into_pyerr()?;
+        Ok(PyTable {
+            table: Arc::new(table),
+            client: self.client.clone(),
+        })
+    }
+
+    pub async fn commit_overlay(&self) -> PyResult<u32> {
+        self.table.commit_overlay().await.into_pyerr()
+    }
+
+    pub async fn sketches(&self) -> PyResult<Py<PyAny>> {
+        let sketches = self.table.sketches().await.into_pyerr()?;
+        Python::with_gil(|py| Ok(pythonize::pythonize(py, &sketches)?))
+    }
+
+    pub async fn expression_completions(&self) -> PyResult<Py<PyAny>> {
+        let completions = self.table.expression_completions().await.into_pyerr()?;
+        Python::with_gil(|py| Ok(pythonize::pythonize(py, &completions)?))
+    }
+
     pub async fn clear(&self) -> PyResult<()> {
         self.table.clear().await.into_pyerr()
     }
@@ -390,8 +618,14 @@ impl PyTable {
         self.table.delete().await.into_pyerr()
     }
 
-    pub async fn make_port(&self) -> PyResult<i32> {
-        self.table.make_port().await.into_pyerr()
+    pub async fn make_port(&self, name: Option<String>) -> PyResult<i32> {
+        let port = self
+            .table
+            .make_port(&name.unwrap_or_default())
+            .await
+            .into_pyerr()?;
+
+        Ok(port.id() as i32)
     }
 
     pub async fn on_delete(&self, callback_py: Py<PyFunction>) -> PyResult<u32> {
@@ -429,23 +663,73 @@ impl PyTable {
         table.remove(table_data).await.into_pyerr()
     }
 
+    pub async fn remove_where(&self, filter: Py<PyAny>) -> PyResult<u64> {
+        let filter = Python::with_gil(|py| depythonize_bound(filter.into_bound(py).into_any()))?;
+        self.table.remove_where(filter).await.into_pyerr()
+    }
+
+    pub async fn edit_cells(&self, edits: Py<PyAny>, user: Option<String>) -> PyResult<()> {
+        let edits = Python::with_gil(|py| depythonize_bound(edits.into_bound(py).into_any()))?;
+        self.table.edit_cells(edits, user).await.into_pyerr()
+    }
+
+    pub async fn edit_log(&self, limit: Option<u32>) -> PyResult<Py<PyAny>> {
+        let records = self.table.edit_log(limit).await.into_pyerr()?;
+        Python::with_gil(|py| Ok(pythonize::pythonize(py, &records)?))
+    }
+
+    pub async fn annotate(
+        &self,
+        index: String,
+        column: String,
+        text: Option<String>,
+        user: Option<String>,
+    ) -> PyResult<()> {
+        self.table
+            .annotate(index, column, text, user)
+            .await
+            .into_pyerr()
+    }
+
+    pub async fn annotations(&self) -> PyResult<Py<PyAny>> {
+        let annotations = self.table.annotations().await.into_pyerr()?;
+        Python::with_gil(|py| Ok(pythonize::pythonize(py, &annotations)?))
+    }
+
+    pub async fn undo(&self, count: u32) -> PyResult<u32> {
+        self.table.undo(count).await.into_pyerr()
+    }
+
+    pub async fn redo(&self, count: u32) -> PyResult<u32> {
+        self.table.redo(count).await.into_pyerr()
+    }
+
     pub async fn replace(&self, input: Py<PyAny>) -> PyResult<()> {
         let table = &self.table;
         let table_data = Python::with_gil(|py| UpdateData::from_py(py, &input))?;
         table.replace(table_data).await.into_pyerr()
     }
 
+    pub async fn replace_atomic(&self, input: Py<PyAny>) -> PyResult<()> {
+        let table = &self.table;
+        let table_data = Python::with_gil(|py| UpdateData::from_py(py, &input))?;
+        table.replace_atomic(table_data).await.into_pyerr()
+    }
+
     pub async fn update(
         &self,
         input: Py<PyAny>,
         format: Option<String>,
         port_id: Option<u32>,
+        columns: Option<Vec<String>>,
     ) -> PyResult<()> {
         let input_data: Py<PyAny> = Python::with_gil(|py| {
             let data = if is_arrow_table(py, input.bind(py))? {
                 to_arrow_bytes(py, input.bind(py))?.to_object(py)
             } else if is_pandas_df(py, input.bind(py))? {
                 pandas_to_arrow_bytes(py, input.bind(py))?.to_object(py)
+            } else if is_numpy_array(py, input.bind(py))? {
+                numpy_to_arrow_bytes(py, input.bind(py), columns)?.to_object(py)
             } else {
                 input
             };
@@ -454,7 +738,11 @@ impl PyTable {
 
         let table = &self.table;
         let table_data = Python::with_gil(|py| UpdateData::from_py(py, &input_data))?;
-        let options = UpdateOptions { format, port_id };
+        let options = UpdateOptions {
+            format,
+            port_id,
+            ..UpdateOptions::default()
+        };
         table.update(table_data, options).await.into_pyerr()?;
         Ok(())
     }
@@ -503,6 +791,10 @@ pub struct PyView {
 assert_view_api!(PyView);
 
 impl PyView {
+    pub(crate) fn name(&self) -> &str {
+        &self.view.name
+    }
+
     pub async fn column_paths(&self) -> PyResult<Vec<String>> {
         self.view.column_paths().await.into_pyerr()
     }
@@ -516,14 +808,36 @@ impl PyView {
         Ok(Python::with_gil(|py| pythonize::pythonize(py, &dim))?)
     }
 
-    pub async fn expand(&self, index: u32) -> PyResult<u32> {
+    pub async fn expand(&self, index: u64) -> PyResult<u32> {
         self.view.expand(index).await.into_pyerr()
     }
 
-    pub async fn collapse(&self, index: u32) -> PyResult<u32> {
+    pub async fn collapse(&self, index: u64) -> PyResult<u32> {
         self.view.collapse(index).await.into_pyerr()
     }
 
+    pub async fn set_params(&self, params: Option<Py<PyDict>>) -> PyResult<()> {
+        let params = Python::with_gil(|py| {
+            params
+                .map(|x| depythonize_bound(x.into_bound(py).into_any()))
+                .transpose()
+        })?
+        .unwrap_or_default();
+
+        self.view.set_params(params).await.into_pyerr()
+    }
+
+    pub async fn update_config(&self, config: Option<Py<PyDict>>) -> PyResult<()> {
+        let config = Python::with_gil(|py| {
+            config
+                .map(|x| depythonize_bound(x.into_bound(py).into_any()))
+                .transpose()
+        })?
+        .unwrap_or_default();
+
+        self.view.update_config(config).await.into_pyerr()
+    }
+
     pub async fn expression_schema(&self) -> PyResult<HashMap<String, String>> {
         Ok(self
             .view
@@ -544,7 +858,22 @@ impl PyView {
         self.view.get_min_max(name).await.into_pyerr()
     }
 
-    pub async fn num_rows(&self) -> PyResult<u32> {
+    pub async fn downsample(
+        &self,
+        x_column: String,
+        y_columns: Vec<String>,
+        n_points: u32,
+    ) -> PyResult<Py<PyAny>> {
+        let series = self
+            .view
+            .downsample(x_column, y_columns, n_points)
+            .await
+            .into_pyerr()?;
+
+        Ok(Python::with_gil(|py| pythonize::pythonize(py, &series))?)
+    }
+
+    pub async fn num_rows(&self) -> PyResult<u64> {
         self.view.num_rows().await.into_pyerr()
     }
 
@@ -589,7 +918,12 @@ impl PyView {
         self.view.remove_delete(callback_id).await.into_pyerr()
     }
 
-    pub async fn on_update(&self, callback: Py<PyFunction>, mode: Option<String>) -> PyResult<u32> {
+    pub async fn on_update(
+        &self,
+        callback: Py<PyFunction>,
+        mode: Option<String>,
+        viewport: Option<Py<PyDict>>,
+    ) -> PyResult<u32> {
         let loop_cb = self.client.loop_cb.read().await.clone();
         let callback = move |x: ViewOnUpdateResp| {
             let loop_cb = loop_cb.clone();
@@ -598,14 +932,19 @@ impl PyView {
                 let aggregate_errors: PyResult<()> = {
                     let callback = callback.clone();
                     Python::with_gil(|py| {
-                        match (&x.delta, &loop_cb) {
+                        let delta = match (&x.delta, &x.viewport) {
+                            (Some(delta), _) => Some(PyBytes::new_bound(py, delta).into_any()),
+                            (None, Some(viewport)) => Some(viewport_to_py(py, viewport)?),
+                            (None, None) => None,
+                        };
+
+                        match (delta, &loop_cb) {
                             (None, None) => callback.call1(py, (x.port_id,))?,
                             (None, Some(loop_cb)) => loop_cb.call1(py, (&callback, x.port_id))?,
-                            (Some(delta), None) => {
-                                callback.call1(py, (x.port_id, PyBytes::new_bound(py, delta)))?
+                            (Some(delta), None) => callback.call1(py, (x.port_id, delta))?,
+                            (Some(delta), Some(loop_cb)) => {
+                                loop_cb.call1(py, (callback, x.port_id, delta))?
                             },
-                            (Some(delta), Some(loop_cb)) => loop_cb
-                                .call1(py, (callback, x.port_id, PyBytes::new_bound(py, delta)))?,
                         };
 
                         Ok(())
@@ -624,8 +963,12 @@ impl PyView {
             .transpose()
             .into_pyerr()?;
 
+        let viewport: Option<ViewWindow> =
+            Python::with_gil(|py| viewport.map(|x| depythonize_bound(x.into_bound(py).into_any())))
+                .transpose()?;
+
         self.view
-            .on_update(Box::new(callback), OnUpdateOptions { mode })
+            .on_update(Box::new(callback), OnUpdateOptions { mode, viewport })
             .await
             .into_pyerr()
     }
@@ -652,6 +995,16 @@ impl PyView {
         self.view.to_csv(window).await.into_pyerr()
     }
 
+    pub async fn annotations(&self, window: Option<Py<PyDict>>) -> PyResult<Py<PyAny>> {
+        let window: ViewWindow =
+            Python::with_gil(|py| window.map(|x| depythonize_bound(x.into_bound(py).into_any())))
+                .transpose()?
+                .unwrap_or_default();
+
+        let annotations = self.view.annotations(window).await.into_pyerr()?;
+        Python::with_gil(|py| Ok(pythonize::pythonize(py, &annotations)?))
+    }
+
     pub async fn to_columns_string(&self, window: Option<Py<PyDict>>) -> PyResult<String> {
         let window: ViewWindow =
             Python::with_gil(|py| window.map(|x| depythonize_bound(x.into_bound(py).into_any())))
@@ -46,10 +46,35 @@ fn perspective(py: Python, m: &Bound<PyModule>) -> PyResult<()> {
     // m.add_class::<client_async::PyAsyncView>()?;
     m.add_class::<server::PySyncServer>()?;
     m.add_class::<server::PySyncSession>()?;
+    m.add_class::<server::PyArrowStream>()?;
     m.add(
         "PerspectivePyError",
         py.get_type_bound::<client::PerspectivePyError>(),
     )?;
+    m.add(
+        "ProtocolError",
+        py.get_type_bound::<client::ProtocolError>(),
+    )?;
+    m.add(
+        "ViewConfigError",
+        py.get_type_bound::<client::ViewConfigError>(),
+    )?;
+    m.add(
+        "MemoryLimitError",
+        py.get_type_bound::<client::MemoryLimitError>(),
+    )?;
+    m.add(
+        "RateLimitError",
+        py.get_type_bound::<client::RateLimitError>(),
+    )?;
+    m.add(
+        "EditRejectedError",
+        py.get_type_bound::<client::EditRejectedError>(),
+    )?;
+    m.add(
+        "SessionClosedError",
+        py.get_type_bound::<client::SessionClosedError>(),
+    )?;
 
     // m.add_function(wrap_pyfunction!(client_async::create_async_client, m)?)?;
     // m.add_function(wrap_pyfunction!(client_sync::_create_sync_client, m)?)?;
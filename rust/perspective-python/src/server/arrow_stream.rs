@@ -0,0 +1,191 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+//! Exchange of Arrow data with other in-process Python libraries (pyarrow,
+//! polars, DuckDB, ...) via the
+//! [Arrow PyCapsule interface](https://arrow.apache.org/docs/format/CDataInterface/PyCapsuleInterface.html),
+//! which passes a C `struct ArrowArrayStream` rather than an Arrow IPC
+//! buffer.
+
+use std::ffi::{c_void, CStr};
+use std::ptr;
+use std::sync::Mutex;
+
+use perspective_server::ArrowStreamPtr;
+use pyo3::exceptions::{PyTypeError, PyValueError};
+use pyo3::prelude::*;
+use pyo3::types::PyCapsule;
+
+const STREAM_CAPSULE_NAME: &CStr = c"arrow_array_stream";
+
+/// The C `struct ArrowArrayStream`. Only `release` is called from Rust; the
+/// remaining callbacks are invoked by the engine or the consumer.
+#[repr(C)]
+struct FFIArrowArrayStream {
+    get_schema: *mut c_void,
+    get_next: *mut c_void,
+    get_last_error: *mut c_void,
+    release: Option<unsafe extern "C" fn(*mut FFIArrowArrayStream)>,
+    private_data: *mut c_void,
+}
+
+// SAFETY: A stream is owned by exactly one holder at a time, and the Arrow C
+// stream interface permits it to be consumed from any thread.
+unsafe impl Send for FFIArrowArrayStream {}
+
+impl FFIArrowArrayStream {
+    fn empty() -> Self {
+        Self {
+            get_schema: ptr::null_mut(),
+            get_next: ptr::null_mut(),
+            get_last_error: ptr::null_mut(),
+            release: None,
+            private_data: ptr::null_mut(),
+        }
+    }
+
+    /// Move the stream exported by `obj.__arrow_c_stream__()` out of its
+    /// capsule, marking the capsule's copy as released.
+    fn import(obj: &Bound<'_, PyAny>) -> PyResult<Box<Self>> {
+        if !obj.hasattr("__arrow_c_stream__")? {
+            return Err(PyTypeError::new_err(
+                "Expected an object implementing `__arrow_c_stream__`",
+            ));
+        }
+
+        let capsule = obj.call_method0("__arrow_c_stream__")?;
+        let capsule = capsule.downcast::<PyCapsule>()?;
+        if capsule.name()? != Some(STREAM_CAPSULE_NAME) {
+            return Err(PyValueError::new_err(
+                "`__arrow_c_stream__` did not return an `arrow_array_stream` capsule",
+            ));
+        }
+
+        let source = capsule.pointer() as *mut Self;
+        // SAFETY: The capsule name guarantees `source` is an `ArrowArrayStream`,
+        // which the PyCapsule protocol permits us to move out of, provided
+        // the source is marked released so its destructor is a no-op.
+        unsafe {
+            if (*source).release.is_none() {
+                return Err(PyValueError::new_err("Arrow stream already consumed"));
+            }
+
+            let stream = Box::new(ptr::read(source));
+            (*source).release = None;
+            Ok(stream)
+        }
+    }
+
+    /// # Safety
+    ///
+    /// The returned pointer must not outlive `self`.
+    unsafe fn as_stream_ptr(&mut self) -> ArrowStreamPtr {
+        ArrowStreamPtr::new(self as *mut Self as *mut c_void)
+    }
+}
+
+impl Drop for FFIArrowArrayStream {
+    fn drop(&mut self) {
+        if let Some(release) = self.release.take() {
+            // SAFETY: `release` is non-null only while the stream is live.
+            unsafe { release(self) }
+        }
+    }
+}
+
+/// Consume the Arrow stream exported by `obj`, passing it to `f`.
+pub(crate) fn with_imported_stream<T>(
+    obj: &Bound<'_, PyAny>,
+    f: impl FnOnce(ArrowStreamPtr) -> T,
+) -> PyResult<T> {
+    let mut stream = FFIArrowArrayStream::import(obj)?;
+    // SAFETY: `stream` outlives `f`. The `Server` releases the stream (which
+    // resets its `release` callback), so dropping it afterwards is a no-op.
+    Ok(f(unsafe { stream.as_stream_ptr() }))
+}
+
+/// An Arrow C stream exported from a `View`, which implements
+/// `__arrow_c_stream__` so it can be read by any library supporting the
+/// Arrow PyCapsule interface, e.g. `pyarrow.table(stream)` or
+/// `polars.from_arrow(stream)`. The stream may only be consumed once.
+#[pyclass(module = "perspective")]
+pub struct PyArrowStream {
+    stream: Mutex<Option<Box<FFIArrowArrayStream>>>,
+}
+
+impl PyArrowStream {
+    /// Export to a new, uninitialized stream with `f`, which must initialize
+    /// it on success.
+    pub(crate) fn export(f: impl FnOnce(ArrowStreamPtr) -> PyResult<()>) -> PyResult<Self> {
+        let mut stream = Box::new(FFIArrowArrayStream::empty());
+        // SAFETY: `stream` outlives `f`.
+        f(unsafe { stream.as_stream_ptr() })?;
+        Ok(Self {
+            stream: Mutex::new(Some(stream)),
+        })
+    }
+}
+
+unsafe extern "C" fn release_stream_capsule(capsule: *mut pyo3::ffi::PyObject) {
+    let stream = pyo3::ffi::PyCapsule_GetPointer(capsule, STREAM_CAPSULE_NAME.as_ptr())
+        as *mut FFIArrowArrayStream;
+
+    // Consumers which move the stream out of the capsule mark it released,
+    // otherwise it is released here.
+    if !stream.is_null() {
+        if let Some(release) = (*stream).release.take() {
+            release(stream);
+        }
+
+        drop(Box::from_raw(stream));
+    }
+}
+
+#[pymethods]
+impl PyArrowStream {
+    #[pyo3(signature = (requested_schema=None))]
+    fn __arrow_c_stream__<'py>(
+        &self,
+        py: Python<'py>,
+        requested_schema: Option<Bound<'py, PyAny>>,
+    ) -> PyResult<Bound<'py, PyCapsule>> {
+        if requested_schema.is_some() {
+            return Err(PyValueError::new_err(
+                "`requested_schema` is not supported, cast the result instead",
+            ));
+        }
+
+        let stream = self
+            .stream
+            .lock()
+            .unwrap()
+            .take()
+            .ok_or_else(|| PyValueError::new_err("Arrow stream already consumed"))?;
+
+        let stream = Box::into_raw(stream);
+        // SAFETY: On success, the capsule owns `stream` and frees it in
+        // `release_stream_capsule`.
+        unsafe {
+            let capsule = pyo3::ffi::PyCapsule_New(
+                stream as *mut c_void,
+                STREAM_CAPSULE_NAME.as_ptr(),
+                Some(release_stream_capsule),
+            );
+
+            if capsule.is_null() {
+                drop(Box::from_raw(stream));
+                return Err(PyErr::fetch(py));
+            }
+
+            Ok(Bound::from_owned_ptr(py, capsule).downcast_into_unchecked())
+        }
+    }
+}
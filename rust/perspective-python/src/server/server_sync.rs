@@ -10,7 +10,7 @@
 // ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
 // ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
 
-use std::sync::Arc;
+use std::sync::{Arc, RwLock};
 
 use perspective_server::{Server, Session, SessionHandler};
 use pollster::FutureExt;
@@ -18,10 +18,14 @@ use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
 use pyo3::types::{PyBytes, PyFunction};
 
+use super::arrow_stream::{with_imported_stream, PyArrowStream};
+use crate::client::client_sync::PySyncView;
+use crate::client::SessionClosedError;
+
 #[pyclass]
 #[derive(Clone)]
 pub struct PySyncSession {
-    session: Arc<Session>,
+    session: Arc<RwLock<Option<Arc<Session>>>>,
 }
 
 #[pyclass]
@@ -56,9 +60,66 @@ impl PySyncServer {
             .new_session(PyConnection(response_cb))
             .block_on();
 
-        let session = Arc::new(session);
+        let session = Arc::new(RwLock::new(Some(Arc::new(session))));
         PySyncSession { session }
     }
+
+    /// Host a new `Table` named `name` from any object implementing the
+    /// Arrow PyCapsule interface (`__arrow_c_stream__`), e.g. a
+    /// `pyarrow.Table` or `polars.DataFrame`, without an Arrow IPC encode.
+    #[pyo3(signature = (name, data, index=None))]
+    pub fn host_arrow_stream(
+        &self,
+        name: &str,
+        data: &Bound<'_, PyAny>,
+        index: Option<&str>,
+    ) -> PyResult<()> {
+        with_imported_stream(data, |stream| {
+            self.server
+                .host_arrow_stream(name, index, stream)
+                .block_on()
+        })?
+        .map_err(|e| PyValueError::new_err(format!("{}", e)))
+    }
+
+    /// Update the hosted `Table` named `name` from any object implementing
+    /// the Arrow PyCapsule interface (`__arrow_c_stream__`).
+    #[pyo3(signature = (name, data, port_id=0))]
+    pub fn update_arrow_stream(
+        &self,
+        name: &str,
+        data: &Bound<'_, PyAny>,
+        port_id: u32,
+    ) -> PyResult<()> {
+        with_imported_stream(data, |stream| {
+            self.server
+                .update_arrow_stream(name, stream, port_id)
+                .block_on()
+        })?
+        .map_err(|e| PyValueError::new_err(format!("{}", e)))
+    }
+
+    /// Export `view` (which must be hosted by this server) as an object
+    /// implementing the Arrow PyCapsule interface, which can be read by
+    /// e.g. `pyarrow.table()` without an Arrow IPC encode.
+    pub fn view_to_arrow_stream(&self, view: &PySyncView) -> PyResult<PyArrowStream> {
+        PyArrowStream::export(|out| {
+            self.server
+                .view_to_arrow_stream(view.name(), out)
+                .block_on()
+                .map_err(|e| PyValueError::new_err(format!("{}", e)))
+        })
+    }
+}
+
+impl PySyncSession {
+    fn session(&self) -> PyResult<Arc<Session>> {
+        self.session
+            .read()
+            .unwrap()
+            .clone()
+            .ok_or_else(|| SessionClosedError::new_err("Session is closed"))
+    }
 }
 
 #[allow(non_local_definitions)]
@@ -67,16 +128,25 @@ impl PySyncSession {
     pub fn handle_request(&self, _py: Python<'_>, data: Vec<u8>) -> PyResult<()> {
         // TODO: Make this return a boolean for "should_poll" to determine whether we
         // immediately schedule a poll after this request.
-        self.session
+        self.session()?
             .handle_request(&data)
             .block_on()
             .map_err(|e| PyValueError::new_err(format!("{}", e)))
     }
 
     pub fn poll(&self, _py: Python<'_>) -> PyResult<()> {
-        self.session
+        self.session()?
             .poll()
             .block_on()
             .map_err(|e| PyValueError::new_err(format!("{}", e)))
     }
+
+    /// Close this session, deleting the views it created. Subsequent calls
+    /// to this session fail.
+    pub fn close(&self, _py: Python<'_>) {
+        let session = self.session.write().unwrap().take();
+        if let Some(session) = session.and_then(Arc::into_inner) {
+            session.close().block_on()
+        }
+    }
 }
@@ -0,0 +1,529 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+//! R bindings for Perspective, built with [`extendr`](https://extendr.github.io).
+//!
+//! The R package either hosts an in-process [`Server`] and talks to it
+//! through a [`LocalClient`], so `Table` and `View` live in the R session's
+//! process, or connects over a WebSocket to a remote server (e.g. one shared
+//! with Python users) via [`PerspectiveClient::connect`]. Data crosses the
+//! boundary as Arrow IPC (`raw` vectors), CSV (`character`) or column-oriented
+//! JSON converted from R `list`/`data.frame` columns; the R-level wrappers in
+//! `R/perspective.R` pick the format.
+
+use std::error::Error as StdError;
+
+use extendr_api::prelude::*;
+use futures::channel::mpsc::{unbounded, UnboundedSender};
+use futures::{SinkExt, StreamExt};
+use perspective::client::config::ViewConfigUpdate;
+use perspective::client::{
+    Client, ClientError, ClientHandler, TableData, TableInitOptions, UpdateData, UpdateOptions,
+};
+use perspective::server::Server;
+use perspective::LocalClient;
+use serde_json::{Map, Value};
+use tokio_tungstenite::tungstenite::Message;
+
+fn r_err(err: ClientError) -> Error {
+    Error::Other(err.to_string())
+}
+
+#[derive(Clone)]
+struct WebSocketClient(UnboundedSender<Vec<u8>>);
+
+impl ClientHandler for WebSocketClient {
+    async fn send_request<'a>(
+        &'a self,
+        msg: &'a [u8],
+    ) -> std::result::Result<(), Box<dyn StdError + Send + Sync>> {
+        Ok(self.0.unbounded_send(msg.to_vec())?)
+    }
+}
+
+/// Connect a [`Client`] to the Perspective server at `url`, relaying its
+/// messages on tasks spawned on the current runtime.
+async fn connect(url: &str) -> std::result::Result<Client, Box<dyn StdError + Send + Sync>> {
+    let (socket, _) = tokio_tungstenite::connect_async(url).await?;
+    let (mut sink, mut stream) = socket.split();
+    let (send, mut receiver) = unbounded::<Vec<u8>>();
+    let client = Client::new(WebSocketClient(send));
+    tokio::spawn(async move {
+        while let Some(msg) = receiver.next().await {
+            if sink.send(Message::Binary(msg)).await.is_err() {
+                break;
+            }
+        }
+    });
+
+    let handler = client.clone();
+    tokio::spawn(async move {
+        while let Some(Ok(msg)) = stream.next().await {
+            match msg {
+                Message::Binary(bytes) => {
+                    let _ = handler.handle_response(&bytes).await;
+                },
+                Message::Close(_) => break,
+                _ => {},
+            }
+        }
+    });
+
+    Ok(client)
+}
+
+enum Connection {
+    Local(LocalClient),
+
+    /// A WebSocket connection, relayed by its own runtime's worker thread so
+    /// that `pollster::block_on` can wait for responses from the R thread.
+    Remote {
+        client: Client,
+        runtime: tokio::runtime::Runtime,
+    },
+}
+
+/// A client of an in-process Perspective engine, or of a remote one.
+pub struct PerspectiveClient {
+    conn: Option<Connection>,
+}
+
+#[extendr]
+impl PerspectiveClient {
+    fn new() -> Self {
+        let server = Server::default();
+        let client = LocalClient::new(&server);
+        PerspectiveClient {
+            conn: Some(Connection::Local(client)),
+        }
+    }
+
+    /// Connect to the Perspective server at the WebSocket `url`, e.g.
+    /// `ws://localhost:8080/websocket`.
+    fn connect(url: String) -> Result<Self> {
+        let runtime = tokio::runtime::Builder::new_multi_thread()
+            .worker_threads(1)
+            .enable_all()
+            .build()
+            .map_err(|e| Error::Other(e.to_string()))?;
+
+        let client = runtime
+            .block_on(connect(&url))
+            .map_err(|e| Error::Other(format!("Failed to connect to {}: {}", url, e)))?;
+
+        Ok(PerspectiveClient {
+            conn: Some(Connection::Remote { client, runtime }),
+        })
+    }
+
+    fn table(
+        &self,
+        data: Robj,
+        name: Nullable<String>,
+        index: Nullable<String>,
+        limit: Nullable<i32>,
+    ) -> Result<PerspectiveTable> {
+        let limit = match limit.into_option() {
+            Some(limit) if limit < 1 => {
+                return Err(Error::Other(format!(
+                    "`limit` must be a positive integer, not {}",
+                    if limit == i32::MIN {
+                        "NA".to_owned()
+                    } else {
+                        limit.to_string()
+                    }
+                )));
+            },
+            limit => limit.map(|x| x as u32),
+        };
+
+        let options = TableInitOptions {
+            name: name.into_option(),
+            index: index.into_option(),
+            limit,
+            ..TableInitOptions::default()
+        };
+
+        let input = TableData::Update(update_data(&data)?);
+        let table = pollster::block_on(self.client()?.table(input, options)).map_err(r_err)?;
+        Ok(PerspectiveTable(table))
+    }
+
+    fn open_table(&self, name: String) -> Result<PerspectiveTable> {
+        let table = pollster::block_on(self.client()?.open_table(name)).map_err(r_err)?;
+        Ok(PerspectiveTable(table))
+    }
+
+    fn get_hosted_table_names(&self) -> Result<Vec<String>> {
+        pollster::block_on(self.client()?.get_hosted_table_names()).map_err(r_err)
+    }
+
+    fn close(&mut self) {
+        match self.conn.take() {
+            Some(Connection::Local(client)) => pollster::block_on(client.close()),
+            Some(Connection::Remote { runtime, .. }) => runtime.shutdown_background(),
+            None => {},
+        }
+    }
+}
+
+impl PerspectiveClient {
+    fn client(&self) -> Result<&Client> {
+        match &self.conn {
+            Some(Connection::Local(client)) => Ok(&**client),
+            Some(Connection::Remote { client, .. }) => Ok(client),
+            None => Err(Error::Other("Client is closed".to_owned())),
+        }
+    }
+}
+
+impl Drop for PerspectiveClient {
+    fn drop(&mut self) {
+        self.close()
+    }
+}
+
+/// A `Table` hosted by a [`PerspectiveClient`].
+pub struct PerspectiveTable(perspective::client::Table);
+
+#[extendr]
+impl PerspectiveTable {
+    fn get_name(&self) -> String {
+        self.0.get_name().to_owned()
+    }
+
+    fn get_index(&self) -> Option<String> {
+        self.0.get_index()
+    }
+
+    fn size(&self) -> Result<f64> {
+        let size = pollster::block_on(self.0.size()).map_err(r_err)?;
+        Ok(size as f64)
+    }
+
+    fn columns(&self) -> Result<Vec<String>> {
+        pollster::block_on(self.0.columns()).map_err(r_err)
+    }
+
+    fn schema(&self) -> Result<List> {
+        let schema = pollster::block_on(self.0.schema()).map_err(r_err)?;
+        let columns = pollster::block_on(self.0.columns()).map_err(r_err)?;
+        schema_to_list(columns, |name| schema.get(name).map(|x| x.to_string()))
+    }
+
+    fn update(&self, data: Robj) -> Result<()> {
+        let input = update_data(&data)?;
+        pollster::block_on(self.0.update(input, UpdateOptions::default())).map_err(r_err)
+    }
+
+    fn replace(&self, data: Robj) -> Result<()> {
+        let input = update_data(&data)?;
+        pollster::block_on(self.0.replace(input)).map_err(r_err)
+    }
+
+    fn remove(&self, data: Robj) -> Result<()> {
+        let input = update_data(&data)?;
+        pollster::block_on(self.0.remove(input)).map_err(r_err)
+    }
+
+    fn clear(&self) -> Result<()> {
+        pollster::block_on(self.0.clear()).map_err(r_err)
+    }
+
+    fn delete(&self) -> Result<()> {
+        pollster::block_on(self.0.delete()).map_err(r_err)
+    }
+
+    fn view(&self, config: Robj) -> Result<PerspectiveView> {
+        let config = if config.is_null() {
+            None
+        } else {
+            Some(view_config(&config)?)
+        };
+
+        let view = pollster::block_on(self.0.view(config)).map_err(r_err)?;
+        Ok(PerspectiveView(view))
+    }
+}
+
+/// A `View` of a [`PerspectiveTable`].
+pub struct PerspectiveView(perspective::client::View);
+
+#[extendr]
+impl PerspectiveView {
+    fn num_rows(&self) -> Result<f64> {
+        let rows = pollster::block_on(self.0.num_rows()).map_err(r_err)?;
+        Ok(rows as f64)
+    }
+
+    fn column_paths(&self) -> Result<Vec<String>> {
+        pollster::block_on(self.0.column_paths()).map_err(r_err)
+    }
+
+    fn schema(&self) -> Result<List> {
+        let schema = pollster::block_on(self.0.schema()).map_err(r_err)?;
+        let mut columns = schema.keys().cloned().collect::<Vec<_>>();
+        columns.sort();
+        schema_to_list(columns, |name| schema.get(name).map(|x| x.to_string()))
+    }
+
+    /// Arrow IPC stream bytes, readable by `arrow::read_ipc_stream`.
+    fn to_arrow(&self) -> Result<Raw> {
+        let arrow = pollster::block_on(self.0.to_arrow(Default::default())).map_err(r_err)?;
+        Ok(Raw::from_bytes(&arrow))
+    }
+
+    fn to_csv(&self) -> Result<String> {
+        pollster::block_on(self.0.to_csv(Default::default())).map_err(r_err)
+    }
+
+    /// A named `list` of column vectors, suitable for `as.data.frame`.
+    fn to_columns(&self) -> Result<List> {
+        let json =
+            pollster::block_on(self.0.to_columns_string(Default::default())).map_err(r_err)?;
+
+        let Value::Object(columns) =
+            serde_json::from_str(&json).map_err(|e| Error::Other(e.to_string()))?
+        else {
+            return Err(Error::Other("Malformed columns".to_owned()));
+        };
+
+        let names = columns.keys().cloned().collect::<Vec<_>>();
+        let values = columns
+            .values()
+            .map(json_column_to_robj)
+            .collect::<Vec<_>>();
+        List::from_names_and_values(names, values)
+    }
+
+    fn delete(&self) -> Result<()> {
+        pollster::block_on(self.0.delete()).map_err(r_err)
+    }
+}
+
+fn schema_to_list(
+    columns: Vec<String>,
+    column_type: impl Fn(&str) -> Option<String>,
+) -> Result<List> {
+    let values = columns
+        .iter()
+        .map(|name| Robj::from(column_type(name).unwrap_or_default()))
+        .collect::<Vec<_>>();
+
+    List::from_names_and_values(columns, values)
+}
+
+/// Convert an R value to the [`UpdateData`] format it most closely resembles:
+/// `raw` is Arrow IPC, a `character` scalar is CSV, and a named `list` (or
+/// `data.frame`) of atomic vectors is column-oriented JSON.
+fn update_data(data: &Robj) -> Result<UpdateData> {
+    if let Some(bytes) = data.as_raw_slice() {
+        Ok(UpdateData::Arrow(bytes.to_vec().into()))
+    } else if let Some(csv) = data.as_str() {
+        Ok(UpdateData::Csv(csv.to_owned()))
+    } else if let Some(list) = data.as_list() {
+        let mut columns = Map::new();
+        for (name, column) in list.iter() {
+            if name.is_empty() || name == "NA" {
+                return Err(Error::Other("Columns must be named".to_owned()));
+            }
+
+            columns.insert(
+                name.to_owned(),
+                Value::Array(column_to_json(name, &column)?),
+            );
+        }
+
+        let json = serde_json::to_string(&columns).map_err(|e| Error::Other(e.to_string()))?;
+        Ok(UpdateData::JsonColumns(json))
+    } else {
+        Err(Error::Other(format!(
+            "Unsupported input type {:?}, expected a data.frame, list, raw (Arrow) or character \
+             (CSV)",
+            data.rtype()
+        )))
+    }
+}
+
+fn column_to_json(name: &str, column: &Robj) -> Result<Vec<Value>> {
+    if column.inherits("factor") {
+        return Err(Error::Other(format!(
+            "Column \"{}\" is a factor, convert it with `as.character`",
+            name
+        )));
+    }
+
+    atomic_to_json(column).ok_or_else(|| {
+        Error::Other(format!(
+            "Column \"{}\" has unsupported type {:?}",
+            name,
+            column.rtype()
+        ))
+    })
+}
+
+/// The elements of an atomic R vector as JSON, with `NA` as `null`.
+fn atomic_to_json(robj: &Robj) -> Option<Vec<Value>> {
+    match robj.rtype() {
+        Rtype::Doubles => Some(
+            Doubles::try_from(robj)
+                .ok()?
+                .iter()
+                .map(|x| {
+                    if x.is_na() {
+                        Value::Null
+                    } else {
+                        serde_json::Number::from_f64(x.inner())
+                            .map(Value::Number)
+                            .unwrap_or(Value::Null)
+                    }
+                })
+                .collect(),
+        ),
+        Rtype::Integers => Some(
+            Integers::try_from(robj)
+                .ok()?
+                .iter()
+                .map(|x| {
+                    if x.is_na() {
+                        Value::Null
+                    } else {
+                        Value::from(x.inner())
+                    }
+                })
+                .collect(),
+        ),
+        Rtype::Logicals => Some(
+            Logicals::try_from(robj)
+                .ok()?
+                .iter()
+                .map(|x| {
+                    if x.is_na() {
+                        Value::Null
+                    } else {
+                        Value::Bool(x.is_true())
+                    }
+                })
+                .collect(),
+        ),
+        Rtype::Strings => Some(
+            Strings::try_from(robj)
+                .ok()?
+                .iter()
+                .map(|x| {
+                    if x.is_na() {
+                        Value::Null
+                    } else {
+                        Value::String(x.as_str().to_owned())
+                    }
+                })
+                .collect(),
+        ),
+        _ => None,
+    }
+}
+
+/// Convert an R `list` to a [`ViewConfigUpdate`]. R has no scalars, so a
+/// length-1 vector becomes a JSON scalar and the fields which are always
+/// arrays are re-wrapped, e.g. `group_by = "x"` and `sort = c("x", "desc")`.
+fn view_config(config: &Robj) -> Result<ViewConfigUpdate> {
+    let mut value = robj_to_json(config)?;
+    if let Value::Object(fields) = &mut value {
+        for key in ["group_by", "split_by", "columns", "expressions"] {
+            if let Some(field) = fields.get_mut(key) {
+                if !field.is_array() && !field.is_object() && !field.is_null() {
+                    *field = Value::Array(vec![field.take()]);
+                }
+            }
+        }
+
+        for key in ["sort", "filter"] {
+            if let Some(Value::Array(terms)) = fields.get_mut(key) {
+                if terms.first().is_some_and(|x| !x.is_array()) {
+                    *terms = vec![Value::Array(std::mem::take(terms))];
+                }
+            }
+        }
+    }
+
+    serde_json::from_value(value).map_err(|e| Error::Other(e.to_string()))
+}
+
+fn robj_to_json(robj: &Robj) -> Result<Value> {
+    if robj.is_null() {
+        Ok(Value::Null)
+    } else if let Some(list) = robj.as_list() {
+        if robj.names().is_some() {
+            let mut fields = Map::new();
+            for (name, item) in list.iter() {
+                fields.insert(name.to_owned(), robj_to_json(&item)?);
+            }
+
+            Ok(Value::Object(fields))
+        } else {
+            let items = list
+                .values()
+                .map(|x| robj_to_json(&x))
+                .collect::<Result<Vec<_>>>()?;
+
+            Ok(Value::Array(items))
+        }
+    } else if let Some(mut values) = atomic_to_json(robj) {
+        if values.len() == 1 {
+            Ok(values.remove(0))
+        } else {
+            Ok(Value::Array(values))
+        }
+    } else {
+        Err(Error::Other(format!(
+            "Unsupported config type {:?}",
+            robj.rtype()
+        )))
+    }
+}
+
+/// Convert a column of `View::to_columns` JSON to the narrowest R vector
+/// type which holds every value, with `null` as `NA`.
+fn json_column_to_robj(column: &Value) -> Robj {
+    let Value::Array(values) = column else {
+        return Robj::from(());
+    };
+
+    let non_null = || values.iter().filter(|x| !x.is_null());
+    if non_null().all(Value::is_boolean) {
+        Logicals::from_values(values.iter().map(|x| match x.as_bool() {
+            Some(x) => Rbool::from(x),
+            None => Rbool::na(),
+        }))
+        .into()
+    } else if non_null().all(Value::is_number) {
+        Doubles::from_values(values.iter().map(|x| match x.as_f64() {
+            Some(x) => Rfloat::from(x),
+            None => Rfloat::na(),
+        }))
+        .into()
+    } else {
+        Strings::from_values(values.iter().map(|x| match x {
+            Value::Null => Rstr::na(),
+            Value::String(x) => Rstr::from(x.as_str()),
+            x => Rstr::from(x.to_string()),
+        }))
+        .into()
+    }
+}
+
+extendr_module! {
+    mod perspective;
+    impl PerspectiveClient;
+    impl PerspectiveTable;
+    impl PerspectiveView;
+}
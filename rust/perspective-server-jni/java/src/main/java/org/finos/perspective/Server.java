@@ -0,0 +1,110 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+package org.finos.perspective;
+
+import org.apache.arrow.c.ArrowArrayStream;
+import org.apache.arrow.c.Data;
+import org.apache.arrow.memory.BufferAllocator;
+import org.apache.arrow.vector.ipc.ArrowReader;
+
+/**
+ * An in-process Perspective engine. Clients connect through a
+ * {@link Session} each, created by {@link #newSession}. Arrow data can also
+ * be exchanged in-process, without an IPC encode, through the Arrow C stream
+ * interface.
+ *
+ * <p>Close every {@link Session} before closing the {@link Server}.
+ */
+public final class Server implements AutoCloseable {
+    static {
+        NativeLibrary.load();
+    }
+
+    private long ptr;
+
+    public Server() {
+        this.ptr = nativeNew();
+    }
+
+    /** Create a new session whose responses are sent to `handler`. */
+    public synchronized Session newSession(SessionHandler handler) {
+        return new Session(nativeNewSession(handle(), handler));
+    }
+
+    /**
+     * Host a new table named `tableId` from the batches of `reader`, which
+     * is consumed by this call.
+     *
+     * @param index The column to index the table by, or `null`.
+     */
+    public synchronized void hostArrowStream(
+            String tableId, String index, ArrowReader reader, BufferAllocator allocator) {
+        try (ArrowArrayStream stream = ArrowArrayStream.allocateNew(allocator)) {
+            Data.exportArrayStream(allocator, reader, stream);
+            nativeHostArrowStream(handle(), tableId, index, stream.memoryAddress());
+        }
+    }
+
+    /**
+     * Update the hosted table named `tableId` from the batches of `reader`,
+     * which is consumed by this call.
+     */
+    public synchronized void updateArrowStream(
+            String tableId, ArrowReader reader, BufferAllocator allocator) {
+        try (ArrowArrayStream stream = ArrowArrayStream.allocateNew(allocator)) {
+            Data.exportArrayStream(allocator, reader, stream);
+            nativeUpdateArrowStream(handle(), tableId, stream.memoryAddress(), 0);
+        }
+    }
+
+    /**
+     * Read the hosted view named `viewId` as Arrow batches. The caller owns
+     * the returned reader, and must close it.
+     */
+    public synchronized ArrowReader viewToArrowStream(String viewId, BufferAllocator allocator) {
+        try (ArrowArrayStream stream = ArrowArrayStream.allocateNew(allocator)) {
+            nativeViewToArrowStream(handle(), viewId, stream.memoryAddress());
+            return Data.importArrayStream(allocator, stream);
+        }
+    }
+
+    @Override
+    public synchronized void close() {
+        if (ptr != 0) {
+            nativeFree(ptr);
+            ptr = 0;
+        }
+    }
+
+    private long handle() {
+        if (ptr == 0) {
+            throw new PerspectiveException("Server is closed");
+        }
+
+        return ptr;
+    }
+
+    private static native long nativeNew();
+
+    private static native void nativeFree(long ptr);
+
+    private static native long nativeNewSession(long ptr, SessionHandler handler);
+
+    private static native void nativeHostArrowStream(
+            long ptr, String tableId, String index, long stream);
+
+    private static native void nativeUpdateArrowStream(
+            long ptr, String tableId, long stream, int portId);
+
+    private static native void nativeViewToArrowStream(long ptr, String viewId, long out);
+}
@@ -0,0 +1,67 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+package org.finos.perspective;
+
+/**
+ * A client's connection to a {@link Server}. Requests from the client are
+ * passed to {@link #handleRequest}, and responses are delivered to the
+ * {@link SessionHandler} this session was created with, on the calling
+ * thread.
+ */
+public final class Session implements AutoCloseable {
+    private long ptr;
+
+    Session(long ptr) {
+        this.ptr = ptr;
+    }
+
+    /** Handle an encoded request from this session's client. */
+    public synchronized void handleRequest(byte[] request) {
+        nativeHandleRequest(handle(), request);
+    }
+
+    /**
+     * Flush pending updates, delivering `on_update` responses to every
+     * session of the {@link Server}.
+     */
+    public synchronized void poll() {
+        nativePoll(handle());
+    }
+
+    /**
+     * Close this session, deleting the views it created. Subsequent calls to
+     * this session throw.
+     */
+    @Override
+    public synchronized void close() {
+        if (ptr != 0) {
+            long handle = ptr;
+            ptr = 0;
+            nativeClose(handle);
+        }
+    }
+
+    private long handle() {
+        if (ptr == 0) {
+            throw new PerspectiveException("Session is closed");
+        }
+
+        return ptr;
+    }
+
+    private static native void nativeHandleRequest(long ptr, byte[] request);
+
+    private static native void nativePoll(long ptr);
+
+    private static native void nativeClose(long ptr);
+}
@@ -0,0 +1,59 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+package org.finos.perspective;
+
+import static org.junit.jupiter.api.Assertions.assertEquals;
+import static org.junit.jupiter.api.Assertions.assertThrows;
+import static org.junit.jupiter.api.Assertions.assertTrue;
+
+import java.util.ArrayList;
+import java.util.List;
+import org.junit.jupiter.api.Test;
+
+class ServerTest {
+    // `Request { msg_id: 1, get_hosted_tables_req: {} }`, encoded.
+    private static final byte[] GET_HOSTED_TABLES = {0x08, 0x01, 0x22, 0x00};
+
+    @Test
+    void sessionRespondsToRequests() {
+        List<byte[]> responses = new ArrayList<>();
+        try (Server server = new Server();
+                Session session = server.newSession(responses::add)) {
+            session.handleRequest(GET_HOSTED_TABLES);
+            session.poll();
+            assertEquals(1, responses.size());
+            assertTrue(responses.get(0).length > 0);
+        }
+    }
+
+    @Test
+    void closedSessionThrows() {
+        try (Server server = new Server()) {
+            Session session = server.newSession(response -> {});
+            session.close();
+            session.close();
+            assertThrows(PerspectiveException.class, () -> session.handleRequest(GET_HOSTED_TABLES));
+        }
+    }
+
+    @Test
+    void handlerExceptionsPropagate() {
+        try (Server server = new Server();
+                Session session = server.newSession(response -> {
+                    throw new IllegalStateException("handler failed");
+                })) {
+            assertThrows(
+                    IllegalStateException.class, () -> session.handleRequest(GET_HOSTED_TABLES));
+        }
+    }
+}
@@ -0,0 +1,223 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+//! JNI bindings for [`perspective_server`], loaded by the
+//! `org.finos.perspective` Java classes in `java/`.
+//!
+//! Native objects are handed to Java as `long` handles (leaked [`Box`]es)
+//! which the Java wrappers own and free exactly once from `close()`. Errors
+//! are thrown as `org.finos.perspective.PerspectiveException`.
+
+use std::ffi::c_void;
+
+use jni::objects::{GlobalRef, JByteArray, JClass, JObject, JString, JValue};
+use jni::sys::{jint, jlong};
+use jni::{JNIEnv, JavaVM};
+use perspective_server::{ArrowStreamPtr, Server, ServerError, Session, SessionHandler};
+use pollster::FutureExt;
+
+const EXCEPTION_CLASS: &str = "org/finos/perspective/PerspectiveException";
+
+/// Forwards a [`Session`]'s responses to a Java `SessionHandler`.
+struct JavaSessionHandler {
+    vm: JavaVM,
+    handler: GlobalRef,
+}
+
+impl SessionHandler for JavaSessionHandler {
+    async fn send_response<'a>(&'a mut self, msg: &'a [u8]) -> Result<(), ServerError> {
+        let mut env = self.vm.attach_current_thread()?;
+        let bytes = env.byte_array_from_slice(msg)?;
+        env.call_method(&self.handler, "sendResponse", "([B)V", &[JValue::Object(
+            &bytes,
+        )])?;
+
+        env.delete_local_ref(bytes)?;
+        Ok(())
+    }
+}
+
+/// Run `f`, throwing its error (unless a Java exception is already pending,
+/// e.g. from a `SessionHandler`) and returning `default` instead.
+fn throw_on_err<T>(
+    env: &mut JNIEnv,
+    default: T,
+    f: impl FnOnce(&mut JNIEnv) -> Result<T, ServerError>,
+) -> T {
+    match f(env) {
+        Ok(x) => x,
+        Err(err) => {
+            if !env.exception_check().unwrap_or(false) {
+                let _ = env.throw_new(EXCEPTION_CLASS, err.to_string());
+            }
+
+            default
+        },
+    }
+}
+
+/// # Safety
+///
+/// `ptr` must be a live handle returned by `Server.nativeNew`.
+unsafe fn server<'a>(ptr: jlong) -> &'a Server {
+    &*(ptr as *const Server)
+}
+
+/// # Safety
+///
+/// `ptr` must be a live handle returned by `Server.nativeNewSession`.
+unsafe fn session<'a>(ptr: jlong) -> &'a Session {
+    &*(ptr as *const Session)
+}
+
+fn optional_string(env: &mut JNIEnv, value: &JString) -> Result<Option<String>, ServerError> {
+    if value.is_null() {
+        Ok(None)
+    } else {
+        Ok(Some(env.get_string(value)?.into()))
+    }
+}
+
+#[no_mangle]
+pub extern "system" fn Java_org_finos_perspective_Server_nativeNew(
+    _env: JNIEnv,
+    _class: JClass,
+) -> jlong {
+    Box::into_raw(Box::new(Server::default())) as jlong
+}
+
+#[no_mangle]
+pub extern "system" fn Java_org_finos_perspective_Server_nativeFree(
+    _env: JNIEnv,
+    _class: JClass,
+    ptr: jlong,
+) {
+    // SAFETY: `Server.close` frees each handle once.
+    drop(unsafe { Box::from_raw(ptr as *mut Server) })
+}
+
+#[no_mangle]
+pub extern "system" fn Java_org_finos_perspective_Server_nativeNewSession(
+    mut env: JNIEnv,
+    _class: JClass,
+    ptr: jlong,
+    handler: JObject,
+) -> jlong {
+    throw_on_err(&mut env, 0, |env| {
+        let handler = JavaSessionHandler {
+            vm: env.get_java_vm()?,
+            handler: env.new_global_ref(handler)?,
+        };
+
+        // SAFETY: The Java `Server` only calls this while open.
+        let session = unsafe { server(ptr) }.new_session(handler).block_on();
+        Ok(Box::into_raw(Box::new(session)) as jlong)
+    })
+}
+
+#[no_mangle]
+pub extern "system" fn Java_org_finos_perspective_Server_nativeHostArrowStream(
+    mut env: JNIEnv,
+    _class: JClass,
+    ptr: jlong,
+    table_id: JString,
+    index: JString,
+    stream: jlong,
+) {
+    throw_on_err(&mut env, (), |env| {
+        let table_id: String = env.get_string(&table_id)?.into();
+        let index = optional_string(env, &index)?;
+        // SAFETY: `Server.hostArrowStream` exports a fresh stream for this call.
+        let stream = unsafe { ArrowStreamPtr::new(stream as *mut c_void) };
+        // SAFETY: The Java `Server` only calls this while open.
+        unsafe { server(ptr) }
+            .host_arrow_stream(&table_id, index.as_deref(), stream)
+            .block_on()
+    })
+}
+
+#[no_mangle]
+pub extern "system" fn Java_org_finos_perspective_Server_nativeUpdateArrowStream(
+    mut env: JNIEnv,
+    _class: JClass,
+    ptr: jlong,
+    table_id: JString,
+    stream: jlong,
+    port_id: jint,
+) {
+    throw_on_err(&mut env, (), |env| {
+        let table_id: String = env.get_string(&table_id)?.into();
+        // SAFETY: `Server.updateArrowStream` exports a fresh stream for this call.
+        let stream = unsafe { ArrowStreamPtr::new(stream as *mut c_void) };
+        // SAFETY: The Java `Server` only calls this while open.
+        unsafe { server(ptr) }
+            .update_arrow_stream(&table_id, stream, port_id as u32)
+            .block_on()
+    })
+}
+
+#[no_mangle]
+pub extern "system" fn Java_org_finos_perspective_Server_nativeViewToArrowStream(
+    mut env: JNIEnv,
+    _class: JClass,
+    ptr: jlong,
+    view_id: JString,
+    out: jlong,
+) {
+    throw_on_err(&mut env, (), |env| {
+        let view_id: String = env.get_string(&view_id)?.into();
+        // SAFETY: `Server.viewToArrowStream` allocates an empty stream for
+        // this call, and imports it on success.
+        let out = unsafe { ArrowStreamPtr::new(out as *mut c_void) };
+        // SAFETY: The Java `Server` only calls this while open.
+        unsafe { server(ptr) }
+            .view_to_arrow_stream(&view_id, out)
+            .block_on()
+    })
+}
+
+#[no_mangle]
+pub extern "system" fn Java_org_finos_perspective_Session_nativeHandleRequest(
+    mut env: JNIEnv,
+    _class: JClass,
+    ptr: jlong,
+    request: JByteArray,
+) {
+    throw_on_err(&mut env, (), |env| {
+        let request = env.convert_byte_array(request)?;
+        // SAFETY: The Java `Session` only calls this while open.
+        unsafe { session(ptr) }.handle_request(&request).block_on()
+    })
+}
+
+#[no_mangle]
+pub extern "system" fn Java_org_finos_perspective_Session_nativePoll(
+    mut env: JNIEnv,
+    _class: JClass,
+    ptr: jlong,
+) {
+    throw_on_err(&mut env, (), |_| {
+        // SAFETY: The Java `Session` only calls this while open.
+        unsafe { session(ptr) }.poll().block_on()
+    })
+}
+
+#[no_mangle]
+pub extern "system" fn Java_org_finos_perspective_Session_nativeClose(
+    _env: JNIEnv,
+    _class: JClass,
+    ptr: jlong,
+) {
+    // SAFETY: `Session.close` frees each handle once.
+    let session = unsafe { Box::from_raw(ptr as *mut Session) };
+    session.close().block_on()
+}
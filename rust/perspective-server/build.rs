@@ -11,7 +11,8 @@
 // ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
 
 use std::collections::HashSet;
-use std::path::Path;
+use std::path::{Path, PathBuf};
+use std::process::Command;
 use std::{fs, io};
 
 use cmake::Config;
@@ -55,15 +56,49 @@ fn cmake_build() -> Result<(), std::io::Error> {
     dst.always_configure(true);
     dst.define("CMAKE_BUILD_TYPE", profile.as_str());
 
-    if std::env::var("TARGET")
-        .unwrap_or_default()
-        .contains("wasm32")
-    {
+    let target = std::env::var("TARGET").unwrap_or_default();
+    let wasi_sdk = wasi_sdk_path(&target);
+    if target.contains("wasm32") && wasi_sdk.is_none() {
         dst.define("PSP_WASM_BUILD", "1");
     } else {
         dst.define("PSP_WASM_BUILD", "0");
     }
-    if std::env::var("CARGO_FEATURE_PYTHON").is_ok() {
+
+    // `wasm32-wasip1` builds use the WASI SDK's clang and CMake toolchain
+    // file. The engine needs C++ exceptions, and `wasm32-wasip1-threads`
+    // additionally enables threads.
+    let wasi_threads = target.ends_with("-threads");
+    if let Some(sdk) = &wasi_sdk {
+        let toolchain = if wasi_threads {
+            "wasi-sdk-pthread.cmake"
+        } else {
+            "wasi-sdk.cmake"
+        };
+
+        dst.define(
+            "CMAKE_TOOLCHAIN_FILE",
+            format!("{}/share/cmake/{}", sdk, toolchain),
+        );
+
+        dst.define("PSP_WASI_THREADS", if wasi_threads { "1" } else { "0" });
+    }
+
+    let sysroot = sysroot_path();
+    if wasi_sdk.is_none() {
+        if let Some(toolchain) = cmake_toolchain_file(&target, sysroot.as_deref())? {
+            dst.define("CMAKE_TOOLCHAIN_FILE", toolchain);
+        }
+    }
+
+    // Reported by `Client::system_info`, to tell which target and version a
+    // (possibly cross-compiled or prebuilt) engine was built for.
+    dst.define("PSP_ENGINE_TARGET", &target);
+    dst.define(
+        "PSP_ENGINE_VERSION",
+        std::env::var("CARGO_PKG_VERSION").unwrap(),
+    );
+
+    if std::env::var("CARGO_FEATURE_PYTHON").is_ok() && wasi_sdk.is_none() {
         dst.define("CMAKE_POSITION_INDEPENDENT_CODE", "ON");
         dst.define("PSP_PYTHON_BUILD", "1");
     }
@@ -73,32 +108,113 @@ fn cmake_build() -> Result<(), std::io::Error> {
 
     // WASM Exceptions don't work with the prebuilt Pyodide distribution.
     // It must be rebuilt with WASM exceptions enabled
-    if std::env::var("CARGO_FEATURE_WASM_EXCEPTIONS").is_ok() {
+    if std::env::var("CARGO_FEATURE_WASM_EXCEPTIONS").is_ok() || wasi_sdk.is_some() {
         dst.define("PSP_WASM_EXCEPTIONS", "1");
     } else {
         dst.define("PSP_WASM_EXCEPTIONS", "0");
     }
 
-    dst.build_arg(format!("-j{}", num_cpus::get()));
-    println!("cargo:warning=MESSAGE Building cmake {}", profile);
-    let artifact = dst.build();
+    // Without `expressions`, ExprTK and the computed functions are not built,
+    // and expression columns are rejected.
+    let expressions = std::env::var("CARGO_FEATURE_EXPRESSIONS").is_ok();
+    dst.define(
+        "PSP_ENABLE_EXPRESSIONS",
+        if expressions { "ON" } else { "OFF" },
+    );
+
+    let manifest = engine_manifest(&target, expressions);
+    let prebuilt = prebuilt_engine_dir(&manifest)?;
+    let artifact = if let Some(dir) = &prebuilt {
+        println!(
+            "cargo:warning=MESSAGE Using prebuilt engine {}",
+            dir.display()
+        );
+        dir.clone()
+    } else {
+        dst.build_arg(format!("-j{}", num_cpus::get()));
+        println!("cargo:warning=MESSAGE Building cmake {}", profile);
+        let artifact = dst.build();
+        println!("cargo:rerun-if-env-changed=PSP_ENGINE_EXPORT_DIR");
+        if let Ok(export_dir) = std::env::var("PSP_ENGINE_EXPORT_DIR") {
+            export_engine(&artifact, Path::new(&export_dir), &manifest)?;
+        }
+
+        artifact
+    };
+
     println!("cargo:warning=MESSAGE Building cxx");
-    cxx_build::bridge("src/ffi.rs")
+    let mut build = cxx_build::bridge("src/ffi.rs");
+    build
         .file("src/server.cpp")
         .include("include")
         .include("cpp/perspective/src/include")
         .flag_if_supported("-std=c++17") // TODO not needed?
         .flag("-fexceptions") // TODO not needed?
-        .static_flag(true)
-        .compile("perspective");
+        .static_flag(true);
 
-    println!(
-        "cargo:rustc-link-search=native={}/build",
-        artifact.display()
-    );
+    if !expressions {
+        build.define("PSP_DISABLE_EXPRESSIONS", None);
+    }
 
+    if let Some(sysroot) = &sysroot {
+        build.flag(format!("--sysroot={}", sysroot));
+    }
+
+    // musl binaries are fully static, so the C++ runtime must be too.
+    let musl = std::env::var("CARGO_CFG_TARGET_ENV").as_deref() == Ok("musl");
+    if musl {
+        build.cpp_link_stdlib(None);
+    }
+
+    if let Some(sdk) = &wasi_sdk {
+        build
+            .compiler(format!("{}/bin/clang++", sdk))
+            .flag(format!("--sysroot={}/share/wasi-sysroot", sdk))
+            .flag("-fwasm-exceptions")
+            .define("PSP_ENABLE_WASM", "1")
+            .define("PSP_ENABLE_WASI", "1");
+
+        if wasi_threads {
+            build.flag("-pthread");
+        }
+    }
+
+    build.compile("perspective");
+
+    // A prebuilt engine is flat, see `export_engine`.
+    let psp_dir = if prebuilt.is_some() {
+        artifact.clone()
+    } else {
+        artifact.join("build")
+    };
+
+    println!("cargo:rustc-link-search=native={}", psp_dir.display());
     println!("cargo:rustc-link-lib=static=psp");
     link_cmake_static_archives(artifact.as_path())?;
+    if let Some(sdk) = &wasi_sdk {
+        println!(
+            "cargo:rustc-link-search=native={}/share/wasi-sysroot/lib/{}",
+            sdk, target
+        );
+
+        for lib in ["c++", "c++abi", "unwind"] {
+            println!("cargo:rustc-link-lib=static={}", lib);
+        }
+    }
+
+    if musl {
+        let compiler = cc::Build::new().cpp(true).get_compiler();
+        let stdlib = compiler_output(compiler.path(), "-print-file-name=libstdc++.a")
+            .map(PathBuf::from)
+            .filter(|path| path.is_absolute());
+
+        if let Some(dir) = stdlib.as_deref().and_then(Path::parent) {
+            println!("cargo:rustc-link-search=native={}", dir.display());
+        }
+
+        println!("cargo:rustc-link-lib=static=stdc++");
+    }
+
     println!("cargo:rerun-if-changed=cpp/perspective");
     println!("cargo:rerun-if-changed=include/server.h");
     println!("cargo:rerun-if-changed=src/server.cpp");
@@ -106,6 +222,170 @@ fn cmake_build() -> Result<(), std::io::Error> {
     Ok(())
 }
 
+/// The WASI SDK install to build with, for `wasi` targets, from the
+/// `WASI_SDK_PATH` environment variable.
+fn wasi_sdk_path(target: &str) -> Option<String> {
+    if target.contains("wasi") {
+        println!("cargo:rerun-if-env-changed=WASI_SDK_PATH");
+        Some(std::env::var("WASI_SDK_PATH").expect("Must set WASI_SDK_PATH for WASI targets"))
+    } else {
+        None
+    }
+}
+
+/// The name of the manifest file which describes a prebuilt engine, see
+/// `export_engine`.
+const ENGINE_MANIFEST: &str = "perspective-engine.manifest";
+
+/// The manifest of the engine this build needs, as it is written to (and
+/// must match) a prebuilt engine's `ENGINE_MANIFEST`: the engine is specific
+/// to this crate's version, the target, and build-affecting features.
+fn engine_manifest(target: &str, expressions: bool) -> String {
+    format!(
+        "version={}\ntarget={}\nexpressions={}\n",
+        std::env::var("CARGO_PKG_VERSION").unwrap(),
+        target,
+        expressions
+    )
+}
+
+/// A directory of prebuilt engine archives to link instead of building the
+/// engine from source, from the `PSP_PREBUILT_ENGINE_DIR` environment
+/// variable. It must have been exported by a build of this same version,
+/// target and features, see `export_engine`.
+fn prebuilt_engine_dir(manifest: &str) -> io::Result<Option<PathBuf>> {
+    println!("cargo:rerun-if-env-changed=PSP_PREBUILT_ENGINE_DIR");
+    let Ok(dir) = std::env::var("PSP_PREBUILT_ENGINE_DIR") else {
+        return Ok(None);
+    };
+
+    let dir = PathBuf::from(dir);
+    let manifest_path = dir.join(ENGINE_MANIFEST);
+    println!("cargo:rerun-if-changed={}", manifest_path.display());
+    let found = fs::read_to_string(&manifest_path).map_err(|e| {
+        io::Error::new(
+            e.kind(),
+            format!("Can't read {}: {}", manifest_path.display(), e),
+        )
+    })?;
+
+    if found != manifest {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!(
+                "Prebuilt engine {} does not match this build.\nExpected:\n{}\nFound:\n{}",
+                dir.display(),
+                manifest,
+                found
+            ),
+        ));
+    }
+
+    Ok(Some(dir))
+}
+
+/// Copy every static archive of the CMake build in `artifact` into
+/// `export_dir`, along with its `manifest`, for later builds (e.g. in CI) to
+/// link via `PSP_PREBUILT_ENGINE_DIR`, set by the `PSP_ENGINE_EXPORT_DIR`
+/// environment variable.
+fn export_engine(artifact: &Path, export_dir: &Path, manifest: &str) -> io::Result<()> {
+    fn copy_archives(dir: &Path, export_dir: &Path) -> io::Result<()> {
+        for entry in fs::read_dir(dir)? {
+            let path = entry?.path();
+            if path.is_dir() {
+                copy_archives(&path, export_dir)?;
+            } else if path.extension().is_some_and(|ext| ext == "a") {
+                fs::copy(&path, export_dir.join(path.file_name().unwrap()))?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fs::create_dir_all(export_dir)?;
+    copy_archives(artifact, export_dir)?;
+    fs::write(export_dir.join(ENGINE_MANIFEST), manifest)?;
+    println!(
+        "cargo:warning=MESSAGE Exported engine to {}",
+        export_dir.display()
+    );
+
+    Ok(())
+}
+
+/// The sysroot of the target, from the `PSP_SYSROOT` environment variable,
+/// for cross-compilers which do not know their own.
+fn sysroot_path() -> Option<String> {
+    println!("cargo:rerun-if-env-changed=PSP_SYSROOT");
+    std::env::var("PSP_SYSROOT").ok()
+}
+
+/// The CMake toolchain file to build the engine with: the
+/// `PSP_CMAKE_TOOLCHAIN_FILE_<target>` or `PSP_CMAKE_TOOLCHAIN_FILE`
+/// environment variable if set, else when cross-compiling, one generated from
+/// the target's C/C++ compilers (per the `cc` crate's `CC_<target>` and
+/// `CXX_<target>` conventions, e.g. `aarch64-linux-musl-g++`) and their
+/// sysroot. CMake passes the toolchain file on to the engine's dependencies.
+fn cmake_toolchain_file(target: &str, sysroot: Option<&str>) -> io::Result<Option<String>> {
+    let target_var = format!("PSP_CMAKE_TOOLCHAIN_FILE_{}", target.replace('-', "_"));
+    for var in [target_var.as_str(), "PSP_CMAKE_TOOLCHAIN_FILE"] {
+        println!("cargo:rerun-if-env-changed={}", var);
+        if let Ok(toolchain) = std::env::var(var) {
+            return Ok(Some(toolchain));
+        }
+    }
+
+    let host = std::env::var("HOST").unwrap_or_default();
+    if target.is_empty() || target == host {
+        return Ok(None);
+    }
+
+    let system_name = if target.contains("linux") {
+        "Linux"
+    } else if target.contains("apple") {
+        "Darwin"
+    } else if target.contains("windows") {
+        "Windows"
+    } else {
+        "Generic"
+    };
+
+    let processor = target.split('-').next().unwrap_or_default();
+    let cc = cc::Build::new().get_compiler();
+    let cxx = cc::Build::new().cpp(true).get_compiler();
+    let sysroot = sysroot
+        .map(str::to_owned)
+        .or_else(|| compiler_output(cxx.path(), "-print-sysroot"))
+        .filter(|sysroot| Path::new(sysroot).is_dir());
+
+    let mut toolchain = vec![
+        format!("set(CMAKE_SYSTEM_NAME {})", system_name),
+        format!("set(CMAKE_SYSTEM_PROCESSOR {})", processor),
+        format!("set(CMAKE_C_COMPILER \"{}\")", cc.path().display()),
+        format!("set(CMAKE_CXX_COMPILER \"{}\")", cxx.path().display()),
+        "set(CMAKE_FIND_ROOT_PATH_MODE_PROGRAM NEVER)".to_owned(),
+        "set(CMAKE_FIND_ROOT_PATH_MODE_LIBRARY ONLY)".to_owned(),
+        "set(CMAKE_FIND_ROOT_PATH_MODE_INCLUDE ONLY)".to_owned(),
+        "set(CMAKE_FIND_ROOT_PATH_MODE_PACKAGE ONLY)".to_owned(),
+    ];
+
+    if let Some(sysroot) = sysroot {
+        toolchain.push(format!("set(CMAKE_SYSROOT \"{}\")", sysroot));
+    }
+
+    let path = PathBuf::from(std::env::var("OUT_DIR").unwrap()).join("toolchain.cmake");
+    fs::write(&path, toolchain.join("\n"))?;
+    Ok(Some(path.display().to_string()))
+}
+
+/// The trimmed stdout of `compiler arg`, if it succeeds and prints anything.
+fn compiler_output(compiler: &Path, arg: &str) -> Option<String> {
+    let output = Command::new(compiler).arg(arg).output().ok()?;
+    let stdout = String::from_utf8(output.stdout).ok()?;
+    let stdout = stdout.trim();
+    (output.status.success() && !stdout.is_empty()).then(|| stdout.to_owned())
+}
+
 /// Walk the cmake output path and emit link instructions for all archives.
 /// TODO Can this be faster pls?
 fn link_cmake_static_archives(dir: &Path) -> Result<(), std::io::Error> {
@@ -26,7 +26,58 @@ void close_session(const ProtoApiServer& server, std::uint32_t client_id);
 rust::Box<ResponseBatch> handle_request(
     const ProtoApiServer& self,
     std::uint32_t client_id,
-    rust::Slice<const std::uint8_t> message
+    rust::Slice<const std::uint8_t> message,
+    rust::Str request_id
 );
 
-rust::Box<ResponseBatch> poll(const ProtoApiServer& self);
\ No newline at end of file
+rust::Box<ResponseBatch>
+unhost_table(const ProtoApiServer& self, rust::Str table_id);
+
+rust::Box<ResponseBatch> poll(const ProtoApiServer& self);
+
+std::int64_t get_poll_delay_ms(const ProtoApiServer& self);
+
+rust::Box<ResponseBatch> host_arrow_stream(
+    const ProtoApiServer& self,
+    rust::Str table_id,
+    rust::Str index,
+    std::size_t stream
+);
+
+void update_arrow_stream(
+    const ProtoApiServer& self,
+    rust::Str table_id,
+    std::size_t stream,
+    std::uint32_t port_id
+);
+
+rust::Box<ResponseBatch> view_to_arrow_stream(
+    const ProtoApiServer& self, rust::Str view_id, std::size_t out
+);
+
+void set_slow_op_threshold(
+    const ProtoApiServer& self, std::int64_t threshold_us
+);
+
+void push_profile(
+    const ProtoApiServer& self, rust::Slice<const std::uint8_t> profile
+);
+
+void define_expression(
+    const ProtoApiServer& self, rust::Str name, rust::Str expression
+);
+
+void remove_expression(const ProtoApiServer& self, rust::Str name);
+
+void set_engine_affinity(
+    rust::Slice<const std::uint32_t> cores,
+    std::int32_t numa_node,
+    std::uint32_t num_threads
+);
+
+void set_engine_config(
+    std::uint64_t parallel_threshold,
+    std::uint64_t expression_block_size,
+    std::uint64_t tree_capacity,
+    std::uint64_t port_capacity
+);
\ No newline at end of file
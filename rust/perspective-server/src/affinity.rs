@@ -0,0 +1,58 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+/// Where the engine should run on large, multi-socket machines, set via
+/// [`crate::Server::set_affinity`]. Cross-node memory traffic slows down
+/// large `group_by` recomputation, so pinning the engine's threads to the
+/// cores of the NUMA node its memory lives on can be a significant speedup.
+///
+/// # Examples
+///
+/// ```rust
+/// # use perspective_server::AffinityConfig;
+/// // Run on the cores of NUMA node 1, allocating memory from it.
+/// let config = AffinityConfig::default().with_numa_node(1);
+///
+/// // Run on cores 0-7 with a 4 thread pool.
+/// let config = AffinityConfig::default().with_cores(0..8).with_threads(4);
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct AffinityConfig {
+    /// The cores to run the engine on. When empty, the cores of
+    /// `numa_node`, or else every core this process may run on.
+    pub cores: Vec<u32>,
+
+    /// The NUMA node engine threads should prefer to allocate memory from.
+    pub numa_node: Option<u32>,
+
+    /// The size of the engine's thread pool, which defaults to the number of
+    /// cores. Only builds with parallel computation (e.g. Python) have a
+    /// thread pool.
+    pub threads: Option<u32>,
+}
+
+impl AffinityConfig {
+    pub fn with_cores<I: IntoIterator<Item = u32>>(mut self, cores: I) -> Self {
+        self.cores = cores.into_iter().collect();
+        self
+    }
+
+    pub fn with_numa_node(mut self, node: u32) -> Self {
+        self.numa_node = Some(node);
+        self
+    }
+
+    pub fn with_threads(mut self, threads: u32) -> Self {
+        self.threads = Some(threads);
+        self
+    }
+}
@@ -0,0 +1,43 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use std::ffi::c_void;
+
+/// A pointer to a C `struct ArrowArrayStream`, as defined by the
+/// [Arrow C stream interface](https://arrow.apache.org/docs/format/CStreamInterface.html),
+/// which can be exchanged with other in-process Arrow libraries (e.g.
+/// `arrow-rs`'s `FFI_ArrowArrayStream`, polars or DuckDB) without an IPC
+/// encode.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ArrowStreamPtr(*mut c_void);
+
+// SAFETY: The caller of `ArrowStreamPtr::new` guarantees exclusive access to
+// the stream until it is consumed by the `Server`.
+unsafe impl Send for ArrowStreamPtr {}
+
+impl ArrowStreamPtr {
+    /// # Safety
+    ///
+    /// `ptr` must be a non-null, aligned pointer to a `struct
+    /// ArrowArrayStream` which is not accessed by any other thread until the
+    /// [`crate::Server`] method it is passed to has returned. Streams passed
+    /// as input must be initialized, and are released by the [`crate::Server`]
+    /// (even on error). Streams passed as output must be uninitialized, and
+    /// on success must be released by the caller.
+    pub unsafe fn new(ptr: *mut c_void) -> Self {
+        Self(ptr)
+    }
+
+    pub fn as_ptr(&self) -> *mut c_void {
+        self.0
+    }
+}
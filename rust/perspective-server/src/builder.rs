@@ -0,0 +1,158 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use crate::{ffi, Server, ServerError};
+
+/// Tunables of the engine which are otherwise fixed, for large deployments
+/// to tune without patching its C++ sources, set via
+/// [`ServerBuilder::with_engine_config`]. The defaults are the engine's
+/// built-in values.
+///
+/// These settings are process-wide, so they apply to every [`Server`] in
+/// this process, and are read by the engine as they are used, so they apply
+/// to tables and views created (and work started) after the [`Server`] which
+/// sets them is built.
+///
+/// # Examples
+///
+/// ```rust
+/// # use perspective_server::EngineConfig;
+/// // Run `group_by` with few aggregates on the calling thread.
+/// let config = EngineConfig::default().with_parallel_threshold(4);
+///
+/// // Pre-allocate for large updates and pivots.
+/// let config = EngineConfig::default()
+///     .with_port_capacity(100_000)
+///     .with_tree_capacity(100_000);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EngineConfig {
+    /// Parallel engine work with fewer tasks (e.g. columns or aggregates)
+    /// than this runs on the calling thread instead of the thread pool,
+    /// where dispatching costs more than it saves. Only builds with parallel
+    /// computation (e.g. Python) have a thread pool. Defaults to 0, i.e.
+    /// always parallel.
+    pub parallel_threshold: u64,
+
+    /// Rows per block evaluated by vectorized expression columns. Defaults
+    /// to 1024.
+    pub expression_block_size: u64,
+
+    /// The initial row capacity of the tree a `group_by` view aggregates
+    /// into, which grows by doubling. Defaults to 4000.
+    pub tree_capacity: u64,
+
+    /// The initial row capacity of a table's update queue, which holds
+    /// updates until the table is processed, and grows by doubling. Defaults
+    /// to 8.
+    pub port_capacity: u64,
+}
+
+impl Default for EngineConfig {
+    fn default() -> Self {
+        Self {
+            parallel_threshold: 0,
+            expression_block_size: 1024,
+            tree_capacity: 4000,
+            port_capacity: 8,
+        }
+    }
+}
+
+impl EngineConfig {
+    pub fn with_parallel_threshold(mut self, tasks: u64) -> Self {
+        self.parallel_threshold = tasks;
+        self
+    }
+
+    pub fn with_expression_block_size(mut self, rows: u64) -> Self {
+        self.expression_block_size = rows;
+        self
+    }
+
+    pub fn with_tree_capacity(mut self, rows: u64) -> Self {
+        self.tree_capacity = rows;
+        self
+    }
+
+    pub fn with_port_capacity(mut self, rows: u64) -> Self {
+        self.port_capacity = rows;
+        self
+    }
+}
+
+/// How a [`Server`] computes the `group_by` aggregates requested through the
+/// `perspective` crate's `accel::group_by`, set via
+/// [`ServerBuilder::with_aggregation_backend`]. Views are always aggregated
+/// by the engine.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum AggregationBackend {
+    /// A `group_by` view in the engine.
+    #[default]
+    Engine,
+
+    /// Experimental: a compute shader which reduces the table's numeric
+    /// columns on a GPU, in single precision, with the `perspective` crate's
+    /// `gpu` feature. Without it, or without a GPU, the same reduction runs
+    /// on the CPU.
+    Gpu,
+}
+
+/// A builder for a [`Server`] with non-default settings, created by
+/// [`Server::builder`].
+///
+/// # Examples
+///
+/// ```rust
+/// # use perspective_server::{EngineConfig, Server};
+/// let server = Server::builder()
+///     .with_engine_config(EngineConfig::default().with_parallel_threshold(4))
+///     .build()
+///     .unwrap();
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct ServerBuilder {
+    engine_config: Option<EngineConfig>,
+    aggregation_backend: AggregationBackend,
+}
+
+impl ServerBuilder {
+    /// Replace the engine's process-wide [`EngineConfig`] when this builder
+    /// is built. When not set, the engine's current settings are kept.
+    pub fn with_engine_config(mut self, config: EngineConfig) -> Self {
+        self.engine_config = Some(config);
+        self
+    }
+
+    /// Select the [`AggregationBackend`] of the built [`Server`].
+    pub fn with_aggregation_backend(mut self, backend: AggregationBackend) -> Self {
+        self.aggregation_backend = backend;
+        self
+    }
+
+    /// Build the [`Server`]. Fails if a size in the [`EngineConfig`] is 0.
+    pub fn build(self) -> Result<Server, ServerError> {
+        if let Some(config) = self.engine_config {
+            ffi::set_engine_config(
+                config.parallel_threshold,
+                config.expression_block_size,
+                config.tree_capacity,
+                config.port_capacity,
+            )?;
+        }
+
+        Ok(Server {
+            aggregation_backend: self.aggregation_backend,
+            ..Server::default()
+        })
+    }
+}
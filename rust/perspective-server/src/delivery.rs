@@ -0,0 +1,300 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use std::any::Any;
+use std::collections::{HashMap, VecDeque};
+use std::panic::AssertUnwindSafe;
+use std::time::{Duration, Instant};
+
+use futures::FutureExt;
+
+use crate::{RequestId, ServerError, SessionCallback};
+
+/// The number of [`DeadLetter`]s a [`crate::Server`] keeps, after which the
+/// oldest are discarded.
+pub const DEAD_LETTER_CAPACITY: usize = 1024;
+
+/// What a [`crate::Server`] does with a response when the callback of the
+/// [`crate::Session`] it is for returns an error, set per [`crate::Server`]
+/// via [`crate::Server::set_delivery_policy`]. A callback which panics always
+/// closes its [`crate::Session`], whatever the policy; see
+/// [`crate::SessionHandler`].
+#[derive(Clone, Debug, Default, PartialEq)]
+pub enum DeliveryPolicy {
+    /// Return the error from the [`crate::Session`] method which dispatched
+    /// the response. The response is lost, as are any others dispatched by
+    /// the same call which had not yet been sent, whichever session they
+    /// were for.
+    #[default]
+    Propagate,
+
+    /// Hold the response and retry it from later [`crate::Session::poll`]
+    /// calls, waiting `backoff` before the first retry and doubling the wait
+    /// after each failure. A response which fails `max_attempts` times in
+    /// total is moved to the dead letter queue. Later responses for the same
+    /// [`crate::Session`] queue behind a held response, so they are delivered
+    /// in order.
+    Retry {
+        max_attempts: u32,
+        backoff: Duration,
+    },
+
+    /// Move the response to the dead letter queue.
+    DeadLetter,
+
+    /// Close the [`crate::Session`], releasing its views and callbacks as
+    /// [`crate::Session::close`] would, and move the response to the dead
+    /// letter queue. Further requests to the [`crate::Session`] fail.
+    CloseSession,
+}
+
+/// A response which could not be delivered to its [`crate::Session`], as
+/// listed by [`crate::Server::dead_letters`].
+#[derive(Clone, Debug)]
+pub struct DeadLetter {
+    pub session_id: u32,
+
+    /// The request which caused this response, if any.
+    pub request_id: Option<RequestId>,
+
+    /// The encoded response.
+    pub message: Vec<u8>,
+
+    /// The error returned by the last delivery attempt.
+    pub error: String,
+
+    /// The number of delivery attempts made.
+    pub attempts: u32,
+}
+
+/// A response held for retry by [`DeliveryPolicy::Retry`].
+#[derive(Debug)]
+pub(crate) struct Pending {
+    pub request_id: Option<RequestId>,
+    pub message: Vec<u8>,
+    error: String,
+    attempts: u32,
+    next_attempt: Instant,
+}
+
+/// The per-[`crate::Server`] state of a [`DeliveryPolicy`].
+#[derive(Debug, Default)]
+pub(crate) struct Delivery {
+    policy: DeliveryPolicy,
+    retries: HashMap<u32, VecDeque<Pending>>,
+    dead_letters: VecDeque<DeadLetter>,
+}
+
+/// What to do about a failed delivery, per [`Delivery::failed`].
+pub(crate) enum Failed {
+    Propagate(ServerError),
+    Handled,
+    CloseSession,
+}
+
+impl Delivery {
+    pub fn set_policy(&mut self, policy: DeliveryPolicy) {
+        self.policy = policy;
+    }
+
+    pub fn dead_letters(&self) -> Vec<DeadLetter> {
+        self.dead_letters.iter().cloned().collect()
+    }
+
+    pub fn take_dead_letters(&mut self) -> Vec<DeadLetter> {
+        self.dead_letters.drain(..).collect()
+    }
+
+    /// The [`crate::Session`]s which have responses held for retry.
+    pub fn held_sessions(&self) -> Vec<u32> {
+        self.retries.keys().copied().collect()
+    }
+
+    pub fn is_held(&self, session_id: u32) -> bool {
+        self.retries.contains_key(&session_id)
+    }
+
+    /// Queue a response behind those already held for `session_id`, to be
+    /// sent as soon as they are.
+    pub fn hold(&mut self, session_id: u32, message: &[u8], request_id: Option<RequestId>) {
+        self.retries
+            .entry(session_id)
+            .or_default()
+            .push_back(Pending {
+                request_id,
+                message: message.to_vec(),
+                error: String::new(),
+                attempts: 0,
+                next_attempt: Instant::now(),
+            });
+    }
+
+    /// Take the next held response for `session_id`, if it is due.
+    pub fn take_due(&mut self, session_id: u32, now: Instant) -> Option<Pending> {
+        let queue = self.retries.get_mut(&session_id)?;
+        if queue.front()?.next_attempt > now {
+            return None;
+        }
+
+        let pending = queue.pop_front();
+        if queue.is_empty() {
+            self.retries.remove(&session_id);
+        }
+
+        pending
+    }
+
+    /// Record a failed first delivery attempt of a response, per the
+    /// [`DeliveryPolicy`].
+    pub fn failed(
+        &mut self,
+        session_id: u32,
+        message: &[u8],
+        request_id: Option<RequestId>,
+        error: ServerError,
+    ) -> Failed {
+        let pending = Pending {
+            request_id,
+            message: message.to_vec(),
+            error: error.to_string(),
+            attempts: 1,
+            next_attempt: Instant::now(),
+        };
+
+        match self.policy {
+            DeliveryPolicy::Propagate => Failed::Propagate(error),
+            DeliveryPolicy::Retry { .. } => {
+                self.retry_failed(session_id, pending);
+                Failed::Handled
+            },
+            DeliveryPolicy::DeadLetter => {
+                self.dead_letter(session_id, pending);
+                Failed::Handled
+            },
+            DeliveryPolicy::CloseSession => {
+                self.dead_letter(session_id, pending);
+                Failed::CloseSession
+            },
+        }
+    }
+
+    /// Return a response taken by [`Delivery::take_due`] to the front of its
+    /// queue after another failed attempt, or dead letter it if it is out of
+    /// attempts. Returns whether it was re-queued.
+    pub fn retry(&mut self, session_id: u32, mut pending: Pending, error: ServerError) -> bool {
+        pending.attempts += 1;
+        pending.error = error.to_string();
+        self.retry_failed(session_id, pending)
+    }
+
+    fn retry_failed(&mut self, session_id: u32, mut pending: Pending) -> bool {
+        let (max_attempts, backoff) = match self.policy {
+            DeliveryPolicy::Retry {
+                max_attempts,
+                backoff,
+            } => (max_attempts, backoff),
+            _ => (0, Duration::ZERO),
+        };
+
+        if pending.attempts >= max_attempts {
+            self.dead_letter(session_id, pending);
+            return false;
+        }
+
+        let exponent = pending.attempts.saturating_sub(1).min(16);
+        pending.next_attempt = Instant::now() + backoff * 2_u32.pow(exponent);
+        tracing::warn!(
+            "Failed to deliver response to session {} (attempt {}), retrying: {}",
+            session_id,
+            pending.attempts,
+            pending.error
+        );
+
+        self.retries
+            .entry(session_id)
+            .or_default()
+            .push_front(pending);
+
+        true
+    }
+
+    fn dead_letter(&mut self, session_id: u32, pending: Pending) {
+        tracing::warn!(
+            "Failed to deliver response to session {} after {} attempt(s): {}",
+            session_id,
+            pending.attempts,
+            pending.error
+        );
+
+        if self.dead_letters.len() >= DEAD_LETTER_CAPACITY {
+            self.dead_letters.pop_front();
+        }
+
+        self.dead_letters.push_back(DeadLetter {
+            session_id,
+            request_id: pending.request_id,
+            message: pending.message,
+            error: pending.error,
+            attempts: pending.attempts,
+        });
+    }
+
+    /// Record a response whose delivery panicked. The [`crate::Session`] is
+    /// closed regardless of the [`DeliveryPolicy`], so its held responses are
+    /// discarded too.
+    pub fn panicked(
+        &mut self,
+        session_id: u32,
+        message: &[u8],
+        request_id: Option<RequestId>,
+        panic: String,
+    ) {
+        tracing::error!("Session {} callback panicked: {}", session_id, panic);
+        self.drop_session(session_id);
+        self.dead_letter(session_id, Pending {
+            request_id,
+            message: message.to_vec(),
+            error: format!("Callback panicked: {}", panic),
+            attempts: 1,
+            next_attempt: Instant::now(),
+        });
+    }
+
+    /// Discard the held responses of a closed [`crate::Session`].
+    pub fn drop_session(&mut self, session_id: u32) {
+        self.retries.remove(&session_id);
+    }
+}
+
+/// Invoke a [`SessionCallback`], catching a panic (either when it is called
+/// or while its future is polled) as `Err` with the panic's message, so it
+/// doesn't unwind through delivery to every other [`crate::Session`].
+pub(crate) async fn call(
+    f: &SessionCallback,
+    msg: &[u8],
+    request_id: Option<RequestId>,
+) -> Result<Result<(), ServerError>, String> {
+    AssertUnwindSafe(async { f(msg, request_id).await })
+        .catch_unwind()
+        .await
+        .map_err(|panic| panic_message(&*panic))
+}
+
+fn panic_message(panic: &(dyn Any + Send)) -> String {
+    if let Some(msg) = panic.downcast_ref::<&str>() {
+        (*msg).to_owned()
+    } else if let Some(msg) = panic.downcast_ref::<String>() {
+        msg.clone()
+    } else {
+        "Unknown panic".to_owned()
+    }
+}
@@ -0,0 +1,240 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::{Arc, OnceLock, Weak};
+
+use perspective_client::config::ViewConfigUpdate;
+use perspective_client::proto;
+use perspective_client::proto::make_table_data::Data;
+use perspective_client::proto::make_table_req::MakeTableOptions;
+use perspective_client::proto::request::ClientReq;
+use perspective_client::proto::response::ClientResp;
+use prost::Message;
+
+use crate::{Server, ServerError, Session};
+
+/// A [`perspective_client::Table`] hosted by [`Server::create_derived_table`],
+/// whose rows mirror a [`perspective_client::View`] of another table.
+pub struct DerivedTable {
+    name: String,
+    source: String,
+    session: Session,
+
+    // The `Session` callback only holds a `Weak`, so the `Server`'s own
+    // callbacks do not keep it alive.
+    _state: Arc<DerivedState>,
+}
+
+impl std::fmt::Debug for DerivedTable {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DerivedTable")
+            .field("name", &self.name)
+            .field("source", &self.source)
+            .finish()
+    }
+}
+
+/// The internal [`Session`]'s side of the derived table: it owns the source
+/// view, and replaces the derived table's contents from it each time it
+/// updates.
+struct DerivedState {
+    server: Server,
+    client_id: u32,
+    name: String,
+    view_id: String,
+    msg_id_gen: AtomicU32,
+    on_update_id: AtomicU32,
+    responses: std::sync::Mutex<Vec<proto::Response>>,
+}
+
+impl DerivedTable {
+    pub(crate) async fn new(
+        server: &Server,
+        name: &str,
+        source: &str,
+        config: ViewConfigUpdate,
+    ) -> Result<Self, ServerError> {
+        let slot = Arc::new(OnceLock::<Weak<DerivedState>>::new());
+        let session = server
+            .new_session_with_callback({
+                let slot = slot.clone();
+                move |msg| {
+                    let state = slot.get().and_then(Weak::upgrade);
+                    Box::pin(async move {
+                        match state {
+                            Some(state) => state.handle_response(msg).await,
+                            None => Ok(()),
+                        }
+                    })
+                }
+            })
+            .await;
+
+        session.set_metadata("derived_table", name);
+        let state = Arc::new(DerivedState {
+            server: server.clone(),
+            client_id: session.id(),
+            name: name.to_owned(),
+            view_id: format!("__derived_{}", session.id()),
+            msg_id_gen: AtomicU32::new(0),
+            on_update_id: AtomicU32::new(0),
+            responses: std::sync::Mutex::default(),
+        });
+
+        let _ = slot.set(Arc::downgrade(&state));
+        if let Err(err) = state.init(source, config).await {
+            session.close().await;
+            return Err(err);
+        }
+
+        Ok(Self {
+            name: name.to_owned(),
+            source: source.to_owned(),
+            session,
+            _state: state,
+        })
+    }
+
+    /// The name of the derived table.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// The name of the table this derived table is computed from.
+    pub fn source(&self) -> &str {
+        &self.source
+    }
+
+    /// Stop maintaining this derived table, deleting its view of the source
+    /// table. The derived table stays hosted with its last contents, and the
+    /// next session to write to it becomes its writer; use
+    /// [`Server::unhost`] to remove it instead.
+    pub async fn close(self) {
+        self.session.close().await
+    }
+}
+
+impl DerivedState {
+    async fn init(&self, source: &str, config: ViewConfigUpdate) -> Result<(), ServerError> {
+        self.request(
+            source,
+            ClientReq::TableMakeViewReq(proto::TableMakeViewReq {
+                view_id: self.view_id.clone(),
+                config: Some(config.into()),
+            }),
+        )
+        .await?;
+
+        self.request(
+            &self.name,
+            ClientReq::MakeTableReq(proto::MakeTableReq {
+                data: Some(self.view_data()),
+                options: Some(MakeTableOptions {
+                    exclusive_writer: true,
+                    ..MakeTableOptions::default()
+                }),
+            }),
+        )
+        .await?;
+
+        // Without a `mode`, update notifications carry no delta.
+        let msg_id = self
+            .send(
+                &self.view_id,
+                ClientReq::ViewOnUpdateReq(proto::ViewOnUpdateReq {
+                    mode: None,
+                    viewport: None,
+                }),
+            )
+            .await?;
+
+        self.on_update_id.store(msg_id, Ordering::Relaxed);
+        Ok(())
+    }
+
+    fn view_data(&self) -> proto::MakeTableData {
+        proto::MakeTableData {
+            data: Some(Data::FromView(self.view_id.clone())),
+            ..proto::MakeTableData::default()
+        }
+    }
+
+    /// Replace the derived table with the source view's current rows, then
+    /// poll so its own views (including other derived tables) update in the
+    /// same cycle as the source.
+    async fn refresh(&self) -> Result<(), ServerError> {
+        self.request(
+            &self.name,
+            ClientReq::TableReplaceAtomicReq(proto::TableReplaceAtomicReq {
+                data: Some(self.view_data()),
+            }),
+        )
+        .await?;
+
+        self.server.poll().await
+    }
+
+    async fn handle_response(&self, msg: &[u8]) -> Result<(), ServerError> {
+        let resp = proto::Response::decode(msg)?;
+        let on_update_id = self.on_update_id.load(Ordering::Relaxed);
+        if on_update_id != 0 && resp.msg_id == on_update_id {
+            if let Some(ClientResp::ViewOnUpdateResp(_)) = resp.client_resp {
+                // Refresh errors belong to this table, not to the session
+                // whose update triggered them.
+                if let Err(err) = self.refresh().await {
+                    tracing::error!("Failed to refresh derived table {}: {}", self.name, err);
+                }
+            }
+        } else {
+            self.responses.lock().unwrap().push(resp);
+        }
+
+        Ok(())
+    }
+
+    async fn send(&self, entity_id: &str, req: ClientReq) -> Result<u32, ServerError> {
+        let msg_id = self.msg_id_gen.fetch_add(1, Ordering::Relaxed) + 1;
+        let req = proto::Request {
+            msg_id,
+            entity_id: entity_id.to_owned(),
+            client_req: Some(req),
+        };
+
+        self.server
+            .handle_request(
+                self.client_id,
+                &req.encode_to_vec(),
+                self.server.gen_request_id(),
+            )
+            .await?;
+
+        Ok(msg_id)
+    }
+
+    /// Send `req`, whose response is dispatched back to this session before
+    /// [`Server::handle_request`] returns.
+    async fn request(&self, entity_id: &str, req: ClientReq) -> Result<ClientResp, ServerError> {
+        let msg_id = self.send(entity_id, req).await?;
+        let resp = {
+            let mut responses = self.responses.lock().unwrap();
+            let idx = responses.iter().position(|x| x.msg_id == msg_id);
+            idx.map(|idx| responses.swap_remove(idx))
+        };
+
+        match resp.and_then(|x| x.client_resp) {
+            Some(ClientResp::ServerError(err)) => Err(err.message.into()),
+            Some(resp) => Ok(resp),
+            None => Err(format!("No response to derived table request {msg_id}").into()),
+        }
+    }
+}
@@ -0,0 +1,37 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use perspective_client::proto;
+
+/// A [`perspective_client::Table::edit_cells`] request, as checked by the
+/// validator set with [`crate::Server::set_edit_validator`].
+#[derive(Clone, Copy, Debug)]
+pub struct CellEditRequest<'a> {
+    /// The id of the [`crate::Session`] which sent the request.
+    pub session_id: u32,
+
+    /// The [`crate::Session::metadata`] of that session, e.g. the user it
+    /// authenticated as, which can be compared to `user`.
+    pub session_metadata: &'a HashMap<String, String>,
+    pub table_id: &'a str,
+
+    /// The user the client reports making the edits, as recorded in the
+    /// table's edit log.
+    pub user: Option<&'a str>,
+    pub edits: &'a [proto::CellEdit],
+}
+
+pub(crate) type EditValidator =
+    Arc<dyn Fn(&CellEditRequest<'_>) -> Result<(), String> + Send + Sync>;
@@ -32,8 +32,39 @@ mod ffi_internal {
             server: &ProtoApiServer,
             client_id: u32,
             val: &[u8],
+            request_id: &str,
         ) -> Box<ResponseBatch>;
+        fn unhost_table(server: &ProtoApiServer, table_id: &str) -> Result<Box<ResponseBatch>>;
         fn poll(server: &ProtoApiServer) -> Box<ResponseBatch>;
+        fn get_poll_delay_ms(server: &ProtoApiServer) -> i64;
+        unsafe fn host_arrow_stream(
+            server: &ProtoApiServer,
+            table_id: &str,
+            index: &str,
+            stream: usize,
+        ) -> Result<Box<ResponseBatch>>;
+        unsafe fn update_arrow_stream(
+            server: &ProtoApiServer,
+            table_id: &str,
+            stream: usize,
+            port_id: u32,
+        ) -> Result<()>;
+        unsafe fn view_to_arrow_stream(
+            server: &ProtoApiServer,
+            view_id: &str,
+            out: usize,
+        ) -> Result<Box<ResponseBatch>>;
+        fn set_slow_op_threshold(server: &ProtoApiServer, threshold_us: i64);
+        fn push_profile(server: &ProtoApiServer, profile: &[u8]) -> Result<()>;
+        fn define_expression(server: &ProtoApiServer, name: &str, expression: &str) -> Result<()>;
+        fn remove_expression(server: &ProtoApiServer, name: &str);
+        fn set_engine_affinity(cores: &[u32], numa_node: i32, num_threads: u32) -> Result<()>;
+        fn set_engine_config(
+            parallel_threshold: u64,
+            expression_block_size: u64,
+            tree_capacity: u64,
+            port_capacity: u64,
+        ) -> Result<()>;
     }
 }
 
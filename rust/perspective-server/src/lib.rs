@@ -51,27 +51,122 @@
 //!   look for Perspective C++ source code in the environment rather than
 //!   locally, e.g. for when you build this crate in-place in the Perspective
 //!   repo source tree.
+//! - `expressions` (default) Builds the engine's ExprTK expression language.
+//!   Without it, the engine is considerably smaller and faster to compile, for
+//!   embedded uses which only need raw tables and pivots; views and
+//!   `validate_expressions` calls with expressions fail with an error, and
+//!   `get_features` reports `expressions: false`.
+//! - `profiling` Adds [`Server::start_cpu_profile`],
+//!   [`Server::stop_cpu_profile`] and [`Server::heap_snapshot`], which capture
+//!   pprof profiles of the server process (including the engine) for debugging
+//!   production hot spots, retrievable by clients via
+//!   [`perspective_client::Client::take_profiles`].
+//!
+//! # WASI
+//!
+//! This crate builds for `wasm32-wasip1` (and `wasm32-wasip1-threads`) with
+//! the [WASI SDK](https://github.com/WebAssembly/wasi-sdk) 24 or later, e.g.
+//! for WASM plugin sandboxes and edge runtimes. Set `WASI_SDK_PATH` to its
+//! install directory and build without default features (re-enabling
+//! `expressions`, if needed). The engine uses C++ exceptions, so the host
+//! runtime must support the WASM exception handling proposal.
+//!
+//! # Cross-compilation
+//!
+//! When `TARGET` differs from the host, the engine's CMake build uses a
+//! toolchain file generated from the target's C and C++ compilers, as the
+//! [`cc`](https://docs.rs/cc) crate finds them (e.g. from `CXX_<target>`),
+//! and their sysroot. Set `PSP_SYSROOT` for compilers which don't know their
+//! own sysroot, or `PSP_CMAKE_TOOLCHAIN_FILE` (or
+//! `PSP_CMAKE_TOOLCHAIN_FILE_<target>`, with `_` for `-`) to use your own
+//! toolchain file instead. For example, a static `aarch64-unknown-linux-musl`
+//! build with a [musl-cross](https://musl.cc) toolchain:
+//!
+//! ```bash
+//! export CC_aarch64_unknown_linux_musl=aarch64-linux-musl-gcc
+//! export CXX_aarch64_unknown_linux_musl=aarch64-linux-musl-g++
+//! export CARGO_TARGET_AARCH64_UNKNOWN_LINUX_MUSL_LINKER=aarch64-linux-musl-gcc
+//! cargo build --release --target aarch64-unknown-linux-musl
+//! ```
+//!
+//! musl targets link the C++ runtime statically.
+//!
+//! # Prebuilt engine
+//!
+//! Compiling the engine dominates clean build times. To build it once (e.g.
+//! in a CI cache job) and reuse it, set `PSP_ENGINE_EXPORT_DIR` to a
+//! directory to copy the engine's static archives to. Later builds with
+//! `PSP_PREBUILT_ENGINE_DIR` set to a copy of that directory link it instead
+//! of building the engine from source. The exported directory records this
+//! crate's version, the target and the `expressions` feature, and builds
+//! which don't match all three fail rather than link a mismatched engine.
 
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::error::Error;
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::Arc;
+use std::time::Duration;
 
-use async_lock::RwLock;
+use async_lock::{Mutex, RwLock};
 use cxx::UniquePtr;
 use futures::future::BoxFuture;
 use futures::Future;
+use perspective_client::config::ViewConfigUpdate;
+use perspective_client::proto;
+use perspective_client::proto::request::ClientReq;
+use perspective_client::proto::response::ClientResp;
+use prost::Message;
+use tracing::Instrument;
 
+mod affinity;
+mod arrow_stream;
+mod builder;
+mod delivery;
+mod derived;
+mod edit_validator;
 mod ffi;
+#[cfg(feature = "profiling")]
+mod profiling;
+mod rate_limit;
+mod request_id;
+mod response_queue;
+
+pub use crate::affinity::AffinityConfig;
+pub use crate::arrow_stream::ArrowStreamPtr;
+pub use crate::builder::{AggregationBackend, EngineConfig, ServerBuilder};
+use crate::delivery::{call, Delivery, Failed};
+pub use crate::delivery::{DeadLetter, DeliveryPolicy, DEAD_LETTER_CAPACITY};
+pub use crate::derived::DerivedTable;
+pub use crate::edit_validator::CellEditRequest;
+use crate::edit_validator::EditValidator;
+use crate::rate_limit::RateLimiter;
+pub use crate::rate_limit::{RateLimit, RateLimitConfig};
+use crate::request_id::RequestHeader;
+pub use crate::request_id::RequestId;
+use crate::response_queue::ResponseQueue;
+pub use crate::response_queue::{QueuedResponse, ResponseQueueConfig, ResponseQueueStats};
 
 pub type ServerError = Box<dyn Error + Send + Sync>;
 
-type SessionCallback =
-    Arc<dyn for<'a> Fn(&'a [u8]) -> BoxFuture<'a, Result<(), ServerError>> + Send + Sync>;
+type SessionMetadata = Arc<std::sync::RwLock<HashMap<String, String>>>;
+
+type SessionCallback = Arc<
+    dyn for<'a> Fn(&'a [u8], Option<RequestId>) -> BoxFuture<'a, Result<(), ServerError>>
+        + Send
+        + Sync,
+>;
 
 /// Use [`SessionHandler`] to implement a callback for messages emitted from
 /// a [`Session`], to be passed to the [`Server::new_session`] constructor.
 /// Alternatively, a [`Session`] can be created from a closure instead via
 /// [`Server::new_session_with_callback`].
+///
+/// A `SessionHandler` which panics only closes its own [`Session`], the same
+/// as [`Session::close`], and the response it was sending is reported via
+/// [`Server::dead_letters`]. Other [`Session`]s' responses are still
+/// delivered. This requires `panic = "unwind"` (the default, and this
+/// workspace's profiles); a binary built with `panic = "abort"`, or for
+/// WebAssembly, aborts on any panic instead.
 ///                                                                         
 /// ```text
 ///                      :
@@ -89,6 +184,20 @@ pub trait SessionHandler: Send + Sync {
         &'a mut self,
         msg: &'a [u8],
     ) -> impl Future<Output = Result<(), ServerError>> + Send + 'a;
+
+    /// Like [`SessionHandler::send_response`], but also receives the
+    /// [`RequestId`] of the request which caused this response. Responses
+    /// emitted from [`Session::poll`] are not caused by a specific request,
+    /// and receive `None`. Defaults to calling
+    /// [`SessionHandler::send_response`].
+    fn send_response_with_id<'a>(
+        &'a mut self,
+        msg: &'a [u8],
+        request_id: Option<RequestId>,
+    ) -> impl Future<Output = Result<(), ServerError>> + Send + 'a {
+        let _ = request_id;
+        self.send_response(msg)
+    }
 }
 
 /// An instance of a Perspective server. Each [`Server`] instance is separate,
@@ -98,17 +207,68 @@ pub trait SessionHandler: Send + Sync {
 pub struct Server {
     server: Arc<UniquePtr<ffi::ProtoApiServer>>,
     callbacks: Arc<RwLock<HashMap<u32, SessionCallback>>>,
+    rate_limits: Arc<RwLock<RateLimitConfig>>,
+    request_id_gen: Arc<AtomicU64>,
+    sessions: Arc<RwLock<HashMap<u32, SessionMetadata>>>,
+    groups: Arc<RwLock<HashMap<String, HashSet<u32>>>>,
+    delivery: Arc<Mutex<Delivery>>,
+    queues: Arc<RwLock<HashMap<u32, Arc<std::sync::Mutex<ResponseQueue>>>>>,
+    edit_validator: Arc<RwLock<Option<EditValidator>>>,
+    aggregation_backend: AggregationBackend,
+    #[cfg(feature = "profiling")]
+    profiler: Arc<Mutex<profiling::Profiler>>,
+}
+
+/// A snapshot of a [`Session`]'s state, as returned by [`Server::sessions`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SessionInfo {
+    pub id: u32,
+    pub metadata: HashMap<String, String>,
 }
 
 impl Default for Server {
     fn default() -> Self {
         let server = Arc::new(ffi::new_proto_server());
         let callbacks = Arc::default();
-        Self { server, callbacks }
+        let rate_limits = Arc::default();
+        let request_id_gen = Arc::default();
+        let sessions = Arc::default();
+        let groups = Arc::default();
+        let delivery = Arc::default();
+        let queues = Arc::default();
+        let edit_validator = Arc::default();
+        let aggregation_backend = AggregationBackend::default();
+        #[cfg(feature = "profiling")]
+        let profiler = Arc::default();
+        Self {
+            server,
+            callbacks,
+            rate_limits,
+            request_id_gen,
+            sessions,
+            groups,
+            delivery,
+            queues,
+            edit_validator,
+            aggregation_backend,
+            #[cfg(feature = "profiling")]
+            profiler,
+        }
     }
 }
 
 impl Server {
+    /// Create a [`ServerBuilder`], to configure a [`Server`] (e.g. its
+    /// [`EngineConfig`]) before it is created.
+    pub fn builder() -> ServerBuilder {
+        ServerBuilder::default()
+    }
+
+    /// The [`AggregationBackend`] this [`Server`] was built with.
+    pub fn aggregation_backend(&self) -> AggregationBackend {
+        self.aggregation_backend
+    }
+
     /// An alternative method for creating a new [`Session`] for this
     /// [`Server`], from a callback closure instead of a via a trait.
     /// See [`Server::new_session`] for details.
@@ -121,6 +281,17 @@ impl Server {
     pub async fn new_session_with_callback<F>(&self, send_response: F) -> Session
     where
         F: for<'a> Fn(&'a [u8]) -> BoxFuture<'a, Result<(), ServerError>> + 'static + Sync + Send,
+    {
+        self.new_session_inner(move |msg, _| send_response(msg))
+            .await
+    }
+
+    async fn new_session_inner<F>(&self, send_response: F) -> Session
+    where
+        F: for<'a> Fn(&'a [u8], Option<RequestId>) -> BoxFuture<'a, Result<(), ServerError>>
+            + 'static
+            + Sync
+            + Send,
     {
         let id = ffi::new_session(&self.server);
         let server = self.clone();
@@ -129,13 +300,166 @@ impl Server {
             .await
             .insert(id, Arc::new(send_response));
 
+        let metadata = SessionMetadata::default();
+        self.sessions.write().await.insert(id, metadata.clone());
+        let rate_limiter = RateLimiter::new(&*self.rate_limits.read().await);
         Session {
             id,
             server,
+            metadata,
+            rate_limiter: std::sync::Mutex::new(rate_limiter),
             closed: false,
         }
     }
 
+    /// List the currently open [`Session`]s of this [`Server`], along with
+    /// any metadata set via [`Session::set_metadata`].
+    pub async fn sessions(&self) -> Vec<SessionInfo> {
+        let mut sessions = self
+            .sessions
+            .read()
+            .await
+            .iter()
+            .map(|(id, metadata)| SessionInfo {
+                id: *id,
+                metadata: metadata.read().unwrap().clone(),
+            })
+            .collect::<Vec<_>>();
+
+        sessions.sort_by_key(|x| x.id);
+        sessions
+    }
+
+    /// Set the default [`RateLimitConfig`] for [`Session`]s created by this
+    /// [`Server`] _after_ this call. Requests which exceed a limit are not
+    /// handled, and instead respond to the [`perspective_client::Client`]
+    /// with a `RATE_LIMITED` error. [`Session::set_rate_limits`] can override
+    /// this per-[`Session`].
+    pub async fn set_rate_limits(&self, config: RateLimitConfig) {
+        *self.rate_limits.write().await = config;
+    }
+
+    /// Check every [`perspective_client::Table::edit_cells`] request with
+    /// `validator` before it is applied, e.g. to authorize the user or
+    /// enforce business rules on the new values. A request which `validator`
+    /// rejects is not applied, and responds to the
+    /// [`perspective_client::Client`] with an `EDIT_REJECTED` error carrying
+    /// the returned message. Replaces any previous validator.
+    pub async fn set_edit_validator<F>(&self, validator: F)
+    where
+        F: Fn(&CellEditRequest<'_>) -> Result<(), String> + Send + Sync + 'static,
+    {
+        *self.edit_validator.write().await = Some(Arc::new(validator));
+    }
+
+    /// Remove the validator set by [`Server::set_edit_validator`].
+    pub async fn clear_edit_validator(&self) {
+        *self.edit_validator.write().await = None;
+    }
+
+    /// Set what this [`Server`] does with a response when a [`Session`]'s
+    /// callback fails to deliver it, for all of its [`Session`]s. Defaults to
+    /// [`DeliveryPolicy::Propagate`].
+    pub async fn set_delivery_policy(&self, policy: DeliveryPolicy) {
+        self.delivery.lock().await.set_policy(policy);
+    }
+
+    /// The responses this [`Server`] has failed to deliver, oldest first, per
+    /// its [`DeliveryPolicy`]. At most [`DEAD_LETTER_CAPACITY`] are kept.
+    pub async fn dead_letters(&self) -> Vec<DeadLetter> {
+        self.delivery.lock().await.dead_letters()
+    }
+
+    /// Like [`Server::dead_letters`], but also clears the dead letter queue,
+    /// e.g. to re-send or persist its contents.
+    pub async fn take_dead_letters(&self) -> Vec<DeadLetter> {
+        self.delivery.lock().await.take_dead_letters()
+    }
+
+    /// Record view creations, exports and table update cycles which take at
+    /// least `threshold` in this [`Server`]'s slow op log, which keeps the
+    /// last 256 and is read by [`perspective_client::Client::diagnostics`].
+    /// `None`, the default, disables the log.
+    pub async fn set_slow_op_threshold(&self, threshold: Option<Duration>) {
+        let threshold_us = threshold.map_or(-1, |threshold| {
+            i64::try_from(threshold.as_micros()).unwrap_or(i64::MAX)
+        });
+
+        ffi::set_slow_op_threshold(&self.server, threshold_us);
+    }
+
+    /// Add `expression` to this [`Server`]'s expression library as `name`,
+    /// replacing any expression of that name, so that many dashboards may
+    /// share one definition of a formula. A view config may then reference
+    /// `name` as if it were a column (in `columns`, `group_by`, `split_by`,
+    /// `sort`, `filter` or `aggregates`) of any table which has no column or
+    /// config expression of that name, and the view is created as if its
+    /// config's `expressions` had included `expression` as `name`. The
+    /// library is read by [`perspective_client::Client::server_expressions`].
+    ///
+    /// Library expressions are resolved when a view is created, so existing
+    /// views are unaffected by redefining or removing an expression.
+    pub async fn define_expression(&self, name: &str, expression: &str) -> Result<(), ServerError> {
+        ffi::define_expression(&self.server, name, expression)?;
+        Ok(())
+    }
+
+    /// Remove `name` from this [`Server`]'s expression library, see
+    /// [`Server::define_expression`].
+    pub async fn remove_expression(&self, name: &str) {
+        ffi::remove_expression(&self.server, name);
+    }
+
+    /// Start sampling the call stacks of every thread of this process
+    /// `frequency` times per second, until [`Server::stop_cpu_profile`]. Only
+    /// one CPU profile may run at a time per process. Only supported on
+    /// Unix.
+    #[cfg(feature = "profiling")]
+    pub async fn start_cpu_profile(&self, frequency: i32) -> Result<(), ServerError> {
+        self.profiler.lock().await.start_cpu(frequency)
+    }
+
+    /// Stop the CPU profile started by [`Server::start_cpu_profile`],
+    /// returning it as an encoded pprof profile. The profile is also kept
+    /// for [`perspective_client::Client::take_profiles`].
+    #[cfg(feature = "profiling")]
+    pub async fn stop_cpu_profile(&self) -> Result<Vec<u8>, ServerError> {
+        let profile = self.profiler.lock().await.stop_cpu()?;
+        ffi::push_profile(&self.server, &profile.encode_to_vec())?;
+        Ok(profile.pprof)
+    }
+
+    /// Capture the live heap allocations of this process as an encoded pprof
+    /// profile, which is also kept for
+    /// [`perspective_client::Client::take_profiles`]. Only supported on
+    /// Linux, when the process uses jemalloc (for the engine's allocations
+    /// too) with profiling enabled, e.g. via
+    /// `_RJEM_MALLOC_CONF=prof:true,prof_active:true`.
+    #[cfg(feature = "profiling")]
+    pub async fn heap_snapshot(&self) -> Result<Vec<u8>, ServerError> {
+        let profile = profiling::heap_snapshot().await?;
+        ffi::push_profile(&self.server, &profile.encode_to_vec())?;
+        Ok(profile.pprof)
+    }
+
+    /// Pin the engine to the cores and NUMA node of `config`: the engine's
+    /// thread pool is resized and pinned immediately, and any thread is
+    /// moved onto these cores (and allocates memory from this node) while it
+    /// handles a [`Session`] request or poll. Affinity is process-wide, so
+    /// this applies to every [`Server`] in the process and replaces any
+    /// previous call. Only supported on Linux.
+    pub async fn set_affinity(&self, config: AffinityConfig) -> Result<(), ServerError> {
+        ffi::set_engine_affinity(
+            &config.cores,
+            config
+                .numa_node
+                .map_or(-1, |node| i32::try_from(node).unwrap_or(i32::MAX)),
+            config.threads.unwrap_or(0),
+        )?;
+
+        Ok(())
+    }
+
     /// Create a [`Session`] for this [`Server`], suitable for exactly one
     /// [`perspective_client::Client`] (not necessarily in this process). A
     /// [`Session`] represents the server-side state of a single
@@ -152,54 +476,299 @@ impl Server {
     where
         F: SessionHandler + 'static + Sync + Send + Clone,
     {
-        self.new_session_with_callback(move |msg| {
+        self.new_session_inner(move |msg, request_id| {
             let mut session_handler = session_handler.clone();
-            Box::pin(async move { session_handler.send_response(msg).await })
+            Box::pin(async move { session_handler.send_response_with_id(msg, request_id).await })
         })
         .await
     }
 
-    async fn handle_request(&self, client_id: u32, val: &[u8]) -> Result<(), ServerError> {
-        for response in ffi::handle_request(&self.server, client_id, val).0 {
-            let cb = self
-                .callbacks
-                .read()
-                .await
-                .get(&response.client_id)
-                .cloned();
+    /// Send an application-level `message` to every [`Session`] which has
+    /// joined `group` via [`Session::join_group`]. Messages are delivered
+    /// through each [`Session`]'s existing response callback, and dispatched
+    /// to [`perspective_client::Client::on_broadcast`] callbacks on the
+    /// receiving end.
+    pub async fn broadcast(&self, group: &str, message: &str) -> Result<(), ServerError> {
+        let members = self.group_members(group).await;
+        let resp = proto::Response {
+            msg_id: 0,
+            entity_id: "".to_owned(),
+            client_resp: Some(ClientResp::ServerBroadcastResp(
+                proto::ServerBroadcastResp {
+                    group: group.to_owned(),
+                    message: message.to_owned(),
+                },
+            )),
+        };
 
-            if let Some(f) = cb {
-                f(&response.resp).await?
-            }
+        for client_id in members {
+            self.send_response(client_id, &resp, None).await?;
         }
 
         Ok(())
     }
 
-    async fn poll(&self) -> Result<(), ServerError> {
-        for response in ffi::poll(&self.server).0 {
-            let cb = self
-                .callbacks
-                .read()
+    /// The ids of the [`Session`]s which are members of `group`.
+    pub async fn group_members(&self, group: &str) -> Vec<u32> {
+        let mut members = self
+            .groups
+            .read()
+            .await
+            .get(group)
+            .map(|x| x.iter().copied().collect::<Vec<_>>())
+            .unwrap_or_default();
+
+        members.sort();
+        members
+    }
+
+    /// Stop hosting the [`perspective_client::Table`] named `table_id`. Unlike
+    /// [`perspective_client::Table::delete`], which fails while the table
+    /// still has views, `unhost` first deletes every dependent view (in any
+    /// [`Session`]), notifying their `on_delete` subscribers, then deletes
+    /// the table itself and notifies its `on_delete` subscribers. Fails if no
+    /// table named `table_id` is hosted.
+    pub async fn unhost(&self, table_id: &str) -> Result<(), ServerError> {
+        self.dispatch(ffi::unhost_table(&self.server, table_id)?.0)
+            .await
+    }
+
+    /// Host a new [`perspective_client::Table`] named `name` whose rows are
+    /// the output of a [`perspective_client::View`] of the table `source`
+    /// with `config`, so an expensive transformation (e.g. a group by over a
+    /// large table) is computed once, and many clients can create cheap views
+    /// of its result. The source view is maintained incrementally by the
+    /// engine, and `name` is atomically replaced with its rows in the same
+    /// poll as each update to `source`, so views of `name` (or further
+    /// derived tables) update as if it had been written to directly.
+    ///
+    /// Only the returned [`DerivedTable`] may write to `name`, until
+    /// [`DerivedTable::close`] is called.
+    pub async fn create_derived_table(
+        &self,
+        name: &str,
+        source: &str,
+        config: ViewConfigUpdate,
+    ) -> Result<DerivedTable, ServerError> {
+        DerivedTable::new(self, name, source, config).await
+    }
+
+    /// Host a new [`perspective_client::Table`] named `table_id` from an
+    /// Arrow C stream, reading its record batches in-process rather than
+    /// decoding an Arrow IPC buffer. The stream is consumed by this call.
+    pub async fn host_arrow_stream(
+        &self,
+        table_id: &str,
+        index: Option<&str>,
+        stream: ArrowStreamPtr,
+    ) -> Result<(), ServerError> {
+        // SAFETY: Guaranteed by `ArrowStreamPtr::new`.
+        let batch = unsafe {
+            ffi::host_arrow_stream(
+                &self.server,
+                table_id,
+                index.unwrap_or_default(),
+                stream.as_ptr() as usize,
+            )?
+        };
+
+        self.dispatch(batch.0).await
+    }
+
+    /// Update the hosted [`perspective_client::Table`] named `table_id` from
+    /// an Arrow C stream, which is consumed by this call.
+    pub async fn update_arrow_stream(
+        &self,
+        table_id: &str,
+        stream: ArrowStreamPtr,
+        port_id: u32,
+    ) -> Result<(), ServerError> {
+        // SAFETY: Guaranteed by `ArrowStreamPtr::new`.
+        unsafe {
+            ffi::update_arrow_stream(&self.server, table_id, stream.as_ptr() as usize, port_id)?;
+        }
+
+        self.poll().await
+    }
+
+    /// Export the hosted [`perspective_client::View`] named `view_id` to the
+    /// uninitialized Arrow C stream `out`, in the same shape as
+    /// [`perspective_client::View::to_arrow`] (without a viewport), but
+    /// without an Arrow IPC encode.
+    pub async fn view_to_arrow_stream(
+        &self,
+        view_id: &str,
+        out: ArrowStreamPtr,
+    ) -> Result<(), ServerError> {
+        // SAFETY: Guaranteed by `ArrowStreamPtr::new`.
+        let batch =
+            unsafe { ffi::view_to_arrow_stream(&self.server, view_id, out.as_ptr() as usize)? };
+
+        self.dispatch(batch.0).await
+    }
+
+    async fn dispatch(&self, responses: Vec<ffi::Response>) -> Result<(), ServerError> {
+        for response in responses {
+            self.deliver(response.client_id, &response.resp, None)
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    /// Send `msg` to the [`Session`] `client_id` via its callback, applying
+    /// this [`Server`]'s [`DeliveryPolicy`] if it fails. A callback which
+    /// panics closes its [`Session`], whatever the policy.
+    async fn deliver(
+        &self,
+        client_id: u32,
+        msg: &[u8],
+        request_id: Option<RequestId>,
+    ) -> Result<(), ServerError> {
+        if let Some(queue) = self.queues.read().await.get(&client_id).cloned() {
+            return Ok(queue.lock().unwrap().push(msg, request_id)?);
+        }
+
+        let Some(f) = self.callbacks.read().await.get(&client_id).cloned() else {
+            return Ok(());
+        };
+
+        if self.delivery.lock().await.is_held(client_id) {
+            self.retry_held(client_id).await;
+            let mut delivery = self.delivery.lock().await;
+            if delivery.is_held(client_id) {
+                delivery.hold(client_id, msg, request_id);
+                return Ok(());
+            }
+        }
+
+        let err = match call(&f, msg, request_id).await {
+            Ok(Ok(())) => return Ok(()),
+            Ok(Err(err)) => err,
+            Err(panic) => {
+                self.delivery
+                    .lock()
+                    .await
+                    .panicked(client_id, msg, request_id, panic);
+
+                self.close(client_id).await;
+                return Ok(());
+            },
+        };
+
+        let failed = self
+            .delivery
+            .lock()
+            .await
+            .failed(client_id, msg, request_id, err);
+
+        match failed {
+            Failed::Propagate(err) => Err(err),
+            Failed::Handled => Ok(()),
+            Failed::CloseSession => {
+                self.close(client_id).await;
+                Ok(())
+            },
+        }
+    }
+
+    /// Re-send the due responses held for `client_id` by
+    /// [`DeliveryPolicy::Retry`], in order, until one fails again.
+    async fn retry_held(&self, client_id: u32) {
+        loop {
+            let Some(pending) = self
+                .delivery
+                .lock()
                 .await
-                .get(&response.client_id)
-                .cloned();
+                .take_due(client_id, std::time::Instant::now())
+            else {
+                return;
+            };
+
+            let Some(f) = self.callbacks.read().await.get(&client_id).cloned() else {
+                self.delivery.lock().await.drop_session(client_id);
+                return;
+            };
 
-            if let Some(f) = cb {
-                f(&response.resp).await?
+            match call(&f, &pending.message, pending.request_id).await {
+                Ok(Ok(())) => {},
+                Ok(Err(err)) => {
+                    if self.delivery.lock().await.retry(client_id, pending, err) {
+                        return;
+                    }
+                },
+                Err(panic) => {
+                    self.delivery.lock().await.panicked(
+                        client_id,
+                        &pending.message,
+                        pending.request_id,
+                        panic,
+                    );
+
+                    self.close(client_id).await;
+                    return;
+                },
             }
         }
+    }
+
+    fn gen_request_id(&self) -> RequestId {
+        RequestId(self.request_id_gen.fetch_add(1, Ordering::Relaxed))
+    }
+
+    async fn handle_request(
+        &self,
+        client_id: u32,
+        val: &[u8],
+        request_id: RequestId,
+    ) -> Result<(), ServerError> {
+        let request_id_str = request_id.to_string();
+        for response in ffi::handle_request(&self.server, client_id, val, &request_id_str).0 {
+            self.deliver(response.client_id, &response.resp, Some(request_id))
+                .await?;
+        }
 
         Ok(())
     }
 
+    async fn send_response(
+        &self,
+        client_id: u32,
+        resp: &proto::Response,
+        request_id: Option<RequestId>,
+    ) -> Result<(), ServerError> {
+        self.deliver(client_id, &resp.encode_to_vec(), request_id)
+            .await
+    }
+
+    async fn poll(&self) -> Result<(), ServerError> {
+        let held = self.delivery.lock().await.held_sessions();
+        for client_id in held {
+            self.retry_held(client_id).await;
+        }
+
+        self.dispatch(ffi::poll(&self.server).0).await
+    }
+
+    async fn is_open(&self, client_id: u32) -> bool {
+        self.callbacks.read().await.contains_key(&client_id)
+    }
+
     async fn close(&self, client_id: u32) {
+        // A `Session` may already have been closed by
+        // `DeliveryPolicy::CloseSession`.
+        if self.callbacks.write().await.remove(&client_id).is_none() {
+            return;
+        }
+
         ffi::close_session(&self.server, client_id);
-        self.callbacks
-            .write()
-            .await
-            .remove(&client_id)
-            .expect("Already closed");
+        self.delivery.lock().await.drop_session(client_id);
+        self.queues.write().await.remove(&client_id);
+        self.sessions.write().await.remove(&client_id);
+        self.groups.write().await.retain(|_, members| {
+            members.remove(&client_id);
+            !members.is_empty()
+        });
     }
 }
 
@@ -211,18 +780,78 @@ impl Server {
 pub struct Session {
     id: u32,
     server: Server,
+    metadata: SessionMetadata,
+    rate_limiter: std::sync::Mutex<RateLimiter>,
     closed: bool,
 }
 
+impl std::fmt::Debug for Session {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Session")
+            .field("id", &self.id)
+            .field("metadata", &*self.metadata.read().unwrap())
+            .finish()
+    }
+}
+
 impl Drop for Session {
     fn drop(&mut self) {
         if !self.closed {
-            tracing::error!("`Session` dropped without `Session::close`");
+            tracing::error!("`Session` dropped without `Session::close` {:?}", self);
         }
     }
 }
 
 impl Session {
+    /// This [`Session`]'s id, unique for its [`Server`].
+    pub fn id(&self) -> u32 {
+        self.id
+    }
+
+    /// Tag this [`Session`] with a `key`/`value` pair, e.g. a user id, origin
+    /// or client version. Metadata is visible to embedders via
+    /// [`Session::get_metadata`] and [`Server::sessions`], and is included in
+    /// this [`Session`]'s [`std::fmt::Debug`] output.
+    pub fn set_metadata<K: Into<String>, V: Into<String>>(&self, key: K, value: V) {
+        self.metadata
+            .write()
+            .unwrap()
+            .insert(key.into(), value.into());
+    }
+
+    /// Get the value of a metadata `key` set by [`Session::set_metadata`].
+    pub fn get_metadata(&self, key: &str) -> Option<String> {
+        self.metadata.read().unwrap().get(key).cloned()
+    }
+
+    /// Add this [`Session`] to the named `group`, creating it if necessary, so
+    /// that it receives messages sent with [`Server::broadcast`].
+    pub async fn join_group(&self, group: &str) {
+        self.server
+            .groups
+            .write()
+            .await
+            .entry(group.to_owned())
+            .or_default()
+            .insert(self.id);
+    }
+
+    /// Remove this [`Session`] from the named `group`.
+    pub async fn leave_group(&self, group: &str) {
+        let mut groups = self.server.groups.write().await;
+        if let Some(members) = groups.get_mut(group) {
+            members.remove(&self.id);
+            if members.is_empty() {
+                groups.remove(group);
+            }
+        }
+    }
+
+    /// A snapshot of all of this [`Session`]'s metadata.
+    pub fn metadata(&self) -> HashMap<String, String> {
+        self.metadata.read().unwrap().clone()
+    }
+
     /// Handle an incoming request from the [`Client`]. Calling
     /// [`Session::handle_request`] will result in the `send_response` parameter
     /// which was used to construct this [`Session`] to fire one or more times.
@@ -243,7 +872,173 @@ impl Session {
     ///   [`Client::new`]'s `send_request` handler (which may-or-may-not be
     ///   local).
     pub async fn handle_request(&self, request: &[u8]) -> Result<(), ServerError> {
-        self.server.handle_request(self.id, request).await
+        let request_id = self.server.gen_request_id();
+        let header = RequestHeader::decode(request).unwrap_or_default();
+        let span = tracing::info_span!(
+            "handle_request",
+            %request_id,
+            session_id = self.id,
+            msg_id = header.msg_id,
+            entity_id = %header.entity_id,
+        );
+
+        async move {
+            tracing::debug!("Handling request");
+            if !self.server.is_open(self.id).await {
+                return Err(format!("{:?} is closed", self).into());
+            }
+
+            if let Some(resp) = self.check_rate_limit(request, request_id)? {
+                return self
+                    .server
+                    .send_response(self.id, &resp, Some(request_id))
+                    .await;
+            }
+
+            if let Some(resp) = self.check_edit(request, request_id).await? {
+                return self
+                    .server
+                    .send_response(self.id, &resp, Some(request_id))
+                    .await;
+            }
+
+            self.server
+                .handle_request(self.id, request, request_id)
+                .await
+        }
+        .instrument(span)
+        .await
+    }
+
+    /// Replace this [`Session`]'s [`RateLimitConfig`], resetting any
+    /// accumulated usage. See [`Server::set_rate_limits`].
+    pub fn set_rate_limits(&self, config: &RateLimitConfig) {
+        *self.rate_limiter.lock().unwrap() = RateLimiter::new(config);
+    }
+
+    /// Returns the throttle error response for `request` if it exceeds this
+    /// [`Session`]'s rate limits. Requests are only decoded when limits are
+    /// configured.
+    fn check_rate_limit(
+        &self,
+        request: &[u8],
+        request_id: RequestId,
+    ) -> Result<Option<proto::Response>, ServerError> {
+        let mut rate_limiter = self.rate_limiter.lock().unwrap();
+        if rate_limiter.is_empty() {
+            return Ok(None);
+        }
+
+        let req = proto::Request::decode(request)?;
+        let Some(client_req) = &req.client_req else {
+            return Ok(None);
+        };
+
+        match rate_limiter.check(client_req) {
+            Ok(()) => Ok(None),
+            Err(throttled) => {
+                tracing::warn!("{:?} throttled: {}", self, throttled);
+                Ok(Some(proto::Response {
+                    msg_id: req.msg_id,
+                    entity_id: req.entity_id,
+                    client_resp: Some(ClientResp::ServerError(proto::ServerError {
+                        message: throttled.to_string(),
+                        status_code: proto::StatusCode::RateLimited as i32,
+                        request_id: request_id.to_string(),
+                    })),
+                }))
+            },
+        }
+    }
+
+    /// Returns the `EDIT_REJECTED` error response for `request` if it is a
+    /// [`perspective_client::Table::edit_cells`] request which the
+    /// [`Server::set_edit_validator`] validator rejects. Requests are only
+    /// decoded when a validator is set.
+    async fn check_edit(
+        &self,
+        request: &[u8],
+        request_id: RequestId,
+    ) -> Result<Option<proto::Response>, ServerError> {
+        let Some(validator) = self.server.edit_validator.read().await.clone() else {
+            return Ok(None);
+        };
+
+        let req = proto::Request::decode(request)?;
+        let Some(ClientReq::TableEditCellsReq(edit_cells)) = &req.client_req else {
+            return Ok(None);
+        };
+
+        let metadata = self.metadata();
+        let edit_request = CellEditRequest {
+            session_id: self.id,
+            session_metadata: &metadata,
+            table_id: &req.entity_id,
+            user: edit_cells.user.as_deref(),
+            edits: &edit_cells.edits,
+        };
+
+        match validator(&edit_request) {
+            Ok(()) => Ok(None),
+            Err(message) => {
+                tracing::warn!("{:?} edit rejected: {}", self, message);
+                Ok(Some(proto::Response {
+                    msg_id: req.msg_id,
+                    entity_id: req.entity_id.clone(),
+                    client_resp: Some(ClientResp::ServerError(proto::ServerError {
+                        message,
+                        status_code: proto::StatusCode::EditRejected as i32,
+                        request_id: request_id.to_string(),
+                    })),
+                }))
+            },
+        }
+    }
+
+    /// Queue this [`Session`]'s responses rather than sending them to its
+    /// callback, for a [`perspective_client::Client`] which consumes
+    /// responses (e.g. a large export) slower than the [`Server`] produces
+    /// them. Read queued responses with [`Session::next_response`], at
+    /// whatever pace the connection allows. Responses beyond
+    /// [`ResponseQueueConfig::memory_limit`] are spilled to a temporary file,
+    /// so a slow [`Session`] does not grow process memory without bound.
+    ///
+    /// Calling this again replaces the config for responses queued after the
+    /// call.
+    pub async fn set_response_queue(&self, config: ResponseQueueConfig) {
+        let mut queues = self.server.queues.write().await;
+        match queues.get(&self.id) {
+            Some(queue) => queue.lock().unwrap().set_config(config),
+            None => {
+                queues.insert(
+                    self.id,
+                    Arc::new(std::sync::Mutex::new(ResponseQueue::new(config))),
+                );
+            },
+        }
+    }
+
+    /// Take the oldest response from this [`Session`]'s response queue, or
+    /// `None` if it is empty (or not enabled via
+    /// [`Session::set_response_queue`]).
+    pub async fn next_response(&self) -> Result<Option<QueuedResponse>, ServerError> {
+        let Some(queue) = self.server.queues.read().await.get(&self.id).cloned() else {
+            return Ok(None);
+        };
+
+        let resp = queue.lock().unwrap().pop()?;
+        Ok(resp)
+    }
+
+    /// The size of this [`Session`]'s response queue, if enabled via
+    /// [`Session::set_response_queue`].
+    pub async fn response_queue_stats(&self) -> Option<ResponseQueueStats> {
+        self.server
+            .queues
+            .read()
+            .await
+            .get(&self.id)
+            .map(|x| x.lock().unwrap().stats())
     }
 
     /// Flush any pending messages which may have resulted from previous
@@ -266,6 +1061,17 @@ impl Session {
         self.server.poll().await
     }
 
+    /// How long until a [`Session::poll`] is needed to commit updates held by
+    /// a [`perspective_client::Table`] created with
+    /// [`perspective_client::TableInitOptions::batch_latency_ms`], or `None`
+    /// if no updates are held. Held updates are otherwise only committed by a
+    /// later poll or read, so whenever this is `Some`, a [`Session::poll`]
+    /// should be scheduled after the returned delay.
+    pub fn next_poll_delay(&self) -> Option<Duration> {
+        let delay_ms = ffi::get_poll_delay_ms(&self.server.server);
+        u64::try_from(delay_ms).ok().map(Duration::from_millis)
+    }
+
     /// Close this [`Session`], cleaning up any callbacks (e.g. arguments
     /// provided to [`Session::handle_request`] or
     /// [`perspective_client::View::OnUpdate`]) and resources (e.g. views
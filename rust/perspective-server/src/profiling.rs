@@ -0,0 +1,107 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use perspective_client::proto;
+
+use crate::ServerError;
+
+/// The CPU profile in progress, if any. The profiler samples every thread of
+/// the process, so at most one profile may run at a time per process.
+#[derive(Default)]
+pub(crate) struct Profiler {
+    #[cfg(unix)]
+    cpu: Option<(pprof::ProfilerGuard<'static>, std::time::Instant)>,
+}
+
+impl Profiler {
+    #[cfg(unix)]
+    pub fn start_cpu(&mut self, frequency: i32) -> Result<(), ServerError> {
+        if self.cpu.is_some() {
+            return Err("A CPU profile is already running".into());
+        }
+
+        let guard = pprof::ProfilerGuardBuilder::default()
+            .frequency(frequency)
+            .blocklist(&["libc", "libgcc", "pthread", "vdso"])
+            .build()?;
+
+        self.cpu = Some((guard, std::time::Instant::now()));
+        Ok(())
+    }
+
+    #[cfg(unix)]
+    pub fn stop_cpu(&mut self) -> Result<proto::Profile, ServerError> {
+        use pprof::protos::Message;
+
+        let (guard, start) = self.cpu.take().ok_or("No CPU profile is running")?;
+        let duration = start.elapsed();
+        let mut pprof = vec![];
+        guard.report().build()?.pprof()?.encode(&mut pprof)?;
+        Ok(new_profile(
+            proto::profile::Kind::Cpu,
+            pprof,
+            Some(duration),
+        ))
+    }
+
+    #[cfg(not(unix))]
+    pub fn start_cpu(&mut self, _frequency: i32) -> Result<(), ServerError> {
+        Err("CPU profiles are not supported on this platform".into())
+    }
+
+    #[cfg(not(unix))]
+    pub fn stop_cpu(&mut self) -> Result<proto::Profile, ServerError> {
+        Err("CPU profiles are not supported on this platform".into())
+    }
+}
+
+/// Dump the live allocations of the process, which requires it to use
+/// jemalloc as its allocator (for C++ too, e.g. via `tikv-jemallocator`'s
+/// `unprefixed_malloc_on_supported_platforms` feature), started with
+/// profiling enabled, e.g. `_RJEM_MALLOC_CONF=prof:true,prof_active:true`.
+#[cfg(target_os = "linux")]
+pub(crate) async fn heap_snapshot() -> Result<proto::Profile, ServerError> {
+    let prof_ctl = jemalloc_pprof::PROF_CTL
+        .as_ref()
+        .ok_or("Heap snapshots require jemalloc with profiling enabled")?;
+
+    let mut prof_ctl = prof_ctl.lock().await;
+    if !prof_ctl.activated() {
+        return Err("Heap profiling is not active".into());
+    }
+
+    let pprof = prof_ctl.dump_pprof().map_err(|e| e.to_string())?;
+    Ok(new_profile(proto::profile::Kind::Heap, pprof, None))
+}
+
+#[cfg(not(target_os = "linux"))]
+pub(crate) async fn heap_snapshot() -> Result<proto::Profile, ServerError> {
+    Err("Heap snapshots are not supported on this platform".into())
+}
+
+#[cfg(unix)]
+fn new_profile(
+    kind: proto::profile::Kind,
+    pprof: Vec<u8>,
+    duration: Option<std::time::Duration>,
+) -> proto::Profile {
+    let timestamp = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap_or_default();
+
+    proto::Profile {
+        kind: kind as i32,
+        pprof,
+        timestamp: timestamp.as_secs_f64() * 1000.0,
+        duration_ms: duration.map_or(0.0, |x| x.as_secs_f64() * 1000.0),
+    }
+}
@@ -0,0 +1,193 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+use perspective_client::proto::request::ClientReq;
+
+/// A token-bucket rate limit: at most `capacity` requests may be made in a
+/// burst, and tokens are replenished continuously at a rate of `capacity` per
+/// `period`. A `capacity` of `0` rejects every request of the limited type.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RateLimit {
+    pub capacity: u32,
+    pub period: Duration,
+}
+
+impl RateLimit {
+    pub fn new(capacity: u32, period: Duration) -> Self {
+        RateLimit { capacity, period }
+    }
+
+    pub fn per_second(capacity: u32) -> Self {
+        Self::new(capacity, Duration::from_secs(1))
+    }
+
+    pub fn per_minute(capacity: u32) -> Self {
+        Self::new(capacity, Duration::from_secs(60))
+    }
+}
+
+/// Rate limits for a [`crate::Session`], set via
+/// [`crate::Server::set_rate_limits`] (for all new sessions) or
+/// [`crate::Session::set_rate_limits`].
+///
+/// A `default` limit is shared by _every_ message the session sends, while
+/// limits added with [`RateLimitConfig::with_limit`] apply only to messages of
+/// that type (keyed by the request's wire name, e.g. `"view_to_arrow_req"`).
+/// A request must satisfy both to be handled.
+///
+/// # Examples
+///
+/// ```rust
+/// # use perspective_server::{RateLimit, RateLimitConfig};
+/// let config = RateLimitConfig::default()
+///     .with_default(RateLimit::per_second(100))
+///     .with_limit("view_to_arrow_req", RateLimit::per_minute(10));
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct RateLimitConfig {
+    pub default: Option<RateLimit>,
+    pub by_request: HashMap<String, RateLimit>,
+}
+
+impl RateLimitConfig {
+    pub fn with_default(mut self, limit: RateLimit) -> Self {
+        self.default = Some(limit);
+        self
+    }
+
+    pub fn with_limit<S: Into<String>>(mut self, req_name: S, limit: RateLimit) -> Self {
+        self.by_request.insert(req_name.into(), limit);
+        self
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.default.is_none() && self.by_request.is_empty()
+    }
+}
+
+#[derive(Debug)]
+struct TokenBucket {
+    limit: RateLimit,
+    tokens: f64,
+    last_refill: Instant,
+}
+
+impl TokenBucket {
+    fn new(limit: RateLimit, now: Instant) -> Self {
+        TokenBucket {
+            limit,
+            tokens: limit.capacity as f64,
+            last_refill: now,
+        }
+    }
+
+    fn rate(&self) -> f64 {
+        self.limit.capacity as f64 / self.limit.period.as_secs_f64()
+    }
+
+    fn refill(&mut self, now: Instant) {
+        let elapsed = now.saturating_duration_since(self.last_refill);
+        self.tokens =
+            (self.tokens + elapsed.as_secs_f64() * self.rate()).min(self.limit.capacity as f64);
+        self.last_refill = now;
+    }
+
+    /// How long until a token is available, or `None` if one is available
+    /// now. A bucket with zero `capacity` never refills, so it waits
+    /// [`Duration::MAX`].
+    fn wait_time(&mut self, now: Instant) -> Option<Duration> {
+        self.refill(now);
+        if self.tokens >= 1.0 {
+            None
+        } else {
+            let secs = (1.0 - self.tokens) / self.rate();
+            Some(Duration::try_from_secs_f64(secs).unwrap_or(Duration::MAX))
+        }
+    }
+}
+
+/// Rejection info returned by [`RateLimiter::check`].
+#[derive(Debug)]
+pub(crate) struct Throttled {
+    pub req_name: &'static str,
+    pub retry_after: Duration,
+}
+
+impl std::fmt::Display for Throttled {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.retry_after == Duration::MAX {
+            return write!(f, "`{}` requests are not allowed", self.req_name);
+        }
+
+        write!(
+            f,
+            "Too many `{}` requests, retry in {}ms",
+            self.req_name,
+            self.retry_after.as_millis()
+        )
+    }
+}
+
+/// The per-[`crate::Session`] state of a [`RateLimitConfig`].
+#[derive(Debug, Default)]
+pub(crate) struct RateLimiter {
+    default: Option<TokenBucket>,
+    by_request: HashMap<String, TokenBucket>,
+}
+
+impl RateLimiter {
+    pub fn new(config: &RateLimitConfig) -> Self {
+        let now = Instant::now();
+        RateLimiter {
+            default: config.default.map(|x| TokenBucket::new(x, now)),
+            by_request: config
+                .by_request
+                .iter()
+                .map(|(k, v)| (k.clone(), TokenBucket::new(*v, now)))
+                .collect(),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.default.is_none() && self.by_request.is_empty()
+    }
+
+    /// Consume a token for `req` from each applicable bucket. Tokens are only
+    /// consumed if _all_ buckets have one available, so a throttled request
+    /// does not count against the session's other limits.
+    pub fn check(&mut self, req: &ClientReq) -> Result<(), Throttled> {
+        let now = Instant::now();
+        let req_name = req.name();
+        let mut buckets = self
+            .default
+            .iter_mut()
+            .chain(self.by_request.get_mut(req_name))
+            .collect::<Vec<_>>();
+
+        let retry_after = buckets.iter_mut().filter_map(|x| x.wait_time(now)).max();
+        if let Some(retry_after) = retry_after {
+            return Err(Throttled {
+                req_name,
+                retry_after,
+            });
+        }
+
+        for bucket in buckets {
+            bucket.tokens -= 1.0;
+        }
+
+        Ok(())
+    }
+}
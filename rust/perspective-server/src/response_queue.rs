@@ -0,0 +1,210 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use std::collections::VecDeque;
+use std::fs::{File, OpenOptions};
+use std::io::{Read, Seek, SeekFrom, Write};
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use crate::RequestId;
+
+static SPILL_FILE_ID: AtomicU64 = AtomicU64::new(0);
+
+/// Configuration for a [`crate::Session`]'s response queue, enabled via
+/// [`crate::Session::set_response_queue`].
+///
+/// Up to `memory_limit` bytes of responses are held in memory, and any
+/// response which would exceed it is written to a temporary file in
+/// `spill_dir` instead (by default, [`std::env::temp_dir`]) until it is read.
+///
+/// # Examples
+///
+/// ```rust
+/// # use perspective_server::ResponseQueueConfig;
+/// let config = ResponseQueueConfig::new(64 * 1024 * 1024).with_spill_dir("/var/tmp");
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct ResponseQueueConfig {
+    pub memory_limit: usize,
+    pub spill_dir: Option<PathBuf>,
+}
+
+impl ResponseQueueConfig {
+    pub fn new(memory_limit: usize) -> Self {
+        Self {
+            memory_limit,
+            spill_dir: None,
+        }
+    }
+
+    pub fn with_spill_dir<P: Into<PathBuf>>(mut self, spill_dir: P) -> Self {
+        self.spill_dir = Some(spill_dir.into());
+        self
+    }
+}
+
+/// A response read from a [`crate::Session`]'s response queue by
+/// [`crate::Session::next_response`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct QueuedResponse {
+    pub message: Vec<u8>,
+
+    /// The request which caused this response, if any.
+    pub request_id: Option<RequestId>,
+}
+
+/// The size of a [`crate::Session`]'s response queue, as returned by
+/// [`crate::Session::response_queue_stats`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ResponseQueueStats {
+    pub len: usize,
+    pub memory_bytes: usize,
+    pub spilled_bytes: u64,
+}
+
+#[derive(Debug)]
+enum Entry {
+    Memory(Vec<u8>),
+    Spilled { offset: u64, len: usize },
+}
+
+/// A temporary file of spilled responses, which is deleted on drop.
+#[derive(Debug)]
+struct SpillFile {
+    path: PathBuf,
+    file: File,
+    len: u64,
+}
+
+impl SpillFile {
+    fn create(config: &ResponseQueueConfig) -> std::io::Result<Self> {
+        let dir = config.spill_dir.clone().unwrap_or_else(std::env::temp_dir);
+        let path = dir.join(format!(
+            "perspective-{}-{}.spill",
+            std::process::id(),
+            SPILL_FILE_ID.fetch_add(1, Ordering::Relaxed)
+        ));
+
+        let file = OpenOptions::new()
+            .read(true)
+            .write(true)
+            .create_new(true)
+            .open(&path)?;
+
+        Ok(Self { path, file, len: 0 })
+    }
+}
+
+impl Drop for SpillFile {
+    fn drop(&mut self) {
+        if let Err(err) = std::fs::remove_file(&self.path) {
+            tracing::warn!("Failed to remove {}: {}", self.path.display(), err);
+        }
+    }
+}
+
+/// A FIFO of responses for one [`crate::Session`] whose in-memory size is
+/// bounded by [`ResponseQueueConfig::memory_limit`].
+#[derive(Debug)]
+pub(crate) struct ResponseQueue {
+    config: ResponseQueueConfig,
+    entries: VecDeque<(Entry, Option<RequestId>)>,
+    memory_bytes: usize,
+    spilled: usize,
+    spill: Option<SpillFile>,
+}
+
+impl ResponseQueue {
+    pub fn new(config: ResponseQueueConfig) -> Self {
+        Self {
+            config,
+            entries: VecDeque::new(),
+            memory_bytes: 0,
+            spilled: 0,
+            spill: None,
+        }
+    }
+
+    /// Replace this queue's config. Responses already queued stay where they
+    /// are.
+    pub fn set_config(&mut self, config: ResponseQueueConfig) {
+        self.config = config;
+    }
+
+    pub fn stats(&self) -> ResponseQueueStats {
+        ResponseQueueStats {
+            len: self.entries.len(),
+            memory_bytes: self.memory_bytes,
+            spilled_bytes: self.spill.as_ref().map_or(0, |x| x.len),
+        }
+    }
+
+    pub fn push(&mut self, msg: &[u8], request_id: Option<RequestId>) -> std::io::Result<()> {
+        let entry = if self.memory_bytes + msg.len() <= self.config.memory_limit {
+            self.memory_bytes += msg.len();
+            Entry::Memory(msg.to_vec())
+        } else {
+            if self.spill.is_none() {
+                self.spill = Some(SpillFile::create(&self.config)?);
+            }
+
+            let spill = self.spill.as_mut().unwrap();
+
+            let offset = spill.len;
+            spill.file.seek(SeekFrom::Start(offset))?;
+            spill.file.write_all(msg)?;
+            spill.len += msg.len() as u64;
+            self.spilled += 1;
+            Entry::Spilled {
+                offset,
+                len: msg.len(),
+            }
+        };
+
+        self.entries.push_back((entry, request_id));
+        Ok(())
+    }
+
+    pub fn pop(&mut self) -> std::io::Result<Option<QueuedResponse>> {
+        let Some((entry, request_id)) = self.entries.pop_front() else {
+            return Ok(None);
+        };
+
+        let message = match entry {
+            Entry::Memory(message) => {
+                self.memory_bytes -= message.len();
+                message
+            },
+            Entry::Spilled { offset, len } => {
+                let spill = self.spill.as_mut().expect("Spill file missing");
+                let mut message = vec![0; len];
+                spill.file.seek(SeekFrom::Start(offset))?;
+                spill.file.read_exact(&mut message)?;
+                self.spilled -= 1;
+                if self.spilled == 0 {
+                    // Every spilled response has been read, so the file can
+                    // be reused from the start.
+                    spill.file.set_len(0)?;
+                    spill.len = 0;
+                }
+
+                message
+            },
+        };
+
+        Ok(Some(QueuedResponse {
+            message,
+            request_id,
+        }))
+    }
+}
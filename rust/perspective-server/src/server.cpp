@@ -39,12 +39,13 @@ rust::Box<ResponseBatch>
 handle_request(
     const ProtoApiServer& self,
     std::uint32_t client_id,
-    rust::Slice<const std::uint8_t> message
+    rust::Slice<const std::uint8_t> message,
+    rust::Str request_id
 ) {
 
     std::string message_str(message.begin(), message.end());
     std::vector<ProtoApiResponse> responses =
-        self.handle_request(client_id, message_str);
+        self.handle_request(client_id, message_str, std::string(request_id));
     rust::Box<ResponseBatch> batch = create_response_batch();
 
     for (const auto& response : responses) {
@@ -54,6 +55,19 @@ handle_request(
     return batch;
 }
 
+rust::Box<ResponseBatch>
+unhost_table(const ProtoApiServer& s, rust::Str table_id) {
+    auto& self = const_cast<ProtoApiServer&>(s);
+    std::vector<ProtoApiResponse> responses =
+        self.unhost_table(std::string(table_id));
+    rust::Box<ResponseBatch> batch = create_response_batch();
+    for (const auto& response : responses) {
+        batch->push_response(response.client_id, response.data);
+    }
+
+    return batch;
+}
+
 rust::Box<ResponseBatch>
 poll(const ProtoApiServer& s) {
     auto& self = const_cast<ProtoApiServer&>(s);
@@ -64,4 +78,117 @@ poll(const ProtoApiServer& s) {
     }
 
     return batch;
+}
+
+std::int64_t
+get_poll_delay_ms(const ProtoApiServer& s) {
+    auto& self = const_cast<ProtoApiServer&>(s);
+    return self.get_poll_delay_ms();
+}
+
+rust::Box<ResponseBatch>
+host_arrow_stream(
+    const ProtoApiServer& s,
+    rust::Str table_id,
+    rust::Str index,
+    std::size_t stream
+) {
+    auto& self = const_cast<ProtoApiServer&>(s);
+    std::vector<ProtoApiResponse> responses = self.host_arrow_stream(
+        std::string(table_id),
+        std::string(index),
+        reinterpret_cast<ArrowArrayStream*>(stream)
+    );
+
+    rust::Box<ResponseBatch> batch = create_response_batch();
+    for (const auto& response : responses) {
+        batch->push_response(response.client_id, response.data);
+    }
+
+    return batch;
+}
+
+void
+update_arrow_stream(
+    const ProtoApiServer& s,
+    rust::Str table_id,
+    std::size_t stream,
+    std::uint32_t port_id
+) {
+    auto& self = const_cast<ProtoApiServer&>(s);
+    self.update_arrow_stream(
+        std::string(table_id),
+        reinterpret_cast<ArrowArrayStream*>(stream),
+        port_id
+    );
+}
+
+rust::Box<ResponseBatch>
+view_to_arrow_stream(
+    const ProtoApiServer& s, rust::Str view_id, std::size_t out
+) {
+    auto& self = const_cast<ProtoApiServer&>(s);
+    std::vector<ProtoApiResponse> responses = self.view_to_arrow_stream(
+        std::string(view_id), reinterpret_cast<ArrowArrayStream*>(out)
+    );
+
+    rust::Box<ResponseBatch> batch = create_response_batch();
+    for (const auto& response : responses) {
+        batch->push_response(response.client_id, response.data);
+    }
+
+    return batch;
+}
+
+void
+set_slow_op_threshold(const ProtoApiServer& s, std::int64_t threshold_us) {
+    auto& self = const_cast<ProtoApiServer&>(s);
+    self.set_slow_op_threshold(threshold_us);
+}
+
+void
+push_profile(
+    const ProtoApiServer& s, rust::Slice<const std::uint8_t> profile
+) {
+    auto& self = const_cast<ProtoApiServer&>(s);
+    self.push_profile(std::string(profile.begin(), profile.end()));
+}
+
+void
+define_expression(
+    const ProtoApiServer& s, rust::Str name, rust::Str expression
+) {
+    auto& self = const_cast<ProtoApiServer&>(s);
+    self.define_expression(std::string(name), std::string(expression));
+}
+
+void
+remove_expression(const ProtoApiServer& s, rust::Str name) {
+    auto& self = const_cast<ProtoApiServer&>(s);
+    self.remove_expression(std::string(name));
+}
+
+void
+set_engine_affinity(
+    rust::Slice<const std::uint32_t> cores,
+    std::int32_t numa_node,
+    std::uint32_t num_threads
+) {
+    ProtoApiServer::set_engine_affinity(
+        std::vector<std::uint32_t>(cores.begin(), cores.end()),
+        numa_node,
+        num_threads
+    );
+}
+
+void
+set_engine_config(
+    std::uint64_t parallel_threshold,
+    std::uint64_t expression_block_size,
+    std::uint64_t tree_capacity,
+    std::uint64_t port_capacity
+) {
+    ProtoApiServer::set_engine_config(
+        parallel_threshold, expression_block_size, tree_capacity, port_capacity
+    );
 }
\ No newline at end of file
@@ -195,6 +195,23 @@ impl FilterColumnProps {
                     _ => Scalar::Bool(false),
                 })),
 
+                // Durations are parsed by the engine, e.g. `1d 02:00:00`
+                Some(ColumnType::Duration) if !val.is_empty() => {
+                    Some(FilterTerm::Scalar(Scalar::String(val)))
+                },
+
+                // Lists and JSON documents compare by their JSON text,
+                // e.g. `[1,2]`, and binary values by their base64 text.
+                // UUIDs and IP addresses (or CIDR blocks, for `in subnet`) are
+                // parsed by the engine.
+                Some(
+                    ColumnType::List
+                    | ColumnType::Json
+                    | ColumnType::Binary
+                    | ColumnType::Uuid
+                    | ColumnType::Ip,
+                ) if !val.is_empty() => Some(FilterTerm::Scalar(Scalar::String(val))),
+
                 // shouldn't be reachable ..
                 _ => None,
             }
@@ -13,6 +13,8 @@
 pub mod stub;
 mod symbol;
 
+use std::sync::Arc;
+
 use itertools::Itertools;
 use perspective_client::{clone, ColumnType};
 use perspective_js::utils::*;
@@ -21,6 +23,7 @@ use yew::{function_component, html, Html, Properties};
 use crate::components::column_settings_sidebar::style_tab::stub::Stub;
 use crate::components::column_settings_sidebar::style_tab::symbol::SymbolStyle;
 use crate::components::datetime_column_style::DatetimeColumnStyle;
+use crate::components::form::select_field::SelectValueField;
 use crate::components::number_column_style::NumberColumnStyle;
 use crate::components::string_column_style::StringColumnStyle;
 use crate::components::style_controls::CustomNumberFormat;
@@ -29,9 +32,11 @@ use crate::custom_events::CustomEvents;
 use crate::derive_model;
 use crate::model::PluginColumnStyles;
 use crate::presentation::Presentation;
-use crate::renderer::Renderer;
+use crate::renderer::{CellRendererRegistryExt, Renderer, CELL_RENDERER_REGISTRY};
 use crate::session::Session;
 
+const DEFAULT_RENDERER: &str = "Default";
+
 #[derive(Clone, PartialEq, Properties)]
 pub struct StyleTabProps {
     pub custom_events: CustomEvents,
@@ -134,6 +139,29 @@ pub fn StyleTab(props: &StyleTabProps) -> Html {
                 }))
             }
 
+            if opts.cell_renderer.unwrap_or_default()
+                && let Some(ty) = props.ty
+            {
+                let mut values = CELL_RENDERER_REGISTRY.available_cell_renderer_names(ty);
+                if !values.is_empty() {
+                    values.insert(0, DEFAULT_RENDERER.to_owned());
+                    let current_value = config.as_ref().and_then(|config| config.renderer.clone());
+                    let on_change = on_change.reform(|x: Option<String>| {
+                        ColumnConfigValueUpdate::Renderer(x.filter(|x| x != DEFAULT_RENDERER))
+                    });
+
+                    components.push(("Renderer", html! {
+                        <SelectValueField<String>
+                            label="renderer"
+                            {current_value}
+                            default_value={DEFAULT_RENDERER.to_owned()}
+                            values={Arc::new(values)}
+                            {on_change}
+                        />
+                    }));
+                }
+            }
+
             if opts.number_string_format.unwrap_or_default() {
                 let restored_config = config
                     .as_ref()
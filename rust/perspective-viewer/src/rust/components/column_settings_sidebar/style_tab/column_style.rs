@@ -57,6 +57,12 @@ where
         ColumnType::Integer => style.integer,
         ColumnType::Float => style.float,
         ColumnType::Boolean => style.bool,
+        ColumnType::Duration => return Err("Durations aren't styled yet.".into()),
+        ColumnType::List => return Err("Lists aren't styled yet.".into()),
+        ColumnType::Json => return Err("JSON columns aren't styled yet.".into()),
+        ColumnType::Binary => return Err("Binary columns aren't styled yet.".into()),
+        ColumnType::Uuid => return Err("UUID columns aren't styled yet.".into()),
+        ColumnType::Ip => return Err("IP address columns aren't styled yet.".into()),
     };
     serde_json::from_value(val)
         .map_err(|e| format!("Could not deserialize default_config with error {e:?}"))
@@ -43,6 +43,11 @@ pub struct ColumnConfigValues {
     #[serde(default)]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub number_format: Option<CustomNumberFormatConfig>,
+
+    /// The name of a cell renderer registered via `registerCellRenderer()`.
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub renderer: Option<String>,
 }
 
 #[derive(Debug)]
@@ -52,6 +57,7 @@ pub enum ColumnConfigValueUpdate {
     DatagridDatetimeStyle(Option<DatetimeColumnStyleConfig>),
     Symbols(Option<HashMap<String, String>>),
     CustomNumberStringFormat(Option<CustomNumberFormatConfig>),
+    Renderer(Option<String>),
 }
 
 impl ColumnConfigValues {
@@ -77,6 +83,10 @@ impl ColumnConfigValues {
                 number_format: update.filter(|x| x != &CustomNumberFormatConfig::default()),
                 ..self
             },
+            ColumnConfigValueUpdate::Renderer(update) => Self {
+                renderer: update,
+                ..self
+            },
         }
     }
 
@@ -96,6 +106,7 @@ pub struct ColumnStyleOpts {
     pub datagrid_datetime_style: Option<DatetimeColumnStyleDefaultConfig>,
     pub symbols: Option<KeyValueOpts>,
     pub number_string_format: Option<bool>,
+    pub cell_renderer: Option<bool>,
 }
 
 #[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Copy)]
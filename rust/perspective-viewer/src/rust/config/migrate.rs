@@ -0,0 +1,208 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+//! Migrations for serialized `ViewerConfig` JSON from older versions of
+//! `<perspective-viewer>`, applied to saved layouts before they are restored.
+
+use serde_json::{Map, Value};
+
+use super::API_VERSION;
+
+type Version = (u32, u32, u32);
+
+/// Each migration upgrades a config _from_ a version older than its key, and
+/// must be listed in ascending version order.
+static MIGRATIONS: &[(Version, fn(&mut Map<String, Value>))] = &[
+    ((1, 0, 0), migrate_pivots),
+    ((1, 0, 0), migrate_plugin_names),
+    ((2, 10, 0), migrate_columns_config),
+];
+
+/// Parse a `"major.minor.patch"` version string, ignoring any pre-release
+/// suffix.  Missing or malformed versions are treated as `0.0.0`, so they
+/// receive every migration.
+fn parse_version(version: Option<&str>) -> Version {
+    let mut parts = version
+        .unwrap_or_default()
+        .split(['.', '-', '+'])
+        .map(|x| x.parse::<u32>().ok());
+
+    match (parts.next(), parts.next(), parts.next()) {
+        (Some(Some(major)), Some(Some(minor)), Some(Some(patch))) => (major, minor, patch),
+        _ => (0, 0, 0),
+    }
+}
+
+/// Migrate a serialized `ViewerConfig` to the current [`struct@API_VERSION`].
+/// Configs newer than the current version are returned unchanged.
+pub fn migrate_viewer_config(mut config: Value) -> Value {
+    if let Value::Object(fields) = &mut config {
+        let version = parse_version(fields.get("version").and_then(|x| x.as_str()));
+        for (_, migration) in MIGRATIONS.iter().filter(|(x, _)| version < *x) {
+            migration(fields);
+        }
+
+        if version < parse_version(Some(*API_VERSION)) {
+            fields.insert("version".to_owned(), Value::from(*API_VERSION));
+        }
+    }
+
+    config
+}
+
+/// `row_pivots` and `column_pivots` were renamed `group_by` and `split_by`.
+fn migrate_pivots(fields: &mut Map<String, Value>) {
+    for (old, new) in [("row_pivots", "group_by"), ("column_pivots", "split_by")] {
+        if let Some(value) = fields.remove(old) {
+            fields.entry(new).or_insert(value);
+        }
+    }
+}
+
+/// Plugins were renamed from their custom element style names to display
+/// names.
+fn migrate_plugin_names(fields: &mut Map<String, Value>) {
+    const PLUGIN_NAMES: &[(&str, &str)] = &[
+        ("datagrid", "Datagrid"),
+        ("d3_y_bar", "Y Bar"),
+        ("d3_x_bar", "X Bar"),
+        ("d3_y_line", "Y Line"),
+        ("d3_y_area", "Y Area"),
+        ("d3_y_scatter", "Y Scatter"),
+        ("d3_xy_scatter", "X/Y Scatter"),
+        ("d3_xy_line", "X/Y Line"),
+        ("d3_treemap", "Treemap"),
+        ("d3_sunburst", "Sunburst"),
+        ("d3_heatmap", "Heatmap"),
+        ("d3_candlestick", "Candlestick"),
+        ("d3_ohlc", "OHLC"),
+    ];
+
+    if let Some(Value::String(plugin)) = fields.get_mut("plugin")
+        && let Some((_, name)) = PLUGIN_NAMES.iter().find(|(x, _)| x == plugin)
+    {
+        *plugin = (*name).to_owned();
+    }
+}
+
+/// Datagrid column styles moved from `plugin_config.columns` to the
+/// viewer-managed `columns_config`, except for column width overrides which
+/// are still owned by the plugin.
+fn migrate_columns_config(fields: &mut Map<String, Value>) {
+    if fields.get("plugin").and_then(|x| x.as_str()) != Some("Datagrid") {
+        return;
+    }
+
+    let Some(Value::Object(plugin_columns)) = fields
+        .get_mut("plugin_config")
+        .and_then(|x| x.get_mut("columns"))
+    else {
+        return;
+    };
+
+    let mut moved = Map::new();
+    for (column_name, styles) in plugin_columns.iter_mut() {
+        if let Value::Object(styles) = styles {
+            let mut rest = std::mem::take(styles);
+            if let Some(size) = rest.remove("column_size_override") {
+                styles.insert("column_size_override".to_owned(), size);
+            }
+
+            if !rest.is_empty() {
+                moved.insert(column_name.clone(), Value::Object(rest));
+            }
+        }
+    }
+
+    plugin_columns.retain(|_, x| x.as_object().map(|x| !x.is_empty()).unwrap_or(true));
+    if let Value::Object(columns_config) = fields
+        .entry("columns_config")
+        .or_insert_with(|| Value::Object(Map::new()))
+    {
+        for (column_name, styles) in moved {
+            columns_config.entry(column_name).or_insert(styles);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+    use wasm_bindgen_test::*;
+
+    use super::*;
+
+    #[wasm_bindgen_test]
+    fn test_parse_version() {
+        assert_eq!(parse_version(Some("2.10.1")), (2, 10, 1));
+        assert_eq!(parse_version(Some("3.0.0-rc.1")), (3, 0, 0));
+        assert_eq!(parse_version(Some("latest")), (0, 0, 0));
+        assert_eq!(parse_version(None), (0, 0, 0));
+    }
+
+    #[wasm_bindgen_test]
+    fn test_migrate_legacy_config() {
+        let config = migrate_viewer_config(json!({
+            "plugin": "datagrid",
+            "row_pivots": ["State"],
+            "column_pivots": ["Region"],
+            "plugin_config": {
+                "columns": {
+                    "Sales": {
+                        "number_fg_mode": "bar",
+                        "column_size_override": 100
+                    }
+                }
+            }
+        }));
+
+        assert_eq!(
+            config,
+            json!({
+                "version": *API_VERSION,
+                "plugin": "Datagrid",
+                "group_by": ["State"],
+                "split_by": ["Region"],
+                "plugin_config": {
+                    "columns": {
+                        "Sales": {
+                            "column_size_override": 100
+                        }
+                    }
+                },
+                "columns_config": {
+                    "Sales": {
+                        "number_fg_mode": "bar"
+                    }
+                }
+            })
+        );
+    }
+
+    #[wasm_bindgen_test]
+    fn test_migrate_current_config_is_unchanged() {
+        let config = json!({
+            "version": *API_VERSION,
+            "plugin": "Datagrid",
+            "group_by": ["State"],
+            "plugin_config": {
+                "columns": {
+                    "Sales": {
+                        "number_fg_mode": "bar"
+                    }
+                }
+            }
+        });
+
+        assert_eq!(migrate_viewer_config(config.clone()), config);
+    }
+}
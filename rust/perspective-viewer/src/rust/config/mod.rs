@@ -15,16 +15,20 @@
 
 mod columns_config;
 mod datetime_column_style;
+mod migrate;
 mod number_column_style;
 mod number_string_format;
+mod saved_layouts;
 mod string_column_style;
 pub mod view_config;
 mod viewer_config;
 
 pub use columns_config::*;
 pub use datetime_column_style::*;
+pub use migrate::*;
 pub use number_column_style::*;
 pub use number_string_format::*;
+pub use saved_layouts::*;
 pub use string_column_style::*;
 pub use view_config::*;
 pub use viewer_config::*;
@@ -0,0 +1,77 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use std::collections::BTreeMap;
+
+use perspective_js::utils::*;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use super::{migrate_viewer_config, ViewerConfig};
+
+/// The `localStorage` key under which all saved layouts are persisted.
+const SAVED_LAYOUTS_KEY: &str = "perspective-viewer-layouts";
+
+/// A collection of named, serialized `ViewerConfig` layouts persisted to
+/// `localStorage`.  Layouts are stored as JSON as-saved and migrated to the
+/// current version when read, so layouts saved by older versions of
+/// `<perspective-viewer>` can still be restored.
+#[derive(Default, Deserialize, Serialize)]
+#[serde(transparent)]
+pub struct SavedLayouts(BTreeMap<String, Value>);
+
+impl SavedLayouts {
+    fn storage() -> ApiResult<web_sys::Storage> {
+        global::window().local_storage()?.into_apierror()
+    }
+
+    /// Load all saved layouts from `localStorage`.
+    pub fn load() -> ApiResult<Self> {
+        match Self::storage()?.get_item(SAVED_LAYOUTS_KEY)? {
+            Some(json) => Ok(serde_json::from_str(&json)?),
+            None => Ok(Self::default()),
+        }
+    }
+
+    fn store(&self) -> ApiResult<()> {
+        let json = serde_json::to_string(self)?;
+        Ok(Self::storage()?.set_item(SAVED_LAYOUTS_KEY, &json)?)
+    }
+
+    /// The names of all saved layouts, in sorted order.
+    pub fn names(&self) -> Vec<String> {
+        self.0.keys().cloned().collect()
+    }
+
+    /// Get a saved layout by name, migrated to the current version.
+    pub fn get(&self, name: &str) -> Option<Value> {
+        self.0.get(name).cloned().map(migrate_viewer_config)
+    }
+
+    /// Save `config` as the layout `name`, replacing any existing layout of
+    /// the same name.
+    pub fn save(&mut self, name: &str, config: &ViewerConfig) -> ApiResult<()> {
+        self.0
+            .insert(name.to_owned(), serde_json::to_value(config)?);
+        self.store()
+    }
+
+    /// Delete the layout `name`, returning whether it existed.
+    pub fn delete(&mut self, name: &str) -> ApiResult<bool> {
+        let existed = self.0.remove(name).is_some();
+        if existed {
+            self.store()?;
+        }
+
+        Ok(existed)
+    }
+}
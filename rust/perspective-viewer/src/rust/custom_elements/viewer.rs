@@ -78,7 +78,13 @@ derive_model!(Renderer, Session, Presentation for PerspectiveViewerElement);
 
 impl CustomElementMetadata for PerspectiveViewerElement {
     const CUSTOM_ELEMENT_NAME: &'static str = "perspective-viewer";
-    const STATICS: &'static [&'static str] = ["registerPlugin", "getExprTKCommands"].as_slice();
+    const STATICS: &'static [&'static str] = [
+        "registerPlugin",
+        "registerCellRenderer",
+        "getCellRenderer",
+        "getExprTKCommands",
+    ]
+    .as_slice();
 }
 
 #[wasm_bindgen]
@@ -315,6 +321,60 @@ impl PerspectiveViewerElement {
         })
     }
 
+    /// Save this element's current state as a named layout in `localStorage`,
+    /// replacing any existing layout with the same name.
+    ///
+    /// # Arguments
+    /// - `name` The name of the layout.
+    #[wasm_bindgen(js_name = "saveLayout")]
+    pub fn save_layout(&self, name: String) -> ApiFuture<()> {
+        let viewer_config_task = self.get_viewer_config();
+        ApiFuture::new(async move {
+            let viewer_config = viewer_config_task.await?;
+            SavedLayouts::load()?.save(&name, &viewer_config)
+        })
+    }
+
+    /// Restore this element from a named layout previously saved with
+    /// `.saveLayout()`, migrating it first if it was saved by an older
+    /// version.
+    ///
+    /// # Arguments
+    /// - `name` The name of the layout.
+    #[wasm_bindgen(js_name = "restoreLayout")]
+    pub fn restore_layout(&self, name: String) -> ApiFuture<()> {
+        let this = self.clone();
+        ApiFuture::new(async move {
+            let layout = SavedLayouts::load()?
+                .get(&name)
+                .ok_or_else(|| format!("Unknown layout \"{}\"", name))?;
+
+            this.restore(JsValue::from_serde_ext(&layout)?).await
+        })
+    }
+
+    /// The names of all layouts saved with `.saveLayout()`, in sorted order.
+    #[wasm_bindgen(js_name = "getLayoutNames")]
+    pub fn get_layout_names(&self) -> ApiResult<js_sys::Array> {
+        Ok(SavedLayouts::load()?
+            .names()
+            .into_iter()
+            .map(JsValue::from)
+            .collect())
+    }
+
+    /// Delete a layout saved with `.saveLayout()`.
+    ///
+    /// # Arguments
+    /// - `name` The name of the layout.
+    ///
+    /// # Returns
+    /// Whether a layout with this name existed.
+    #[wasm_bindgen(js_name = "deleteLayout")]
+    pub fn delete_layout(&self, name: String) -> ApiResult<bool> {
+        SavedLayouts::load()?.delete(&name)
+    }
+
     /// Download this viewer's `View` or `Table` data as a `.csv` file.
     ///
     /// # Arguments
@@ -498,6 +558,34 @@ impl PerspectiveViewerElement {
         self.renderer.set_throttle(val);
     }
 
+    /// Set the render throttling behavior for updates.
+    ///
+    /// # Arguments
+    /// - `options` An object with optional fields `max_fps`, the maximum number
+    ///   of redraws per second (unlimited if omitted), and `batch_window`, the
+    ///   number of milliseconds to conflate updates for before redrawing
+    ///   (adaptive if omitted).
+    #[wasm_bindgen(js_name = "setRenderThrottle")]
+    pub fn set_render_throttle(&self, options: JsValue) -> ApiResult<()> {
+        let options = if options.is_undefined() || options.is_null() {
+            RenderThrottleOptions::default()
+        } else {
+            options.into_serde_ext()?
+        };
+
+        self.renderer.set_render_throttle(&options);
+        Ok(())
+    }
+
+    /// Get the current render throttling options, as set by
+    /// `setRenderThrottle()` or `setThrottle()`.
+    #[wasm_bindgen(js_name = "getRenderThrottle")]
+    pub fn get_render_throttle(&self) -> ApiResult<JsValue> {
+        Ok(JsValue::from_serde_ext(
+            &self.renderer.get_render_throttle(),
+        )?)
+    }
+
     /// Toggle (or force) the config panel open/closed.
     ///
     /// # Arguments
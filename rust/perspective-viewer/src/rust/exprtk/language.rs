@@ -148,7 +148,7 @@ thread_local! {
             CompletionItemSuggestion {
                 label: "sum",
                 insert_text: "sum(${1:x})",
-                documentation: "Sum of all inputs",
+                documentation: "Sum of all inputs, including the elements of lists",
             },
             CompletionItemSuggestion {
                 label: "trunc",
@@ -265,6 +265,111 @@ thread_local! {
                 insert_text: "lower(${1:x})",
                 documentation: "Lowercase of x",
             },
+            CompletionItemSuggestion {
+                label: "levenshtein",
+                insert_text: "levenshtein(${1:x}, ${2:y})",
+                documentation: "Number of single character edits needed to turn string x into string y",
+            },
+            CompletionItemSuggestion {
+                label: "jaro_winkler",
+                insert_text: "jaro_winkler(${1:x}, ${2:y})",
+                documentation: "Jaro-Winkler similarity of strings x and y, from 0 (no characters in common) to 1 (identical)",
+            },
+            CompletionItemSuggestion {
+                label: "soundex",
+                insert_text: "soundex(${1:x})",
+                documentation: "Four character Soundex code of a name, such as soundex('Robert') == 'R163'",
+            },
+            CompletionItemSuggestion {
+                label: "starts_with",
+                insert_text: "starts_with(${1:x}, ${2:prefix})",
+                documentation: "Whether string x starts with prefix",
+            },
+            CompletionItemSuggestion {
+                label: "ends_with",
+                insert_text: "ends_with(${1:x}, ${2:suffix})",
+                documentation: "Whether string x ends with suffix",
+            },
+            CompletionItemSuggestion {
+                label: "len",
+                insert_text: "len(${1:x})",
+                documentation: "Number of elements in a list, or characters in a string",
+            },
+            CompletionItemSuggestion {
+                label: "contains",
+                insert_text: "contains(${1:x}, ${2:value})",
+                documentation: "Whether a list contains value, or a string contains the substring value",
+            },
+            CompletionItemSuggestion {
+                label: "json_extract",
+                insert_text: "json_extract(${1:x}, '${2:$.path}')",
+                documentation: "Returns the value at a path in a JSON document as a string",
+            },
+            CompletionItemSuggestion {
+                label: "json_extract_float",
+                insert_text: "json_extract_float(${1:x}, '${2:$.path}')",
+                documentation: "Returns the value at a path in a JSON document as a float",
+            },
+            CompletionItemSuggestion {
+                label: "json_extract_bool",
+                insert_text: "json_extract_bool(${1:x}, '${2:$.path}')",
+                documentation: "Returns the value at a path in a JSON document as a boolean",
+            },
+            CompletionItemSuggestion {
+                label: "haversine_distance",
+                insert_text: "haversine_distance(${1:lat1}, ${2:lon1}, ${3:lat2}, ${4:lon2})",
+                documentation: "Great-circle distance in kilometers between two points",
+            },
+            CompletionItemSuggestion {
+                label: "within_bbox",
+                insert_text: "within_bbox(${1:lat}, ${2:lon}, ${3:min_lat}, ${4:min_lon}, ${5:max_lat}, ${6:max_lon})",
+                documentation: "Whether a point lies inside a bounding box",
+            },
+            CompletionItemSuggestion {
+                label: "geohash",
+                insert_text: "geohash(${1:lat}, ${2:lon}, ${3:precision})",
+                documentation: "Geohash of a point with precision characters, for bucketing",
+            },
+            CompletionItemSuggestion {
+                label: "in_subnet",
+                insert_text: "in_subnet(${1:ip}, '${2:10.0.0.0/8}')",
+                documentation: "Whether an IP address lies within a CIDR block",
+            },
+            CompletionItemSuggestion {
+                label: "round_to_tick",
+                insert_text: "round_to_tick(${1:x}, ${2:0.05})",
+                documentation: "Round a number to the nearest multiple of a tick size",
+            },
+            CompletionItemSuggestion {
+                label: "bps",
+                insert_text: "bps(${1:x}, ${2:base})",
+                documentation: "Change from base to x in basis points",
+            },
+            CompletionItemSuggestion {
+                label: "pv",
+                insert_text: "pv(${1:rate}, ${2:nper}, ${3:pmt}, ${4:fv})",
+                documentation: "Present value of an annuity",
+            },
+            CompletionItemSuggestion {
+                label: "fv",
+                insert_text: "fv(${1:rate}, ${2:nper}, ${3:pmt}, ${4:pv})",
+                documentation: "Future value of an annuity",
+            },
+            CompletionItemSuggestion {
+                label: "rate",
+                insert_text: "rate(${1:nper}, ${2:pmt}, ${3:pv}, ${4:fv})",
+                documentation: "Per-period interest rate of an annuity, solved iteratively",
+            },
+            CompletionItemSuggestion {
+                label: "irr",
+                insert_text: "irr(${1:flows})",
+                documentation: "Internal rate of return of a list of cash flows, solved iteratively",
+            },
+            CompletionItemSuggestion {
+                label: "year_frac",
+                insert_text: "year_frac(${1:start}, ${2:end}, '${3:act/360}')",
+                documentation: "Years between two dates under a day count convention: 'act/360', 'act/365', '30/360' or 'act/act'",
+            },
             CompletionItemSuggestion {
                 label: "hour_of_day",
                 insert_text: "hour_of_day(${1:x})",
@@ -350,6 +455,21 @@ thread_local! {
                 insert_text: "datetime(${1:timestamp})",
                 documentation: "Given a POSIX timestamp of milliseconds since epoch, create a new datetime",
             },
+            CompletionItemSuggestion {
+                label: "duration",
+                insert_text: "duration(${1:milliseconds})",
+                documentation: "Given a number of milliseconds, create a new duration",
+            },
+            CompletionItemSuggestion {
+                label: "convert_tz",
+                insert_text: "convert_tz(${1:x}, '${2:America/New_York}')",
+                documentation: "Returns the wall-clock time of a datetime in the given time zone",
+            },
+            CompletionItemSuggestion {
+                label: "at_tz",
+                insert_text: "at_tz(${1:x}, '${2:America/New_York}')",
+                documentation: "Reads a datetime as wall-clock time in the given time zone",
+            },
             CompletionItemSuggestion {
                 label: "boolean",
                 insert_text: "boolean(${1:x})",
@@ -395,6 +515,21 @@ thread_local! {
                 insert_text: "replace(${1:string}, ${2:pattern}, ${3:replacer})",
                 documentation: "Replaces all non-overlapping matches of pattern in string with replacer, or return the original string if no replaces were made.",
             },
+            CompletionItemSuggestion {
+                label: "regex_match",
+                insert_text: "regex_match(${1:string}, '${2:pattern}')",
+                documentation: "Whether the string contains a match of pattern, like match()",
+            },
+            CompletionItemSuggestion {
+                label: "regex_extract",
+                insert_text: "regex_extract(${1:string}, '${2:pattern}', ${3:1})",
+                documentation: "Returns the substring matched by a capturing group of the first match of pattern (0 for the whole match), or null if the string does not match",
+            },
+            CompletionItemSuggestion {
+                label: "regex_replace",
+                insert_text: "regex_replace(${1:string}, '${2:pattern}', ${3:replacer})",
+                documentation: "Replaces all matches of pattern in string with replacer, which may refer to capturing groups as \\1, like replace_all()",
+            },
             CompletionItemSuggestion {
                 label: "index",
                 insert_text: "index()",
@@ -410,6 +545,26 @@ thread_local! {
                 insert_text: "vlookup(${1:string}, ${2:uint64})",
                 documentation: "Looks up a value in another column by index",
             },
+            CompletionItemSuggestion {
+                label: "zscore",
+                insert_text: "zscore('${1:x}', '${2:group}')",
+                documentation: "Standard score of a column's value within its group, or across the table without a group column",
+            },
+            CompletionItemSuggestion {
+                label: "percent_of_group",
+                insert_text: "percent_of_group('${1:x}', '${2:group}')",
+                documentation: "A column's value as a percentage of its group's total",
+            },
+            CompletionItemSuggestion {
+                label: "percent_of_total",
+                insert_text: "percent_of_total('${1:x}')",
+                documentation: "A column's value as a percentage of the column's total",
+            },
+            CompletionItemSuggestion {
+                label: "rank_in_group",
+                insert_text: "rank_in_group('${1:x}', '${2:group}')",
+                documentation: "Rank of a column's value within its group, largest first",
+            },
         ]
     ;
 }
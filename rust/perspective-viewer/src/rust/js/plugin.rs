@@ -15,6 +15,7 @@ use serde::*;
 use wasm_bindgen::prelude::*;
 
 use crate::presentation::ColumnConfigMap;
+use crate::renderer::{CellRendererRegistryExt, CELL_RENDERER_REGISTRY};
 
 /// Perspective FFI
 #[wasm_bindgen]
@@ -102,10 +103,37 @@ extern "C" {
 
 }
 
+/// A custom cell renderer, as registered via `registerCellRenderer()`. The
+/// `render()` and `edit()` methods are invoked by the plugin, so only the
+/// metadata is bound here.
+#[wasm_bindgen]
+#[rustfmt::skip]
+extern "C" {
+    #[derive(Clone)]
+    pub type JsCellRenderer;
+
+    #[wasm_bindgen(method, getter)]
+    pub fn types(this: &JsCellRenderer) -> Option<js_sys::Array>;
+}
+
 impl JsPerspectiveViewerPlugin {
+    /// Restore this plugin's config, resolving each column's `renderer` name
+    /// to its registered [`JsCellRenderer`] as the `cell_renderer` property,
+    /// so plugins can invoke it synchronously while drawing.
     pub fn restore(&self, token: &JsValue, columns_config: Option<&ColumnConfigMap>) {
-        let columns_config = JsValue::from_serde_ext(&columns_config).unwrap();
-        self._restore(token, &columns_config)
+        let js_columns_config = JsValue::from_serde_ext(&columns_config).unwrap();
+        for (column_name, config) in columns_config.into_iter().flatten() {
+            if let Some(renderer) = config
+                .renderer
+                .as_ref()
+                .and_then(|name| CELL_RENDERER_REGISTRY.get_cell_renderer(name))
+            {
+                let column = js_sys::Reflect::get(&js_columns_config, &column_name.into()).unwrap();
+                js_sys::Reflect::set(&column, &"cell_renderer".into(), &renderer).unwrap();
+            }
+        }
+
+        self._restore(token, &js_columns_config)
     }
 }
 
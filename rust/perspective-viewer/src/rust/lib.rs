@@ -58,6 +58,20 @@ pub fn js_register_plugin(name: &str) {
     PLUGIN_REGISTRY.register_plugin(name);
 }
 
+/// Register a custom cell renderer globally.
+#[wasm_bindgen(js_name = "registerCellRenderer")]
+pub fn js_register_cell_renderer(name: &str, renderer: js::JsCellRenderer) {
+    use crate::renderer::*;
+    CELL_RENDERER_REGISTRY.register_cell_renderer(name, renderer);
+}
+
+/// Get a previously registered custom cell renderer by name.
+#[wasm_bindgen(js_name = "getCellRenderer")]
+pub fn js_get_cell_renderer(name: &str) -> Option<js::JsCellRenderer> {
+    use crate::renderer::*;
+    CELL_RENDERER_REGISTRY.get_cell_renderer(name)
+}
+
 // // #[cfg(feature = "metadata")]
 // #[wasm_bindgen(js_name = "getTypes")]
 // pub fn generate_type_bindings() -> String {
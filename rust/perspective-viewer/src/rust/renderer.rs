@@ -18,6 +18,7 @@
 //! references throughout the application.
 
 mod activate;
+mod cell_renderer_registry;
 mod limits;
 mod plugin_store;
 mod registry;
@@ -39,9 +40,11 @@ use web_sys::*;
 use yew::html::ImplicitClone;
 
 use self::activate::*;
+pub use self::cell_renderer_registry::*;
 use self::limits::*;
 use self::plugin_store::*;
 pub use self::registry::*;
+pub use self::render_timer::RenderThrottleOptions;
 use self::render_timer::*;
 use crate::config::*;
 use crate::js::plugin::*;
@@ -192,6 +195,16 @@ impl Renderer {
         self.0.borrow_mut().timer.set_throttle(val);
     }
 
+    pub fn set_render_throttle(&self, options: &RenderThrottleOptions) {
+        let mut data = self.0.borrow_mut();
+        data.timer.set_throttle(options.batch_window);
+        data.timer.set_max_fps(options.max_fps);
+    }
+
+    pub fn get_render_throttle(&self) -> RenderThrottleOptions {
+        self.0.borrow().timer.get_options()
+    }
+
     pub fn set_selection(&self, window: Option<ViewWindow>) {
         self.borrow_mut().selection = window
     }
@@ -0,0 +1,82 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::thread::LocalKey;
+
+use extend::ext;
+use perspective_client::ColumnType;
+
+use crate::js::plugin::*;
+
+thread_local! {
+    pub static CELL_RENDERER_REGISTRY: Rc<RefCell<Vec<CellRendererRecord>>> = Rc::new(RefCell::new(vec![]));
+}
+
+pub struct CellRendererRecord {
+    name: String,
+    types: Option<Vec<ColumnType>>,
+    renderer: JsCellRenderer,
+}
+
+impl CellRendererRecord {
+    fn supports(&self, ty: ColumnType) -> bool {
+        self.types.as_ref().map(|x| x.contains(&ty)).unwrap_or(true)
+    }
+}
+
+/// A global registry of all custom cell renderers that have been registered.
+#[ext(name = CellRendererRegistryExt)]
+pub impl LocalKey<Rc<RefCell<Vec<CellRendererRecord>>>> {
+    fn register_cell_renderer(&'static self, name: &str, renderer: JsCellRenderer) {
+        let types = renderer.types().map(|types| {
+            types
+                .iter()
+                .filter_map(|x| x.as_string()?.parse::<ColumnType>().ok())
+                .collect()
+        });
+
+        self.with(|renderers| {
+            let mut renderers = renderers.borrow_mut();
+            renderers.retain(|x| x.name != name);
+            renderers.push(CellRendererRecord {
+                name: name.to_owned(),
+                types,
+                renderer,
+            });
+        })
+    }
+
+    fn get_cell_renderer(&'static self, name: &str) -> Option<JsCellRenderer> {
+        self.with(|renderers| {
+            renderers
+                .borrow()
+                .iter()
+                .find(|x| x.name == name)
+                .map(|x| x.renderer.clone())
+        })
+    }
+
+    /// The names of all renderers which support columns of type `ty`, in
+    /// order of registration.
+    fn available_cell_renderer_names(&'static self, ty: ColumnType) -> Vec<String> {
+        self.with(|renderers| {
+            renderers
+                .borrow()
+                .iter()
+                .filter(|x| x.supports(ty))
+                .map(|x| x.name.to_owned())
+                .collect()
+        })
+    }
+}
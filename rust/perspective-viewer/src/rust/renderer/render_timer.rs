@@ -22,7 +22,42 @@ use wasm_bindgen::JsCast;
 
 /// A utility struct to track and calculate framerate metrics.
 #[derive(Default, Clone)]
-pub struct MovingWindowRenderTimer(Rc<RefCell<RenderTimerType>>);
+pub struct MovingWindowRenderTimer(Rc<RefCell<RenderTimerType>>, Rc<RefCell<FrameRateLimit>>);
+
+/// Caps the rate at which frames are drawn, independent of the batch window,
+/// by tracking when the last frame began.
+#[derive(Default)]
+struct FrameRateLimit {
+    max_fps: Option<f64>,
+    last_frame_start: Option<f64>,
+}
+
+impl FrameRateLimit {
+    /// Milliseconds until the next frame may begin.
+    fn remaining(&self, now: f64) -> f64 {
+        match (self.max_fps, self.last_frame_start) {
+            (Some(max_fps), Some(last)) if max_fps > 0_f64 => {
+                f64::max(0_f64, 1000_f64 / max_fps - (now - last))
+            },
+            _ => 0_f64,
+        }
+    }
+}
+
+/// Render throttling options for the JS API call.  `batch_window` is the
+/// number of milliseconds to wait after an update before redrawing, so
+/// updates arriving within the window are conflated into one draw; if
+/// `None`, the window is calculated adaptively from recent render times.
+/// `max_fps` caps the number of redraws per second regardless of the batch
+/// window, or is unlimited if `None`.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct RenderThrottleOptions {
+    #[serde(default)]
+    pub max_fps: Option<f64>,
+
+    #[serde(default)]
+    pub batch_window: Option<f64>,
+}
 
 enum RenderTimerType {
     Moving(Closure<dyn Fn(JsValue)>, Rc<RefCell<RenderTimerState>>),
@@ -48,10 +83,8 @@ pub struct RenderTimerStats {
 impl MovingWindowRenderTimer {
     pub async fn capture_time<T>(&self, f: impl Future<Output = T>) -> T {
         let perf = global::window().performance().unwrap();
-        let start = match *self.0.borrow() {
-            RenderTimerType::Constant(_) => 0_f64,
-            RenderTimerType::Moving(..) => perf.now(),
-        };
+        let start = perf.now();
+        self.1.borrow_mut().last_frame_start = Some(start);
 
         let result = f.await;
         match &mut *self.0.borrow_mut() {
@@ -96,18 +129,37 @@ impl MovingWindowRenderTimer {
         }
     }
 
+    pub fn set_max_fps(&mut self, max_fps: Option<f64>) {
+        self.1.borrow_mut().max_fps = max_fps;
+    }
+
+    pub fn get_options(&self) -> RenderThrottleOptions {
+        RenderThrottleOptions {
+            max_fps: self.1.borrow().max_fps,
+            batch_window: match &*self.0.borrow() {
+                RenderTimerType::Constant(constant) => Some(*constant),
+                RenderTimerType::Moving(..) => None,
+            },
+        }
+    }
+
+    /// The number of milliseconds to wait before the next frame, which is the
+    /// larger of the batch window and the remaining frame rate limit interval.
     pub fn get_throttle(&self) -> i32 {
-        match &*self.0.borrow() {
-            RenderTimerType::Constant(constant) => *constant as i32,
+        let batch_window = match &*self.0.borrow() {
+            RenderTimerType::Constant(constant) => *constant,
             RenderTimerType::Moving(_, timings) => {
                 let state = timings.borrow();
                 if state.render_times.len() < 5 {
-                    0_i32
+                    0_f64
                 } else {
-                    f64::min(5000_f64, state.virtual_fps()) as i32
+                    f64::min(5000_f64, state.virtual_fps())
                 }
             },
-        }
+        };
+
+        let now = global::window().performance().unwrap().now();
+        f64::max(batch_window, self.1.borrow().remaining(now)) as i32
     }
 }
 
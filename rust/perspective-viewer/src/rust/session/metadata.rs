@@ -64,7 +64,7 @@ impl SessionMetadata {
         let features = table.get_features()?.clone();
         let column_names = table.columns().await?;
         let table_schema = table.schema().await?;
-        let edit_port = table.make_port().await? as f64;
+        let edit_port = table.make_port("").await?.id() as f64;
         Ok(Self(Some(SessionMetadataState {
             features,
             column_names,
@@ -39,6 +39,15 @@ pub impl ViewConfig {
             aggregates,
             filter_op: _,
             group_by_depth: _,
+            timezone: _,
+            null_groups: _,
+            null_aggregates: _,
+            params: _,
+            column_groups,
+            grand_total: _,
+            subtotals: _,
+            show_values_as,
+            unnest,
         } = self.clone();
 
         let expressions = expressions
@@ -104,6 +113,35 @@ pub impl ViewConfig {
             })
             .collect::<Vec<_>>();
 
+        let column_groups = column_groups
+            .into_iter()
+            .map(|x| ColumnGroup {
+                columns: x
+                    .columns
+                    .into_iter()
+                    .map(|x| {
+                        if x == old_expr.name {
+                            new_expr.name.as_ref().to_owned()
+                        } else {
+                            x
+                        }
+                    })
+                    .collect(),
+                ..x
+            })
+            .collect::<Vec<_>>();
+
+        let show_values_as = show_values_as
+            .into_iter()
+            .map(|x| {
+                if x.0 == old_expr.name {
+                    (new_expr.name.as_ref().to_owned(), x.1)
+                } else {
+                    x
+                }
+            })
+            .collect::<HashMap<_, _>>();
+
         // TODO expression editing can change type, which may invalidate filters
         let filter = filter
             .into_iter()
@@ -130,6 +168,15 @@ pub impl ViewConfig {
             filter: Some(filter),
             filter_op: None,
             group_by_depth: None,
+            timezone: None,
+            null_groups: None,
+            null_aggregates: None,
+            params: None,
+            column_groups: Some(column_groups),
+            grand_total: None,
+            subtotals: None,
+            show_values_as: Some(show_values_as),
+            unnest: Some(unnest),
         }
     }
 }
@@ -35,7 +35,13 @@ for (const key of names) {
     });
 }
 
-for (const key of ["registerPlugin", "getExprTKCommands", "getTypes"]) {
+for (const key of [
+    "registerPlugin",
+    "registerCellRenderer",
+    "getCellRenderer",
+    "getExprTKCommands",
+    "getTypes",
+]) {
     Object.defineProperty(HTMLPerspectiveViewerElement, key, {
         value: async function (...args: any[]) {
             return (init as any)[key].call(init, ...args);
@@ -11,7 +11,10 @@
 // ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
 
 import type { IPerspectiveViewerElement } from "./viewer";
-import type { HTMLPerspectiveViewerPluginElement } from "./plugin";
+import type {
+    HTMLPerspectiveViewerPluginElement,
+    IPerspectiveCellRenderer,
+} from "./plugin";
 import { PerspectiveViewerElement } from "../../dist/pkg/perspective-viewer.js";
 import type React from "react";
 
@@ -90,4 +93,36 @@ export declare class HTMLPerspectiveViewerElement
      * ```
      */
     static registerPlugin(name: string): Promise<void>;
+
+    /**
+     * Register a custom cell renderer, which can then be selected per-column
+     * via the `renderer` field of `columns_config` (or the column settings
+     * sidebar), for plugins which support it.  Registering a renderer with
+     * an existing name replaces it.
+     *
+     * @category Plugin
+     * @param name The unique name of this renderer.
+     * @param renderer The renderer implementation.
+     * @example
+     * ```javascript
+     * customElements.get("perspective-viewer").registerCellRenderer(
+     *     "Sparkline",
+     *     { types: ["float"], render(td, value) { ... } }
+     * );
+     * ```
+     */
+    static registerCellRenderer(
+        name: string,
+        renderer: IPerspectiveCellRenderer
+    ): Promise<void>;
+
+    /**
+     * Get a cell renderer previously registered via `registerCellRenderer()`.
+     *
+     * @category Plugin
+     * @param name The name of the renderer.
+     */
+    static getCellRenderer(
+        name: string
+    ): Promise<IPerspectiveCellRenderer | undefined>;
 }
@@ -32,6 +32,10 @@
  */
 
 export { IPerspectiveViewerPlugin } from "./plugin";
+export type {
+    IPerspectiveCellRenderer,
+    PerspectiveCellMetadata,
+} from "./plugin";
 export { HTMLPerspectiveViewerPluginElement } from "./plugin";
 export { IPerspectiveViewerElement } from "./viewer";
 
@@ -189,6 +189,71 @@ export interface IPerspectiveViewerPlugin {
     delete(): Promise<void>;
 }
 
+/**
+ * Column metadata passed to an `IPerspectiveCellRenderer`.
+ */
+export interface PerspectiveCellMetadata {
+    /**
+     * The name of the column this cell belongs to.
+     */
+    column_name: string;
+
+    /**
+     * The Perspective type of this column, e.g. `"float"`.
+     */
+    type: string;
+
+    /**
+     * This column's `columns_config`, as set in the column settings sidebar.
+     */
+    config: any;
+
+    /**
+     * Whether this cell is currently editable.
+     */
+    editable: boolean;
+
+    /**
+     * Write a new value for this cell back to the `Table`.
+     */
+    commit(value: any): void;
+}
+
+/**
+ * The `IPerspectiveCellRenderer` interface defines a custom cell renderer
+ * (and optionally editor), which can be selected per-column in the column
+ * settings sidebar of plugins which support it, such as
+ * `@finos/perspective-viewer-datagrid`.
+ *
+ * @example
+ * ```javascript
+ * const Viewer = customElements.get("perspective-viewer");
+ * Viewer.registerCellRenderer("Status Pill", {
+ *     types: ["string"],
+ *     render(td, value, meta) {
+ *         td.innerHTML = `<span class="pill">${value}</span>`;
+ *     },
+ * });
+ * ```
+ */
+export interface IPerspectiveCellRenderer {
+    /**
+     * The column types this renderer supports, or all types if omitted.
+     */
+    types?: string[];
+
+    /**
+     * Render `value` into the cell element `td`.
+     */
+    render(td: HTMLElement, value: any, meta: PerspectiveCellMetadata): void;
+
+    /**
+     * Render an editor for `value` into `td`, when the cell is editable.  New
+     * values should be written via `meta.commit()`.  Defaults to `render()`.
+     */
+    edit?(td: HTMLElement, value: any, meta: PerspectiveCellMetadata): void;
+}
+
 /**
  * The `<perspective-viewer-plugin>` element, the default perspective plugin
  * which is registered and activated automcatically when a
@@ -44,6 +44,19 @@ export type RenderStats = {
     actual_fps: number;
 };
 
+export type RenderThrottleOptions = {
+    /**
+     * The maximum number of redraws per second, or unlimited if `null`.
+     */
+    max_fps?: number | null;
+
+    /**
+     * The number of milliseconds to wait after an update before redrawing, or
+     * adaptive (based on recent render times) if `null`.
+     */
+    batch_window?: number | null;
+};
+
 /**
  * The Custom Elements implementation for `<perspective-viewer>`, as well at its
  * API.  `PerspectiveViewerElement` should not be constructed directly (like its
@@ -265,6 +278,50 @@ export interface IPerspectiveViewerElement {
         format?: "json" | "arraybuffer" | "string"
     ): Promise<perspective_viewer.ViewerConfigUpdate | string | ArrayBuffer>;
 
+    /**
+     * Save this element's state as a named layout in `localStorage`, replacing
+     * any existing layout of the same name.  Saved layouts are shared by all
+     * `<perspective-viewer>` elements on the same origin.
+     *
+     * @category Persistence
+     * @param name The name of the layout.
+     * @example
+     * ```javascript
+     * await viewer.saveLayout("By Region");
+     * ```
+     */
+    saveLayout(name: string): Promise<void>;
+
+    /**
+     * Restore this element from a layout saved with `saveLayout()`.  Layouts
+     * saved by older versions of `<perspective-viewer>` are migrated to the
+     * current config format before being restored.
+     *
+     * @category Persistence
+     * @param name The name of the layout.
+     * @example
+     * ```javascript
+     * await viewer.restoreLayout("By Region");
+     * ```
+     */
+    restoreLayout(name: string): Promise<void>;
+
+    /**
+     * Get the names of all layouts saved with `saveLayout()`, in sorted order.
+     *
+     * @category Persistence
+     */
+    getLayoutNames(): Array<string>;
+
+    /**
+     * Delete a layout saved with `saveLayout()`.
+     *
+     * @category Persistence
+     * @param name The name of the layout.
+     * @returns Whether a layout with this name existed.
+     */
+    deleteLayout(name: string): boolean;
+
     /**
      * Flush any pending modifications to this `<perspective-viewer>`.  Since
      * `<perspective-viewer>`'s API is almost entirely `async`, it may take
@@ -433,6 +490,32 @@ export interface IPerspectiveViewerElement {
      */
     setThrottle(value?: number): void;
 
+    /**
+     * Set the render throttling behavior for updates, to trade redraw cost
+     * against latency, e.g. for high-frequency tick streams or low-power
+     * devices.  Updates which arrive while waiting to redraw are conflated
+     * into a single redraw.
+     *
+     * @category Util
+     * @param options `max_fps` caps the number of redraws per second
+     * (unlimited if omitted).  `batch_window` is the number of milliseconds
+     * to wait after an update before redrawing (adaptive, as with
+     * `setThrottle()`, if omitted).
+     * @example <caption>Redraw at most 10 times per second</caption>
+     *
+     * ```javascript
+     * await viewer.setRenderThrottle({ max_fps: 10, batch_window: 50 });
+     * ```
+     */
+    setRenderThrottle(options?: RenderThrottleOptions): void;
+
+    /**
+     * Get the current render throttling options.
+     *
+     * @category Util
+     */
+    getRenderThrottle(): RenderThrottleOptions;
+
     /**
      * Opens/closes the element's config menu, equivalent to clicking the
      * settings button in the UI.  This method is equivalent to
@@ -0,0 +1,76 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+import { test, expect } from "@finos/perspective-test";
+
+test.beforeEach(async ({ page }) => {
+    await page.goto("/rust/perspective-viewer/test/html/superstore.html");
+    await page.evaluate(async () => {
+        while (!window["__TEST_PERSPECTIVE_READY__"]) {
+            await new Promise((x) => setTimeout(x, 10));
+        }
+    });
+
+    await page.evaluate(async () => {
+        await document.querySelector("perspective-viewer").restore({
+            plugin: "Debug",
+        });
+    });
+});
+
+test.describe("Render Throttle", () => {
+    test("defaults to adaptive and unlimited", async ({ page }) => {
+        const options = await page.evaluate(async () => {
+            const viewer = document.querySelector("perspective-viewer");
+            return await viewer.getRenderThrottle();
+        });
+
+        expect(options).toEqual({ max_fps: null, batch_window: null });
+    });
+
+    test("setRenderThrottle round trips options", async ({ page }) => {
+        const options = await page.evaluate(async () => {
+            const viewer = document.querySelector("perspective-viewer");
+            await viewer.setRenderThrottle({ max_fps: 10, batch_window: 50 });
+            const set = await viewer.getRenderThrottle();
+            await viewer.setThrottle(100);
+            const throttle = await viewer.getRenderThrottle();
+            await viewer.setRenderThrottle();
+            return [set, throttle, await viewer.getRenderThrottle()];
+        });
+
+        expect(options).toEqual([
+            { max_fps: 10, batch_window: 50 },
+            { max_fps: 10, batch_window: 100 },
+            { max_fps: null, batch_window: null },
+        ]);
+    });
+
+    test("max_fps conflates updates", async ({ page }) => {
+        const count = await page.evaluate(async () => {
+            const viewer = document.querySelector("perspective-viewer");
+            const table = await viewer.getTable();
+            await viewer.setRenderThrottle({ max_fps: 2 });
+            await viewer.flush();
+            await viewer.getRenderStats();
+            for (let i = 0; i < 20; i++) {
+                await table.update([{ "Row ID": 1, Sales: i }]);
+                await new Promise((x) => setTimeout(x, 10));
+            }
+
+            await viewer.flush();
+            const stats = await viewer.getRenderStats();
+            return stats.total_render_count;
+        });
+
+        expect(count).toBeLessThan(5);
+    });
+});
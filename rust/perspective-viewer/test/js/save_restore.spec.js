@@ -143,3 +143,78 @@ test.describe("Save/Restore", async () => {
         ]);
     });
 });
+
+test.describe("Saved Layouts", async () => {
+    test.beforeEach(async ({ page }) => {
+        await page.evaluate(() => localStorage.clear());
+    });
+
+    test("saveLayout and restoreLayout round trip a config", async ({
+        page,
+    }) => {
+        const config = await page.evaluate(async () => {
+            const viewer = document.querySelector("perspective-viewer");
+            await viewer.getTable();
+            await viewer.restore({
+                group_by: ["State"],
+                columns: ["Profit", "Sales"],
+            });
+
+            await viewer.saveLayout("By State");
+            await viewer.reset();
+            await viewer.restoreLayout("By State");
+            return await viewer.save();
+        });
+
+        expect(config).toEqual({
+            ...DEFAULT_CONFIG,
+            columns: ["Profit", "Sales"],
+            plugin: "Debug",
+            group_by: ["State"],
+        });
+    });
+
+    test("getLayoutNames and deleteLayout manage saved layouts", async ({
+        page,
+    }) => {
+        const names = await page.evaluate(async () => {
+            const viewer = document.querySelector("perspective-viewer");
+            await viewer.getTable();
+            await viewer.saveLayout("b");
+            await viewer.saveLayout("a");
+            const before = await viewer.getLayoutNames();
+            const deleted = await viewer.deleteLayout("b");
+            const missing = await viewer.deleteLayout("b");
+            return [before, deleted, missing, await viewer.getLayoutNames()];
+        });
+
+        expect(names).toEqual([["a", "b"], true, false, ["a"]]);
+    });
+
+    test("restoreLayout migrates legacy layouts", async ({ page }) => {
+        const config = await page.evaluate(async () => {
+            localStorage.setItem(
+                "perspective-viewer-layouts",
+                JSON.stringify({
+                    legacy: {
+                        plugin: "Debug",
+                        row_pivots: ["State"],
+                        columns: ["Sales"],
+                    },
+                })
+            );
+
+            const viewer = document.querySelector("perspective-viewer");
+            await viewer.getTable();
+            await viewer.restoreLayout("legacy");
+            return await viewer.save();
+        });
+
+        expect(config).toEqual({
+            ...DEFAULT_CONFIG,
+            columns: ["Sales"],
+            plugin: "Debug",
+            group_by: ["State"],
+        });
+    });
+});
@@ -0,0 +1,680 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+//! Experimental accelerated `group_by` aggregation of large numeric columns,
+//! for deployments which pivot very large tables and have a GPU sitting idle.
+//!
+//! [`group_by`] aggregates a hosted table with the [`AggregationBackend`] its
+//! [`Server`] was built with. With [`AggregationBackend::Gpu`], the grouping
+//! and aggregated columns are copied out of the engine, rows are sorted by
+//! group on the CPU, and each group is cut into segments of at most
+//! [`SEGMENT_ROWS`] rows, which a compute shader reduces in parallel (with
+//! this crate's `gpu` feature, via `wgpu`). The partial results of each
+//! group's segments are then merged on the CPU. Without the `gpu` feature,
+//! or if no GPU adapter is found, the same segmented reduction runs on the
+//! CPU.
+//!
+//! Only `sum`, `count`, `mean` (or `avg`), `min` and `max` are accelerated,
+//! and values are reduced in single precision, with compensated summation.
+
+use std::cmp::Ordering;
+use std::collections::HashMap;
+
+use perspective_client::config::{Aggregate, SingleAggregate, ViewConfigUpdate};
+use perspective_client::{Table, ViewWindow};
+use perspective_server::{AggregationBackend, Server, ServerError};
+use serde_json::{Map, Value};
+
+use crate::LocalClient;
+
+/// The most rows of one group which a single shader invocation reduces.
+pub const SEGMENT_ROWS: usize = 1024;
+
+/// One group of a [`group_by`] result.
+#[derive(Clone, Debug, PartialEq)]
+pub struct GroupRow {
+    /// The group's value of the `group_by` column.
+    pub key: Value,
+
+    /// The aggregate of each requested column, in order. `min`, `max` and
+    /// `mean` are `None` for a group whose values are all null.
+    pub values: Vec<Option<f64>>,
+}
+
+/// Aggregate the hosted table `table_name` by the column `by`, with one
+/// aggregate per column of `aggregates`, on `server`'s
+/// [`AggregationBackend`]. Returns a [`GroupRow`] per distinct value of `by`,
+/// in ascending order (nulls first), without a total row.
+pub async fn group_by(
+    server: &Server,
+    table_name: &str,
+    by: &str,
+    aggregates: &[(String, SingleAggregate)],
+) -> Result<Vec<GroupRow>, ServerError> {
+    let mut seen = vec![];
+    for (column, _) in aggregates {
+        if seen.contains(&column) {
+            return Err(format!("Column `{}` is aggregated more than once", column).into());
+        }
+
+        seen.push(column);
+    }
+
+    let client = LocalClient::new(server);
+    let result = async {
+        let table = client.open_table(table_name.to_owned()).await?;
+        match server.aggregation_backend() {
+            AggregationBackend::Engine => engine_group_by(&table, by, aggregates).await,
+            AggregationBackend::Gpu => accelerated_group_by(&table, by, aggregates).await,
+        }
+    }
+    .await;
+
+    client.close().await;
+    result
+}
+
+async fn engine_group_by(
+    table: &Table,
+    by: &str,
+    aggregates: &[(String, SingleAggregate)],
+) -> Result<Vec<GroupRow>, ServerError> {
+    let view = table
+        .view(Some(ViewConfigUpdate {
+            group_by: Some(vec![by.to_owned()]),
+            columns: Some(aggregates.iter().map(|(x, _)| Some(x.clone())).collect()),
+            aggregates: Some(
+                aggregates
+                    .iter()
+                    .map(|(x, aggregate)| (x.clone(), Aggregate::SingleAggregate(*aggregate)))
+                    .collect(),
+            ),
+            ..ViewConfigUpdate::default()
+        }))
+        .await?;
+
+    let json = view.to_columns_string(ViewWindow::default()).await;
+    view.delete().await?;
+    let mut data: Map<String, Value> = serde_json::from_str(&json?)?;
+    let paths = take_column(&mut data, "__ROW_PATH__")?;
+    let columns = aggregates
+        .iter()
+        .map(|(x, _)| take_column(&mut data, x))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    // The total row's path is empty.
+    Ok(paths
+        .into_iter()
+        .enumerate()
+        .filter_map(|(ridx, path)| match path {
+            Value::Array(mut path) if path.len() == 1 => Some(GroupRow {
+                key: path.pop()?,
+                values: columns
+                    .iter()
+                    .map(|column| column.get(ridx).and_then(Value::as_f64))
+                    .collect(),
+            }),
+            _ => None,
+        })
+        .collect())
+}
+
+async fn accelerated_group_by(
+    table: &Table,
+    by: &str,
+    aggregates: &[(String, SingleAggregate)],
+) -> Result<Vec<GroupRow>, ServerError> {
+    use SingleAggregate::*;
+    if let Some((_, aggregate)) = aggregates
+        .iter()
+        .find(|(_, x)| !matches!(x, Sum | Count | Mean | Avg | Min | Max))
+    {
+        return Err(format!(
+            "Aggregate `{}` is not supported by the GPU aggregation backend",
+            aggregate
+        )
+        .into());
+    }
+
+    let mut columns = vec![Some(by.to_owned())];
+    columns.extend(
+        aggregates
+            .iter()
+            .filter(|(x, _)| x != by)
+            .map(|(x, _)| Some(x.clone())),
+    );
+
+    let view = table
+        .view(Some(ViewConfigUpdate {
+            columns: Some(columns),
+            ..ViewConfigUpdate::default()
+        }))
+        .await?;
+
+    let json = view.to_columns_string(ViewWindow::default()).await;
+    view.delete().await?;
+    let data: Map<String, Value> = serde_json::from_str(&json?)?;
+    let column = |name: &str| match data.get(name) {
+        Some(Value::Array(values)) => Ok(values),
+        _ => Err(ServerError::from(format!("Missing column `{}`", name))),
+    };
+
+    let groups = Groups::new(column(by)?)?;
+    let reducer = Reducer::new();
+    let mut rows = vec![vec![None; aggregates.len()]; groups.keys.len()];
+    for (cidx, (name, aggregate)) in aggregates.iter().enumerate() {
+        if *aggregate == Count {
+            for (gidx, row) in rows.iter_mut().enumerate() {
+                row[cidx] = Some((groups.offsets[gidx + 1] - groups.offsets[gidx]) as f64);
+            }
+
+            continue;
+        }
+
+        let (values, valid) = groups.sorted_values(name, column(name)?)?;
+        let partials = reducer.reduce(&values, &valid, &groups.segments).await?;
+        for (gidx, merged) in groups.merge(&partials).into_iter().enumerate() {
+            rows[gidx][cidx] = merged.aggregate(*aggregate);
+        }
+    }
+
+    Ok(groups
+        .keys
+        .into_iter()
+        .zip(rows)
+        .map(|(key, values)| GroupRow { key, values })
+        .collect())
+}
+
+fn take_column(data: &mut Map<String, Value>, name: &str) -> Result<Vec<Value>, ServerError> {
+    match data.remove(name) {
+        Some(Value::Array(values)) => Ok(values),
+        _ => Err(format!("Missing column `{}`", name).into()),
+    }
+}
+
+/// The order of `group_by` keys, as the engine sorts them.
+fn compare_keys(a: &Value, b: &Value) -> Ordering {
+    let rank = |x: &Value| match x {
+        Value::Null => 0,
+        Value::Bool(_) => 1,
+        Value::Number(_) => 2,
+        Value::String(_) => 3,
+        _ => 4,
+    };
+
+    match (a, b) {
+        (Value::Bool(a), Value::Bool(b)) => a.cmp(b),
+        (Value::Number(a), Value::Number(b)) => a
+            .as_f64()
+            .unwrap_or_default()
+            .total_cmp(&b.as_f64().unwrap_or_default()),
+        (Value::String(a), Value::String(b)) => a.cmp(b),
+        _ => rank(a).cmp(&rank(b)),
+    }
+}
+
+/// The rows of a table sorted by group, and cut into segments.
+struct Groups {
+    /// The distinct keys, sorted.
+    keys: Vec<Value>,
+
+    /// Row indices, sorted by group and then by row.
+    order: Vec<usize>,
+
+    /// The range of `order` of the `i`-th group is
+    /// `offsets[i]..offsets[i + 1]`.
+    offsets: Vec<usize>,
+
+    /// Ranges of `order` of at most [`SEGMENT_ROWS`] rows of one group.
+    segments: Vec<[u32; 2]>,
+
+    /// The group of each segment.
+    segment_groups: Vec<usize>,
+}
+
+impl Groups {
+    fn new(keys: &[Value]) -> Result<Self, ServerError> {
+        if u32::try_from(keys.len()).is_err() {
+            return Err("Too many rows to aggregate on the GPU backend".into());
+        }
+
+        let mut ids: HashMap<String, usize> = HashMap::new();
+        let mut unique = vec![];
+        let row_groups = keys
+            .iter()
+            .map(|key| {
+                *ids.entry(key.to_string()).or_insert_with(|| {
+                    unique.push(key.clone());
+                    unique.len() - 1
+                })
+            })
+            .collect::<Vec<_>>();
+
+        let mut sorted = (0..unique.len()).collect::<Vec<_>>();
+        sorted.sort_by(|a, b| compare_keys(&unique[*a], &unique[*b]));
+        let mut rank = vec![0; unique.len()];
+        for (new, old) in sorted.iter().enumerate() {
+            rank[*old] = new;
+        }
+
+        let mut order = (0..keys.len()).collect::<Vec<_>>();
+        order.sort_by_key(|row| rank[row_groups[*row]]);
+        let mut offsets = vec![0; unique.len() + 1];
+        for group in &row_groups {
+            offsets[rank[*group] + 1] += 1;
+        }
+
+        let mut end = 0;
+        for offset in offsets.iter_mut() {
+            end += *offset;
+            *offset = end;
+        }
+
+        let mut segments = vec![];
+        let mut segment_groups = vec![];
+        for (gidx, range) in offsets.windows(2).enumerate() {
+            let end = range[1];
+            for start in (range[0]..end).step_by(SEGMENT_ROWS) {
+                segments.push([start as u32, end.min(start + SEGMENT_ROWS) as u32]);
+                segment_groups.push(gidx);
+            }
+        }
+
+        let keys = sorted.into_iter().map(|x| unique[x].clone()).collect();
+        Ok(Self {
+            keys,
+            order,
+            offsets,
+            segments,
+            segment_groups,
+        })
+    }
+
+    /// The values of the column `name` in group order, in single precision,
+    /// and whether each is non-null.
+    fn sorted_values(
+        &self,
+        name: &str,
+        values: &[Value],
+    ) -> Result<(Vec<f32>, Vec<u32>), ServerError> {
+        let mut sorted = Vec::with_capacity(self.order.len());
+        let mut valid = Vec::with_capacity(self.order.len());
+        for row in &self.order {
+            match values.get(*row) {
+                None | Some(Value::Null) => {
+                    sorted.push(0.0);
+                    valid.push(0);
+                },
+                Some(value) => match value.as_f64() {
+                    Some(x) => {
+                        sorted.push(x as f32);
+                        valid.push(1);
+                    },
+                    None => return Err(format!("Column `{}` is not numeric", name).into()),
+                },
+            }
+        }
+
+        Ok((sorted, valid))
+    }
+
+    /// Merge the [`Partial`] of each segment into one per group.
+    fn merge(&self, partials: &[Partial]) -> Vec<Merged> {
+        let mut merged = vec![Merged::default(); self.keys.len()];
+        for (partial, gidx) in partials.iter().zip(&self.segment_groups) {
+            let merged = &mut merged[*gidx];
+            if partial.count > 0.0 {
+                merged.sum += partial.sum as f64;
+                merged.count += partial.count as u64;
+                merged.min = merged.min.min(partial.min as f64);
+                merged.max = merged.max.max(partial.max as f64);
+            }
+        }
+
+        merged
+    }
+}
+
+/// The reduction of one segment's non-null values, as written by the
+/// shader.
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Partial {
+    sum: f32,
+    count: f32,
+    min: f32,
+    max: f32,
+}
+
+/// The reduction of a group's segments.
+#[derive(Clone, Copy, Debug)]
+struct Merged {
+    sum: f64,
+    count: u64,
+    min: f64,
+    max: f64,
+}
+
+impl Default for Merged {
+    fn default() -> Self {
+        Self {
+            sum: 0.0,
+            count: 0,
+            min: f64::INFINITY,
+            max: f64::NEG_INFINITY,
+        }
+    }
+}
+
+impl Merged {
+    fn aggregate(&self, aggregate: SingleAggregate) -> Option<f64> {
+        let any = self.count > 0;
+        match aggregate {
+            SingleAggregate::Sum => Some(self.sum),
+            SingleAggregate::Mean | SingleAggregate::Avg => {
+                any.then(|| self.sum / self.count as f64)
+            },
+            SingleAggregate::Min => any.then_some(self.min),
+            SingleAggregate::Max => any.then_some(self.max),
+            _ => None,
+        }
+    }
+}
+
+/// The CPU equivalent of the shader, reducing the rows `start..end` of
+/// `values`.
+fn reduce_segment(values: &[f32], valid: &[u32], [start, end]: [u32; 2]) -> Partial {
+    let mut partial = Partial {
+        sum: 0.0,
+        count: 0.0,
+        min: f32::MAX,
+        max: f32::MIN,
+    };
+
+    let mut compensation = 0.0_f32;
+    let rows = start as usize..end as usize;
+    for (&x, _) in values[rows.clone()]
+        .iter()
+        .zip(&valid[rows])
+        .filter(|(_, valid)| **valid != 0)
+    {
+        let y = x - compensation;
+        let t = partial.sum + y;
+        compensation = (t - partial.sum) - y;
+        partial.sum = t;
+        partial.count += 1.0;
+        partial.min = partial.min.min(x);
+        partial.max = partial.max.max(x);
+    }
+
+    partial
+}
+
+/// Where segments are reduced.
+enum Reducer {
+    Cpu,
+    #[cfg(feature = "gpu")]
+    Gpu(&'static gpu::GpuDevice),
+}
+
+impl Reducer {
+    fn new() -> Self {
+        #[cfg(feature = "gpu")]
+        if let Some(device) = gpu::device() {
+            return Self::Gpu(device);
+        }
+
+        Self::Cpu
+    }
+
+    async fn reduce(
+        &self,
+        values: &[f32],
+        valid: &[u32],
+        segments: &[[u32; 2]],
+    ) -> Result<Vec<Partial>, ServerError> {
+        match self {
+            Self::Cpu => Ok(segments
+                .iter()
+                .map(|segment| reduce_segment(values, valid, *segment))
+                .collect()),
+            #[cfg(feature = "gpu")]
+            Self::Gpu(device) => device.reduce(values, valid, segments).await,
+        }
+    }
+}
+
+#[cfg(feature = "gpu")]
+mod gpu {
+    use std::sync::OnceLock;
+
+    use perspective_server::ServerError;
+    use wgpu::util::DeviceExt;
+
+    use super::Partial;
+
+    const WORKGROUP_SIZE: usize = 64;
+
+    /// Reduces each segment of `values` (skipping rows which are not
+    /// `valid`) to its sum, count, min and max, as in `reduce_segment`.
+    const SHADER: &str = r#"
+        @group(0) @binding(0) var<storage, read> values: array<f32>;
+        @group(0) @binding(1) var<storage, read> valid: array<u32>;
+        @group(0) @binding(2) var<storage, read> segments: array<vec2<u32>>;
+        @group(0) @binding(3) var<storage, read_write> partials: array<vec4<f32>>;
+
+        @compute @workgroup_size(64)
+        fn main(@builtin(global_invocation_id) id: vec3<u32>) {
+            if (id.x >= arrayLength(&segments)) {
+                return;
+            }
+
+            let segment = segments[id.x];
+            var sum = 0.0;
+            var compensation = 0.0;
+            var count = 0.0;
+            var lo = 3.40282347e+38;
+            var hi = -3.40282347e+38;
+            for (var row = segment.x; row < segment.y; row++) {
+                if (valid[row] == 0u) {
+                    continue;
+                }
+
+                let x = values[row];
+                let y = x - compensation;
+                let t = sum + y;
+                compensation = (t - sum) - y;
+                sum = t;
+                count += 1.0;
+                lo = min(lo, x);
+                hi = max(hi, x);
+            }
+
+            partials[id.x] = vec4<f32>(sum, count, lo, hi);
+        }
+    "#;
+
+    pub(super) struct GpuDevice {
+        device: wgpu::Device,
+        queue: wgpu::Queue,
+        pipeline: wgpu::ComputePipeline,
+    }
+
+    /// The first high-performance GPU adapter, shared by every
+    /// [`super::group_by`], or `None` if there is none.
+    pub(super) fn device() -> Option<&'static GpuDevice> {
+        static DEVICE: OnceLock<Option<GpuDevice>> = OnceLock::new();
+        DEVICE
+            .get_or_init(|| {
+                let device = futures::executor::block_on(GpuDevice::new());
+                if device.is_none() {
+                    tracing::warn!("No GPU adapter found, aggregating on the CPU");
+                }
+
+                device
+            })
+            .as_ref()
+    }
+
+    fn to_bytes<const N: usize>(words: impl Iterator<Item = [u8; N]>) -> Vec<u8> {
+        words.flatten().collect()
+    }
+
+    impl GpuDevice {
+        async fn new() -> Option<Self> {
+            let instance = wgpu::Instance::new(wgpu::InstanceDescriptor::default());
+            let adapter = instance
+                .request_adapter(&wgpu::RequestAdapterOptions {
+                    power_preference: wgpu::PowerPreference::HighPerformance,
+                    force_fallback_adapter: false,
+                    compatible_surface: None,
+                })
+                .await?;
+
+            let descriptor = wgpu::DeviceDescriptor {
+                label: Some("perspective-accel"),
+                required_features: wgpu::Features::empty(),
+                required_limits: adapter.limits(),
+            };
+
+            let (device, queue) = adapter.request_device(&descriptor, None).await.ok()?;
+            let module = device.create_shader_module(wgpu::ShaderModuleDescriptor {
+                label: Some("perspective-accel"),
+                source: wgpu::ShaderSource::Wgsl(SHADER.into()),
+            });
+
+            let pipeline = device.create_compute_pipeline(&wgpu::ComputePipelineDescriptor {
+                label: Some("perspective-accel"),
+                layout: None,
+                module: &module,
+                entry_point: "main",
+            });
+
+            Some(Self {
+                device,
+                queue,
+                pipeline,
+            })
+        }
+
+        pub(super) async fn reduce(
+            &self,
+            values: &[f32],
+            valid: &[u32],
+            segments: &[[u32; 2]],
+        ) -> Result<Vec<Partial>, ServerError> {
+            if segments.is_empty() {
+                return Ok(vec![]);
+            }
+
+            let workgroups = u32::try_from(segments.len().div_ceil(WORKGROUP_SIZE))?;
+            if workgroups > self.device.limits().max_compute_workgroups_per_dimension {
+                return Err("Too many rows to aggregate in one GPU dispatch".into());
+            }
+
+            let storage = |label, contents: &[u8]| {
+                self.device
+                    .create_buffer_init(&wgpu::util::BufferInitDescriptor {
+                        label: Some(label),
+                        contents,
+                        usage: wgpu::BufferUsages::STORAGE,
+                    })
+            };
+
+            let values = storage("values", &to_bytes(values.iter().map(|x| x.to_le_bytes())));
+            let valid = storage("valid", &to_bytes(valid.iter().map(|x| x.to_le_bytes())));
+            let segments_buf = storage(
+                "segments",
+                &to_bytes(segments.iter().flatten().map(|x| x.to_le_bytes())),
+            );
+
+            let size = (segments.len() * 16) as u64;
+            let partials = self.device.create_buffer(&wgpu::BufferDescriptor {
+                label: Some("partials"),
+                size,
+                usage: wgpu::BufferUsages::STORAGE | wgpu::BufferUsages::COPY_SRC,
+                mapped_at_creation: false,
+            });
+
+            let readback = self.device.create_buffer(&wgpu::BufferDescriptor {
+                label: Some("readback"),
+                size,
+                usage: wgpu::BufferUsages::MAP_READ | wgpu::BufferUsages::COPY_DST,
+                mapped_at_creation: false,
+            });
+
+            let bind_group = self.device.create_bind_group(&wgpu::BindGroupDescriptor {
+                label: None,
+                layout: &self.pipeline.get_bind_group_layout(0),
+                entries: &[
+                    wgpu::BindGroupEntry {
+                        binding: 0,
+                        resource: values.as_entire_binding(),
+                    },
+                    wgpu::BindGroupEntry {
+                        binding: 1,
+                        resource: valid.as_entire_binding(),
+                    },
+                    wgpu::BindGroupEntry {
+                        binding: 2,
+                        resource: segments_buf.as_entire_binding(),
+                    },
+                    wgpu::BindGroupEntry {
+                        binding: 3,
+                        resource: partials.as_entire_binding(),
+                    },
+                ],
+            });
+
+            let mut encoder = self
+                .device
+                .create_command_encoder(&wgpu::CommandEncoderDescriptor { label: None });
+
+            {
+                let mut pass = encoder.begin_compute_pass(&wgpu::ComputePassDescriptor {
+                    label: None,
+                    timestamp_writes: None,
+                });
+
+                pass.set_pipeline(&self.pipeline);
+                pass.set_bind_group(0, &bind_group, &[]);
+                pass.dispatch_workgroups(workgroups, 1, 1);
+            }
+
+            encoder.copy_buffer_to_buffer(&partials, 0, &readback, 0, size);
+            self.queue.submit(Some(encoder.finish()));
+            let slice = readback.slice(..);
+            let (sender, receiver) = futures::channel::oneshot::channel();
+            slice.map_async(wgpu::MapMode::Read, move |result| {
+                let _ = sender.send(result);
+            });
+
+            let _ = self.device.poll(wgpu::Maintain::Wait);
+            receiver.await??;
+            let bytes = slice.get_mapped_range();
+            let word = |x: &[u8]| f32::from_le_bytes([x[0], x[1], x[2], x[3]]);
+            let result = bytes
+                .chunks_exact(16)
+                .map(|x| Partial {
+                    sum: word(&x[0..4]),
+                    count: word(&x[4..8]),
+                    min: word(&x[8..12]),
+                    max: word(&x[12..16]),
+                })
+                .collect();
+
+            drop(bytes);
+            readback.unmap();
+            Ok(result)
+        }
+    }
+}
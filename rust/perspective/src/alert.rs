@@ -0,0 +1,291 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::future::Future;
+use std::str::FromStr;
+use std::sync::{Arc, Mutex};
+
+use perspective_client::config::{Aggregate, Filter, ViewConfigUpdate};
+use perspective_client::proto::{ViewOnUpdateResp, ViewportUpdate};
+use perspective_client::*;
+use perspective_server::*;
+use serde_json::Value;
+
+use crate::LocalClient;
+
+/// The comparison of a [`Condition`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Comparison {
+    Lt,
+    Le,
+    Gt,
+    Ge,
+    Eq,
+    Ne,
+}
+
+impl Comparison {
+    /// Longest first, so `<=` is not parsed as `<`.
+    const SYMBOLS: [(&'static str, Comparison); 6] = [
+        ("<=", Comparison::Le),
+        (">=", Comparison::Ge),
+        ("==", Comparison::Eq),
+        ("!=", Comparison::Ne),
+        ("<", Comparison::Lt),
+        (">", Comparison::Gt),
+    ];
+
+    pub fn eval(&self, lhs: f64, rhs: f64) -> bool {
+        match self {
+            Comparison::Lt => lhs < rhs,
+            Comparison::Le => lhs <= rhs,
+            Comparison::Gt => lhs > rhs,
+            Comparison::Ge => lhs >= rhs,
+            Comparison::Eq => lhs == rhs,
+            Comparison::Ne => lhs != rhs,
+        }
+    }
+}
+
+/// A threshold on an aggregate of one column, parsed from e.g.
+/// `sum(pnl) < -1e6` or `avg("Unit Price") >= 100`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Condition {
+    pub aggregate: Aggregate,
+    pub column: String,
+    pub comparison: Comparison,
+    pub threshold: f64,
+}
+
+impl FromStr for Condition {
+    type Err = String;
+
+    fn from_str(input: &str) -> Result<Self, String> {
+        let invalid = || format!("Expected `aggregate(column) < number`, found `{}`", input);
+        let close = input.rfind(')').ok_or_else(invalid)?;
+        let open = input[..close].find('(').ok_or_else(invalid)?;
+        let aggregate = input[..open].trim().parse()?;
+        let column = input[open + 1..close].trim();
+        let column = column
+            .strip_prefix('"')
+            .and_then(|x| x.strip_suffix('"'))
+            .unwrap_or(column);
+
+        let rest = input[close + 1..].trim_start();
+        let (symbol, comparison) = Comparison::SYMBOLS
+            .into_iter()
+            .find(|(symbol, _)| rest.starts_with(symbol))
+            .ok_or_else(invalid)?;
+
+        let threshold = rest[symbol.len()..].trim().parse().map_err(|_| invalid())?;
+
+        Ok(Condition {
+            aggregate,
+            column: column.to_owned(),
+            comparison,
+            threshold,
+        })
+    }
+}
+
+/// A [`Condition`] evaluated for each group of a [`Table`].
+#[derive(Clone, Debug)]
+pub struct AlertRule {
+    pub name: String,
+    pub table: String,
+    pub group_by: Vec<String>,
+    pub filter: Vec<Filter>,
+    pub condition: Condition,
+}
+
+impl AlertRule {
+    pub fn new(name: &str, table: &str, group_by: Vec<String>, condition: Condition) -> Self {
+        Self {
+            name: name.to_owned(),
+            table: table.to_owned(),
+            group_by,
+            filter: vec![],
+            condition,
+        }
+    }
+
+    /// Only aggregate the rows which match `filter`.
+    pub fn with_filter(mut self, filter: Vec<Filter>) -> Self {
+        self.filter = filter;
+        self
+    }
+
+    fn view_config(&self) -> ViewConfigUpdate {
+        let column = self.condition.column.clone();
+        ViewConfigUpdate {
+            group_by: Some(self.group_by.clone()),
+            columns: Some(vec![Some(column.clone())]),
+            filter: Some(self.filter.clone()),
+            aggregates: Some(HashMap::from([(column, self.condition.aggregate.clone())])),
+            ..ViewConfigUpdate::default()
+        }
+    }
+}
+
+/// The groups which began to match an [`AlertRule`], as JSON rows of its
+/// [`View`] (with `__ROW_PATH__` and the [`Condition`]'s column).
+#[derive(Clone, Debug)]
+pub struct Alert {
+    pub rule: String,
+    pub rows: Vec<Value>,
+}
+
+/// The last known leaf rows of an [`AlertRule`]'s [`View`], by position, as
+/// their group key and whether they matched.
+#[derive(Default)]
+struct AlertState {
+    rows: BTreeMap<u64, (String, bool)>,
+}
+
+impl AlertState {
+    fn firing(&self) -> HashSet<String> {
+        self.rows
+            .values()
+            .filter(|(_, matched)| *matched)
+            .map(|(key, _)| key.clone())
+            .collect()
+    }
+
+    /// Apply a viewport push, returning the rows of groups which began to
+    /// match.
+    fn update(&mut self, rule: &AlertRule, viewport: ViewportUpdate) -> Vec<Value> {
+        let before = self.firing();
+        self.rows.split_off(&viewport.num_rows);
+        let mut positions: Vec<_> = viewport.rows.into_iter().collect();
+        positions.sort_by_key(|(position, _)| *position);
+        let mut matched = vec![];
+        for (position, json) in positions {
+            let row: Value = match serde_json::from_str(&json) {
+                Ok(row) => row,
+                Err(err) => {
+                    tracing::error!("Failed to parse row of `{}`: {}", rule.name, err);
+                    continue;
+                },
+            };
+
+            let path = &row["__ROW_PATH__"];
+            if path.as_array().map_or(0, Vec::len) != rule.group_by.len() {
+                self.rows.remove(&position);
+                continue;
+            }
+
+            let condition = &rule.condition;
+            let is_match = row[&condition.column]
+                .as_f64()
+                .is_some_and(|x| condition.comparison.eval(x, condition.threshold));
+
+            self.rows.insert(position, (path.to_string(), is_match));
+            if is_match {
+                matched.push((path.to_string(), row));
+            }
+        }
+
+        let after = self.firing();
+        matched
+            .into_iter()
+            .filter(|(key, _)| after.contains(key) && !before.contains(key))
+            .map(|(_, row)| row)
+            .collect()
+    }
+}
+
+/// An alert registered with [`on_alert`].
+pub struct AlertHook {
+    client: LocalClient,
+    view: View,
+    callback_id: u32,
+}
+
+/// Register a `callback` which is invoked with an [`Alert`] whenever groups
+/// of the [`Table`] named by `rule` begin to match its [`Condition`], e.g.
+/// to post the offending rows to a webhook. A group alerts again only after
+/// it has stopped matching.
+///
+/// The rule is evaluated incrementally: it is implemented as a grouped
+/// [`View`] owned by its own [`Session`], subscribed with
+/// [`OnUpdateMode::Viewport`], so each update only re-evaluates the groups
+/// whose aggregates it changed. Groups which already match when the hook is
+/// registered alert immediately. The callback is awaited from within
+/// [`Server`]'s dispatch, so it must not itself wait on another request to
+/// the same [`Server`].
+///
+/// Like any other [`View`], the hook prevents [`Table::delete`]; call
+/// [`AlertHook::remove`] first, or use [`Server::unhost`].
+pub async fn on_alert<F, U>(
+    server: &Server,
+    rule: AlertRule,
+    callback: F,
+) -> ClientResult<AlertHook>
+where
+    F: Fn(Alert) -> U + Send + Sync + 'static,
+    U: Future<Output = ()> + Send + 'static,
+{
+    if rule.group_by.is_empty() {
+        return Err(ClientError::Unknown(
+            "`AlertRule::group_by` must not be empty".to_owned(),
+        ));
+    }
+
+    let client = LocalClient::new(server);
+    let table = client.open_table(rule.table.clone()).await?;
+    let view = table.view(Some(rule.view_config())).await?;
+    let state = Arc::new(Mutex::new(AlertState::default()));
+    let callback = Arc::new(callback);
+    let on_update = move |resp: ViewOnUpdateResp| {
+        let rows = resp
+            .viewport
+            .map(|viewport| state.lock().unwrap().update(&rule, viewport))
+            .unwrap_or_default();
+
+        let alert = Alert {
+            rule: rule.name.clone(),
+            rows,
+        };
+
+        let callback = callback.clone();
+        async move {
+            if !alert.rows.is_empty() {
+                callback(alert).await
+            }
+        }
+    };
+
+    let callback_id = view
+        .on_update(on_update, OnUpdateOptions {
+            mode: Some(OnUpdateMode::Viewport),
+            ..OnUpdateOptions::default()
+        })
+        .await?;
+
+    Ok(AlertHook {
+        client,
+        view,
+        callback_id,
+    })
+}
+
+impl AlertHook {
+    /// Unregister this alert, deleting its [`View`] and closing its
+    /// [`Session`].
+    pub async fn remove(self) -> ClientResult<()> {
+        self.view.remove_update(self.callback_id).await?;
+        self.view.delete().await?;
+        self.client.close().await;
+        Ok(())
+    }
+}
@@ -0,0 +1,510 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+//! Share one table namespace between several backend
+//! [`perspective_server::Server`]s ("nodes"), placing each table on the node
+//! its name hashes to on a [`HashRing`], and relaying
+//! [`perspective_client::Client`] sessions to the owning node through the
+//! [`crate::proxy`] layer.
+
+use std::collections::{BTreeMap, HashMap};
+use std::sync::{Arc, Mutex, RwLock};
+
+use perspective_client::proto::make_table_data::Data;
+use perspective_client::proto::request::ClientReq;
+use perspective_client::proto::response::ClientResp;
+use perspective_client::proto::{Request, Response};
+use perspective_client::{Client, Table, TableInitOptions, UpdateData, ViewWindow};
+use perspective_server::{ServerError, SessionHandler};
+use prost::Message;
+
+use crate::proxy::{
+    BackendConnection, BackendConnector, Proxy, ProxyCallback, ProxyConfig, Transform,
+};
+
+/// How many points each node occupies on a [`HashRing`].
+const VIRTUAL_NODES: u32 = 64;
+
+/// 64-bit FNV-1a, which (unlike [`std::collections::hash_map::DefaultHasher`])
+/// is stable across processes and versions, so that every edge process
+/// agrees on which node owns a table.
+fn fnv1a(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf29ce484222325, |hash, byte| {
+        (hash ^ *byte as u64).wrapping_mul(0x100000001b3)
+    })
+}
+
+/// Assigns table names to nodes by consistent hashing, such that changing
+/// the nodes of a [`Cluster`] only moves the tables owned by the nodes which
+/// joined or left.
+#[derive(Clone, Debug, Default)]
+pub struct HashRing {
+    nodes: Vec<String>,
+    ring: BTreeMap<u64, usize>,
+}
+
+impl HashRing {
+    pub fn new<I, S>(nodes: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        let mut ring_nodes: Vec<String> = vec![];
+        for node in nodes.into_iter().map(Into::into) {
+            if !ring_nodes.contains(&node) {
+                ring_nodes.push(node);
+            }
+        }
+
+        let mut ring = BTreeMap::new();
+        for (idx, node) in ring_nodes.iter().enumerate() {
+            for replica in 0..VIRTUAL_NODES {
+                let hash = fnv1a(format!("{}#{}", node, replica).as_bytes());
+
+                // Resolve collisions independently of node order.
+                ring.entry(hash)
+                    .and_modify(|owner: &mut usize| {
+                        if *node < ring_nodes[*owner] {
+                            *owner = idx;
+                        }
+                    })
+                    .or_insert(idx);
+            }
+        }
+
+        Self {
+            nodes: ring_nodes,
+            ring,
+        }
+    }
+
+    /// The nodes of this [`HashRing`], in the order they were given.
+    pub fn nodes(&self) -> &[String] {
+        &self.nodes
+    }
+
+    /// The node which owns `key`, or `None` if this [`HashRing`] is empty.
+    pub fn node_for(&self, key: &str) -> Option<&str> {
+        let hash = fnv1a(key.as_bytes());
+        self.ring
+            .range(hash..)
+            .next()
+            .or_else(|| self.ring.iter().next())
+            .map(|(_, idx)| self.nodes[*idx].as_str())
+    }
+}
+
+/// A [`Transform`] which withholds the responses to a request fanned out to
+/// `n` nodes until the last arrives, then relays it (with every node's
+/// `table_infos`, for a `GetHostedTablesResp`). An error is relayed
+/// immediately, and the remaining responses are dropped.
+fn gather(n: usize) -> Transform {
+    let state = Arc::new(Mutex::new((n, vec![])));
+    Arc::new(move |mut resp: Response| {
+        let mut state = state.lock().unwrap();
+        let (remaining, table_infos) = &mut *state;
+        if *remaining == 0 {
+            return None;
+        }
+
+        match &mut resp.client_resp {
+            Some(ClientResp::ServerError(_)) => {
+                *remaining = 0;
+                return Some(resp);
+            },
+            Some(ClientResp::GetHostedTablesResp(x)) => table_infos.append(&mut x.table_infos),
+            _ => {},
+        }
+
+        *remaining -= 1;
+        if *remaining > 0 {
+            return None;
+        }
+
+        if let Some(ClientResp::GetHostedTablesResp(x)) = &mut resp.client_resp {
+            x.table_infos = std::mem::take(table_infos);
+        }
+
+        Some(resp)
+    })
+}
+
+/// A [`Client`] of one node, used to migrate tables between nodes.
+struct AdminClient {
+    id: u32,
+    conn: Arc<BackendConnection>,
+    client: Client,
+}
+
+impl AdminClient {
+    fn new(proxy: &Proxy, node: &str) -> Self {
+        let id = proxy.gen_session_id();
+        let conn = proxy.connection(node.to_owned());
+        let sender = conn.clone();
+        let client = Client::new_with_callback(move |msg| {
+            let conn = sender.clone();
+            Box::pin(async move { conn.relay(id, Request::decode(msg)?, None).await })
+        });
+
+        let receiver = client.clone();
+        let callback: ProxyCallback = Arc::new(move |msg| {
+            let client = receiver.clone();
+            Box::pin(async move { Ok(client.handle_response(msg).await?) })
+        });
+
+        conn.sessions.lock().unwrap().insert(id, callback);
+        Self { id, conn, client }
+    }
+
+    async fn close(self) {
+        self.conn.close_session(self.id, vec![]).await
+    }
+}
+
+/// A [`Proxy`] which treats its backends as the nodes of one table
+/// namespace. Each table lives on the node its name hashes to (see
+/// [`Cluster::node_for`]), and each view on the node its table lived on when
+/// the view was created. [`Client::get_hosted_table_names`] and
+/// [`Client::on_hosted_tables_update`] span every node.
+///
+/// Every edge process which serves the same nodes must be given the same
+/// nodes, in any order; see [`Cluster::set_nodes`].
+#[derive(Clone)]
+pub struct Cluster {
+    proxy: Proxy,
+    ring: Arc<RwLock<HashRing>>,
+    membership: Arc<async_lock::Mutex<()>>,
+}
+
+impl Cluster {
+    /// Create a [`Cluster`] of `nodes`, backend addresses which `connector`
+    /// can connect to.
+    pub fn new<C, I, S>(connector: C, nodes: I) -> Self
+    where
+        C: BackendConnector,
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        Self {
+            proxy: Proxy::new(connector),
+            ring: Arc::new(RwLock::new(HashRing::new(nodes))),
+            membership: Arc::default(),
+        }
+    }
+
+    pub fn with_config(mut self, config: ProxyConfig) -> Self {
+        self.proxy = self.proxy.clone().with_config(config);
+        self
+    }
+
+    /// The current nodes of this [`Cluster`].
+    pub fn nodes(&self) -> Vec<String> {
+        self.ring.read().unwrap().nodes().to_vec()
+    }
+
+    /// The node which owns the table `table_id`.
+    pub fn node_for(&self, table_id: &str) -> Option<String> {
+        self.ring
+            .read()
+            .unwrap()
+            .node_for(table_id)
+            .map(str::to_owned)
+    }
+
+    /// Create a [`ClusterSession`] for one [`perspective_client::Client`],
+    /// analogous to [`perspective_server::Server::new_session`].
+    pub async fn new_session<F>(&self, session_handler: F) -> ClusterSession
+    where
+        F: SessionHandler + 'static + Sync + Send + Clone,
+    {
+        let callback: ProxyCallback = Arc::new(move |msg| {
+            let mut session_handler = session_handler.clone();
+            Box::pin(async move { session_handler.send_response(msg).await })
+        });
+
+        ClusterSession {
+            id: self.proxy.gen_session_id(),
+            cluster: self.clone(),
+            callback,
+            conns: Mutex::default(),
+            views: Mutex::default(),
+            closed: false,
+        }
+    }
+
+    /// Change the nodes of this [`Cluster`], first copying each table whose
+    /// owner changes to its new owner, then deleting the original.
+    ///
+    /// Migration is not atomic: updates to a table while it is being copied
+    /// may be lost, so writers should be paused first. Views of a migrated
+    /// table stay on its former node, and (as the table can't be deleted
+    /// while it has views) so does a stale copy of the table, until they are
+    /// deleted.
+    pub async fn set_nodes<I, S>(&self, nodes: I) -> Result<(), ServerError>
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        let _guard = self.membership.lock().await;
+        let old_nodes = self.nodes();
+        let ring = HashRing::new(nodes);
+        let mut admins: HashMap<String, AdminClient> = HashMap::new();
+        for node in old_nodes.iter().chain(ring.nodes()) {
+            if !admins.contains_key(node) {
+                admins.insert(node.clone(), AdminClient::new(&self.proxy, node));
+            }
+        }
+
+        let result = self.migrate(&old_nodes, ring, &admins).await;
+        for admin in admins.into_values() {
+            admin.close().await;
+        }
+
+        result
+    }
+
+    async fn migrate(
+        &self,
+        old_nodes: &[String],
+        ring: HashRing,
+        admins: &HashMap<String, AdminClient>,
+    ) -> Result<(), ServerError> {
+        let mut migrated: Vec<Table> = vec![];
+        for node in old_nodes {
+            let client = &admins[node].client;
+            for table_id in client.get_hosted_table_names().await? {
+                let owner = ring.node_for(&table_id).ok_or("Cluster has no nodes")?;
+                if owner == node.as_str() {
+                    continue;
+                }
+
+                tracing::debug!("Migrating `{}` from `{}` to `{}`", table_id, node, owner);
+                let table = client.open_table(table_id.clone()).await?;
+                let view = table.view(None).await?;
+                let arrow = view.to_arrow(ViewWindow::default()).await?;
+                view.delete().await?;
+                let mut options = TableInitOptions::default();
+                options.set_name(&table_id);
+                options.index = table.get_index();
+                options.limit = table.get_limit();
+                admins[owner]
+                    .client
+                    .table(UpdateData::Arrow(arrow).into(), options)
+                    .await?;
+
+                migrated.push(table);
+            }
+        }
+
+        *self.ring.write().unwrap() = ring;
+        for table in migrated {
+            if let Err(err) = table.delete().await {
+                tracing::warn!("Failed to delete migrated `{}`: {}", table.get_name(), err);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// The edge of a single [`perspective_client::Client`]'s connection through
+/// a [`Cluster`], analogous to [`perspective_server::Session`].
+pub struct ClusterSession {
+    id: u32,
+    cluster: Cluster,
+    callback: ProxyCallback,
+    conns: Mutex<HashMap<String, Arc<BackendConnection>>>,
+    views: Mutex<HashMap<String, String>>,
+    closed: bool,
+}
+
+impl std::fmt::Debug for ClusterSession {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ClusterSession")
+            .field("id", &self.id)
+            .finish()
+    }
+}
+
+impl Drop for ClusterSession {
+    fn drop(&mut self) {
+        if !self.closed {
+            tracing::error!(
+                "`ClusterSession` dropped without `ClusterSession::close` {:?}",
+                self
+            );
+        }
+    }
+}
+
+impl ClusterSession {
+    /// This session's connection to `node`.
+    fn connection(&self, node: &str) -> Arc<BackendConnection> {
+        self.conns
+            .lock()
+            .unwrap()
+            .entry(node.to_owned())
+            .or_insert_with(|| {
+                let conn = self.cluster.proxy.connection(node.to_owned());
+                conn.sessions
+                    .lock()
+                    .unwrap()
+                    .insert(self.id, self.callback.clone());
+                conn
+            })
+            .clone()
+    }
+
+    fn owner(&self, table_id: &str) -> Result<String, ServerError> {
+        Ok(self
+            .cluster
+            .node_for(table_id)
+            .ok_or("Cluster has no nodes")?)
+    }
+
+    /// This session's connections to every node.
+    fn connections(&self) -> Result<Vec<Arc<BackendConnection>>, ServerError> {
+        let nodes = self.cluster.nodes();
+        if nodes.is_empty() {
+            return Err("Cluster has no nodes".into());
+        }
+
+        Ok(nodes.iter().map(|node| self.connection(node)).collect())
+    }
+
+    /// Send `req` to each of `conns`, relaying their responses through
+    /// `transform`.
+    async fn fan_out(
+        &self,
+        conns: Vec<Arc<BackendConnection>>,
+        req: Request,
+        transform: Option<Transform>,
+    ) -> Result<(), ServerError> {
+        for conn in conns {
+            conn.relay(self.id, req.clone(), transform.clone()).await?;
+        }
+
+        Ok(())
+    }
+
+    /// Relay a request message from this [`ClusterSession`]'s
+    /// [`perspective_client::Client`] to the node which owns the table or
+    /// view it addresses.
+    pub async fn handle_request(&self, msg: &[u8]) -> Result<(), ServerError> {
+        let req = Request::decode(msg)?;
+        let node = match &req.client_req {
+            Some(
+                ClientReq::ServerHelloReq(_)
+                | ClientReq::GetFeaturesReq(_)
+                | ClientReq::ServerSystemInfoReq(_)
+                | ClientReq::ServerExpressionsReq(_),
+            ) => self
+                .cluster
+                .nodes()
+                .into_iter()
+                .next()
+                .ok_or("Cluster has no nodes")?,
+            Some(ClientReq::GetHostedTablesReq(x)) if !x.subscribe => {
+                let conns = self.connections()?;
+                let gather = gather(conns.len());
+                return self.fan_out(conns, req, Some(gather)).await;
+            },
+            Some(ClientReq::GetHostedTablesReq(_)) => {
+                return self.fan_out(self.connections()?, req, None).await
+            },
+            Some(ClientReq::RemoveHostedTablesUpdateReq(x)) => {
+                let conns: Vec<_> = self
+                    .conns
+                    .lock()
+                    .unwrap()
+                    .values()
+                    .filter(|conn| conn.subscription_id(self.id, x.id).is_some())
+                    .cloned()
+                    .collect();
+
+                if conns.is_empty() {
+                    return Err(format!("Unknown subscription {}", x.id).into());
+                }
+
+                let gather = gather(conns.len());
+                return self.fan_out(conns, req, Some(gather)).await;
+            },
+            Some(ClientReq::TableMakeViewReq(x)) => {
+                let node = self.owner(&req.entity_id)?;
+                self.views
+                    .lock()
+                    .unwrap()
+                    .insert(x.view_id.clone(), node.clone());
+                node
+            },
+            Some(ClientReq::ViewDeleteReq(_)) => {
+                let node = self.views.lock().unwrap().remove(&req.entity_id);
+                node.ok_or_else(|| format!("Unknown view `{}`", req.entity_id))?
+            },
+            Some(ClientReq::MakeTableReq(x)) => {
+                let node = self.owner(&req.entity_id)?;
+                if let Some(Data::FromView(view_id)) = x.data.as_ref().and_then(|x| x.data.as_ref())
+                {
+                    let view_node = self.views.lock().unwrap().get(view_id).cloned();
+                    if view_node.as_ref() != Some(&node) {
+                        return Err(format!(
+                            "Can't create `{}` on node `{}` from a view on another node",
+                            req.entity_id, node
+                        )
+                        .into());
+                    }
+                }
+
+                node
+            },
+            Some(ClientReq::TableRenameReq(x)) => {
+                let node = self.owner(&req.entity_id)?;
+                if self.owner(&x.new_name)? != node {
+                    return Err(format!(
+                        "Can't rename `{}` to `{}`, which is owned by another node",
+                        req.entity_id, x.new_name
+                    )
+                    .into());
+                }
+
+                node
+            },
+            _ => {
+                let node = self.views.lock().unwrap().get(&req.entity_id).cloned();
+                match node {
+                    Some(node) => node,
+                    None => self.owner(&req.entity_id)?,
+                }
+            },
+        };
+
+        self.connection(&node).relay(self.id, req, None).await
+    }
+
+    /// Close this [`ClusterSession`], deleting the views its
+    /// [`perspective_client::Client`] created on each node.
+    pub async fn close(mut self) {
+        self.closed = true;
+        let conns = std::mem::take(&mut *self.conns.lock().unwrap());
+        let mut views = std::mem::take(&mut *self.views.lock().unwrap());
+        for (node, conn) in conns {
+            let node_views = views
+                .iter()
+                .filter(|(_, view_node)| **view_node == node)
+                .map(|(view_id, _)| view_id.clone())
+                .collect();
+
+            views.retain(|_, view_node| *view_node != node);
+            conn.close_session(self.id, node_views).await;
+        }
+    }
+}
@@ -11,18 +11,33 @@
 // ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
 
 use std::ops::Deref;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::{Arc, OnceLock};
+use std::time::Duration;
 
 use async_lock::{RwLock, RwLockReadGuard};
 use perspective_client::*;
 use perspective_server::*;
 pub use {perspective_client as client, perspective_server as server};
 
+pub mod accel;
+mod alert;
+pub mod cluster;
+mod on_commit;
+pub mod persist;
+pub mod proxy;
+pub mod replica;
+pub mod schedule;
+
+pub use crate::alert::{on_alert, Alert, AlertHook, AlertRule, Comparison, Condition};
+pub use crate::on_commit::{on_commit, Commit, CommitHook};
+
 #[derive(Clone, Default)]
 struct LocalClientState {
     client: Arc<OnceLock<Client>>,
     session: Arc<OnceLock<RwLock<Option<Session>>>>,
     server: Server,
+    poll_scheduled: Arc<AtomicBool>,
 }
 
 impl SessionHandler for LocalClientState {
@@ -41,6 +56,10 @@ impl ClientHandler for LocalClientState {
         let session = session_lock.as_ref().unwrap();
         session.handle_request(msg).await?;
         session.poll().await?;
+        if let Some(delay) = session.next_poll_delay() {
+            self.schedule_poll(delay);
+        }
+
         Ok(())
     }
 }
@@ -50,6 +69,36 @@ impl LocalClientState {
         self.client.get_or_init(|| Client::new(self.clone()))
     }
 
+    /// Poll the [`Session`] after `delay`, to commit updates held by a
+    /// batched [`Table`] even if no further requests are made. This crate
+    /// does not depend on an async runtime, so the timer is a thread; at most
+    /// one is pending per [`LocalClient`].
+    fn schedule_poll(&self, delay: Duration) {
+        if self.poll_scheduled.swap(true, Ordering::AcqRel) {
+            return;
+        }
+
+        let state = self.clone();
+        std::thread::spawn(move || {
+            std::thread::sleep(delay);
+            state.poll_scheduled.store(false, Ordering::Release);
+            futures::executor::block_on(async {
+                let session_lock = state.get_session().await;
+                let Some(session) = session_lock.as_ref() else {
+                    return;
+                };
+
+                if let Err(err) = session.poll().await {
+                    tracing::error!("Scheduled poll failed: {}", err);
+                }
+
+                if let Some(delay) = session.next_poll_delay() {
+                    state.schedule_poll(delay);
+                }
+            })
+        });
+    }
+
     async fn get_session(&self) -> RwLockReadGuard<'_, Option<Session>> {
         if self.session.get().is_none() {
             let session = self.server.new_session(self.clone()).await;
@@ -93,6 +142,7 @@ impl LocalClient {
             server: server.clone(),
             client: Arc::default(),
             session: Arc::default(),
+            poll_scheduled: Arc::default(),
         };
 
         LocalClient(state)
@@ -0,0 +1,105 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use std::future::Future;
+use std::sync::Arc;
+
+use perspective_client::*;
+use perspective_server::*;
+
+use crate::LocalClient;
+
+/// One commit observed by an [`on_commit`] hook.
+#[derive(Clone, Debug)]
+pub struct Commit {
+    /// The inserted and updated rows, as an Arrow IPC stream.
+    pub delta: Vec<u8>,
+
+    /// The index of each removed row (or its implicit row number, for a
+    /// [`Table`] without an `index`).
+    pub removed: Vec<serde_json::Value>,
+}
+
+/// A write-through hook registered with [`on_commit`].
+pub struct CommitHook {
+    client: LocalClient,
+    view: View,
+    callback_id: u32,
+}
+
+/// Register a `callback` which is invoked with the applied [`Commit`] (the
+/// updated rows and the index of the removed rows) after each successful
+/// update or removal of the [`Table`] named `table_name`, e.g. to write
+/// through to a database or message bus.
+///
+/// The hook is implemented as an un-pivoted [`View`] owned by its own
+/// [`Session`], so deltas are delivered in the same poll, and the same order,
+/// that every other [`View`] of the table observes them. The callback is
+/// awaited from within [`Server`]'s dispatch, so it must not itself wait on
+/// another request to the same [`Server`].
+///
+/// Like any other [`View`], the hook prevents [`Table::delete`]; call
+/// [`CommitHook::remove`] first, or use [`Server::unhost`].
+pub async fn on_commit<F, U>(
+    server: &Server,
+    table_name: &str,
+    callback: F,
+) -> ClientResult<CommitHook>
+where
+    F: Fn(Commit) -> U + Send + Sync + 'static,
+    U: Future<Output = ()> + Send + 'static,
+{
+    let client = LocalClient::new(server);
+    let table = client.open_table(table_name.to_owned()).await?;
+    let view = table.view(None).await?;
+    let callback = Arc::new(callback);
+    let on_update = move |resp: perspective_client::proto::ViewOnUpdateResp| {
+        let callback = callback.clone();
+        async move {
+            let Some(delta) = resp.delta else {
+                return;
+            };
+
+            let removed = resp
+                .removed
+                .iter()
+                .filter_map(|index| serde_json::from_str(index).ok())
+                .collect();
+
+            callback(Commit { delta, removed }).await
+        }
+    };
+
+    let callback_id = view
+        .on_update(on_update, OnUpdateOptions {
+            mode: Some(OnUpdateMode::Row),
+            ..OnUpdateOptions::default()
+        })
+        .await?;
+
+    Ok(CommitHook {
+        client,
+        view,
+        callback_id,
+    })
+}
+
+impl CommitHook {
+    /// Unregister this hook, deleting its [`View`] and closing its
+    /// [`Session`].
+    pub async fn remove(self) -> ClientResult<()> {
+        self.view.remove_update(self.callback_id).await?;
+        self.view.delete().await?;
+        self.client.close().await;
+        Ok(())
+    }
+}
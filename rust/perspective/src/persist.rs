@@ -0,0 +1,731 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+//! Persist hosted tables to disk, as a snapshot of each table's rows plus a
+//! write-ahead log (WAL) of the commits since, and restore them into a
+//! [`Server`] on startup. Files may be encrypted at rest with AES-256-GCM,
+//! with keys from a pluggable [`KeyProvider`].
+//!
+//! Each table persisted by a [`Store`] has its own directory, holding files
+//! numbered by generation. [`PersistedTable::snapshot`] starts a new
+//! generation: commits are logged to the new generation's WAL before its
+//! snapshot is read, so a restore replays the WAL of the newest snapshot's
+//! generation (and any later one) over it. Replay is idempotent because a
+//! persisted table must have an `index`, so a commit which is also in the
+//! snapshot is applied twice to the same rows.
+//!
+//! Views pinned with [`PersistedTable::pin_view`] are saved with the
+//! snapshot, and recreated by [`Store::restore`] before it returns, so
+//! expensive standing aggregations are materialized before a restored server
+//! takes any load.
+//!
+//! A [`Store`] created [`Store::with_retained_snapshots`] keeps that many of
+//! each table's newest snapshots as versions, which
+//! [`PersistedTable::diff`] compares, e.g. for end-of-day reconciliation.
+
+use std::collections::{HashMap, HashSet};
+use std::fs::{File, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+
+use aes_gcm::aead::{Aead, AeadCore, KeyInit, OsRng, Payload};
+use aes_gcm::{Aes256Gcm, Key, Nonce};
+use perspective_client::config::ViewConfigUpdate;
+use perspective_client::{Table, TableInitOptions, UpdateData, UpdateOptions, View, ViewWindow};
+use perspective_server::{Server, ServerError};
+
+use crate::{on_commit, Commit, CommitHook, LocalClient};
+
+const SNAPSHOT_MAGIC: &[u8; 4] = b"PSPS";
+const WAL_MAGIC: &[u8; 4] = b"PSPW";
+const FORMAT_VERSION: u8 = 1;
+const NONCE_LEN: usize = 12;
+
+const WAL_UPDATE: u8 = 0;
+const WAL_REMOVE: u8 = 1;
+
+/// A source of the AES-256 keys which encrypt a [`Store`]'s files, e.g. a
+/// KMS or secrets manager client. Each encrypted record names the key it was
+/// encrypted with, so keys can be rotated by changing
+/// [`KeyProvider::current_key_id`], as long as [`KeyProvider::key`] can
+/// still return the old keys until every file which uses them has been
+/// compacted by [`PersistedTable::snapshot`].
+pub trait KeyProvider: Send + Sync {
+    /// The id of the key to encrypt new records with.
+    fn current_key_id(&self) -> String;
+
+    /// The 256-bit key named `key_id`.
+    fn key(&self, key_id: &str) -> Result<[u8; 32], ServerError>;
+}
+
+/// A [`KeyProvider`] with a single key.
+#[derive(Clone)]
+pub struct StaticKeyProvider {
+    key_id: String,
+    key: [u8; 32],
+}
+
+impl StaticKeyProvider {
+    pub fn new(key_id: &str, key: [u8; 32]) -> Self {
+        Self {
+            key_id: key_id.to_owned(),
+            key,
+        }
+    }
+}
+
+impl KeyProvider for StaticKeyProvider {
+    fn current_key_id(&self) -> String {
+        self.key_id.clone()
+    }
+
+    fn key(&self, key_id: &str) -> Result<[u8; 32], ServerError> {
+        if key_id == self.key_id {
+            Ok(self.key)
+        } else {
+            Err(format!("Unknown key `{}`", key_id).into())
+        }
+    }
+}
+
+/// Seals and opens the records of one file. Records are bound to their file
+/// (its kind, table and generation) as associated data, so an encrypted
+/// record can't be moved to another file undetected.
+#[derive(Clone)]
+struct Codec {
+    keys: Option<Arc<dyn KeyProvider>>,
+    aad: Vec<u8>,
+}
+
+impl Codec {
+    fn new(keys: &Option<Arc<dyn KeyProvider>>, magic: &[u8; 4], name: &str, gen: u64) -> Self {
+        let mut aad = magic.to_vec();
+        aad.extend_from_slice(&gen.to_le_bytes());
+        aad.extend_from_slice(name.as_bytes());
+        Self {
+            keys: keys.clone(),
+            aad,
+        }
+    }
+
+    fn header(&self, magic: &[u8; 4]) -> [u8; 8] {
+        let encrypted = self.keys.is_some() as u8;
+        let mut header = [0; 8];
+        header[..4].copy_from_slice(magic);
+        header[4] = FORMAT_VERSION;
+        header[5] = encrypted;
+        header
+    }
+
+    fn check_header(&self, magic: &[u8; 4], header: &[u8]) -> Result<(), ServerError> {
+        if header.len() < 8 || &header[..4] != magic || header[4] != FORMAT_VERSION {
+            return Err("Not a persisted table file".into());
+        }
+
+        match (header[5] != 0, self.keys.is_some()) {
+            (true, false) => Err("File is encrypted, but the `Store` has no `KeyProvider`".into()),
+            (false, true) => Err("File is not encrypted".into()),
+            _ => Ok(()),
+        }
+    }
+
+    /// A record is `key_id_len: u8, key_id, nonce, ciphertext` when
+    /// encrypted, or the plaintext itself otherwise.
+    fn seal(&self, msg: &[u8]) -> Result<Vec<u8>, ServerError> {
+        let Some(keys) = &self.keys else {
+            return Ok(msg.to_vec());
+        };
+
+        let key_id = keys.current_key_id();
+        let key_len = u8::try_from(key_id.len()).map_err(|_| "Key id too long")?;
+        let cipher = Aes256Gcm::new(Key::<Aes256Gcm>::from_slice(&keys.key(&key_id)?));
+        let nonce = Aes256Gcm::generate_nonce(&mut OsRng);
+        let payload = Payload {
+            msg,
+            aad: &self.aad,
+        };
+
+        let ciphertext = cipher
+            .encrypt(&nonce, payload)
+            .map_err(|_| "Encryption failed")?;
+
+        let mut record = vec![key_len];
+        record.extend_from_slice(key_id.as_bytes());
+        record.extend_from_slice(&nonce);
+        record.extend_from_slice(&ciphertext);
+        Ok(record)
+    }
+
+    fn open(&self, record: &[u8]) -> Result<Vec<u8>, ServerError> {
+        let Some(keys) = &self.keys else {
+            return Ok(record.to_vec());
+        };
+
+        let (&key_len, rest) = record.split_first().ok_or("Truncated record")?;
+        let key_len = key_len as usize;
+        if rest.len() < key_len + NONCE_LEN {
+            return Err("Truncated record".into());
+        }
+
+        let (key_id, rest) = rest.split_at(key_len);
+        let (nonce, ciphertext) = rest.split_at(NONCE_LEN);
+        let key_id = std::str::from_utf8(key_id)?;
+        let cipher = Aes256Gcm::new(Key::<Aes256Gcm>::from_slice(&keys.key(key_id)?));
+        let payload = Payload {
+            msg: ciphertext,
+            aad: &self.aad,
+        };
+
+        cipher
+            .decrypt(Nonce::from_slice(nonce), payload)
+            .map_err(|_| "Record failed authentication (wrong key, or corrupt)".into())
+    }
+}
+
+fn write_record(out: &mut impl Write, record: &[u8]) -> Result<(), ServerError> {
+    let len = u32::try_from(record.len()).map_err(|_| "Record too large")?;
+    out.write_all(&len.to_le_bytes())?;
+    out.write_all(record)?;
+    Ok(())
+}
+
+/// The records of a file, after its header. A truncated final record (e.g.
+/// from a crash mid-append) is ignored.
+fn read_records(bytes: &[u8]) -> Vec<&[u8]> {
+    let mut records = vec![];
+    let mut rest = bytes;
+    while rest.len() >= 4 {
+        let len = u32::from_le_bytes(rest[..4].try_into().unwrap()) as usize;
+        if rest.len() < 4 + len {
+            break;
+        }
+
+        records.push(&rest[4..4 + len]);
+        rest = &rest[4 + len..];
+    }
+
+    records
+}
+
+/// A directory of persisted tables, see the [module docs](self).
+#[derive(Clone)]
+pub struct Store {
+    dir: PathBuf,
+    keys: Option<Arc<dyn KeyProvider>>,
+    retained: usize,
+}
+
+impl Store {
+    /// A [`Store`] of unencrypted files in `dir`.
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self {
+            dir: dir.into(),
+            keys: None,
+            retained: 1,
+        }
+    }
+
+    /// Encrypt this [`Store`]'s files with keys from `keys`. An encrypted
+    /// [`Store`] will not read unencrypted files, and vice-versa.
+    pub fn with_key_provider(self, keys: impl KeyProvider + 'static) -> Self {
+        Self {
+            keys: Some(Arc::new(keys)),
+            ..self
+        }
+    }
+
+    /// Keep the newest `count` (at least 1) snapshots of each table, rather
+    /// than only the newest, as versions for [`PersistedTable::diff`].
+    pub fn with_retained_snapshots(self, count: usize) -> Self {
+        Self {
+            retained: count.max(1),
+            ..self
+        }
+    }
+
+    fn table_dir(&self, name: &str) -> PathBuf {
+        let hex: String = name.bytes().map(|x| format!("{:02x}", x)).collect();
+        self.dir.join(hex)
+    }
+
+    /// Start persisting the hosted table `name`, which must have an `index`,
+    /// writing its first snapshot before returning.
+    pub async fn persist(
+        &self,
+        server: &Server,
+        name: &str,
+    ) -> Result<PersistedTable, ServerError> {
+        self.persist_pinned(server, name, vec![]).await
+    }
+
+    async fn persist_pinned(
+        &self,
+        server: &Server,
+        name: &str,
+        pinned: Vec<ViewConfigUpdate>,
+    ) -> Result<PersistedTable, ServerError> {
+        let client = LocalClient::new(server);
+        let table = match client.open_table(name.to_owned()).await {
+            Ok(table) if table.get_index().is_some() => table,
+            Ok(_) => {
+                client.close().await;
+                return Err(format!("Persisted table `{}` must have an index", name).into());
+            },
+            Err(err) => {
+                client.close().await;
+                return Err(err.into());
+            },
+        };
+
+        let dir = self.table_dir(name);
+        std::fs::create_dir_all(&dir)?;
+        let gen = generations(&dir, "wal")?
+            .into_iter()
+            .chain(generations(&dir, "snapshot")?)
+            .max()
+            .map_or(0, |gen| gen + 1);
+
+        let wal = Arc::new(Mutex::new(Wal::create(self, &dir, name, gen)?));
+        let hook = on_commit(server, name, {
+            let wal = wal.clone();
+            move |commit| {
+                if let Err(err) = wal.lock().unwrap().append(&commit) {
+                    tracing::error!("Failed to log commit: {}", err);
+                }
+
+                futures::future::ready(())
+            }
+        })
+        .await?;
+
+        let mut persisted = PersistedTable {
+            store: self.clone(),
+            dir,
+            client,
+            table,
+            hook,
+            wal,
+            pinned: vec![],
+        };
+
+        for config in pinned {
+            let view = persisted.table.view(Some(config.clone())).await?;
+            persisted.pinned.push((config, view));
+        }
+
+        persisted.write_snapshot(gen).await?;
+        Ok(persisted)
+    }
+
+    /// Host every table in this [`Store`] on `server`, from its newest
+    /// snapshot and the WAL since, recreate their pinned views, and resume
+    /// persisting them. Fails if any file can't be read or authenticated.
+    pub async fn restore(&self, server: &Server) -> Result<Vec<PersistedTable>, ServerError> {
+        let mut tables = vec![];
+        if !self.dir.exists() {
+            return Ok(tables);
+        }
+
+        let mut dirs = std::fs::read_dir(&self.dir)?
+            .map(|entry| Ok(entry?.path()))
+            .collect::<Result<Vec<_>, std::io::Error>>()?;
+
+        dirs.sort();
+        for dir in dirs.into_iter().filter(|x| x.is_dir()) {
+            let Some(&gen) = generations(&dir, "snapshot")?.last() else {
+                continue;
+            };
+
+            let (name, pinned) = self.restore_table(server, &dir, gen).await?;
+            tables.push(self.persist_pinned(server, &name, pinned).await?);
+        }
+
+        Ok(tables)
+    }
+
+    async fn restore_table(
+        &self,
+        server: &Server,
+        dir: &Path,
+        gen: u64,
+    ) -> Result<(String, Vec<ViewConfigUpdate>), ServerError> {
+        // The table name is part of each record's associated data, so it is
+        // read (unauthenticated) from the directory name, then checked by
+        // opening the records.
+        let name = table_name(dir)?;
+        let (meta, arrow) = self.read_snapshot(dir, &name, gen)?;
+        let index = meta["index"].as_str().ok_or("Snapshot has no index")?;
+        let pinned = match meta.get("pinned") {
+            Some(pinned) => serde_json::from_value(pinned.clone())?,
+            None => vec![],
+        };
+
+        let client = LocalClient::new(server);
+        let result = async {
+            let table = client
+                .table(UpdateData::Arrow(arrow.into()).into(), TableInitOptions {
+                    name: Some(name.clone()),
+                    index: Some(index.to_owned()),
+                    ..TableInitOptions::default()
+                })
+                .await?;
+
+            for wal_gen in generations(dir, "wal")?.into_iter().filter(|x| *x >= gen) {
+                self.replay_wal(&table, dir, &name, wal_gen).await?;
+            }
+
+            Ok::<_, ServerError>(())
+        }
+        .await;
+
+        client.close().await;
+        result.map(|_| (name, pinned))
+    }
+
+    /// The metadata and Arrow rows of the snapshot `gen` of the table `name`.
+    fn read_snapshot(
+        &self,
+        dir: &Path,
+        name: &str,
+        gen: u64,
+    ) -> Result<(serde_json::Value, Vec<u8>), ServerError> {
+        let codec = Codec::new(&self.keys, SNAPSHOT_MAGIC, name, gen);
+        let bytes = std::fs::read(dir.join(file_name(gen, "snapshot")))?;
+        codec.check_header(SNAPSHOT_MAGIC, &bytes)?;
+        let [meta, arrow] = read_records(&bytes[8..])[..] else {
+            return Err(format!("Malformed snapshot {}", dir.display()).into());
+        };
+
+        let meta = serde_json::from_slice(&codec.open(meta)?)?;
+        Ok((meta, codec.open(arrow)?))
+    }
+
+    async fn replay_wal(
+        &self,
+        table: &Table,
+        dir: &Path,
+        name: &str,
+        gen: u64,
+    ) -> Result<(), ServerError> {
+        let bytes = std::fs::read(dir.join(file_name(gen, "wal")))?;
+        let codec = Codec::new(&self.keys, WAL_MAGIC, name, gen);
+        codec.check_header(WAL_MAGIC, &bytes)?;
+        for record in read_records(&bytes[8..]) {
+            let record = codec.open(record)?;
+            match record.split_first() {
+                Some((&WAL_REMOVE, removed)) => {
+                    let removed = std::str::from_utf8(removed)?.to_owned();
+                    table.remove(UpdateData::JsonRows(removed)).await?;
+                },
+                Some((&WAL_UPDATE, delta)) => {
+                    table
+                        .update(
+                            UpdateData::Arrow(delta.to_vec().into()),
+                            UpdateOptions::default(),
+                        )
+                        .await?;
+                },
+                _ => return Err(format!("Malformed WAL record in {}", dir.display()).into()),
+            }
+        }
+
+        Ok(())
+    }
+}
+
+fn file_name(gen: u64, ext: &str) -> String {
+    format!("{:016x}.{}", gen, ext)
+}
+
+/// The generations of the files in `dir` with extension `ext`, in order.
+fn generations(dir: &Path, ext: &str) -> Result<Vec<u64>, ServerError> {
+    let mut gens = vec![];
+    for entry in std::fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.extension().and_then(|x| x.to_str()) == Some(ext) {
+            let stem = path
+                .file_stem()
+                .and_then(|x| x.to_str())
+                .unwrap_or_default();
+            if let Ok(gen) = u64::from_str_radix(stem, 16) {
+                gens.push(gen);
+            }
+        }
+    }
+
+    gens.sort();
+    Ok(gens)
+}
+
+fn table_name(dir: &Path) -> Result<String, ServerError> {
+    let hex = dir
+        .file_name()
+        .and_then(|x| x.to_str())
+        .ok_or("Bad table directory")?;
+
+    let bytes = (0..hex.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(hex.get(i..i + 2).unwrap_or_default(), 16))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(String::from_utf8(bytes)?)
+}
+
+/// The WAL file of the current generation of a [`PersistedTable`].
+struct Wal {
+    file: File,
+    codec: Codec,
+    gen: u64,
+}
+
+impl Wal {
+    fn create(store: &Store, dir: &Path, name: &str, gen: u64) -> Result<Self, ServerError> {
+        let codec = Codec::new(&store.keys, WAL_MAGIC, name, gen);
+        let mut file = OpenOptions::new()
+            .create(true)
+            .truncate(true)
+            .write(true)
+            .open(dir.join(file_name(gen, "wal")))?;
+
+        file.write_all(&codec.header(WAL_MAGIC))?;
+        file.sync_data()?;
+        Ok(Self { file, codec, gen })
+    }
+
+    /// Log `commit` durably. Removals are logged first, so that replaying a
+    /// commit which removed then re-inserted a row leaves it inserted.
+    fn append(&mut self, commit: &Commit) -> Result<(), ServerError> {
+        if !commit.removed.is_empty() {
+            let mut record = vec![WAL_REMOVE];
+            serde_json::to_writer(&mut record, &commit.removed)?;
+            write_record(&mut self.file, &self.codec.seal(&record)?)?;
+        }
+
+        let mut record = vec![WAL_UPDATE];
+        record.extend_from_slice(&commit.delta);
+        write_record(&mut self.file, &self.codec.seal(&record)?)?;
+        self.file.sync_data()?;
+        Ok(())
+    }
+}
+
+/// The rows which differ between two versions of a [`PersistedTable`], each
+/// as an Arrow IPC stream with the table's schema.
+#[derive(Clone, Debug, Default)]
+pub struct TableDiff {
+    /// Rows of the later version whose index is not in the earlier one.
+    pub added: Vec<u8>,
+
+    /// Rows of the earlier version whose index is not in the later one.
+    pub removed: Vec<u8>,
+
+    /// Rows of the later version whose values differ from the earlier one's
+    /// row with the same index.
+    pub changed: Vec<u8>,
+}
+
+/// The rows of a table keyed by their index value's JSON.
+type Rows = HashMap<String, (serde_json::Value, serde_json::Value)>;
+
+/// A table persisted by [`Store::persist`] or [`Store::restore`], whose
+/// commits are logged until [`PersistedTable::close`].
+pub struct PersistedTable {
+    store: Store,
+    dir: PathBuf,
+    client: LocalClient,
+    table: Table,
+    hook: CommitHook,
+    wal: Arc<Mutex<Wal>>,
+    pinned: Vec<(ViewConfigUpdate, View)>,
+}
+
+impl PersistedTable {
+    /// The name of the persisted table.
+    pub fn name(&self) -> &str {
+        self.table.get_name()
+    }
+
+    /// Create a view of the table which is saved with its snapshots and
+    /// recreated by [`Store::restore`], and write a new snapshot to save it.
+    /// The view lives until [`PersistedTable::close`].
+    pub async fn pin_view(&mut self, config: ViewConfigUpdate) -> Result<&View, ServerError> {
+        let view = self.table.view(Some(config.clone())).await?;
+        self.pinned.push((config, view));
+        self.snapshot().await?;
+        Ok(&self.pinned[self.pinned.len() - 1].1)
+    }
+
+    /// The table's pinned views, in the order they were pinned.
+    pub fn pinned_views(&self) -> impl Iterator<Item = &View> {
+        self.pinned.iter().map(|(_, view)| view)
+    }
+
+    /// Write a new snapshot of the table and start a new WAL, deleting the
+    /// files of earlier generations (except retained snapshots), so that a
+    /// restore need not replay every commit since [`Store::persist`].
+    /// Returns the new snapshot's version.
+    pub async fn snapshot(&self) -> Result<u64, ServerError> {
+        let gen = {
+            let mut wal = self.wal.lock().unwrap();
+            let next = Wal::create(&self.store, &self.dir, self.name(), wal.gen + 1)?;
+            *wal = next;
+            wal.gen
+        };
+
+        self.write_snapshot(gen).await?;
+        Ok(gen)
+    }
+
+    /// The versions of the table which [`PersistedTable::diff`] can compare,
+    /// oldest first: those of its retained snapshots.
+    pub fn versions(&self) -> Result<Vec<u64>, ServerError> {
+        generations(&self.dir, "snapshot")
+    }
+
+    /// The rows added, removed and changed between the snapshots `from` and
+    /// `to`, see [`PersistedTable::versions`].
+    pub async fn diff(&self, from: u64, to: u64) -> Result<TableDiff, ServerError> {
+        let (_, from_arrow) = self.store.read_snapshot(&self.dir, self.name(), from)?;
+        let (_, to_arrow) = self.store.read_snapshot(&self.dir, self.name(), to)?;
+        let from_rows = self.rows(&from_arrow).await?;
+        let to_rows = self.rows(&to_arrow).await?;
+        let mut added = HashSet::new();
+        let mut changed = HashSet::new();
+        for (key, (_, row)) in &to_rows {
+            match from_rows.get(key) {
+                None => added.insert(key),
+                Some((_, old)) if old != row => changed.insert(key),
+                Some(_) => false,
+            };
+        }
+
+        let removed = from_rows
+            .keys()
+            .filter(|key| !to_rows.contains_key(*key))
+            .collect::<HashSet<_>>();
+
+        Ok(TableDiff {
+            added: self.select(&to_arrow, &to_rows, &added).await?,
+            removed: self.select(&from_arrow, &from_rows, &removed).await?,
+            changed: self.select(&to_arrow, &to_rows, &changed).await?,
+        })
+    }
+
+    /// Host `arrow` as a temporary table with this table's index.
+    async fn temp_table(&self, arrow: &[u8]) -> Result<Table, ServerError> {
+        let options = TableInitOptions {
+            index: self.table.get_index(),
+            ..TableInitOptions::default()
+        };
+
+        let data = UpdateData::Arrow(arrow.to_vec().into());
+        Ok(self.client.table(data.into(), options).await?)
+    }
+
+    async fn rows(&self, arrow: &[u8]) -> Result<Rows, ServerError> {
+        let table = self.temp_table(arrow).await?;
+        let view = table.view(None).await?;
+        let json = view.to_columns_string(ViewWindow::default()).await;
+        view.delete().await?;
+        table.delete().await?;
+        let columns: serde_json::Map<String, serde_json::Value> = serde_json::from_str(&json?)?;
+        let index = self.table.get_index().unwrap_or_default();
+        let size = columns[&index].as_array().map_or(0, |x| x.len());
+        let rows = (0..size).map(|ridx| {
+            let row = columns
+                .iter()
+                .map(|(name, values)| (name.clone(), values[ridx].clone()))
+                .collect::<serde_json::Map<_, _>>();
+
+            let key = row[&index].clone();
+            (key.to_string(), (key, row.into()))
+        });
+
+        Ok(rows.collect())
+    }
+
+    /// The rows of `arrow` whose keys are in `keys`, as Arrow.
+    async fn select(
+        &self,
+        arrow: &[u8],
+        rows: &Rows,
+        keys: &HashSet<&String>,
+    ) -> Result<Vec<u8>, ServerError> {
+        let table = self.temp_table(arrow).await?;
+        let others = rows
+            .iter()
+            .filter(|(key, _)| !keys.contains(key))
+            .map(|(_, (index, _))| index)
+            .collect::<Vec<_>>();
+
+        if !others.is_empty() {
+            let others = serde_json::to_string(&others)?;
+            table.remove(UpdateData::JsonRows(others)).await?;
+        }
+
+        let view = table.view(None).await?;
+        let arrow = view.to_arrow(ViewWindow::default()).await;
+        view.delete().await?;
+        table.delete().await?;
+        Ok(arrow?.to_vec())
+    }
+
+    async fn write_snapshot(&self, gen: u64) -> Result<(), ServerError> {
+        let view = self.table.view(None).await?;
+        let arrow = view.to_arrow(ViewWindow::default()).await;
+        view.delete().await?;
+        let arrow = arrow?;
+        let codec = Codec::new(&self.store.keys, SNAPSHOT_MAGIC, self.name(), gen);
+        let pinned = self.pinned.iter().map(|(config, _)| config);
+        let meta = serde_json::json!({
+            "index": self.table.get_index(),
+            "pinned": pinned.collect::<Vec<_>>(),
+        });
+        let path = self.dir.join(file_name(gen, "snapshot"));
+        let tmp = path.with_extension("tmp");
+        let mut file = File::create(&tmp)?;
+        file.write_all(&codec.header(SNAPSHOT_MAGIC))?;
+        write_record(&mut file, &codec.seal(&serde_json::to_vec(&meta)?)?)?;
+        write_record(&mut file, &codec.seal(&arrow)?)?;
+        file.sync_all()?;
+        std::fs::rename(&tmp, &path)?;
+        self.delete_before(gen)
+    }
+
+    /// Delete the WALs of generations before `gen`, and the snapshots
+    /// before the newest retained ones.
+    fn delete_before(&self, gen: u64) -> Result<(), ServerError> {
+        let snapshots = generations(&self.dir, "snapshot")?;
+        let expired = snapshots.len().saturating_sub(self.store.retained);
+        for old in &snapshots[..expired] {
+            std::fs::remove_file(self.dir.join(file_name(*old, "snapshot")))?;
+        }
+
+        for old in generations(&self.dir, "wal")?
+            .into_iter()
+            .filter(|x| *x < gen)
+        {
+            std::fs::remove_file(self.dir.join(file_name(old, "wal")))?;
+        }
+
+        Ok(())
+    }
+
+    /// Stop logging commits to the table, and delete its pinned views. Its
+    /// files are kept, for a later [`Store::restore`].
+    pub async fn close(self) -> Result<(), ServerError> {
+        self.hook.remove().await?;
+        self.client.close().await;
+        Ok(())
+    }
+}
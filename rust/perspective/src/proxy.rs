@@ -0,0 +1,667 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+//! Relay [`perspective_client::Client`] sessions from an edge process (e.g. a
+//! gateway which terminates TLS and authenticates users) to backend
+//! [`perspective_server::Server`]s.
+
+use std::collections::{HashMap, HashSet};
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::{Arc, Mutex, Weak};
+
+use futures::future::BoxFuture;
+use perspective_client::proto::request::ClientReq;
+use perspective_client::proto::response::ClientResp;
+use perspective_client::proto::{self, Request, Response};
+use perspective_server::{ServerError, SessionHandler};
+use prost::Message;
+
+pub(crate) type ProxyCallback =
+    Arc<dyn for<'a> Fn(&'a [u8]) -> BoxFuture<'a, Result<(), ServerError>> + Send + Sync>;
+
+type Router = Arc<dyn Fn(&str) -> String + Send + Sync>;
+
+/// Rewrites (or, by returning `None`, withholds) a backend's response before
+/// it is relayed to the session which made the request.
+pub(crate) type Transform = Arc<dyn Fn(Response) -> Option<Response> + Send + Sync>;
+
+/// The sending half of a connection to a backend
+/// [`perspective_server::Server`], e.g. a WebSocket to a remote process or a
+/// [`perspective_server::Session`] in this one.
+pub trait BackendSender: Send + Sync + 'static {
+    /// Send a request message to the backend.
+    fn send<'a>(&'a self, msg: &'a [u8]) -> BoxFuture<'a, Result<(), ServerError>>;
+}
+
+/// Opens connections to backend [`perspective_server::Server`]s for a
+/// [`Proxy`].
+pub trait BackendConnector: Send + Sync + 'static {
+    /// Open a new connection to `backend`, an address returned by the
+    /// [`Proxy`]'s router. Every message received on the connection must be
+    /// passed to [`BackendReceiver::handle_response`], and the connection's
+    /// closing reported with [`BackendReceiver::close`].
+    fn connect<'a>(
+        &'a self,
+        backend: &'a str,
+        receiver: BackendReceiver,
+    ) -> BoxFuture<'a, Result<Box<dyn BackendSender>, ServerError>>;
+}
+
+/// The receiving half of a connection opened by a [`BackendConnector`].
+#[derive(Clone)]
+pub struct BackendReceiver {
+    conn: Weak<BackendConnection>,
+    generation: u64,
+}
+
+impl BackendReceiver {
+    /// Relay a response message from the backend to the [`ProxySession`]
+    /// which made the request.
+    pub async fn handle_response(&self, msg: &[u8]) -> Result<(), ServerError> {
+        match self.conn.upgrade() {
+            Some(conn) => conn.handle_response(msg).await,
+            None => Ok(()),
+        }
+    }
+
+    /// Report that this connection has closed. Requests which have not been
+    /// answered fail, and the next request reconnects.
+    pub async fn close(&self) {
+        if let Some(conn) = self.conn.upgrade() {
+            conn.disconnect(self.generation).await
+        }
+    }
+}
+
+/// Options for a [`Proxy`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ProxyConfig {
+    /// How many connections to open to each backend. [`ProxySession`]s share
+    /// these connections, and are assigned to the least used one.
+    pub connections_per_backend: usize,
+}
+
+impl Default for ProxyConfig {
+    fn default() -> Self {
+        Self {
+            connections_per_backend: 4,
+        }
+    }
+}
+
+/// Where to send a response from the backend.
+#[derive(Clone)]
+struct Route {
+    session_id: u32,
+    msg_id: u32,
+    entity_id: String,
+    subscription: bool,
+    view_on_delete: bool,
+    transform: Option<Transform>,
+}
+
+#[derive(Default)]
+struct ConnectionState {
+    sender: Option<Arc<dyn BackendSender>>,
+    generation: u64,
+}
+
+/// A pooled connection to a backend, multiplexing the requests of many
+/// [`ProxySession`]s by rewriting their `msg_id`s.
+pub(crate) struct BackendConnection {
+    pub(crate) backend: String,
+    connector: Arc<dyn BackendConnector>,
+    state: async_lock::Mutex<ConnectionState>,
+    msg_id_gen: AtomicU32,
+    routes: Mutex<HashMap<u32, Route>>,
+    pub(crate) sessions: Mutex<HashMap<u32, ProxyCallback>>,
+
+    /// The views each [`ProxySession`] created on the current connection.
+    views: Mutex<HashMap<u32, HashSet<String>>>,
+
+    /// The views each [`ProxySession`] created on a connection which has
+    /// since closed, which the backend has deleted.
+    dropped_views: Mutex<HashMap<u32, HashSet<String>>>,
+}
+
+impl BackendConnection {
+    fn new(backend: String, connector: Arc<dyn BackendConnector>) -> Self {
+        Self {
+            backend,
+            connector,
+            state: async_lock::Mutex::new(ConnectionState::default()),
+            msg_id_gen: AtomicU32::new(1),
+            routes: Mutex::default(),
+            sessions: Mutex::default(),
+            views: Mutex::default(),
+            dropped_views: Mutex::default(),
+        }
+    }
+
+    pub(crate) fn gen_msg_id(&self) -> u32 {
+        self.msg_id_gen.fetch_add(1, Ordering::Relaxed)
+    }
+
+    /// The upstream `msg_id` of the subscription request `msg_id` made by
+    /// session `session_id`.
+    pub(crate) fn subscription_id(&self, session_id: u32, msg_id: u32) -> Option<u32> {
+        self.routes
+            .lock()
+            .unwrap()
+            .iter()
+            .find(|(_, route)| {
+                route.subscription && route.session_id == session_id && route.msg_id == msg_id
+            })
+            .map(|(id, _)| *id)
+    }
+
+    /// The current connection's sender, connecting first if there is none.
+    async fn sender(self: &Arc<Self>) -> Result<(Arc<dyn BackendSender>, u64), ServerError> {
+        let mut state = self.state.lock().await;
+        if let Some(sender) = &state.sender {
+            return Ok((sender.clone(), state.generation));
+        }
+
+        state.generation += 1;
+        let receiver = BackendReceiver {
+            conn: Arc::downgrade(self),
+            generation: state.generation,
+        };
+
+        tracing::debug!("Connecting to backend `{}`", self.backend);
+        let sender: Arc<dyn BackendSender> = self
+            .connector
+            .connect(&self.backend, receiver)
+            .await?
+            .into();
+
+        state.sender = Some(sender.clone());
+        Ok((sender, state.generation))
+    }
+
+    pub(crate) async fn send(self: &Arc<Self>, msg: &[u8]) -> Result<(), ServerError> {
+        let (sender, generation) = self.sender().await?;
+        if let Err(err) = sender.send(msg).await {
+            self.disconnect(generation).await;
+            return Err(err);
+        }
+
+        Ok(())
+    }
+
+    async fn handle_response(&self, msg: &[u8]) -> Result<(), ServerError> {
+        let mut resp = Response::decode(msg)?;
+        if resp.msg_id == 0 {
+            return self.broadcast(msg).await;
+        }
+
+        let route = {
+            let mut routes = self.routes.lock().unwrap();
+            let route = match routes.get(&resp.msg_id) {
+                Some(route) if route.subscription => Some(route.clone()),
+                Some(_) => routes.remove(&resp.msg_id),
+                None => None,
+            };
+
+            // The subscriptions to a deleted view or table never fire again.
+            if let Some(route) = route.as_ref().filter(|_| is_deletion(&resp)) {
+                routes.retain(|_, x| !x.subscription || x.entity_id != route.entity_id);
+            }
+
+            route
+        };
+
+        let Some(route) = route else {
+            tracing::debug!("Dropping unrouted response {}", resp.msg_id);
+            return Ok(());
+        };
+
+        resp.msg_id = route.msg_id;
+        let resp = match &route.transform {
+            Some(transform) => match transform(resp) {
+                Some(resp) => resp,
+                None => return Ok(()),
+            },
+            None => resp,
+        };
+
+        let callback = self
+            .sessions
+            .lock()
+            .unwrap()
+            .get(&route.session_id)
+            .cloned();
+
+        if let Some(callback) = callback {
+            callback(&resp.encode_to_vec()).await?;
+        }
+
+        Ok(())
+    }
+
+    /// Relay a response which is not for any request, e.g. from
+    /// [`perspective_server::Server::broadcast`], to every session on this
+    /// connection.
+    async fn broadcast(&self, msg: &[u8]) -> Result<(), ServerError> {
+        let callbacks = self
+            .sessions
+            .lock()
+            .unwrap()
+            .values()
+            .cloned()
+            .collect::<Vec<_>>();
+
+        for callback in callbacks {
+            if let Err(err) = callback(msg).await {
+                tracing::error!("Failed to relay broadcast {}", err);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Send `req` from session `session_id`, routing its response(s) back to
+    /// that session through `transform`. `req`'s `msg_id`, and the
+    /// subscription it cancels (if any), are rewritten to this connection's.
+    pub(crate) async fn relay(
+        self: &Arc<Self>,
+        session_id: u32,
+        mut req: Request,
+        transform: Option<Transform>,
+    ) -> Result<(), ServerError> {
+        let msg_id = req.msg_id;
+        let mut cancels = None;
+        if let Some(id) = subscription_ref(&mut req) {
+            let upstream = self
+                .subscription_id(session_id, *id)
+                .ok_or_else(|| format!("Unknown subscription {}", id))?;
+
+            *id = upstream;
+            cancels = Some(upstream);
+        }
+
+        req.msg_id = self.gen_msg_id();
+        self.routes.lock().unwrap().insert(req.msg_id, Route {
+            session_id,
+            msg_id,
+            entity_id: req.entity_id.clone(),
+            subscription: is_subscription(&req),
+            view_on_delete: matches!(&req.client_req, Some(ClientReq::ViewOnDeleteReq(_))),
+            transform,
+        });
+
+        if let Err(err) = self.send(&req.encode_to_vec()).await {
+            self.routes.lock().unwrap().remove(&req.msg_id);
+            return Err(err);
+        }
+
+        if let Some(upstream) = cancels {
+            self.routes.lock().unwrap().remove(&upstream);
+        }
+
+        Ok(())
+    }
+
+    /// Close session `session_id`'s use of this connection, deleting
+    /// `views` (which it created) from the backend.
+    pub(crate) async fn close_session(self: &Arc<Self>, session_id: u32, views: Vec<String>) {
+        self.sessions.lock().unwrap().remove(&session_id);
+        self.views.lock().unwrap().remove(&session_id);
+        self.dropped_views.lock().unwrap().remove(&session_id);
+        self.routes
+            .lock()
+            .unwrap()
+            .retain(|_, route| route.session_id != session_id);
+
+        for view_id in views {
+            let req = Request {
+                msg_id: self.gen_msg_id(),
+                entity_id: view_id,
+                client_req: Some(ClientReq::ViewDeleteReq(proto::ViewDeleteReq {})),
+            };
+
+            if let Err(err) = self.send(&req.encode_to_vec()).await {
+                tracing::warn!("Failed to delete view {}: {}", req.entity_id, err);
+            }
+        }
+    }
+
+    /// Drop connection `generation` (if it is still current), failing every
+    /// request routed through it. The backend deletes the views created on
+    /// the connection, so their `on_delete` subscriptions fire.
+    async fn disconnect(&self, generation: u64) {
+        {
+            let mut state = self.state.lock().await;
+            if state.generation != generation || state.sender.is_none() {
+                return;
+            }
+
+            state.sender = None;
+        }
+
+        tracing::warn!("Disconnected from backend `{}`", self.backend);
+        {
+            let views = std::mem::take(&mut *self.views.lock().unwrap());
+            let mut dropped_views = self.dropped_views.lock().unwrap();
+            for (session_id, views) in views {
+                dropped_views.entry(session_id).or_default().extend(views);
+            }
+        }
+
+        let routes = std::mem::take(&mut *self.routes.lock().unwrap());
+        for route in routes.into_values() {
+            let client_resp = if route.view_on_delete {
+                ClientResp::ViewOnDeleteResp(proto::ViewOnDeleteResp {})
+            } else {
+                backend_closed_error()
+            };
+
+            let resp = Response {
+                msg_id: route.msg_id,
+                entity_id: route.entity_id,
+                client_resp: Some(client_resp),
+            };
+
+            let callback = self
+                .sessions
+                .lock()
+                .unwrap()
+                .get(&route.session_id)
+                .cloned();
+
+            if let Some(callback) = callback {
+                if let Err(err) = callback(&resp.encode_to_vec()).await {
+                    tracing::error!("Failed to send error response {}", err);
+                }
+            }
+        }
+    }
+}
+
+fn backend_closed_error() -> ClientResp {
+    ClientResp::ServerError(proto::ServerError {
+        message: "Backend connection closed".to_owned(),
+        status_code: proto::StatusCode::ServerError as i32,
+        request_id: "".to_owned(),
+    })
+}
+
+/// Whether `resp` reports that the entity it is for has been deleted.
+fn is_deletion(resp: &Response) -> bool {
+    matches!(
+        &resp.client_resp,
+        Some(
+            ClientResp::ViewDeleteResp(_)
+                | ClientResp::ViewOnDeleteResp(_)
+                | ClientResp::TableDeleteResp(_)
+                | ClientResp::TableOnDeleteResp(_)
+        )
+    )
+}
+
+/// Whether `req` subscribes to a stream of responses, rather than expecting
+/// exactly one.
+fn is_subscription(req: &Request) -> bool {
+    matches!(
+        &req.client_req,
+        Some(
+            ClientReq::ViewOnUpdateReq(_)
+                | ClientReq::ViewOnDeleteReq(_)
+                | ClientReq::TableOnDeleteReq(_)
+        )
+    ) || matches!(&req.client_req, Some(ClientReq::GetHostedTablesReq(x)) if x.subscribe)
+}
+
+/// The `msg_id` of an earlier subscription which `req` cancels.
+pub(crate) fn subscription_ref(req: &mut Request) -> Option<&mut u32> {
+    match req.client_req.as_mut()? {
+        ClientReq::ViewRemoveOnUpdateReq(x) => Some(&mut x.id),
+        ClientReq::ViewRemoveDeleteReq(x) => Some(&mut x.id),
+        ClientReq::TableRemoveDeleteReq(x) => Some(&mut x.id),
+        ClientReq::RemoveHostedTablesUpdateReq(x) => Some(&mut x.id),
+        _ => None,
+    }
+}
+
+/// Terminates [`perspective_client::Client`] sessions at an edge process, and
+/// relays them to backend [`perspective_server::Server`]s over pooled
+/// connections opened by a [`BackendConnector`].
+#[derive(Clone)]
+pub struct Proxy {
+    connector: Arc<dyn BackendConnector>,
+    router: Router,
+    config: ProxyConfig,
+    pools: Arc<Mutex<HashMap<String, Vec<Arc<BackendConnection>>>>>,
+    session_id_gen: Arc<AtomicU32>,
+}
+
+impl Proxy {
+    /// Create a [`Proxy`] which routes every tenant to the backend `""`. See
+    /// [`Proxy::with_router`].
+    pub fn new<C: BackendConnector>(connector: C) -> Self {
+        Self {
+            connector: Arc::new(connector),
+            router: Arc::new(|_| "".to_owned()),
+            config: ProxyConfig::default(),
+            pools: Arc::default(),
+            session_id_gen: Arc::default(),
+        }
+    }
+
+    /// Route each [`ProxySession`] to the backend address `router` returns
+    /// for its tenant.
+    pub fn with_router<F>(mut self, router: F) -> Self
+    where
+        F: Fn(&str) -> String + Send + Sync + 'static,
+    {
+        self.router = Arc::new(router);
+        self
+    }
+
+    pub fn with_config(mut self, config: ProxyConfig) -> Self {
+        self.config = config;
+        self
+    }
+
+    pub(crate) fn gen_session_id(&self) -> u32 {
+        self.session_id_gen.fetch_add(1, Ordering::Relaxed)
+    }
+
+    /// The number of subscriptions (e.g. `View::on_update`) relayed by this
+    /// [`Proxy`]'s connections which are still routed to a [`ProxySession`].
+    pub fn num_subscriptions(&self) -> usize {
+        self.pools
+            .lock()
+            .unwrap()
+            .values()
+            .flatten()
+            .map(|conn| {
+                conn.routes
+                    .lock()
+                    .unwrap()
+                    .values()
+                    .filter(|route| route.subscription)
+                    .count()
+            })
+            .sum()
+    }
+
+    /// The least used pooled connection to `backend`.
+    pub(crate) fn connection(&self, backend: String) -> Arc<BackendConnection> {
+        let mut pools = self.pools.lock().unwrap();
+        let pool = pools.entry(backend.clone()).or_insert_with(|| {
+            (0..self.config.connections_per_backend.max(1))
+                .map(|_| {
+                    Arc::new(BackendConnection::new(
+                        backend.clone(),
+                        self.connector.clone(),
+                    ))
+                })
+                .collect()
+        });
+
+        pool.iter()
+            .min_by_key(|conn| conn.sessions.lock().unwrap().len())
+            .unwrap()
+            .clone()
+    }
+
+    /// Create a [`ProxySession`] for one [`perspective_client::Client`] of
+    /// `tenant`, analogous to [`perspective_server::Server::new_session`].
+    pub async fn new_session<F>(&self, tenant: &str, session_handler: F) -> ProxySession
+    where
+        F: SessionHandler + 'static + Sync + Send + Clone,
+    {
+        let id = self.gen_session_id();
+        let conn = self.connection((self.router)(tenant));
+        let callback: ProxyCallback = Arc::new(move |msg| {
+            let mut session_handler = session_handler.clone();
+            Box::pin(async move { session_handler.send_response(msg).await })
+        });
+
+        conn.sessions.lock().unwrap().insert(id, callback);
+        ProxySession {
+            id,
+            conn,
+            closed: false,
+        }
+    }
+}
+
+/// The edge of a single [`perspective_client::Client`]'s connection through
+/// a [`Proxy`], analogous to [`perspective_server::Session`].
+pub struct ProxySession {
+    id: u32,
+    conn: Arc<BackendConnection>,
+    closed: bool,
+}
+
+impl std::fmt::Debug for ProxySession {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ProxySession")
+            .field("id", &self.id)
+            .field("backend", &self.conn.backend)
+            .finish()
+    }
+}
+
+impl Drop for ProxySession {
+    fn drop(&mut self) {
+        if !self.closed {
+            tracing::error!(
+                "`ProxySession` dropped without `ProxySession::close` {:?}",
+                self
+            );
+        }
+    }
+}
+
+impl ProxySession {
+    /// Relay a request message from this [`ProxySession`]'s
+    /// [`perspective_client::Client`] to its backend. Unlike
+    /// [`perspective_server::Session`], there is no need to `poll()`, as the
+    /// backend does this itself.
+    ///
+    /// Requests for views which the backend deleted when its connection
+    /// closed are answered here: `View::delete` succeeds, and anything else
+    /// fails.
+    pub async fn handle_request(&self, msg: &[u8]) -> Result<(), ServerError> {
+        let req = Request::decode(msg)?;
+        let dropped = self
+            .conn
+            .dropped_views
+            .lock()
+            .unwrap()
+            .get_mut(&self.id)
+            .and_then(|views| match &req.client_req {
+                Some(ClientReq::ViewDeleteReq(_)) => views.take(&req.entity_id),
+                _ => views.get(&req.entity_id).cloned(),
+            });
+
+        if let Some(view_id) = dropped {
+            let client_resp = match &req.client_req {
+                Some(ClientReq::ViewDeleteReq(_)) => {
+                    ClientResp::ViewDeleteResp(proto::ViewDeleteResp {})
+                },
+                _ => ClientResp::ServerError(proto::ServerError {
+                    message: format!(
+                        "View `{}` was deleted when the backend connection closed",
+                        view_id
+                    ),
+                    status_code: proto::StatusCode::ServerError as i32,
+                    request_id: "".to_owned(),
+                }),
+            };
+
+            return self
+                .respond(Response {
+                    msg_id: req.msg_id,
+                    entity_id: view_id,
+                    client_resp: Some(client_resp),
+                })
+                .await;
+        }
+
+        let created = match &req.client_req {
+            Some(ClientReq::TableMakeViewReq(x)) => Some(x.view_id.clone()),
+            Some(ClientReq::ViewDeleteReq(_)) => {
+                if let Some(views) = self.conn.views.lock().unwrap().get_mut(&self.id) {
+                    views.remove(&req.entity_id);
+                }
+
+                None
+            },
+            _ => None,
+        };
+
+        self.conn.relay(self.id, req, None).await?;
+        if let Some(view_id) = created {
+            self.conn
+                .views
+                .lock()
+                .unwrap()
+                .entry(self.id)
+                .or_default()
+                .insert(view_id);
+        }
+
+        Ok(())
+    }
+
+    /// Send `resp` to this [`ProxySession`]'s
+    /// [`perspective_client::Client`].
+    async fn respond(&self, resp: Response) -> Result<(), ServerError> {
+        let callback = self.conn.sessions.lock().unwrap().get(&self.id).cloned();
+        match callback {
+            Some(callback) => callback(&resp.encode_to_vec()).await,
+            None => Ok(()),
+        }
+    }
+
+    /// Close this [`ProxySession`], deleting the views its
+    /// [`perspective_client::Client`] created on the shared backend
+    /// connection.
+    pub async fn close(mut self) {
+        self.closed = true;
+        let views = self
+            .conn
+            .views
+            .lock()
+            .unwrap()
+            .remove(&self.id)
+            .unwrap_or_default();
+
+        self.conn
+            .close_session(self.id, views.into_iter().collect())
+            .await
+    }
+}
@@ -0,0 +1,441 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+//! Serve the views of a writer [`Server`]'s tables from read replicas, local
+//! [`Server`]s which mirror every table the writer hosts, so that ingest and
+//! view traffic scale independently.
+
+use std::collections::{HashMap, HashSet};
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::{Arc, Mutex};
+
+use futures::channel::mpsc;
+use futures::StreamExt;
+use perspective_client::proto::request::ClientReq;
+use perspective_client::proto::response::ClientResp;
+use perspective_client::proto::view_on_update_req::Mode;
+use perspective_client::proto::{self, HostedTable, Request, Response};
+use perspective_client::{Client, Table, TableInitOptions, UpdateData, UpdateOptions};
+use perspective_server::{Server, ServerError};
+use prost::Message;
+
+use crate::proxy::{BackendConnection, BackendConnector, Proxy, ProxyCallback, ProxyConfig};
+use crate::LocalClient;
+
+enum Event {
+    Response(Response),
+    Close,
+}
+
+fn unexpected(resp: Option<ClientResp>) -> ServerError {
+    match resp {
+        Some(ClientResp::ServerError(err)) => err.message.into(),
+        resp => format!("Unexpected response {:?}", resp).into(),
+    }
+}
+
+/// Mirrors every table hosted by a writer [`Server`] into a local replica
+/// [`Server`], which then serves views of them (e.g. to dashboards) without
+/// loading the writer.
+///
+/// Each table is replicated by subscribing to the row deltas of a flat view
+/// of it on the writer, and catching up from a snapshot of that view, taken
+/// in order with the deltas so that every update is applied exactly once.
+/// Tables created on (or deleted from) the writer are added to (or unhosted
+/// from) the replica as they are.
+///
+/// `writers` lists the addresses of the writer and its standbys, which must
+/// host the same tables. When the connection to one fails, the [`Replica`]
+/// fails over to the next and resyncs every table from a fresh snapshot,
+/// replacing the replica's copy atomically so views of it are never empty.
+///
+/// Rows removed on the writer (by [`Table::remove`] or
+/// [`Table::remove_where`]) are removed from an indexed table's replica by
+/// their index, and cause an un-indexed table to resync from a fresh
+/// snapshot, as its rows have no stable identity to remove by.
+/// [`Table::replace`] and [`Table::clear`] on the writer are only reflected
+/// after the next resync. A writer table can't be deleted with
+/// [`Table::delete`] while replicas have views of it; use [`Server::unhost`]
+/// instead. Replica tables must not be written to other than by their
+/// [`Replica`].
+#[derive(Clone)]
+pub struct Replica {
+    proxy: Proxy,
+    writers: Vec<String>,
+    server: Server,
+    msg_id_gen: Arc<AtomicU32>,
+    sender: mpsc::UnboundedSender<Event>,
+    receiver: Arc<Mutex<Option<mpsc::UnboundedReceiver<Event>>>>,
+}
+
+impl Replica {
+    /// Create a [`Replica`] of `writers`, backend addresses which `connector`
+    /// can connect to, into `server`.
+    pub fn new<C, I, S>(connector: C, writers: I, server: &Server) -> Self
+    where
+        C: BackendConnector,
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        let (sender, receiver) = mpsc::unbounded();
+        Self {
+            proxy: Proxy::new(connector).with_config(ProxyConfig {
+                connections_per_backend: 1,
+            }),
+            writers: writers.into_iter().map(Into::into).collect(),
+            server: server.clone(),
+            msg_id_gen: Arc::new(AtomicU32::new(1)),
+            sender,
+            receiver: Arc::new(Mutex::new(Some(receiver))),
+        }
+    }
+
+    /// Replicate the writer's tables until [`Replica::close`] is called, or
+    /// until no writer can be connected to, in which case this returns the
+    /// last connection error (and may be called again to retry).
+    pub async fn run(&self) -> Result<(), ServerError> {
+        let mut receiver = self
+            .receiver
+            .lock()
+            .unwrap()
+            .take()
+            .ok_or("`Replica::run` is already running")?;
+
+        let client = LocalClient::new(&self.server);
+        let result = self.replicate(&client, &mut receiver).await;
+        client.close().await;
+        *self.receiver.lock().unwrap() = Some(receiver);
+        result
+    }
+
+    /// Stop a running [`Replica::run`]. The replica's tables stay hosted on
+    /// its [`Server`].
+    pub fn close(&self) {
+        let _ = self.sender.unbounded_send(Event::Close);
+    }
+
+    fn gen_msg_id(&self) -> u32 {
+        self.msg_id_gen.fetch_add(1, Ordering::Relaxed)
+    }
+
+    async fn replicate(
+        &self,
+        client: &Client,
+        receiver: &mut mpsc::UnboundedReceiver<Event>,
+    ) -> Result<(), ServerError> {
+        let mut tables: HashMap<String, Table> = HashMap::new();
+        let mut writer = 0;
+        let mut failures = 0;
+        loop {
+            let address = self.writers.get(writer).ok_or("Replica has no writers")?;
+            let mut stream = WriterStream::new(self, address);
+            if let Err(err) = stream.subscribe().await {
+                tracing::warn!("Failed to connect to writer `{}`: {}", address, err);
+                stream.close().await;
+                failures += 1;
+                if failures >= self.writers.len() {
+                    return Err(err);
+                }
+
+                writer = (writer + 1) % self.writers.len();
+                continue;
+            }
+
+            failures = 0;
+            let result = loop {
+                match receiver.next().await {
+                    Some(Event::Response(resp)) => {
+                        if let Err(err) = stream.handle(resp, client, &mut tables).await {
+                            break Err(err);
+                        }
+                    },
+                    Some(Event::Close) | None => break Ok(()),
+                }
+            };
+
+            stream.close().await;
+            match result {
+                Ok(()) => return Ok(()),
+                Err(err) => {
+                    tracing::warn!("Replication from `{}` failed: {}", address, err);
+                    writer = (writer + 1) % self.writers.len();
+                },
+            }
+        }
+    }
+}
+
+/// The replication state of one table on the current writer.
+struct TableSync {
+    info: HostedTable,
+    view_id: String,
+    synced: bool,
+}
+
+/// The subscriptions of one connection to a writer. Responses arrive (and
+/// are handled) in the order the writer sent them.
+struct WriterStream<'a> {
+    replica: &'a Replica,
+    session_id: u32,
+    conn: Arc<BackendConnection>,
+    hosted_tables_id: u32,
+    listing_id: Option<u32>,
+    syncs: HashMap<String, TableSync>,
+    ids: HashMap<u32, String>,
+}
+
+impl<'a> WriterStream<'a> {
+    fn new(replica: &'a Replica, address: &str) -> Self {
+        let session_id = replica.proxy.gen_session_id();
+        let conn = replica.proxy.connection(address.to_owned());
+        let sender = replica.sender.clone();
+        let callback: ProxyCallback = Arc::new(move |msg| {
+            let sender = sender.clone();
+            Box::pin(async move {
+                let _ = sender.unbounded_send(Event::Response(Response::decode(msg)?));
+                Ok(())
+            })
+        });
+
+        conn.sessions.lock().unwrap().insert(session_id, callback);
+        Self {
+            replica,
+            session_id,
+            conn,
+            hosted_tables_id: 0,
+            listing_id: None,
+            syncs: HashMap::default(),
+            ids: HashMap::default(),
+        }
+    }
+
+    async fn send(&self, entity_id: &str, req: ClientReq) -> Result<u32, ServerError> {
+        let msg_id = self.replica.gen_msg_id();
+        let req = Request {
+            msg_id,
+            entity_id: entity_id.to_owned(),
+            client_req: Some(req),
+        };
+
+        self.conn.relay(self.session_id, req, None).await?;
+        Ok(msg_id)
+    }
+
+    async fn subscribe(&mut self) -> Result<(), ServerError> {
+        let req = proto::GetHostedTablesReq { subscribe: true };
+        self.hosted_tables_id = self.send("", ClientReq::GetHostedTablesReq(req)).await?;
+        self.list().await
+    }
+
+    async fn list(&mut self) -> Result<(), ServerError> {
+        let req = proto::GetHostedTablesReq { subscribe: false };
+        self.listing_id = Some(self.send("", ClientReq::GetHostedTablesReq(req)).await?);
+        Ok(())
+    }
+
+    /// Subscribe to a table's row deltas, then snapshot it.
+    async fn sync(&mut self, info: HostedTable) -> Result<(), ServerError> {
+        let name = info.entity_id.clone();
+        let view_id = format!(
+            "__replica_{}_{}",
+            self.session_id,
+            self.replica.gen_msg_id()
+        );
+        let make_view = proto::TableMakeViewReq {
+            view_id: view_id.clone(),
+            config: None,
+        };
+
+        let on_update = proto::ViewOnUpdateReq {
+            mode: Some(Mode::Row as i32),
+            viewport: None,
+        };
+
+        let snapshot = proto::ViewToArrowReq {
+            viewport: None,
+            compression: None,
+        };
+
+        self.syncs.insert(name.clone(), TableSync {
+            info,
+            view_id: view_id.clone(),
+            synced: false,
+        });
+
+        for (entity_id, req) in [
+            (&name, ClientReq::TableMakeViewReq(make_view)),
+            (&view_id, ClientReq::ViewOnUpdateReq(on_update)),
+            (&view_id, ClientReq::ViewToArrowReq(snapshot)),
+        ] {
+            let msg_id = self.send(entity_id, req).await?;
+            self.ids.insert(msg_id, name.clone());
+        }
+
+        Ok(())
+    }
+
+    /// Snapshot `name` again, replacing the replica's copy, for changes its
+    /// row deltas can't express. Deltas which arrive before the snapshot are
+    /// included in it, so they are skipped.
+    async fn resync(&mut self, name: &str) -> Result<(), ServerError> {
+        let Some(sync) = self.syncs.get_mut(name) else {
+            return Ok(());
+        };
+
+        sync.synced = false;
+        let view_id = sync.view_id.clone();
+        let snapshot = proto::ViewToArrowReq {
+            viewport: None,
+            compression: None,
+        };
+
+        let msg_id = self
+            .send(&view_id, ClientReq::ViewToArrowReq(snapshot))
+            .await?;
+
+        self.ids.insert(msg_id, name.to_owned());
+        Ok(())
+    }
+
+    /// Stop replicating `name` from this writer.
+    async fn unsync(&mut self, name: &str) -> Result<(), ServerError> {
+        self.ids.retain(|_, table| table != name);
+        if let Some(sync) = self.syncs.remove(name) {
+            let req = ClientReq::ViewDeleteReq(proto::ViewDeleteReq {});
+            self.send(&sync.view_id, req).await?;
+        }
+
+        Ok(())
+    }
+
+    async fn handle(
+        &mut self,
+        resp: Response,
+        client: &Client,
+        tables: &mut HashMap<String, Table>,
+    ) -> Result<(), ServerError> {
+        if resp.msg_id == self.hosted_tables_id {
+            return match resp.client_resp {
+                Some(ClientResp::GetHostedTablesResp(_)) => self.list().await,
+                resp => Err(unexpected(resp)),
+            };
+        }
+
+        if Some(resp.msg_id) == self.listing_id {
+            self.listing_id = None;
+            let infos = match resp.client_resp {
+                Some(ClientResp::GetHostedTablesResp(x)) => x.table_infos,
+                resp => return Err(unexpected(resp)),
+            };
+
+            let names: HashSet<&String> = infos.iter().map(|info| &info.entity_id).collect();
+            let removed: HashSet<String> = tables
+                .keys()
+                .chain(self.syncs.keys())
+                .filter(|name| !names.contains(name))
+                .cloned()
+                .collect();
+
+            for name in removed {
+                self.unsync(&name).await?;
+                if tables.remove(&name).is_some() {
+                    self.replica.server.unhost(&name).await?;
+                }
+            }
+
+            for info in infos {
+                if !self.syncs.contains_key(&info.entity_id) {
+                    self.sync(info).await?;
+                }
+            }
+
+            return Ok(());
+        }
+
+        let Some(name) = self.ids.get(&resp.msg_id).cloned() else {
+            return Ok(());
+        };
+
+        match resp.client_resp {
+            Some(ClientResp::ViewOnUpdateResp(x)) => {
+                let index = match self.syncs.get(&name) {
+                    Some(sync) if sync.synced => sync.info.index.clone(),
+                    _ => return Ok(()),
+                };
+
+                let Some(table) = tables.get(&name) else {
+                    return Ok(());
+                };
+
+                if let Some(delta) = x.delta {
+                    table
+                        .update(UpdateData::Arrow(delta.into()), UpdateOptions::default())
+                        .await?;
+                }
+
+                if x.removed.is_empty() {
+                    return Ok(());
+                }
+
+                let Some(index) = index else {
+                    return self.resync(&name).await;
+                };
+
+                let removed = x
+                    .removed
+                    .iter()
+                    .map(|x| serde_json::from_str(x))
+                    .collect::<Result<Vec<serde_json::Value>, _>>()?;
+
+                let mut columns = serde_json::Map::new();
+                columns.insert(index, removed.into());
+                let data = serde_json::Value::Object(columns).to_string();
+                table.remove(UpdateData::JsonColumns(data)).await?;
+            },
+            Some(ClientResp::ViewToArrowResp(x)) => {
+                self.ids.remove(&resp.msg_id);
+                let Some(sync) = self.syncs.get_mut(&name) else {
+                    return Ok(());
+                };
+
+                let data = UpdateData::Arrow(x.arrow.into());
+                match tables.get(&name) {
+                    Some(table) => table.replace_atomic(data).await?,
+                    None => {
+                        let mut options = TableInitOptions::default();
+                        options.set_name(&name);
+                        options.index = sync.info.index.clone();
+                        options.limit = sync.info.limit;
+                        let table = client.table(data.into(), options).await?;
+                        tables.insert(name.clone(), table);
+                    },
+                }
+
+                sync.synced = true;
+            },
+            Some(ClientResp::ServerError(err)) => {
+                tracing::warn!("Failed to replicate `{}`: {}", name, err.message);
+                self.unsync(&name).await?;
+            },
+            _ => {
+                self.ids.remove(&resp.msg_id);
+            },
+        }
+
+        Ok(())
+    }
+
+    async fn close(self) {
+        let views = self.syncs.into_values().map(|sync| sync.view_id).collect();
+        self.conn.close_session(self.session_id, views).await
+    }
+}
@@ -0,0 +1,471 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+//! Run saved view configs on a cron-like [`Schedule`], and deliver each
+//! result to an [`ExportSink`], e.g. a [`FileSink`] directory or a
+//! [`CallbackSink`] which posts it to object storage or a webhook.
+
+use std::collections::HashMap;
+use std::future::Future;
+use std::path::PathBuf;
+use std::str::FromStr;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use futures::channel::mpsc;
+use futures::future::{select, BoxFuture, Either};
+use futures::StreamExt;
+use perspective_client::config::ViewConfigUpdate;
+use perspective_client::ViewWindow;
+use perspective_server::{Server, ServerError};
+
+use crate::LocalClient;
+
+/// The longest [`Scheduler::run`] sleeps before checking for new jobs.
+const MAX_SLEEP: Duration = Duration::from_secs(60);
+
+/// The year, month and day of the `days`-th day since the Unix epoch.
+fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let days = days + 719468;
+    let era = days.div_euclid(146097);
+    let doe = days.rem_euclid(146097);
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = (doy - (153 * mp + 2) / 5 + 1) as u32;
+    let month = (if mp < 10 { mp + 3 } else { mp - 9 }) as u32;
+    let year = yoe + era * 400 + if month <= 2 { 1 } else { 0 };
+    (year, month, day)
+}
+
+/// Parse one field of a cron expression (e.g. `*/15`, `1-5` or `0,30`) into
+/// a bitmask of the values in `min..=max` it matches.
+fn parse_field(field: &str, min: u32, max: u32) -> Result<u64, ServerError> {
+    let mut bits = 0;
+    for part in field.split(',') {
+        let (range, step) = match part.split_once('/') {
+            Some((range, step)) => (range, step.parse::<u32>()?),
+            None => (part, 1),
+        };
+
+        let (lo, hi) = match range.split_once('-') {
+            _ if range == "*" => (min, max),
+            Some((lo, hi)) => (lo.parse()?, hi.parse()?),
+            None if step > 1 => (range.parse()?, max),
+            None => {
+                let value = range.parse()?;
+                (value, value)
+            },
+        };
+
+        if step == 0 || lo < min || hi > max || lo > hi {
+            return Err(format!("`{}` is not in {}-{}", part, min, max).into());
+        }
+
+        for value in (lo..=hi).step_by(step as usize) {
+            bits |= 1 << value;
+        }
+    }
+
+    Ok(bits)
+}
+
+/// A cron-like schedule in UTC, parsed from the usual five fields `minute
+/// hour day-of-month month day-of-week` (where day-of-week `0` and `7` are
+/// Sunday), or one of `@hourly`, `@daily`, `@weekly`, `@monthly` or
+/// `@yearly`. As in cron, when both day fields are restricted, a day which
+/// matches either is scheduled.
+///
+/// # Examples
+///
+/// ```rust
+/// # use perspective::schedule::Schedule;
+/// let weekday_mornings: Schedule = "30 6 * * 1-5".parse().unwrap();
+/// let quarter_hourly: Schedule = "*/15 * * * *".parse().unwrap();
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Schedule {
+    minutes: u64,
+    hours: u64,
+    days: u64,
+    months: u64,
+    weekdays: u64,
+    any_day: bool,
+    any_weekday: bool,
+}
+
+impl FromStr for Schedule {
+    type Err = ServerError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let expr = match s.trim() {
+            "@hourly" => "0 * * * *",
+            "@daily" | "@midnight" => "0 0 * * *",
+            "@weekly" => "0 0 * * 0",
+            "@monthly" => "0 0 1 * *",
+            "@yearly" | "@annually" => "0 0 1 1 *",
+            expr => expr,
+        };
+
+        let fields: Vec<&str> = expr.split_whitespace().collect();
+        let [minute, hour, day, month, weekday] = fields[..] else {
+            return Err(format!("Expected 5 fields in schedule `{}`", s).into());
+        };
+
+        let weekdays = parse_field(weekday, 0, 7)?;
+        Ok(Self {
+            minutes: parse_field(minute, 0, 59)?,
+            hours: parse_field(hour, 0, 23)?,
+            days: parse_field(day, 1, 31)?,
+            months: parse_field(month, 1, 12)?,
+            weekdays: (weekdays | (weekdays >> 7)) & 0x7f,
+            any_day: day.starts_with('*'),
+            any_weekday: weekday.starts_with('*'),
+        })
+    }
+}
+
+impl Schedule {
+    fn matches_day(&self, month: u32, day: u32, weekday: u32) -> bool {
+        let day_matches = self.days & (1 << day) != 0;
+        let weekday_matches = self.weekdays & (1 << weekday) != 0;
+        self.months & (1 << month) != 0
+            && if self.any_day || self.any_weekday {
+                day_matches && weekday_matches
+            } else {
+                day_matches || weekday_matches
+            }
+    }
+
+    /// The first scheduled minute after `time`, or `None` if there is none
+    /// (e.g. `0 0 31 2 *`).
+    pub fn next_after(&self, time: SystemTime) -> Option<SystemTime> {
+        let start = time.duration_since(UNIX_EPOCH).ok()?.as_secs() / 60 + 1;
+        let mut day = (start / 1440) as i64;
+        let mut minute = (start % 1440) as u32;
+
+        // February 29th recurs at least once every 8 years.
+        for _ in 0..366 * 8 {
+            let (_, month, day_of_month) = civil_from_days(day);
+            let weekday = (day + 4).rem_euclid(7) as u32;
+            if self.matches_day(month, day_of_month, weekday) {
+                let next = (minute..1440).find(|minute| {
+                    self.hours & (1 << (minute / 60)) != 0
+                        && self.minutes & (1 << (minute % 60)) != 0
+                });
+
+                if let Some(next) = next {
+                    let secs = (day as u64 * 1440 + next as u64) * 60;
+                    return Some(UNIX_EPOCH + Duration::from_secs(secs));
+                }
+            }
+
+            day += 1;
+            minute = 0;
+        }
+
+        None
+    }
+}
+
+/// The serialization of an [`Export`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ExportFormat {
+    #[default]
+    Csv,
+    Arrow,
+    Json,
+}
+
+impl ExportFormat {
+    pub fn extension(&self) -> &'static str {
+        match self {
+            ExportFormat::Csv => "csv",
+            ExportFormat::Arrow => "arrow",
+            ExportFormat::Json => "json",
+        }
+    }
+}
+
+/// One run of an [`ExportJob`].
+#[derive(Clone, Debug)]
+pub struct Export {
+    pub job: String,
+    pub time: SystemTime,
+    pub format: ExportFormat,
+    pub data: Vec<u8>,
+}
+
+impl Export {
+    /// A file name for this [`Export`], unique per job and second, e.g.
+    /// `daily_pnl-20240108T063000Z.csv`.
+    pub fn file_name(&self) -> String {
+        let secs = self
+            .time
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs();
+
+        let (year, month, day) = civil_from_days((secs / 86400) as i64);
+        let secs = secs % 86400;
+        format!(
+            "{}-{:04}{:02}{:02}T{:02}{:02}{:02}Z.{}",
+            self.job,
+            year,
+            month,
+            day,
+            secs / 3600,
+            secs / 60 % 60,
+            secs % 60,
+            self.format.extension()
+        )
+    }
+}
+
+/// Where an [`ExportJob`] delivers its [`Export`]s.
+pub trait ExportSink: Send + Sync + 'static {
+    fn deliver<'a>(&'a self, export: &'a Export) -> BoxFuture<'a, Result<(), ServerError>>;
+}
+
+/// An [`ExportSink`] which writes each [`Export`] to a directory, named by
+/// [`Export::file_name`].
+#[derive(Clone, Debug)]
+pub struct FileSink {
+    dir: PathBuf,
+}
+
+impl FileSink {
+    pub fn new<P: Into<PathBuf>>(dir: P) -> Self {
+        Self { dir: dir.into() }
+    }
+}
+
+impl ExportSink for FileSink {
+    fn deliver<'a>(&'a self, export: &'a Export) -> BoxFuture<'a, Result<(), ServerError>> {
+        Box::pin(async move {
+            std::fs::create_dir_all(&self.dir)?;
+            std::fs::write(self.dir.join(export.file_name()), &export.data)?;
+            Ok(())
+        })
+    }
+}
+
+/// An [`ExportSink`] which passes each [`Export`] to a callback, e.g. to
+/// upload it to object storage or post it to a webhook.
+pub struct CallbackSink<F>(pub F);
+
+impl<F, U> ExportSink for CallbackSink<F>
+where
+    F: Fn(Export) -> U + Send + Sync + 'static,
+    U: Future<Output = Result<(), ServerError>> + Send + 'static,
+{
+    fn deliver<'a>(&'a self, export: &'a Export) -> BoxFuture<'a, Result<(), ServerError>> {
+        Box::pin((self.0)(export.clone()))
+    }
+}
+
+/// A saved view config of a table, run on a [`Schedule`] by a [`Scheduler`].
+#[derive(Clone)]
+pub struct ExportJob {
+    pub name: String,
+    pub table: String,
+    pub config: ViewConfigUpdate,
+    pub format: ExportFormat,
+    pub schedule: Schedule,
+    pub sink: Arc<dyn ExportSink>,
+}
+
+impl ExportJob {
+    /// Create an [`ExportJob`] which exports CSV, see
+    /// [`ExportJob::with_format`].
+    pub fn new<S: ExportSink>(
+        name: &str,
+        table: &str,
+        config: ViewConfigUpdate,
+        schedule: Schedule,
+        sink: S,
+    ) -> Self {
+        Self {
+            name: name.to_owned(),
+            table: table.to_owned(),
+            config,
+            format: ExportFormat::default(),
+            schedule,
+            sink: Arc::new(sink),
+        }
+    }
+
+    pub fn with_format(mut self, format: ExportFormat) -> Self {
+        self.format = format;
+        self
+    }
+
+    /// Run this [`ExportJob`] against `server` once.
+    async fn export(&self, server: &Server, time: SystemTime) -> Result<Export, ServerError> {
+        let client = LocalClient::new(server);
+        let result = async {
+            let table = client.open_table(self.table.clone()).await?;
+            let view = table.view(Some(self.config.clone())).await?;
+            let window = ViewWindow::default();
+            let data = match self.format {
+                ExportFormat::Csv => view.to_csv(window).await.map(String::into_bytes),
+                ExportFormat::Arrow => view.to_arrow(window).await.map(|x| x.to_vec()),
+                ExportFormat::Json => view.to_json_string(window).await.map(String::into_bytes),
+            };
+
+            view.delete().await?;
+            Ok::<_, ServerError>(data?)
+        }
+        .await;
+
+        client.close().await;
+        Ok(Export {
+            job: self.name.clone(),
+            time,
+            format: self.format,
+            data: result?,
+        })
+    }
+}
+
+struct ScheduledJob {
+    job: Arc<ExportJob>,
+    next: Option<SystemTime>,
+}
+
+/// Runs [`ExportJob`]s against a [`Server`] when they are due.
+#[derive(Clone)]
+pub struct Scheduler {
+    server: Server,
+    jobs: Arc<Mutex<HashMap<String, ScheduledJob>>>,
+    sender: mpsc::UnboundedSender<()>,
+    receiver: Arc<Mutex<Option<mpsc::UnboundedReceiver<()>>>>,
+}
+
+impl Scheduler {
+    pub fn new(server: &Server) -> Self {
+        let (sender, receiver) = mpsc::unbounded();
+        Self {
+            server: server.clone(),
+            jobs: Arc::default(),
+            sender,
+            receiver: Arc::new(Mutex::new(Some(receiver))),
+        }
+    }
+
+    /// Schedule `job`, replacing any job of the same name. It first runs at
+    /// the next time its [`Schedule`] matches after now.
+    pub fn add(&self, job: ExportJob) {
+        let next = job.schedule.next_after(SystemTime::now());
+        self.jobs
+            .lock()
+            .unwrap()
+            .insert(job.name.clone(), ScheduledJob {
+                job: Arc::new(job),
+                next,
+            });
+    }
+
+    /// Unschedule the job `name`, returning whether it existed.
+    pub fn remove(&self, name: &str) -> bool {
+        self.jobs.lock().unwrap().remove(name).is_some()
+    }
+
+    /// The time the next job is due.
+    pub fn next_run(&self) -> Option<SystemTime> {
+        self.jobs
+            .lock()
+            .unwrap()
+            .values()
+            .filter_map(|x| x.next)
+            .min()
+    }
+
+    /// Run the job `name` now, regardless of its [`Schedule`].
+    pub async fn run_job(&self, name: &str) -> Result<(), ServerError> {
+        let job = self
+            .jobs
+            .lock()
+            .unwrap()
+            .get(name)
+            .map(|x| x.job.clone())
+            .ok_or_else(|| format!("Unknown job `{}`", name))?;
+
+        let export = job.export(&self.server, SystemTime::now()).await?;
+        job.sink.deliver(&export).await
+    }
+
+    /// Run every job which was due at or before `now` (once, however many
+    /// runs it missed), returning each job's name and result.
+    pub async fn run_due(&self, now: SystemTime) -> Vec<(String, Result<(), ServerError>)> {
+        let due: Vec<Arc<ExportJob>> = self
+            .jobs
+            .lock()
+            .unwrap()
+            .values_mut()
+            .filter(|x| x.next.is_some_and(|next| next <= now))
+            .map(|x| {
+                x.next = x.job.schedule.next_after(now);
+                x.job.clone()
+            })
+            .collect();
+
+        let mut results = vec![];
+        for job in due {
+            let result = match job.export(&self.server, now).await {
+                Ok(export) => job.sink.deliver(&export).await,
+                Err(err) => Err(err),
+            };
+
+            results.push((job.name.clone(), result));
+        }
+
+        results
+    }
+
+    /// Run jobs as they come due until [`Scheduler::close`] is called.
+    /// `sleep` is the async runtime's timer, e.g. `tokio::time::sleep`.
+    pub async fn run<F, U>(&self, sleep: F)
+    where
+        F: Fn(Duration) -> U,
+        U: Future<Output = ()>,
+    {
+        let Some(mut receiver) = self.receiver.lock().unwrap().take() else {
+            tracing::error!("`Scheduler::run` is already running");
+            return;
+        };
+
+        loop {
+            for (job, result) in self.run_due(SystemTime::now()).await {
+                if let Err(err) = result {
+                    tracing::error!("Export `{}` failed: {}", job, err);
+                }
+            }
+
+            let wait = self
+                .next_run()
+                .and_then(|next| next.duration_since(SystemTime::now()).ok())
+                .map_or(MAX_SLEEP, |wait| wait.min(MAX_SLEEP));
+
+            if let Either::Right(_) = select(Box::pin(sleep(wait)), receiver.next()).await {
+                break;
+            }
+        }
+
+        *self.receiver.lock().unwrap() = Some(receiver);
+    }
+
+    /// Stop a running [`Scheduler::run`].
+    pub fn close(&self) {
+        let _ = self.sender.unbounded_send(());
+    }
+}
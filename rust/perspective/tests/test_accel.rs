@@ -0,0 +1,133 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use std::error::Error;
+
+use perspective::accel::{group_by, GroupRow, SEGMENT_ROWS};
+use perspective::client::config::SingleAggregate;
+use perspective::client::{TableInitOptions, UpdateData};
+use perspective::server::{AggregationBackend, Server};
+use perspective::LocalClient;
+
+/// A server hosting `trades`, whose groups span several segments. Every
+/// value is a multiple of 0.25 and every sum is below 2^22, so the single
+/// precision reduction is exact.
+async fn trades_server(backend: AggregationBackend) -> Result<Server, Box<dyn Error>> {
+    let server = Server::builder()
+        .with_aggregation_backend(backend)
+        .build()?;
+
+    let client = LocalClient::new(&server);
+    let rows = (0..3 * SEGMENT_ROWS + 17)
+        .map(|i| {
+            let desk = ["A", "B"][i % 2];
+            let price = ((i % 100) + 1) as f64 * 0.25;
+            format!(
+                r#"{{"desk":"{}","price":{},"qty":{}}}"#,
+                desk,
+                price,
+                i % 13
+            )
+        })
+        .collect::<Vec<_>>();
+
+    client
+        .table(
+            UpdateData::JsonRows(format!("[{}]", rows.join(","))).into(),
+            TableInitOptions {
+                name: Some("trades".to_owned()),
+                ..TableInitOptions::default()
+            },
+        )
+        .await?;
+
+    client.close().await;
+    Ok(server)
+}
+
+fn aggregates() -> Vec<(String, SingleAggregate)> {
+    vec![
+        ("price".to_owned(), SingleAggregate::Sum),
+        ("qty".to_owned(), SingleAggregate::Mean),
+    ]
+}
+
+#[tokio::test]
+async fn test_gpu_group_by_matches_engine() -> Result<(), Box<dyn Error>> {
+    let engine = trades_server(AggregationBackend::Engine).await?;
+    let gpu = trades_server(AggregationBackend::Gpu).await?;
+    for aggregate in [
+        SingleAggregate::Sum,
+        SingleAggregate::Count,
+        SingleAggregate::Mean,
+        SingleAggregate::Min,
+        SingleAggregate::Max,
+    ] {
+        let aggregates = [
+            ("price".to_owned(), aggregate),
+            ("qty".to_owned(), SingleAggregate::Sum),
+        ];
+
+        let expected = group_by(&engine, "trades", "desk", &aggregates).await?;
+        assert_eq!(expected.len(), 2);
+        assert_eq!(
+            group_by(&gpu, "trades", "desk", &aggregates).await?,
+            expected
+        );
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_gpu_group_by_numeric_key() -> Result<(), Box<dyn Error>> {
+    let server = trades_server(AggregationBackend::Gpu).await?;
+    let result = group_by(&server, "trades", "qty", &[(
+        "qty".to_owned(),
+        SingleAggregate::Max,
+    )])
+    .await?;
+
+    assert_eq!(result.len(), 13);
+    assert_eq!(result[0], GroupRow {
+        key: 0.into(),
+        values: vec![Some(0.0)],
+    });
+
+    assert_eq!(result[12], GroupRow {
+        key: 12.into(),
+        values: vec![Some(12.0)],
+    });
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_gpu_group_by_rejects_unsupported_aggregates() -> Result<(), Box<dyn Error>> {
+    let server = trades_server(AggregationBackend::Gpu).await?;
+    let unsupported = [("price".to_owned(), SingleAggregate::Median)];
+    assert!(group_by(&server, "trades", "desk", &unsupported)
+        .await
+        .is_err());
+
+    let mut duplicated = aggregates();
+    duplicated.push(("price".to_owned(), SingleAggregate::Max));
+    assert!(group_by(&server, "trades", "desk", &duplicated)
+        .await
+        .is_err());
+
+    assert!(group_by(&server, "trades", "desk", &aggregates())
+        .await
+        .is_ok());
+
+    Ok(())
+}
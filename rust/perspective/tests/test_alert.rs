@@ -0,0 +1,94 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use std::error::Error;
+use std::sync::{Arc, Mutex};
+
+use perspective::client::{TableInitOptions, UpdateData, UpdateOptions};
+use perspective::server::Server;
+use perspective::{on_alert, Alert, AlertRule, Comparison, Condition, LocalClient};
+
+#[test]
+fn test_condition_parses() -> Result<(), Box<dyn Error>> {
+    let condition: Condition = "sum(pnl) < -1e6".parse()?;
+    assert_eq!(condition.aggregate, "sum".parse()?);
+    assert_eq!(condition.column, "pnl");
+    assert_eq!(condition.comparison, Comparison::Lt);
+    assert_eq!(condition.threshold, -1e6);
+
+    let condition: Condition = r#"avg("Unit Price") >= 100"#.parse()?;
+    assert_eq!(condition.column, "Unit Price");
+    assert_eq!(condition.comparison, Comparison::Ge);
+    assert!("sum(pnl) ~ 1".parse::<Condition>().is_err());
+    assert!("pnl < 1".parse::<Condition>().is_err());
+    assert!("bogus(pnl) < 1".parse::<Condition>().is_err());
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_alert_fires_when_groups_begin_to_match() -> Result<(), Box<dyn Error>> {
+    let server = Server::default();
+    let client = LocalClient::new(&server);
+    let mut options = TableInitOptions::default();
+    options.set_name("trades");
+    let data = UpdateData::Csv("desk,pnl\na,10\nb,-150".to_owned());
+    let table = client.table(data.into(), options).await?;
+    let alerts: Arc<Mutex<Vec<Alert>>> = Arc::default();
+    let rule = AlertRule::new(
+        "drawdown",
+        "trades",
+        vec!["desk".to_owned()],
+        "sum(pnl) < -100".parse()?,
+    );
+
+    let hook = on_alert(&server, rule, {
+        let alerts = alerts.clone();
+        move |alert| {
+            alerts.lock().unwrap().push(alert);
+            async {}
+        }
+    })
+    .await?;
+
+    let desks = |alerts: &[Alert]| -> Vec<Vec<String>> {
+        alerts
+            .iter()
+            .map(|alert| {
+                alert
+                    .rows
+                    .iter()
+                    .map(|row| row["__ROW_PATH__"][0].as_str().unwrap().to_owned())
+                    .collect()
+            })
+            .collect()
+    };
+
+    assert_eq!(desks(&alerts.lock().unwrap()), vec![vec!["b"]]);
+    for (update, expected) in [
+        // `a` begins to match.
+        ("desk,pnl\na,-200", vec![vec!["b"], vec!["a"]]),
+        // `b` is still matching, so does not alert again.
+        ("desk,pnl\nb,-1", vec![vec!["b"], vec!["a"]]),
+        // `b` stops matching, then matches again.
+        ("desk,pnl\nb,200", vec![vec!["b"], vec!["a"]]),
+        ("desk,pnl\nb,-200", vec![vec!["b"], vec!["a"], vec!["b"]]),
+    ] {
+        let data = UpdateData::Csv(update.to_owned());
+        table.update(data, UpdateOptions::default()).await?;
+        assert_eq!(desks(&alerts.lock().unwrap()), expected, "{}", update);
+    }
+
+    assert_eq!(alerts.lock().unwrap()[1].rows[0]["pnl"], -190);
+    hook.remove().await?;
+    client.close().await;
+    Ok(())
+}
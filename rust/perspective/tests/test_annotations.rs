@@ -0,0 +1,116 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use std::error::Error;
+
+use perspective::server::Server;
+use perspective::LocalClient;
+use perspective_client::config::{Sort, SortDir, ViewConfigUpdate};
+use perspective_client::{Table, TableInitOptions, UpdateData, ViewWindow};
+
+async fn rates_table(client: &LocalClient) -> Result<Table, Box<dyn Error>> {
+    Ok(client
+        .table(
+            UpdateData::Csv("id,region,rate\nEUR,eu,1.08\nGBP,eu,1.27\nJPY,asia,0.0067".to_owned())
+                .into(),
+            TableInitOptions {
+                index: Some("id".to_owned()),
+                ..TableInitOptions::default()
+            },
+        )
+        .await?)
+}
+
+#[tokio::test]
+async fn test_annotations_are_shared_and_removable() -> Result<(), Box<dyn Error>> {
+    let server = Server::default();
+    let alice = LocalClient::new(&server);
+    let table = rates_table(&alice).await?;
+    table
+        .annotate(
+            r#""GBP""#.to_owned(),
+            "rate".to_owned(),
+            Some("Stale fixing".to_owned()),
+            Some("alice".to_owned()),
+        )
+        .await?;
+
+    let bob = LocalClient::new(&server);
+    let shared = bob.open_table(table.get_name().to_owned()).await?;
+    let annotations = shared.annotations().await?;
+    assert_eq!(annotations.len(), 1);
+    assert_eq!(annotations[0].index, r#""GBP""#);
+    assert_eq!(annotations[0].column, "rate");
+    assert_eq!(annotations[0].text, "Stale fixing");
+    assert_eq!(annotations[0].user.as_deref(), Some("alice"));
+
+    shared
+        .annotate(r#""GBP""#.to_owned(), "rate".to_owned(), None, None)
+        .await?;
+
+    assert!(table.annotations().await?.is_empty());
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_annotate_rejects_missing_rows() -> Result<(), Box<dyn Error>> {
+    let server = Server::default();
+    let client = LocalClient::new(&server);
+    let table = rates_table(&client).await?;
+    let result = table
+        .annotate(
+            r#""CHF""#.to_owned(),
+            "rate".to_owned(),
+            Some("?".to_owned()),
+            None,
+        )
+        .await;
+
+    assert!(result.is_err());
+    assert!(table.annotations().await?.is_empty());
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_view_annotations_follow_view_rows() -> Result<(), Box<dyn Error>> {
+    let server = Server::default();
+    let client = LocalClient::new(&server);
+    let table = rates_table(&client).await?;
+    for (index, column) in [(r#""JPY""#, "rate"), (r#""EUR""#, "region")] {
+        table
+            .annotate(
+                index.to_owned(),
+                column.to_owned(),
+                Some("Check".to_owned()),
+                None,
+            )
+            .await?;
+    }
+
+    let view = table
+        .view(Some(ViewConfigUpdate {
+            columns: Some(vec![Some("rate".to_owned())]),
+            sort: Some(vec![Sort("rate".to_owned(), SortDir::Desc)]),
+            ..ViewConfigUpdate::default()
+        }))
+        .await?;
+
+    let annotations = view.annotations(ViewWindow::default()).await?;
+    assert_eq!(annotations.len(), 1);
+    assert_eq!(annotations[0].row, 2);
+    assert_eq!(
+        annotations[0].annotation.as_ref().map(|x| x.index.as_str()),
+        Some(r#""JPY""#)
+    );
+
+    Ok(())
+}
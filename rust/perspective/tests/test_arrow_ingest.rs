@@ -0,0 +1,122 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use std::error::Error;
+
+use perspective::LocalClient;
+use perspective_client::{
+    ColumnType, TableData, TableInitOptions, UpdateData, UpdateOptions, ViewWindow,
+};
+
+const ROWS: &str = r#"[
+    {"x": 1, "side": "buy"},
+    {"x": 2, "side": "sell"},
+    {"x": 3, "side": "buy"}
+]"#;
+
+#[tokio::test]
+async fn test_arrow_ingest_decodes_arbitrary_chunks() -> Result<(), Box<dyn Error>> {
+    let server = perspective::server::Server::default();
+    let client = LocalClient::new(&server);
+    let source = client
+        .table(
+            UpdateData::JsonRows(ROWS.to_owned()).into(),
+            TableInitOptions::default(),
+        )
+        .await?;
+
+    let source_view = source.view(None).await?;
+    let arrow = source_view.to_arrow(ViewWindow::default()).await?;
+    let schema = vec![
+        ("x".to_owned(), ColumnType::Integer),
+        ("side".to_owned(), ColumnType::String),
+    ];
+
+    let table = client
+        .table(TableData::Schema(schema), TableInitOptions::default())
+        .await?;
+
+    let ingest = table.arrow_ingest(UpdateOptions::default());
+    let mut chunks = arrow.chunks(7).collect::<Vec<_>>();
+    let last = chunks.pop().unwrap();
+    let mut num_rows = 0;
+    for chunk in chunks {
+        num_rows = ingest.write(chunk.to_vec()).await?;
+    }
+
+    assert!(num_rows <= 3);
+    assert_eq!(ingest.finish(last.to_vec()).await?, 3);
+    let view = table.view(None).await?;
+    assert_eq!(
+        view.to_csv(ViewWindow::default()).await?,
+        source_view.to_csv(ViewWindow::default()).await?
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_arrow_ingest_rejects_malformed_streams() -> Result<(), Box<dyn Error>> {
+    let server = perspective::server::Server::default();
+    let client = LocalClient::new(&server);
+    let table = client
+        .table(
+            UpdateData::JsonRows(ROWS.to_owned()).into(),
+            TableInitOptions::default(),
+        )
+        .await?;
+
+    let ingest = table.arrow_ingest(UpdateOptions::default());
+    assert!(ingest.finish(vec![0xFF; 64]).await.is_err());
+    assert_eq!(table.size().await?, 3);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_delete_abandons_arrow_ingest() -> Result<(), Box<dyn Error>> {
+    let server = perspective::server::Server::default();
+    let client = LocalClient::new(&server);
+    let source = client
+        .table(
+            UpdateData::JsonRows(ROWS.to_owned()).into(),
+            TableInitOptions::default(),
+        )
+        .await?;
+
+    let source_view = source.view(None).await?;
+    let arrow = source_view.to_arrow(ViewWindow::default()).await?;
+    let schema = vec![
+        ("x".to_owned(), ColumnType::Integer),
+        ("side".to_owned(), ColumnType::String),
+    ];
+
+    let options = TableInitOptions {
+        name: Some("t".to_owned()),
+        ..TableInitOptions::default()
+    };
+
+    let table = client
+        .table(TableData::Schema(schema.clone()), options.clone())
+        .await?;
+
+    let ingest = table.arrow_ingest(UpdateOptions::default());
+    let (head, tail) = arrow.split_at(arrow.len() / 2);
+    ingest.write(head.to_vec()).await?;
+    table.delete().await?;
+
+    // A table created under the same name does not inherit the stream.
+    let table = client.table(TableData::Schema(schema), options).await?;
+
+    assert!(ingest.finish(tail.to_vec()).await.is_err());
+    assert_eq!(table.size().await?, 0);
+    Ok(())
+}
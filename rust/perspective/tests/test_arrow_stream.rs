@@ -0,0 +1,110 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use std::error::Error;
+use std::ffi::c_void;
+
+use perspective::server::{ArrowStreamPtr, Server};
+use perspective::LocalClient;
+use perspective_client::{TableInitOptions, UpdateData, ViewWindow};
+
+/// Storage for a C `struct ArrowArrayStream`, which is five pointers wide.
+#[repr(C)]
+#[derive(Default)]
+struct RawArrowArrayStream([usize; 5]);
+
+impl RawArrowArrayStream {
+    fn as_ptr(&mut self) -> ArrowStreamPtr {
+        unsafe { ArrowStreamPtr::new(self as *mut Self as *mut c_void) }
+    }
+}
+
+#[tokio::test]
+async fn test_arrow_stream_round_trip() -> Result<(), Box<dyn Error>> {
+    let server = Server::default();
+    let client = LocalClient::new(&server);
+    let table = client
+        .table(
+            UpdateData::Csv("x,y\n1,a\n2,b\n3,".to_owned()).into(),
+            TableInitOptions::default(),
+        )
+        .await?;
+
+    let view = table.view(None).await?;
+    let mut stream = RawArrowArrayStream::default();
+    server
+        .view_to_arrow_stream(&view.name, stream.as_ptr())
+        .await?;
+
+    server
+        .host_arrow_stream("copy", None, stream.as_ptr())
+        .await?;
+
+    let copy = client.open_table("copy".to_owned()).await?;
+    let copy_view = copy.view(None).await?;
+    assert_eq!(
+        copy_view.to_csv(ViewWindow::default()).await?,
+        view.to_csv(ViewWindow::default()).await?
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_arrow_stream_update() -> Result<(), Box<dyn Error>> {
+    let server = Server::default();
+    let client = LocalClient::new(&server);
+    let table = client
+        .table(
+            UpdateData::Csv("x,y\n1,a\n2,b".to_owned()).into(),
+            TableInitOptions::default(),
+        )
+        .await?;
+
+    let view = table.view(None).await?;
+    let mut stream = RawArrowArrayStream::default();
+    server
+        .view_to_arrow_stream(&view.name, stream.as_ptr())
+        .await?;
+
+    server
+        .update_arrow_stream(table.get_name(), stream.as_ptr(), 0)
+        .await?;
+
+    assert_eq!(table.size().await?, 4);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_arrow_stream_rejects_existing_table() -> Result<(), Box<dyn Error>> {
+    let server = Server::default();
+    let client = LocalClient::new(&server);
+    let table = client
+        .table(
+            UpdateData::Csv("x\n1".to_owned()).into(),
+            TableInitOptions::default(),
+        )
+        .await?;
+
+    let view = table.view(None).await?;
+    let mut stream = RawArrowArrayStream::default();
+    server
+        .view_to_arrow_stream(&view.name, stream.as_ptr())
+        .await?;
+
+    let result = server
+        .host_arrow_stream(table.get_name(), None, stream.as_ptr())
+        .await;
+
+    assert!(result.is_err());
+    Ok(())
+}
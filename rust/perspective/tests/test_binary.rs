@@ -0,0 +1,117 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use std::collections::HashMap;
+use std::error::Error;
+
+use perspective::LocalClient;
+use perspective_client::config::{Aggregate, SingleAggregate, ViewConfigUpdate};
+use perspective_client::{
+    ColumnType, TableData, TableInitOptions, UpdateData, UpdateOptions, ViewWindow,
+};
+
+async fn binary_table(client: &LocalClient) -> Result<perspective_client::Table, Box<dyn Error>> {
+    let table = client
+        .table(
+            TableData::Schema(vec![
+                ("k".to_owned(), ColumnType::String),
+                ("blob".to_owned(), ColumnType::Binary),
+            ]),
+            TableInitOptions::default(),
+        )
+        .await?;
+
+    table
+        .update(
+            UpdateData::JsonRows(
+                r#"[
+                    {"k": "a", "blob": "aGk="},
+                    {"k": "a", "blob": [1, 2, 3]},
+                    {"k": "b", "blob": "aGVsbG8="},
+                    {"k": "b", "blob": null}
+                ]"#
+                .to_owned(),
+            ),
+            UpdateOptions::default(),
+        )
+        .await?;
+
+    Ok(table)
+}
+
+#[tokio::test]
+async fn test_binary_column_exports_base64() -> Result<(), Box<dyn Error>> {
+    let server = perspective::server::Server::default();
+    let client = LocalClient::new(&server);
+    let table = binary_table(&client).await?;
+    let schema = table.schema().await?;
+    assert_eq!(schema.get("blob"), Some(&ColumnType::Binary));
+
+    let view = table.view(None).await?;
+    let json = view.to_columns_string(ViewWindow::default()).await?;
+    assert_eq!(
+        json,
+        r#"{"k":["a","a","b","b"],"blob":["aGk=","AQID","aGVsbG8=",null]}"#
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_binary_size_aggregates() -> Result<(), Box<dyn Error>> {
+    let server = perspective::server::Server::default();
+    let client = LocalClient::new(&server);
+    let table = binary_table(&client).await?;
+    for (agg, expected) in [
+        (
+            SingleAggregate::TotalBytes,
+            r#"{"__ROW_PATH__":[[],["a"],["b"]],"blob":[10,5,5]}"#,
+        ),
+        (
+            SingleAggregate::MaxBytes,
+            r#"{"__ROW_PATH__":[[],["a"],["b"]],"blob":[5,3,5]}"#,
+        ),
+    ] {
+        let view = table
+            .view(Some(ViewConfigUpdate {
+                group_by: Some(vec!["k".to_owned()]),
+                columns: Some(vec![Some("blob".to_owned())]),
+                aggregates: Some(HashMap::from([(
+                    "blob".to_owned(),
+                    Aggregate::SingleAggregate(agg),
+                )])),
+                ..ViewConfigUpdate::default()
+            }))
+            .await?;
+
+        let json = view.to_columns_string(ViewWindow::default()).await?;
+        assert_eq!(json, expected);
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_binary_column_rejects_invalid_base64() -> Result<(), Box<dyn Error>> {
+    let server = perspective::server::Server::default();
+    let client = LocalClient::new(&server);
+    let table = binary_table(&client).await?;
+    let result = table
+        .update(
+            UpdateData::JsonRows(r#"[{"k": "c", "blob": "not base64!"}]"#.to_owned()),
+            UpdateOptions::default(),
+        )
+        .await;
+
+    assert!(result.is_err());
+    Ok(())
+}
@@ -0,0 +1,106 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+use std::sync::{Arc, Mutex, OnceLock};
+
+use perspective::client::Client;
+use perspective::server::{Server, Session};
+
+async fn connect(server: &Server) -> (Client, Arc<Session>) {
+    let client_slot: Arc<OnceLock<Client>> = Arc::default();
+    let session = Arc::new(
+        server
+            .new_session_with_callback({
+                let client_slot = client_slot.clone();
+                move |msg| {
+                    let client_slot = client_slot.clone();
+                    Box::pin(async move {
+                        client_slot.get().unwrap().handle_response(msg).await?;
+                        Ok(())
+                    })
+                }
+            })
+            .await,
+    );
+
+    let client = Client::new_with_callback({
+        let session = session.clone();
+        move |msg| {
+            let session = session.clone();
+            Box::pin(async move {
+                session.handle_request(msg).await?;
+                session.poll().await?;
+                Ok(())
+            })
+        }
+    });
+
+    let _ = client_slot.set(client.clone());
+    (client, session)
+}
+
+fn record_broadcasts(client: &Client) -> (u32, Arc<Mutex<Vec<(String, String)>>>) {
+    let received: Arc<Mutex<Vec<(String, String)>>> = Arc::default();
+    let id = client.on_broadcast({
+        let received = received.clone();
+        move |msg| received.lock().unwrap().push((msg.group, msg.message))
+    });
+
+    (id, received)
+}
+
+#[tokio::test]
+async fn test_broadcast_reaches_group_members_only() {
+    let server = Server::default();
+    let (client1, session1) = connect(&server).await;
+    let (client2, session2) = connect(&server).await;
+    let (_, received1) = record_broadcasts(&client1);
+    let (_, received2) = record_broadcasts(&client2);
+
+    session1.join_group("desk").await;
+    assert_eq!(server.group_members("desk").await, vec![session1.id()]);
+    server.broadcast("desk", "halt").await.unwrap();
+    assert_eq!(*received1.lock().unwrap(), vec![(
+        "desk".to_owned(),
+        "halt".to_owned()
+    )]);
+
+    assert!(received2.lock().unwrap().is_empty());
+
+    session2.join_group("desk").await;
+    session1.leave_group("desk").await;
+    server.broadcast("desk", "resume").await.unwrap();
+    assert_eq!(received1.lock().unwrap().len(), 1);
+    assert_eq!(*received2.lock().unwrap(), vec![(
+        "desk".to_owned(),
+        "resume".to_owned()
+    )]);
+
+    session2.leave_group("desk").await;
+    assert!(server.group_members("desk").await.is_empty());
+}
+
+#[tokio::test]
+async fn test_remove_broadcast() {
+    let server = Server::default();
+    let (client, session) = connect(&server).await;
+    let (id, received) = record_broadcasts(&client);
+    session.join_group("desk").await;
+    client.remove_broadcast(id).unwrap();
+    server.broadcast("desk", "halt").await.unwrap();
+    assert!(received.lock().unwrap().is_empty());
+
+    // Removing an unknown (or already removed) callback is an error.
+    assert!(client.remove_broadcast(id).is_err());
+
+    // Broadcasting to a group with no members is a no-op.
+    server.broadcast("nobody", "halt").await.unwrap();
+}
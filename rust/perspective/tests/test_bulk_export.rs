@@ -0,0 +1,95 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use std::error::Error;
+
+use perspective::LocalClient;
+use perspective_client::config::{Filter, FilterTerm, Scalar, ViewConfigUpdate};
+use perspective_client::{TableInitOptions, UpdateData, ViewWindow};
+
+const ROWS: &str = r#"[
+    {"x": 1, "side": "buy"},
+    {"x": 2, "side": "sell"},
+    {"x": 3, "side": "buy"}
+]"#;
+
+#[tokio::test]
+async fn test_bulk_export_slices_each_entity() -> Result<(), Box<dyn Error>> {
+    let server = perspective::server::Server::default();
+    let client = LocalClient::new(&server);
+    let table = client
+        .table(
+            UpdateData::JsonRows(ROWS.to_owned()).into(),
+            TableInitOptions::default(),
+        )
+        .await?;
+
+    let view = table
+        .view(Some(ViewConfigUpdate {
+            filter: Some(vec![Filter::new(
+                "side".to_owned(),
+                "==".to_owned(),
+                FilterTerm::Scalar(Scalar::String("buy".to_owned())),
+            )]),
+            ..ViewConfigUpdate::default()
+        }))
+        .await?;
+
+    let export = client
+        .bulk_export(vec![(&table).into(), (&view).into()], None)
+        .await?;
+
+    let num_rows = export
+        .manifest()
+        .iter()
+        .map(|entry| entry.num_rows)
+        .collect::<Vec<_>>();
+
+    assert_eq!(num_rows, vec![3, 2]);
+    for (entity, arrow) in export.iter() {
+        let copy = client
+            .table(UpdateData::Arrow(arrow).into(), TableInitOptions::default())
+            .await?;
+
+        let expected = if entity.entity_id == view.name {
+            view.to_csv(ViewWindow::default()).await?
+        } else {
+            table
+                .view(None)
+                .await?
+                .to_csv(ViewWindow::default())
+                .await?
+        };
+
+        let copy_view = copy.view(None).await?;
+        assert_eq!(copy_view.to_csv(ViewWindow::default()).await?, expected);
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_bulk_export_rejects_unknown_entities() -> Result<(), Box<dyn Error>> {
+    let server = perspective::server::Server::default();
+    let client = LocalClient::new(&server);
+    let table = client
+        .table(
+            UpdateData::JsonRows(ROWS.to_owned()).into(),
+            TableInitOptions::default(),
+        )
+        .await?;
+
+    let mut entity = perspective_client::BulkExportEntity::from(&table);
+    entity.entity_id = "missing".to_owned();
+    assert!(client.bulk_export(vec![entity], None).await.is_err());
+    Ok(())
+}
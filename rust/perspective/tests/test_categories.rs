@@ -0,0 +1,108 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use std::collections::HashMap;
+use std::error::Error;
+
+use perspective::LocalClient;
+use perspective_client::config::{Sort, SortDir, ViewConfigUpdate};
+use perspective_client::{TableInitOptions, UpdateData, ViewWindow};
+
+const ROWS: &str = r#"[
+    {"priority": "High", "x": 1},
+    {"priority": "Low", "x": 2},
+    {"priority": "Medium", "x": 3},
+    {"priority": "Low", "x": 4},
+    {"priority": "Urgent", "x": 5}
+]"#;
+
+fn categories() -> TableInitOptions {
+    TableInitOptions {
+        categories: Some(HashMap::from([("priority".to_owned(), vec![
+            "Low".to_owned(),
+            "Medium".to_owned(),
+            "High".to_owned(),
+        ])])),
+        ..TableInitOptions::default()
+    }
+}
+
+#[tokio::test]
+async fn test_categories_order_group_by_and_sort() -> Result<(), Box<dyn Error>> {
+    let server = perspective::server::Server::default();
+    let client = LocalClient::new(&server);
+    let table = client
+        .table(UpdateData::JsonRows(ROWS.to_owned()).into(), categories())
+        .await?;
+
+    let view = table
+        .view(Some(ViewConfigUpdate {
+            group_by: Some(vec!["priority".to_owned()]),
+            columns: Some(vec![Some("x".to_owned())]),
+            ..ViewConfigUpdate::default()
+        }))
+        .await?;
+
+    let json = view.to_columns_string(ViewWindow::default()).await?;
+    assert_eq!(
+        json,
+        r#"{"__ROW_PATH__":[[],["Low"],["Medium"],["High"],["Urgent"]],"x":[15,6,3,1,5]}"#
+    );
+
+    let view = table
+        .view(Some(ViewConfigUpdate {
+            columns: Some(vec![Some("priority".to_owned())]),
+            sort: Some(vec![Sort("priority".to_owned(), SortDir::Desc)]),
+            ..ViewConfigUpdate::default()
+        }))
+        .await?;
+
+    let json = view.to_columns_string(ViewWindow::default()).await?;
+    assert_eq!(
+        json,
+        r#"{"priority":["Urgent","High","Medium","Low","Low"]}"#
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_categories_introspection() -> Result<(), Box<dyn Error>> {
+    let server = perspective::server::Server::default();
+    let client = LocalClient::new(&server);
+    let table = client
+        .table(UpdateData::JsonRows(ROWS.to_owned()).into(), categories())
+        .await?;
+
+    let categories = table.categories().await?;
+    assert_eq!(
+        categories,
+        HashMap::from([("priority".to_owned(), vec![
+            "Low".to_owned(),
+            "Medium".to_owned(),
+            "High".to_owned()
+        ])])
+    );
+
+    let result = client
+        .table(
+            UpdateData::JsonRows(ROWS.to_owned()).into(),
+            TableInitOptions {
+                categories: Some(HashMap::from([("x".to_owned(), vec!["1".to_owned()])])),
+                ..TableInitOptions::default()
+            },
+        )
+        .await;
+
+    assert!(result.is_err());
+    Ok(())
+}
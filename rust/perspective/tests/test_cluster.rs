@@ -0,0 +1,197 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use std::collections::HashMap;
+use std::error::Error;
+use std::sync::{Arc, OnceLock};
+
+use futures::future::BoxFuture;
+use perspective::client::{Client, TableInitOptions, UpdateData};
+use perspective::cluster::{Cluster, ClusterSession, HashRing};
+use perspective::proxy::{BackendConnector, BackendReceiver, BackendSender};
+use perspective::server::{Server, ServerError, Session, SessionHandler};
+use perspective::LocalClient;
+
+#[derive(Clone)]
+struct BackendHandler(BackendReceiver);
+
+impl SessionHandler for BackendHandler {
+    async fn send_response<'a>(&'a mut self, msg: &'a [u8]) -> Result<(), ServerError> {
+        self.0.handle_response(msg).await
+    }
+}
+
+struct LocalSender(Session);
+
+impl BackendSender for LocalSender {
+    fn send<'a>(&'a self, msg: &'a [u8]) -> BoxFuture<'a, Result<(), ServerError>> {
+        Box::pin(async move {
+            self.0.handle_request(msg).await?;
+            self.0.poll().await
+        })
+    }
+}
+
+/// Connects to in-process [`Server`]s by name.
+#[derive(Clone)]
+struct LocalConnector(HashMap<String, Server>);
+
+impl BackendConnector for LocalConnector {
+    fn connect<'a>(
+        &'a self,
+        backend: &'a str,
+        receiver: BackendReceiver,
+    ) -> BoxFuture<'a, Result<Box<dyn BackendSender>, ServerError>> {
+        Box::pin(async move {
+            let server = self.0.get(backend).ok_or("Unknown backend")?;
+            let session = server.new_session(BackendHandler(receiver)).await;
+            Ok(Box::new(LocalSender(session)) as Box<dyn BackendSender>)
+        })
+    }
+}
+
+#[derive(Clone)]
+struct EdgeHandler(Arc<OnceLock<Client>>);
+
+impl SessionHandler for EdgeHandler {
+    async fn send_response<'a>(&'a mut self, msg: &'a [u8]) -> Result<(), ServerError> {
+        self.0.get().unwrap().handle_response(msg).await?;
+        Ok(())
+    }
+}
+
+/// A [`Client`] connected through `cluster`.
+async fn edge_client(cluster: &Cluster) -> Client {
+    let cell = Arc::new(OnceLock::new());
+    let session: Arc<ClusterSession> =
+        Arc::new(cluster.new_session(EdgeHandler(cell.clone())).await);
+
+    let client = Client::new_with_callback(move |msg| {
+        let session = session.clone();
+        Box::pin(async move { session.handle_request(msg).await })
+    });
+
+    let _ = cell.set(client.clone());
+    client
+}
+
+fn servers(names: &[&str]) -> HashMap<String, Server> {
+    names
+        .iter()
+        .map(|name| (name.to_string(), Server::default()))
+        .collect()
+}
+
+fn named(name: &str) -> TableInitOptions {
+    let mut options = TableInitOptions::default();
+    options.set_name(name);
+    options
+}
+
+async fn hosted_table_names(server: &Server) -> Result<Vec<String>, Box<dyn Error>> {
+    let client = LocalClient::new(server);
+    let mut names = client.get_hosted_table_names().await?;
+    client.close().await;
+    names.sort();
+    Ok(names)
+}
+
+#[test]
+fn test_hash_ring_moves_only_departed_keys() {
+    let ring = HashRing::new(["a", "b", "c"]);
+    let smaller = HashRing::new(["c", "a"]);
+    assert_eq!(
+        HashRing::new(["c", "b", "a"]).node_for("x"),
+        ring.node_for("x")
+    );
+    for key in (0..200).map(|i| format!("table_{}", i)) {
+        let owner = ring.node_for(&key).unwrap();
+        if owner != "b" {
+            assert_eq!(smaller.node_for(&key), Some(owner));
+        }
+    }
+
+    assert_eq!(HashRing::new(Vec::<String>::new()).node_for("x"), None);
+}
+
+#[tokio::test]
+async fn test_cluster_routes_tables_to_owners() -> Result<(), Box<dyn Error>> {
+    let servers = servers(&["a", "b", "c"]);
+    let cluster = Cluster::new(LocalConnector(servers.clone()), ["a", "b", "c"]);
+    let client = edge_client(&cluster).await;
+    let mut names: Vec<String> = (0..30).map(|i| format!("table_{}", i)).collect();
+    names.sort();
+    for name in &names {
+        let data = UpdateData::Csv(format!("x\n{}", name.len()));
+        client.table(data.into(), named(name)).await?;
+    }
+
+    for (node, server) in &servers {
+        let owned: Vec<String> = names
+            .iter()
+            .filter(|name| cluster.node_for(name).as_ref() == Some(node))
+            .cloned()
+            .collect();
+
+        assert_eq!(hosted_table_names(server).await?, owned);
+    }
+
+    let mut hosted = client.get_hosted_table_names().await?;
+    hosted.sort();
+    assert_eq!(hosted, names);
+    let table = client.open_table("table_7".to_owned()).await?;
+    let view = table.view(None).await?;
+    assert_eq!(view.num_rows().await?, 1);
+    view.delete().await?;
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_cluster_migrates_tables_on_membership_change() -> Result<(), Box<dyn Error>> {
+    let servers = servers(&["a", "b", "c"]);
+    let cluster = Cluster::new(LocalConnector(servers.clone()), ["a", "b"]);
+    let client = edge_client(&cluster).await;
+    let names: Vec<String> = (0..30).map(|i| format!("table_{}", i)).collect();
+    for name in &names {
+        let mut options = named(name);
+        options.index = Some("x".to_owned());
+        let data = UpdateData::Csv("x,y\n1,a\n2,b".to_owned());
+        client.table(data.into(), options).await?;
+    }
+
+    let before: HashMap<String, String> = names
+        .iter()
+        .map(|name| (name.clone(), cluster.node_for(name).unwrap()))
+        .collect();
+
+    cluster.set_nodes(["a", "b", "c"]).await?;
+    let moved: Vec<&String> = names
+        .iter()
+        .filter(|name| cluster.node_for(name) != Some(before[*name].clone()))
+        .collect();
+
+    assert!(!moved.is_empty());
+    assert!(moved
+        .iter()
+        .all(|name| cluster.node_for(name).as_deref() == Some("c")));
+
+    for (node, server) in &servers {
+        for name in hosted_table_names(server).await? {
+            assert_eq!(cluster.node_for(&name).as_ref(), Some(node));
+        }
+    }
+
+    let table = client.open_table(moved[0].clone()).await?;
+    assert_eq!(table.get_index(), Some("x".to_owned()));
+    assert_eq!(table.size().await?, 2);
+    Ok(())
+}
@@ -0,0 +1,100 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use std::error::Error;
+
+use perspective::server::Server;
+use perspective::LocalClient;
+use perspective_client::config::{ColumnGroup, ViewConfigUpdate};
+use perspective_client::{ColumnGroupPaths, Table, TableInitOptions, UpdateData, ViewWindow};
+use serde_json::{json, Value};
+
+async fn quotes_table(client: &LocalClient) -> Result<Table, Box<dyn Error>> {
+    Ok(client
+        .table(
+            UpdateData::Csv("venue,bid,ask\nLDN,1.08,1.09".to_owned()).into(),
+            TableInitOptions::default(),
+        )
+        .await?)
+}
+
+fn quote_groups() -> Vec<ColumnGroup> {
+    vec![
+        ColumnGroup {
+            name: "EUR/USD".to_owned(),
+            columns: vec!["bid".to_owned(), "ask".to_owned()],
+        },
+        ColumnGroup {
+            name: "FX".to_owned(),
+            columns: vec!["EUR/USD".to_owned()],
+        },
+    ]
+}
+
+#[tokio::test]
+async fn test_column_groups_prefix_columns() -> Result<(), Box<dyn Error>> {
+    let server = Server::default();
+    let client = LocalClient::new(&server);
+    let table = quotes_table(&client).await?;
+    let view = table
+        .view(Some(ViewConfigUpdate {
+            column_groups: Some(quote_groups()),
+            ..ViewConfigUpdate::default()
+        }))
+        .await?;
+
+    let json = view.to_columns_string(ViewWindow::default()).await?;
+    assert_eq!(
+        serde_json::from_str::<Value>(&json)?,
+        json!({"venue": ["LDN"], "bid": [1.08], "ask": [1.09]})
+    );
+
+    let json = view
+        .to_columns_string(ViewWindow {
+            column_group_paths: Some(ColumnGroupPaths::Prefix),
+            ..ViewWindow::default()
+        })
+        .await?;
+
+    assert_eq!(
+        serde_json::from_str::<Value>(&json)?,
+        json!({"venue": ["LDN"], "FX|EUR/USD|bid": [1.08], "FX|EUR/USD|ask": [1.09]})
+    );
+
+    assert_eq!(view.get_config().await?.column_groups, quote_groups());
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_column_groups_reject_shared_members() -> Result<(), Box<dyn Error>> {
+    let server = Server::default();
+    let client = LocalClient::new(&server);
+    let table = quotes_table(&client).await?;
+    let result = table
+        .view(Some(ViewConfigUpdate {
+            column_groups: Some(vec![
+                ColumnGroup {
+                    name: "EUR/USD".to_owned(),
+                    columns: vec!["bid".to_owned()],
+                },
+                ColumnGroup {
+                    name: "GBP/USD".to_owned(),
+                    columns: vec!["bid".to_owned()],
+                },
+            ]),
+            ..ViewConfigUpdate::default()
+        }))
+        .await;
+
+    assert!(result.is_err());
+    Ok(())
+}
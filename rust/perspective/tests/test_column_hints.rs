@@ -0,0 +1,73 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use std::collections::HashMap;
+use std::error::Error;
+
+use perspective::LocalClient;
+use perspective_client::{ColumnHints, TableInitOptions, UpdateData};
+
+const ROWS: &str = r#"[{"notional": 1234.5, "side": "buy"}]"#;
+
+fn notional_hints() -> ColumnHints {
+    ColumnHints {
+        aggregate: Some("sum".to_owned()),
+        precision: Some(2),
+        thousands_separator: Some(true),
+        editable: None,
+        timezone: None,
+    }
+}
+
+#[tokio::test]
+async fn test_column_hints_are_shared_between_clients() -> Result<(), Box<dyn Error>> {
+    let server = perspective::server::Server::default();
+    let client1 = LocalClient::new(&server);
+    let client2 = LocalClient::new(&server);
+    client1
+        .table(
+            UpdateData::JsonRows(ROWS.to_owned()).into(),
+            TableInitOptions {
+                name: Some("trades".to_owned()),
+                column_hints: Some(HashMap::from([("notional".to_owned(), notional_hints())])),
+                ..TableInitOptions::default()
+            },
+        )
+        .await?;
+
+    let table = client2.open_table("trades".to_owned()).await?;
+    let hints = table.column_hints().await?;
+    assert_eq!(hints.len(), 1);
+    assert_eq!(hints["notional"], notional_hints());
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_column_hints_reject_unknown_aggregate() -> Result<(), Box<dyn Error>> {
+    let server = perspective::server::Server::default();
+    let client = LocalClient::new(&server);
+    let result = client
+        .table(
+            UpdateData::JsonRows(ROWS.to_owned()).into(),
+            TableInitOptions {
+                column_hints: Some(HashMap::from([("notional".to_owned(), ColumnHints {
+                    aggregate: Some("not an aggregate".to_owned()),
+                    ..ColumnHints::default()
+                })])),
+                ..TableInitOptions::default()
+            },
+        )
+        .await;
+
+    assert!(result.is_err());
+    Ok(())
+}
@@ -0,0 +1,126 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use std::error::Error;
+
+use perspective::LocalClient;
+use perspective_client::config::ViewConfigUpdate;
+use perspective_client::{Table, TableInitOptions, UpdateData, ViewWindow};
+
+fn history_csv(start: i64, end: i64) -> String {
+    let mut csv = "id,ts,side,qty,px\n".to_owned();
+    for id in start..end {
+        let side = if id % 3 == 0 { "buy" } else { "sell" };
+        let ts = 1_700_000_000_000_i64 + id * 1000;
+        csv.push_str(&format!(
+            "{},{},{},{},{}\n",
+            id,
+            ts,
+            side,
+            100,
+            id as f64 * 0.5
+        ));
+    }
+
+    csv
+}
+
+async fn make_table(client: &LocalClient, compress: bool) -> Result<Table, Box<dyn Error>> {
+    let table = client
+        .table(
+            UpdateData::Csv(history_csv(0, 2000)).into(),
+            TableInitOptions {
+                index: Some("id".to_owned()),
+                compress: Some(compress),
+                ..TableInitOptions::default()
+            },
+        )
+        .await?;
+
+    Ok(table)
+}
+
+async fn snapshot(table: &Table) -> Result<String, Box<dyn Error>> {
+    let view = table.view(None).await?;
+    let json = view.to_columns_string(ViewWindow::default()).await?;
+    view.delete().await?;
+    Ok(json)
+}
+
+#[tokio::test]
+async fn test_compressed_table_reads_match_uncompressed() -> Result<(), Box<dyn Error>> {
+    let server = perspective::server::Server::default();
+    let client = LocalClient::new(&server);
+    let compressed = make_table(&client, true).await?;
+    let plain = make_table(&client, false).await?;
+    assert_eq!(snapshot(&compressed).await?, snapshot(&plain).await?);
+
+    // Read twice, so the second read decompresses columns which were
+    // compressed again after the first.
+    assert_eq!(snapshot(&compressed).await?, snapshot(&plain).await?);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_compressed_table_accepts_updates() -> Result<(), Box<dyn Error>> {
+    let server = perspective::server::Server::default();
+    let client = LocalClient::new(&server);
+    let compressed = make_table(&client, true).await?;
+    let plain = make_table(&client, false).await?;
+    assert_eq!(compressed.size().await?, 2000);
+    for table in [&compressed, &plain] {
+        table
+            .update(UpdateData::Csv(history_csv(1500, 2500)), Default::default())
+            .await?;
+    }
+
+    assert_eq!(compressed.size().await?, 2500);
+    let config = || ViewConfigUpdate {
+        group_by: Some(vec!["side".to_owned()]),
+        columns: Some(vec![Some("qty".to_owned()), Some("px".to_owned())]),
+        ..ViewConfigUpdate::default()
+    };
+
+    let compressed_view = compressed.view(Some(config())).await?;
+    let plain_view = plain.view(Some(config())).await?;
+    assert_eq!(
+        compressed_view
+            .to_columns_string(ViewWindow::default())
+            .await?,
+        plain_view.to_columns_string(ViewWindow::default()).await?
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_compressed_table_stats_report_smaller_storage() -> Result<(), Box<dyn Error>> {
+    let server = perspective::server::Server::default();
+    let client = LocalClient::new(&server);
+    let compressed = make_table(&client, true).await?;
+    let plain = make_table(&client, false).await?;
+    let plain_stats = plain.stats(None).await?;
+    assert!(plain_stats.raw_bytes > 0);
+    assert_eq!(plain_stats.stored_bytes, plain_stats.raw_bytes);
+
+    // Sequential `id`s and `ts`s, two `side`s and a constant `qty` all
+    // encode to a fraction of their size, and are compressed again after
+    // a read decompresses them.
+    for _ in 0..2 {
+        let stats = compressed.stats(None).await?;
+        assert_eq!(stats.raw_bytes, plain_stats.raw_bytes);
+        assert!(stats.stored_bytes * 2 < stats.raw_bytes);
+        snapshot(&compressed).await?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,118 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use std::collections::HashMap;
+use std::error::Error;
+
+use perspective::client::{
+    ColumnType, CsvOptions, TableInitOptions, UpdateData, UpdateOptions, ViewWindow,
+};
+use perspective::server::Server;
+use perspective::LocalClient;
+
+/// A European export: a title row above the column names, `;`-delimited,
+/// `1.234,56` numbers, `-` and `n/a` for missing values, and `dd.mm.yyyy`
+/// timestamps.
+fn european_options() -> CsvOptions {
+    CsvOptions {
+        delimiter: Some(';'),
+        decimal_separator: Some(','),
+        thousands_separator: Some('.'),
+        header_rows: Some(2),
+        null_values: Some(vec!["-".to_owned(), "n/a".to_owned()]),
+        timestamp_formats: Some(vec!["%d.%m.%Y %H:%M".to_owned()]),
+        ..CsvOptions::default()
+    }
+}
+
+#[tokio::test]
+async fn test_csv_options_european_csv() -> Result<(), Box<dyn Error>> {
+    let server = Server::default();
+    let client = LocalClient::new(&server);
+    let csv = [
+        "Handelsexport Q1",
+        "trader;notional;qty;traded_at",
+        "Anna;1.234,56;10;15.03.2024 14:30",
+        "Bernd;-;n/a;16.03.2024 09:05",
+        "Clara;987,5;3;-",
+    ]
+    .join("\n");
+
+    let table = client
+        .table(UpdateData::Csv(csv).into(), TableInitOptions {
+            csv: Some(european_options()),
+            ..TableInitOptions::default()
+        })
+        .await?;
+
+    assert_eq!(
+        table.schema().await?,
+        HashMap::from([
+            ("trader".to_owned(), ColumnType::String),
+            ("notional".to_owned(), ColumnType::Float),
+            ("qty".to_owned(), ColumnType::Integer),
+            ("traded_at".to_owned(), ColumnType::Datetime),
+        ])
+    );
+
+    let view = table.view(None).await?;
+    assert_eq!(
+        view.to_columns_string(ViewWindow::default()).await?,
+        r#"{"trader":["Anna","Bernd","Clara"],"notional":[1234.56,null,987.5],"qty":[10,null,3],"traded_at":[1710513000000,1710579900000,null]}"#
+    );
+
+    // Updates are parsed with their own options, against the table's schema.
+    let update_options = UpdateOptions {
+        csv: Some(european_options()),
+        ..UpdateOptions::default()
+    };
+
+    table
+        .update(
+            UpdateData::Csv(
+                "Nachtrag\ntrader;notional;qty;traded_at\nDieter;12.000,25;n/a;-\n".to_owned(),
+            ),
+            update_options,
+        )
+        .await?;
+
+    assert_eq!(
+        view.to_columns_string(ViewWindow::default()).await?,
+        r#"{"trader":["Anna","Bernd","Clara","Dieter"],"notional":[1234.56,null,987.5,12000.25],"qty":[10,null,3,null],"traded_at":[1710513000000,1710579900000,null,null]}"#
+    );
+
+    client.close().await;
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_csv_options_rejects_ambiguous_thousands_separator() -> Result<(), Box<dyn Error>> {
+    let server = Server::default();
+    let client = LocalClient::new(&server);
+    let result = client
+        .table(
+            UpdateData::Csv("x;y\n1;2\n".to_owned()).into(),
+            TableInitOptions {
+                csv: Some(CsvOptions {
+                    delimiter: Some(';'),
+                    thousands_separator: Some(';'),
+                    ..CsvOptions::default()
+                }),
+                ..TableInitOptions::default()
+            },
+        )
+        .await;
+
+    assert!(result.is_err());
+    client.close().await;
+    Ok(())
+}
@@ -0,0 +1,178 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use std::error::Error;
+
+use futures::TryStreamExt;
+use perspective::client::config::ViewConfigUpdate;
+use perspective::client::{
+    Client, CsvExportOptions, CsvQuoting, Table, TableInitOptions, UpdateData, View,
+};
+use perspective::server::Server;
+use perspective::LocalClient;
+
+async fn trades(client: &Client) -> Result<Table, Box<dyn Error>> {
+    let rows = r#"[
+        {"desk": "A", "side": "buy", "name": "a;b", "price": 1.5, "qty": 1},
+        {"desk": "B", "side": "sell", "name": "say \"hi\"", "price": null, "qty": 2},
+        {"desk": "A", "side": "buy", "name": null, "price": 2.25, "qty": 3},
+        {"desk": "B", "side": "sell", "name": "plain", "price": 10.0, "qty": null},
+        {"desk": "A", "side": "sell", "name": "x", "price": 0.5, "qty": 5}
+    ]"#;
+
+    let table = client
+        .table(
+            UpdateData::JsonRows(rows.to_owned()).into(),
+            TableInitOptions::default(),
+        )
+        .await?;
+
+    Ok(table)
+}
+
+/// `rows` as CSV text, each terminated by a newline.
+fn lines(rows: &[&str]) -> String {
+    rows.iter().map(|x| format!("{}\n", x)).collect()
+}
+
+/// The chunks of a [`View::to_csv_stream`], the header first.
+async fn csv_chunks(view: &View, options: CsvExportOptions) -> Result<Vec<String>, Box<dyn Error>> {
+    Ok(view.to_csv_stream(options).await?.try_collect().await?)
+}
+
+#[tokio::test]
+async fn test_csv_stream_spans_chunks() -> Result<(), Box<dyn Error>> {
+    let server = Server::default();
+    let client = LocalClient::new(&server);
+    let table = trades(&client).await?;
+    let view = table
+        .view(Some(ViewConfigUpdate {
+            columns: Some(vec![
+                Some("name".to_owned()),
+                Some("price".to_owned()),
+                Some("qty".to_owned()),
+            ]),
+            ..ViewConfigUpdate::default()
+        }))
+        .await?;
+
+    let options = CsvExportOptions {
+        delimiter: Some(';'),
+        precision: Some(2),
+        decimal_separator: Some(','),
+        null_value: Some("NA".to_owned()),
+        chunk_rows: Some(2),
+        ..CsvExportOptions::default()
+    };
+
+    let chunks = csv_chunks(&view, options).await?;
+    assert_eq!(chunks, vec![
+        "name;price;qty\n",
+        "\"a;b\";1,50;1\n\"say \"\"hi\"\"\";NA;2\n",
+        "NA;2,25;3\nplain;10,00;NA\n",
+        "x;0,50;5\n",
+    ]);
+
+    assert_eq!(chunks.concat().matches("name;price;qty").count(), 1);
+    client.close().await;
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_csv_stream_quoting() -> Result<(), Box<dyn Error>> {
+    let server = Server::default();
+    let client = LocalClient::new(&server);
+    let table = trades(&client).await?;
+    let view = table
+        .view(Some(ViewConfigUpdate {
+            columns: Some(vec![Some("name".to_owned()), Some("price".to_owned())]),
+            ..ViewConfigUpdate::default()
+        }))
+        .await?;
+
+    let csv = |quoting| CsvExportOptions {
+        quoting: Some(quoting),
+        chunk_rows: Some(3),
+        ..CsvExportOptions::default()
+    };
+
+    assert_eq!(
+        csv_chunks(&view, csv(CsvQuoting::NonNumeric))
+            .await?
+            .concat(),
+        lines(&[
+            r#""name","price""#,
+            r#""a;b",1.5"#,
+            r#""say ""hi""","#,
+            r#",2.25"#,
+            r#""plain",10"#,
+            r#""x",0.5"#,
+        ])
+    );
+
+    assert_eq!(
+        csv_chunks(&view, csv(CsvQuoting::All)).await?.concat(),
+        lines(&[
+            r#""name","price""#,
+            r#""a;b","1.5""#,
+            r#""say ""hi""","""#,
+            r#""","2.25""#,
+            r#""plain","10""#,
+            r#""x","0.5""#,
+        ])
+    );
+
+    assert_eq!(
+        csv_chunks(&view, csv(CsvQuoting::Never)).await?.concat(),
+        lines(&[
+            r#"name,price"#,
+            r#"a;b,1.5"#,
+            r#"say "hi","#,
+            r#",2.25"#,
+            r#"plain,10"#,
+            r#"x,0.5"#,
+        ])
+    );
+
+    client.close().await;
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_csv_stream_group_by_row_path() -> Result<(), Box<dyn Error>> {
+    let server = Server::default();
+    let client = LocalClient::new(&server);
+    let table = trades(&client).await?;
+    let view = table
+        .view(Some(ViewConfigUpdate {
+            group_by: Some(vec!["desk".to_owned(), "side".to_owned()]),
+            columns: Some(vec![Some("qty".to_owned())]),
+            ..ViewConfigUpdate::default()
+        }))
+        .await?;
+
+    let options = CsvExportOptions {
+        chunk_rows: Some(4),
+        ..CsvExportOptions::default()
+    };
+
+    // The total row's path is empty, and each group's path is joined by `|`.
+    let chunks = csv_chunks(&view, options).await?;
+    assert_eq!(chunks, vec![
+        "__ROW_PATH__,qty\n",
+        ",11\nA,9\nA|buy,4\nA|sell,5\n",
+        "B,2\nB|sell,2\n",
+    ]);
+
+    client.close().await;
+    Ok(())
+}
@@ -0,0 +1,185 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use std::error::Error;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use perspective::server::{DeliveryPolicy, Server, ServerError, Session};
+use perspective_client::proto::request::ClientReq;
+use perspective_client::proto::{Request, Response, ServerHelloReq};
+use perspective_client::protocol::MAX_PROTOCOL_VERSION;
+use prost::Message;
+
+fn hello(msg_id: u32) -> Vec<u8> {
+    Request {
+        msg_id,
+        entity_id: "".to_owned(),
+        client_req: Some(ClientReq::ServerHelloReq(ServerHelloReq {
+            min_protocol_version: MAX_PROTOCOL_VERSION,
+            max_protocol_version: MAX_PROTOCOL_VERSION,
+            features: vec![],
+            client_version: "".to_owned(),
+        })),
+    }
+    .encode_to_vec()
+}
+
+/// A [`Session`] whose callback fails while `failing` is set, recording the
+/// `msg_id`s it successfully delivers.
+async fn flaky_session(
+    server: &Server,
+    failing: Arc<AtomicBool>,
+    delivered: Arc<Mutex<Vec<u32>>>,
+) -> Session {
+    server
+        .new_session_with_callback(move |msg| {
+            let failing = failing.clone();
+            let delivered = delivered.clone();
+            let msg_id = Response::decode(msg).map(|x| x.msg_id);
+            Box::pin(async move {
+                if failing.load(Ordering::SeqCst) {
+                    return Err("Connection reset".into());
+                }
+
+                delivered.lock().unwrap().push(msg_id?);
+                Ok::<_, ServerError>(())
+            })
+        })
+        .await
+}
+
+#[tokio::test]
+async fn test_delivery_failure_propagates_by_default() -> Result<(), Box<dyn Error>> {
+    let server = Server::default();
+    let delivered = Arc::default();
+    let session = flaky_session(&server, Arc::new(AtomicBool::new(true)), delivered).await;
+    assert!(session.handle_request(&hello(1)).await.is_err());
+    assert!(server.dead_letters().await.is_empty());
+    session.close().await;
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_delivery_failure_is_dead_lettered() -> Result<(), Box<dyn Error>> {
+    let server = Server::default();
+    server.set_delivery_policy(DeliveryPolicy::DeadLetter).await;
+    let delivered = Arc::default();
+    let session = flaky_session(&server, Arc::new(AtomicBool::new(true)), delivered).await;
+    session.handle_request(&hello(1)).await?;
+    let dead_letters = server.dead_letters().await;
+    assert_eq!(dead_letters.len(), 1);
+    assert_eq!(dead_letters[0].session_id, session.id());
+    assert_eq!(dead_letters[0].attempts, 1);
+    assert!(dead_letters[0].request_id.is_some());
+    assert!(dead_letters[0].error.contains("Connection reset"));
+    assert_eq!(
+        Response::decode(dead_letters[0].message.as_slice())?.msg_id,
+        1
+    );
+    assert_eq!(server.take_dead_letters().await.len(), 1);
+    assert!(server.dead_letters().await.is_empty());
+    session.close().await;
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_delivery_retry_preserves_order() -> Result<(), Box<dyn Error>> {
+    let server = Server::default();
+    server
+        .set_delivery_policy(DeliveryPolicy::Retry {
+            max_attempts: 5,
+            backoff: Duration::ZERO,
+        })
+        .await;
+
+    let failing = Arc::new(AtomicBool::new(true));
+    let delivered = Arc::new(Mutex::new(vec![]));
+    let session = flaky_session(&server, failing.clone(), delivered.clone()).await;
+    session.handle_request(&hello(1)).await?;
+    session.handle_request(&hello(2)).await?;
+    assert!(delivered.lock().unwrap().is_empty());
+    failing.store(false, Ordering::SeqCst);
+    session.poll().await?;
+    assert_eq!(*delivered.lock().unwrap(), vec![1, 2]);
+    assert!(server.dead_letters().await.is_empty());
+    session.close().await;
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_delivery_retry_exhausted_is_dead_lettered() -> Result<(), Box<dyn Error>> {
+    let server = Server::default();
+    server
+        .set_delivery_policy(DeliveryPolicy::Retry {
+            max_attempts: 2,
+            backoff: Duration::ZERO,
+        })
+        .await;
+
+    let delivered = Arc::new(Mutex::new(vec![]));
+    let session = flaky_session(&server, Arc::new(AtomicBool::new(true)), delivered).await;
+    session.handle_request(&hello(1)).await?;
+    assert!(server.dead_letters().await.is_empty());
+    session.poll().await?;
+    let dead_letters = server.dead_letters().await;
+    assert_eq!(dead_letters.len(), 1);
+    assert_eq!(dead_letters[0].attempts, 2);
+    session.close().await;
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_delivery_failure_closes_session() -> Result<(), Box<dyn Error>> {
+    let server = Server::default();
+    server
+        .set_delivery_policy(DeliveryPolicy::CloseSession)
+        .await;
+
+    let delivered = Arc::default();
+    let session = flaky_session(&server, Arc::new(AtomicBool::new(true)), delivered).await;
+    session.handle_request(&hello(1)).await?;
+    assert!(server.sessions().await.is_empty());
+    assert_eq!(server.dead_letters().await.len(), 1);
+    assert!(session.handle_request(&hello(2)).await.is_err());
+    session.close().await;
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_panicking_callback_closes_only_its_session() -> Result<(), Box<dyn Error>> {
+    let server = Server::default();
+    let panicking = server
+        .new_session_with_callback(|_| panic!("Handler bug"))
+        .await;
+
+    let delivered = Arc::new(Mutex::new(vec![]));
+    let healthy = flaky_session(&server, Arc::default(), delivered.clone()).await;
+    panicking.join_group("all").await;
+    healthy.join_group("all").await;
+    server.broadcast("all", "hello").await?;
+    assert_eq!(*delivered.lock().unwrap(), vec![0]);
+    let sessions = server.sessions().await;
+    assert_eq!(sessions.len(), 1);
+    assert_eq!(sessions[0].id, healthy.id());
+    let dead_letters = server.dead_letters().await;
+    assert_eq!(dead_letters.len(), 1);
+    assert_eq!(dead_letters[0].session_id, panicking.id());
+    assert!(dead_letters[0].error.contains("Handler bug"));
+    assert!(panicking.handle_request(&hello(1)).await.is_err());
+    healthy.handle_request(&hello(2)).await?;
+    assert_eq!(*delivered.lock().unwrap(), vec![0, 2]);
+    panicking.close().await;
+    healthy.close().await;
+    Ok(())
+}
@@ -0,0 +1,91 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use std::error::Error;
+
+use perspective::client::config::ViewConfigUpdate;
+use perspective::client::{TableInitOptions, UpdateData, UpdateOptions, View, ViewWindow};
+use perspective::server::Server;
+use perspective::LocalClient;
+use serde_json::{json, Value};
+
+async fn pnl(view: &View) -> Result<Value, Box<dyn Error>> {
+    let json = view.to_columns_string(ViewWindow::default()).await?;
+    let json: Value = serde_json::from_str(&json)?;
+    Ok(json["pnl"].clone())
+}
+
+#[tokio::test]
+async fn test_derived_table_follows_source() -> Result<(), Box<dyn Error>> {
+    let server = Server::default();
+    let client = LocalClient::new(&server);
+    let mut options = TableInitOptions::default();
+    options.set_name("trades");
+    let data = UpdateData::Csv("desk,pnl\na,10\nb,-150\na,5".to_owned());
+    let trades = client.table(data.into(), options).await?;
+    let config = ViewConfigUpdate {
+        group_by: Some(vec!["desk".to_owned()]),
+        columns: Some(vec![Some("pnl".to_owned())]),
+        ..ViewConfigUpdate::default()
+    };
+
+    let derived = server
+        .create_derived_table("by_desk", "trades", config)
+        .await?;
+
+    let by_desk = client.open_table("by_desk".to_owned()).await?;
+    let view = by_desk.view(None).await?;
+    // The total row of the grouped view comes first.
+    assert_eq!(pnl(&view).await?, json!([-135, 15, -150]));
+    trades
+        .update(
+            UpdateData::Csv("desk,pnl\nb,200".to_owned()),
+            UpdateOptions::default(),
+        )
+        .await?;
+
+    assert_eq!(pnl(&view).await?, json!([65, 15, 50]));
+
+    // Clients can read the derived table, but not write to it.
+    assert!(by_desk
+        .update(
+            UpdateData::Csv("pnl\n1".to_owned()),
+            UpdateOptions::default()
+        )
+        .await
+        .is_err());
+
+    derived.close().await;
+    trades
+        .update(
+            UpdateData::Csv("desk,pnl\nc,1".to_owned()),
+            UpdateOptions::default(),
+        )
+        .await?;
+
+    assert_eq!(pnl(&view).await?, json!([65, 15, 50]));
+    view.delete().await?;
+    client.close().await;
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_derived_table_missing_source() -> Result<(), Box<dyn Error>> {
+    let server = Server::default();
+    let result = server
+        .create_derived_table("derived", "missing", ViewConfigUpdate::default())
+        .await;
+
+    assert!(result.is_err());
+    assert!(server.sessions().await.is_empty());
+    Ok(())
+}
@@ -0,0 +1,101 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use std::collections::HashMap;
+use std::error::Error;
+
+use perspective::LocalClient;
+use perspective_client::{DictionaryOptions, TableInitOptions, UpdateData, UpdateOptions};
+
+const ROWS: &str = r#"[
+    {"x": 1, "order_id": "a1", "side": "buy"},
+    {"x": 2, "order_id": "a2", "side": "sell"},
+    {"x": 3, "order_id": "a3", "side": "buy"}
+]"#;
+
+fn options() -> TableInitOptions {
+    TableInitOptions {
+        index: Some("x".to_owned()),
+        dictionaries: Some(HashMap::from([(
+            "order_id".to_owned(),
+            DictionaryOptions {
+                reserve: Some(1024),
+                max_cardinality: Some(4),
+            },
+        )])),
+        ..TableInitOptions::default()
+    }
+}
+
+#[tokio::test]
+async fn test_dictionary_stats_counts_unique_strings() -> Result<(), Box<dyn Error>> {
+    let server = perspective::server::Server::default();
+    let client = LocalClient::new(&server);
+    let table = client
+        .table(UpdateData::JsonRows(ROWS.to_owned()).into(), options())
+        .await?;
+
+    let stats = table.dictionary_stats().await?;
+    let mut columns = stats.keys().cloned().collect::<Vec<_>>();
+    columns.sort();
+    assert_eq!(columns, vec!["order_id", "side"]);
+    assert_eq!(stats["order_id"].size, 3);
+    assert_eq!(stats["order_id"].max_cardinality, Some(4));
+    assert_eq!(stats["side"].size, 2);
+    assert_eq!(stats["side"].max_cardinality, None);
+    assert!(stats["order_id"].bytes > 0);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_dictionary_stats_keep_overwritten_strings() -> Result<(), Box<dyn Error>> {
+    let server = perspective::server::Server::default();
+    let client = LocalClient::new(&server);
+    let table = client
+        .table(UpdateData::JsonRows(ROWS.to_owned()).into(), options())
+        .await?;
+
+    table
+        .update(
+            UpdateData::JsonRows(
+                r#"[{"x": 1, "order_id": "b1"}, {"x": 2, "order_id": "b2"}]"#.to_owned(),
+            ),
+            UpdateOptions::default(),
+        )
+        .await?;
+
+    assert_eq!(table.size().await?, 3);
+    let stats = table.dictionary_stats().await?;
+    assert_eq!(stats["order_id"].size, 5);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_dictionary_options_reject_non_string_column() -> Result<(), Box<dyn Error>> {
+    let server = perspective::server::Server::default();
+    let client = LocalClient::new(&server);
+    let result = client
+        .table(
+            UpdateData::JsonRows(ROWS.to_owned()).into(),
+            TableInitOptions {
+                dictionaries: Some(HashMap::from([("x".to_owned(), DictionaryOptions {
+                    reserve: Some(16),
+                    max_cardinality: None,
+                })])),
+                ..TableInitOptions::default()
+            },
+        )
+        .await;
+
+    assert!(result.is_err());
+    Ok(())
+}
@@ -0,0 +1,93 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use std::error::Error;
+
+use perspective::LocalClient;
+use perspective_client::config::ViewConfigUpdate;
+use perspective_client::{TableInitOptions, UpdateData};
+
+fn csv(rows: impl Iterator<Item = (i64, String)>) -> UpdateData {
+    let mut csv = "x,y\n".to_owned();
+    for (x, y) in rows {
+        csv.push_str(&format!("{},{}\n", x, y));
+    }
+
+    UpdateData::Csv(csv)
+}
+
+#[tokio::test]
+async fn test_downsample_keeps_endpoints_and_peaks() -> Result<(), Box<dyn Error>> {
+    let server = perspective::server::Server::default();
+    let client = LocalClient::new(&server);
+    let data = csv((0..1000).map(|x| (x, if x == 500 { 100 } else { 0 }.to_string())));
+    let table = client
+        .table(data.into(), TableInitOptions::default())
+        .await?;
+
+    let view = table.view(None).await?;
+    let series = view
+        .downsample("x".to_owned(), vec!["y".to_owned()], 10)
+        .await?;
+    let y = &series["y"];
+    assert_eq!(y.x.len(), 10);
+    assert_eq!(y.y.len(), 10);
+    assert_eq!(y.x.first(), Some(&0.0));
+    assert_eq!(y.x.last(), Some(&999.0));
+    assert!(y.x.windows(2).all(|w| w[0] < w[1]));
+    assert!(y.x.contains(&500.0));
+    assert!(y.y.contains(&100.0));
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_downsample_returns_all_points_when_small() -> Result<(), Box<dyn Error>> {
+    let server = perspective::server::Server::default();
+    let client = LocalClient::new(&server);
+    let data = csv([(3, "1"), (1, ""), (2, "4")]
+        .map(|(x, y)| (x, y.to_owned()))
+        .into_iter());
+    let table = client
+        .table(data.into(), TableInitOptions::default())
+        .await?;
+
+    let view = table.view(None).await?;
+    let series = view
+        .downsample("x".to_owned(), vec!["y".to_owned()], 10)
+        .await?;
+    assert_eq!(series["y"].x, vec![2.0, 3.0]);
+    assert_eq!(series["y"].y, vec![4.0, 1.0]);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_downsample_rejects_group_by() -> Result<(), Box<dyn Error>> {
+    let server = perspective::server::Server::default();
+    let client = LocalClient::new(&server);
+    let data = csv((0..10).map(|x| (x, x.to_string())));
+    let table = client
+        .table(data.into(), TableInitOptions::default())
+        .await?;
+
+    let view = table
+        .view(Some(ViewConfigUpdate {
+            group_by: Some(vec!["x".to_owned()]),
+            ..ViewConfigUpdate::default()
+        }))
+        .await?;
+
+    let result = view
+        .downsample("x".to_owned(), vec!["y".to_owned()], 5)
+        .await;
+    assert!(result.is_err());
+    Ok(())
+}
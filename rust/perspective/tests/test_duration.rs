@@ -0,0 +1,48 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use std::error::Error;
+
+use perspective::LocalClient;
+use perspective_client::{
+    ColumnType, TableData, TableInitOptions, UpdateData, UpdateOptions, ViewWindow,
+};
+
+#[tokio::test]
+async fn test_duration_column_accepts_ms_and_formatted_strings() -> Result<(), Box<dyn Error>> {
+    let server = perspective::server::Server::default();
+    let client = LocalClient::new(&server);
+    let table = client
+        .table(
+            TableData::Schema(vec![("elapsed".to_owned(), ColumnType::Duration)]),
+            TableInitOptions::default(),
+        )
+        .await?;
+
+    table
+        .update(
+            UpdateData::JsonRows(
+                r#"[{"elapsed": 1500}, {"elapsed": "1d 02:03:04.005"}, {"elapsed": "-00:00:01"}]"#
+                    .to_owned(),
+            ),
+            UpdateOptions::default(),
+        )
+        .await?;
+
+    let schema = table.schema().await?;
+    assert_eq!(schema.get("elapsed"), Some(&ColumnType::Duration));
+
+    let view = table.view(None).await?;
+    let json = view.to_columns_string(ViewWindow::default()).await?;
+    assert_eq!(json, r#"{"elapsed":[1500,93784005,-1000]}"#);
+    Ok(())
+}
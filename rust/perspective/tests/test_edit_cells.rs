@@ -0,0 +1,133 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use std::collections::HashMap;
+use std::error::Error;
+
+use perspective::server::Server;
+use perspective::LocalClient;
+use perspective_client::{
+    CellEdit, ClientError, ColumnHints, Table, TableInitOptions, UpdateData, ViewWindow,
+};
+
+async fn rates_table(client: &LocalClient) -> Result<Table, Box<dyn Error>> {
+    Ok(client
+        .table(
+            UpdateData::Csv("id,rate,source\nEUR,1.08,ecb\nGBP,1.27,boe".to_owned()).into(),
+            TableInitOptions {
+                index: Some("id".to_owned()),
+                column_hints: Some(HashMap::from([("source".to_owned(), ColumnHints {
+                    editable: Some(false),
+                    ..ColumnHints::default()
+                })])),
+                ..TableInitOptions::default()
+            },
+        )
+        .await?)
+}
+
+fn edit(index: &str, column: &str, value: &str) -> CellEdit {
+    CellEdit {
+        index: index.to_owned(),
+        column: column.to_owned(),
+        value: value.to_owned(),
+    }
+}
+
+#[tokio::test]
+async fn test_edit_cells_are_applied_and_logged() -> Result<(), Box<dyn Error>> {
+    let server = Server::default();
+    let client = LocalClient::new(&server);
+    let table = rates_table(&client).await?;
+    table
+        .edit_cells(
+            vec![edit(r#""EUR""#, "rate", "1.09")],
+            Some("alice".to_owned()),
+        )
+        .await?;
+
+    let view = table.view(None).await?;
+    let json = view.to_columns_string(ViewWindow::default()).await?;
+    assert_eq!(
+        json,
+        r#"{"id":["EUR","GBP"],"rate":[1.09,1.27],"source":["ecb","boe"]}"#
+    );
+
+    let log = table.edit_log(None).await?;
+    assert_eq!(log.len(), 1);
+    assert_eq!(log[0].edit, Some(edit(r#""EUR""#, "rate", "1.09")));
+    assert_eq!(log[0].previous_value, "1.08");
+    assert_eq!(log[0].user.as_deref(), Some("alice"));
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_edit_cells_rejects_read_only_columns() -> Result<(), Box<dyn Error>> {
+    let server = Server::default();
+    let client = LocalClient::new(&server);
+    let table = rates_table(&client).await?;
+    for edits in [
+        vec![
+            edit(r#""EUR""#, "rate", "1.09"),
+            edit(r#""EUR""#, "source", r#""fed""#),
+        ],
+        vec![edit(r#""EUR""#, "id", r#""USD""#)],
+        vec![edit(r#""JPY""#, "rate", "160")],
+        vec![edit(r#""EUR""#, "rate", r#""high""#)],
+    ] {
+        assert!(matches!(
+            table.edit_cells(edits, None).await,
+            Err(ClientError::EditRejected { .. })
+        ));
+    }
+
+    let view = table.view(None).await?;
+    let json = view.to_columns_string(ViewWindow::default()).await?;
+    assert_eq!(
+        json,
+        r#"{"id":["EUR","GBP"],"rate":[1.08,1.27],"source":["ecb","boe"]}"#
+    );
+
+    assert!(table.edit_log(None).await?.is_empty());
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_edit_validator() -> Result<(), Box<dyn Error>> {
+    let server = Server::default();
+    server
+        .set_edit_validator(|req| match req.user {
+            Some("alice") => Ok(()),
+            user => Err(format!("{:?} may not edit {}", user, req.table_id)),
+        })
+        .await;
+
+    let client = LocalClient::new(&server);
+    let table = rates_table(&client).await?;
+    let result = table
+        .edit_cells(
+            vec![edit(r#""GBP""#, "rate", "1.3")],
+            Some("bob".to_owned()),
+        )
+        .await;
+
+    assert!(matches!(result, Err(ClientError::EditRejected { .. })));
+    table
+        .edit_cells(
+            vec![edit(r#""GBP""#, "rate", "1.3")],
+            Some("alice".to_owned()),
+        )
+        .await?;
+
+    assert_eq!(table.edit_log(None).await?.len(), 1);
+    Ok(())
+}
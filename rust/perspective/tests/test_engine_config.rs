@@ -0,0 +1,86 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use std::collections::HashMap;
+use std::error::Error;
+
+use perspective::server::{EngineConfig, Server};
+use perspective::LocalClient;
+use perspective_client::config::{Expressions, ViewConfigUpdate};
+use perspective_client::{TableInitOptions, UpdateData, UpdateOptions, ViewWindow};
+
+const ROWS: &str = r#"[
+    {"x": 1, "side": "buy"},
+    {"x": 2, "side": "sell"},
+    {"x": 3, "side": "buy"}
+]"#;
+
+async fn group_by_columns(server: &Server) -> Result<String, Box<dyn Error>> {
+    let client = LocalClient::new(server);
+    let table = client
+        .table(
+            UpdateData::JsonRows(ROWS.to_owned()).into(),
+            TableInitOptions::default(),
+        )
+        .await?;
+
+    table
+        .update(
+            UpdateData::JsonRows(r#"[{"x": 4, "side": "sell"}]"#.to_owned()),
+            UpdateOptions::default(),
+        )
+        .await?;
+
+    let view = table
+        .view(Some(ViewConfigUpdate {
+            group_by: Some(vec!["side".to_owned()]),
+            columns: Some(vec![Some("x".to_owned()), Some("y".to_owned())]),
+            expressions: Some(Expressions(HashMap::from([(
+                "y".to_owned(),
+                r#""x" * 2"#.to_owned(),
+            )]))),
+            ..ViewConfigUpdate::default()
+        }))
+        .await?;
+
+    Ok(view.to_columns_string(ViewWindow::default()).await?)
+}
+
+#[tokio::test]
+async fn test_engine_config_does_not_change_results() -> Result<(), Box<dyn Error>> {
+    let tuned = Server::builder()
+        .with_engine_config(
+            EngineConfig::default()
+                .with_parallel_threshold(u64::MAX)
+                .with_expression_block_size(2)
+                .with_tree_capacity(1)
+                .with_port_capacity(1),
+        )
+        .build()?;
+
+    let tuned_json = group_by_columns(&tuned).await?;
+    let server = Server::builder()
+        .with_engine_config(EngineConfig::default())
+        .build()?;
+
+    assert_eq!(tuned_json, group_by_columns(&server).await?);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_engine_config_rejects_zero_sizes() {
+    let result = Server::builder()
+        .with_engine_config(EngineConfig::default().with_tree_capacity(0))
+        .build();
+
+    assert!(result.is_err());
+}
@@ -0,0 +1,55 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use std::error::Error;
+
+use perspective::server::Server;
+use perspective::LocalClient;
+use perspective_client::config::ViewConfigUpdate;
+use perspective_client::{ClientError, TableInitOptions, UpdateData};
+
+#[tokio::test]
+async fn test_invalid_view_config_is_view_config_error() -> Result<(), Box<dyn Error>> {
+    let server = Server::default();
+    let client = LocalClient::new(&server);
+    let table = client
+        .table(
+            UpdateData::Csv("x,y\n1,2".to_owned()).into(),
+            TableInitOptions::default(),
+        )
+        .await?;
+
+    let config = ViewConfigUpdate {
+        group_by: Some(vec!["z".to_owned()]),
+        ..ViewConfigUpdate::default()
+    };
+
+    match table.view(Some(config)).await {
+        Err(ClientError::ViewConfig {
+            message,
+            request_id,
+        }) => {
+            assert!(message.contains("Invalid column 'z'"));
+            assert!(!request_id.is_empty());
+        },
+        x => panic!("Expected a view config error, got {:?}", x.map(|_| ())),
+    }
+
+    // Other failures remain internal errors.
+    table.view(None).await?;
+    assert!(matches!(
+        table.delete().await,
+        Err(ClientError::Internal { .. })
+    ));
+    client.close().await;
+    Ok(())
+}
@@ -0,0 +1,142 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use std::error::Error;
+
+use perspective::client::{Client, Table, TableInitOptions, UpdateData, UpdateOptions};
+use perspective::server::Server;
+use perspective::LocalClient;
+
+async fn exclusive_table(client: &Client) -> Result<Table, Box<dyn Error>> {
+    let table = client
+        .table(
+            UpdateData::Csv("x,y\n1,a\n2,b".to_owned()).into(),
+            TableInitOptions {
+                name: Some("prices".to_owned()),
+                index: Some("x".to_owned()),
+                exclusive_writer: Some(true),
+                ..TableInitOptions::default()
+            },
+        )
+        .await?;
+
+    Ok(table)
+}
+
+async fn update(table: &Table) -> Result<(), Box<dyn Error>> {
+    table
+        .update(
+            UpdateData::Csv("x,y\n3,c".to_owned()),
+            UpdateOptions::default(),
+        )
+        .await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_exclusive_writer_rejects_other_sessions() -> Result<(), Box<dyn Error>> {
+    let server = Server::default();
+    let writer = LocalClient::new(&server);
+    let reader = LocalClient::new(&server);
+    let table = exclusive_table(&writer).await?;
+    let other = reader.open_table("prices".to_owned()).await?;
+    assert!(update(&other).await.is_err());
+    assert!(other
+        .remove(UpdateData::JsonRows("[1]".to_owned()))
+        .await
+        .is_err());
+
+    assert!(other
+        .replace(UpdateData::Csv("x,y\n9,z".to_owned()))
+        .await
+        .is_err());
+
+    assert!(other.delete().await.is_err());
+
+    // The table is unchanged, and still readable from the other session.
+    assert_eq!(other.size().await?, 2);
+    update(&table).await?;
+    assert_eq!(other.size().await?, 3);
+    writer.close().await;
+    reader.close().await;
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_take_writer_transfers_writer() -> Result<(), Box<dyn Error>> {
+    let server = Server::default();
+    let writer = LocalClient::new(&server);
+    let standby = LocalClient::new(&server);
+    let table = exclusive_table(&writer).await?;
+    let other = standby.open_table("prices".to_owned()).await?;
+    assert!(update(&other).await.is_err());
+    other.take_writer().await?;
+    update(&other).await?;
+    assert!(update(&table).await.is_err());
+    assert_eq!(table.size().await?, 3);
+    writer.close().await;
+    standby.close().await;
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_take_writer_requires_exclusive_writer() -> Result<(), Box<dyn Error>> {
+    let server = Server::default();
+    let client = LocalClient::new(&server);
+    let table = client
+        .table(
+            UpdateData::Csv("x\n1".to_owned()).into(),
+            TableInitOptions::default(),
+        )
+        .await?;
+
+    assert!(table.take_writer().await.is_err());
+    client.close().await;
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_closed_writer_is_claimed_by_next_session() -> Result<(), Box<dyn Error>> {
+    let server = Server::default();
+    let writer = LocalClient::new(&server);
+    let first = LocalClient::new(&server);
+    let second = LocalClient::new(&server);
+    exclusive_table(&writer).await?;
+    let first_table = first.open_table("prices".to_owned()).await?;
+    let second_table = second.open_table("prices".to_owned()).await?;
+    writer.close().await;
+    update(&first_table).await?;
+    assert!(update(&second_table).await.is_err());
+    assert_eq!(second_table.size().await?, 3);
+    first.close().await;
+    second.close().await;
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_exclusive_writer_rejects_rename_by_other_session() -> Result<(), Box<dyn Error>> {
+    let server = Server::default();
+    let writer = LocalClient::new(&server);
+    let reader = LocalClient::new(&server);
+    let mut table = exclusive_table(&writer).await?;
+    let mut other = reader.open_table("prices".to_owned()).await?;
+    assert!(other.rename("stolen".to_owned()).await.is_err());
+    assert_eq!(other.get_name(), "prices");
+    assert_eq!(reader.get_hosted_table_names().await?, vec!["prices"]);
+    table.rename("prices_2024".to_owned()).await?;
+    assert_eq!(reader.get_hosted_table_names().await?, vec!["prices_2024"]);
+
+    writer.close().await;
+    reader.close().await;
+    Ok(())
+}
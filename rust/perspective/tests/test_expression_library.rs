@@ -0,0 +1,86 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use std::collections::HashMap;
+use std::error::Error;
+
+use perspective::server::Server;
+use perspective::LocalClient;
+use perspective_client::config::ViewConfigUpdate;
+use perspective_client::{Table, TableInitOptions, UpdateData, ViewWindow};
+
+async fn quotes_table(client: &LocalClient, csv: &str) -> Result<Table, Box<dyn Error>> {
+    Ok(client
+        .table(
+            UpdateData::Csv(csv.to_owned()).into(),
+            TableInitOptions::default(),
+        )
+        .await?)
+}
+
+fn columns(names: &[&str]) -> Option<ViewConfigUpdate> {
+    Some(ViewConfigUpdate {
+        columns: Some(names.iter().map(|x| Some((*x).to_owned())).collect()),
+        ..ViewConfigUpdate::default()
+    })
+}
+
+#[tokio::test]
+async fn test_library_expressions_are_referenced_by_name() -> Result<(), Box<dyn Error>> {
+    let server = Server::default();
+    server
+        .define_expression("mid", r#"("bid" + "ask") / 2"#)
+        .await?;
+
+    let client = LocalClient::new(&server);
+    assert_eq!(
+        client.server_expressions().await?,
+        HashMap::from([("mid".to_owned(), r#"("bid" + "ask") / 2"#.to_owned())])
+    );
+
+    let table = quotes_table(&client, "bid,ask\n99,101\n199,201").await?;
+    let view = table.view(columns(&["bid", "mid"])).await?;
+    let json = view.to_columns_string(ViewWindow::default()).await?;
+    assert_eq!(json, r#"{"bid":[99,199],"mid":[100.0,200.0]}"#);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_table_columns_shadow_library_expressions() -> Result<(), Box<dyn Error>> {
+    let server = Server::default();
+    server
+        .define_expression("mid", r#"("bid" + "ask") / 2"#)
+        .await?;
+
+    let client = LocalClient::new(&server);
+    let table = quotes_table(&client, "bid,ask,mid\n99,101,1").await?;
+    let view = table.view(columns(&["mid"])).await?;
+    let json = view.to_columns_string(ViewWindow::default()).await?;
+    assert_eq!(json, r#"{"mid":[1]}"#);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_removed_library_expressions_are_unknown() -> Result<(), Box<dyn Error>> {
+    let server = Server::default();
+    server
+        .define_expression("mid", r#"("bid" + "ask") / 2"#)
+        .await?;
+
+    server.remove_expression("mid").await;
+    let client = LocalClient::new(&server);
+    assert!(client.server_expressions().await?.is_empty());
+
+    let table = quotes_table(&client, "bid,ask\n99,101").await?;
+    assert!(table.view(columns(&["mid"])).await.is_err());
+    Ok(())
+}
@@ -0,0 +1,69 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use std::collections::HashMap;
+use std::error::Error;
+
+use perspective::client::config::{Expressions, ViewConfigUpdate};
+use perspective::client::{TableInitOptions, UpdateData, ViewWindow};
+use perspective::server::Server;
+use perspective::LocalClient;
+
+fn doubled() -> Expressions {
+    Expressions(HashMap::from([("y".to_owned(), r#""x" * 2"#.to_owned())]))
+}
+
+/// Expression views and validation agree with the `expressions` the server
+/// reports, which is `false` only when the engine is built without the
+/// `expressions` feature.
+#[tokio::test]
+async fn test_expressions_match_reported_feature() -> Result<(), Box<dyn Error>> {
+    let server = Server::default();
+    let client = LocalClient::new(&server);
+    let table = client
+        .table(
+            UpdateData::Csv("x\n1\n2".to_owned()).into(),
+            TableInitOptions::default(),
+        )
+        .await?;
+
+    let features = table.get_features()?;
+    let validated = table.validate_expressions(doubled()).await?;
+    let view = table
+        .view(Some(ViewConfigUpdate {
+            columns: Some(vec![Some("y".to_owned())]),
+            expressions: Some(doubled()),
+            ..ViewConfigUpdate::default()
+        }))
+        .await;
+
+    if features.expressions {
+        assert!(validated.errors.is_empty());
+        assert_eq!(
+            view?.to_columns_string(ViewWindow::default()).await?,
+            r#"{"y":[2,4]}"#
+        );
+    } else {
+        assert!(validated.errors.contains_key("y"));
+        assert!(view.is_err());
+    }
+
+    // Tables without expressions are unaffected either way.
+    let plain = table.view(None).await?;
+    assert_eq!(
+        plain.to_columns_string(ViewWindow::default()).await?,
+        r#"{"x":[1,2]}"#
+    );
+
+    client.close().await;
+    Ok(())
+}
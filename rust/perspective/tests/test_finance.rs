@@ -0,0 +1,154 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use std::collections::HashMap;
+use std::error::Error;
+
+use perspective::LocalClient;
+use perspective_client::config::{Expressions, ViewConfigUpdate};
+use perspective_client::{TableInitOptions, UpdateData, ViewWindow};
+
+async fn eval_columns(rows: &str, exprs: &[(&str, &str)]) -> Result<String, Box<dyn Error>> {
+    let server = perspective::server::Server::default();
+    let client = LocalClient::new(&server);
+    let table = client
+        .table(
+            UpdateData::JsonRows(rows.to_owned()).into(),
+            TableInitOptions::default(),
+        )
+        .await?;
+
+    let expressions = Expressions(HashMap::from_iter(
+        exprs
+            .iter()
+            .map(|(name, expr)| (name.to_string(), expr.to_string())),
+    ));
+
+    let view = table
+        .view(Some(ViewConfigUpdate {
+            columns: Some(
+                exprs
+                    .iter()
+                    .map(|(name, _)| Some(name.to_string()))
+                    .collect(),
+            ),
+            expressions: Some(expressions),
+            ..ViewConfigUpdate::default()
+        }))
+        .await?;
+
+    Ok(view.to_columns_string(ViewWindow::default()).await?)
+}
+
+#[tokio::test]
+async fn test_round_to_tick_and_bps() -> Result<(), Box<dyn Error>> {
+    let json = eval_columns(r#"[{"x": 1.24, "base": 1.0}, {"x": 1.5, "base": 0.0}]"#, &[
+        ("tick", r#"round_to_tick("x", 0.05)"#),
+        ("bps", r#"round(bps("x", "base"))"#),
+    ])
+    .await?;
+
+    assert_eq!(json, r#"{"tick":[1.25,1.5],"bps":[2400.0,null]}"#);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_time_value_of_money() -> Result<(), Box<dyn Error>> {
+    let json = eval_columns(r#"[{"rate": 0.0}, {"rate": 0.05}]"#, &[
+        ("pv", r#"round(pv("rate", 10, -100, 0))"#),
+        ("fv", r#"round(fv("rate", 10, -100, 0))"#),
+        (
+            "roundtrip",
+            r#"abs(rate(10, -100, pv("rate", 10, -100, 0), 0) - "rate") < 1e-9"#,
+        ),
+    ])
+    .await?;
+
+    assert_eq!(
+        json,
+        r#"{"pv":[1000.0,772.0],"fv":[1000.0,1258.0],"roundtrip":[true,true]}"#
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_irr() -> Result<(), Box<dyn Error>> {
+    let json = eval_columns(r#"[{"flows": [-100, 110]}, {"flows": [100, 10]}]"#, &[
+        ("list", r#"round(irr("flows") * 1000)"#),
+        ("scalars", r#"round(irr(-100, 60, 60) * 1000000)"#),
+    ])
+    .await?;
+
+    assert_eq!(
+        json,
+        r#"{"list":[100.0,null],"scalars":[130662.0,130662.0]}"#
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_year_frac() -> Result<(), Box<dyn Error>> {
+    let json = eval_columns(r#"[{"x": 1}]"#, &[
+        (
+            "act_360",
+            "round(year_frac(date(2024, 1, 1), date(2025, 1, 1), 'act/360') * 360)",
+        ),
+        (
+            "act_365",
+            "round(year_frac(date(2024, 1, 1), date(2025, 1, 1), 'act/365') * 365)",
+        ),
+        (
+            "thirty_360",
+            "round(year_frac(date(2024, 1, 31), date(2024, 3, 31), '30/360') * 360)",
+        ),
+        (
+            "act_act",
+            "year_frac(date(2024, 1, 1), date(2025, 1, 1), 'act/act')",
+        ),
+        (
+            "negative",
+            "year_frac(date(2025, 1, 1), date(2024, 1, 1), 'act/act')",
+        ),
+    ])
+    .await?;
+
+    assert_eq!(
+        json,
+        r#"{"act_360":[366.0],"act_365":[366.0],"thirty_360":[60.0],"act_act":[1.0],"negative":[-1.0]}"#
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_year_frac_rejects_unknown_basis() -> Result<(), Box<dyn Error>> {
+    let server = perspective::server::Server::default();
+    let client = LocalClient::new(&server);
+    let table = client
+        .table(
+            UpdateData::JsonRows(r#"[{"x": 1}]"#.to_owned()).into(),
+            TableInitOptions::default(),
+        )
+        .await?;
+
+    let expressions = Expressions(HashMap::from([(
+        "bad".to_owned(),
+        "year_frac(date(2024, 1, 1), date(2025, 1, 1), 'act/364')".to_owned(),
+    )]));
+
+    let result = table.validate_expressions(expressions).await?;
+    assert!(result.errors.contains_key("bad"));
+    Ok(())
+}
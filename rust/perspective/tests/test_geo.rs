@@ -0,0 +1,106 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use std::collections::HashMap;
+use std::error::Error;
+
+use perspective::LocalClient;
+use perspective_client::config::{Expressions, ViewConfigUpdate};
+use perspective_client::{TableInitOptions, UpdateData, ViewWindow};
+
+const ROWS: &str = r#"[
+    {"city": "London", "lat": 51.5074, "lon": -0.1278},
+    {"city": "Paris", "lat": 48.8566, "lon": 2.3522},
+    {"city": "New York", "lat": 40.7128, "lon": -74.006},
+    {"city": "Greenwich", "lat": 51.4769, "lon": -0.0005}
+]"#;
+
+#[tokio::test]
+async fn test_haversine_distance_and_within_bbox() -> Result<(), Box<dyn Error>> {
+    let server = perspective::server::Server::default();
+    let client = LocalClient::new(&server);
+    let table = client
+        .table(
+            UpdateData::JsonRows(ROWS.to_owned()).into(),
+            TableInitOptions::default(),
+        )
+        .await?;
+
+    let expressions = Expressions(HashMap::from([
+        (
+            "near_paris".to_owned(),
+            r#"haversine_distance("lat", "lon", 48.8566, 2.3522) < 500"#.to_owned(),
+        ),
+        (
+            "in_europe".to_owned(),
+            r#"within_bbox("lat", "lon", 35, -10, 60, 30)"#.to_owned(),
+        ),
+        (
+            "across_antimeridian".to_owned(),
+            r#"within_bbox("lat", "lon", -90, 170, 90, -60)"#.to_owned(),
+        ),
+    ]));
+
+    let view = table
+        .view(Some(ViewConfigUpdate {
+            columns: Some(vec![
+                Some("near_paris".to_owned()),
+                Some("in_europe".to_owned()),
+                Some("across_antimeridian".to_owned()),
+            ]),
+            expressions: Some(expressions),
+            ..ViewConfigUpdate::default()
+        }))
+        .await?;
+
+    let json = view.to_columns_string(ViewWindow::default()).await?;
+    assert_eq!(
+        json,
+        r#"{"near_paris":[true,true,false,true],"in_europe":[true,true,false,true],"across_antimeridian":[false,false,true,false]}"#
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_geohash_group_by() -> Result<(), Box<dyn Error>> {
+    let server = perspective::server::Server::default();
+    let client = LocalClient::new(&server);
+    let table = client
+        .table(
+            UpdateData::JsonRows(ROWS.to_owned()).into(),
+            TableInitOptions::default(),
+        )
+        .await?;
+
+    let expressions = Expressions(HashMap::from([(
+        "cell".to_owned(),
+        r#"geohash("lat", "lon", 3)"#.to_owned(),
+    )]));
+
+    let view = table
+        .view(Some(ViewConfigUpdate {
+            group_by: Some(vec!["cell".to_owned()]),
+            columns: Some(vec![Some("city".to_owned())]),
+            expressions: Some(expressions),
+            ..ViewConfigUpdate::default()
+        }))
+        .await?;
+
+    let json = view.to_columns_string(ViewWindow::default()).await?;
+    assert_eq!(
+        json,
+        r#"{"__ROW_PATH__":[[],["dr5"],["gcp"],["u09"]],"city":[4,1,2,1]}"#
+    );
+
+    Ok(())
+}
@@ -0,0 +1,136 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use std::collections::HashMap;
+use std::error::Error;
+
+use perspective::LocalClient;
+use perspective_client::config::{Expressions, ViewConfigUpdate};
+use perspective_client::{TableInitOptions, UpdateData, UpdateOptions, ViewWindow};
+
+const ROWS: &str = r#"[
+    {"g": "a", "x": 1},
+    {"g": "a", "x": 3},
+    {"g": "b", "x": 2},
+    {"g": "b", "x": 2},
+    {"g": "b", "x": 6}
+]"#;
+
+fn expressions() -> Expressions {
+    Expressions(HashMap::from([
+        (
+            "zscore".to_owned(),
+            "round(zscore('x', 'g') * 1000)".to_owned(),
+        ),
+        (
+            "of_group".to_owned(),
+            "round(percent_of_group('x', 'g'))".to_owned(),
+        ),
+        (
+            "of_total".to_owned(),
+            "round(percent_of_total('x'))".to_owned(),
+        ),
+        ("rank".to_owned(), "rank_in_group('x', 'g')".to_owned()),
+    ]))
+}
+
+#[tokio::test]
+async fn test_group_stat_functions() -> Result<(), Box<dyn Error>> {
+    let server = perspective::server::Server::default();
+    let client = LocalClient::new(&server);
+    let table = client
+        .table(
+            UpdateData::JsonRows(ROWS.to_owned()).into(),
+            TableInitOptions::default(),
+        )
+        .await?;
+
+    let view = table
+        .view(Some(ViewConfigUpdate {
+            columns: Some(vec![
+                Some("zscore".to_owned()),
+                Some("of_group".to_owned()),
+                Some("of_total".to_owned()),
+                Some("rank".to_owned()),
+            ]),
+            expressions: Some(expressions()),
+            ..ViewConfigUpdate::default()
+        }))
+        .await?;
+
+    let json = view.to_columns_string(ViewWindow::default()).await?;
+    assert_eq!(
+        json,
+        r#"{"zscore":[-1000.0,1000.0,-707.0,-707.0,1414.0],"of_group":[25.0,75.0,20.0,20.0,60.0],"of_total":[7.0,21.0,14.0,14.0,43.0],"rank":[2,1,2,2,1]}"#
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_group_stats_cover_the_whole_table_after_update() -> Result<(), Box<dyn Error>> {
+    let server = perspective::server::Server::default();
+    let client = LocalClient::new(&server);
+    let table = client
+        .table(
+            UpdateData::JsonRows(ROWS.to_owned()).into(),
+            TableInitOptions::default(),
+        )
+        .await?;
+
+    let view = table
+        .view(Some(ViewConfigUpdate {
+            columns: Some(vec![Some("of_group".to_owned()), Some("rank".to_owned())]),
+            expressions: Some(expressions()),
+            ..ViewConfigUpdate::default()
+        }))
+        .await?;
+
+    table
+        .update(
+            UpdateData::JsonRows(r#"[{"g": "a", "x": 5}]"#.to_owned()),
+            UpdateOptions::default(),
+        )
+        .await?;
+
+    let json = view.to_columns_string(ViewWindow::default()).await?;
+    assert_eq!(
+        json,
+        r#"{"of_group":[11.0,33.0,20.0,20.0,60.0,56.0],"rank":[3,2,2,2,1,1]}"#
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_group_stat_rejects_unknown_columns() -> Result<(), Box<dyn Error>> {
+    let server = perspective::server::Server::default();
+    let client = LocalClient::new(&server);
+    let table = client
+        .table(
+            UpdateData::JsonRows(ROWS.to_owned()).into(),
+            TableInitOptions::default(),
+        )
+        .await?;
+
+    let expressions = Expressions(HashMap::from([
+        ("missing".to_owned(), "percent_of_total('y')".to_owned()),
+        ("not_numeric".to_owned(), "zscore('g')".to_owned()),
+        ("no_group".to_owned(), "rank_in_group('x')".to_owned()),
+    ]));
+
+    let result = table.validate_expressions(expressions).await?;
+    assert!(result.errors.contains_key("missing"));
+    assert!(result.errors.contains_key("not_numeric"));
+    assert!(result.errors.contains_key("no_group"));
+    Ok(())
+}
@@ -0,0 +1,71 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+use std::error::Error;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+use perspective::client::{TableInitOptions, UpdateData};
+use perspective::server::Server;
+use perspective::LocalClient;
+
+fn named(name: &str) -> TableInitOptions {
+    TableInitOptions {
+        name: Some(name.to_owned()),
+        ..TableInitOptions::default()
+    }
+}
+
+#[tokio::test]
+async fn test_on_hosted_tables_update() -> Result<(), Box<dyn Error>> {
+    let server = Server::default();
+    let client = LocalClient::new(&server);
+    let observer = LocalClient::new(&server);
+    let count = Arc::new(AtomicUsize::default());
+    let id = observer
+        .on_hosted_tables_update({
+            let count = count.clone();
+            move || {
+                count.fetch_add(1, Ordering::SeqCst);
+                async {}
+            }
+        })
+        .await?;
+
+    assert_eq!(count.load(Ordering::SeqCst), 0);
+    let mut table = client
+        .table(UpdateData::Csv("x\n1".to_owned()).into(), named("a"))
+        .await?;
+
+    assert_eq!(count.load(Ordering::SeqCst), 1);
+    client
+        .table(UpdateData::Csv("x\n1".to_owned()).into(), named("b"))
+        .await?;
+
+    assert_eq!(count.load(Ordering::SeqCst), 2);
+    table.rename("c".to_owned()).await?;
+    assert_eq!(count.load(Ordering::SeqCst), 3);
+    table.delete().await?;
+    assert_eq!(count.load(Ordering::SeqCst), 4);
+    server.unhost("b").await?;
+    assert_eq!(count.load(Ordering::SeqCst), 5);
+    assert!(observer.get_hosted_table_names().await?.is_empty());
+
+    observer.remove_hosted_tables_update(id).await?;
+    client
+        .table(UpdateData::Csv("x\n1".to_owned()).into(), named("d"))
+        .await?;
+
+    assert_eq!(count.load(Ordering::SeqCst), 5);
+    client.close().await;
+    observer.close().await;
+    Ok(())
+}
@@ -0,0 +1,82 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use std::collections::HashMap;
+use std::error::Error;
+
+use perspective::LocalClient;
+use perspective_client::config::{Expressions, ViewConfigUpdate};
+use perspective_client::{TableInitOptions, UpdateData, UpdateOptions, ViewWindow};
+
+#[tokio::test]
+async fn test_expressions_follow_partial_updates_and_removes() -> Result<(), Box<dyn Error>> {
+    let server = perspective::server::Server::default();
+    let client = LocalClient::new(&server);
+    let table = client
+        .table(
+            UpdateData::JsonRows(
+                r#"[
+                    {"id": 1, "x": 1, "y": 1, "name": "a"},
+                    {"id": 2, "x": 2, "y": 2, "name": "b"},
+                    {"id": 3, "x": 3, "y": 3, "name": "c"},
+                    {"id": 4, "x": 4, "y": 4, "name": "d"}
+                ]"#
+                .to_owned(),
+            )
+            .into(),
+            TableInitOptions {
+                index: Some("id".to_owned()),
+                ..TableInitOptions::default()
+            },
+        )
+        .await?;
+
+    let view = table
+        .view(Some(ViewConfigUpdate {
+            columns: Some(vec![
+                Some("id".to_owned()),
+                Some("sum".to_owned()),
+                Some("label".to_owned()),
+                Some("share".to_owned()),
+            ]),
+            expressions: Some(Expressions(HashMap::from([
+                ("sum".to_owned(), r#""x" + "y""#.to_owned()),
+                ("label".to_owned(), r#"upper("name")"#.to_owned()),
+                ("share".to_owned(), "percent_of_total('x')".to_owned()),
+            ]))),
+            ..ViewConfigUpdate::default()
+        }))
+        .await?;
+
+    table
+        .update(
+            UpdateData::JsonRows(
+                r#"[{"id": 2, "x": 10}, {"id": 5, "x": 1, "y": 1, "name": "e"}]"#.to_owned(),
+            ),
+            UpdateOptions::default(),
+        )
+        .await?;
+
+    table
+        .remove(UpdateData::JsonRows(r#"[{"id": 3}]"#.to_owned()))
+        .await?;
+
+    // Rows the updates did not touch keep their values, while `share` reads
+    // the whole table and changes for every row.
+    let json = view.to_columns_string(ViewWindow::default()).await?;
+    assert_eq!(
+        json,
+        r#"{"id":[1,2,4,5],"sum":[2.0,12.0,8.0,2.0],"label":["A","B","D","E"],"share":[6.25,62.5,25.0,6.25]}"#
+    );
+
+    Ok(())
+}
@@ -0,0 +1,153 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use std::collections::HashMap;
+use std::error::Error;
+
+use perspective::LocalClient;
+use perspective_client::config::{Expressions, Filter, FilterTerm, Scalar, ViewConfigUpdate};
+use perspective_client::{ColumnType, TableInitOptions, UpdateData, ViewWindow};
+use serde_json::{json, Value};
+
+const ROWS: &str = r#"[
+    {"x": 1},
+    {"x": 9007199254740992},
+    {"x": 9007199254740993},
+    {"x": -3000000000}
+]"#;
+
+#[tokio::test]
+async fn test_int32_overflow_promotes_to_int64() -> Result<(), Box<dyn Error>> {
+    let server = perspective::server::Server::default();
+    let client = LocalClient::new(&server);
+    let table = client
+        .table(
+            UpdateData::JsonRows(ROWS.to_owned()).into(),
+            TableInitOptions::default(),
+        )
+        .await?;
+
+    assert_eq!(table.schema().await?.get("x"), Some(&ColumnType::Integer));
+    let view = table.view(None).await?;
+    let json = view.to_columns_string(ViewWindow::default()).await?;
+    assert_eq!(
+        serde_json::from_str::<Value>(&json)?,
+        json!({"x": [1, "9007199254740992", "9007199254740993", -3000000000_i64]})
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_int64_filter_is_exact() -> Result<(), Box<dyn Error>> {
+    let server = perspective::server::Server::default();
+    let client = LocalClient::new(&server);
+    let table = client
+        .table(
+            UpdateData::JsonRows(ROWS.to_owned()).into(),
+            TableInitOptions::default(),
+        )
+        .await?;
+
+    let filter = vec![Filter::new(
+        "x".to_owned(),
+        "==".to_owned(),
+        FilterTerm::Scalar(Scalar::Int64(9007199254740993)),
+    )];
+
+    let view = table
+        .view(Some(ViewConfigUpdate {
+            filter: Some(filter.clone()),
+            ..ViewConfigUpdate::default()
+        }))
+        .await?;
+
+    let json = view.to_columns_string(ViewWindow::default()).await?;
+    assert_eq!(json, r#"{"x":["9007199254740993"]}"#);
+    assert_eq!(view.get_config().await?.filter, filter);
+    Ok(())
+}
+
+#[test]
+fn test_int64_scalar_serde_round_trip() -> Result<(), Box<dyn Error>> {
+    let filter = vec![
+        Filter::new(
+            "x".to_owned(),
+            "==".to_owned(),
+            FilterTerm::Scalar(Scalar::Int64(-9007199254740993)),
+        ),
+        Filter::new(
+            "x".to_owned(),
+            "in".to_owned(),
+            FilterTerm::Array(vec![Scalar::Uint64(u64::MAX), Scalar::Float(1.0)]),
+        ),
+    ];
+
+    let config = ViewConfigUpdate {
+        filter: Some(filter.clone()),
+        ..ViewConfigUpdate::default()
+    };
+
+    let json = serde_json::to_value(&config)?;
+    assert_eq!(
+        json["filter"],
+        json!([
+            ["x", "==", {"int64": "-9007199254740993"}],
+            ["x", "in", [{"uint64": "18446744073709551615"}, 1.0]]
+        ])
+    );
+
+    let config: ViewConfigUpdate = serde_json::from_str(&serde_json::to_string(&config)?)?;
+    assert_eq!(config.filter, Some(filter));
+
+    // Bare numbers remain `Float`.
+    let term: FilterTerm = serde_json::from_str("9007199254740993")?;
+    assert_eq!(term, FilterTerm::Scalar(Scalar::Float(9007199254740992.0)));
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_int64_expression_arithmetic_is_exact() -> Result<(), Box<dyn Error>> {
+    let server = perspective::server::Server::default();
+    let client = LocalClient::new(&server);
+    let table = client
+        .table(
+            UpdateData::JsonRows(ROWS.to_owned()).into(),
+            TableInitOptions::default(),
+        )
+        .await?;
+
+    let view = table
+        .view(Some(ViewConfigUpdate {
+            columns: Some(vec![Some("sum".to_owned()), Some("product".to_owned())]),
+            expressions: Some(Expressions(HashMap::from([
+                ("sum".to_owned(), r#""x" + "x""#.to_owned()),
+                ("product".to_owned(), r#""x" * "x""#.to_owned()),
+            ]))),
+            ..ViewConfigUpdate::default()
+        }))
+        .await?;
+
+    let schema = view.expression_schema().await?;
+    assert_eq!(schema.get("sum"), Some(&ColumnType::Integer));
+    let json = view.to_columns_string(ViewWindow::default()).await?;
+    assert_eq!(
+        serde_json::from_str::<Value>(&json)?,
+        json!({
+            "sum": [2, "18014398509481984", "18014398509481986", -6000000000_i64],
+            // `9007199254740992 * 9007199254740992` overflows to null.
+            "product": [1, null, null, "9000000000000000000"]
+        })
+    );
+
+    Ok(())
+}
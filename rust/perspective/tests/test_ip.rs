@@ -0,0 +1,133 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use std::collections::HashMap;
+use std::error::Error;
+
+use perspective::LocalClient;
+use perspective_client::config::{Expressions, Filter, FilterTerm, Scalar, ViewConfigUpdate};
+use perspective_client::{
+    ColumnType, TableData, TableInitOptions, UpdateData, UpdateOptions, ViewWindow,
+};
+
+async fn ip_table(client: &LocalClient) -> Result<perspective_client::Table, Box<dyn Error>> {
+    let table = client
+        .table(
+            TableData::Schema(vec![
+                ("host".to_owned(), ColumnType::String),
+                ("ip".to_owned(), ColumnType::Ip),
+            ]),
+            TableInitOptions::default(),
+        )
+        .await?;
+
+    table
+        .update(
+            UpdateData::JsonRows(
+                r#"[
+                    {"host": "a", "ip": "10.1.2.3"},
+                    {"host": "b", "ip": "192.168.1.10"},
+                    {"host": "c", "ip": "2001:0DB8:0000::0001"},
+                    {"host": "d", "ip": "8.8.8.8"}
+                ]"#
+                .to_owned(),
+            ),
+            UpdateOptions::default(),
+        )
+        .await?;
+
+    Ok(table)
+}
+
+#[tokio::test]
+async fn test_ip_column_normalizes_addresses() -> Result<(), Box<dyn Error>> {
+    let server = perspective::server::Server::default();
+    let client = LocalClient::new(&server);
+    let table = ip_table(&client).await?;
+    assert_eq!(table.schema().await?.get("ip"), Some(&ColumnType::Ip));
+
+    let view = table.view(None).await?;
+    let json = view.to_columns_string(ViewWindow::default()).await?;
+    assert_eq!(
+        json,
+        r#"{"host":["a","b","c","d"],"ip":["10.1.2.3","192.168.1.10","2001:db8::1","8.8.8.8"]}"#
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_ip_column_in_subnet_filter() -> Result<(), Box<dyn Error>> {
+    let server = perspective::server::Server::default();
+    let client = LocalClient::new(&server);
+    let table = ip_table(&client).await?;
+    for (subnet, expected) in [
+        ("10.0.0.0/8", r#"{"host":["a"]}"#),
+        ("192.168.0.0/16", r#"{"host":["b"]}"#),
+        ("2001:db8::/32", r#"{"host":["c"]}"#),
+        ("0.0.0.0/0", r#"{"host":["a","b","d"]}"#),
+    ] {
+        let view = table
+            .view(Some(ViewConfigUpdate {
+                columns: Some(vec![Some("host".to_owned())]),
+                filter: Some(vec![Filter::new(
+                    "ip".to_owned(),
+                    "in subnet".to_owned(),
+                    FilterTerm::Scalar(Scalar::String(subnet.to_owned())),
+                )]),
+                ..ViewConfigUpdate::default()
+            }))
+            .await?;
+
+        let json = view.to_columns_string(ViewWindow::default()).await?;
+        assert_eq!(json, expected);
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_in_subnet_expression() -> Result<(), Box<dyn Error>> {
+    let server = perspective::server::Server::default();
+    let client = LocalClient::new(&server);
+    let table = ip_table(&client).await?;
+    let view = table
+        .view(Some(ViewConfigUpdate {
+            columns: Some(vec![Some("private".to_owned())]),
+            expressions: Some(Expressions(HashMap::from([(
+                "private".to_owned(),
+                r#"in_subnet("ip", '192.168.0.0/16')"#.to_owned(),
+            )]))),
+            ..ViewConfigUpdate::default()
+        }))
+        .await?;
+
+    let json = view.to_columns_string(ViewWindow::default()).await?;
+    assert_eq!(json, r#"{"private":[false,true,false,false]}"#);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_ip_column_rejects_invalid_address() -> Result<(), Box<dyn Error>> {
+    let server = perspective::server::Server::default();
+    let client = LocalClient::new(&server);
+    let table = ip_table(&client).await?;
+    let result = table
+        .update(
+            UpdateData::JsonRows(r#"[{"host": "e", "ip": "10.0.0.256"}]"#.to_owned()),
+            UpdateOptions::default(),
+        )
+        .await;
+
+    assert!(result.is_err());
+    Ok(())
+}
@@ -0,0 +1,118 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use std::collections::HashMap;
+use std::error::Error;
+
+use perspective::LocalClient;
+use perspective_client::config::{Expressions, ViewConfigUpdate};
+use perspective_client::{
+    ColumnType, TableData, TableInitOptions, UpdateData, UpdateOptions, ViewWindow,
+};
+
+#[tokio::test]
+async fn test_json_column_keeps_documents_whole() -> Result<(), Box<dyn Error>> {
+    let server = perspective::server::Server::default();
+    let client = LocalClient::new(&server);
+    let table = client
+        .table(
+            TableData::Schema(vec![
+                ("id".to_owned(), ColumnType::Integer),
+                ("doc".to_owned(), ColumnType::Json),
+            ]),
+            TableInitOptions::default(),
+        )
+        .await?;
+
+    table
+        .update(
+            UpdateData::JsonRows(
+                r#"[
+                    {"id": 1, "doc": {"a": {"b": 2}, "s": "x"}},
+                    {"id": 2, "doc": "{\"a\": {\"b\": \"3.5\"}}"},
+                    {"id": 3, "doc": null}
+                ]"#
+                .to_owned(),
+            ),
+            UpdateOptions::default(),
+        )
+        .await?;
+
+    let view = table.view(None).await?;
+    let json = view.to_columns_string(ViewWindow::default()).await?;
+    assert_eq!(
+        json,
+        r#"{"id":[1,2,3],"doc":[{"a":{"b":2},"s":"x"},{"a":{"b":"3.5"}},null]}"#
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_json_extract_expressions() -> Result<(), Box<dyn Error>> {
+    let server = perspective::server::Server::default();
+    let client = LocalClient::new(&server);
+    let table = client
+        .table(
+            TableData::Schema(vec![("doc".to_owned(), ColumnType::Json)]),
+            TableInitOptions::default(),
+        )
+        .await?;
+
+    table
+        .update(
+            UpdateData::JsonRows(
+                r#"[
+                    {"doc": {"a": {"b": 2}, "s": "x", "ok": true, "items": [{"sku": "q"}]}},
+                    {"doc": {"a": {"b": "3.5"}, "ok": "false"}}
+                ]"#
+                .to_owned(),
+            ),
+            UpdateOptions::default(),
+        )
+        .await?;
+
+    let expressions = Expressions(HashMap::from([
+        ("s".to_owned(), r#"json_extract("doc", '$.s')"#.to_owned()),
+        (
+            "b".to_owned(),
+            r#"json_extract_float("doc", '$.a.b')"#.to_owned(),
+        ),
+        (
+            "ok".to_owned(),
+            r#"json_extract_bool("doc", '$.ok')"#.to_owned(),
+        ),
+        (
+            "sku".to_owned(),
+            r#"json_extract("doc", '$.items[0].sku')"#.to_owned(),
+        ),
+    ]));
+
+    let view = table
+        .view(Some(ViewConfigUpdate {
+            columns: Some(vec![
+                Some("s".to_owned()),
+                Some("b".to_owned()),
+                Some("ok".to_owned()),
+                Some("sku".to_owned()),
+            ]),
+            expressions: Some(expressions),
+            ..ViewConfigUpdate::default()
+        }))
+        .await?;
+
+    let json = view.to_columns_string(ViewWindow::default()).await?;
+    assert_eq!(
+        json,
+        r#"{"s":["x",null],"b":[2.0,3.5],"ok":[true,false],"sku":["q",null]}"#
+    );
+    Ok(())
+}
@@ -0,0 +1,128 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+use std::collections::HashMap;
+use std::error::Error;
+
+use perspective::LocalClient;
+use perspective_client::config::ViewConfigUpdate;
+use perspective_client::{
+    ColumnType, DatetimeFormat, GroupPaths, NullHandling, Table, TableData, TableInitOptions,
+    UpdateData, UpdateOptions, ViewWindow,
+};
+use serde_json::{json, Value};
+
+async fn make_table(client: &LocalClient) -> Result<Table, Box<dyn Error>> {
+    let table = client
+        .table(
+            TableData::Schema(vec![
+                ("g".to_owned(), ColumnType::String),
+                ("t".to_owned(), ColumnType::Datetime),
+                ("x".to_owned(), ColumnType::Integer),
+            ]),
+            TableInitOptions::default(),
+        )
+        .await?;
+
+    table
+        .update(
+            UpdateData::JsonRows(
+                r#"[{"g": "a", "t": 1709210096789, "x": 1}, {"g": "b", "t": null, "x": null}]"#
+                    .to_owned(),
+            ),
+            UpdateOptions::default(),
+        )
+        .await?;
+
+    Ok(table)
+}
+
+#[tokio::test]
+async fn test_iso8601_datetime_format() -> Result<(), Box<dyn Error>> {
+    let server = perspective::server::Server::default();
+    let client = LocalClient::new(&server);
+    let view = make_table(&client).await?.view(None).await?;
+    let window = ViewWindow {
+        datetime_format: Some(DatetimeFormat::Iso8601),
+        ..ViewWindow::default()
+    };
+
+    let json: Value = serde_json::from_str(&view.to_columns_string(window).await?)?;
+    assert_eq!(
+        json,
+        json!({"g": ["a", "b"], "t": ["2024-02-29T12:34:56.789Z", null], "x": [1, null]})
+    );
+
+    // The default is unchanged, epoch milliseconds.
+    let json: Value = serde_json::from_str(&view.to_columns_string(ViewWindow::default()).await?)?;
+    assert_eq!(json["t"][0].as_f64(), Some(1709210096789.0));
+    assert_eq!(json["t"][1], Value::Null);
+    client.close().await;
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_null_handling() -> Result<(), Box<dyn Error>> {
+    let server = perspective::server::Server::default();
+    let client = LocalClient::new(&server);
+    let view = make_table(&client).await?.view(None).await?;
+    let window = ViewWindow {
+        null_handling: Some(NullHandling::Omit),
+        ..ViewWindow::default()
+    };
+
+    let json: Value = serde_json::from_str(&view.to_json_string(window.clone()).await?)?;
+    assert_eq!(json[0]["x"], json!(1));
+    assert!(json[0]["t"].is_number());
+    assert_eq!(json[1], json!({"g": "b"}));
+
+    // Columns have no per-value keys to omit, so nulls are kept.
+    let json: Value = serde_json::from_str(&view.to_columns_string(window).await?)?;
+    assert_eq!(json["x"], json!([1, null]));
+
+    let window = ViewWindow {
+        null_handling: Some(NullHandling::EmptyString),
+        ..ViewWindow::default()
+    };
+
+    let json: Value = serde_json::from_str(&view.to_columns_string(window).await?)?;
+    assert_eq!(json["x"], json!([1, ""]));
+    client.close().await;
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_flat_group_paths_and_column_names() -> Result<(), Box<dyn Error>> {
+    let server = perspective::server::Server::default();
+    let client = LocalClient::new(&server);
+    let view = make_table(&client)
+        .await?
+        .view(Some(ViewConfigUpdate {
+            group_by: Some(vec!["g".to_owned()]),
+            columns: Some(vec![Some("x".to_owned())]),
+            ..ViewConfigUpdate::default()
+        }))
+        .await?;
+
+    let window = ViewWindow {
+        group_paths: Some(GroupPaths::Flat),
+        column_names: Some(HashMap::from([("x".to_owned(), "Quantity".to_owned())])),
+        ..ViewWindow::default()
+    };
+
+    let json: Value = serde_json::from_str(&view.to_columns_string(window).await?)?;
+    assert_eq!(json["__ROW_PATH__"], json!(["", "a", "b"]));
+    assert_eq!(json["Quantity"][1], json!(1));
+    assert!(json.get("x").is_none());
+
+    client.close().await;
+    Ok(())
+}
@@ -0,0 +1,143 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use std::error::Error;
+use std::fmt::Write;
+
+use perspective::LocalClient;
+use perspective_client::proto::ViewPort;
+use perspective_client::{TableInitOptions, UpdateData, ViewWindow};
+
+#[test]
+fn test_view_window_rows_are_exact_beyond_f32() {
+    let viewport = ViewPort::from(ViewWindow {
+        start_row: Some(16_777_217.0),
+        end_row: Some(4_294_967_297.0),
+        ..ViewWindow::default()
+    });
+
+    assert_eq!(viewport.start_row, Some(16_777_217));
+    assert_eq!(viewport.end_row, Some(4_294_967_297));
+}
+
+#[tokio::test]
+async fn test_dimensions_are_64_bit() -> Result<(), Box<dyn Error>> {
+    let server = perspective::server::Server::default();
+    let client = LocalClient::new(&server);
+    let table = client
+        .table(
+            UpdateData::Csv("x\n1\n2\n3".to_owned()).into(),
+            TableInitOptions::default(),
+        )
+        .await?;
+
+    let view = table.view(None).await?;
+    let num_rows: u64 = view.num_rows().await?;
+    let dimensions = view.dimensions().await?;
+    let num_table_rows: u64 = dimensions.num_table_rows;
+    assert_eq!(num_rows, 3);
+    assert_eq!(num_table_rows, 3);
+    Ok(())
+}
+
+/// Row indices past 2^31 and 2^32 reach the engine's extent clamping intact;
+/// were they truncated to 32 bits, these windows would select rows of this
+/// 3-row table.
+#[tokio::test]
+async fn test_view_window_rows_beyond_32_bits() -> Result<(), Box<dyn Error>> {
+    let server = perspective::server::Server::default();
+    let client = LocalClient::new(&server);
+    let table = client
+        .table(
+            UpdateData::Csv("x\n1\n2\n3".to_owned()).into(),
+            TableInitOptions::default(),
+        )
+        .await?;
+
+    let view = table.view(None).await?;
+    let empty = view
+        .to_columns_string(ViewWindow {
+            start_row: Some(3.0),
+            end_row: Some(3.0),
+            ..ViewWindow::default()
+        })
+        .await?;
+
+    for start_row in [2_147_483_648.0, 4_294_967_297.0] {
+        let json = view
+            .to_columns_string(ViewWindow {
+                start_row: Some(start_row),
+                end_row: Some(start_row + 2.0),
+                ..ViewWindow::default()
+            })
+            .await?;
+
+        assert_eq!(json, empty);
+    }
+
+    let tail_csv = view
+        .to_csv(ViewWindow {
+            start_row: Some(1.0),
+            end_row: Some(3.0),
+            ..ViewWindow::default()
+        })
+        .await?;
+
+    for end_row in [2_147_483_649.0, 4_294_967_297.0] {
+        let window = ViewWindow {
+            start_row: Some(1.0),
+            end_row: Some(end_row),
+            ..ViewWindow::default()
+        };
+
+        assert_eq!(
+            view.to_columns_string(window.clone()).await?,
+            r#"{"x":[2,3]}"#
+        );
+
+        assert_eq!(view.to_csv(window).await?, tail_csv);
+    }
+
+    Ok(())
+}
+
+/// Reads rows whose indices `f32` cannot represent. This allocates a table of
+/// ~16.8M rows, so it only runs with `cargo test -- --ignored`.
+#[tokio::test]
+#[ignore]
+async fn test_view_window_beyond_f32_at_scale() -> Result<(), Box<dyn Error>> {
+    const NUM_ROWS: u64 = (1 << 24) + 3;
+    let mut csv = String::with_capacity(NUM_ROWS as usize * 9);
+    csv.push_str("x\n");
+    for x in 0..NUM_ROWS {
+        writeln!(csv, "{}", x)?;
+    }
+
+    let server = perspective::server::Server::default();
+    let client = LocalClient::new(&server);
+    let table = client
+        .table(UpdateData::Csv(csv).into(), TableInitOptions::default())
+        .await?;
+
+    let view = table.view(None).await?;
+    assert_eq!(view.num_rows().await?, NUM_ROWS);
+    let json = view
+        .to_columns_string(ViewWindow {
+            start_row: Some(16_777_217.0),
+            end_row: Some(16_777_219.0),
+            ..ViewWindow::default()
+        })
+        .await?;
+
+    assert_eq!(json, r#"{"x":[16777217,16777218]}"#);
+    Ok(())
+}
@@ -0,0 +1,216 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use std::collections::HashMap;
+use std::error::Error;
+
+use perspective::LocalClient;
+use perspective_client::config::{Aggregate, Expressions, SingleAggregate, ViewConfigUpdate};
+use perspective_client::{
+    ColumnType, TableData, TableInitOptions, UpdateData, UpdateOptions, ViewWindow,
+};
+
+#[tokio::test]
+async fn test_list_column_from_json_arrays() -> Result<(), Box<dyn Error>> {
+    let server = perspective::server::Server::default();
+    let client = LocalClient::new(&server);
+    let table = client
+        .table(
+            UpdateData::JsonRows(r#"[{"x": [1, 2, 3]}, {"x": []}, {"x": null}]"#.to_owned()).into(),
+            TableInitOptions::default(),
+        )
+        .await?;
+
+    let schema = table.schema().await?;
+    assert_eq!(schema.get("x"), Some(&ColumnType::List));
+
+    let view = table.view(None).await?;
+    let json = view.to_columns_string(ViewWindow::default()).await?;
+    assert_eq!(json, r#"{"x":[[1,2,3],[],null]}"#);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_list_expression_functions() -> Result<(), Box<dyn Error>> {
+    let server = perspective::server::Server::default();
+    let client = LocalClient::new(&server);
+    let table = client
+        .table(
+            TableData::Schema(vec![("tags".to_owned(), ColumnType::List)]),
+            TableInitOptions::default(),
+        )
+        .await?;
+
+    table
+        .update(
+            UpdateData::JsonRows(
+                r#"[{"tags": ["a", "b"]}, {"tags": "[1, 2.5, \"c\"]"}]"#.to_owned(),
+            ),
+            UpdateOptions::default(),
+        )
+        .await?;
+
+    let expressions = Expressions(HashMap::from([
+        ("n".to_owned(), r#"len("tags")"#.to_owned()),
+        ("has_b".to_owned(), r#"contains("tags", 'b')"#.to_owned()),
+        ("total".to_owned(), r#"sum("tags")"#.to_owned()),
+    ]));
+
+    let view = table
+        .view(Some(ViewConfigUpdate {
+            columns: Some(vec![
+                Some("n".to_owned()),
+                Some("has_b".to_owned()),
+                Some("total".to_owned()),
+            ]),
+            expressions: Some(expressions),
+            ..ViewConfigUpdate::default()
+        }))
+        .await?;
+
+    let json = view.to_columns_string(ViewWindow::default()).await?;
+    assert_eq!(
+        json,
+        r#"{"n":[2.0,3.0],"has_b":[true,false],"total":[0.0,3.5]}"#
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_unnest_list_column() -> Result<(), Box<dyn Error>> {
+    let server = perspective::server::Server::default();
+    let client = LocalClient::new(&server);
+    let table = client
+        .table(
+            UpdateData::JsonRows(
+                r#"[{"id": 1, "tags": ["a", "b"]}, {"id": 2, "tags": []}, {"id": 3, "tags": null}]"#
+                    .to_owned(),
+            )
+            .into(),
+            TableInitOptions::default(),
+        )
+        .await?;
+
+    let view = table
+        .view(Some(ViewConfigUpdate {
+            unnest: Some(vec!["tags".to_owned()]),
+            ..ViewConfigUpdate::default()
+        }))
+        .await?;
+
+    let json = view.to_columns_string(ViewWindow::default()).await?;
+    assert_eq!(json, r#"{"id":[1,1,2,3],"tags":["a","b",null,null]}"#);
+    assert_eq!(view.schema().await?.get("tags"), Some(&ColumnType::String));
+    assert_eq!(view.get_config().await?.unnest, vec!["tags".to_owned()]);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_unnest_group_by_follows_updates() -> Result<(), Box<dyn Error>> {
+    let server = perspective::server::Server::default();
+    let client = LocalClient::new(&server);
+    let table = client
+        .table(
+            UpdateData::JsonRows(
+                r#"[{"id": 1, "tags": ["a", "b"]}, {"id": 2, "tags": ["b"]}]"#.to_owned(),
+            )
+            .into(),
+            TableInitOptions::default(),
+        )
+        .await?;
+
+    let view = table
+        .view(Some(ViewConfigUpdate {
+            unnest: Some(vec!["tags".to_owned()]),
+            group_by: Some(vec!["tags".to_owned()]),
+            columns: Some(vec![Some("id".to_owned())]),
+            aggregates: Some(HashMap::from([(
+                "id".to_owned(),
+                Aggregate::SingleAggregate(SingleAggregate::Count),
+            )])),
+            ..ViewConfigUpdate::default()
+        }))
+        .await?;
+
+    let json = view.to_columns_string(ViewWindow::default()).await?;
+    assert_eq!(json, r#"{"__ROW_PATH__":[[],["a"],["b"]],"id":[3,1,2]}"#);
+
+    table
+        .update(
+            UpdateData::JsonRows(r#"[{"id": 3, "tags": ["b", "c"]}]"#.to_owned()),
+            UpdateOptions::default(),
+        )
+        .await?;
+
+    let json = view.to_columns_string(ViewWindow::default()).await?;
+    assert_eq!(
+        json,
+        r#"{"__ROW_PATH__":[[],["a"],["b"],["c"]],"id":[5,1,3,1]}"#
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_unnest_lists_together_with_element_types() -> Result<(), Box<dyn Error>> {
+    let server = perspective::server::Server::default();
+    let client = LocalClient::new(&server);
+    let table = client
+        .table(
+            UpdateData::JsonRows(
+                r#"[{"k": "x", "qty": [1, 2], "px": [1.5, 2.5, 3.5]}]"#.to_owned(),
+            )
+            .into(),
+            TableInitOptions::default(),
+        )
+        .await?;
+
+    let view = table
+        .view(Some(ViewConfigUpdate {
+            unnest: Some(vec!["qty".to_owned(), "px".to_owned()]),
+            ..ViewConfigUpdate::default()
+        }))
+        .await?;
+
+    let schema = view.schema().await?;
+    assert_eq!(schema.get("qty"), Some(&ColumnType::Integer));
+    assert_eq!(schema.get("px"), Some(&ColumnType::Float));
+    let json = view.to_columns_string(ViewWindow::default()).await?;
+    assert_eq!(
+        json,
+        r#"{"k":["x","x","x"],"qty":[1,2,null],"px":[1.5,2.5,3.5]}"#
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_unnest_rejects_non_list_column() -> Result<(), Box<dyn Error>> {
+    let server = perspective::server::Server::default();
+    let client = LocalClient::new(&server);
+    let table = client
+        .table(
+            UpdateData::JsonRows(r#"[{"id": 1, "tags": ["a"]}]"#.to_owned()).into(),
+            TableInitOptions::default(),
+        )
+        .await?;
+
+    let result = table
+        .view(Some(ViewConfigUpdate {
+            unnest: Some(vec!["id".to_owned()]),
+            ..ViewConfigUpdate::default()
+        }))
+        .await;
+
+    assert!(result.is_err());
+    Ok(())
+}
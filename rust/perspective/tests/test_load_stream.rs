@@ -0,0 +1,201 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use std::error::Error;
+
+use futures::io::Cursor;
+use perspective::LocalClient;
+use perspective_client::{
+    ColumnType, CsvOptions, LoadStreamOptions, StreamFormat, TableData, TableInitOptions,
+    UpdateData, ViewWindow,
+};
+
+#[tokio::test]
+async fn test_load_stream_csv_splits_outside_quoted_fields() -> Result<(), Box<dyn Error>> {
+    let server = perspective::server::Server::default();
+    let client = LocalClient::new(&server);
+    let table = client
+        .table(
+            TableData::Schema(vec![
+                ("x".to_owned(), ColumnType::Integer),
+                ("note".to_owned(), ColumnType::String),
+            ]),
+            TableInitOptions::default(),
+        )
+        .await?;
+
+    // Chunks of a few bytes end inside the quoted, multi-line fields.
+    let csv = "x,note\n1,\"a\nb\"\n2,plain\n3,\"c,\nd\"\n";
+    let options = LoadStreamOptions {
+        chunk_size: 4,
+        ..LoadStreamOptions::default()
+    };
+
+    let progress = table
+        .load_stream(Cursor::new(csv.as_bytes()), StreamFormat::Csv, options)
+        .await?;
+
+    assert_eq!(progress.bytes_read, csv.len() as u64);
+    assert_eq!(progress.chunks_loaded, 3);
+    let view = table.view(None).await?;
+    let json = view.to_columns_string(ViewWindow::default()).await?;
+    assert_eq!(json, r#"{"x":[1,2,3],"note":["a\nb","plain","c,\nd"]}"#);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_load_stream_csv_uses_csv_options() -> Result<(), Box<dyn Error>> {
+    let server = perspective::server::Server::default();
+    let client = LocalClient::new(&server);
+    let table = client
+        .table(
+            TableData::Schema(vec![
+                ("x".to_owned(), ColumnType::Integer),
+                ("note".to_owned(), ColumnType::String),
+            ]),
+            TableInitOptions::default(),
+        )
+        .await?;
+
+    // A title row above the column names, `'`-quoted fields (in which `"` is
+    // an ordinary character), and chunks which end inside a quoted newline.
+    let csv = "Trades export\nx;note\n1;'a\nb'\n2;'say \"hi\"'\n3;'c;\nd'\n";
+    let options = LoadStreamOptions {
+        chunk_size: 4,
+        csv: Some(CsvOptions {
+            delimiter: Some(';'),
+            quote_char: Some('\''),
+            header_rows: Some(2),
+            ..CsvOptions::default()
+        }),
+        ..LoadStreamOptions::default()
+    };
+
+    let progress = table
+        .load_stream(Cursor::new(csv.as_bytes()), StreamFormat::Csv, options)
+        .await?;
+
+    assert_eq!(progress.bytes_read, csv.len() as u64);
+    assert_eq!(progress.chunks_loaded, 3);
+    let view = table.view(None).await?;
+    let json = view.to_columns_string(ViewWindow::default()).await?;
+    assert_eq!(
+        json,
+        r#"{"x":[1,2,3],"note":["a\nb","say \"hi\"","c;\nd"]}"#
+    );
+
+    Ok(())
+}
+
+/// A single Arrow IPC stream of two record batches, spliced from two streams
+/// of the same schema: the first without its end-of-stream marker, then the
+/// second without its schema message. Neither may have string columns, whose
+/// dictionary batches would conflict.
+fn two_batch_stream(first: &[u8], second: &[u8]) -> Vec<u8> {
+    let schema_len = 8 + u32::from_le_bytes(second[4..8].try_into().unwrap()) as usize;
+    let mut stream = first[..first.len() - 8].to_vec();
+    stream.extend_from_slice(&second[schema_len..]);
+    stream
+}
+
+#[tokio::test]
+async fn test_load_stream_arrow_splits_on_message_boundaries() -> Result<(), Box<dyn Error>> {
+    let server = perspective::server::Server::default();
+    let client = LocalClient::new(&server);
+    let source = client
+        .table(
+            UpdateData::Csv("x,y\n1,1.5\n2,2.5\n3,3.5\n4,4.5".to_owned()).into(),
+            TableInitOptions::default(),
+        )
+        .await?;
+
+    let view = source.view(None).await?;
+    let first = view
+        .to_arrow(ViewWindow {
+            end_row: Some(2.0),
+            ..ViewWindow::default()
+        })
+        .await?;
+
+    let second = view
+        .to_arrow(ViewWindow {
+            start_row: Some(2.0),
+            ..ViewWindow::default()
+        })
+        .await?;
+
+    let stream = two_batch_stream(&first, &second);
+    let table = client
+        .table(
+            TableData::Schema(vec![
+                ("x".to_owned(), ColumnType::Integer),
+                ("y".to_owned(), ColumnType::Float),
+            ]),
+            TableInitOptions::default(),
+        )
+        .await?;
+
+    // Every byte is a chunk boundary, so each record batch is sent on its
+    // own, prefixed with the schema.
+    let options = LoadStreamOptions {
+        chunk_size: 1,
+        ..LoadStreamOptions::default()
+    };
+
+    let progress = table
+        .load_stream(Cursor::new(stream.as_slice()), StreamFormat::Arrow, options)
+        .await?;
+
+    assert_eq!(progress.bytes_read, stream.len() as u64);
+    assert_eq!(progress.chunks_loaded, 2);
+    let view = table.view(None).await?;
+    let json = view.to_columns_string(ViewWindow::default()).await?;
+    assert_eq!(json, r#"{"x":[1,2,3,4],"y":[1.5,2.5,3.5,4.5]}"#);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_load_stream_arrow_sends_large_batches_whole() -> Result<(), Box<dyn Error>> {
+    let server = perspective::server::Server::default();
+    let client = LocalClient::new(&server);
+    let source = client
+        .table(
+            UpdateData::Csv("x\n1\n2\n3".to_owned()).into(),
+            TableInitOptions::default(),
+        )
+        .await?;
+
+    let stream = source
+        .view(None)
+        .await?
+        .to_arrow(ViewWindow::default())
+        .await?;
+    let table = client
+        .table(
+            TableData::Schema(vec![("x".to_owned(), ColumnType::Integer)]),
+            TableInitOptions::default(),
+        )
+        .await?;
+
+    let options = LoadStreamOptions {
+        chunk_size: 16,
+        ..LoadStreamOptions::default()
+    };
+
+    let progress = table
+        .load_stream(Cursor::new(stream.to_vec()), StreamFormat::Arrow, options)
+        .await?;
+
+    assert_eq!(progress.chunks_loaded, 1);
+    assert_eq!(table.size().await?, 3);
+    Ok(())
+}
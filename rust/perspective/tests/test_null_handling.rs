@@ -0,0 +1,165 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use std::collections::HashMap;
+use std::error::Error;
+
+use perspective::LocalClient;
+use perspective_client::config::{
+    Aggregate, NullAggregates, NullGroups, SingleAggregate, ViewConfigUpdate,
+};
+use perspective_client::{
+    ColumnType, TableData, TableInitOptions, UpdateData, UpdateOptions, ViewWindow,
+};
+
+async fn nulls_table(client: &LocalClient) -> Result<perspective_client::Table, Box<dyn Error>> {
+    let table = client
+        .table(
+            TableData::Schema(vec![
+                ("g".to_owned(), ColumnType::String),
+                ("x".to_owned(), ColumnType::Integer),
+            ]),
+            TableInitOptions::default(),
+        )
+        .await?;
+
+    table
+        .update(
+            UpdateData::JsonRows(
+                r#"[
+                    {"g": "a", "x": 1},
+                    {"g": "a", "x": null},
+                    {"g": null, "x": 3},
+                    {"g": "b", "x": 4},
+                    {"g": "b", "x": 6}
+                ]"#
+                .to_owned(),
+            ),
+            UpdateOptions::default(),
+        )
+        .await?;
+
+    Ok(table)
+}
+
+fn config(agg: SingleAggregate, null_aggregates: NullAggregates) -> ViewConfigUpdate {
+    ViewConfigUpdate {
+        group_by: Some(vec!["g".to_owned()]),
+        columns: Some(vec![Some("x".to_owned())]),
+        aggregates: Some(HashMap::from([(
+            "x".to_owned(),
+            Aggregate::SingleAggregate(agg),
+        )])),
+        null_groups: Some(NullGroups::Exclude),
+        null_aggregates: Some(null_aggregates),
+        ..ViewConfigUpdate::default()
+    }
+}
+
+#[tokio::test]
+async fn test_null_groups() -> Result<(), Box<dyn Error>> {
+    let server = perspective::server::Server::default();
+    let client = LocalClient::new(&server);
+    let table = nulls_table(&client).await?;
+    let grouped = table
+        .view(Some(ViewConfigUpdate {
+            group_by: Some(vec!["g".to_owned()]),
+            columns: Some(vec![Some("x".to_owned())]),
+            ..ViewConfigUpdate::default()
+        }))
+        .await?;
+
+    assert_eq!(grouped.num_rows().await?, 4);
+    assert_eq!(grouped.get_config().await?.null_groups, NullGroups::Group);
+
+    let excluded = table
+        .view(Some(ViewConfigUpdate {
+            group_by: Some(vec!["g".to_owned()]),
+            columns: Some(vec![Some("x".to_owned())]),
+            null_groups: Some(NullGroups::Exclude),
+            ..ViewConfigUpdate::default()
+        }))
+        .await?;
+
+    let json = excluded.to_columns_string(ViewWindow::default()).await?;
+    assert_eq!(json, r#"{"__ROW_PATH__":[[],["a"],["b"]],"x":[11,1,10]}"#);
+    assert_eq!(
+        excluded.get_config().await?.null_groups,
+        NullGroups::Exclude
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_null_aggregates_count() -> Result<(), Box<dyn Error>> {
+    let server = perspective::server::Server::default();
+    let client = LocalClient::new(&server);
+    let table = nulls_table(&client).await?;
+    for (null_aggregates, expected) in [
+        (
+            NullAggregates::Default,
+            r#"{"__ROW_PATH__":[[],["a"],["b"]],"x":[4,2,2]}"#,
+        ),
+        (
+            NullAggregates::Ignore,
+            r#"{"__ROW_PATH__":[[],["a"],["b"]],"x":[3,1,2]}"#,
+        ),
+        (
+            NullAggregates::Propagate,
+            r#"{"__ROW_PATH__":[[],["a"],["b"]],"x":[4,2,2]}"#,
+        ),
+    ] {
+        let view = table
+            .view(Some(config(SingleAggregate::Count, null_aggregates)))
+            .await?;
+
+        let json = view.to_columns_string(ViewWindow::default()).await?;
+        assert_eq!(json, expected);
+        assert_eq!(view.get_config().await?.null_aggregates, null_aggregates);
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_null_aggregates_propagate() -> Result<(), Box<dyn Error>> {
+    let server = perspective::server::Server::default();
+    let client = LocalClient::new(&server);
+    let table = nulls_table(&client).await?;
+    let view = table
+        .view(Some(config(
+            SingleAggregate::Sum,
+            NullAggregates::Propagate,
+        )))
+        .await?;
+
+    let json = view.to_columns_string(ViewWindow::default()).await?;
+    assert_eq!(
+        json,
+        r#"{"__ROW_PATH__":[[],["a"],["b"]],"x":[null,null,10]}"#
+    );
+
+    table
+        .update(
+            UpdateData::JsonRows(r#"[{"g": "b", "x": null}]"#.to_owned()),
+            UpdateOptions::default(),
+        )
+        .await?;
+
+    let json = view.to_columns_string(ViewWindow::default()).await?;
+    assert_eq!(
+        json,
+        r#"{"__ROW_PATH__":[[],["a"],["b"]],"x":[null,null,null]}"#
+    );
+
+    Ok(())
+}
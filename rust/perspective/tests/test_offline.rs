@@ -0,0 +1,209 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, OnceLock};
+
+use async_lock::{Mutex, RwLock};
+use perspective::client::{
+    Client, ClientError, OfflineBufferOptions, OverflowPolicy, TableInitOptions, UpdateData,
+    UpdateOptions,
+};
+use perspective::server::{Server, Session};
+
+type SharedSession = Arc<RwLock<Option<Session>>>;
+
+/// A [`Client`] whose transport fails while `connected` is unset, and waits
+/// while `gate` is locked.
+async fn flaky_client(
+    server: &Server,
+    connected: Arc<AtomicBool>,
+    gate: Arc<Mutex<()>>,
+) -> (Client, SharedSession) {
+    let client_slot: Arc<OnceLock<Client>> = Arc::default();
+    let session = Arc::new(RwLock::new(Some(
+        server
+            .new_session_with_callback({
+                let client_slot = client_slot.clone();
+                move |msg| {
+                    let client_slot = client_slot.clone();
+                    Box::pin(async move {
+                        client_slot.get().unwrap().handle_response(msg).await?;
+                        Ok(())
+                    })
+                }
+            })
+            .await,
+    )));
+
+    let client = Client::new_with_callback({
+        let session = session.clone();
+        move |msg| {
+            let session = session.clone();
+            let connected = connected.clone();
+            let gate = gate.clone();
+            Box::pin(async move {
+                let _gate = gate.lock().await;
+                if !connected.load(Ordering::SeqCst) {
+                    return Err("Disconnected".into());
+                }
+
+                let session = session.read().await;
+                let session = session.as_ref().ok_or("Session closed")?;
+                session.handle_request(msg).await?;
+                session.poll().await?;
+                Ok(())
+            })
+        }
+    });
+
+    let _ = client_slot.set(client.clone());
+    (client, session)
+}
+
+fn csv(data: &str) -> UpdateData {
+    UpdateData::Csv(format!("x\n{}", data))
+}
+
+#[tokio::test]
+async fn test_updates_replay_after_reconnect() -> Result<(), ClientError> {
+    let server = Server::default();
+    let connected = Arc::new(AtomicBool::new(true));
+    let (client, session) = flaky_client(&server, connected.clone(), Arc::default()).await;
+    client.set_offline_buffer(Some(OfflineBufferOptions {
+        capacity: 10,
+        overflow: OverflowPolicy::Error,
+    }));
+
+    let table = client
+        .table(csv("1").into(), TableInitOptions::default())
+        .await?;
+
+    connected.store(false, Ordering::SeqCst);
+    table.update(csv("2"), UpdateOptions::default()).await?;
+    table.update(csv("3"), UpdateOptions::default()).await?;
+    assert_eq!(client.offline_updates_len(), 2);
+    assert!(table.size().await.is_err());
+    assert!(client.replay_offline_updates().await.is_err());
+    assert_eq!(client.offline_updates_len(), 2);
+
+    connected.store(true, Ordering::SeqCst);
+    assert_eq!(client.replay_offline_updates().await?, 2);
+    assert_eq!(client.offline_updates_len(), 0);
+    assert_eq!(table.size().await?, 3);
+    session.write().await.take().unwrap().close().await;
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_offline_buffer_overflow_policies() -> Result<(), ClientError> {
+    let server = Server::default();
+    let connected = Arc::new(AtomicBool::new(true));
+    let (client, session) = flaky_client(&server, connected.clone(), Arc::default()).await;
+    client.set_offline_buffer(Some(OfflineBufferOptions {
+        capacity: 1,
+        overflow: OverflowPolicy::Error,
+    }));
+
+    let table = client
+        .table(csv("1").into(), TableInitOptions::default())
+        .await?;
+
+    connected.store(false, Ordering::SeqCst);
+    table.update(csv("2"), UpdateOptions::default()).await?;
+    assert!(matches!(
+        table.update(csv("3"), UpdateOptions::default()).await,
+        Err(ClientError::OfflineBufferFull(1))
+    ));
+
+    client.set_offline_buffer(Some(OfflineBufferOptions {
+        capacity: 1,
+        overflow: OverflowPolicy::DropOldest,
+    }));
+
+    table.update(csv("4"), UpdateOptions::default()).await?;
+    assert_eq!(client.offline_updates_len(), 1);
+
+    connected.store(true, Ordering::SeqCst);
+    assert_eq!(client.replay_offline_updates().await?, 1);
+    let view = table.view(None).await?;
+    let json = view
+        .to_columns_string(perspective::client::ViewWindow::default())
+        .await?;
+
+    assert_eq!(json, r#"{"x":[1,4]}"#);
+    view.delete().await?;
+    session.write().await.take().unwrap().close().await;
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_update_during_replay_keeps_unsent_updates() -> Result<(), ClientError> {
+    let server = Server::default();
+    let connected = Arc::new(AtomicBool::new(true));
+    let gate: Arc<Mutex<()>> = Arc::default();
+    let (client, session) = flaky_client(&server, connected.clone(), gate.clone()).await;
+    client.set_offline_buffer(Some(OfflineBufferOptions {
+        capacity: 2,
+        overflow: OverflowPolicy::DropOldest,
+    }));
+
+    let table = client
+        .table(csv("1").into(), TableInitOptions::default())
+        .await?;
+
+    connected.store(false, Ordering::SeqCst);
+    table.update(csv("2"), UpdateOptions::default()).await?;
+    table.update(csv("3"), UpdateOptions::default()).await?;
+    connected.store(true, Ordering::SeqCst);
+
+    // Hold the replay of "2" in flight while an update evicts it from the
+    // full buffer, which must not discard "3" in its place.
+    let guard = gate.lock().await;
+    let mut replay = std::pin::pin!(client.replay_offline_updates());
+    assert!(futures::poll!(replay.as_mut()).is_pending());
+    table.update(csv("4"), UpdateOptions::default()).await?;
+    assert_eq!(client.offline_updates_len(), 2);
+    drop(guard);
+
+    assert_eq!(replay.await?, 3);
+    assert_eq!(client.offline_updates_len(), 0);
+    let view = table.view(None).await?;
+    let json = view
+        .to_columns_string(perspective::client::ViewWindow::default())
+        .await?;
+
+    assert_eq!(json, r#"{"x":[1,2,3,4]}"#);
+    view.delete().await?;
+    session.write().await.take().unwrap().close().await;
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_updates_fail_without_offline_buffer() -> Result<(), ClientError> {
+    let server = Server::default();
+    let connected = Arc::new(AtomicBool::new(true));
+    let (client, session) = flaky_client(&server, connected.clone(), Arc::default()).await;
+    let table = client
+        .table(csv("1").into(), TableInitOptions::default())
+        .await?;
+
+    connected.store(false, Ordering::SeqCst);
+    assert!(matches!(
+        table.update(csv("2"), UpdateOptions::default()).await,
+        Err(ClientError::ExternalError(_))
+    ));
+
+    assert_eq!(client.offline_updates_len(), 0);
+    session.write().await.take().unwrap().close().await;
+    Ok(())
+}
@@ -0,0 +1,125 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use std::error::Error;
+use std::sync::Arc;
+
+use perspective::{Commit, LocalClient};
+use perspective_client::{Client, TableInitOptions, UpdateData, UpdateOptions, ViewWindow};
+use serde_json::{json, Value};
+use tokio::sync::Mutex;
+
+async fn delta_columns(client: &Client, commit: &Commit) -> Result<Value, Box<dyn Error>> {
+    let delta = UpdateData::Arrow(commit.delta.clone().into());
+    let table = client
+        .table(delta.into(), TableInitOptions::default())
+        .await?;
+
+    let view = table.view(None).await?;
+    let json = view.to_columns_string(ViewWindow::default()).await?;
+    view.delete().await?;
+    table.delete().await?;
+    Ok(serde_json::from_str(&json)?)
+}
+
+#[tokio::test]
+async fn test_on_commit_receives_each_delta() -> Result<(), Box<dyn Error>> {
+    let server = perspective::server::Server::default();
+    let client = LocalClient::new(&server);
+    let table = client
+        .table(
+            UpdateData::Csv("x,y\n1,2".to_owned()).into(),
+            TableInitOptions {
+                name: Some("Table1".to_owned()),
+                index: Some("x".to_owned()),
+                ..TableInitOptions::default()
+            },
+        )
+        .await?;
+
+    let commits = Arc::new(Mutex::new(vec![]));
+    let hook = perspective::on_commit(&server, "Table1", {
+        let commits = commits.clone();
+        move |commit| {
+            let commits = commits.clone();
+            async move { commits.lock().await.push(commit) }
+        }
+    })
+    .await?;
+
+    for csv in ["x,y\n3,4", "x,y\n1,5"] {
+        table
+            .update(UpdateData::Csv(csv.to_owned()), UpdateOptions::default())
+            .await?;
+    }
+
+    let commits = commits.lock().await.clone();
+    assert_eq!(commits.len(), 2);
+    assert_eq!(
+        delta_columns(&client, &commits[0]).await?,
+        json!({"x": [3], "y": [4]})
+    );
+
+    assert_eq!(
+        delta_columns(&client, &commits[1]).await?,
+        json!({"x": [1], "y": [5]})
+    );
+
+    assert!(commits.iter().all(|commit| commit.removed.is_empty()));
+    hook.remove().await?;
+    table.delete().await?;
+    client.close().await;
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_on_commit_receives_removals() -> Result<(), Box<dyn Error>> {
+    let server = perspective::server::Server::default();
+    let client = LocalClient::new(&server);
+    let table = client
+        .table(
+            UpdateData::Csv("x,y\n1,2\n3,4\n5,6".to_owned()).into(),
+            TableInitOptions {
+                name: Some("Table1".to_owned()),
+                index: Some("x".to_owned()),
+                ..TableInitOptions::default()
+            },
+        )
+        .await?;
+
+    let commits = Arc::new(Mutex::new(vec![]));
+    let hook = perspective::on_commit(&server, "Table1", {
+        let commits = commits.clone();
+        move |commit| {
+            let commits = commits.clone();
+            async move { commits.lock().await.push(commit) }
+        }
+    })
+    .await?;
+
+    table
+        .remove(UpdateData::JsonRows(r#"[{"x": 1}, {"x": 5}]"#.to_owned()))
+        .await?;
+
+    let commits = commits.lock().await.clone();
+    assert_eq!(commits.len(), 1);
+    assert_eq!(commits[0].removed, vec![json!(1), json!(5)]);
+    assert_eq!(
+        delta_columns(&client, &commits[0]).await?,
+        json!({"x": [], "y": []})
+    );
+
+    hook.remove().await?;
+    table.delete().await?;
+    client.close().await;
+    Ok(())
+}
@@ -0,0 +1,254 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use std::error::Error;
+use std::sync::{Arc, Mutex};
+
+use perspective::LocalClient;
+use perspective_client::proto::request::ClientReq;
+use perspective_client::proto::response::ClientResp;
+use perspective_client::proto::{
+    Request, Response, TableDeleteReq, TableRenameReq, TableSchemaReq, TableSizeReq,
+};
+use perspective_client::{
+    CellEdit, Table, TableInitOptions, UpdateData, UpdateOptions, ViewWindow,
+};
+use prost::Message;
+
+async fn indexed_table(client: &LocalClient) -> Result<Table, Box<dyn Error>> {
+    Ok(client
+        .table(
+            UpdateData::Csv("id,x\n1,a\n2,b\n3,c".to_owned()).into(),
+            TableInitOptions {
+                index: Some("id".to_owned()),
+                ..TableInitOptions::default()
+            },
+        )
+        .await?)
+}
+
+#[tokio::test]
+async fn test_overlay_edits_are_private() -> Result<(), Box<dyn Error>> {
+    let server = perspective::server::Server::default();
+    let client = LocalClient::new(&server);
+    let table = indexed_table(&client).await?;
+    let overlay = table.overlay().await?;
+    overlay
+        .update(
+            UpdateData::Csv("id,x\n2,y".to_owned()),
+            UpdateOptions::default(),
+        )
+        .await?;
+
+    let base_view = table.view(None).await?;
+    let json = base_view.to_columns_string(ViewWindow::default()).await?;
+    assert_eq!(json, r#"{"id":[1,2,3],"x":["a","b","c"]}"#);
+
+    let overlay_view = overlay.view(None).await?;
+    let json = overlay_view
+        .to_columns_string(ViewWindow::default())
+        .await?;
+    assert_eq!(json, r#"{"id":[1,2,3],"x":["a","y","c"]}"#);
+    assert_eq!(client.get_hosted_table_names().await?.len(), 1);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_overlay_commit() -> Result<(), Box<dyn Error>> {
+    let server = perspective::server::Server::default();
+    let client = LocalClient::new(&server);
+    let table = indexed_table(&client).await?;
+    let overlay = table.overlay().await?;
+    overlay
+        .update(
+            UpdateData::Csv("id,x\n2,y\n4,z".to_owned()),
+            UpdateOptions::default(),
+        )
+        .await?;
+
+    overlay
+        .remove(UpdateData::JsonRows("[1]".to_owned()))
+        .await?;
+
+    assert_eq!(overlay.commit_overlay().await?, 3);
+    assert_eq!(overlay.commit_overlay().await?, 0);
+    let view = table.view(None).await?;
+    let json = view.to_columns_string(ViewWindow::default()).await?;
+    assert_eq!(json, r#"{"id":[2,3,4],"x":["y","c","z"]}"#);
+    overlay.delete().await?;
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_overlay_reads_base_updates() -> Result<(), Box<dyn Error>> {
+    let server = perspective::server::Server::default();
+    let client = LocalClient::new(&server);
+    let table = indexed_table(&client).await?;
+    let overlay = table.overlay().await?;
+    overlay
+        .update(
+            UpdateData::Csv("id,x\n2,y".to_owned()),
+            UpdateOptions::default(),
+        )
+        .await?;
+
+    overlay
+        .remove(UpdateData::JsonRows("[3]".to_owned()))
+        .await?;
+
+    let view = overlay.view(None).await?;
+    table
+        .update(
+            UpdateData::Csv("id,x\n1,p\n2,q\n5,r".to_owned()),
+            UpdateOptions::default(),
+        )
+        .await?;
+
+    // The overlay's edits are merged over the base table's current rows.
+    let json = view.to_columns_string(ViewWindow::default()).await?;
+    assert_eq!(json, r#"{"id":[1,2,5],"x":["p","y","r"]}"#);
+    assert_eq!(overlay.size().await?, 3);
+    assert_eq!(overlay.commit_overlay().await?, 2);
+    let base_view = table.view(None).await?;
+    let json = base_view.to_columns_string(ViewWindow::default()).await?;
+    assert_eq!(json, r#"{"id":[1,2,5],"x":["p","y","r"]}"#);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_overlay_edit_cells() -> Result<(), Box<dyn Error>> {
+    let server = perspective::server::Server::default();
+    let client = LocalClient::new(&server);
+    let table = indexed_table(&client).await?;
+    let overlay = table.overlay().await?;
+    overlay
+        .edit_cells(
+            vec![CellEdit {
+                index: "3".to_owned(),
+                column: "x".to_owned(),
+                value: r#""z""#.to_owned(),
+            }],
+            None,
+        )
+        .await?;
+
+    let log = overlay.edit_log(None).await?;
+    assert_eq!(log[0].previous_value, r#""c""#);
+    let view = overlay.view(None).await?;
+    let json = view.to_columns_string(ViewWindow::default()).await?;
+    assert_eq!(json, r#"{"id":[1,2,3],"x":["a","b","z"]}"#);
+    let base_view = table.view(None).await?;
+    let json = base_view.to_columns_string(ViewWindow::default()).await?;
+    assert_eq!(json, r#"{"id":[1,2,3],"x":["a","b","c"]}"#);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_overlay_base_cannot_be_deleted() -> Result<(), Box<dyn Error>> {
+    let server = perspective::server::Server::default();
+    let client = LocalClient::new(&server);
+    let table = indexed_table(&client).await?;
+    let overlay = table.overlay().await?;
+    assert!(table.delete().await.is_err());
+    overlay.delete().await?;
+    table.delete().await?;
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_overlay_is_private_to_session() -> Result<(), Box<dyn Error>> {
+    let server = perspective::server::Server::default();
+    let client = LocalClient::new(&server);
+    let table = indexed_table(&client).await?;
+    let overlay = table.overlay().await?;
+
+    let other = LocalClient::new(&server);
+    assert!(other.open_table(table.get_name().to_owned()).await.is_ok());
+    assert!(other
+        .open_table(overlay.get_name().to_owned())
+        .await
+        .is_err());
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_overlay_rejects_requests_from_other_sessions() -> Result<(), Box<dyn Error>> {
+    let server = perspective::server::Server::default();
+    let client = LocalClient::new(&server);
+    let table = indexed_table(&client).await?;
+    let overlay = table.overlay().await?;
+
+    // Another session which guesses the overlay's name can't address it.
+    let responses = Arc::new(Mutex::new(vec![]));
+    let session = server
+        .new_session_with_callback({
+            let responses = responses.clone();
+            move |msg| {
+                responses.lock().unwrap().push(msg.to_vec());
+                Box::pin(async { Ok(()) })
+            }
+        })
+        .await;
+
+    let reqs = [
+        ClientReq::TableSizeReq(TableSizeReq {}),
+        ClientReq::TableSchemaReq(TableSchemaReq {}),
+        ClientReq::TableRenameReq(TableRenameReq {
+            new_name: "stolen".to_owned(),
+        }),
+        ClientReq::TableDeleteReq(TableDeleteReq {}),
+    ];
+
+    for (msg_id, client_req) in reqs.into_iter().enumerate() {
+        let req = Request {
+            msg_id: msg_id as u32 + 1,
+            entity_id: overlay.get_name().to_owned(),
+            client_req: Some(client_req),
+        };
+
+        session.handle_request(&req.encode_to_vec()).await?;
+    }
+
+    session.close().await;
+    let responses = responses.lock().unwrap();
+    assert_eq!(responses.len(), 4);
+    for resp in responses.iter() {
+        match Response::decode(resp.as_slice())?.client_resp {
+            Some(ClientResp::ServerError(err)) => {
+                assert!(err.message.contains("private to session"))
+            },
+            x => panic!("Expected an error, got {:?}", x),
+        }
+    }
+
+    // The overlay is untouched for the session which owns it.
+    assert_eq!(overlay.size().await?, 3);
+    let view = overlay.view(None).await?;
+    let json = view.to_columns_string(ViewWindow::default()).await?;
+    assert_eq!(json, r#"{"id":[1,2,3],"x":["a","b","c"]}"#);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_overlay_requires_index() -> Result<(), Box<dyn Error>> {
+    let server = perspective::server::Server::default();
+    let client = LocalClient::new(&server);
+    let table = client
+        .table(
+            UpdateData::Csv("x\n1\n2".to_owned()).into(),
+            TableInitOptions::default(),
+        )
+        .await?;
+
+    assert!(table.overlay().await.is_err());
+    Ok(())
+}
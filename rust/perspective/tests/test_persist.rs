@@ -0,0 +1,323 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use std::error::Error;
+use std::path::{Path, PathBuf};
+
+use perspective::persist::{StaticKeyProvider, Store};
+use perspective::server::Server;
+use perspective::LocalClient;
+use perspective_client::{Client, TableInitOptions, UpdateData, UpdateOptions, ViewWindow};
+
+const KEY: [u8; 32] = [7; 32];
+
+fn store_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!(
+        "perspective-persist-{}-{}",
+        name,
+        std::process::id()
+    ));
+    let _ = std::fs::remove_dir_all(&dir);
+    dir
+}
+
+async fn host_trades(client: &Client) -> Result<(), Box<dyn Error>> {
+    client
+        .table(
+            UpdateData::Csv("id,desk\n1,SECRET-DESK-A\n2,SECRET-DESK-B".to_owned()).into(),
+            TableInitOptions {
+                name: Some("trades".to_owned()),
+                index: Some("id".to_owned()),
+                ..TableInitOptions::default()
+            },
+        )
+        .await?;
+
+    Ok(())
+}
+
+async fn update_trades(client: &Client) -> Result<(), Box<dyn Error>> {
+    let table = client.open_table("trades".to_owned()).await?;
+    table
+        .update(
+            UpdateData::Csv("id,desk\n2,SECRET-DESK-C\n3,SECRET-DESK-D".to_owned()),
+            UpdateOptions::default(),
+        )
+        .await?;
+
+    table.remove(UpdateData::JsonRows("[1]".to_owned())).await?;
+    Ok(())
+}
+
+async fn trades_csv(client: &Client) -> Result<String, Box<dyn Error>> {
+    let table = client.open_table("trades".to_owned()).await?;
+    let view = table.view(None).await?;
+    let csv = view.to_csv(ViewWindow::default()).await?;
+    view.delete().await?;
+    Ok(csv)
+}
+
+fn files(dir: &Path) -> Result<Vec<PathBuf>, Box<dyn Error>> {
+    let mut files = vec![];
+    for entry in std::fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.is_dir() {
+            files.extend(self::files(&path)?);
+        } else {
+            files.push(path);
+        }
+    }
+
+    files.sort();
+    Ok(files)
+}
+
+#[tokio::test]
+async fn test_restore_replays_wal_over_snapshot() -> Result<(), Box<dyn Error>> {
+    let dir = store_dir("wal");
+    let store = Store::new(&dir);
+    let server = Server::default();
+    let client = LocalClient::new(&server);
+    host_trades(&client).await?;
+    let persisted = store.persist(&server, "trades").await?;
+    update_trades(&client).await?;
+    let expected = trades_csv(&client).await?;
+    persisted.close().await?;
+    client.close().await;
+
+    let server = Server::default();
+    let restored = store.restore(&server).await?;
+    assert_eq!(restored.len(), 1);
+    assert_eq!(restored[0].name(), "trades");
+    let client = LocalClient::new(&server);
+    assert_eq!(trades_csv(&client).await?, expected);
+    for persisted in restored {
+        persisted.close().await?;
+    }
+
+    client.close().await;
+    std::fs::remove_dir_all(&dir)?;
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_snapshot_compacts_wal() -> Result<(), Box<dyn Error>> {
+    let dir = store_dir("compact");
+    let store = Store::new(&dir);
+    let server = Server::default();
+    let client = LocalClient::new(&server);
+    host_trades(&client).await?;
+    let persisted = store.persist(&server, "trades").await?;
+    update_trades(&client).await?;
+    persisted.snapshot().await?;
+    let names: Vec<_> = files(&dir)?
+        .iter()
+        .map(|x| x.file_name().unwrap().to_string_lossy().into_owned())
+        .collect();
+
+    assert_eq!(names, vec![
+        "0000000000000001.snapshot",
+        "0000000000000001.wal"
+    ]);
+
+    let expected = trades_csv(&client).await?;
+    persisted.close().await?;
+    client.close().await;
+
+    let server = Server::default();
+    for persisted in store.restore(&server).await? {
+        persisted.close().await?;
+    }
+
+    let client = LocalClient::new(&server);
+    assert_eq!(trades_csv(&client).await?, expected);
+    client.close().await;
+    std::fs::remove_dir_all(&dir)?;
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_restore_recreates_pinned_views() -> Result<(), Box<dyn Error>> {
+    let dir = store_dir("pinned");
+    let store = Store::new(&dir);
+    let server = Server::default();
+    let client = LocalClient::new(&server);
+    host_trades(&client).await?;
+    let config = serde_json::from_str(r#"{"group_by": ["desk"], "columns": ["id"]}"#)?;
+    let mut persisted = store.persist(&server, "trades").await?;
+    persisted.pin_view(config).await?;
+    update_trades(&client).await?;
+    let pinned = persisted.pinned_views().collect::<Vec<_>>();
+    assert_eq!(pinned.len(), 1);
+    let expected = pinned[0].to_columns_string(ViewWindow::default()).await?;
+    persisted.close().await?;
+    client.close().await;
+
+    // The pinned view is materialized before `restore` returns, over the
+    // snapshot and the WAL since.
+    let server = Server::default();
+    let restored = store.restore(&server).await?;
+    let pinned = restored[0].pinned_views().collect::<Vec<_>>();
+    assert_eq!(pinned.len(), 1);
+    assert_eq!(pinned[0].get_config().await?.group_by, vec!["desk"]);
+    assert_eq!(
+        pinned[0].to_columns_string(ViewWindow::default()).await?,
+        expected
+    );
+
+    for persisted in restored {
+        persisted.close().await?;
+    }
+
+    std::fs::remove_dir_all(&dir)?;
+    Ok(())
+}
+
+/// `arrow` as CSV, via a table on `client`.
+async fn arrow_csv(client: &Client, arrow: Vec<u8>) -> Result<String, Box<dyn Error>> {
+    let table = client
+        .table(
+            UpdateData::Arrow(arrow.into()).into(),
+            TableInitOptions::default(),
+        )
+        .await?;
+
+    let view = table.view(None).await?;
+    let csv = view.to_csv(ViewWindow::default()).await?;
+    view.delete().await?;
+    table.delete().await?;
+    Ok(csv.replace('"', ""))
+}
+
+#[tokio::test]
+async fn test_diff_between_retained_snapshots() -> Result<(), Box<dyn Error>> {
+    let dir = store_dir("diff");
+    let store = Store::new(&dir).with_retained_snapshots(2);
+    let server = Server::default();
+    let client = LocalClient::new(&server);
+    host_trades(&client).await?;
+    let persisted = store.persist(&server, "trades").await?;
+    update_trades(&client).await?;
+    let version = persisted.snapshot().await?;
+    assert_eq!(persisted.versions()?, vec![0, version]);
+
+    let diff = persisted.diff(0, version).await?;
+    assert_eq!(
+        arrow_csv(&client, diff.added).await?,
+        "id,desk\n3,SECRET-DESK-D\n"
+    );
+
+    assert_eq!(
+        arrow_csv(&client, diff.removed).await?,
+        "id,desk\n1,SECRET-DESK-A\n"
+    );
+
+    assert_eq!(
+        arrow_csv(&client, diff.changed).await?,
+        "id,desk\n2,SECRET-DESK-C\n"
+    );
+
+    // Only the newest two snapshots are retained.
+    let newest = persisted.snapshot().await?;
+    assert_eq!(persisted.versions()?, vec![version, newest]);
+    let diff = persisted.diff(version, newest).await?;
+    assert!(!arrow_csv(&client, diff.changed).await?.contains("SECRET"));
+    persisted.close().await?;
+    client.close().await;
+    std::fs::remove_dir_all(&dir)?;
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_persist_requires_index() -> Result<(), Box<dyn Error>> {
+    let dir = store_dir("index");
+    let server = Server::default();
+    let client = LocalClient::new(&server);
+    client
+        .table(
+            UpdateData::Csv("x\n1".to_owned()).into(),
+            TableInitOptions {
+                name: Some("unindexed".to_owned()),
+                ..TableInitOptions::default()
+            },
+        )
+        .await?;
+
+    assert!(Store::new(&dir)
+        .persist(&server, "unindexed")
+        .await
+        .is_err());
+    client.close().await;
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_encrypted_store() -> Result<(), Box<dyn Error>> {
+    let dir = store_dir("encrypted");
+    let store = Store::new(&dir).with_key_provider(StaticKeyProvider::new("k1", KEY));
+    let server = Server::default();
+    let client = LocalClient::new(&server);
+    host_trades(&client).await?;
+    let persisted = store.persist(&server, "trades").await?;
+    update_trades(&client).await?;
+    let expected = trades_csv(&client).await?;
+    persisted.close().await?;
+    client.close().await;
+
+    // Neither the snapshot nor the WAL contain the plaintext.
+    let files = files(&dir)?;
+    assert_eq!(files.len(), 2);
+    for path in &files {
+        let bytes = std::fs::read(path)?;
+        assert!(!bytes.windows(11).any(|x| x == b"SECRET-DESK"));
+    }
+
+    // Without the key, or with the wrong one, the files can't be read.
+    assert!(Store::new(&dir).restore(&Server::default()).await.is_err());
+    let wrong_key = Store::new(&dir).with_key_provider(StaticKeyProvider::new("k1", [8; 32]));
+    assert!(wrong_key.restore(&Server::default()).await.is_err());
+
+    let server = Server::default();
+    for persisted in store.restore(&server).await? {
+        persisted.close().await?;
+    }
+
+    let client = LocalClient::new(&server);
+    assert_eq!(trades_csv(&client).await?, expected);
+    client.close().await;
+    std::fs::remove_dir_all(&dir)?;
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_encrypted_store_detects_tampering() -> Result<(), Box<dyn Error>> {
+    let dir = store_dir("tamper");
+    let store = Store::new(&dir).with_key_provider(StaticKeyProvider::new("k1", KEY));
+    let server = Server::default();
+    let client = LocalClient::new(&server);
+    host_trades(&client).await?;
+    store.persist(&server, "trades").await?.close().await?;
+    client.close().await;
+
+    let snapshot = files(&dir)?
+        .into_iter()
+        .find(|x| x.extension().is_some_and(|x| x == "snapshot"))
+        .unwrap();
+
+    let mut bytes = std::fs::read(&snapshot)?;
+    let last = bytes.len() - 1;
+    bytes[last] ^= 1;
+    std::fs::write(&snapshot, bytes)?;
+    assert!(store.restore(&Server::default()).await.is_err());
+    std::fs::remove_dir_all(&dir)?;
+    Ok(())
+}
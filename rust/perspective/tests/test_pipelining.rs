@@ -0,0 +1,253 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use std::future::Future;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex, OnceLock};
+use std::task::Poll;
+
+use perspective::client::proto::Request;
+use perspective::client::{Client, ClientError, TableInitOptions, UpdateData, UpdateOptions};
+use perspective::server::{Server, Session};
+use prost::Message;
+
+/// A [`Client`] whose requests are queued until [`DeferredClient::flush`],
+/// like a connection to a remote [`Server`].
+struct DeferredClient {
+    client: Client,
+    session: Session,
+    pending: Arc<Mutex<Vec<Vec<u8>>>>,
+    sent: Mutex<Vec<&'static str>>,
+}
+
+impl DeferredClient {
+    async fn new(server: &Server) -> Self {
+        let client_slot: Arc<OnceLock<Client>> = Arc::default();
+        let session = server
+            .new_session_with_callback({
+                let client_slot = client_slot.clone();
+                move |msg| {
+                    let client_slot = client_slot.clone();
+                    Box::pin(async move {
+                        client_slot.get().unwrap().handle_response(msg).await?;
+                        Ok(())
+                    })
+                }
+            })
+            .await;
+
+        let pending: Arc<Mutex<Vec<Vec<u8>>>> = Arc::default();
+        let client = Client::new_with_callback({
+            let pending = pending.clone();
+            move |msg| {
+                pending.lock().unwrap().push(msg.to_vec());
+                Box::pin(async { Ok(()) })
+            }
+        });
+
+        let _ = client_slot.set(client.clone());
+        DeferredClient {
+            client,
+            session,
+            pending,
+            sent: Mutex::default(),
+        }
+    }
+
+    async fn flush(&self) {
+        let pending = std::mem::take(&mut *self.pending.lock().unwrap());
+        for msg in pending {
+            let req = Request::decode(msg.as_slice()).unwrap();
+            self.sent
+                .lock()
+                .unwrap()
+                .push(req.client_req.unwrap().name());
+
+            self.session.handle_request(&msg).await.unwrap();
+            self.session.poll().await.unwrap();
+        }
+    }
+
+    /// Run `fut` to completion, sending its requests whenever it blocks.
+    async fn drive<T>(&self, fut: impl Future<Output = T>) -> T {
+        let mut fut = std::pin::pin!(fut);
+        loop {
+            if let Poll::Ready(x) = futures::poll!(fut.as_mut()) {
+                return x;
+            }
+
+            self.flush().await;
+        }
+    }
+
+    fn count_sent(&self, name: &str) -> usize {
+        self.sent
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|x| **x == name)
+            .count()
+    }
+}
+
+#[tokio::test]
+async fn test_identical_reads_share_response() {
+    let server = Server::default();
+    let client = DeferredClient::new(&server).await;
+    let table = client
+        .drive(client.client.table(
+            UpdateData::Csv("x,y\n1,a\n2,b".to_owned()).into(),
+            TableInitOptions::default(),
+        ))
+        .await
+        .unwrap();
+
+    let (size1, size2, schema) = client
+        .drive(futures::future::join3(
+            table.size(),
+            table.size(),
+            table.schema(),
+        ))
+        .await;
+
+    assert_eq!(size1.unwrap(), 2);
+    assert_eq!(size2.unwrap(), 2);
+    assert_eq!(schema.unwrap().len(), 2);
+    assert_eq!(client.count_sent("table_size_req"), 1);
+    assert_eq!(client.count_sent("table_schema_req"), 1);
+    client.session.close().await;
+}
+
+#[tokio::test]
+async fn test_write_ends_read_sharing() {
+    let server = Server::default();
+    let client = DeferredClient::new(&server).await;
+    let table = client
+        .drive(client.client.table(
+            UpdateData::Csv("x,y\n1,a\n2,b".to_owned()).into(),
+            TableInitOptions::default(),
+        ))
+        .await
+        .unwrap();
+
+    let (before, update, after) = client
+        .drive(futures::future::join3(
+            table.size(),
+            table.update(
+                UpdateData::Csv("x,y\n3,c".to_owned()),
+                UpdateOptions::default(),
+            ),
+            table.size(),
+        ))
+        .await;
+
+    update.unwrap();
+    assert_eq!(before.unwrap(), 2);
+    assert_eq!(after.unwrap(), 3);
+    assert_eq!(client.count_sent("table_size_req"), 2);
+    client.session.close().await;
+}
+
+#[tokio::test]
+async fn test_dropped_read_does_not_block_shared_reads() {
+    let server = Server::default();
+    let client = DeferredClient::new(&server).await;
+    let table = client
+        .drive(client.client.table(
+            UpdateData::Csv("x,y\n1,a\n2,b".to_owned()).into(),
+            TableInitOptions::default(),
+        ))
+        .await
+        .unwrap();
+
+    // The second read shares the first, which is then dropped (e.g. by a
+    // timeout) before its response arrives.
+    let mut first = Box::pin(table.size());
+    let mut second = Box::pin(table.size());
+    assert!(futures::poll!(first.as_mut()).is_pending());
+    assert!(futures::poll!(second.as_mut()).is_pending());
+    drop(first);
+
+    assert_eq!(client.drive(second).await.unwrap(), 2);
+    assert_eq!(client.drive(table.size()).await.unwrap(), 2);
+    assert_eq!(client.count_sent("table_size_req"), 3);
+    client.session.close().await;
+}
+
+#[tokio::test]
+async fn test_shared_read_failure_is_shared() {
+    let server = Server::default();
+    let client_slot: Arc<OnceLock<Client>> = Arc::default();
+    let session = Arc::new(
+        server
+            .new_session_with_callback({
+                let client_slot = client_slot.clone();
+                move |msg| {
+                    let client_slot = client_slot.clone();
+                    Box::pin(async move {
+                        client_slot.get().unwrap().handle_response(msg).await?;
+                        Ok(())
+                    })
+                }
+            })
+            .await,
+    );
+
+    // Once disconnected, sends fail after yielding, so an identical read
+    // made in the meantime shares the in-flight request.
+    let connected = Arc::new(AtomicBool::new(true));
+    let failed_sends = Arc::new(AtomicUsize::new(0));
+    let client = Client::new_with_callback({
+        let session = session.clone();
+        let connected = connected.clone();
+        let failed_sends = failed_sends.clone();
+        move |msg| {
+            let session = session.clone();
+            let connected = connected.load(Ordering::SeqCst);
+            let failed_sends = failed_sends.clone();
+            Box::pin(async move {
+                if !connected {
+                    tokio::task::yield_now().await;
+                    failed_sends.fetch_add(1, Ordering::SeqCst);
+                    return Err("Disconnected".into());
+                }
+
+                session.handle_request(msg).await?;
+                session.poll().await?;
+                Ok(())
+            })
+        }
+    });
+
+    let _ = client_slot.set(client.clone());
+    let table = client
+        .table(
+            UpdateData::Csv("x,y\n1,a\n2,b".to_owned()).into(),
+            TableInitOptions::default(),
+        )
+        .await
+        .unwrap();
+
+    connected.store(false, Ordering::SeqCst);
+    let (size1, size2) = futures::future::join(table.size(), table.size()).await;
+    assert_eq!(failed_sends.load(Ordering::SeqCst), 1);
+    match (size1, size2) {
+        (Err(ClientError::ExternalError(err1)), Err(ClientError::ExternalError(err2))) => {
+            assert_eq!(err1.to_string(), "Disconnected");
+            assert_eq!(err2.to_string(), "Disconnected");
+        },
+        x => panic!(
+            "Expected both reads to fail with `ExternalError`, got {:?}",
+            x
+        ),
+    }
+}
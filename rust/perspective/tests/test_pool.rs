@@ -0,0 +1,52 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use perspective::client::{ClientError, ClientPool, TableInitOptions, UpdateData};
+use perspective::server::Server;
+use perspective::LocalClient;
+
+#[tokio::test]
+async fn test_pool_routes_by_server_name() -> Result<(), ClientError> {
+    let (server1, server2) = (Server::default(), Server::default());
+    let (client1, client2) = (LocalClient::new(&server1), LocalClient::new(&server2));
+    for (client, name, data) in [(&client1, "a", "x\n1"), (&client2, "b", "x\n1\n2")] {
+        let options = TableInitOptions {
+            name: Some(name.to_owned()),
+            ..TableInitOptions::default()
+        };
+
+        client
+            .table(UpdateData::Csv(data.to_owned()).into(), options)
+            .await?;
+    }
+
+    let pool = ClientPool::default();
+    pool.add("one", (*client1).clone())?;
+    pool.add("two", (*client2).clone())?;
+    assert!(pool.add("bad/name", (*client1).clone()).is_err());
+
+    let mut names = pool.get_hosted_table_names().await?;
+    names.sort();
+    assert_eq!(names, vec!["one/a", "two/b"]);
+    assert_eq!(pool.open_table("one/a").await?.size().await?, 1);
+    assert_eq!(pool.open_table("two/b").await?.size().await?, 2);
+    assert!(pool.open_table("two/a").await.is_err());
+    assert!(matches!(
+        pool.open_table("three/a").await,
+        Err(ClientError::UnknownServer(x)) if x == "three"
+    ));
+
+    drop(pool);
+    client1.close().await;
+    client2.close().await;
+    Ok(())
+}
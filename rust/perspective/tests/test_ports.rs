@@ -0,0 +1,131 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use std::error::Error;
+use std::sync::Arc;
+
+use perspective::LocalClient;
+use perspective_client::proto::ViewOnUpdateResp;
+use perspective_client::{OnUpdateOptions, TableInitOptions, UpdateData, UpdateOptions};
+use tokio::sync::Mutex;
+
+fn csv(data: &str) -> UpdateData {
+    UpdateData::Csv(data.to_owned())
+}
+
+#[tokio::test]
+async fn test_named_ports_are_reused_by_name() -> Result<(), Box<dyn Error>> {
+    let server = perspective::server::Server::default();
+    let client = LocalClient::new(&server);
+    let table = client
+        .table(csv("x,y\n1,2").into(), TableInitOptions::default())
+        .await?;
+
+    let risk = table.make_port("risk_feed").await?;
+    let prices = table.make_port("prices").await?;
+    let again = table.make_port("risk_feed").await?;
+    let anonymous = table.make_port("").await?;
+    assert_eq!(risk.name(), "risk_feed");
+    assert_eq!(again.id(), risk.id());
+    assert_ne!(prices.id(), risk.id());
+    assert_ne!(anonymous.id(), risk.id());
+    assert_ne!(anonymous.id(), prices.id());
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_port_updates_are_attributed_in_on_update() -> Result<(), Box<dyn Error>> {
+    let server = perspective::server::Server::default();
+    let client = LocalClient::new(&server);
+    let table = client
+        .table(csv("x,y\n1,2").into(), TableInitOptions::default())
+        .await?;
+
+    let port = table.make_port("risk_feed").await?;
+    let view = table.view(None).await?;
+    let updates: Arc<Mutex<Vec<ViewOnUpdateResp>>> = Arc::default();
+    view.on_update(
+        {
+            let updates = updates.clone();
+            move |update| {
+                let updates = updates.clone();
+                async move { updates.lock().await.push(update) }
+            }
+        },
+        OnUpdateOptions::default(),
+    )
+    .await?;
+
+    assert_eq!(
+        port.update(csv("x,y\n3,4"), UpdateOptions::default())
+            .await?,
+        1
+    );
+    assert_eq!(
+        port.update(csv("x,y\n5,6"), UpdateOptions::default())
+            .await?,
+        2
+    );
+    table
+        .update(csv("x,y\n7,8"), UpdateOptions::default())
+        .await?;
+
+    let updates = updates.lock().await;
+    let attributions = updates
+        .iter()
+        .map(|x| (x.port_id, x.port_name.clone(), x.port_sequence))
+        .collect::<Vec<_>>();
+
+    assert_eq!(attributions, vec![
+        (port.id(), Some("risk_feed".to_owned()), 1),
+        (port.id(), Some("risk_feed".to_owned()), 2),
+        (0, None, 1),
+    ]);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_port_flush_commits_batched_updates() -> Result<(), Box<dyn Error>> {
+    let server = perspective::server::Server::default();
+    let client = LocalClient::new(&server);
+    let table = client
+        .table(csv("x,y\n1,2").into(), TableInitOptions {
+            batch_latency_ms: Some(60_000),
+            ..TableInitOptions::default()
+        })
+        .await?;
+
+    let port = table.make_port("risk_feed").await?;
+    let view = table.view(None).await?;
+    let sequences: Arc<Mutex<Vec<u64>>> = Arc::default();
+    view.on_update(
+        {
+            let sequences = sequences.clone();
+            move |update: ViewOnUpdateResp| {
+                let sequences = sequences.clone();
+                async move { sequences.lock().await.push(update.port_sequence) }
+            }
+        },
+        OnUpdateOptions::default(),
+    )
+    .await?;
+
+    port.update(csv("x,y\n3,4"), UpdateOptions::default())
+        .await?;
+    port.update(csv("x,y\n5,6"), UpdateOptions::default())
+        .await?;
+    assert!(sequences.lock().await.is_empty());
+    port.flush().await?;
+    assert_eq!(*sequences.lock().await, vec![2]);
+    Ok(())
+}
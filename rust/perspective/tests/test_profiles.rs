@@ -0,0 +1,44 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use std::error::Error;
+
+use perspective::LocalClient;
+
+#[tokio::test]
+async fn test_take_profiles_is_empty_by_default() -> Result<(), Box<dyn Error>> {
+    let server = perspective::server::Server::default();
+    let client = LocalClient::new(&server);
+    assert!(client.take_profiles().await?.is_empty());
+    assert!(client.diagnostics(false).await?.profiles.is_empty());
+    Ok(())
+}
+
+#[cfg(all(feature = "profiling", unix))]
+#[tokio::test]
+async fn test_cpu_profile_is_taken_once() -> Result<(), Box<dyn Error>> {
+    use perspective_client::proto::profile::Kind;
+
+    let server = perspective::server::Server::default();
+    let client = LocalClient::new(&server);
+    server.start_cpu_profile(100).await?;
+    let pprof = server.stop_cpu_profile().await?;
+    assert!(!pprof.is_empty());
+    assert!(server.stop_cpu_profile().await.is_err());
+
+    let profiles = client.take_profiles().await?;
+    assert_eq!(profiles.len(), 1);
+    assert_eq!(profiles[0].kind(), Kind::Cpu);
+    assert_eq!(profiles[0].pprof, pprof);
+    assert!(client.take_profiles().await?.is_empty());
+    Ok(())
+}
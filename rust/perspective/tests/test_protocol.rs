@@ -0,0 +1,225 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use std::collections::HashMap;
+use std::error::Error;
+use std::sync::{Arc, Mutex, OnceLock};
+
+use perspective::client::config::ViewConfigUpdate;
+use perspective::client::{Client, TableInitOptions, UpdateData, ViewWindow};
+use perspective::server::Server;
+use perspective::LocalClient;
+use perspective_client::proto::request::ClientReq;
+use perspective_client::proto::response::ClientResp;
+use perspective_client::proto::{Request, Response, ServerHelloReq, StatusCode};
+use perspective_client::protocol::{self, FEATURES, MAX_PROTOCOL_VERSION};
+use prost::bytes::Buf;
+use prost::encoding::{decode_key, decode_varint, skip_field, DecodeContext, WireType};
+use prost::Message;
+use prost_types::field_descriptor_proto::Type;
+use prost_types::DescriptorProto;
+
+#[tokio::test]
+async fn test_client_negotiates_protocol_on_init() -> Result<(), Box<dyn Error>> {
+    let server = Server::default();
+    let client = LocalClient::new(&server);
+    client.init().await?;
+    let protocol = client.get_protocol()?;
+    assert_eq!(protocol.protocol_version, MAX_PROTOCOL_VERSION);
+    for feature in FEATURES {
+        assert!(protocol.supports(feature));
+    }
+
+    assert!(!protocol.supports("not_a_feature"));
+    client.close().await;
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_server_rejects_disjoint_protocol_versions() -> Result<(), Box<dyn Error>> {
+    let server = Server::default();
+    let responses = Arc::new(Mutex::new(vec![]));
+    let session = server
+        .new_session_with_callback({
+            let responses = responses.clone();
+            move |msg| {
+                responses.lock().unwrap().push(msg.to_vec());
+                Box::pin(async { Ok(()) })
+            }
+        })
+        .await;
+
+    let req = Request {
+        msg_id: 1,
+        entity_id: "".to_owned(),
+        client_req: Some(ClientReq::ServerHelloReq(ServerHelloReq {
+            min_protocol_version: MAX_PROTOCOL_VERSION + 1,
+            max_protocol_version: MAX_PROTOCOL_VERSION + 2,
+            features: vec!["table_flush".to_owned()],
+            client_version: "99.0.0".to_owned(),
+        })),
+    };
+
+    session.handle_request(&req.encode_to_vec()).await?;
+    session.close().await;
+    let responses = responses.lock().unwrap();
+    assert_eq!(responses.len(), 1);
+    match Response::decode(responses[0].as_slice())?.client_resp {
+        Some(ClientResp::ServerError(err)) => {
+            assert_eq!(err.status_code(), StatusCode::ProtocolMismatch);
+            assert!(err.message.contains("99.0.0"));
+        },
+        x => panic!("Expected a protocol mismatch, got {:?}", x),
+    }
+
+    Ok(())
+}
+
+/// Every message in [`protocol::descriptor`], by fully-qualified name.
+fn descriptor_messages() -> HashMap<String, DescriptorProto> {
+    fn insert(scope: &str, message: DescriptorProto, out: &mut HashMap<String, DescriptorProto>) {
+        let name = format!("{}.{}", scope, message.name());
+        for nested in message.nested_type.clone() {
+            insert(&name, nested, out);
+        }
+
+        out.insert(name, message);
+    }
+
+    let mut messages = HashMap::new();
+    for file in protocol::descriptor().file {
+        let package = format!(".{}", file.package());
+        for message in file.message_type {
+            insert(&package, message, &mut messages);
+        }
+    }
+
+    messages
+}
+
+/// Assert that every field on the wire in `buf`, recursively, is declared by
+/// the descriptor of `type_name`, returning the names of its top-level
+/// fields.
+fn check_wire_fields(
+    messages: &HashMap<String, DescriptorProto>,
+    type_name: &str,
+    mut buf: &[u8],
+) -> Vec<String> {
+    let message = &messages[type_name];
+    let mut names = vec![];
+    while buf.has_remaining() {
+        let (tag, wire_type) = decode_key(&mut buf).unwrap();
+        let field = message
+            .field
+            .iter()
+            .find(|x| x.number() == tag as i32)
+            .unwrap_or_else(|| panic!("{} has no field {}", type_name, tag));
+
+        names.push(field.json_name().to_owned());
+        if field.r#type() == Type::Message && wire_type == WireType::LengthDelimited {
+            let len = decode_varint(&mut buf).unwrap() as usize;
+            check_wire_fields(messages, field.type_name(), &buf[..len]);
+            buf.advance(len);
+        } else {
+            skip_field(wire_type, tag, &mut buf, DecodeContext::default()).unwrap();
+        }
+    }
+
+    names
+}
+
+#[tokio::test]
+async fn test_descriptor_describes_exchanged_messages() -> Result<(), Box<dyn Error>> {
+    let server = Server::default();
+    let requests = Arc::new(Mutex::new(vec![]));
+    let responses = Arc::new(Mutex::new(vec![]));
+    let client_cell = Arc::new(OnceLock::<Client>::new());
+    let session = Arc::new(
+        server
+            .new_session_with_callback({
+                let responses = responses.clone();
+                let client_cell = client_cell.clone();
+                move |msg| {
+                    responses.lock().unwrap().push(msg.to_vec());
+                    let client = client_cell.get().unwrap().clone();
+                    Box::pin(async move {
+                        client.handle_response(msg).await?;
+                        Ok(())
+                    })
+                }
+            })
+            .await,
+    );
+
+    let client = Client::new_with_callback({
+        let requests = requests.clone();
+        let session = Arc::downgrade(&session);
+        move |msg| {
+            requests.lock().unwrap().push(msg.to_vec());
+            let session = session.upgrade().unwrap();
+            Box::pin(async move {
+                session.handle_request(msg).await?;
+                session.poll().await?;
+                Ok(())
+            })
+        }
+    });
+
+    let _ = client_cell.set(client.clone());
+    client.init().await?;
+    client.system_info().await?;
+    let table = client
+        .table(
+            UpdateData::Csv("x,y\n1,a\n2,b".to_owned()).into(),
+            TableInitOptions::default(),
+        )
+        .await?;
+
+    let view = table
+        .view(Some(ViewConfigUpdate {
+            group_by: Some(vec!["y".to_owned()]),
+            ..ViewConfigUpdate::default()
+        }))
+        .await?;
+
+    view.to_json_string(ViewWindow::default()).await?;
+    view.delete().await?;
+    table.delete().await?;
+    let Ok(session) = Arc::try_unwrap(session) else {
+        panic!("`Session` is still referenced");
+    };
+
+    session.close().await;
+
+    // Each message's payload is a field of the root message which the JSON
+    // Schema also describes.
+    let messages = descriptor_messages();
+    let schema = protocol::json_schema();
+    for (root, exchanged) in [("Request", requests), ("Response", responses)] {
+        let exchanged = exchanged.lock().unwrap();
+        assert!(!exchanged.is_empty());
+        let properties = &schema["$defs"][format!("perspective.proto.{}", root)]["properties"];
+        for msg in exchanged.iter() {
+            let names = check_wire_fields(&messages, &format!(".perspective.proto.{}", root), msg);
+            for name in names {
+                assert!(
+                    properties.get(&name).is_some(),
+                    "{}.{} not in schema",
+                    root,
+                    name
+                );
+            }
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,296 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use std::collections::HashMap;
+use std::error::Error;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex, OnceLock};
+use std::time::Duration;
+
+use futures::future::BoxFuture;
+use perspective::client::{Client, OnUpdateOptions, TableInitOptions, UpdateData, UpdateOptions};
+use perspective::proxy::{
+    BackendConnector, BackendReceiver, BackendSender, Proxy, ProxyConfig, ProxySession,
+};
+use perspective::server::{Server, ServerError, Session, SessionHandler};
+use perspective::LocalClient;
+
+#[derive(Clone)]
+struct BackendHandler(BackendReceiver);
+
+impl SessionHandler for BackendHandler {
+    async fn send_response<'a>(&'a mut self, msg: &'a [u8]) -> Result<(), ServerError> {
+        self.0.handle_response(msg).await
+    }
+}
+
+struct LocalSender(Session);
+
+impl BackendSender for LocalSender {
+    fn send<'a>(&'a self, msg: &'a [u8]) -> BoxFuture<'a, Result<(), ServerError>> {
+        Box::pin(async move {
+            self.0.handle_request(msg).await?;
+            self.0.poll().await
+        })
+    }
+}
+
+/// Connects to in-process [`Server`]s by name, joining each connection's
+/// backend [`Session`] to `groups`.
+#[derive(Clone, Default)]
+struct LocalConnector {
+    servers: HashMap<String, Server>,
+    groups: Vec<String>,
+    receivers: Arc<Mutex<Vec<BackendReceiver>>>,
+    connects: Arc<AtomicUsize>,
+}
+
+impl BackendConnector for LocalConnector {
+    fn connect<'a>(
+        &'a self,
+        backend: &'a str,
+        receiver: BackendReceiver,
+    ) -> BoxFuture<'a, Result<Box<dyn BackendSender>, ServerError>> {
+        Box::pin(async move {
+            let server = self.servers.get(backend).ok_or("Unknown backend")?;
+            self.connects.fetch_add(1, Ordering::SeqCst);
+            self.receivers.lock().unwrap().push(receiver.clone());
+            let session = server.new_session(BackendHandler(receiver)).await;
+            for group in &self.groups {
+                session.join_group(group).await;
+            }
+
+            Ok(Box::new(LocalSender(session)) as Box<dyn BackendSender>)
+        })
+    }
+}
+
+#[derive(Clone)]
+struct EdgeHandler(Arc<OnceLock<Client>>);
+
+impl SessionHandler for EdgeHandler {
+    async fn send_response<'a>(&'a mut self, msg: &'a [u8]) -> Result<(), ServerError> {
+        self.0.get().unwrap().handle_response(msg).await?;
+        Ok(())
+    }
+}
+
+/// A [`Client`] for `tenant` connected through `proxy`.
+async fn edge_client(proxy: &Proxy, tenant: &str) -> Client {
+    let cell = Arc::new(OnceLock::new());
+    let session: Arc<ProxySession> =
+        Arc::new(proxy.new_session(tenant, EdgeHandler(cell.clone())).await);
+
+    let client = Client::new_with_callback(move |msg| {
+        let session = session.clone();
+        Box::pin(async move { session.handle_request(msg).await })
+    });
+
+    let _ = cell.set(client.clone());
+    client
+}
+
+fn csv(data: &str) -> UpdateData {
+    UpdateData::Csv(data.to_owned())
+}
+
+fn named(name: &str) -> TableInitOptions {
+    let mut options = TableInitOptions::default();
+    options.set_name(name);
+    options
+}
+
+async fn hosted_table_names(server: &Server) -> Result<Vec<String>, Box<dyn Error>> {
+    let client = LocalClient::new(server);
+    let names = client.get_hosted_table_names().await?;
+    client.close().await;
+    Ok(names)
+}
+
+#[tokio::test]
+async fn test_proxy_routes_tenants_to_backends() -> Result<(), Box<dyn Error>> {
+    let (a, b) = (Server::default(), Server::default());
+    let connector = LocalConnector {
+        servers: HashMap::from([("a".to_owned(), a.clone()), ("b".to_owned(), b.clone())]),
+        ..LocalConnector::default()
+    };
+
+    let proxy = Proxy::new(connector).with_router(|tenant| tenant.to_owned());
+    let client_a = edge_client(&proxy, "a").await;
+    let client_b = edge_client(&proxy, "b").await;
+    client_a.table(csv("x\n1").into(), named("t_a")).await?;
+    client_b.table(csv("x\n1").into(), named("t_b")).await?;
+    assert_eq!(hosted_table_names(&a).await?, vec!["t_a".to_owned()]);
+    assert_eq!(hosted_table_names(&b).await?, vec!["t_b".to_owned()]);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_proxy_relays_subscriptions_between_sessions() -> Result<(), Box<dyn Error>> {
+    let server = Server::default();
+    let connector = LocalConnector {
+        servers: HashMap::from([("".to_owned(), server.clone())]),
+        ..LocalConnector::default()
+    };
+
+    let connects = connector.connects.clone();
+    let proxy = Proxy::new(connector).with_config(ProxyConfig {
+        connections_per_backend: 1,
+    });
+
+    let reader = edge_client(&proxy, "tenant").await;
+    let writer = edge_client(&proxy, "tenant").await;
+    let table = reader.table(csv("x\n1").into(), named("t")).await?;
+    let view = table.view(None).await?;
+    let (send, mut receive) = tokio::sync::mpsc::unbounded_channel();
+    let update_id = view
+        .on_update(
+            move |_| {
+                let send = send.clone();
+                async move {
+                    let _ = send.send(());
+                }
+            },
+            OnUpdateOptions::default(),
+        )
+        .await?;
+
+    let writer_table = writer.open_table("t".to_owned()).await?;
+    writer_table
+        .update(csv("x\n2"), UpdateOptions::default())
+        .await?;
+
+    tokio::time::timeout(Duration::from_secs(5), receive.recv()).await?;
+    view.remove_update(update_id).await?;
+    assert_eq!(view.num_rows().await?, 2);
+    assert_eq!(connects.load(Ordering::SeqCst), 1);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_proxy_reconnects_after_close() -> Result<(), Box<dyn Error>> {
+    let server = Server::default();
+    let connector = LocalConnector {
+        servers: HashMap::from([("".to_owned(), server.clone())]),
+        ..LocalConnector::default()
+    };
+
+    let connects = connector.connects.clone();
+    let receivers = connector.receivers.clone();
+    let proxy = Proxy::new(connector);
+    let client = edge_client(&proxy, "tenant").await;
+    let table = client.table(csv("x\n1\n2").into(), named("t")).await?;
+    let receiver = receivers.lock().unwrap()[0].clone();
+    receiver.close().await;
+    assert_eq!(table.size().await?, 2);
+    assert_eq!(connects.load(Ordering::SeqCst), 2);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_proxy_drops_subscriptions_of_deleted_views() -> Result<(), Box<dyn Error>> {
+    let server = Server::default();
+    let connector = LocalConnector {
+        servers: HashMap::from([("".to_owned(), server.clone())]),
+        ..LocalConnector::default()
+    };
+
+    let proxy = Proxy::new(connector);
+    let client = edge_client(&proxy, "tenant").await;
+    let table = client.table(csv("x\n1").into(), named("t")).await?;
+    let view = table.view(None).await?;
+    view.on_update(|_| async {}, OnUpdateOptions::default())
+        .await?;
+
+    let deleted = Arc::new(AtomicBool::default());
+    view.on_delete(Box::new({
+        let deleted = deleted.clone();
+        move || deleted.store(true, Ordering::SeqCst)
+    }))
+    .await?;
+
+    assert_eq!(proxy.num_subscriptions(), 2);
+    view.delete().await?;
+    assert!(deleted.load(Ordering::SeqCst));
+    assert_eq!(proxy.num_subscriptions(), 0);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_proxy_relays_backend_notifications() -> Result<(), Box<dyn Error>> {
+    let server = Server::default();
+    let connector = LocalConnector {
+        servers: HashMap::from([("".to_owned(), server.clone())]),
+        groups: vec!["desk".to_owned()],
+        ..LocalConnector::default()
+    };
+
+    let proxy = Proxy::new(connector).with_config(ProxyConfig {
+        connections_per_backend: 1,
+    });
+
+    let reader = edge_client(&proxy, "tenant").await;
+    let writer = edge_client(&proxy, "tenant").await;
+    let broadcasts = Arc::new(Mutex::new(vec![]));
+    reader.on_broadcast({
+        let broadcasts = broadcasts.clone();
+        move |x| broadcasts.lock().unwrap().push(x.message)
+    });
+
+    let (send, mut receive) = tokio::sync::mpsc::unbounded_channel();
+    reader
+        .on_hosted_tables_update(move || {
+            let send = send.clone();
+            async move {
+                let _ = send.send(());
+            }
+        })
+        .await?;
+
+    writer.table(csv("x\n1").into(), named("t")).await?;
+    tokio::time::timeout(Duration::from_secs(5), receive.recv()).await?;
+    server.broadcast("desk", "halt").await?;
+    assert_eq!(*broadcasts.lock().unwrap(), vec!["halt".to_owned()]);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_proxy_invalidates_views_after_close() -> Result<(), Box<dyn Error>> {
+    let server = Server::default();
+    let connector = LocalConnector {
+        servers: HashMap::from([("".to_owned(), server.clone())]),
+        ..LocalConnector::default()
+    };
+
+    let receivers = connector.receivers.clone();
+    let proxy = Proxy::new(connector);
+    let client = edge_client(&proxy, "tenant").await;
+    let table = client.table(csv("x\n1\n2").into(), named("t")).await?;
+    let view = table.view(None).await?;
+    let deleted = Arc::new(AtomicBool::default());
+    view.on_delete(Box::new({
+        let deleted = deleted.clone();
+        move || deleted.store(true, Ordering::SeqCst)
+    }))
+    .await?;
+
+    let receiver = receivers.lock().unwrap()[0].clone();
+    receiver.close().await;
+    assert!(deleted.load(Ordering::SeqCst));
+    let err = view.num_rows().await.unwrap_err();
+    assert!(err.to_string().contains("backend connection closed"));
+    view.delete().await?;
+
+    let view = table.view(None).await?;
+    assert_eq!(view.num_rows().await?, 2);
+    Ok(())
+}
@@ -0,0 +1,75 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use std::error::Error;
+
+use perspective::server::{RateLimit, RateLimitConfig, Server};
+use perspective::LocalClient;
+use perspective_client::{ClientError, TableInitOptions, UpdateData};
+
+#[tokio::test]
+async fn test_rate_limited_requests_return_throttle_error() -> Result<(), Box<dyn Error>> {
+    let server = Server::default();
+    server
+        .set_rate_limits(
+            RateLimitConfig::default().with_limit("table_size_req", RateLimit::per_minute(1)),
+        )
+        .await;
+
+    let client = LocalClient::new(&server);
+    let table = client
+        .table(
+            UpdateData::Csv("x,y\n1,2\n3,4".to_owned()).into(),
+            TableInitOptions::default(),
+        )
+        .await?;
+
+    assert_eq!(table.size().await?, 2);
+    assert!(matches!(
+        table.size().await,
+        Err(ClientError::RateLimited { .. })
+    ));
+
+    assert_eq!(table.schema().await?.len(), 2);
+    client.close().await;
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_zero_capacity_rate_limit_rejects_without_poisoning_session(
+) -> Result<(), Box<dyn Error>> {
+    let server = Server::default();
+    server
+        .set_rate_limits(
+            RateLimitConfig::default().with_limit("table_size_req", RateLimit::per_second(0)),
+        )
+        .await;
+
+    let client = LocalClient::new(&server);
+    let table = client
+        .table(
+            UpdateData::Csv("x,y\n1,2\n3,4".to_owned()).into(),
+            TableInitOptions::default(),
+        )
+        .await?;
+
+    for _ in 0..2 {
+        assert!(matches!(
+            table.size().await,
+            Err(ClientError::RateLimited { .. })
+        ));
+    }
+
+    assert_eq!(table.schema().await?.len(), 2);
+    client.close().await;
+    Ok(())
+}
@@ -0,0 +1,107 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use std::collections::HashMap;
+use std::error::Error;
+
+use perspective::LocalClient;
+use perspective_client::config::{Expressions, ViewConfigUpdate};
+use perspective_client::{TableInitOptions, UpdateData, ViewWindow};
+
+const ROWS: &str = r#"[
+    {"line": "GET /api/users 200 12ms"},
+    {"line": "POST /api/orders 500 340ms"},
+    {"line": "healthcheck ok"}
+]"#;
+
+#[tokio::test]
+async fn test_regex_functions() -> Result<(), Box<dyn Error>> {
+    let server = perspective::server::Server::default();
+    let client = LocalClient::new(&server);
+    let table = client
+        .table(
+            UpdateData::JsonRows(ROWS.to_owned()).into(),
+            TableInitOptions::default(),
+        )
+        .await?;
+
+    let expressions = Expressions(HashMap::from([
+        (
+            "is_error".to_owned(),
+            r#"regex_match("line", ' 5[0-9][0-9] ')"#.to_owned(),
+        ),
+        (
+            "method".to_owned(),
+            r#"regex_extract("line", '^([A-Z]+) ([^ ]+)', 1)"#.to_owned(),
+        ),
+        (
+            "path".to_owned(),
+            r#"regex_extract("line", '^([A-Z]+) ([^ ]+)', 2)"#.to_owned(),
+        ),
+        (
+            "latency".to_owned(),
+            r#"regex_extract("line", '[0-9]+ms', 0)"#.to_owned(),
+        ),
+        (
+            "masked".to_owned(),
+            r#"regex_replace("line", '[0-9]+', '#')"#.to_owned(),
+        ),
+    ]));
+
+    let view = table
+        .view(Some(ViewConfigUpdate {
+            columns: Some(vec![
+                Some("is_error".to_owned()),
+                Some("method".to_owned()),
+                Some("path".to_owned()),
+                Some("latency".to_owned()),
+                Some("masked".to_owned()),
+            ]),
+            expressions: Some(expressions),
+            ..ViewConfigUpdate::default()
+        }))
+        .await?;
+
+    let json = view.to_columns_string(ViewWindow::default()).await?;
+    assert_eq!(
+        json,
+        r#"{"is_error":[false,true,false],"method":["GET","POST",null],"path":["/api/users","/api/orders",null],"latency":["12ms","340ms",null],"masked":["GET /api/users # #ms","POST /api/orders # #ms","healthcheck ok"]}"#
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_regex_extract_rejects_missing_group() -> Result<(), Box<dyn Error>> {
+    let server = perspective::server::Server::default();
+    let client = LocalClient::new(&server);
+    let table = client
+        .table(
+            UpdateData::JsonRows(ROWS.to_owned()).into(),
+            TableInitOptions::default(),
+        )
+        .await?;
+
+    let result = table
+        .view(Some(ViewConfigUpdate {
+            columns: Some(vec![Some("x".to_owned())]),
+            expressions: Some(Expressions(HashMap::from([(
+                "x".to_owned(),
+                r#"regex_extract("line", '([A-Z]+)', 2)"#.to_owned(),
+            )]))),
+            ..ViewConfigUpdate::default()
+        }))
+        .await;
+
+    assert!(result.is_err());
+    Ok(())
+}
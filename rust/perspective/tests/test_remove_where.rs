@@ -0,0 +1,87 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use std::error::Error;
+
+use perspective::LocalClient;
+use perspective_client::config::{Filter, FilterTerm, Scalar, ViewConfigUpdate};
+use perspective_client::{TableInitOptions, UpdateData, ViewWindow};
+
+fn filter(column: &str, op: &str, value: Scalar) -> Filter {
+    Filter::new(column.to_owned(), op.to_owned(), FilterTerm::Scalar(value))
+}
+
+#[tokio::test]
+async fn test_remove_where_removes_matching_rows() -> Result<(), Box<dyn Error>> {
+    let server = perspective::server::Server::default();
+    let client = LocalClient::new(&server);
+    let csv = "id,status,qty\n1,open,10\n2,closed,0\n3,closed,5\n4,open,0";
+    let table = client
+        .table(UpdateData::Csv(csv.to_owned()).into(), TableInitOptions {
+            index: Some("id".to_owned()),
+            ..TableInitOptions::default()
+        })
+        .await?;
+
+    let terms = vec![
+        filter("status", "==", Scalar::String("closed".to_owned())),
+        filter("qty", "==", Scalar::Float(0.0)),
+    ];
+
+    assert_eq!(table.remove_where(terms).await?, 1);
+    let view = table
+        .view(Some(ViewConfigUpdate {
+            columns: Some(vec![Some("id".to_owned())]),
+            ..ViewConfigUpdate::default()
+        }))
+        .await?;
+
+    let json = view.to_columns_string(ViewWindow::default()).await?;
+    assert_eq!(json, r#"{"id":[1,3,4]}"#);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_remove_where_without_matches_is_inert() -> Result<(), Box<dyn Error>> {
+    let server = perspective::server::Server::default();
+    let client = LocalClient::new(&server);
+    let table = client
+        .table(
+            UpdateData::Csv("id,qty\n1,10\n2,20".to_owned()).into(),
+            TableInitOptions {
+                index: Some("id".to_owned()),
+                ..TableInitOptions::default()
+            },
+        )
+        .await?;
+
+    let terms = vec![filter("qty", ">", Scalar::Float(100.0))];
+    assert_eq!(table.remove_where(terms).await?, 0);
+    assert_eq!(table.size().await?, 2);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_remove_where_requires_index() -> Result<(), Box<dyn Error>> {
+    let server = perspective::server::Server::default();
+    let client = LocalClient::new(&server);
+    let table = client
+        .table(
+            UpdateData::Csv("id,qty\n1,10\n2,20".to_owned()).into(),
+            TableInitOptions::default(),
+        )
+        .await?;
+
+    let terms = vec![filter("qty", ">", Scalar::Float(15.0))];
+    assert!(table.remove_where(terms).await.is_err());
+    Ok(())
+}
@@ -0,0 +1,220 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+use std::error::Error;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+use perspective::client::{
+    ColumnType, TableData, TableInitOptions, UpdateData, UpdateOptions, ViewWindow,
+};
+use perspective::server::Server;
+use perspective::LocalClient;
+
+#[tokio::test]
+async fn test_rename_preserves_views() -> Result<(), Box<dyn Error>> {
+    let server = Server::default();
+    let client = LocalClient::new(&server);
+    let mut table = client
+        .table(
+            UpdateData::Csv("x,y\n1,a\n2,b".to_owned()).into(),
+            TableInitOptions {
+                name: Some("prices".to_owned()),
+                ..TableInitOptions::default()
+            },
+        )
+        .await?;
+
+    let view = table.view(None).await?;
+    table.rename("prices_2024".to_owned()).await?;
+    assert_eq!(table.get_name(), "prices_2024");
+    assert_eq!(client.get_hosted_table_names().await?, vec!["prices_2024"]);
+    assert!(client.open_table("prices".to_owned()).await.is_err());
+
+    let reopened = client.open_table("prices_2024".to_owned()).await?;
+    assert_eq!(reopened.size().await?, 2);
+
+    table
+        .update(
+            UpdateData::Csv("x,y\n3,c".to_owned()),
+            UpdateOptions::default(),
+        )
+        .await?;
+
+    assert_eq!(
+        view.to_columns_string(ViewWindow::default()).await?,
+        r#"{"x":[1,2,3],"y":["a","b","c"]}"#
+    );
+
+    client.close().await;
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_rename_keeps_other_handles_valid() -> Result<(), Box<dyn Error>> {
+    let server = Server::default();
+    let client = LocalClient::new(&server);
+    let other_client = LocalClient::new(&server);
+    let mut table = client
+        .table(
+            UpdateData::Csv("x\n1".to_owned()).into(),
+            TableInitOptions {
+                name: Some("prices".to_owned()),
+                ..TableInitOptions::default()
+            },
+        )
+        .await?;
+
+    let clone = table.clone();
+    let other = other_client.open_table("prices".to_owned()).await?;
+    table.rename("prices_2024".to_owned()).await?;
+    assert_eq!(clone.size().await?, 1);
+    other
+        .update(UpdateData::Csv("x\n2".to_owned()), UpdateOptions::default())
+        .await?;
+
+    assert_eq!(table.size().await?, 2);
+    let view = other.view(None).await?;
+    assert_eq!(
+        view.to_columns_string(ViewWindow::default()).await?,
+        r#"{"x":[1,2]}"#
+    );
+
+    // A new table under the old name is no longer an alias of the renamed one.
+    let replacement = client
+        .table(
+            UpdateData::Csv("x\n3".to_owned()).into(),
+            TableInitOptions {
+                name: Some("prices".to_owned()),
+                ..TableInitOptions::default()
+            },
+        )
+        .await?;
+
+    assert_eq!(clone.size().await?, 1);
+    assert_eq!(replacement.size().await?, 1);
+    assert_eq!(table.size().await?, 2);
+    view.delete().await?;
+    other_client.close().await;
+    client.close().await;
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_rename_continues_arrow_ingest() -> Result<(), Box<dyn Error>> {
+    let server = Server::default();
+    let client = LocalClient::new(&server);
+    let source = client
+        .table(
+            UpdateData::Csv("x\n1\n2\n3".to_owned()).into(),
+            TableInitOptions::default(),
+        )
+        .await?;
+
+    let arrow = source
+        .view(None)
+        .await?
+        .to_arrow(ViewWindow::default())
+        .await?;
+
+    let mut table = client
+        .table(
+            TableData::Schema(vec![("x".to_owned(), ColumnType::Integer)]),
+            TableInitOptions {
+                name: Some("t".to_owned()),
+                ..TableInitOptions::default()
+            },
+        )
+        .await?;
+
+    let ingest = table.arrow_ingest(UpdateOptions::default());
+    let (head, tail) = arrow.split_at(arrow.len() / 2);
+    ingest.write(head.to_vec()).await?;
+    table.rename("t2".to_owned()).await?;
+    assert_eq!(ingest.finish(tail.to_vec()).await?, 3);
+    assert_eq!(table.size().await?, 3);
+    client.close().await;
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_rename_to_existing_name_fails() -> Result<(), Box<dyn Error>> {
+    let server = Server::default();
+    let client = LocalClient::new(&server);
+    let mut table = client
+        .table(
+            UpdateData::Csv("x\n1".to_owned()).into(),
+            TableInitOptions {
+                name: Some("a".to_owned()),
+                ..TableInitOptions::default()
+            },
+        )
+        .await?;
+
+    client
+        .table(
+            UpdateData::Csv("x\n2".to_owned()).into(),
+            TableInitOptions {
+                name: Some("b".to_owned()),
+                ..TableInitOptions::default()
+            },
+        )
+        .await?;
+
+    assert!(table.rename("b".to_owned()).await.is_err());
+    assert_eq!(table.get_name(), "a");
+    client.close().await;
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_unhost_deletes_views() -> Result<(), Box<dyn Error>> {
+    let server = Server::default();
+    let client = LocalClient::new(&server);
+    let table = client
+        .table(
+            UpdateData::Csv("x\n1".to_owned()).into(),
+            TableInitOptions {
+                name: Some("t".to_owned()),
+                ..TableInitOptions::default()
+            },
+        )
+        .await?;
+
+    let view = table.view(None).await?;
+    let view_deleted = Arc::new(AtomicBool::default());
+    view.on_delete(Box::new({
+        let view_deleted = view_deleted.clone();
+        move || view_deleted.store(true, Ordering::SeqCst)
+    }))
+    .await?;
+
+    let table_deleted = Arc::new(AtomicBool::default());
+    table
+        .on_delete(Box::new({
+            let table_deleted = table_deleted.clone();
+            move || table_deleted.store(true, Ordering::SeqCst)
+        }))
+        .await?;
+
+    server.unhost("t").await?;
+    assert!(view_deleted.load(Ordering::SeqCst));
+    assert!(table_deleted.load(Ordering::SeqCst));
+    assert!(client.get_hosted_table_names().await?.is_empty());
+    client.close().await;
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_unhost_missing_table_fails() {
+    let server = Server::default();
+    assert!(server.unhost("missing").await.is_err());
+}
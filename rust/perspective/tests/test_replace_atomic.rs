@@ -0,0 +1,101 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use std::error::Error;
+use std::sync::Arc;
+
+use perspective::LocalClient;
+use perspective_client::{OnUpdateOptions, Table, TableInitOptions, UpdateData, View, ViewWindow};
+use tokio::sync::Mutex;
+
+async fn count_updates(view: &View) -> Result<Arc<Mutex<u32>>, Box<dyn Error>> {
+    let count = Arc::new(Mutex::new(0));
+    view.on_update(
+        {
+            let count = count.clone();
+            move |_| {
+                let count = count.clone();
+                async move { *count.lock().await += 1 }
+            }
+        },
+        OnUpdateOptions::default(),
+    )
+    .await?;
+
+    Ok(count)
+}
+
+async fn indexed_table(client: &LocalClient) -> Result<Table, Box<dyn Error>> {
+    Ok(client
+        .table(
+            UpdateData::Csv("id,x\n1,a\n2,b\n3,c".to_owned()).into(),
+            TableInitOptions {
+                index: Some("id".to_owned()),
+                ..TableInitOptions::default()
+            },
+        )
+        .await?)
+}
+
+#[tokio::test]
+async fn test_replace_atomic_commits_once() -> Result<(), Box<dyn Error>> {
+    let server = perspective::server::Server::default();
+    let client = LocalClient::new(&server);
+    let table = indexed_table(&client).await?;
+    let view = table.view(None).await?;
+    let count = count_updates(&view).await?;
+    table
+        .replace_atomic(UpdateData::Csv("id,x\n2,y\n4,z".to_owned()))
+        .await?;
+
+    let json = view.to_columns_string(ViewWindow::default()).await?;
+    assert_eq!(json, r#"{"id":[2,4],"x":["y","z"]}"#);
+    assert_eq!(*count.lock().await, 1);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_replace_atomic_unindexed() -> Result<(), Box<dyn Error>> {
+    let server = perspective::server::Server::default();
+    let client = LocalClient::new(&server);
+    let table = client
+        .table(
+            UpdateData::Csv("x\n1\n2\n3".to_owned()).into(),
+            TableInitOptions::default(),
+        )
+        .await?;
+
+    let view = table.view(None).await?;
+    table
+        .replace_atomic(UpdateData::Csv("x\n4\n5".to_owned()))
+        .await?;
+
+    let json = view.to_columns_string(ViewWindow::default()).await?;
+    assert_eq!(json, r#"{"x":[4,5]}"#);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_replace_atomic_failure_leaves_table_unchanged() -> Result<(), Box<dyn Error>> {
+    let server = perspective::server::Server::default();
+    let client = LocalClient::new(&server);
+    let table = indexed_table(&client).await?;
+    let view = table.view(None).await?;
+    let result = table
+        .replace_atomic(UpdateData::JsonRows("[1, 2]".to_owned()))
+        .await;
+
+    assert!(result.is_err());
+    let json = view.to_columns_string(ViewWindow::default()).await?;
+    assert_eq!(json, r#"{"id":[1,2,3],"x":["a","b","c"]}"#);
+    Ok(())
+}
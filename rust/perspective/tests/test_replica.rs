@@ -0,0 +1,249 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use std::collections::HashMap;
+use std::error::Error;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use futures::future::BoxFuture;
+use perspective::client::config::{Filter, FilterTerm, Scalar};
+use perspective::client::{TableInitOptions, UpdateData, UpdateOptions, ViewWindow};
+use perspective::proxy::{BackendConnector, BackendReceiver, BackendSender};
+use perspective::replica::Replica;
+use perspective::server::{Server, ServerError, Session, SessionHandler};
+use perspective::LocalClient;
+
+#[derive(Clone)]
+struct BackendHandler(BackendReceiver);
+
+impl SessionHandler for BackendHandler {
+    async fn send_response<'a>(&'a mut self, msg: &'a [u8]) -> Result<(), ServerError> {
+        self.0.handle_response(msg).await
+    }
+}
+
+struct LocalSender(Session);
+
+impl BackendSender for LocalSender {
+    fn send<'a>(&'a self, msg: &'a [u8]) -> BoxFuture<'a, Result<(), ServerError>> {
+        Box::pin(async move {
+            self.0.handle_request(msg).await?;
+            self.0.poll().await
+        })
+    }
+}
+
+/// Connects to in-process [`Server`]s by name.
+#[derive(Clone, Default)]
+struct LocalConnector {
+    servers: HashMap<String, Server>,
+    receivers: Arc<Mutex<Vec<(String, BackendReceiver)>>>,
+}
+
+impl BackendConnector for LocalConnector {
+    fn connect<'a>(
+        &'a self,
+        backend: &'a str,
+        receiver: BackendReceiver,
+    ) -> BoxFuture<'a, Result<Box<dyn BackendSender>, ServerError>> {
+        Box::pin(async move {
+            let server = self.servers.get(backend).ok_or("Unknown backend")?;
+            self.receivers
+                .lock()
+                .unwrap()
+                .push((backend.to_owned(), receiver.clone()));
+
+            let session = server.new_session(BackendHandler(receiver)).await;
+            Ok(Box::new(LocalSender(session)) as Box<dyn BackendSender>)
+        })
+    }
+}
+
+fn named(name: &str) -> TableInitOptions {
+    let mut options = TableInitOptions::default();
+    options.set_name(name);
+    options
+}
+
+/// The size of `table` on `server`, or `None` if it is not hosted.
+async fn table_size(server: &Server, table: &str) -> Result<Option<usize>, Box<dyn Error>> {
+    let client = LocalClient::new(server);
+    let size = if client
+        .get_hosted_table_names()
+        .await?
+        .iter()
+        .any(|x| x == table)
+    {
+        Some(client.open_table(table.to_owned()).await?.size().await?)
+    } else {
+        None
+    };
+
+    client.close().await;
+    Ok(size)
+}
+
+/// Wait for `table` on `server` to have `size` rows (or, if `None`, to not
+/// be hosted).
+async fn wait_for_size(
+    server: &Server,
+    table: &str,
+    size: Option<usize>,
+) -> Result<(), Box<dyn Error>> {
+    for _ in 0..100 {
+        if table_size(server, table).await? == size {
+            return Ok(());
+        }
+
+        tokio::time::sleep(Duration::from_millis(50)).await;
+    }
+
+    Err(format!("Timed out waiting for `{}` to have {:?} rows", table, size).into())
+}
+
+#[tokio::test]
+async fn test_replica_catches_up_and_follows_writer() -> Result<(), Box<dyn Error>> {
+    let (writer, replica_server) = (Server::default(), Server::default());
+    let connector = LocalConnector {
+        servers: HashMap::from([("writer".to_owned(), writer.clone())]),
+        ..LocalConnector::default()
+    };
+
+    let client = LocalClient::new(&writer);
+    let mut options = named("t");
+    options.index = Some("x".to_owned());
+    let table = client
+        .table(UpdateData::Csv("x,y\n1,a\n2,b".to_owned()).into(), options)
+        .await?;
+
+    let replica = Replica::new(connector, ["writer"], &replica_server);
+    let task = tokio::spawn({
+        let replica = replica.clone();
+        async move { replica.run().await.map_err(|e| e.to_string()) }
+    });
+
+    wait_for_size(&replica_server, "t", Some(2)).await?;
+    let data = UpdateData::Csv("x,y\n2,c\n3,d".to_owned());
+    table.update(data, UpdateOptions::default()).await?;
+    wait_for_size(&replica_server, "t", Some(3)).await?;
+    client
+        .table(UpdateData::Csv("z\n1".to_owned()).into(), named("u"))
+        .await?;
+
+    wait_for_size(&replica_server, "u", Some(1)).await?;
+    writer.unhost("u").await?;
+    wait_for_size(&replica_server, "u", None).await?;
+    replica.close();
+    task.await??;
+    client.close().await;
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_replica_fails_over_to_standby_writer() -> Result<(), Box<dyn Error>> {
+    let (primary, standby, replica_server) =
+        (Server::default(), Server::default(), Server::default());
+
+    let connector = LocalConnector {
+        servers: HashMap::from([
+            ("primary".to_owned(), primary.clone()),
+            ("standby".to_owned(), standby.clone()),
+        ]),
+        ..LocalConnector::default()
+    };
+
+    let receivers = connector.receivers.clone();
+    for (server, data) in [(&primary, "x\n1"), (&standby, "x\n1\n2")] {
+        let client = LocalClient::new(server);
+        client
+            .table(UpdateData::Csv(data.to_owned()).into(), named("t"))
+            .await?;
+
+        client.close().await;
+    }
+
+    let replica = Replica::new(connector, ["primary", "standby"], &replica_server);
+    let task = tokio::spawn({
+        let replica = replica.clone();
+        async move { replica.run().await.map_err(|e| e.to_string()) }
+    });
+
+    wait_for_size(&replica_server, "t", Some(1)).await?;
+    let receiver = receivers.lock().unwrap()[0].clone();
+    assert_eq!(receiver.0, "primary");
+    receiver.1.close().await;
+    wait_for_size(&replica_server, "t", Some(2)).await?;
+    replica.close();
+    task.await??;
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_replica_follows_writer_removals() -> Result<(), Box<dyn Error>> {
+    let (writer, replica_server) = (Server::default(), Server::default());
+    let connector = LocalConnector {
+        servers: HashMap::from([("writer".to_owned(), writer.clone())]),
+        ..LocalConnector::default()
+    };
+
+    let client = LocalClient::new(&writer);
+    let mut options = named("t");
+    options.index = Some("x".to_owned());
+    let indexed = client
+        .table(
+            UpdateData::Csv("x,y\n1,a\n2,b\n3,c".to_owned()).into(),
+            options,
+        )
+        .await?;
+
+    let unindexed = client
+        .table(UpdateData::Csv("z\n1\n2".to_owned()).into(), named("u"))
+        .await?;
+
+    let replica = Replica::new(connector, ["writer"], &replica_server);
+    let task = tokio::spawn({
+        let replica = replica.clone();
+        async move { replica.run().await.map_err(|e| e.to_string()) }
+    });
+
+    wait_for_size(&replica_server, "t", Some(3)).await?;
+    wait_for_size(&replica_server, "u", Some(2)).await?;
+    indexed.remove(UpdateData::Csv("x\n2".to_owned())).await?;
+
+    wait_for_size(&replica_server, "t", Some(2)).await?;
+    let replica_client = LocalClient::new(&replica_server);
+    let view = replica_client
+        .open_table("t".to_owned())
+        .await?
+        .view(None)
+        .await?;
+
+    let json = view.to_columns_string(ViewWindow::default()).await?;
+    assert_eq!(json, r#"{"x":[1,3],"y":["a","c"]}"#);
+
+    view.delete().await?;
+    replica_client.close().await;
+    unindexed
+        .remove_where(vec![Filter::new(
+            "z".to_owned(),
+            "==".to_owned(),
+            FilterTerm::Scalar(Scalar::Float(1.0)),
+        )])
+        .await?;
+
+    wait_for_size(&replica_server, "u", Some(1)).await?;
+    replica.close();
+    task.await??;
+    client.close().await;
+    Ok(())
+}
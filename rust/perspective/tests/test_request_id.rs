@@ -0,0 +1,167 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+use std::collections::HashSet;
+use std::error::Error;
+use std::sync::{Arc, Mutex, OnceLock};
+
+use perspective::client::config::ViewConfigUpdate;
+use perspective::client::{Client, ClientError, TableInitOptions, UpdateData};
+use perspective::server::{RequestId, Server, ServerError, Session, SessionHandler};
+
+/// A [`SessionHandler`] which records the [`RequestId`] of every response.
+#[derive(Clone, Default)]
+struct RecordingHandler {
+    client: Arc<OnceLock<Client>>,
+    request_ids: Arc<Mutex<Vec<Option<RequestId>>>>,
+}
+
+impl SessionHandler for RecordingHandler {
+    async fn send_response<'a>(&'a mut self, msg: &'a [u8]) -> Result<(), ServerError> {
+        self.client.get().unwrap().handle_response(msg).await?;
+        Ok(())
+    }
+
+    async fn send_response_with_id<'a>(
+        &'a mut self,
+        msg: &'a [u8],
+        request_id: Option<RequestId>,
+    ) -> Result<(), ServerError> {
+        self.request_ids.lock().unwrap().push(request_id);
+        self.send_response(msg).await
+    }
+}
+
+async fn recording_client(server: &Server) -> (Client, RecordingHandler, Arc<Session>) {
+    let handler = RecordingHandler::default();
+    let session = Arc::new(server.new_session(handler.clone()).await);
+    let client = Client::new_with_callback({
+        // The handler holds the `Client`, so a strong reference here would
+        // keep the `Session` alive after `close_session`.
+        let session = Arc::downgrade(&session);
+        move |msg| {
+            let session = session.upgrade();
+            Box::pin(async move {
+                let Some(session) = session else {
+                    return Ok(());
+                };
+
+                session.handle_request(msg).await?;
+                session.poll().await?;
+                Ok(())
+            })
+        }
+    });
+
+    let _ = handler.client.set(client.clone());
+    (client, handler, session)
+}
+
+async fn close_session(session: Arc<Session>) {
+    let Ok(session) = Arc::try_unwrap(session) else {
+        panic!("`Session` is still referenced");
+    };
+
+    session.close().await;
+}
+
+/// The [`RequestId`] of the last response the handler received which has
+/// one; responses from `Session::poll` have none.
+fn last_request_id(handler: &RecordingHandler) -> Option<String> {
+    handler
+        .request_ids
+        .lock()
+        .unwrap()
+        .iter()
+        .rev()
+        .find_map(|x| *x)
+        .map(|x| x.to_string())
+}
+
+#[tokio::test]
+async fn test_responses_receive_distinct_request_ids() -> Result<(), Box<dyn Error>> {
+    let server = Server::default();
+    let (client, handler, session) = recording_client(&server).await;
+    let table = client
+        .table(
+            UpdateData::Csv("x,y\n1,2".to_owned()).into(),
+            TableInitOptions::default(),
+        )
+        .await?;
+
+    table.size().await?;
+    table.schema().await?;
+    let request_ids = handler.request_ids.lock().unwrap().clone();
+    let request_ids: Vec<RequestId> = request_ids.into_iter().flatten().collect();
+    assert!(request_ids.len() >= 3);
+    let unique: HashSet<RequestId> = request_ids.iter().copied().collect();
+    assert_eq!(unique.len(), request_ids.len());
+    close_session(session).await;
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_error_request_id_matches_handler() -> Result<(), Box<dyn Error>> {
+    let server = Server::default();
+    let (client, handler, session) = recording_client(&server).await;
+    let table = client
+        .table(
+            UpdateData::Csv("x,y\n1,2".to_owned()).into(),
+            TableInitOptions::default(),
+        )
+        .await?;
+
+    let config = ViewConfigUpdate {
+        group_by: Some(vec!["z".to_owned()]),
+        ..ViewConfigUpdate::default()
+    };
+
+    let request_id = match table.view(Some(config)).await {
+        Err(ClientError::ViewConfig { request_id, .. }) => request_id,
+        x => panic!("Expected a view config error, got {:?}", x.map(|_| ())),
+    };
+
+    assert!(request_id.starts_with("req-"));
+    assert_eq!(Some(request_id), last_request_id(&handler));
+    close_session(session).await;
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_engine_error_request_id_matches_handler() -> Result<(), Box<dyn Error>> {
+    let server = Server::default();
+    let (client, handler, session) = recording_client(&server).await;
+    let table = client
+        .table(
+            UpdateData::Csv("x,y\n1,2".to_owned()).into(),
+            TableInitOptions::default(),
+        )
+        .await?;
+
+    // A generic engine failure, rather than one with its own status code.
+    let view = table.view(None).await?;
+    let request_id = match table.delete().await {
+        Err(ClientError::Internal {
+            message,
+            request_id,
+        }) => {
+            assert!(message.contains("Cannot delete table with views"));
+            request_id
+        },
+        x => panic!("Expected an internal error, got {:?}", x),
+    };
+
+    assert!(request_id.starts_with("req-"));
+    assert_eq!(Some(request_id), last_request_id(&handler));
+    view.delete().await?;
+    close_session(session).await;
+    Ok(())
+}
@@ -0,0 +1,115 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use std::error::Error;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+use perspective::server::{ResponseQueueConfig, Server};
+use perspective_client::proto::request::ClientReq;
+use perspective_client::proto::{Request, Response, ServerHelloReq};
+use perspective_client::protocol::MAX_PROTOCOL_VERSION;
+use prost::Message;
+
+fn hello(msg_id: u32) -> Vec<u8> {
+    Request {
+        msg_id,
+        entity_id: "".to_owned(),
+        client_req: Some(ClientReq::ServerHelloReq(ServerHelloReq {
+            min_protocol_version: MAX_PROTOCOL_VERSION,
+            max_protocol_version: MAX_PROTOCOL_VERSION,
+            features: vec![],
+            client_version: "".to_owned(),
+        })),
+    }
+    .encode_to_vec()
+}
+
+#[tokio::test]
+async fn test_response_queue_spills_to_disk_in_order() -> Result<(), Box<dyn Error>> {
+    let spill_dir = std::env::temp_dir().join(format!(
+        "perspective-test-response-queue-{}",
+        std::process::id()
+    ));
+
+    std::fs::create_dir_all(&spill_dir)?;
+    let server = Server::default();
+    let sent = Arc::new(AtomicUsize::new(0));
+    let session = server
+        .new_session_with_callback({
+            let sent = sent.clone();
+            move |_| {
+                sent.fetch_add(1, Ordering::SeqCst);
+                Box::pin(async { Ok(()) })
+            }
+        })
+        .await;
+
+    session.handle_request(&hello(1)).await?;
+    assert_eq!(sent.load(Ordering::SeqCst), 1);
+    session
+        .set_response_queue(ResponseQueueConfig::new(1).with_spill_dir(&spill_dir))
+        .await;
+
+    for msg_id in 2..5 {
+        session.handle_request(&hello(msg_id)).await?;
+    }
+
+    assert_eq!(sent.load(Ordering::SeqCst), 1);
+    let stats = session.response_queue_stats().await.unwrap();
+    assert_eq!(stats.len, 3);
+    assert_eq!(stats.memory_bytes, 0);
+    assert!(stats.spilled_bytes > 0);
+    assert_eq!(std::fs::read_dir(&spill_dir)?.count(), 1);
+    for msg_id in 2..5 {
+        let resp = session.next_response().await?.unwrap();
+        assert!(resp.request_id.is_some());
+        assert_eq!(Response::decode(resp.message.as_slice())?.msg_id, msg_id);
+    }
+
+    assert!(session.next_response().await?.is_none());
+    assert_eq!(
+        session.response_queue_stats().await.unwrap().spilled_bytes,
+        0
+    );
+    session.close().await;
+    assert_eq!(std::fs::read_dir(&spill_dir)?.count(), 0);
+    std::fs::remove_dir(&spill_dir)?;
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_response_queue_holds_responses_in_memory() -> Result<(), Box<dyn Error>> {
+    let server = Server::default();
+    let session = server
+        .new_session_with_callback(|_| panic!("Unexpected response"))
+        .await;
+
+    assert!(session.response_queue_stats().await.is_none());
+    session
+        .set_response_queue(ResponseQueueConfig::new(1024 * 1024))
+        .await;
+
+    session.handle_request(&hello(1)).await?;
+    let stats = session.response_queue_stats().await.unwrap();
+    assert_eq!(stats.len, 1);
+    assert!(stats.memory_bytes > 0);
+    assert_eq!(stats.spilled_bytes, 0);
+    let resp = session.next_response().await?.unwrap();
+    assert_eq!(Response::decode(resp.message.as_slice())?.msg_id, 1);
+    assert_eq!(
+        session.response_queue_stats().await.unwrap().memory_bytes,
+        0
+    );
+    session.close().await;
+    Ok(())
+}
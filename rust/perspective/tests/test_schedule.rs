@@ -0,0 +1,142 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use std::error::Error;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use perspective::client::config::ViewConfigUpdate;
+use perspective::client::{TableInitOptions, UpdateData};
+use perspective::schedule::{
+    CallbackSink, Export, ExportFormat, ExportJob, FileSink, Schedule, Scheduler,
+};
+use perspective::server::Server;
+use perspective::LocalClient;
+
+fn at(secs: u64) -> SystemTime {
+    UNIX_EPOCH + Duration::from_secs(secs)
+}
+
+async fn server_with_table() -> Result<Server, Box<dyn Error>> {
+    let server = Server::default();
+    let client = LocalClient::new(&server);
+    let mut options = TableInitOptions::default();
+    options.set_name("trades");
+    let data = UpdateData::Csv("desk,pnl\na,1\na,2\nb,3".to_owned());
+    client.table(data.into(), options).await?;
+    client.close().await;
+    Ok(server)
+}
+
+fn group_by_desk() -> ViewConfigUpdate {
+    ViewConfigUpdate {
+        group_by: Some(vec!["desk".to_owned()]),
+        columns: Some(vec![Some("pnl".to_owned())]),
+        ..ViewConfigUpdate::default()
+    }
+}
+
+#[test]
+fn test_schedule_next_after() -> Result<(), Box<dyn Error>> {
+    // Friday 2024-01-05T07:00:00Z to Monday 2024-01-08T06:30:00Z.
+    let weekdays: Schedule = "30 6 * * 1-5".parse()?;
+    assert_eq!(weekdays.next_after(at(1704438000)), Some(at(1704695400)));
+
+    // 2024-01-31T12:00:00Z to 2024-02-01T00:00:00Z.
+    let monthly: Schedule = "@monthly".parse()?;
+    assert_eq!(monthly.next_after(at(1706702400)), Some(at(1706745600)));
+
+    // 2024-03-01T00:00:00Z to 2028-02-29T00:00:00Z.
+    let leap_day: Schedule = "0 0 29 2 *".parse()?;
+    assert_eq!(leap_day.next_after(at(1709251200)), Some(at(1835395200)));
+
+    let every_15: Schedule = "*/15 * * * *".parse()?;
+    assert_eq!(every_15.next_after(at(1704438000)), Some(at(1704438900)));
+    let never: Schedule = "0 0 31 2 *".parse()?;
+    assert_eq!(never.next_after(at(1704438000)), None);
+    assert!("61 * * * *".parse::<Schedule>().is_err());
+    assert!("* * *".parse::<Schedule>().is_err());
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_scheduler_runs_due_jobs_once() -> Result<(), Box<dyn Error>> {
+    let server = server_with_table().await?;
+    let exports: Arc<Mutex<Vec<Export>>> = Arc::default();
+    let sink = CallbackSink({
+        let exports = exports.clone();
+        move |export| {
+            let exports = exports.clone();
+            async move {
+                exports.lock().unwrap().push(export);
+                Ok(())
+            }
+        }
+    });
+
+    let scheduler = Scheduler::new(&server);
+    let job = ExportJob::new("pnl", "trades", group_by_desk(), "@daily".parse()?, sink);
+    scheduler.add(job.with_format(ExportFormat::Json));
+    let next = scheduler.next_run().unwrap();
+    assert!(scheduler
+        .run_due(next - Duration::from_secs(1))
+        .await
+        .is_empty());
+    let results = scheduler.run_due(next).await;
+    assert_eq!(results.len(), 1);
+    assert!(results[0].1.is_ok());
+    assert!(scheduler.run_due(next).await.is_empty());
+    assert_eq!(
+        scheduler.next_run(),
+        Some(next + Duration::from_secs(86400))
+    );
+    let exports = exports.lock().unwrap();
+    assert_eq!(exports.len(), 1);
+    assert_eq!(exports[0].format, ExportFormat::Json);
+    let json = String::from_utf8(exports[0].data.clone())?;
+    assert!(json.contains("__ROW_PATH__"), "{}", json);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_file_sink_writes_named_exports() -> Result<(), Box<dyn Error>> {
+    let server = server_with_table().await?;
+    let dir = std::env::temp_dir().join(format!("perspective-schedule-{}", std::process::id()));
+    let scheduler = Scheduler::new(&server);
+    let job = ExportJob::new(
+        "desks",
+        "trades",
+        group_by_desk(),
+        "@hourly".parse()?,
+        FileSink::new(&dir),
+    );
+
+    scheduler.add(job);
+    let next = scheduler.next_run().unwrap();
+    for (_, result) in scheduler.run_due(next).await {
+        result?;
+    }
+
+    let export = Export {
+        job: "desks".to_owned(),
+        time: next,
+        format: ExportFormat::Csv,
+        data: vec![],
+    };
+
+    let csv = std::fs::read_to_string(dir.join(export.file_name()))?;
+    assert!(csv.contains("desk"), "{}", csv);
+    std::fs::remove_dir_all(&dir)?;
+    assert!(scheduler.remove("desks"));
+    assert!(scheduler.run_job("desks").await.is_err());
+    Ok(())
+}
@@ -0,0 +1,62 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+use std::collections::HashMap;
+
+use perspective::server::{Server, Session, SessionInfo};
+
+async fn new_session(server: &Server) -> Session {
+    server
+        .new_session_with_callback(|_| Box::pin(async { Ok(()) }))
+        .await
+}
+
+#[tokio::test]
+async fn test_sessions_lists_open_sessions_with_metadata() {
+    let server = Server::default();
+    let session1 = new_session(&server).await;
+    let session2 = new_session(&server).await;
+    session1.set_metadata("user", "alice");
+    session1.set_metadata("origin", "https://example.com");
+    session2.set_metadata("user", "bob");
+    session2.set_metadata("user", "carol");
+
+    assert_eq!(session1.get_metadata("user"), Some("alice".to_owned()));
+    assert_eq!(session2.get_metadata("user"), Some("carol".to_owned()));
+    assert_eq!(session2.get_metadata("origin"), None);
+    assert_ne!(session1.id(), session2.id());
+
+    let mut expected = vec![
+        SessionInfo {
+            id: session1.id(),
+            metadata: HashMap::from([
+                ("user".to_owned(), "alice".to_owned()),
+                ("origin".to_owned(), "https://example.com".to_owned()),
+            ]),
+        },
+        SessionInfo {
+            id: session2.id(),
+            metadata: HashMap::from([("user".to_owned(), "carol".to_owned())]),
+        },
+    ];
+
+    expected.sort_by_key(|x| x.id);
+    assert_eq!(server.sessions().await, expected);
+    assert!(format!("{:?}", session1).contains("alice"));
+
+    let id2 = session2.id();
+    session1.close().await;
+    let sessions = server.sessions().await;
+    assert_eq!(sessions.len(), 1);
+    assert_eq!(sessions[0].id, id2);
+    session2.close().await;
+    assert!(server.sessions().await.is_empty());
+}
@@ -0,0 +1,123 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use std::collections::HashMap;
+use std::error::Error;
+
+use perspective::server::Server;
+use perspective::LocalClient;
+use perspective_client::config::{ShowValuesAs, ViewConfigUpdate};
+use perspective_client::{ColumnType, Table, TableInitOptions, UpdateData, ViewWindow};
+use serde_json::{json, Value};
+
+async fn sales_table(client: &LocalClient) -> Result<Table, Box<dyn Error>> {
+    Ok(client
+        .table(
+            UpdateData::Csv("region,year,v\na,1,1\na,2,3\nb,1,3\nb,2,3".to_owned()).into(),
+            TableInitOptions::default(),
+        )
+        .await?)
+}
+
+fn show_v_as(show: ShowValuesAs) -> Option<HashMap<String, ShowValuesAs>> {
+    Some(HashMap::from([("v".to_owned(), show)]))
+}
+
+#[tokio::test]
+async fn test_show_values_as_across_split_by() -> Result<(), Box<dyn Error>> {
+    let server = Server::default();
+    let client = LocalClient::new(&server);
+    let table = sales_table(&client).await?;
+    let cases = [
+        (ShowValuesAs::Value, json!([4, 1, 3]), json!([6, 3, 3])),
+        (
+            ShowValuesAs::PercentOfRowTotal,
+            json!([40.0, 25.0, 50.0]),
+            json!([60.0, 75.0, 50.0]),
+        ),
+        (
+            ShowValuesAs::PercentOfColumnTotal,
+            json!([100.0, 25.0, 75.0]),
+            json!([100.0, 50.0, 50.0]),
+        ),
+        (
+            ShowValuesAs::PercentOfGrandTotal,
+            json!([40.0, 10.0, 30.0]),
+            json!([60.0, 30.0, 30.0]),
+        ),
+        (
+            ShowValuesAs::DifferenceFromPrevious,
+            json!([null, null, null]),
+            json!([2, 2, 0]),
+        ),
+        (
+            ShowValuesAs::RunningTotal,
+            json!([4, 1, 3]),
+            json!([10, 4, 6]),
+        ),
+    ];
+
+    for (show, first, second) in cases {
+        let view = table
+            .view(Some(ViewConfigUpdate {
+                group_by: Some(vec!["region".to_owned()]),
+                split_by: Some(vec!["year".to_owned()]),
+                columns: Some(vec![Some("v".to_owned())]),
+                show_values_as: show_v_as(show),
+                ..ViewConfigUpdate::default()
+            }))
+            .await?;
+
+        let json = view.to_columns_string(ViewWindow::default()).await?;
+        assert_eq!(
+            serde_json::from_str::<Value>(&json)?,
+            json!({"__ROW_PATH__": [[], ["a"], ["b"]], "1|v": first, "2|v": second}),
+            "{show:?}"
+        );
+
+        view.delete().await?;
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_show_values_as_percent_is_float() -> Result<(), Box<dyn Error>> {
+    let server = Server::default();
+    let client = LocalClient::new(&server);
+    let table = sales_table(&client).await?;
+    let view = table
+        .view(Some(ViewConfigUpdate {
+            group_by: Some(vec!["region".to_owned()]),
+            columns: Some(vec![Some("v".to_owned())]),
+            show_values_as: show_v_as(ShowValuesAs::PercentOfGrandTotal),
+            ..ViewConfigUpdate::default()
+        }))
+        .await?;
+
+    let json = view.to_columns_string(ViewWindow::default()).await?;
+    assert_eq!(
+        serde_json::from_str::<Value>(&json)?,
+        json!({"__ROW_PATH__": [[], ["a"], ["b"]], "v": [100.0, 40.0, 60.0]})
+    );
+
+    let schema = view.schema().await?;
+    assert_eq!(schema.get("v"), Some(&ColumnType::Float));
+
+    let config = view.get_config().await?;
+    assert_eq!(
+        config.show_values_as.get("v"),
+        Some(&ShowValuesAs::PercentOfGrandTotal)
+    );
+
+    Ok(())
+}
@@ -0,0 +1,82 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use std::error::Error;
+
+use perspective::LocalClient;
+use perspective_client::{TableInitOptions, UpdateData, UpdateOptions};
+
+const ROWS: &str = r#"[
+    {"x": 3, "side": "buy", "price": 1.5},
+    {"x": 1, "side": "sell", "price": null},
+    {"x": 2, "side": "buy", "price": 0.5}
+]"#;
+
+#[tokio::test]
+async fn test_sketches_summarize_written_values() -> Result<(), Box<dyn Error>> {
+    let server = perspective::server::Server::default();
+    let client = LocalClient::new(&server);
+    let table = client
+        .table(
+            UpdateData::JsonRows(ROWS.to_owned()).into(),
+            TableInitOptions::default(),
+        )
+        .await?;
+
+    let sketches = table.sketches().await?;
+    let side = &sketches["side"];
+    assert_eq!(side.count, 3);
+    assert_eq!(side.min.as_deref(), Some(r#""buy""#));
+    assert_eq!(side.max.as_deref(), Some(r#""sell""#));
+    let top = side
+        .top_values
+        .iter()
+        .map(|x| (x.value.as_str(), x.count))
+        .collect::<Vec<_>>();
+
+    assert_eq!(top, vec![(r#""buy""#, 2), (r#""sell""#, 1)]);
+
+    let price = &sketches["price"];
+    assert_eq!(price.count, 2);
+    assert_eq!(price.min.as_deref(), Some("0.5"));
+    assert_eq!(price.max.as_deref(), Some("1.5"));
+    assert!(price.top_values.is_empty());
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_sketches_update_incrementally_and_reset_on_clear() -> Result<(), Box<dyn Error>> {
+    let server = perspective::server::Server::default();
+    let client = LocalClient::new(&server);
+    let table = client
+        .table(
+            UpdateData::JsonRows(ROWS.to_owned()).into(),
+            TableInitOptions::default(),
+        )
+        .await?;
+
+    table
+        .update(
+            UpdateData::JsonRows(r#"[{"x": 10, "side": "short"}]"#.to_owned()),
+            UpdateOptions::default(),
+        )
+        .await?;
+
+    let sketches = table.sketches().await?;
+    assert_eq!(sketches["x"].count, 4);
+    assert_eq!(sketches["x"].max.as_deref(), Some("10"));
+    assert_eq!(sketches["side"].max.as_deref(), Some(r#""short""#));
+
+    table.clear().await?;
+    assert!(table.sketches().await?.is_empty());
+    Ok(())
+}
@@ -0,0 +1,101 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use std::error::Error;
+use std::time::Duration;
+
+use perspective::LocalClient;
+use perspective_client::config::ViewConfigUpdate;
+use perspective_client::proto::slow_op::Kind;
+use perspective_client::{TableInitOptions, UpdateData, UpdateOptions, ViewWindow};
+
+const ROWS: &str = r#"[
+    {"x": 1, "side": "buy"},
+    {"x": 2, "side": "sell"},
+    {"x": 3, "side": "buy"}
+]"#;
+
+#[tokio::test]
+async fn test_slow_ops_are_logged_over_threshold() -> Result<(), Box<dyn Error>> {
+    let server = perspective::server::Server::default();
+    server.set_slow_op_threshold(Some(Duration::ZERO)).await;
+    let client = LocalClient::new(&server);
+    let table = client
+        .table(
+            UpdateData::JsonRows(ROWS.to_owned()).into(),
+            TableInitOptions::default(),
+        )
+        .await?;
+
+    let view = table
+        .view(Some(ViewConfigUpdate {
+            group_by: Some(vec!["side".to_owned()]),
+            ..ViewConfigUpdate::default()
+        }))
+        .await?;
+
+    table
+        .update(
+            UpdateData::JsonRows(r#"[{"x": 4, "side": "sell"}]"#.to_owned()),
+            UpdateOptions::default(),
+        )
+        .await?;
+
+    view.to_csv(ViewWindow::default()).await?;
+    let slow_ops = client.diagnostics(true).await?.slow_ops;
+    let kinds = slow_ops.iter().map(|op| op.kind()).collect::<Vec<_>>();
+    assert_eq!(kinds, vec![
+        Kind::ViewCreate,
+        Kind::TableUpdate,
+        Kind::ViewToCsv
+    ]);
+    for op in &slow_ops {
+        assert_eq!(op.table_id, table.get_name());
+        assert!(op.timestamp > 0.0);
+    }
+
+    let export = &slow_ops[2];
+    assert_eq!(export.entity_id, view.name);
+    assert_eq!(export.table_size, 4);
+    assert_eq!(
+        export.view_config.as_ref().map(|x| x.group_by.clone()),
+        Some(vec!["side".to_owned()])
+    );
+
+    assert_eq!(slow_ops[1].view_config, None);
+    assert!(client.diagnostics(false).await?.slow_ops.is_empty());
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_slow_op_log_is_disabled_by_default() -> Result<(), Box<dyn Error>> {
+    let server = perspective::server::Server::default();
+    let client = LocalClient::new(&server);
+    let table = client
+        .table(
+            UpdateData::JsonRows(ROWS.to_owned()).into(),
+            TableInitOptions::default(),
+        )
+        .await?;
+
+    let view = table.view(None).await?;
+    view.to_arrow(ViewWindow::default()).await?;
+    assert!(client.diagnostics(false).await?.slow_ops.is_empty());
+
+    server
+        .set_slow_op_threshold(Some(Duration::from_secs(3600)))
+        .await;
+
+    view.to_arrow(ViewWindow::default()).await?;
+    assert!(client.diagnostics(false).await?.slow_ops.is_empty());
+    Ok(())
+}
@@ -0,0 +1,96 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use std::collections::HashMap;
+use std::error::Error;
+
+use perspective::LocalClient;
+use perspective_client::config::{Expressions, ViewConfigUpdate};
+use perspective_client::{TableInitOptions, UpdateData, ViewWindow};
+
+const ROWS: &str = r#"[
+    {"a": "kitten", "b": "sitting"},
+    {"a": "MARTHA", "b": "MARHTA"},
+    {"a": "Robert", "b": "Rupert"}
+]"#;
+
+#[tokio::test]
+async fn test_string_similarity_functions() -> Result<(), Box<dyn Error>> {
+    let server = perspective::server::Server::default();
+    let client = LocalClient::new(&server);
+    let table = client
+        .table(
+            UpdateData::JsonRows(ROWS.to_owned()).into(),
+            TableInitOptions::default(),
+        )
+        .await?;
+
+    let expressions = Expressions(HashMap::from([
+        ("lev".to_owned(), r#"levenshtein("a", "b")"#.to_owned()),
+        (
+            "jw".to_owned(),
+            r#"jaro_winkler("a", "b") > 0.96"#.to_owned(),
+        ),
+        ("soundex".to_owned(), r#"soundex("a")"#.to_owned()),
+        ("prefix".to_owned(), r#"starts_with("b", 'MAR')"#.to_owned()),
+        ("suffix".to_owned(), r#"ends_with("a", 'en')"#.to_owned()),
+    ]));
+
+    let view = table
+        .view(Some(ViewConfigUpdate {
+            columns: Some(vec![
+                Some("lev".to_owned()),
+                Some("jw".to_owned()),
+                Some("soundex".to_owned()),
+                Some("prefix".to_owned()),
+                Some("suffix".to_owned()),
+            ]),
+            expressions: Some(expressions),
+            ..ViewConfigUpdate::default()
+        }))
+        .await?;
+
+    let json = view.to_columns_string(ViewWindow::default()).await?;
+    assert_eq!(
+        json,
+        r#"{"lev":[3.0,2.0,2.0],"jw":[false,true,false],"soundex":["K350","M630","R163"],"prefix":[false,true,false],"suffix":[true,false,false]}"#
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_levenshtein_counts_characters() -> Result<(), Box<dyn Error>> {
+    let server = perspective::server::Server::default();
+    let client = LocalClient::new(&server);
+    let table = client
+        .table(
+            UpdateData::JsonRows(r#"[{"a": "café", "b": "cafe"}]"#.to_owned()).into(),
+            TableInitOptions::default(),
+        )
+        .await?;
+
+    let view = table
+        .view(Some(ViewConfigUpdate {
+            columns: Some(vec![Some("lev".to_owned())]),
+            expressions: Some(Expressions(HashMap::from([(
+                "lev".to_owned(),
+                r#"levenshtein("a", "b")"#.to_owned(),
+            )]))),
+            ..ViewConfigUpdate::default()
+        }))
+        .await?;
+
+    let json = view.to_columns_string(ViewWindow::default()).await?;
+    assert_eq!(json, r#"{"lev":[1.0]}"#);
+    Ok(())
+}
@@ -0,0 +1,75 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use std::collections::HashMap;
+use std::error::Error;
+
+use perspective::LocalClient;
+use perspective_client::config::{Expressions, ViewConfigUpdate};
+use perspective_client::{ColumnType, StructPaths, TableInitOptions, UpdateData, ViewWindow};
+
+#[tokio::test]
+async fn test_json_objects_flatten_to_dot_paths() -> Result<(), Box<dyn Error>> {
+    let server = perspective::server::Server::default();
+    let client = LocalClient::new(&server);
+    let table = client
+        .table(
+            UpdateData::JsonRows(
+                r#"[{"id": 1, "order": {"price": 2.5, "item": {"sku": "a"}}}]"#.to_owned(),
+            )
+            .into(),
+            TableInitOptions::default(),
+        )
+        .await?;
+
+    let schema = table.schema().await?;
+    assert_eq!(schema.get("order.price"), Some(&ColumnType::Float));
+    assert_eq!(schema.get("order.item.sku"), Some(&ColumnType::String));
+
+    let view = table
+        .view(Some(ViewConfigUpdate {
+            columns: Some(vec![Some("id".to_owned()), Some("double".to_owned())]),
+            expressions: Some(Expressions(HashMap::from([(
+                "double".to_owned(),
+                r#""order.price" * 2"#.to_owned(),
+            )]))),
+            ..ViewConfigUpdate::default()
+        }))
+        .await?;
+
+    let json = view.to_columns_string(ViewWindow::default()).await?;
+    assert_eq!(json, r#"{"id":[1],"double":[5.0]}"#);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_struct_paths_nested_export() -> Result<(), Box<dyn Error>> {
+    let server = perspective::server::Server::default();
+    let client = LocalClient::new(&server);
+    let table = client
+        .table(
+            UpdateData::JsonRows(r#"[{"id": 1, "order": {"price": 2.5}}]"#.to_owned()).into(),
+            TableInitOptions::default(),
+        )
+        .await?;
+
+    let view = table.view(None).await?;
+    let json = view
+        .to_json_string(ViewWindow {
+            struct_paths: Some(StructPaths::Nested),
+            ..ViewWindow::default()
+        })
+        .await?;
+
+    assert_eq!(json, r#"[{"id":1,"order":{"price":2.5}}]"#);
+    Ok(())
+}
@@ -0,0 +1,73 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use std::error::Error;
+
+use perspective::client::{TableInitOptions, UpdateData};
+use perspective::server::Server;
+use perspective::LocalClient;
+
+#[tokio::test]
+async fn test_system_info_reports_heap_size() -> Result<(), Box<dyn Error>> {
+    let server = Server::default();
+    let client = LocalClient::new(&server);
+    let before = client.system_info().await?.heap_size;
+    assert!(before > 0.0);
+
+    let csv = (0..100_000).fold("x,y\n".to_owned(), |csv, i| {
+        csv + &format!("{},{}\n", i, i * 2)
+    });
+
+    client
+        .table(UpdateData::Csv(csv).into(), TableInitOptions::default())
+        .await?;
+
+    // Reporting does not abort a native server, and the heap is a whole
+    // number of bytes.
+    let after = client.system_info().await?.heap_size;
+    assert!(after > 0.0);
+    assert_eq!(after.fract(), 0.0);
+    client.close().await;
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_system_info_reports_engine_target() -> Result<(), Box<dyn Error>> {
+    let server = Server::default();
+    let client = LocalClient::new(&server);
+    let target = client.system_info().await?.engine_target;
+
+    // The engine is built for the same target as this test, cross-compiled
+    // or not, e.g. `aarch64-unknown-linux-musl`.
+    let os = match std::env::consts::OS {
+        "macos" => "darwin",
+        os => os,
+    };
+
+    assert!(target.starts_with(std::env::consts::ARCH), "{}", target);
+    assert!(target.contains(os), "{}", target);
+    client.close().await;
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_system_info_reports_engine_version() -> Result<(), Box<dyn Error>> {
+    let server = Server::default();
+    let client = LocalClient::new(&server);
+
+    // `perspective` and `perspective-server` are versioned together, so an
+    // engine built (or prebuilt) for another version is caught here.
+    let version = client.system_info().await?.engine_version;
+    assert_eq!(version, env!("CARGO_PKG_VERSION"));
+    client.close().await;
+    Ok(())
+}
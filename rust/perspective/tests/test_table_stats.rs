@@ -0,0 +1,89 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use std::error::Error;
+
+use perspective::LocalClient;
+use perspective_client::{TableInitOptions, UpdateData, UpdateOptions};
+
+const ROWS: &str = r#"[
+    {"x": 1, "side": "buy"},
+    {"x": 2, "side": "sell"},
+    {"x": 3, "side": "buy"}
+]"#;
+
+#[tokio::test]
+async fn test_table_stats_count_added_updated_and_removed_rows() -> Result<(), Box<dyn Error>> {
+    let server = perspective::server::Server::default();
+    let client = LocalClient::new(&server);
+    let table = client
+        .table(
+            UpdateData::JsonRows(ROWS.to_owned()).into(),
+            TableInitOptions {
+                index: Some("x".to_owned()),
+                ..TableInitOptions::default()
+            },
+        )
+        .await?;
+
+    table
+        .update(
+            UpdateData::JsonRows(r#"[{"x": 1, "side": "sell"}, {"x": 4}]"#.to_owned()),
+            UpdateOptions::default(),
+        )
+        .await?;
+
+    assert_eq!(table.size().await?, 4);
+    table
+        .remove(UpdateData::JsonRows(r#"[{"x": 2}]"#.to_owned()))
+        .await?;
+
+    let stats = table.stats(None).await?;
+    assert_eq!(stats.num_rows, 3);
+    assert_eq!(stats.interval_ms, 60000);
+    assert!(stats.last_update.is_some());
+
+    let total = stats.total.unwrap_or_default();
+    assert_eq!(
+        (total.added, total.updated, total.removed, total.batches),
+        (4, 1, 1, 3)
+    );
+
+    assert_eq!(stats.recent.unwrap_or_default(), total);
+
+    // The remove is 1 row, and the load and update are 2-3 rows each.
+    assert_eq!(&stats.batch_sizes[..2], &[1, 2]);
+    assert_eq!(stats.batch_sizes.iter().sum::<u64>(), 3);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_table_stats_before_any_update() -> Result<(), Box<dyn Error>> {
+    let server = perspective::server::Server::default();
+    let client = LocalClient::new(&server);
+    let table = client
+        .table(
+            perspective_client::TableData::Schema(vec![(
+                "x".to_owned(),
+                perspective_client::ColumnType::Integer,
+            )]),
+            TableInitOptions::default(),
+        )
+        .await?;
+
+    let stats = table.stats(Some(1000)).await?;
+    assert_eq!(stats.num_rows, 0);
+    assert_eq!(stats.interval_ms, 1000);
+    assert_eq!(stats.last_update, None);
+    assert_eq!(stats.total.unwrap_or_default().batches, 0);
+    Ok(())
+}
@@ -0,0 +1,212 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use std::collections::HashMap;
+use std::error::Error;
+
+use perspective::LocalClient;
+use perspective_client::config::{Expressions, ViewConfigUpdate};
+use perspective_client::{
+    ColumnHints, ColumnType, TableData, TableInitOptions, UpdateData, UpdateOptions, ViewWindow,
+};
+
+// IANA zones resolve through the system tz database, which Windows builds
+// lack.
+#[cfg(not(windows))]
+#[tokio::test]
+async fn test_view_timezone_applies_to_calendar_functions() -> Result<(), Box<dyn Error>> {
+    let server = perspective::server::Server::default();
+    let client = LocalClient::new(&server);
+    let table = client
+        .table(
+            TableData::Schema(vec![("t".to_owned(), ColumnType::Datetime)]),
+            TableInitOptions::default(),
+        )
+        .await?;
+
+    // 2024-03-10T04:30:00Z, which is still Saturday evening in New York.
+    table
+        .update(
+            UpdateData::JsonRows(r#"[{"t": 1710045000000}]"#.to_owned()),
+            UpdateOptions::default(),
+        )
+        .await?;
+
+    let expressions = Expressions(HashMap::from([
+        ("dow".to_owned(), r#"day_of_week("t")"#.to_owned()),
+        ("hour".to_owned(), r#"hour_of_day("t")"#.to_owned()),
+    ]));
+
+    let view = table
+        .view(Some(ViewConfigUpdate {
+            columns: Some(vec![Some("dow".to_owned()), Some("hour".to_owned())]),
+            expressions: Some(expressions.clone()),
+            timezone: Some("America/New_York".to_owned()),
+            ..ViewConfigUpdate::default()
+        }))
+        .await?;
+
+    let json = view.to_columns_string(ViewWindow::default()).await?;
+    assert_eq!(json, r#"{"dow":["7 Saturday"],"hour":[23.0]}"#);
+
+    let view = table
+        .view(Some(ViewConfigUpdate {
+            columns: Some(vec![Some("dow".to_owned()), Some("hour".to_owned())]),
+            expressions: Some(expressions),
+            timezone: Some("UTC".to_owned()),
+            ..ViewConfigUpdate::default()
+        }))
+        .await?;
+
+    let json = view.to_columns_string(ViewWindow::default()).await?;
+    assert_eq!(json, r#"{"dow":["1 Sunday"],"hour":[4.0]}"#);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_view_rejects_unknown_timezone() -> Result<(), Box<dyn Error>> {
+    let server = perspective::server::Server::default();
+    let client = LocalClient::new(&server);
+    let table = client
+        .table(
+            TableData::Schema(vec![("t".to_owned(), ColumnType::Datetime)]),
+            TableInitOptions::default(),
+        )
+        .await?;
+
+    let view = table
+        .view(Some(ViewConfigUpdate {
+            timezone: Some("Mars/Olympus_Mons".to_owned()),
+            ..ViewConfigUpdate::default()
+        }))
+        .await;
+
+    assert!(view.is_err());
+    Ok(())
+}
+
+#[cfg(not(windows))]
+#[tokio::test]
+async fn test_view_timezone_applies_historical_rules() -> Result<(), Box<dyn Error>> {
+    let server = perspective::server::Server::default();
+    let client = LocalClient::new(&server);
+    let table = client
+        .table(
+            TableData::Schema(vec![("t".to_owned(), ColumnType::Datetime)]),
+            TableInitOptions::default(),
+        )
+        .await?;
+
+    // Noon UTC on March 20th of 2006 and 2024. US daylight saving time began
+    // on the first Sunday of April until 2007, so only the latter is EDT.
+    table
+        .update(
+            UpdateData::JsonRows(r#"[{"t": 1142856000000}, {"t": 1710936000000}]"#.to_owned()),
+            UpdateOptions::default(),
+        )
+        .await?;
+
+    let view = table
+        .view(Some(ViewConfigUpdate {
+            columns: Some(vec![Some("hour".to_owned())]),
+            expressions: Some(Expressions(HashMap::from([(
+                "hour".to_owned(),
+                r#"hour_of_day("t")"#.to_owned(),
+            )]))),
+            timezone: Some("America/New_York".to_owned()),
+            ..ViewConfigUpdate::default()
+        }))
+        .await?;
+
+    let json = view.to_columns_string(ViewWindow::default()).await?;
+    assert_eq!(json, r#"{"hour":[7.0,8.0]}"#);
+    Ok(())
+}
+
+#[cfg(not(windows))]
+#[tokio::test]
+async fn test_column_timezone_hint_is_the_default_timezone() -> Result<(), Box<dyn Error>> {
+    let server = perspective::server::Server::default();
+    let client = LocalClient::new(&server);
+    let table = client
+        .table(
+            TableData::Schema(vec![("t".to_owned(), ColumnType::Datetime)]),
+            TableInitOptions {
+                column_hints: Some(HashMap::from([("t".to_owned(), ColumnHints {
+                    timezone: Some("America/New_York".to_owned()),
+                    ..ColumnHints::default()
+                })])),
+                ..TableInitOptions::default()
+            },
+        )
+        .await?;
+
+    table
+        .update(
+            UpdateData::JsonRows(r#"[{"t": 1710045000000}]"#.to_owned()),
+            UpdateOptions::default(),
+        )
+        .await?;
+
+    let hints = table.column_hints().await?;
+    assert_eq!(hints["t"].timezone.as_deref(), Some("America/New_York"));
+    let expressions = Expressions(HashMap::from([(
+        "hour".to_owned(),
+        r#"hour_of_day("t")"#.to_owned(),
+    )]));
+
+    let view = table
+        .view(Some(ViewConfigUpdate {
+            columns: Some(vec![Some("hour".to_owned())]),
+            expressions: Some(expressions.clone()),
+            ..ViewConfigUpdate::default()
+        }))
+        .await?;
+
+    let json = view.to_columns_string(ViewWindow::default()).await?;
+    assert_eq!(json, r#"{"hour":[23.0]}"#);
+
+    // The view's own time zone takes precedence.
+    let view = table
+        .view(Some(ViewConfigUpdate {
+            columns: Some(vec![Some("hour".to_owned())]),
+            expressions: Some(expressions),
+            timezone: Some("UTC".to_owned()),
+            ..ViewConfigUpdate::default()
+        }))
+        .await?;
+
+    let json = view.to_columns_string(ViewWindow::default()).await?;
+    assert_eq!(json, r#"{"hour":[4.0]}"#);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_column_timezone_hint_rejects_unknown_timezone() -> Result<(), Box<dyn Error>> {
+    let server = perspective::server::Server::default();
+    let client = LocalClient::new(&server);
+    let table = client
+        .table(
+            TableData::Schema(vec![("t".to_owned(), ColumnType::Datetime)]),
+            TableInitOptions {
+                column_hints: Some(HashMap::from([("t".to_owned(), ColumnHints {
+                    timezone: Some("Mars/Olympus_Mons".to_owned()),
+                    ..ColumnHints::default()
+                })])),
+                ..TableInitOptions::default()
+            },
+        )
+        .await;
+
+    assert!(table.is_err());
+    Ok(())
+}
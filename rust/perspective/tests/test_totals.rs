@@ -0,0 +1,93 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use std::error::Error;
+
+use perspective::server::Server;
+use perspective::LocalClient;
+use perspective_client::config::{TotalPosition, ViewConfigUpdate};
+use perspective_client::{Table, TableInitOptions, UpdateData, ViewWindow};
+
+async fn grouped_table(client: &LocalClient) -> Result<Table, Box<dyn Error>> {
+    Ok(client
+        .table(
+            UpdateData::Csv("g,h,v\na,x,1\na,y,2\nb,x,3".to_owned()).into(),
+            TableInitOptions::default(),
+        )
+        .await?)
+}
+
+fn grouped_config() -> ViewConfigUpdate {
+    ViewConfigUpdate {
+        group_by: Some(vec!["g".to_owned(), "h".to_owned()]),
+        columns: Some(vec![Some("v".to_owned())]),
+        ..ViewConfigUpdate::default()
+    }
+}
+
+#[tokio::test]
+async fn test_totals_at_bottom() -> Result<(), Box<dyn Error>> {
+    let server = Server::default();
+    let client = LocalClient::new(&server);
+    let table = grouped_table(&client).await?;
+    let view = table
+        .view(Some(ViewConfigUpdate {
+            grand_total: Some(TotalPosition::Bottom),
+            subtotals: Some(TotalPosition::Bottom),
+            ..grouped_config()
+        }))
+        .await?;
+
+    let json = view.to_columns_string(ViewWindow::default()).await?;
+    assert_eq!(
+        json,
+        r#"{"__ROW_PATH__":[["a","x"],["a","y"],["a"],["b","x"],["b"],[]],"v":[1,2,3,3,3,6]}"#
+    );
+
+    let config = view.get_config().await?;
+    assert_eq!(config.grand_total, TotalPosition::Bottom);
+    assert_eq!(config.subtotals, TotalPosition::Bottom);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_totals_hidden() -> Result<(), Box<dyn Error>> {
+    let server = Server::default();
+    let client = LocalClient::new(&server);
+    let table = grouped_table(&client).await?;
+    let view = table.view(Some(grouped_config())).await?;
+    assert_eq!(view.num_rows().await?, 6);
+
+    view.update_config(ViewConfigUpdate {
+        grand_total: Some(TotalPosition::Hidden),
+        subtotals: Some(TotalPosition::Hidden),
+        ..ViewConfigUpdate::default()
+    })
+    .await?;
+
+    let json = view.to_columns_string(ViewWindow::default()).await?;
+    assert_eq!(
+        json,
+        r#"{"__ROW_PATH__":[["a","x"],["a","y"],["b","x"]],"v":[1,2,3]}"#
+    );
+
+    // Collapsed groups have no rows of their own, so they are still shown.
+    view.update_config(ViewConfigUpdate {
+        group_by_depth: Some(1),
+        ..ViewConfigUpdate::default()
+    })
+    .await?;
+
+    let json = view.to_columns_string(ViewWindow::default()).await?;
+    assert_eq!(json, r#"{"__ROW_PATH__":[["a"],["b"]],"v":[3,3]}"#);
+    Ok(())
+}
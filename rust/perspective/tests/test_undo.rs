@@ -0,0 +1,102 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use std::error::Error;
+
+use perspective::server::Server;
+use perspective::LocalClient;
+use perspective_client::{CellEdit, Table, TableInitOptions, UpdateData, ViewWindow};
+
+async fn rates_table(client: &LocalClient) -> Result<Table, Box<dyn Error>> {
+    Ok(client
+        .table(
+            UpdateData::Csv("id,rate\nEUR,1.08\nGBP,1.27".to_owned()).into(),
+            TableInitOptions {
+                index: Some("id".to_owned()),
+                ..TableInitOptions::default()
+            },
+        )
+        .await?)
+}
+
+fn edit(index: &str, value: &str) -> CellEdit {
+    CellEdit {
+        index: index.to_owned(),
+        column: "rate".to_owned(),
+        value: value.to_owned(),
+    }
+}
+
+async fn rates(table: &Table) -> Result<String, Box<dyn Error>> {
+    let view = table.view(None).await?;
+    let json = view.to_columns_string(ViewWindow::default()).await?;
+    view.delete().await?;
+    Ok(json)
+}
+
+#[tokio::test]
+async fn test_undo_and_redo_cell_edits() -> Result<(), Box<dyn Error>> {
+    let server = Server::default();
+    let client = LocalClient::new(&server);
+    let table = rates_table(&client).await?;
+    table
+        .edit_cells(vec![edit(r#""EUR""#, "1.09")], None)
+        .await?;
+    table
+        .edit_cells(
+            vec![edit(r#""EUR""#, "1.1"), edit(r#""GBP""#, "1.28")],
+            None,
+        )
+        .await?;
+
+    assert_eq!(table.undo(1).await?, 1);
+    assert_eq!(
+        rates(&table).await?,
+        r#"{"id":["EUR","GBP"],"rate":[1.09,1.27]}"#
+    );
+
+    assert_eq!(table.undo(5).await?, 1);
+    assert_eq!(
+        rates(&table).await?,
+        r#"{"id":["EUR","GBP"],"rate":[1.08,1.27]}"#
+    );
+
+    assert_eq!(table.redo(2).await?, 2);
+    assert_eq!(
+        rates(&table).await?,
+        r#"{"id":["EUR","GBP"],"rate":[1.1,1.28]}"#
+    );
+
+    assert_eq!(table.edit_log(None).await?.len(), 9);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_edit_cells_clears_redo() -> Result<(), Box<dyn Error>> {
+    let server = Server::default();
+    let client = LocalClient::new(&server);
+    let table = rates_table(&client).await?;
+    table
+        .edit_cells(vec![edit(r#""EUR""#, "1.09")], None)
+        .await?;
+    assert_eq!(table.undo(1).await?, 1);
+    table
+        .edit_cells(vec![edit(r#""GBP""#, "1.28")], None)
+        .await?;
+    assert_eq!(table.redo(1).await?, 0);
+    assert_eq!(
+        rates(&table).await?,
+        r#"{"id":["EUR","GBP"],"rate":[1.08,1.28]}"#
+    );
+
+    Ok(())
+}
@@ -0,0 +1,134 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use std::error::Error;
+use std::sync::Arc;
+use std::time::Duration;
+
+use perspective::LocalClient;
+use perspective_client::{
+    OnUpdateOptions, Table, TableInitOptions, UpdateData, UpdateOptions, View,
+};
+use tokio::sync::Mutex;
+
+async fn count_updates(view: &View) -> Result<Arc<Mutex<u32>>, Box<dyn Error>> {
+    let count = Arc::new(Mutex::new(0));
+    view.on_update(
+        {
+            let count = count.clone();
+            move |_| {
+                let count = count.clone();
+                async move { *count.lock().await += 1 }
+            }
+        },
+        OnUpdateOptions::default(),
+    )
+    .await?;
+
+    Ok(count)
+}
+
+async fn update(table: &Table, csv: &str) -> Result<(), Box<dyn Error>> {
+    table
+        .update(UpdateData::Csv(csv.to_owned()), UpdateOptions::default())
+        .await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_unbatched_updates_commit_individually() -> Result<(), Box<dyn Error>> {
+    let server = perspective::server::Server::default();
+    let client = LocalClient::new(&server);
+    let table = client
+        .table(
+            UpdateData::Csv("x,y\n1,2".to_owned()).into(),
+            TableInitOptions::default(),
+        )
+        .await?;
+
+    let view = table.view(None).await?;
+    let count = count_updates(&view).await?;
+    update(&table, "x,y\n3,4").await?;
+    update(&table, "x,y\n5,6").await?;
+    assert_eq!(*count.lock().await, 2);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_batched_updates_commit_together_on_read() -> Result<(), Box<dyn Error>> {
+    let server = perspective::server::Server::default();
+    let client = LocalClient::new(&server);
+    let table = client
+        .table(
+            UpdateData::Csv("x,y\n1,2".to_owned()).into(),
+            TableInitOptions {
+                batch_latency_ms: Some(60_000),
+                ..TableInitOptions::default()
+            },
+        )
+        .await?;
+
+    let view = table.view(None).await?;
+    let count = count_updates(&view).await?;
+    update(&table, "x,y\n3,4").await?;
+    update(&table, "x,y\n5,6").await?;
+    assert_eq!(*count.lock().await, 0);
+    assert_eq!(view.num_rows().await?, 3);
+    assert_eq!(*count.lock().await, 1);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_batched_update_after_quiet_period_commits() -> Result<(), Box<dyn Error>> {
+    let server = perspective::server::Server::default();
+    let client = LocalClient::new(&server);
+    let table = client
+        .table(
+            UpdateData::Csv("x,y\n1,2".to_owned()).into(),
+            TableInitOptions {
+                batch_latency_ms: Some(20),
+                ..TableInitOptions::default()
+            },
+        )
+        .await?;
+
+    let view = table.view(None).await?;
+    let count = count_updates(&view).await?;
+    tokio::time::sleep(Duration::from_millis(50)).await;
+    update(&table, "x,y\n3,4").await?;
+    assert_eq!(*count.lock().await, 1);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_batched_update_commits_without_further_requests() -> Result<(), Box<dyn Error>> {
+    let server = perspective::server::Server::default();
+    let client = LocalClient::new(&server);
+    let table = client
+        .table(
+            UpdateData::Csv("x,y\n1,2".to_owned()).into(),
+            TableInitOptions {
+                batch_latency_ms: Some(50),
+                ..TableInitOptions::default()
+            },
+        )
+        .await?;
+
+    let view = table.view(None).await?;
+    let count = count_updates(&view).await?;
+    update(&table, "x,y\n3,4").await?;
+    assert_eq!(*count.lock().await, 0);
+    tokio::time::sleep(Duration::from_millis(500)).await;
+    assert_eq!(*count.lock().await, 1);
+    Ok(())
+}
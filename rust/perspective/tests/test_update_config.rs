@@ -0,0 +1,215 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use std::error::Error;
+use std::sync::Arc;
+
+use perspective::server::Server;
+use perspective::LocalClient;
+use perspective_client::config::{Filter, FilterTerm, Scalar, Sort, SortDir, ViewConfigUpdate};
+use perspective_client::{OnUpdateOptions, Table, TableInitOptions, UpdateData, ViewWindow};
+use tokio::sync::Mutex;
+
+async fn letters_table(client: &LocalClient) -> Result<Table, Box<dyn Error>> {
+    Ok(client
+        .table(
+            UpdateData::Csv("x,y\n1,a\n2,b\n3,c".to_owned()).into(),
+            TableInitOptions::default(),
+        )
+        .await?)
+}
+
+#[tokio::test]
+async fn test_update_config_keeps_subscriptions() -> Result<(), Box<dyn Error>> {
+    let server = Server::default();
+    let client = LocalClient::new(&server);
+    let table = letters_table(&client).await?;
+    let view = table
+        .view(Some(ViewConfigUpdate {
+            columns: Some(vec![Some("x".to_owned())]),
+            ..ViewConfigUpdate::default()
+        }))
+        .await?;
+
+    let count: Arc<Mutex<u32>> = Arc::default();
+    view.on_update(
+        {
+            let count = count.clone();
+            move |_| {
+                let count = count.clone();
+                async move { *count.lock().await += 1 }
+            }
+        },
+        OnUpdateOptions::default(),
+    )
+    .await?;
+
+    view.update_config(ViewConfigUpdate {
+        sort: Some(vec![Sort("x".to_owned(), SortDir::Desc)]),
+        ..ViewConfigUpdate::default()
+    })
+    .await?;
+
+    let json = view.to_columns_string(ViewWindow::default()).await?;
+    assert_eq!(json, r#"{"x":[3,2,1]}"#);
+
+    view.update_config(ViewConfigUpdate {
+        filter: Some(vec![Filter::new(
+            "y".to_owned(),
+            "!=".to_owned(),
+            FilterTerm::Scalar(Scalar::String("b".to_owned())),
+        )]),
+        ..ViewConfigUpdate::default()
+    })
+    .await?;
+
+    let json = view.to_columns_string(ViewWindow::default()).await?;
+    assert_eq!(json, r#"{"x":[3,1]}"#);
+    assert_eq!(*count.lock().await, 2);
+
+    let config = view.get_config().await?;
+    assert_eq!(config.columns, vec![Some("x".to_owned())]);
+    assert_eq!(config.sort, vec![Sort("x".to_owned(), SortDir::Desc)]);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_update_config_sets_depth() -> Result<(), Box<dyn Error>> {
+    let server = Server::default();
+    let client = LocalClient::new(&server);
+    let table = letters_table(&client).await?;
+    let view = table
+        .view(Some(ViewConfigUpdate {
+            group_by: Some(vec!["y".to_owned(), "x".to_owned()]),
+            ..ViewConfigUpdate::default()
+        }))
+        .await?;
+
+    // The total, a row for each `y`, and a row for each `x`.
+    assert_eq!(view.num_rows().await?, 7);
+    view.update_config(ViewConfigUpdate {
+        group_by_depth: Some(1),
+        ..ViewConfigUpdate::default()
+    })
+    .await?;
+
+    assert_eq!(view.num_rows().await?, 4);
+    Ok(())
+}
+
+fn not_b() -> Filter {
+    Filter::new(
+        "y".to_owned(),
+        "!=".to_owned(),
+        FilterTerm::Scalar(Scalar::String("b".to_owned())),
+    )
+}
+
+#[tokio::test]
+async fn test_update_config_sorts_and_filters_pivoted_views() -> Result<(), Box<dyn Error>> {
+    let server = Server::default();
+    let client = LocalClient::new(&server);
+    let table = letters_table(&client).await?;
+    let view = table
+        .view(Some(ViewConfigUpdate {
+            group_by: Some(vec!["y".to_owned()]),
+            columns: Some(vec![Some("x".to_owned())]),
+            ..ViewConfigUpdate::default()
+        }))
+        .await?;
+
+    view.update_config(ViewConfigUpdate {
+        sort: Some(vec![Sort("x".to_owned(), SortDir::Desc)]),
+        ..ViewConfigUpdate::default()
+    })
+    .await?;
+
+    let json = view.to_columns_string(ViewWindow::default()).await?;
+    assert_eq!(
+        json,
+        r#"{"__ROW_PATH__":[[],["c"],["b"],["a"]],"x":[6,3,2,1]}"#
+    );
+    view.update_config(ViewConfigUpdate {
+        filter: Some(vec![not_b()]),
+        ..ViewConfigUpdate::default()
+    })
+    .await?;
+
+    let json = view.to_columns_string(ViewWindow::default()).await?;
+    assert_eq!(json, r#"{"__ROW_PATH__":[[],["c"],["a"]],"x":[4,3,1]}"#);
+    view.update_config(ViewConfigUpdate {
+        sort: Some(vec![]),
+        ..ViewConfigUpdate::default()
+    })
+    .await?;
+
+    let json = view.to_columns_string(ViewWindow::default()).await?;
+    assert_eq!(json, r#"{"__ROW_PATH__":[[],["a"],["c"]],"x":[4,1,3]}"#);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_update_config_filter_keeps_expansion() -> Result<(), Box<dyn Error>> {
+    let server = Server::default();
+    let client = LocalClient::new(&server);
+    let table = letters_table(&client).await?;
+    let view = table
+        .view(Some(ViewConfigUpdate {
+            group_by: Some(vec!["y".to_owned(), "x".to_owned()]),
+            ..ViewConfigUpdate::default()
+        }))
+        .await?;
+
+    // Collapse `a`, leaving the total, `a`, and `b` and `c` with their `x`.
+    view.collapse(1).await?;
+    assert_eq!(view.num_rows().await?, 6);
+    view.update_config(ViewConfigUpdate {
+        filter: Some(vec![not_b()]),
+        ..ViewConfigUpdate::default()
+    })
+    .await?;
+
+    // The total, `a` (still collapsed), and `c` with its `x`.
+    assert_eq!(view.num_rows().await?, 4);
+    view.update_config(ViewConfigUpdate {
+        filter: Some(vec![]),
+        ..ViewConfigUpdate::default()
+    })
+    .await?;
+
+    assert_eq!(view.num_rows().await?, 6);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_update_config_unsorts_flat_views() -> Result<(), Box<dyn Error>> {
+    let server = Server::default();
+    let client = LocalClient::new(&server);
+    let table = letters_table(&client).await?;
+    let view = table
+        .view(Some(ViewConfigUpdate {
+            columns: Some(vec![Some("x".to_owned())]),
+            sort: Some(vec![Sort("x".to_owned(), SortDir::Desc)]),
+            ..ViewConfigUpdate::default()
+        }))
+        .await?;
+
+    view.update_config(ViewConfigUpdate {
+        sort: Some(vec![]),
+        ..ViewConfigUpdate::default()
+    })
+    .await?;
+
+    let json = view.to_columns_string(ViewWindow::default()).await?;
+    assert_eq!(json, r#"{"x":[1,2,3]}"#);
+    Ok(())
+}
@@ -0,0 +1,138 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use std::error::Error;
+use std::sync::Arc;
+
+use perspective::LocalClient;
+use perspective_client::{
+    OnUpdateMode, OnUpdateOptions, Table, TableInitOptions, UpdateData, UpdateOptions, View,
+    ViewWindow,
+};
+use tokio::sync::Mutex;
+
+async fn subscribe(
+    view: &View,
+    options: OnUpdateOptions,
+) -> Result<(u32, Arc<Mutex<Vec<u64>>>), Box<dyn Error>> {
+    let sequences: Arc<Mutex<Vec<u64>>> = Arc::default();
+    let id = view
+        .on_update(
+            {
+                let sequences = sequences.clone();
+                move |resp| {
+                    let sequences = sequences.clone();
+                    async move { sequences.lock().await.push(resp.sequence) }
+                }
+            },
+            options,
+        )
+        .await?;
+
+    Ok((id, sequences))
+}
+
+async fn update(table: &Table, csv: &str) -> Result<(), Box<dyn Error>> {
+    table
+        .update(UpdateData::Csv(csv.to_owned()), UpdateOptions::default())
+        .await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_on_update_sequence_increments_per_subscription() -> Result<(), Box<dyn Error>> {
+    let server = perspective::server::Server::default();
+    let client = LocalClient::new(&server);
+    let table = client
+        .table(
+            UpdateData::Csv("x\n1".to_owned()).into(),
+            TableInitOptions::default(),
+        )
+        .await?;
+
+    let view = table.view(None).await?;
+    let (id, first) = subscribe(&view, OnUpdateOptions {
+        mode: Some(OnUpdateMode::Row),
+        ..OnUpdateOptions::default()
+    })
+    .await?;
+
+    update(&table, "x\n2").await?;
+    let (_, second) = subscribe(&view, OnUpdateOptions::default()).await?;
+    update(&table, "x\n3").await?;
+    update(&table, "x\n4").await?;
+    assert_eq!(*first.lock().await, vec![1, 2, 3]);
+    assert_eq!(*second.lock().await, vec![1, 2]);
+
+    let resync = view.resync(id).await?;
+    assert_eq!(resync.sequence, 3);
+    assert!(resync.arrow.is_some_and(|x| !x.is_empty()));
+    assert!(resync.viewport.is_none());
+
+    update(&table, "x\n5").await?;
+    assert_eq!(first.lock().await.last(), Some(&4));
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_resync_returns_viewport_rows() -> Result<(), Box<dyn Error>> {
+    let server = perspective::server::Server::default();
+    let client = LocalClient::new(&server);
+    let table = client
+        .table(
+            UpdateData::Csv("id,x\n1,a\n2,b\n3,c".to_owned()).into(),
+            TableInitOptions {
+                index: Some("id".to_owned()),
+                ..TableInitOptions::default()
+            },
+        )
+        .await?;
+
+    let view = table.view(None).await?;
+    let (id, sequences) = subscribe(&view, OnUpdateOptions {
+        mode: Some(OnUpdateMode::Viewport),
+        viewport: Some(ViewWindow {
+            start_row: Some(0.0),
+            end_row: Some(2.0),
+            ..ViewWindow::default()
+        }),
+    })
+    .await?;
+
+    update(&table, "id,x\n2,y").await?;
+    assert_eq!(*sequences.lock().await, vec![1, 2]);
+
+    let resync = view.resync(id).await?;
+    assert_eq!(resync.sequence, 2);
+    let viewport = resync.viewport.expect("viewport subscription");
+    assert_eq!(viewport.num_rows, 2);
+    assert_eq!(viewport.rows.len(), 2);
+    assert!(resync.arrow.is_none());
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_resync_rejects_unknown_subscription() -> Result<(), Box<dyn Error>> {
+    let server = perspective::server::Server::default();
+    let client = LocalClient::new(&server);
+    let table = client
+        .table(
+            UpdateData::Csv("x\n1".to_owned()).into(),
+            TableInitOptions::default(),
+        )
+        .await?;
+
+    let view = table.view(None).await?;
+    assert!(view.resync(12345).await.is_err());
+    Ok(())
+}
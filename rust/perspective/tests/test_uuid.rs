@@ -0,0 +1,111 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use std::error::Error;
+
+use perspective::LocalClient;
+use perspective_client::config::ViewConfigUpdate;
+use perspective_client::{
+    ColumnType, TableData, TableInitOptions, UpdateData, UpdateOptions, ViewWindow,
+};
+
+const LOW: &str = "00000000-0000-4000-8000-000000000001";
+const HIGH: &str = "f81d4fae-7dec-11d0-a765-00a0c91e6bf6";
+
+async fn uuid_table(client: &LocalClient) -> Result<perspective_client::Table, Box<dyn Error>> {
+    let table = client
+        .table(
+            TableData::Schema(vec![
+                ("id".to_owned(), ColumnType::Uuid),
+                ("x".to_owned(), ColumnType::Integer),
+            ]),
+            TableInitOptions {
+                index: Some("id".to_owned()),
+                ..TableInitOptions::default()
+            },
+        )
+        .await?;
+
+    table
+        .update(
+            UpdateData::JsonRows(format!(
+                r#"[{{"id": "{HIGH}", "x": 1}}, {{"id": "{LOW}", "x": 2}}]"#
+            )),
+            UpdateOptions::default(),
+        )
+        .await?;
+
+    Ok(table)
+}
+
+#[tokio::test]
+async fn test_uuid_index_upserts_any_format() -> Result<(), Box<dyn Error>> {
+    let server = perspective::server::Server::default();
+    let client = LocalClient::new(&server);
+    let table = uuid_table(&client).await?;
+    assert_eq!(table.schema().await?.get("id"), Some(&ColumnType::Uuid));
+    table
+        .update(
+            UpdateData::JsonRows(
+                r#"[
+                    {"id": "{F81D4FAE-7DEC-11D0-A765-00A0C91E6BF6}", "x": 3},
+                    {"id": "urn:uuid:00000000000040008000000000000001", "x": 4}
+                ]"#
+                .to_owned(),
+            ),
+            UpdateOptions::default(),
+        )
+        .await?;
+
+    let view = table.view(None).await?;
+    let json = view.to_columns_string(ViewWindow::default()).await?;
+    assert_eq!(json, format!(r#"{{"id":["{LOW}","{HIGH}"],"x":[4,3]}}"#));
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_uuid_column_rejects_invalid_uuid() -> Result<(), Box<dyn Error>> {
+    let server = perspective::server::Server::default();
+    let client = LocalClient::new(&server);
+    let table = uuid_table(&client).await?;
+    let result = table
+        .update(
+            UpdateData::JsonRows(r#"[{"id": "f81d4fae-7dec-11d0", "x": 5}]"#.to_owned()),
+            UpdateOptions::default(),
+        )
+        .await;
+
+    assert!(result.is_err());
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_uuid_group_by() -> Result<(), Box<dyn Error>> {
+    let server = perspective::server::Server::default();
+    let client = LocalClient::new(&server);
+    let table = uuid_table(&client).await?;
+    let view = table
+        .view(Some(ViewConfigUpdate {
+            group_by: Some(vec!["id".to_owned()]),
+            columns: Some(vec![Some("x".to_owned())]),
+            ..ViewConfigUpdate::default()
+        }))
+        .await?;
+
+    let json = view.to_columns_string(ViewWindow::default()).await?;
+    assert_eq!(
+        json,
+        format!(r#"{{"__ROW_PATH__":[[],["{LOW}"],["{HIGH}"]],"x":[3,2,1]}}"#)
+    );
+
+    Ok(())
+}
@@ -0,0 +1,88 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use std::collections::HashMap;
+use std::error::Error;
+
+use perspective::LocalClient;
+use perspective_client::config::{Expressions, ViewConfigUpdate};
+use perspective_client::{TableInitOptions, UpdateData, ViewWindow};
+
+const ROWS: &str = r#"[
+    {"x": 1.5, "y": 2, "z": 0.5},
+    {"x": null, "y": 0, "z": 3.0},
+    {"x": -2.0, "y": 4, "z": -2.0}
+]"#;
+
+async fn eval_columns(exprs: &[(&str, &str)]) -> Result<String, Box<dyn Error>> {
+    let server = perspective::server::Server::default();
+    let client = LocalClient::new(&server);
+    let table = client
+        .table(
+            UpdateData::JsonRows(ROWS.to_owned()).into(),
+            TableInitOptions::default(),
+        )
+        .await?;
+
+    let view = table
+        .view(Some(ViewConfigUpdate {
+            columns: Some(
+                exprs
+                    .iter()
+                    .map(|(name, _)| Some(name.to_string()))
+                    .collect(),
+            ),
+            expressions: Some(Expressions(HashMap::from_iter(
+                exprs
+                    .iter()
+                    .map(|(name, expr)| (name.to_string(), expr.to_string())),
+            ))),
+            ..ViewConfigUpdate::default()
+        }))
+        .await?;
+
+    Ok(view.to_columns_string(ViewWindow::default()).await?)
+}
+
+#[tokio::test]
+async fn test_numeric_arithmetic() -> Result<(), Box<dyn Error>> {
+    let json = eval_columns(&[
+        ("sum", r#""x" + "y" * 2"#),
+        ("ratio", r#"("z" - 1) / "y""#),
+        ("negated", r#"-"x" * 3"#),
+    ])
+    .await?;
+
+    assert_eq!(
+        json,
+        r#"{"sum":[5.5,null,6.0],"ratio":[-0.25,null,-0.75],"negated":[-4.5,null,6.0]}"#
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_numeric_comparisons() -> Result<(), Box<dyn Error>> {
+    let json = eval_columns(&[
+        ("float", r#""z" > 0"#),
+        ("same_column", r#""z" == "z""#),
+        ("with_null", r#""x" > 0"#),
+    ])
+    .await?;
+
+    assert_eq!(
+        json,
+        r#"{"float":[true,true,false],"same_column":[true,true,true],"with_null":[true,false,false]}"#
+    );
+
+    Ok(())
+}
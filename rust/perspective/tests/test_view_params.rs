@@ -0,0 +1,143 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use std::collections::HashMap;
+use std::error::Error;
+use std::sync::Arc;
+
+use perspective::server::Server;
+use perspective::LocalClient;
+use perspective_client::config::{Filter, FilterTerm, Scalar, ViewConfigUpdate};
+use perspective_client::proto::ViewOnUpdateResp;
+use perspective_client::{OnUpdateOptions, Table, TableInitOptions, UpdateData, ViewWindow};
+use tokio::sync::Mutex;
+
+async fn rates_table(client: &LocalClient) -> Result<Table, Box<dyn Error>> {
+    Ok(client
+        .table(
+            UpdateData::Csv(
+                "id,region,rate\nEUR,eu,1.08\nGBP,eu,1.27\nJPY,asia,0.0067\nCNY,asia,0.14"
+                    .to_owned(),
+            )
+            .into(),
+            TableInitOptions {
+                index: Some("id".to_owned()),
+                ..TableInitOptions::default()
+            },
+        )
+        .await?)
+}
+
+fn param_filter(column: &str, op: &str, name: &str) -> Filter {
+    Filter::new(
+        column.to_owned(),
+        op.to_owned(),
+        FilterTerm::Scalar(Scalar::String(format!(":{}", name))),
+    )
+}
+
+#[tokio::test]
+async fn test_set_params_rebinds_filters_in_place() -> Result<(), Box<dyn Error>> {
+    let server = Server::default();
+    let client = LocalClient::new(&server);
+    let table = rates_table(&client).await?;
+    let view = table
+        .view(Some(ViewConfigUpdate {
+            columns: Some(vec![Some("id".to_owned())]),
+            filter: Some(vec![param_filter("region", "==", "region")]),
+            params: Some(HashMap::from([(
+                "region".to_owned(),
+                Scalar::String("eu".to_owned()),
+            )])),
+            ..ViewConfigUpdate::default()
+        }))
+        .await?;
+
+    let json = view.to_columns_string(ViewWindow::default()).await?;
+    assert_eq!(json, r#"{"id":["EUR","GBP"]}"#);
+
+    let updates: Arc<Mutex<Vec<ViewOnUpdateResp>>> = Arc::default();
+    view.on_update(
+        {
+            let updates = updates.clone();
+            move |update| {
+                let updates = updates.clone();
+                async move { updates.lock().await.push(update) }
+            }
+        },
+        OnUpdateOptions::default(),
+    )
+    .await?;
+
+    view.set_params(HashMap::from([(
+        "region".to_owned(),
+        Scalar::String("asia".to_owned()),
+    )]))
+    .await?;
+
+    let json = view.to_columns_string(ViewWindow::default()).await?;
+    assert_eq!(json, r#"{"id":["CNY","JPY"]}"#);
+    assert_eq!(updates.lock().await.len(), 1);
+
+    let config = view.get_config().await?;
+    assert_eq!(config.filter, vec![param_filter("region", "==", "region")]);
+    assert_eq!(
+        config.params,
+        HashMap::from([("region".to_owned(), Scalar::String("asia".to_owned()))])
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_set_params_keeps_expand_state() -> Result<(), Box<dyn Error>> {
+    let server = Server::default();
+    let client = LocalClient::new(&server);
+    let table = rates_table(&client).await?;
+    let view = table
+        .view(Some(ViewConfigUpdate {
+            group_by: Some(vec!["region".to_owned(), "id".to_owned()]),
+            filter: Some(vec![param_filter("rate", ">", "min")]),
+            params: Some(HashMap::from([("min".to_owned(), Scalar::Float(0.0))])),
+            ..ViewConfigUpdate::default()
+        }))
+        .await?;
+
+    // The total, `asia` and `eu` rows, and a row for each `id`.
+    assert_eq!(view.num_rows().await?, 7);
+    view.collapse(1).await?;
+    assert_eq!(view.num_rows().await?, 5);
+
+    view.set_params(HashMap::from([("min".to_owned(), Scalar::Float(0.1))]))
+        .await?;
+
+    // `asia` is still collapsed, and `JPY` is filtered out of it.
+    assert_eq!(view.num_rows().await?, 5);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_set_params_rejects_unknown_params() -> Result<(), Box<dyn Error>> {
+    let server = Server::default();
+    let client = LocalClient::new(&server);
+    let table = rates_table(&client).await?;
+    let view = table.view(None).await?;
+    let result = view
+        .set_params(HashMap::from([(
+            "region".to_owned(),
+            Scalar::String("eu".to_owned()),
+        )]))
+        .await;
+
+    assert!(result.is_err());
+    Ok(())
+}
@@ -0,0 +1,131 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use std::collections::HashMap;
+use std::error::Error;
+use std::sync::Arc;
+
+use perspective::LocalClient;
+use perspective_client::proto::ViewportUpdate;
+use perspective_client::{
+    OnUpdateMode, OnUpdateOptions, TableInitOptions, UpdateData, UpdateOptions, View, ViewWindow,
+};
+use tokio::sync::Mutex;
+
+async fn subscribe_viewport(
+    view: &View,
+    viewport: ViewWindow,
+) -> Result<Arc<Mutex<Vec<ViewportUpdate>>>, Box<dyn Error>> {
+    let updates: Arc<Mutex<Vec<ViewportUpdate>>> = Arc::default();
+    view.on_update(
+        {
+            let updates = updates.clone();
+            move |resp| {
+                let updates = updates.clone();
+                async move { updates.lock().await.extend(resp.viewport) }
+            }
+        },
+        OnUpdateOptions {
+            mode: Some(OnUpdateMode::Viewport),
+            viewport: Some(viewport),
+        },
+    )
+    .await?;
+
+    Ok(updates)
+}
+
+fn rows(rows: &[(u64, &str)]) -> HashMap<u64, String> {
+    rows.iter().map(|(k, v)| (*k, v.to_string())).collect()
+}
+
+#[tokio::test]
+async fn test_viewport_pushes_only_changed_rows() -> Result<(), Box<dyn Error>> {
+    let server = perspective::server::Server::default();
+    let client = LocalClient::new(&server);
+    let table = client
+        .table(
+            UpdateData::Csv("id,x\n1,a\n2,b\n3,c\n4,d".to_owned()).into(),
+            TableInitOptions {
+                index: Some("id".to_owned()),
+                ..TableInitOptions::default()
+            },
+        )
+        .await?;
+
+    let view = table.view(None).await?;
+    let updates = subscribe_viewport(&view, ViewWindow {
+        start_row: Some(0.0),
+        end_row: Some(2.0),
+        ..ViewWindow::default()
+    })
+    .await?;
+
+    // Outside of the window
+    table
+        .update(
+            UpdateData::Csv("id,x\n4,z".to_owned()),
+            UpdateOptions::default(),
+        )
+        .await?;
+
+    // Inside of the window
+    table
+        .update(
+            UpdateData::Csv("id,x\n2,y".to_owned()),
+            UpdateOptions::default(),
+        )
+        .await?;
+
+    let updates = updates.lock().await;
+    assert_eq!(updates.len(), 2);
+    assert_eq!(updates[0].num_rows, 2);
+    assert_eq!(
+        updates[0].rows,
+        rows(&[(0, r#"{"id":1,"x":"a"}"#), (1, r#"{"id":2,"x":"b"}"#)])
+    );
+
+    assert_eq!(updates[1].num_rows, 2);
+    assert_eq!(updates[1].rows, rows(&[(1, r#"{"id":2,"x":"y"}"#)]));
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_viewport_reports_rows_leaving() -> Result<(), Box<dyn Error>> {
+    let server = perspective::server::Server::default();
+    let client = LocalClient::new(&server);
+    let table = client
+        .table(
+            UpdateData::Csv("id,x\n1,a\n2,b\n3,c".to_owned()).into(),
+            TableInitOptions {
+                index: Some("id".to_owned()),
+                ..TableInitOptions::default()
+            },
+        )
+        .await?;
+
+    let view = table.view(None).await?;
+    let updates = subscribe_viewport(&view, ViewWindow {
+        start_row: Some(1.0),
+        ..ViewWindow::default()
+    })
+    .await?;
+
+    table.remove(UpdateData::JsonRows("[3]".to_owned())).await?;
+
+    let updates = updates.lock().await;
+    assert_eq!(updates.len(), 2);
+    assert_eq!(updates[0].num_rows, 2);
+    assert_eq!(updates[1].num_rows, 1);
+    assert!(updates[1].rows.is_empty());
+    Ok(())
+}
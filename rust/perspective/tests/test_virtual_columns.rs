@@ -0,0 +1,96 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use std::collections::HashMap;
+use std::error::Error;
+
+use perspective::server::Server;
+use perspective::LocalClient;
+use perspective_client::{Table, TableInitOptions, UpdateData, UpdateOptions, ViewWindow};
+
+fn mid() -> HashMap<String, String> {
+    HashMap::from([("mid".to_owned(), r#"("bid" + "ask") / 2"#.to_owned())])
+}
+
+async fn quotes_table(client: &LocalClient) -> Result<Table, Box<dyn Error>> {
+    Ok(client
+        .table(
+            UpdateData::Csv("sym,bid,ask\nAAPL,99,101\nMSFT,199,201".to_owned()).into(),
+            TableInitOptions {
+                index: Some("sym".to_owned()),
+                virtual_columns: Some(mid()),
+                ..TableInitOptions::default()
+            },
+        )
+        .await?)
+}
+
+#[tokio::test]
+async fn test_virtual_columns_appear_in_views() -> Result<(), Box<dyn Error>> {
+    let server = Server::default();
+    let client = LocalClient::new(&server);
+    let table = quotes_table(&client).await?;
+    assert_eq!(table.virtual_columns().await?, mid());
+    assert!(table.schema().await?.contains_key("mid"));
+
+    let view = table.view(None).await?;
+    let json = view.to_columns_string(ViewWindow::default()).await?;
+    assert_eq!(
+        json,
+        r#"{"sym":["AAPL","MSFT"],"bid":[99,199],"ask":[101,201],"mid":[100.0,200.0]}"#
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_virtual_columns_recompute_on_partial_update() -> Result<(), Box<dyn Error>> {
+    let server = Server::default();
+    let client = LocalClient::new(&server);
+    let table = quotes_table(&client).await?;
+    table
+        .update(
+            UpdateData::JsonRows(r#"[{"sym": "AAPL", "ask": 103}]"#.to_owned()),
+            UpdateOptions::default(),
+        )
+        .await?;
+
+    let view = table.view(None).await?;
+    let json = view.to_columns_string(ViewWindow::default()).await?;
+    assert_eq!(
+        json,
+        r#"{"sym":["AAPL","MSFT"],"bid":[99,199],"ask":[103,201],"mid":[101.0,200.0]}"#
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_virtual_columns_reject_invalid_expressions() -> Result<(), Box<dyn Error>> {
+    let server = Server::default();
+    let client = LocalClient::new(&server);
+    let result = client
+        .table(
+            UpdateData::Csv("sym,bid,ask\nAAPL,99,101".to_owned()).into(),
+            TableInitOptions {
+                virtual_columns: Some(HashMap::from([(
+                    "mid".to_owned(),
+                    r#""nope" + 1"#.to_owned(),
+                )])),
+                ..TableInitOptions::default()
+            },
+        )
+        .await;
+
+    assert!(result.is_err());
+    Ok(())
+}
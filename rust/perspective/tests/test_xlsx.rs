@@ -0,0 +1,89 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+#![cfg(feature = "xlsx")]
+
+use std::error::Error;
+use std::io::{Cursor, Read};
+
+use perspective::client::config::ViewConfigUpdate;
+use perspective::client::{TableInitOptions, UpdateData};
+use perspective::server::Server;
+use perspective::LocalClient;
+
+/// Read the file `name` from the XLSX (zip) archive `bytes`.
+fn read_part(bytes: &[u8], name: &str) -> Result<String, Box<dyn Error>> {
+    let mut archive = zip::ZipArchive::new(Cursor::new(bytes))?;
+    let mut part = String::new();
+    archive.by_name(name)?.read_to_string(&mut part)?;
+    Ok(part)
+}
+
+#[tokio::test]
+async fn test_to_xlsx_writes_typed_cells() -> Result<(), Box<dyn Error>> {
+    let server = Server::default();
+    let client = LocalClient::new(&server);
+    let table = client
+        .table(
+            UpdateData::Csv("x,y,s\n1,1.5,a\n2,2.5,b".to_owned()).into(),
+            TableInitOptions::default(),
+        )
+        .await?;
+
+    let bytes = table.view(None).await?.to_xlsx().await?;
+    let sheet = read_part(&bytes, "xl/worksheets/sheet1.xml")?;
+    let strings = read_part(&bytes, "xl/sharedStrings.xml")?;
+
+    // Numbers are written as numeric cells, strings via the shared table.
+    assert!(sheet.contains("<v>1.5</v>"));
+    assert!(sheet.contains("<v>2</v>"));
+    for text in ["x", "y", "s", "a", "b"] {
+        assert!(strings.contains(&format!("<t>{}</t>", text)));
+    }
+
+    assert!(!strings.contains("<t>1.5</t>"));
+
+    // The single header row is frozen.
+    assert!(sheet.contains(r#"ySplit="1""#));
+    client.close().await;
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_to_xlsx_merges_split_by_headers() -> Result<(), Box<dyn Error>> {
+    let server = Server::default();
+    let client = LocalClient::new(&server);
+    let table = client
+        .table(
+            UpdateData::Csv("x,y,s\n1,1.5,a\n2,2.5,b".to_owned()).into(),
+            TableInitOptions::default(),
+        )
+        .await?;
+
+    let view = table
+        .view(Some(ViewConfigUpdate {
+            split_by: Some(vec!["s".to_owned()]),
+            columns: Some(vec![Some("x".to_owned()), Some("y".to_owned())]),
+            ..ViewConfigUpdate::default()
+        }))
+        .await?;
+
+    let bytes = view.to_xlsx().await?;
+    let sheet = read_part(&bytes, "xl/worksheets/sheet1.xml")?;
+
+    // `a|x`, `a|y`, `b|x`, `b|y` become two header rows, with each split
+    // group merged across its two columns.
+    assert!(sheet.contains(r#"<mergeCell ref="A1:B1"/>"#));
+    assert!(sheet.contains(r#"<mergeCell ref="C1:D1"/>"#));
+    assert!(sheet.contains(r#"ySplit="2""#));
+    client.close().await;
+    Ok(())
+}